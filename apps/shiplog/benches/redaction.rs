@@ -80,9 +80,19 @@ fn bench_redact_workstreams(c: &mut Criterion) {
                         pull_requests: 6,
                         reviews: 0,
                         manual_events: 0,
+                        issues: 0,
+                        deployments: 0,
+                        releases: 0,
+                        comments: 0,
+                        incidents: 0,
+                        documents: 0,
+                        logged_minutes: 0,
+                        elapsed_minutes: 0,
                     },
                     events: ws_events.clone(),
                     receipts: ws_events,
+                    receipt_weights: Default::default(),
+                    rules: Default::default(),
                 }
             })
             .collect(),
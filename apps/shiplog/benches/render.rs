@@ -0,0 +1,64 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use shiplog::fixtures::{FixtureSpec, generate};
+use shiplog::merge::{MergeStrategy, merge_events};
+use shiplog::ports::{Renderer, WorkstreamClusterer};
+use shiplog::render::md::MarkdownRenderer;
+use shiplog::workstreams::RepoClusterer;
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+fn fixture(events: usize, seed: u64) -> shiplog::fixtures::Fixture {
+    generate(&FixtureSpec {
+        events,
+        repos: 20,
+        seed,
+        ..FixtureSpec::default()
+    })
+    .unwrap()
+}
+
+fn bench_render_packet(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render_packet_markdown");
+    let renderer = MarkdownRenderer::new();
+
+    for size in SIZES {
+        let fixture = fixture(size, 1);
+        let workstreams = RepoClusterer.cluster(&fixture.events).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(size), &fixture, |b, fixture| {
+            b.iter(|| {
+                renderer.render_packet_markdown(
+                    "demo-dev",
+                    "2025",
+                    black_box(&fixture.events),
+                    &workstreams,
+                    &fixture.coverage,
+                )
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_merge(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge_events");
+
+    for size in SIZES {
+        // Same seed twice gives every event a duplicate; a third seed adds
+        // distinct events on top.
+        let sources = vec![
+            fixture(size, 1).events,
+            fixture(size, 1).events,
+            fixture(size, 2).events,
+        ];
+        group.bench_with_input(BenchmarkId::from_parameter(size), &sources, |b, sources| {
+            b.iter(|| merge_events(black_box(sources.clone()), &MergeStrategy::KeepMostComplete));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_render_packet, bench_merge);
+criterion_main!(benches);
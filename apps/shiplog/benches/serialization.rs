@@ -1,6 +1,6 @@
 use chrono::{NaiveDate, TimeZone, Utc};
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use shiplog::ids::{EventId, RunId, WorkstreamId};
+use shiplog::ids::{EventId, OpaqueId, RunId, WorkstreamId};
 use shiplog::schema::coverage::*;
 use shiplog::schema::event::*;
 use shiplog::schema::workstream::*;
@@ -9,6 +9,7 @@ use std::hint::black_box;
 fn make_pr_event(i: u64) -> EventEnvelope {
     let ts = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", "acme/widgets", &i.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: ts,
@@ -56,14 +57,18 @@ fn make_pr_event(i: u64) -> EventEnvelope {
             url: Some(format!(
                 "https://api.github.com/repos/acme/widgets/pulls/{i}"
             )),
-            opaque_id: Some(format!("PR_node_{i}")),
+            opaque_id: Some(OpaqueId::from_raw("test", &format!("PR_node_{i}"))),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_review_event(i: u64) -> EventEnvelope {
     let ts = Utc.with_ymd_and_hms(2025, 2, 10, 9, 30, 0).unwrap();
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "review", "acme/widgets", &i.to_string(), "1"]),
         kind: EventKind::Review,
         occurred_at: ts,
@@ -82,6 +87,8 @@ fn make_review_event(i: u64) -> EventEnvelope {
             submitted_at: ts,
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec!["review".into()],
         links: vec![],
@@ -90,6 +97,9 @@ fn make_review_event(i: u64) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -153,6 +163,7 @@ fn bench_event_jsonl_roundtrip(c: &mut Criterion) {
 
 fn bench_coverage_manifest_serialize(c: &mut Criterion) {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("bench_run_123456".into()),
         generated_at: Utc::now(),
         user: "octocat".into(),
@@ -177,10 +188,14 @@ fn bench_coverage_manifest_serialize(c: &mut Criterion) {
                 fetched: 10 + i as u64,
                 incomplete_results: Some(false),
                 notes: vec!["all fetched".into()],
+                source: None,
             })
             .collect(),
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let mut group = c.benchmark_group("coverage_manifest");
@@ -209,6 +224,14 @@ fn bench_workstreams_file_serialize(c: &mut Criterion) {
                     pull_requests: 8,
                     reviews: 3,
                     manual_events: 1,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: (0..8)
                     .map(|j| {
@@ -230,6 +253,8 @@ fn bench_workstreams_file_serialize(c: &mut Criterion) {
                         ])
                     })
                     .collect(),
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             })
             .collect(),
     };
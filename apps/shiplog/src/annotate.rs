@@ -0,0 +1,282 @@
+//! User annotations on ledger events.
+//!
+//! `ledger.events.jsonl` is rewritten by every refresh, so context added to
+//! it by hand does not survive. `annotations.yaml` in the run directory keeps
+//! tags, notes, and importance scores keyed by event ID instead, and the
+//! engine merges them into the events before clustering and rendering.
+//!
+//! ```yaml
+//! version: 1
+//! annotations:
+//!   9f2c4e0b7a1d3e5f:  # event ID from ledger.events.jsonl
+//!     tags: [launch]
+//!     note: Unblocked the Q3 billing migration.
+//!     importance: 5
+//! ```
+//!
+//! Tags are appended to the event's own tags. The note is stored in the
+//! [`NOTE_EXTENSION`] extension, which redaction strips from manager and
+//! public packets; the importance is stored in [`IMPORTANCE_EXTENSION`] and
+//! raises or lowers the event's receipt weight.
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use shiplog::ids::EventId;
+use shiplog::schema::event::EventEnvelope;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// File name of the annotations file inside a run directory.
+pub const ANNOTATIONS_FILENAME: &str = "annotations.yaml";
+
+/// Event extension holding an annotation note. Internal-only.
+pub const NOTE_EXTENSION: &str = "internal.note";
+
+/// Event extension holding an annotation importance score.
+pub const IMPORTANCE_EXTENSION: &str = "public.importance";
+
+/// Highest accepted importance score. Scores run from 1 to this value.
+pub const MAX_IMPORTANCE: u8 = 5;
+
+/// What a user attached to one event.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// Extra tags for the event.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Free-text context, shown beside the receipt in the internal packet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Importance from 1 (minor) to 5 (headline).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub importance: Option<u8>,
+}
+
+/// Contents of `annotations.yaml`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnotationsFile {
+    /// File format version.
+    pub version: u32,
+    /// Annotations keyed by event ID.
+    #[serde(default)]
+    pub annotations: BTreeMap<EventId, Annotation>,
+}
+
+impl Default for AnnotationsFile {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            annotations: BTreeMap::new(),
+        }
+    }
+}
+
+impl AnnotationsFile {
+    /// Merge the annotations into `events`, returning how many events were
+    /// annotated. Annotations for events not in the slice are ignored, so an
+    /// annotation outlives a run that happens not to collect its event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::annotate::{Annotation, AnnotationsFile, IMPORTANCE_EXTENSION};
+    /// use shiplog_testkit::pr_event;
+    ///
+    /// let mut events = vec![pr_event("acme/payments", 42, "Migrate invoices")];
+    /// let mut file = AnnotationsFile::default();
+    /// file.annotations.insert(
+    ///     events[0].id.clone(),
+    ///     Annotation {
+    ///         tags: vec!["launch".into()],
+    ///         note: None,
+    ///         importance: Some(5),
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(file.apply(&mut events), 1);
+    /// assert!(events[0].tags.contains(&"launch".to_string()));
+    /// assert_eq!(events[0].extensions[IMPORTANCE_EXTENSION], 5);
+    /// ```
+    pub fn apply(&self, events: &mut [EventEnvelope]) -> usize {
+        let mut applied = 0;
+        for event in events {
+            if let Some(annotation) = self.annotations.get(&event.id) {
+                annotation.apply(event);
+                applied += 1;
+            }
+        }
+        applied
+    }
+
+    /// Reject importance scores outside `1..=MAX_IMPORTANCE`.
+    pub fn validate(&self) -> Result<()> {
+        for (id, annotation) in &self.annotations {
+            if let Some(importance) = annotation.importance
+                && !(1..=MAX_IMPORTANCE).contains(&importance)
+            {
+                bail!(
+                    "annotation for {id}: importance must be 1-{MAX_IMPORTANCE}, got {importance}"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Annotation {
+    fn apply(&self, event: &mut EventEnvelope) {
+        for tag in &self.tags {
+            let tag = tag.trim();
+            if !tag.is_empty() && !event.tags.iter().any(|existing| existing == tag) {
+                event.tags.push(tag.to_string());
+            }
+        }
+        if let Some(note) = self
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|note| !note.is_empty())
+        {
+            event
+                .extensions
+                .insert(NOTE_EXTENSION.to_string(), note.into());
+        }
+        if let Some(importance) = self.importance {
+            event
+                .extensions
+                .insert(IMPORTANCE_EXTENSION.to_string(), importance.into());
+        }
+    }
+}
+
+/// Path of `annotations.yaml` in `run_dir`.
+pub fn annotations_path(run_dir: &Path) -> PathBuf {
+    run_dir.join(ANNOTATIONS_FILENAME)
+}
+
+/// Read and validate an annotations file, or an empty one when the file
+/// does not exist.
+pub fn read_annotations(path: &Path) -> Result<AnnotationsFile> {
+    if !path.exists() {
+        return Ok(AnnotationsFile::default());
+    }
+    let text =
+        std::fs::read_to_string(path).with_context(|| format!("read annotations from {path:?}"))?;
+    let file: AnnotationsFile =
+        serde_yaml::from_str(&text).with_context(|| format!("parse annotations yaml {path:?}"))?;
+    file.validate()
+        .with_context(|| format!("validate annotations {path:?}"))?;
+    Ok(file)
+}
+
+/// Importance score an annotation left on `event`, if any.
+#[must_use]
+pub fn event_importance(event: &EventEnvelope) -> Option<u8> {
+    event
+        .extensions
+        .get(IMPORTANCE_EXTENSION)
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|value| u8::try_from(value).ok())
+}
+
+/// Annotation note left on `event`, if any.
+#[must_use]
+pub fn event_note(event: &EventEnvelope) -> Option<&str> {
+    event
+        .extensions
+        .get(NOTE_EXTENSION)
+        .and_then(serde_json::Value::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use shiplog::schema::event::*;
+
+    fn pr(number: u64, tags: &[&str]) -> EventEnvelope {
+        let at = Utc.with_ymd_and_hms(2025, 3, 3, 12, 0, 0).unwrap();
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["test", "acme/app", &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: at,
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "acme/app".into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: format!("PR {number}"),
+                state: PullRequestState::Merged,
+                created_at: at,
+                merged_at: Some(at),
+                additions: None,
+                deletions: None,
+                changed_files: None,
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    #[test]
+    fn apply_merges_tags_note_and_importance() {
+        let mut events = vec![pr(1, &["billing"]), pr(2, &[])];
+        let mut file = AnnotationsFile::default();
+        file.annotations.insert(
+            events[0].id.clone(),
+            Annotation {
+                tags: vec!["billing".into(), " launch ".into(), "".into()],
+                note: Some("  Unblocked the migration. ".into()),
+                importance: Some(4),
+            },
+        );
+        file.annotations
+            .insert(EventId::from_parts(["gone"]), Annotation::default());
+
+        assert_eq!(file.apply(&mut events), 1);
+        assert_eq!(events[0].tags, vec!["billing", "launch"]);
+        assert_eq!(event_note(&events[0]), Some("Unblocked the migration."));
+        assert_eq!(event_importance(&events[0]), Some(4));
+        assert_eq!(events[1], pr(2, &[]));
+
+        // Applying twice, as a re-render does, changes nothing.
+        let once = events.clone();
+        file.apply(&mut events);
+        assert_eq!(events, once);
+    }
+
+    #[test]
+    fn read_annotations_handles_missing_files_and_bad_scores() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = annotations_path(dir.path());
+        assert_eq!(read_annotations(&path).unwrap(), AnnotationsFile::default());
+
+        std::fs::write(
+            &path,
+            "version: 1\nannotations:\n  abc123:\n    importance: 9\n",
+        )
+        .unwrap();
+        let err = read_annotations(&path).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("importance must be 1-5"),
+            "{err:#}"
+        );
+    }
+}
@@ -0,0 +1,493 @@
+//! Career-ladder rubric comparison.
+//!
+//! A rubric lists levels, and each level lists the competencies expected at
+//! it. A competency names keywords and the evidence types that usually back
+//! it. Events match a competency when their text, or the title, summary, or
+//! tags of their workstream, hit one of the keywords and their kind is an
+//! expected type. Matches that were selected as curated receipts count as
+//! strong evidence.
+//!
+//! Like readiness, the result describes the packet's evidence, not the person:
+//! a thin competency may mean the work happened but was never captured.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::ids::EventId;
+use crate::schema::event::{EventEnvelope, EventKind, EventPayload};
+use crate::schema::workstream::{Workstream, WorkstreamsFile};
+
+/// Curated receipts a competency needs to count as strong, unless the rubric
+/// sets `min_receipts`.
+pub const DEFAULT_MIN_RECEIPTS: usize = 2;
+
+/// A career-ladder rubric, usually read from `rubric.yaml`.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::assess::Rubric;
+///
+/// let rubric: Rubric = serde_yaml::from_str(
+///     "levels:\n  - name: Senior\n    competencies:\n      - name: Design\n        keywords: [design]\n",
+/// )
+/// .unwrap();
+/// assert_eq!(rubric.level(None).unwrap().name, "Senior");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rubric {
+    /// Display name, such as the ladder's title.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Levels, in ladder order.
+    pub levels: Vec<RubricLevel>,
+}
+
+/// One level of a rubric.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RubricLevel {
+    /// Level name, such as `Senior`.
+    pub name: String,
+    /// Competencies expected at this level.
+    pub competencies: Vec<Competency>,
+}
+
+/// One competency and the evidence that backs it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Competency {
+    /// Competency name, such as `Technical design`.
+    pub name: String,
+    /// Words or phrases that signal the competency, matched as whole words
+    /// ignoring case. Empty matches any text.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Event kinds that back the competency. Empty accepts any kind.
+    #[serde(default)]
+    pub evidence: Vec<EvidenceType>,
+    /// Curated receipts needed to count as strong.
+    #[serde(default = "default_min_receipts")]
+    pub min_receipts: usize,
+}
+
+fn default_min_receipts() -> usize {
+    DEFAULT_MIN_RECEIPTS
+}
+
+/// Event kind named in a rubric's `evidence` list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceType {
+    /// Authored pull or merge requests.
+    PullRequest,
+    /// Code reviews.
+    Review,
+    /// Manual events.
+    Manual,
+    /// Issues.
+    Issue,
+    /// Deployments.
+    Deployment,
+    /// Releases.
+    Release,
+    /// Discussion comments.
+    Comment,
+    /// Incidents.
+    Incident,
+    /// Documents and design docs.
+    Document,
+}
+
+impl EvidenceType {
+    /// Rubric spelling, as written in `rubric.yaml`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PullRequest => "pull_request",
+            Self::Review => "review",
+            Self::Manual => "manual",
+            Self::Issue => "issue",
+            Self::Deployment => "deployment",
+            Self::Release => "release",
+            Self::Comment => "comment",
+            Self::Incident => "incident",
+            Self::Document => "document",
+        }
+    }
+
+    fn matches(self, kind: &EventKind) -> bool {
+        matches!(
+            (self, kind),
+            (Self::PullRequest, EventKind::PullRequest)
+                | (Self::Review, EventKind::Review)
+                | (Self::Manual, EventKind::Manual)
+                | (Self::Issue, EventKind::Issue)
+                | (Self::Deployment, EventKind::Deployment)
+                | (Self::Release, EventKind::Release)
+                | (Self::Comment, EventKind::Comment)
+                | (Self::Incident, EventKind::Incident)
+                | (Self::Document, EventKind::Document)
+        )
+    }
+}
+
+impl Rubric {
+    /// Read and validate a rubric YAML file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+        let rubric: Self =
+            serde_yaml::from_str(&text).with_context(|| format!("parse {}", path.display()))?;
+        rubric
+            .validate()
+            .with_context(|| format!("invalid rubric {}", path.display()))?;
+        Ok(rubric)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.levels.is_empty() {
+            anyhow::bail!("rubric has no levels");
+        }
+        for level in &self.levels {
+            if level.name.trim().is_empty() {
+                anyhow::bail!("every level needs a name");
+            }
+            if level.competencies.is_empty() {
+                anyhow::bail!("level {:?} has no competencies", level.name);
+            }
+            for competency in &level.competencies {
+                if competency.name.trim().is_empty() {
+                    anyhow::bail!("level {:?} has a competency without a name", level.name);
+                }
+                if competency.keywords.iter().all(|k| k.trim().is_empty())
+                    && competency.evidence.is_empty()
+                {
+                    anyhow::bail!(
+                        "competency {:?} needs keywords or evidence types",
+                        competency.name
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Select a level by name, ignoring case. `None` picks the only level
+    /// and fails when there are several.
+    pub fn level(&self, name: Option<&str>) -> Result<&RubricLevel> {
+        let names = || {
+            self.levels
+                .iter()
+                .map(|level| level.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match name {
+            Some(name) => self
+                .levels
+                .iter()
+                .find(|level| level.name.eq_ignore_ascii_case(name.trim()))
+                .with_context(|| format!("rubric has no level {name:?}; levels: {}", names())),
+            None => match self.levels.as_slice() {
+                [level] => Ok(level),
+                _ => anyhow::bail!("rubric has several levels; pick one of: {}", names()),
+            },
+        }
+    }
+}
+
+/// How well the packet backs a competency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompetencyStatus {
+    /// No matching events.
+    Missing,
+    /// Matching events, but fewer curated receipts than required.
+    Thin,
+    /// Enough curated receipts.
+    Strong,
+}
+
+impl CompetencyStatus {
+    /// Lowercase label.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Missing => "missing",
+            Self::Thin => "thin",
+            Self::Strong => "strong",
+        }
+    }
+}
+
+/// Result for one competency.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompetencyAssessment {
+    /// Competency name.
+    pub name: String,
+    /// Overall status.
+    pub status: CompetencyStatus,
+    /// Matching events selected as curated receipts.
+    pub receipts: usize,
+    /// All matching events.
+    pub evidence: usize,
+    /// Receipts needed to count as strong.
+    pub min_receipts: usize,
+    /// Titles of workstreams holding matching events, in workstream order.
+    pub workstreams: Vec<String>,
+    /// Expected evidence types from the rubric.
+    pub expected: Vec<EvidenceType>,
+    /// Keywords from the rubric.
+    pub keywords: Vec<String>,
+}
+
+/// Gap analysis of one rubric level against a run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RubricAssessment {
+    /// Rubric display name.
+    pub rubric: Option<String>,
+    /// Level assessed.
+    pub level: String,
+    /// Per-competency results in rubric order.
+    pub competencies: Vec<CompetencyAssessment>,
+}
+
+/// Map a run's workstreams and events against one rubric level.
+pub fn assess_rubric(
+    rubric: &Rubric,
+    level: &RubricLevel,
+    workstreams: &WorkstreamsFile,
+    events: &[EventEnvelope],
+) -> Result<RubricAssessment> {
+    let owner: HashMap<&EventId, &Workstream> = workstreams
+        .workstreams
+        .iter()
+        .flat_map(|ws| ws.events.iter().map(move |id| (id, ws)))
+        .collect();
+    let receipts: BTreeSet<&EventId> = workstreams
+        .workstreams
+        .iter()
+        .flat_map(|ws| ws.receipts.iter())
+        .collect();
+
+    let mut competencies = Vec::new();
+    for competency in &level.competencies {
+        let keywords = keyword_pattern(&competency.keywords)
+            .with_context(|| format!("keywords for {:?}", competency.name))?;
+        let mut evidence = 0;
+        let mut receipt_count = 0;
+        let mut matched_workstreams = BTreeSet::new();
+        for event in events {
+            if !competency.evidence.is_empty()
+                && !competency
+                    .evidence
+                    .iter()
+                    .any(|kind| kind.matches(&event.kind))
+            {
+                continue;
+            }
+            let workstream = owner.get(&event.id).copied();
+            if let Some(keywords) = &keywords
+                && !keywords.is_match(&event_text(event))
+                && !workstream.is_some_and(|ws| keywords.is_match(&workstream_text(ws)))
+            {
+                continue;
+            }
+            evidence += 1;
+            if receipts.contains(&event.id) {
+                receipt_count += 1;
+            }
+            if let Some(ws) = workstream {
+                matched_workstreams.insert(ws.id.to_string());
+            }
+        }
+
+        let status = if evidence == 0 {
+            CompetencyStatus::Missing
+        } else if receipt_count >= competency.min_receipts {
+            CompetencyStatus::Strong
+        } else {
+            CompetencyStatus::Thin
+        };
+        competencies.push(CompetencyAssessment {
+            name: competency.name.clone(),
+            status,
+            receipts: receipt_count,
+            evidence,
+            min_receipts: competency.min_receipts,
+            workstreams: workstreams
+                .workstreams
+                .iter()
+                .filter(|ws| matched_workstreams.contains(&ws.id.to_string()))
+                .map(|ws| ws.title.clone())
+                .collect(),
+            expected: competency.evidence.clone(),
+            keywords: competency.keywords.clone(),
+        });
+    }
+
+    Ok(RubricAssessment {
+        rubric: rubric.name.clone(),
+        level: level.name.clone(),
+        competencies,
+    })
+}
+
+fn keyword_pattern(keywords: &[String]) -> Result<Option<Regex>> {
+    let keywords: Vec<String> = keywords
+        .iter()
+        .map(|keyword| keyword.trim())
+        .filter(|keyword| !keyword.is_empty())
+        .map(regex::escape)
+        .collect();
+    if keywords.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Regex::new(&format!(
+        r"(?i)\b(?:{})\b",
+        keywords.join("|")
+    ))?))
+}
+
+fn event_text(event: &EventEnvelope) -> String {
+    let mut parts: Vec<&str> = event.tags.iter().map(String::as_str).collect();
+    match &event.payload {
+        EventPayload::PullRequest(payload) => parts.push(&payload.title),
+        EventPayload::Review(payload) => parts.push(&payload.pull_title),
+        EventPayload::Manual(payload) => {
+            parts.push(&payload.title);
+            parts.extend(payload.description.as_deref());
+            parts.extend(payload.impact.as_deref());
+        }
+        EventPayload::Issue(payload) => {
+            parts.push(&payload.title);
+            parts.extend(payload.labels.iter().map(String::as_str));
+        }
+        EventPayload::Deployment(payload) => parts.push(&payload.environment),
+        EventPayload::Release(payload) => {
+            parts.extend(payload.name.as_deref());
+            parts.push(&payload.version);
+        }
+        EventPayload::Comment(payload) => parts.push(&payload.target_title),
+        EventPayload::Incident(payload) => {
+            parts.push(&payload.title);
+            parts.extend(payload.summary.as_deref());
+        }
+        EventPayload::Document(payload) => parts.push(&payload.title),
+    }
+    parts.join("\n")
+}
+
+fn workstream_text(workstream: &Workstream) -> String {
+    let mut parts = vec![workstream.title.as_str()];
+    parts.extend(workstream.summary.as_deref());
+    parts.extend(workstream.tags.iter().map(String::as_str));
+    parts.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ids::WorkstreamId;
+    use crate::schema::event::*;
+    use crate::schema::workstream::WorkstreamStats;
+    use chrono::{NaiveDate, Utc};
+
+    fn manual(title: &str) -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["manual", title]),
+            kind: EventKind::Manual,
+            occurred_at: Utc::now(),
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "manual".into(),
+                html_url: None,
+                visibility: RepoVisibility::Unknown,
+            },
+            payload: EventPayload::Manual(ManualEvent {
+                event_type: ManualEventType::Other,
+                title: title.into(),
+                description: None,
+                started_at: NaiveDate::from_ymd_opt(2026, 9, 1),
+                ended_at: None,
+                impact: None,
+                metrics: vec![],
+                effort: None,
+                evidence: vec![],
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Manual,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    fn competency(name: &str, keyword: &str, min_receipts: usize) -> Competency {
+        Competency {
+            name: name.into(),
+            keywords: vec![keyword.into()],
+            evidence: vec![EvidenceType::Manual],
+            min_receipts,
+        }
+    }
+
+    #[test]
+    fn receipts_make_competencies_strong_and_matches_alone_thin() {
+        let mentoring = manual("Weekly Mentoring with new hires");
+        let retro = manual("Incident retro for checkout outage");
+        let rubric = Rubric {
+            name: None,
+            levels: vec![RubricLevel {
+                name: "Senior".into(),
+                competencies: vec![
+                    competency("Mentoring", "mentoring", 1),
+                    competency("Incident response", "incident", 1),
+                    competency("Technical design", "rfc", 1),
+                ],
+            }],
+        };
+        let workstreams = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "team"]),
+                title: "Team health".into(),
+                summary: None,
+                tags: vec![],
+                stats: WorkstreamStats::zero(),
+                events: vec![mentoring.id.clone(), retro.id.clone()],
+                receipts: vec![mentoring.id.clone()],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        };
+
+        let level = rubric.level(Some("senior")).unwrap();
+        let assessment = assess_rubric(&rubric, level, &workstreams, &[mentoring, retro]).unwrap();
+        let statuses: Vec<(&str, CompetencyStatus, usize)> = assessment
+            .competencies
+            .iter()
+            .map(|c| (c.name.as_str(), c.status, c.evidence))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("Mentoring", CompetencyStatus::Strong, 1),
+                ("Incident response", CompetencyStatus::Thin, 1),
+                ("Technical design", CompetencyStatus::Missing, 0),
+            ]
+        );
+        assert_eq!(assessment.competencies[1].workstreams, ["Team health"]);
+    }
+}
@@ -0,0 +1,193 @@
+//! Needs-attention analysis for a finished run.
+//!
+//! Inspects the ledger, workstreams, and coverage of a run and lists what to
+//! fix before sharing, most urgent first. The engine writes the list to
+//! `needs_attention.json` and renders it at the top of the internal packet.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::coverage::{Completeness, CoverageManifest};
+use crate::schema::event::EventEnvelope;
+use crate::schema::workstream::WorkstreamsFile;
+
+/// How soon an item should be fixed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttentionPriority {
+    /// The packet makes a claim it cannot back.
+    High,
+    /// The packet is incomplete in a way a reviewer may notice.
+    Medium,
+    /// Polish.
+    Low,
+}
+
+impl AttentionPriority {
+    /// Human label.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::High => "High",
+            Self::Medium => "Medium",
+            Self::Low => "Low",
+        }
+    }
+}
+
+/// What kind of problem an item describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttentionKind {
+    /// A workstream with no curated receipts.
+    NoReceipts,
+    /// A query window that fetched fewer results than the source reported.
+    PartialCoverage,
+    /// A workstream without a written summary.
+    UnfilledClaim,
+    /// Receipts with no link back to their source.
+    MissingLinks,
+}
+
+/// One thing to fix before sharing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttentionItem {
+    /// How soon to fix it.
+    pub priority: AttentionPriority,
+    /// What kind of problem it is.
+    pub kind: AttentionKind,
+    /// Workstream title or coverage window the item is about.
+    pub subject: String,
+    /// What is wrong and how to fix it.
+    pub message: String,
+}
+
+/// Contents of `needs_attention.json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeedsAttention {
+    /// Run the items were computed for.
+    pub run_id: String,
+    /// Items, most urgent first.
+    pub items: Vec<AttentionItem>,
+}
+
+/// List what needs fixing in a run, ordered by priority, then kind, then the
+/// order workstreams and coverage slices appear in.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::attention::{AttentionKind, analyze_run};
+/// use shiplog::ids::WorkstreamId;
+/// use shiplog::schema::coverage::Completeness;
+/// use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
+/// use shiplog_testkit::fixtures::test_coverage;
+/// use chrono::Utc;
+///
+/// let workstreams = WorkstreamsFile {
+///     version: 1,
+///     generated_at: Utc::now(),
+///     workstreams: vec![Workstream {
+///         id: WorkstreamId::from_parts(["ws", "billing"]),
+///         title: "Billing".into(),
+///         summary: None,
+///         tags: vec![],
+///         stats: WorkstreamStats::zero(),
+///         events: vec![],
+///         receipts: vec![],
+///         receipt_weights: Default::default(),
+///         rules: Default::default(),
+///     }],
+/// };
+/// let coverage = test_coverage("octo", Completeness::Complete);
+///
+/// let items = analyze_run(&[], &workstreams, &coverage);
+/// let kinds: Vec<_> = items.iter().map(|item| item.kind).collect();
+/// assert_eq!(kinds, [AttentionKind::NoReceipts, AttentionKind::UnfilledClaim]);
+/// ```
+pub fn analyze_run(
+    events: &[EventEnvelope],
+    workstreams: &WorkstreamsFile,
+    coverage: &CoverageManifest,
+) -> Vec<AttentionItem> {
+    let by_id: HashMap<&str, &EventEnvelope> =
+        events.iter().map(|e| (e.id.0.as_str(), e)).collect();
+    let mut items = Vec::new();
+
+    for workstream in &workstreams.workstreams {
+        let title = &workstream.title;
+        if workstream.receipts.is_empty() {
+            items.push(AttentionItem {
+                priority: AttentionPriority::High,
+                kind: AttentionKind::NoReceipts,
+                subject: title.clone(),
+                message: format!(
+                    "{title:?} has no receipts; select the events that back it or merge it into another workstream."
+                ),
+            });
+        }
+        if workstream
+            .summary
+            .as_deref()
+            .is_none_or(|summary| summary.trim().is_empty())
+        {
+            items.push(AttentionItem {
+                priority: AttentionPriority::Medium,
+                kind: AttentionKind::UnfilledClaim,
+                subject: title.clone(),
+                message: format!("{title:?} has no summary; write the claim in workstreams.yaml."),
+            });
+        }
+        let unlinked = workstream
+            .receipts
+            .iter()
+            .filter_map(|id| by_id.get(id.0.as_str()))
+            .filter(|event| event.links.is_empty() && event.source.url.is_none())
+            .count();
+        if unlinked > 0 {
+            items.push(AttentionItem {
+                priority: AttentionPriority::Low,
+                kind: AttentionKind::MissingLinks,
+                subject: title.clone(),
+                message: format!(
+                    "{unlinked} receipt{} in {title:?} {} no link; add a URL so reviewers can check {}.",
+                    if unlinked == 1 { "" } else { "s" },
+                    if unlinked == 1 { "has" } else { "have" },
+                    if unlinked == 1 { "it" } else { "them" },
+                ),
+            });
+        }
+    }
+
+    let partial: Vec<_> = coverage
+        .slices
+        .iter()
+        .filter(|slice| slice.is_partial())
+        .collect();
+    for slice in &partial {
+        let window = format!("{}..{}", slice.window.since, slice.window.until);
+        items.push(AttentionItem {
+            priority: AttentionPriority::Medium,
+            kind: AttentionKind::PartialCoverage,
+            message: format!(
+                "{window} fetched {} of {} results for `{}`; collect again or narrow the window.",
+                slice.fetched, slice.total_count, slice.query
+            ),
+            subject: window,
+        });
+    }
+    if partial.is_empty() && coverage.completeness == Completeness::Partial {
+        let window = format!("{}..{}", coverage.window.since, coverage.window.until);
+        items.push(AttentionItem {
+            priority: AttentionPriority::Medium,
+            kind: AttentionKind::PartialCoverage,
+            message: format!(
+                "Coverage for {window} is partial; see coverage.report.md for skipped sources."
+            ),
+            subject: window,
+        });
+    }
+
+    items.sort_by_key(|item| (item.priority, item.kind));
+    items
+}
@@ -0,0 +1,366 @@
+//! Multi-year backfill, one calendar quarter at a time.
+//!
+//! A long range is split into quarter chunks. Each chunk is collected from the
+//! configured sources into its own run directory, sharing the output root's
+//! API cache, and `backfill.index.json` is rewritten after every chunk so an
+//! interrupted backfill resumes where it stopped. Once every chunk is in, the
+//! chunk ledgers are merged into one historical run.
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::*;
+
+const BACKFILL_INDEX_FILENAME: &str = "backfill.index.json";
+const BACKFILL_INDEX_SCHEMA_VERSION: &str = "backfill.index.v1";
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BackfillIndex {
+    schema_version: String,
+    generated_at: String,
+    since: NaiveDate,
+    until: NaiveDate,
+    state: BackfillState,
+    chunks: Vec<BackfillChunk>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    merged: Option<BackfillMerged>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BackfillState {
+    Pending,
+    InProgress,
+    Failed,
+    Completed,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BackfillChunk {
+    label: String,
+    since: NaiveDate,
+    until: NaiveDate,
+    state: BackfillState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    run_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    events: Option<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skipped_sources: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BackfillMerged {
+    run_id: String,
+    events: usize,
+}
+
+pub(super) fn run_backfill(args: BackfillArgs) -> Result<()> {
+    if args.since >= args.until {
+        anyhow::bail!("backfill window must satisfy --since < --until");
+    }
+    let config = load_config_for_command(&args.config)?;
+    ensure_supported_config_version(&config)?;
+    let base_dir = config_base_dir(&args.config);
+    let out = args
+        .out
+        .clone()
+        .unwrap_or_else(|| config_default_out(&config, &base_dir));
+    let bundle_profile = args
+        .profile
+        .clone()
+        .map(Ok)
+        .unwrap_or_else(|| doctor_config_profile(config.defaults.profile.as_deref()))?;
+    let redaction_key = RedactionKey::resolve_with_env(
+        args.redact_key.clone(),
+        &bundle_profile,
+        &config_redaction_key_env(&config),
+    )?;
+    let clusterer = build_clusterer(config.workstreams.clusterer, false, None, "", "", None, "");
+    let redactor = cli_redactor(redaction_key.engine_key(), &config.redaction)?;
+    let (engine, redactor) = create_engine_with_redactor(redactor, clusterer, &bundle_profile);
+    let engine = engine.with_profile_rendering(redaction_key.render_profiles());
+    let engine = configure_engine_bundle(engine, &args.config, &config)?;
+
+    std::fs::create_dir_all(&out).with_context(|| format!("create {}", out.display()))?;
+    let index_path = out.join(BACKFILL_INDEX_FILENAME);
+    let mut index = if args.resume {
+        resume_index(&index_path, args.since, args.until)?
+    } else {
+        new_index(args.since, args.until)?
+    };
+
+    println!(
+        "Backfilling {}..{} in {} quarter chunk(s):",
+        index.since,
+        index.until,
+        index.chunks.len()
+    );
+    for position in 0..index.chunks.len() {
+        let Some(chunk) = index.chunks.get(position) else {
+            break;
+        };
+        if chunk.state == BackfillState::Completed {
+            println!("- {}: already collected", chunk.label);
+            continue;
+        }
+
+        let label = chunk.label.clone();
+        let window = ResolvedWindow {
+            since: chunk.since,
+            until: chunk.until,
+            label: WindowLabel::Explicit,
+            period: None,
+        };
+        let collected = collect_configured_sources(&args.config, &config, window.clone(), &out)
+            .and_then(|configured| {
+                run_configured_multi_pipeline(
+                    &args.config,
+                    &config,
+                    &out,
+                    window,
+                    &bundle_profile,
+                    args.conflict,
+                    configured,
+                    false,
+                    false,
+                    false,
+                    &engine,
+                    redactor,
+                    &[],
+                )
+            })
+            .with_context(|| format!("collect backfill chunk {label}"));
+
+        let result = match collected {
+            Ok(result) => result,
+            Err(err) => {
+                if let Some(chunk) = index.chunks.get_mut(position) {
+                    chunk.state = BackfillState::Failed;
+                    chunk.error = Some(format!("{err:#}"));
+                }
+                index.state = BackfillState::Failed;
+                write_index(&index_path, &mut index)?;
+                println!("- {label}: failed");
+                println!("Checkpoint: {}", display_path_for_cli(&index_path));
+                println!(
+                    "Next: {}",
+                    resume_command(&args.config, &out, index.since, index.until)
+                );
+                return Err(err);
+            }
+        };
+        let events = count_ledger_events(&result.outputs.ledger_events_jsonl)?;
+        println!(
+            "- {label}: {}, {}",
+            event_count_phrase(events),
+            display_path_for_cli(&result.outputs.out_dir)
+        );
+        if let Some(chunk) = index.chunks.get_mut(position) {
+            chunk.state = BackfillState::Completed;
+            chunk.run_id = Some(result.run_id);
+            chunk.events = Some(events);
+            chunk.skipped_sources = result
+                .configured
+                .failures
+                .iter()
+                .map(|failure| failure.name.clone())
+                .collect();
+            chunk.error = None;
+        }
+        index.state = BackfillState::InProgress;
+        write_index(&index_path, &mut index)?;
+    }
+
+    let merged = match index.merged.take() {
+        Some(merged) => merged,
+        None => merge_chunks(
+            &out,
+            &index,
+            &config,
+            &bundle_profile,
+            args.conflict,
+            &engine,
+            redactor,
+        )?,
+    };
+    println!("Merged historical run:");
+    println!("- {}", display_path_for_cli(&out.join(&merged.run_id)));
+    println!("- {}", event_count_phrase(merged.events));
+    index.merged = Some(merged);
+    index.state = BackfillState::Completed;
+    write_index(&index_path, &mut index)?;
+    println!("Index: {}", display_path_for_cli(&index_path));
+    Ok(())
+}
+
+fn new_index(since: NaiveDate, until: NaiveDate) -> Result<BackfillIndex> {
+    let chunks = quarter_chunks(since, until)?
+        .into_iter()
+        .map(|(label, since, until)| BackfillChunk {
+            label,
+            since,
+            until,
+            state: BackfillState::Pending,
+            run_id: None,
+            events: None,
+            skipped_sources: Vec::new(),
+            error: None,
+        })
+        .collect();
+    Ok(BackfillIndex {
+        schema_version: BACKFILL_INDEX_SCHEMA_VERSION.to_string(),
+        generated_at: Utc::now().to_rfc3339(),
+        since,
+        until,
+        state: BackfillState::Pending,
+        chunks,
+        merged: None,
+    })
+}
+
+fn resume_index(path: &Path, since: NaiveDate, until: NaiveDate) -> Result<BackfillIndex> {
+    if !path.exists() {
+        return new_index(since, until);
+    }
+    let text = std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    let index: BackfillIndex =
+        serde_json::from_str(&text).with_context(|| format!("parse {}", path.display()))?;
+    if index.since != since || index.until != until {
+        anyhow::bail!(
+            "{} covers {}..{}, not {since}..{until}; resume with the same --since and --until or choose another --out",
+            path.display(),
+            index.since,
+            index.until
+        );
+    }
+    Ok(index)
+}
+
+fn write_index(path: &Path, index: &mut BackfillIndex) -> Result<()> {
+    index.generated_at = Utc::now().to_rfc3339();
+    let json = serde_json::to_string_pretty(index).context("serialize backfill index")?;
+    std::fs::write(path, format!("{json}\n")).with_context(|| format!("write {}", path.display()))
+}
+
+/// Calendar quarters covering `since..until`, clipped to the range.
+fn quarter_chunks(
+    since: NaiveDate,
+    until: NaiveDate,
+) -> Result<Vec<(String, NaiveDate, NaiveDate)>> {
+    let mut chunks = Vec::new();
+    let mut start = since;
+    while start < until {
+        let quarter = quarter_start(start.year(), start.month())?;
+        let next = quarter
+            .checked_add_months(Months::new(3))
+            .ok_or_else(|| anyhow::anyhow!("quarter after {quarter} is out of range"))?;
+        let end = next.min(until);
+        let label = format!("{}-Q{}", start.year(), start.month0() / 3 + 1);
+        chunks.push((label, start, end));
+        start = end;
+    }
+    Ok(chunks)
+}
+
+fn count_ledger_events(path: &Path) -> Result<usize> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    Ok(text.lines().filter(|line| !line.trim().is_empty()).count())
+}
+
+/// Merge every chunk's ledger into one run covering the whole range.
+fn merge_chunks(
+    out: &Path,
+    index: &BackfillIndex,
+    config: &ShiplogConfig,
+    bundle_profile: &BundleProfile,
+    conflict: MergeConflict,
+    engine: &Engine<'_>,
+    redactor: &DeterministicRedactor,
+) -> Result<BackfillMerged> {
+    let mut ingest_outputs = Vec::with_capacity(index.chunks.len());
+    for chunk in &index.chunks {
+        let run_id = chunk
+            .run_id
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("backfill chunk {} has no run", chunk.label))?;
+        ingest_outputs.push(
+            load_run_ingest(&out.join(run_id))
+                .with_context(|| format!("load backfill chunk {}", chunk.label))?,
+        );
+    }
+
+    let mut merged = engine
+        .merge(ingest_outputs, conflict.into())
+        .context("merge backfill chunks")?;
+    let user = config_user_label(config).unwrap_or_else(|| merged.coverage.user.clone());
+    merged.coverage.user = user.clone();
+    merged.coverage.window = TimeWindow {
+        since: index.since,
+        until: index.until,
+    };
+    let events = merged.events.len();
+    let run_id = merged.coverage.run_id.to_string();
+    let run_dir = out.join(&run_id);
+    let window_label = format!("backfill ({}..{})", index.since, index.until);
+
+    let cache_path = DeterministicRedactor::cache_path(&run_dir);
+    let _ = redactor.load_cache(&cache_path);
+    engine
+        .run(
+            merged,
+            &user,
+            &window_label,
+            &run_dir,
+            false,
+            bundle_profile,
+        )
+        .context("run merged backfill pipeline")?;
+    redactor
+        .save_cache(&cache_path)
+        .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
+
+    Ok(BackfillMerged { run_id, events })
+}
+
+fn resume_command(config: &Path, out: &Path, since: NaiveDate, until: NaiveDate) -> String {
+    format!(
+        "shiplog backfill --config {} --out {} --since {since} --until {until} --resume",
+        quote_cli_value(&config.display().to_string()),
+        quote_cli_value(&display_path_for_cli(out))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn quarter_chunks_clip_to_the_range() {
+        let chunks = quarter_chunks(date(2023, 2, 15), date(2023, 11, 1)).unwrap();
+        assert_eq!(
+            chunks,
+            vec![
+                ("2023-Q1".to_string(), date(2023, 2, 15), date(2023, 4, 1)),
+                ("2023-Q2".to_string(), date(2023, 4, 1), date(2023, 7, 1)),
+                ("2023-Q3".to_string(), date(2023, 7, 1), date(2023, 10, 1)),
+                ("2023-Q4".to_string(), date(2023, 10, 1), date(2023, 11, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn three_years_split_into_twelve_quarters() {
+        let chunks = quarter_chunks(date(2023, 1, 1), date(2026, 1, 1)).unwrap();
+        assert_eq!(chunks.len(), 12);
+        assert_eq!(chunks[11].0, "2025-Q4");
+    }
+}
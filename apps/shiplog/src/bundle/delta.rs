@@ -0,0 +1,243 @@
+//! Incremental bundles built against a previous bundle manifest.
+//!
+//! Weekly re-shares of a long packet mostly repeat files the reviewer already
+//! has. A delta bundle carries only files that were added or changed since the
+//! base manifest, plus `bundle.delta.json` describing the difference and the
+//! full current `bundle.manifest.json` so the receiver can verify the result.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use shiplog::schema::bundle::{BundleDelta, BundleManifest};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::layout::{BundleFormat, FILE_BUNDLE_DELTA_JSON, FILE_BUNDLE_MANIFEST_JSON};
+use super::sign::signature_entries;
+use super::write_archive_entries;
+
+/// Read a `bundle.manifest.json` file, or the one inside a run directory.
+pub fn read_bundle_manifest(path: &Path) -> Result<BundleManifest> {
+    let path = if path.is_dir() {
+        path.join(FILE_BUNDLE_MANIFEST_JSON)
+    } else {
+        path.to_path_buf()
+    };
+    let text =
+        std::fs::read_to_string(&path).with_context(|| format!("read bundle manifest {path:?}"))?;
+    serde_json::from_str(&text).with_context(|| format!("parse bundle manifest {path:?}"))
+}
+
+/// Compare two manifests by path and SHA-256.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Utc;
+/// use shiplog::bundle::diff_manifests;
+/// use shiplog::ids::RunId;
+/// use shiplog::schema::bundle::{BundleManifest, BundleProfile, FileChecksum};
+///
+/// let file = |path: &str, sha256: &str| FileChecksum {
+///     path: path.into(),
+///     sha256: sha256.into(),
+///     bytes: 1,
+/// };
+/// let manifest = |run: &str, files| BundleManifest {
+///     run_id: RunId(run.into()),
+///     generated_at: Utc::now(),
+///     profile: BundleProfile::Internal,
+///     files,
+///     scope: None,
+///     provenance: None,
+///     parts: Vec::new(),
+/// };
+///
+/// let base = manifest("run_1", vec![file("a.md", "1"), file("b.md", "2")]);
+/// let current = manifest("run_2", vec![file("a.md", "1"), file("b.md", "3"), file("c.md", "4")]);
+///
+/// let delta = diff_manifests(&base, &current);
+/// assert_eq!(delta.changed[0].path, "b.md");
+/// assert_eq!(delta.added[0].path, "c.md");
+/// assert_eq!(delta.unchanged, 1);
+/// ```
+pub fn diff_manifests(base: &BundleManifest, current: &BundleManifest) -> BundleDelta {
+    let base_files: BTreeMap<&str, &str> = base
+        .files
+        .iter()
+        .map(|file| (file.path.as_str(), file.sha256.as_str()))
+        .collect();
+
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    let mut unchanged = 0usize;
+    for file in &current.files {
+        match base_files.get(file.path.as_str()) {
+            None => added.push(file.clone()),
+            Some(sha256) if *sha256 != file.sha256 => changed.push(file.clone()),
+            Some(_) => unchanged += 1,
+        }
+    }
+
+    let mut removed: Vec<String> = base
+        .files
+        .iter()
+        .filter(|file| !current.files.iter().any(|cur| cur.path == file.path))
+        .map(|file| file.path.clone())
+        .collect();
+    added.sort_by(|a, b| a.path.cmp(&b.path));
+    changed.sort_by(|a, b| a.path.cmp(&b.path));
+    removed.sort();
+
+    BundleDelta {
+        run_id: current.run_id.clone(),
+        base_run_id: base.run_id.clone(),
+        generated_at: Utc::now(),
+        profile: current.profile.clone(),
+        added,
+        changed,
+        removed,
+        unchanged,
+    }
+}
+
+/// Write an incremental archive for `out_dir` relative to `base`.
+///
+/// The run directory must already contain `bundle.manifest.json`; its profile
+/// must match the base manifest so a delta never widens what was shared.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::{BundleFormat, read_bundle_manifest, write_delta_archive};
+/// use std::path::Path;
+///
+/// let base = read_bundle_manifest(Path::new("./out/run_122"))?;
+/// let delta = write_delta_archive(
+///     Path::new("./out/run_123"),
+///     Path::new("./out/run_123.delta.zip"),
+///     &base,
+///     BundleFormat::Zip,
+/// )?;
+/// println!("{} changed files", delta.added.len() + delta.changed.len());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn write_delta_archive(
+    out_dir: &Path,
+    archive_path: &Path,
+    base: &BundleManifest,
+    format: BundleFormat,
+) -> Result<BundleDelta> {
+    let current = read_bundle_manifest(out_dir)?;
+    if current.profile != base.profile {
+        anyhow::bail!(
+            "base manifest profile {} does not match current bundle profile {}",
+            base.profile,
+            current.profile
+        );
+    }
+    let delta = diff_manifests(base, &current);
+
+    let mut entries = Vec::new();
+    for file in delta.added.iter().chain(&delta.changed) {
+        let path = out_dir.join(&file.path);
+        let bytes = std::fs::read(&path).with_context(|| format!("read {path:?}"))?;
+        entries.push((file.path.clone(), bytes));
+    }
+    entries.push((
+        FILE_BUNDLE_DELTA_JSON.to_string(),
+        serde_json::to_vec_pretty(&delta).context("serialize bundle delta")?,
+    ));
+    let signed = signature_entries(out_dir)?;
+    if signed.is_empty() {
+        entries.push((
+            FILE_BUNDLE_MANIFEST_JSON.to_string(),
+            serde_json::to_vec_pretty(&current).context("serialize bundle manifest")?,
+        ));
+    } else {
+        entries.extend(signed);
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    write_archive_entries(archive_path, format, entries).context("write delta archive")?;
+    Ok(delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bundle::{FILE_COVERAGE_MANIFEST_JSON, FILE_PACKET_MD, write_bundle_manifest};
+    use shiplog::ids::RunId;
+    use shiplog::schema::bundle::BundleProfile;
+
+    fn zip_names(path: &Path) -> Vec<String> {
+        let archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        (0..archive.len())
+            .map(|i| archive.name_for_index(i).unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn delta_archive_ships_only_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let run = dir.path().join("run");
+        std::fs::create_dir_all(&run).unwrap();
+        std::fs::write(run.join(FILE_PACKET_MD), "# v1").unwrap();
+        std::fs::write(run.join(FILE_COVERAGE_MANIFEST_JSON), "{}").unwrap();
+        std::fs::write(run.join("old.txt"), "gone soon").unwrap();
+        let base =
+            write_bundle_manifest(&run, &RunId("run_1".into()), &BundleProfile::Internal).unwrap();
+
+        std::fs::write(run.join(FILE_PACKET_MD), "# v2").unwrap();
+        std::fs::remove_file(run.join("old.txt")).unwrap();
+        std::fs::write(run.join("new.txt"), "fresh").unwrap();
+        write_bundle_manifest(&run, &RunId("run_2".into()), &BundleProfile::Internal).unwrap();
+
+        let archive = dir.path().join("run.delta.zip");
+        let delta = write_delta_archive(&run, &archive, &base, BundleFormat::Zip).unwrap();
+
+        assert_eq!(delta.base_run_id, RunId("run_1".into()));
+        assert_eq!(delta.added.len(), 1);
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(delta.removed, vec!["old.txt".to_string()]);
+        assert_eq!(delta.unchanged, 1);
+        assert_eq!(
+            zip_names(&archive),
+            vec![
+                FILE_BUNDLE_DELTA_JSON,
+                FILE_BUNDLE_MANIFEST_JSON,
+                "new.txt",
+                FILE_PACKET_MD,
+            ]
+        );
+    }
+
+    #[test]
+    fn delta_archive_rejects_profile_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(FILE_COVERAGE_MANIFEST_JSON), "{}").unwrap();
+        let base =
+            write_bundle_manifest(dir.path(), &RunId("a".into()), &BundleProfile::Public).unwrap();
+        write_bundle_manifest(dir.path(), &RunId("b".into()), &BundleProfile::Manager).unwrap();
+
+        let err = write_delta_archive(
+            dir.path(),
+            &dir.path().join("d.zip"),
+            &base,
+            BundleFormat::Zip,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{err}");
+    }
+
+    #[test]
+    fn identical_manifests_produce_empty_delta() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(FILE_PACKET_MD), "# same").unwrap();
+        let manifest =
+            write_bundle_manifest(dir.path(), &RunId("a".into()), &BundleProfile::Internal)
+                .unwrap();
+        let delta = diff_manifests(&manifest, &manifest);
+        assert!(delta.is_empty());
+        assert_eq!(delta.unchanged, 1);
+    }
+}
@@ -0,0 +1,440 @@
+//! Run-to-run comparison of bundle contents.
+//!
+//! Reviewers ask "what changed since the last packet?". [`diff_bundles`]
+//! compares two run directories' ledgers, workstreams, and coverage so the
+//! answer does not require diffing JSONL by hand.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use shiplog::coverage::{CoverageDiff, diff_coverage};
+use shiplog::engine::read_events;
+use shiplog::ids::{EventId, RunId, WorkstreamId};
+use shiplog::schema::coverage::CoverageManifest;
+use shiplog::schema::event::{EventEnvelope, EventKind, EventPayload};
+use shiplog::schema::workstream::Workstream;
+use shiplog::workstreams::WorkstreamManager;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use super::layout::{FILE_COVERAGE_MANIFEST_JSON, FILE_LEDGER_EVENTS_JSONL};
+
+/// What changed between two runs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BundleDiff {
+    /// Run the comparison starts from.
+    pub from_run: RunId,
+    /// Run the comparison ends at.
+    pub to_run: RunId,
+    /// Events only the later run contains.
+    pub events_added: Vec<EventChange>,
+    /// Events only the earlier run contains.
+    pub events_removed: Vec<EventChange>,
+    /// Events present in both runs whose content differs.
+    pub events_changed: Vec<EventChange>,
+    /// Workstream additions, removals, and membership changes.
+    pub workstreams: WorkstreamDiff,
+    /// Coverage window, source, and slice changes.
+    pub coverage: CoverageDiff,
+}
+
+impl BundleDiff {
+    /// Returns `true` when neither events nor workstreams changed.
+    pub fn is_unchanged(&self) -> bool {
+        self.events_added.is_empty()
+            && self.events_removed.is_empty()
+            && self.events_changed.is_empty()
+            && self.workstreams.is_unchanged()
+    }
+}
+
+/// One added, removed, or changed ledger event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EventChange {
+    /// Event identifier shared by both runs.
+    pub id: EventId,
+    /// Event kind in the later run (or the earlier one, for removals).
+    pub kind: EventKind,
+    /// When the event happened.
+    pub occurred_at: DateTime<Utc>,
+    /// Repository the event belongs to.
+    pub repo: String,
+    /// Title, or the closest thing the event kind has to one.
+    pub title: String,
+    /// Dotted paths of the fields that differ, for changed events.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+/// Workstream changes, matched by workstream ID.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct WorkstreamDiff {
+    /// Titles of workstreams only the later run has.
+    pub added: Vec<String>,
+    /// Titles of workstreams only the earlier run had.
+    pub removed: Vec<String>,
+    /// Workstreams present in both runs that were renamed or gained or
+    /// lost events.
+    pub changed: Vec<WorkstreamChange>,
+}
+
+impl WorkstreamDiff {
+    /// Returns `true` when no workstream was added, removed, or changed.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A workstream present in both runs whose title or events differ.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WorkstreamChange {
+    /// Title in the later run.
+    pub title: String,
+    /// Title in the earlier run, when it was renamed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renamed_from: Option<String>,
+    /// Number of events the workstream gained.
+    pub events_added: usize,
+    /// Number of events the workstream lost.
+    pub events_removed: usize,
+}
+
+/// Compare two run directories, `old_dir` being the earlier run.
+///
+/// Events are matched by ID and compared field by field; workstreams are
+/// read from `workstreams.yaml`, falling back to the suggested file, and a
+/// run with neither counts as having no workstreams.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::diff_bundles;
+/// use shiplog::render::md::render_bundle_diff;
+/// use std::path::Path;
+///
+/// let diff = diff_bundles(Path::new("./out/run_122"), Path::new("./out/run_123"))?;
+/// println!("{} new events", diff.events_added.len());
+/// print!("{}", render_bundle_diff(&diff));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn diff_bundles(old_dir: &Path, new_dir: &Path) -> Result<BundleDiff> {
+    let old_coverage = read_coverage(old_dir)?;
+    let new_coverage = read_coverage(new_dir)?;
+    let old_events = read_ledger(old_dir)?;
+    let new_events = read_ledger(new_dir)?;
+
+    let mut events_added = Vec::new();
+    let mut events_changed = Vec::new();
+    for (id, event) in &new_events {
+        match old_events.get(id) {
+            None => events_added.push(event_change(event, Vec::new())),
+            Some(old) => {
+                let fields = changed_fields(old, event)?;
+                if !fields.is_empty() {
+                    events_changed.push(event_change(event, fields));
+                }
+            }
+        }
+    }
+    let events_removed = old_events
+        .iter()
+        .filter(|(id, _)| !new_events.contains_key(*id))
+        .map(|(_, event)| event_change(event, Vec::new()))
+        .collect();
+
+    let workstreams = diff_workstreams(
+        &WorkstreamManager::try_load(old_dir)?.map(|file| file.workstreams),
+        &WorkstreamManager::try_load(new_dir)?.map(|file| file.workstreams),
+    );
+
+    Ok(BundleDiff {
+        from_run: old_coverage.run_id.clone(),
+        to_run: new_coverage.run_id.clone(),
+        events_added: sorted(events_added),
+        events_removed: sorted(events_removed),
+        events_changed: sorted(events_changed),
+        workstreams,
+        coverage: diff_coverage(&old_coverage, &new_coverage),
+    })
+}
+
+fn read_coverage(run_dir: &Path) -> Result<CoverageManifest> {
+    let path = run_dir.join(FILE_COVERAGE_MANIFEST_JSON);
+    let text = std::fs::read_to_string(&path).with_context(|| format!("read {path:?}"))?;
+    serde_json::from_str(&text).with_context(|| format!("parse {path:?}"))
+}
+
+fn read_ledger(run_dir: &Path) -> Result<BTreeMap<EventId, EventEnvelope>> {
+    let path = run_dir.join(FILE_LEDGER_EVENTS_JSONL);
+    let file = std::fs::File::open(&path).with_context(|| format!("open {path:?}"))?;
+    read_events(std::io::BufReader::new(file))
+        .map(|event| event.map(|event| (event.id.clone(), event)))
+        .collect::<Result<_>>()
+        .with_context(|| format!("read {path:?}"))
+}
+
+fn event_change(event: &EventEnvelope, fields: Vec<String>) -> EventChange {
+    EventChange {
+        id: event.id.clone(),
+        kind: event.kind.clone(),
+        occurred_at: event.occurred_at,
+        repo: event.repo.full_name.clone(),
+        title: event_title(event).to_string(),
+        fields,
+    }
+}
+
+fn sorted(mut changes: Vec<EventChange>) -> Vec<EventChange> {
+    changes.sort_by(|a, b| (a.occurred_at, &a.id.0).cmp(&(b.occurred_at, &b.id.0)));
+    changes
+}
+
+fn event_title(event: &EventEnvelope) -> &str {
+    match &event.payload {
+        EventPayload::PullRequest(payload) => &payload.title,
+        EventPayload::Review(payload) => &payload.pull_title,
+        EventPayload::Manual(payload) => &payload.title,
+        EventPayload::Issue(payload) => &payload.title,
+        EventPayload::Deployment(payload) => &payload.environment,
+        EventPayload::Release(payload) => payload.name.as_deref().unwrap_or(&payload.version),
+        EventPayload::Comment(payload) => &payload.target_title,
+        EventPayload::Incident(payload) => &payload.title,
+        EventPayload::Document(payload) => &payload.title,
+    }
+}
+
+/// Dotted paths of the leaf values that differ between two events.
+fn changed_fields(old: &EventEnvelope, new: &EventEnvelope) -> Result<Vec<String>> {
+    let old = serde_json::to_value(old).context("serialize event")?;
+    let new = serde_json::to_value(new).context("serialize event")?;
+    let mut fields = Vec::new();
+    collect_changed_fields(&old, &new, "", &mut fields);
+    Ok(fields)
+}
+
+fn collect_changed_fields(
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    path: &str,
+    fields: &mut Vec<String>,
+) {
+    match (old, new) {
+        (serde_json::Value::Object(old), serde_json::Value::Object(new)) => {
+            let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match (old.get(key), new.get(key)) {
+                    (Some(old), Some(new)) => collect_changed_fields(old, new, &child, fields),
+                    _ => fields.push(child),
+                }
+            }
+        }
+        _ if old != new => fields.push(path.to_string()),
+        _ => {}
+    }
+}
+
+fn diff_workstreams(
+    old: &Option<Vec<Workstream>>,
+    new: &Option<Vec<Workstream>>,
+) -> WorkstreamDiff {
+    let earlier: HashMap<&WorkstreamId, &Workstream> = old
+        .iter()
+        .flatten()
+        .map(|workstream| (&workstream.id, workstream))
+        .collect();
+    let mut diff = WorkstreamDiff::default();
+    for workstream in new.iter().flatten() {
+        let Some(previous) = earlier.get(&workstream.id) else {
+            diff.added.push(workstream.title.clone());
+            continue;
+        };
+        let before: BTreeSet<&EventId> = previous.events.iter().collect();
+        let after: BTreeSet<&EventId> = workstream.events.iter().collect();
+        let renamed_from = (previous.title != workstream.title).then(|| previous.title.clone());
+        let events_added = after.difference(&before).count();
+        let events_removed = before.difference(&after).count();
+        if renamed_from.is_some() || events_added > 0 || events_removed > 0 {
+            diff.changed.push(WorkstreamChange {
+                title: workstream.title.clone(),
+                renamed_from,
+                events_added,
+                events_removed,
+            });
+        }
+    }
+    let kept: HashSet<&WorkstreamId> = new.iter().flatten().map(|ws| &ws.id).collect();
+    diff.removed = old
+        .iter()
+        .flatten()
+        .filter(|workstream| !kept.contains(&workstream.id))
+        .map(|workstream| workstream.title.clone())
+        .collect();
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::write_events;
+    use shiplog::schema::coverage::{Completeness, TimeWindow};
+    use shiplog::schema::event::{
+        Actor, ManualEvent, ManualEventType, RepoRef, RepoVisibility, SourceRef, SourceSystem,
+    };
+    use shiplog::schema::version::SchemaVersion;
+    use shiplog::schema::workstream::{WorkstreamStats, WorkstreamsFile};
+
+    fn event(key: &str, title: &str) -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["diff", key]),
+            canonical_id: None,
+            kind: EventKind::Manual,
+            occurred_at: Utc::now(),
+            actor: Actor {
+                login: "octo".into(),
+                id: None,
+            },
+            contributors: vec![],
+            repo: RepoRef {
+                full_name: "acme/api".into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::Manual(ManualEvent {
+                event_type: ManualEventType::Other,
+                title: title.into(),
+                description: None,
+                started_at: None,
+                ended_at: None,
+                impact: None,
+                metrics: vec![],
+                effort: None,
+                evidence: vec![],
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Manual,
+                url: None,
+                opaque_id: None,
+            },
+            extensions: Default::default(),
+        }
+    }
+
+    fn workstream(id: &str, title: &str, events: &[&EventEnvelope]) -> Workstream {
+        Workstream {
+            id: WorkstreamId::from_parts(["diff", id]),
+            title: title.into(),
+            summary: None,
+            tags: vec![],
+            stats: WorkstreamStats::zero(),
+            events: events.iter().map(|event| event.id.clone()).collect(),
+            receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
+        }
+    }
+
+    fn write_run(dir: &Path, run: &str, events: &[EventEnvelope], workstreams: Vec<Workstream>) {
+        std::fs::create_dir_all(dir).unwrap();
+        let file = std::fs::File::create(dir.join(FILE_LEDGER_EVENTS_JSONL)).unwrap();
+        write_events(file, events).unwrap();
+        let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
+            run_id: RunId(run.into()),
+            generated_at: Utc::now(),
+            user: "octo".into(),
+            window: TimeWindow {
+                since: chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                until: chrono::NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+            },
+            mode: "merged".into(),
+            sources: vec!["manual".into()],
+            slices: vec![],
+            warnings: vec![],
+            completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
+        };
+        std::fs::write(
+            dir.join(FILE_COVERAGE_MANIFEST_JSON),
+            serde_json::to_string(&coverage).unwrap(),
+        )
+        .unwrap();
+        let file = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams,
+        };
+        std::fs::write(
+            WorkstreamManager::curated_path(dir),
+            serde_yaml::to_string(&file).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn reports_event_workstream_and_run_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let kept = event("kept", "Kept");
+        let edited = event("edited", "Before");
+        let dropped = event("dropped", "Dropped");
+        let old_dir = dir.path().join("run_1");
+        write_run(
+            &old_dir,
+            "run_1",
+            &[kept.clone(), edited.clone(), dropped.clone()],
+            vec![
+                workstream("api", "API", &[&kept, &edited]),
+                workstream("ops", "Ops", &[&dropped]),
+            ],
+        );
+
+        let mut after = edited.clone();
+        if let EventPayload::Manual(payload) = &mut after.payload {
+            payload.title = "After".into();
+        }
+        let added = event("added", "Added");
+        let new_dir = dir.path().join("run_2");
+        write_run(
+            &new_dir,
+            "run_2",
+            &[kept.clone(), after, added.clone()],
+            vec![
+                workstream("api", "API platform", &[&kept, &edited, &added]),
+                workstream("docs", "Docs", &[]),
+            ],
+        );
+
+        let diff = diff_bundles(&old_dir, &new_dir).unwrap();
+        assert_eq!(diff.from_run, RunId("run_1".into()));
+        assert_eq!(diff.to_run, RunId("run_2".into()));
+        assert_eq!(diff.events_added.len(), 1);
+        assert_eq!(diff.events_added[0].title, "Added");
+        assert_eq!(diff.events_removed[0].title, "Dropped");
+        assert_eq!(diff.events_changed.len(), 1);
+        assert_eq!(diff.events_changed[0].fields, vec!["payload.data.title"]);
+        assert_eq!(diff.workstreams.added, vec!["Docs"]);
+        assert_eq!(diff.workstreams.removed, vec!["Ops"]);
+        assert_eq!(
+            diff.workstreams.changed,
+            vec![WorkstreamChange {
+                title: "API platform".into(),
+                renamed_from: Some("API".into()),
+                events_added: 1,
+                events_removed: 0,
+            }]
+        );
+        assert!(!diff.is_unchanged());
+        let report = crate::render::md::render_bundle_diff(&diff);
+        assert!(report.contains("- Changed: API platform (renamed from API), +1 / -0 events"));
+        assert!(diff_bundles(&new_dir, &new_dir).unwrap().is_unchanged());
+    }
+}
@@ -0,0 +1,135 @@
+//! age encryption for bundle archives.
+//!
+//! Public and manager bundles are often sent over email or chat. Encrypting
+//! the archive to the reviewers' age X25519 public keys keeps the contents
+//! confidential regardless of the transport.
+
+use anyhow::{Context, Result, anyhow};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Extension appended to encrypted archives (`run.zip` -> `run.zip.age`).
+pub const AGE_EXTENSION: &str = "age";
+
+/// Parse `age1...` recipient public keys.
+///
+/// Blank entries are rejected so a typo in config does not silently drop a
+/// reviewer from the recipient list.
+pub fn parse_age_recipients(raw: &[String]) -> Result<Vec<age::x25519::Recipient>> {
+    raw.iter()
+        .map(|recipient| {
+            recipient
+                .trim()
+                .parse::<age::x25519::Recipient>()
+                .map_err(|err| anyhow!("invalid age recipient {recipient:?}: {err}"))
+        })
+        .collect()
+}
+
+/// Path of the encrypted copy of `archive_path`.
+pub fn encrypted_archive_path(archive_path: &Path) -> PathBuf {
+    let mut name = archive_path.as_os_str().to_owned();
+    name.push(".");
+    name.push(AGE_EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Encrypt an archive to the given recipients and remove the plaintext copy.
+///
+/// Returns the path of the `.age` file. Fails without touching the archive
+/// when `recipients` is empty or contains an invalid key.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::encrypt_archive;
+/// use std::path::Path;
+///
+/// let encrypted = encrypt_archive(
+///     Path::new("./out/run_123.public.zip"),
+///     &["age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p".to_string()],
+/// )?;
+/// assert!(encrypted.ends_with("run_123.public.zip.age"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn encrypt_archive(archive_path: &Path, recipients: &[String]) -> Result<PathBuf> {
+    let recipients = parse_age_recipients(recipients)?;
+    if recipients.is_empty() {
+        anyhow::bail!("age encryption requires at least one recipient");
+    }
+    let encryptor = age::Encryptor::with_recipients(
+        recipients
+            .iter()
+            .map(|recipient| recipient as &dyn age::Recipient),
+    )
+    .context("prepare age encryptor")?;
+
+    let plaintext =
+        std::fs::read(archive_path).with_context(|| format!("read {archive_path:?}"))?;
+    let encrypted_path = encrypted_archive_path(archive_path);
+    let file = std::fs::File::create(&encrypted_path)
+        .with_context(|| format!("create {encrypted_path:?}"))?;
+    let mut writer = encryptor
+        .wrap_output(file)
+        .context("start age encryption stream")?;
+    writer
+        .write_all(&plaintext)
+        .context("write age encrypted archive")?;
+    writer.finish().context("finalize age encrypted archive")?;
+
+    std::fs::remove_file(archive_path)
+        .with_context(|| format!("remove plaintext archive {archive_path:?}"))?;
+    Ok(encrypted_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn decrypt(path: &Path, identity: &age::x25519::Identity) -> Vec<u8> {
+        let file = std::fs::File::open(path).unwrap();
+        let decryptor = age::Decryptor::new(file).unwrap();
+        let mut reader = decryptor
+            .decrypt(std::iter::once(identity as &dyn age::Identity))
+            .unwrap();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn encrypt_archive_round_trips_for_each_recipient() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("run.public.zip");
+        std::fs::write(&archive, b"archive bytes").unwrap();
+        let alice = age::x25519::Identity::generate();
+        let bob = age::x25519::Identity::generate();
+
+        let encrypted = encrypt_archive(
+            &archive,
+            &[alice.to_public().to_string(), bob.to_public().to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(encrypted, dir.path().join("run.public.zip.age"));
+        assert!(!archive.exists(), "plaintext archive should be removed");
+        assert_eq!(decrypt(&encrypted, &alice), b"archive bytes");
+        assert_eq!(decrypt(&encrypted, &bob), b"archive bytes");
+    }
+
+    #[test]
+    fn invalid_or_missing_recipients_leave_archive_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("run.zip");
+        std::fs::write(&archive, b"archive bytes").unwrap();
+
+        let err = encrypt_archive(&archive, &["age1notakey".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("invalid age recipient"), "{err}");
+        let err = encrypt_archive(&archive, &[]).unwrap_err();
+        assert!(err.to_string().contains("at least one recipient"), "{err}");
+
+        assert!(archive.exists());
+        assert!(!encrypted_archive_path(&archive).exists());
+    }
+}
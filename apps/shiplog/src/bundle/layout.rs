@@ -4,13 +4,24 @@ use std::path::{Path, PathBuf};
 
 /// Canonical artifact filenames emitted by the shiplog pipeline.
 pub const FILE_PACKET_MD: &str = "packet.md";
+pub const FILE_PACKET_PDF: &str = "packet.pdf";
+pub const FILE_PACKET_JSON: &str = "packet.json";
 pub const FILE_LEDGER_EVENTS_JSONL: &str = "ledger.events.jsonl";
 pub const FILE_COVERAGE_MANIFEST_JSON: &str = "coverage.manifest.json";
+pub const FILE_COVERAGE_REPORT_MD: &str = "coverage.report.md";
+pub const FILE_COVERAGE_GAPS_JSON: &str = "coverage.gaps.json";
+pub const FILE_NEEDS_ATTENTION_JSON: &str = "needs_attention.json";
 pub const FILE_BUNDLE_MANIFEST_JSON: &str = "bundle.manifest.json";
+pub const FILE_BUNDLE_SIGNATURE_JSON: &str = "bundle.manifest.sig.json";
+pub const FILE_BUNDLE_DELTA_JSON: &str = "bundle.delta.json";
 pub const FILE_REDACTION_ALIASES_JSON: &str = "redaction.aliases.json";
+pub const FILE_SEARCH_INDEX_SQLITE: &str = "search.index.sqlite";
+pub const FILE_SCAN_REPORT_JSON: &str = "scan.report.json";
 
 /// Canonical directory names used by profile-based outputs.
 pub const DIR_PROFILES: &str = "profiles";
+/// Directory holding copies of local evidence files attached to manual events.
+pub const DIR_EVIDENCE: &str = "evidence";
 pub const PROFILE_INTERNAL: &str = "internal";
 pub const PROFILE_MANAGER: &str = "manager";
 pub const PROFILE_PUBLIC: &str = "public";
@@ -22,9 +33,9 @@ pub const PROFILE_PUBLIC: &str = "public";
 /// [`policy/clippy-protected-fields.toml`](../../../../policy/clippy-protected-fields.toml))
 /// guards against producer-local paths leaking into manifests.
 /// External callers reach the run-directory components via the
-/// accessor methods on this type (`packet_md`, `ledger_events`,
-/// `coverage_manifest`, `bundle_manifest`, `redaction_aliases`,
-/// `profile_packet`). The post-#206 audit confirmed zero external
+/// accessor methods on this type (`packet_md`, `packet_pdf`, `packet_json`, `ledger_events`,
+/// `coverage_manifest`, `coverage_report_md`, `coverage_gaps_json`, `needs_attention_json`,
+/// `bundle_manifest`, `redaction_aliases`, `search_index`, `evidence_dir`, `profile_packet`). The post-#206 audit confirmed zero external
 /// callers read this field directly, so tightening visibility is a
 /// no-behavior-change refactor.
 #[derive(Debug, Clone)]
@@ -45,6 +56,16 @@ impl RunArtifactPaths {
         self.out_dir.join(FILE_PACKET_MD)
     }
 
+    /// `packet.pdf`
+    pub fn packet_pdf(&self) -> PathBuf {
+        self.out_dir.join(FILE_PACKET_PDF)
+    }
+
+    /// `packet.json`
+    pub fn packet_json(&self) -> PathBuf {
+        self.out_dir.join(FILE_PACKET_JSON)
+    }
+
     /// `ledger.events.jsonl`
     pub fn ledger_events(&self) -> PathBuf {
         self.out_dir.join(FILE_LEDGER_EVENTS_JSONL)
@@ -55,11 +76,36 @@ impl RunArtifactPaths {
         self.out_dir.join(FILE_COVERAGE_MANIFEST_JSON)
     }
 
+    /// `coverage.report.md`
+    pub fn coverage_report_md(&self) -> PathBuf {
+        self.out_dir.join(FILE_COVERAGE_REPORT_MD)
+    }
+
+    /// `coverage.gaps.json`
+    pub fn coverage_gaps_json(&self) -> PathBuf {
+        self.out_dir.join(FILE_COVERAGE_GAPS_JSON)
+    }
+
+    /// `needs_attention.json`
+    pub fn needs_attention_json(&self) -> PathBuf {
+        self.out_dir.join(FILE_NEEDS_ATTENTION_JSON)
+    }
+
     /// `bundle.manifest.json`
     pub fn bundle_manifest(&self) -> PathBuf {
         self.out_dir.join(FILE_BUNDLE_MANIFEST_JSON)
     }
 
+    /// `search.index.sqlite`
+    pub fn search_index(&self) -> PathBuf {
+        self.out_dir.join(FILE_SEARCH_INDEX_SQLITE)
+    }
+
+    /// `evidence/`
+    pub fn evidence_dir(&self) -> PathBuf {
+        self.out_dir.join(DIR_EVIDENCE)
+    }
+
     /// `profiles/<profile>/packet.md`
     pub fn profile_packet(&self, profile: impl AsRef<str>) -> PathBuf {
         self.out_dir
@@ -69,16 +115,80 @@ impl RunArtifactPaths {
     }
 }
 
+/// Archive container written next to a run directory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BundleFormat {
+    /// Deflate-compressed zip archive (the default).
+    #[default]
+    Zip,
+    /// Gzip-compressed tarball with normalised entry metadata.
+    TarGz,
+}
+
+impl BundleFormat {
+    /// File extension used for archives of this format, without a leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Zip => "zip",
+            Self::TarGz => "tar.gz",
+        }
+    }
+}
+
 /// Compute the zip file path for a run profile.
 /// - `"internal"` -> `<run_dir>.zip`
 /// - any other value -> `<run_dir>.<profile>.zip`
 pub fn zip_path_for_profile(out_dir: &Path, profile: &str) -> PathBuf {
+    archive_path_for_profile(out_dir, profile, BundleFormat::Zip)
+}
+
+/// Compute the incremental (delta) archive path for a run profile.
+/// - `"internal"` -> `<run_dir>.delta.<ext>`
+/// - any other value -> `<run_dir>.<profile>.delta.<ext>`
+pub fn delta_archive_path_for_profile(
+    out_dir: &Path,
+    profile: &str,
+    format: BundleFormat,
+) -> PathBuf {
+    let stem = out_dir.file_name().unwrap_or_default().to_string_lossy();
+    let name = if profile == PROFILE_INTERNAL {
+        format!("{stem}.delta.{}", format.extension())
+    } else {
+        format!("{stem}.{profile}.delta.{}", format.extension())
+    };
+    out_dir.with_file_name(name)
+}
+
+/// Compute the path of one part of a split archive.
+/// - `"internal"` -> `<run_dir>.part<index>of<count>.<ext>`
+/// - any other value -> `<run_dir>.<profile>.part<index>of<count>.<ext>`
+pub fn part_archive_path_for_profile(
+    out_dir: &Path,
+    profile: &str,
+    format: BundleFormat,
+    index: u32,
+    count: u32,
+) -> PathBuf {
+    let stem = out_dir.file_name().unwrap_or_default().to_string_lossy();
+    let part = format!("part{index}of{count}.{}", format.extension());
+    let name = if profile == PROFILE_INTERNAL {
+        format!("{stem}.{part}")
+    } else {
+        format!("{stem}.{profile}.{part}")
+    };
+    out_dir.with_file_name(name)
+}
+
+/// Compute the archive path for a run profile and bundle format.
+/// - `"internal"` -> `<run_dir>.<ext>`
+/// - any other value -> `<run_dir>.<profile>.<ext>`
+pub fn archive_path_for_profile(out_dir: &Path, profile: &str, format: BundleFormat) -> PathBuf {
     if profile == PROFILE_INTERNAL {
-        return out_dir.with_extension("zip");
+        return out_dir.with_extension(format.extension());
     }
 
     let stem = out_dir.file_name().unwrap_or_default().to_string_lossy();
-    out_dir.with_file_name(format!("{}.{}.zip", stem, profile))
+    out_dir.with_file_name(format!("{}.{}.{}", stem, profile, format.extension()))
 }
 
 #[cfg(test)]
@@ -115,20 +225,67 @@ mod tests {
         assert_eq!(manager, Path::new("/tmp/run_01.manager.zip"));
     }
 
+    #[test]
+    fn archive_path_uses_format_extension() {
+        let internal = archive_path_for_profile(
+            Path::new("/tmp/run_01"),
+            PROFILE_INTERNAL,
+            BundleFormat::TarGz,
+        );
+        let public = archive_path_for_profile(
+            Path::new("/tmp/run_01"),
+            PROFILE_PUBLIC,
+            BundleFormat::TarGz,
+        );
+        assert_eq!(internal, Path::new("/tmp/run_01.tar.gz"));
+        assert_eq!(public, Path::new("/tmp/run_01.public.tar.gz"));
+        assert_eq!(
+            archive_path_for_profile(Path::new("/tmp/run_01"), PROFILE_MANAGER, BundleFormat::Zip),
+            zip_path_for_profile(Path::new("/tmp/run_01"), PROFILE_MANAGER)
+        );
+    }
+
+    #[test]
+    fn delta_archive_path_marks_delta_before_extension() {
+        assert_eq!(
+            delta_archive_path_for_profile(
+                Path::new("/tmp/run_01"),
+                PROFILE_INTERNAL,
+                BundleFormat::Zip
+            ),
+            Path::new("/tmp/run_01.delta.zip")
+        );
+        assert_eq!(
+            delta_archive_path_for_profile(
+                Path::new("/tmp/run_01"),
+                PROFILE_MANAGER,
+                BundleFormat::TarGz
+            ),
+            Path::new("/tmp/run_01.manager.delta.tar.gz")
+        );
+    }
+
     // --- Constant value tests ---
 
     #[test]
     fn file_constants_have_expected_values() {
         assert_eq!(FILE_PACKET_MD, "packet.md");
+        assert_eq!(FILE_PACKET_PDF, "packet.pdf");
+        assert_eq!(FILE_PACKET_JSON, "packet.json");
         assert_eq!(FILE_LEDGER_EVENTS_JSONL, "ledger.events.jsonl");
         assert_eq!(FILE_COVERAGE_MANIFEST_JSON, "coverage.manifest.json");
+        assert_eq!(FILE_COVERAGE_REPORT_MD, "coverage.report.md");
+        assert_eq!(FILE_COVERAGE_GAPS_JSON, "coverage.gaps.json");
+        assert_eq!(FILE_NEEDS_ATTENTION_JSON, "needs_attention.json");
         assert_eq!(FILE_BUNDLE_MANIFEST_JSON, "bundle.manifest.json");
         assert_eq!(FILE_REDACTION_ALIASES_JSON, "redaction.aliases.json");
+        assert_eq!(FILE_SEARCH_INDEX_SQLITE, "search.index.sqlite");
     }
 
     #[test]
     fn profile_constants_have_expected_values() {
         assert_eq!(DIR_PROFILES, "profiles");
+        assert_eq!(DIR_EVIDENCE, "evidence");
         assert_eq!(PROFILE_INTERNAL, "internal");
         assert_eq!(PROFILE_MANAGER, "manager");
         assert_eq!(PROFILE_PUBLIC, "public");
@@ -199,6 +356,9 @@ mod tests {
         assert!(paths.packet_md().starts_with(&base));
         assert!(paths.ledger_events().starts_with(&base));
         assert!(paths.coverage_manifest().starts_with(&base));
+        assert!(paths.coverage_report_md().starts_with(&base));
+        assert!(paths.coverage_gaps_json().starts_with(&base));
+        assert!(paths.needs_attention_json().starts_with(&base));
         assert!(paths.bundle_manifest().starts_with(&base));
         assert!(paths.profile_packet("any").starts_with(&base));
     }
@@ -376,4 +536,28 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn part_archive_path_numbers_parts_before_extension() {
+        assert_eq!(
+            part_archive_path_for_profile(
+                Path::new("/tmp/run_01"),
+                PROFILE_INTERNAL,
+                BundleFormat::Zip,
+                1,
+                3
+            ),
+            Path::new("/tmp/run_01.part1of3.zip")
+        );
+        assert_eq!(
+            part_archive_path_for_profile(
+                Path::new("/tmp/run_01"),
+                PROFILE_PUBLIC,
+                BundleFormat::TarGz,
+                2,
+                2
+            ),
+            Path::new("/tmp/run_01.public.part2of2.tar.gz")
+        );
+    }
 }
@@ -1,46 +1,70 @@
 //! Bundle writer for shiplog run outputs.
 //!
 //! Generates `bundle.manifest.json` (file checksums + sizes) and builds
-//! profile-scoped zip archives for `internal`, `manager`, and `public` handoff.
+//! profile-scoped zip or tar.gz archives for `internal`, `manager`, and
+//! `public` handoff, optionally age-encrypted to configured recipients.
+//! Incremental (delta) archives carry only files changed since a previous
+//! manifest, and emitted bundles can be re-verified against their manifest.
+//! Manifests can be signed with an ed25519 key so recipients can check a
+//! bundle was not modified after export, and two runs can be diffed to show
+//! what changed since the last packet.
 
 use anyhow::{Context, Result};
 use chrono::Utc;
 use sha2::{Digest, Sha256};
 use shiplog::ids::RunId;
-use shiplog::schema::bundle::{BundleManifest, BundleProfile, FileChecksum};
+use shiplog::schema::bundle::{
+    BundleManifest, BundleProfile, BundleProvenance, BundleScope, FileChecksum,
+};
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 
+pub mod delta;
+pub mod diff;
+pub mod encrypt;
 pub mod layout;
-
+pub mod scope;
+pub mod sign;
+pub mod split;
+pub mod verify;
+
+pub use delta::{diff_manifests, read_bundle_manifest, write_delta_archive};
+pub use diff::{BundleDiff, EventChange, WorkstreamChange, WorkstreamDiff, diff_bundles};
+pub use encrypt::{AGE_EXTENSION, encrypt_archive, encrypted_archive_path, parse_age_recipients};
 pub use layout::{
-    DIR_PROFILES, FILE_BUNDLE_MANIFEST_JSON, FILE_COVERAGE_MANIFEST_JSON, FILE_LEDGER_EVENTS_JSONL,
-    FILE_PACKET_MD, FILE_REDACTION_ALIASES_JSON, PROFILE_INTERNAL, PROFILE_MANAGER, PROFILE_PUBLIC,
-    RunArtifactPaths, zip_path_for_profile,
+    BundleFormat, DIR_EVIDENCE, DIR_PROFILES, FILE_BUNDLE_DELTA_JSON, FILE_BUNDLE_MANIFEST_JSON,
+    FILE_BUNDLE_SIGNATURE_JSON, FILE_COVERAGE_GAPS_JSON, FILE_COVERAGE_MANIFEST_JSON,
+    FILE_COVERAGE_REPORT_MD, FILE_LEDGER_EVENTS_JSONL, FILE_NEEDS_ATTENTION_JSON, FILE_PACKET_JSON,
+    FILE_PACKET_MD, FILE_PACKET_PDF, FILE_REDACTION_ALIASES_JSON, FILE_SCAN_REPORT_JSON,
+    FILE_SEARCH_INDEX_SQLITE, PROFILE_INTERNAL, PROFILE_MANAGER, PROFILE_PUBLIC, RunArtifactPaths,
+    archive_path_for_profile, delta_archive_path_for_profile, part_archive_path_for_profile,
+    zip_path_for_profile,
 };
+pub use scope::BundleScopes;
+use scope::is_scoped_include;
+use sign::signature_entries;
+pub use sign::{
+    BundleSigner, SIGNATURE_ALGORITHM, SIGNING_KEY_ENV, SignatureCheck, remove_bundle_signature,
+    verify_manifest_signature,
+};
+pub use split::{plan_bundle_parts, write_archive_parts};
+pub use verify::{BundleVerifyReport, bundle_source_dir, verify_bundle};
 
 /// Files excluded from bundles regardless of profile. `redaction.aliases.json`
 /// contains plaintext-to-alias mappings that would defeat redaction.
 /// `bundle.manifest.json` is excluded because it is written *after*
-/// the file walk and must not checksum itself.
-const ALWAYS_EXCLUDED: &[&str] = &[FILE_REDACTION_ALIASES_JSON, FILE_BUNDLE_MANIFEST_JSON];
-
-/// Decide whether `rel_path` (forward-slash normalised, relative to the run
-/// directory) should be included in a bundle for the given profile.
-fn is_scoped_include(rel_path: &str, profile: &BundleProfile) -> bool {
-    match profile {
-        BundleProfile::Internal => true,
-        BundleProfile::Manager => {
-            rel_path == format!("{DIR_PROFILES}/{PROFILE_MANAGER}/{FILE_PACKET_MD}")
-                || rel_path == FILE_COVERAGE_MANIFEST_JSON
-        }
-        BundleProfile::Public => {
-            rel_path == format!("{DIR_PROFILES}/{PROFILE_PUBLIC}/{FILE_PACKET_MD}")
-                || rel_path == FILE_COVERAGE_MANIFEST_JSON
-        }
-    }
-}
+/// the file walk and must not checksum itself; signed archives add it and
+/// `bundle.manifest.sig.json` back verbatim. `search.index.sqlite` is a
+/// local lookup aid rebuilt from the ledger, not a shareable artifact, and
+/// `scan.report.json` is a pre-share check written after the manifest.
+const ALWAYS_EXCLUDED: &[&str] = &[
+    FILE_REDACTION_ALIASES_JSON,
+    FILE_BUNDLE_MANIFEST_JSON,
+    FILE_BUNDLE_SIGNATURE_JSON,
+    FILE_SEARCH_INDEX_SQLITE,
+    FILE_SCAN_REPORT_JSON,
+];
 
 /// Write `bundle.manifest.json` containing SHA-256 checksums for all files
 /// included in the given profile scope.
@@ -66,9 +90,58 @@ pub fn write_bundle_manifest(
     run_id: &RunId,
     profile: &BundleProfile,
 ) -> Result<BundleManifest> {
+    write_bundle_manifest_with_options(out_dir, run_id, profile, &BundleManifestOptions::default())
+}
+
+/// Optional records written into `bundle.manifest.json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BundleManifestOptions {
+    /// Configured content selection that narrows or replaces the profile
+    /// default. Recorded so `bundle verify` checks the same file selection.
+    pub scope: Option<BundleScope>,
+    /// How the bundle was produced.
+    pub provenance: Option<BundleProvenance>,
+    /// Split archives so no part holds more than this many uncompressed
+    /// bytes; see [`plan_bundle_parts`].
+    pub max_part_bytes: Option<u64>,
+}
+
+/// Write `bundle.manifest.json` with a configured scope and/or provenance.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::{BundleManifestOptions, write_bundle_manifest_with_options};
+/// use shiplog::ids::RunId;
+/// use shiplog::schema::bundle::{BundleProfile, BundleScope};
+/// use std::path::Path;
+///
+/// let options = BundleManifestOptions {
+///     scope: Some(BundleScope {
+///         include: vec![],
+///         exclude: vec!["coverage.manifest.json".into()],
+///     }),
+///     provenance: None,
+///     max_part_bytes: None,
+/// };
+/// write_bundle_manifest_with_options(
+///     Path::new("./out/run_123"),
+///     &RunId::now("example"),
+///     &BundleProfile::Public,
+///     &options,
+/// )?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn write_bundle_manifest_with_options(
+    out_dir: &Path,
+    run_id: &RunId,
+    profile: &BundleProfile,
+    options: &BundleManifestOptions,
+) -> Result<BundleManifest> {
+    let scope = options.scope.as_ref();
     let mut files = Vec::new();
 
-    for path in walk_files(out_dir, profile)? {
+    for path in walk_files(out_dir, profile, scope)? {
         let bytes = std::fs::metadata(&path)
             .with_context(|| format!("read metadata for {path:?}"))?
             .len();
@@ -86,11 +159,18 @@ pub fn write_bundle_manifest(
         });
     }
 
+    let parts = options
+        .max_part_bytes
+        .map(|max_part_bytes| plan_bundle_parts(&files, max_part_bytes))
+        .unwrap_or_default();
     let manifest = BundleManifest {
         run_id: run_id.clone(),
         generated_at: Utc::now(),
         profile: profile.clone(),
         files,
+        scope: options.scope.clone(),
+        provenance: options.provenance.clone(),
+        parts,
     };
 
     let text = serde_json::to_string_pretty(&manifest).context("serialize bundle manifest")?;
@@ -101,6 +181,10 @@ pub fn write_bundle_manifest(
 
 /// Write a profile-scoped zip archive from the run directory.
 ///
+/// When the run has been signed (see [`BundleSigner::sign_run`]) the archive
+/// also carries `bundle.manifest.json` and `bundle.manifest.sig.json`, so it
+/// can be verified on its own.
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -116,37 +200,170 @@ pub fn write_bundle_manifest(
 /// # Ok::<(), anyhow::Error>(())
 /// ```
 pub fn write_zip(out_dir: &Path, zip_path: &Path, profile: &BundleProfile) -> Result<()> {
+    write_zip_with_scope(out_dir, zip_path, profile, None)
+}
+
+/// Write a zip archive limited to the files a configured scope selects.
+pub fn write_zip_with_scope(
+    out_dir: &Path,
+    zip_path: &Path,
+    profile: &BundleProfile,
+    scope: Option<&BundleScope>,
+) -> Result<()> {
     let file = File::create(zip_path).with_context(|| format!("create zip {zip_path:?}"))?;
+    let mut entries = read_entries(archive_entries(out_dir, zip_path, profile, scope)?)?;
+    entries.extend(signature_entries(out_dir)?);
+    zip_entries(file, entries)
+}
+
+/// Write a profile-scoped gzip-compressed tarball from the run directory.
+///
+/// Entries are sorted and carry fixed metadata (mtime 0, uid/gid 0, mode
+/// `0644`, no owner names), and the gzip header has no timestamp, so the same
+/// run directory always produces byte-identical archives.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::write_tar_gz;
+/// use shiplog::schema::bundle::BundleProfile;
+/// use std::path::Path;
+///
+/// write_tar_gz(
+///     Path::new("./out/run_123"),
+///     Path::new("./out/run_123.tar.gz"),
+///     &BundleProfile::Internal,
+/// )?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn write_tar_gz(out_dir: &Path, tar_path: &Path, profile: &BundleProfile) -> Result<()> {
+    write_tar_gz_with_scope(out_dir, tar_path, profile, None)
+}
+
+/// Write a tarball limited to the files a configured scope selects.
+pub fn write_tar_gz_with_scope(
+    out_dir: &Path,
+    tar_path: &Path,
+    profile: &BundleProfile,
+    scope: Option<&BundleScope>,
+) -> Result<()> {
+    let file = File::create(tar_path).with_context(|| format!("create tarball {tar_path:?}"))?;
+    let mut entries = read_entries(archive_entries(out_dir, tar_path, profile, scope)?)?;
+    entries.extend(signature_entries(out_dir)?);
+    tar_gz_entries(file, entries)
+}
+
+/// Write a profile-scoped archive in the requested format.
+pub fn write_archive(
+    out_dir: &Path,
+    archive_path: &Path,
+    profile: &BundleProfile,
+    format: BundleFormat,
+) -> Result<()> {
+    write_archive_with_scope(out_dir, archive_path, profile, format, None)
+}
+
+/// Write an archive in the requested format, honouring a configured scope.
+pub fn write_archive_with_scope(
+    out_dir: &Path,
+    archive_path: &Path,
+    profile: &BundleProfile,
+    format: BundleFormat,
+    scope: Option<&BundleScope>,
+) -> Result<()> {
+    match format {
+        BundleFormat::Zip => write_zip_with_scope(out_dir, archive_path, profile, scope),
+        BundleFormat::TarGz => write_tar_gz_with_scope(out_dir, archive_path, profile, scope),
+    }
+}
+
+/// Write in-memory `(archive path, bytes)` entries in the requested format.
+fn write_archive_entries(
+    archive_path: &Path,
+    format: BundleFormat,
+    entries: Vec<(String, Vec<u8>)>,
+) -> Result<()> {
+    let file =
+        File::create(archive_path).with_context(|| format!("create archive {archive_path:?}"))?;
+    match format {
+        BundleFormat::Zip => zip_entries(file, entries),
+        BundleFormat::TarGz => tar_gz_entries(file, entries),
+    }
+}
+
+fn zip_entries(file: File, entries: Vec<(String, Vec<u8>)>) -> Result<()> {
     let mut zip = zip::ZipWriter::new(file);
     let opts: zip::write::FileOptions<()> = zip::write::FileOptions::default()
         .compression_method(zip::CompressionMethod::Deflated)
         .unix_permissions(0o644);
-    let zip_target = zip_path
-        .canonicalize()
-        .unwrap_or_else(|_| zip_path.to_path_buf());
 
-    for path in walk_files(out_dir, profile)? {
+    for (rel, buf) in entries {
+        zip.start_file(rel, opts).context("start zip entry")?;
+        zip.write_all(&buf).context("write zip entry")?;
+    }
+
+    zip.finish().context("finalize zip archive")?;
+    Ok(())
+}
+
+fn tar_gz_entries(file: File, entries: Vec<(String, Vec<u8>)>) -> Result<()> {
+    let gz = flate2::GzBuilder::new()
+        .mtime(0)
+        .write(file, flate2::Compression::default());
+    let mut tar = tar::Builder::new(gz);
+
+    for (rel, buf) in entries {
+        let mut header = tar::Header::new_ustar();
+        header.set_size(buf.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(0);
+        header.set_uid(0);
+        header.set_gid(0);
+        header.set_entry_type(tar::EntryType::Regular);
+        tar.append_data(&mut header, &rel, buf.as_slice())
+            .with_context(|| format!("append {rel} to tarball"))?;
+    }
+
+    let gz = tar.into_inner().context("finalize tar archive")?;
+    gz.finish().context("finalize gzip stream")?;
+    Ok(())
+}
+
+fn read_entries(entries: Vec<(PathBuf, String)>) -> Result<Vec<(String, Vec<u8>)>> {
+    entries
+        .into_iter()
+        .map(|(path, rel)| {
+            let buf = std::fs::read(&path).with_context(|| format!("read {path:?}"))?;
+            Ok((rel, buf))
+        })
+        .collect()
+}
+
+/// Sorted `(source path, archive-relative path)` pairs for a bundle, skipping
+/// the archive itself when it is written inside the run directory.
+fn archive_entries(
+    out_dir: &Path,
+    archive_path: &Path,
+    profile: &BundleProfile,
+    scope: Option<&BundleScope>,
+) -> Result<Vec<(PathBuf, String)>> {
+    let target = archive_path
+        .canonicalize()
+        .unwrap_or_else(|_| archive_path.to_path_buf());
+    let mut entries = Vec::new();
+    for path in walk_files(out_dir, profile, scope)? {
         let source = path.canonicalize().unwrap_or_else(|_| path.clone());
-        if source == zip_target {
+        if source == target {
             continue;
         }
-
         let rel = path
             .strip_prefix(out_dir)
             .unwrap_or(&path)
             .to_string_lossy()
             .replace('\\', "/");
-
-        zip.start_file(rel, opts).context("start zip entry")?;
-        let mut f = File::open(&path).with_context(|| format!("open {path:?} for zip"))?;
-        let mut buf = Vec::new();
-        f.read_to_end(&mut buf)
-            .with_context(|| format!("read {path:?}"))?;
-        zip.write_all(&buf).context("write zip entry")?;
+        entries.push((path, rel));
     }
-
-    zip.finish().context("finalize zip archive")?;
-    Ok(())
+    Ok(entries)
 }
 
 fn sha256_file(path: &Path) -> Result<String> {
@@ -159,7 +376,11 @@ fn sha256_file(path: &Path) -> Result<String> {
     Ok(hex::encode(h.finalize()))
 }
 
-fn walk_files(root: &Path, profile: &BundleProfile) -> Result<Vec<PathBuf>> {
+fn walk_files(
+    root: &Path,
+    profile: &BundleProfile,
+    scope: Option<&BundleScope>,
+) -> Result<Vec<PathBuf>> {
     let mut out = Vec::new();
     let mut stack = vec![root.to_path_buf()];
     while let Some(p) = stack.pop() {
@@ -178,7 +399,7 @@ fn walk_files(root: &Path, profile: &BundleProfile) -> Result<Vec<PathBuf>> {
                     .unwrap_or(&path)
                     .to_string_lossy()
                     .replace('\\', "/");
-                if is_scoped_include(&rel, profile) {
+                if is_scoped_include(&rel, profile, scope) {
                     out.push(path);
                 }
             } else {
@@ -240,7 +461,7 @@ mod tests {
         std::fs::write(dir.path().join(FILE_REDACTION_ALIASES_JSON), "{}").unwrap();
         std::fs::write(dir.path().join(FILE_LEDGER_EVENTS_JSONL), "").unwrap();
 
-        let files = walk_files(dir.path(), &BundleProfile::Internal).unwrap();
+        let files = walk_files(dir.path(), &BundleProfile::Internal, None).unwrap();
         let names = file_names(&files);
 
         assert!(names.contains(&FILE_PACKET_MD.to_string()));
@@ -319,7 +540,7 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         make_test_dir(dir.path());
 
-        let files = walk_files(dir.path(), &BundleProfile::Manager).unwrap();
+        let files = walk_files(dir.path(), &BundleProfile::Manager, None).unwrap();
         let rels = rel_paths(dir.path(), &files);
 
         assert!(rels.contains(&FILE_COVERAGE_MANIFEST_JSON.to_string()));
@@ -337,7 +558,7 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         make_test_dir(dir.path());
 
-        let files = walk_files(dir.path(), &BundleProfile::Public).unwrap();
+        let files = walk_files(dir.path(), &BundleProfile::Public, None).unwrap();
         let rels = rel_paths(dir.path(), &files);
 
         assert!(rels.contains(&FILE_COVERAGE_MANIFEST_JSON.to_string()));
@@ -359,7 +580,7 @@ mod tests {
             BundleProfile::Manager,
             BundleProfile::Public,
         ] {
-            let files = walk_files(dir.path(), &profile).unwrap();
+            let files = walk_files(dir.path(), &profile, None).unwrap();
             let names = file_names(&files);
             assert!(
                 !names.contains(&FILE_REDACTION_ALIASES_JSON.to_string()),
@@ -404,6 +625,79 @@ mod tests {
         );
     }
 
+    fn tar_gz_names(path: &Path) -> Vec<String> {
+        let gz = flate2::read::GzDecoder::new(File::open(path).unwrap());
+        let mut archive = tar::Archive::new(gz);
+        archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                assert_eq!(entry.header().mtime().unwrap(), 0);
+                entry.path().unwrap().to_string_lossy().into_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tar_gz_respects_profile_and_sorts_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        make_test_dir(dir.path());
+
+        let tar_path = dir.path().join("test.tar.gz");
+        write_tar_gz(dir.path(), &tar_path, &BundleProfile::Manager).unwrap();
+        assert_eq!(
+            tar_gz_names(&tar_path),
+            vec![
+                FILE_COVERAGE_MANIFEST_JSON.to_string(),
+                format!("{DIR_PROFILES}/{PROFILE_MANAGER}/{FILE_PACKET_MD}"),
+            ]
+        );
+
+        let internal_path = dir.path().join("internal.tar.gz");
+        write_tar_gz(dir.path(), &internal_path, &BundleProfile::Internal).unwrap();
+        let names = tar_gz_names(&internal_path);
+        assert!(
+            !names
+                .iter()
+                .any(|n| n.contains(FILE_REDACTION_ALIASES_JSON))
+        );
+        assert!(!names.iter().any(|n| n == "internal.tar.gz"));
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn tar_gz_is_byte_for_byte_deterministic() {
+        let dir = tempfile::tempdir().unwrap();
+        make_test_dir(dir.path());
+        let out = tempfile::tempdir().unwrap();
+
+        let first = out.path().join("a.tar.gz");
+        let second = out.path().join("b.tar.gz");
+        write_archive(
+            dir.path(),
+            &first,
+            &BundleProfile::Internal,
+            BundleFormat::TarGz,
+        )
+        .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        write_archive(
+            dir.path(),
+            &second,
+            &BundleProfile::Internal,
+            BundleFormat::TarGz,
+        )
+        .unwrap();
+
+        assert_eq!(
+            std::fs::read(first).unwrap(),
+            std::fs::read(second).unwrap()
+        );
+    }
+
     #[test]
     fn zip_respects_profile() {
         let dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,218 @@
+//! Which run files each bundle profile contains.
+//!
+//! Every profile has a fixed default file set. A configured
+//! [`BundleScope`] can replace or trim that set per profile, for example to
+//! ship the ledger in manager bundles but never in public ones.
+
+use shiplog::schema::bundle::{BundleProfile, BundleScope};
+use std::collections::BTreeMap;
+
+use super::layout::{
+    DIR_PROFILES, FILE_COVERAGE_MANIFEST_JSON, FILE_PACKET_JSON, FILE_PACKET_MD, FILE_PACKET_PDF,
+    PROFILE_MANAGER, PROFILE_PUBLIC,
+};
+
+/// Configured content overrides keyed by bundle profile.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::bundle::BundleScopes;
+/// use shiplog::schema::bundle::{BundleProfile, BundleScope};
+///
+/// let scopes = BundleScopes {
+///     manager: Some(BundleScope {
+///         include: vec!["profiles/manager/packet.md".into(), "ledger.events.jsonl".into()],
+///         exclude: vec![],
+///     }),
+///     ..BundleScopes::default()
+/// };
+/// assert!(scopes.for_profile(&BundleProfile::Manager).is_some());
+/// assert!(scopes.for_profile(&BundleProfile::Public).is_none());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BundleScopes {
+    /// Override for internal bundles.
+    pub internal: Option<BundleScope>,
+    /// Override for manager bundles.
+    pub manager: Option<BundleScope>,
+    /// Override for public bundles.
+    pub public: Option<BundleScope>,
+    /// Overrides for custom profiles, keyed by profile name.
+    pub custom: BTreeMap<String, BundleScope>,
+}
+
+impl BundleScopes {
+    /// The configured scope for `profile`, if any.
+    pub fn for_profile(&self, profile: &BundleProfile) -> Option<&BundleScope> {
+        match profile {
+            BundleProfile::Internal => self.internal.as_ref(),
+            BundleProfile::Manager => self.manager.as_ref(),
+            BundleProfile::Public => self.public.as_ref(),
+            BundleProfile::Custom(name) => self.custom.get(name),
+        }
+    }
+}
+
+/// Decide whether `rel_path` (forward-slash normalised, relative to the run
+/// directory) should be included in a bundle for the given profile.
+///
+/// A scope with a non-empty `include` list replaces the profile default;
+/// its `exclude` list always applies last.
+pub(super) fn is_scoped_include(
+    rel_path: &str,
+    profile: &BundleProfile,
+    scope: Option<&BundleScope>,
+) -> bool {
+    let included = match scope {
+        Some(scope) if !scope.include.is_empty() => scope.matches_include(rel_path),
+        _ => is_default_include(rel_path, profile),
+    };
+    included && !scope.is_some_and(|scope| scope.matches_exclude(rel_path))
+}
+
+fn is_default_include(rel_path: &str, profile: &BundleProfile) -> bool {
+    match profile {
+        BundleProfile::Internal => true,
+        BundleProfile::Manager => {
+            is_profile_packet(rel_path, PROFILE_MANAGER) || rel_path == FILE_COVERAGE_MANIFEST_JSON
+        }
+        BundleProfile::Public => {
+            is_profile_packet(rel_path, PROFILE_PUBLIC) || rel_path == FILE_COVERAGE_MANIFEST_JSON
+        }
+        BundleProfile::Custom(name) => {
+            is_profile_packet(rel_path, name) || rel_path == FILE_COVERAGE_MANIFEST_JSON
+        }
+    }
+}
+
+/// The profile's `packet.md` and `packet.json`, and its `packet.pdf` when one
+/// was rendered.
+fn is_profile_packet(rel_path: &str, profile: &str) -> bool {
+    [FILE_PACKET_MD, FILE_PACKET_JSON, FILE_PACKET_PDF]
+        .iter()
+        .any(|file| rel_path == format!("{DIR_PROFILES}/{profile}/{file}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bundle::FILE_LEDGER_EVENTS_JSONL;
+
+    #[test]
+    fn no_scope_keeps_profile_defaults() {
+        assert!(is_scoped_include(
+            FILE_LEDGER_EVENTS_JSONL,
+            &BundleProfile::Internal,
+            None
+        ));
+        assert!(!is_scoped_include(
+            FILE_LEDGER_EVENTS_JSONL,
+            &BundleProfile::Manager,
+            None
+        ));
+        assert!(is_scoped_include(
+            FILE_COVERAGE_MANIFEST_JSON,
+            &BundleProfile::Public,
+            None
+        ));
+    }
+
+    #[test]
+    fn profile_defaults_carry_their_own_packet_files_only() {
+        let manager_pdf = format!("{DIR_PROFILES}/{PROFILE_MANAGER}/{FILE_PACKET_PDF}");
+        assert!(is_scoped_include(
+            &manager_pdf,
+            &BundleProfile::Manager,
+            None
+        ));
+        assert!(!is_scoped_include(
+            &manager_pdf,
+            &BundleProfile::Public,
+            None
+        ));
+        assert!(!is_scoped_include(
+            FILE_PACKET_PDF,
+            &BundleProfile::Manager,
+            None
+        ));
+        assert!(is_scoped_include(
+            &format!("{DIR_PROFILES}/{PROFILE_PUBLIC}/{FILE_PACKET_JSON}"),
+            &BundleProfile::Public,
+            None
+        ));
+    }
+
+    #[test]
+    fn custom_profile_defaults_carry_their_own_packet_files() {
+        let skip_level = BundleProfile::Custom("skip-level".into());
+        assert!(is_scoped_include(
+            &format!("{DIR_PROFILES}/skip-level/{FILE_PACKET_MD}"),
+            &skip_level,
+            None
+        ));
+        assert!(is_scoped_include(
+            FILE_COVERAGE_MANIFEST_JSON,
+            &skip_level,
+            None
+        ));
+        assert!(!is_scoped_include(
+            &format!("{DIR_PROFILES}/{PROFILE_MANAGER}/{FILE_PACKET_MD}"),
+            &skip_level,
+            None
+        ));
+        assert!(!is_scoped_include(
+            FILE_LEDGER_EVENTS_JSONL,
+            &skip_level,
+            None
+        ));
+    }
+
+    #[test]
+    fn exclude_only_scope_trims_profile_defaults() {
+        let scope = BundleScope {
+            include: vec![],
+            exclude: vec![FILE_COVERAGE_MANIFEST_JSON.into()],
+        };
+        let public = BundleProfile::Public;
+        assert!(!is_scoped_include(
+            FILE_COVERAGE_MANIFEST_JSON,
+            &public,
+            Some(&scope)
+        ));
+        assert!(is_scoped_include(
+            "profiles/public/packet.md",
+            &public,
+            Some(&scope)
+        ));
+    }
+
+    #[test]
+    fn include_scope_replaces_profile_defaults() {
+        let scope = BundleScope {
+            include: vec!["profiles/manager/".into(), FILE_LEDGER_EVENTS_JSONL.into()],
+            exclude: vec!["profiles/manager/share.manifest.json".into()],
+        };
+        let manager = BundleProfile::Manager;
+        assert!(is_scoped_include(
+            FILE_LEDGER_EVENTS_JSONL,
+            &manager,
+            Some(&scope)
+        ));
+        assert!(is_scoped_include(
+            "profiles/manager/packet.md",
+            &manager,
+            Some(&scope)
+        ));
+        assert!(!is_scoped_include(
+            "profiles/manager/share.manifest.json",
+            &manager,
+            Some(&scope)
+        ));
+        assert!(!is_scoped_include(
+            FILE_COVERAGE_MANIFEST_JSON,
+            &manager,
+            Some(&scope)
+        ));
+    }
+}
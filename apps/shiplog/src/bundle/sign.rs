@@ -0,0 +1,240 @@
+//! ed25519 signatures over bundle manifests.
+//!
+//! `bundle.manifest.json` lists a SHA-256 checksum for every bundled file, so
+//! signing the manifest's exact bytes vouches for the whole bundle. The
+//! signature is written next to the manifest as `bundle.manifest.sig.json`,
+//! and signed archives carry both files so a recipient can check the packet
+//! was not modified after export without access to the run directory.
+
+use anyhow::{Context, Result, anyhow};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use shiplog::schema::bundle::BundleSignature;
+use std::path::Path;
+
+use super::layout::{FILE_BUNDLE_MANIFEST_JSON, FILE_BUNDLE_SIGNATURE_JSON};
+
+/// Environment variable holding a hex-encoded signing key. Takes precedence
+/// over `bundle.signing_key_file` in `shiplog.toml`.
+pub const SIGNING_KEY_ENV: &str = "SHIPLOG_BUNDLE_SIGNING_KEY";
+
+/// Signature scheme recorded in [`BundleSignature::algorithm`].
+pub const SIGNATURE_ALGORITHM: &str = "ed25519";
+
+/// Signs bundle manifests with an ed25519 key.
+#[derive(Clone)]
+pub struct BundleSigner {
+    key: SigningKey,
+}
+
+impl std::fmt::Debug for BundleSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BundleSigner")
+            .field("public_key", &self.public_key_hex())
+            .finish()
+    }
+}
+
+impl BundleSigner {
+    /// Build a signer from a hex-encoded 32-byte ed25519 secret key.
+    ///
+    /// Surrounding whitespace is ignored so keys can be read straight from a
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::bundle::BundleSigner;
+    ///
+    /// let signer = BundleSigner::from_hex(&"07".repeat(32))?;
+    /// assert_eq!(signer.public_key_hex().len(), 64);
+    /// assert!(BundleSigner::from_hex("not-a-key").is_err());
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn from_hex(secret_key: &str) -> Result<Self> {
+        let bytes = hex::decode(secret_key.trim())
+            .map_err(|err| anyhow!("bundle signing key is not valid hex: {err}"))?;
+        let seed = <[u8; 32]>::try_from(bytes.as_slice()).map_err(|_| {
+            anyhow!(
+                "bundle signing key must be 32 bytes (64 hex characters), got {} bytes",
+                bytes.len()
+            )
+        })?;
+        Ok(Self {
+            key: SigningKey::from_bytes(&seed),
+        })
+    }
+
+    /// Read a hex-encoded secret key from a file.
+    pub fn from_key_file(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("read bundle signing key {path:?}"))?;
+        Self::from_hex(&text).with_context(|| format!("parse bundle signing key {path:?}"))
+    }
+
+    /// Hex-encoded public key recipients use to check signatures.
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key.verifying_key().as_bytes())
+    }
+
+    /// Sign `message`, normally the bytes of `bundle.manifest.json`.
+    pub fn sign(&self, message: &[u8]) -> BundleSignature {
+        BundleSignature {
+            algorithm: SIGNATURE_ALGORITHM.to_string(),
+            public_key: self.public_key_hex(),
+            signature: hex::encode(self.key.sign(message).to_bytes()),
+        }
+    }
+
+    /// Sign the run's `bundle.manifest.json` and write
+    /// `bundle.manifest.sig.json` next to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use shiplog::bundle::BundleSigner;
+    /// use std::path::Path;
+    ///
+    /// let signer = BundleSigner::from_key_file(Path::new("./keys/bundle.key"))?;
+    /// let signature = signer.sign_run(Path::new("./out/run_123"))?;
+    /// println!("signed by {}", signature.public_key);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn sign_run(&self, out_dir: &Path) -> Result<BundleSignature> {
+        let manifest_path = out_dir.join(FILE_BUNDLE_MANIFEST_JSON);
+        let manifest = std::fs::read(&manifest_path)
+            .with_context(|| format!("read bundle manifest {manifest_path:?}"))?;
+        let signature = self.sign(&manifest);
+        let text =
+            serde_json::to_string_pretty(&signature).context("serialize bundle signature")?;
+        std::fs::write(out_dir.join(FILE_BUNDLE_SIGNATURE_JSON), text)
+            .with_context(|| format!("write {FILE_BUNDLE_SIGNATURE_JSON}"))?;
+        Ok(signature)
+    }
+}
+
+/// Result of checking a manifest signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureCheck {
+    /// Hex-encoded public key the signature claims.
+    pub public_key: String,
+    /// Whether the signature matches the manifest bytes under that key.
+    pub valid: bool,
+}
+
+/// Check a `bundle.manifest.sig.json` document against the manifest bytes.
+///
+/// Malformed signature files, unknown algorithms, and bad keys all report
+/// `valid: false`. A valid check only proves the manifest was signed by
+/// `public_key`; callers decide whether they trust that key.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::bundle::{BundleSigner, verify_manifest_signature};
+///
+/// let signer = BundleSigner::from_hex(&"07".repeat(32))?;
+/// let signature = serde_json::to_vec(&signer.sign(b"{}"))?;
+/// assert!(verify_manifest_signature(b"{}", &signature).valid);
+/// assert!(!verify_manifest_signature(b"{ }", &signature).valid);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn verify_manifest_signature(manifest: &[u8], signature: &[u8]) -> SignatureCheck {
+    let Ok(signature) = serde_json::from_slice::<BundleSignature>(signature) else {
+        return SignatureCheck {
+            public_key: String::new(),
+            valid: false,
+        };
+    };
+    let valid = signature.algorithm == SIGNATURE_ALGORITHM
+        && check_ed25519(manifest, &signature.public_key, &signature.signature).is_some();
+    SignatureCheck {
+        public_key: signature.public_key,
+        valid,
+    }
+}
+
+fn check_ed25519(message: &[u8], public_key: &str, signature: &str) -> Option<()> {
+    let key = <[u8; 32]>::try_from(hex::decode(public_key).ok()?.as_slice()).ok()?;
+    let key = VerifyingKey::from_bytes(&key).ok()?;
+    let signature = Signature::from_slice(&hex::decode(signature).ok()?).ok()?;
+    key.verify_strict(message, &signature).ok()
+}
+
+/// Remove a signature left by an earlier signed bundle so it never sits next
+/// to a manifest it does not cover.
+pub fn remove_bundle_signature(out_dir: &Path) -> Result<()> {
+    let path = out_dir.join(FILE_BUNDLE_SIGNATURE_JSON);
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err).with_context(|| format!("remove stale {path:?}")),
+    }
+}
+
+/// Archive entries for a signed run: the manifest's exact bytes and its
+/// signature. Empty when the run is not signed.
+pub(crate) fn signature_entries(out_dir: &Path) -> Result<Vec<(String, Vec<u8>)>> {
+    let signature_path = out_dir.join(FILE_BUNDLE_SIGNATURE_JSON);
+    if !signature_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let manifest_path = out_dir.join(FILE_BUNDLE_MANIFEST_JSON);
+    let manifest =
+        std::fs::read(&manifest_path).with_context(|| format!("read {manifest_path:?}"))?;
+    let signature =
+        std::fs::read(&signature_path).with_context(|| format!("read {signature_path:?}"))?;
+    Ok(vec![
+        (FILE_BUNDLE_MANIFEST_JSON.to_string(), manifest),
+        (FILE_BUNDLE_SIGNATURE_JSON.to_string(), signature),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> BundleSigner {
+        BundleSigner::from_hex(&"2a".repeat(32)).unwrap()
+    }
+
+    #[test]
+    fn signed_run_verifies_until_manifest_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(FILE_BUNDLE_MANIFEST_JSON), "{\"files\":[]}").unwrap();
+        let signature = signer().sign_run(dir.path()).unwrap();
+        assert_eq!(signature.algorithm, SIGNATURE_ALGORITHM);
+
+        let entries = signature_entries(dir.path()).unwrap();
+        assert_eq!(entries.len(), 2);
+        let check = verify_manifest_signature(&entries[0].1, &entries[1].1);
+        assert!(check.valid);
+        assert_eq!(check.public_key, signer().public_key_hex());
+        assert!(!verify_manifest_signature(b"{\"files\":[1]}", &entries[1].1).valid);
+    }
+
+    #[test]
+    fn forged_or_malformed_signatures_are_invalid() {
+        let mut forged = signer().sign(b"manifest");
+        forged.public_key = BundleSigner::from_hex(&"01".repeat(32))
+            .unwrap()
+            .public_key_hex();
+        let forged = serde_json::to_vec(&forged).unwrap();
+        assert!(!verify_manifest_signature(b"manifest", &forged).valid);
+        assert!(!verify_manifest_signature(b"manifest", b"not json").valid);
+    }
+
+    #[test]
+    fn signing_keys_must_be_32_bytes_of_hex() {
+        assert!(BundleSigner::from_hex(&"2a".repeat(31)).is_err());
+        assert!(BundleSigner::from_hex(&format!("{}\n", "2a".repeat(32))).is_ok());
+        let debug = format!("{:?}", signer());
+        assert!(!debug.contains(&"2a".repeat(32)));
+    }
+
+    #[test]
+    fn unsigned_runs_have_no_signature_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(signature_entries(dir.path()).unwrap().is_empty());
+        remove_bundle_signature(dir.path()).unwrap();
+    }
+}
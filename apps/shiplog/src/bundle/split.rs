@@ -0,0 +1,189 @@
+//! Multi-part archives for bundles over an attachment size limit.
+//!
+//! HR and upload systems often cap attachments at 10–25 MB. A split bundle
+//! packs the manifest's files, in manifest order, into parts whose
+//! uncompressed content stays under the limit. Compression only shrinks the
+//! result, so each archive lands under the limit as well, apart from a file
+//! that is larger than the limit on its own, which gets a part to itself.
+
+use anyhow::{Context, Result};
+use shiplog::schema::bundle::{BundleManifest, BundlePart, FileChecksum};
+use std::path::{Path, PathBuf};
+
+use super::layout::{BundleFormat, FILE_BUNDLE_MANIFEST_JSON, part_archive_path_for_profile};
+use super::sign::signature_entries;
+use super::write_archive_entries;
+
+/// Group files into parts of at most `max_part_bytes` uncompressed bytes.
+///
+/// Returns no parts when everything fits in a single archive.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::bundle::plan_bundle_parts;
+/// use shiplog::schema::bundle::FileChecksum;
+///
+/// let file = |path: &str, bytes| FileChecksum {
+///     path: path.into(),
+///     sha256: String::new(),
+///     bytes,
+/// };
+/// let parts = plan_bundle_parts(&[file("a", 6), file("b", 6), file("c", 3)], 10);
+/// assert_eq!(parts.len(), 2);
+/// assert_eq!(parts[1].files, vec!["b".to_string(), "c".to_string()]);
+/// ```
+pub fn plan_bundle_parts(files: &[FileChecksum], max_part_bytes: u64) -> Vec<BundlePart> {
+    let total: u64 = files.iter().map(|file| file.bytes).sum();
+    if total <= max_part_bytes {
+        return Vec::new();
+    }
+
+    let mut parts: Vec<BundlePart> = Vec::new();
+    for file in files {
+        match parts.last_mut() {
+            Some(part) if part.bytes + file.bytes <= max_part_bytes => {
+                part.files.push(file.path.clone());
+                part.bytes += file.bytes;
+            }
+            _ => parts.push(BundlePart {
+                index: parts.len() as u32 + 1,
+                files: vec![file.path.clone()],
+                bytes: file.bytes,
+            }),
+        }
+    }
+    parts
+}
+
+/// Write one archive per entry in `manifest.parts`.
+///
+/// Each part holds its files plus a copy of the manifest, and of its
+/// signature when the run is signed. Returns the part paths in order.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::{BundleFormat, read_bundle_manifest, write_archive_parts};
+/// use std::path::Path;
+///
+/// let run = Path::new("./out/run_123");
+/// let manifest = read_bundle_manifest(run)?;
+/// for part in write_archive_parts(run, &manifest, BundleFormat::Zip)? {
+///     println!("{}", part.display());
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn write_archive_parts(
+    out_dir: &Path,
+    manifest: &BundleManifest,
+    format: BundleFormat,
+) -> Result<Vec<PathBuf>> {
+    let mut signed = signature_entries(out_dir)?;
+    if signed.is_empty() {
+        let manifest_json =
+            serde_json::to_vec_pretty(manifest).context("serialize bundle manifest")?;
+        signed.push((FILE_BUNDLE_MANIFEST_JSON.to_string(), manifest_json));
+    }
+    let count = manifest.parts.len() as u32;
+    let mut paths = Vec::new();
+    for part in &manifest.parts {
+        let mut entries = Vec::new();
+        for rel in &part.files {
+            let path = out_dir.join(rel);
+            let bytes = std::fs::read(&path).with_context(|| format!("read {path:?}"))?;
+            entries.push((rel.clone(), bytes));
+        }
+        entries.extend(signed.iter().cloned());
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let path = part_archive_path_for_profile(
+            out_dir,
+            manifest.profile.as_str(),
+            format,
+            part.index,
+            count,
+        );
+        write_archive_entries(&path, format, entries)
+            .with_context(|| format!("write bundle part {} of {count}", part.index))?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bundle::{
+        BundleManifestOptions, FILE_COVERAGE_MANIFEST_JSON, FILE_LEDGER_EVENTS_JSONL,
+        FILE_PACKET_MD, verify_bundle, write_bundle_manifest_with_options,
+    };
+    use shiplog::ids::RunId;
+    use shiplog::schema::bundle::BundleProfile;
+
+    fn file(path: &str, bytes: u64) -> FileChecksum {
+        FileChecksum {
+            path: path.into(),
+            sha256: String::new(),
+            bytes,
+        }
+    }
+
+    #[test]
+    fn bundle_under_limit_is_not_split() {
+        assert!(plan_bundle_parts(&[file("a", 4), file("b", 6)], 10).is_empty());
+    }
+
+    #[test]
+    fn oversized_file_gets_its_own_part() {
+        let parts = plan_bundle_parts(&[file("a", 2), file("big", 50), file("c", 2)], 10);
+        let files: Vec<Vec<String>> = parts.iter().map(|part| part.files.clone()).collect();
+        assert_eq!(
+            files,
+            vec![
+                vec!["a".to_string()],
+                vec!["big".to_string()],
+                vec!["c".to_string()]
+            ]
+        );
+        assert_eq!(
+            parts.iter().map(|part| part.index).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn each_written_part_verifies_on_its_own() {
+        let dir = tempfile::tempdir().unwrap();
+        let run = dir.path().join("run_1");
+        std::fs::create_dir_all(&run).unwrap();
+        std::fs::write(run.join(FILE_PACKET_MD), "p".repeat(40)).unwrap();
+        std::fs::write(run.join(FILE_LEDGER_EVENTS_JSONL), "l".repeat(40)).unwrap();
+        std::fs::write(run.join(FILE_COVERAGE_MANIFEST_JSON), "{}").unwrap();
+
+        let manifest = write_bundle_manifest_with_options(
+            &run,
+            &RunId("run_1".into()),
+            &BundleProfile::Internal,
+            &BundleManifestOptions {
+                max_part_bytes: Some(50),
+                ..BundleManifestOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(manifest.parts.len(), 2);
+
+        let paths = write_archive_parts(&run, &manifest, BundleFormat::Zip).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.path().join("run_1.part1of2.zip"),
+                dir.path().join("run_1.part2of2.zip"),
+            ]
+        );
+        for path in &paths {
+            let report = verify_bundle(path, None).unwrap();
+            assert!(report.passed(), "{path:?}: {:?}", report.problems);
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! Integrity checks for emitted bundles.
+//!
+//! Re-computes SHA-256 checksums against `bundle.manifest.json` and checks the
+//! bundled file set against the manifest's profile scope, and checks the
+//! manifest signature when the bundle is signed. Works on run directories,
+//! zip archives, and tar.gz archives.
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use shiplog::schema::bundle::{BundleDelta, BundleManifest, BundleProfile, BundleProvenance};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use super::layout::{
+    DIR_PROFILES, FILE_BUNDLE_DELTA_JSON, FILE_BUNDLE_MANIFEST_JSON, FILE_BUNDLE_SIGNATURE_JSON,
+    FILE_PACKET_MD, FILE_REDACTION_ALIASES_JSON,
+};
+use super::sign::{SignatureCheck, verify_manifest_signature};
+use super::{ALWAYS_EXCLUDED, is_scoped_include};
+
+/// Outcome of [`verify_bundle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleVerifyReport {
+    /// Profile recorded in the manifest.
+    pub profile: BundleProfile,
+    /// Provenance recorded in the manifest, if any.
+    pub provenance: Option<BundleProvenance>,
+    /// Number of manifest entries whose checksum and size matched.
+    pub verified_files: usize,
+    /// Manifest signature check; `None` when the bundle is unsigned.
+    pub signature: Option<SignatureCheck>,
+    /// Human-readable problems; empty means the bundle passed.
+    pub problems: Vec<String>,
+    /// Bundled file contents keyed by bundle-relative path.
+    pub files: BTreeMap<String, Vec<u8>>,
+}
+
+impl BundleVerifyReport {
+    /// Whether every check passed.
+    pub fn passed(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Whether the manifest carries a valid signature by `public_key`
+    /// (hex-encoded). Any key can sign a bundle, so recipients compare
+    /// against the key they were given out of band.
+    pub fn signed_by(&self, public_key: &str) -> bool {
+        self.signature.as_ref().is_some_and(|check| {
+            check.valid && check.public_key.eq_ignore_ascii_case(public_key.trim())
+        })
+    }
+}
+
+/// Verify a run directory, `.zip`, or `.tar.gz` bundle.
+///
+/// `manifest` overrides where `bundle.manifest.json` is read from. Without it
+/// the manifest is taken from inside the bundle, falling back to the run
+/// directory next to an archive (`out/run_1.zip` -> `out/run_1/`), because
+/// unsigned full archives do not carry their own manifest. Delta archives and
+/// parts of a split bundle are checked only for the files they are meant to
+/// carry.
+///
+/// When `bundle.manifest.sig.json` accompanies the manifest, the signature is
+/// checked against the manifest's exact bytes and an invalid one is a problem.
+/// Use [`BundleVerifyReport::signed_by`] to require a particular signer.
+///
+/// Archives are checked strictly: unlisted files, files outside the profile
+/// scope, and the redaction alias map are all problems. A run directory also
+/// holds artifacts the profile intentionally leaves out, so only the files the
+/// manifest lists are checked there.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::verify_bundle;
+/// use std::path::Path;
+///
+/// let report = verify_bundle(Path::new("./out/run_123.manager.zip"), None)?;
+/// for problem in &report.problems {
+///     eprintln!("{problem}");
+/// }
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn verify_bundle(path: &Path, manifest: Option<&Path>) -> Result<BundleVerifyReport> {
+    let is_dir = path.is_dir();
+    let mut files = read_bundle_files(path)?;
+    let embedded = files.remove(FILE_BUNDLE_MANIFEST_JSON);
+    let embedded_signature = files.remove(FILE_BUNDLE_SIGNATURE_JSON);
+    let (manifest_bytes, signature) = match (manifest, embedded) {
+        (Some(manifest_path), _) => read_manifest_from_disk(manifest_path)?,
+        (None, Some(bytes)) => (bytes, embedded_signature),
+        (None, None) => read_manifest_from_disk(&bundle_source_dir(path)?)
+            .with_context(|| format!("find bundle manifest for {path:?}"))?,
+    };
+    let manifest: BundleManifest = serde_json::from_slice(&manifest_bytes)
+        .with_context(|| format!("parse {FILE_BUNDLE_MANIFEST_JSON} for {path:?}"))?;
+    let signature = signature.map(|bytes| verify_manifest_signature(&manifest_bytes, &bytes));
+    // Delta archives only carry the files that changed since their base, and
+    // split archives only the files of their own part.
+    let shipped: Option<BTreeSet<String>> = match files.remove(FILE_BUNDLE_DELTA_JSON) {
+        Some(bytes) => {
+            let delta: BundleDelta = serde_json::from_slice(&bytes)
+                .with_context(|| format!("parse {FILE_BUNDLE_DELTA_JSON} in {path:?}"))?;
+            Some(
+                delta
+                    .added
+                    .into_iter()
+                    .chain(delta.changed)
+                    .map(|file| file.path)
+                    .collect(),
+            )
+        }
+        None if !is_dir && !manifest.parts.is_empty() => {
+            let part = manifest
+                .parts
+                .iter()
+                .find(|part| part.files.iter().any(|rel| files.contains_key(rel)))
+                .with_context(|| format!("{path:?} matches no part in the bundle manifest"))?;
+            Some(part.files.iter().cloned().collect())
+        }
+        None => None,
+    };
+    if is_dir {
+        files.retain(|rel, _| manifest.files.iter().any(|entry| &entry.path == rel));
+    }
+    let mut report = check_files(&manifest, files, shipped.as_ref());
+    if signature.as_ref().is_some_and(|check| !check.valid) {
+        report.problems.push(format!(
+            "{FILE_BUNDLE_SIGNATURE_JSON} does not match the manifest"
+        ));
+    }
+    report.signature = signature;
+    Ok(report)
+}
+
+/// Raw bytes of a `bundle.manifest.json` file, or the one inside a run
+/// directory, plus the signature stored next to it, if any.
+fn read_manifest_from_disk(path: &Path) -> Result<(Vec<u8>, Option<Vec<u8>>)> {
+    let manifest_path = if path.is_dir() {
+        path.join(FILE_BUNDLE_MANIFEST_JSON)
+    } else {
+        path.to_path_buf()
+    };
+    let bytes = std::fs::read(&manifest_path)
+        .with_context(|| format!("read bundle manifest {manifest_path:?}"))?;
+    let signature_path = manifest_path.with_file_name(FILE_BUNDLE_SIGNATURE_JSON);
+    let signature = if signature_path.is_file() {
+        Some(std::fs::read(&signature_path).with_context(|| format!("read {signature_path:?}"))?)
+    } else {
+        None
+    };
+    Ok((bytes, signature))
+}
+
+fn check_files(
+    manifest: &BundleManifest,
+    files: BTreeMap<String, Vec<u8>>,
+    shipped: Option<&BTreeSet<String>>,
+) -> BundleVerifyReport {
+    let mut problems = Vec::new();
+    let mut verified_files = 0usize;
+
+    for entry in &manifest.files {
+        if shipped.is_some_and(|shipped| !shipped.contains(&entry.path)) {
+            continue;
+        }
+        let Some(bytes) = files.get(&entry.path) else {
+            problems.push(format!("missing file listed in manifest: {}", entry.path));
+            continue;
+        };
+        let sha256 = hex::encode(Sha256::digest(bytes));
+        if sha256 != entry.sha256 {
+            problems.push(format!("checksum mismatch: {}", entry.path));
+        } else if bytes.len() as u64 != entry.bytes {
+            problems.push(format!("size mismatch: {}", entry.path));
+        } else {
+            verified_files += 1;
+        }
+    }
+
+    for path in files.keys() {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        if name == FILE_REDACTION_ALIASES_JSON {
+            problems.push(format!("bundle contains redaction alias map: {path}"));
+        } else if !manifest.files.iter().any(|entry| &entry.path == path) {
+            problems.push(format!("file not listed in manifest: {path}"));
+        }
+        if !ALWAYS_EXCLUDED.contains(&name)
+            && !is_scoped_include(path, &manifest.profile, manifest.scope.as_ref())
+        {
+            problems.push(format!(
+                "file outside {} profile scope: {path}",
+                manifest.profile
+            ));
+        }
+    }
+
+    if let Some(packet) = expected_profile_packet(&manifest.profile)
+        && is_scoped_include(&packet, &manifest.profile, manifest.scope.as_ref())
+        && !manifest.files.iter().any(|entry| entry.path == packet)
+    {
+        problems.push(format!(
+            "{} bundle is missing its profile packet {packet}",
+            manifest.profile
+        ));
+    }
+
+    BundleVerifyReport {
+        profile: manifest.profile.clone(),
+        provenance: manifest.provenance.clone(),
+        verified_files,
+        signature: None,
+        problems,
+        files,
+    }
+}
+
+fn expected_profile_packet(profile: &BundleProfile) -> Option<String> {
+    match profile {
+        BundleProfile::Internal => None,
+        BundleProfile::Manager | BundleProfile::Public | BundleProfile::Custom(_) => Some(format!(
+            "{DIR_PROFILES}/{}/{FILE_PACKET_MD}",
+            profile.as_str()
+        )),
+    }
+}
+
+/// Run directory a bundle was built from.
+///
+/// A directory is its own source; archives map to the sibling run directory
+/// (`out/run_1.public.zip` or `out/run_1.delta.tar.gz` -> `out/run_1`).
+pub fn bundle_source_dir(path: &Path) -> Result<PathBuf> {
+    if path.is_dir() {
+        return Ok(path.to_path_buf());
+    }
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("bundle path has no file name: {path:?}"))?;
+    let stem = name.split('.').next().unwrap_or(name);
+    Ok(path.with_file_name(stem))
+}
+
+fn read_bundle_files(path: &Path) -> Result<BTreeMap<String, Vec<u8>>> {
+    if path.is_dir() {
+        return read_dir_files(path);
+    }
+    let name = path.to_string_lossy();
+    if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        read_tar_gz_files(path)
+    } else {
+        read_zip_files(path)
+    }
+}
+
+fn read_dir_files(root: &Path) -> Result<BTreeMap<String, Vec<u8>>> {
+    let mut files = BTreeMap::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        for entry in std::fs::read_dir(&dir).with_context(|| format!("read directory {dir:?}"))? {
+            let path = entry
+                .with_context(|| format!("read entry in {dir:?}"))?
+                .path();
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            let rel = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let bytes = std::fs::read(&path).with_context(|| format!("read {path:?}"))?;
+            files.insert(rel, bytes);
+        }
+    }
+    Ok(files)
+}
+
+fn read_zip_files(path: &Path) -> Result<BTreeMap<String, Vec<u8>>> {
+    let file = std::fs::File::open(path).with_context(|| format!("open zip {path:?}"))?;
+    let mut archive = zip::ZipArchive::new(file).with_context(|| format!("read zip {path:?}"))?;
+    let mut files = BTreeMap::new();
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .with_context(|| format!("read zip entry {index} in {path:?}"))?;
+        if entry.is_dir() {
+            continue;
+        }
+        let mut bytes = Vec::new();
+        entry
+            .read_to_end(&mut bytes)
+            .with_context(|| format!("read zip entry {}", entry.name()))?;
+        files.insert(entry.name().to_string(), bytes);
+    }
+    Ok(files)
+}
+
+fn read_tar_gz_files(path: &Path) -> Result<BTreeMap<String, Vec<u8>>> {
+    let file = std::fs::File::open(path).with_context(|| format!("open tarball {path:?}"))?;
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+    let mut files = BTreeMap::new();
+    for entry in archive
+        .entries()
+        .with_context(|| format!("read tarball {path:?}"))?
+    {
+        let mut entry = entry.with_context(|| format!("read tarball entry in {path:?}"))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry
+            .path()
+            .context("read tarball entry path")?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let mut bytes = Vec::new();
+        entry
+            .read_to_end(&mut bytes)
+            .with_context(|| format!("read tarball entry {name}"))?;
+        files.insert(name, bytes);
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bundle::{
+        BundleFormat, BundleSigner, FILE_COVERAGE_MANIFEST_JSON, PROFILE_MANAGER,
+        write_bundle_manifest, write_delta_archive, write_zip, zip_entries,
+    };
+    use shiplog::ids::RunId;
+
+    fn make_run(root: &Path) -> PathBuf {
+        let run = root.join("run_1");
+        let manager = run.join(DIR_PROFILES).join(PROFILE_MANAGER);
+        std::fs::create_dir_all(&manager).unwrap();
+        std::fs::write(run.join(FILE_PACKET_MD), "# Internal").unwrap();
+        std::fs::write(run.join(FILE_COVERAGE_MANIFEST_JSON), "{}").unwrap();
+        std::fs::write(run.join(FILE_REDACTION_ALIASES_JSON), "{}").unwrap();
+        std::fs::write(manager.join(FILE_PACKET_MD), "# Manager").unwrap();
+        run
+    }
+
+    #[test]
+    fn zip_bundle_verifies_against_sibling_run_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let run = make_run(dir.path());
+        write_bundle_manifest(&run, &RunId("run_1".into()), &BundleProfile::Manager).unwrap();
+        let zip = dir.path().join("run_1.manager.zip");
+        write_zip(&run, &zip, &BundleProfile::Manager).unwrap();
+
+        let report = verify_bundle(&zip, None).unwrap();
+        assert!(report.passed(), "{:?}", report.problems);
+        assert_eq!(report.profile, BundleProfile::Manager);
+        assert_eq!(report.verified_files, 2);
+
+        let report = verify_bundle(&run, None).unwrap();
+        assert!(report.passed(), "{:?}", report.problems);
+    }
+
+    #[test]
+    fn tampered_and_out_of_scope_files_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let run = make_run(dir.path());
+        write_bundle_manifest(&run, &RunId("run_1".into()), &BundleProfile::Manager).unwrap();
+        let zip = dir.path().join("run_1.manager.zip");
+        zip_entries(
+            std::fs::File::create(&zip).unwrap(),
+            vec![
+                (FILE_COVERAGE_MANIFEST_JSON.into(), b"{}".to_vec()),
+                (FILE_PACKET_MD.into(), b"# Internal".to_vec()),
+                (FILE_REDACTION_ALIASES_JSON.into(), b"{}".to_vec()),
+                (
+                    format!("{DIR_PROFILES}/{PROFILE_MANAGER}/{FILE_PACKET_MD}"),
+                    b"# Edited".to_vec(),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let report = verify_bundle(&zip, Some(&run.join(FILE_BUNDLE_MANIFEST_JSON))).unwrap();
+        assert!(!report.passed());
+        let problems = report.problems.join("\n");
+        assert!(problems.contains("checksum mismatch: profiles/manager/packet.md"));
+        assert!(problems.contains("file not listed in manifest: packet.md"));
+        assert!(problems.contains("file outside manager profile scope: packet.md"));
+        assert!(problems.contains("redaction alias map"));
+    }
+
+    #[test]
+    fn signed_zip_verifies_on_its_own_and_detects_a_re_signed_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let run = make_run(dir.path());
+        write_bundle_manifest(&run, &RunId("run_1".into()), &BundleProfile::Manager).unwrap();
+        let signer = BundleSigner::from_hex(&"2a".repeat(32)).unwrap();
+        signer.sign_run(&run).unwrap();
+        let zip = dir.path().join("shared.zip");
+        write_zip(&run, &zip, &BundleProfile::Manager).unwrap();
+
+        let report = verify_bundle(&zip, None).unwrap();
+        assert!(report.passed(), "{:?}", report.problems);
+        assert!(report.signed_by(&signer.public_key_hex()));
+        assert!(!report.files.contains_key(FILE_BUNDLE_SIGNATURE_JSON));
+
+        let mut files = read_bundle_files(&zip).unwrap();
+        let manifest = files.get_mut(FILE_BUNDLE_MANIFEST_JSON).unwrap();
+        *manifest = String::from_utf8(manifest.clone())
+            .unwrap()
+            .replace("run_1", "run_9")
+            .into_bytes();
+        let tampered = dir.path().join("tampered.zip");
+        zip_entries(
+            std::fs::File::create(&tampered).unwrap(),
+            files.into_iter().collect(),
+        )
+        .unwrap();
+        let report = verify_bundle(&tampered, None).unwrap();
+        assert!(!report.passed());
+        assert!(!report.signed_by(&signer.public_key_hex()));
+        assert!(report.problems[0].contains("does not match the manifest"));
+    }
+
+    #[test]
+    fn delta_archive_only_expects_shipped_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let run = make_run(dir.path());
+        let base =
+            write_bundle_manifest(&run, &RunId("run_1".into()), &BundleProfile::Internal).unwrap();
+        std::fs::write(run.join(FILE_PACKET_MD), "# Internal v2").unwrap();
+        write_bundle_manifest(&run, &RunId("run_2".into()), &BundleProfile::Internal).unwrap();
+        let archive = dir.path().join("run_1.delta.zip");
+        write_delta_archive(&run, &archive, &base, BundleFormat::Zip).unwrap();
+
+        let report = verify_bundle(&archive, None).unwrap();
+        assert!(report.passed(), "{:?}", report.problems);
+        assert_eq!(report.verified_files, 1);
+    }
+}
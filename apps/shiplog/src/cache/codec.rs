@@ -0,0 +1,109 @@
+//! On-disk encoding of cached response bodies.
+//!
+//! This module has one responsibility:
+//! turning serialized JSON into the bytes stored in the `data` column and back.
+//! Large bodies are zstd-compressed; small ones stay plain because the zstd
+//! frame overhead would make them bigger. Rows written before compression
+//! existed carry no encoding marker and are read as plain JSON text.
+
+use anyhow::{Context, Result, bail};
+use rusqlite::types::Value;
+
+/// Bodies shorter than this many bytes are stored uncompressed.
+pub(crate) const COMPRESSION_MIN_BYTES: usize = 256;
+
+/// zstd level used for cache bodies (fast, still good on repetitive JSON).
+const ZSTD_LEVEL: i32 = 3;
+
+/// Encoding marker stored in the `encoding` column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CacheEncoding {
+    /// Plain UTF-8 JSON text.
+    Identity,
+    /// zstd-compressed UTF-8 JSON, stored as a BLOB.
+    Zstd,
+}
+
+impl CacheEncoding {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Identity => "identity",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    /// Parse the column value; `NULL` marks a legacy (pre-compression) row.
+    pub(crate) fn from_column(value: Option<&str>) -> Result<Self> {
+        match value {
+            None | Some("identity") => Ok(Self::Identity),
+            Some("zstd") => Ok(Self::Zstd),
+            Some(other) => bail!("unknown cache entry encoding: {other}"),
+        }
+    }
+}
+
+/// Encode a serialized JSON body for storage.
+pub(crate) fn encode(json: String) -> Result<(CacheEncoding, Value)> {
+    if json.len() < COMPRESSION_MIN_BYTES {
+        return Ok((CacheEncoding::Identity, Value::Text(json)));
+    }
+    let compressed =
+        zstd::encode_all(json.as_bytes(), ZSTD_LEVEL).context("compress cache entry")?;
+    Ok((CacheEncoding::Zstd, Value::Blob(compressed)))
+}
+
+/// Decode a stored `data` column back into JSON text.
+pub(crate) fn decode(encoding: CacheEncoding, data: Value) -> Result<String> {
+    match (encoding, data) {
+        (CacheEncoding::Identity, Value::Text(text)) => Ok(text),
+        (CacheEncoding::Identity, Value::Blob(bytes)) => {
+            String::from_utf8(bytes).context("cache entry is not valid UTF-8")
+        }
+        (CacheEncoding::Zstd, Value::Blob(bytes)) => {
+            let raw = zstd::decode_all(bytes.as_slice()).context("decompress cache entry")?;
+            String::from_utf8(raw).context("decompressed cache entry is not valid UTF-8")
+        }
+        (encoding, other) => bail!(
+            "cache entry with {} encoding has unexpected {:?} storage",
+            encoding.as_str(),
+            other.data_type()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_bodies_stay_plain_text() -> Result<()> {
+        let (encoding, data) = encode("\"short\"".to_string())?;
+        assert_eq!(encoding, CacheEncoding::Identity);
+        assert_eq!(data, Value::Text("\"short\"".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn large_bodies_round_trip_through_zstd() -> Result<()> {
+        let json = serde_json::to_string(&vec!["repeated pull request title"; 64])?;
+        let (encoding, data) = encode(json.clone())?;
+        assert_eq!(encoding, CacheEncoding::Zstd);
+        let Value::Blob(bytes) = &data else {
+            panic!("compressed body should be stored as a blob");
+        };
+        assert!(bytes.len() < json.len());
+        assert_eq!(decode(encoding, data)?, json);
+        Ok(())
+    }
+
+    #[test]
+    fn legacy_rows_without_marker_decode_as_text() -> Result<()> {
+        let encoding = CacheEncoding::from_column(None)?;
+        assert_eq!(
+            decode(encoding, Value::Text("{\"a\":1}".to_string()))?,
+            "{\"a\":1}"
+        );
+        assert!(CacheEncoding::from_column(Some("brotli")).is_err());
+        Ok(())
+    }
+}
@@ -36,6 +36,34 @@ impl CacheKey {
         format!("gitlab:mr:notes:project{project_id}:mr{mr_iid}:page{page}")
     }
 
+    /// Create a key for a Jira JQL search page.
+    #[must_use]
+    pub fn jira_search(jql: &str, start_at: u64) -> String {
+        format!("jira:search:{}:start{}", Self::hash_query(jql), start_at)
+    }
+
+    /// Create a key for a page of Bitbucket pull requests.
+    #[must_use]
+    pub fn bitbucket_pullrequests(query: &str, page: u32) -> String {
+        format!(
+            "bitbucket:pullrequests:{}:page{}",
+            Self::hash_query(query),
+            page
+        )
+    }
+
+    /// Create a key for a page of a Gerrit change query.
+    #[must_use]
+    pub fn gerrit_changes(query: &str, start: u64) -> String {
+        format!("gerrit:changes:{}:start{}", Self::hash_query(query), start)
+    }
+
+    /// Create a key for a page of Slack channel history.
+    #[must_use]
+    pub fn slack_history(channel: &str, oldest: &str, latest: &str, page: u32) -> String {
+        format!("slack:history:{channel}:{oldest}-{latest}:page{page}")
+    }
+
     fn hash_query(query: &str) -> String {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
@@ -79,6 +107,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn jira_search_key_has_expected_shape() {
+        let key = CacheKey::jira_search("assignee = 'alice'", 100);
+        assert!(key.starts_with("jira:search:"));
+        assert!(key.ends_with(":start100"));
+    }
+
+    #[test]
+    fn bitbucket_pullrequests_key_has_expected_shape() {
+        let key = CacheKey::bitbucket_pullrequests("created_on >= 2025-01-01", 3);
+        assert!(key.starts_with("bitbucket:pullrequests:"));
+        assert!(key.ends_with(":page3"));
+    }
+
+    #[test]
+    fn gerrit_changes_key_has_expected_shape() {
+        let key = CacheKey::gerrit_changes("owner:alice status:merged", 200);
+        assert!(key.starts_with("gerrit:changes:"));
+        assert!(key.ends_with(":start200"));
+    }
+
+    #[test]
+    fn slack_history_key_has_expected_segments() {
+        let key = CacheKey::slack_history("C0SHIPPED1", "1735689600", "1738368000", 2);
+        assert_eq!(key, "slack:history:C0SHIPPED1:1735689600-1738368000:page2");
+    }
+
     #[test]
     fn mr_notes_key_has_expected_segments() {
         let key = CacheKey::mr_notes(42, 7, 2);
@@ -1,14 +1,18 @@
 //! Public facade for shiplog cache APIs.
 //!
-//! Cache key construction, expiry semantics, statistics normalization, and the
-//! SQLite-backed API cache live here as module-level implementation seams.
+//! Cache key construction, expiry semantics, statistics normalization, body
+//! compression, and the SQLite-backed API cache live here as module-level
+//! implementation seams.
 
 pub mod expiry;
 
+mod codec;
 mod key;
 mod sqlite;
 mod stats;
+mod validators;
 
 pub use key::CacheKey;
 pub use sqlite::{ApiCache, CacheInspection, CacheLookup};
 pub use stats::{BYTES_PER_MEGABYTE, CacheStats};
+pub use validators::CacheValidators;
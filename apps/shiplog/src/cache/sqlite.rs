@@ -2,13 +2,16 @@
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Duration, Utc};
+use rusqlite::types::Value;
 use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::path::Path;
 
+use super::codec::{self, COMPRESSION_MIN_BYTES, CacheEncoding};
 use super::expiry::{CacheExpiryWindow, is_valid, now_rfc3339, parse_rfc3339_utc};
 use super::stats::CacheStats;
+use super::validators::CacheValidators;
 
 /// Cache for API responses backed by a local SQLite database.
 ///
@@ -77,23 +80,31 @@ impl ApiCache {
                 key TEXT PRIMARY KEY,
                 data TEXT NOT NULL,
                 cached_at TEXT NOT NULL,
-                expires_at TEXT NOT NULL
+                expires_at TEXT NOT NULL,
+                etag TEXT,
+                last_modified TEXT,
+                encoding TEXT
             )",
             [],
         )?;
+        migrate_optional_columns(&conn)?;
 
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_expires ON cache_entries(expires_at)",
             [],
         )?;
 
-        Ok(Self {
+        let cache = Self {
             inner: ApiCacheInner {
                 conn,
                 default_ttl: Duration::hours(24),
                 max_size_bytes: None,
             },
-        })
+        };
+        cache
+            .compress_legacy_entries()
+            .context("compress legacy cache entries")?;
+        Ok(cache)
     }
 
     /// Open an existing cache in read-only mode without initializing schema.
@@ -119,7 +130,10 @@ impl ApiCache {
                 key TEXT PRIMARY KEY,
                 data TEXT NOT NULL,
                 cached_at TEXT NOT NULL,
-                expires_at TEXT NOT NULL
+                expires_at TEXT NOT NULL,
+                etag TEXT,
+                last_modified TEXT,
+                encoding TEXT
             )",
             [],
         )?;
@@ -149,18 +163,19 @@ impl ApiCache {
     pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
         let now = now_rfc3339();
 
-        let row: Option<String> = self
+        let row: Option<(Value, Option<String>)> = self
             .inner
             .conn
             .query_row(
-                "SELECT data FROM cache_entries WHERE key = ?1 AND expires_at > ?2",
+                "SELECT data, encoding FROM cache_entries WHERE key = ?1 AND expires_at > ?2",
                 params![key, now],
-                |row| row.get(0),
+                |row| Ok((row.get(0)?, row.get(1)?)),
             )
             .optional()?;
 
         match row {
-            Some(data) => {
+            Some((data, encoding)) => {
+                let data = decode_body(key, data, encoding.as_deref())?;
                 let value: T = serde_json::from_str(&data)
                     .with_context(|| format!("deserialize cached value for key: {key}"))?;
                 Ok(Some(value))
@@ -173,20 +188,21 @@ impl ApiCache {
     pub fn lookup<T: DeserializeOwned>(&self, key: &str) -> Result<CacheLookup<T>> {
         let now = Utc::now();
 
-        let row: Option<(String, String)> = self
+        let row: Option<(Value, Option<String>, String)> = self
             .inner
             .conn
             .query_row(
-                "SELECT data, expires_at FROM cache_entries WHERE key = ?1",
+                "SELECT data, encoding, expires_at FROM cache_entries WHERE key = ?1",
                 params![key],
-                |row| Ok((row.get(0)?, row.get(1)?)),
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
             )
             .optional()?;
 
-        let Some((data, expires_at)) = row else {
+        let Some((data, encoding, expires_at)) = row else {
             return Ok(CacheLookup::Miss);
         };
 
+        let data = decode_body(key, data, encoding.as_deref())?;
         let value: T = serde_json::from_str(&data)
             .with_context(|| format!("deserialize cached value for key: {key}"))?;
         let expires_at = parse_rfc3339_utc(&expires_at)
@@ -206,23 +222,125 @@ impl ApiCache {
 
     /// Store a value with a custom TTL.
     pub fn set_with_ttl<T: Serialize>(&self, key: &str, value: &T, ttl: Duration) -> Result<()> {
+        self.store(key, value, ttl, &CacheValidators::default())
+    }
+
+    /// Store a value together with the HTTP validators its response carried.
+    ///
+    /// Validators let a later stale lookup be revalidated with a conditional
+    /// request instead of re-downloading the body.
+    pub fn set_with_validators<T: Serialize>(
+        &self,
+        key: &str,
+        value: &T,
+        validators: &CacheValidators,
+    ) -> Result<()> {
+        self.store(key, value, self.inner.default_ttl, validators)
+    }
+
+    /// Read the HTTP validators stored for a key, regardless of expiry.
+    ///
+    /// Returns `None` when the key is absent. Entries written without
+    /// validators return an empty [`CacheValidators`].
+    pub fn validators(&self, key: &str) -> Result<Option<CacheValidators>> {
+        let row: Option<(Option<String>, Option<String>)> = self
+            .inner
+            .conn
+            .query_row(
+                "SELECT etag, last_modified FROM cache_entries WHERE key = ?1",
+                params![key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .with_context(|| format!("read cache validators for key: {key}"))?;
+
+        Ok(row.map(|(etag, last_modified)| CacheValidators {
+            etag,
+            last_modified,
+        }))
+    }
+
+    /// Restart the TTL of an existing entry without touching its body.
+    ///
+    /// Used after a `304 Not Modified` response confirmed the cached body is
+    /// still current. Returns `false` when the key is absent.
+    pub fn refresh_expiry(&self, key: &str) -> Result<bool> {
+        let window = CacheExpiryWindow::from_now(self.inner.default_ttl);
+        let updated = self.inner.conn.execute(
+            "UPDATE cache_entries SET cached_at = ?2, expires_at = ?3 WHERE key = ?1",
+            params![key, window.cached_at_rfc3339(), window.expires_at_rfc3339()],
+        )?;
+        Ok(updated > 0)
+    }
+
+    fn store<T: Serialize>(
+        &self,
+        key: &str,
+        value: &T,
+        ttl: Duration,
+        validators: &CacheValidators,
+    ) -> Result<()> {
         let window = CacheExpiryWindow::from_now(ttl);
-        let data = serde_json::to_string(value)
+        let json = serde_json::to_string(value)
             .with_context(|| format!("serialize value for key: {key}"))?;
+        let (encoding, data) =
+            codec::encode(json).with_context(|| format!("encode value for key: {key}"))?;
 
         self.inner.conn.execute(
-            "INSERT OR REPLACE INTO cache_entries (key, data, cached_at, expires_at) VALUES (?1, ?2, ?3, ?4)",
+            "INSERT OR REPLACE INTO cache_entries (key, data, cached_at, expires_at, etag, last_modified, encoding) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             params![
                 key,
                 data,
                 window.cached_at_rfc3339(),
                 window.expires_at_rfc3339(),
+                validators.etag,
+                validators.last_modified,
+                encoding.as_str(),
             ],
         )?;
 
         Ok(())
     }
 
+    /// Rewrite entries stored before compression existed.
+    ///
+    /// Legacy rows (no `encoding` marker) large enough to benefit are
+    /// recompressed; the rest are tagged as plain so they are not revisited.
+    /// Expiry and validators are left untouched. Returns how many rows were
+    /// compressed. [`ApiCache::open`] runs this automatically.
+    pub fn compress_legacy_entries(&self) -> Result<usize> {
+        let tx = self.inner.conn.unchecked_transaction()?;
+        let legacy = {
+            let mut stmt = tx.prepare(
+                "SELECT key, data FROM cache_entries WHERE encoding IS NULL AND LENGTH(CAST(data AS BLOB)) >= ?1",
+            )?;
+            stmt.query_map(params![COMPRESSION_MIN_BYTES as i64], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Value>(1)?))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?
+        };
+
+        let mut compressed = 0usize;
+        for (key, data) in legacy {
+            let json = decode_body(&key, data, None)?;
+            let (encoding, data) =
+                codec::encode(json).with_context(|| format!("encode value for key: {key}"))?;
+            tx.execute(
+                "UPDATE cache_entries SET data = ?2, encoding = ?3 WHERE key = ?1",
+                params![key, data, encoding.as_str()],
+            )?;
+            if encoding == CacheEncoding::Zstd {
+                compressed += 1;
+            }
+        }
+        tx.execute(
+            "UPDATE cache_entries SET encoding = ?1 WHERE encoding IS NULL",
+            params![CacheEncoding::Identity.as_str()],
+        )?;
+        tx.commit()?;
+        Ok(compressed)
+    }
+
     /// Check if a key exists and hasn't expired.
     pub fn contains(&self, key: &str) -> Result<bool> {
         let now = now_rfc3339();
@@ -291,7 +409,7 @@ impl ApiCache {
         )?;
 
         let size_bytes: i64 = self.inner.conn.query_row(
-            "SELECT SUM(LENGTH(data)) FROM cache_entries",
+            "SELECT SUM(LENGTH(CAST(data AS BLOB))) FROM cache_entries",
             [],
             |row| Ok(row.get::<_, Option<i64>>(0).unwrap_or(Some(0)).unwrap_or(0)),
         )?;
@@ -322,6 +440,35 @@ impl ApiCache {
     }
 }
 
+fn decode_body(key: &str, data: Value, encoding: Option<&str>) -> Result<String> {
+    CacheEncoding::from_column(encoding)
+        .and_then(|encoding| codec::decode(encoding, data))
+        .with_context(|| format!("decode cached value for key: {key}"))
+}
+
+/// Add columns introduced after the original schema: the validator pair
+/// (HTTP revalidation) and the body encoding marker (compression).
+fn migrate_optional_columns(conn: &Connection) -> Result<()> {
+    let mut stmt = conn
+        .prepare("PRAGMA table_info(cache_entries)")
+        .context("inspect cache schema")?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("read cache schema columns")?;
+
+    for column in ["etag", "last_modified", "encoding"] {
+        if !columns.iter().any(|existing| existing == column) {
+            conn.execute(
+                &format!("ALTER TABLE cache_entries ADD COLUMN {column} TEXT"),
+                [],
+            )
+            .with_context(|| format!("add {column} column to cache schema"))?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -407,6 +554,136 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn validators_round_trip_and_survive_expiry_refresh() -> Result<()> {
+        let cache = ApiCache::open_in_memory()?;
+        let validators = CacheValidators {
+            etag: Some("\"v1\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+        };
+
+        assert_eq!(cache.validators("key1")?, None);
+        cache.set_with_validators("key1", &"body", &validators)?;
+        assert_eq!(cache.validators("key1")?, Some(validators.clone()));
+
+        cache.set("plain", &"body")?;
+        assert_eq!(cache.validators("plain")?, Some(CacheValidators::default()));
+
+        assert!(cache.refresh_expiry("key1")?);
+        assert!(!cache.refresh_expiry("missing")?);
+        assert_eq!(cache.validators("key1")?, Some(validators));
+        Ok(())
+    }
+
+    #[test]
+    fn refresh_expiry_turns_stale_entry_fresh() -> Result<()> {
+        let cache = ApiCache::open_in_memory()?;
+        cache.set_with_ttl("key1", &"body", Duration::seconds(-1))?;
+        assert_eq!(
+            cache.lookup::<String>("key1")?,
+            CacheLookup::Stale("body".to_string())
+        );
+
+        cache.refresh_expiry("key1")?;
+        assert_eq!(
+            cache.lookup::<String>("key1")?,
+            CacheLookup::Fresh("body".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn open_migrates_cache_created_without_validator_columns() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("legacy.db");
+        {
+            let conn = Connection::open(&path)?;
+            conn.execute(
+                "CREATE TABLE cache_entries (
+                    key TEXT PRIMARY KEY,
+                    data TEXT NOT NULL,
+                    cached_at TEXT NOT NULL,
+                    expires_at TEXT NOT NULL
+                )",
+                [],
+            )?;
+            conn.execute(
+                "INSERT INTO cache_entries VALUES ('old', '\"legacy\"', '2020-01-01T00:00:00Z', '2999-01-01T00:00:00Z')",
+                [],
+            )?;
+        }
+
+        let cache = ApiCache::open(&path)?;
+        assert_eq!(cache.get::<String>("old")?, Some("legacy".to_string()));
+        assert_eq!(cache.validators("old")?, Some(CacheValidators::default()));
+        Ok(())
+    }
+
+    #[test]
+    fn large_values_are_stored_compressed() -> Result<()> {
+        let cache = ApiCache::open_in_memory()?;
+        let body = vec!["feat: add cache compression"; 200];
+        let json_len = serde_json::to_string(&body)?.len() as u64;
+
+        cache.set("big", &body)?;
+
+        assert_eq!(cache.get::<Vec<String>>("big")?.map(|v| v.len()), Some(200));
+        let stored: i64 = cache.inner.conn.query_row(
+            "SELECT SUM(LENGTH(CAST(data AS BLOB))) FROM cache_entries",
+            [],
+            |row| row.get(0),
+        )?;
+        assert!((stored as u64) < json_len / 4, "{stored} vs {json_len}");
+        Ok(())
+    }
+
+    #[test]
+    fn open_compresses_legacy_uncompressed_entries() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("plain.db");
+        let body = serde_json::to_string(&vec!["review comment body"; 100])?;
+        {
+            let conn = Connection::open(&path)?;
+            conn.execute(
+                "CREATE TABLE cache_entries (
+                    key TEXT PRIMARY KEY,
+                    data TEXT NOT NULL,
+                    cached_at TEXT NOT NULL,
+                    expires_at TEXT NOT NULL,
+                    etag TEXT,
+                    last_modified TEXT
+                )",
+                [],
+            )?;
+            conn.execute(
+                "INSERT INTO cache_entries VALUES ('big', ?1, '2020-01-01T00:00:00Z', '2999-01-01T00:00:00Z', '\"e1\"', NULL)",
+                params![body],
+            )?;
+            conn.execute(
+                "INSERT INTO cache_entries VALUES ('small', '1', '2020-01-01T00:00:00Z', '2999-01-01T00:00:00Z', NULL, NULL)",
+                [],
+            )?;
+        }
+
+        let cache = ApiCache::open(&path)?;
+        let encodings: Vec<String> = cache
+            .inner
+            .conn
+            .prepare("SELECT encoding FROM cache_entries ORDER BY key")?
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        assert_eq!(encodings, ["zstd", "identity"]);
+
+        assert_eq!(cache.get::<Vec<String>>("big")?.map(|v| v.len()), Some(100));
+        assert_eq!(cache.get::<u32>("small")?, Some(1));
+        assert_eq!(
+            cache.validators("big")?.and_then(|v| v.etag),
+            Some("\"e1\"".to_string())
+        );
+        assert_eq!(cache.compress_legacy_entries()?, 0);
+        Ok(())
+    }
+
     #[test]
     fn cache_stats() {
         let cache = ApiCache::open_in_memory().unwrap();
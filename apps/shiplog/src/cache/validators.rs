@@ -0,0 +1,58 @@
+//! HTTP validator metadata stored alongside cached response bodies.
+//!
+//! This module has one responsibility:
+//! carrying the `ETag` / `Last-Modified` pair a server returned so a later
+//! request can be made conditional (`If-None-Match` / `If-Modified-Since`).
+
+use serde::{Deserialize, Serialize};
+
+/// Validators captured from a successful HTTP response.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::cache::CacheValidators;
+///
+/// let none = CacheValidators::default();
+/// assert!(none.is_empty());
+///
+/// let tagged = CacheValidators {
+///     etag: Some("\"abc\"".into()),
+///     last_modified: None,
+/// };
+/// assert!(!tagged.is_empty());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheValidators {
+    /// Opaque entity tag, sent back as `If-None-Match`.
+    pub etag: Option<String>,
+    /// HTTP-date of the last modification, sent back as `If-Modified-Since`.
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    /// Whether neither validator is present (the entry cannot be revalidated).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CacheValidators;
+
+    #[test]
+    fn empty_when_no_validators_present() {
+        assert!(CacheValidators::default().is_empty());
+    }
+
+    #[test]
+    fn last_modified_alone_is_enough_to_revalidate() {
+        let validators = CacheValidators {
+            etag: None,
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".into()),
+        };
+        assert!(!validators.is_empty());
+    }
+}
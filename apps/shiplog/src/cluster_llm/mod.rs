@@ -1,17 +1,21 @@
-//! LLM-assisted workstream clustering adapters.
+//! LLM-assisted workstream clustering and summary adapters.
 //!
 //! Provides [`LlmClusterer`] plus pluggable backends for OpenAI-compatible
 //! APIs, and [`LlmWithFallback`] for repo-based fallback on LLM failure.
+//! [`LlmSummarizer`] drafts workstream summaries over the same backends, with
+//! [`LlmSummarizerWithFallback`] falling back to the heuristic summary.
 
 mod client;
 mod config;
 pub mod parse;
 pub mod prompt;
+mod summarize;
 
 pub use client::{FailingLlmBackend, LlmBackend, MockLlmBackend, OpenAiCompatibleBackend};
 pub use config::LlmConfig;
 pub use parse::parse_llm_response;
 pub use prompt::{chunk_events, format_event_list, summarize_event, system_prompt};
+pub use summarize::{LlmSummarizer, LlmSummarizerWithFallback, summary_system_prompt};
 
 use crate::workstreams::RepoClusterer;
 use anyhow::Result;
@@ -101,7 +105,7 @@ impl WorkstreamClusterer for LlmWithFallback {
         match self.llm.cluster(events) {
             Ok(ws) => Ok(ws),
             Err(e) => {
-                eprintln!("WARN: LLM clustering failed, falling back to repo-based: {e}");
+                tracing::warn!("LLM clustering failed, falling back to repo-based: {e}");
                 self.fallback.cluster(events)
             }
         }
@@ -113,10 +117,12 @@ mod tests {
     use super::*;
     use chrono::Utc;
     use shiplog::ids::EventId;
+    use shiplog::ports::Summarizer;
     use shiplog::schema::event::*;
 
     fn make_test_event(repo: &str, pr_num: u64, title: &str) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", &pr_num.to_string()]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -148,6 +154,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -262,4 +271,36 @@ mod tests {
         let events = vec![make_test_event("org/repo", 1, "Test")];
         assert!(clusterer.cluster(&events).is_err());
     }
+
+    #[test]
+    fn mock_llm_summary_is_used() {
+        let backend = MockLlmBackend {
+            response: serde_json::json!({"summary": "Moved login to OAuth."}).to_string(),
+        };
+        let summarizer = LlmSummarizer::new(Box::new(backend), LlmConfig::default());
+        let events = vec![make_test_event("org/auth", 1, "Add OAuth")];
+        let ws = RepoClusterer.cluster(&events).unwrap();
+
+        let summary = summarizer
+            .summarize(&ws.workstreams[0], &events.iter().collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(summary, "Moved login to OAuth.");
+    }
+
+    #[test]
+    fn summary_falls_back_to_heuristic_on_llm_failure() {
+        let llm = LlmSummarizer::new(Box::new(FailingLlmBackend), LlmConfig::default());
+        let summarizer = LlmSummarizerWithFallback::new(llm);
+        let events = vec![make_test_event("org/auth", 1, "Add OAuth")];
+        let ws = RepoClusterer.cluster(&events).unwrap();
+
+        let summary = summarizer
+            .summarize(&ws.workstreams[0], &events.iter().collect::<Vec<_>>())
+            .unwrap();
+        assert!(
+            summary.starts_with("1 pull request in org/auth"),
+            "{summary}"
+        );
+        assert!(summary.contains("\"Add OAuth\""), "{summary}");
+    }
 }
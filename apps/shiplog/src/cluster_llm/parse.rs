@@ -61,6 +61,7 @@ mod tests {
 
     fn make_pr_event(num: u64) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", "pr", &num.to_string()]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -92,11 +93,15 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
     fn make_review_event(num: u64) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", "review", &num.to_string()]),
             kind: EventKind::Review,
             occurred_at: Utc::now(),
@@ -115,6 +120,8 @@ mod tests {
                 submitted_at: Utc::now(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             tags: vec![],
             links: vec![],
@@ -123,11 +130,15 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
     fn make_manual_event(num: u64) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", "manual", &num.to_string()]),
             kind: EventKind::Manual,
             occurred_at: Utc::now(),
@@ -147,6 +158,9 @@ mod tests {
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![],
@@ -155,6 +169,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -15,6 +15,7 @@ pub(super) fn summarize_events(events: &[EventEnvelope], indices: &[usize]) -> E
         let event = &events[index];
         event_ids.push(event.id.clone());
         count_event_kind(&mut stats, &event.kind);
+        stats.add_effort(event.payload.effort());
     }
 
     EventSelection { stats, event_ids }
@@ -37,5 +38,11 @@ fn count_event_kind(stats: &mut WorkstreamStats, kind: &EventKind) {
         EventKind::PullRequest => stats.pull_requests += 1,
         EventKind::Review => stats.reviews += 1,
         EventKind::Manual => stats.manual_events += 1,
+        EventKind::Issue => stats.issues += 1,
+        EventKind::Deployment => stats.deployments += 1,
+        EventKind::Release => stats.releases += 1,
+        EventKind::Comment => stats.comments += 1,
+        EventKind::Incident => stats.incidents += 1,
+        EventKind::Document => stats.documents += 1,
     }
 }
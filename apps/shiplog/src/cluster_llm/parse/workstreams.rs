@@ -42,6 +42,8 @@ pub(super) fn build_uncategorized_workstream(
         stats: selection.stats,
         events: selection.event_ids,
         receipts,
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     })
 }
 
@@ -72,6 +74,8 @@ fn build_claimed_workstream(
         stats: selection.stats,
         events: selection.event_ids,
         receipts: receipt_ids,
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     })
 }
 
@@ -25,6 +25,53 @@ pub fn summarize_event(ev: &EventEnvelope) -> String {
             let date = ev.occurred_at.format("%Y-%m-%d");
             format!("{:?}: {} ({})", m.event_type, m.title, date)
         }
+        EventPayload::Issue(issue) => {
+            let date = ev.occurred_at.format("%Y-%m-%d");
+            format!(
+                "Issue#{} in {}: {} [{}] ({})",
+                issue.number, ev.repo.full_name, issue.title, issue.state, date
+            )
+        }
+        EventPayload::Deployment(deployment) => {
+            let date = ev.occurred_at.format("%Y-%m-%d");
+            let version = deployment
+                .version
+                .as_deref()
+                .map(|version| format!(" {version}"))
+                .unwrap_or_default();
+            format!(
+                "Deploy{} to {} in {} [{}] ({})",
+                version, deployment.environment, ev.repo.full_name, deployment.status, date
+            )
+        }
+        EventPayload::Release(release) => {
+            let date = ev.occurred_at.format("%Y-%m-%d");
+            format!(
+                "Release {} in {} [{}] ({})",
+                release.version, ev.repo.full_name, release.status, date
+            )
+        }
+        EventPayload::Comment(comment) => {
+            let date = ev.occurred_at.format("%Y-%m-%d");
+            format!(
+                "{} comment(s) on {} in {}: {} ({})",
+                comment.count, comment.target, ev.repo.full_name, comment.target_title, date
+            )
+        }
+        EventPayload::Incident(incident) => {
+            let date = incident.detected_at.format("%Y-%m-%d");
+            format!(
+                "Incident ({}, {}) in {}: {} ({})",
+                incident.severity, incident.role, ev.repo.full_name, incident.title, date
+            )
+        }
+        EventPayload::Document(document) => {
+            let date = document.last_edited_at.format("%Y-%m-%d");
+            format!(
+                "{} doc in {} ({}): {} ({})",
+                document.doc_type, document.system, ev.repo.full_name, document.title, date
+            )
+        }
     }
 }
 
@@ -112,6 +159,7 @@ mod tests {
 
     fn make_pr_event(num: u64, title: &str) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", "pr", &num.to_string()]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -143,6 +191,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -247,6 +298,7 @@ mod tests {
     #[test]
     fn summarize_event_review() {
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", "review", "1"]),
             kind: EventKind::Review,
             occurred_at: Utc::now(),
@@ -265,6 +317,8 @@ mod tests {
                 submitted_at: Utc::now(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             tags: vec![],
             links: vec![],
@@ -273,6 +327,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
         let summary = summarize_event(&ev);
         assert!(summary.contains("Review on PR#42"));
@@ -283,6 +340,7 @@ mod tests {
     #[test]
     fn summarize_event_manual() {
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", "manual", "1"]),
             kind: EventKind::Manual,
             occurred_at: Utc::now(),
@@ -302,6 +360,9 @@ mod tests {
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![],
@@ -310,6 +371,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
         let summary = summarize_event(&ev);
         assert!(summary.contains("Incident"), "should contain event type");
@@ -319,6 +383,7 @@ mod tests {
     #[test]
     fn summarize_event_pr_without_stats() {
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", "pr", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -350,6 +415,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
         let summary = summarize_event(&ev);
         assert!(summary.contains("PR#99"));
@@ -0,0 +1,88 @@
+use super::client::LlmBackend;
+use super::config::LlmConfig;
+use super::prompt::summarize_event;
+use crate::workstreams::HeuristicSummarizer;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use shiplog::ports::Summarizer;
+use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::workstream::Workstream;
+
+#[derive(Deserialize)]
+struct LlmSummaryResponse {
+    summary: String,
+}
+
+/// System prompt for drafting one workstream summary.
+pub fn summary_system_prompt() -> String {
+    "You draft short summaries of a software engineer's workstreams for a self-review.\n\
+     Given a workstream title and its events, write one or two factual sentences about what \
+     the work was, using only the events given. Do not invent outcomes or numbers.\n\n\
+     Respond with JSON only:\n\
+     {\"summary\": \"...\"}"
+        .to_string()
+}
+
+/// LLM-backed workstream summarizer.
+pub struct LlmSummarizer {
+    backend: Box<dyn LlmBackend>,
+    config: LlmConfig,
+}
+
+impl LlmSummarizer {
+    pub fn new(backend: Box<dyn LlmBackend>, config: LlmConfig) -> Self {
+        Self { backend, config }
+    }
+}
+
+impl Summarizer for LlmSummarizer {
+    fn summarize(&self, workstream: &Workstream, events: &[&EventEnvelope]) -> Result<String> {
+        // Same ~4 chars/token budget the clusterer uses; later events are dropped.
+        let budget = self.config.max_input_tokens.saturating_mul(4);
+        let mut event_list = String::new();
+        for ev in events {
+            let line = summarize_event(ev);
+            if !event_list.is_empty() && event_list.len() + line.len() + 1 > budget {
+                break;
+            }
+            event_list.push_str(&line);
+            event_list.push('\n');
+        }
+
+        let user_msg = format!("Workstream: {}\n\nEvents:\n{event_list}", workstream.title);
+        let response = self.backend.complete(&summary_system_prompt(), &user_msg)?;
+        let parsed: LlmSummaryResponse =
+            serde_json::from_str(&response).context("parse LLM summary response")?;
+        Ok(parsed.summary)
+    }
+}
+
+/// Wrapper that falls back to the heuristic summary on LLM failure.
+pub struct LlmSummarizerWithFallback {
+    llm: LlmSummarizer,
+    fallback: HeuristicSummarizer,
+}
+
+impl LlmSummarizerWithFallback {
+    pub fn new(llm: LlmSummarizer) -> Self {
+        Self {
+            llm,
+            fallback: HeuristicSummarizer,
+        }
+    }
+}
+
+impl Summarizer for LlmSummarizerWithFallback {
+    fn summarize(&self, workstream: &Workstream, events: &[&EventEnvelope]) -> Result<String> {
+        match self.llm.summarize(workstream, events) {
+            Ok(summary) => Ok(summary),
+            Err(e) => {
+                tracing::warn!(
+                    "LLM summary failed for {}, falling back to heuristic: {e}",
+                    workstream.title
+                );
+                self.fallback.summarize(workstream, events)
+            }
+        }
+    }
+}
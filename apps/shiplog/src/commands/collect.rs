@@ -1,11 +1,12 @@
 use anyhow::Context;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::*;
 
 #[expect(clippy::too_many_arguments, reason = "policy:clippy-0001")]
 pub(super) fn handle(
-    source: CollectSource,
+    source: Option<CollectSource>,
+    registered: RegisteredSourceArgs,
     out: Option<PathBuf>,
     zip: bool,
     redact_key: Option<String>,
@@ -15,13 +16,27 @@ pub(super) fn handle(
     llm_api_endpoint: String,
     llm_model: String,
     llm_api_key: Option<String>,
+    summarize: Option<SummarizeMode>,
+    search_index: bool,
+    dry_run: bool,
+    pdf: bool,
 ) -> Result<()> {
-    let source = match source {
-        CollectSource::Multi {
-            config,
-            window,
-            conflict,
-        } => {
+    let source = match (source, registered.name.is_some()) {
+        (Some(_), true) => anyhow::bail!("pass either a source subcommand or --source, not both"),
+        (None, false) => anyhow::bail!(
+            "pass a source subcommand such as `shiplog collect github`, or --source <NAME>"
+        ),
+        (None, true) => None,
+        (Some(CollectSource::Source(source)), false) => Some(source),
+        (
+            Some(CollectSource::Multi {
+                config,
+                window,
+                conflict,
+                incremental,
+            }),
+            false,
+        ) => {
             let config_model = load_shiplog_config(&config)?;
             ensure_supported_config_version(&config_model)?;
             let base_dir = config_base_dir(&config);
@@ -37,15 +52,56 @@ pub(super) fn handle(
                 &config_redaction_key_env(&config_model),
             )?;
             let clusterer = build_clusterer(
+                config_model.workstreams.clusterer,
                 llm_cluster,
+                summarize,
                 &llm_api_endpoint,
                 &llm_model,
                 llm_api_key.clone(),
+                redaction_key.engine_key(),
             );
+            let redactor = cli_redactor(redaction_key.engine_key(), &config_model.redaction)?;
             let (engine, redactor) =
-                create_engine(redaction_key.engine_key(), clusterer, &bundle_profile);
-            let engine = engine.with_profile_rendering(redaction_key.render_profiles());
+                create_engine_with_redactor(redactor, clusterer, &bundle_profile);
+            let engine = engine
+                .with_profile_rendering(redaction_key.render_profiles())
+                .with_pdf_rendering(pdf);
+            let engine = configure_engine_bundle(engine, &config, &config_model)?;
             let window = resolve_multi_window(window, &config_model)?;
+            if dry_run {
+                return print_configured_plan(&engine, &config, &config_model, &window, &out);
+            }
+            if incremental && let Some(run_dir) = incremental_run_dir(&out) {
+                let sources = config_model.sources.enabled_names();
+                let result = collect_incremental_into(
+                    &engine,
+                    redactor,
+                    &run_dir,
+                    &window,
+                    &sources,
+                    zip,
+                    &bundle_profile,
+                    |source, delta| {
+                        let mut model = load_shiplog_config(&config)?;
+                        model.sources.retain_only(source);
+                        let delta = ResolvedWindow {
+                            since: delta.since,
+                            until: delta.until,
+                            label: WindowLabel::Explicit,
+                            period: None,
+                        };
+                        let configured = collect_configured_sources(&config, &model, delta, &out)?;
+                        configured
+                            .successes
+                            .into_iter()
+                            .next()
+                            .map(|(_, ingest)| ingest)
+                            .with_context(|| format!("{source} returned no output"))
+                    },
+                )?;
+                index_collected_run(search_index, &result.outputs)?;
+                return Ok(());
+            }
             let configured =
                 collect_configured_sources(&config, &config_model, window.clone(), &out)?;
             let result = run_configured_multi_pipeline(
@@ -77,18 +133,108 @@ pub(super) fn handle(
             println!("Merged and wrote:");
             println!("- inputs: {}", result.configured.successes.len());
             println!("- conflict: {}", conflict.as_str());
-            print_outputs(&result.outputs, result.ws_source);
+            index_collected_run(search_index, &result.outputs)?;
+            print_outputs(&result.outputs, result.ws_source.clone());
+            notify_configured_run(&config_model, &result);
+            enforce_coverage_requirements(&config_model, &result)?;
             return Ok(());
         }
-
-        CollectSource::Source(source) => source,
     };
-    let out = out.unwrap_or_else(|| PathBuf::from("./out"));
+    let out = out.unwrap_or_else(|| PathBuf::from(DEFAULT_OUT_DIR));
     let bundle_profile = bundle_profile.unwrap_or_default();
     let redaction_key = RedactionKey::resolve(redact_key, &bundle_profile)?;
-    let clusterer = build_clusterer(llm_cluster, &llm_api_endpoint, &llm_model, llm_api_key);
+    let clusterer = build_clusterer(
+        ClusterStrategy::Repo,
+        llm_cluster,
+        summarize,
+        &llm_api_endpoint,
+        &llm_model,
+        llm_api_key,
+        redaction_key.engine_key(),
+    );
     let (engine, redactor) = create_engine(redaction_key.engine_key(), clusterer, &bundle_profile);
-    let engine = engine.with_profile_rendering(redaction_key.render_profiles());
+    let engine = engine
+        .with_profile_rendering(redaction_key.render_profiles())
+        .with_pdf_rendering(pdf);
+
+    let Some(source) = source else {
+        let name = registered.name.unwrap_or_default();
+        let window = resolve_date_window(registered.window)?;
+        let registry = crate::source_registry::builtin_ingestor_registry()?;
+        let mut config = shiplog::registry::SourceConfig::new(&name, window.since, window.until)
+            .with_option_pairs(&registered.options)?;
+        config.user = registered.user.clone();
+        config.cache_dir = planned_cache_dir(
+            resolve_cache_dir(&out, registered.cache_dir, registered.no_cache),
+            dry_run,
+        );
+        if dry_run {
+            let ing = registry.create(&name, &config)?;
+            return print_ingestor_plan(&engine, &name, ing.as_ref());
+        }
+        if registered.incremental
+            && let Some(run_dir) = incremental_run_dir(&out)
+        {
+            let result = collect_incremental_into(
+                &engine,
+                redactor,
+                &run_dir,
+                &window,
+                &[name.as_str()],
+                zip,
+                &bundle_profile,
+                |source, delta| {
+                    let mut config = config.clone();
+                    config.since = delta.since;
+                    config.until = delta.until;
+                    registry.create(source, &config)?.ingest()
+                },
+            )?;
+            index_collected_run(search_index, &result.outputs)?;
+            return Ok(());
+        }
+        let ingest = registry
+            .create(&name, &config)?
+            .ingest()
+            .with_context(|| format!("ingest {name} events"))?;
+        let user = registered
+            .user
+            .unwrap_or_else(|| ingest.coverage.user.clone());
+        let run_id = ingest.coverage.run_id.to_string();
+        let run_dir = out.join(&run_id);
+        let window_label = window.window_label();
+
+        // Check if user has curated workstreams and warn
+        if !regen && shiplog::workstreams::WorkstreamManager::has_curated(&run_dir) {
+            eprintln!("Note: Using existing workstreams.yaml (user-curated).");
+            eprintln!("      Use --regen to regenerate suggestions.");
+        }
+
+        // If --regen, delete existing suggested workstreams so the engine regenerates them
+        if regen {
+            let suggested = shiplog::workstreams::WorkstreamManager::suggested_path(&run_dir);
+            if suggested.exists() {
+                std::fs::remove_file(&suggested)
+                    .with_context(|| format!("remove {:?} for --regen", suggested))?;
+            }
+        }
+
+        let cache_path = DeterministicRedactor::cache_path(&run_dir);
+        let _ = redactor.load_cache(&cache_path);
+
+        let (outputs, ws_source) = engine
+            .run(ingest, &user, &window_label, &run_dir, zip, &bundle_profile)
+            .context("run engine pipeline")?;
+
+        redactor
+            .save_cache(&cache_path)
+            .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
+
+        println!("Collected and wrote:");
+        index_collected_run(search_index, &outputs)?;
+        print_outputs(&outputs, ws_source);
+        return Ok(());
+    };
 
     match source {
         Source::Github {
@@ -109,7 +255,8 @@ pub(super) fn handle(
                 discover_github_user(&api_base, token.as_deref())
             })?;
             let window = resolve_date_window(window)?;
-            let cache_dir = resolve_cache_dir(&out, cache_dir, no_cache);
+            let cache_dir =
+                planned_cache_dir(resolve_cache_dir(&out, cache_dir, no_cache), dry_run);
             let ing = make_github_ingestor(
                 &user,
                 window.since,
@@ -124,6 +271,9 @@ pub(super) fn handle(
                 cache_dir,
             )
             .context("create GitHub ingestor")?;
+            if dry_run {
+                return print_ingestor_plan(&engine, "github", &ing);
+            }
             let ingest = ing.ingest().context("ingest events")?;
             let run_id = ingest.coverage.run_id.to_string();
             let run_dir = out.join(&run_id);
@@ -157,6 +307,7 @@ pub(super) fn handle(
                 .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
 
             println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
             print_outputs(&outputs, ws_source);
         }
 
@@ -176,7 +327,8 @@ pub(super) fn handle(
                 discover_gitlab_user(&instance, token.as_deref())
             })?;
             let window = resolve_date_window(window)?;
-            let cache_dir = resolve_cache_dir(&out, cache_dir, no_cache);
+            let cache_dir =
+                planned_cache_dir(resolve_cache_dir(&out, cache_dir, no_cache), dry_run);
             let ing = make_gitlab_ingestor(
                 &user,
                 window.since,
@@ -189,6 +341,9 @@ pub(super) fn handle(
                 cache_dir,
             )
             .context("create GitLab ingestor")?;
+            if dry_run {
+                return print_ingestor_plan(&engine, "gitlab", &ing);
+            }
             let ingest = ing.ingest().context("ingest events")?;
             let run_id = ingest.coverage.run_id.to_string();
             let run_dir = out.join(&run_id);
@@ -222,6 +377,7 @@ pub(super) fn handle(
                 .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
 
             println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
             print_outputs(&outputs, ws_source);
         }
 
@@ -237,7 +393,8 @@ pub(super) fn handle(
             no_cache,
         } => {
             let window = resolve_date_window(window)?;
-            let cache_dir = resolve_cache_dir(&out, cache_dir, no_cache);
+            let cache_dir =
+                planned_cache_dir(resolve_cache_dir(&out, cache_dir, no_cache), dry_run);
             let ing = make_jira_ingestor(
                 &user,
                 auth_user,
@@ -250,6 +407,9 @@ pub(super) fn handle(
                 cache_dir,
             )
             .context("create Jira ingestor")?;
+            if dry_run {
+                return print_ingestor_plan(&engine, "jira", &ing);
+            }
             let ingest = ing.ingest().context("ingest events")?;
             let run_id = ingest.coverage.run_id.to_string();
             let run_dir = out.join(&run_id);
@@ -283,6 +443,7 @@ pub(super) fn handle(
                 .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
 
             println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
             print_outputs(&outputs, ws_source);
         }
 
@@ -297,7 +458,8 @@ pub(super) fn handle(
             no_cache,
         } => {
             let window = resolve_date_window(window)?;
-            let cache_dir = resolve_cache_dir(&out, cache_dir, no_cache);
+            let cache_dir =
+                planned_cache_dir(resolve_cache_dir(&out, cache_dir, no_cache), dry_run);
             let ing = make_linear_ingestor(
                 &user_id,
                 window.since,
@@ -309,6 +471,9 @@ pub(super) fn handle(
                 cache_dir,
             )
             .context("create Linear ingestor")?;
+            if dry_run {
+                return print_ingestor_plan(&engine, "linear", &ing);
+            }
             let ingest = ing.ingest().context("ingest events")?;
             let run_id = ingest.coverage.run_id.to_string();
             let run_dir = out.join(&run_id);
@@ -349,6 +514,7 @@ pub(super) fn handle(
                 .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
 
             println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
             print_outputs(&outputs, ws_source);
         }
 
@@ -362,6 +528,9 @@ pub(super) fn handle(
                 events_path: events,
                 coverage_path: coverage,
             };
+            if dry_run {
+                return print_ingestor_plan(&engine, "json", &ing);
+            }
             let ingest = ing.ingest().context("ingest events")?;
             let run_id = ingest.coverage.run_id.to_string();
             let run_dir = out.join(&run_id);
@@ -393,6 +562,7 @@ pub(super) fn handle(
                 .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
 
             println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
             print_outputs(&outputs, ws_source);
         }
 
@@ -403,6 +573,9 @@ pub(super) fn handle(
         } => {
             let window = resolve_date_window(window)?;
             let ing = ManualIngestor::new(&events, user.clone(), window.since, window.until);
+            if dry_run {
+                return print_ingestor_plan(&engine, "manual", &ing);
+            }
             let ingest = ing.ingest().context("ingest events")?;
             let run_id = ingest.coverage.run_id.to_string();
             let run_dir = out.join(&run_id);
@@ -435,6 +608,7 @@ pub(super) fn handle(
                 .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
 
             println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
             print_outputs(&outputs, ws_source);
         }
 
@@ -446,6 +620,9 @@ pub(super) fn handle(
         } => {
             let window = resolve_date_window(window)?;
             let ing = make_git_ingestor(&repo, window.since, window.until, author, include_merges);
+            if dry_run {
+                return print_ingestor_plan(&engine, "git", &ing);
+            }
             let ingest = ing.ingest().context("ingest events")?;
             let run_id = ingest.coverage.run_id.to_string();
             let run_dir = out.join(&run_id);
@@ -485,9 +662,222 @@ pub(super) fn handle(
                 .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
 
             println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
             print_outputs(&outputs, ws_source);
         }
+
+        Source::GithubExport {
+            archive,
+            user,
+            window,
+        } => {
+            let window = resolve_date_window(window)?;
+            let ing = make_github_export_ingestor(&archive, window.since, window.until, user);
+            if dry_run {
+                return print_ingestor_plan(&engine, "github-export", &ing);
+            }
+            let ingest = ing.ingest().context("ingest events")?;
+            let user = ingest.coverage.user.clone();
+            let run_id = ingest.coverage.run_id.to_string();
+            let run_dir = out.join(&run_id);
+            let window_label = window.window_label();
+
+            // Check if user has curated workstreams and warn
+            if !regen && shiplog::workstreams::WorkstreamManager::has_curated(&run_dir) {
+                eprintln!("Note: Using existing workstreams.yaml (user-curated).");
+                eprintln!("      Use --regen to regenerate suggestions.");
+            }
+
+            // If --regen, delete existing suggested workstreams so the engine regenerates them
+            if regen {
+                let suggested = shiplog::workstreams::WorkstreamManager::suggested_path(&run_dir);
+                if suggested.exists() {
+                    std::fs::remove_file(&suggested)
+                        .with_context(|| format!("remove {:?} for --regen", suggested))?;
+                }
+            }
+
+            let cache_path = DeterministicRedactor::cache_path(&run_dir);
+            let _ = redactor.load_cache(&cache_path);
+
+            let (outputs, ws_source) = engine
+                .run(ingest, &user, &window_label, &run_dir, zip, &bundle_profile)
+                .context("run engine pipeline")?;
+
+            redactor
+                .save_cache(&cache_path)
+                .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
+
+            println!("Collected and wrote:");
+            index_collected_run(search_index, &outputs)?;
+            print_outputs(&outputs, ws_source);
+        }
+    }
+
+    Ok(())
+}
+
+/// Latest run under `out` to collect into, or `None` (with a note) when
+/// there is none and the caller should collect the full window.
+/// Print what `ing` would fetch for `collect --dry-run`.
+fn print_ingestor_plan(engine: &Engine<'_>, name: &str, ing: &dyn Ingestor) -> Result<()> {
+    let plan = engine.plan(&[(name, ing)])?;
+    print_collection_plan(&plan, &[]);
+    Ok(())
+}
+
+/// Print what `collect multi --dry-run` would fetch from each enabled source.
+fn print_configured_plan(
+    engine: &Engine<'_>,
+    config_path: &Path,
+    config: &ShiplogConfig,
+    window: &ResolvedWindow,
+    out: &Path,
+) -> Result<()> {
+    let mut ingestors = Vec::new();
+    let mut skipped = Vec::new();
+    for configured in configured_ingestors(config_path, config, window, out, true)? {
+        match configured.ingestor {
+            Ok(ing) => ingestors.push((configured.name, ing)),
+            Err(err) => skipped.push(ConfiguredSourceFailure::from_error(configured.name, &err)),
+        }
+    }
+    let ingestors = ingestors
+        .iter()
+        .map(|(name, ing)| (*name, ing.as_ref()))
+        .collect::<Vec<_>>();
+    let plan = engine.plan(&ingestors)?;
+    print_collection_plan(&plan, &skipped);
+    Ok(())
+}
+
+fn print_collection_plan(
+    plan: &shiplog::engine::CollectionPlan,
+    skipped: &[ConfiguredSourceFailure],
+) {
+    println!("Dry run: no API requests were sent and no run was written.");
+    for source in &plan.sources {
+        println!(
+            "- {}: ~{}, {} of {} queries cached",
+            source.source,
+            request_count_phrase(source.estimated_requests()),
+            source.cached_queries(),
+            source.queries.len()
+        );
+        for query in &source.queries {
+            let cost = if query.cached {
+                "cached".to_string()
+            } else {
+                format!("~{}", request_count_phrase(query.estimated_requests))
+            };
+            println!(
+                "  {}..{} {} ({cost})",
+                query.window.since, query.window.until, query.query
+            );
+        }
+        for note in &source.notes {
+            println!("  note: {note}");
+        }
+    }
+    for name in &plan.local {
+        println!("- {name}: local, no API requests");
     }
+    for failure in skipped {
+        println!("- {}: skipped, {}", failure.name, failure.error);
+    }
+    println!(
+        "Estimated API requests: ~{} ({} queries cached)",
+        plan.estimated_requests(),
+        plan.cached_queries()
+    );
+}
 
+fn request_count_phrase(count: u64) -> String {
+    let noun = if count == 1 { "request" } else { "requests" };
+    format!("{count} {noun}")
+}
+
+fn incremental_run_dir(out: &Path) -> Option<PathBuf> {
+    let run_dir = find_most_recent_run(out).ok().filter(|run_dir| {
+        shiplog::bundle::RunArtifactPaths::new(run_dir)
+            .coverage_manifest()
+            .exists()
+    });
+    if run_dir.is_none() {
+        eprintln!(
+            "Note: no previous run in {}; collecting the full window.",
+            display_path_for_cli(out)
+        );
+    }
+    run_dir
+}
+
+fn collect_incremental_into(
+    engine: &Engine,
+    redactor: &DeterministicRedactor,
+    run_dir: &Path,
+    window: &ResolvedWindow,
+    sources: &[&str],
+    zip: bool,
+    bundle_profile: &BundleProfile,
+    fetch: impl FnMut(&str, &TimeWindow) -> Result<IngestOutput>,
+) -> Result<shiplog::engine::IncrementalOutputs> {
+    let cache_path = DeterministicRedactor::cache_path(run_dir);
+    let _ = redactor.load_cache(&cache_path);
+    let requested = TimeWindow {
+        since: window.since,
+        until: window.until,
+    };
+    let result = engine
+        .collect_incremental(run_dir, &requested, sources, zip, bundle_profile, fetch)
+        .context("collect incrementally")?;
+    redactor
+        .save_cache(&cache_path)
+        .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
+
+    println!(
+        "Collected incrementally into {}:",
+        display_path_for_cli(run_dir)
+    );
+    for delta in &result.deltas {
+        if delta.is_current() {
+            println!("- {}: already covered", delta.source);
+        } else {
+            let windows: Vec<String> = delta
+                .uncovered
+                .iter()
+                .map(|part| format!("{}..{}", part.since, part.until))
+                .collect();
+            println!("- {}: queried {}", delta.source, windows.join(", "));
+        }
+    }
+    for delta in &result.unrequested {
+        let windows: Vec<String> = delta
+            .uncovered
+            .iter()
+            .map(|part| format!("{}..{}", part.since, part.until))
+            .collect();
+        println!(
+            "- {}: not requested, left uncovered {}",
+            delta.source,
+            windows.join(", ")
+        );
+    }
+    for failure in &result.failures {
+        println!("- failed: {failure}");
+    }
+    println!(
+        "- events: {} before, {} after",
+        result.previous_events, result.events
+    );
+    println!("Refreshed while preserving workstream curation:");
+    print_outputs_simple(&result.outputs);
+    Ok(result)
+}
+
+fn index_collected_run(search_index: bool, outputs: &shiplog::engine::RunOutputs) -> Result<()> {
+    if search_index {
+        build_run_search_index(&outputs.out_dir).context("build search index")?;
+    }
     Ok(())
 }
@@ -17,6 +17,7 @@ pub(super) fn handle(
     llm_api_endpoint: String,
     llm_model: String,
     llm_api_key: Option<String>,
+    summarize: Option<SummarizeMode>,
 ) -> Result<()> {
     let events_path = dir.join("ledger.events.jsonl");
     let coverage_path = dir.join("coverage.manifest.json");
@@ -35,7 +36,15 @@ pub(super) fn handle(
     }
 
     let redaction_key = RedactionKey::resolve(redact_key, &bundle_profile)?;
-    let clusterer = build_clusterer(llm_cluster, &llm_api_endpoint, &llm_model, llm_api_key);
+    let clusterer = build_clusterer(
+        ClusterStrategy::Repo,
+        llm_cluster,
+        summarize,
+        &llm_api_endpoint,
+        &llm_model,
+        llm_api_key,
+        redaction_key.engine_key(),
+    );
     let (engine, redactor) = create_engine(redaction_key.engine_key(), clusterer, &bundle_profile);
     let engine = engine.with_profile_rendering(redaction_key.render_profiles());
 
@@ -8,6 +8,7 @@ mod import;
 mod merge;
 mod refresh;
 mod run;
+mod workstream_edit;
 
 use clap::Parser;
 
@@ -37,6 +38,8 @@ fn run_share_preflight(options: &ShareOptions, bundle_profile: BundleProfile) ->
 
 pub(super) fn dispatch() -> Result<()> {
     let cli = Cli::parse();
+    crate::logging::init(cli.log_format);
+    crate::timings::start(cli.timings);
     let command = match cli.cmd {
         Some(command) => command,
         None => {
@@ -78,6 +81,33 @@ pub(super) fn dispatch() -> Result<()> {
             run_intake(args)?;
         }
 
+        Command::Backfill(args) => {
+            backfill::run_backfill(args)?;
+        }
+
+        Command::Validate(args) => {
+            run_validate(args)?;
+        }
+        Command::Completions(args) => {
+            run_completions(args)?;
+        }
+        Command::Man(args) => {
+            run_man(args)?;
+        }
+        Command::SelfUpdate(args) => {
+            run_self_update(args)?;
+        }
+        Command::Daemon(args) => {
+            run_daemon(args)?;
+        }
+        Command::Schedule { cmd } => match cmd {
+            ScheduleCommand::Next { config, count } => run_schedule_next(&config, count)?,
+            ScheduleCommand::Install(args) => run_schedule_install(args)?,
+        },
+        Command::GenerateFixture(args) => {
+            run_generate_fixture(args)?;
+        }
+        Command::Search(args) => run_search(args)?,
         Command::Config { cmd } => match cmd {
             ConfigCommand::Validate { config } => {
                 run_config_validate(&config)?;
@@ -147,6 +177,12 @@ pub(super) fn dispatch() -> Result<()> {
             CacheCommand::Clean(args) => run_cache_clean(args)?,
         },
 
+        Command::Bundle { cmd } => match cmd {
+            BundleCommand::Delta(args) => run_bundle_delta(args)?,
+            BundleCommand::Verify(args) => run_bundle_verify(args)?,
+            BundleCommand::Diff(args) => run_bundle_diff(args)?,
+        },
+
         Command::Identify { cmd } => match cmd {
             IdentifyCommand::Jira {
                 instance,
@@ -160,10 +196,13 @@ pub(super) fn dispatch() -> Result<()> {
             JournalCommand::Add(args) => run_journal_add(args)?,
             JournalCommand::List(args) => run_journal_list(args)?,
             JournalCommand::Edit(args) => run_journal_edit(args)?,
+            JournalCommand::Import(args) => run_journal_import(args)?,
+            JournalCommand::Scaffold(args) => manual_templates::run_journal_scaffold(args)?,
         },
 
         Command::Collect {
             source,
+            registered,
             out,
             zip,
             redact_key,
@@ -173,8 +212,13 @@ pub(super) fn dispatch() -> Result<()> {
             llm_api_endpoint,
             llm_model,
             llm_api_key,
+            summarize,
+            search_index,
+            dry_run,
+            pdf,
         } => collect::handle(
             source,
+            registered,
             out,
             zip,
             redact_key,
@@ -184,6 +228,10 @@ pub(super) fn dispatch() -> Result<()> {
             llm_api_endpoint,
             llm_model,
             llm_api_key,
+            summarize,
+            search_index,
+            dry_run,
+            pdf,
         )?,
 
         Command::Render {
@@ -197,7 +245,13 @@ pub(super) fn dispatch() -> Result<()> {
             mode,
             receipt_limit,
             appendix,
+            heatmap,
+            min_pr_size,
             zip,
+            config,
+            acknowledge_pii,
+            allow_secret_findings,
+            pdf,
         } => {
             let redaction_key = RedactionKey::resolve(redact_key, &bundle_profile)?;
             let outputs = render_existing_run(RenderExistingArgs {
@@ -211,13 +265,27 @@ pub(super) fn dispatch() -> Result<()> {
                 mode,
                 receipt_limit,
                 appendix,
+                heatmap,
+                min_pr_size,
                 zip,
+                config: &config,
+                acknowledge_pii,
+                allow_secret_findings,
+                pdf,
             })?;
 
             println!("Rendered from existing events:");
             print_outputs(&outputs, WorkstreamSource::Curated);
         }
 
+        Command::Prompt(args) => prompt::run_prompt(args)?,
+        Command::Assess(args) => rubric_report::run_assess(args)?,
+
+        Command::Publish { cmd } => match cmd {
+            PublishCommand::Notion(args) => run_publish_notion(args)?,
+            PublishCommand::GoogleDocs(args) => run_publish_google_docs(args)?,
+        },
+
         Command::Share { cmd } => match cmd {
             ShareCommand::Manager(options) => {
                 let bundle_profile = BundleProfile::Manager;
@@ -236,6 +304,12 @@ pub(super) fn dispatch() -> Result<()> {
                     receipt_limit: None,
                     appendix: None,
                     zip: options.zip,
+                    heatmap: false,
+                    min_pr_size: None,
+                    config: &options.config,
+                    acknowledge_pii: options.acknowledge_pii,
+                    allow_secret_findings: options.allow_secret_findings,
+                    pdf: options.pdf,
                 })?;
                 let manifest_path =
                     write_share_manifest(&outputs, &BundleProfile::Manager, &redaction_key)?;
@@ -258,6 +332,12 @@ pub(super) fn dispatch() -> Result<()> {
                     receipt_limit: None,
                     appendix: None,
                     zip: options.zip,
+                    heatmap: false,
+                    min_pr_size: None,
+                    config: &options.config,
+                    acknowledge_pii: options.acknowledge_pii,
+                    allow_secret_findings: options.allow_secret_findings,
+                    pdf: options.pdf,
                 })?;
                 let manifest_path =
                     write_share_manifest(&outputs, &BundleProfile::Public, &redaction_key)?;
@@ -348,7 +428,7 @@ pub(super) fn dispatch() -> Result<()> {
             } => {
                 let run_dir = resolve_render_run_dir(&out, run, latest)?;
                 let (mut workstreams, source, _) = load_effective_workstreams_for_run(&run_dir)?;
-                let old_title = rename_workstream(&mut workstreams, &from, &to)?;
+                let old_title = WorkstreamManager::rename(&mut workstreams, &from, &to)?;
                 write_curated_workstreams(&run_dir, &workstreams)?;
                 println!("Renamed workstream: {old_title} -> {}", to.trim());
                 println!(
@@ -619,6 +699,10 @@ pub(super) fn dispatch() -> Result<()> {
                     println!("Created curated workstreams.yaml from suggested workstreams.");
                 }
             }
+            WorkstreamsCommand::Edit { cmd } => workstream_edit::handle(cmd)?,
+        },
+        Command::Coverage { cmd } => match cmd {
+            CoverageCommand::Diff(args) => run_coverage_diff(args)?,
         },
         Command::Runs { cmd } => match cmd {
             RunsCommand::List { out } => {
@@ -651,6 +735,38 @@ pub(super) fn dispatch() -> Result<()> {
             } => {
                 run_quality_diff_command(&out, latest, from, to)?;
             }
+            RunsCommand::Migrate {
+                out,
+                run,
+                latest,
+                dry_run,
+            } => {
+                let run_dir = resolve_render_run_dir(&out, run, latest)?;
+                run_runs_migrate(&run_dir, dry_run)?;
+            }
+        },
+        Command::History { cmd } => match cmd {
+            HistoryCommand::List {
+                out,
+                month,
+                curated,
+                rebuild,
+            } => history::print_history(&out, month.as_deref(), curated, rebuild)?,
+            HistoryCommand::Compare { from, to, out } => {
+                history::compare_history(&out, &from, &to)?
+            }
+            HistoryCommand::Open {
+                run,
+                out,
+                print_path,
+            } => history::open_history(&out, &run, print_path)?,
+            HistoryCommand::Shipped {
+                since,
+                until,
+                out,
+                json,
+            } => history::print_shipped(&out, since, until, json)?,
+            HistoryCommand::Covering { date, out } => history::print_covering(&out, date)?,
         },
         Command::Review { cmd, options } => match cmd {
             Some(ReviewCommand::Weekly {
@@ -672,6 +788,38 @@ pub(super) fn dispatch() -> Result<()> {
                 let run_dir = resolve_render_run_dir(&out, run, latest)?;
                 print_review_fixups(&run_dir, &out, commands_only, journal_template)?;
             }
+            Some(ReviewCommand::Status { out, run, latest }) => {
+                let run_dir = resolve_render_run_dir(&out, run, latest)?;
+                review_checklist::print_review_status(&run_dir, &out)?;
+            }
+            Some(ReviewCommand::Mark {
+                item,
+                workstream,
+                all,
+                undo,
+                out,
+                run,
+                latest,
+            }) => {
+                let run_dir = resolve_render_run_dir(&out, run, latest)?;
+                review_checklist::mark_review_check(
+                    &run_dir,
+                    item.into(),
+                    workstream.as_deref(),
+                    all,
+                    undo,
+                )?;
+            }
+            Some(ReviewCommand::Approve {
+                profile,
+                revoke,
+                out,
+                run,
+                latest,
+            }) => {
+                let run_dir = resolve_render_run_dir(&out, run, latest)?;
+                review_checklist::approve_share_profile(&run_dir, &profile, revoke)?;
+            }
             None => {
                 let run_dir = resolve_review_run_dir(
                     &options.out,
@@ -744,7 +892,7 @@ pub(super) fn dispatch() -> Result<()> {
                 )?;
             }
             None => {
-                let out = PathBuf::from("./out");
+                let out = PathBuf::from(DEFAULT_OUT_DIR);
                 let run_dir = resolve_render_run_dir(&out, None, true)?;
                 let packet = run_dir.join("packet.md");
                 open_existing_path(&packet, "Packet", "Run `shiplog intake` first.", print_path)?;
@@ -819,6 +967,7 @@ pub(super) fn dispatch() -> Result<()> {
             llm_api_endpoint,
             llm_model,
             llm_api_key,
+            summarize,
         } => import::handle(
             dir,
             out,
@@ -832,6 +981,7 @@ pub(super) fn dispatch() -> Result<()> {
             llm_api_endpoint,
             llm_model,
             llm_api_key,
+            summarize,
         )?,
 
         Command::Run {
@@ -844,6 +994,7 @@ pub(super) fn dispatch() -> Result<()> {
             llm_api_endpoint,
             llm_model,
             llm_api_key,
+            summarize,
         } => run::handle(
             source,
             out,
@@ -854,6 +1005,7 @@ pub(super) fn dispatch() -> Result<()> {
             llm_api_endpoint,
             llm_model,
             llm_api_key,
+            summarize,
         )?,
     }
 
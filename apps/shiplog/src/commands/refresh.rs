@@ -365,6 +365,38 @@ pub(super) fn handle(
             println!("Refreshed while preserving workstream curation:");
             print_outputs_simple(&outputs);
         }
+
+        Source::GithubExport {
+            archive,
+            user,
+            window,
+        } => {
+            let window = resolve_date_window(window)?;
+            if !shiplog::workstreams::WorkstreamManager::has_curated(&run_dir)
+                && !shiplog::workstreams::WorkstreamManager::suggested_path(&run_dir).exists()
+            {
+                anyhow::bail!(
+                    "No workstreams found in {:?}. Run `shiplog collect` first.",
+                    run_dir
+                );
+            }
+
+            let ing = make_github_export_ingestor(&archive, window.since, window.until, user);
+            let ingest = ing.ingest().context("ingest events")?;
+            let user = ingest.coverage.user.clone();
+            let window_label = window.window_label();
+
+            let outputs = engine
+                .refresh(ingest, &user, &window_label, &run_dir, zip, &bundle_profile)
+                .context("refresh engine pipeline")?;
+
+            redactor
+                .save_cache(&cache_path)
+                .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
+
+            println!("Refreshed while preserving workstream curation:");
+            print_outputs_simple(&outputs);
+        }
     }
 
     Ok(())
@@ -14,10 +14,19 @@ pub(super) fn handle(
     llm_api_endpoint: String,
     llm_model: String,
     llm_api_key: Option<String>,
+    summarize: Option<SummarizeMode>,
 ) -> Result<()> {
     // Legacy mode: just do collect
     let redaction_key = RedactionKey::resolve(redact_key, &bundle_profile)?;
-    let clusterer = build_clusterer(llm_cluster, &llm_api_endpoint, &llm_model, llm_api_key);
+    let clusterer = build_clusterer(
+        ClusterStrategy::Repo,
+        llm_cluster,
+        summarize,
+        &llm_api_endpoint,
+        &llm_model,
+        llm_api_key,
+        redaction_key.engine_key(),
+    );
     let (engine, redactor) = create_engine(redaction_key.engine_key(), clusterer, &bundle_profile);
     let engine = engine.with_profile_rendering(redaction_key.render_profiles());
 
@@ -308,6 +317,34 @@ pub(super) fn handle(
             println!("Wrote:");
             print_outputs(&outputs, ws_source);
         }
+
+        Source::GithubExport {
+            archive,
+            user,
+            window,
+        } => {
+            let window = resolve_date_window(window)?;
+            let ing = make_github_export_ingestor(&archive, window.since, window.until, user);
+            let ingest = ing.ingest().context("ingest events")?;
+            let user = ingest.coverage.user.clone();
+            let run_id = ingest.coverage.run_id.to_string();
+            let run_dir = out.join(&run_id);
+            let window_label = window.window_label();
+
+            let cache_path = DeterministicRedactor::cache_path(&run_dir);
+            let _ = redactor.load_cache(&cache_path);
+
+            let (outputs, ws_source) = engine
+                .run(ingest, &user, &window_label, &run_dir, zip, &bundle_profile)
+                .context("run engine pipeline")?;
+
+            redactor
+                .save_cache(&cache_path)
+                .with_context(|| format!("save redaction cache to {cache_path:?}"))?;
+
+            println!("Wrote:");
+            print_outputs(&outputs, ws_source);
+        }
     }
 
     Ok(())
@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use crate::*;
+
+pub(super) fn handle(cmd: WorkstreamEditCommand) -> Result<()> {
+    match cmd {
+        WorkstreamEditCommand::Merge {
+            out,
+            run,
+            latest,
+            from,
+            into,
+        } => {
+            let run_dir = resolve_render_run_dir(&out, run, latest)?;
+            let (mut workstreams, source, _) = load_effective_workstreams_for_run(&run_dir)?;
+            let ledger_events = load_run_events(&run_dir)?;
+            let result = WorkstreamManager::merge(&mut workstreams, &from, &into, &ledger_events)?;
+            save(&run_dir, &workstreams, &ledger_events)?;
+            println!(
+                "Merged workstream {} into {}",
+                result.from_title, result.into_title
+            );
+            println!(
+                "Moved {} event(s) and {} receipt anchor(s).",
+                result.event_count, result.receipt_count
+            );
+            print_updated(&run_dir, source);
+        }
+        WorkstreamEditCommand::Split {
+            out,
+            run,
+            latest,
+            from,
+            event,
+            title,
+        } => {
+            let run_dir = resolve_render_run_dir(&out, run, latest)?;
+            let (mut workstreams, source, _) = load_effective_workstreams_for_run(&run_dir)?;
+            let ledger_events = load_run_events(&run_dir)?;
+            let result =
+                WorkstreamManager::split(&mut workstreams, &from, &event, &title, &ledger_events)?;
+            save(&run_dir, &workstreams, &ledger_events)?;
+            println!(
+                "Split {} event(s) from {} into new workstream {}",
+                result.event_count, result.from_title, result.title
+            );
+            println!("ID: {}", result.id);
+            if result.receipt_count > 0 {
+                println!("Moved {} receipt anchor(s).", result.receipt_count);
+            }
+            print_updated(&run_dir, source);
+        }
+        WorkstreamEditCommand::Rename {
+            out,
+            run,
+            latest,
+            workstream,
+            title,
+        } => {
+            let run_dir = resolve_render_run_dir(&out, run, latest)?;
+            let (mut workstreams, source, _) = load_effective_workstreams_for_run(&run_dir)?;
+            let ledger_events = load_run_events(&run_dir)?;
+            let old_title = WorkstreamManager::rename(&mut workstreams, &workstream, &title)?;
+            save(&run_dir, &workstreams, &ledger_events)?;
+            println!("Renamed workstream: {old_title} -> {}", title.trim());
+            print_updated(&run_dir, source);
+        }
+        WorkstreamEditCommand::Retag {
+            out,
+            run,
+            latest,
+            workstream,
+            add,
+            remove,
+        } => {
+            let run_dir = resolve_render_run_dir(&out, run, latest)?;
+            let (mut workstreams, source, _) = load_effective_workstreams_for_run(&run_dir)?;
+            let ledger_events = load_run_events(&run_dir)?;
+            let tags = WorkstreamManager::retag(&mut workstreams, &workstream, &add, &remove)?;
+            save(&run_dir, &workstreams, &ledger_events)?;
+            if tags.is_empty() {
+                println!("Tags: (none)");
+            } else {
+                println!("Tags: {}", tags.join(", "));
+            }
+            print_updated(&run_dir, source);
+        }
+    }
+    Ok(())
+}
+
+/// Validate the edited workstreams against the ledger, then write them as
+/// the curated file. Nothing is written when validation fails.
+fn save(
+    run_dir: &Path,
+    workstreams: &WorkstreamsFile,
+    ledger_events: &[EventEnvelope],
+) -> Result<()> {
+    let errors = validate_workstreams_against_events(workstreams, ledger_events);
+    if !errors.is_empty() {
+        for error in &errors {
+            eprintln!("- {error}");
+        }
+        anyhow::bail!("{} workstream validation error(s)", errors.len());
+    }
+    write_curated_workstreams(run_dir, workstreams)
+}
+
+fn print_updated(run_dir: &Path, source: WorkstreamsFileSource) {
+    println!(
+        "Updated: {}",
+        WorkstreamManager::curated_path(run_dir).display()
+    );
+    if matches!(source, WorkstreamsFileSource::Suggested) {
+        println!("Created curated workstreams.yaml from suggested workstreams.");
+    }
+}
@@ -0,0 +1,150 @@
+//! Run-to-run comparison of coverage manifests.
+
+use serde::Serialize;
+use shiplog::ids::RunId;
+use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// What changed in coverage between two runs.
+///
+/// Slices are matched by window and query, so a re-run over the same period
+/// lines up slice for slice even when its run id and timestamps differ.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CoverageDiff {
+    /// Run the comparison starts from.
+    pub from_run: RunId,
+    /// Run the comparison ends at.
+    pub to_run: RunId,
+    /// Window of the earlier run.
+    pub from_window: TimeWindow,
+    /// Window of the later run.
+    pub to_window: TimeWindow,
+    /// Overall completeness of the earlier run.
+    pub from_completeness: Completeness,
+    /// Overall completeness of the later run.
+    pub to_completeness: Completeness,
+    /// Sources queried by the later run but not the earlier one.
+    pub sources_added: Vec<String>,
+    /// Sources queried by the earlier run but not the later one.
+    pub sources_removed: Vec<String>,
+    /// Slices that were partial (or missing) before and are complete now.
+    pub newly_complete: Vec<CoverageSlice>,
+    /// Slices that were complete (or missing) before and are partial now.
+    pub newly_partial: Vec<CoverageSlice>,
+    /// Slices that are partial in both runs.
+    pub still_partial: Vec<CoverageSlice>,
+    /// Slices the later run no longer reports.
+    pub slices_removed: Vec<CoverageSlice>,
+}
+
+impl CoverageDiff {
+    /// Returns `true` when the windows of the two runs differ.
+    pub fn window_changed(&self) -> bool {
+        self.from_window != self.to_window
+    }
+
+    /// Returns `true` when the later run has no partial slices left.
+    pub fn gaps_closed(&self) -> bool {
+        self.newly_partial.is_empty() && self.still_partial.is_empty()
+    }
+}
+
+/// Compare two coverage manifests, `from` being the earlier run.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::coverage::diff_coverage;
+/// use shiplog::ids::RunId;
+/// use shiplog::schema::coverage::*;
+/// use chrono::{NaiveDate, Utc};
+///
+/// let window = TimeWindow {
+///     since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///     until: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+/// };
+/// let slice = |fetched| CoverageSlice {
+///     window: window.clone(),
+///     query: "author:octo is:pr".into(),
+///     total_count: 10,
+///     fetched,
+///     incomplete_results: Some(false),
+///     notes: vec![],
+///     source: None,
+/// };
+/// let manifest = |slice, completeness| CoverageManifest {
+///     schema_version: SchemaVersion::CURRENT,
+///     run_id: RunId::now("test"),
+///     generated_at: Utc::now(),
+///     user: "octo".into(),
+///     window: window.clone(),
+///     mode: "merged".into(),
+///     sources: vec!["github".into()],
+///     slices: vec![slice],
+///     warnings: vec![],
+///     completeness,
+///     score: None,
+///     timezone: None,
+///     budget: vec![],
+/// };
+///
+/// let before = manifest(slice(6), Completeness::Partial);
+/// let after = manifest(slice(10), Completeness::Complete);
+/// let diff = diff_coverage(&before, &after);
+///
+/// assert_eq!(diff.newly_complete.len(), 1);
+/// assert!(diff.gaps_closed());
+/// ```
+pub fn diff_coverage(from: &CoverageManifest, to: &CoverageManifest) -> CoverageDiff {
+    let from_sources = source_set(&from.sources);
+    let to_sources = source_set(&to.sources);
+
+    let mut earlier: BTreeMap<SliceKey<'_>, &CoverageSlice> = from
+        .slices
+        .iter()
+        .map(|slice| (slice_key(slice), slice))
+        .collect();
+
+    let mut newly_complete = Vec::new();
+    let mut newly_partial = Vec::new();
+    let mut still_partial = Vec::new();
+    for slice in &to.slices {
+        let was_partial = earlier
+            .remove(&slice_key(slice))
+            .map(CoverageSlice::is_partial);
+        match (was_partial, slice.is_partial()) {
+            (Some(true), true) => still_partial.push(slice.clone()),
+            (Some(false), true) | (None, true) => newly_partial.push(slice.clone()),
+            (Some(true), false) | (None, false) => newly_complete.push(slice.clone()),
+            (Some(false), false) => {}
+        }
+    }
+
+    CoverageDiff {
+        from_run: from.run_id.clone(),
+        to_run: to.run_id.clone(),
+        from_window: from.window.clone(),
+        to_window: to.window.clone(),
+        from_completeness: from.completeness.clone(),
+        to_completeness: to.completeness.clone(),
+        sources_added: to_sources.difference(&from_sources).cloned().collect(),
+        sources_removed: from_sources.difference(&to_sources).cloned().collect(),
+        newly_complete,
+        newly_partial,
+        still_partial,
+        slices_removed: earlier.into_values().cloned().collect(),
+    }
+}
+
+type SliceKey<'a> = (chrono::NaiveDate, chrono::NaiveDate, &'a str);
+
+fn slice_key(slice: &CoverageSlice) -> SliceKey<'_> {
+    (slice.window.since, slice.window.until, slice.query.as_str())
+}
+
+fn source_set(sources: &[String]) -> BTreeSet<String> {
+    sources
+        .iter()
+        .map(|source| source.trim().to_ascii_lowercase())
+        .collect()
+}
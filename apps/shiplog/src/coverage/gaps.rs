@@ -0,0 +1,175 @@
+//! Calendar-week activity gaps.
+//!
+//! A week with no events from any source is either time off or data that
+//! never arrived. An expired token, for example, silences a source from some
+//! date to the end of the window. Gaps are classified by what the sources did
+//! around them so the packet can say which one is more likely.
+
+use std::collections::BTreeSet;
+
+use shiplog::schema::coverage::{CoverageManifest, TimeWindow};
+use shiplog::schema::event::EventEnvelope;
+
+use super::timezone::ReportingTimezone;
+use super::windows::{week_windows, window_len_days};
+
+/// Prefix of the coverage warnings written for activity gaps.
+pub const ACTIVITY_GAP_WARNING_PREFIX: &str = "Activity gap:";
+
+/// Fewest active weeks needed before empty weeks count as gaps. Sparser
+/// activity has no baseline to compare against.
+const MIN_ACTIVE_WEEKS: usize = 4;
+
+/// Longest interior gap still read as time off.
+const VACATION_MAX_WEEKS: usize = 3;
+
+/// Why a run of empty weeks most likely happened.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GapCause {
+    /// A short break with the same sources active on both sides.
+    LikelyVacation,
+    /// A source stopped or started around the gap, the gap touches the
+    /// window edge, or it is too long to be time off.
+    LikelyMissingSource,
+}
+
+/// A run of consecutive calendar weeks with no events from any source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActivityGap {
+    /// Monday-started span the gap covers (`until` is exclusive).
+    pub window: TimeWindow,
+    /// Number of empty weeks.
+    pub weeks: usize,
+    /// Most likely explanation.
+    pub cause: GapCause,
+    /// Sources active before the gap with no events after it.
+    pub stopped: Vec<String>,
+    /// Sources active after the gap with no events before it.
+    pub started: Vec<String>,
+}
+
+impl ActivityGap {
+    /// Coverage warning line describing this gap.
+    pub fn warning(&self) -> String {
+        let weeks = if self.weeks == 1 {
+            "1 week".to_string()
+        } else {
+            format!("{} weeks", self.weeks)
+        };
+        let cause = match self.cause {
+            GapCause::LikelyVacation => "likely vacation".to_string(),
+            GapCause::LikelyMissingSource if !self.stopped.is_empty() => format!(
+                "likely missing source ({} has no events after {})",
+                self.stopped.join(", "),
+                self.window.since
+            ),
+            GapCause::LikelyMissingSource if !self.started.is_empty() => format!(
+                "likely missing source ({} has no events before {})",
+                self.started.join(", "),
+                self.window.until
+            ),
+            GapCause::LikelyMissingSource => {
+                format!("likely missing source (longer than {VACATION_MAX_WEEKS} weeks)")
+            }
+        };
+        format!(
+            "{ACTIVITY_GAP_WARNING_PREFIX} no events from any source for {weeks} from {} to {}; {cause}",
+            self.window.since, self.window.until
+        )
+    }
+}
+
+/// Find calendar weeks inside `window` with no events from any source.
+///
+/// Only full Monday-to-Sunday weeks are considered, and events are placed in
+/// weeks by their date in `timezone`. Returns nothing when fewer than four
+/// weeks have any activity.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::coverage::{ReportingTimezone, activity_gaps};
+/// use shiplog::schema::coverage::TimeWindow;
+/// use chrono::NaiveDate;
+///
+/// let window = TimeWindow {
+///     since: NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(),
+///     until: NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+/// };
+///
+/// // No events at all: nothing to compare against.
+/// assert!(activity_gaps(&window, &[], ReportingTimezone::UTC).is_empty());
+/// ```
+pub fn activity_gaps(
+    window: &TimeWindow,
+    events: &[EventEnvelope],
+    timezone: ReportingTimezone,
+) -> Vec<ActivityGap> {
+    let weeks: Vec<TimeWindow> = week_windows(window.since, window.until)
+        .into_iter()
+        .filter(|week| window_len_days(week) == 7)
+        .collect();
+    let active: Vec<BTreeSet<String>> = weeks
+        .iter()
+        .map(|week| {
+            events
+                .iter()
+                .filter(|event| timezone.contains(week, &event.occurred_at))
+                .map(|event| event.source.system.as_str().to_string())
+                .collect()
+        })
+        .collect();
+    if active.iter().filter(|sources| !sources.is_empty()).count() < MIN_ACTIVE_WEEKS {
+        return Vec::new();
+    }
+
+    let mut gaps = Vec::new();
+    let mut index = 0;
+    while index < weeks.len() {
+        if !active[index].is_empty() {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < weeks.len() && active[index].is_empty() {
+            index += 1;
+        }
+        let before: BTreeSet<&String> = active[..start].iter().flatten().collect();
+        let after: BTreeSet<&String> = active[index..].iter().flatten().collect();
+        let stopped: Vec<String> = before.difference(&after).map(|s| s.to_string()).collect();
+        let started: Vec<String> = after.difference(&before).map(|s| s.to_string()).collect();
+        let len = index - start;
+        let interior = start > 0 && index < weeks.len();
+        let cause =
+            if interior && len <= VACATION_MAX_WEEKS && stopped.is_empty() && started.is_empty() {
+                GapCause::LikelyVacation
+            } else {
+                GapCause::LikelyMissingSource
+            };
+        gaps.push(ActivityGap {
+            window: TimeWindow {
+                since: weeks[start].since,
+                until: weeks[index - 1].until,
+            },
+            weeks: len,
+            cause,
+            stopped,
+            started,
+        });
+    }
+    gaps
+}
+
+/// Replace the activity-gap warnings on `coverage` with ones computed from
+/// `events` in the manifest's timezone, leaving every other warning in place.
+pub fn refresh_activity_gap_warnings(coverage: &mut CoverageManifest, events: &[EventEnvelope]) {
+    coverage
+        .warnings
+        .retain(|warning| !warning.starts_with(ACTIVITY_GAP_WARNING_PREFIX));
+    let timezone = ReportingTimezone::from_manifest(coverage.timezone.as_deref());
+    coverage.warnings.extend(
+        activity_gaps(&coverage.window, events, timezone)
+            .iter()
+            .map(ActivityGap::warning),
+    );
+}
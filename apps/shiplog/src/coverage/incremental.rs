@@ -0,0 +1,197 @@
+//! Uncovered windows for incremental collection.
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use shiplog::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
+
+/// Coverage warning prefix for a delta window that could not be fetched. The
+/// rest of the warning reads `<source> <since>..<until>: <error>`.
+pub const INCREMENTAL_FAILURE_WARNING_PREFIX: &str = "Incremental collection failed for ";
+
+/// What one source still needs fetched.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SourceDelta {
+    /// Source name as the caller passed it.
+    pub source: String,
+    /// Windows the previous run already fetched completely.
+    pub covered: Vec<TimeWindow>,
+    /// Windows to query now, in date order.
+    pub uncovered: Vec<TimeWindow>,
+}
+
+impl SourceDelta {
+    /// Returns `true` when nothing needs fetching.
+    pub fn is_current(&self) -> bool {
+        self.uncovered.is_empty()
+    }
+}
+
+/// Split `window` into covered and uncovered parts for each of `sources`.
+///
+/// A source counts as covered for the previous run's window when the previous
+/// manifest lists it, up to the day the run was generated: that day is
+/// fetched again because work could land after collection. Names match
+/// loosely, so `git` finds `local_git` and `github-export` finds
+/// `github_export`. When the previous run was not complete, its partial
+/// slices are uncovered for every source, since older manifests do not name
+/// a slice's source. A source the previous run did not
+/// collect, or listed with a `Configured source <name> was skipped` warning,
+/// is uncovered for the whole window, and windows named in an
+/// [`INCREMENTAL_FAILURE_WARNING_PREFIX`] warning stay uncovered for their
+/// source.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::coverage::incremental_deltas;
+/// use shiplog::ids::RunId;
+/// use shiplog::schema::coverage::*;
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let day = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+/// let previous = CoverageManifest {
+///     schema_version: SchemaVersion::CURRENT,
+///     run_id: RunId::now("test"),
+///     generated_at: Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap(),
+///     user: "octo".into(),
+///     window: TimeWindow { since: day(1, 1), until: day(3, 4) },
+///     mode: "merged".into(),
+///     sources: vec!["github".into()],
+///     slices: vec![],
+///     warnings: vec![],
+///     completeness: Completeness::Complete,
+///     score: None,
+///     timezone: None,
+///     budget: vec![],
+/// };
+/// let window = TimeWindow { since: day(1, 1), until: day(3, 10) };
+/// let deltas = incremental_deltas(&previous, &window, &["github", "jira"]);
+///
+/// assert_eq!(deltas[0].uncovered, vec![TimeWindow { since: day(3, 3), until: day(3, 10) }]);
+/// assert_eq!(deltas[1].uncovered, vec![window.clone()]);
+/// ```
+pub fn incremental_deltas(
+    previous: &CoverageManifest,
+    window: &TimeWindow,
+    sources: &[&str],
+) -> Vec<SourceDelta> {
+    let observed_until = previous
+        .window
+        .until
+        .min(previous.generated_at.date_naive());
+    let mut fetched = vec![TimeWindow {
+        since: previous.window.since,
+        until: observed_until,
+    }];
+    if previous.completeness != Completeness::Complete {
+        for slice in previous.slices.iter().filter(|slice| slice.is_partial()) {
+            fetched = subtract(fetched, &slice.window);
+        }
+    }
+    let fetched: Vec<TimeWindow> = fetched
+        .iter()
+        .filter_map(|part| intersect(part, window))
+        .collect();
+
+    sources
+        .iter()
+        .map(|source| {
+            let collected = previous
+                .sources
+                .iter()
+                .any(|name| source_key(name) == source_key(source));
+            let skipped = previous
+                .warnings
+                .iter()
+                .any(|warning| is_skip_warning_for(warning, source));
+            let covered = if collected && !skipped {
+                previous
+                    .warnings
+                    .iter()
+                    .filter_map(|warning| failed_window_for(warning, source))
+                    .fold(fetched.clone(), |parts, failed| subtract(parts, &failed))
+            } else {
+                Vec::new()
+            };
+            let uncovered = covered.iter().fold(vec![window.clone()], subtract);
+            SourceDelta {
+                source: (*source).to_string(),
+                covered,
+                uncovered,
+            }
+        })
+        .collect()
+}
+
+/// Whether two source names refer to the same source, matching as loosely
+/// as [`incremental_deltas`] does.
+pub(crate) fn is_same_source(a: &str, b: &str) -> bool {
+    source_key(a) == source_key(b)
+}
+
+/// Whether `warning` says a configured `source` was skipped.
+pub(crate) fn is_skip_warning_for(warning: &str, source: &str) -> bool {
+    skip_reason(warning).is_some_and(|(name, _)| source_key(name) == source_key(source))
+}
+
+/// Source name and reason from a `Configured source <name> was skipped:
+/// <reason>` warning. The reason is empty when the warning gives none.
+pub(super) fn skip_reason(warning: &str) -> Option<(&str, &str)> {
+    let (name, rest) = warning
+        .strip_prefix("Configured source ")?
+        .split_once(" was skipped")?;
+    Some((name, rest.trim_start_matches(':').trim()))
+}
+
+fn failed_window_for(warning: &str, source: &str) -> Option<TimeWindow> {
+    let (name, rest) = warning
+        .strip_prefix(INCREMENTAL_FAILURE_WARNING_PREFIX)?
+        .split_once(' ')?;
+    if source_key(name) != source_key(source) {
+        return None;
+    }
+    let (range, _) = rest.split_once(':')?;
+    let (since, until) = range.split_once("..")?;
+    Some(TimeWindow {
+        since: NaiveDate::parse_from_str(since, "%Y-%m-%d").ok()?,
+        until: NaiveDate::parse_from_str(until, "%Y-%m-%d").ok()?,
+    })
+}
+
+pub(super) fn source_key(name: &str) -> String {
+    let key = name.trim().to_ascii_lowercase().replace(['-', ' '], "_");
+    match key.as_str() {
+        "local_git" => "git".to_string(),
+        "json_import" => "json".to_string(),
+        _ => key,
+    }
+}
+
+fn intersect(a: &TimeWindow, b: &TimeWindow) -> Option<TimeWindow> {
+    let since = a.since.max(b.since);
+    let until = a.until.min(b.until);
+    (since < until).then_some(TimeWindow { since, until })
+}
+
+pub(super) fn subtract(parts: Vec<TimeWindow>, remove: &TimeWindow) -> Vec<TimeWindow> {
+    let mut out = Vec::with_capacity(parts.len() + 1);
+    for part in parts {
+        if intersect(&part, remove).is_none() {
+            out.push(part);
+            continue;
+        }
+        if part.since < remove.since {
+            out.push(TimeWindow {
+                since: part.since,
+                until: remove.since,
+            });
+        }
+        if remove.until < part.until {
+            out.push(TimeWindow {
+                since: remove.until,
+                until: part.until,
+            });
+        }
+    }
+    out
+}
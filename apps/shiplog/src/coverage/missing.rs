@@ -0,0 +1,193 @@
+//! What a run's coverage is missing, per source.
+//!
+//! The manifest records what each query returned. This compares the requested
+//! window with the union of each source's query slices and lists the date
+//! ranges no slice covered, the slices that came back short, and the sources
+//! that contributed nothing, so a packet's blind spots are known before it is
+//! sent.
+
+use serde::{Deserialize, Serialize};
+use shiplog::schema::coverage::{CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::event::EventEnvelope;
+
+use super::incremental::{skip_reason, source_key, subtract};
+
+/// Coverage gaps for one run, written to `coverage.gaps.json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageGaps {
+    /// Run the gaps were computed for.
+    pub run_id: String,
+    /// Window the run requested.
+    pub window: TimeWindow,
+    /// One entry per source the run queried or skipped, in manifest order.
+    pub sources: Vec<SourceGaps>,
+}
+
+impl CoverageGaps {
+    /// Returns `true` when every source covered the whole window, fetched
+    /// everything it reported, and contributed events.
+    pub fn is_empty(&self) -> bool {
+        self.sources.iter().all(SourceGaps::is_empty)
+    }
+
+    /// Sources that were queried but contributed no events.
+    pub fn zero_event_sources(&self) -> impl Iterator<Item = &SourceGaps> {
+        self.sources
+            .iter()
+            .filter(|source| source.skipped.is_none() && source.events == 0)
+    }
+}
+
+/// What one source is missing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceGaps {
+    /// Source name as the manifest lists it.
+    pub source: String,
+    /// Events from this source in the ledger.
+    pub events: usize,
+    /// Query slices attributed to this source.
+    pub slices: usize,
+    /// Why the source was not searched, when it was skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+    /// Parts of the requested window no slice of this source covered, in
+    /// date order. A skipped source is uncovered for the whole window; a
+    /// source with no attributed slices has nothing to compare and lists none.
+    pub uncovered: Vec<TimeWindow>,
+    /// Slices that fetched fewer results than the source reported or that the
+    /// source flagged incomplete.
+    pub capped: Vec<CoverageSlice>,
+}
+
+impl SourceGaps {
+    /// Returns `true` when nothing is missing for this source.
+    pub fn is_empty(&self) -> bool {
+        self.skipped.is_none()
+            && self.events > 0
+            && self.uncovered.is_empty()
+            && self.capped.is_empty()
+    }
+}
+
+/// Compare the requested window with each source's query slices.
+///
+/// A slice counts for the source it names. Slices from manifests written
+/// before slices were attributed count for the manifest's only source, and
+/// for no source when there were several. Events count for the source whose
+/// system they name, or all for the manifest's only source, since exports
+/// such as `github_export` write events under another system. Sources named
+/// by a `Configured source <name> was skipped` warning are listed as skipped.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::coverage::coverage_gaps;
+/// use shiplog::ids::RunId;
+/// use shiplog::schema::coverage::*;
+/// use chrono::{NaiveDate, Utc};
+///
+/// let day = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+/// let coverage = CoverageManifest {
+///     schema_version: SchemaVersion::CURRENT,
+///     run_id: RunId::now("test"),
+///     generated_at: Utc::now(),
+///     user: "octo".into(),
+///     window: TimeWindow { since: day(1, 1), until: day(4, 1) },
+///     mode: "merged".into(),
+///     sources: vec!["github".into()],
+///     slices: vec![CoverageSlice {
+///         window: TimeWindow { since: day(1, 1), until: day(3, 1) },
+///         query: "author:octo is:pr".into(),
+///         total_count: 0,
+///         fetched: 0,
+///         incomplete_results: Some(false),
+///         notes: vec![],
+///         source: Some("github".into()),
+///     }],
+///     warnings: vec![],
+///     completeness: Completeness::Complete,
+///     score: None,
+///     timezone: None,
+///     budget: vec![],
+/// };
+///
+/// let gaps = coverage_gaps(&coverage, &[]);
+/// let github = &gaps.sources[0];
+/// assert_eq!(github.uncovered, vec![TimeWindow { since: day(3, 1), until: day(4, 1) }]);
+/// assert_eq!(gaps.zero_event_sources().count(), 1);
+/// ```
+pub fn coverage_gaps(coverage: &CoverageManifest, events: &[EventEnvelope]) -> CoverageGaps {
+    let mut names: Vec<&str> = coverage.sources.iter().map(String::as_str).collect();
+    for warning in &coverage.warnings {
+        if let Some((name, _)) = skip_reason(warning)
+            && !names
+                .iter()
+                .any(|known| source_key(known) == source_key(name))
+        {
+            names.push(name);
+        }
+    }
+    let sole_source = match coverage.sources.as_slice() {
+        [source] => Some(source.as_str()),
+        _ => None,
+    };
+
+    let sources = names
+        .into_iter()
+        .map(|name| {
+            let key = source_key(name);
+            let slices: Vec<&CoverageSlice> = coverage
+                .slices
+                .iter()
+                .filter(|slice| {
+                    slice
+                        .source
+                        .as_deref()
+                        .or(sole_source)
+                        .is_some_and(|source| source_key(source) == key)
+                })
+                .collect();
+            let skipped = coverage.warnings.iter().find_map(|warning| {
+                skip_reason(warning)
+                    .filter(|(skipped, _)| source_key(skipped) == key)
+                    .map(|(_, reason)| reason.to_string())
+            });
+            let uncovered = if skipped.is_some() {
+                vec![coverage.window.clone()]
+            } else if slices.is_empty() {
+                Vec::new()
+            } else {
+                slices
+                    .iter()
+                    .fold(vec![coverage.window.clone()], |parts, slice| {
+                        subtract(parts, &slice.window)
+                    })
+            };
+            SourceGaps {
+                source: name.to_string(),
+                events: if sole_source == Some(name) {
+                    events.len()
+                } else {
+                    events
+                        .iter()
+                        .filter(|event| source_key(event.source.system.as_str()) == key)
+                        .count()
+                },
+                slices: slices.len(),
+                skipped,
+                uncovered,
+                capped: slices
+                    .iter()
+                    .filter(|slice| slice.is_partial())
+                    .map(|slice| (*slice).clone())
+                    .collect(),
+            }
+        })
+        .collect();
+
+    CoverageGaps {
+        run_id: coverage.run_id.to_string(),
+        window: coverage.window.clone(),
+        sources,
+    }
+}
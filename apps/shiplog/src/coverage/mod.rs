@@ -2,7 +2,12 @@
 //! Date-window utilities for coverage and ingestion slicing.
 //!
 //! This module owns the coverage-facing windowing primitives that keep query
-//! windows explicit, contiguous, and auditable.
+//! windows explicit, contiguous, and auditable, plus the numeric coverage
+//! score, minimum coverage requirements, calendar-week activity gaps, the
+//! reporting timezone that window dates are read in, the run-to-run
+//! comparison used by `shiplog coverage diff`, the uncovered windows
+//! incremental collection fetches, and the per-source gaps written to
+//! `coverage.gaps.json`.
 //!
 //! # Examples
 //!
@@ -19,6 +24,23 @@
 //! assert_eq!(window_len_days(&months[0]), 31);
 //! ```
 
+mod diff;
+mod gaps;
+pub(crate) mod incremental;
+mod missing;
+mod requirements;
+mod score;
+mod timezone;
 mod windows;
 
+pub use diff::{CoverageDiff, diff_coverage};
+pub use gaps::{
+    ACTIVITY_GAP_WARNING_PREFIX, ActivityGap, GapCause, activity_gaps,
+    refresh_activity_gap_warnings,
+};
+pub use incremental::{INCREMENTAL_FAILURE_WARNING_PREFIX, SourceDelta, incremental_deltas};
+pub use missing::{CoverageGaps, SourceGaps, coverage_gaps};
+pub use requirements::{CoverageRequirement, CoverageShortfall, check_requirement};
+pub use score::coverage_score;
+pub use timezone::ReportingTimezone;
 pub use windows::{day_windows, month_windows, week_windows, window_len_days};
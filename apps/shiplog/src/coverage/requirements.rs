@@ -0,0 +1,102 @@
+//! Minimum coverage requirements for automated runs.
+
+use serde::{Deserialize, Serialize};
+use shiplog::schema::coverage::{Completeness, CoverageManifest};
+
+use super::score::coverage_score;
+
+/// A minimum bar a run (or one of its sources) must clear.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoverageRequirement {
+    /// Completeness must be `Complete`.
+    pub require_complete: bool,
+    /// The 0–100 coverage score must be at least this value.
+    pub min_score: Option<u8>,
+}
+
+impl CoverageRequirement {
+    /// Returns `true` when the requirement asks for nothing.
+    pub fn is_empty(&self) -> bool {
+        !self.require_complete && self.min_score.is_none()
+    }
+}
+
+/// One requirement a run did not meet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CoverageShortfall {
+    /// Source the requirement applies to, or `None` for the whole run.
+    pub source: Option<String>,
+    /// Why the requirement was not met.
+    pub reason: String,
+}
+
+/// Check `coverage` against `requirement`.
+///
+/// `source` labels the shortfalls; pass `None` when checking the merged run.
+/// A score requirement is not met when the manifest has no slices to score.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::coverage::{CoverageRequirement, check_requirement};
+/// use shiplog::ids::RunId;
+/// use shiplog::schema::coverage::*;
+/// use chrono::{NaiveDate, Utc};
+///
+/// let coverage = CoverageManifest {
+///     schema_version: SchemaVersion::CURRENT,
+///     run_id: RunId::now("test"),
+///     generated_at: Utc::now(),
+///     user: "octo".into(),
+///     window: TimeWindow {
+///         since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///         until: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+///     },
+///     mode: "merged".into(),
+///     sources: vec!["github".into()],
+///     slices: vec![],
+///     warnings: vec![],
+///     completeness: Completeness::Partial,
+///     score: None,
+///     timezone: None,
+///     budget: vec![],
+/// };
+/// let requirement = CoverageRequirement { require_complete: true, min_score: None };
+///
+/// let shortfalls = check_requirement(Some("github"), &coverage, &requirement);
+/// assert_eq!(shortfalls.len(), 1);
+/// assert_eq!(shortfalls[0].reason, "completeness is Partial, Complete required");
+/// ```
+pub fn check_requirement(
+    source: Option<&str>,
+    coverage: &CoverageManifest,
+    requirement: &CoverageRequirement,
+) -> Vec<CoverageShortfall> {
+    let mut reasons = Vec::new();
+    if requirement.require_complete && coverage.completeness != Completeness::Complete {
+        reasons.push(format!(
+            "completeness is {}, Complete required",
+            coverage.completeness
+        ));
+    }
+    if let Some(min_score) = requirement.min_score {
+        match coverage.score.or_else(|| coverage_score(&coverage.slices)) {
+            Some(score) if score < min_score => {
+                reasons.push(format!("score {score}/100 is below {min_score}"))
+            }
+            Some(_) => {}
+            None => reasons.push(format!(
+                "no query slices to score, score of {min_score} required"
+            )),
+        }
+    }
+
+    reasons
+        .into_iter()
+        .map(|reason| CoverageShortfall {
+            source: source.map(str::to_string),
+            reason,
+        })
+        .collect()
+}
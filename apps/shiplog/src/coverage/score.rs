@@ -0,0 +1,69 @@
+//! Numeric coverage score derived from query slices.
+
+use shiplog::schema::coverage::CoverageSlice;
+
+use super::windows::window_len_days;
+
+/// Share of a slice's fetch ratio kept when the source flagged the results
+/// as incomplete.
+const INCOMPLETE_RESULTS_FACTOR: f64 = 0.5;
+
+/// Score coverage from 0 to 100.
+///
+/// Each slice contributes its fetched/total ratio, halved when the source
+/// reported `incomplete_results`, weighted by the number of days its window
+/// spans. Returns `None` when there are no slices to score.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::coverage::coverage_score;
+/// use shiplog::schema::coverage::{CoverageSlice, TimeWindow};
+/// use chrono::NaiveDate;
+///
+/// let slice = |until_day, fetched| CoverageSlice {
+///     window: TimeWindow {
+///         since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///         until: NaiveDate::from_ymd_opt(2025, 1, until_day).unwrap(),
+///     },
+///     query: "author:octo".into(),
+///     total_count: 10,
+///     fetched,
+///     incomplete_results: Some(false),
+///     notes: vec![],
+///     source: None,
+/// };
+///
+/// // 30 fully fetched days outweigh 10 half-fetched ones.
+/// let slices = vec![slice(31, 10), slice(11, 5)];
+/// assert_eq!(coverage_score(&slices), Some(88));
+/// assert_eq!(coverage_score(&[]), None);
+/// ```
+pub fn coverage_score(slices: &[CoverageSlice]) -> Option<u8> {
+    if slices.is_empty() {
+        return None;
+    }
+
+    let mut weighted = 0.0;
+    let mut total_weight = 0.0;
+    for slice in slices {
+        let weight = window_len_days(&slice.window).max(1) as f64;
+        weighted += weight * slice_ratio(slice);
+        total_weight += weight;
+    }
+
+    Some((weighted / total_weight * 100.0).round() as u8)
+}
+
+fn slice_ratio(slice: &CoverageSlice) -> f64 {
+    let ratio = if slice.total_count == 0 {
+        1.0
+    } else {
+        (slice.fetched as f64 / slice.total_count as f64).min(1.0)
+    };
+    if slice.incomplete_results.unwrap_or(false) {
+        ratio * INCOMPLETE_RESULTS_FACTOR
+    } else {
+        ratio
+    }
+}
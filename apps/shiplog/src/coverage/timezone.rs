@@ -0,0 +1,172 @@
+//! Reporting timezone for window boundaries and event dates.
+//!
+//! Windows are calendar dates. Without a timezone they are read as UTC days,
+//! so a Friday-evening merge in San Francisco lands on Saturday and can fall
+//! into the next review period. A [`ReportingTimezone`] says whose midnight
+//! the dates mean, and every ingestor and bucketing step uses it.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use shiplog::schema::coverage::TimeWindow;
+
+/// IANA timezone used to interpret window dates. Defaults to UTC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReportingTimezone(Tz);
+
+impl ReportingTimezone {
+    /// UTC, the default when no timezone is configured.
+    pub const UTC: Self = Self(Tz::UTC);
+
+    /// Returns `true` for UTC.
+    pub fn is_utc(&self) -> bool {
+        self.0 == Tz::UTC
+    }
+
+    /// IANA name, e.g. `America/Los_Angeles`.
+    pub fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    /// Name to record in a coverage manifest: `None` for UTC so manifests
+    /// written before timezones existed keep their meaning.
+    pub fn manifest_name(&self) -> Option<String> {
+        (!self.is_utc()).then(|| self.name().to_string())
+    }
+
+    /// Timezone recorded in a coverage manifest, UTC when absent or unknown.
+    pub fn from_manifest(name: Option<&str>) -> Self {
+        name.and_then(|name| name.parse().ok()).unwrap_or_default()
+    }
+
+    /// Calendar date of `instant` in this timezone.
+    pub fn local_date(&self, instant: &DateTime<Utc>) -> NaiveDate {
+        instant.with_timezone(&self.0).date_naive()
+    }
+
+    /// `instant` as wall-clock time in this timezone.
+    pub fn local_time(&self, instant: &DateTime<Utc>) -> DateTime<FixedOffset> {
+        instant.with_timezone(&self.0).fixed_offset()
+    }
+
+    /// Instant of the wall-clock time `local` in this timezone: the first
+    /// occurrence when a DST change repeats it, `None` when one skips it.
+    pub fn resolve_local(&self, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        self.0
+            .from_local_datetime(&local)
+            .earliest()
+            .map(|instant| instant.with_timezone(&Utc))
+    }
+
+    /// Today's date in this timezone.
+    pub fn today(&self) -> NaiveDate {
+        self.local_date(&Utc::now())
+    }
+
+    /// First instant of `date` in this timezone.
+    ///
+    /// Where a DST change skips local midnight, the day starts at the first
+    /// local time that exists.
+    pub fn day_start(&self, date: NaiveDate) -> DateTime<FixedOffset> {
+        let mut local = date.and_hms_opt(0, 0, 0).expect("midnight is valid");
+        loop {
+            if let Some(start) = self.0.from_local_datetime(&local).earliest() {
+                return start.fixed_offset();
+            }
+            local += Duration::minutes(30);
+        }
+    }
+
+    /// Returns `true` when `instant` falls inside `window` read in this
+    /// timezone (`until` is exclusive).
+    pub fn contains(&self, window: &TimeWindow, instant: &DateTime<Utc>) -> bool {
+        let date = self.local_date(instant);
+        window.since <= date && date < window.until
+    }
+
+    /// Lower bound for a provider date filter.
+    ///
+    /// UTC keeps the plain `YYYY-MM-DD` form; other timezones use an RFC 3339
+    /// timestamp with the local offset so the provider filters on local
+    /// midnight.
+    pub fn query_bound(&self, date: NaiveDate) -> String {
+        if self.is_utc() {
+            date.format("%Y-%m-%d").to_string()
+        } else {
+            self.day_start(date)
+                .format("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string()
+        }
+    }
+
+    /// Inclusive `(start, end)` bounds for search syntaxes such as GitHub's
+    /// `merged:START..END`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::coverage::ReportingTimezone;
+    /// use shiplog::schema::coverage::TimeWindow;
+    /// use chrono::NaiveDate;
+    ///
+    /// let window = TimeWindow {
+    ///     since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+    ///     until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+    /// };
+    ///
+    /// let utc = ReportingTimezone::UTC.inclusive_query_range(&window);
+    /// assert_eq!(utc, ("2025-01-01".to_string(), "2025-01-31".to_string()));
+    ///
+    /// let la: ReportingTimezone = "America/Los_Angeles".parse().unwrap();
+    /// assert_eq!(
+    ///     la.inclusive_query_range(&window),
+    ///     (
+    ///         "2025-01-01T00:00:00-08:00".to_string(),
+    ///         "2025-01-31T23:59:59-08:00".to_string(),
+    ///     )
+    /// );
+    /// ```
+    pub fn inclusive_query_range(&self, window: &TimeWindow) -> (String, String) {
+        if self.is_utc() {
+            let end = window.until.pred_opt().unwrap_or(window.until);
+            return (
+                window.since.format("%Y-%m-%d").to_string(),
+                end.format("%Y-%m-%d").to_string(),
+            );
+        }
+        let end = self.day_start(window.until) - Duration::seconds(1);
+        (
+            self.query_bound(window.since),
+            end.format("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+        )
+    }
+}
+
+impl Default for ReportingTimezone {
+    fn default() -> Self {
+        Self::UTC
+    }
+}
+
+impl FromStr for ReportingTimezone {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("utc") {
+            return Ok(Self::UTC);
+        }
+        name.parse::<Tz>().map(Self).map_err(|_| {
+            anyhow!("unknown timezone {name:?}; use an IANA name such as America/Los_Angeles")
+        })
+    }
+}
+
+impl fmt::Display for ReportingTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,230 @@
+//! Platform-aware locations for shiplog's files.
+//!
+//! Runs, their caches, and their redaction alias maps live under the output
+//! root so a run directory stays self-contained. User-level locations (the
+//! config, cache, and data directories) follow each platform's convention:
+//!
+//! | Platform | Config | Cache | Data |
+//! |---|---|---|---|
+//! | Linux and other Unix | `$XDG_CONFIG_HOME/shiplog` | `$XDG_CACHE_HOME/shiplog` | `$XDG_DATA_HOME/shiplog` |
+//! | macOS | `~/Library/Application Support/shiplog` | `~/Library/Caches/shiplog` | `~/Library/Application Support/shiplog` |
+//! | Windows | `%APPDATA%\shiplog` | `%LOCALAPPDATA%\shiplog\cache` | `%LOCALAPPDATA%\shiplog` |
+//!
+//! `SHIPLOG_CONFIG_DIR`, `SHIPLOG_CACHE_DIR`, and `SHIPLOG_DATA_DIR` override
+//! each one. `SHIPLOG_CACHE_DIR` also moves the source API caches out of
+//! `<out>/.cache`, so several output roots can share one cache.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Directory name under each platform location.
+pub const APP_DIR_NAME: &str = "shiplog";
+
+/// Output root used when neither the command line nor the config sets one.
+pub const DEFAULT_OUT_DIR: &str = "./out";
+
+/// Source API cache directory under an output root.
+pub const OUT_CACHE_DIR_NAME: &str = ".cache";
+
+/// Overrides the config directory.
+pub const CONFIG_DIR_ENV: &str = "SHIPLOG_CONFIG_DIR";
+
+/// Overrides the cache directory and the source API cache location.
+pub const CACHE_DIR_ENV: &str = "SHIPLOG_CACHE_DIR";
+
+/// Overrides the data directory.
+pub const DATA_DIR_ENV: &str = "SHIPLOG_DATA_DIR";
+
+/// Directory convention to resolve locations for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Platform {
+    /// XDG base directories.
+    Unix,
+    /// `~/Library`.
+    MacOs,
+    /// Known Folders, read from `APPDATA` and `LOCALAPPDATA`.
+    Windows,
+}
+
+impl Platform {
+    /// The platform shiplog was built for.
+    pub fn current() -> Self {
+        if cfg!(windows) {
+            Self::Windows
+        } else if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else {
+            Self::Unix
+        }
+    }
+}
+
+/// Resolved user-level directories. A location is `None` when neither its
+/// override nor the variables it derives from (such as `HOME`) are set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShiplogDirs {
+    /// User-level configuration.
+    pub config: Option<PathBuf>,
+    /// Disposable caches.
+    pub cache: Option<PathBuf>,
+    /// Persistent user data.
+    pub data: Option<PathBuf>,
+}
+
+impl ShiplogDirs {
+    /// Resolve directories for this platform from the process environment.
+    pub fn resolve() -> Self {
+        Self::resolve_with(Platform::current(), |name| std::env::var_os(name))
+    }
+
+    /// Resolve directories for `platform`, reading variables through `env`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use shiplog::dirs::{Platform, ShiplogDirs};
+    ///
+    /// let dirs = ShiplogDirs::resolve_with(Platform::Unix, |name| match name {
+    ///     "HOME" => Some("/home/octo".into()),
+    ///     "SHIPLOG_CACHE_DIR" => Some("/var/cache/shiplog".into()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(dirs.config, Some(PathBuf::from("/home/octo/.config/shiplog")));
+    /// assert_eq!(dirs.cache, Some(PathBuf::from("/var/cache/shiplog")));
+    /// ```
+    pub fn resolve_with(platform: Platform, env: impl Fn(&str) -> Option<OsString>) -> Self {
+        let var = |name: &str| {
+            env(name)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from)
+        };
+        let (config, cache, data) = match platform {
+            Platform::Unix => {
+                let home = var("HOME");
+                let base = |xdg: &str, fallback: &str| {
+                    var(xdg).or_else(|| home.as_ref().map(|home| home.join(fallback)))
+                };
+                (
+                    base("XDG_CONFIG_HOME", ".config").map(|dir| dir.join(APP_DIR_NAME)),
+                    base("XDG_CACHE_HOME", ".cache").map(|dir| dir.join(APP_DIR_NAME)),
+                    base("XDG_DATA_HOME", ".local/share").map(|dir| dir.join(APP_DIR_NAME)),
+                )
+            }
+            Platform::MacOs => {
+                let library = var("HOME").map(|home| home.join("Library"));
+                let support = library
+                    .as_ref()
+                    .map(|dir| dir.join("Application Support").join(APP_DIR_NAME));
+                (
+                    support.clone(),
+                    library.map(|dir| dir.join("Caches").join(APP_DIR_NAME)),
+                    support,
+                )
+            }
+            Platform::Windows => {
+                let local = var("LOCALAPPDATA").map(|dir| dir.join(APP_DIR_NAME));
+                (
+                    var("APPDATA").map(|dir| dir.join(APP_DIR_NAME)),
+                    local.as_ref().map(|dir| dir.join("cache")),
+                    local,
+                )
+            }
+        };
+        Self {
+            config: var(CONFIG_DIR_ENV).or(config),
+            cache: var(CACHE_DIR_ENV).or(cache),
+            data: var(DATA_DIR_ENV).or(data),
+        }
+    }
+}
+
+/// Source API cache directory for runs under `out_root`: `SHIPLOG_CACHE_DIR`
+/// when set, otherwise `<out_root>/.cache`.
+pub fn api_cache_dir(out_root: &Path) -> PathBuf {
+    api_cache_dir_with(out_root, |name| std::env::var_os(name))
+}
+
+fn api_cache_dir_with(out_root: &Path, env: impl Fn(&str) -> Option<OsString>) -> PathBuf {
+    env(CACHE_DIR_ENV)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| out_root.join(OUT_CACHE_DIR_NAME))
+}
+
+/// The user's home directory: `HOME`, or `USERPROFILE` on Windows.
+pub fn home_dir() -> Option<PathBuf> {
+    let name = match Platform::current() {
+        Platform::Windows => "USERPROFILE",
+        Platform::Unix | Platform::MacOs => "HOME",
+    };
+    std::env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The XDG config home (`XDG_CONFIG_HOME`, or `~/.config`), which holds
+/// other tools' user-level files such as systemd units.
+pub fn xdg_config_home() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".config")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<OsString> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| OsString::from(value))
+        }
+    }
+
+    #[test]
+    fn resolves_each_platform_convention_and_overrides() {
+        let unix = ShiplogDirs::resolve_with(
+            Platform::Unix,
+            env(&[
+                ("HOME", "/home/o"),
+                ("XDG_DATA_HOME", "/data"),
+                ("XDG_CACHE_HOME", ""),
+            ]),
+        );
+        assert_eq!(unix.cache, Some(PathBuf::from("/home/o/.cache/shiplog")));
+        assert_eq!(unix.data, Some(PathBuf::from("/data/shiplog")));
+
+        let mac = ShiplogDirs::resolve_with(
+            Platform::MacOs,
+            env(&[("HOME", "/Users/o"), (CONFIG_DIR_ENV, "/etc/shiplog")]),
+        );
+        assert_eq!(mac.config, Some(PathBuf::from("/etc/shiplog")));
+        assert_eq!(
+            mac.cache,
+            Some(PathBuf::from("/Users/o/Library/Caches/shiplog"))
+        );
+
+        let windows = ShiplogDirs::resolve_with(
+            Platform::Windows,
+            env(&[("APPDATA", "C:/Roaming"), ("LOCALAPPDATA", "C:/Local")]),
+        );
+        assert_eq!(windows.config, Some(PathBuf::from("C:/Roaming/shiplog")));
+        assert_eq!(windows.cache, Some(PathBuf::from("C:/Local/shiplog/cache")));
+
+        assert_eq!(
+            ShiplogDirs::resolve_with(Platform::Unix, env(&[])),
+            ShiplogDirs::default()
+        );
+        assert_eq!(
+            api_cache_dir_with(Path::new("out"), env(&[(CACHE_DIR_ENV, "/shared")])),
+            PathBuf::from("/shared")
+        );
+        assert_eq!(
+            api_cache_dir_with(Path::new("out"), env(&[])),
+            Path::new("out").join(".cache")
+        );
+    }
+}
@@ -1,14 +1,162 @@
-//! JSON writers for canonical shiplog artifacts.
+//! JSON readers and writers for canonical shiplog artifacts.
 //!
-//! Emits line-delimited events (`ledger.events.jsonl`) and pretty-formatted
-//! coverage manifests (`coverage.manifest.json`).
+//! Emits line-delimited events (`ledger.events.jsonl`), pretty-formatted
+//! coverage manifests (`coverage.manifest.json`), per-source coverage gaps
+//! (`coverage.gaps.json`), and the needs-attention list
+//! (`needs_attention.json`). Event ledgers are read and
+//! written one line at a time, through [`EventReader`] and [`EventWriter`], so
+//! multi-year ledgers never sit in memory as a single string.
 
 use anyhow::{Context, Result};
+use shiplog::attention::NeedsAttention;
+use shiplog::coverage::CoverageGaps;
 use shiplog::schema::coverage::CoverageManifest;
 use shiplog::schema::event::EventEnvelope;
-use std::io::Write;
+use std::borrow::Borrow;
+use std::io::{BufRead, BufWriter, Write};
 use std::path::Path;
 
+/// Stream events from a JSONL reader.
+///
+/// Blank lines are skipped. Each item is parsed lazily, and parse errors name
+/// the 1-based line they came from. Iteration should stop at the first error.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use shiplog::engine::read_events;
+/// use std::io::BufReader;
+///
+/// # fn example() -> anyhow::Result<()> {
+/// let file = std::fs::File::open("ledger.events.jsonl")?;
+/// for event in read_events(BufReader::new(file)) {
+///     println!("{}", event?.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_events<R: BufRead>(reader: R) -> EventReader<R> {
+    EventReader::new(reader)
+}
+
+/// Iterator over the events of a JSONL ledger, parsed one line at a time.
+///
+/// Only the current line is buffered, so memory stays flat however large the
+/// ledger is. Returned by [`read_events`]; a named type so callers can store
+/// the reader in a struct or return it.
+pub struct EventReader<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> EventReader<R> {
+    /// Read events from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    /// 1-based number of the last line read, blank lines included.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Next non-blank line, trimmed and unparsed, with its 1-based number.
+    ///
+    /// The iterator parses what this returns; callers that report bad lines
+    /// with their own error type read lines through it instead.
+    pub fn next_line(&mut self) -> Option<std::io::Result<(usize, &str)>> {
+        loop {
+            self.line.clear();
+            self.line_number += 1;
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) if self.line.trim().is_empty() => continue,
+                Ok(_) => return Some(Ok((self.line_number, self.line.trim()))),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for EventReader<R> {
+    type Item = Result<EventEnvelope>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(match self.next_line()? {
+            Ok((line_number, line)) => serde_json::from_str(line)
+                .with_context(|| format!("parse event line {line_number}")),
+            Err(err) => Err(err).with_context(|| format!("read event line {}", self.line_number)),
+        })
+    }
+}
+
+/// Stream events to a JSONL writer, one event per line.
+///
+/// Returns the number of events written. Callers writing to files should wrap
+/// the handle in a [`BufWriter`].
+pub fn write_events<W, I>(writer: W, events: I) -> Result<usize>
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: Borrow<EventEnvelope>,
+{
+    let mut writer = EventWriter::new(writer);
+    for ev in events {
+        writer.write(ev.borrow())?;
+    }
+    writer.finish()
+}
+
+/// Incremental JSONL event writer, for producers that generate events one
+/// at a time instead of holding them in a collection.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use shiplog::engine::EventWriter;
+/// use std::io::BufWriter;
+///
+/// # fn example(events: impl Iterator<Item = shiplog::schema::event::EventEnvelope>) -> anyhow::Result<()> {
+/// let file = std::fs::File::create("ledger.events.jsonl")?;
+/// let mut writer = EventWriter::new(BufWriter::new(file));
+/// for event in events {
+///     writer.write(&event)?;
+/// }
+/// let written = writer.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct EventWriter<W: Write> {
+    writer: W,
+    written: usize,
+}
+
+impl<W: Write> EventWriter<W> {
+    /// Write events to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer, written: 0 }
+    }
+
+    /// Append one event as a JSON line.
+    pub fn write(&mut self, event: &EventEnvelope) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, event).context("serialize event")?;
+        self.writer.write_all(b"\n")?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Flush the underlying writer and return the number of events written.
+    pub fn finish(mut self) -> Result<usize> {
+        self.writer.flush()?;
+        Ok(self.written)
+    }
+}
+
 /// Write canonical events to JSONL.
 ///
 /// JSONL is the right primitive:
@@ -29,12 +177,8 @@ use std::path::Path;
 /// # }
 /// ```
 pub fn write_events_jsonl(path: &Path, events: &[EventEnvelope]) -> Result<()> {
-    let mut f = std::fs::File::create(path).with_context(|| format!("create {path:?}"))?;
-    for ev in events {
-        let line = serde_json::to_string(ev).context("serialize event")?;
-        f.write_all(line.as_bytes())?;
-        f.write_all(b"\n")?;
-    }
+    let f = std::fs::File::create(path).with_context(|| format!("create {path:?}"))?;
+    write_events(BufWriter::new(f), events).with_context(|| format!("write {path:?}"))?;
     Ok(())
 }
 
@@ -50,6 +194,7 @@ pub fn write_events_jsonl(path: &Path, events: &[EventEnvelope]) -> Result<()> {
 /// use std::path::Path;
 ///
 /// let cov = CoverageManifest {
+///     schema_version: SchemaVersion::CURRENT,
 ///     run_id: RunId::now("example"),
 ///     generated_at: Utc::now(),
 ///     user: "octocat".into(),
@@ -62,6 +207,9 @@ pub fn write_events_jsonl(path: &Path, events: &[EventEnvelope]) -> Result<()> {
 ///     slices: vec![],
 ///     warnings: vec![],
 ///     completeness: Completeness::Complete,
+///     score: None,
+///     timezone: None,
+///     budget: vec![],
 /// };
 /// write_coverage_manifest(Path::new("coverage.manifest.json"), &cov).unwrap();
 /// ```
@@ -71,6 +219,20 @@ pub fn write_coverage_manifest(path: &Path, cov: &CoverageManifest) -> Result<()
     Ok(())
 }
 
+/// Write per-source coverage gaps as pretty-printed JSON.
+pub fn write_coverage_gaps(path: &Path, gaps: &CoverageGaps) -> Result<()> {
+    let text = serde_json::to_string_pretty(gaps).context("serialize coverage gaps")?;
+    std::fs::write(path, text).with_context(|| format!("write {path:?}"))?;
+    Ok(())
+}
+
+/// Write the needs-attention list as pretty-printed JSON.
+pub fn write_needs_attention(path: &Path, attention: &NeedsAttention) -> Result<()> {
+    let text = serde_json::to_string_pretty(attention).context("serialize needs attention")?;
+    std::fs::write(path, text).with_context(|| format!("write {path:?}"))?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,6 +244,7 @@ mod tests {
 
     fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["github", "pr", repo, &number.to_string()]),
             kind: EventKind::PullRequest,
             occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -119,11 +282,15 @@ mod tests {
                 url: Some("https://api.github.com/...".into()),
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
     fn test_coverage() -> CoverageManifest {
         CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId("test_run".into()),
             generated_at: Utc.timestamp_opt(0, 0).unwrap(),
             user: "tester".into(),
@@ -136,6 +303,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         }
     }
 
@@ -159,6 +329,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn streaming_read_matches_written_events_and_skips_blank_lines() {
+        let events = vec![
+            pr_event("acme/foo", 1, "First PR"),
+            pr_event("acme/foo", 2, "Second PR"),
+        ];
+        let mut buf = Vec::new();
+        assert_eq!(write_events(&mut buf, &events).unwrap(), 2);
+        buf.extend_from_slice(b"\n   \n");
+
+        let read: Vec<EventEnvelope> = read_events(buf.as_slice()).collect::<Result<_>>().unwrap();
+        assert_eq!(read, events);
+    }
+
+    #[test]
+    fn streaming_read_reports_the_failing_line() {
+        let mut buf = Vec::new();
+        write_events(&mut buf, [pr_event("acme/foo", 1, "First PR")]).unwrap();
+        buf.extend_from_slice(b"\n{not json}\n");
+
+        let mut events = read_events(buf.as_slice());
+        assert!(events.next().unwrap().is_ok());
+        let err = events.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "parse event line 3");
+        assert_eq!(events.line_number(), 3);
+    }
+
+    #[test]
+    fn event_writer_counts_incremental_writes() {
+        let mut buf = Vec::new();
+        let mut writer = EventWriter::new(&mut buf);
+        writer.write(&pr_event("acme/foo", 1, "First PR")).unwrap();
+        writer.write(&pr_event("acme/foo", 2, "Second PR")).unwrap();
+        assert_eq!(writer.finish().unwrap(), 2);
+        assert_eq!(read_events(buf.as_slice()).count(), 2);
+    }
+
     #[test]
     fn coverage_manifest_round_trip() {
         let dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,199 @@
+//! Copies local evidence files into the run directory.
+//!
+//! Manual events may point at screenshots, dashboard exports, or documents on
+//! disk. Before the ledger is written, each file is checksummed and copied to
+//! `evidence/<sha256 prefix>-<file name>`, and the event is rewritten to point
+//! at that run-relative copy. The copy then lands in the internal bundle
+//! manifest like any other run file.
+
+use crate::bundle::DIR_EVIDENCE;
+use anyhow::{Context, Result, bail};
+use sha2::{Digest, Sha256};
+use shiplog::schema::event::{EventEnvelope, EventPayload, EvidenceFile};
+use std::path::Path;
+
+/// Hex digits of the checksum kept in the copied file name.
+const NAME_PREFIX_LEN: usize = 12;
+
+/// Copy every evidence file referenced by `events` into `out_dir/evidence/`
+/// and point the events at the copies. Returns how many files were staged.
+///
+/// A file already staged in this run, present under `out_dir` with contents
+/// matching its recorded checksum, is left alone, so re-rendering a run is a
+/// no-op. Every other path is read as a source file, even one that happens to
+/// start with `evidence/`. A file whose contents no longer match a recorded
+/// checksum is an error rather than a silent substitution.
+pub(super) fn stage_evidence(out_dir: &Path, events: &mut [EventEnvelope]) -> Result<usize> {
+    let mut staged = 0;
+    for event in events {
+        let EventPayload::Manual(manual) = &mut event.payload else {
+            continue;
+        };
+        for evidence in &mut manual.evidence {
+            if is_staged(out_dir, evidence) {
+                continue;
+            }
+            stage_file(out_dir, evidence)
+                .with_context(|| format!("stage evidence for event {}", event.id))?;
+            staged += 1;
+        }
+    }
+    Ok(staged)
+}
+
+fn is_staged(out_dir: &Path, evidence: &EvidenceFile) -> bool {
+    let Some(expected) = &evidence.sha256 else {
+        return false;
+    };
+    if !evidence
+        .path
+        .strip_prefix(DIR_EVIDENCE)
+        .is_some_and(|rest| rest.starts_with('/'))
+    {
+        return false;
+    }
+    std::fs::read(out_dir.join(&evidence.path))
+        .is_ok_and(|bytes| hex::encode(Sha256::digest(&bytes)) == *expected)
+}
+
+fn stage_file(out_dir: &Path, evidence: &mut EvidenceFile) -> Result<()> {
+    let source = Path::new(&evidence.path);
+    let bytes = std::fs::read(source).with_context(|| format!("read evidence file {source:?}"))?;
+    let sha256 = hex::encode(Sha256::digest(&bytes));
+    if let Some(expected) = &evidence.sha256
+        && *expected != sha256
+    {
+        bail!("evidence file {source:?} changed: expected sha256 {expected}, found {sha256}");
+    }
+    let name = source
+        .file_name()
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("evidence path {source:?} has no file name"))?;
+
+    let dir = out_dir.join(DIR_EVIDENCE);
+    std::fs::create_dir_all(&dir).with_context(|| format!("create {dir:?}"))?;
+    let file_name = format!("{}-{name}", &sha256[..NAME_PREFIX_LEN]);
+    let dest = dir.join(&file_name);
+    if !dest.exists() {
+        std::fs::write(&dest, &bytes).with_context(|| format!("copy evidence to {dest:?}"))?;
+    }
+
+    evidence.path = format!("{DIR_EVIDENCE}/{file_name}");
+    evidence.sha256 = Some(sha256);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shiplog::fixtures::{FixtureSpec, generate};
+    use shiplog::schema::event::{EventKind, ManualEvent, ManualEventType};
+
+    fn manual_event(evidence: Vec<EvidenceFile>) -> EventEnvelope {
+        let mut event = generate(&FixtureSpec {
+            events: 1,
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events
+        .remove(0);
+        event.kind = EventKind::Manual;
+        event.payload = EventPayload::Manual(ManualEvent {
+            event_type: ManualEventType::Note,
+            title: "Latency review".into(),
+            description: None,
+            started_at: None,
+            ended_at: None,
+            impact: None,
+            metrics: Vec::new(),
+            effort: None,
+            evidence,
+        });
+        event
+    }
+
+    fn evidence(event: &EventEnvelope) -> &[EvidenceFile] {
+        match &event.payload {
+            EventPayload::Manual(manual) => &manual.evidence,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn copies_files_under_checksum_names_and_rewrites_paths() {
+        let tmp = tempfile::tempdir().unwrap();
+        let source = tmp.path().join("dashboard.png");
+        std::fs::write(&source, "hello world").unwrap();
+        let out_dir = tmp.path().join("run");
+        let mut events = vec![manual_event(vec![EvidenceFile {
+            path: source.to_string_lossy().into_owned(),
+            label: Some("Dashboard".into()),
+            sha256: None,
+        }])];
+
+        assert_eq!(stage_evidence(&out_dir, &mut events).unwrap(), 1);
+        let staged = &evidence(&events[0])[0];
+        assert_eq!(staged.path, "evidence/b94d27b9934d-dashboard.png");
+        assert_eq!(
+            staged.sha256.as_deref(),
+            Some("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9")
+        );
+        assert_eq!(
+            std::fs::read_to_string(out_dir.join(&staged.path)).unwrap(),
+            "hello world"
+        );
+
+        assert_eq!(stage_evidence(&out_dir, &mut events).unwrap(), 0);
+    }
+
+    #[test]
+    fn only_verified_copies_under_the_run_count_as_staged() {
+        let tmp = tempfile::tempdir().unwrap();
+        let out_dir = tmp.path().join("run");
+        std::fs::create_dir_all(out_dir.join(DIR_EVIDENCE)).unwrap();
+        std::fs::write(out_dir.join("evidence/shot.png"), "hello world").unwrap();
+        let sha256 = hex::encode(Sha256::digest("hello world"));
+        let file = |path: &str, sha256: Option<&str>| EvidenceFile {
+            path: path.into(),
+            label: None,
+            sha256: sha256.map(str::to_string),
+        };
+
+        assert!(is_staged(
+            &out_dir,
+            &file("evidence/shot.png", Some(&sha256))
+        ));
+        assert!(!is_staged(&out_dir, &file("evidence/shot.png", None)));
+        assert!(!is_staged(
+            &out_dir,
+            &file("evidence/other.png", Some(&sha256))
+        ));
+        std::fs::write(out_dir.join("evidence/shot.png"), "edited").unwrap();
+        assert!(!is_staged(
+            &out_dir,
+            &file("evidence/shot.png", Some(&sha256))
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_files_and_changed_contents() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut missing = vec![manual_event(vec![EvidenceFile {
+            path: tmp.path().join("gone.pdf").to_string_lossy().into_owned(),
+            label: None,
+            sha256: None,
+        }])];
+        let err = stage_evidence(tmp.path(), &mut missing).unwrap_err();
+        assert!(format!("{err:#}").contains("read evidence file"));
+
+        let source = tmp.path().join("notes.md");
+        std::fs::write(&source, "edited").unwrap();
+        let mut changed = vec![manual_event(vec![EvidenceFile {
+            path: source.to_string_lossy().into_owned(),
+            label: None,
+            sha256: Some("0".repeat(64)),
+        }])];
+        let err = stage_evidence(tmp.path(), &mut changed).unwrap_err();
+        assert!(format!("{err:#}").contains("changed: expected sha256"));
+    }
+}
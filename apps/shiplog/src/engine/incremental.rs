@@ -0,0 +1,215 @@
+//! Incremental collection into an existing run.
+
+use super::{Engine, RunOutputs};
+use crate::bundle::RunArtifactPaths;
+use crate::coverage::incremental::{is_same_source, is_skip_warning_for};
+use crate::coverage::{INCREMENTAL_FAILURE_WARNING_PREFIX, SourceDelta, incremental_deltas};
+use crate::ingest::json::JsonIngestor;
+use crate::merge::ConflictResolution;
+use anyhow::{Context, Result};
+use shiplog::ports::{IngestOutput, Ingestor};
+use shiplog::schema::bundle::BundleProfile;
+use shiplog::schema::coverage::{Completeness, TimeWindow};
+use std::path::Path;
+
+/// Result of [`Engine::collect_incremental`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncrementalOutputs {
+    /// Artifacts of the refreshed run.
+    pub outputs: RunOutputs,
+    /// What each source had covered and what was fetched for it.
+    pub deltas: Vec<SourceDelta>,
+    /// Events in the ledger before the delta was merged.
+    pub previous_events: usize,
+    /// Events in the ledger afterwards.
+    pub events: usize,
+    /// Delta windows that failed, as `source since..until: error`.
+    pub failures: Vec<String>,
+    /// Sources the previous run collected that were not requested now, with
+    /// the parts of the run's window they leave uncovered.
+    pub unrequested: Vec<SourceDelta>,
+}
+
+impl Engine<'_> {
+    /// Fetch only what `run_dir` has not covered yet and merge it into that run.
+    ///
+    /// Reads the run's `coverage.manifest.json` and `ledger.events.jsonl`,
+    /// works out each source's uncovered windows with
+    /// [`incremental_deltas`](crate::coverage::incremental_deltas), and calls
+    /// `fetch` once per source and uncovered window. The new events are
+    /// deduplicated against the ledger, and the run is refreshed in place, so
+    /// curated workstreams and the run's user label are kept. A failed fetch becomes a coverage warning
+    /// and marks the run partial instead of aborting; the window is retried
+    /// next time.
+    ///
+    /// The run's window grows to span both the previous and the requested
+    /// window, and deltas are worked out over all of it: days between two
+    /// disjoint windows are fetched, and a source the previous run did not
+    /// collect is fetched for the whole span. A source the previous run
+    /// collected but `sources` leaves out is not fetched; the parts of the
+    /// span it has not covered are recorded as failed windows, so the run is
+    /// partial until a later run requests that source again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use shiplog::engine::Engine;
+    /// use shiplog::ports::{IngestOutput, Renderer, WorkstreamClusterer, Redactor};
+    /// use shiplog::schema::bundle::BundleProfile;
+    /// use shiplog::schema::coverage::TimeWindow;
+    /// use chrono::NaiveDate;
+    /// use std::path::Path;
+    ///
+    /// # fn example(
+    /// #     renderer: &dyn Renderer,
+    /// #     clusterer: &dyn WorkstreamClusterer,
+    /// #     redactor: &dyn Redactor,
+    /// #     fetch_github: impl Fn(&TimeWindow) -> anyhow::Result<IngestOutput>,
+    /// # ) -> anyhow::Result<()> {
+    /// let engine = Engine::new(renderer, clusterer, redactor);
+    /// let window = TimeWindow {
+    ///     since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+    ///     until: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+    /// };
+    /// let result = engine.collect_incremental(
+    ///     Path::new("./out/existing_run"),
+    ///     &window,
+    ///     &["github"],
+    ///     false,
+    ///     &BundleProfile::Internal,
+    ///     |_source, delta| fetch_github(delta),
+    /// )?;
+    /// println!("{} new events", result.events - result.previous_events);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "pipeline",
+        skip_all,
+        fields(mode = "incremental", out_dir = %run_dir.display())
+    )]
+    pub fn collect_incremental<F>(
+        &self,
+        run_dir: &Path,
+        window: &TimeWindow,
+        sources: &[&str],
+        zip: bool,
+        bundle_profile: &BundleProfile,
+        mut fetch: F,
+    ) -> Result<IncrementalOutputs>
+    where
+        F: FnMut(&str, &TimeWindow) -> Result<IngestOutput>,
+    {
+        let paths = RunArtifactPaths::new(run_dir);
+        let mut previous = JsonIngestor {
+            events_path: paths.ledger_events(),
+            coverage_path: paths.coverage_manifest(),
+        }
+        .ingest()
+        .with_context(|| format!("load previous run {}", run_dir.display()))?;
+        let previous_events = previous.events.len();
+        let merged_window = TimeWindow {
+            since: previous.coverage.window.since.min(window.since),
+            until: previous.coverage.window.until.max(window.until),
+        };
+        let deltas = incremental_deltas(&previous.coverage, &merged_window, sources);
+        let unrequested_sources: Vec<String> = previous
+            .coverage
+            .sources
+            .iter()
+            .filter(|name| !sources.iter().any(|source| is_same_source(name, source)))
+            .cloned()
+            .collect();
+        let unrequested_sources: Vec<&str> =
+            unrequested_sources.iter().map(String::as_str).collect();
+        let unrequested: Vec<SourceDelta> =
+            incremental_deltas(&previous.coverage, &merged_window, &unrequested_sources)
+                .into_iter()
+                .filter(|delta| !delta.is_current())
+                .collect();
+        // Earlier failures are retried below or recorded again for sources
+        // left out, and conflict counts from the previous merge describe that
+        // merge, not this one.
+        previous.coverage.warnings.retain(|warning| {
+            !warning.starts_with(INCREMENTAL_FAILURE_WARNING_PREFIX)
+                && !warning.starts_with("Resolved ")
+        });
+
+        let mut fetched = Vec::new();
+        let mut failures = Vec::new();
+        for delta in &deltas {
+            let failed_before = failures.len();
+            for part in &delta.uncovered {
+                match fetch(&delta.source, part) {
+                    Ok(output) => fetched.push(output),
+                    Err(err) => failures.push(format!(
+                        "{} {}..{}: {err:#}",
+                        delta.source, part.since, part.until
+                    )),
+                }
+            }
+            if failures.len() == failed_before && !delta.is_current() {
+                previous
+                    .coverage
+                    .warnings
+                    .retain(|warning| !is_skip_warning_for(warning, &delta.source));
+            }
+        }
+        tracing::info!(
+            fetched = fetched.len(),
+            failed = failures.len(),
+            "collected incremental windows"
+        );
+
+        // Every source re-queried the windows of an incomplete run's partial
+        // slices, so those slices are superseded unless something failed or
+        // a source was left out.
+        if failures.is_empty()
+            && unrequested.is_empty()
+            && previous.coverage.completeness != Completeness::Complete
+        {
+            previous.coverage.slices.retain(|slice| !slice.is_partial());
+            previous.coverage.completeness = Completeness::Complete;
+        }
+        let run_id = previous.coverage.run_id.clone();
+        let mut inputs = vec![previous];
+        inputs.extend(fetched);
+        let mut merged = self
+            .merge(inputs, ConflictResolution::PreferMostRecent)
+            .context("merge incremental events into the ledger")?;
+        merged.coverage.run_id = run_id;
+        merged.coverage.window = merged_window.clone();
+        for failure in &failures {
+            merged
+                .coverage
+                .warnings
+                .push(format!("{INCREMENTAL_FAILURE_WARNING_PREFIX}{failure}"));
+        }
+        for delta in &unrequested {
+            for part in &delta.uncovered {
+                merged.coverage.warnings.push(format!(
+                    "{INCREMENTAL_FAILURE_WARNING_PREFIX}{} {}..{}: not requested in this run",
+                    delta.source, part.since, part.until
+                ));
+            }
+        }
+        if !failures.is_empty() || !unrequested.is_empty() {
+            merged.coverage.completeness = Completeness::Partial;
+        }
+        let events = merged.events.len();
+        let user = merged.coverage.user.clone();
+
+        let window_label = format!("{}..{}", merged_window.since, merged_window.until);
+        let outputs = self
+            .refresh(merged, &user, &window_label, run_dir, zip, bundle_profile)
+            .context("refresh run with incremental events")?;
+        Ok(IncrementalOutputs {
+            outputs,
+            deltas,
+            previous_events,
+            events,
+            failures,
+            unrequested,
+        })
+    }
+}
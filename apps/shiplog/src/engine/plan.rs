@@ -0,0 +1,69 @@
+//! Dry-run collection plans.
+
+use super::Engine;
+use anyhow::{Context, Result};
+use shiplog::ports::{IngestPlan, Ingestor};
+
+/// Result of [`Engine::plan`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectionPlan {
+    /// Plans for the sources that query an API.
+    pub sources: Vec<IngestPlan>,
+    /// Sources that read local data and cost no API requests.
+    pub local: Vec<String>,
+}
+
+impl CollectionPlan {
+    /// Requests the whole collection is expected to send.
+    pub fn estimated_requests(&self) -> u64 {
+        self.sources
+            .iter()
+            .map(IngestPlan::estimated_requests)
+            .sum()
+    }
+
+    /// Queries, across all sources, that the API cache would answer.
+    pub fn cached_queries(&self) -> usize {
+        self.sources.iter().map(IngestPlan::cached_queries).sum()
+    }
+}
+
+impl Engine<'_> {
+    /// Ask each ingestor what it would fetch, without fetching anything.
+    ///
+    /// `ingestors` pairs a source name with its ingestor. Sources whose
+    /// [`Ingestor::plan`] returns `None` are listed in
+    /// [`CollectionPlan::local`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use shiplog::engine::Engine;
+    /// use shiplog::ports::{Ingestor, Renderer, WorkstreamClusterer, Redactor};
+    ///
+    /// # fn example(
+    /// #     renderer: &dyn Renderer,
+    /// #     clusterer: &dyn WorkstreamClusterer,
+    /// #     redactor: &dyn Redactor,
+    /// #     github: &dyn Ingestor,
+    /// # ) -> anyhow::Result<()> {
+    /// let engine = Engine::new(renderer, clusterer, redactor);
+    /// let plan = engine.plan(&[("github", github)])?;
+    /// println!("~{} requests", plan.estimated_requests());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn plan(&self, ingestors: &[(&str, &dyn Ingestor)]) -> Result<CollectionPlan> {
+        let mut plan = CollectionPlan::default();
+        for (name, ingestor) in ingestors {
+            match ingestor
+                .plan()
+                .with_context(|| format!("plan {name} collection"))?
+            {
+                Some(source) => plan.sources.push(source),
+                None => plan.local.push((*name).to_string()),
+            }
+        }
+        Ok(plan)
+    }
+}
@@ -0,0 +1,635 @@
+//! Synthetic ledgers for benchmarks and demos.
+//!
+//! [`generate`] builds an event ledger and a matching coverage manifest from a
+//! [`FixtureSpec`]: how many events, spread over how many repositories, with
+//! what mix of kinds and what spread of dates. The same spec always produces
+//! the same events, so benchmark runs compare like with like. Repositories,
+//! titles, and logins come from fixed word lists; nothing is real data.
+
+use std::fmt;
+use std::io::BufWriter;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+
+use crate::coverage::{coverage_score, month_windows};
+use crate::engine::write_events;
+use crate::ids::{EventId, RunId};
+use crate::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use crate::schema::event::{
+    Actor, EventEnvelope, EventKind, EventPayload, IssueEvent, IssueState, Link, ManualEvent,
+    ManualEventType, PullRequestEvent, PullRequestState, RepoRef, RepoVisibility, ReviewEvent,
+    SourceRef, SourceSystem,
+};
+use crate::schema::version::SchemaVersion;
+
+const ORGS: [&str; 3] = ["acme", "globex", "initech"];
+const REPOS: [&str; 12] = [
+    "widgets",
+    "billing",
+    "search",
+    "gateway",
+    "ledger",
+    "portal",
+    "scheduler",
+    "notifier",
+    "catalog",
+    "auth",
+    "metrics",
+    "docs",
+];
+const VERBS: [&str; 8] = [
+    "Add", "Fix", "Refactor", "Speed up", "Document", "Remove", "Harden", "Migrate",
+];
+const TOPICS: [&str; 10] = [
+    "retry handling",
+    "pagination",
+    "cache invalidation",
+    "login flow",
+    "CSV export",
+    "rate limiting",
+    "schema migration",
+    "error messages",
+    "request metrics",
+    "config loading",
+];
+const PATHS: [&str; 6] = [
+    "src/lib.rs",
+    "src/api/handlers.rs",
+    "src/db/queries.rs",
+    "src/config.rs",
+    "tests/integration.rs",
+    "docs/README.md",
+];
+const LABELS: [&str; 4] = ["bug", "enhancement", "tech-debt", "customer"];
+const REVIEW_STATES: [&str; 3] = ["approved", "changes_requested", "commented"];
+const MANUAL_TYPES: [ManualEventType; 5] = [
+    ManualEventType::Incident,
+    ManualEventType::Design,
+    ManualEventType::Mentoring,
+    ManualEventType::Launch,
+    ManualEventType::Migration,
+];
+
+/// Shape of a synthetic ledger.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::fixtures::{FixtureSpec, generate};
+///
+/// let spec = FixtureSpec {
+///     events: 50,
+///     repos: 3,
+///     ..FixtureSpec::default()
+/// };
+/// let fixture = generate(&spec).unwrap();
+/// assert_eq!(fixture.events.len(), 50);
+/// assert_eq!(fixture.events, generate(&spec).unwrap().events);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixtureSpec {
+    /// Login every event is attributed to.
+    pub user: String,
+    /// Window the events fall in.
+    pub window: TimeWindow,
+    /// Number of events to generate.
+    pub events: usize,
+    /// Number of repositories the events spread over. Earlier repositories
+    /// get more events, as real activity clusters in a few places.
+    pub repos: usize,
+    /// Relative weight of each event kind.
+    pub mix: EventMix,
+    /// How event dates spread over the window.
+    pub dates: DateSpread,
+    /// Monthly coverage slices to mark as not fully fetched.
+    pub incomplete_months: usize,
+    /// Seed for the generator. Different seeds give different ledgers of
+    /// the same shape.
+    pub seed: u64,
+}
+
+impl Default for FixtureSpec {
+    fn default() -> Self {
+        Self {
+            user: "demo-dev".to_string(),
+            window: TimeWindow {
+                since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap_or_default(),
+                until: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap_or_default(),
+            },
+            events: 200,
+            repos: 6,
+            mix: EventMix::default(),
+            dates: DateSpread::Weekdays,
+            incomplete_months: 0,
+            seed: 0,
+        }
+    }
+}
+
+/// Relative weights of the generated event kinds.
+///
+/// Parses from and displays as `pr=6,review=3,issue=2,manual=1`. Kinds left
+/// out of the string get weight 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventMix {
+    /// Pull requests.
+    pub pull_requests: u32,
+    /// Code reviews.
+    pub reviews: u32,
+    /// Tracker issues.
+    pub issues: u32,
+    /// Manual events such as incidents and design work.
+    pub manual: u32,
+}
+
+impl Default for EventMix {
+    fn default() -> Self {
+        Self {
+            pull_requests: 6,
+            reviews: 3,
+            issues: 2,
+            manual: 1,
+        }
+    }
+}
+
+impl EventMix {
+    fn total(&self) -> u64 {
+        [self.pull_requests, self.reviews, self.issues, self.manual]
+            .iter()
+            .map(|weight| u64::from(*weight))
+            .sum()
+    }
+
+    fn pick(&self, rng: &mut Rng) -> EventKind {
+        let mut roll = rng.below(self.total());
+        for (weight, kind) in [
+            (self.pull_requests, EventKind::PullRequest),
+            (self.reviews, EventKind::Review),
+            (self.issues, EventKind::Issue),
+            (self.manual, EventKind::Manual),
+        ] {
+            if roll < u64::from(weight) {
+                return kind;
+            }
+            roll -= u64::from(weight);
+        }
+        EventKind::PullRequest
+    }
+}
+
+impl FromStr for EventMix {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let mut mix = Self {
+            pull_requests: 0,
+            reviews: 0,
+            issues: 0,
+            manual: 0,
+        };
+        for part in text
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            let (name, weight) = part
+                .split_once('=')
+                .ok_or_else(|| anyhow!("event mix entry `{part}` must look like `pr=6`"))?;
+            let weight: u32 = weight
+                .trim()
+                .parse()
+                .with_context(|| format!("event mix weight `{weight}` is not a number"))?;
+            match name.trim() {
+                "pr" => mix.pull_requests = weight,
+                "review" => mix.reviews = weight,
+                "issue" => mix.issues = weight,
+                "manual" => mix.manual = weight,
+                other => {
+                    bail!("unknown event kind `{other}`; expected pr, review, issue, or manual")
+                }
+            }
+        }
+        if mix.total() == 0 {
+            bail!("event mix `{text}` needs at least one non-zero weight");
+        }
+        Ok(mix)
+    }
+}
+
+impl fmt::Display for EventMix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pr={},review={},issue={},manual={}",
+            self.pull_requests, self.reviews, self.issues, self.manual
+        )
+    }
+}
+
+/// How event dates spread over the window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateSpread {
+    /// Any day, evenly.
+    Uniform,
+    /// Monday to Friday only.
+    Weekdays,
+    /// Clustered around a few busy weeks, with quiet stretches between.
+    Bursty,
+}
+
+impl FromStr for DateSpread {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        match text {
+            "uniform" => Ok(Self::Uniform),
+            "weekdays" => Ok(Self::Weekdays),
+            "bursty" => Ok(Self::Bursty),
+            other => bail!("unknown date spread `{other}`; expected uniform, weekdays, or bursty"),
+        }
+    }
+}
+
+impl fmt::Display for DateSpread {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uniform => f.write_str("uniform"),
+            Self::Weekdays => f.write_str("weekdays"),
+            Self::Bursty => f.write_str("bursty"),
+        }
+    }
+}
+
+/// A generated ledger and its coverage manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fixture {
+    /// Events sorted by time.
+    pub events: Vec<EventEnvelope>,
+    /// One slice per month of the window.
+    pub coverage: CoverageManifest,
+}
+
+impl Fixture {
+    /// Write `ledger.events.jsonl` and `coverage.manifest.json` into `dir`,
+    /// the pair `shiplog collect json` reads.
+    pub fn write_to(&self, dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(dir).with_context(|| format!("create {dir:?}"))?;
+        let ledger = dir.join("ledger.events.jsonl");
+        let file = std::fs::File::create(&ledger).with_context(|| format!("create {ledger:?}"))?;
+        write_events(BufWriter::new(file), &self.events)
+            .with_context(|| format!("write {ledger:?}"))?;
+        let coverage = dir.join("coverage.manifest.json");
+        let text = serde_json::to_string_pretty(&self.coverage).context("serialize coverage")?;
+        std::fs::write(&coverage, text).with_context(|| format!("write {coverage:?}"))
+    }
+}
+
+/// Generate the ledger `spec` describes.
+pub fn generate(spec: &FixtureSpec) -> Result<Fixture> {
+    if spec.window.since >= spec.window.until {
+        bail!("fixture window must end after it starts");
+    }
+    if spec.repos == 0 {
+        bail!("fixtures need at least one repository");
+    }
+    if spec.mix.total() == 0 {
+        bail!("event mix needs at least one non-zero weight");
+    }
+
+    let mut rng = Rng(spec.seed);
+    let repos: Vec<RepoRef> = (0..spec.repos).map(fixture_repo).collect();
+    let bursts = burst_centres(spec, &mut rng);
+    let mut numbers = vec![0_u64; repos.len()];
+
+    let mut events: Vec<EventEnvelope> = (0..spec.events)
+        .map(|_| {
+            let repo_index = pick_repo(repos.len(), &mut rng);
+            numbers[repo_index] += 1;
+            let kind = spec.mix.pick(&mut rng);
+            let day = pick_day(spec, &bursts, &mut rng);
+            fixture_event(
+                spec,
+                &repos[repo_index],
+                numbers[repo_index],
+                kind,
+                day,
+                &mut rng,
+            )
+        })
+        .collect();
+    events.sort_by(|a, b| a.occurred_at.cmp(&b.occurred_at).then(a.id.0.cmp(&b.id.0)));
+
+    let coverage = fixture_coverage(spec, &events, &mut rng);
+    Ok(Fixture { events, coverage })
+}
+
+/// Repository `index`, cycling through the word lists.
+fn fixture_repo(index: usize) -> RepoRef {
+    let org = ORGS[index % ORGS.len()];
+    let name = REPOS[index % REPOS.len()];
+    let full_name = match index / REPOS.len() {
+        0 => format!("{org}/{name}"),
+        round => format!("{org}/{name}-{}", round + 1),
+    };
+    RepoRef {
+        html_url: Some(format!("https://github.com/{full_name}")),
+        full_name,
+        // Every third repository is public so redaction profiles have
+        // both kinds to work on.
+        visibility: if index % 3 == 2 {
+            RepoVisibility::Public
+        } else {
+            RepoVisibility::Private
+        },
+    }
+}
+
+/// Repository index weighted 1/(i+1), so the first few dominate.
+fn pick_repo(count: usize, rng: &mut Rng) -> usize {
+    let weights: Vec<u64> = (1..=count as u64).map(|rank| 10_000 / rank).collect();
+    let mut roll = rng.below(weights.iter().sum());
+    for (index, weight) in weights.iter().enumerate() {
+        if roll < *weight {
+            return index;
+        }
+        roll -= weight;
+    }
+    0
+}
+
+/// Busy days for [`DateSpread::Bursty`], roughly one per month.
+fn burst_centres(spec: &FixtureSpec, rng: &mut Rng) -> Vec<NaiveDate> {
+    if spec.dates != DateSpread::Bursty {
+        return Vec::new();
+    }
+    let days = window_days(&spec.window);
+    (0..(days / 30).max(1))
+        .map(|_| spec.window.since + Duration::days(rng.below(days as u64) as i64))
+        .collect()
+}
+
+fn window_days(window: &TimeWindow) -> i64 {
+    (window.until - window.since).num_days()
+}
+
+fn pick_day(spec: &FixtureSpec, bursts: &[NaiveDate], rng: &mut Rng) -> NaiveDate {
+    let days = window_days(&spec.window);
+    let random_day =
+        |rng: &mut Rng| spec.window.since + Duration::days(rng.below(days as u64) as i64);
+    match spec.dates {
+        DateSpread::Uniform => random_day(rng),
+        DateSpread::Weekdays => {
+            let day = random_day(rng);
+            // Weekend days move to the nearest weekday still in the window.
+            let shifted = match day.weekday() {
+                Weekday::Sat => [day - Duration::days(1), day + Duration::days(2)],
+                Weekday::Sun => [day + Duration::days(1), day - Duration::days(2)],
+                _ => return day,
+            };
+            shifted
+                .into_iter()
+                .find(|candidate| spec.window.contains(*candidate))
+                .unwrap_or(day)
+        }
+        DateSpread::Bursty => {
+            let centre = bursts[rng.below(bursts.len() as u64) as usize];
+            let day = centre + Duration::days(rng.below(7) as i64 - 3);
+            if spec.window.contains(day) {
+                day
+            } else {
+                centre
+            }
+        }
+    }
+}
+
+fn fixture_event(
+    spec: &FixtureSpec,
+    repo: &RepoRef,
+    number: u64,
+    kind: EventKind,
+    day: NaiveDate,
+    rng: &mut Rng,
+) -> EventEnvelope {
+    let time = NaiveTime::from_hms_opt(9 + rng.below(9) as u32, rng.below(60) as u32, 0)
+        .unwrap_or_default();
+    let at: DateTime<Utc> = day.and_time(time).and_utc();
+    let title = format!(
+        "{} {}",
+        VERBS[rng.below(VERBS.len() as u64) as usize],
+        TOPICS[rng.below(TOPICS.len() as u64) as usize]
+    );
+    let html = repo.html_url.clone().unwrap_or_default();
+    let window = Some(spec.window.clone());
+
+    let (payload, link, system) = match kind {
+        EventKind::Review => (
+            EventPayload::Review(ReviewEvent {
+                pull_number: number,
+                pull_title: title,
+                submitted_at: at,
+                state: REVIEW_STATES[rng.below(REVIEW_STATES.len() as u64) as usize].to_string(),
+                window,
+                pull_created_at: None,
+                pull_author: None,
+            }),
+            Some(format!("{html}/pull/{number}")),
+            SourceSystem::Github,
+        ),
+        EventKind::Issue => {
+            let closed = rng.below(10) < 8;
+            (
+                EventPayload::Issue(IssueEvent {
+                    number,
+                    title,
+                    state: if closed {
+                        IssueState::Closed
+                    } else {
+                        IssueState::Open
+                    },
+                    labels: vec![LABELS[rng.below(LABELS.len() as u64) as usize].to_string()],
+                    opened_at: at - Duration::days(rng.below(14) as i64),
+                    closed_at: closed.then_some(at),
+                    window,
+                    effort: None,
+                }),
+                Some(format!("{html}/issues/{number}")),
+                SourceSystem::Github,
+            )
+        }
+        EventKind::Manual => {
+            let started = day - Duration::days(rng.below(10) as i64);
+            (
+                EventPayload::Manual(ManualEvent {
+                    event_type: MANUAL_TYPES[rng.below(MANUAL_TYPES.len() as u64) as usize].clone(),
+                    title,
+                    description: None,
+                    started_at: Some(started),
+                    ended_at: Some(day),
+                    impact: Some(format!("Unblocked the {} team", repo.full_name)),
+                    metrics: Vec::new(),
+                    effort: None,
+                    evidence: Vec::new(),
+                }),
+                None,
+                SourceSystem::Manual,
+            )
+        }
+        // [`EventMix::pick`] only returns the four generated kinds.
+        _ => {
+            let roll = rng.below(20);
+            let state = match roll {
+                0 => PullRequestState::Closed,
+                1 | 2 => PullRequestState::Open,
+                _ => PullRequestState::Merged,
+            };
+            let additions = 5 + rng.below(400);
+            let touched = (0..1 + rng.below(3))
+                .map(|_| PATHS[rng.below(PATHS.len() as u64) as usize].to_string())
+                .collect();
+            (
+                EventPayload::PullRequest(PullRequestEvent {
+                    number,
+                    title,
+                    created_at: at - Duration::hours(1 + rng.below(72) as i64),
+                    merged_at: (state == PullRequestState::Merged).then_some(at),
+                    state,
+                    additions: Some(additions),
+                    deletions: Some(rng.below(additions)),
+                    changed_files: Some(1 + rng.below(12)),
+                    touched_paths_hint: touched,
+                    window,
+                }),
+                Some(format!("{html}/pull/{number}")),
+                SourceSystem::Github,
+            )
+        }
+    };
+
+    EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
+        id: EventId::from_parts([
+            "fixture",
+            &kind.to_string(),
+            &repo.full_name,
+            &number.to_string(),
+        ]),
+        canonical_id: None,
+        kind,
+        occurred_at: at,
+        actor: Actor {
+            login: spec.user.clone(),
+            id: None,
+        },
+        contributors: Vec::new(),
+        repo: repo.clone(),
+        payload,
+        tags: Vec::new(),
+        links: link
+            .iter()
+            .map(|url| Link {
+                label: "source".to_string(),
+                url: url.clone(),
+            })
+            .collect(),
+        source: SourceRef {
+            system,
+            url: link,
+            opaque_id: None,
+        },
+        extensions: Default::default(),
+    }
+    .with_canonical_id()
+}
+
+/// One slice per month; the first `incomplete_months` busy months report
+/// more results than were fetched.
+fn fixture_coverage(
+    spec: &FixtureSpec,
+    events: &[EventEnvelope],
+    rng: &mut Rng,
+) -> CoverageManifest {
+    let mut slices: Vec<CoverageSlice> = month_windows(spec.window.since, spec.window.until)
+        .into_iter()
+        .map(|window| {
+            let fetched = events
+                .iter()
+                .filter(|event| window.contains(event.occurred_at.date_naive()))
+                .count() as u64;
+            CoverageSlice {
+                query: format!("fixture:{} user:{}", spec.mix, spec.user),
+                total_count: fetched,
+                fetched,
+                incomplete_results: Some(false),
+                notes: vec!["synthetic fixture".to_string()],
+                window,
+                source: None,
+            }
+        })
+        .collect();
+    for slice in slices
+        .iter_mut()
+        .filter(|slice| slice.fetched > 0)
+        .take(spec.incomplete_months)
+    {
+        slice.total_count = slice.fetched + 1 + rng.below(slice.fetched);
+        slice.incomplete_results = Some(true);
+    }
+
+    let incomplete = slices
+        .iter()
+        .any(|slice| slice.incomplete_results == Some(true));
+    let mut sources = vec!["github".to_string()];
+    if events.iter().any(|event| event.kind == EventKind::Manual) {
+        sources.push("manual".to_string());
+    }
+    CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
+        run_id: RunId::now("fixture"),
+        generated_at: Utc::now(),
+        user: spec.user.clone(),
+        window: spec.window.clone(),
+        timezone: None,
+        mode: "merged".to_string(),
+        sources,
+        score: coverage_score(&slices),
+        slices,
+        warnings: if incomplete {
+            vec!["Synthetic fixture: some months were generated as incomplete.".to_string()]
+        } else {
+            Vec::new()
+        },
+        completeness: if incomplete {
+            Completeness::Partial
+        } else {
+            Completeness::Complete
+        },
+        budget: vec![],
+    }
+}
+
+/// SplitMix64: small, fast, and stable across platforms and releases, which
+/// is all a fixture needs.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform-enough value in `0..bound`; 0 when `bound` is 0.
+    fn below(&mut self, bound: u64) -> u64 {
+        match bound {
+            0 => 0,
+            _ => self.next_u64() % bound,
+        }
+    }
+}
@@ -674,7 +674,7 @@ fn run_activity_profile(
     let run_dir = out_dir.join(&run_id);
     let bundle_profile = BundleProfile::Internal;
     let redaction_key = RedactionKey::resolve(None, &bundle_profile)?;
-    let clusterer = build_clusterer(false, "", "", None);
+    let clusterer = build_clusterer(ClusterStrategy::Repo, false, None, "", "", None, "");
     let (engine, redactor) = create_engine(redaction_key.engine_key(), clusterer, &bundle_profile);
     let engine = engine.with_profile_rendering(redaction_key.render_profiles());
     let cache_path = DeterministicRedactor::cache_path(&run_dir);
@@ -874,7 +874,7 @@ fn activity_execution(
         source
             .and_then(|source| source.cache_dir.as_ref())
             .map(|cache_dir| resolve_config_path(base_dir, cache_dir))
-            .or_else(|| Some(out_dir.join(".cache")))
+            .or_else(|| Some(api_cache_dir(out_dir)))
     };
     let (include_reviews, no_details) = match profile {
         GithubActivityProfile::Scout => (false, true),
@@ -1433,6 +1433,7 @@ fn read_window_ingest_output(
 
     Ok(IngestOutput {
         events,
+        budget: coverage.budget.clone(),
         coverage,
         freshness,
     })
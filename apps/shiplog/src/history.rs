@@ -0,0 +1,253 @@
+//! `shiplog history list|compare|open|shipped|covering`: past runs from the
+//! run index and history database.
+//!
+//! The engine keeps `runs.index.json` and `history.db` current for every run
+//! it writes. Runs written before either existed, or copied in from
+//! elsewhere, are recorded from disk the first time `history` sees them.
+
+use anyhow::{Context, Result};
+use shiplog::history_db::{HISTORY_DB_FILENAME, HistoryDb, run_outputs};
+use shiplog::run_index::{RUN_INDEX_FILENAME, RunIndex, RunIndexEntry};
+use shiplog::workstreams::WorkstreamManager;
+
+use crate::*;
+
+pub(super) fn print_history(
+    out: &Path,
+    month: Option<&str>,
+    curated_only: bool,
+    rebuild: bool,
+) -> Result<()> {
+    let month = month.map(parse_history_month).transpose()?;
+    let index = load_history(out, rebuild)?;
+    let runs: Vec<&RunIndexEntry> = index
+        .runs
+        .iter()
+        .filter(|run| !curated_only || run.curated)
+        .filter(|run| {
+            month
+                .as_deref()
+                .is_none_or(|month| month_label(run) == month)
+        })
+        .collect();
+
+    println!("History: {}", display_path_for_cli(out));
+    println!("Runs: {} of {}", runs.len(), index.runs.len());
+    for run in runs {
+        let curation = if run.curated { "curated" } else { "suggested" };
+        println!("- {}", run.dir);
+        println!("  updated: {}", run.updated_at.format("%Y-%m-%d %H:%M UTC"));
+        println!("  window: {}..{}", run.window.since, run.window.until);
+        println!("  sources: {}", source_count_label(run));
+        println!("  events: {}", run.event_count);
+        println!("  workstreams: {} ({curation})", run.workstream_count);
+        println!(
+            "  readiness: {}/100 ({})",
+            run.readiness_score, run.readiness_grade
+        );
+    }
+    Ok(())
+}
+
+pub(super) fn compare_history(out: &Path, from: &str, to: &str) -> Result<()> {
+    let index = load_history(out, false)?;
+    let from_dir = resolve_history_run(out, &index, from)?;
+    let to_dir = resolve_history_run(out, &index, to)?;
+    let comparison = compare_runs(&from_dir, &to_dir)?;
+    print_run_compare(&comparison, out);
+    Ok(())
+}
+
+pub(super) fn open_history(out: &Path, run: &str, print_path: bool) -> Result<()> {
+    let index = load_history(out, false)?;
+    let run_dir = resolve_history_run(out, &index, run)?;
+    open_existing_path(
+        &run_dir.join("packet.md"),
+        "Packet",
+        "Run `shiplog render --latest` to create it.",
+        print_path,
+    )
+}
+
+pub(super) fn print_shipped(
+    out: &Path,
+    since: NaiveDate,
+    until: NaiveDate,
+    json: bool,
+) -> Result<()> {
+    if since >= until {
+        anyhow::bail!("--since {since} must be before --until {until}");
+    }
+    let db = open_history_db(out)?;
+    let events = db.events_between(since, until)?;
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&events).context("serialize shipped events")?
+        );
+        return Ok(());
+    }
+
+    println!("Shipped: {since}..{until}");
+    println!("Events: {}", events.len());
+    for hit in &events {
+        println!(
+            "- {} {:?} {}: {} (run {})",
+            hit.event.occurred_at.format("%Y-%m-%d"),
+            hit.event.kind,
+            hit.event.repo.full_name,
+            event_title(&hit.event),
+            hit.run_dir
+        );
+    }
+    Ok(())
+}
+
+pub(super) fn print_covering(out: &Path, date: NaiveDate) -> Result<()> {
+    let db = open_history_db(out)?;
+    let run = db.latest_run_covering(date)?.with_context(|| {
+        format!(
+            "no recorded run covers {date}; list runs with `shiplog history list --out {}`",
+            quote_cli_value(&out.display().to_string())
+        )
+    })?;
+    println!("Covering {date}: {}", run.entry.dir);
+    println!(
+        "  window: {}..{}",
+        run.entry.window.since, run.entry.window.until
+    );
+    println!(
+        "  updated: {}",
+        run.entry.updated_at.format("%Y-%m-%d %H:%M UTC")
+    );
+    println!("  events: {}", run.entry.event_count);
+    println!("  outputs: {}", run.outputs.join(", "));
+    Ok(())
+}
+
+/// Open the history database with every indexed run recorded, recording
+/// runs it is missing or holds an older copy of and forgetting runs that
+/// left the index.
+fn open_history_db(out: &Path) -> Result<HistoryDb> {
+    let index = load_history(out, false)?;
+    let mut db = HistoryDb::open(out)?;
+    let recorded = db.recorded_updates()?;
+    let mut changed = false;
+    for run in &index.runs {
+        if recorded.get(&run.dir) == Some(&run.updated_at) {
+            continue;
+        }
+        let run_dir = out.join(&run.dir);
+        let ingest =
+            load_run_ingest(&run_dir).with_context(|| format!("load run {}", run_dir.display()))?;
+        db.record_run(run, &ingest.events, &run_outputs(&run_dir)?)?;
+        changed = true;
+    }
+    for dir in recorded.keys() {
+        if !index.runs.iter().any(|run| &run.dir == dir) {
+            changed |= db.remove_run(dir)?;
+        }
+    }
+    if changed {
+        eprintln!(
+            "Recorded: {}",
+            display_path_for_cli(&out.join(HISTORY_DB_FILENAME))
+        );
+    }
+    Ok(db)
+}
+
+/// Load the index, adding runs it has not seen and dropping runs that are
+/// gone. `rebuild` re-reads every run from disk.
+fn load_history(out: &Path, rebuild: bool) -> Result<RunIndex> {
+    let existing = if rebuild { None } else { RunIndex::load(out)? };
+    let mut changed = existing.is_none();
+    let mut index = existing.unwrap_or_default();
+    changed |= index.retain_existing(out) > 0;
+
+    for run_dir in discover_run_dirs(out)? {
+        let dir = run_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if index.runs.iter().any(|run| run.dir == dir) {
+            continue;
+        }
+        index.upsert(index_entry_from_disk(&run_dir)?);
+        changed = true;
+    }
+
+    if changed {
+        index.save(out)?;
+        eprintln!(
+            "Indexed: {}",
+            display_path_for_cli(&out.join(RUN_INDEX_FILENAME))
+        );
+    }
+    Ok(index)
+}
+
+fn index_entry_from_disk(run_dir: &Path) -> Result<RunIndexEntry> {
+    let ingest =
+        load_run_ingest(run_dir).with_context(|| format!("load run {}", run_dir.display()))?;
+    let workstreams = WorkstreamManager::try_load(run_dir)?.unwrap_or_else(empty_workstreams_file);
+    // Without an engine write to date it, the packet's mtime is the best
+    // record of when the run was last rendered.
+    let updated_at = run_dir
+        .join("packet.md")
+        .metadata()
+        .and_then(|meta| meta.modified())
+        .map(DateTime::<Utc>::from)
+        .unwrap_or(ingest.coverage.generated_at);
+    Ok(RunIndexEntry::new(
+        run_dir,
+        &ingest.events,
+        &workstreams,
+        &ingest.coverage,
+        WorkstreamManager::has_curated(run_dir),
+        updated_at,
+    ))
+}
+
+/// Resolve `latest`, a run directory or ID, or a `YYYY-MM` month (the most
+/// recently updated run that month) to a run directory.
+fn resolve_history_run(out: &Path, index: &RunIndex, selector: &str) -> Result<PathBuf> {
+    let found = if selector == "latest" {
+        index.runs.first()
+    } else if let Ok(month) = parse_history_month(selector) {
+        index.runs.iter().find(|run| month_label(run) == month)
+    } else {
+        index
+            .runs
+            .iter()
+            .find(|run| run.dir == selector || run.run_id == selector)
+    };
+    let run = found.with_context(|| {
+        format!(
+            "no indexed run matches {selector:?}; list runs with `shiplog history list --out {}`",
+            quote_cli_value(&out.display().to_string())
+        )
+    })?;
+    Ok(out.join(&run.dir))
+}
+
+fn parse_history_month(value: &str) -> Result<String> {
+    NaiveDate::parse_from_str(&format!("{value}-01"), "%Y-%m-%d")
+        .map(|date| date.format("%Y-%m").to_string())
+        .with_context(|| format!("invalid month {value:?}; expected YYYY-MM"))
+}
+
+fn month_label(run: &RunIndexEntry) -> String {
+    run.updated_at.format("%Y-%m").to_string()
+}
+
+fn source_count_label(run: &RunIndexEntry) -> String {
+    if run.events_by_source.is_empty() {
+        return source_list_label(&run.sources);
+    }
+    run.events_by_source
+        .iter()
+        .map(|(source, count)| format!("{source} ({count})"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,486 @@
+//! Cross-run history database.
+//!
+//! `runs.index.json` answers which runs exist. `history.db`, a SQLite file
+//! next to it, also keeps every run's events, so questions that span runs
+//! ("what shipped in Q3?", "which run covered March 3rd?", "what changed
+//! between these two runs?") are answered without re-reading ledgers. Every
+//! run the engine records in the run index is recorded here too.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::{Deserialize, Serialize};
+
+use crate::run_index::RunIndexEntry;
+use crate::schema::event::EventEnvelope;
+
+/// Output-root file holding the history database.
+pub const HISTORY_DB_FILENAME: &str = "history.db";
+
+/// Current `history.db` schema version, stored as SQLite `user_version`.
+pub const HISTORY_DB_SCHEMA_VERSION: i64 = 1;
+
+/// One recorded run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryRun {
+    /// Run metadata, as in the run index.
+    #[serde(flatten)]
+    pub entry: RunIndexEntry,
+    /// Files in the run directory when it was recorded, relative to it.
+    pub outputs: Vec<String>,
+}
+
+/// An event and the run it was read from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEvent {
+    /// Directory name of the run under the output root.
+    pub run_dir: String,
+    pub event: EventEnvelope,
+}
+
+/// Events that differ between two recorded runs, matched by event ID.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HistoryDiff {
+    /// Events only in the later run.
+    pub added: Vec<EventEnvelope>,
+    /// Events only in the earlier run.
+    pub removed: Vec<EventEnvelope>,
+}
+
+/// SQLite store of recorded runs and their events.
+#[derive(Debug)]
+pub struct HistoryDb {
+    conn: Connection,
+}
+
+impl HistoryDb {
+    /// Open or create the history database under an output root.
+    pub fn open(out_root: &Path) -> Result<Self> {
+        let path = out_root.join(HISTORY_DB_FILENAME);
+        let conn = Connection::open(&path)
+            .with_context(|| format!("open history database {}", path.display()))?;
+        Self::init(conn).with_context(|| format!("initialize {}", path.display()))
+    }
+
+    /// Create an in-memory database (for testing).
+    pub fn open_in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory().context("open in-memory history database")?;
+        Self::init(conn)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        if version > HISTORY_DB_SCHEMA_VERSION {
+            anyhow::bail!(
+                "history database uses schema {version}; this shiplog reads up to {HISTORY_DB_SCHEMA_VERSION}"
+            );
+        }
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS runs (
+                dir TEXT PRIMARY KEY,
+                run_id TEXT NOT NULL,
+                since TEXT NOT NULL,
+                until TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                entry TEXT NOT NULL,
+                outputs TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS events (
+                dir TEXT NOT NULL,
+                event_id TEXT NOT NULL,
+                occurred_at TEXT NOT NULL,
+                event TEXT NOT NULL,
+                PRIMARY KEY (dir, event_id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_events_occurred_at ON events(occurred_at);
+            CREATE INDEX IF NOT EXISTS idx_events_event_id ON events(event_id);",
+        )?;
+        conn.pragma_update(None, "user_version", HISTORY_DB_SCHEMA_VERSION)?;
+        Ok(Self { conn })
+    }
+
+    /// Record a run and its events, replacing any earlier record of the same
+    /// run directory. `outputs` lists the files the run wrote.
+    pub fn record_run(
+        &mut self,
+        entry: &RunIndexEntry,
+        events: &[EventEnvelope],
+        outputs: &[String],
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM events WHERE dir = ?1", params![entry.dir])?;
+        tx.execute(
+            "INSERT OR REPLACE INTO runs (dir, run_id, since, until, updated_at, entry, outputs)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                entry.dir,
+                entry.run_id,
+                entry.window.since.to_string(),
+                entry.window.until.to_string(),
+                timestamp(entry.updated_at),
+                serde_json::to_string(entry).context("serialize run entry")?,
+                serde_json::to_string(outputs).context("serialize run outputs")?,
+            ],
+        )?;
+        {
+            let mut insert = tx.prepare(
+                "INSERT OR REPLACE INTO events (dir, event_id, occurred_at, event)
+                 VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for event in events {
+                insert.execute(params![
+                    entry.dir,
+                    event.id.0,
+                    timestamp(event.occurred_at),
+                    serde_json::to_string(event).context("serialize event")?,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Forget a run. Returns whether it was recorded.
+    pub fn remove_run(&mut self, dir: &str) -> Result<bool> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM events WHERE dir = ?1", params![dir])?;
+        let removed = tx.execute("DELETE FROM runs WHERE dir = ?1", params![dir])?;
+        tx.commit()?;
+        Ok(removed > 0)
+    }
+
+    /// When each recorded run was last updated, by run directory.
+    pub fn recorded_updates(&self) -> Result<BTreeMap<String, DateTime<Utc>>> {
+        let mut stmt = self.conn.prepare("SELECT dir, entry FROM runs")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        let mut out = BTreeMap::new();
+        for row in rows {
+            let (dir, entry) = row?;
+            out.insert(dir, parse_entry(&entry)?.updated_at);
+        }
+        Ok(out)
+    }
+
+    /// Every recorded run, most recently updated first.
+    pub fn runs(&self) -> Result<Vec<HistoryRun>> {
+        self.query_runs(
+            "SELECT entry, outputs FROM runs ORDER BY updated_at DESC, dir",
+            params![],
+        )
+    }
+
+    /// The most recently updated run whose window contains `date`.
+    pub fn latest_run_covering(&self, date: NaiveDate) -> Result<Option<HistoryRun>> {
+        Ok(self
+            .query_runs(
+                "SELECT entry, outputs FROM runs WHERE since <= ?1 AND until > ?1
+                 ORDER BY updated_at DESC, dir LIMIT 1",
+                params![date.to_string()],
+            )?
+            .pop())
+    }
+
+    /// Events that occurred in `since..until` in any recorded run.
+    ///
+    /// An event collected by several runs is returned once, from the most
+    /// recently updated of them. Results are ordered by occurrence.
+    pub fn events_between(&self, since: NaiveDate, until: NaiveDate) -> Result<Vec<HistoryEvent>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT dir, event FROM (
+                SELECT e.dir, e.event, e.occurred_at, e.event_id,
+                       ROW_NUMBER() OVER (
+                           PARTITION BY e.event_id ORDER BY r.updated_at DESC, e.dir
+                       ) AS newest
+                FROM events e JOIN runs r ON r.dir = e.dir
+                WHERE e.occurred_at >= ?1 AND e.occurred_at < ?2
+             )
+             WHERE newest = 1
+             ORDER BY occurred_at, event_id",
+        )?;
+        let rows = stmt.query_map(params![since.to_string(), until.to_string()], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        let mut out = Vec::new();
+        for row in rows {
+            let (run_dir, event) = row?;
+            out.push(HistoryEvent {
+                run_dir,
+                event: parse_event(&event)?,
+            });
+        }
+        Ok(out)
+    }
+
+    /// Events added and removed going from run `from` to run `to`, both
+    /// given as run directory names.
+    pub fn diff_runs(&self, from: &str, to: &str) -> Result<HistoryDiff> {
+        for dir in [from, to] {
+            let known = self
+                .conn
+                .query_row(
+                    "SELECT 1 FROM runs WHERE dir = ?1",
+                    params![dir],
+                    |_| Ok(()),
+                )
+                .optional()?;
+            if known.is_none() {
+                anyhow::bail!("run {dir:?} is not in the history database");
+            }
+        }
+        Ok(HistoryDiff {
+            added: self.events_only_in(to, from)?,
+            removed: self.events_only_in(from, to)?,
+        })
+    }
+
+    fn events_only_in(&self, dir: &str, other: &str) -> Result<Vec<EventEnvelope>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT event FROM events
+             WHERE dir = ?1 AND event_id NOT IN (SELECT event_id FROM events WHERE dir = ?2)
+             ORDER BY occurred_at, event_id",
+        )?;
+        let rows = stmt.query_map(params![dir, other], |row| row.get::<_, String>(0))?;
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(parse_event(&row?)?);
+        }
+        Ok(out)
+    }
+
+    fn query_runs(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<HistoryRun>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let rows = stmt.query_map(params, |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        let mut out = Vec::new();
+        for row in rows {
+            let (entry, outputs) = row?;
+            out.push(HistoryRun {
+                entry: parse_entry(&entry)?,
+                outputs: serde_json::from_str(&outputs).context("parse recorded run outputs")?,
+            });
+        }
+        Ok(out)
+    }
+}
+
+/// Record a freshly written run in the history database of its output root,
+/// the run directory's parent.
+pub fn record_run(run_dir: &Path, entry: &RunIndexEntry, events: &[EventEnvelope]) -> Result<()> {
+    let Some(out_root) = run_dir.parent().filter(|root| root.is_dir()) else {
+        return Ok(());
+    };
+    let outputs = run_outputs(run_dir)?;
+    HistoryDb::open(out_root)?.record_run(entry, events, &outputs)
+}
+
+/// Files directly inside a run directory, sorted.
+pub fn run_outputs(run_dir: &Path) -> Result<Vec<String>> {
+    let mut outputs = Vec::new();
+    for entry in
+        std::fs::read_dir(run_dir).with_context(|| format!("read {}", run_dir.display()))?
+    {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            outputs.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    outputs.sort();
+    Ok(outputs)
+}
+
+/// Fixed-width UTC timestamps, so text order matches time order in SQL.
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+fn parse_entry(text: &str) -> Result<RunIndexEntry> {
+    serde_json::from_str(text).context("parse recorded run entry")
+}
+
+fn parse_event(text: &str) -> Result<EventEnvelope> {
+    serde_json::from_str(text).context("parse recorded event")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ids::EventId;
+    use crate::readiness::ReadinessGrade;
+    use crate::schema::coverage::{Completeness, TimeWindow};
+    use crate::schema::event::{
+        Actor, EventKind, EventPayload, ManualEvent, ManualEventType, RepoRef, RepoVisibility,
+        SourceRef, SourceSystem,
+    };
+    use crate::schema::version::SchemaVersion;
+    use chrono::TimeZone;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn event(key: &str, title: &str, occurred: NaiveDate) -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["history", key]),
+            canonical_id: None,
+            kind: EventKind::Manual,
+            occurred_at: occurred.and_hms_opt(12, 0, 0).unwrap().and_utc(),
+            actor: Actor {
+                login: "octo".into(),
+                id: None,
+            },
+            contributors: vec![],
+            repo: RepoRef {
+                full_name: "acme/api".into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::Manual(ManualEvent {
+                event_type: ManualEventType::Other,
+                title: title.into(),
+                description: None,
+                started_at: None,
+                ended_at: None,
+                impact: None,
+                metrics: vec![],
+                effort: None,
+                evidence: vec![],
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Manual,
+                url: None,
+                opaque_id: None,
+            },
+            extensions: Default::default(),
+        }
+    }
+
+    fn entry(dir: &str, window: (NaiveDate, NaiveDate), updated_day: u32) -> RunIndexEntry {
+        RunIndexEntry {
+            run_id: dir.to_string(),
+            dir: dir.to_string(),
+            generated_at: Utc.with_ymd_and_hms(2025, 10, 1, 9, 0, 0).unwrap(),
+            updated_at: Utc
+                .with_ymd_and_hms(2025, 10, updated_day, 9, 0, 0)
+                .unwrap(),
+            user: "octo".to_string(),
+            window: TimeWindow {
+                since: window.0,
+                until: window.1,
+            },
+            sources: vec!["manual".to_string()],
+            event_count: 0,
+            events_by_source: BTreeMap::new(),
+            workstream_count: 0,
+            curated: false,
+            completeness: Completeness::Complete,
+            readiness_score: 0,
+            readiness_grade: ReadinessGrade::F,
+        }
+    }
+
+    fn title(event: &EventEnvelope) -> &str {
+        match &event.payload {
+            EventPayload::Manual(manual) => &manual.title,
+            _ => "",
+        }
+    }
+
+    /// Q3 run, then a half-year rerun that retitled one event and
+    /// collected a later one.
+    fn overlapping_runs() -> HistoryDb {
+        let mut db = HistoryDb::open_in_memory().unwrap();
+        let q3 = (date(2025, 7, 1), date(2025, 10, 1));
+        let h2 = (date(2025, 7, 1), date(2026, 1, 1));
+        db.record_run(
+            &entry("run_q3", q3, 2),
+            &[
+                event("a", "Launch", date(2025, 7, 4)),
+                event("b", "Draft", date(2025, 8, 9)),
+            ],
+            &["packet.md".to_string()],
+        )
+        .unwrap();
+        db.record_run(
+            &entry("run_h2", h2, 5),
+            &[
+                event("b", "Final", date(2025, 8, 9)),
+                event("c", "Migration", date(2025, 11, 20)),
+            ],
+            &["ledger.events.jsonl".to_string(), "packet.md".to_string()],
+        )
+        .unwrap();
+        db
+    }
+
+    #[test]
+    fn events_between_spans_runs_and_prefers_the_newest_copy() {
+        let db = overlapping_runs();
+        let q3 = db
+            .events_between(date(2025, 7, 1), date(2025, 10, 1))
+            .unwrap();
+        let found: Vec<(&str, &str)> = q3
+            .iter()
+            .map(|hit| (hit.run_dir.as_str(), title(&hit.event)))
+            .collect();
+        assert_eq!(found, [("run_q3", "Launch"), ("run_h2", "Final")]);
+    }
+
+    #[test]
+    fn latest_run_covering_uses_half_open_windows() {
+        let db = overlapping_runs();
+        let covering = |d| db.latest_run_covering(d).unwrap().map(|run| run.entry.dir);
+        assert_eq!(covering(date(2025, 8, 1)).as_deref(), Some("run_h2"));
+        assert_eq!(covering(date(2025, 12, 31)).as_deref(), Some("run_h2"));
+        assert_eq!(covering(date(2026, 1, 1)), None);
+        assert_eq!(
+            db.runs().unwrap()[0].outputs,
+            ["ledger.events.jsonl", "packet.md"]
+        );
+    }
+
+    #[test]
+    fn diff_runs_matches_by_event_id() {
+        let db = overlapping_runs();
+        let diff = db.diff_runs("run_q3", "run_h2").unwrap();
+        let titles = |events: &[EventEnvelope]| {
+            events
+                .iter()
+                .map(|e| title(e).to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(titles(&diff.added), ["Migration"]);
+        assert_eq!(titles(&diff.removed), ["Launch"]);
+        assert!(db.diff_runs("run_q3", "run_missing").is_err());
+    }
+
+    #[test]
+    fn rerecording_and_removing_replace_a_runs_events() {
+        let mut db = overlapping_runs();
+        let h2 = (date(2025, 7, 1), date(2026, 1, 1));
+        db.record_run(&entry("run_h2", h2, 6), &[], &[]).unwrap();
+        assert_eq!(
+            db.events_between(date(2025, 1, 1), date(2026, 1, 1))
+                .unwrap()
+                .len(),
+            2
+        );
+
+        assert!(db.remove_run("run_q3").unwrap());
+        assert!(!db.remove_run("run_q3").unwrap());
+        assert!(
+            db.events_between(date(2025, 1, 1), date(2026, 1, 1))
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(db.recorded_updates().unwrap().len(), 1);
+    }
+}
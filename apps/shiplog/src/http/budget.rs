@@ -0,0 +1,409 @@
+//! Run-wide HTTP request budget shared by every ingestor.
+//!
+//! `[http]` in `shiplog.toml` caps how many requests a run may send to each
+//! host and how long a run may keep sending them. Every ingestor holds a clone
+//! of the same [`HttpBudget`] and asks [`HttpBudget::admit`] before each
+//! request. A refused request surfaces as an [`HttpBudgetExhausted`] error;
+//! ingestors stop paging when they see one, keep what they already fetched,
+//! and mark their coverage partial with the cutoff as the explanation. That
+//! keeps a collection inside a CI job's time limit instead of being killed
+//! mid-write.
+//!
+//! The budget also records the rate-limit headers each host answers with, so
+//! an ingestor can report its [`RateBudget`] in the coverage manifest and a
+//! partial run can be traced to the server's rate limit.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use shiplog::schema::coverage::RateBudget;
+use url::Url;
+
+use super::retry::{LIMIT_HEADERS, REMAINING_HEADERS, header_count, is_rate_limited, reset_at};
+
+/// Limits for one run. `None` means unlimited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HttpBudgetLimits {
+    /// Requests each host may receive during the run.
+    pub max_requests_per_host: Option<u64>,
+    /// Time after the run starts when no new request is sent.
+    pub max_wall_clock: Option<Duration>,
+}
+
+impl HttpBudgetLimits {
+    /// Returns `true` when neither limit is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_requests_per_host.is_none() && self.max_wall_clock.is_none()
+    }
+}
+
+/// Shared request counter and clock for one run.
+///
+/// Clones share state, so a request counted by one ingestor counts against
+/// the same host for every other ingestor in the run.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::http::{HttpBudget, HttpBudgetLimits};
+///
+/// let budget = HttpBudget::new(HttpBudgetLimits {
+///     max_requests_per_host: Some(1),
+///     max_wall_clock: None,
+/// });
+/// let shared = budget.clone();
+///
+/// assert!(budget.admit("https://api.github.com/search/issues").is_ok());
+/// let refused = shared.admit("https://api.github.com/repos/a/b").unwrap_err();
+/// assert_eq!(refused.host, "api.github.com");
+/// assert!(shared.admit("https://gitlab.com/api/v4/user").is_ok());
+/// assert_eq!(budget.cutoffs().len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HttpBudget {
+    limits: HttpBudgetLimits,
+    started: Instant,
+    state: Arc<Mutex<BudgetState>>,
+}
+
+#[derive(Debug, Default)]
+struct BudgetState {
+    requests: BTreeMap<String, u64>,
+    /// First refusal per host, kept to explain the cutoff.
+    cutoffs: BTreeMap<String, HttpBudgetExhausted>,
+    /// Rate-limit answers per host.
+    rate_limits: BTreeMap<String, HostRateLimit>,
+}
+
+/// What one host reported about its rate limit.
+#[derive(Clone, Debug, Default)]
+struct HostRateLimit {
+    rate_limited: u64,
+    limit: Option<u64>,
+    /// Lowest remaining count seen, with the limit and reset sent alongside.
+    remaining: Option<u64>,
+    reset_at: Option<DateTime<Utc>>,
+}
+
+impl Default for HttpBudget {
+    fn default() -> Self {
+        Self::new(HttpBudgetLimits::default())
+    }
+}
+
+impl HttpBudget {
+    /// Start a run's budget clock with `limits`.
+    pub fn new(limits: HttpBudgetLimits) -> Self {
+        Self {
+            limits,
+            started: Instant::now(),
+            state: Arc::default(),
+        }
+    }
+
+    /// Limits this budget enforces.
+    pub fn limits(&self) -> HttpBudgetLimits {
+        self.limits
+    }
+
+    /// Count a request to `url`, or refuse it when its host has used its
+    /// share or the run's wall-clock limit has passed.
+    pub fn admit(&self, url: &str) -> Result<(), HttpBudgetExhausted> {
+        let host = host_of(url);
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let used = state.requests.get(&host).copied().unwrap_or(0);
+
+        let limit = match (
+            self.limits.max_wall_clock,
+            self.limits.max_requests_per_host,
+        ) {
+            (Some(max), _) if self.started.elapsed() >= max => {
+                Some(HttpBudgetLimit::WallClock { max })
+            }
+            (_, Some(max)) if used >= max => Some(HttpBudgetLimit::Requests { max }),
+            _ => None,
+        };
+        if let Some(limit) = limit {
+            let refused = HttpBudgetExhausted { host, limit };
+            state
+                .cutoffs
+                .entry(refused.host.clone())
+                .or_insert_with(|| refused.clone());
+            return Err(refused);
+        }
+
+        state.requests.insert(host, used + 1);
+        Ok(())
+    }
+
+    /// Requests sent so far, by host.
+    pub fn requests(&self) -> BTreeMap<String, u64> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .requests
+            .clone()
+    }
+
+    /// Record the rate-limit headers of a response from the host of `url`,
+    /// and count it when the host refused the request for rate limiting.
+    ///
+    /// [`send_with_retry`](super::send_with_retry) calls this for every
+    /// response it receives.
+    pub fn observe(&self, url: &str, status: StatusCode, headers: &HeaderMap) {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let seen = state.rate_limits.entry(host_of(url)).or_default();
+        if is_rate_limited(status, headers) {
+            seen.rate_limited += 1;
+        }
+        if let Some(remaining) = header_count(headers, &REMAINING_HEADERS)
+            && seen.remaining.is_none_or(|lowest| remaining <= lowest)
+        {
+            seen.remaining = Some(remaining);
+            seen.limit = header_count(headers, &LIMIT_HEADERS);
+            seen.reset_at = reset_at(headers);
+        }
+    }
+
+    /// Requests sent to the host of `url` and the rate limit it reported,
+    /// attributed to `source` for the coverage manifest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reqwest::StatusCode;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    /// use shiplog::http::HttpBudget;
+    ///
+    /// let budget = HttpBudget::default();
+    /// let url = "https://gitlab.com/api/v4/projects";
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("ratelimit-remaining", HeaderValue::from_static("0"));
+    /// budget.admit(url).unwrap();
+    /// budget.observe(url, StatusCode::TOO_MANY_REQUESTS, &headers);
+    ///
+    /// let used = budget.rate_budget("gitlab", url);
+    /// assert_eq!((used.requests, used.rate_limited), (1, 1));
+    /// assert!(used.warning().is_some());
+    /// ```
+    pub fn rate_budget(&self, source: &str, url: &str) -> RateBudget {
+        let host = host_of(url);
+        let state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let seen = state.rate_limits.get(&host).cloned().unwrap_or_default();
+        RateBudget {
+            source: source.to_string(),
+            requests: state.requests.get(&host).copied().unwrap_or(0),
+            host,
+            rate_limited: seen.rate_limited,
+            limit: seen.limit,
+            remaining: seen.remaining,
+            reset_at: seen.reset_at,
+        }
+    }
+
+    /// The first refused request to the host of `url`, if one was refused.
+    pub fn cutoff_for(&self, url: &str) -> Option<HttpBudgetExhausted> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .cutoffs
+            .get(&host_of(url))
+            .cloned()
+    }
+
+    /// The first refused request for each host that hit a limit.
+    pub fn cutoffs(&self) -> Vec<HttpBudgetExhausted> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .cutoffs
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Host part of `url`; bare hostnames such as `gitlab.com` are their own host.
+fn host_of(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| url.to_string())
+}
+
+/// Which limit stopped a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HttpBudgetLimit {
+    /// The host already received `max` requests.
+    Requests {
+        /// `http.max_requests_per_host`.
+        max: u64,
+    },
+    /// The run has been sending requests for longer than `max`.
+    WallClock {
+        /// `http.max_wall_clock_secs`.
+        max: Duration,
+    },
+}
+
+/// A request the run's [`HttpBudget`] refused to send.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpBudgetExhausted {
+    /// Host the request was for.
+    pub host: String,
+    /// Limit that refused it.
+    pub limit: HttpBudgetLimit,
+}
+
+impl fmt::Display for HttpBudgetExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.limit {
+            HttpBudgetLimit::Requests { max } => write!(
+                f,
+                "HTTP budget stopped requests to {} after {max} (http.max_requests_per_host)",
+                self.host
+            ),
+            HttpBudgetLimit::WallClock { max } => write!(
+                f,
+                "HTTP budget stopped requests to {} after {}s of run time (http.max_wall_clock_secs)",
+                self.host,
+                max.as_secs()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HttpBudgetExhausted {}
+
+/// The budget refusal behind `err`, if that is what stopped it.
+pub fn budget_cutoff(err: &anyhow::Error) -> Option<&HttpBudgetExhausted> {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<HttpBudgetExhausted>())
+}
+
+/// Coverage warning for a source whose collection `cutoff` ended early.
+pub fn cutoff_warning(cutoff: &HttpBudgetExhausted) -> String {
+    format!("{cutoff}; later results were not fetched, so coverage is partial.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn request_limit_is_counted_per_host_and_shared_by_clones() {
+        let budget = HttpBudget::new(HttpBudgetLimits {
+            max_requests_per_host: Some(2),
+            max_wall_clock: None,
+        });
+        let gitlab = budget.clone();
+
+        assert!(budget.admit("https://api.github.com/search/issues").is_ok());
+        assert!(gitlab.admit("https://api.github.com/user").is_ok());
+        assert!(gitlab.admit("https://gitlab.com/api/v4/user").is_ok());
+
+        let refused = budget
+            .admit("https://api.github.com/repos/a/b")
+            .unwrap_err();
+        assert_eq!(refused.limit, HttpBudgetLimit::Requests { max: 2 });
+        assert_eq!(budget.requests()["api.github.com"], 2);
+        assert_eq!(gitlab.cutoff_for("https://api.github.com"), Some(refused));
+        assert_eq!(budget.cutoff_for("https://gitlab.com/api/v4"), None);
+    }
+
+    #[test]
+    fn wall_clock_limit_refuses_every_host() {
+        let budget = HttpBudget::new(HttpBudgetLimits {
+            max_requests_per_host: None,
+            max_wall_clock: Some(Duration::ZERO),
+        });
+
+        let refused = budget.admit("https://api.linear.app/graphql").unwrap_err();
+        assert_eq!(
+            refused.limit,
+            HttpBudgetLimit::WallClock {
+                max: Duration::ZERO
+            }
+        );
+        assert!(refused.to_string().contains("http.max_wall_clock_secs"));
+        assert!(budget.requests().is_empty());
+    }
+
+    #[test]
+    fn unlimited_budget_admits_everything() {
+        let budget = HttpBudget::default();
+        assert!(budget.limits().is_unlimited());
+        for _ in 0..100 {
+            assert!(budget.admit("https://gitlab.com/api/v4/projects").is_ok());
+        }
+        assert!(budget.cutoffs().is_empty());
+    }
+
+    #[test]
+    fn budget_cutoff_finds_the_refusal_behind_context() {
+        let budget = HttpBudget::new(HttpBudgetLimits {
+            max_requests_per_host: Some(0),
+            max_wall_clock: None,
+        });
+        let err = budget
+            .admit("https://company.atlassian.net/rest/api/3/search")
+            .context("search Jira issues")
+            .unwrap_err();
+
+        let cutoff = budget_cutoff(&err).expect("refusal should be found");
+        assert_eq!(cutoff.host, "company.atlassian.net");
+        assert!(cutoff_warning(cutoff).ends_with("coverage is partial."));
+        assert!(budget_cutoff(&anyhow::anyhow!("GET failed: 500")).is_none());
+    }
+
+    #[test]
+    fn rate_budget_keeps_the_lowest_remaining_per_host() {
+        use reqwest::header::{HeaderValue, RETRY_AFTER};
+
+        let headers = |remaining: &'static str, reset: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("x-ratelimit-limit", HeaderValue::from_static("30"));
+            headers.insert("x-ratelimit-remaining", HeaderValue::from_static(remaining));
+            headers.insert("x-ratelimit-reset", HeaderValue::from_static(reset));
+            headers
+        };
+        let budget = HttpBudget::default();
+        let github = "https://api.github.com/search/issues";
+        for _ in 0..3 {
+            budget.admit(github).unwrap();
+        }
+        budget.observe(github, StatusCode::OK, &headers("2", "1740830400"));
+        budget.observe(github, StatusCode::OK, &headers("9", "1740830460"));
+        let mut slowed = HeaderMap::new();
+        slowed.insert(RETRY_AFTER, HeaderValue::from_static("5"));
+        budget.observe(github, StatusCode::FORBIDDEN, &slowed);
+
+        let used = budget.rate_budget("github", github);
+        assert_eq!(used.host, "api.github.com");
+        assert_eq!(used.requests, 3);
+        assert_eq!(used.rate_limited, 1);
+        assert_eq!((used.limit, used.remaining), (Some(30), Some(2)));
+        assert_eq!(used.reset_at.map(|at| at.timestamp()), Some(1_740_830_400));
+        assert!(used.is_exhausted());
+
+        let untouched = budget.rate_budget("jira", "https://company.atlassian.net");
+        assert_eq!(untouched.requests, 0);
+        assert_eq!(untouched.remaining, None);
+        assert_eq!(untouched.warning(), None);
+    }
+}
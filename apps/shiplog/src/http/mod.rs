@@ -0,0 +1,280 @@
+#![warn(missing_docs)]
+//! Shared HTTP client semantics for shiplog ingest adapters.
+//!
+//! Adapters keep their own authentication and error mapping. This module owns
+//! the behaviour that must be identical across GitHub, GitLab, and Jira:
+//! conditional requests built from cached `ETag` / `Last-Modified`
+//! validators, and replaying the cached body when the server answers
+//! `304 Not Modified`. It also owns the run-wide request budget in
+//! [`HttpBudget`] and retrying rate limits and server errors with
+//! [`send_with_retry`].
+
+mod budget;
+mod retry;
+
+pub use budget::{
+    HttpBudget, HttpBudgetExhausted, HttpBudgetLimit, HttpBudgetLimits, budget_cutoff,
+    cutoff_warning,
+};
+pub use retry::{RetryPolicy, send_with_retry};
+
+use crate::cache::{ApiCache, CacheLookup, CacheValidators};
+use anyhow::{Context, Result, anyhow};
+use reqwest::StatusCode;
+use reqwest::blocking::RequestBuilder;
+use reqwest::header::{ETAG, HeaderMap, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Result of a conditional request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Revalidated<T> {
+    /// The server answered `304 Not Modified`; the cached body is current.
+    NotModified,
+    /// The server returned a new body with (possibly empty) validators.
+    Modified {
+        /// Decoded response body.
+        value: T,
+        /// Validators to store alongside the body.
+        validators: CacheValidators,
+    },
+}
+
+/// Where the value returned by [`fetch_with_revalidation`] came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CachedFetch<T> {
+    /// Unexpired cache entry; no request was sent.
+    Fresh(T),
+    /// Expired cache entry confirmed current by a `304 Not Modified`.
+    Revalidated(T),
+    /// Body downloaded from the server (cache miss or changed resource).
+    Fetched(T),
+}
+
+impl<T> CachedFetch<T> {
+    /// Unwrap the value regardless of where it came from.
+    pub fn into_value(self) -> T {
+        match self {
+            Self::Fresh(value) | Self::Revalidated(value) | Self::Fetched(value) => value,
+        }
+    }
+}
+
+/// Attach `If-None-Match` / `If-Modified-Since` headers for the given validators.
+///
+/// Passing `None` (or empty validators) leaves the request unconditional.
+pub fn with_validators(
+    mut req: RequestBuilder,
+    validators: Option<&CacheValidators>,
+) -> RequestBuilder {
+    let Some(validators) = validators else {
+        return req;
+    };
+    if let Some(etag) = &validators.etag {
+        req = req.header(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = &validators.last_modified {
+        req = req.header(IF_MODIFIED_SINCE, last_modified);
+    }
+    req
+}
+
+/// Capture `ETag` / `Last-Modified` from response headers.
+pub fn validators_from_headers(headers: &HeaderMap) -> CacheValidators {
+    let read = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    CacheValidators {
+        etag: read(ETAG),
+        last_modified: read(LAST_MODIFIED),
+    }
+}
+
+/// Whether a response status means "use your cached copy".
+pub fn is_not_modified(status: StatusCode) -> bool {
+    status == StatusCode::NOT_MODIFIED
+}
+
+/// Serve `key` from the cache, revalidating expired entries conditionally.
+///
+/// - Fresh entries are returned without calling `fetch`.
+/// - Stale entries call `fetch` with their stored validators; a
+///   [`Revalidated::NotModified`] answer restarts the entry's TTL and returns
+///   the cached body.
+/// - Misses (and stale entries without validators) call `fetch` with `None`
+///   and store the new body together with its validators.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::cache::{ApiCache, CacheValidators};
+/// use shiplog::http::{CachedFetch, Revalidated, fetch_with_revalidation};
+///
+/// let cache = ApiCache::open_in_memory()?;
+/// let first = fetch_with_revalidation(&cache, "k", |_| {
+///     Ok(Revalidated::Modified {
+///         value: 1_u32,
+///         validators: CacheValidators { etag: Some("\"a\"".into()), last_modified: None },
+///     })
+/// })?;
+/// assert_eq!(first, CachedFetch::Fetched(1));
+///
+/// let second = fetch_with_revalidation::<u32, _>(&cache, "k", |_| unreachable!())?;
+/// assert_eq!(second, CachedFetch::Fresh(1));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn fetch_with_revalidation<T, F>(
+    cache: &ApiCache,
+    key: &str,
+    fetch: F,
+) -> Result<CachedFetch<T>>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce(Option<&CacheValidators>) -> Result<Revalidated<T>>,
+{
+    let stale = match cache.lookup::<T>(key)? {
+        CacheLookup::Fresh(value) => return Ok(CachedFetch::Fresh(value)),
+        CacheLookup::Stale(value) => Some(value),
+        CacheLookup::Miss => None,
+    };
+
+    let validators = match stale {
+        Some(_) => cache.validators(key)?.filter(|v| !v.is_empty()),
+        None => None,
+    };
+
+    match fetch(validators.as_ref())? {
+        Revalidated::NotModified => {
+            let value = stale
+                .filter(|_| validators.is_some())
+                .ok_or_else(|| anyhow!("server answered 304 for uncached request {key}"))?;
+            cache
+                .refresh_expiry(key)
+                .with_context(|| format!("refresh cache expiry for {key}"))?;
+            Ok(CachedFetch::Revalidated(value))
+        }
+        Revalidated::Modified { value, validators } => {
+            cache.set_with_validators(key, &value, &validators)?;
+            Ok(CachedFetch::Fetched(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use reqwest::header::HeaderValue;
+
+    fn tagged(etag: &str) -> CacheValidators {
+        CacheValidators {
+            etag: Some(etag.to_string()),
+            last_modified: None,
+        }
+    }
+
+    #[test]
+    fn miss_fetches_unconditionally_and_stores_validators() -> Result<()> {
+        let cache = ApiCache::open_in_memory()?;
+        let got = fetch_with_revalidation(&cache, "k", |validators| {
+            assert!(validators.is_none());
+            Ok(Revalidated::Modified {
+                value: "body".to_string(),
+                validators: tagged("\"v1\""),
+            })
+        })?;
+
+        assert_eq!(got, CachedFetch::Fetched("body".to_string()));
+        assert_eq!(cache.validators("k")?, Some(tagged("\"v1\"")));
+        Ok(())
+    }
+
+    #[test]
+    fn stale_entry_is_revalidated_and_replayed_on_not_modified() -> Result<()> {
+        let cache = ApiCache::open_in_memory()?.with_ttl(Duration::seconds(-1));
+        cache.set_with_validators("k", &"cached".to_string(), &tagged("\"v1\""))?;
+
+        let got = fetch_with_revalidation::<String, _>(&cache, "k", |validators| {
+            assert_eq!(validators, Some(&tagged("\"v1\"")));
+            Ok(Revalidated::NotModified)
+        })?;
+
+        assert_eq!(got, CachedFetch::Revalidated("cached".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn stale_entry_is_replaced_when_modified() -> Result<()> {
+        let cache = ApiCache::open_in_memory()?;
+        cache.set_with_ttl("k", &"old".to_string(), Duration::seconds(-1))?;
+
+        let got = fetch_with_revalidation(&cache, "k", |validators| {
+            assert!(
+                validators.is_none(),
+                "entry without validators is a plain refetch"
+            );
+            Ok(Revalidated::Modified {
+                value: "new".to_string(),
+                validators: tagged("\"v2\""),
+            })
+        })?;
+
+        assert_eq!(got, CachedFetch::Fetched("new".to_string()));
+        assert_eq!(
+            cache.lookup::<String>("k")?,
+            CacheLookup::Fresh("new".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn not_modified_without_cached_body_is_an_error() -> Result<()> {
+        let cache = ApiCache::open_in_memory()?;
+        let err =
+            fetch_with_revalidation::<String, _>(&cache, "k", |_| Ok(Revalidated::NotModified))
+                .unwrap_err();
+        assert!(err.to_string().contains("304"));
+        Ok(())
+    }
+
+    #[test]
+    fn validators_from_headers_reads_etag_and_last_modified() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("W/\"abc\""));
+        headers.insert(
+            LAST_MODIFIED,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        let validators = validators_from_headers(&headers);
+        assert_eq!(validators.etag.as_deref(), Some("W/\"abc\""));
+        assert_eq!(
+            validators.last_modified.as_deref(),
+            Some("Wed, 21 Oct 2015 07:28:00 GMT")
+        );
+        assert!(validators_from_headers(&HeaderMap::new()).is_empty());
+    }
+
+    #[test]
+    fn with_validators_sets_conditional_headers() -> Result<()> {
+        let client = reqwest::blocking::Client::new();
+        let validators = CacheValidators {
+            etag: Some("\"v1\"".into()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".into()),
+        };
+        let req =
+            with_validators(client.get("https://example.invalid/"), Some(&validators)).build()?;
+        assert_eq!(req.headers()[IF_NONE_MATCH], "\"v1\"");
+        assert_eq!(
+            req.headers()[IF_MODIFIED_SINCE],
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+        );
+
+        let plain = with_validators(client.get("https://example.invalid/"), None).build()?;
+        assert!(plain.headers().get(IF_NONE_MATCH).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,398 @@
+//! Retry with backoff for transient HTTP failures.
+//!
+//! HTTP ingestors send their requests through [`send_with_retry`]. A rate-limit
+//! answer (`429`, or a `403` that carries `Retry-After` or an exhausted
+//! `X-RateLimit-Remaining`), a `5xx`, or a timed-out request is tried again
+//! after a delay instead of aborting the source. The delay is the one the
+//! server asked for through `Retry-After` or a rate-limit reset header when
+//! it sent one, and exponential backoff with jitter otherwise. A server that
+//! asks for a longer wait than [`RetryPolicy::max_delay`] is not retried, so
+//! an hour-long primary rate-limit reset still surfaces as a rate-limit
+//! error the caller can report.
+
+use std::hash::{BuildHasher, RandomState};
+use std::thread::sleep;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use reqwest::blocking::{RequestBuilder, Response};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+use super::HttpBudget;
+
+/// Reset headers that carry a Unix timestamp in seconds: GitHub's
+/// `X-RateLimit-Reset` and GitLab's `RateLimit-Reset`.
+const RESET_HEADERS: [&str; 2] = ["x-ratelimit-reset", "ratelimit-reset"];
+
+/// Requests left in the current window: GitHub's `X-RateLimit-Remaining` and
+/// GitLab's `RateLimit-Remaining`.
+pub(super) const REMAINING_HEADERS: [&str; 2] = ["x-ratelimit-remaining", "ratelimit-remaining"];
+
+/// Requests allowed per window: GitHub's `X-RateLimit-Limit` and GitLab's
+/// `RateLimit-Limit`.
+pub(super) const LIMIT_HEADERS: [&str; 2] = ["x-ratelimit-limit", "ratelimit-limit"];
+
+/// How often and how long to retry a transient failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt. `0` disables retrying.
+    pub max_retries: u32,
+    /// Backoff before the first retry; each later retry doubles it.
+    pub base_delay: Duration,
+    /// Longest single wait, whether from backoff or from the server.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_mins(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends each request once.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// How long to wait before retry number `attempt` (starting at `0`) of a
+    /// response with `status` and `headers`, or `None` to give up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use reqwest::StatusCode;
+    /// use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+    /// use shiplog::http::RetryPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = RetryPolicy::default();
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+    ///
+    /// assert_eq!(
+    ///     policy.delay_for(0, StatusCode::TOO_MANY_REQUESTS, &headers, Utc::now()),
+    ///     Some(Duration::from_secs(7))
+    /// );
+    /// assert_eq!(policy.delay_for(0, StatusCode::NOT_FOUND, &headers, Utc::now()), None);
+    /// ```
+    pub fn delay_for(
+        &self,
+        attempt: u32,
+        status: StatusCode,
+        headers: &HeaderMap,
+        now: DateTime<Utc>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries || !is_retryable(status, headers) {
+            return None;
+        }
+        match server_delay(headers, now) {
+            Some(wait) if wait > self.max_delay => None,
+            Some(wait) => Some(wait),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+
+    /// Exponential backoff for retry `attempt`, with up to 50% added jitter
+    /// so parallel runs do not retry in step.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter_ms = (exponential.as_millis() / 2) as u64;
+        let jitter = if jitter_ms == 0 {
+            0
+        } else {
+            RandomState::new().hash_one(attempt) % (jitter_ms + 1)
+        };
+        exponential
+            .saturating_add(Duration::from_millis(jitter))
+            .min(self.max_delay)
+    }
+}
+
+/// Send `req` to `url`, retrying transient failures under `policy`.
+///
+/// Each attempt, including each retry, is admitted by `budget` first, so a
+/// run that hits its request or wall-clock limit while waiting stops with the
+/// usual budget cutoff. Every response's rate-limit headers are recorded in
+/// `budget` for [`HttpBudget::rate_budget`]. The last response is returned as
+/// is, successful or not, for the caller's usual status handling.
+#[mutants::skip]
+pub fn send_with_retry(
+    policy: &RetryPolicy,
+    budget: &HttpBudget,
+    url: &str,
+    req: RequestBuilder,
+) -> Result<Response> {
+    let mut attempt = 0;
+    loop {
+        budget.admit(url)?;
+        let this_try = req
+            .try_clone()
+            .ok_or_else(|| anyhow!("request to {url} has a body that cannot be resent"))?;
+        let wait = match this_try.send() {
+            Ok(resp) => {
+                budget.observe(url, resp.status(), resp.headers());
+                match policy.delay_for(attempt, resp.status(), resp.headers(), Utc::now()) {
+                    Some(wait) => {
+                        tracing::debug!(
+                            status = resp.status().as_u16(),
+                            url,
+                            wait_ms = wait.as_millis() as u64,
+                            "retrying request"
+                        );
+                        wait
+                    }
+                    None => return Ok(resp),
+                }
+            }
+            Err(err) if attempt < policy.max_retries && err.is_timeout() => {
+                let wait = policy.backoff(attempt);
+                tracing::debug!(
+                    url,
+                    wait_ms = wait.as_millis() as u64,
+                    "retrying timed-out request"
+                );
+                wait
+            }
+            Err(err) => return Err(err.into()),
+        };
+        sleep(wait);
+        attempt += 1;
+    }
+}
+
+/// Rate limits and server errors are worth another attempt; other client
+/// errors will fail the same way again.
+fn is_retryable(status: StatusCode, headers: &HeaderMap) -> bool {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::FORBIDDEN => is_rate_limited(status, headers),
+        StatusCode::INTERNAL_SERVER_ERROR
+        | StatusCode::BAD_GATEWAY
+        | StatusCode::SERVICE_UNAVAILABLE
+        | StatusCode::GATEWAY_TIMEOUT => true,
+        _ => false,
+    }
+}
+
+/// Whether the server refused the request for rate limiting: a `429`, or a
+/// `403` that carries `Retry-After` or an exhausted `X-RateLimit-Remaining`.
+pub(super) fn is_rate_limited(status: StatusCode, headers: &HeaderMap) -> bool {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS => true,
+        StatusCode::FORBIDDEN => {
+            headers.contains_key(RETRY_AFTER)
+                || header_str(headers, "x-ratelimit-remaining") == Some("0")
+        }
+        _ => false,
+    }
+}
+
+/// The wait the server asked for: `Retry-After` in seconds or as an HTTP
+/// date, else the time until a rate-limit reset timestamp.
+fn server_delay(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    if let Some(value) = header_str(headers, RETRY_AFTER.as_str()) {
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+        if let Ok(at) = DateTime::parse_from_rfc2822(value) {
+            return Some(until(at.with_timezone(&Utc), now));
+        }
+    }
+    reset_at(headers).map(|at| until(at, now))
+}
+
+/// When the current rate-limit window resets, from a reset header that
+/// carries a Unix timestamp in seconds.
+pub(super) fn reset_at(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+    RESET_HEADERS
+        .iter()
+        .filter_map(|name| header_str(headers, name)?.parse::<i64>().ok())
+        .find_map(|seconds| DateTime::from_timestamp(seconds, 0))
+}
+
+/// First of `names` present in `headers`, parsed as a count.
+pub(super) fn header_count(headers: &HeaderMap, names: &[&str]) -> Option<u64> {
+    names
+        .iter()
+        .find_map(|name| header_str(headers, name)?.parse().ok())
+}
+
+fn until(at: DateTime<Utc>, now: DateTime<Utc>) -> Duration {
+    (at - now).to_std().unwrap_or_default()
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::HttpBudgetLimits;
+    use chrono::TimeZone;
+    use reqwest::header::HeaderValue;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn retries_rate_limits_and_server_errors_only() {
+        let policy = RetryPolicy::default();
+        let none = HeaderMap::new();
+        for status in [
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::BAD_GATEWAY,
+            StatusCode::SERVICE_UNAVAILABLE,
+        ] {
+            assert!(
+                policy.delay_for(0, status, &none, now()).is_some(),
+                "{status}"
+            );
+        }
+        for status in [
+            StatusCode::UNAUTHORIZED,
+            StatusCode::FORBIDDEN,
+            StatusCode::NOT_FOUND,
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ] {
+            assert!(
+                policy.delay_for(0, status, &none, now()).is_none(),
+                "{status}"
+            );
+        }
+        let exhausted = headers(&[("x-ratelimit-remaining", "0")]);
+        assert!(
+            policy
+                .delay_for(0, StatusCode::FORBIDDEN, &exhausted, now())
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn honors_retry_after_seconds_and_http_dates() {
+        let policy = RetryPolicy::default();
+        let seconds = headers(&[("retry-after", "12")]);
+        assert_eq!(
+            policy.delay_for(0, StatusCode::FORBIDDEN, &seconds, now()),
+            Some(Duration::from_secs(12))
+        );
+        let date = headers(&[("retry-after", "Sat, 01 Mar 2025 12:00:30 GMT")]);
+        assert_eq!(
+            policy.delay_for(0, StatusCode::TOO_MANY_REQUESTS, &date, now()),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn waits_for_rate_limit_reset_within_max_delay() {
+        let policy = RetryPolicy::default();
+        // 2025-03-01T12:00:45Z
+        let soon = headers(&[
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1740830445"),
+        ]);
+        assert_eq!(
+            policy.delay_for(0, StatusCode::FORBIDDEN, &soon, now()),
+            Some(Duration::from_secs(45))
+        );
+        // 2025-03-01T13:00:00Z is past the 60-second cap.
+        let later = headers(&[("ratelimit-reset", "1740834000")]);
+        assert_eq!(
+            policy.delay_for(0, StatusCode::TOO_MANY_REQUESTS, &later, now()),
+            None
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_with_bounded_jitter_and_stops_after_max_retries() {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(5),
+        };
+        let none = HeaderMap::new();
+        let status = StatusCode::SERVICE_UNAVAILABLE;
+
+        let first = policy.delay_for(0, status, &none, now()).unwrap();
+        assert!(first >= Duration::from_secs(2) && first <= Duration::from_secs(3));
+        let second = policy.delay_for(1, status, &none, now()).unwrap();
+        assert!(second >= Duration::from_secs(4) && second <= Duration::from_secs(5));
+        assert_eq!(
+            policy.delay_for(2, status, &none, now()),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(policy.delay_for(3, status, &none, now()), None);
+        assert_eq!(RetryPolicy::none().delay_for(0, status, &none, now()), None);
+    }
+
+    #[test]
+    fn send_with_retry_resends_after_a_server_error() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/search", listener.local_addr()?);
+        let server = thread::spawn(move || {
+            for reply in [
+                "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                "HTTP/1.1 200 OK\r\nratelimit-remaining: 7\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok",
+            ] {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                stream.write_all(reply.as_bytes()).unwrap();
+            }
+        });
+        let policy = RetryPolicy {
+            base_delay: Duration::ZERO,
+            ..RetryPolicy::default()
+        };
+        let budget = HttpBudget::new(HttpBudgetLimits {
+            max_requests_per_host: Some(2),
+            max_wall_clock: None,
+        });
+
+        let client = reqwest::blocking::Client::new();
+        let resp = send_with_retry(&policy, &budget, &url, client.get(&url))?;
+        server.join().unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.text()?, "ok");
+        let used = budget.rate_budget("gitlab", &url);
+        assert_eq!((used.requests, used.remaining), (2, Some(7)));
+        assert!(budget.admit(&url).is_err(), "both attempts were counted");
+        Ok(())
+    }
+}
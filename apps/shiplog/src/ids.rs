@@ -1,12 +1,15 @@
 #![warn(missing_docs)]
 //! Stable identifier types used across the shiplog pipeline.
 //!
-//! Includes deterministic SHA-256 constructors for event/workstream IDs and a
-//! timestamp-based run ID helper.
+//! Includes deterministic SHA-256 constructors for event/workstream IDs,
+//! cross-source canonical identities, hashed provider IDs, and sortable
+//! ULID-based run IDs.
 
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 use sha2::{Digest, Sha256};
 use std::fmt;
+use std::sync::Mutex;
 
 /// Stable identifiers used across the shiplog pipeline.
 ///
@@ -28,7 +31,7 @@ use std::fmt;
 /// let id2 = EventId::from_parts(["github", "pr", "owner/repo", "42"]);
 /// assert_eq!(id, id2);
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct EventId(pub String);
 
@@ -58,7 +61,161 @@ impl fmt::Display for WorkstreamId {
     }
 }
 
-/// A timestamp-based run identifier.
+/// A content-derived identity shared by every copy of the same source object.
+///
+/// [`EventId`]s depend on how an event was ingested (GitLab uses its global MR
+/// id, a team member's ledger may use another scheme), so the same pull
+/// request can arrive under several event ids. The canonical id hashes only
+/// the normalized repository and a source-native key such as `pull/42`, so
+/// merge can collapse those copies.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ids::CanonicalId;
+///
+/// let a = CanonicalId::from_native("Acme/Widgets", "pull/42");
+/// let b = CanonicalId::from_native("https://github.com/acme/widgets.git", "pull/42");
+/// assert_eq!(a, b);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CanonicalId(pub String);
+
+impl fmt::Display for CanonicalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl CanonicalId {
+    /// Canonical id for `native_key` within `repo`.
+    ///
+    /// The repository is normalized with [`normalize_repo`]; the key is
+    /// trimmed and lowercased.
+    pub fn from_native(repo: &str, native_key: &str) -> Self {
+        Self(hash_hex([
+            "canonical",
+            &normalize_repo(repo),
+            &native_key.trim().to_ascii_lowercase(),
+        ]))
+    }
+}
+
+/// Normalize a repository reference to lowercase `owner/name`.
+///
+/// Accepts plain full names, web URLs, and clone URLs: the scheme and host,
+/// a `git@host:` prefix, trailing slashes, and a `.git` suffix are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ids::normalize_repo;
+///
+/// assert_eq!(normalize_repo("git@github.com:Acme/Widgets.git"), "acme/widgets");
+/// assert_eq!(normalize_repo("https://gitlab.com/group/sub/proj/"), "group/sub/proj");
+/// ```
+pub fn normalize_repo(repo: &str) -> String {
+    let repo = repo.trim();
+    let path = if let Some((_, rest)) = repo.split_once("://") {
+        rest.split_once('/').map_or("", |(_, path)| path)
+    } else if let Some(rest) = repo.strip_prefix("git@") {
+        rest.split_once(':').map_or(rest, |(_, path)| path)
+    } else {
+        repo
+    };
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    path.to_ascii_lowercase()
+}
+
+/// A provider identifier hashed at the ingest boundary.
+///
+/// Raw provider ids (GitHub node ids, Jira account ids, Linear UUIDs) must
+/// not reach ledgers or bundles. Hashed ids are written as `sha256:<hex>`, so
+/// a stored value is never mistaken for a raw id or the other way round.
+/// Deserializing accepts only that form; raw ids in ledgers written before
+/// ids were hashed are hashed by [`SourceRef`](crate::schema::event::SourceRef)
+/// under their source system, the same scope ingest uses.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ids::OpaqueId;
+///
+/// let id = OpaqueId::from_raw("github", "PR_kwDOAbc123");
+/// assert_eq!(id, OpaqueId::from_raw("github", "PR_kwDOAbc123"));
+/// assert!(id.as_str().starts_with("sha256:"));
+/// assert!(!id.as_str().contains("PR_kwDOAbc123"));
+/// assert_eq!(OpaqueId::from_stored("github", "PR_kwDOAbc123"), id);
+/// assert_eq!(OpaqueId::from_stored("jira", id.as_str()), id);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct OpaqueId(String);
+
+impl fmt::Display for OpaqueId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'de> Deserialize<'de> for OpaqueId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        if OpaqueId::is_hashed(&value) {
+            Ok(Self(value))
+        } else {
+            Err(serde::de::Error::custom(format!(
+                "opaque id {value:?} is not a {OPAQUE_ID_PREFIX}<hex> hash"
+            )))
+        }
+    }
+}
+
+/// Marks a stored [`OpaqueId`] as hashed.
+const OPAQUE_ID_PREFIX: &str = "sha256:";
+
+impl OpaqueId {
+    /// Hash a raw provider id, scoped by the system that issued it.
+    pub fn from_raw(system: &str, raw: &str) -> Self {
+        Self(format!(
+            "{OPAQUE_ID_PREFIX}{}",
+            hash_hex(["opaque", system, raw])
+        ))
+    }
+
+    /// Read a stored id: `sha256:<hex>` values are kept, and anything else is
+    /// a raw id from an older ledger and is hashed under `system`.
+    pub fn from_stored(system: &str, value: &str) -> Self {
+        if Self::is_hashed(value) {
+            Self(value.to_string())
+        } else {
+            Self::from_raw(system, value)
+        }
+    }
+
+    fn is_hashed(value: &str) -> bool {
+        value.strip_prefix(OPAQUE_ID_PREFIX).is_some_and(|hex| {
+            hex.len() == 64
+                && hex
+                    .bytes()
+                    .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        })
+    }
+
+    /// The stored form: `sha256:` followed by the hex digest.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A run identifier of the form `<prefix>_<ULID>`.
+///
+/// The ULID suffix starts with the creation time in milliseconds, so run IDs
+/// with the same prefix sort chronologically as plain strings. IDs written by
+/// older builds use a decimal nanosecond suffix; [`RunId::parse`] and
+/// [`RunId::created_at`] accept both.
 ///
 /// # Examples
 ///
@@ -67,6 +224,7 @@ impl fmt::Display for WorkstreamId {
 ///
 /// let id = RunId::now("shiplog");
 /// assert!(id.0.starts_with("shiplog_"));
+/// assert!(id.created_at().is_some());
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -118,8 +276,48 @@ impl WorkstreamId {
     }
 }
 
+/// Error returned by [`RunId::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunIdError {
+    input: String,
+}
+
+impl fmt::Display for RunIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid run id {:?}: expected <prefix>_<ULID> or <prefix>_<nanoseconds>",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for RunIdError {}
+
+/// Digits in a legacy nanosecond suffix for any date from 2001 to 2262.
+const LEGACY_NANOS_LEN: usize = 19;
+
+/// Legacy nanosecond suffixes outside 2015-01-01..2100-01-01 are not
+/// timestamps shiplog ever wrote; `run_1` or `run_2024` are run names.
+const LEGACY_NANOS_RANGE: std::ops::Range<i64> =
+    1_420_070_400_000_000_000..4_102_444_800_000_000_000;
+
+/// The same 2015-01-01..2100-01-01 bound for a ULID's millisecond field, so
+/// a hand-named 26-character suffix is not read as a made-up date.
+const ULID_MILLIS_RANGE: std::ops::Range<u128> = 1_420_070_400_000..4_102_444_800_000;
+
+/// Last ULID handed out, so IDs minted within one millisecond still increase.
+static LAST_ULID: Mutex<u128> = Mutex::new(0);
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ULID_LEN: usize = 26;
+
 impl RunId {
-    /// Non-deterministic enough to avoid collisions without dragging in UUID/rand.
+    /// Mint a new sortable run id without dragging in UUID/rand.
+    ///
+    /// The 80 random ULID bits come from hashing the clock, process id, and a
+    /// per-process sequence. Calls within the same millisecond increment the
+    /// previous value, so IDs from one process are strictly increasing.
     ///
     /// # Examples
     ///
@@ -127,17 +325,121 @@ impl RunId {
     /// use shiplog::ids::RunId;
     ///
     /// let run = RunId::now("shiplog");
-    /// // Each call generates a unique ID:
+    /// // Each call generates a unique, later-sorting ID:
     /// let run2 = RunId::now("shiplog");
-    /// assert_ne!(run, run2);
+    /// assert!(run2.0 > run.0);
     /// ```
     pub fn now(prefix: &str) -> Self {
-        let nanos = std::time::SystemTime::now()
+        let elapsed = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos();
-        RunId(format!("{prefix}_{nanos}"))
+            .unwrap_or_default();
+        let millis = elapsed.as_millis() & ((1 << 48) - 1);
+
+        let mut last = LAST_ULID
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let ulid = if millis <= *last >> 80 {
+            *last + 1
+        } else {
+            let seed = hash_hex([
+                elapsed.as_nanos().to_string(),
+                std::process::id().to_string(),
+                last.to_string(),
+            ]);
+            let entropy = u128::from_str_radix(&seed[..32], 16).unwrap_or_default();
+            (millis << 80) | (entropy & ((1 << 80) - 1))
+        };
+        *last = ulid;
+
+        RunId(format!("{prefix}_{}", encode_ulid(ulid)))
+    }
+
+    /// Validate a run id string, accepting ULID and legacy nanosecond suffixes.
+    ///
+    /// The prefix is everything before the last `_` and may be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::ids::RunId;
+    ///
+    /// assert!(RunId::parse("shiplog_01JABCDEFGHJKMNPQRSTVWXYZ0").is_ok());
+    /// assert!(RunId::parse("shiplog_1735689600000000000").is_ok());
+    /// assert!(RunId::parse("my-run").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, RunIdError> {
+        let id = RunId(s.to_string());
+        match id.created_at() {
+            Some(_) => Ok(id),
+            None => Err(RunIdError {
+                input: s.to_string(),
+            }),
+        }
+    }
+
+    /// When the run id was minted, read from its suffix.
+    ///
+    /// Returns `None` for ids that are not `<prefix>_<ULID>` or
+    /// `<prefix>_<nanoseconds>`, such as hand-named run directories. A
+    /// nanosecond suffix must have 19 digits, and either suffix must fall
+    /// between 2015 and 2100, so numbered names such as `run_1` and words
+    /// that happen to be 26 Crockford characters are not read as dates.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        let (_, suffix) = self.0.rsplit_once('_')?;
+        if let Some(ulid) = decode_ulid(suffix) {
+            let millis = ulid >> 80;
+            return ULID_MILLIS_RANGE
+                .contains(&millis)
+                .then(|| DateTime::from_timestamp_millis(millis as i64))
+                .flatten();
+        }
+        if suffix.len() != LEGACY_NANOS_LEN || !suffix.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let nanos: i64 = suffix.parse().ok()?;
+        LEGACY_NANOS_RANGE
+            .contains(&nanos)
+            .then(|| DateTime::from_timestamp_nanos(nanos))
+    }
+
+    /// Key that orders run ids chronologically regardless of prefix.
+    ///
+    /// Ties within a millisecond fall back to the ULID suffix, which is
+    /// monotonic per process. Ids without a readable creation time, such as
+    /// hand-named run directories, sort after all minted ones, by name.
+    pub fn sort_key(&self) -> (DateTime<Utc>, &str) {
+        match (self.created_at(), self.0.rsplit_once('_')) {
+            (Some(created), Some((_, suffix))) => (created, suffix),
+            _ => (DateTime::<Utc>::MAX_UTC, &self.0),
+        }
+    }
+}
+
+fn encode_ulid(mut value: u128) -> String {
+    let mut out = [0u8; ULID_LEN];
+    for slot in out.iter_mut().rev() {
+        *slot = CROCKFORD[(value & 0x1f) as usize];
+        value >>= 5;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn decode_ulid(text: &str) -> Option<u128> {
+    if text.len() != ULID_LEN {
+        return None;
     }
+    let mut value: u128 = 0;
+    for (i, byte) in text.bytes().enumerate() {
+        let digit = CROCKFORD
+            .iter()
+            .position(|&c| c == byte.to_ascii_uppercase())?;
+        // The leading character only carries the top 3 of 128 bits.
+        if i == 0 && digit > 7 {
+            return None;
+        }
+        value = (value << 5) | digit as u128;
+    }
+    Some(value)
 }
 
 fn hash_hex(parts: impl IntoIterator<Item = impl AsRef<str>>) -> String {
@@ -194,12 +496,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn canonical_id_ignores_repo_spelling_but_not_key() {
+        let a = CanonicalId::from_native("acme/widgets", "pull/42");
+        for repo in [
+            "Acme/Widgets",
+            " acme/widgets/ ",
+            "https://github.com/acme/widgets",
+            "https://github.example.com/acme/widgets.git",
+            "git@github.com:acme/widgets.git",
+        ] {
+            assert_eq!(CanonicalId::from_native(repo, "Pull/42 "), a, "{repo}");
+        }
+        assert_ne!(CanonicalId::from_native("acme/widgets", "pull/43"), a);
+        assert_ne!(CanonicalId::from_native("acme/gadgets", "pull/42"), a);
+        assert_ne!(a.0, EventId::from_parts(["acme/widgets", "pull/42"]).0);
+    }
+
+    #[test]
+    fn opaque_id_hashes_raw_values_and_keeps_hashes_on_read() {
+        let id = OpaqueId::from_raw("jira", "5b10ac8d82e05b22cc7d4ef5");
+        assert_eq!(id.as_str().len(), "sha256:".len() + 64);
+        assert_ne!(id, OpaqueId::from_raw("linear", "5b10ac8d82e05b22cc7d4ef5"));
+
+        let json = serde_json::to_string(&id).unwrap();
+        let back: OpaqueId = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, id);
+
+        // A raw id is never taken for a hash, even when it looks like one.
+        let hex_raw = "ab".repeat(32);
+        assert!(serde_json::from_str::<OpaqueId>(&format!("{hex_raw:?}")).is_err());
+        assert_eq!(
+            OpaqueId::from_stored("local_git", &hex_raw),
+            OpaqueId::from_raw("local_git", &hex_raw)
+        );
+        assert!(serde_json::from_str::<OpaqueId>(r#""MDExOlB1bGxSZXF1ZXN0MQ==""#).is_err());
+    }
+
     #[test]
     fn run_id_starts_with_prefix() {
         let id = RunId::now("shiplog");
         assert!(id.0.starts_with("shiplog_"));
     }
 
+    #[test]
+    fn run_ids_are_monotonic_and_carry_their_creation_time() {
+        let before = Utc::now();
+        let ids: Vec<RunId> = (0..100).map(|_| RunId::now("shiplog")).collect();
+        assert!(ids.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        let created = ids[0].created_at().unwrap();
+        assert!((created - before).num_seconds().abs() <= 1);
+        assert_eq!(ids[0].0.len(), "shiplog_".len() + ULID_LEN);
+    }
+
+    #[test]
+    fn ulid_encoding_round_trips() {
+        for value in [0, 1, u128::MAX, 0x0123_4567_89ab_cdef_u128 << 40] {
+            assert_eq!(decode_ulid(&encode_ulid(value)), Some(value));
+        }
+        assert_eq!(decode_ulid("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"), None);
+        assert_eq!(decode_ulid("0123456789ABCDEFGHJKMNPQRI"), None);
+    }
+
+    #[test]
+    fn run_id_parse_accepts_ulid_and_legacy_suffixes() {
+        let legacy = RunId::parse("merge_1735689600000000000").unwrap();
+        assert_eq!(
+            legacy.created_at().unwrap().to_rfc3339(),
+            "2025-01-01T00:00:00+00:00"
+        );
+        let minted = RunId::now("test/run_2025");
+        assert_eq!(RunId::parse(&minted.0), Ok(minted));
+
+        for bad in [
+            "",
+            "shiplog",
+            "shiplog_",
+            "shiplog_12ab",
+            "custom-run-name",
+            "run_1",
+            "run_2024",
+            "run_0000000000000000001",
+            "run_9999999999999999999",
+            "run_17356896000000000000",
+            "run_00000000000000000000000000",
+            "run_7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+        ] {
+            let err = RunId::parse(bad).unwrap_err();
+            assert!(err.to_string().contains("invalid run id"), "{bad}");
+        }
+    }
+
+    #[test]
+    fn sort_key_orders_across_prefixes_and_formats() {
+        let mut ids = [
+            RunId::now("team"),
+            RunId("shiplog_1735689600000000000".into()),
+            RunId("hand-named".into()),
+            RunId::now("merge"),
+        ];
+        ids.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        let names: Vec<&str> = ids
+            .iter()
+            .map(|id| id.0.split('_').next().unwrap())
+            .collect();
+        assert_eq!(names, ["shiplog", "team", "merge", "hand-named"]);
+    }
+
     #[test]
     fn display_matches_inner() {
         let id = EventId::from_parts(["display", "test"]);
@@ -0,0 +1,942 @@
+//! Bitbucket Cloud API ingestor with cache support.
+//!
+//! Collects pull requests authored by a user through the 2.0 API, tracks
+//! coverage slices, and marks partial completeness when the page cap or the
+//! request budget stops pagination early.
+
+use crate::cache::{ApiCache, CacheKey, CacheValidators};
+use crate::coverage::ReportingTimezone;
+use crate::http::{
+    HttpBudget, RetryPolicy, Revalidated, budget_cutoff, cutoff_warning, fetch_with_revalidation,
+    is_not_modified, send_with_retry, validators_from_headers, with_validators,
+};
+use crate::ingest::error::IngestError;
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ports::{IngestOutput, IngestPlan, Ingestor, PlannedQuery};
+use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::event::{
+    Actor, EventEnvelope, EventKind, EventPayload, Link, PullRequestEvent, PullRequestState,
+    RepoRef, RepoVisibility, SourceRef, SourceSystem,
+};
+use shiplog::schema::version::SchemaVersion;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// Pull requests requested per page; 50 is the 2.0 API maximum.
+const PAGE_LEN: u32 = 50;
+
+/// Bitbucket pull request state filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrState {
+    Open,
+    Merged,
+    Declined,
+    Superseded,
+    All,
+}
+
+impl PrState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Open => "open",
+            Self::Merged => "merged",
+            Self::Declined => "declined",
+            Self::Superseded => "superseded",
+            Self::All => "all",
+        }
+    }
+
+    /// Values for the API's repeatable `state` parameter. Without one the
+    /// API returns open pull requests only, so `All` names every state.
+    fn api_states(&self) -> Vec<&'static str> {
+        match self {
+            Self::Open => vec!["OPEN"],
+            Self::Merged => vec!["MERGED"],
+            Self::Declined => vec!["DECLINED"],
+            Self::Superseded => vec!["SUPERSEDED"],
+            Self::All => vec!["OPEN", "MERGED", "DECLINED", "SUPERSEDED"],
+        }
+    }
+}
+
+impl std::str::FromStr for PrState {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "open" | "opened" => Ok(Self::Open),
+            "merged" => Ok(Self::Merged),
+            "declined" | "closed" => Ok(Self::Declined),
+            "superseded" => Ok(Self::Superseded),
+            "all" => Ok(Self::All),
+            _ => Err(anyhow!("Invalid pull request state: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BitbucketIngestor {
+    /// Bitbucket username or `{account UUID}` whose pull requests are collected.
+    pub user: String,
+    pub since: NaiveDate,
+    pub until: NaiveDate,
+    pub state: PrState,
+    /// Optional workspace slug; unset searches every workspace the
+    /// credentials can read.
+    pub workspace: Option<String>,
+    pub throttle_ms: u64,
+    /// Username the app password belongs to.
+    pub auth_user: Option<String>,
+    pub app_password: Option<String>,
+    /// Pages followed before the collection is marked partial.
+    pub max_pages: u32,
+    /// Optional cache for API responses
+    pub cache: Option<ApiCache>,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
+    /// Run-wide request budget shared with the other ingestors. Default:
+    /// unlimited.
+    pub http_budget: HttpBudget,
+    /// Retries for rate-limited and failed requests. Default: three, with
+    /// backoff.
+    pub retry: RetryPolicy,
+}
+
+impl BitbucketIngestor {
+    pub fn new(user: String, since: NaiveDate, until: NaiveDate) -> Self {
+        Self {
+            user,
+            since,
+            until,
+            state: PrState::Merged,
+            workspace: None,
+            throttle_ms: 0,
+            auth_user: None,
+            app_password: None,
+            max_pages: 10,
+            cache: None,
+            timezone: ReportingTimezone::UTC,
+            http_budget: HttpBudget::default(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Authenticate with an app password created by `auth_user`.
+    pub fn with_app_password(mut self, auth_user: String, app_password: String) -> Result<Self> {
+        if auth_user.is_empty() {
+            return Err(anyhow!("Bitbucket username cannot be empty"));
+        }
+        if app_password.is_empty() {
+            return Err(anyhow!("Bitbucket app password cannot be empty"));
+        }
+        self.auth_user = Some(auth_user);
+        self.app_password = Some(app_password);
+        Ok(self)
+    }
+
+    /// Only collect pull requests in `workspace`.
+    pub fn with_workspace(mut self, workspace: String) -> Result<Self> {
+        if workspace.is_empty() || workspace.contains('/') {
+            return Err(anyhow!("Invalid Bitbucket workspace: {:?}", workspace));
+        }
+        self.workspace = Some(workspace);
+        Ok(self)
+    }
+
+    /// Set the pull request state filter.
+    pub fn with_state(mut self, state: PrState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Stop after `pages` pages of results and mark the run partial.
+    pub fn with_max_pages(mut self, pages: u32) -> Result<Self> {
+        if pages == 0 {
+            return Err(anyhow!("Bitbucket page cap must be at least 1"));
+        }
+        self.max_pages = pages;
+        Ok(self)
+    }
+
+    /// Count requests against `budget`, shared with the run's other
+    /// ingestors.
+    pub fn with_http_budget(mut self, budget: HttpBudget) -> Self {
+        self.http_budget = budget;
+        self
+    }
+
+    /// Retry rate-limited and failed requests under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Read window dates in `timezone` instead of UTC.
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Enable caching with the given cache directory.
+    pub fn with_cache(mut self, cache_dir: impl Into<PathBuf>) -> Result<Self> {
+        let cache_path = cache_dir.into().join("bitbucket-api-cache.db");
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("create Bitbucket cache directory {parent:?}"))?;
+        }
+        let cache = ApiCache::open(cache_path)?;
+        self.cache = Some(cache);
+        Ok(self)
+    }
+
+    /// Enable in-memory caching (useful for testing).
+    pub fn with_in_memory_cache(mut self) -> Result<Self> {
+        let cache = ApiCache::open_in_memory()?;
+        self.cache = Some(cache);
+        Ok(self)
+    }
+
+    /// Set throttle delay between API requests (in milliseconds).
+    pub fn with_throttle(mut self, ms: u64) -> Self {
+        self.throttle_ms = ms;
+        self
+    }
+
+    fn html_base_url(&self) -> String {
+        "https://bitbucket.org".to_string()
+    }
+
+    fn api_base_url(&self) -> String {
+        "https://api.bitbucket.org/2.0".to_string()
+    }
+
+    #[mutants::skip]
+    fn client(&self) -> Result<Client> {
+        Client::builder()
+            .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .context("build reqwest client")
+    }
+
+    fn pullrequests_url(&self) -> String {
+        let user = encode_path_segment(&self.user);
+        match &self.workspace {
+            Some(workspace) => format!(
+                "{}/workspaces/{}/pullrequests/{user}",
+                self.api_base_url(),
+                encode_path_segment(workspace)
+            ),
+            None => format!("{}/pullrequests/{user}", self.api_base_url()),
+        }
+    }
+
+    #[mutants::skip]
+    fn throttle(&self) {
+        if self.throttle_ms > 0 {
+            sleep(Duration::from_millis(self.throttle_ms));
+        }
+    }
+
+    #[mutants::skip]
+    fn get_json<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+    ) -> Result<T> {
+        match self.get_json_conditional(client, url, params, None)? {
+            Revalidated::Modified { value, .. } => Ok(value),
+            Revalidated::NotModified => Err(anyhow!(
+                "Bitbucket API answered 304 Not Modified to an unconditional request for {url}"
+            )),
+        }
+    }
+
+    /// Fetch JSON through the response cache, revalidating stale entries.
+    #[mutants::skip]
+    fn get_json_cached<T: DeserializeOwned + Serialize>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        cache_key: &str,
+    ) -> Result<T> {
+        match self.cache {
+            Some(ref cache) => Ok(fetch_with_revalidation(cache, cache_key, |validators| {
+                self.get_json_conditional(client, url, params, validators)
+            })?
+            .into_value()),
+            None => self.get_json(client, url, params),
+        }
+    }
+
+    #[mutants::skip]
+    fn get_json_conditional<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        validators: Option<&CacheValidators>,
+    ) -> Result<Revalidated<T>> {
+        let request_url = build_url_with_params(url, params)?;
+        let request_url_for_err = request_url.as_str().to_string();
+
+        let mut req = client.get(request_url).header("Accept", "application/json");
+
+        // App passwords use HTTP Basic auth with the owning username.
+        if let (Some(auth_user), Some(password)) = (&self.auth_user, &self.app_password) {
+            req = req.basic_auth(auth_user, Some(password));
+        }
+
+        let resp = send_with_retry(
+            &self.retry,
+            &self.http_budget,
+            &request_url_for_err,
+            with_validators(req, validators),
+        )
+        .with_context(|| format!("GET {request_url_for_err}"))?;
+        self.throttle();
+
+        let status = resp.status();
+        if is_not_modified(status) {
+            return Ok(Revalidated::NotModified);
+        }
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            let body = resp.text().unwrap_or_default();
+            return Err(IngestError::from_response("Bitbucket", status, &headers, body).into());
+        }
+
+        let validators = validators_from_headers(resp.headers());
+        let value = resp.json::<T>().map_err(|err| IngestError::Parse {
+            system: "Bitbucket",
+            context: request_url_for_err.to_string(),
+            detail: err.to_string(),
+        })?;
+        Ok(Revalidated::Modified { value, validators })
+    }
+
+    /// The `q` filter for pull requests created in the window.
+    fn created_filter(&self) -> String {
+        format!(
+            "created_on >= {} AND created_on < {}",
+            self.timezone.query_bound(self.since),
+            self.timezone.query_bound(self.until)
+        )
+    }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("q", self.created_filter()),
+            ("sort", "created_on".to_string()),
+            ("pagelen", PAGE_LEN.to_string()),
+        ];
+        for state in self.state.api_states() {
+            params.push(("state", state.to_string()));
+        }
+        params
+    }
+
+    fn coverage_query(&self) -> String {
+        let mut parts = vec![format!("author = '{}'", self.user)];
+        if let Some(workspace) = &self.workspace {
+            parts.push(format!("workspace = '{workspace}'"));
+        }
+        parts.push(self.created_filter());
+        parts.push(format!("state IN ({})", self.state.api_states().join(", ")));
+        parts.join(" AND ")
+    }
+
+    /// Page through the user's pull requests, following `next` links until
+    /// they run out or [`BitbucketIngestor::max_pages`] is reached.
+    #[mutants::skip]
+    #[tracing::instrument(
+        name = "window",
+        level = "debug",
+        skip_all,
+        fields(since = %self.since, until = %self.until)
+    )]
+    fn collect_pull_requests(
+        &self,
+        client: &Client,
+    ) -> Result<(Vec<BitbucketPullRequest>, Vec<CoverageSlice>, bool)> {
+        let query = self.coverage_query();
+        let first_url = self.pullrequests_url();
+        let first_params = self.query_params();
+        let mut next: Option<String> = None;
+        let mut pull_requests = Vec::new();
+        let mut total_count: Option<u64> = None;
+        let mut partial = false;
+
+        for page in 1..=self.max_pages {
+            let (url, params) = match &next {
+                Some(url) => (url.as_str(), &[][..]),
+                None => (first_url.as_str(), &first_params[..]),
+            };
+            let cache_key = CacheKey::bitbucket_pullrequests(&format!("{first_url} {query}"), page);
+            let response: BitbucketPage<BitbucketPullRequest> =
+                match self.get_json_cached(client, url, params, &cache_key) {
+                    Ok(response) => response,
+                    // Keep the pages already fetched; `ingest` explains the cutoff.
+                    Err(err) if budget_cutoff(&err).is_some() => {
+                        partial = true;
+                        break;
+                    }
+                    Err(err) => return Err(err),
+                };
+
+            total_count = total_count.or(response.size);
+            pull_requests.extend(response.values);
+            next = response.next;
+            if next.is_none() {
+                break;
+            }
+        }
+        if next.is_some() {
+            partial = true;
+        }
+
+        let fetched = pull_requests.len() as u64;
+        let slices = vec![CoverageSlice {
+            window: TimeWindow {
+                since: self.since,
+                until: self.until,
+            },
+            query,
+            total_count: total_count.unwrap_or(fetched).max(fetched),
+            fetched,
+            incomplete_results: Some(partial),
+            notes: vec!["pullrequests:bitbucket".to_string()],
+            source: Some("bitbucket".to_string()),
+        }];
+
+        Ok((pull_requests, slices, partial))
+    }
+
+    /// Convert Bitbucket pull requests to shiplog events
+    #[mutants::skip]
+    fn pull_requests_to_events(
+        &self,
+        pull_requests: Vec<BitbucketPullRequest>,
+    ) -> Result<Vec<EventEnvelope>> {
+        let mut events = Vec::new();
+        let html_base = self.html_base_url();
+
+        for pr in pull_requests {
+            let repo_name = pr.destination.repository.full_name.clone();
+            let state = match pr.state.as_str() {
+                "OPEN" => PullRequestState::Open,
+                "MERGED" => PullRequestState::Merged,
+                "DECLINED" | "SUPERSEDED" => PullRequestState::Closed,
+                _ => PullRequestState::Unknown,
+            };
+            // The 2.0 API has no merge timestamp; a merged pull request is
+            // not updated after the merge, so `updated_on` stands in for it.
+            let merged_at = (state == PullRequestState::Merged)
+                .then_some(pr.updated_on)
+                .flatten();
+            let pr_url = pr
+                .links
+                .html
+                .as_ref()
+                .map(|link| link.href.clone())
+                .unwrap_or_else(|| format!("{}/{}/pull-requests/{}", html_base, repo_name, pr.id));
+            let raw_id = format!("{}#{}", repo_name, pr.id);
+
+            let event = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
+                id: EventId::from_parts(["bitbucket", "pr", &repo_name, &pr.id.to_string()]),
+                kind: EventKind::PullRequest,
+                occurred_at: pr.created_on,
+                actor: Actor {
+                    login: pr
+                        .author
+                        .as_ref()
+                        .and_then(|author| author.nickname.clone())
+                        .unwrap_or_else(|| self.user.clone()),
+                    id: None, // Bitbucket identifies accounts by UUID, not u64
+                },
+                repo: RepoRef {
+                    full_name: repo_name.clone(),
+                    html_url: Some(format!("{}/{}", html_base, repo_name)),
+                    visibility: RepoVisibility::Private,
+                },
+                payload: EventPayload::PullRequest(PullRequestEvent {
+                    number: pr.id,
+                    title: pr.title,
+                    state,
+                    created_at: pr.created_on,
+                    merged_at,
+                    additions: None,
+                    deletions: None,
+                    changed_files: None,
+                    touched_paths_hint: vec![],
+                    window: None,
+                }),
+                tags: vec![],
+                links: vec![Link {
+                    label: "Bitbucket PR".to_string(),
+                    url: pr_url.clone(),
+                }],
+                source: SourceRef {
+                    system: SourceSystem::Other("bitbucket".to_string()),
+                    url: Some(pr_url),
+                    opaque_id: Some(OpaqueId::from_raw("bitbucket", &raw_id)),
+                },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
+            };
+
+            events.push(event.with_canonical_id());
+        }
+
+        Ok(events)
+    }
+}
+
+impl Ingestor for BitbucketIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "bitbucket"))]
+    #[mutants::skip]
+    fn ingest(&self) -> Result<IngestOutput> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+
+        if self.app_password.is_none() {
+            return Err(anyhow!(
+                "Bitbucket app password is required. Set it using with_app_password() or BITBUCKET_USERNAME and BITBUCKET_APP_PASSWORD environment variables"
+            ));
+        }
+
+        let client = self.client()?;
+        let run_id = RunId::now("shiplog");
+        let mut warnings: Vec<String> = Vec::new();
+        let mut completeness = Completeness::Complete;
+
+        let (pull_requests, slices, partial) = self.collect_pull_requests(&client)?;
+        if partial {
+            completeness = Completeness::Partial;
+        }
+        let rate_budget = self
+            .http_budget
+            .rate_budget("bitbucket", &self.api_base_url());
+        warnings.extend(rate_budget.warning());
+        if let Some(cutoff) = self.http_budget.cutoff_for(&self.api_base_url()) {
+            warnings.push(cutoff_warning(&cutoff));
+        } else if partial {
+            warnings.push(format!(
+                "Bitbucket pull requests stopped at the {}-page cap; raise max_pages or narrow the window.",
+                self.max_pages
+            ));
+        }
+
+        let mut events = self.pull_requests_to_events(pull_requests)?;
+
+        // Sort for stable output
+        events.sort_by_key(|e| e.occurred_at);
+
+        let cov = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
+            run_id,
+            generated_at: Utc::now(),
+            user: self.user.clone(),
+            window: TimeWindow {
+                since: self.since,
+                until: self.until,
+            },
+            mode: self.state.as_str().to_string(),
+            sources: vec!["bitbucket".to_string()],
+            slices,
+            warnings,
+            completeness,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![rate_budget.clone()],
+        };
+
+        Ok(IngestOutput {
+            events,
+            coverage: cov,
+            freshness: Vec::new(),
+            budget: vec![rate_budget],
+        })
+    }
+
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        let query = self.coverage_query();
+        let first_url = self.pullrequests_url();
+        let cached = match &self.cache {
+            Some(cache) => cache.contains(&CacheKey::bitbucket_pullrequests(
+                &format!("{first_url} {query}"),
+                1,
+            ))?,
+            None => false,
+        };
+        let window = TimeWindow {
+            since: self.since,
+            until: self.until,
+        };
+        Ok(Some(IngestPlan {
+            source: "bitbucket".to_string(),
+            window: window.clone(),
+            queries: vec![PlannedQuery {
+                window,
+                query,
+                estimated_requests: 1,
+                cached,
+            }],
+            notes: vec![format!(
+                "One request per {PAGE_LEN} pull requests, up to {} pages.",
+                self.max_pages
+            )],
+        }))
+    }
+}
+
+// Bitbucket API types
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketPage<T> {
+    values: Vec<T>,
+    #[serde(default)]
+    size: Option<u64>,
+    #[serde(default)]
+    next: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketPullRequest {
+    id: u64,
+    title: String,
+    state: String,
+    created_on: DateTime<Utc>,
+    #[serde(default)]
+    updated_on: Option<DateTime<Utc>>,
+    #[serde(default)]
+    author: Option<BitbucketAccount>,
+    destination: BitbucketEndpoint,
+    #[serde(default)]
+    links: BitbucketLinks,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketAccount {
+    #[serde(default)]
+    nickname: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketEndpoint {
+    repository: BitbucketRepository,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketRepository {
+    full_name: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct BitbucketLinks {
+    #[serde(default)]
+    html: Option<BitbucketLink>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketLink {
+    href: String,
+}
+
+fn build_url_with_params(base: &str, params: &[(&str, String)]) -> Result<url::Url> {
+    let mut url = url::Url::parse(base).with_context(|| format!("parse url {base}"))?;
+    if !params.is_empty() {
+        let mut query = url.query_pairs_mut();
+        for (k, v) in params {
+            query.append_pair(k, v);
+        }
+    }
+    Ok(url)
+}
+
+/// Percent-encode a path segment; account UUIDs arrive wrapped in braces.
+fn encode_path_segment(segment: &str) -> String {
+    url::form_urlencoded::byte_serialize(segment.as_bytes()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_ingestor() -> BitbucketIngestor {
+        BitbucketIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        )
+    }
+
+    fn sample_pull_request_json(id: u64, state: &str) -> serde_json::Value {
+        serde_json::json!({
+            "type": "pullrequest",
+            "id": id,
+            "title": format!("Add retry budget #{id}"),
+            "state": state,
+            "created_on": "2025-01-10T09:00:00.000000+00:00",
+            "updated_on": "2025-01-12T17:30:00.000000+00:00",
+            "comment_count": 2,
+            "author": {
+                "display_name": "Alice Smith",
+                "nickname": "alice",
+                "uuid": "{5d7f0d9a-0000-4000-8000-000000000001}"
+            },
+            "source": {"branch": {"name": "retry-budget"}},
+            "destination": {
+                "branch": {"name": "main"},
+                "repository": {"full_name": "acme/payments", "name": "payments"}
+            },
+            "links": {
+                "html": {"href": format!("https://bitbucket.org/acme/payments/pull-requests/{id}")}
+            }
+        })
+    }
+
+    #[test]
+    fn with_cache_creates_missing_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache_dir = temp.path().join("nested").join("cache");
+
+        let ing = default_ingestor().with_cache(&cache_dir).unwrap();
+
+        assert!(ing.cache.is_some());
+        assert!(cache_dir.join("bitbucket-api-cache.db").exists());
+    }
+
+    #[test]
+    fn with_app_password_validates_non_empty() {
+        let err = default_ingestor()
+            .with_app_password("alice".to_string(), String::new())
+            .unwrap_err();
+        assert!(err.to_string().contains("app password cannot be empty"));
+        let err = default_ingestor()
+            .with_app_password(String::new(), "secret".to_string())
+            .unwrap_err();
+        assert!(err.to_string().contains("username cannot be empty"));
+
+        let ing = default_ingestor()
+            .with_app_password("alice".to_string(), "secret".to_string())
+            .unwrap();
+        assert_eq!(ing.auth_user.as_deref(), Some("alice"));
+        assert_eq!(ing.app_password.as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn builders_validate_workspace_and_page_cap() {
+        assert!(
+            default_ingestor()
+                .with_workspace("acme/api".into())
+                .is_err()
+        );
+        assert!(default_ingestor().with_max_pages(0).is_err());
+        let ing = default_ingestor()
+            .with_workspace("acme".into())
+            .unwrap()
+            .with_max_pages(3)
+            .unwrap();
+        assert_eq!(ing.workspace.as_deref(), Some("acme"));
+        assert_eq!(ing.max_pages, 3);
+    }
+
+    #[test]
+    fn pr_state_from_str_and_api_states() {
+        assert_eq!("merged".parse::<PrState>().unwrap(), PrState::Merged);
+        assert_eq!("OPEN".parse::<PrState>().unwrap(), PrState::Open);
+        assert_eq!("closed".parse::<PrState>().unwrap(), PrState::Declined);
+        assert_eq!(
+            "superseded".parse::<PrState>().unwrap(),
+            PrState::Superseded
+        );
+        assert!("invalid".parse::<PrState>().is_err());
+        assert_eq!(PrState::Merged.api_states(), vec!["MERGED"]);
+        assert_eq!(PrState::All.api_states().len(), 4);
+        assert_eq!(PrState::All.as_str(), "all");
+    }
+
+    #[test]
+    fn pullrequests_url_scopes_to_workspace_and_encodes_uuids() {
+        let ing = BitbucketIngestor::new(
+            "{5d7f0d9a}".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        );
+        assert_eq!(
+            ing.pullrequests_url(),
+            "https://api.bitbucket.org/2.0/pullrequests/%7B5d7f0d9a%7D"
+        );
+        let ing = default_ingestor().with_workspace("acme".into()).unwrap();
+        assert_eq!(
+            ing.pullrequests_url(),
+            "https://api.bitbucket.org/2.0/workspaces/acme/pullrequests/alice"
+        );
+    }
+
+    #[test]
+    fn query_params_filter_window_and_state_upstream() {
+        let ing = default_ingestor().with_state(PrState::All);
+        let params = ing.query_params();
+        assert!(params.contains(&(
+            "q",
+            "created_on >= 2025-01-01 AND created_on < 2025-02-01".to_string()
+        )));
+        assert!(params.contains(&("pagelen", "50".to_string())));
+        let states: Vec<_> = params
+            .iter()
+            .filter(|(key, _)| *key == "state")
+            .map(|(_, value)| value.as_str())
+            .collect();
+        assert_eq!(states, vec!["OPEN", "MERGED", "DECLINED", "SUPERSEDED"]);
+
+        let query = default_ingestor()
+            .with_workspace("acme".into())
+            .unwrap()
+            .coverage_query();
+        assert_eq!(
+            query,
+            "author = 'alice' AND workspace = 'acme' AND created_on >= 2025-01-01 AND created_on < 2025-02-01 AND state IN (MERGED)"
+        );
+    }
+
+    #[test]
+    fn collect_pull_requests_replays_cached_pages_and_marks_page_cap_partial() {
+        let ing = default_ingestor()
+            .with_in_memory_cache()
+            .unwrap()
+            .with_max_pages(2)
+            .unwrap();
+        let cache = ing.cache.as_ref().unwrap();
+        let query = format!("{} {}", ing.pullrequests_url(), ing.coverage_query());
+        for (page, id) in [(1, 41), (2, 42)] {
+            let body: BitbucketPage<BitbucketPullRequest> =
+                serde_json::from_value(serde_json::json!({
+                    "pagelen": 50,
+                    "size": 3,
+                    "page": page,
+                    "values": [sample_pull_request_json(id, "MERGED")],
+                    "next": format!("https://api.bitbucket.org/2.0/pullrequests/alice?page={}", page + 1)
+                }))
+                .unwrap();
+            cache
+                .set(&CacheKey::bitbucket_pullrequests(&query, page), &body)
+                .unwrap();
+        }
+
+        let (prs, slices, partial) = ing.collect_pull_requests(&Client::new()).unwrap();
+
+        assert_eq!(prs.iter().map(|pr| pr.id).collect::<Vec<_>>(), vec![41, 42]);
+        assert!(partial);
+        assert_eq!(slices.len(), 1);
+        assert_eq!(slices[0].total_count, 3);
+        assert_eq!(slices[0].fetched, 2);
+        assert_eq!(slices[0].incomplete_results, Some(true));
+    }
+
+    #[test]
+    fn collect_pull_requests_is_complete_when_pages_run_out() {
+        let ing = default_ingestor().with_in_memory_cache().unwrap();
+        let query = format!("{} {}", ing.pullrequests_url(), ing.coverage_query());
+        let body: BitbucketPage<BitbucketPullRequest> = serde_json::from_value(serde_json::json!({
+            "pagelen": 50,
+            "page": 1,
+            "values": [sample_pull_request_json(7, "MERGED")]
+        }))
+        .unwrap();
+        ing.cache
+            .as_ref()
+            .unwrap()
+            .set(&CacheKey::bitbucket_pullrequests(&query, 1), &body)
+            .unwrap();
+
+        let (prs, slices, partial) = ing.collect_pull_requests(&Client::new()).unwrap();
+
+        assert_eq!(prs.len(), 1);
+        assert!(!partial);
+        assert_eq!(slices[0].total_count, 1);
+        assert_eq!(slices[0].incomplete_results, Some(false));
+    }
+
+    #[test]
+    fn pull_requests_to_events_maps_states_and_links() {
+        let prs: Vec<BitbucketPullRequest> = serde_json::from_value(serde_json::json!([
+            sample_pull_request_json(42, "MERGED"),
+            sample_pull_request_json(43, "DECLINED"),
+            {
+                "id": 44,
+                "title": "Draft",
+                "state": "OPEN",
+                "created_on": "2025-01-20T10:00:00+00:00",
+                "destination": {"repository": {"full_name": "acme/web"}}
+            }
+        ]))
+        .unwrap();
+
+        let events = default_ingestor().pull_requests_to_events(prs).unwrap();
+
+        assert_eq!(events.len(), 3);
+        let merged = &events[0];
+        assert_eq!(merged.kind, EventKind::PullRequest);
+        assert_eq!(merged.actor.login, "alice");
+        assert_eq!(merged.repo.full_name, "acme/payments");
+        assert_eq!(
+            merged.source.system,
+            SourceSystem::Other("bitbucket".to_string())
+        );
+        assert_eq!(
+            merged.source.url.as_deref(),
+            Some("https://bitbucket.org/acme/payments/pull-requests/42")
+        );
+        let EventPayload::PullRequest(pr) = &merged.payload else {
+            panic!("Expected PullRequest payload");
+        };
+        assert_eq!(pr.number, 42);
+        assert_eq!(pr.state, PullRequestState::Merged);
+        assert_eq!(
+            pr.merged_at.unwrap().to_rfc3339(),
+            "2025-01-12T17:30:00+00:00"
+        );
+
+        let EventPayload::PullRequest(declined) = &events[1].payload else {
+            panic!("Expected PullRequest payload");
+        };
+        assert_eq!(declined.state, PullRequestState::Closed);
+        assert!(declined.merged_at.is_none());
+
+        let open = &events[2];
+        assert_eq!(open.actor.login, "alice");
+        assert_eq!(
+            open.links[0].url,
+            "https://bitbucket.org/acme/web/pull-requests/44"
+        );
+        assert_ne!(events[0].id, events[1].id);
+    }
+
+    #[test]
+    fn ingest_rejects_reversed_dates_and_missing_credentials() {
+        let ing = BitbucketIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        );
+        assert!(ing.ingest().unwrap_err().to_string().contains("since"));
+
+        let err = default_ingestor().ingest().unwrap_err();
+        assert!(err.to_string().contains("app password is required"));
+    }
+}
@@ -0,0 +1,291 @@
+//! Typed failures shared by the HTTP source adapters.
+//!
+//! Adapters still return `anyhow::Result`, so context added on the way up is
+//! kept. The [`IngestError`] at the bottom of the chain tells callers what
+//! kind of failure it was, recovered with [`ingest_error`], so the CLI can
+//! decide between waiting and rerunning, skipping the source, or failing the
+//! run without matching on message text.
+
+use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Why a source could not be collected.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum IngestError {
+    /// The source rejected the credentials (401) or refused access (403).
+    #[error("{system} {}", auth_detail(*.status, .detail))]
+    Auth {
+        /// Source system, such as `"GitHub"`.
+        system: &'static str,
+        /// HTTP status the source answered with.
+        status: u16,
+        /// Response body or a short explanation.
+        detail: String,
+    },
+    /// The source throttled the run.
+    #[error("{system} API rate limit exceeded{}", retry_hint(*.retry_after))]
+    RateLimited {
+        /// Source system, such as `"GitHub"`.
+        system: &'static str,
+        /// How long the source asked callers to wait, when it said.
+        retry_after: Option<Duration>,
+    },
+    /// The user, project, or endpoint does not exist.
+    #[error("{system} resource not found: {detail}")]
+    NotFound {
+        /// Source system, such as `"GitHub"`.
+        system: &'static str,
+        /// Response body or the missing resource.
+        detail: String,
+    },
+    /// The source answered, but not with the shape shiplog expects.
+    #[error("parse {system} response from {context}: {detail}")]
+    Parse {
+        /// Source system, such as `"GitHub"`.
+        system: &'static str,
+        /// Request or document that failed to parse.
+        context: String,
+        /// Decoder message.
+        detail: String,
+    },
+    /// Any other unsuccessful HTTP status.
+    #[error("{system} API error {}: {body}", status_label(*.status))]
+    Api {
+        /// Source system, such as `"GitHub"`.
+        system: &'static str,
+        /// HTTP status the source answered with.
+        status: u16,
+        /// Response body.
+        body: String,
+    },
+}
+
+/// What a caller should do about an [`IngestError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IngestErrorAction {
+    /// Transient: the same request can succeed later.
+    Retry,
+    /// Configuration or access problem with one source; collect the others.
+    SkipSource,
+    /// shiplog cannot trust what the source returned; stop the run.
+    FailRun,
+}
+
+impl IngestError {
+    /// Map an unsuccessful response to an error.
+    ///
+    /// 403s that mention a rate limit or carry `Retry-After` count as rate
+    /// limiting rather than an access problem, as GitHub reports secondary
+    /// limits that way.
+    pub fn from_response(
+        system: &'static str,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: String,
+    ) -> Self {
+        let retry_after = headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let rate_limited = status == StatusCode::TOO_MANY_REQUESTS
+            || status == StatusCode::FORBIDDEN
+                && (retry_after.is_some() || body.to_ascii_lowercase().contains("rate limit"));
+        match status {
+            _ if rate_limited => Self::RateLimited {
+                system,
+                retry_after,
+            },
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::Auth {
+                system,
+                status: status.as_u16(),
+                detail: body,
+            },
+            StatusCode::NOT_FOUND => Self::NotFound {
+                system,
+                detail: body,
+            },
+            _ => Self::Api {
+                system,
+                status: status.as_u16(),
+                body,
+            },
+        }
+    }
+
+    /// How a caller should react.
+    pub fn action(&self) -> IngestErrorAction {
+        match self {
+            Self::RateLimited { .. } => IngestErrorAction::Retry,
+            Self::Api { status, .. } if *status >= 500 => IngestErrorAction::Retry,
+            Self::Auth { .. } | Self::NotFound { .. } | Self::Api { .. } => {
+                IngestErrorAction::SkipSource
+            }
+            Self::Parse { .. } => IngestErrorAction::FailRun,
+        }
+    }
+
+    /// Process exit code for a run that stops on this error.
+    ///
+    /// `1` stays the generic failure and `3` the missed coverage requirement,
+    /// so these start at `4`.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Auth { .. } => 4,
+            Self::RateLimited { .. } => 5,
+            Self::NotFound { .. } => 6,
+            Self::Parse { .. } => 7,
+            Self::Api { .. } => 8,
+        }
+    }
+
+    /// Stable snake_case name, as written to `source.failures.json`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Auth { .. } => "auth_rejected",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::NotFound { .. } => "not_found",
+            Self::Parse { .. } => "parse_failed",
+            Self::Api { .. } => "api_error",
+        }
+    }
+}
+
+/// The typed ingest failure behind `err`, if there is one.
+pub fn ingest_error(err: &anyhow::Error) -> Option<&IngestError> {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<IngestError>())
+}
+
+/// 401 bodies rarely say more than the status, so they are left out.
+fn auth_detail(status: u16, detail: &str) -> String {
+    if status == 401 {
+        "authentication failed: invalid or expired token".to_string()
+    } else {
+        format!("API access forbidden: {detail}")
+    }
+}
+
+/// `404 Not Found` style label, falling back to the bare code.
+fn status_label(status: u16) -> String {
+    StatusCode::from_u16(status)
+        .map(|status| status.to_string())
+        .unwrap_or_else(|_| status.to_string())
+}
+
+fn retry_hint(retry_after: Option<Duration>) -> String {
+    retry_after
+        .map(|wait| format!("; retry after {}s", wait.as_secs()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use reqwest::header::HeaderValue;
+
+    fn response(status: u16, retry_after: Option<&str>, body: &str) -> IngestError {
+        let mut headers = HeaderMap::new();
+        if let Some(value) = retry_after {
+            headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+        }
+        IngestError::from_response(
+            "GitHub",
+            StatusCode::from_u16(status).unwrap(),
+            &headers,
+            body.to_string(),
+        )
+    }
+
+    #[test]
+    fn classifies_statuses() {
+        assert_eq!(
+            response(401, None, ""),
+            IngestError::Auth {
+                system: "GitHub",
+                status: 401,
+                detail: String::new(),
+            }
+        );
+        assert_eq!(
+            response(403, Some("30"), "secondary limit"),
+            IngestError::RateLimited {
+                system: "GitHub",
+                retry_after: Some(Duration::from_secs(30)),
+            }
+        );
+        assert!(matches!(
+            response(403, None, "API rate limit exceeded for user"),
+            IngestError::RateLimited {
+                retry_after: None,
+                ..
+            }
+        ));
+        assert!(matches!(
+            response(403, None, "Resource not accessible"),
+            IngestError::Auth { status: 403, .. }
+        ));
+        assert!(matches!(
+            response(404, None, "Not Found"),
+            IngestError::NotFound { .. }
+        ));
+        assert!(matches!(
+            response(502, None, "bad gateway"),
+            IngestError::Api { status: 502, .. }
+        ));
+    }
+
+    #[test]
+    fn actions_and_exit_codes_follow_the_class() {
+        assert_eq!(response(429, None, "").action(), IngestErrorAction::Retry);
+        assert_eq!(response(503, None, "").action(), IngestErrorAction::Retry);
+        assert_eq!(
+            response(401, None, "").action(),
+            IngestErrorAction::SkipSource
+        );
+        assert_eq!(
+            response(422, None, "").action(),
+            IngestErrorAction::SkipSource
+        );
+        let parse = IngestError::Parse {
+            system: "GitLab",
+            context: "GET /merge_requests".into(),
+            detail: "expected a sequence".into(),
+        };
+        assert_eq!(parse.action(), IngestErrorAction::FailRun);
+        assert_eq!(response(401, None, "").exit_code(), 4);
+        assert_eq!(parse.exit_code(), 7);
+    }
+
+    #[test]
+    fn messages_keep_the_source_and_retry_hint() {
+        assert_eq!(
+            response(401, None, "").to_string(),
+            "GitHub authentication failed: invalid or expired token"
+        );
+        assert_eq!(
+            response(403, None, "no access").to_string(),
+            "GitHub API access forbidden: no access"
+        );
+        assert_eq!(
+            response(429, Some("60"), "").to_string(),
+            "GitHub API rate limit exceeded; retry after 60s"
+        );
+        assert_eq!(
+            response(500, None, "oops").to_string(),
+            "GitHub API error 500 Internal Server Error: oops"
+        );
+    }
+
+    #[test]
+    fn found_through_added_context() {
+        let err = Err::<(), _>(response(404, None, "no such user"))
+            .context("collect configured GitHub source")
+            .unwrap_err();
+        assert_eq!(ingest_error(&err).map(IngestError::kind), Some("not_found"));
+        assert!(ingest_error(&anyhow::anyhow!("plain failure")).is_none());
+    }
+}
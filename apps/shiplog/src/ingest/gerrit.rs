@@ -0,0 +1,1068 @@
+//! Gerrit REST ingestor with cache support.
+//!
+//! Collects changes owned by a user and, optionally, the label votes the user
+//! cast on other people's changes. Tracks coverage slices and marks partial
+//! completeness when the page cap or the request budget stops pagination.
+
+use crate::cache::{ApiCache, CacheKey, CacheValidators};
+use crate::coverage::ReportingTimezone;
+use crate::http::{
+    HttpBudget, RetryPolicy, Revalidated, budget_cutoff, cutoff_warning, fetch_with_revalidation,
+    is_not_modified, send_with_retry, validators_from_headers, with_validators,
+};
+use crate::ingest::error::IngestError;
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ports::{IngestOutput, IngestPlan, Ingestor, PlannedQuery};
+use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::event::{
+    Actor, EventEnvelope, EventKind, EventPayload, Link, PullRequestEvent, PullRequestState,
+    RepoRef, RepoVisibility, ReviewEvent, SourceRef, SourceSystem,
+};
+use shiplog::schema::version::SchemaVersion;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// Changes requested per page.
+const PAGE_SIZE: u64 = 100;
+
+/// Prefix Gerrit puts before every JSON body to defeat XSSI.
+const XSSI_PREFIX: &str = ")]}'";
+
+/// Gerrit change status filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeStatus {
+    Open,
+    Merged,
+    Abandoned,
+    All,
+}
+
+impl ChangeStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Open => "open",
+            Self::Merged => "merged",
+            Self::Abandoned => "abandoned",
+            Self::All => "all",
+        }
+    }
+}
+
+impl std::str::FromStr for ChangeStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "open" | "new" => Ok(Self::Open),
+            "merged" => Ok(Self::Merged),
+            "abandoned" | "closed" => Ok(Self::Abandoned),
+            "all" => Ok(Self::All),
+            _ => Err(anyhow!("Invalid change status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GerritIngestor {
+    /// Gerrit username, email, or numeric account ID to report on.
+    pub user: String,
+    pub since: NaiveDate,
+    pub until: NaiveDate,
+    pub status: ChangeStatus,
+    /// Also collect the label votes `user` cast on other owners' changes.
+    pub include_reviews: bool,
+    pub throttle_ms: u64,
+    /// Gerrit base URL, including any path prefix (e.g.
+    /// "https://review.example.com/gerrit").
+    pub instance: String,
+    /// Username for the HTTP password.
+    pub auth_user: Option<String>,
+    /// HTTP password from the Gerrit settings page. Unset queries anonymously.
+    pub http_password: Option<String>,
+    /// Pages followed per query before the collection is marked partial.
+    pub max_pages: u32,
+    /// Optional cache for API responses
+    pub cache: Option<ApiCache>,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
+    /// Run-wide request budget shared with the other ingestors. Default:
+    /// unlimited.
+    pub http_budget: HttpBudget,
+    /// Retries for rate-limited and failed requests. Default: three, with
+    /// backoff.
+    pub retry: RetryPolicy,
+}
+
+impl GerritIngestor {
+    pub fn new(user: String, since: NaiveDate, until: NaiveDate) -> Self {
+        Self {
+            user,
+            since,
+            until,
+            status: ChangeStatus::Merged,
+            include_reviews: false,
+            throttle_ms: 0,
+            instance: String::new(),
+            auth_user: None,
+            http_password: None,
+            max_pages: 10,
+            cache: None,
+            timezone: ReportingTimezone::UTC,
+            http_budget: HttpBudget::default(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Set the Gerrit base URL. A bare hostname is read as `https://<host>`.
+    pub fn with_instance(mut self, instance: String) -> Result<Self> {
+        if instance.is_empty() {
+            return Err(anyhow!("Gerrit instance cannot be empty"));
+        }
+        let with_scheme = if instance.contains("://") {
+            instance.clone()
+        } else {
+            format!("https://{instance}")
+        };
+        let url = url::Url::parse(&with_scheme)
+            .ok()
+            .filter(|url| url.host_str().is_some())
+            .ok_or_else(|| anyhow!("Invalid Gerrit instance URL: {}", instance))?;
+        self.instance = url.as_str().trim_end_matches('/').to_string();
+        Ok(self)
+    }
+
+    /// Authenticate with an HTTP password generated for `auth_user`.
+    pub fn with_http_password(mut self, auth_user: String, http_password: String) -> Result<Self> {
+        if auth_user.is_empty() {
+            return Err(anyhow!("Gerrit username cannot be empty"));
+        }
+        if http_password.is_empty() {
+            return Err(anyhow!("Gerrit HTTP password cannot be empty"));
+        }
+        self.auth_user = Some(auth_user);
+        self.http_password = Some(http_password);
+        Ok(self)
+    }
+
+    /// Set the change status filter.
+    pub fn with_status(mut self, status: ChangeStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set whether to collect the user's review votes.
+    pub fn with_include_reviews(mut self, include: bool) -> Self {
+        self.include_reviews = include;
+        self
+    }
+
+    /// Stop each query after `pages` pages and mark the run partial.
+    pub fn with_max_pages(mut self, pages: u32) -> Result<Self> {
+        if pages == 0 {
+            return Err(anyhow!("Gerrit page cap must be at least 1"));
+        }
+        self.max_pages = pages;
+        Ok(self)
+    }
+
+    /// Count requests against `budget`, shared with the run's other
+    /// ingestors.
+    pub fn with_http_budget(mut self, budget: HttpBudget) -> Self {
+        self.http_budget = budget;
+        self
+    }
+
+    /// Retry rate-limited and failed requests under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Read window dates in `timezone` instead of UTC.
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Enable caching with the given cache directory.
+    pub fn with_cache(mut self, cache_dir: impl Into<PathBuf>) -> Result<Self> {
+        let cache_path = cache_dir.into().join("gerrit-api-cache.db");
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("create Gerrit cache directory {parent:?}"))?;
+        }
+        let cache = ApiCache::open(cache_path)?;
+        self.cache = Some(cache);
+        Ok(self)
+    }
+
+    /// Enable in-memory caching (useful for testing).
+    pub fn with_in_memory_cache(mut self) -> Result<Self> {
+        let cache = ApiCache::open_in_memory()?;
+        self.cache = Some(cache);
+        Ok(self)
+    }
+
+    /// Set throttle delay between API requests (in milliseconds).
+    pub fn with_throttle(mut self, ms: u64) -> Self {
+        self.throttle_ms = ms;
+        self
+    }
+
+    fn html_base_url(&self) -> String {
+        self.instance.clone()
+    }
+
+    /// REST root. Authenticated requests go through Gerrit's `/a/` prefix.
+    fn api_base_url(&self) -> String {
+        if self.http_password.is_some() {
+            format!("{}/a", self.instance)
+        } else {
+            self.instance.clone()
+        }
+    }
+
+    fn change_url(&self, change: &GerritChange) -> String {
+        format!(
+            "{}/c/{}/+/{}",
+            self.html_base_url(),
+            change.project,
+            change.number
+        )
+    }
+
+    #[mutants::skip]
+    fn client(&self) -> Result<Client> {
+        Client::builder()
+            .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .context("build reqwest client")
+    }
+
+    #[mutants::skip]
+    fn throttle(&self) {
+        if self.throttle_ms > 0 {
+            sleep(Duration::from_millis(self.throttle_ms));
+        }
+    }
+
+    /// Fetch one page of a change query through the response cache.
+    #[mutants::skip]
+    fn query_page(
+        &self,
+        client: &Client,
+        query: &str,
+        options: &[&str],
+        start: u64,
+    ) -> Result<Vec<GerritChange>> {
+        let url = format!("{}/changes/", self.api_base_url());
+        let mut params = vec![
+            ("q", query.to_string()),
+            ("n", PAGE_SIZE.to_string()),
+            ("S", start.to_string()),
+        ];
+        for option in options {
+            params.push(("o", (*option).to_string()));
+        }
+        let key = CacheKey::gerrit_changes(&format!("{} {query}", self.instance), start);
+        match self.cache {
+            Some(ref cache) => Ok(fetch_with_revalidation(cache, &key, |validators| {
+                self.get_json_conditional(client, &url, &params, validators)
+            })?
+            .into_value()),
+            None => match self.get_json_conditional(client, &url, &params, None)? {
+                Revalidated::Modified { value, .. } => Ok(value),
+                Revalidated::NotModified => Err(anyhow!(
+                    "Gerrit answered 304 Not Modified to an unconditional request for {url}"
+                )),
+            },
+        }
+    }
+
+    #[mutants::skip]
+    fn get_json_conditional(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        validators: Option<&CacheValidators>,
+    ) -> Result<Revalidated<Vec<GerritChange>>> {
+        let request_url = build_url_with_params(url, params)?;
+        let request_url_for_err = request_url.as_str().to_string();
+
+        let mut req = client.get(request_url).header("Accept", "application/json");
+        if let (Some(auth_user), Some(password)) = (&self.auth_user, &self.http_password) {
+            req = req.basic_auth(auth_user, Some(password));
+        }
+
+        let resp = send_with_retry(
+            &self.retry,
+            &self.http_budget,
+            &request_url_for_err,
+            with_validators(req, validators),
+        )
+        .with_context(|| format!("GET {request_url_for_err}"))?;
+        self.throttle();
+
+        let status = resp.status();
+        if is_not_modified(status) {
+            return Ok(Revalidated::NotModified);
+        }
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            let body = resp.text().unwrap_or_default();
+            return Err(IngestError::from_response("Gerrit", status, &headers, body).into());
+        }
+
+        let validators = validators_from_headers(resp.headers());
+        let body = resp
+            .text()
+            .with_context(|| format!("read {request_url_for_err}"))?;
+        let value = parse_gerrit_json(&body).map_err(|err| IngestError::Parse {
+            system: "Gerrit",
+            context: request_url_for_err.to_string(),
+            detail: err.to_string(),
+        })?;
+        Ok(Revalidated::Modified { value, validators })
+    }
+
+    /// Lower `after:` bound. Gerrit filters on the last update in the
+    /// server's timezone, so the bound starts a day early and the window is
+    /// applied again to each change's creation or vote time.
+    fn after_bound(&self) -> String {
+        let since = self.since.pred_opt().unwrap_or(self.since);
+        format!("after:\"{}\"", since.format("%Y-%m-%d"))
+    }
+
+    fn owned_query(&self) -> String {
+        let mut query = format!("owner:\"{}\" {}", self.user, self.after_bound());
+        if self.status != ChangeStatus::All {
+            query.push_str(&format!(" status:{}", self.status.as_str()));
+        }
+        query
+    }
+
+    fn reviewed_query(&self) -> String {
+        format!(
+            "reviewer:\"{user}\" -owner:\"{user}\" {}",
+            self.after_bound(),
+            user = self.user
+        )
+    }
+
+    /// Run `query` page by page. Returns the changes, one coverage slice, and
+    /// whether pagination stopped early.
+    #[mutants::skip]
+    #[tracing::instrument(
+        name = "window",
+        level = "debug",
+        skip_all,
+        fields(since = %self.since, until = %self.until, query = %query)
+    )]
+    fn collect_changes(
+        &self,
+        client: &Client,
+        query: &str,
+        options: &[&str],
+        note: &str,
+    ) -> Result<(Vec<GerritChange>, CoverageSlice, bool)> {
+        let mut changes = Vec::new();
+        let mut more = false;
+        let mut partial = false;
+
+        for page in 0..u64::from(self.max_pages) {
+            let page_changes = match self.query_page(client, query, options, page * PAGE_SIZE) {
+                Ok(page_changes) => page_changes,
+                // Keep the pages already fetched; `ingest` explains the cutoff.
+                Err(err) if budget_cutoff(&err).is_some() => {
+                    partial = true;
+                    break;
+                }
+                Err(err) => return Err(err),
+            };
+            more = page_changes
+                .last()
+                .is_some_and(|change| change.more_changes == Some(true));
+            changes.extend(page_changes);
+            if !more {
+                break;
+            }
+        }
+        if more {
+            partial = true;
+        }
+
+        let fetched = changes.len() as u64;
+        let slice = CoverageSlice {
+            window: TimeWindow {
+                since: self.since,
+                until: self.until,
+            },
+            query: query.to_string(),
+            total_count: fetched,
+            fetched,
+            incomplete_results: Some(partial),
+            notes: vec![note.to_string()],
+            source: Some("gerrit".to_string()),
+        };
+        Ok((changes, slice, partial))
+    }
+
+    fn window(&self) -> TimeWindow {
+        TimeWindow {
+            since: self.since,
+            until: self.until,
+        }
+    }
+
+    /// Whether `account` is the user this ingestor reports on.
+    fn is_user(&self, account: &GerritAccount) -> bool {
+        account.username.as_deref() == Some(self.user.as_str())
+            || account.email.as_deref() == Some(self.user.as_str())
+            || account.account_id.map(|id| id.to_string()).as_deref() == Some(self.user.as_str())
+    }
+
+    /// Convert owned changes created in the window to pull request events.
+    #[mutants::skip]
+    fn changes_to_events(&self, changes: Vec<GerritChange>) -> Result<Vec<EventEnvelope>> {
+        let window = self.window();
+        let mut events = Vec::new();
+
+        for change in changes {
+            if !self.timezone.contains(&window, &change.created.0) {
+                continue;
+            }
+            let state = match change.status.as_str() {
+                "NEW" => PullRequestState::Open,
+                "MERGED" => PullRequestState::Merged,
+                "ABANDONED" => PullRequestState::Closed,
+                _ => PullRequestState::Unknown,
+            };
+            let merged_at = (state == PullRequestState::Merged)
+                .then_some(change.submitted.map(|at| at.0))
+                .flatten();
+            let change_url = self.change_url(&change);
+
+            let event = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
+                id: EventId::from_parts(["gerrit", "change", &change.id]),
+                kind: EventKind::PullRequest,
+                occurred_at: change.created.0,
+                actor: Actor {
+                    login: change
+                        .owner
+                        .as_ref()
+                        .and_then(|owner| owner.username.clone())
+                        .unwrap_or_else(|| self.user.clone()),
+                    id: change.owner.as_ref().and_then(|owner| owner.account_id),
+                },
+                repo: RepoRef {
+                    full_name: change.project.clone(),
+                    html_url: Some(format!(
+                        "{}/admin/repos/{}",
+                        self.html_base_url(),
+                        change.project
+                    )),
+                    visibility: RepoVisibility::Private,
+                },
+                payload: EventPayload::PullRequest(PullRequestEvent {
+                    number: change.number,
+                    title: change.subject,
+                    state,
+                    created_at: change.created.0,
+                    merged_at,
+                    additions: change.insertions,
+                    deletions: change.deletions,
+                    changed_files: None,
+                    touched_paths_hint: vec![],
+                    window: None,
+                }),
+                tags: change.hashtags,
+                links: vec![Link {
+                    label: "Gerrit change".to_string(),
+                    url: change_url.clone(),
+                }],
+                source: SourceRef {
+                    system: SourceSystem::Other("gerrit".to_string()),
+                    url: Some(change_url),
+                    opaque_id: Some(OpaqueId::from_raw("gerrit", &change.id)),
+                },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
+            };
+
+            events.push(event.with_canonical_id());
+        }
+
+        Ok(events)
+    }
+
+    /// Convert the user's non-zero label votes cast in the window to review
+    /// events, one per label.
+    #[mutants::skip]
+    fn votes_to_review_events(&self, changes: &[GerritChange]) -> Result<Vec<EventEnvelope>> {
+        let window = self.window();
+        let mut events = Vec::new();
+
+        for change in changes {
+            let change_url = self.change_url(change);
+            for (label, info) in &change.labels {
+                for vote in &info.all {
+                    let (Some(value), Some(date)) = (vote.value, vote.date) else {
+                        continue;
+                    };
+                    if value == 0
+                        || !self.is_user(&vote.account)
+                        || !self.timezone.contains(&window, &date.0)
+                    {
+                        continue;
+                    }
+                    let vote_label = format!("{label}{value:+}");
+
+                    let event = EventEnvelope {
+                        schema_version: SchemaVersion::CURRENT,
+                        id: EventId::from_parts(["gerrit", "review", &change.id, label]),
+                        kind: EventKind::Review,
+                        occurred_at: date.0,
+                        actor: Actor {
+                            login: vote
+                                .account
+                                .username
+                                .clone()
+                                .unwrap_or_else(|| self.user.clone()),
+                            id: vote.account.account_id,
+                        },
+                        repo: RepoRef {
+                            full_name: change.project.clone(),
+                            html_url: Some(format!(
+                                "{}/admin/repos/{}",
+                                self.html_base_url(),
+                                change.project
+                            )),
+                            visibility: RepoVisibility::Private,
+                        },
+                        payload: EventPayload::Review(ReviewEvent {
+                            pull_number: change.number,
+                            pull_title: change.subject.clone(),
+                            submitted_at: date.0,
+                            pull_created_at: Some(change.created.0),
+                            pull_author: change
+                                .owner
+                                .as_ref()
+                                .and_then(|owner| owner.username.clone()),
+                            state: vote_label,
+                            window: None,
+                        }),
+                        tags: vec![],
+                        links: vec![Link {
+                            label: "Gerrit review".to_string(),
+                            url: change_url.clone(),
+                        }],
+                        source: SourceRef {
+                            system: SourceSystem::Other("gerrit".to_string()),
+                            url: Some(change_url.clone()),
+                            opaque_id: Some(OpaqueId::from_raw(
+                                "gerrit",
+                                &format!("{}:{label}", change.id),
+                            )),
+                        },
+                        contributors: Vec::new(),
+                        extensions: Default::default(),
+                        canonical_id: None,
+                    };
+
+                    events.push(event.with_canonical_id());
+                }
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+impl Ingestor for GerritIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "gerrit"))]
+    #[mutants::skip]
+    fn ingest(&self) -> Result<IngestOutput> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        if self.instance.is_empty() {
+            return Err(anyhow!(
+                "Gerrit instance is required. Set it using with_instance()"
+            ));
+        }
+
+        let client = self.client()?;
+        let run_id = RunId::now("shiplog");
+        let mut slices: Vec<CoverageSlice> = Vec::new();
+        let mut warnings: Vec<String> = Vec::new();
+        let mut completeness = Completeness::Complete;
+
+        let (changes, slice, partial) = self.collect_changes(
+            &client,
+            &self.owned_query(),
+            &["DETAILED_ACCOUNTS"],
+            "changes:gerrit",
+        )?;
+        slices.push(slice);
+        if partial {
+            completeness = Completeness::Partial;
+        }
+        let mut events = self.changes_to_events(changes)?;
+
+        if self.include_reviews {
+            warnings.push(
+                "Gerrit reviews are the latest vote per label; earlier votes on the same change are not listed."
+                    .to_string(),
+            );
+            let (reviewed, slice, partial) = self.collect_changes(
+                &client,
+                &self.reviewed_query(),
+                &["DETAILED_LABELS", "DETAILED_ACCOUNTS"],
+                "reviews:gerrit",
+            )?;
+            slices.push(slice);
+            if partial {
+                completeness = Completeness::Partial;
+            }
+            events.extend(self.votes_to_review_events(&reviewed)?);
+        }
+
+        let rate_budget = self.http_budget.rate_budget("gerrit", &self.api_base_url());
+        warnings.extend(rate_budget.warning());
+
+        if let Some(cutoff) = self.http_budget.cutoff_for(&self.api_base_url()) {
+            completeness = Completeness::Partial;
+            warnings.push(cutoff_warning(&cutoff));
+        } else if completeness == Completeness::Partial {
+            warnings.push(format!(
+                "Gerrit changes stopped at the {}-page cap; raise max_pages or narrow the window.",
+                self.max_pages
+            ));
+        }
+
+        // Sort for stable output
+        events.sort_by_key(|e| e.occurred_at);
+
+        let cov = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
+            run_id,
+            generated_at: Utc::now(),
+            user: self.user.clone(),
+            window: self.window(),
+            mode: self.status.as_str().to_string(),
+            sources: vec!["gerrit".to_string()],
+            slices,
+            warnings,
+            completeness,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![rate_budget.clone()],
+        };
+
+        Ok(IngestOutput {
+            events,
+            coverage: cov,
+            freshness: Vec::new(),
+            budget: vec![rate_budget],
+        })
+    }
+
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        let window = TimeWindow {
+            since: self.since,
+            until: self.until,
+        };
+        let mut queries = vec![self.owned_query()];
+        if self.include_reviews {
+            queries.push(self.reviewed_query());
+        }
+        let queries = queries
+            .into_iter()
+            .map(|query| {
+                let cached = match &self.cache {
+                    Some(cache) => cache.contains(&CacheKey::gerrit_changes(
+                        &format!("{} {query}", self.instance),
+                        0,
+                    ))?,
+                    None => false,
+                };
+                Ok(PlannedQuery {
+                    window: window.clone(),
+                    query,
+                    estimated_requests: 1,
+                    cached,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(IngestPlan {
+            source: "gerrit".to_string(),
+            window,
+            queries,
+            notes: vec![format!(
+                "Each query costs one request per {PAGE_SIZE} changes, up to {} pages.",
+                self.max_pages
+            )],
+        }))
+    }
+}
+
+// Gerrit REST types
+
+#[derive(Debug, Deserialize, Serialize)]
+struct GerritChange {
+    id: String,
+    project: String,
+    subject: String,
+    status: String,
+    created: GerritTimestamp,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    submitted: Option<GerritTimestamp>,
+    #[serde(rename = "_number")]
+    number: u64,
+    #[serde(default)]
+    insertions: Option<u64>,
+    #[serde(default)]
+    deletions: Option<u64>,
+    #[serde(default)]
+    owner: Option<GerritAccount>,
+    #[serde(default)]
+    hashtags: Vec<String>,
+    #[serde(default)]
+    labels: BTreeMap<String, GerritLabel>,
+    #[serde(
+        rename = "_more_changes",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    more_changes: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct GerritAccount {
+    #[serde(rename = "_account_id", default)]
+    account_id: Option<u64>,
+    #[serde(default)]
+    username: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct GerritLabel {
+    #[serde(default)]
+    all: Vec<GerritApproval>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct GerritApproval {
+    #[serde(flatten)]
+    account: GerritAccount,
+    #[serde(default)]
+    value: Option<i32>,
+    #[serde(default)]
+    date: Option<GerritTimestamp>,
+}
+
+/// Gerrit timestamps are UTC without a zone: `2025-01-10 09:00:00.000000000`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct GerritTimestamp(DateTime<Utc>);
+
+impl Serialize for GerritTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.format("%Y-%m-%d %H:%M:%S%.9f").to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GerritTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        NaiveDateTime::parse_from_str(&raw, "%Y-%m-%d %H:%M:%S%.f")
+            .map(|at| Self(at.and_utc()))
+            .map_err(|err| serde::de::Error::custom(format!("Gerrit timestamp {raw:?}: {err}")))
+    }
+}
+
+/// Parse a Gerrit JSON body, dropping the `)]}'` line Gerrit prepends.
+fn parse_gerrit_json(body: &str) -> serde_json::Result<Vec<GerritChange>> {
+    let body = body.trim_start();
+    let body = body.strip_prefix(XSSI_PREFIX).unwrap_or(body);
+    serde_json::from_str(body)
+}
+
+fn build_url_with_params(base: &str, params: &[(&str, String)]) -> Result<url::Url> {
+    let mut url = url::Url::parse(base).with_context(|| format!("parse url {base}"))?;
+    if !params.is_empty() {
+        let mut query = url.query_pairs_mut();
+        for (k, v) in params {
+            query.append_pair(k, v);
+        }
+    }
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_ingestor() -> GerritIngestor {
+        GerritIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        )
+        .with_instance("https://review.example.com/gerrit/".to_string())
+        .unwrap()
+    }
+
+    fn recorded_changes_body() -> String {
+        format!(
+            "{XSSI_PREFIX}\n{}",
+            serde_json::json!([
+                {
+                    "id": "infra%2Fdeploy~main~I8473b95934b5732ac55d26311a706c9c2bde9940",
+                    "project": "infra/deploy",
+                    "branch": "main",
+                    "hashtags": ["rollout"],
+                    "change_id": "I8473b95934b5732ac55d26311a706c9c2bde9940",
+                    "subject": "Canary the new load balancer pool",
+                    "status": "MERGED",
+                    "created": "2025-01-10 09:00:00.000000000",
+                    "updated": "2025-01-14 12:00:00.000000000",
+                    "submitted": "2025-01-14 11:59:00.000000000",
+                    "insertions": 42,
+                    "deletions": 7,
+                    "_number": 3965,
+                    "owner": {"_account_id": 1000096, "username": "alice", "email": "alice@example.com"}
+                },
+                {
+                    "id": "infra%2Fdeploy~main~Iold",
+                    "project": "infra/deploy",
+                    "subject": "Created before the window",
+                    "status": "MERGED",
+                    "created": "2024-12-20 09:00:00.000000000",
+                    "_number": 3900,
+                    "owner": {"_account_id": 1000096, "username": "alice"},
+                    "_more_changes": true
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn with_instance_normalizes_urls() {
+        let ing = GerritIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        );
+        let bare = ing.with_instance("review.example.com".to_string()).unwrap();
+        assert_eq!(bare.instance, "https://review.example.com");
+        assert_eq!(bare.api_base_url(), "https://review.example.com");
+        assert!(bare.with_instance(String::new()).is_err());
+
+        let ing = default_ingestor()
+            .with_http_password("alice".to_string(), "secret".to_string())
+            .unwrap();
+        assert_eq!(ing.instance, "https://review.example.com/gerrit");
+        assert_eq!(ing.api_base_url(), "https://review.example.com/gerrit/a");
+    }
+
+    #[test]
+    fn with_http_password_and_page_cap_validate_input() {
+        assert!(
+            default_ingestor()
+                .with_http_password("alice".to_string(), String::new())
+                .is_err()
+        );
+        assert!(
+            default_ingestor()
+                .with_http_password(String::new(), "secret".to_string())
+                .is_err()
+        );
+        assert!(default_ingestor().with_max_pages(0).is_err());
+    }
+
+    #[test]
+    fn with_cache_creates_missing_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache_dir = temp.path().join("nested").join("cache");
+
+        let ing = default_ingestor().with_cache(&cache_dir).unwrap();
+
+        assert!(ing.cache.is_some());
+        assert!(cache_dir.join("gerrit-api-cache.db").exists());
+    }
+
+    #[test]
+    fn change_status_from_str() {
+        assert_eq!(
+            "merged".parse::<ChangeStatus>().unwrap(),
+            ChangeStatus::Merged
+        );
+        assert_eq!("NEW".parse::<ChangeStatus>().unwrap(), ChangeStatus::Open);
+        assert_eq!(
+            "closed".parse::<ChangeStatus>().unwrap(),
+            ChangeStatus::Abandoned
+        );
+        assert_eq!("all".parse::<ChangeStatus>().unwrap(), ChangeStatus::All);
+        assert!("draft".parse::<ChangeStatus>().is_err());
+    }
+
+    #[test]
+    fn queries_filter_owner_reviewer_and_status() {
+        let ing = default_ingestor();
+        assert_eq!(
+            ing.owned_query(),
+            "owner:\"alice\" after:\"2024-12-31\" status:merged"
+        );
+        assert_eq!(
+            ing.with_status(ChangeStatus::All).owned_query(),
+            "owner:\"alice\" after:\"2024-12-31\""
+        );
+        assert_eq!(
+            default_ingestor().reviewed_query(),
+            "reviewer:\"alice\" -owner:\"alice\" after:\"2024-12-31\""
+        );
+    }
+
+    #[test]
+    fn recorded_changes_parse_and_convert_inside_the_window() {
+        let changes = parse_gerrit_json(&recorded_changes_body()).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].more_changes, Some(true));
+
+        let events = default_ingestor().changes_to_events(changes).unwrap();
+
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.kind, EventKind::PullRequest);
+        assert_eq!(event.actor.login, "alice");
+        assert_eq!(event.actor.id, Some(1000096));
+        assert_eq!(event.repo.full_name, "infra/deploy");
+        assert_eq!(event.tags, vec!["rollout".to_string()]);
+        assert_eq!(
+            event.source.url.as_deref(),
+            Some("https://review.example.com/gerrit/c/infra/deploy/+/3965")
+        );
+        let EventPayload::PullRequest(pr) = &event.payload else {
+            panic!("Expected PullRequest payload");
+        };
+        assert_eq!(pr.number, 3965);
+        assert_eq!(pr.state, PullRequestState::Merged);
+        assert_eq!(pr.additions, Some(42));
+        assert_eq!(
+            pr.merged_at.unwrap().to_rfc3339(),
+            "2025-01-14T11:59:00+00:00"
+        );
+    }
+
+    #[test]
+    fn collect_changes_replays_cached_pages_and_marks_more_changes_partial() {
+        let ing = default_ingestor()
+            .with_in_memory_cache()
+            .unwrap()
+            .with_max_pages(1)
+            .unwrap();
+        let query = ing.owned_query();
+        let changes = parse_gerrit_json(&recorded_changes_body()).unwrap();
+        ing.cache
+            .as_ref()
+            .unwrap()
+            .set(
+                &CacheKey::gerrit_changes(&format!("{} {query}", ing.instance), 0),
+                &changes,
+            )
+            .unwrap();
+
+        let (changes, slice, partial) = ing
+            .collect_changes(&Client::new(), &query, &[], "changes:gerrit")
+            .unwrap();
+
+        assert_eq!(changes.len(), 2);
+        assert!(partial);
+        assert_eq!(slice.fetched, 2);
+        assert_eq!(slice.incomplete_results, Some(true));
+        assert_eq!(slice.query, query);
+    }
+
+    #[test]
+    fn votes_to_review_events_keeps_the_users_nonzero_votes_in_window() {
+        let body = serde_json::json!([{
+            "id": "infra%2Fdns~main~Iabc",
+            "project": "infra/dns",
+            "subject": "Move TTLs to 300s",
+            "status": "NEW",
+            "created": "2025-01-05 08:00:00.000000000",
+            "_number": 812,
+            "owner": {"_account_id": 1000200, "username": "bob"},
+            "labels": {
+                "Code-Review": {"all": [
+                    {"_account_id": 1000096, "username": "alice", "value": 2, "date": "2025-01-06 10:00:00.000000000"},
+                    {"_account_id": 1000300, "username": "carol", "value": 1, "date": "2025-01-06 11:00:00.000000000"}
+                ]},
+                "Verified": {"all": [
+                    {"_account_id": 1000096, "username": "alice", "value": 0, "date": "2025-01-06 10:00:00.000000000"}
+                ]},
+                "Deploy-Risk": {"all": [
+                    {"_account_id": 1000096, "username": "alice", "value": -1, "date": "2025-02-06 10:00:00.000000000"}
+                ]}
+            }
+        }]);
+        let changes: Vec<GerritChange> = serde_json::from_value(body).unwrap();
+
+        let events = default_ingestor().votes_to_review_events(&changes).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, EventKind::Review);
+        let EventPayload::Review(review) = &events[0].payload else {
+            panic!("Expected Review payload");
+        };
+        assert_eq!(review.state, "Code-Review+2");
+        assert_eq!(review.pull_number, 812);
+        assert_eq!(review.pull_author.as_deref(), Some("bob"));
+    }
+
+    #[test]
+    fn gerrit_timestamps_round_trip_through_the_cache_format() {
+        let stamp: GerritTimestamp =
+            serde_json::from_value(serde_json::json!("2025-01-10 09:00:00.123000000")).unwrap();
+        assert_eq!(stamp.0.to_rfc3339(), "2025-01-10T09:00:00.123+00:00");
+        let back: GerritTimestamp =
+            serde_json::from_value(serde_json::to_value(stamp).unwrap()).unwrap();
+        assert_eq!(back, stamp);
+        assert!(serde_json::from_value::<GerritTimestamp>(serde_json::json!("yesterday")).is_err());
+    }
+
+    #[test]
+    fn ingest_requires_instance_and_ordered_dates() {
+        let ing = GerritIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        );
+        assert!(
+            ing.ingest()
+                .unwrap_err()
+                .to_string()
+                .contains("instance is required")
+        );
+        let ing = GerritIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        );
+        assert!(ing.ingest().unwrap_err().to_string().contains("since"));
+    }
+}
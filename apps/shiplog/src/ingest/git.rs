@@ -3,17 +3,19 @@
 //! Collects commit history from local git repositories without
 //! requiring GitHub API access or authentication.
 
+use crate::coverage::ReportingTimezone;
 use anyhow::{Context, Result, anyhow};
 use chrono::{DateTime, NaiveDate, Utc};
 use git2::{Repository, Time};
-use shiplog::ids::{EventId, RunId};
+use shiplog::ids::{EventId, OpaqueId, RunId};
 use shiplog::ports::{IngestOutput, Ingestor};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::{
-    Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
-    RepoVisibility, SourceRef, SourceSystem,
+    Actor, Contributor, ContributorRole, EventEnvelope, EventKind, EventPayload, PullRequestEvent,
+    PullRequestState, RepoRef, RepoVisibility, SourceRef, SourceSystem,
 };
 use shiplog::schema::freshness::{FreshnessStatus, SourceFreshness};
+use shiplog::schema::version::SchemaVersion;
 use std::path::{Path, PathBuf};
 
 /// Local git repository ingestor.
@@ -32,6 +34,8 @@ pub struct LocalGitIngestor {
     pub author: Option<String>,
     /// Include merge commits in the output
     pub include_merges: bool,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
 }
 
 impl LocalGitIngestor {
@@ -43,6 +47,7 @@ impl LocalGitIngestor {
             until,
             author: None,
             include_merges: false,
+            timezone: ReportingTimezone::UTC,
         }
     }
 
@@ -58,6 +63,12 @@ impl LocalGitIngestor {
         self
     }
 
+    /// Read window dates in `timezone` instead of UTC.
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Open the git repository.
     fn open_repo(&self) -> Result<Repository> {
         let path = &self.repo_path;
@@ -112,7 +123,7 @@ impl LocalGitIngestor {
 
     /// Check if a commit is within the date range. `since` is inclusive, `until` is exclusive.
     fn is_in_date_range(&self, commit_time: &DateTime<Utc>) -> bool {
-        let commit_date = commit_time.date_naive();
+        let commit_date = self.timezone.local_date(commit_time);
         commit_date >= self.since && commit_date < self.until
     }
 
@@ -134,6 +145,44 @@ impl LocalGitIngestor {
         }
     }
 
+    /// Read `Co-authored-by: Name <email>` trailers from a commit message.
+    ///
+    /// Contributors are identified the same way as the actor: by email when
+    /// present, otherwise by name. The actor and repeated entries are skipped.
+    fn co_authors(message: &str, actor_login: &str) -> Vec<Contributor> {
+        let mut contributors: Vec<Contributor> = Vec::new();
+        for line in message.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            if !key.trim().eq_ignore_ascii_case("co-authored-by") {
+                continue;
+            }
+            let value = value.trim();
+            let login = match value.split_once('<') {
+                Some((name, rest)) => match rest.split_once('>') {
+                    Some((email, _)) if !email.trim().is_empty() => email.trim(),
+                    _ => name.trim(),
+                },
+                None => value,
+            };
+            if login.is_empty()
+                || login.eq_ignore_ascii_case(actor_login)
+                || contributors
+                    .iter()
+                    .any(|c| c.login.eq_ignore_ascii_case(login))
+            {
+                continue;
+            }
+            contributors.push(Contributor {
+                login: login.to_string(),
+                id: None,
+                role: ContributorRole::CoAuthor,
+            });
+        }
+        contributors
+    }
+
     /// Check if a commit is a merge commit.
     fn is_merge_commit(commit: &git2::Commit) -> bool {
         commit.parent_count() > 1
@@ -176,7 +225,7 @@ impl LocalGitIngestor {
         let source = SourceRef {
             system: SourceSystem::LocalGit,
             url: None,
-            opaque_id: Some(commit_hash),
+            opaque_id: Some(OpaqueId::from_raw("local_git", &commit_hash)),
         };
 
         // Create repository reference
@@ -186,6 +235,8 @@ impl LocalGitIngestor {
             visibility: RepoVisibility::Unknown,
         };
 
+        let contributors = Self::co_authors(commit.message().unwrap_or(""), &actor_login);
+
         // Create actor
         let actor = Actor {
             login: actor_login,
@@ -215,6 +266,7 @@ impl LocalGitIngestor {
         let links = vec![];
 
         Ok(EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: event_id,
             kind: EventKind::PullRequest,
             occurred_at: commit_time,
@@ -224,6 +276,9 @@ impl LocalGitIngestor {
             tags: vec![],
             links,
             source,
+            contributors,
+            extensions: Default::default(),
+            canonical_id: None,
         })
     }
 
@@ -252,7 +307,7 @@ impl LocalGitIngestor {
             let commit_time = Self::git_time_to_datetime(&commit.time());
 
             // Stop if we've gone past the since date
-            if commit_time.date_naive() < self.since {
+            if self.timezone.local_date(&commit_time) < self.since {
                 break;
             }
 
@@ -276,7 +331,7 @@ impl LocalGitIngestor {
                 Ok(event) => events.push(event),
                 Err(e) => {
                     // Log error but continue processing other commits
-                    eprintln!("Warning: Failed to convert commit to event: {}", e);
+                    tracing::warn!(commit = %commit.id(), "failed to convert commit to event: {e}");
                 }
             }
         }
@@ -289,6 +344,7 @@ impl LocalGitIngestor {
 }
 
 impl Ingestor for LocalGitIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "git"))]
     fn ingest(&self) -> Result<IngestOutput> {
         if self.since >= self.until {
             return Err(anyhow!("since must be < until"));
@@ -311,11 +367,13 @@ impl Ingestor for LocalGitIngestor {
             fetched: events.len() as u64,
             incomplete_results: Some(false),
             notes: vec![],
+            source: Some("local_git".to_string()),
         };
 
         // Create coverage manifest
         let fetched_at = Utc::now();
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: run_id.clone(),
             generated_at: fetched_at,
             user: "local".to_string(),
@@ -328,6 +386,9 @@ impl Ingestor for LocalGitIngestor {
             slices: vec![coverage_slice],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![],
         };
 
         let freshness = vec![SourceFreshness {
@@ -343,6 +404,7 @@ impl Ingestor for LocalGitIngestor {
             events,
             coverage,
             freshness,
+            budget: Vec::new(),
         })
     }
 }
@@ -934,6 +996,24 @@ mod tests {
         assert!(err.to_string().contains("Failed to open git repository"));
     }
 
+    #[test]
+    fn co_author_trailers_become_contributors() {
+        let message = "Pair on cache eviction\n\n\
+            Co-authored-by: Alice <alice@example.com>\n\
+            co-authored-by: Bob\n\
+            Co-authored-by: Alice Again <ALICE@example.com>\n\
+            Co-authored-by: Test User <test@example.com>\n";
+
+        let contributors = LocalGitIngestor::co_authors(message, "test@example.com");
+        let logins: Vec<_> = contributors.iter().map(|c| c.login.as_str()).collect();
+        assert_eq!(logins, vec!["alice@example.com", "Bob"]);
+        assert!(
+            contributors
+                .iter()
+                .all(|c| c.role == ContributorRole::CoAuthor)
+        );
+    }
+
     #[test]
     fn git_time_to_datetime_at_epoch() {
         let time = Time::new(0, 0);
@@ -3,22 +3,30 @@
 //! Collects PR/review events, tracks coverage slices, and marks partial
 //! completeness when search caps or incomplete API responses are detected.
 
-use crate::cache::{ApiCache, CacheKey, CacheLookup};
-use crate::coverage::{day_windows, month_windows, week_windows, window_len_days};
+use crate::cache::{ApiCache, CacheKey, CacheLookup, CacheValidators};
+use crate::coverage::{
+    ReportingTimezone, day_windows, month_windows, week_windows, window_len_days,
+};
+use crate::http::{
+    HttpBudget, RetryPolicy, Revalidated, budget_cutoff, cutoff_warning, is_not_modified,
+    send_with_retry, validators_from_headers, with_validators,
+};
+use crate::ingest::error::IngestError;
 use anyhow::{Context, Result, anyhow};
 use chrono::{DateTime, Duration as ChronoDuration, NaiveDate, Utc};
 use reqwest::blocking::Client;
 use reqwest::header::HeaderMap;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use shiplog::ids::{EventId, RunId};
-use shiplog::ports::{IngestOutput, Ingestor};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ports::{IngestOutput, IngestPlan, Ingestor, PlannedQuery};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, Link, PullRequestEvent, PullRequestState,
     RepoRef, RepoVisibility, ReviewEvent, SourceRef, SourceSystem,
 };
 use shiplog::schema::freshness::{FreshnessStatus, SourceFreshness};
+use shiplog::schema::version::SchemaVersion;
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 use std::sync::Mutex;
@@ -46,6 +54,17 @@ pub struct GithubIngestor {
     pub cache: Option<ApiCache>,
     /// Optional live API request budget for bounded harvest runs.
     pub api_budget: Option<GithubApiBudget>,
+    /// Revalidate stale cache entries with conditional requests instead of
+    /// replaying them as-is. Default: `false` (stale entries are reused).
+    pub revalidate_stale: bool,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
+    /// Run-wide request budget shared with the other ingestors. Default:
+    /// unlimited.
+    pub http_budget: HttpBudget,
+    /// Retries for rate-limited and failed requests. Default: three, with
+    /// backoff.
+    pub retry: RetryPolicy,
     /// Adapter-local cache hit counter for the most recent (or
     /// in-progress) `ingest()` call. Incremented every time
     /// `self.cache.get(...)` returns `Some(_)`. Reported in the
@@ -314,6 +333,10 @@ impl GithubIngestor {
             api_base: "https://api.github.com".to_string(),
             cache: None,
             api_budget: None,
+            revalidate_stale: false,
+            timezone: ReportingTimezone::UTC,
+            http_budget: HttpBudget::default(),
+            retry: RetryPolicy::default(),
             cache_hits: AtomicU64::new(0),
             cache_misses: AtomicU64::new(0),
             cache_stale_hits: AtomicU64::new(0),
@@ -368,6 +391,42 @@ impl GithubIngestor {
         self
     }
 
+    /// Revalidate stale cache entries with `If-None-Match` / `If-Modified-Since`.
+    ///
+    /// A `304 Not Modified` answer replays the cached body and restarts its
+    /// TTL, so a warm rerun pays only for resources that actually changed.
+    /// Entries cached before validators were recorded are refetched.
+    #[must_use]
+    pub fn with_stale_revalidation(mut self, revalidate: bool) -> Self {
+        self.revalidate_stale = revalidate;
+        self
+    }
+
+    /// Read window dates in `timezone` instead of UTC.
+    ///
+    /// Search qualifiers use local-midnight timestamps and reviews are kept
+    /// by their local submission date.
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Count requests against `budget`, shared with the run's other
+    /// ingestors.
+    #[must_use]
+    pub fn with_http_budget(mut self, budget: HttpBudget) -> Self {
+        self.http_budget = budget;
+        self
+    }
+
+    /// Retry rate-limited and failed requests under `policy`.
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
     /// Configure live GitHub API request budget guardrails.
     #[must_use]
     pub fn with_api_budget(mut self, budget: GithubApiBudget) -> Self {
@@ -518,9 +577,27 @@ impl GithubIngestor {
         params: &[(&str, String)],
         bucket: GithubApiBucket,
     ) -> Result<T> {
+        match self.get_json_conditional(client, url, params, bucket, None)? {
+            Revalidated::Modified { value, .. } => Ok(value),
+            Revalidated::NotModified => Err(anyhow!(
+                "GitHub API answered 304 Not Modified to an unconditional request for {url}"
+            )),
+        }
+    }
+
+    #[mutants::skip]
+    fn get_json_conditional<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        bucket: GithubApiBucket,
+        validators: Option<&CacheValidators>,
+    ) -> Result<Revalidated<T>> {
         let request_url = build_url_with_params(url, params)?;
         let request_url_for_err = request_url.as_str().to_string();
         self.record_live_api_request(bucket)?;
+        tracing::trace!(url = %request_url_for_err, "GitHub request");
 
         let mut req = client
             .get(request_url)
@@ -529,21 +606,34 @@ impl GithubIngestor {
         if let Some(t) = &self.token {
             req = req.bearer_auth(t);
         }
-        let resp = req
-            .send()
-            .with_context(|| format!("GET {request_url_for_err}"))?;
+        let resp = send_with_retry(
+            &self.retry,
+            &self.http_budget,
+            &request_url_for_err,
+            with_validators(req, validators),
+        )
+        .with_context(|| format!("GET {request_url_for_err}"))?;
         self.throttle();
 
         let status = resp.status();
         self.record_rate_limit_headers(bucket, resp.headers(), status.as_u16());
 
+        if is_not_modified(status) {
+            return Ok(Revalidated::NotModified);
+        }
         if !status.is_success() {
+            let headers = resp.headers().clone();
             let body = resp.text().unwrap_or_default();
-            return Err(anyhow!("GitHub API error {status}: {body}"));
+            return Err(IngestError::from_response("GitHub", status, &headers, body).into());
         }
 
-        resp.json::<T>()
-            .with_context(|| format!("parse json from {request_url_for_err}"))
+        let validators = validators_from_headers(resp.headers());
+        let value = resp.json::<T>().map_err(|err| IngestError::Parse {
+            system: "GitHub",
+            context: request_url_for_err.to_string(),
+            detail: err.to_string(),
+        })?;
+        Ok(Revalidated::Modified { value, validators })
     }
 
     fn record_live_api_request(&self, bucket: GithubApiBucket) -> Result<()> {
@@ -624,34 +714,78 @@ impl GithubIngestor {
         bucket: GithubApiBucket,
         phase: GithubApiCachePhase,
     ) -> Result<T> {
-        if let Some(ref cache) = self.cache {
-            match cache.lookup::<T>(cache_key)? {
-                CacheLookup::Fresh(cached) => {
-                    self.cache_hits.fetch_add(1, Ordering::Relaxed);
-                    self.cache_phase_counters(phase).record_fresh_hit();
-                    return Ok(cached);
-                }
-                CacheLookup::Stale(cached) => {
+        let Some(ref cache) = self.cache else {
+            return self.get_json(client, url, params, bucket);
+        };
+
+        match cache.lookup::<T>(cache_key)? {
+            CacheLookup::Fresh(cached) => {
+                self.cache_hits.fetch_add(1, Ordering::Relaxed);
+                self.cache_phase_counters(phase).record_fresh_hit();
+                Ok(cached)
+            }
+            CacheLookup::Stale(cached) => {
+                if !self.revalidate_stale {
                     self.cache_hits.fetch_add(1, Ordering::Relaxed);
                     self.cache_stale_hits.fetch_add(1, Ordering::Relaxed);
                     self.cache_phase_counters(phase).record_stale_hit();
                     return Ok(cached);
                 }
-                CacheLookup::Miss => {
-                    self.cache_misses.fetch_add(1, Ordering::Relaxed);
-                    self.cache_phase_counters(phase).record_miss();
-                    let fetched = self.get_json(client, url, params, bucket)?;
-                    cache.set(cache_key, &fetched)?;
-                    return Ok(fetched);
+                let Some(validators) = cache.validators(cache_key)?.filter(|v| !v.is_empty())
+                else {
+                    return self.fetch_and_cache(client, url, params, cache_key, bucket, phase);
+                };
+
+                match self.get_json_conditional(client, url, params, bucket, Some(&validators))? {
+                    Revalidated::NotModified => {
+                        cache.refresh_expiry(cache_key)?;
+                        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+                        self.cache_stale_hits.fetch_add(1, Ordering::Relaxed);
+                        self.cache_phase_counters(phase).record_stale_hit();
+                        Ok(cached)
+                    }
+                    Revalidated::Modified { value, validators } => {
+                        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+                        self.cache_phase_counters(phase).record_miss();
+                        cache.set_with_validators(cache_key, &value, &validators)?;
+                        Ok(value)
+                    }
                 }
             }
+            CacheLookup::Miss => {
+                self.fetch_and_cache(client, url, params, cache_key, bucket, phase)
+            }
         }
+    }
 
-        self.get_json(client, url, params, bucket)
+    #[mutants::skip]
+    fn fetch_and_cache<T: DeserializeOwned + Serialize>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        cache_key: &str,
+        bucket: GithubApiBucket,
+        phase: GithubApiCachePhase,
+    ) -> Result<T> {
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        self.cache_phase_counters(phase).record_miss();
+        match self.get_json_conditional(client, url, params, bucket, None)? {
+            Revalidated::Modified { value, validators } => {
+                if let Some(ref cache) = self.cache {
+                    cache.set_with_validators(cache_key, &value, &validators)?;
+                }
+                Ok(value)
+            }
+            Revalidated::NotModified => Err(anyhow!(
+                "GitHub API answered 304 Not Modified to an unconditional request for {url}"
+            )),
+        }
     }
 }
 
 impl Ingestor for GithubIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "github"))]
     #[mutants::skip]
     fn ingest(&self) -> Result<IngestOutput> {
         if self.since >= self.until {
@@ -701,6 +835,14 @@ impl Ingestor for GithubIngestor {
             events.extend(review_events.events);
         }
 
+        let rate_budget = self.http_budget.rate_budget("github", &self.api_base);
+        warnings.extend(rate_budget.warning());
+
+        if let Some(cutoff) = self.http_budget.cutoff_for(&self.api_base) {
+            completeness = Completeness::Partial;
+            warnings.push(cutoff_warning(&cutoff));
+        }
+
         // Sort for stable output
         events.sort_by_key(|e| e.occurred_at);
 
@@ -719,6 +861,7 @@ impl Ingestor for GithubIngestor {
             }
         }
         let cov = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id,
             generated_at: fetched_at,
             user: self.user.clone(),
@@ -731,6 +874,9 @@ impl Ingestor for GithubIngestor {
             slices,
             warnings,
             completeness,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![rate_budget.clone()],
         };
 
         // Snapshot the run's cache counters and derive freshness status.
@@ -759,11 +905,70 @@ impl Ingestor for GithubIngestor {
             events,
             coverage: cov,
             freshness,
+            budget: vec![rate_budget],
         })
     }
+
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        let mut queries = self.plan_search(|w| self.build_pr_query(w))?;
+        let mut notes = vec![
+            "Months over 1000 results are split into weeks, then days, at two requests per slice."
+                .to_string(),
+        ];
+        if self.fetch_details {
+            notes.push(
+                "Each pull request found costs one core request for its details.".to_string(),
+            );
+        }
+        if self.include_reviews {
+            queries.extend(self.plan_search(|w| self.build_reviewed_query(w))?);
+            notes.push(
+                "Each reviewed pull request costs at least one core request for its reviews."
+                    .to_string(),
+            );
+        }
+        Ok(Some(IngestPlan {
+            source: "github".to_string(),
+            window: TimeWindow {
+                since: self.since,
+                until: self.until,
+            },
+            queries,
+            notes,
+        }))
+    }
 }
 
 impl GithubIngestor {
+    /// One planned search per month: the probe plus the first page.
+    fn plan_search<F>(&self, make_query: F) -> Result<Vec<PlannedQuery>>
+    where
+        F: Fn(&TimeWindow) -> String,
+    {
+        month_windows(self.since, self.until)
+            .into_iter()
+            .map(|window| {
+                let query = make_query(&window);
+                let cached = match &self.cache {
+                    Some(cache) => {
+                        cache.contains(&CacheKey::search(&query, 1, 1))?
+                            && cache.contains(&CacheKey::search(&query, 1, 100))?
+                    }
+                    None => false,
+                };
+                Ok(PlannedQuery {
+                    window,
+                    query,
+                    estimated_requests: 2,
+                    cached,
+                })
+            })
+            .collect()
+    }
+
     fn reset_run_counters(&self) {
         self.cache_hits.store(0, Ordering::Relaxed);
         self.cache_misses.store(0, Ordering::Relaxed);
@@ -783,7 +988,7 @@ impl GithubIngestor {
     }
 
     fn build_pr_query(&self, w: &TimeWindow) -> String {
-        let (start, end) = github_inclusive_range(w);
+        let (start, end) = github_inclusive_range(w, self.timezone);
         match self.mode.as_str() {
             "created" => format!("is:pr author:{} created:{}..{}", self.user, start, end),
             _ => format!(
@@ -796,7 +1001,7 @@ impl GithubIngestor {
     fn build_reviewed_query(&self, w: &TimeWindow) -> String {
         // GitHub does not expose review submission time in search qualifiers.
         // We use `updated:` to find candidate PRs, then filter reviews by submitted_at.
-        let (start, end) = github_inclusive_range(w);
+        let (start, end) = github_inclusive_range(w, self.timezone);
         format!("is:pr reviewed-by:{} updated:{}..{}", self.user, start, end)
     }
 
@@ -824,7 +1029,15 @@ impl GithubIngestor {
 
         for w in month_windows(since, until) {
             let (mut i, mut s, p) =
-                self.collect_window(client, &make_query, &w, Granularity::Month, label)?;
+                match self.collect_window(client, &make_query, &w, Granularity::Month, label) {
+                    Ok(found) => found,
+                    // Keep the months already collected; `ingest` explains the cutoff.
+                    Err(err) if budget_cutoff(&err).is_some() => {
+                        partial = true;
+                        break;
+                    }
+                    Err(err) => return Err(err),
+                };
             items.append(&mut i);
             slices.append(&mut s);
             partial |= p;
@@ -834,6 +1047,12 @@ impl GithubIngestor {
     }
 
     #[mutants::skip]
+    #[tracing::instrument(
+        name = "window",
+        level = "debug",
+        skip_all,
+        fields(query = label, since = %window.since, until = %window.until)
+    )]
     fn collect_window<F>(
         &self,
         client: &Client,
@@ -858,12 +1077,18 @@ impl GithubIngestor {
             fetched: 0,
             incomplete_results: Some(meta_incomplete),
             notes: vec![format!("probe:{label}")],
+            source: Some("github".to_string()),
         }];
 
         // Decide if we need to subdivide
         let need_subdivide = meta_total > 1000 || meta_incomplete;
         let can_subdivide = gran != Granularity::Day && window_len_days(window) > 1;
 
+        tracing::debug!(
+            total = meta_total,
+            incomplete = meta_incomplete,
+            "probed window"
+        );
         if need_subdivide && can_subdivide {
             slices[0].notes.push(format!(
                 "subdivide:{}",
@@ -905,6 +1130,7 @@ impl GithubIngestor {
 
         let fetched_items = self.fetch_all_search_items(client, &query)?;
         let fetched = fetched_items.len() as u64;
+        tracing::debug!(fetched, partial, "fetched window");
 
         // Record a fetch slice (separate from the probe for clarity)
         slices.push(CoverageSlice {
@@ -914,6 +1140,7 @@ impl GithubIngestor {
             fetched,
             incomplete_results: Some(meta_incomplete),
             notes: vec![format!("fetch:{label}")],
+            source: Some("github".to_string()),
         });
 
         Ok((fetched_items, slices, partial))
@@ -973,6 +1200,11 @@ impl GithubIngestor {
     }
 
     #[mutants::skip]
+    #[tracing::instrument(
+        name = "detail_fetch",
+        skip_all,
+        fields(source = "github", kind = "prs", items = items.len())
+    )]
     fn items_to_pr_events(
         &self,
         client: &Client,
@@ -1053,6 +1285,7 @@ impl GithubIngestor {
                 ]);
 
                 let ev = EventEnvelope {
+                    schema_version: SchemaVersion::CURRENT,
                     id,
                     kind: EventKind::PullRequest,
                     occurred_at,
@@ -1085,11 +1318,14 @@ impl GithubIngestor {
                     source: SourceRef {
                         system: SourceSystem::Github,
                         url: Some(pr_ref.url.clone()),
-                        opaque_id: Some(item.id.to_string()),
+                        opaque_id: Some(OpaqueId::from_raw("github", &item.id.to_string())),
                     },
+                    contributors: Vec::new(),
+                    extensions: Default::default(),
+                    canonical_id: None,
                 };
 
-                out.push(ev);
+                out.push(ev.with_canonical_id());
             }
         }
         Ok(GithubFilteredEvents {
@@ -1099,6 +1335,11 @@ impl GithubIngestor {
     }
 
     #[mutants::skip]
+    #[tracing::instrument(
+        name = "detail_fetch",
+        skip_all,
+        fields(source = "github", kind = "reviews", items = items.len())
+    )]
     fn items_to_review_events(
         &self,
         client: &Client,
@@ -1127,7 +1368,7 @@ impl GithubIngestor {
                     Some(s) => s,
                     None => continue,
                 };
-                let submitted_date = submitted.date_naive();
+                let submitted_date = self.timezone.local_date(&submitted);
                 if submitted_date < self.since || submitted_date >= self.until {
                     continue;
                 }
@@ -1141,6 +1382,7 @@ impl GithubIngestor {
                 ]);
 
                 let ev = EventEnvelope {
+                    schema_version: SchemaVersion::CURRENT,
                     id,
                     kind: EventKind::Review,
                     occurred_at: submitted,
@@ -1157,6 +1399,8 @@ impl GithubIngestor {
                         pull_number: item.number,
                         pull_title: item.title.clone(),
                         submitted_at: submitted,
+                        pull_created_at: item.created_at,
+                        pull_author: item.user.as_ref().map(|user| user.login.clone()),
                         state: r.state,
                         window: None,
                     }),
@@ -1168,11 +1412,14 @@ impl GithubIngestor {
                     source: SourceRef {
                         system: SourceSystem::Github,
                         url: Some(pr_ref.url.clone()),
-                        opaque_id: Some(r.id.to_string()),
+                        opaque_id: Some(OpaqueId::from_raw("github", &r.id.to_string())),
                     },
+                    contributors: Vec::new(),
+                    extensions: Default::default(),
+                    canonical_id: None,
                 };
 
-                out.push(ev);
+                out.push(ev.with_canonical_id());
             }
         }
         Ok(GithubFilteredEvents {
@@ -1183,41 +1430,15 @@ impl GithubIngestor {
 
     #[mutants::skip]
     fn fetch_pr_details(&self, client: &Client, pr_api_url: &str) -> Result<PullRequestDetails> {
-        // Check cache first
         let cache_key = CacheKey::pr_details(pr_api_url);
-        if let Some(ref cache) = self.cache {
-            match cache.lookup::<PullRequestDetails>(&cache_key)? {
-                CacheLookup::Fresh(cached) => {
-                    self.cache_hits.fetch_add(1, Ordering::Relaxed);
-                    self.cache_phase_counters(GithubApiCachePhase::PullDetail)
-                        .record_fresh_hit();
-                    return Ok(cached);
-                }
-                CacheLookup::Stale(cached) => {
-                    self.cache_hits.fetch_add(1, Ordering::Relaxed);
-                    self.cache_stale_hits.fetch_add(1, Ordering::Relaxed);
-                    self.cache_phase_counters(GithubApiCachePhase::PullDetail)
-                        .record_stale_hit();
-                    return Ok(cached);
-                }
-                CacheLookup::Miss => {
-                    self.cache_misses.fetch_add(1, Ordering::Relaxed);
-                    self.cache_phase_counters(GithubApiCachePhase::PullDetail)
-                        .record_miss();
-                }
-            }
-        }
-
-        // Fetch from API
-        let details: PullRequestDetails =
-            self.get_json(client, pr_api_url, &[], GithubApiBucket::Core)?;
-
-        // Store in cache
-        if let Some(ref cache) = self.cache {
-            cache.set(&cache_key, &details)?;
-        }
-
-        Ok(details)
+        self.get_json_cached(
+            client,
+            pr_api_url,
+            &[],
+            &cache_key,
+            GithubApiBucket::Core,
+            GithubApiCachePhase::PullDetail,
+        )
     }
 
     #[mutants::skip]
@@ -1232,53 +1453,17 @@ impl GithubIngestor {
         for page in 1..=10 {
             let cache_key = CacheKey::pr_reviews(pr_api_url, page);
 
-            // Try to get from cache first
-            let page_reviews: Vec<PullRequestReview> = if let Some(ref cache) = self.cache {
-                match cache.lookup::<Vec<PullRequestReview>>(&cache_key)? {
-                    CacheLookup::Fresh(cached) => {
-                        self.cache_hits.fetch_add(1, Ordering::Relaxed);
-                        self.cache_phase_counters(GithubApiCachePhase::ReviewPage)
-                            .record_fresh_hit();
-                        cached
-                    }
-                    CacheLookup::Stale(cached) => {
-                        self.cache_hits.fetch_add(1, Ordering::Relaxed);
-                        self.cache_stale_hits.fetch_add(1, Ordering::Relaxed);
-                        self.cache_phase_counters(GithubApiCachePhase::ReviewPage)
-                            .record_stale_hit();
-                        cached
-                    }
-                    CacheLookup::Miss => {
-                        self.cache_misses.fetch_add(1, Ordering::Relaxed);
-                        self.cache_phase_counters(GithubApiCachePhase::ReviewPage)
-                            .record_miss();
-                        // Not in cache, fetch from API
-                        let reviews: Vec<PullRequestReview> = self.get_json(
-                            client,
-                            &url,
-                            &[
-                                ("per_page", per_page.to_string()),
-                                ("page", page.to_string()),
-                            ],
-                            GithubApiBucket::Core,
-                        )?;
-                        // Store in cache
-                        cache.set(&cache_key, &reviews)?;
-                        reviews
-                    }
-                }
-            } else {
-                // No cache configured, fetch directly
-                self.get_json(
-                    client,
-                    &url,
-                    &[
-                        ("per_page", per_page.to_string()),
-                        ("page", page.to_string()),
-                    ],
-                    GithubApiBucket::Core,
-                )?
-            };
+            let page_reviews: Vec<PullRequestReview> = self.get_json_cached(
+                client,
+                &url,
+                &[
+                    ("per_page", per_page.to_string()),
+                    ("page", page.to_string()),
+                ],
+                &cache_key,
+                GithubApiBucket::Core,
+                GithubApiCachePhase::ReviewPage,
+            )?;
 
             let n = page_reviews.len();
             out.extend(page_reviews);
@@ -1307,11 +1492,8 @@ impl Granularity {
     }
 }
 
-fn github_inclusive_range(w: &TimeWindow) -> (String, String) {
-    let start = w.since.format("%Y-%m-%d").to_string();
-    let end_date = w.until.pred_opt().unwrap_or(w.until);
-    let end = end_date.format("%Y-%m-%d").to_string();
-    (start, end)
+fn github_inclusive_range(w: &TimeWindow, timezone: ReportingTimezone) -> (String, String) {
+    timezone.inclusive_query_range(w)
 }
 
 fn build_url_with_params(base: &str, params: &[(&str, String)]) -> Result<Url> {
@@ -1450,6 +1632,8 @@ struct SearchIssueItem {
 
     // Search returns these for issues; for PR queries they are present and useful.
     created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    user: Option<ReviewUser>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -1573,6 +1757,7 @@ mod tests {
                 None
             },
             created_at: Some(Utc::now()),
+            user: None,
         }
     }
 
@@ -1631,7 +1816,7 @@ mod tests {
             until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
         };
 
-        let (start, end) = github_inclusive_range(&window);
+        let (start, end) = github_inclusive_range(&window, ReportingTimezone::UTC);
         assert_eq!(start, "2025-01-01");
         assert_eq!(end, "2025-01-31");
     }
@@ -2680,7 +2865,7 @@ mod tests {
             since: NaiveDate::from_ymd_opt(2025, 3, 15).unwrap(),
             until: NaiveDate::from_ymd_opt(2025, 3, 16).unwrap(),
         };
-        let (start, end) = github_inclusive_range(&window);
+        let (start, end) = github_inclusive_range(&window, ReportingTimezone::UTC);
         assert_eq!(start, "2025-03-15");
         assert_eq!(end, "2025-03-15");
     }
@@ -2691,7 +2876,7 @@ mod tests {
             since: NaiveDate::from_ymd_opt(2024, 12, 1).unwrap(),
             until: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
         };
-        let (start, end) = github_inclusive_range(&window);
+        let (start, end) = github_inclusive_range(&window, ReportingTimezone::UTC);
         assert_eq!(start, "2024-12-01");
         assert_eq!(end, "2024-12-31");
     }
@@ -2703,7 +2888,7 @@ mod tests {
             since: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
             until: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
         };
-        let (start, end) = github_inclusive_range(&window);
+        let (start, end) = github_inclusive_range(&window, ReportingTimezone::UTC);
         assert_eq!(start, "2025-06-01");
         assert_eq!(end, "2025-05-31");
     }
@@ -2843,6 +3028,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn plan_lists_monthly_searches_and_marks_cached_ones() {
+        let mut ing = make_ingestor("octocat").with_in_memory_cache().unwrap();
+        ing.include_reviews = true;
+        ing.until = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let january = ing.build_pr_query(&TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        });
+        let cache = ing.cache.as_ref().unwrap();
+        let empty = serde_json::json!({"total_count": 0, "incomplete_results": false, "items": []});
+        cache
+            .set(&CacheKey::search(&january, 1, 1), &empty)
+            .unwrap();
+        cache
+            .set(&CacheKey::search(&january, 1, 100), &empty)
+            .unwrap();
+
+        let plan = ing.plan().unwrap().expect("GitHub plans its searches");
+
+        assert_eq!(plan.source, "github");
+        assert_eq!(plan.queries.len(), 4, "PRs and reviews for two months");
+        assert_eq!(plan.queries[0].query, january);
+        assert!(plan.queries[0].cached);
+        assert!(plan.queries[1..].iter().all(|query| !query.cached));
+        assert_eq!(plan.estimated_requests(), 6);
+    }
+
     // -- build_reviewed_query format --
 
     #[test]
@@ -3287,7 +3500,10 @@ mod tests {
         assert_eq!(pr_event.repo.visibility, RepoVisibility::Private);
         assert_eq!(pr_event.source.system, SourceSystem::Github);
         assert_eq!(pr_event.source.url.as_deref(), Some(pr_url.as_str()));
-        assert_eq!(pr_event.source.opaque_id.as_deref(), Some("1000001347"));
+        assert_eq!(
+            pr_event.source.opaque_id,
+            Some(OpaqueId::from_raw("github", "1000001347"))
+        );
 
         if let EventPayload::PullRequest(pr) = &pr_event.payload {
             assert_eq!(pr.number, 1347);
@@ -3323,11 +3539,15 @@ mod tests {
         assert_eq!(review_event.actor.login, "alice");
         assert_eq!(review_event.repo.full_name, "octocat/Hello-World");
         assert_eq!(review_event.source.url.as_deref(), Some(pr_url.as_str()));
-        assert_eq!(review_event.source.opaque_id.as_deref(), Some("99001"));
+        assert_eq!(
+            review_event.source.opaque_id,
+            Some(OpaqueId::from_raw("github", "99001"))
+        );
 
         if let EventPayload::Review(review) = &review_event.payload {
             assert_eq!(review.pull_number, 1347);
             assert_eq!(review.pull_title, "Reduce deploy rollback toil");
+            assert_eq!(review.pull_author.as_deref(), Some("alice"));
             assert_eq!(review.state, "APPROVED");
             assert_eq!(
                 review.submitted_at,
@@ -3598,20 +3818,20 @@ mod tests {
     proptest! {
         #[test]
         fn prop_github_inclusive_range_start_lte_end(w in arb_time_window()) {
-            let (start, end) = github_inclusive_range(&w);
+            let (start, end) = github_inclusive_range(&w, ReportingTimezone::UTC);
             prop_assert!(start <= end, "start={start} > end={end}");
         }
 
         #[test]
         fn prop_github_inclusive_range_start_matches_since(w in arb_time_window()) {
-            let (start, _) = github_inclusive_range(&w);
+            let (start, _) = github_inclusive_range(&w, ReportingTimezone::UTC);
             let expected = w.since.format("%Y-%m-%d").to_string();
             prop_assert_eq!(start, expected);
         }
 
         #[test]
         fn prop_github_inclusive_range_end_is_until_minus_one(w in arb_time_window()) {
-            let (_, end) = github_inclusive_range(&w);
+            let (_, end) = github_inclusive_range(&w, ReportingTimezone::UTC);
             let expected_date = w.until.pred_opt().unwrap_or(w.until);
             let expected = expected_date.format("%Y-%m-%d").to_string();
             prop_assert_eq!(end, expected);
@@ -5,18 +5,27 @@
 
 use crate::cache::ApiCache;
 use crate::cache::CacheKey;
+use crate::cache::CacheValidators;
+use crate::coverage::ReportingTimezone;
+use crate::http::{
+    HttpBudget, RetryPolicy, Revalidated, budget_cutoff, cutoff_warning, fetch_with_revalidation,
+    is_not_modified, send_with_retry, validators_from_headers, with_validators,
+};
+use crate::ingest::error::IngestError;
 use anyhow::{Context, Result, anyhow};
 use chrono::{DateTime, NaiveDate, Utc};
 use reqwest::blocking::Client;
-use serde::Deserialize;
 use serde::de::DeserializeOwned;
-use shiplog::ids::{EventId, RunId};
-use shiplog::ports::{IngestOutput, Ingestor};
+use serde::{Deserialize, Serialize};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ports::{IngestOutput, IngestPlan, Ingestor, PlannedQuery};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::{
-    Actor, EventEnvelope, EventKind, EventPayload, Link, PullRequestEvent, PullRequestState,
-    RepoRef, RepoVisibility, ReviewEvent, SourceRef, SourceSystem,
+    Actor, Contributor, ContributorRole, EventEnvelope, EventKind, EventPayload, Link,
+    PullRequestEvent, PullRequestState, RepoRef, RepoVisibility, ReviewEvent, SourceRef,
+    SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use std::path::PathBuf;
 use std::thread::sleep;
 use std::time::Duration;
@@ -69,6 +78,14 @@ pub struct GitlabIngestor {
     pub instance: String,
     /// Optional cache for API responses
     pub cache: Option<ApiCache>,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
+    /// Run-wide request budget shared with the other ingestors. Default:
+    /// unlimited.
+    pub http_budget: HttpBudget,
+    /// Retries for rate-limited and failed requests. Default: three, with
+    /// backoff.
+    pub retry: RetryPolicy,
 }
 
 impl GitlabIngestor {
@@ -84,6 +101,9 @@ impl GitlabIngestor {
             token: None,
             instance: "gitlab.com".to_string(),
             cache: None,
+            timezone: ReportingTimezone::UTC,
+            http_budget: HttpBudget::default(),
+            retry: RetryPolicy::default(),
         }
     }
 
@@ -123,6 +143,25 @@ impl GitlabIngestor {
         self
     }
 
+    /// Count requests against `budget`, shared with the run's other
+    /// ingestors.
+    pub fn with_http_budget(mut self, budget: HttpBudget) -> Self {
+        self.http_budget = budget;
+        self
+    }
+
+    /// Retry rate-limited and failed requests under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Read window dates in `timezone` instead of UTC.
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Enable review collection.
     pub fn with_include_reviews(mut self, include: bool) -> Self {
         self.include_reviews = include;
@@ -190,6 +229,40 @@ impl GitlabIngestor {
         url: &str,
         params: &[(&str, String)],
     ) -> Result<T> {
+        match self.get_json_conditional(client, url, params, None)? {
+            Revalidated::Modified { value, .. } => Ok(value),
+            Revalidated::NotModified => Err(anyhow!(
+                "GitLab API answered 304 Not Modified to an unconditional request for {url}"
+            )),
+        }
+    }
+
+    /// Fetch JSON through the response cache, revalidating stale entries.
+    #[mutants::skip]
+    fn get_json_cached<T: DeserializeOwned + Serialize>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        cache_key: &str,
+    ) -> Result<T> {
+        match self.cache {
+            Some(ref cache) => Ok(fetch_with_revalidation(cache, cache_key, |validators| {
+                self.get_json_conditional(client, url, params, validators)
+            })?
+            .into_value()),
+            None => self.get_json(client, url, params),
+        }
+    }
+
+    #[mutants::skip]
+    fn get_json_conditional<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        validators: Option<&CacheValidators>,
+    ) -> Result<Revalidated<T>> {
         let request_url = build_url_with_params(url, params)?;
         let request_url_for_err = request_url.as_str().to_string();
 
@@ -200,34 +273,32 @@ impl GitlabIngestor {
             req = req.header("PRIVATE-TOKEN", t);
         }
 
-        let resp = req
-            .send()
-            .with_context(|| format!("GET {request_url_for_err}"))?;
+        let resp = send_with_retry(
+            &self.retry,
+            &self.http_budget,
+            &request_url_for_err,
+            with_validators(req, validators),
+        )
+        .with_context(|| format!("GET {request_url_for_err}"))?;
         self.throttle();
 
         let status = resp.status();
+        if is_not_modified(status) {
+            return Ok(Revalidated::NotModified);
+        }
         if !status.is_success() {
+            let headers = resp.headers().clone();
             let body = resp.text().unwrap_or_default();
-
-            // Handle specific GitLab error cases
-            if status.as_u16() == 401 {
-                return Err(anyhow!(
-                    "GitLab authentication failed: invalid or expired token"
-                ));
-            } else if status.as_u16() == 403 {
-                if body.to_lowercase().contains("rate limit") {
-                    return Err(anyhow!("GitLab API rate limit exceeded"));
-                }
-                return Err(anyhow!("GitLab API access forbidden: {}", body));
-            } else if status.as_u16() == 404 {
-                return Err(anyhow!("GitLab resource not found: {}", body));
-            }
-
-            return Err(anyhow!("GitLab API error {status}: {body}"));
+            return Err(IngestError::from_response("GitLab", status, &headers, body).into());
         }
 
-        resp.json::<T>()
-            .with_context(|| format!("parse json from {request_url_for_err}"))
+        let validators = validators_from_headers(resp.headers());
+        let value = resp.json::<T>().map_err(|err| IngestError::Parse {
+            system: "GitLab",
+            context: request_url_for_err.to_string(),
+            detail: err.to_string(),
+        })?;
+        Ok(Revalidated::Modified { value, validators })
     }
 
     /// Get user ID from username (required for GitLab API queries)
@@ -282,9 +353,10 @@ impl GitlabIngestor {
     ) -> Result<(Vec<GitlabMergeRequest>, Vec<CoverageSlice>, bool)> {
         let mut all_mrs = Vec::new();
         let mut slices = Vec::new();
-        let partial = false;
+        let mut partial = false;
 
         for project in projects {
+            let _project = tracing::debug_span!("project", id = project.id).entered();
             let url = self.api_url(&format!("/projects/{}/merge_requests", project.id));
 
             let mut params = vec![
@@ -300,13 +372,18 @@ impl GitlabIngestor {
             }
 
             // Add date filters
-            let start = self.since.format("%Y-%m-%d").to_string();
-            let end = self.until.format("%Y-%m-%d").to_string();
+            let start = self.timezone.query_bound(self.since);
+            let end = self.timezone.query_bound(self.until);
             params.push(("created_after", start));
             params.push(("created_before", end));
 
             let mut page_mrs: Vec<GitlabMergeRequest> = match self.get_json(client, &url, &params) {
                 Ok(mrs) => mrs,
+                // Keep the projects already collected; `ingest` explains the cutoff.
+                Err(e) if budget_cutoff(&e).is_some() => {
+                    partial = true;
+                    break;
+                }
                 Err(e) => {
                     // Skip projects we can't access (e.g., private projects)
                     if e.to_string().contains("404") || e.to_string().contains("403") {
@@ -334,6 +411,7 @@ impl GitlabIngestor {
                 fetched: mr_count,
                 incomplete_results: Some(false),
                 notes: vec![format!("project:{}", project.path_with_namespace)],
+                source: Some("gitlab".to_string()),
             });
 
             all_mrs.extend(page_mrs);
@@ -342,6 +420,22 @@ impl GitlabIngestor {
         Ok((all_mrs, slices, partial))
     }
 
+    /// Collect review events for the user's MRs into `events`.
+    #[mutants::skip]
+    fn collect_review_events(&self, events: &mut Vec<EventEnvelope>) -> Result<()> {
+        let client = self.client()?;
+        let user_id = self.get_user_id(&client)?;
+        let projects = self.get_user_projects(&client, user_id)?;
+
+        let (mrs, _, _) = self.collect_mrs_from_projects(&client, projects)?;
+
+        for mr in mrs {
+            let notes = self.collect_mr_notes(&client, mr.project_id, mr.iid)?;
+            events.extend(self.notes_to_review_events(notes, &mr)?);
+        }
+        Ok(())
+    }
+
     /// Collect notes (reviews) for an MR
     #[mutants::skip]
     fn collect_mr_notes(
@@ -361,31 +455,15 @@ impl GitlabIngestor {
         for page in 1..=10 {
             let cache_key = CacheKey::mr_notes(project_id, mr_iid, page);
 
-            let page_notes: Vec<GitlabNote> = if let Some(ref cache) = self.cache {
-                if let Some(cached) = cache.get::<Vec<GitlabNote>>(&cache_key)? {
-                    cached
-                } else {
-                    let notes: Vec<GitlabNote> = self.get_json(
-                        client,
-                        &url,
-                        &[
-                            ("per_page", per_page.to_string()),
-                            ("page", page.to_string()),
-                        ],
-                    )?;
-                    cache.set(&cache_key, &notes)?;
-                    notes
-                }
-            } else {
-                self.get_json(
-                    client,
-                    &url,
-                    &[
-                        ("per_page", per_page.to_string()),
-                        ("page", page.to_string()),
-                    ],
-                )?
-            };
+            let page_notes: Vec<GitlabNote> = self.get_json_cached(
+                client,
+                &url,
+                &[
+                    ("per_page", per_page.to_string()),
+                    ("page", page.to_string()),
+                ],
+                &cache_key,
+            )?;
 
             let n = page_notes.len();
             notes.extend(page_notes);
@@ -417,8 +495,19 @@ impl GitlabIngestor {
             let mr_url = mr.web_url.clone().unwrap_or_else(|| {
                 format!("{}/{}/-/merge_requests/{}", html_base, project_path, mr.iid)
             });
+            let contributors = mr
+                .reviewers
+                .iter()
+                .filter(|reviewer| reviewer.id != mr.author.id)
+                .map(|reviewer| Contributor {
+                    login: reviewer.username.clone(),
+                    id: Some(reviewer.id),
+                    role: ContributorRole::Reviewer,
+                })
+                .collect();
 
             let event = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: EventId::from_parts(["gitlab", "mr", &mr.id.to_string()]),
                 kind: EventKind::PullRequest,
                 occurred_at: mr.created_at,
@@ -455,11 +544,14 @@ impl GitlabIngestor {
                 source: SourceRef {
                     system: SourceSystem::Other("gitlab".to_string()),
                     url: Some(mr_url.clone()),
-                    opaque_id: Some(mr.id.to_string()),
+                    opaque_id: Some(OpaqueId::from_raw("gitlab", &mr.id.to_string())),
                 },
+                contributors,
+                extensions: Default::default(),
+                canonical_id: None,
             };
 
-            events.push(event);
+            events.push(event.with_canonical_id());
         }
 
         Ok(events)
@@ -492,6 +584,7 @@ impl GitlabIngestor {
             };
 
             let event = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: EventId::from_parts(["gitlab", "review", &note.id.to_string()]),
                 kind: EventKind::Review,
                 occurred_at: note.created_at,
@@ -512,6 +605,8 @@ impl GitlabIngestor {
                     pull_number: mr.iid,
                     pull_title: mr.title.clone(),
                     submitted_at: note.created_at,
+                    pull_created_at: Some(mr.created_at),
+                    pull_author: Some(mr.author.username.clone()),
                     state: "approved".to_string(),
                     window: None,
                 }),
@@ -523,11 +618,14 @@ impl GitlabIngestor {
                 source: SourceRef {
                     system: SourceSystem::Other("gitlab".to_string()),
                     url: Some(mr_url.clone()),
-                    opaque_id: Some(note.id.to_string()),
+                    opaque_id: Some(OpaqueId::from_raw("gitlab", &note.id.to_string())),
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             };
 
-            events.push(event);
+            events.push(event.with_canonical_id());
         }
 
         Ok(events)
@@ -535,6 +633,7 @@ impl GitlabIngestor {
 }
 
 impl Ingestor for GitlabIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "gitlab"))]
     #[mutants::skip]
     fn ingest(&self) -> Result<IngestOutput> {
         if self.since >= self.until {
@@ -579,22 +678,28 @@ impl Ingestor for GitlabIngestor {
                 "Reviews are collected via MR notes; treat as best-effort coverage.".to_string(),
             );
 
-            let client = self.client()?;
-            let user_id = self.get_user_id(&client)?;
-            let projects = self.get_user_projects(&client, user_id)?;
+            // Reviews found before a budget cutoff are kept; the MR events
+            // above are never dropped because the review pass ran out.
+            match self.collect_review_events(&mut events) {
+                Ok(()) => {}
+                Err(err) if budget_cutoff(&err).is_some() => completeness = Completeness::Partial,
+                Err(err) => return Err(err),
+            }
+        }
 
-            let (mrs, _, _) = self.collect_mrs_from_projects(&client, projects)?;
+        let rate_budget = self.http_budget.rate_budget("gitlab", &self.api_base_url());
+        warnings.extend(rate_budget.warning());
 
-            for mr in mrs {
-                let notes = self.collect_mr_notes(&client, mr.project_id, mr.iid)?;
-                events.extend(self.notes_to_review_events(notes, &mr)?);
-            }
+        if let Some(cutoff) = self.http_budget.cutoff_for(&self.api_base_url()) {
+            completeness = Completeness::Partial;
+            warnings.push(cutoff_warning(&cutoff));
         }
 
         // Sort for stable output
         events.sort_by_key(|e| e.occurred_at);
 
         let cov = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id,
             generated_at: Utc::now(),
             user: self.user.clone(),
@@ -607,14 +712,52 @@ impl Ingestor for GitlabIngestor {
             slices,
             warnings,
             completeness,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![rate_budget.clone()],
         };
 
         Ok(IngestOutput {
             events,
             coverage: cov,
             freshness: Vec::new(),
+            budget: vec![rate_budget],
         })
     }
+
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        let window = TimeWindow {
+            since: self.since,
+            until: self.until,
+        };
+        let discovery = |query: String| PlannedQuery {
+            window: window.clone(),
+            query,
+            estimated_requests: 1,
+            cached: false,
+        };
+        let mut notes = vec![
+            "Merge requests are listed per project, at one or more requests for each project the user can see."
+                .to_string(),
+        ];
+        if self.include_reviews {
+            notes.push(
+                "Each merge request costs at least one more request for its notes.".to_string(),
+            );
+        }
+        Ok(Some(IngestPlan {
+            source: "gitlab".to_string(),
+            window: window.clone(),
+            queries: vec![
+                discovery(format!("users?username={}", self.user)),
+                discovery("users/:id/projects".to_string()),
+            ],
+            notes,
+        }))
+    }
 }
 
 // GitLab API types
@@ -649,6 +792,8 @@ struct GitlabMergeRequest {
     changed_files: Option<u64>,
     labels: Vec<String>,
     author: GitlabAuthor,
+    #[serde(default)]
+    reviewers: Vec<GitlabAuthor>,
     web_url: Option<String>,
     #[serde(default)]
     project: Option<GitlabProjectInfo>,
@@ -687,7 +832,7 @@ impl GitlabMergeRequest {
     }
 }
 
-#[derive(Debug, Deserialize, serde::Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct GitlabAuthor {
     id: u64,
     username: String,
@@ -701,7 +846,7 @@ struct GitlabProjectInfo {
     public: bool,
 }
 
-#[derive(Debug, Deserialize, serde::Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct GitlabNote {
     id: u64,
     system: bool,
@@ -1234,8 +1379,19 @@ mod tests {
             event.source.url.as_deref(),
             Some("https://gitlab.example.com/platform/reliability/-/merge_requests/42")
         );
-        assert_eq!(event.source.opaque_id.as_deref(), Some("424242"));
+        assert_eq!(
+            event.source.opaque_id,
+            Some(OpaqueId::from_raw("gitlab", "424242"))
+        );
         assert_eq!(event.tags, vec!["reliability", "deploys"]);
+        assert_eq!(
+            event.contributors,
+            vec![Contributor {
+                login: "bob".into(),
+                id: Some(101),
+                role: ContributorRole::Reviewer,
+            }]
+        );
 
         if let EventPayload::PullRequest(pr) = &event.payload {
             assert_eq!(pr.number, 42);
@@ -1306,6 +1462,7 @@ mod tests {
         if let EventPayload::Review(payload) = &review.payload {
             assert_eq!(payload.pull_number, 42);
             assert_eq!(payload.pull_title, "Reduce deploy rollback toil");
+            assert_eq!(payload.pull_author.as_deref(), Some("alice"));
             assert_eq!(payload.state, "approved");
         } else {
             panic!("Expected Review payload");
@@ -1339,7 +1496,10 @@ mod tests {
                 .unwrap()
                 .contains("merge_requests/42")
         );
-        assert_eq!(ev.source.opaque_id.as_deref(), Some("101"));
+        assert_eq!(
+            ev.source.opaque_id,
+            Some(OpaqueId::from_raw("gitlab", "101"))
+        );
 
         // Check links
         assert_eq!(ev.links.len(), 1);
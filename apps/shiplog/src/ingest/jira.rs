@@ -1,26 +1,42 @@
 //! Jira API ingestor with cache support.
 //!
-//! Collects issue events, tracks coverage slices, and marks partial
-//! completeness when search caps or incomplete API responses are detected.
+//! Collects issue events, slicing large windows month→week→day and paging
+//! each search with `startAt`. Marks partial completeness when a day still
+//! exceeds the window cap or a search ends before its reported total.
 
-use crate::cache::ApiCache;
+use crate::cache::{ApiCache, CacheKey, CacheValidators};
+use crate::coverage::{
+    ReportingTimezone, day_windows, month_windows, week_windows, window_len_days,
+};
+use crate::http::{
+    HttpBudget, RetryPolicy, Revalidated, budget_cutoff, cutoff_warning, fetch_with_revalidation,
+    is_not_modified, send_with_retry, validators_from_headers, with_validators,
+};
+use crate::ingest::error::IngestError;
 use anyhow::{Context, Result, anyhow};
 use chrono::{DateTime, NaiveDate, Utc};
 use reqwest::blocking::Client;
-use serde::Deserialize;
 use serde::de::DeserializeOwned;
-use shiplog::ids::{EventId, RunId};
-use shiplog::ports::{IngestOutput, Ingestor};
+use serde::{Deserialize, Serialize};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ports::{IngestOutput, IngestPlan, Ingestor, PlannedQuery};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::{
-    Actor, EventEnvelope, EventKind, EventPayload, Link, ManualEvent, ManualEventType, RepoRef,
-    RepoVisibility, SourceRef, SourceSystem,
+    Actor, Effort, EventEnvelope, EventKind, EventPayload, Link, ManualEvent, ManualEventType,
+    RepoRef, RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use std::path::PathBuf;
 use std::thread::sleep;
 use std::time::Duration;
 use url::Url;
 
+/// Issues requested per search page; Jira Cloud caps `maxResults` at 100.
+const PAGE_SIZE: u64 = 100;
+
+/// Search results above which a window is split into smaller windows.
+const WINDOW_CAP: u64 = 1000;
+
 /// Jira issue status filter
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IssueStatus {
@@ -73,6 +89,14 @@ pub struct JiraIngestor {
     pub instance: String,
     /// Optional cache for API responses
     pub cache: Option<ApiCache>,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
+    /// Run-wide request budget shared with the other ingestors. Default:
+    /// unlimited.
+    pub http_budget: HttpBudget,
+    /// Retries for rate-limited and failed requests. Default: three, with
+    /// backoff.
+    pub retry: RetryPolicy,
 }
 
 impl JiraIngestor {
@@ -87,9 +111,35 @@ impl JiraIngestor {
             auth_user: None,
             instance: "jira.atlassian.com".to_string(),
             cache: None,
+            timezone: ReportingTimezone::UTC,
+            http_budget: HttpBudget::default(),
+            retry: RetryPolicy::default(),
         }
     }
 
+    /// Count requests against `budget`, shared with the run's other
+    /// ingestors.
+    pub fn with_http_budget(mut self, budget: HttpBudget) -> Self {
+        self.http_budget = budget;
+        self
+    }
+
+    /// Retry rate-limited and failed requests under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Read window dates in `timezone` instead of UTC.
+    ///
+    /// JQL dates are evaluated in the Jira account's profile timezone, so a
+    /// non-UTC window queries one extra day on each side and keeps issues by
+    /// their local creation date.
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Set the Jira API token.
     pub fn with_token(mut self, token: String) -> Result<Self> {
         if token.is_empty() {
@@ -199,6 +249,40 @@ impl JiraIngestor {
         url: &str,
         params: &[(&str, String)],
     ) -> Result<T> {
+        match self.get_json_conditional(client, url, params, None)? {
+            Revalidated::Modified { value, .. } => Ok(value),
+            Revalidated::NotModified => Err(anyhow!(
+                "Jira API answered 304 Not Modified to an unconditional request for {url}"
+            )),
+        }
+    }
+
+    /// Fetch JSON through the response cache, revalidating stale entries.
+    #[mutants::skip]
+    fn get_json_cached<T: DeserializeOwned + Serialize>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        cache_key: &str,
+    ) -> Result<T> {
+        match self.cache {
+            Some(ref cache) => Ok(fetch_with_revalidation(cache, cache_key, |validators| {
+                self.get_json_conditional(client, url, params, validators)
+            })?
+            .into_value()),
+            None => self.get_json(client, url, params),
+        }
+    }
+
+    #[mutants::skip]
+    fn get_json_conditional<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        url: &str,
+        params: &[(&str, String)],
+        validators: Option<&CacheValidators>,
+    ) -> Result<Revalidated<T>> {
         let request_url = build_url_with_params(url, params)?;
         let request_url_for_err = request_url.as_str().to_string();
 
@@ -210,48 +294,41 @@ impl JiraIngestor {
             req = req.basic_auth(auth_user, Some(t));
         }
 
-        let resp = req
-            .send()
-            .with_context(|| format!("GET {request_url_for_err}"))?;
+        let resp = send_with_retry(
+            &self.retry,
+            &self.http_budget,
+            &request_url_for_err,
+            with_validators(req, validators),
+        )
+        .with_context(|| format!("GET {request_url_for_err}"))?;
         self.throttle();
 
         let status = resp.status();
+        if is_not_modified(status) {
+            return Ok(Revalidated::NotModified);
+        }
         if !status.is_success() {
+            let headers = resp.headers().clone();
             let body = resp.text().unwrap_or_default();
-
-            // Handle specific Jira error cases
-            if status.as_u16() == 401 {
-                return Err(anyhow!(
-                    "Jira authentication failed: invalid or expired token"
-                ));
-            } else if status.as_u16() == 403 {
-                if body.to_lowercase().contains("rate limit") {
-                    return Err(anyhow!("Jira API rate limit exceeded"));
-                }
-                return Err(anyhow!("Jira API access forbidden: {}", body));
-            } else if status.as_u16() == 404 {
-                return Err(anyhow!("Jira resource not found: {}", body));
-            }
-
-            return Err(anyhow!("Jira API error {status}: {body}"));
+            return Err(IngestError::from_response("Jira", status, &headers, body).into());
         }
 
-        resp.json::<T>()
-            .with_context(|| format!("parse json from {request_url_for_err}"))
+        let validators = validators_from_headers(resp.headers());
+        let value = resp.json::<T>().map_err(|err| IngestError::Parse {
+            system: "Jira",
+            context: request_url_for_err.to_string(),
+            detail: err.to_string(),
+        })?;
+        Ok(Revalidated::Modified { value, validators })
     }
 
-    /// Search for issues using JQL
-    #[mutants::skip]
-    fn search_issues(&self, client: &Client) -> Result<(Vec<JiraIssue>, Vec<CoverageSlice>, bool)> {
-        let mut slices = Vec::new();
-        let mut partial = false;
-
-        // Build JQL query
+    /// JQL for issues assigned to the user and created inside `window`.
+    fn jql_for(&self, window: &TimeWindow) -> String {
         let jql = format!(
             "assignee = '{}' AND created >= '{}' AND created < '{}'",
             self.user,
-            self.since.format("%Y-%m-%d"),
-            self.until.format("%Y-%m-%d")
+            window.since.format("%Y-%m-%d"),
+            window.until.format("%Y-%m-%d")
         );
 
         // Add status filter if not "all"
@@ -261,36 +338,155 @@ impl JiraIngestor {
             jql
         };
 
+        // A fixed order keeps `startAt` offsets stable between pages.
+        format!("{jql} ORDER BY created ASC")
+    }
+
+    /// Fetch one page of search results starting at `start_at`.
+    #[mutants::skip]
+    fn search_page(&self, client: &Client, jql: &str, start_at: u64) -> Result<JiraSearchResponse> {
         let url = self.api_url("/search");
         let params = vec![
-            ("jql", jql.clone()),
+            ("jql", jql.to_string()),
             (
                 "fields",
-                "summary,status,created,updated,issuetype,priority".to_string(),
+                "summary,status,created,updated,issuetype,priority,timespent".to_string(),
             ),
-            ("maxResults", "100".to_string()),
+            ("startAt", start_at.to_string()),
+            ("maxResults", PAGE_SIZE.to_string()),
         ];
+        let cache_key = CacheKey::jira_search(jql, start_at);
+        self.get_json_cached(client, &url, &params, &cache_key)
+    }
 
-        let search_response: JiraSearchResponse = self.get_json(client, &url, &params)?;
+    /// Dates the searches span. JQL dates are read in the account's profile
+    /// timezone, so a non-UTC window searches one extra day on each side.
+    fn search_bounds(&self) -> (NaiveDate, NaiveDate) {
+        if self.timezone.is_utc() {
+            (self.since, self.until)
+        } else {
+            (
+                self.since.pred_opt().unwrap_or(self.since),
+                self.until.succ_opt().unwrap_or(self.until),
+            )
+        }
+    }
 
-        let issue_count = search_response.total;
-        slices.push(CoverageSlice {
-            window: TimeWindow {
-                since: self.since,
-                until: self.until,
-            },
-            query: jql.clone(),
-            total_count: issue_count,
-            fetched: search_response.issues.len() as u64,
-            incomplete_results: Some(issue_count > 100),
-            notes: vec!["search:jira".to_string()],
-        });
+    /// Search for issues using JQL, adaptively slicing to keep each search
+    /// under [`WINDOW_CAP`] results.
+    ///
+    /// Returns:
+    /// - issues
+    /// - coverage slices
+    /// - whether coverage is partial
+    #[mutants::skip]
+    fn search_issues(&self, client: &Client) -> Result<(Vec<JiraIssue>, Vec<CoverageSlice>, bool)> {
+        let (since, until) = self.search_bounds();
+        let mut slices: Vec<CoverageSlice> = Vec::new();
+        let mut issues: Vec<JiraIssue> = Vec::new();
+        let mut partial = false;
+
+        for w in month_windows(since, until) {
+            let (mut i, mut s, p) = match self.collect_window(client, &w, Granularity::Month) {
+                Ok(found) => found,
+                // Keep the months already collected; `ingest` explains the cutoff.
+                Err(err) if budget_cutoff(&err).is_some() => {
+                    partial = true;
+                    break;
+                }
+                Err(err) => return Err(err),
+            };
+            issues.append(&mut i);
+            slices.append(&mut s);
+            partial |= p;
+        }
+
+        Ok((issues, slices, partial))
+    }
+
+    #[mutants::skip]
+    #[tracing::instrument(
+        name = "window",
+        level = "debug",
+        skip_all,
+        fields(since = %window.since, until = %window.until)
+    )]
+    fn collect_window(
+        &self,
+        client: &Client,
+        window: &TimeWindow,
+        gran: Granularity,
+    ) -> Result<(Vec<JiraIssue>, Vec<CoverageSlice>, bool)> {
+        if window.since >= window.until {
+            return Ok((vec![], vec![], false));
+        }
 
-        if issue_count > 100 {
-            partial = true;
+        // The first page doubles as the probe: it reports the window's total.
+        let jql = self.jql_for(window);
+        let first = self.search_page(client, &jql, 0)?;
+        let total = first.total;
+        tracing::debug!(total, "probed window");
+
+        let can_subdivide = gran != Granularity::Day && window_len_days(window) > 1;
+        if total > WINDOW_CAP && can_subdivide {
+            let mut out_issues = Vec::new();
+            let mut out_slices = vec![CoverageSlice {
+                window: window.clone(),
+                query: jql,
+                total_count: total,
+                fetched: 0,
+                incomplete_results: Some(false),
+                notes: vec!["probe:jira".to_string(), "subdivide:cap".to_string()],
+                source: Some("jira".to_string()),
+            }];
+            let mut partial = false;
+
+            let subs = match gran {
+                Granularity::Month => week_windows(window.since, window.until),
+                Granularity::Week => day_windows(window.since, window.until),
+                Granularity::Day => vec![],
+            };
+
+            for sub in subs {
+                let (mut i, mut s, p) = self.collect_window(client, &sub, gran.next())?;
+                out_issues.append(&mut i);
+                out_slices.append(&mut s);
+                partial |= p;
+            }
+            return Ok((out_issues, out_slices, partial));
         }
 
-        Ok((search_response.issues, slices, partial))
+        // `startAt` paging has no cap, so even a day over the window cap is
+        // fetched in full.
+        let mut notes = vec!["search:jira".to_string()];
+        if total > WINDOW_CAP {
+            notes.push("over_cap:unsplittable_at_this_granularity".to_string());
+        }
+        let mut issues = first.issues;
+        let mut partial = false;
+        while (issues.len() as u64) < total {
+            let page = self.search_page(client, &jql, issues.len() as u64)?;
+            if page.issues.is_empty() {
+                // Issues moved out of the query while paging.
+                partial = true;
+                notes.push("partial:short_page".to_string());
+                break;
+            }
+            issues.extend(page.issues);
+        }
+        let fetched = issues.len() as u64;
+        tracing::debug!(fetched, partial, "fetched window");
+
+        let slices = vec![CoverageSlice {
+            window: window.clone(),
+            query: jql,
+            total_count: total,
+            fetched,
+            incomplete_results: Some(partial),
+            notes,
+            source: Some("jira".to_string()),
+        }];
+        Ok((issues, slices, partial))
     }
 
     /// Convert Jira issues to shiplog events
@@ -298,11 +494,19 @@ impl JiraIngestor {
     fn issues_to_events(&self, issues: Vec<JiraIssue>) -> Result<Vec<EventEnvelope>> {
         let mut events = Vec::new();
         let html_base = self.html_base_url();
+        let window = TimeWindow {
+            since: self.since,
+            until: self.until,
+        };
 
         for issue in issues {
+            if !self.timezone.is_utc() && !self.timezone.contains(&window, &issue.fields.created) {
+                continue;
+            }
             let issue_url = format!("{}/browse/{}", html_base, issue.key);
 
             let event = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: EventId::from_parts(["jira", "issue", &issue.id]),
                 kind: EventKind::Manual,
                 occurred_at: issue.fields.created,
@@ -327,6 +531,9 @@ impl JiraIngestor {
                     started_at: Some(issue.fields.created.date_naive()),
                     ended_at: issue.fields.resolutiondate.as_ref().map(|d| d.date_naive()),
                     impact: Some(format!("Issue: {}", issue.key)),
+                    effort: issue.fields.timespent.map(Effort::logged_seconds),
+                    metrics: Vec::new(),
+                    evidence: Vec::new(),
                 }),
                 tags: vec![],
                 links: vec![Link {
@@ -336,8 +543,11 @@ impl JiraIngestor {
                 source: SourceRef {
                     system: SourceSystem::Other("jira".to_string()),
                     url: Some(issue_url),
-                    opaque_id: Some(issue.id),
+                    opaque_id: Some(OpaqueId::from_raw("jira", &issue.id)),
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             };
 
             events.push(event);
@@ -348,6 +558,7 @@ impl JiraIngestor {
 }
 
 impl Ingestor for JiraIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "jira"))]
     #[mutants::skip]
     fn ingest(&self) -> Result<IngestOutput> {
         if self.since >= self.until {
@@ -361,17 +572,25 @@ impl Ingestor for JiraIngestor {
         let client = self.client()?;
         let run_id = RunId::now("shiplog");
         let mut slices: Vec<CoverageSlice> = Vec::new();
-        let warnings: Vec<String> = Vec::new();
+        let mut warnings: Vec<String> = Vec::new();
         let mut completeness = Completeness::Complete;
 
         let mut events: Vec<EventEnvelope> = Vec::new();
 
-        // Search for issues
+        // Search for issues; months fetched before a budget cutoff are kept,
+        // and the coverage warning below explains the gap.
         let (issues, search_slices, search_partial) = self.search_issues(&client)?;
         slices.extend(search_slices);
         if search_partial {
             completeness = Completeness::Partial;
         }
+        let rate_budget = self
+            .http_budget
+            .rate_budget("jira", &self.api_url("/search"));
+        warnings.extend(rate_budget.warning());
+        if let Some(cutoff) = self.http_budget.cutoff_for(&self.api_url("/search")) {
+            warnings.push(cutoff_warning(&cutoff));
+        }
 
         // Convert issues to events
         events.extend(self.issues_to_events(issues)?);
@@ -380,6 +599,7 @@ impl Ingestor for JiraIngestor {
         events.sort_by_key(|e| e.occurred_at);
 
         let cov = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id,
             generated_at: Utc::now(),
             user: self.user.clone(),
@@ -392,19 +612,57 @@ impl Ingestor for JiraIngestor {
             slices,
             warnings,
             completeness,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![rate_budget.clone()],
         };
 
         Ok(IngestOutput {
             events,
             coverage: cov,
             freshness: Vec::new(),
+            budget: vec![rate_budget],
         })
     }
+
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        let (since, until) = self.search_bounds();
+        let queries = month_windows(since, until)
+            .into_iter()
+            .map(|window| {
+                let query = self.jql_for(&window);
+                let cached = match &self.cache {
+                    Some(cache) => cache.contains(&CacheKey::jira_search(&query, 0))?,
+                    None => false,
+                };
+                Ok(PlannedQuery {
+                    window,
+                    query,
+                    estimated_requests: 1,
+                    cached,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(IngestPlan {
+            source: "jira".to_string(),
+            window: TimeWindow {
+                since: self.since,
+                until: self.until,
+            },
+            queries,
+            notes: vec![format!(
+                "Each month costs one request per {PAGE_SIZE} issues; months over {WINDOW_CAP} issues are split into weeks, then days."
+            )],
+        }))
+    }
 }
 
 // Jira API types
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 struct JiraSearchResponse {
     #[serde(rename = "startAt")]
@@ -415,7 +673,7 @@ struct JiraSearchResponse {
     issues: Vec<JiraIssue>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 struct JiraIssue {
     id: String,
@@ -425,7 +683,7 @@ struct JiraIssue {
     fields: JiraIssueFields,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 struct JiraIssueFields {
     summary: String,
@@ -437,27 +695,30 @@ struct JiraIssueFields {
     issuetype: Option<JiraIssueType>,
     priority: Option<JiraPriority>,
     assignee: Option<JiraUser>,
+    /// Total worklog time in seconds.
+    #[serde(default)]
+    timespent: Option<u64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 struct JiraIssueStatus {
     name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 struct JiraIssueType {
     name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 struct JiraPriority {
     name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 struct JiraUser {
     #[serde(rename = "accountId")]
@@ -467,6 +728,23 @@ struct JiraUser {
     display_name: String,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Granularity {
+    Month,
+    Week,
+    Day,
+}
+
+impl Granularity {
+    fn next(&self) -> Granularity {
+        match self {
+            Granularity::Month => Granularity::Week,
+            Granularity::Week => Granularity::Day,
+            Granularity::Day => Granularity::Day,
+        }
+    }
+}
+
 fn build_url_with_params(base: &str, params: &[(&str, String)]) -> Result<Url> {
     let mut url = Url::parse(base).with_context(|| format!("parse url {base}"))?;
     if !params.is_empty() {
@@ -481,6 +759,7 @@ fn build_url_with_params(base: &str, params: &[(&str, String)]) -> Result<Url> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use shiplog::schema::event::EffortBasis;
     use std::str::FromStr;
 
     #[test]
@@ -668,6 +947,7 @@ mod tests {
                         "updated": "2025-03-12T17:45:00.000+0000",
                         "resolutiondate": "2025-03-12T17:45:00.000+0000",
                         "description": "Added rollback checklist and deploy validation.",
+                        "timespent": 16200,
                         "issuetype": {
                             "self": "https://company.atlassian.net/rest/api/3/issuetype/10001",
                             "id": "10001",
@@ -732,6 +1012,13 @@ mod tests {
                 manual.ended_at,
                 Some(NaiveDate::from_ymd_opt(2025, 3, 12).unwrap())
             );
+            assert_eq!(
+                manual.effort,
+                Some(Effort {
+                    minutes: 270,
+                    basis: EffortBasis::Logged,
+                })
+            );
         } else {
             panic!("Expected Manual payload");
         }
@@ -782,6 +1069,7 @@ mod tests {
                     name: "alice".to_string(),
                     display_name: "Alice Smith".to_string(),
                 }),
+                timespent: Some(7200),
             },
         }];
 
@@ -819,10 +1107,206 @@ mod tests {
                 issuetype: None,
                 priority: None,
                 assignee: None,
+                timespent: None,
             },
         }];
 
         let events = ing.issues_to_events(issues).unwrap();
         insta::assert_yaml_snapshot!(events);
     }
+
+    fn search_issue(id: u32, created: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id.to_string(),
+            "key": format!("OPS-{id}"),
+            "self": format!("https://company.atlassian.net/rest/api/3/issue/{id}"),
+            "fields": {
+                "summary": format!("Issue {id}"),
+                "status": { "name": "Done" },
+                "created": created,
+                "updated": created
+            }
+        })
+    }
+
+    fn seed_search_page(
+        ing: &JiraIngestor,
+        jql: &str,
+        start_at: u64,
+        total: u64,
+        issues: Vec<serde_json::Value>,
+    ) {
+        let page: JiraSearchResponse = serde_json::from_value(serde_json::json!({
+            "startAt": start_at,
+            "maxResults": 100,
+            "total": total,
+            "issues": issues
+        }))
+        .unwrap();
+        ing.cache
+            .as_ref()
+            .unwrap()
+            .set(&CacheKey::jira_search(jql, start_at), &page)
+            .unwrap();
+    }
+
+    #[test]
+    fn jql_for_orders_by_created_and_filters_status() {
+        let ing = JiraIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        );
+        let window = TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(),
+            until: NaiveDate::from_ymd_opt(2025, 1, 13).unwrap(),
+        };
+
+        assert_eq!(
+            ing.jql_for(&window),
+            "assignee = 'alice' AND created >= '2025-01-06' AND created < '2025-01-13' \
+             AND status = 'done' ORDER BY created ASC"
+        );
+        let all = ing.with_status(IssueStatus::All);
+        assert!(!all.jql_for(&window).contains("status ="));
+    }
+
+    #[test]
+    fn plan_lists_one_search_per_month_and_marks_cached_ones() {
+        let ing = JiraIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+        )
+        .with_in_memory_cache()
+        .unwrap();
+        let january = TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        };
+        seed_search_page(&ing, &ing.jql_for(&january), 0, 0, Vec::new());
+
+        let plan = ing.plan().unwrap().expect("Jira plans its searches");
+
+        assert_eq!(plan.queries.len(), 2);
+        assert_eq!(plan.queries[0].window, january);
+        assert!(plan.queries[0].cached);
+        assert!(!plan.queries[1].cached);
+        assert_eq!(plan.estimated_requests(), 1);
+    }
+
+    #[test]
+    fn collect_window_pages_with_start_at_until_the_total() {
+        let ing = JiraIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        )
+        .with_in_memory_cache()
+        .unwrap();
+        let window = TimeWindow {
+            since: ing.since,
+            until: ing.until,
+        };
+        let jql = ing.jql_for(&window);
+        seed_search_page(
+            &ing,
+            &jql,
+            0,
+            3,
+            vec![
+                search_issue(1, "2025-01-02T10:00:00.000+0000"),
+                search_issue(2, "2025-01-03T10:00:00.000+0000"),
+            ],
+        );
+        seed_search_page(
+            &ing,
+            &jql,
+            2,
+            3,
+            vec![search_issue(3, "2025-01-04T10:00:00.000+0000")],
+        );
+
+        let (issues, slices, partial) = ing
+            .collect_window(&Client::new(), &window, Granularity::Month)
+            .unwrap();
+
+        assert_eq!(issues.len(), 3);
+        assert!(!partial);
+        assert_eq!(slices.len(), 1);
+        assert_eq!(slices[0].total_count, 3);
+        assert_eq!(slices[0].fetched, 3);
+        assert_eq!(slices[0].incomplete_results, Some(false));
+    }
+
+    #[test]
+    fn collect_window_subdivides_a_month_over_the_cap_into_weeks() {
+        let ing = JiraIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        )
+        .with_in_memory_cache()
+        .unwrap();
+        let month = TimeWindow {
+            since: ing.since,
+            until: ing.until,
+        };
+        seed_search_page(&ing, &ing.jql_for(&month), 0, WINDOW_CAP + 1, Vec::new());
+        let weeks = week_windows(month.since, month.until);
+        for (n, week) in weeks.iter().enumerate() {
+            let created = format!("{}T10:00:00.000+0000", week.since.format("%Y-%m-%d"));
+            seed_search_page(
+                &ing,
+                &ing.jql_for(week),
+                0,
+                1,
+                vec![search_issue(n as u32, &created)],
+            );
+        }
+
+        let (issues, slices, partial) = ing
+            .collect_window(&Client::new(), &month, Granularity::Month)
+            .unwrap();
+
+        assert!(!partial);
+        assert_eq!(issues.len(), weeks.len());
+        assert_eq!(slices.len(), weeks.len() + 1);
+        assert_eq!(slices[0].notes, vec!["probe:jira", "subdivide:cap"]);
+        assert_eq!(slices[1].window, weeks[0]);
+        assert_eq!(slices[1].fetched, 1);
+    }
+
+    #[test]
+    fn collect_window_marks_a_short_page_partial() {
+        let ing = JiraIngestor::new(
+            "alice".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+        )
+        .with_in_memory_cache()
+        .unwrap();
+        let day = TimeWindow {
+            since: ing.since,
+            until: ing.until,
+        };
+        let jql = ing.jql_for(&day);
+        seed_search_page(
+            &ing,
+            &jql,
+            0,
+            2,
+            vec![search_issue(1, "2025-01-01T10:00:00.000+0000")],
+        );
+        seed_search_page(&ing, &jql, 1, 2, Vec::new());
+
+        let (issues, slices, partial) = ing
+            .collect_window(&Client::new(), &day, Granularity::Day)
+            .unwrap();
+
+        assert!(partial);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(slices[0].incomplete_results, Some(true));
+        assert!(slices[0].notes.contains(&"partial:short_page".to_string()));
+    }
 }
@@ -2,19 +2,30 @@
 
 use anyhow::{Context, Result};
 use chrono::Utc;
+use shiplog::engine::EventReader;
 use shiplog::ports::{IngestOutput, Ingestor};
 use shiplog::schema::coverage::CoverageManifest;
 use shiplog::schema::event::EventEnvelope;
 use shiplog::schema::freshness::{FreshnessStatus, SourceFreshness};
+use shiplog::schema::json_schema::{SchemaDocument, SchemaViolation, describe_violations};
+use shiplog::schema::migrate::{migrate_coverage, migrate_events};
 use std::fmt;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 
-/// Error returned when a JSONL event line fails to parse.
+/// Error returned when a JSONL event line fails to read or parse.
 #[derive(Debug)]
 pub struct JsonlParseError {
     line: usize,
     source: String,
-    source_error: serde_json::Error,
+    source_error: LineError,
+    violations: Vec<SchemaViolation>,
+}
+
+#[derive(Debug)]
+enum LineError {
+    Read(std::io::Error),
+    Parse(serde_json::Error),
 }
 
 impl JsonlParseError {
@@ -27,34 +38,58 @@ impl JsonlParseError {
     pub fn input_source(&self) -> &str {
         &self.source
     }
+
+    /// Event-schema violations found on the failing line, if it was valid
+    /// JSON at all.
+    pub fn violations(&self) -> &[SchemaViolation] {
+        &self.violations
+    }
 }
 
 impl fmt::Display for JsonlParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "parse event json line {} in {}: {}",
-            self.line, self.source, self.source_error
-        )
+        match &self.source_error {
+            LineError::Read(err) => {
+                write!(f, "read event line {} in {}: {err}", self.line, self.source)?
+            }
+            LineError::Parse(err) => write!(
+                f,
+                "parse event json line {} in {}: {err}",
+                self.line, self.source
+            )?,
+        }
+        if !self.violations.is_empty() {
+            write!(f, " (schema: {})", describe_violations(&self.violations))?;
+        }
+        Ok(())
     }
 }
 
 impl std::error::Error for JsonlParseError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        Some(&self.source_error)
+        match &self.source_error {
+            LineError::Read(err) => Some(err),
+            LineError::Parse(err) => Some(err),
+        }
     }
 }
 
 /// Simple adapter that ingests JSONL events plus a JSON coverage manifest.
+///
+/// Ledgers written by older builds are migrated to the current schema on
+/// read; ledgers from newer builds are rejected.
 pub struct JsonIngestor {
     pub events_path: PathBuf,
     pub coverage_path: PathBuf,
 }
 
 impl Ingestor for JsonIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "json"))]
     fn ingest(&self) -> Result<IngestOutput> {
-        let events = read_events(&self.events_path)?;
-        let coverage = read_coverage(&self.coverage_path)?;
+        let (events, _) = migrate_events(read_events(&self.events_path)?)
+            .with_context(|| format!("migrate events {:?}", self.events_path))?;
+        let (coverage, _) = migrate_coverage(read_coverage(&self.coverage_path)?)
+            .with_context(|| format!("migrate coverage manifest {:?}", self.coverage_path))?;
         let freshness = vec![SourceFreshness {
             source: "json_import".to_string(),
             status: FreshnessStatus::Fresh,
@@ -67,6 +102,7 @@ impl Ingestor for JsonIngestor {
             events,
             coverage,
             freshness,
+            budget: Vec::new(),
         })
     }
 }
@@ -74,32 +110,63 @@ impl Ingestor for JsonIngestor {
 /// Parse newline-delimited JSON event envelopes.
 ///
 /// Blank lines are ignored. `source` is included in parse error context so
-/// callers can report the file, fixture, or input surface that failed.
+/// callers can report the file, fixture, or input surface that failed, and a
+/// failing line is checked against the event JSON Schema so the error names
+/// every offending field rather than only the first one serde hit.
 pub fn parse_events_jsonl(text: &str, source: &str) -> Result<Vec<EventEnvelope>, JsonlParseError> {
+    read_events_jsonl(text.as_bytes(), source)
+}
+
+/// [`parse_events_jsonl`] over a reader, one line at a time through
+/// [`EventReader`], so only parsed events, not the ledger text as well, are
+/// held in memory.
+pub fn read_events_jsonl<R: BufRead>(
+    reader: R,
+    source: &str,
+) -> Result<Vec<EventEnvelope>, JsonlParseError> {
+    let mut reader = EventReader::new(reader);
     let mut out = Vec::new();
-    for (i, line) in text.lines().enumerate() {
-        if line.trim().is_empty() {
-            continue;
+    while let Some(line) = reader.next_line() {
+        match line {
+            Ok((line_number, line)) => out.push(parse_event_line(line, line_number, source)?),
+            Err(err) => {
+                return Err(JsonlParseError {
+                    line: reader.line_number(),
+                    source: source.to_string(),
+                    source_error: LineError::Read(err),
+                    violations: Vec::new(),
+                });
+            }
         }
-        let ev: EventEnvelope = serde_json::from_str(line).map_err(|err| JsonlParseError {
-            line: i + 1,
-            source: source.to_string(),
-            source_error: err,
-        })?;
-        out.push(ev);
     }
     Ok(out)
 }
 
+fn parse_event_line(
+    line: &str,
+    line_number: usize,
+    source: &str,
+) -> Result<EventEnvelope, JsonlParseError> {
+    serde_json::from_str(line).map_err(|err| JsonlParseError {
+        line: line_number,
+        source: source.to_string(),
+        source_error: LineError::Parse(err),
+        violations: serde_json::from_str::<serde_json::Value>(line)
+            .map(|value| SchemaDocument::EventEnvelope.validate(&value))
+            .unwrap_or_default(),
+    })
+}
+
 fn read_events(path: &Path) -> Result<Vec<EventEnvelope>> {
-    let text = std::fs::read_to_string(path).with_context(|| format!("read {path:?}"))?;
-    parse_events_jsonl(&text, &format!("{path:?}"))
+    let file = std::fs::File::open(path).with_context(|| format!("read {path:?}"))?;
+    read_events_jsonl(BufReader::new(file), &format!("{path:?}"))
         .with_context(|| format!("parse events jsonl {path:?}"))
 }
 
 fn read_coverage(path: &Path) -> Result<CoverageManifest> {
     let text = std::fs::read_to_string(path).with_context(|| format!("read {path:?}"))?;
-    let cov: CoverageManifest =
-        serde_json::from_str(&text).with_context(|| format!("parse coverage manifest {path:?}"))?;
+    let cov: CoverageManifest = serde_json::from_str(&text)
+        .map_err(|err| SchemaDocument::CoverageManifest.annotate_json(&text, err))
+        .with_context(|| format!("parse coverage manifest {path:?}"))?;
     Ok(cov)
 }
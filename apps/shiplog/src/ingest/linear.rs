@@ -4,18 +4,22 @@
 //! completeness when search caps or incomplete API responses are detected.
 
 use crate::cache::ApiCache;
+use crate::coverage::ReportingTimezone;
+use crate::http::{HttpBudget, RetryPolicy, budget_cutoff, cutoff_warning, send_with_retry};
+use crate::ingest::error::IngestError;
 use anyhow::{Context, Result, anyhow};
 use chrono::{DateTime, NaiveDate, Utc};
 use reqwest::blocking::Client;
 use serde::Deserialize;
 use serde::de::DeserializeOwned;
-use shiplog::ids::{EventId, RunId};
-use shiplog::ports::{IngestOutput, Ingestor};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ports::{IngestOutput, IngestPlan, Ingestor, PlannedQuery};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, Link, ManualEvent, ManualEventType, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use std::path::PathBuf;
 use std::thread::sleep;
 use std::time::Duration;
@@ -83,6 +87,14 @@ pub struct LinearIngestor {
     pub project: Option<String>,
     /// Optional cache for API responses
     pub cache: Option<ApiCache>,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
+    /// Run-wide request budget shared with the other ingestors. Default:
+    /// unlimited.
+    pub http_budget: HttpBudget,
+    /// Retries for rate-limited and failed requests. Default: three, with
+    /// backoff.
+    pub retry: RetryPolicy,
 }
 
 impl LinearIngestor {
@@ -96,6 +108,9 @@ impl LinearIngestor {
             api_key: None,
             project: None,
             cache: None,
+            timezone: ReportingTimezone::UTC,
+            http_budget: HttpBudget::default(),
+            retry: RetryPolicy::default(),
         }
     }
 
@@ -114,6 +129,25 @@ impl LinearIngestor {
         self
     }
 
+    /// Count requests against `budget`, shared with the run's other
+    /// ingestors.
+    pub fn with_http_budget(mut self, budget: HttpBudget) -> Self {
+        self.http_budget = budget;
+        self
+    }
+
+    /// Retry rate-limited and failed requests under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Read window dates in `timezone` instead of UTC.
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Set the issue status filter.
     pub fn with_status(mut self, status: IssueStatus) -> Self {
         self.status = status;
@@ -190,31 +224,22 @@ impl LinearIngestor {
             req = req.header("Authorization", key);
         }
 
-        let resp = req.send().context("execute Linear GraphQL query")?;
+        let resp = send_with_retry(&self.retry, &self.http_budget, &self.api_base_url(), req)
+            .context("execute Linear GraphQL query")?;
         self.throttle();
 
         let status = resp.status();
         if !status.is_success() {
+            let headers = resp.headers().clone();
             let body = resp.text().unwrap_or_default();
-
-            // Handle specific Linear error cases
-            if status.as_u16() == 401 {
-                return Err(anyhow!(
-                    "Linear authentication failed: invalid or expired API key"
-                ));
-            } else if status.as_u16() == 403 {
-                if body.to_lowercase().contains("rate limit") {
-                    return Err(anyhow!("Linear API rate limit exceeded"));
-                }
-                return Err(anyhow!("Linear API access forbidden: {}", body));
-            } else if status.as_u16() == 404 {
-                return Err(anyhow!("Linear resource not found: {}", body));
-            }
-
-            return Err(anyhow!("Linear API error {status}: {body}"));
+            return Err(IngestError::from_response("Linear", status, &headers, body).into());
         }
 
-        let response: LinearResponse<T> = resp.json().context("parse Linear GraphQL response")?;
+        let response: LinearResponse<T> = resp.json().map_err(|err| IngestError::Parse {
+            system: "Linear",
+            context: "GraphQL response".to_string(),
+            detail: err.to_string(),
+        })?;
 
         if let Some(errors) = response.errors {
             return Err(anyhow!(
@@ -234,6 +259,12 @@ impl LinearIngestor {
 
     /// Query Linear issues
     #[mutants::skip]
+    #[tracing::instrument(
+        name = "window",
+        level = "debug",
+        skip_all,
+        fields(since = %self.since, until = %self.until)
+    )]
     fn query_issues(
         &self,
         client: &Client,
@@ -292,7 +323,15 @@ impl LinearIngestor {
             }
 
             let response: LinearData<LinearIssuesResponse> =
-                self.execute_query(client, query, &variables)?;
+                match self.execute_query(client, query, &variables) {
+                    Ok(response) => response,
+                    // Keep the pages already fetched; `ingest` explains the cutoff.
+                    Err(err) if budget_cutoff(&err).is_some() => {
+                        partial = true;
+                        break;
+                    }
+                    Err(err) => return Err(err),
+                };
 
             if let Some(issue_connection) = response.data.and_then(|u| u.issues) {
                 if let Some(nodes) = issue_connection.nodes {
@@ -328,6 +367,7 @@ impl LinearIngestor {
             fetched: issues.len() as u64,
             incomplete_results: Some(partial),
             notes: vec!["search:linear".to_string()],
+            source: Some("linear".to_string()),
         });
 
         Ok((issues, slices, partial))
@@ -341,8 +381,8 @@ impl LinearIngestor {
                 },
             },
             "createdAt": {
-                "gte": self.since.format("%Y-%m-%d").to_string(),
-                "lt": self.until.format("%Y-%m-%d").to_string(),
+                "gte": self.timezone.query_bound(self.since),
+                "lt": self.timezone.query_bound(self.until),
             },
         });
 
@@ -368,8 +408,8 @@ impl LinearIngestor {
     fn coverage_query(&self) -> String {
         let mut parts = vec![
             format!("assignee.id = '{}'", self.user),
-            format!("createdAt >= '{}'", self.since.format("%Y-%m-%d")),
-            format!("createdAt < '{}'", self.until.format("%Y-%m-%d")),
+            format!("createdAt >= '{}'", self.timezone.query_bound(self.since)),
+            format!("createdAt < '{}'", self.timezone.query_bound(self.until)),
         ];
 
         if let Some(state_type) = self.status.linear_state_type() {
@@ -402,6 +442,7 @@ impl LinearIngestor {
             let event_type = ManualEventType::Other;
 
             let event = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: EventId::from_parts(["linear", "issue", &issue.id]),
                 kind: EventKind::Manual,
                 occurred_at,
@@ -432,6 +473,9 @@ impl LinearIngestor {
                         .or(issue.canceled_at)
                         .map(|d| d.date_naive()),
                     impact: Some(format!("Issue: {}", issue.identifier)),
+                    effort: None,
+                    metrics: Vec::new(),
+                    evidence: Vec::new(),
                 }),
                 tags: vec![],
                 links: vec![Link {
@@ -441,8 +485,11 @@ impl LinearIngestor {
                 source: SourceRef {
                     system: SourceSystem::Other("linear".to_string()),
                     url: Some(issue_url),
-                    opaque_id: Some(issue.id),
+                    opaque_id: Some(OpaqueId::from_raw("linear", &issue.id)),
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             };
 
             events.push(event);
@@ -453,6 +500,7 @@ impl LinearIngestor {
 }
 
 impl Ingestor for LinearIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "linear"))]
     #[mutants::skip]
     fn ingest(&self) -> Result<IngestOutput> {
         if self.since >= self.until {
@@ -466,7 +514,7 @@ impl Ingestor for LinearIngestor {
         let client = self.client()?;
         let run_id = RunId::now("shiplog");
         let mut slices: Vec<CoverageSlice> = Vec::new();
-        let warnings: Vec<String> = Vec::new();
+        let mut warnings: Vec<String> = Vec::new();
         let mut completeness = Completeness::Complete;
 
         let mut events: Vec<EventEnvelope> = Vec::new();
@@ -477,6 +525,11 @@ impl Ingestor for LinearIngestor {
         if query_partial {
             completeness = Completeness::Partial;
         }
+        let rate_budget = self.http_budget.rate_budget("linear", &self.api_base_url());
+        warnings.extend(rate_budget.warning());
+        if let Some(cutoff) = self.http_budget.cutoff_for(&self.api_base_url()) {
+            warnings.push(cutoff_warning(&cutoff));
+        }
 
         // Convert issues to events
         events.extend(self.issues_to_events(issues)?);
@@ -485,6 +538,7 @@ impl Ingestor for LinearIngestor {
         events.sort_by_key(|e| e.occurred_at);
 
         let cov = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id,
             generated_at: Utc::now(),
             user: self.user.clone(),
@@ -497,14 +551,42 @@ impl Ingestor for LinearIngestor {
             slices,
             warnings,
             completeness,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![rate_budget.clone()],
         };
 
         Ok(IngestOutput {
             events,
             coverage: cov,
             freshness: Vec::new(),
+            budget: vec![rate_budget],
         })
     }
+
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        let window = TimeWindow {
+            since: self.since,
+            until: self.until,
+        };
+        Ok(Some(IngestPlan {
+            source: "linear".to_string(),
+            window: window.clone(),
+            queries: vec![PlannedQuery {
+                window,
+                query: self.coverage_query(),
+                estimated_requests: 1,
+                cached: false,
+            }],
+            notes: vec![
+                "Linear responses are not cached; each further page of issues costs one request."
+                    .to_string(),
+            ],
+        }))
+    }
 }
 
 // Linear API types
@@ -0,0 +1,456 @@
+//! Brag-doc markdown parsing for `shiplog journal import`.
+//!
+//! A brag doc is free-form markdown: headings that group work by year, month,
+//! quarter, or project, and bullets that each describe one piece of work.
+//! Each top-level bullet becomes one entry, dated from a date in the bullet or
+//! else from the nearest dated heading. Indented lines under a bullet become
+//! its description. Markdown links and bare URLs become receipts.
+
+use anyhow::{Context, Result, bail};
+use chrono::{Datelike, NaiveDate};
+use regex::Regex;
+use shiplog::schema::event::{Link, ManualDate};
+
+/// Date patterns tried, in order, when none are configured.
+///
+/// Patterns use the named groups `year`, `month`, and `day`. `month` is
+/// required and may be a number or an English month name; a pattern without
+/// `day` dates the whole month, and one without `year` takes the year from the
+/// enclosing headings.
+pub const DEFAULT_DATE_PATTERNS: &[&str] = &[
+    r"\b(?P<year>\d{4})-(?P<month>\d{1,2})-(?P<day>\d{1,2})\b",
+    r"(?i)\b(?P<month>jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\b\.?\s+(?P<day>\d{1,2})(?:st|nd|rd|th)?\b(?:,?\s+(?P<year>\d{4})\b)?",
+    r"(?i)\b(?P<day>\d{1,2})(?:st|nd|rd|th)?\s+(?P<month>jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\b\.?(?:,?\s+(?P<year>\d{4})\b)?",
+    r"(?i)\b(?P<month>jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\b\.?,?\s+(?P<year>\d{4})\b",
+    r"\b(?P<year>\d{4})-(?P<month>\d{1,2})\b",
+];
+
+/// One bullet parsed from a brag doc.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BragEntry {
+    /// 1-based line number of the bullet.
+    pub line: usize,
+    /// Bullet date, or the period of the enclosing dated heading.
+    pub date: ManualDate,
+    /// Bullet text without its date and links' URLs.
+    pub title: String,
+    /// Indented lines under the bullet.
+    pub description: Option<String>,
+    /// Links found in the bullet and its description.
+    pub receipts: Vec<Link>,
+    /// Nearest heading that carries no date, e.g. a project name.
+    pub section: Option<String>,
+}
+
+/// A bullet that could not be dated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedBullet {
+    /// 1-based line number of the bullet.
+    pub line: usize,
+    /// Bullet text.
+    pub text: String,
+}
+
+/// Result of parsing a brag doc.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BragImport {
+    /// Dated entries, in document order.
+    pub entries: Vec<BragEntry>,
+    /// Bullets with no date of their own and no dated heading above them.
+    pub skipped: Vec<SkippedBullet>,
+}
+
+/// Parser for brag-doc markdown.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ingest::manual::brag::BragDocParser;
+///
+/// let doc = "## March 2025\n- Shipped the billing retry queue\n- 2025-03-20: Led the DB failover drill\n";
+/// let import = BragDocParser::new().parse(doc);
+/// assert_eq!(import.entries.len(), 2);
+/// assert_eq!(import.entries[1].title, "Led the DB failover drill");
+/// ```
+#[derive(Clone, Debug)]
+pub struct BragDocParser {
+    patterns: Vec<Regex>,
+    default_year: Option<i32>,
+}
+
+impl Default for BragDocParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BragDocParser {
+    /// Create a parser using [`DEFAULT_DATE_PATTERNS`].
+    pub fn new() -> Self {
+        Self {
+            patterns: DEFAULT_DATE_PATTERNS
+                .iter()
+                .map(|pattern| Regex::new(pattern).expect("default brag date pattern is valid"))
+                .collect(),
+            default_year: None,
+        }
+    }
+
+    /// Try `patterns` before the defaults.
+    pub fn with_date_patterns<I, S>(mut self, patterns: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut custom = Vec::new();
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            let regex = Regex::new(pattern)
+                .with_context(|| format!("invalid brag date pattern {pattern:?}"))?;
+            if !regex.capture_names().flatten().any(|name| name == "month") {
+                bail!("brag date pattern {pattern:?} must have a named `month` group");
+            }
+            custom.push(regex);
+        }
+        custom.append(&mut self.patterns);
+        self.patterns = custom;
+        Ok(self)
+    }
+
+    /// Year for dates that carry none and sit under no year heading.
+    pub fn with_default_year(mut self, year: i32) -> Self {
+        self.default_year = Some(year);
+        self
+    }
+
+    /// Parse `markdown` into dated entries and undated bullets.
+    pub fn parse(&self, markdown: &str) -> BragImport {
+        let mut import = BragImport::default();
+        let mut headings: Vec<Heading> = Vec::new();
+        let mut current: Option<PendingBullet> = None;
+
+        for (index, line) in markdown.lines().enumerate() {
+            let line_number = index + 1;
+            if let Some(pending) = current.as_mut()
+                && line.starts_with([' ', '\t'])
+                && !line.trim().is_empty()
+            {
+                pending
+                    .details
+                    .push(strip_bullet_marker(line.trim()).to_string());
+                continue;
+            }
+            if let Some(pending) = current.take() {
+                self.finish(pending, &headings, &mut import);
+            }
+
+            if let Some((level, text)) = parse_heading(line) {
+                headings.retain(|heading| heading.level < level);
+                let heading = self.heading(level, text, &headings);
+                headings.push(heading);
+            } else if let Some(text) = parse_bullet(line) {
+                current = Some(PendingBullet {
+                    line: line_number,
+                    text: text.to_string(),
+                    details: Vec::new(),
+                });
+            }
+        }
+        if let Some(pending) = current.take() {
+            self.finish(pending, &headings, &mut import);
+        }
+        import
+    }
+
+    fn heading(&self, level: usize, text: &str, parents: &[Heading]) -> Heading {
+        let parent_year = parents.iter().rev().find_map(|heading| heading.year);
+        let context_year = parent_year.or(self.default_year);
+        let mut heading = Heading {
+            level,
+            year: None,
+            period: None,
+            section: None,
+        };
+        if let Some(found) = self.find_date(text, context_year) {
+            heading.year = Some(found.period.0.year());
+            heading.period = Some(found.period);
+        } else if let Some(year) = context_year
+            && let Some(month) = parse_month(text.trim_end_matches(':'))
+            && let Some(period) = month_range(year, month)
+        {
+            heading.year = Some(year);
+            heading.period = Some(period);
+        } else if let Some((year, quarter)) = parse_quarter(text, context_year) {
+            heading.year = Some(year);
+            heading.period = quarter_range(year, quarter);
+        } else if let Some(year) = parse_year(text) {
+            heading.year = Some(year);
+        } else {
+            heading.section = Some(clean_text(text));
+        }
+        heading
+    }
+
+    fn finish(&self, pending: PendingBullet, headings: &[Heading], import: &mut BragImport) {
+        let year = headings
+            .iter()
+            .rev()
+            .find_map(|heading| heading.year)
+            .or(self.default_year);
+        let (date, text) = match self.find_date(&pending.text, year) {
+            Some(found) => {
+                let mut text = pending.text.clone();
+                text.replace_range(found.span.clone(), " ");
+                (Some(period_date(found.period)), text)
+            }
+            None => (
+                headings
+                    .iter()
+                    .rev()
+                    .find_map(|heading| heading.period)
+                    .map(period_date),
+                pending.text.clone(),
+            ),
+        };
+        let Some(date) = date else {
+            import.skipped.push(SkippedBullet {
+                line: pending.line,
+                text: pending.text,
+            });
+            return;
+        };
+
+        let mut receipts = Vec::new();
+        let title = extract_links(&text, &mut receipts);
+        let details: Vec<String> = pending
+            .details
+            .iter()
+            .map(|detail| extract_links(detail, &mut receipts))
+            .filter(|detail| !detail.is_empty())
+            .collect();
+        let title = trim_separators(&title);
+        if title.is_empty() {
+            import.skipped.push(SkippedBullet {
+                line: pending.line,
+                text: pending.text,
+            });
+            return;
+        }
+        import.entries.push(BragEntry {
+            line: pending.line,
+            date,
+            title,
+            description: (!details.is_empty()).then(|| details.join("\n")),
+            receipts,
+            section: headings
+                .iter()
+                .rev()
+                .find_map(|heading| heading.section.clone()),
+        });
+    }
+
+    fn find_date(&self, text: &str, context_year: Option<i32>) -> Option<FoundDate> {
+        self.patterns.iter().find_map(|pattern| {
+            pattern.captures_iter(text).find_map(|captures| {
+                let whole = captures.get(0)?;
+                let month = parse_month(captures.name("month")?.as_str())?;
+                let year = match captures.name("year") {
+                    Some(year) => year.as_str().parse().ok()?,
+                    None => context_year?,
+                };
+                let period = match captures.name("day") {
+                    Some(day) => {
+                        let date =
+                            NaiveDate::from_ymd_opt(year, month, day.as_str().parse().ok()?)?;
+                        (date, date)
+                    }
+                    None => month_range(year, month)?,
+                };
+                Some(FoundDate {
+                    span: whole.range(),
+                    period,
+                })
+            })
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Heading {
+    level: usize,
+    year: Option<i32>,
+    period: Option<(NaiveDate, NaiveDate)>,
+    section: Option<String>,
+}
+
+#[derive(Debug)]
+struct PendingBullet {
+    line: usize,
+    text: String,
+    details: Vec<String>,
+}
+
+#[derive(Debug)]
+struct FoundDate {
+    span: std::ops::Range<usize>,
+    period: (NaiveDate, NaiveDate),
+}
+
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.bytes().take_while(|byte| *byte == b'#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let text = line[level..].strip_prefix([' ', '\t'])?;
+    Some((level, text.trim().trim_end_matches('#').trim()))
+}
+
+fn parse_bullet(line: &str) -> Option<&str> {
+    let stripped = strip_bullet_marker(line);
+    (stripped.len() != line.len() && !stripped.is_empty()).then_some(stripped)
+}
+
+fn strip_bullet_marker(line: &str) -> &str {
+    if let Some(rest) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+    {
+        let rest = rest.trim_start();
+        return rest
+            .strip_prefix("[ ] ")
+            .or_else(|| rest.strip_prefix("[x] "))
+            .or_else(|| rest.strip_prefix("[X] "))
+            .unwrap_or(rest)
+            .trim();
+    }
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    if digits > 0
+        && let Some(rest) = line[digits..]
+            .strip_prefix(". ")
+            .or_else(|| line[digits..].strip_prefix(") "))
+    {
+        return rest.trim();
+    }
+    line
+}
+
+fn parse_month(value: &str) -> Option<u32> {
+    if let Ok(month) = value.parse::<u32>() {
+        return (1..=12).contains(&month).then_some(month);
+    }
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let value = value.trim().trim_end_matches('.').to_ascii_lowercase();
+    if value.len() < 3 {
+        return None;
+    }
+    MONTHS
+        .iter()
+        .position(|month| month.starts_with(&value) || (value == "sept" && *month == "september"))
+        .map(|index| index as u32 + 1)
+}
+
+fn parse_year(text: &str) -> Option<i32> {
+    let year = Regex::new(r"\b(?:19|20)\d{2}\b").ok()?;
+    year.find(text)?.as_str().parse().ok()
+}
+
+fn parse_quarter(text: &str, context_year: Option<i32>) -> Option<(i32, u32)> {
+    let quarter = Regex::new(r"(?i)\bQ(?P<quarter>[1-4])\b").ok()?;
+    let quarter = quarter.captures(text)?["quarter"].parse().ok()?;
+    Some((parse_year(text).or(context_year)?, quarter))
+}
+
+fn month_range(year: i32, month: u32) -> Option<(NaiveDate, NaiveDate)> {
+    let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    Some((start, next.pred_opt()?))
+}
+
+fn quarter_range(year: i32, quarter: u32) -> Option<(NaiveDate, NaiveDate)> {
+    let first_month = (quarter - 1) * 3 + 1;
+    let (start, _) = month_range(year, first_month)?;
+    let (_, end) = month_range(year, first_month + 2)?;
+    Some((start, end))
+}
+
+fn period_date((start, end): (NaiveDate, NaiveDate)) -> ManualDate {
+    if start == end {
+        ManualDate::Single(start)
+    } else {
+        ManualDate::Range { start, end }
+    }
+}
+
+/// Replace markdown links with their text and drop bare URLs, collecting both
+/// as receipts.
+fn extract_links(text: &str, receipts: &mut Vec<Link>) -> String {
+    let markdown_link =
+        Regex::new(r"\[([^\]]+)\]\((https?://[^)\s]+)\)").expect("markdown link regex is valid");
+    let bare_url = Regex::new(r"<?(https?://[^\s<>)]+)>?").expect("bare URL regex is valid");
+
+    let mut without_links = String::new();
+    let mut last = 0;
+    for captures in markdown_link.captures_iter(text) {
+        let whole = captures.get(0).expect("match has a whole group");
+        without_links.push_str(&text[last..whole.start()]);
+        without_links.push_str(&captures[1]);
+        receipts.push(Link {
+            label: clean_text(&captures[1]),
+            url: captures[2].to_string(),
+        });
+        last = whole.end();
+    }
+    without_links.push_str(&text[last..]);
+
+    let mut cleaned = String::new();
+    let mut last = 0;
+    for captures in bare_url.captures_iter(&without_links) {
+        let whole = captures.get(0).expect("match has a whole group");
+        cleaned.push_str(&without_links[last..whole.start()]);
+        receipts.push(Link {
+            label: "link".to_string(),
+            url: captures[1].trim_end_matches(['.', ',', ';']).to_string(),
+        });
+        last = whole.end();
+    }
+    cleaned.push_str(&without_links[last..]);
+    clean_text(&cleaned)
+}
+
+/// Drop emphasis markers and collapse whitespace.
+fn clean_text(text: &str) -> String {
+    text.replace("**", "")
+        .replace("__", "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Trim punctuation left behind where a date or link was removed.
+fn trim_separators(text: &str) -> String {
+    let separators: &[char] = &[' ', ':', '-', '–', '—', '|', ','];
+    let text = clean_text(text)
+        .replace("( )", "")
+        .replace("()", "")
+        .replace("[ ]", "")
+        .replace("[]", "");
+    clean_text(&text).trim_matches(separators).to_string()
+}
@@ -1,13 +1,17 @@
 //! Manual events file and mapping utilities for the manual ingest adapter.
 
 use anyhow::{Context, Result};
-use chrono::{NaiveDate, Utc};
-use shiplog::ids::EventId;
+use chrono::{Days, Months, NaiveDate, Utc};
+use shiplog::ids::{EventId, OpaqueId};
 use shiplog::schema::coverage::TimeWindow;
 use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, ManualDate, ManualEvent, ManualEventEntry,
-    ManualEventsFile, RepoRef, RepoVisibility, SourceRef, SourceSystem,
+    ManualEventsFile, ManualRepeat, RepoRef, RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::json_schema::SchemaDocument;
+use shiplog::schema::version::SchemaVersion;
+
+pub use shiplog::schema::migrate::promote_manual_incident;
 use std::path::Path;
 
 /// Read a manual events file from disk.
@@ -15,6 +19,7 @@ pub fn read_manual_events(path: &Path) -> Result<ManualEventsFile> {
     let text = std::fs::read_to_string(path)
         .with_context(|| format!("read manual events from {path:?}"))?;
     let file: ManualEventsFile = serde_yaml::from_str(&text)
+        .map_err(|err| SchemaDocument::ManualEventsFile.annotate_yaml(&text, err))
         .with_context(|| format!("parse manual events yaml {path:?}"))?;
     Ok(file)
 }
@@ -52,6 +57,9 @@ pub fn create_entry(
         tags: Vec::new(),
         receipts: Vec::new(),
         impact: None,
+        metrics: Vec::new(),
+        evidence: Vec::new(),
+        repeat: None,
     }
 }
 
@@ -64,6 +72,10 @@ pub fn entry_date_range(entry: &ManualEventEntry) -> (NaiveDate, NaiveDate) {
 }
 
 /// Convert a single entry to a canonical envelope.
+///
+/// `Incident` entries come out as structured
+/// [`IncidentEvent`](shiplog::schema::event::IncidentEvent)s; see
+/// [`promote_manual_incident`].
 pub fn entry_to_event(entry: &ManualEventEntry, user: &str) -> EventEnvelope {
     let (start_date, end_date) = entry_date_range(entry);
     let occurred_at = end_date
@@ -79,9 +91,13 @@ pub fn entry_to_event(entry: &ManualEventEntry, user: &str) -> EventEnvelope {
         started_at: Some(start_date),
         ended_at: Some(end_date),
         impact: entry.impact.clone(),
+        metrics: entry.metrics.clone(),
+        effort: None,
+        evidence: entry.evidence.clone(),
     };
 
-    EventEnvelope {
+    promote_manual_incident(EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id,
         kind: EventKind::Manual,
         occurred_at,
@@ -103,8 +119,60 @@ pub fn entry_to_event(entry: &ManualEventEntry, user: &str) -> EventEnvelope {
         source: SourceRef {
             system: SourceSystem::Manual,
             url: None,
-            opaque_id: Some(entry.id.clone()),
+            opaque_id: Some(OpaqueId::from_raw("manual", &entry.id)),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
+    })
+}
+
+/// Dates a recurring entry falls on inside the half-open `window`, in order.
+///
+/// Occurrences count from the entry's first date and stop at the end of its
+/// range, or at the end of the window when the entry has a single date.
+pub fn recurrence_dates(
+    entry: &ManualEventEntry,
+    repeat: ManualRepeat,
+    window: &TimeWindow,
+) -> Vec<NaiveDate> {
+    let (first, last) = match &entry.date {
+        ManualDate::Single(date) => (*date, None),
+        ManualDate::Range { start, end } => (*start, Some(*end)),
+    };
+    let mut dates = Vec::new();
+    for n in 0.. {
+        let Some(date) = nth_occurrence(first, repeat, n) else {
+            break;
+        };
+        if date >= window.until || last.is_some_and(|last| date > last) {
+            break;
+        }
+        if date >= window.since {
+            dates.push(date);
+        }
+    }
+    dates
+}
+
+/// Counted from `first` rather than the previous occurrence, so monthly
+/// entries on the 31st return to the 31st after a short month.
+fn nth_occurrence(first: NaiveDate, repeat: ManualRepeat, n: u32) -> Option<NaiveDate> {
+    match repeat {
+        ManualRepeat::Weekly => first.checked_add_days(Days::new(7 * u64::from(n))),
+        ManualRepeat::Biweekly => first.checked_add_days(Days::new(14 * u64::from(n))),
+        ManualRepeat::Monthly => first.checked_add_months(Months::new(n)),
+    }
+}
+
+/// One occurrence of a recurring entry, as a single-day entry whose id
+/// carries the date.
+fn occurrence(entry: &ManualEventEntry, date: NaiveDate) -> ManualEventEntry {
+    ManualEventEntry {
+        id: format!("{}-{date}", entry.id),
+        date: ManualDate::Single(date),
+        repeat: None,
+        ..entry.clone()
     }
 }
 
@@ -112,6 +180,7 @@ pub fn entry_to_event(entry: &ManualEventEntry, user: &str) -> EventEnvelope {
 ///
 /// * Includes only entries that intersect the half-open window.
 /// * Emits a warning for partial overlaps on the boundary.
+/// * Expands recurring entries into one event per occurrence in the window.
 pub fn events_in_window(
     entries: &[ManualEventEntry],
     user: &str,
@@ -121,6 +190,15 @@ pub fn events_in_window(
     let mut warnings = Vec::new();
 
     for entry in entries {
+        if let Some(repeat) = entry.repeat {
+            events.extend(
+                recurrence_dates(entry, repeat, window)
+                    .into_iter()
+                    .map(|date| entry_to_event(&occurrence(entry, date), user)),
+            );
+            continue;
+        }
+
         let (start_date, end_date) = entry_date_range(entry);
 
         if end_date < window.since || start_date >= window.until {
@@ -145,7 +223,7 @@ mod tests {
     use super::*;
     use chrono::NaiveDate;
     use proptest::prelude::*;
-    use shiplog::schema::event::{Link, ManualEventType};
+    use shiplog::schema::event::{IncidentRole, IncidentSeverity, Link, ManualEventType};
 
     fn make_entry(id: &str, date: ManualDate) -> ManualEventEntry {
         create_entry(id, ManualEventType::Note, date, format!("Event {id}"))
@@ -201,7 +279,7 @@ mod tests {
     fn entry_to_event_sets_manual_defaults() {
         let mut entry = create_entry(
             "event-1",
-            ManualEventType::Incident,
+            ManualEventType::Note,
             ManualDate::Range {
                 start: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
                 end: NaiveDate::from_ymd_opt(2025, 2, 5).unwrap(),
@@ -221,6 +299,76 @@ mod tests {
         assert!(!ev.links.is_empty());
     }
 
+    #[test]
+    fn entry_to_event_promotes_incidents() {
+        let mut entry = create_entry(
+            "incident-1",
+            ManualEventType::Incident,
+            ManualDate::Range {
+                start: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+                end: NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(),
+            },
+            "Queue backlog",
+        );
+        entry.description = Some("Drained the dead-letter queue".to_string());
+        entry.impact = Some("Delayed emails for 2 days".to_string());
+
+        let ev = entry_to_event(&entry, "alice");
+
+        assert_eq!(ev.kind, EventKind::Incident);
+        assert_eq!(ev.id, EventId::from_parts(["manual", "incident-1"]));
+        assert_eq!(
+            ev.source.opaque_id,
+            Some(OpaqueId::from_raw("manual", "incident-1"))
+        );
+        let EventPayload::Incident(incident) = ev.payload else {
+            panic!("expected incident payload");
+        };
+        assert_eq!(incident.title, "Queue backlog");
+        assert_eq!(incident.severity, IncidentSeverity::Unknown);
+        assert_eq!(incident.role, IncidentRole::Unknown);
+        assert_eq!(incident.duration_minutes, Some(2 * 24 * 60));
+        assert_eq!(
+            incident.summary.as_deref(),
+            Some("Drained the dead-letter queue")
+        );
+        assert_eq!(
+            incident.customer_impact.as_deref(),
+            Some("Delayed emails for 2 days")
+        );
+    }
+
+    #[test]
+    fn promote_manual_incident_leaves_single_day_resolution_unknown() {
+        let entry = create_entry(
+            "incident-2",
+            ManualEventType::Incident,
+            ManualDate::Single(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
+            "Pager storm",
+        );
+        let mut legacy = entry_to_event(&entry, "alice");
+        let EventPayload::Incident(incident) = &legacy.payload else {
+            panic!("expected incident payload");
+        };
+        assert!(incident.resolved_at.is_none());
+        assert!(incident.duration_minutes.is_none());
+
+        // Non-incident manual events pass through untouched.
+        legacy.kind = EventKind::Manual;
+        legacy.payload = EventPayload::Manual(ManualEvent {
+            event_type: ManualEventType::Design,
+            title: "Design".to_string(),
+            description: None,
+            started_at: None,
+            ended_at: None,
+            impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+        });
+        assert_eq!(promote_manual_incident(legacy.clone()), legacy);
+    }
+
     // --- YAML parsing edge cases ---
 
     #[test]
@@ -301,6 +449,11 @@ mod tests {
         for et in types {
             let entry = create_entry("t", et.clone(), d.clone(), "Title");
             let ev = entry_to_event(&entry, "user");
+            if et == ManualEventType::Incident {
+                assert_eq!(ev.kind, EventKind::Incident);
+                assert!(matches!(ev.payload, EventPayload::Incident(_)));
+                continue;
+            }
             assert_eq!(ev.kind, EventKind::Manual);
             if let EventPayload::Manual(m) = &ev.payload {
                 assert_eq!(m.event_type, et);
@@ -352,7 +505,10 @@ mod tests {
         );
         let ev = entry_to_event(&entry, "user");
         assert_eq!(ev.source.system, SourceSystem::Manual);
-        assert_eq!(ev.source.opaque_id, Some("src-test".to_string()));
+        assert_eq!(
+            ev.source.opaque_id,
+            Some(OpaqueId::from_raw("manual", "src-test"))
+        );
     }
 
     #[test]
@@ -419,6 +575,64 @@ mod tests {
         assert!(events.is_empty());
     }
 
+    #[test]
+    fn events_in_window_expands_weekly_entry_inside_window() {
+        let window = TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
+            until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        };
+        let mut entry = make_entry(
+            "triage",
+            ManualDate::Single(NaiveDate::from_ymd_opt(2025, 1, 6).unwrap()),
+        );
+        entry.repeat = Some(ManualRepeat::Weekly);
+
+        let (events, warnings) = events_in_window(&[entry], "user", &window);
+        let dates: Vec<_> = events.iter().map(|e| e.occurred_at.date_naive()).collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2025, 1, 13).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 20).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 27).unwrap(),
+            ]
+        );
+        assert_eq!(
+            events[0].id,
+            EventId::from_parts(["manual", "triage-2025-01-13"])
+        );
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn recurrence_stops_at_range_end_and_keeps_month_day() {
+        let window = TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            until: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        };
+        let mut entry = make_entry(
+            "sync",
+            ManualDate::Range {
+                start: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+                end: NaiveDate::from_ymd_opt(2025, 4, 30).unwrap(),
+            },
+        );
+        entry.repeat = Some(ManualRepeat::Monthly);
+        assert_eq!(
+            recurrence_dates(&entry, ManualRepeat::Monthly, &window),
+            vec![
+                NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 4, 30).unwrap(),
+            ]
+        );
+        assert_eq!(
+            recurrence_dates(&entry, ManualRepeat::Biweekly, &window).len(),
+            7
+        );
+    }
+
     #[test]
     fn events_in_window_range_partially_before_window_warns() {
         let window = TimeWindow {
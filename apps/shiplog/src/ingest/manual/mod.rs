@@ -7,14 +7,17 @@ use anyhow::Result;
 use chrono::Utc;
 use shiplog::ports::{IngestOutput, Ingestor};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::event::{EventEnvelope, EventPayload};
 use shiplog::schema::freshness::{FreshnessStatus, SourceFreshness};
+use shiplog::schema::version::SchemaVersion;
 use std::path::Path;
 
+pub mod brag;
 pub mod events;
 
 pub use events::{
     create_empty_file, create_entry, entry_date_range, entry_to_event, events_in_window,
-    read_manual_events, write_manual_events,
+    promote_manual_incident, read_manual_events, recurrence_dates, write_manual_events,
 };
 
 /// Ingestor for manual events from YAML files.
@@ -80,6 +83,7 @@ impl ManualIngestor {
 }
 
 impl Ingestor for ManualIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "manual"))]
     fn ingest(&self) -> Result<IngestOutput> {
         if !self.events_path.exists() {
             // Return empty output if file doesn't exist
@@ -87,6 +91,7 @@ impl Ingestor for ManualIngestor {
             return Ok(IngestOutput {
                 events: Vec::new(),
                 coverage: CoverageManifest {
+                    schema_version: SchemaVersion::CURRENT,
                     run_id: shiplog::ids::RunId::now("manual"),
                     generated_at: observed_at,
                     user: self.user.clone(),
@@ -100,12 +105,16 @@ impl Ingestor for ManualIngestor {
                         fetched: 0,
                         incomplete_results: Some(false),
                         notes: vec!["manual_events_file_not_found".to_string()],
+                        source: Some("manual".to_string()),
                     }],
                     warnings: vec![format!(
                         "Manual events file not found: {:?}",
                         self.events_path
                     )],
                     completeness: Completeness::Unknown,
+                    score: None,
+                    timezone: None,
+                    budget: vec![],
                 },
                 freshness: vec![SourceFreshness {
                     source: "manual".to_string(),
@@ -118,14 +127,18 @@ impl Ingestor for ManualIngestor {
                         self.events_path
                     )),
                 }],
+                budget: Vec::new(),
             });
         }
 
         let file = read_manual_events(&self.events_path)?;
-        let (events, warnings) = events_in_window(&file.events, &self.user, &self.window);
+        let (mut events, mut warnings) = events_in_window(&file.events, &self.user, &self.window);
+        let base_dir = self.events_path.parent().unwrap_or(Path::new(""));
+        warnings.extend(resolve_evidence_paths(&mut events, base_dir));
 
         let observed_at = Utc::now();
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: shiplog::ids::RunId::now("manual"),
             generated_at: observed_at,
             user: self.user.clone(),
@@ -139,9 +152,13 @@ impl Ingestor for ManualIngestor {
                 fetched: events.len() as u64,
                 incomplete_results: Some(false),
                 notes: vec!["manual_events".to_string()],
+                source: Some("manual".to_string()),
             }],
             warnings,
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let freshness = vec![SourceFreshness {
@@ -157,10 +174,36 @@ impl Ingestor for ManualIngestor {
             events,
             coverage,
             freshness,
+            budget: Vec::new(),
         })
     }
 }
 
+/// Point evidence paths at files relative to `base_dir` (the directory of
+/// `manual_events.yaml`), dropping entries whose file is missing. Returns one
+/// warning per dropped file.
+fn resolve_evidence_paths(events: &mut [EventEnvelope], base_dir: &Path) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for event in events {
+        let EventPayload::Manual(manual) = &mut event.payload else {
+            continue;
+        };
+        manual.evidence.retain_mut(|evidence| {
+            let path = base_dir.join(&evidence.path);
+            if !path.is_file() {
+                warnings.push(format!(
+                    "Manual event {:?} evidence file not found: {:?}",
+                    manual.title, path
+                ));
+                return false;
+            }
+            evidence.path = path.to_string_lossy().into_owned();
+            true
+        });
+    }
+    warnings
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,6 +224,9 @@ mod tests {
                 url: "https://example.com/doc".to_string(),
             }],
             impact: Some("Made things better".to_string()),
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         }
     }
 
@@ -222,6 +268,9 @@ mod tests {
                     tags: vec![],
                     receipts: vec![],
                     impact: None,
+                    metrics: Vec::new(),
+                    evidence: Vec::new(),
+                    repeat: None,
                 },
                 ManualEventEntry {
                     id: "outside".to_string(),
@@ -233,6 +282,9 @@ mod tests {
                     tags: vec![],
                     receipts: vec![],
                     impact: None,
+                    metrics: Vec::new(),
+                    evidence: Vec::new(),
+                    repeat: None,
                 },
             ],
         };
@@ -250,7 +302,7 @@ mod tests {
         assert_eq!(output.events.len(), 1);
         assert_eq!(
             output.events[0].source.opaque_id,
-            Some("inside".to_string())
+            Some(shiplog::ids::OpaqueId::from_raw("manual", "inside"))
         );
         // Freshness receipt: the manual source reads a YAML file from
         // disk every run, so a successful ingest always reports Fresh
@@ -310,6 +362,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file).unwrap();
@@ -345,6 +400,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file).unwrap();
@@ -378,6 +436,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file).unwrap();
@@ -413,6 +474,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file).unwrap();
@@ -455,6 +519,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file).unwrap();
@@ -497,6 +564,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file).unwrap();
@@ -549,6 +619,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file).unwrap();
@@ -581,6 +654,9 @@ mod tests {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         write_manual_events(&path, &file2).unwrap();
@@ -598,4 +674,52 @@ mod tests {
             "event on window.since boundary must be included"
         );
     }
+
+    #[test]
+    fn ingest_resolves_evidence_next_to_the_yaml_and_warns_on_missing_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("manual_events.yaml");
+        std::fs::create_dir(temp.path().join("shots")).unwrap();
+        std::fs::write(temp.path().join("shots/latency.png"), "png").unwrap();
+
+        let mut entry = make_test_entry("with-evidence");
+        entry.evidence = vec![
+            shiplog::schema::event::EvidenceFile {
+                path: "shots/latency.png".to_string(),
+                label: None,
+                sha256: None,
+            },
+            shiplog::schema::event::EvidenceFile {
+                path: "shots/missing.png".to_string(),
+                label: None,
+                sha256: None,
+            },
+        ];
+        let file = ManualEventsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            events: vec![entry],
+        };
+        write_manual_events(&path, &file).unwrap();
+
+        let output = ManualIngestor::new(
+            &path,
+            "testuser".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+        )
+        .ingest()
+        .unwrap();
+
+        let EventPayload::Manual(manual) = &output.events[0].payload else {
+            panic!("expected manual payload");
+        };
+        assert_eq!(manual.evidence.len(), 1);
+        assert_eq!(
+            Path::new(&manual.evidence[0].path),
+            temp.path().join("shots/latency.png")
+        );
+        assert_eq!(output.coverage.warnings.len(), 1);
+        assert!(output.coverage.warnings[0].contains("missing.png"));
+    }
 }
@@ -1,9 +1,14 @@
 //! Internal source adapter modules.
 
+pub mod bitbucket;
+pub mod error;
+pub mod gerrit;
 pub mod git;
 pub mod github;
+pub mod github_export;
 pub mod gitlab;
 pub mod jira;
 pub mod json;
 pub mod linear;
 pub mod manual;
+pub mod slack;
@@ -0,0 +1,865 @@
+//! Slack Web API ingestor for "ship notes" channels, with cache support.
+//!
+//! Reads the history of designated channels (e.g. `#shipped`, `#launches`),
+//! keeps top-level messages the user posted inside the window, and turns each
+//! into a manual event. Marks partial completeness when the page cap or the
+//! request budget stops pagination.
+
+use crate::cache::{ApiCache, CacheKey};
+use crate::coverage::ReportingTimezone;
+use crate::http::{HttpBudget, RetryPolicy, budget_cutoff, cutoff_warning, send_with_retry};
+use crate::ingest::error::IngestError;
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ports::{IngestOutput, IngestPlan, Ingestor, PlannedQuery};
+use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::event::{
+    Actor, EventEnvelope, EventKind, EventPayload, Link, ManualEvent, ManualEventType, RepoRef,
+    RepoVisibility, SourceRef, SourceSystem,
+};
+use shiplog::schema::version::SchemaVersion;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+use url::Url;
+
+/// Messages requested per history page.
+const PAGE_LIMIT: u32 = 200;
+
+/// Longest title taken from a message's first line.
+const TITLE_CHARS: usize = 80;
+
+#[derive(Debug)]
+pub struct SlackIngestor {
+    /// Slack member ID (`U…`) whose messages are collected.
+    pub user: String,
+    pub since: NaiveDate,
+    pub until: NaiveDate,
+    /// Channels to read, by ID (`C…`) or name (`shipped` or `#shipped`).
+    pub channels: Vec<String>,
+    /// Channels whose messages become launches; the rest become notes.
+    pub launch_channels: Vec<String>,
+    /// Workspace subdomain for permalinks (`acme` for `acme.slack.com`).
+    pub workspace: Option<String>,
+    pub throttle_ms: u64,
+    pub token: Option<String>,
+    /// History pages read per channel before the collection is marked partial.
+    pub max_pages: u32,
+    /// Optional cache for API responses
+    pub cache: Option<ApiCache>,
+    /// Timezone the `since`/`until` dates are read in. Default: UTC.
+    pub timezone: ReportingTimezone,
+    /// Run-wide request budget shared with the other ingestors. Default:
+    /// unlimited.
+    pub http_budget: HttpBudget,
+    /// Retries for rate-limited and failed requests. Default: three, with
+    /// backoff.
+    pub retry: RetryPolicy,
+}
+
+impl SlackIngestor {
+    pub fn new(user: String, since: NaiveDate, until: NaiveDate) -> Self {
+        Self {
+            user,
+            since,
+            until,
+            channels: Vec::new(),
+            launch_channels: Vec::new(),
+            workspace: None,
+            throttle_ms: 0,
+            token: None,
+            max_pages: 10,
+            cache: None,
+            timezone: ReportingTimezone::UTC,
+            http_budget: HttpBudget::default(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Set the Slack token (a bot or user token with `channels:history`).
+    pub fn with_token(mut self, token: String) -> Result<Self> {
+        if token.is_empty() {
+            return Err(anyhow!("Slack token cannot be empty"));
+        }
+        self.token = Some(token);
+        Ok(self)
+    }
+
+    /// Set the channels to read.
+    pub fn with_channels(mut self, channels: Vec<String>) -> Result<Self> {
+        let channels: Vec<String> = channels
+            .iter()
+            .map(|channel| channel_key(channel))
+            .filter(|channel| !channel.is_empty())
+            .collect();
+        if channels.is_empty() {
+            return Err(anyhow!("Slack channels cannot be empty"));
+        }
+        self.channels = channels;
+        Ok(self)
+    }
+
+    /// Set the channels whose messages are recorded as launches.
+    pub fn with_launch_channels(mut self, channels: Vec<String>) -> Self {
+        self.launch_channels = channels
+            .iter()
+            .map(|channel| channel_key(channel))
+            .collect();
+        self
+    }
+
+    /// Set the workspace subdomain used in message permalinks.
+    pub fn with_workspace(mut self, workspace: String) -> Self {
+        self.workspace = Some(workspace);
+        self
+    }
+
+    /// Stop each channel after `pages` history pages and mark the run partial.
+    pub fn with_max_pages(mut self, pages: u32) -> Result<Self> {
+        if pages == 0 {
+            return Err(anyhow!("Slack page cap must be at least 1"));
+        }
+        self.max_pages = pages;
+        Ok(self)
+    }
+
+    /// Count requests against `budget`, shared with the run's other
+    /// ingestors.
+    pub fn with_http_budget(mut self, budget: HttpBudget) -> Self {
+        self.http_budget = budget;
+        self
+    }
+
+    /// Retry rate-limited and failed requests under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Read window dates in `timezone` instead of UTC.
+    pub fn with_timezone(mut self, timezone: ReportingTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Enable caching with the given cache directory.
+    pub fn with_cache(mut self, cache_dir: impl Into<PathBuf>) -> Result<Self> {
+        let cache_path = cache_dir.into().join("slack-api-cache.db");
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("create Slack cache directory {parent:?}"))?;
+        }
+        let cache = ApiCache::open(cache_path)?;
+        self.cache = Some(cache);
+        Ok(self)
+    }
+
+    /// Enable in-memory caching (useful for testing).
+    pub fn with_in_memory_cache(mut self) -> Result<Self> {
+        let cache = ApiCache::open_in_memory()?;
+        self.cache = Some(cache);
+        Ok(self)
+    }
+
+    /// Set throttle delay between API requests (in milliseconds).
+    pub fn with_throttle(mut self, ms: u64) -> Self {
+        self.throttle_ms = ms;
+        self
+    }
+
+    fn html_base_url(&self) -> String {
+        match &self.workspace {
+            Some(workspace) => format!("https://{workspace}.slack.com"),
+            None => "https://slack.com".to_string(),
+        }
+    }
+
+    fn api_base_url(&self) -> String {
+        "https://slack.com/api".to_string()
+    }
+
+    #[mutants::skip]
+    fn client(&self) -> Result<Client> {
+        Client::builder()
+            .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .context("build reqwest client")
+    }
+
+    #[mutants::skip]
+    fn throttle(&self) {
+        if self.throttle_ms > 0 {
+            sleep(Duration::from_millis(self.throttle_ms));
+        }
+    }
+
+    /// Call a Web API method. Slack reports most failures as HTTP 200 with
+    /// `"ok": false`, so both layers are checked.
+    #[mutants::skip]
+    fn call<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        method: &str,
+        params: &[(&str, String)],
+    ) -> Result<T> {
+        let url = Url::parse_with_params(&format!("{}/{method}", self.api_base_url()), params)
+            .with_context(|| format!("build Slack {method} url"))?;
+        let mut req = client.get(url.clone());
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+
+        let resp = send_with_retry(&self.retry, &self.http_budget, url.as_str(), req)
+            .with_context(|| format!("GET {url}"))?;
+        self.throttle();
+
+        let status = resp.status();
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            let body = resp.text().unwrap_or_default();
+            return Err(IngestError::from_response("Slack", status, &headers, body).into());
+        }
+
+        let body: serde_json::Value = resp.json().map_err(|err| IngestError::Parse {
+            system: "Slack",
+            context: method.to_string(),
+            detail: err.to_string(),
+        })?;
+        if body["ok"] != serde_json::Value::Bool(true) {
+            let error = body["error"].as_str().unwrap_or("unknown error");
+            return Err(anyhow!("Slack {method} failed: {error}"));
+        }
+        serde_json::from_value(body).map_err(|err| {
+            IngestError::Parse {
+                system: "Slack",
+                context: method.to_string(),
+                detail: err.to_string(),
+            }
+            .into()
+        })
+    }
+
+    /// Call a Web API method through the response cache. Slack sends no
+    /// validators, so entries are reused until they expire.
+    #[mutants::skip]
+    fn call_cached<T: DeserializeOwned + Serialize>(
+        &self,
+        client: &Client,
+        method: &str,
+        params: &[(&str, String)],
+        cache_key: &str,
+    ) -> Result<T> {
+        if let Some(cache) = &self.cache
+            && let Some(value) = cache.get(cache_key)?
+        {
+            return Ok(value);
+        }
+        let value = self.call(client, method, params)?;
+        if let Some(cache) = &self.cache {
+            cache.set(cache_key, &value)?;
+        }
+        Ok(value)
+    }
+
+    /// Map configured channel names to IDs. IDs pass through; names are
+    /// looked up with `conversations.list`.
+    #[mutants::skip]
+    fn resolve_channels(&self, client: &Client) -> Result<Vec<SlackChannel>> {
+        let needs_lookup = self.channels.iter().any(|channel| !is_channel_id(channel));
+        let known = if needs_lookup {
+            self.list_channels(client)?
+        } else {
+            Vec::new()
+        };
+
+        self.channels
+            .iter()
+            .map(|channel| {
+                if is_channel_id(channel) {
+                    let name = known
+                        .iter()
+                        .find(|known| &known.id == channel)
+                        .map_or_else(|| channel.clone(), |known| known.name.clone());
+                    return Ok(SlackChannel {
+                        id: channel.clone(),
+                        name,
+                    });
+                }
+                known
+                    .iter()
+                    .find(|known| &known.name == channel)
+                    .cloned()
+                    .ok_or_else(|| {
+                        anyhow!("Slack channel #{channel} not found or not visible to the token")
+                    })
+            })
+            .collect()
+    }
+
+    #[mutants::skip]
+    fn list_channels(&self, client: &Client) -> Result<Vec<SlackChannel>> {
+        let mut channels = Vec::new();
+        let mut cursor = String::new();
+        for _ in 0..self.max_pages {
+            let mut params = vec![
+                ("types", "public_channel,private_channel".to_string()),
+                ("exclude_archived", "true".to_string()),
+                ("limit", PAGE_LIMIT.to_string()),
+            ];
+            if !cursor.is_empty() {
+                params.push(("cursor", cursor.clone()));
+            }
+            let page: SlackChannelList = self.call(client, "conversations.list", &params)?;
+            channels.extend(page.channels);
+            cursor = page.response_metadata.next_cursor;
+            if cursor.is_empty() {
+                break;
+            }
+        }
+        Ok(channels)
+    }
+
+    /// Unix timestamps bounding the window, as Slack's `oldest`/`latest`.
+    fn window_bounds(&self) -> (String, String) {
+        (
+            self.timezone.day_start(self.since).timestamp().to_string(),
+            self.timezone.day_start(self.until).timestamp().to_string(),
+        )
+    }
+
+    /// Read one channel's history inside the window.
+    #[mutants::skip]
+    #[tracing::instrument(
+        name = "window",
+        level = "debug",
+        skip_all,
+        fields(since = %self.since, until = %self.until, channel = %channel.name)
+    )]
+    fn collect_channel(
+        &self,
+        client: &Client,
+        channel: &SlackChannel,
+    ) -> Result<(Vec<SlackMessage>, CoverageSlice, bool)> {
+        let (oldest, latest) = self.window_bounds();
+        let mut messages = Vec::new();
+        let mut cursor = String::new();
+        let mut partial = false;
+
+        for page in 1..=self.max_pages {
+            let mut params = vec![
+                ("channel", channel.id.clone()),
+                ("oldest", oldest.clone()),
+                ("latest", latest.clone()),
+                ("inclusive", "false".to_string()),
+                ("limit", PAGE_LIMIT.to_string()),
+            ];
+            if !cursor.is_empty() {
+                params.push(("cursor", cursor.clone()));
+            }
+            let key = CacheKey::slack_history(&channel.id, &oldest, &latest, page);
+            let history: SlackHistory =
+                match self.call_cached(client, "conversations.history", &params, &key) {
+                    Ok(history) => history,
+                    // Keep the pages already fetched; `ingest` explains the cutoff.
+                    Err(err) if budget_cutoff(&err).is_some() => {
+                        partial = true;
+                        break;
+                    }
+                    Err(err) => return Err(err),
+                };
+            messages.extend(history.messages);
+            cursor = history.response_metadata.next_cursor;
+            if !history.has_more || cursor.is_empty() {
+                cursor.clear();
+                break;
+            }
+        }
+        if !cursor.is_empty() {
+            partial = true;
+        }
+
+        let fetched = messages.len() as u64;
+        let slice = CoverageSlice {
+            window: TimeWindow {
+                since: self.since,
+                until: self.until,
+            },
+            query: format!("channel:#{} from:{}", channel.name, self.user),
+            total_count: fetched,
+            fetched,
+            incomplete_results: Some(partial),
+            notes: vec![format!("channel:{}", channel.id)],
+            source: Some("slack".to_string()),
+        };
+        Ok((messages, slice, partial))
+    }
+
+    /// Convert the user's top-level messages to manual events.
+    #[mutants::skip]
+    fn messages_to_events(
+        &self,
+        channel: &SlackChannel,
+        messages: Vec<SlackMessage>,
+    ) -> Result<Vec<EventEnvelope>> {
+        let window = TimeWindow {
+            since: self.since,
+            until: self.until,
+        };
+        let event_type = if self.launch_channels.contains(&channel.name)
+            || self.launch_channels.contains(&channel.id)
+        {
+            ManualEventType::Launch
+        } else {
+            ManualEventType::Note
+        };
+        let mut events = Vec::new();
+
+        for message in messages {
+            // Joins, edits, and bot posts carry a subtype; thread replies are
+            // discussion, not the announcement itself.
+            let is_reply = message
+                .thread_ts
+                .as_ref()
+                .is_some_and(|thread| thread != &message.ts);
+            if message.user.as_deref() != Some(self.user.as_str())
+                || message.subtype.is_some()
+                || is_reply
+                || message.text.trim().is_empty()
+            {
+                continue;
+            }
+            let Some(posted_at) = parse_ts(&message.ts) else {
+                continue;
+            };
+            if !self.timezone.contains(&window, &posted_at) {
+                continue;
+            }
+            let url = format!(
+                "{}/archives/{}/p{}",
+                self.html_base_url(),
+                channel.id,
+                message.ts.replace('.', "")
+            );
+            let raw_id = format!("{}:{}", channel.id, message.ts);
+
+            let event = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
+                id: EventId::from_parts(["slack", "message", &channel.id, &message.ts]),
+                kind: EventKind::Manual,
+                occurred_at: posted_at,
+                actor: Actor {
+                    login: self.user.clone(),
+                    id: None, // Slack member IDs are strings, not u64
+                },
+                repo: RepoRef {
+                    full_name: format!("slack/#{}", channel.name),
+                    html_url: Some(format!("{}/archives/{}", self.html_base_url(), channel.id)),
+                    visibility: RepoVisibility::Private,
+                },
+                payload: EventPayload::Manual(ManualEvent {
+                    event_type: event_type.clone(),
+                    title: message_title(&message.text),
+                    description: Some(message.text.trim().to_string()),
+                    started_at: None,
+                    ended_at: Some(self.timezone.local_date(&posted_at)),
+                    impact: None,
+                    effort: None,
+                    metrics: Vec::new(),
+                    evidence: Vec::new(),
+                }),
+                tags: vec![format!("slack:{}", channel.name)],
+                links: vec![Link {
+                    label: "Slack message".to_string(),
+                    url: url.clone(),
+                }],
+                source: SourceRef {
+                    system: SourceSystem::Other("slack".to_string()),
+                    url: Some(url),
+                    opaque_id: Some(OpaqueId::from_raw("slack", &raw_id)),
+                },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
+            };
+
+            events.push(event);
+        }
+
+        Ok(events)
+    }
+}
+
+impl Ingestor for SlackIngestor {
+    #[tracing::instrument(name = "ingest", skip_all, fields(source = "slack"))]
+    #[mutants::skip]
+    fn ingest(&self) -> Result<IngestOutput> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        if self.channels.is_empty() {
+            return Err(anyhow!(
+                "Slack channels are required. Set them using with_channels()"
+            ));
+        }
+        let _token = self.token.as_ref().ok_or_else(|| {
+            anyhow!("Slack token is required. Set it using with_token() or SLACK_TOKEN environment variable")
+        })?;
+
+        let client = self.client()?;
+        let run_id = RunId::now("shiplog");
+        let mut slices: Vec<CoverageSlice> = Vec::new();
+        let mut warnings: Vec<String> = Vec::new();
+        let mut completeness = Completeness::Complete;
+        let mut events: Vec<EventEnvelope> = Vec::new();
+
+        let mut capped: Vec<String> = Vec::new();
+
+        for channel in self.resolve_channels(&client)? {
+            let (messages, slice, partial) = self.collect_channel(&client, &channel)?;
+            slices.push(slice);
+            if partial {
+                completeness = Completeness::Partial;
+                capped.push(channel.name.clone());
+            }
+            events.extend(self.messages_to_events(&channel, messages)?);
+        }
+
+        let rate_budget = self.http_budget.rate_budget("slack", &self.api_base_url());
+        warnings.extend(rate_budget.warning());
+
+        if let Some(cutoff) = self.http_budget.cutoff_for(&self.api_base_url()) {
+            completeness = Completeness::Partial;
+            warnings.push(cutoff_warning(&cutoff));
+        } else {
+            warnings.extend(capped.iter().map(|name| {
+                format!(
+                    "Slack #{name} stopped at the {}-page cap; raise max_pages or narrow the window.",
+                    self.max_pages
+                )
+            }));
+        }
+
+        // Sort for stable output
+        events.sort_by_key(|e| e.occurred_at);
+
+        let cov = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
+            run_id,
+            generated_at: Utc::now(),
+            user: self.user.clone(),
+            window: TimeWindow {
+                since: self.since,
+                until: self.until,
+            },
+            mode: "messages".to_string(),
+            sources: vec!["slack".to_string()],
+            slices,
+            warnings,
+            completeness,
+            score: None,
+            timezone: self.timezone.manifest_name(),
+            budget: vec![rate_budget.clone()],
+        };
+
+        Ok(IngestOutput {
+            events,
+            coverage: cov,
+            freshness: Vec::new(),
+            budget: vec![rate_budget],
+        })
+    }
+
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        if self.since >= self.until {
+            return Err(anyhow!("since must be < until"));
+        }
+        let window = TimeWindow {
+            since: self.since,
+            until: self.until,
+        };
+        let (oldest, latest) = self.window_bounds();
+        let mut notes = vec![format!(
+            "Each channel costs one request per {PAGE_LIMIT} messages, up to {} pages.",
+            self.max_pages
+        )];
+        if self.channels.iter().any(|channel| !is_channel_id(channel)) {
+            notes.push(
+                "Channel names are resolved with conversations.list, at one request per page; pass channel IDs to skip it."
+                    .to_string(),
+            );
+        }
+        let queries =
+            self.channels
+                .iter()
+                .map(|channel| {
+                    // Histories are cached by channel ID, so a name is never a hit.
+                    let cached = match &self.cache {
+                        Some(cache) if is_channel_id(channel) => cache
+                            .contains(&CacheKey::slack_history(channel, &oldest, &latest, 1))?,
+                        _ => false,
+                    };
+                    Ok(PlannedQuery {
+                        window: window.clone(),
+                        query: format!("channel:#{channel} from:{}", self.user),
+                        estimated_requests: 1,
+                        cached,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+        Ok(Some(IngestPlan {
+            source: "slack".to_string(),
+            window,
+            queries,
+            notes,
+        }))
+    }
+}
+
+// Slack API types
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+struct SlackChannel {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackChannelList {
+    channels: Vec<SlackChannel>,
+    #[serde(default)]
+    response_metadata: SlackResponseMetadata,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SlackHistory {
+    messages: Vec<SlackMessage>,
+    #[serde(default)]
+    has_more: bool,
+    #[serde(default)]
+    response_metadata: SlackResponseMetadata,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct SlackResponseMetadata {
+    #[serde(default)]
+    next_cursor: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SlackMessage {
+    ts: String,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    subtype: Option<String>,
+    #[serde(default)]
+    thread_ts: Option<String>,
+}
+
+/// Channel names without the leading `#`; IDs are kept as given.
+fn channel_key(channel: &str) -> String {
+    channel.trim().trim_start_matches('#').to_string()
+}
+
+/// Slack conversation IDs start with `C` (public), `G` (private), or `D`
+/// (direct message) followed by uppercase letters and digits.
+fn is_channel_id(channel: &str) -> bool {
+    channel.len() >= 9
+        && channel.starts_with(['C', 'G', 'D'])
+        && channel
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Parse a message `ts` such as `1736500000.123456`.
+fn parse_ts(ts: &str) -> Option<DateTime<Utc>> {
+    let (secs, micros) = ts.split_once('.').unwrap_or((ts, "0"));
+    let micros = format!("{micros:0<6}");
+    DateTime::from_timestamp(
+        secs.parse().ok()?,
+        micros.get(..6)?.parse::<u32>().ok()? * 1000,
+    )
+}
+
+/// First line of a message, cut to [`TITLE_CHARS`].
+fn message_title(text: &str) -> String {
+    let line = text.trim().lines().next().unwrap_or_default().trim();
+    if line.chars().count() <= TITLE_CHARS {
+        return line.to_string();
+    }
+    let cut: String = line.chars().take(TITLE_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_ingestor() -> SlackIngestor {
+        SlackIngestor::new(
+            "U024BE7LH".to_string(),
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        )
+    }
+
+    fn shipped() -> SlackChannel {
+        SlackChannel {
+            id: "C0SHIPPED1".to_string(),
+            name: "shipped".to_string(),
+        }
+    }
+
+    fn recorded_history() -> SlackHistory {
+        serde_json::from_value(serde_json::json!({
+            "ok": true,
+            "messages": [
+                {"type": "message", "user": "U024BE7LH", "text": "Shipped the new checkout flow :tada:\nBehind the `checkout_v2` flag.", "ts": "1736500000.000200"},
+                {"type": "message", "user": "U0OTHER01", "text": "Nice!", "ts": "1736500100.000100"},
+                {"type": "message", "user": "U024BE7LH", "text": "reply in thread", "ts": "1736500200.000100", "thread_ts": "1736500000.000200"},
+                {"type": "message", "subtype": "channel_join", "user": "U024BE7LH", "text": "<@U024BE7LH> has joined the channel", "ts": "1736400000.000100"},
+                {"type": "message", "user": "U024BE7LH", "text": "Posted before the window", "ts": "1735000000.000100"}
+            ],
+            "has_more": true,
+            "response_metadata": {"next_cursor": "bmV4dF90czoxNzM2"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn with_token_and_channels_validate_input() {
+        assert!(default_ingestor().with_token(String::new()).is_err());
+        assert!(default_ingestor().with_channels(vec!["#".into()]).is_err());
+        assert!(default_ingestor().with_max_pages(0).is_err());
+
+        let ing = default_ingestor()
+            .with_channels(vec!["#shipped".into(), " C0LAUNCH01 ".into()])
+            .unwrap()
+            .with_launch_channels(vec!["#launches".into()]);
+        assert_eq!(ing.channels, vec!["shipped", "C0LAUNCH01"]);
+        assert_eq!(ing.launch_channels, vec!["launches"]);
+    }
+
+    #[test]
+    fn with_cache_creates_missing_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache_dir = temp.path().join("nested").join("cache");
+
+        let ing = default_ingestor().with_cache(&cache_dir).unwrap();
+
+        assert!(ing.cache.is_some());
+        assert!(cache_dir.join("slack-api-cache.db").exists());
+    }
+
+    #[test]
+    fn channel_ids_and_timestamps_parse() {
+        assert!(is_channel_id("C0SHIPPED1"));
+        assert!(is_channel_id("G01234ABCD"));
+        assert!(!is_channel_id("shipped"));
+        assert!(!is_channel_id("Cshort"));
+        assert_eq!(
+            parse_ts("1736500000.000200").unwrap().to_rfc3339(),
+            "2025-01-10T09:06:40.000200+00:00"
+        );
+        assert_eq!(parse_ts("1736500000").unwrap().timestamp(), 1736500000);
+        assert!(parse_ts("soon").is_none());
+    }
+
+    #[test]
+    fn window_bounds_are_unix_day_starts() {
+        assert_eq!(
+            default_ingestor().window_bounds(),
+            ("1735689600".to_string(), "1738368000".to_string())
+        );
+    }
+
+    #[test]
+    fn message_title_uses_the_first_line_and_truncates() {
+        assert_eq!(message_title("  Shipped X\nDetails"), "Shipped X");
+        let long = "a".repeat(100);
+        let title = message_title(&long);
+        assert_eq!(title.chars().count(), TITLE_CHARS);
+        assert!(title.ends_with('…'));
+    }
+
+    #[test]
+    fn collect_channel_replays_cached_pages_and_marks_page_cap_partial() {
+        let ing = default_ingestor()
+            .with_in_memory_cache()
+            .unwrap()
+            .with_max_pages(1)
+            .unwrap();
+        let (oldest, latest) = ing.window_bounds();
+        ing.cache
+            .as_ref()
+            .unwrap()
+            .set(
+                &CacheKey::slack_history("C0SHIPPED1", &oldest, &latest, 1),
+                &recorded_history(),
+            )
+            .unwrap();
+
+        let (messages, slice, partial) = ing.collect_channel(&Client::new(), &shipped()).unwrap();
+
+        assert_eq!(messages.len(), 5);
+        assert!(partial);
+        assert_eq!(slice.query, "channel:#shipped from:U024BE7LH");
+        assert_eq!(slice.incomplete_results, Some(true));
+    }
+
+    #[test]
+    fn messages_to_events_keeps_the_users_announcements_in_window() {
+        let ing = default_ingestor()
+            .with_workspace("acme".into())
+            .with_launch_channels(vec!["shipped".into()]);
+
+        let events = ing
+            .messages_to_events(&shipped(), recorded_history().messages)
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.kind, EventKind::Manual);
+        assert_eq!(event.repo.full_name, "slack/#shipped");
+        assert_eq!(event.tags, vec!["slack:shipped".to_string()]);
+        assert_eq!(
+            event.source.url.as_deref(),
+            Some("https://acme.slack.com/archives/C0SHIPPED1/p1736500000000200")
+        );
+        let EventPayload::Manual(manual) = &event.payload else {
+            panic!("Expected Manual payload");
+        };
+        assert_eq!(manual.event_type, ManualEventType::Launch);
+        assert_eq!(manual.title, "Shipped the new checkout flow :tada:");
+        assert_eq!(
+            manual.ended_at,
+            Some(NaiveDate::from_ymd_opt(2025, 1, 10).unwrap())
+        );
+
+        let notes = default_ingestor()
+            .messages_to_events(&shipped(), recorded_history().messages)
+            .unwrap();
+        let EventPayload::Manual(note) = &notes[0].payload else {
+            panic!("Expected Manual payload");
+        };
+        assert_eq!(note.event_type, ManualEventType::Note);
+    }
+
+    #[test]
+    fn ingest_requires_channels_and_token() {
+        let err = default_ingestor().ingest().unwrap_err();
+        assert!(err.to_string().contains("channels are required"));
+        let err = default_ingestor()
+            .with_channels(vec!["shipped".into()])
+            .unwrap()
+            .ingest()
+            .unwrap_err();
+        assert!(err.to_string().contains("token is required"));
+    }
+}
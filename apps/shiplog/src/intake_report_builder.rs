@@ -121,6 +121,7 @@ pub(crate) fn build_intake_report(
             artifacts: &artifacts,
         });
     }
+    let readiness_assessment = assess_readiness(&workstreams, &events, &coverage);
     let packet_quality = build_packet_quality(PacketQualityInputs {
         readiness,
         events: &events,
@@ -159,6 +160,7 @@ pub(crate) fn build_intake_report(
         repair_sources,
         repair_items,
         packet_quality,
+        readiness_assessment,
         curation_notes,
         good,
         needs_attention: attention,
@@ -592,9 +594,9 @@ fn manual_journal_add_blocked(repair_sources: &[IntakeReportRepairSource]) -> bo
 }
 
 fn setup_blocked_source_failures(failures: &[ConfiguredSourceFailure]) -> bool {
-    failures.iter().any(|failure| {
-        setup_blocked_repair_kind(classify_intake_repair_kind(&failure.name, &failure.error))
-    })
+    failures
+        .iter()
+        .any(|failure| setup_blocked_repair_kind(failure.repair_kind()))
 }
 
 fn setup_blocked_repair_kind(kind: IntakeRepairKind) -> bool {
@@ -929,12 +931,30 @@ fn build_claim_candidates(
         let title = claim_candidate_title(workstream, &supporting_events);
         let claim_id = unique_claim_id(&title, &mut seen_ids);
         let supporting_sources = claim_supporting_sources(&supporting_events);
-        let evidence_strength = claim_evidence_strength(&supporting_sources, packet_strength);
+        let strongest_receipt = workstream
+            .receipts
+            .iter()
+            .filter_map(|id| events_by_id.get(id.0.as_str()))
+            .map(|event| receipt_weight(workstream, event))
+            .max();
+        let light_receipts = strongest_receipt == Some(ReceiptWeight::Low);
+        let mut evidence_strength = claim_evidence_strength(&supporting_sources, packet_strength);
+        if evidence_strength == "strong" && light_receipts {
+            evidence_strength = "partial";
+        }
         let mut caveats = Vec::new();
         if evidence_strength == "manual_only" {
             caveats.push("Only manual evidence currently supports this candidate.".to_string());
         } else if evidence_strength == "partial" {
-            caveats.push(format!("Packet evidence strength is {packet_strength}."));
+            if light_receipts {
+                caveats.push(
+                    "Only low-weight receipts (small PRs, reviews, comments) support this candidate."
+                        .to_string(),
+                );
+            }
+            if packet_strength != "strong" {
+                caveats.push(format!("Packet evidence strength is {packet_strength}."));
+            }
         }
 
         candidates.push(IntakeReportClaimCandidate {
@@ -975,6 +995,15 @@ fn event_claim_title(event: &EventEnvelope) -> String {
         EventPayload::PullRequest(pr) => pr.title.clone(),
         EventPayload::Review(review) => format!("Review support for {}", review.pull_title),
         EventPayload::Manual(manual) => manual.title.clone(),
+        EventPayload::Issue(issue) => issue.title.clone(),
+        EventPayload::Deployment(deployment) => match &deployment.version {
+            Some(version) => format!("Deployed {version} to {}", deployment.environment),
+            None => format!("Deployed to {}", deployment.environment),
+        },
+        EventPayload::Release(release) => format!("Released {}", release.version),
+        EventPayload::Comment(comment) => format!("Discussion on {}", comment.target_title),
+        EventPayload::Incident(incident) => format!("Responded to {}", incident.title),
+        EventPayload::Document(document) => format!("Wrote {}", document.title),
     }
 }
 
@@ -2,18 +2,37 @@
 
 extern crate self as shiplog;
 
+pub mod annotate;
+pub mod assess;
+pub mod attention;
 pub mod bundle;
 pub mod cache;
 #[cfg(feature = "llm")]
 pub mod cluster_llm;
 pub mod coverage;
+pub mod dirs;
 pub mod engine;
+pub mod fixtures;
+pub mod history_db;
+pub mod http;
 pub mod ids;
 pub mod ingest;
 pub mod merge;
+pub mod notify;
 pub mod ports;
+pub mod publish;
+pub mod readiness;
 pub mod redact;
+pub mod registry;
 pub mod render;
+pub mod review_state;
+pub mod run_index;
+pub mod scan;
+pub mod schedule;
 pub mod schema;
+pub mod search;
+pub mod stats;
 pub mod team;
+pub mod template;
+pub mod update;
 pub mod workstreams;
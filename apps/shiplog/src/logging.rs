@@ -0,0 +1,111 @@
+//! Diagnostic output for the CLI.
+//!
+//! Library code reports warnings and progress through `tracing`, inside spans
+//! for each stage and source. This module installs the subscriber that writes
+//! them to stderr: `pretty` keeps the `Warning: ...` lines shiplog has always
+//! printed, and `json` emits one object per line, with the enclosing spans, for
+//! log aggregation.
+
+use crate::timings::TimingLayer;
+use clap::ValueEnum;
+use std::fmt;
+use std::io::IsTerminal;
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::Layer;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Environment variable holding a filter such as `debug` or
+/// `shiplog::ingest=trace`.
+pub(crate) const LOG_FILTER_ENV: &str = "SHIPLOG_LOG";
+
+/// How diagnostics are written to stderr.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum LogFormat {
+    /// Plain lines for a terminal; warnings only unless `SHIPLOG_LOG` asks
+    /// for more.
+    #[default]
+    Pretty,
+    /// One JSON object per line, including stage progress at `info`.
+    Json,
+}
+
+impl LogFormat {
+    fn default_filter(self) -> &'static str {
+        match self {
+            Self::Pretty => "warn",
+            Self::Json => "info",
+        }
+    }
+}
+
+/// Install the global subscriber. Later calls are ignored.
+///
+/// The format and `SHIPLOG_LOG` filter only apply to what is written;
+/// stage timing sees every span.
+pub(crate) fn init(format: LogFormat) {
+    let filter = EnvFilter::try_from_env(LOG_FILTER_ENV)
+        .unwrap_or_else(|_| EnvFilter::new(format.default_filter()));
+    let layer = tracing_subscriber::fmt::layer()
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_writer(std::io::stderr);
+    let registry = tracing_subscriber::registry().with(TimingLayer);
+    let _ = match format {
+        LogFormat::Pretty => registry
+            .with(layer.event_format(PrettyFormat).with_filter(filter))
+            .try_init(),
+        LogFormat::Json => registry
+            .with(
+                layer
+                    .json()
+                    .with_current_span(true)
+                    .with_span_list(true)
+                    .with_filter(filter),
+            )
+            .try_init(),
+    };
+}
+
+/// `Warning: message` for warnings and errors, the bare message for info,
+/// and the level plus span path for debug and trace.
+struct PrettyFormat;
+
+impl<S, N> FormatEvent<S, N> for PrettyFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        match *event.metadata().level() {
+            Level::ERROR => write!(writer, "Error: ")?,
+            Level::WARN => write!(writer, "Warning: ")?,
+            Level::INFO => {}
+            level => {
+                write!(writer, "{level} ")?;
+                if let Some(scope) = ctx.event_scope() {
+                    for span in scope.from_root() {
+                        write!(writer, "{}", span.name())?;
+                        let extensions = span.extensions();
+                        if let Some(fields) = extensions.get::<FormattedFields<N>>()
+                            && !fields.is_empty()
+                        {
+                            write!(writer, "{{{fields}}}")?;
+                        }
+                        write!(writer, ": ")?;
+                    }
+                }
+            }
+        }
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
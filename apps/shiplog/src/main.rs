@@ -6,45 +6,89 @@
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, Utc};
-use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use logging::LogFormat;
 use regex::{Regex, RegexBuilder};
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use shiplog::cache::ApiCache;
+use shiplog::coverage::{
+    CoverageRequirement, CoverageShortfall, ReportingTimezone, check_requirement,
+};
+use shiplog::dirs::{DEFAULT_OUT_DIR, api_cache_dir};
 use shiplog::engine::{ConflictResolution, Engine, WorkstreamSource};
+use shiplog::fixtures::{DateSpread, EventMix, FixtureSpec};
+use shiplog::http::{HttpBudget, HttpBudgetLimits, RetryPolicy};
 use shiplog::ids::{EventId, WorkstreamId};
+use shiplog::ingest::bitbucket::{BitbucketIngestor, PrState as BitbucketPrState};
+use shiplog::ingest::error::{IngestError, IngestErrorAction, ingest_error};
+use shiplog::ingest::gerrit::{ChangeStatus as GerritChangeStatus, GerritIngestor};
 use shiplog::ingest::git::LocalGitIngestor;
 use shiplog::ingest::github::GithubIngestor;
+use shiplog::ingest::github_export::GithubExportIngestor;
 use shiplog::ingest::gitlab::{GitlabIngestor, MrState};
 use shiplog::ingest::jira::{IssueStatus, JiraIngestor};
 use shiplog::ingest::json::JsonIngestor;
 use shiplog::ingest::linear::{IssueStatus as LinearIssueStatus, LinearIngestor};
+use shiplog::ingest::manual::brag::BragDocParser;
 use shiplog::ingest::manual::{
     ManualIngestor, create_empty_file, read_manual_events, write_manual_events,
 };
+use shiplog::ingest::slack::SlackIngestor;
+use shiplog::notify::{Notifier, RunNotification, SlackWebhook, SmtpEmail, SmtpSecurity};
 use shiplog::ports::{IngestOutput, Ingestor, Redactor, Renderer};
-use shiplog::redact::DeterministicRedactor;
+use shiplog::publish::PublishRecord;
+use shiplog::publish::google_docs::GoogleDocsPublisher;
+use shiplog::publish::notion::NotionPublisher;
+use shiplog::readiness::{ReadinessAssessment, assess_readiness};
+use shiplog::redact::{DeterministicRedactor, PiiScanner, RedactionPolicy, RedactionProfile};
 use shiplog::render::md::{
-    AppendixMode, MarkdownRenderOptions, MarkdownRenderer, SectionOrder, format_receipt_markdown,
+    AppendixMode, MarkdownRenderOptions, MarkdownRenderer, PacketTemplate, SectionOrder,
+    format_receipt_markdown,
 };
+use shiplog::review_state::ReviewCheck;
+use shiplog::scan::SecretScanner;
+use shiplog::schedule::Schedule;
 use shiplog::schema::{
     bundle::BundleProfile,
-    coverage::{CoverageManifest, TimeWindow},
+    coverage::{CoverageManifest, CoverageSlice, TimeWindow},
     event::{EventEnvelope, EventPayload},
-    event::{Link, ManualDate, ManualEventEntry, ManualEventType},
-    workstream::{Workstream, WorkstreamStats, WorkstreamsFile},
+    event::{ImpactMetric, Link, ManualDate, ManualEventEntry, ManualEventType},
+    json_schema::SchemaDocument,
+    migrate,
+    version::SchemaVersion,
+    workstream::{ReceiptWeight, Workstream, WorkstreamStats, WorkstreamsFile},
+};
+use shiplog::search::SearchIndex;
+use shiplog::stats::PrSize;
+use shiplog::update::{
+    CHECKSUMS_ASSET, ReleaseSource, Updater, compare_versions, platform_asset_name,
+    replace_executable,
+};
+use shiplog::workstreams::{
+    ClusterStrategy, HeuristicSummarizer, RepoClusterer, SummarizingClusterer,
+    WORKSTREAM_RECEIPT_RENDER_LIMIT, WorkstreamManager, receipt_weight,
 };
-use shiplog::workstreams::{RepoClusterer, WORKSTREAM_RECEIPT_RENDER_LIMIT};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::File;
-use std::io::Read;
+use std::io::{BufRead, Read};
 use std::path::{Component, Path, PathBuf};
+mod backfill;
 mod doctor;
 mod github_activity;
+mod history;
 mod intake_report_builder;
+mod logging;
+mod manual_templates;
+mod prompt;
+mod review_checklist;
+mod rubric_report;
+mod source_registry;
 mod status;
+mod timings;
 use intake_report_builder::build_intake_report;
+use manual_templates::TemplateField;
 
 const TOP_LEVEL_AFTER_HELP: &str = "\
 Start here:
@@ -374,6 +418,12 @@ Safety posture:
 struct Cli {
     #[command(subcommand)]
     cmd: Option<Command>,
+    /// Format for warnings and diagnostics on stderr.
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Pretty)]
+    log_format: LogFormat,
+    /// Print how long each stage and source took after the command.
+    #[arg(long, global = true)]
+    timings: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -429,12 +479,46 @@ enum Command {
     )]
     Intake(IntakeArgs),
 
+    /// Collect a multi-year range from shiplog.toml one quarter at a time,
+    /// then merge the quarters into one historical run.
+    Backfill(BackfillArgs),
+
     /// Validate and explain shiplog.toml without collecting data.
     Config {
         #[command(subcommand)]
         cmd: ConfigCommand,
     },
 
+    /// Check ledgers, coverage, workstreams, and manual events against the
+    /// published JSON Schemas.
+    Validate(ValidateArgs),
+
+    /// Print a shell completion script generated from the CLI definition.
+    Completions(CompletionsArgs),
+
+    /// Print the roff manual page generated from the CLI definition.
+    Man(ManArgs),
+
+    /// Replace this binary with the latest checksum-verified GitHub release.
+    SelfUpdate(SelfUpdateArgs),
+
+    /// Stay running and collect from shiplog.toml on `[defaults] schedule`.
+    Daemon(DaemonArgs),
+
+    /// Preview the collection schedule or install it as systemd or launchd
+    /// units.
+    Schedule {
+        #[command(subcommand)]
+        cmd: ScheduleCommand,
+    },
+
+    /// Write a synthetic ledger and coverage manifest for benchmarks and
+    /// demos.
+    GenerateFixture(GenerateFixtureArgs),
+
+    /// Full-text search a run's events, best matches first.
+    Search(SearchArgs),
+
     /// Inspect, list, and enable or disable sources without collecting data.
     #[command(
         about = "Inspect, list, and enable or disable sources without collecting data.",
@@ -487,6 +571,12 @@ enum Command {
         cmd: CacheCommand,
     },
 
+    /// Build share bundles for an existing run.
+    Bundle {
+        #[command(subcommand)]
+        cmd: BundleCommand,
+    },
+
     /// Discover provider identities for source configuration.
     Identify {
         #[command(subcommand)]
@@ -503,9 +593,14 @@ enum Command {
     ///
     /// This creates `workstreams.suggested.yaml` which you can rename to
     /// `workstreams.yaml` and edit to curate your narrative.
+    ///
+    /// Name a source with a subcommand, or with `--source <NAME>` to build it
+    /// from the ingestor registry.
     Collect {
         #[command(subcommand)]
-        source: CollectSource,
+        source: Option<CollectSource>,
+        #[command(flatten)]
+        registered: RegisteredSourceArgs,
         /// Output directory (a run folder will be created inside).
         #[arg(long)]
         out: Option<PathBuf>,
@@ -516,7 +611,7 @@ enum Command {
         /// If omitted, SHIPLOG_REDACT_KEY is used.
         #[arg(long)]
         redact_key: Option<String>,
-        /// Bundle profile: internal (full), manager, or public.
+        /// Bundle profile: internal (full), manager, public, or a custom profile.
         #[arg(long)]
         bundle_profile: Option<BundleProfile>,
         /// Regenerate workstreams even if workstreams.yaml exists.
@@ -536,6 +631,20 @@ enum Command {
         /// LLM API key (or set SHIPLOG_LLM_API_KEY).
         #[arg(long)]
         llm_api_key: Option<String>,
+        /// Prefill empty summaries in workstreams.suggested.yaml: heuristic, or
+        /// llm (uses the --llm-* endpoint settings). workstreams.yaml is never changed.
+        #[arg(long, value_enum)]
+        summarize: Option<SummarizeMode>,
+        /// Also build `search.index.sqlite` for `shiplog search`.
+        #[arg(long)]
+        search_index: bool,
+        /// Print the queries, windows, and estimated API requests for each
+        /// source, and whether the cache would answer them, without fetching.
+        #[arg(long)]
+        dry_run: bool,
+        /// Also write each packet as `packet.pdf`.
+        #[arg(long)]
+        pdf: bool,
     },
 
     /// Render packets from existing events and workstreams.
@@ -544,7 +653,7 @@ enum Command {
     /// without fetching new data.
     Render {
         /// Directory containing ledger.events.jsonl and workstreams.yaml
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to render (uses most recent if not specified)
         #[arg(long)]
@@ -562,7 +671,7 @@ enum Command {
         /// If omitted, SHIPLOG_REDACT_KEY is used.
         #[arg(long)]
         redact_key: Option<String>,
-        /// Bundle profile: internal (full), manager, or public.
+        /// Bundle profile: internal (full), manager, public, or a custom profile.
         #[arg(long, default_value = "internal")]
         bundle_profile: BundleProfile,
         /// Packet output mode.
@@ -578,11 +687,46 @@ enum Command {
         /// Defaults depend on output mode and selected bundle profile.
         #[arg(long, value_enum)]
         appendix: Option<RenderAppendixMode>,
+        /// Add a per-day activity heatmap after the packet summary.
+        #[arg(long)]
+        heatmap: bool,
+        /// Hide PRs smaller than this size from the main receipts section.
+        ///
+        /// Sizes bucket additions plus deletions: xs (<10 lines), s (<50),
+        /// m (<250), l (<1000), xl. PRs without line counts are always kept.
+        #[arg(long, value_enum)]
+        min_pr_size: Option<PrSizeArg>,
         /// Also write a zip next to the run folder.
         #[arg(long)]
         zip: bool,
+        /// Path to shiplog.toml for `[redaction]` PII terms.
+        #[arg(long, default_value = CONFIG_FILENAME)]
+        config: PathBuf,
+        /// Render share profiles even though manual events look like they
+        /// contain PII.
+        #[arg(long)]
+        acknowledge_pii: bool,
+        /// Write a public zip even though the secret scan has high-severity
+        /// findings in shipped files.
+        #[arg(long)]
+        allow_secret_findings: bool,
+        /// Also write each packet as `packet.pdf`.
+        #[arg(long)]
+        pdf: bool,
     },
 
+    /// Print a prompt for drafting self-review prose with your own LLM.
+    ///
+    /// Combines workstreams, receipts, claim scaffolds, and coverage limits,
+    /// redacted for the chosen profile. shiplog does not call any model.
+    Prompt(PromptArgs),
+
+    /// Compare a run against a career-ladder rubric.
+    ///
+    /// Maps workstreams and events to each competency of one rubric level and
+    /// reports which have strong receipts and which are thin or missing.
+    Assess(AssessArgs),
+
     /// Explain, verify, or render a manager- or public-safe share packet.
     #[command(
         about = "Explain, verify, or render manager/public share profiles.",
@@ -594,6 +738,12 @@ enum Command {
         cmd: ShareCommand,
     },
 
+    /// Publish a rendered packet to an external document tool.
+    Publish {
+        #[command(subcommand)]
+        cmd: PublishCommand,
+    },
+
     /// Refresh event data while preserving workstream curation.
     ///
     /// This re-fetches events from the source and updates receipts/stats,
@@ -602,7 +752,7 @@ enum Command {
         #[command(subcommand)]
         source: Source,
         /// Output directory containing existing workstreams.yaml
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Explicit run directory to refresh into (overrides auto-detection).
         #[arg(long)]
@@ -614,7 +764,7 @@ enum Command {
         /// If omitted, SHIPLOG_REDACT_KEY is used.
         #[arg(long)]
         redact_key: Option<String>,
-        /// Bundle profile: internal (full), manager, or public.
+        /// Bundle profile: internal (full), manager, public, or a custom profile.
         #[arg(long, default_value = "internal")]
         bundle_profile: BundleProfile,
     },
@@ -631,6 +781,18 @@ enum Command {
         cmd: RunsCommand,
     },
 
+    /// Find, compare, and open past runs from the run index.
+    History {
+        #[command(subcommand)]
+        cmd: HistoryCommand,
+    },
+
+    /// Compare coverage manifests across runs.
+    Coverage {
+        #[command(subcommand)]
+        cmd: CoverageCommand,
+    },
+
     /// Inspect a run and suggest review-prep next steps.
     Review {
         #[command(subcommand)]
@@ -671,7 +833,7 @@ enum Command {
         #[arg(long = "input", required = true)]
         inputs: Vec<PathBuf>,
         /// Output directory (a merged run folder will be created inside).
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Duplicate event conflict policy.
         #[arg(long, value_enum, default_value = "prefer-most-recent")]
@@ -689,7 +851,7 @@ enum Command {
         /// If omitted, SHIPLOG_REDACT_KEY is used.
         #[arg(long)]
         redact_key: Option<String>,
-        /// Bundle profile: internal (full), manager, or public.
+        /// Bundle profile: internal (full), manager, public, or a custom profile.
         #[arg(long, default_value = "internal")]
         bundle_profile: BundleProfile,
         /// Regenerate workstreams even if workstreams.yaml exists.
@@ -708,7 +870,7 @@ enum Command {
         #[arg(long)]
         dir: PathBuf,
         /// Output directory (a run folder will be created inside).
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// User label for rendering.
         #[arg(long, default_value = "user")]
@@ -720,7 +882,7 @@ enum Command {
         /// If omitted, SHIPLOG_REDACT_KEY is used.
         #[arg(long)]
         redact_key: Option<String>,
-        /// Bundle profile: internal (full), manager, or public.
+        /// Bundle profile: internal (full), manager, public, or a custom profile.
         #[arg(long, default_value = "internal")]
         bundle_profile: BundleProfile,
         /// Also write a zip next to the run folder.
@@ -741,6 +903,10 @@ enum Command {
         /// LLM API key (or set SHIPLOG_LLM_API_KEY).
         #[arg(long)]
         llm_api_key: Option<String>,
+        /// Prefill empty summaries in workstreams.suggested.yaml: heuristic, or
+        /// llm (uses the --llm-* endpoint settings). workstreams.yaml is never changed.
+        #[arg(long, value_enum)]
+        summarize: Option<SummarizeMode>,
     },
 
     /// Run the full pipeline (collect + render) in one command.
@@ -751,7 +917,7 @@ enum Command {
         #[command(subcommand)]
         source: Source,
         /// Output directory (a run folder will be created inside).
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Also write a zip next to the run folder.
         #[arg(long)]
@@ -760,7 +926,7 @@ enum Command {
         /// If omitted, SHIPLOG_REDACT_KEY is used.
         #[arg(long)]
         redact_key: Option<String>,
-        /// Bundle profile: internal (full), manager, or public.
+        /// Bundle profile: internal (full), manager, public, or a custom profile.
         #[arg(long, default_value = "internal")]
         bundle_profile: BundleProfile,
         /// Use LLM-assisted workstream clustering instead of repo-based.
@@ -775,13 +941,17 @@ enum Command {
         /// LLM API key (or set SHIPLOG_LLM_API_KEY).
         #[arg(long)]
         llm_api_key: Option<String>,
+        /// Prefill empty summaries in workstreams.suggested.yaml: heuristic, or
+        /// llm (uses the --llm-* endpoint settings). workstreams.yaml is never changed.
+        #[arg(long, value_enum)]
+        summarize: Option<SummarizeMode>,
     },
 }
 
 #[derive(Args, Debug)]
 struct ReviewOptions {
     /// Output directory containing run folders.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
     /// Path to shiplog.toml when resolving --period.
     #[arg(long, default_value = CONFIG_FILENAME)]
@@ -805,7 +975,7 @@ enum ReviewCommand {
     /// Inspect the latest weekly evidence and suggest next steps.
     Weekly {
         /// Output directory containing run folders.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to review (uses most recent if not specified).
         #[arg(long)]
@@ -821,7 +991,7 @@ enum ReviewCommand {
     /// Print the highest-value curation actions for a run.
     Fixups {
         /// Output directory containing run folders.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to review (uses most recent if not specified).
         #[arg(long)]
@@ -836,6 +1006,83 @@ enum ReviewCommand {
         #[arg(long)]
         journal_template: bool,
     },
+
+    /// Show the run's review checklist and share approvals.
+    Status {
+        /// Output directory containing run folders.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to show (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Show the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+    },
+
+    /// Mark claim scaffolds filled or receipts verified for a workstream.
+    ///
+    /// Marks are kept in the run's review.state.json and summarized at the
+    /// top of the internal packet.
+    Mark {
+        /// Checklist item to mark.
+        #[arg(value_enum)]
+        item: ReviewCheckArg,
+        /// Workstream title or ID.
+        #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+        workstream: Option<String>,
+        /// Mark every workstream in the run.
+        #[arg(long)]
+        all: bool,
+        /// Clear the mark instead of setting it.
+        #[arg(long)]
+        undo: bool,
+        /// Output directory containing run folders.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to mark (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Mark the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+    },
+
+    /// Record that a manager or public packet is approved for sharing.
+    Approve {
+        /// Share profile to approve: manager or public.
+        profile: BundleProfile,
+        /// Withdraw an earlier approval.
+        #[arg(long)]
+        revoke: bool,
+        /// Output directory containing run folders.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to approve (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Approve the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+    },
+}
+
+/// Review checklist items that `review mark` can set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ReviewCheckArg {
+    /// The workstream's claim scaffold is written up.
+    Claims,
+    /// The workstream's receipts were checked against their sources.
+    Receipts,
+}
+
+impl From<ReviewCheckArg> for ReviewCheck {
+    fn from(arg: ReviewCheckArg) -> Self {
+        match arg {
+            ReviewCheckArg::Claims => Self::ClaimsFilled,
+            ReviewCheckArg::Receipts => Self::ReceiptsVerified,
+        }
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -1034,13 +1281,248 @@ struct SourcesToggleArgs {
     sources: Vec<InitSource>,
 }
 
+#[derive(Args, Debug)]
+struct ValidateArgs {
+    /// Files or run directories to check. File formats are inferred from
+    /// their names unless --kind is given.
+    paths: Vec<PathBuf>,
+    /// Check every file as this format.
+    #[arg(long, value_enum)]
+    kind: Option<SchemaKindArg>,
+    /// Print the JSON Schema for a format instead of validating.
+    #[arg(long, value_enum, conflicts_with_all = ["paths", "kind"])]
+    print_schema: Option<SchemaKindArg>,
+}
+
+#[derive(Args, Debug)]
+struct CompletionsArgs {
+    /// Shell to generate completions for.
+    #[arg(value_enum)]
+    shell: clap_complete::Shell,
+    /// Write the script into this directory under the shell's conventional
+    /// file name instead of printing it.
+    #[arg(long)]
+    dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct ManArgs {
+    /// Write `shiplog.1` and one page per subcommand into this directory
+    /// instead of printing the top-level page.
+    #[arg(long)]
+    dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum PublishCommand {
+    /// Create or update a Notion page holding a run's packet.
+    Notion(PublishNotionArgs),
+    /// Create or update a Google Doc holding a run's packet.
+    GoogleDocs(PublishGoogleDocsArgs),
+}
+
+#[derive(Args, Debug)]
+struct PublishNotionArgs {
+    #[command(flatten)]
+    packet: PublishPacketArgs,
+    /// Notion page to create the packet page under. Overrides
+    /// `publish.notion.parent_page_id`.
+    #[arg(long)]
+    parent_page: Option<String>,
+}
+
+#[derive(Args, Debug)]
+struct PublishGoogleDocsArgs {
+    #[command(flatten)]
+    packet: PublishPacketArgs,
+    /// Drive folder to create the doc in. Overrides
+    /// `publish.google_docs.folder_id`; without either the doc goes to the
+    /// token owner's My Drive.
+    #[arg(long)]
+    folder: Option<String>,
+}
+
+/// Which run and packet a publisher pushes.
+#[derive(Args, Debug)]
+struct PublishPacketArgs {
+    /// Output directory containing run folders.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    out: PathBuf,
+    /// Run ID to publish (uses most recent if not specified).
+    #[arg(long)]
+    run: Option<String>,
+    /// Publish the most recent run explicitly.
+    #[arg(long)]
+    latest: bool,
+    /// Packet profile to publish. Manager and public packets must be rendered
+    /// first with `shiplog share`.
+    #[arg(long, default_value = "internal")]
+    bundle_profile: BundleProfile,
+    /// Path to shiplog.toml.
+    #[arg(long, default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+}
+
+#[derive(Args, Debug)]
+struct SelfUpdateArgs {
+    /// Report whether a newer release exists without downloading it.
+    #[arg(long)]
+    check: bool,
+}
+
+#[derive(Args, Debug)]
+struct GenerateFixtureArgs {
+    /// Directory to write `ledger.events.jsonl` and `coverage.manifest.json`
+    /// into.
+    #[arg(long, default_value = "fixture")]
+    out: PathBuf,
+    /// Number of events to generate.
+    #[arg(long, default_value_t = 200)]
+    events: usize,
+    /// Number of repositories the events spread over.
+    #[arg(long, default_value_t = 6)]
+    repos: usize,
+    /// Start date (inclusive), YYYY-MM-DD.
+    #[arg(long, default_value = "2025-01-01")]
+    since: NaiveDate,
+    /// End date (exclusive), YYYY-MM-DD.
+    #[arg(long, default_value = "2026-01-01")]
+    until: NaiveDate,
+    /// Relative weight of each event kind, as `pr=6,review=3,issue=2,manual=1`.
+    #[arg(long, default_value = "pr=6,review=3,issue=2,manual=1")]
+    mix: EventMix,
+    /// How event dates spread: `uniform`, `weekdays`, or `bursty`.
+    #[arg(long, default_value = "weekdays")]
+    dates: DateSpread,
+    /// Number of busy months whose coverage slice is marked incomplete.
+    #[arg(long, default_value_t = 0)]
+    incomplete_months: usize,
+    /// Seed; the same options and seed always produce the same events.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Login the events are attributed to.
+    #[arg(long, default_value = "demo-dev")]
+    user: String,
+}
+
+#[derive(Args, Debug)]
+struct SearchArgs {
+    /// Words to search for; every word must match. A trailing `*` matches a
+    /// prefix.
+    #[arg(required = true)]
+    terms: Vec<String>,
+    /// Output directory containing run folders.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    out: PathBuf,
+    /// Run folder name to search (default: most recent).
+    #[arg(long)]
+    run: Option<String>,
+    /// Search the most recent run explicitly.
+    #[arg(long)]
+    latest: bool,
+    /// Maximum number of matches to print.
+    #[arg(long, default_value_t = 20)]
+    limit: usize,
+    /// Print matches as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct DaemonArgs {
+    /// Path to shiplog.toml.
+    #[arg(long, default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+}
+
+#[derive(Subcommand, Debug)]
+enum ScheduleCommand {
+    /// Print the next times `[defaults] schedule` fires.
+    Next {
+        /// Path to shiplog.toml.
+        #[arg(long, default_value = CONFIG_FILENAME)]
+        config: PathBuf,
+        /// Number of upcoming runs to list.
+        #[arg(long, default_value_t = 5)]
+        count: usize,
+    },
+    /// Write systemd timer or launchd agent units that run `collect multi`
+    /// on `[defaults] schedule`.
+    Install(ScheduleInstallArgs),
+}
+
+#[derive(Args, Debug)]
+struct ScheduleInstallArgs {
+    /// Path to shiplog.toml.
+    #[arg(long, default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+    /// Unit format. Defaults to launchd on macOS and systemd elsewhere.
+    #[arg(long, value_enum)]
+    format: Option<ScheduleFormat>,
+    /// Directory to write units to. Defaults to the user unit directory for
+    /// the format.
+    #[arg(long, conflicts_with = "print")]
+    dir: Option<PathBuf>,
+    /// Print the units instead of writing them.
+    #[arg(long)]
+    print: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+enum ScheduleFormat {
+    /// systemd user `.service` and `.timer` units.
+    Systemd,
+    /// launchd user agent plist.
+    Launchd,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum PrSizeArg {
+    Xs,
+    S,
+    M,
+    L,
+    Xl,
+}
+
+impl From<PrSizeArg> for PrSize {
+    fn from(size: PrSizeArg) -> Self {
+        match size {
+            PrSizeArg::Xs => Self::XS,
+            PrSizeArg::S => Self::S,
+            PrSizeArg::M => Self::M,
+            PrSizeArg::L => Self::L,
+            PrSizeArg::Xl => Self::XL,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum SchemaKindArg {
+    EventEnvelope,
+    CoverageManifest,
+    Workstreams,
+    ManualEvents,
+}
+
+impl From<SchemaKindArg> for SchemaDocument {
+    fn from(kind: SchemaKindArg) -> Self {
+        match kind {
+            SchemaKindArg::EventEnvelope => Self::EventEnvelope,
+            SchemaKindArg::CoverageManifest => Self::CoverageManifest,
+            SchemaKindArg::Workstreams => Self::WorkstreamsFile,
+            SchemaKindArg::ManualEvents => Self::ManualEventsFile,
+        }
+    }
+}
+
 #[derive(Args, Debug)]
 struct StatusArgs {
     /// Path to shiplog.toml.
     #[arg(long, default_value = CONFIG_FILENAME)]
     config: PathBuf,
     /// Output directory containing run folders.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
     /// Inspect the most recent run explicitly.
     #[arg(long)]
@@ -1060,7 +1542,7 @@ struct NextArgs {
     #[arg(long, default_value = CONFIG_FILENAME)]
     config: PathBuf,
     /// Output directory containing run folders.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
     /// Print the selected action and receipt references as JSON.
     #[arg(long)]
@@ -1086,6 +1568,55 @@ struct UpdateArgs {
     no_open: bool,
 }
 
+#[derive(Args, Debug)]
+struct PromptArgs {
+    /// Output directory containing run folders.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    out: PathBuf,
+    /// Run ID to read (uses most recent if not specified).
+    #[arg(long)]
+    run: Option<String>,
+    /// Read the most recent run explicitly.
+    #[arg(long)]
+    latest: bool,
+    /// Bundle profile whose redaction applies: internal, manager, or public.
+    #[arg(long, default_value = "internal")]
+    bundle_profile: BundleProfile,
+    /// Redaction key. Required for manager/public profiles.
+    /// If omitted, SHIPLOG_REDACT_KEY is used.
+    #[arg(long)]
+    redact_key: Option<String>,
+    /// Maximum receipts per workstream. Defaults depend on the profile.
+    #[arg(long)]
+    receipt_limit: Option<usize>,
+    /// Write the prompt to this file instead of stdout.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct AssessArgs {
+    /// Rubric YAML listing levels and their competencies.
+    #[arg(long, default_value = "rubric.yaml")]
+    rubric: PathBuf,
+    /// Rubric level to compare against. Required when the rubric has
+    /// several levels.
+    #[arg(long)]
+    level: Option<String>,
+    /// Output directory containing run folders.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    out: PathBuf,
+    /// Run ID to read (uses most recent if not specified).
+    #[arg(long)]
+    run: Option<String>,
+    /// Read the most recent run explicitly.
+    #[arg(long)]
+    latest: bool,
+    /// Write the gap analysis to this file instead of stdout.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
 #[derive(Args, Debug)]
 struct AddArgs {
     /// Factual title for the work.
@@ -1096,9 +1627,14 @@ struct AddArgs {
     /// Single event date, in YYYY-MM-DD format. Defaults to today.
     #[arg(long)]
     date: Option<NaiveDate>,
-    /// Manual event type.
-    #[arg(long = "type", value_enum, default_value = "note")]
-    event_type: JournalEventType,
+    /// Manual event template (incident, design-review, mentoring, or one
+    /// from `[templates.<name>]`). Fills its type, tags, and workstream, and
+    /// requires its fields.
+    #[arg(long)]
+    template: Option<String>,
+    /// Manual event type. Defaults to the template's type, or note.
+    #[arg(long = "type", value_enum)]
+    event_type: Option<JournalEventType>,
     /// Optional factual context.
     #[arg(long)]
     description: Option<String>,
@@ -1114,6 +1650,10 @@ struct AddArgs {
     /// Receipt link as LABEL=URL. Repeat for multiple receipts.
     #[arg(long = "receipt", value_name = "LABEL=URL")]
     receipts: Vec<String>,
+    /// Measured outcome as NAME=VALUE or NAME=BASELINE->VALUE. Repeat for
+    /// multiple metrics.
+    #[arg(long = "metric", value_name = "NAME=VALUE")]
+    metrics: Vec<String>,
     /// Print the entry that would be added without writing.
     #[arg(long)]
     dry_run: bool,
@@ -1169,6 +1709,18 @@ enum CacheCommand {
     Clean(CacheCleanArgs),
 }
 
+#[derive(Subcommand, Debug)]
+enum BundleCommand {
+    /// Write an incremental bundle with only files changed since a previous manifest.
+    Delta(BundleDeltaArgs),
+
+    /// Re-check a bundle's checksums, profile scope, and (optionally) privacy leaks.
+    Verify(BundleVerifyArgs),
+
+    /// Report events, workstreams, and coverage that changed between two runs.
+    Diff(BundleDiffArgs),
+}
+
 #[derive(Subcommand, Debug)]
 enum IdentifyCommand {
     /// Show the authenticated Jira account ID for use with `--user`.
@@ -1205,6 +1757,10 @@ enum JournalCommand {
     List(JournalListArgs),
     /// Edit one manual evidence entry in manual_events.yaml.
     Edit(JournalEditArgs),
+    /// Import bullets from an existing brag-doc markdown file.
+    Import(JournalImportArgs),
+    /// Append commented template entries to manual_events.yaml.
+    Scaffold(JournalScaffoldArgs),
 }
 
 #[derive(Args, Debug)]
@@ -1257,6 +1813,11 @@ struct JournalAddArgs {
     /// Optional outcome or impact note.
     #[arg(long)]
     impact: Option<String>,
+    /// Measured outcome as NAME=VALUE or NAME=BASELINE->VALUE, with an
+    /// optional unit suffix (e.g. "p95 latency=420ms->180ms"). Repeat for
+    /// multiple metrics.
+    #[arg(long = "metric", value_name = "NAME=VALUE")]
+    metrics: Vec<String>,
     /// Print the entry that would be added without writing.
     #[arg(long)]
     dry_run: bool,
@@ -1264,6 +1825,25 @@ struct JournalAddArgs {
     quick: bool,
 }
 
+#[derive(Args, Debug)]
+struct JournalScaffoldArgs {
+    /// Template to scaffold. Repeat for several; defaults to every template.
+    #[arg(long = "template")]
+    templates: Vec<String>,
+    /// Path to shiplog.toml for `[templates.<name>]` and the manual journal path.
+    #[arg(long, default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+    /// Manual events YAML file to append to. Defaults to the configured journal.
+    #[arg(long)]
+    events: Option<PathBuf>,
+    /// Date to prefill, in YYYY-MM-DD format. Defaults to today.
+    #[arg(long)]
+    date: Option<NaiveDate>,
+    /// Print the scaffold without writing.
+    #[arg(long)]
+    dry_run: bool,
+}
+
 #[derive(Args, Debug)]
 struct JournalListArgs {
     /// Manual events YAML file to inspect.
@@ -1320,7 +1900,44 @@ struct JournalEditArgs {
     dry_run: bool,
 }
 
-#[derive(Clone, Copy, Debug, ValueEnum)]
+#[derive(Args, Debug)]
+struct JournalImportArgs {
+    /// Brag-doc markdown file to import.
+    file: PathBuf,
+    /// Manual events YAML file to create or append to.
+    #[arg(long, default_value = MANUAL_EVENTS_FILENAME)]
+    events: PathBuf,
+    /// Year for dates without one that sit under no year heading.
+    #[arg(long)]
+    year: Option<i32>,
+    /// Extra date regex with named `month` and optional `year`/`day` groups,
+    /// tried before the built-in patterns. Repeat for multiple patterns.
+    #[arg(long = "date-pattern", value_name = "REGEX")]
+    date_patterns: Vec<String>,
+    /// Manual event type for imported entries.
+    #[arg(long = "type", value_enum, default_value = "note")]
+    event_type: JournalEventType,
+    /// Tag to attach in addition to `brag-doc`. Repeat for multiple tags.
+    #[arg(long = "tag")]
+    tags: Vec<String>,
+    /// Use the nearest undated heading (e.g. a project name) as the workstream.
+    #[arg(long)]
+    headings_as_workstreams: bool,
+    /// Print what would be imported without writing.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+/// How `--summarize` drafts summaries for suggested workstreams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum SummarizeMode {
+    /// Count events by kind and quote a few titles, without network calls.
+    Heuristic,
+    /// Ask the LLM endpoint, falling back to the heuristic summary.
+    Llm,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
 enum JournalEventType {
     Note,
     Incident,
@@ -1350,9 +1967,9 @@ impl From<JournalEventType> for ManualEventType {
 #[derive(Args, Debug)]
 struct CacheArgs {
     /// Output directory whose `.cache` directory should be inspected.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
-    /// Cache directory to inspect instead of `<out>/.cache`.
+    /// Cache directory to inspect instead of `SHIPLOG_CACHE_DIR` or `<out>/.cache`.
     #[arg(long)]
     cache_dir: Option<PathBuf>,
     /// Limit to one or more source caches.
@@ -1363,9 +1980,9 @@ struct CacheArgs {
 #[derive(Args, Debug)]
 struct CacheCleanArgs {
     /// Output directory whose `.cache` directory should be cleaned.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
-    /// Cache directory to clean instead of `<out>/.cache`.
+    /// Cache directory to clean instead of `SHIPLOG_CACHE_DIR` or `<out>/.cache`.
     #[arg(long)]
     cache_dir: Option<PathBuf>,
     /// Limit to one or more source caches.
@@ -1385,17 +2002,91 @@ struct CacheCleanArgs {
     yes: bool,
 }
 
+#[derive(Args, Debug)]
+struct BundleDeltaArgs {
+    /// Output directory containing run folders.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    out: PathBuf,
+    /// Run ID to bundle (uses most recent if not specified).
+    #[arg(long)]
+    run: Option<String>,
+    /// Bundle the most recent run explicitly.
+    #[arg(long)]
+    latest: bool,
+    /// Previous bundle.manifest.json, or the run directory that contains it.
+    #[arg(long)]
+    base: PathBuf,
+    /// Archive format for the delta bundle.
+    #[arg(long, value_enum, default_value_t = BundleFormatArg::Zip)]
+    format: BundleFormatArg,
+}
+
+#[derive(Args, Debug)]
+struct BundleDiffArgs {
+    /// Earlier run: a run ID under --out, "latest", or a run directory.
+    from: String,
+    /// Later run: a run ID under --out, "latest", or a run directory.
+    to: String,
+    /// Output directory containing shiplog runs.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    out: PathBuf,
+    /// Print the comparison as JSON instead of Markdown.
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct BundleVerifyArgs {
+    /// Bundle archive (.zip, .tar.gz) or run directory to verify.
+    path: PathBuf,
+    /// bundle.manifest.json to verify against (defaults to the bundled or sibling run manifest).
+    #[arg(long)]
+    manifest: Option<PathBuf>,
+    /// Also scan manager/public packets for original titles, names, and URLs from the source run.
+    #[arg(long)]
+    leak_check: bool,
+    /// Require a valid manifest signature by this hex-encoded ed25519 public key.
+    #[arg(long, value_name = "PUBLIC_KEY")]
+    signer: Option<String>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum BundleFormatArg {
+    Zip,
+    TarGz,
+}
+
+impl From<BundleFormatArg> for shiplog::bundle::BundleFormat {
+    fn from(value: BundleFormatArg) -> Self {
+        match value {
+            BundleFormatArg::Zip => Self::Zip,
+            BundleFormatArg::TarGz => Self::TarGz,
+        }
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum CacheSource {
     Github,
     Gitlab,
     Jira,
     Linear,
+    Bitbucket,
+    Gerrit,
+    Slack,
 }
 
 impl CacheSource {
-    fn all() -> [Self; 4] {
-        [Self::Github, Self::Gitlab, Self::Jira, Self::Linear]
+    fn all() -> [Self; 7] {
+        [
+            Self::Github,
+            Self::Gitlab,
+            Self::Jira,
+            Self::Linear,
+            Self::Bitbucket,
+            Self::Gerrit,
+            Self::Slack,
+        ]
     }
 
     fn as_str(self) -> &'static str {
@@ -1404,6 +2095,9 @@ impl CacheSource {
             Self::Gitlab => "gitlab",
             Self::Jira => "jira",
             Self::Linear => "linear",
+            Self::Bitbucket => "bitbucket",
+            Self::Gerrit => "gerrit",
+            Self::Slack => "slack",
         }
     }
 
@@ -1413,6 +2107,9 @@ impl CacheSource {
             Self::Gitlab => "gitlab-api-cache.db",
             Self::Jira => "jira-api-cache.db",
             Self::Linear => "linear-api-cache.db",
+            Self::Bitbucket => "bitbucket-api-cache.db",
+            Self::Gerrit => "gerrit-api-cache.db",
+            Self::Slack => "slack-api-cache.db",
         }
     }
 }
@@ -1482,19 +2179,107 @@ impl MergeConflict {
     }
 }
 
+#[derive(Subcommand, Debug)]
+enum CoverageCommand {
+    /// Report window, slice, and source changes between two runs' coverage.
+    Diff(CoverageDiffArgs),
+}
+
+#[derive(Args, Debug)]
+struct CoverageDiffArgs {
+    /// Earlier run: a run ID under --out, "latest", a run directory, or a coverage.manifest.json.
+    from: String,
+    /// Later run: a run ID under --out, "latest", a run directory, or a coverage.manifest.json.
+    to: String,
+    /// Output directory containing shiplog runs.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    out: PathBuf,
+    /// Print the comparison as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum HistoryCommand {
+    /// List indexed runs, most recently updated first.
+    List {
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Only runs last written in this month (YYYY-MM).
+        #[arg(long)]
+        month: Option<String>,
+        /// Only runs with a curated workstreams.yaml.
+        #[arg(long)]
+        curated: bool,
+        /// Re-read every run directory instead of trusting runs.index.json.
+        #[arg(long)]
+        rebuild: bool,
+    },
+
+    /// Compare two past runs.
+    Compare {
+        /// Earlier run: "latest", a run ID, or a YYYY-MM month.
+        from: String,
+        /// Later run: "latest", a run ID, or a YYYY-MM month.
+        to: String,
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+    },
+
+    /// Open a past run's packet.
+    Open {
+        /// Run to open: "latest", a run ID, or a YYYY-MM month.
+        #[arg(default_value = "latest")]
+        run: String,
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Print the packet path instead of opening it.
+        #[arg(long)]
+        print_path: bool,
+    },
+
+    /// List everything shipped in a date range, whichever runs collected it.
+    Shipped {
+        /// Start date (inclusive), YYYY-MM-DD.
+        #[arg(long)]
+        since: NaiveDate,
+        /// End date (exclusive), YYYY-MM-DD.
+        #[arg(long)]
+        until: NaiveDate,
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Print events as JSON, each with the run it came from.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show the most recently updated run whose window covers a date.
+    Covering {
+        /// Date to look up, YYYY-MM-DD.
+        date: NaiveDate,
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum RunsCommand {
     /// List discovered runs under an output directory.
     List {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
     },
 
     /// Show details for one run.
     Show {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to inspect (uses most recent if not specified).
         #[arg(long)]
@@ -1507,7 +2292,7 @@ enum RunsCommand {
     /// Compare two existing runs.
     Compare {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Path to shiplog.toml when resolving period selectors.
         #[arg(long, default_value = CONFIG_FILENAME)]
@@ -1534,7 +2319,7 @@ enum RunsCommand {
     )]
     Diff {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Compare the latest two runs.
         #[arg(long)]
@@ -1546,6 +2331,22 @@ enum RunsCommand {
         #[arg(long)]
         to: Option<String>,
     },
+
+    /// Upgrade a run's ledger, coverage, and workstreams to the current schema.
+    Migrate {
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to migrate (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Migrate the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+        /// Report the planned changes without writing.
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -1553,7 +2354,7 @@ enum OpenCommand {
     /// Open the rendered packet for a run.
     Packet {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to open (uses most recent if not specified).
         #[arg(long)]
@@ -1569,7 +2370,7 @@ enum OpenCommand {
     /// Open the effective workstreams file for a run.
     Workstreams {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to open (uses most recent if not specified).
         #[arg(long)]
@@ -1586,7 +2387,7 @@ enum OpenCommand {
     #[command(alias = "report")]
     IntakeReport {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to open (uses most recent if not specified).
         #[arg(long)]
@@ -1602,7 +2403,7 @@ enum OpenCommand {
     /// Open the run output directory.
     Out {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to open (uses most recent if not specified).
         #[arg(long)]
@@ -1621,7 +2422,7 @@ enum ReportCommand {
     /// Validate intake.report.json and its referenced run artifacts.
     Validate {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to validate (uses most recent if not specified).
         #[arg(long)]
@@ -1636,7 +2437,7 @@ enum ReportCommand {
     /// Summarize intake.report.json without rewriting run artifacts.
     Summarize {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to summarize (uses most recent if not specified).
         #[arg(long)]
@@ -1651,7 +2452,7 @@ enum ReportCommand {
     /// Export a compact machine-readable pack for future UI or agent consumers.
     ExportAgentPack {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to export (uses most recent if not specified).
         #[arg(long)]
@@ -1673,7 +2474,7 @@ enum RepairCommand {
     /// Print a receipt-derived repair queue from the latest intake report.
     Plan {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to read (uses most recent if not specified).
         #[arg(long)]
@@ -1685,7 +2486,7 @@ enum RepairCommand {
     /// Compare repair item state across the latest two compatible reports.
     Diff {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Compare the most recent compatible reports explicitly.
         #[arg(long)]
@@ -1698,7 +2499,7 @@ enum WorkstreamsCommand {
     /// List workstreams and their event/receipt counts.
     List {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to inspect (uses most recent if not specified).
         #[arg(long)]
@@ -1711,7 +2512,7 @@ enum WorkstreamsCommand {
     /// Validate the effective workstreams file against the run ledger.
     Validate {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to validate (uses most recent if not specified).
         #[arg(long)]
@@ -1724,7 +2525,7 @@ enum WorkstreamsCommand {
     /// Rename a workstream in the curated workstreams file.
     Rename {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to edit (uses most recent if not specified).
         #[arg(long)]
@@ -1743,7 +2544,7 @@ enum WorkstreamsCommand {
     /// Move or assign one event to a workstream.
     Move {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to edit (uses most recent if not specified).
         #[arg(long)]
@@ -1762,7 +2563,7 @@ enum WorkstreamsCommand {
     /// List curated receipt anchors for a workstream.
     Receipts {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to inspect (uses most recent if not specified).
         #[arg(long)]
@@ -1784,7 +2585,7 @@ enum WorkstreamsCommand {
     /// Create an empty curated workstream.
     Create {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to edit (uses most recent if not specified).
         #[arg(long)]
@@ -1800,7 +2601,7 @@ enum WorkstreamsCommand {
     /// Delete a workstream, optionally moving its events to another workstream.
     Delete {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to edit (uses most recent if not specified).
         #[arg(long)]
@@ -1822,7 +2623,7 @@ enum WorkstreamsCommand {
     /// Split matching events out of one workstream into another.
     Split {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to edit (uses most recent if not specified).
         #[arg(long)]
@@ -1843,6 +2644,97 @@ enum WorkstreamsCommand {
         #[arg(long)]
         create: bool,
     },
+
+    /// Merge, split, rename, or re-tag workstreams in the curated file.
+    Edit {
+        #[command(subcommand)]
+        cmd: WorkstreamEditCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum WorkstreamEditCommand {
+    /// Fold one workstream into another and remove it.
+    Merge {
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to edit (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Edit the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+        /// Workstream title or ID to merge away.
+        #[arg(long)]
+        from: String,
+        /// Workstream title or ID that keeps the merged events.
+        #[arg(long)]
+        into: String,
+    },
+
+    /// Move selected events out of a workstream into a new one.
+    Split {
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to edit (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Edit the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+        /// Source workstream title or ID.
+        #[arg(long)]
+        from: String,
+        /// Event ID to move; repeat for several events.
+        #[arg(long, required = true)]
+        event: Vec<String>,
+        /// Title of the new workstream.
+        #[arg(long)]
+        title: String,
+    },
+
+    /// Retitle a workstream, keeping its ID.
+    Rename {
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to edit (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Edit the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+        /// Workstream title or ID.
+        #[arg(long)]
+        workstream: String,
+        /// New workstream title.
+        #[arg(long)]
+        title: String,
+    },
+
+    /// Add or remove workstream tags.
+    Retag {
+        /// Output directory containing shiplog runs.
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
+        out: PathBuf,
+        /// Run ID to edit (uses most recent if not specified).
+        #[arg(long)]
+        run: Option<String>,
+        /// Edit the most recent run explicitly.
+        #[arg(long)]
+        latest: bool,
+        /// Workstream title or ID.
+        #[arg(long)]
+        workstream: String,
+        /// Tag to add; repeat for several tags.
+        #[arg(long)]
+        add: Vec<String>,
+        /// Tag to remove; repeat for several tags.
+        #[arg(long)]
+        remove: Vec<String>,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -1850,7 +2742,7 @@ enum WorkstreamReceiptCommand {
     /// Add an assigned event as a receipt anchor for one workstream.
     Add {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to edit (uses most recent if not specified).
         #[arg(long)]
@@ -1869,7 +2761,7 @@ enum WorkstreamReceiptCommand {
     /// Remove a receipt anchor from one workstream.
     Remove {
         /// Output directory containing shiplog runs.
-        #[arg(long, default_value = "./out")]
+        #[arg(long, default_value = DEFAULT_OUT_DIR)]
         out: PathBuf,
         /// Run ID to edit (uses most recent if not specified).
         #[arg(long)]
@@ -1947,7 +2839,7 @@ enum ShareCommand {
 #[derive(Args, Debug)]
 struct ShareOptions {
     /// Output directory containing run folders.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
     /// Run ID to share (uses most recent if not specified).
     #[arg(long)]
@@ -1961,6 +2853,19 @@ struct ShareOptions {
     /// Also write a zip next to the run folder.
     #[arg(long)]
     zip: bool,
+    /// Path to shiplog.toml for `[redaction]` PII terms.
+    #[arg(long, default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+    /// Share even though manual events look like they contain PII.
+    #[arg(long)]
+    acknowledge_pii: bool,
+    /// Write a public zip even though the secret scan has high-severity
+    /// findings in shipped files.
+    #[arg(long)]
+    allow_secret_findings: bool,
+    /// Also write the shared packet as `packet.pdf`.
+    #[arg(long)]
+    pdf: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -1984,7 +2889,7 @@ enum ShareExplainCommand {
 #[derive(Args, Debug)]
 struct ShareExplainOptions {
     /// Output directory containing run folders.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
     /// Run ID to explain (uses most recent if not specified).
     #[arg(long)]
@@ -2020,7 +2925,7 @@ enum ShareVerifyCommand {
 #[derive(Args, Debug)]
 struct ShareVerifyOptions {
     /// Output directory containing run folders.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
     /// Run ID to verify (uses most recent if not specified).
     #[arg(long)]
@@ -2039,7 +2944,7 @@ struct ShareVerifyOptions {
 #[derive(Args, Debug)]
 struct ShareManifestVerifyOptions {
     /// Output directory containing run folders.
-    #[arg(long, default_value = "./out")]
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
     out: PathBuf,
     /// Run ID to verify (uses most recent if not specified).
     #[arg(long)]
@@ -2069,6 +2974,33 @@ impl From<ShareManifestProfile> for BundleProfile {
     }
 }
 
+#[derive(Args, Debug, Clone, Default)]
+struct RegisteredSourceArgs {
+    /// Registered source to collect instead of a subcommand: github, gitlab,
+    /// jira, linear, bitbucket, gerrit, slack, git, github-export, json, or
+    /// manual.
+    #[arg(long = "source", value_name = "NAME")]
+    name: Option<String>,
+    /// User, author, or user ID for `--source`, depending on the source.
+    #[arg(long, requires = "name")]
+    user: Option<String>,
+    /// Source-specific setting for `--source`. Repeat for several.
+    #[arg(long = "source-option", value_name = "KEY=VALUE", requires = "name")]
+    options: Vec<String>,
+    /// Override the API cache directory for `--source` (defaults to `SHIPLOG_CACHE_DIR` or `<out>/.cache`).
+    #[arg(long, requires = "name")]
+    cache_dir: Option<PathBuf>,
+    /// Disable API caching for `--source`.
+    #[arg(long, requires = "name")]
+    no_cache: bool,
+    /// Fetch only what the latest run has not covered for `--source` and
+    /// merge it into that run.
+    #[arg(long, requires = "name")]
+    incremental: bool,
+    #[command(flatten)]
+    window: DateArgs,
+}
+
 #[derive(Subcommand, Debug, Clone)]
 enum CollectSource {
     /// Collect all enabled sources from shiplog.toml and render one merged packet.
@@ -2081,6 +3013,10 @@ enum CollectSource {
         /// Duplicate event conflict policy.
         #[arg(long, value_enum, default_value = "prefer-most-recent")]
         conflict: MergeConflict,
+        /// Fetch only what the latest run has not covered and merge it into
+        /// that run. Falls back to a full collection when there is no run yet.
+        #[arg(long)]
+        incremental: bool,
     },
 
     #[command(flatten)]
@@ -2120,7 +3056,7 @@ enum Source {
         /// API base for GHES.
         #[arg(long, default_value = "https://api.github.com")]
         api_base: String,
-        /// Override GitHub API cache directory (defaults to `<out>/.cache`).
+        /// Override GitHub API cache directory (defaults to `SHIPLOG_CACHE_DIR` or `<out>/.cache`).
         #[arg(long)]
         cache_dir: Option<PathBuf>,
         /// Disable GitHub API caching.
@@ -2153,7 +3089,7 @@ enum Source {
         /// GitLab token (or set GITLAB_TOKEN).
         #[arg(long)]
         token: Option<String>,
-        /// Override GitLab API cache directory (defaults to `<out>/.cache`).
+        /// Override GitLab API cache directory (defaults to `SHIPLOG_CACHE_DIR` or `<out>/.cache`).
         #[arg(long)]
         cache_dir: Option<PathBuf>,
         /// Disable GitLab API caching.
@@ -2183,7 +3119,7 @@ enum Source {
         /// Jira API token (or set JIRA_TOKEN).
         #[arg(long)]
         token: Option<String>,
-        /// Override Jira API cache directory (defaults to `<out>/.cache`).
+        /// Override Jira API cache directory (defaults to `SHIPLOG_CACHE_DIR` or `<out>/.cache`).
         #[arg(long)]
         cache_dir: Option<PathBuf>,
         /// Disable Jira API caching.
@@ -2210,7 +3146,7 @@ enum Source {
         /// Linear API key (or set LINEAR_API_KEY).
         #[arg(long)]
         api_key: Option<String>,
-        /// Override Linear API cache directory (defaults to `<out>/.cache`).
+        /// Override Linear API cache directory (defaults to `SHIPLOG_CACHE_DIR` or `<out>/.cache`).
         #[arg(long)]
         cache_dir: Option<PathBuf>,
         /// Disable Linear API caching.
@@ -2258,6 +3194,18 @@ enum Source {
         #[arg(long)]
         include_merges: bool,
     },
+
+    /// Import a GitHub account data export without calling the API.
+    GithubExport {
+        /// Export archive (`.tar.gz` or `.tar`) or the directory it was extracted to.
+        #[arg(long)]
+        archive: PathBuf,
+        /// GitHub login whose work to import. Defaults to the export's only user.
+        #[arg(long)]
+        user: Option<String>,
+        #[command(flatten)]
+        window: DateArgs,
+    },
 }
 
 #[derive(Args, Debug, Clone, Default)]
@@ -2310,6 +3258,36 @@ struct IntakeArgs {
     window: ConfigWindowArgs,
 }
 
+#[derive(Args, Debug, Clone)]
+struct BackfillArgs {
+    /// Path to shiplog.toml.
+    #[arg(long, default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+    /// Output directory for the quarter runs, shared cache, and merged run.
+    #[arg(long)]
+    out: Option<PathBuf>,
+    /// Start date (inclusive), YYYY-MM-DD.
+    #[arg(long)]
+    since: NaiveDate,
+    /// End date (exclusive), YYYY-MM-DD.
+    #[arg(long)]
+    until: NaiveDate,
+    /// Bundle profile to render. Internal is default unless config says otherwise.
+    #[arg(long)]
+    profile: Option<BundleProfile>,
+    /// Redaction key. Required for manager/public profiles.
+    /// If omitted, SHIPLOG_REDACT_KEY or the configured redaction env var is used.
+    #[arg(long)]
+    redact_key: Option<String>,
+    /// Duplicate event conflict policy.
+    #[arg(long, value_enum, default_value = "prefer-most-recent")]
+    conflict: MergeConflict,
+    /// Skip quarters already collected in `backfill.index.json` and continue
+    /// from the first one that is not.
+    #[arg(long)]
+    resume: bool,
+}
+
 #[derive(Args, Debug, Clone, Default)]
 struct ConfigWindowArgs {
     #[command(flatten)]
@@ -2362,6 +3340,14 @@ struct ShiplogConfig {
     sources: ConfigSources,
     github_activity: ConfigGithubActivity,
     redaction: ConfigRedaction,
+    bundle: ConfigBundle,
+    render: ConfigRender,
+    workstreams: ConfigWorkstreams,
+    coverage: ConfigCoverage,
+    notify: ConfigNotify,
+    publish: ConfigPublish,
+    http: ConfigHttp,
+    templates: BTreeMap<String, ConfigTemplate>,
 }
 
 #[derive(Deserialize, Debug, Default)]
@@ -2377,6 +3363,8 @@ struct ConfigDefaults {
     window: Option<String>,
     profile: Option<String>,
     include_reviews: Option<bool>,
+    timezone: Option<String>,
+    schedule: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Default)]
@@ -2405,6 +3393,50 @@ struct ConfigSources {
     manual: Option<ConfigManualSource>,
 }
 
+impl ConfigSources {
+    /// Names of the enabled sources, in collection order.
+    fn enabled_names(&self) -> Vec<&'static str> {
+        [
+            ("github", self.github.as_ref().map(|s| s.enabled)),
+            ("gitlab", self.gitlab.as_ref().map(|s| s.enabled)),
+            ("jira", self.jira.as_ref().map(|s| s.enabled)),
+            ("linear", self.linear.as_ref().map(|s| s.enabled)),
+            ("git", self.git.as_ref().map(|s| s.enabled)),
+            ("json", self.json.as_ref().map(|s| s.enabled)),
+            ("manual", self.manual.as_ref().map(|s| s.enabled)),
+        ]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.unwrap_or(false).then_some(name))
+        .collect()
+    }
+
+    /// Disable every source except `name`.
+    fn retain_only(&mut self, name: &str) {
+        let keep = |source: &str| source == name;
+        if let Some(s) = self.github.as_mut() {
+            s.enabled &= keep("github");
+        }
+        if let Some(s) = self.gitlab.as_mut() {
+            s.enabled &= keep("gitlab");
+        }
+        if let Some(s) = self.jira.as_mut() {
+            s.enabled &= keep("jira");
+        }
+        if let Some(s) = self.linear.as_mut() {
+            s.enabled &= keep("linear");
+        }
+        if let Some(s) = self.git.as_mut() {
+            s.enabled &= keep("git");
+        }
+        if let Some(s) = self.json.as_mut() {
+            s.enabled &= keep("json");
+        }
+        if let Some(s) = self.manual.as_mut() {
+            s.enabled &= keep("manual");
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(default)]
 struct ConfigGithubSource {
@@ -2533,50 +3565,217 @@ struct ConfigManualSource {
 #[serde(default)]
 struct ConfigRedaction {
     key_env: Option<String>,
+    /// Customer names and other terms to flag in manual event text.
+    pii_terms: Vec<String>,
+    /// Refuse to render share profiles while manual events look like PII.
+    block_public_on_pii: bool,
+    /// Internal domains the pre-bundle secret scan flags.
+    internal_hosts: Vec<String>,
+    /// Field overrides keyed by profile name.
+    profiles: BTreeMap<String, RedactionPolicy>,
+    /// Share aliases across runs under the output root.
+    shared_aliases: bool,
 }
 
-#[derive(Debug)]
-struct ConfiguredSourceFailure {
-    name: String,
-    error: String,
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigBundle {
+    age_recipients: Vec<String>,
+    profiles: BTreeMap<String, shiplog::schema::bundle::BundleScope>,
+    max_part_mb: Option<u64>,
+    pdf: bool,
+    signing_key_file: Option<PathBuf>,
 }
 
-#[derive(Debug, Serialize)]
-struct SourceFailuresFile {
-    schema_version: u8,
-    run_id: String,
-    generated_at: String,
-    window: SourceFailureWindow,
-    failures: Vec<SourceFailureRecord>,
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigRender {
+    template: Option<PathBuf>,
 }
 
-#[derive(Debug, Serialize, Clone)]
-struct SourceFailureWindow {
-    since: String,
-    until: String,
-    label: String,
-    period: Option<String>,
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigWorkstreams {
+    clusterer: ClusterStrategy,
 }
 
-#[derive(Debug, Serialize)]
-struct SourceFailureRecord {
-    source: String,
-    kind: String,
-    reason: String,
-    recorded_at: String,
-    window: SourceFailureWindow,
-    rerun_command: String,
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigCoverage {
+    require_complete: bool,
+    min_score: Option<u8>,
+    sources: BTreeMap<String, CoverageRequirement>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct ShareManifest {
-    schema_version: u8,
-    profile: String,
-    input_run_id: String,
-    created_at: String,
-    redaction_key_source: String,
-    coverage_completeness: String,
-    skipped_source_count: usize,
+impl ConfigCoverage {
+    fn run_requirement(&self) -> CoverageRequirement {
+        CoverageRequirement {
+            require_complete: self.require_complete,
+            min_score: self.min_score,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.run_requirement().is_empty()
+            && self.sources.values().all(CoverageRequirement::is_empty)
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigNotify {
+    slack: Option<ConfigNotifySlack>,
+    email: Option<ConfigNotifyEmail>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigNotifySlack {
+    webhook_url_env: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigNotifyEmail {
+    smtp_host: Option<String>,
+    smtp_port: Option<u16>,
+    security: Option<String>,
+    username: Option<String>,
+    password_env: Option<String>,
+    from: Option<String>,
+    to: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigTemplate {
+    #[serde(rename = "type")]
+    event_type: Option<String>,
+    workstream: Option<String>,
+    tags: Option<Vec<String>>,
+    required: Option<Vec<String>>,
+    prompts: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigHttp {
+    max_requests_per_host: Option<u64>,
+    max_wall_clock_secs: Option<u64>,
+    max_retries: Option<u32>,
+    max_retry_wait_secs: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigPublish {
+    notion: Option<ConfigPublishNotion>,
+    google_docs: Option<ConfigPublishGoogleDocs>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigPublishNotion {
+    parent_page_id: Option<String>,
+    token_env: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ConfigPublishGoogleDocs {
+    folder_id: Option<String>,
+    token_env: Option<String>,
+}
+
+const DEFAULT_SLACK_WEBHOOK_ENV: &str = "SHIPLOG_SLACK_WEBHOOK_URL";
+const DEFAULT_SMTP_PASSWORD_ENV: &str = "SHIPLOG_SMTP_PASSWORD";
+const DEFAULT_NOTION_TOKEN_ENV: &str = "NOTION_TOKEN";
+const DEFAULT_GOOGLE_DOCS_TOKEN_ENV: &str = "SHIPLOG_GOOGLE_TOKEN";
+
+/// Exit code for `collect multi` and `intake` runs that miss a `[coverage]`
+/// requirement. Distinct from `1` so pipelines can tell thin data from errors.
+const COVERAGE_REQUIREMENTS_EXIT_CODE: i32 = 3;
+
+#[derive(Debug)]
+struct ConfiguredSourceFailure {
+    name: String,
+    error: String,
+    /// Class of the typed [`IngestError`] behind `error`, when there was one.
+    repair_kind: Option<IntakeRepairKind>,
+    retry_after_secs: Option<u64>,
+}
+
+impl ConfiguredSourceFailure {
+    fn new(name: &str, error: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            error: error.into(),
+            repair_kind: None,
+            retry_after_secs: None,
+        }
+    }
+
+    fn from_error(name: &str, err: &anyhow::Error) -> Self {
+        let mut failure = Self::new(name, format!("{err:#}"));
+        if let Some(ingest) = ingest_error(err) {
+            failure.repair_kind = ingest_error_repair_kind(ingest);
+            if let IngestError::RateLimited {
+                retry_after: Some(wait),
+                ..
+            } = ingest
+            {
+                failure.retry_after_secs = Some(wait.as_secs());
+            }
+        }
+        failure
+    }
+
+    /// The typed class when the source raised one; otherwise read from the
+    /// message text.
+    fn repair_kind(&self) -> IntakeRepairKind {
+        self.repair_kind
+            .unwrap_or_else(|| classify_intake_repair_kind(&self.name, &self.error))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SourceFailuresFile {
+    schema_version: u8,
+    run_id: String,
+    generated_at: String,
+    window: SourceFailureWindow,
+    failures: Vec<SourceFailureRecord>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct SourceFailureWindow {
+    since: String,
+    until: String,
+    label: String,
+    period: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SourceFailureRecord {
+    source: String,
+    kind: String,
+    reason: String,
+    recorded_at: String,
+    window: SourceFailureWindow,
+    rerun_command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_after_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ShareManifest {
+    schema_version: u8,
+    profile: String,
+    input_run_id: String,
+    created_at: String,
+    redaction_key_source: String,
+    coverage_completeness: String,
+    skipped_source_count: usize,
     strict_verify_result: ShareManifestStrictVerifyResult,
     packet_path: String,
     zip_path: Option<String>,
@@ -2640,6 +3839,7 @@ struct IntakeReport {
     repair_sources: Vec<IntakeReportRepairSource>,
     repair_items: Vec<IntakeReportRepairItem>,
     packet_quality: IntakeReportPacketQuality,
+    readiness_assessment: ReadinessAssessment,
     curation_notes: Vec<String>,
     good: Vec<String>,
     needs_attention: Vec<String>,
@@ -2843,6 +4043,24 @@ struct IntakeReportArtifact {
     path: String,
 }
 
+/// An enabled `[sources.<name>]` section, built but not yet collected.
+struct ConfiguredIngestor {
+    name: &'static str,
+    /// Display name used in error context.
+    label: &'static str,
+    ingestor: Result<Box<dyn Ingestor>>,
+}
+
+impl ConfiguredIngestor {
+    fn new(name: &'static str, label: &'static str, ingestor: Result<Box<dyn Ingestor>>) -> Self {
+        Self {
+            name,
+            label,
+            ingestor,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ConfiguredSourceOutputs {
     successes: Vec<(String, IngestOutput)>,
@@ -3165,9 +4383,19 @@ fn run_intake(args: IntakeArgs) -> Result<()> {
     intake_plan.failures.append(&mut configured.failures);
     configured.failures = intake_plan.failures;
 
-    let clusterer = build_clusterer(false, "", "", None);
-    let (engine, redactor) = create_engine(redaction_key.engine_key(), clusterer, &bundle_profile);
+    let clusterer = build_clusterer(
+        config_model.workstreams.clusterer,
+        false,
+        None,
+        "",
+        "",
+        None,
+        "",
+    );
+    let redactor = cli_redactor(redaction_key.engine_key(), &config_model.redaction)?;
+    let (engine, redactor) = create_engine_with_redactor(redactor, clusterer, &bundle_profile);
     let engine = engine.with_profile_rendering(redaction_key.render_profiles());
+    let engine = configure_engine_bundle(engine, &args.config, &config_model)?;
     let result = run_configured_multi_pipeline(
         &args.config,
         &config_model,
@@ -3234,6 +4462,8 @@ fn run_intake(args: IntakeArgs) -> Result<()> {
         include_footer_out,
         include_footer_config,
     );
+    notify_configured_run(&config_model, &result);
+    enforce_coverage_requirements(&config_model, &result)?;
 
     if args.no_open {
         println!();
@@ -3308,6 +4538,28 @@ fn run_update(args: UpdateArgs) -> Result<()> {
 fn run_add(args: AddArgs) -> Result<()> {
     let events = configured_manual_events_path(&args.config, true)
         .unwrap_or_else(|| PathBuf::from(MANUAL_EVENTS_FILENAME));
+    let mut event_type = args.event_type;
+    let mut workstream = optional_text_arg(args.workstream);
+    let mut tags = args.tags;
+    if let Some(name) = &args.template {
+        let config = if args.config.exists() {
+            Some(load_shiplog_config(&args.config)?)
+        } else {
+            None
+        };
+        let template = manual_templates::resolve_template(config.as_ref(), name)?;
+        event_type = event_type.or(Some(template.event_type));
+        workstream = workstream.or_else(|| template.workstream.clone());
+        tags = template.tags.iter().cloned().chain(tags).collect();
+        manual_templates::ensure_template_fields(&template, |field| match field {
+            TemplateField::Description => optional_text_arg(args.description.clone()).is_some(),
+            TemplateField::Workstream => workstream.is_some(),
+            TemplateField::Tag => !tags.is_empty(),
+            TemplateField::Receipt => !args.receipts.is_empty(),
+            TemplateField::Impact => optional_text_arg(args.impact.clone()).is_some(),
+            TemplateField::Metric => !args.metrics.is_empty(),
+        })?;
+    }
     run_journal_add(JournalAddArgs {
         events,
         from_repair: None,
@@ -3315,16 +4567,17 @@ fn run_add(args: AddArgs) -> Result<()> {
         run: None,
         latest: false,
         id: None,
-        event_type: args.event_type,
+        event_type: event_type.unwrap_or(JournalEventType::Note),
         date: args.date,
         start: None,
         end: None,
         title: Some(args.title),
         description: args.description,
-        workstream: args.workstream,
-        tags: args.tags,
+        workstream,
+        tags,
         receipts: args.receipts,
         impact: args.impact,
+        metrics: args.metrics,
         dry_run: args.dry_run,
         quick: true,
     })
@@ -3556,6 +4809,10 @@ fn print_intake_readiness_report(report: &IntakeReport) {
     println!("Intake readiness:");
     println!("Intake status: {}", report.readiness);
     println!("Packet readiness: {}", packet_readiness_display(report));
+    println!(
+        "Readiness score: {}/100 ({})",
+        report.readiness_assessment.score, report.readiness_assessment.grade
+    );
     println!();
     println!("Good:");
     for item in &report.good {
@@ -3760,6 +5017,31 @@ fn packet_receipt_ref_label(receipt: &IntakeReportQualityReceiptRef) -> String {
     label
 }
 
+fn render_readiness_assessment_section(out: &mut String, assessment: &ReadinessAssessment) {
+    out.push_str("## Readiness Score\n\n");
+    out.push_str(&format!(
+        "Score: **{}/100 ({})**\n\n",
+        assessment.score, assessment.grade
+    ));
+    for component in &assessment.components {
+        out.push_str(&format!(
+            "- {}: {}/100 (weight {}%) — {}\n",
+            component.kind.label(),
+            component.score,
+            component.weight,
+            component.detail
+        ));
+    }
+    out.push('\n');
+    if !assessment.suggestions.is_empty() {
+        out.push_str("Suggestions:\n\n");
+        for suggestion in &assessment.suggestions {
+            out.push_str(&format!("- {}\n", suggestion.message));
+        }
+        out.push('\n');
+    }
+}
+
 fn render_intake_report_markdown(report: &IntakeReport) -> String {
     let mut out = String::new();
     out.push_str("# Review Intake Report\n\n");
@@ -3904,6 +5186,8 @@ implicitly fresh; see `## Skipped Sources` and `## Source Decisions` for the res
     }
     out.push('\n');
 
+    render_readiness_assessment_section(&mut out, &report.readiness_assessment);
+
     out.push_str("## Evidence Debt\n\n");
     if report.evidence_debt.is_empty() {
         out.push_str("- No obvious evidence debt detected.\n");
@@ -4036,7 +5320,12 @@ const INTAKE_REPORT_REQUIRED_FIELDS: &[&str] = &[
     "next_commands",
     "artifacts",
 ];
-const INTAKE_REPORT_OPTIONAL_FIELDS: &[&str] = &["actions", "repair_items", "packet_quality"];
+const INTAKE_REPORT_OPTIONAL_FIELDS: &[&str] = &[
+    "actions",
+    "repair_items",
+    "packet_quality",
+    "readiness_assessment",
+];
 const INTAKE_REPORT_ARRAY_FIELDS: &[&str] = &[
     "included_sources",
     "skipped_sources",
@@ -4812,7 +6101,7 @@ fn find_run_dirs_for_repair(out_dir: &Path) -> Result<Vec<PathBuf>> {
         .filter(|entry| entry.path().is_dir())
         .filter(|entry| entry.path().join("ledger.events.jsonl").exists())
         .collect();
-    runs.sort_by_key(|entry| std::cmp::Reverse(entry.file_name()));
+    runs.sort_by_cached_key(|entry| std::cmp::Reverse(status::run_sort_key(&entry.path())));
 
     Ok(runs.into_iter().map(|entry| entry.path()).collect())
 }
@@ -5119,6 +6408,13 @@ fn validate_report_items(report: &serde_json::Value) -> Result<()> {
     if let Some(packet_quality) = report.get("packet_quality") {
         validate_report_packet_quality(packet_quality)?;
     }
+    if let Some(assessment) = report.get("readiness_assessment") {
+        let assessment: ReadinessAssessment = serde_json::from_value(assessment.clone())
+            .context("intake report field \"readiness_assessment\" is malformed")?;
+        if assessment.score > 100 {
+            anyhow::bail!("intake report readiness_assessment score must be 0-100")
+        }
+    }
     validate_report_source_identity_consistency(report)?;
 
     Ok(())
@@ -6760,10 +8056,8 @@ fn push_intake_include(plan: &mut IntakeSourcePlan, name: &str, reason: impl Int
 
 fn push_intake_skip(plan: &mut IntakeSourcePlan, name: &str, error: impl Into<String>) {
     let error = error.into();
-    plan.failures.push(ConfiguredSourceFailure {
-        name: name.to_string(),
-        error: error.clone(),
-    });
+    plan.failures
+        .push(ConfiguredSourceFailure::new(name, error.clone()));
     push_intake_explanation(
         &mut plan.explanations,
         name,
@@ -7233,6 +8527,9 @@ fn render_manual_events_template() -> String {
 #       - label: "runbook"
 #         url: "https://example.com/runbook"
 #     impact: "Reduced operational risk."
+#     evidence:
+#       - path: "evidence/error-budget.png"
+#         label: "error budget dashboard"
 
 version: 1
 generated_at: "{generated_at}"
@@ -7284,6 +8581,7 @@ fn run_journal_add(args: JournalAddArgs) -> Result<()> {
         mut tags,
         receipts,
         impact,
+        metrics,
         dry_run,
         quick,
     } = args;
@@ -7353,6 +8651,9 @@ fn run_journal_add(args: JournalAddArgs) -> Result<()> {
         tags: normalize_journal_tags(tags)?,
         receipts: parse_journal_receipts(&receipts)?,
         impact: optional_text_arg(impact),
+        metrics: parse_journal_metrics(&metrics)?,
+        evidence: Vec::new(),
+        repeat: None,
     };
 
     if dry_run {
@@ -7398,7 +8699,10 @@ fn resolve_journal_repair_context(args: &JournalAddArgs) -> Result<Option<Journa
     };
 
     let repair_id = required_text_arg("--from-repair", from_repair)?;
-    let out_dir = args.out.as_deref().unwrap_or_else(|| Path::new("./out"));
+    let out_dir = args
+        .out
+        .as_deref()
+        .unwrap_or_else(|| Path::new(DEFAULT_OUT_DIR));
     let Some(report_path) =
         resolve_repair_plan_report_path(out_dir, args.run.clone(), args.latest)?
     else {
@@ -7672,6 +8976,108 @@ fn run_journal_list(args: JournalListArgs) -> Result<()> {
     Ok(())
 }
 
+fn run_journal_import(args: JournalImportArgs) -> Result<()> {
+    let text = std::fs::read_to_string(&args.file)
+        .with_context(|| format!("read brag doc {}", args.file.display()))?;
+    let mut parser = BragDocParser::new().with_date_patterns(&args.date_patterns)?;
+    if let Some(year) = args.year {
+        parser = parser.with_default_year(year);
+    }
+    let import = parser.parse(&text);
+
+    let mut tags = vec!["brag-doc".to_string()];
+    tags.extend(args.tags);
+    let tags = normalize_journal_tags(tags)?;
+
+    let mut file = if args.events.exists() {
+        read_manual_events(&args.events)?
+    } else {
+        create_empty_file()
+    };
+    if file.version != 1 {
+        anyhow::bail!(
+            "unsupported manual events version {}; expected 1",
+            file.version
+        );
+    }
+
+    let mut added = Vec::new();
+    let mut duplicates = 0;
+    for entry in import.entries {
+        let id = generated_journal_id(&entry.date, &entry.title);
+        if file
+            .events
+            .iter()
+            .chain(&added)
+            .any(|existing: &ManualEventEntry| existing.id == id)
+        {
+            duplicates += 1;
+            continue;
+        }
+        added.push(ManualEventEntry {
+            id,
+            event_type: args.event_type.into(),
+            date: entry.date,
+            title: entry.title,
+            description: entry.description,
+            workstream: entry.section.filter(|_| args.headings_as_workstreams),
+            tags: tags.clone(),
+            receipts: entry.receipts,
+            impact: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
+        });
+    }
+
+    let verb = if args.dry_run {
+        "Would import"
+    } else {
+        "Imported"
+    };
+    println!(
+        "{verb} {} manual events from {} into {}",
+        added.len(),
+        args.file.display(),
+        args.events.display()
+    );
+    for entry in &added {
+        println!(
+            "- {} | {} | {}",
+            entry.id,
+            journal_date_label(&entry.date),
+            entry.title
+        );
+    }
+    if duplicates > 0 {
+        println!("Already present: {duplicates}");
+    }
+    if !import.skipped.is_empty() {
+        println!("Undated bullets (not imported): {}", import.skipped.len());
+        for skipped in &import.skipped {
+            println!("  line {}: {}", skipped.line, skipped.text);
+        }
+        println!(
+            "Add a date or a dated heading to these bullets, or pass --year or --date-pattern."
+        );
+    }
+
+    if args.dry_run || added.is_empty() {
+        return Ok(());
+    }
+    if let Some(parent) = args
+        .events
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("create manual events directory {}", parent.display()))?;
+    }
+    file.events.extend(added);
+    write_manual_events(&args.events, &file)?;
+    Ok(())
+}
+
 fn run_journal_edit(args: JournalEditArgs) -> Result<()> {
     let id = required_text_arg("--id", &args.id)?;
     validate_journal_id(&id)?;
@@ -7928,11 +9334,76 @@ fn parse_journal_receipts(values: &[String]) -> Result<Vec<Link>> {
     Ok(receipts)
 }
 
+fn parse_journal_metrics(values: &[String]) -> Result<Vec<ImpactMetric>> {
+    let mut metrics = Vec::new();
+    for value in values {
+        let Some((name, measured)) = value.split_once('=') else {
+            anyhow::bail!("journal metric must be NAME=VALUE, got {value:?}");
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("journal metric name cannot be blank in {value:?}");
+        }
+        let (baseline, measured) = match measured.split_once("->") {
+            Some((baseline, measured)) => (Some(baseline), measured),
+            None => (None, measured),
+        };
+        let (number, unit) = split_metric_number(measured)
+            .with_context(|| format!("journal metric value in {value:?}"))?;
+        let baseline = match baseline {
+            Some(baseline) => {
+                let (number, baseline_unit) = split_metric_number(baseline)
+                    .with_context(|| format!("journal metric baseline in {value:?}"))?;
+                if !baseline_unit.is_empty() && !unit.is_empty() && baseline_unit != unit {
+                    anyhow::bail!(
+                        "journal metric baseline unit {baseline_unit:?} does not match {unit:?} in {value:?}"
+                    );
+                }
+                Some((number, baseline_unit))
+            }
+            None => None,
+        };
+        let unit = match (unit.is_empty(), &baseline) {
+            (true, Some((_, baseline_unit))) => baseline_unit.clone(),
+            _ => unit,
+        };
+        metrics.push(ImpactMetric {
+            name: name.to_string(),
+            value: number,
+            unit: (!unit.is_empty()).then_some(unit),
+            baseline: baseline.map(|(number, _)| number),
+        });
+    }
+    Ok(metrics)
+}
+
+/// Split `"420ms"` into the number `420` and the unit `"ms"`.
+fn split_metric_number(text: &str) -> Result<(serde_json::Number, String)> {
+    let text = text.trim();
+    let end = text
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+        .unwrap_or(text.len());
+    let (digits, unit) = text.split_at(end);
+    let number = if let Ok(int) = digits.parse::<i64>() {
+        serde_json::Number::from(int)
+    } else {
+        digits
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .ok_or_else(|| anyhow::anyhow!("expected a number, got {text:?}"))?
+    };
+    Ok((number, unit.trim().to_string()))
+}
+
 fn print_journal_entry(label: &str, path: &Path, entry: &ManualEventEntry) {
     println!("{label}: {}", entry.id);
     println!("File: {}", path.display());
     println!("Type: {}", entry.event_type);
     println!("Date: {}", journal_date_label(&entry.date));
+    if let Some(repeat) = entry.repeat {
+        println!("Repeats: {repeat}");
+    }
     println!("Title: {}", entry.title);
     if let Some(workstream) = &entry.workstream {
         println!("Workstream: {workstream}");
@@ -7943,6 +9414,9 @@ fn print_journal_entry(label: &str, path: &Path, entry: &ManualEventEntry) {
     if !entry.receipts.is_empty() {
         println!("Receipts: {}", entry.receipts.len());
     }
+    for metric in &entry.metrics {
+        println!("Metric: {metric}");
+    }
 }
 
 fn journal_date_start(date: &ManualDate) -> NaiveDate {
@@ -8291,7 +9765,7 @@ fn run_next(args: NextArgs) -> Result<()> {
 
 fn run_home() -> Result<()> {
     let config = Path::new(CONFIG_FILENAME);
-    let out = Path::new("./out");
+    let out = Path::new(DEFAULT_OUT_DIR);
     let resolution = status::resolve_latest_review_loop_receipts(out);
 
     if !config.exists() && resolution.latest_run.is_none() {
@@ -9464,48 +10938,774 @@ fn run_config_migrate(config_path: &Path, dry_run: bool) -> Result<()> {
     }
 }
 
-fn run_periods_list(args: PeriodsArgs) -> Result<()> {
-    let config = load_config_for_command(&args.config)?;
-    ensure_supported_config_version(&config)?;
-    let base_dir = config_base_dir(&args.config);
-    let out_dir = periods_out_dir(args.out.as_deref(), &config, &base_dir);
-
-    println!("Configured periods:");
-    println!("Config: {}", args.config.display());
-    println!("Out: {}", out_dir.display());
-    if config.periods.is_empty() {
-        println!("- none");
-        return Ok(());
+fn run_completions(args: CompletionsArgs) -> Result<()> {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_string();
+    match args.dir {
+        Some(dir) => {
+            std::fs::create_dir_all(&dir)
+                .with_context(|| format!("create completions directory {}", dir.display()))?;
+            let path = clap_complete::generate_to(args.shell, &mut cmd, name, &dir)
+                .with_context(|| format!("write {} completions", args.shell))?;
+            println!("Wrote {}", path.display());
+        }
+        None => clap_complete::generate(args.shell, &mut cmd, name, &mut std::io::stdout()),
     }
+    Ok(())
+}
 
-    for name in config.periods.keys() {
-        let window = resolve_config_period(&config, name)?;
-        let source = period_definition_label(&config.periods[name]);
-        let latest = latest_period_run_summary(&out_dir, &window)?;
-        println!("- {name}");
-        println!("  - definition: {source}");
-        println!("  - window: {}..{}", window.since, window.until);
-        println!("  - label: {}", window.window_label());
-        if let Some(run) = latest {
-            println!("  - latest run: {}", run.run_id);
-            println!("  - packet: {}", run.packet_path.display());
-        } else {
-            println!("  - latest run: none");
+fn run_man(args: ManArgs) -> Result<()> {
+    let cmd = Cli::command();
+    match args.dir {
+        Some(dir) => {
+            std::fs::create_dir_all(&dir)
+                .with_context(|| format!("create man directory {}", dir.display()))?;
+            clap_mangen::generate_to(cmd, &dir)
+                .with_context(|| format!("write man pages to {}", dir.display()))?;
+            println!("Wrote man pages to {}", dir.display());
         }
+        None => clap_mangen::Man::new(cmd)
+            .render(&mut std::io::stdout())
+            .context("render man page")?,
+    }
+    Ok(())
+}
+
+fn run_self_update(args: SelfUpdateArgs) -> Result<()> {
+    let current = env!("CARGO_PKG_VERSION");
+    let updater = Updater::new(ReleaseSource::from_env())?;
+    let release = updater.latest_release()?;
+    let latest = release.version();
+    if compare_versions(latest, current)? != std::cmp::Ordering::Greater {
         println!(
-            "  - next: {}",
-            period_intake_command(&args.config, &out_dir, name)
+            "shiplog {current} is up to date (latest release: {}).",
+            release.tag_name
         );
+        return Ok(());
+    }
+
+    println!("shiplog {latest} is available (installed: {current}).");
+    if !release.html_url.is_empty() {
+        println!("Release notes: {}", release.html_url);
+    }
+    if args.check {
+        println!("Run `shiplog self-update` to install it.");
+        return Ok(());
     }
 
+    let asset = platform_asset_name().ok_or_else(|| {
+        anyhow::anyhow!(
+            "no prebuilt shiplog binary is published for this platform; \
+             update with `cargo install shiplog --locked`"
+        )
+    })?;
+    let bytes = updater.download_verified(&release, asset)?;
+    println!("Verified {asset} against {CHECKSUMS_ASSET}.");
+    let exe = std::env::current_exe().context("locate the running shiplog executable")?;
+    replace_executable(&exe, &bytes)?;
+    println!("Updated {} to shiplog {latest}.", exe.display());
     Ok(())
 }
 
-fn run_periods_explain(args: PeriodExplainArgs) -> Result<()> {
-    let config = load_config_for_command(&args.config)?;
-    ensure_supported_config_version(&config)?;
-    let base_dir = config_base_dir(&args.config);
-    let out_dir = periods_out_dir(args.out.as_deref(), &config, &base_dir);
+/// Units and daemon runs are named after this.
+const SCHEDULE_UNIT_NAME: &str = "shiplog-collect";
+const SCHEDULE_LAUNCHD_LABEL: &str = "com.effortlessmetrics.shiplog.collect";
+
+/// Longest single sleep while waiting for the next run, so a suspended
+/// machine or a clock change is noticed promptly.
+const DAEMON_POLL: std::time::Duration = std::time::Duration::from_mins(1);
+
+/// Schedule and timezone from `[defaults]`, with the absolute config path
+/// that scheduled runs collect from.
+fn load_config_schedule(config_path: &Path) -> Result<(Schedule, ReportingTimezone, PathBuf)> {
+    let config = load_shiplog_config(config_path)?;
+    let expression = non_empty_string(config.defaults.schedule.as_deref()).ok_or_else(|| {
+        anyhow::anyhow!(
+            "{} has no [defaults] schedule; add one such as schedule = \"0 9 * * MON\"",
+            config_path.display()
+        )
+    })?;
+    let schedule: Schedule = expression
+        .parse()
+        .with_context(|| "invalid defaults.schedule in shiplog.toml")?;
+    let timezone = config_reporting_timezone(&config)?;
+    let config_path = std::path::absolute(config_path)
+        .with_context(|| format!("resolve {}", config_path.display()))?;
+    Ok((schedule, timezone, config_path))
+}
+
+/// `shiplog collect multi` invocation run on each scheduled tick.
+fn scheduled_collect_command(config_path: &Path) -> Result<Vec<String>> {
+    let exe = std::env::current_exe().context("locate the running shiplog executable")?;
+    Ok(vec![
+        exe.display().to_string(),
+        "collect".to_string(),
+        "multi".to_string(),
+        "--config".to_string(),
+        config_path.display().to_string(),
+    ])
+}
+
+fn config_working_dir(config_path: &Path) -> PathBuf {
+    config_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn run_daemon(args: DaemonArgs) -> Result<()> {
+    let (schedule, timezone, config_path) = load_config_schedule(&args.config)?;
+    let command = scheduled_collect_command(&config_path)?;
+    let working_dir = config_working_dir(&config_path);
+    println!(
+        "shiplog daemon: collecting from {} on `{schedule}` ({}).",
+        config_path.display(),
+        timezone.name()
+    );
+
+    loop {
+        let next = schedule
+            .next_after(&Utc::now(), &timezone)
+            .ok_or_else(|| anyhow::anyhow!("schedule `{schedule}` never fires"))?;
+        println!("Next run: {}", next.to_rfc3339());
+        while let Ok(remaining) = (next - Utc::now()).to_std() {
+            if remaining.is_zero() {
+                break;
+            }
+            std::thread::sleep(remaining.min(DAEMON_POLL));
+        }
+
+        println!(
+            "Starting scheduled collection at {}.",
+            Utc::now().to_rfc3339()
+        );
+        // A failed run is reported and the daemon keeps its schedule; the
+        // next tick may well succeed.
+        match std::process::Command::new(&command[0])
+            .args(&command[1..])
+            .current_dir(&working_dir)
+            .status()
+        {
+            Ok(status) if status.success() => println!("Scheduled collection finished."),
+            Ok(status) => tracing::warn!("scheduled collection exited with {status}."),
+            Err(err) => tracing::warn!("could not start scheduled collection: {err}"),
+        }
+    }
+}
+
+fn run_schedule_next(config: &Path, count: usize) -> Result<()> {
+    let (schedule, timezone, _) = load_config_schedule(config)?;
+    let upcoming = schedule.upcoming(&Utc::now(), &timezone, count);
+    if upcoming.is_empty() {
+        anyhow::bail!("schedule `{schedule}` never fires");
+    }
+    println!("Schedule: `{schedule}` ({})", timezone.name());
+    for instant in upcoming {
+        println!(
+            "- {}",
+            timezone
+                .local_time(&instant)
+                .format("%a %Y-%m-%d %H:%M %:z")
+        );
+    }
+    Ok(())
+}
+
+fn run_schedule_install(args: ScheduleInstallArgs) -> Result<()> {
+    let (schedule, timezone, config_path) = load_config_schedule(&args.config)?;
+    let command = scheduled_collect_command(&config_path)?;
+    let working_dir = config_working_dir(&config_path);
+    let format = args.format.unwrap_or(if cfg!(target_os = "macos") {
+        ScheduleFormat::Launchd
+    } else {
+        ScheduleFormat::Systemd
+    });
+
+    let units = match format {
+        ScheduleFormat::Systemd => {
+            let (service, timer) =
+                shiplog::schedule::systemd_units(&schedule, &timezone, &command, &working_dir);
+            vec![
+                (format!("{SCHEDULE_UNIT_NAME}.service"), service),
+                (format!("{SCHEDULE_UNIT_NAME}.timer"), timer),
+            ]
+        }
+        ScheduleFormat::Launchd => vec![(
+            format!("{SCHEDULE_LAUNCHD_LABEL}.plist"),
+            shiplog::schedule::launchd_plist(
+                &schedule,
+                SCHEDULE_LAUNCHD_LABEL,
+                &command,
+                &working_dir,
+            ),
+        )],
+    };
+
+    if args.print {
+        for (name, contents) in &units {
+            println!("# {name}\n{contents}");
+        }
+        return Ok(());
+    }
+
+    let dir = match args.dir {
+        Some(dir) => dir,
+        None => default_schedule_unit_dir(format)?,
+    };
+    std::fs::create_dir_all(&dir).with_context(|| format!("create {}", dir.display()))?;
+    for (name, contents) in &units {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).with_context(|| format!("write {}", path.display()))?;
+        println!("Wrote {}", path.display());
+    }
+    match format {
+        ScheduleFormat::Systemd => println!(
+            "Enable with: systemctl --user daemon-reload && systemctl --user enable --now {SCHEDULE_UNIT_NAME}.timer"
+        ),
+        ScheduleFormat::Launchd => {
+            println!(
+                "Load with: launchctl load -w {}",
+                dir.join(&units[0].0).display()
+            );
+            println!(
+                "Note: launchd reads `{schedule}` in this Mac's local timezone; `shiplog daemon` reads it in {}.",
+                timezone.name()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn default_schedule_unit_dir(format: ScheduleFormat) -> Result<PathBuf> {
+    let dir = match format {
+        ScheduleFormat::Systemd => {
+            shiplog::dirs::xdg_config_home().map(|config_home| config_home.join("systemd/user"))
+        }
+        ScheduleFormat::Launchd => {
+            shiplog::dirs::home_dir().map(|home| home.join("Library/LaunchAgents"))
+        }
+    };
+    dir.ok_or_else(|| anyhow::anyhow!("HOME is not set; pass --dir"))
+}
+
+fn run_publish_notion(args: PublishNotionArgs) -> Result<()> {
+    let config = load_publish_config(&args.packet.config)?;
+    let notion = config.publish.notion.unwrap_or_default();
+    let parent_page_id = optional_config_string(args.parent_page.as_deref())
+        .or_else(|| optional_config_string(notion.parent_page_id.as_deref()))
+        .ok_or_else(|| {
+            anyhow::anyhow!("pass --parent-page or set publish.notion.parent_page_id")
+        })?;
+    let token = publish_token(
+        notion.token_env.as_deref(),
+        DEFAULT_NOTION_TOKEN_ENV,
+        "create a Notion integration token and export it",
+    )?;
+
+    let packet = PublishedPacket::load(&args.packet, "notion")?;
+    let mut publisher = NotionPublisher::new(token)?;
+    if let Some(api_base) = publish_api_override("SHIPLOG_NOTION_API") {
+        publisher = publisher.with_api_base(api_base);
+    }
+    let page = publisher.publish(
+        &parent_page_id,
+        packet.existing_document_id(),
+        &packet.title,
+        &packet.blocks,
+    )?;
+    packet.record(&page.id, &page.url, "Notion page")
+}
+
+fn run_publish_google_docs(args: PublishGoogleDocsArgs) -> Result<()> {
+    let config = load_publish_config(&args.packet.config)?;
+    let google_docs = config.publish.google_docs.unwrap_or_default();
+    let folder_id = optional_config_string(args.folder.as_deref())
+        .or_else(|| optional_config_string(google_docs.folder_id.as_deref()));
+    let token = publish_token(
+        google_docs.token_env.as_deref(),
+        DEFAULT_GOOGLE_DOCS_TOKEN_ENV,
+        "export an OAuth access token with the documents and drive.file scopes",
+    )?;
+
+    let packet = PublishedPacket::load(&args.packet, "google-docs")?;
+    let mut publisher = GoogleDocsPublisher::new(token)?;
+    if let Some(api_base) = publish_api_override("SHIPLOG_GOOGLE_API") {
+        publisher = publisher.with_api_base(api_base);
+    }
+    let doc = publisher.publish(
+        folder_id.as_deref(),
+        packet.existing_document_id(),
+        &packet.title,
+        &packet.blocks,
+    )?;
+    packet.record(&doc.id, &doc.url, "Google Doc")
+}
+
+fn load_publish_config(path: &Path) -> Result<ShiplogConfig> {
+    if path.exists() {
+        load_shiplog_config(path)
+    } else {
+        Ok(ShiplogConfig::default())
+    }
+}
+
+/// Read a publisher token from `configured` or `default_env`.
+fn publish_token(configured: Option<&str>, default_env: &str, hint: &str) -> Result<String> {
+    let token_env = optional_config_string(configured).unwrap_or_else(|| default_env.to_string());
+    std::env::var(&token_env)
+        .ok()
+        .filter(|token| !token.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("{token_env} is not set; {hint}"))
+}
+
+/// API root override used to point publishers at a local stand-in.
+fn publish_api_override(env: &str) -> Option<String> {
+    std::env::var(env)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// A run's packet ready to publish, with the record of any earlier publish.
+struct PublishedPacket {
+    target: &'static str,
+    profile: BundleProfile,
+    packet_path: PathBuf,
+    title: String,
+    blocks: Vec<shiplog::publish::Block>,
+    record_path: PathBuf,
+    existing: Option<PublishRecord>,
+}
+
+impl PublishedPacket {
+    fn load(args: &PublishPacketArgs, target: &'static str) -> Result<Self> {
+        let run_dir = resolve_render_run_dir(&args.out, args.run.clone(), args.latest)?;
+        let (packet_path, title) = published_packet(&run_dir, &args.bundle_profile)?;
+        let markdown = std::fs::read_to_string(&packet_path)
+            .with_context(|| format!("read {}", packet_path.display()))?;
+        let record_path = PublishRecord::path(&run_dir, target, args.bundle_profile.as_str());
+        let existing = PublishRecord::load(&record_path)?;
+        Ok(Self {
+            target,
+            profile: args.bundle_profile.clone(),
+            packet_path,
+            title,
+            blocks: shiplog::publish::parse_markdown(&markdown),
+            record_path,
+            existing,
+        })
+    }
+
+    fn existing_document_id(&self) -> Option<&str> {
+        self.existing
+            .as_ref()
+            .map(|record| record.document_id.as_str())
+    }
+
+    /// Save the publish record and report where the packet went.
+    fn record(&self, document_id: &str, url: &str, noun: &str) -> Result<()> {
+        let updated = self.existing_document_id() == Some(document_id);
+        PublishRecord {
+            target: self.target.to_string(),
+            profile: self.profile.to_string(),
+            document_id: document_id.to_string(),
+            url: url.to_string(),
+            published_at: Utc::now(),
+        }
+        .save(&self.record_path)?;
+
+        let verb = if updated { "Updated" } else { "Created" };
+        println!("{verb} {noun} for {}: {url}", self.packet_path.display());
+        println!("Record: {}", self.record_path.display());
+        Ok(())
+    }
+}
+
+/// Packet path and document title for publishing `run_dir` at `profile`.
+fn published_packet(run_dir: &Path, profile: &BundleProfile) -> Result<(PathBuf, String)> {
+    let packet = match profile {
+        BundleProfile::Internal => run_dir.join("packet.md"),
+        _ => run_dir
+            .join("profiles")
+            .join(profile.as_str())
+            .join("packet.md"),
+    };
+    if !packet.exists() {
+        match profile {
+            BundleProfile::Internal => {
+                anyhow::bail!("{} not found; run `shiplog render` first", packet.display())
+            }
+            _ => anyhow::bail!(
+                "{} not found; run `shiplog share {profile}` first",
+                packet.display()
+            ),
+        }
+    }
+
+    let run_id = run_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json"))
+        .ok()
+        .and_then(|text| serde_json::from_str::<CoverageManifest>(&text).ok());
+    let mut title = match coverage {
+        Some(coverage) => format!(
+            "{} packet, {} to {}",
+            coverage.user, coverage.window.since, coverage.window.until
+        ),
+        None => format!("shiplog packet {run_id}"),
+    };
+    if !matches!(profile, BundleProfile::Internal) {
+        title.push_str(&format!(" ({profile})"));
+    }
+    Ok((packet, title))
+}
+
+fn run_generate_fixture(args: GenerateFixtureArgs) -> Result<()> {
+    let spec = FixtureSpec {
+        user: args.user,
+        window: TimeWindow {
+            since: args.since,
+            until: args.until,
+        },
+        events: args.events,
+        repos: args.repos,
+        mix: args.mix,
+        dates: args.dates,
+        incomplete_months: args.incomplete_months,
+        seed: args.seed,
+    };
+    let fixture = shiplog::fixtures::generate(&spec)?;
+    fixture.write_to(&args.out)?;
+
+    println!(
+        "Wrote {} synthetic events across {} repositories to {}",
+        fixture.events.len(),
+        spec.repos,
+        args.out.display()
+    );
+    println!("Coverage: {}", fixture.coverage.completeness);
+    println!("Next:");
+    println!(
+        "  shiplog collect json --events {} --coverage {}",
+        args.out.join("ledger.events.jsonl").display(),
+        args.out.join("coverage.manifest.json").display()
+    );
+    Ok(())
+}
+
+fn run_search(args: SearchArgs) -> Result<()> {
+    let run_dir = resolve_render_run_dir(&args.out, args.run, args.latest)?;
+    let paths = shiplog::bundle::RunArtifactPaths::new(&run_dir);
+    let index_path = paths.search_index();
+    let index = if search_index_is_current(&index_path, &paths.ledger_events()) {
+        SearchIndex::open(&index_path)?
+    } else {
+        let index = build_run_search_index(&run_dir)?;
+        eprintln!(
+            "Indexed {} into {}",
+            event_count_phrase(index.len()?),
+            display_path_for_cli(&index_path)
+        );
+        index
+    };
+
+    let terms = args.terms.join(" ");
+    let hits = index.search(&terms, args.limit)?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&hits)?);
+        return Ok(());
+    }
+    if hits.is_empty() {
+        println!(
+            "No events match {terms:?} in {}",
+            display_path_for_cli(&run_dir)
+        );
+        return Ok(());
+    }
+    let noun = if hits.len() == 1 { "match" } else { "matches" };
+    println!(
+        "{} {noun} for {terms:?} in {}:",
+        hits.len(),
+        display_path_for_cli(&run_dir)
+    );
+    for hit in &hits {
+        println!(
+            "- {} {} {}: {}",
+            hit.occurred_at.format("%Y-%m-%d"),
+            hit.kind,
+            hit.repo,
+            hit.title
+        );
+        if let Some(url) = &hit.url {
+            println!("  {url}");
+        }
+    }
+    Ok(())
+}
+
+/// An index is current when it exists and is no older than the ledger.
+fn search_index_is_current(index_path: &Path, ledger_path: &Path) -> bool {
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|meta| meta.modified());
+    match (modified(index_path), modified(ledger_path)) {
+        (Ok(index), Ok(ledger)) => index >= ledger,
+        _ => false,
+    }
+}
+
+fn build_run_search_index(run_dir: &Path) -> Result<SearchIndex> {
+    let paths = shiplog::bundle::RunArtifactPaths::new(run_dir);
+    let ledger_path = paths.ledger_events();
+    if !ledger_path.exists() {
+        anyhow::bail!(
+            "No ledger.events.jsonl found in {:?}. Run `shiplog collect` first.",
+            run_dir
+        );
+    }
+    let file = std::fs::File::open(&ledger_path)
+        .with_context(|| format!("open {}", ledger_path.display()))?;
+    let events = shiplog::engine::read_events(std::io::BufReader::new(file))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| format!("read {}", ledger_path.display()))?;
+    SearchIndex::build(paths.search_index(), &events)
+}
+
+fn run_validate(args: ValidateArgs) -> Result<()> {
+    if let Some(kind) = args.print_schema {
+        let schema = SchemaDocument::from(kind).schema();
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+        return Ok(());
+    }
+    if args.paths.is_empty() {
+        anyhow::bail!("pass at least one file or run directory, or --print-schema <KIND>");
+    }
+
+    let mut targets = Vec::new();
+    for path in &args.paths {
+        if path.is_dir() {
+            let found = [
+                "ledger.events.jsonl",
+                "coverage.manifest.json",
+                shiplog::workstreams::CURATED_FILENAME,
+                shiplog::workstreams::SUGGESTED_FILENAME,
+                "manual_events.yaml",
+            ]
+            .into_iter()
+            .map(|name| path.join(name))
+            .filter(|candidate| candidate.exists())
+            .filter_map(|candidate| SchemaDocument::infer(&candidate).map(|doc| (candidate, doc)))
+            .collect::<Vec<_>>();
+            if found.is_empty() {
+                anyhow::bail!("{} contains no shiplog files to validate", path.display());
+            }
+            targets.extend(found);
+        } else {
+            let doc = match args.kind {
+                Some(kind) => SchemaDocument::from(kind),
+                None => SchemaDocument::infer(path).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "cannot tell which schema applies to {}; pass --kind",
+                        path.display()
+                    )
+                })?,
+            };
+            targets.push((path.clone(), doc));
+        }
+    }
+
+    let mut failed = 0;
+    for (path, doc) in &targets {
+        let (records, problems) = validate_schema_file(path, *doc)?;
+        if problems.is_empty() {
+            println!("{}: ok ({doc}, {records} records)", path.display());
+        } else {
+            failed += 1;
+            println!("{}: {} problems ({doc})", path.display(), problems.len());
+            for problem in &problems {
+                println!("  - {problem}");
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!(
+            "{failed} of {} files failed schema validation",
+            targets.len()
+        );
+    }
+    Ok(())
+}
+
+/// Check one file, returning the record count and any problems found.
+fn validate_schema_file(path: &Path, doc: SchemaDocument) -> Result<(usize, Vec<String>)> {
+    if doc == SchemaDocument::EventEnvelope {
+        // Ledgers can be large; check them a line at a time.
+        let file = std::fs::File::open(path).with_context(|| format!("read {}", path.display()))?;
+        let mut records = 0;
+        let mut problems = Vec::new();
+        for (i, line) in std::io::BufReader::new(file).lines().enumerate() {
+            let line = line.with_context(|| format!("read {}", path.display()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            records += 1;
+            match serde_json::from_str::<serde_json::Value>(&line) {
+                Ok(value) => problems.extend(
+                    doc.validate(&value)
+                        .into_iter()
+                        .map(|violation| format!("line {}: {violation}", i + 1)),
+                ),
+                Err(err) => problems.push(format!("line {}: invalid JSON: {err}", i + 1)),
+            }
+        }
+        return Ok((records, problems));
+    }
+
+    let text = std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    let is_yaml = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+    let value = if is_yaml {
+        serde_yaml::from_str::<serde_json::Value>(&text)
+            .map_err(|err| format!("invalid YAML: {err}"))
+    } else {
+        serde_json::from_str::<serde_json::Value>(&text)
+            .map_err(|err| format!("invalid JSON: {err}"))
+    };
+    let problems = match value {
+        Ok(value) => doc
+            .validate(&value)
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        Err(problem) => vec![problem],
+    };
+    Ok((1, problems))
+}
+
+fn run_runs_migrate(run_dir: &Path, dry_run: bool) -> Result<()> {
+    let events_path = run_dir.join("ledger.events.jsonl");
+    let coverage_path = run_dir.join("coverage.manifest.json");
+    let events_file = std::fs::File::open(&events_path)
+        .with_context(|| format!("read {}", events_path.display()))?;
+    let events = shiplog::engine::read_events(std::io::BufReader::new(events_file))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| format!("parse {}", events_path.display()))?;
+    let coverage_text = std::fs::read_to_string(&coverage_path)
+        .with_context(|| format!("read {}", coverage_path.display()))?;
+    let coverage: CoverageManifest = serde_json::from_str(&coverage_text)
+        .map_err(|err| SchemaDocument::CoverageManifest.annotate_json(&coverage_text, err))
+        .with_context(|| format!("parse {}", coverage_path.display()))?;
+
+    let (events, report) = migrate::migrate_events(events)?;
+    let (coverage, coverage_from) = migrate::migrate_coverage(coverage)?;
+    let mut workstreams = Vec::new();
+    for path in [
+        shiplog::workstreams::WorkstreamManager::curated_path(run_dir),
+        shiplog::workstreams::WorkstreamManager::suggested_path(run_dir),
+    ] {
+        if !path.exists() {
+            continue;
+        }
+        let text =
+            std::fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+        let mut file: WorkstreamsFile = serde_yaml::from_str(&text)
+            .map_err(|err| SchemaDocument::WorkstreamsFile.annotate_yaml(&text, err))
+            .with_context(|| format!("parse {}", path.display()))?;
+        if migrate::migrate_workstreams(&mut file, &report)? {
+            workstreams.push((path, file));
+        }
+    }
+
+    let current = SchemaVersion::CURRENT;
+    println!("Run: {}", run_dir.display());
+    if report.is_noop() && coverage_from == current && workstreams.is_empty() {
+        println!("Migration: already current ({current})");
+        return Ok(());
+    }
+
+    let verb = if dry_run { "would migrate" } else { "migrated" };
+    match report.from {
+        Some(from) => println!(
+            "Ledger: {verb} {from} -> {current} ({} events, {} incidents promoted)",
+            report.events_upgraded,
+            report.promoted_incidents.len()
+        ),
+        None => println!("Ledger: already current ({current})"),
+    }
+    if coverage_from == current {
+        println!("Coverage: already current ({current})");
+    } else {
+        println!("Coverage: {verb} {coverage_from} -> {current}");
+    }
+    for (path, _) in &workstreams {
+        println!("Workstreams: {verb} {}", path.display());
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    let mut ledger = String::new();
+    for event in &events {
+        ledger.push_str(&serde_json::to_string(event).context("serialize event")?);
+        ledger.push('\n');
+    }
+    std::fs::write(&events_path, ledger)
+        .with_context(|| format!("write {}", events_path.display()))?;
+    std::fs::write(
+        &coverage_path,
+        serde_json::to_string_pretty(&coverage).context("serialize coverage")?,
+    )
+    .with_context(|| format!("write {}", coverage_path.display()))?;
+    for (path, file) in &workstreams {
+        shiplog::workstreams::write_workstreams(path, file)?;
+    }
+    println!("Run migrated; re-render to refresh packet.md");
+    Ok(())
+}
+
+fn run_periods_list(args: PeriodsArgs) -> Result<()> {
+    let config = load_config_for_command(&args.config)?;
+    ensure_supported_config_version(&config)?;
+    let base_dir = config_base_dir(&args.config);
+    let out_dir = periods_out_dir(args.out.as_deref(), &config, &base_dir);
+
+    println!("Configured periods:");
+    println!("Config: {}", args.config.display());
+    println!("Out: {}", out_dir.display());
+    if config.periods.is_empty() {
+        println!("- none");
+        return Ok(());
+    }
+
+    for name in config.periods.keys() {
+        let window = resolve_config_period(&config, name)?;
+        let source = period_definition_label(&config.periods[name]);
+        let latest = latest_period_run_summary(&out_dir, &window)?;
+        println!("- {name}");
+        println!("  - definition: {source}");
+        println!("  - window: {}..{}", window.since, window.until);
+        println!("  - label: {}", window.window_label());
+        if let Some(run) = latest {
+            println!("  - latest run: {}", run.run_id);
+            println!("  - packet: {}", run.packet_path.display());
+        } else {
+            println!("  - latest run: none");
+        }
+        println!(
+            "  - next: {}",
+            period_intake_command(&args.config, &out_dir, name)
+        );
+    }
+
+    Ok(())
+}
+
+fn run_periods_explain(args: PeriodExplainArgs) -> Result<()> {
+    let config = load_config_for_command(&args.config)?;
+    ensure_supported_config_version(&config)?;
+    let base_dir = config_base_dir(&args.config);
+    let out_dir = periods_out_dir(args.out.as_deref(), &config, &base_dir);
     let configured = config
         .periods
         .get(&args.name)
@@ -9605,6 +11805,7 @@ fn validate_shiplog_config(config: &ShiplogConfig, base_dir: &Path) -> Vec<Confi
         issues.push(config_issue("Window", err.to_string()));
     }
     validate_config_periods(config, &mut issues);
+    manual_templates::validate_config_templates(config, &mut issues);
     if let Err(err) = doctor_config_profile(config.defaults.profile.as_deref()) {
         issues.push(config_issue("Profile", err.to_string()));
     }
@@ -9625,10 +11826,95 @@ fn validate_shiplog_config(config: &ShiplogConfig, base_dir: &Path) -> Vec<Confi
     validate_config_git(config, base_dir, &mut issues);
     validate_config_json(config, base_dir, &mut issues);
     validate_config_manual(config, base_dir, &mut issues);
+    if let Err(err) = shiplog::bundle::parse_age_recipients(&config.bundle.age_recipients) {
+        issues.push(config_issue(
+            "Bundle",
+            format!("bundle.age_recipients: {err}"),
+        ));
+    }
+    if let Err(err) = config_bundle_scopes(&config.bundle, &config.redaction) {
+        issues.push(config_issue("Bundle", format!("{err:#}")));
+    }
+    if let Err(err) = config_bundle_max_part_bytes(&config.bundle) {
+        issues.push(config_issue("Bundle", err.to_string()));
+    }
+    if let Some(path) = &config.bundle.signing_key_file
+        && let Err(err) =
+            shiplog::bundle::BundleSigner::from_key_file(&resolve_config_path(base_dir, path))
+    {
+        issues.push(config_issue(
+            "Bundle",
+            format!("bundle.signing_key_file: {err:#}"),
+        ));
+    }
+    if let Err(err) = config_redaction_policies(&config.redaction) {
+        issues.push(config_issue("Redaction", format!("{err:#}")));
+    }
+    if let Some(path) = &config.render.template
+        && let Err(err) = load_packet_template(&resolve_config_path(base_dir, path))
+    {
+        issues.push(config_issue("Render", format!("{err:#}")));
+    }
+    validate_config_coverage(&config.coverage, &mut issues);
+    validate_config_notify(&config.notify, &mut issues);
+    for (key, value) in [
+        (
+            "http.max_requests_per_host",
+            config.http.max_requests_per_host,
+        ),
+        ("http.max_wall_clock_secs", config.http.max_wall_clock_secs),
+        ("http.max_retry_wait_secs", config.http.max_retry_wait_secs),
+    ] {
+        if value == Some(0) {
+            issues.push(config_issue("HTTP", format!("{key} must be at least 1")));
+        }
+    }
+    if let Err(err) = config_reporting_timezone(config) {
+        issues.push(config_issue("Timezone", format!("{err:#}")));
+    }
+    if let Some(expression) = non_empty_string(config.defaults.schedule.as_deref())
+        && let Err(err) = expression.parse::<Schedule>()
+    {
+        issues.push(config_issue(
+            "Schedule",
+            format!("defaults.schedule: {err:#}"),
+        ));
+    }
 
     issues
 }
 
+fn validate_config_coverage(coverage: &ConfigCoverage, issues: &mut Vec<ConfigIssue>) {
+    let scores = std::iter::once(("coverage.min_score".to_string(), coverage.min_score)).chain(
+        coverage.sources.iter().map(|(name, requirement)| {
+            (
+                format!("coverage.sources.{name}.min_score"),
+                requirement.min_score,
+            )
+        }),
+    );
+    for (key, score) in scores {
+        if let Some(score) = score.filter(|score| *score > 100) {
+            issues.push(config_issue(
+                "Coverage",
+                format!("{key} is {score}; scores range from 0 to 100"),
+            ));
+        }
+    }
+    for name in coverage.sources.keys() {
+        if !InitSource::ALL
+            .iter()
+            .any(|known| sources_match(known.as_str(), name))
+        {
+            let known = InitSource::ALL.map(InitSource::as_str).join(", ");
+            issues.push(config_issue(
+                "Coverage",
+                format!("coverage.sources.{name} is not a known source; use one of {known}"),
+            ));
+        }
+    }
+}
+
 fn validate_config_periods(config: &ShiplogConfig, issues: &mut Vec<ConfigIssue>) {
     for name in config.periods.keys() {
         if let Err(err) = resolve_config_period(config, name) {
@@ -10102,7 +12388,7 @@ fn config_default_out(config: &ShiplogConfig, base_dir: &Path) -> PathBuf {
         .as_ref()
         .filter(|path| !path.as_os_str().is_empty())
         .map(|path| resolve_config_path(base_dir, path))
-        .unwrap_or_else(|| resolve_config_path(base_dir, Path::new("./out")))
+        .unwrap_or_else(|| resolve_config_path(base_dir, Path::new(DEFAULT_OUT_DIR)))
 }
 
 fn config_redaction_key_env(config: &ShiplogConfig) -> String {
@@ -10339,7 +12625,7 @@ fn doctor_defaults(report: &mut DoctorReport, config: &ShiplogConfig, base_dir:
         .as_ref()
         .filter(|path| !path.as_os_str().is_empty())
         .map(|path| resolve_config_path(base_dir, path))
-        .unwrap_or_else(|| resolve_config_path(base_dir, Path::new("./out")));
+        .unwrap_or_else(|| resolve_config_path(base_dir, Path::new(DEFAULT_OUT_DIR)));
     match doctor_check_output_writable(&out) {
         Ok(()) => report.ok("Output", format!("{} writable", out.display())),
         Err(err) => report.error("Output", err.to_string()),
@@ -11081,8 +13367,9 @@ fn env_var_present(name: &str) -> bool {
 }
 
 fn resolve_multi_window(args: ConfigWindowArgs, config: &ShiplogConfig) -> Result<ResolvedWindow> {
+    let today = config_today(config);
     if date_args_has_any(&args.dates) {
-        return resolve_date_window(args.dates);
+        return resolve_date_window_for_today(args.dates, today);
     }
 
     if let Some(period) = non_empty_string(args.period.as_deref()) {
@@ -11090,10 +13377,52 @@ fn resolve_multi_window(args: ConfigWindowArgs, config: &ShiplogConfig) -> Resul
     }
 
     if let Some(window) = non_empty_string(config.defaults.window.as_deref()) {
-        return resolve_date_window(date_args_from_config_window(&window)?);
+        return resolve_date_window_for_today(date_args_from_config_window(&window)?, today);
     }
 
-    resolve_date_window(DateArgs::default())
+    resolve_date_window_for_today(DateArgs::default(), today)
+}
+
+/// Reporting timezone from `[defaults] timezone`, UTC when unset.
+fn config_reporting_timezone(config: &ShiplogConfig) -> Result<ReportingTimezone> {
+    match non_empty_string(config.defaults.timezone.as_deref()) {
+        Some(name) => name
+            .parse()
+            .with_context(|| "invalid defaults.timezone in shiplog.toml"),
+        None => Ok(ReportingTimezone::UTC),
+    }
+}
+
+/// Run-wide request budget from `[http]`; unset keys are unlimited.
+fn config_http_budget(config: &ShiplogConfig) -> HttpBudget {
+    HttpBudget::new(HttpBudgetLimits {
+        max_requests_per_host: config.http.max_requests_per_host,
+        max_wall_clock: config
+            .http
+            .max_wall_clock_secs
+            .map(std::time::Duration::from_secs),
+    })
+}
+
+/// Retry policy from `[http]`; unset keys keep the defaults.
+fn config_retry_policy(config: &ShiplogConfig) -> RetryPolicy {
+    let default = RetryPolicy::default();
+    RetryPolicy {
+        max_retries: config.http.max_retries.unwrap_or(default.max_retries),
+        max_delay: config
+            .http
+            .max_retry_wait_secs
+            .map_or(default.max_delay, std::time::Duration::from_secs),
+        ..default
+    }
+}
+
+/// Today's date in the configured reporting timezone. An invalid timezone
+/// falls back to UTC here; collection and `config validate` report it.
+fn config_today(config: &ShiplogConfig) -> NaiveDate {
+    config_reporting_timezone(config)
+        .unwrap_or_default()
+        .today()
 }
 
 fn resolve_config_period(config: &ShiplogConfig, period: &str) -> Result<ResolvedWindow> {
@@ -11119,8 +13448,11 @@ fn resolve_config_period(config: &ShiplogConfig, period: &str) -> Result<Resolve
     }
 
     if let Some(preset) = non_empty_string(configured.preset.as_deref()) {
-        return resolve_date_window(date_args_from_config_window(&preset)?)
-            .map(|window| window.with_period(period.to_string()));
+        return resolve_date_window_for_today(
+            date_args_from_config_window(&preset)?,
+            config_today(config),
+        )
+        .map(|window| window.with_period(period.to_string()));
     }
 
     anyhow::bail!("period {period:?} must set preset or since/until")
@@ -11220,6 +13552,12 @@ fn config_base_dir(config_path: &Path) -> PathBuf {
         .to_path_buf()
 }
 
+/// A dry run only reads an API cache that already exists, so planning never
+/// creates the output directory or an empty cache database.
+fn planned_cache_dir(cache_dir: Option<PathBuf>, dry_run: bool) -> Option<PathBuf> {
+    cache_dir.filter(|dir| !dry_run || dir.is_dir())
+}
+
 fn resolve_config_cache_dir(
     base_dir: &Path,
     out_root: &Path,
@@ -11229,22 +13567,46 @@ fn resolve_config_cache_dir(
     if no_cache {
         None
     } else {
-        optional_config_path(base_dir, explicit_cache_dir).or_else(|| Some(out_root.join(".cache")))
+        optional_config_path(base_dir, explicit_cache_dir).or_else(|| Some(api_cache_dir(out_root)))
     }
 }
 
+/// Record one source's outcome. A source that answered with data shiplog
+/// cannot parse fails the whole run instead of being skipped.
 fn push_configured_source_result(
     successes: &mut Vec<(String, IngestOutput)>,
     failures: &mut Vec<ConfiguredSourceFailure>,
     name: &str,
     result: Result<IngestOutput>,
-) {
+) -> Result<()> {
     match result {
-        Ok(ingest) => successes.push((name.to_string(), ingest)),
-        Err(err) => failures.push(ConfiguredSourceFailure {
-            name: name.to_string(),
-            error: format!("{err:#}"),
-        }),
+        Ok(ingest) => {
+            tracing::info!(
+                source = name,
+                events = ingest.events.len(),
+                "collected source"
+            );
+            successes.push((name.to_string(), ingest));
+        }
+        Err(err)
+            if ingest_error(&err)
+                .is_some_and(|ingest| ingest.action() == IngestErrorAction::FailRun) =>
+        {
+            return Err(err.context(format!("collect configured {name} source")));
+        }
+        Err(err) => {
+            tracing::info!(source = name, "skipped source: {err:#}");
+            failures.push(ConfiguredSourceFailure::from_error(name, &err));
+        }
+    }
+    Ok(())
+}
+
+fn ingest_error_repair_kind(err: &IngestError) -> Option<IntakeRepairKind> {
+    match err {
+        IngestError::Auth { .. } => Some(IntakeRepairKind::AuthRejected),
+        IngestError::RateLimited { .. } => Some(IntakeRepairKind::RateLimited),
+        IngestError::NotFound { .. } | IngestError::Parse { .. } | IngestError::Api { .. } => None,
     }
 }
 
@@ -11273,13 +13635,12 @@ fn write_source_failures_file(
         .iter()
         .map(|failure| SourceFailureRecord {
             source: failure.name.clone(),
-            kind: classify_intake_repair_kind(&failure.name, &failure.error)
-                .as_str()
-                .to_string(),
+            kind: failure.repair_kind().as_str().to_string(),
             reason: failure.error.clone(),
             recorded_at: generated_at.clone(),
             window: window.clone(),
             rerun_command: rerun_command.clone(),
+            retry_after_secs: failure.retry_after_secs,
         })
         .collect();
     let file = SourceFailuresFile {
@@ -11320,16 +13681,25 @@ fn source_failure_rerun_command(
     format!("shiplog intake --config {config_arg} {window_args} --explain")
 }
 
-fn collect_configured_sources(
+fn configured_ingestors(
     config_path: &Path,
     config: &ShiplogConfig,
-    window: ResolvedWindow,
+    window: &ResolvedWindow,
     out_root: &Path,
-) -> Result<ConfiguredSourceOutputs> {
+    dry_run: bool,
+) -> Result<Vec<ConfiguredIngestor>> {
     let base_dir = config_base_dir(config_path);
+    let cache_dir_for = |explicit: Option<&PathBuf>, no_cache: bool| {
+        planned_cache_dir(
+            resolve_config_cache_dir(&base_dir, out_root, explicit, no_cache),
+            dry_run,
+        )
+    };
     let default_include_reviews = config.defaults.include_reviews.unwrap_or(false);
-    let mut successes = Vec::new();
-    let mut failures = Vec::new();
+    let timezone = config_reporting_timezone(config)?;
+    let http_budget = config_http_budget(config);
+    let retry = config_retry_policy(config);
+    let mut ingestors = Vec::new();
 
     if let Some(source) = config
         .sources
@@ -11339,12 +13709,7 @@ fn collect_configured_sources(
     {
         let api_base = optional_config_string(source.api_base.as_deref())
             .unwrap_or_else(|| "https://api.github.com".to_string());
-        let cache_dir = resolve_config_cache_dir(
-            &base_dir,
-            out_root,
-            source.cache_dir.as_ref(),
-            source.no_cache,
-        );
+        let cache_dir = cache_dir_for(source.cache_dir.as_ref(), source.no_cache);
         let ing = resolve_github_credential(&api_base).and_then(|credential| {
             let user = resolve_user_or_me(
                 "GitHub",
@@ -11366,9 +13731,15 @@ fn collect_configured_sources(
                 cache_dir,
             )
             .context("create configured GitHub ingestor")
-            .and_then(|ing| ing.ingest().context("collect configured GitHub source"))
+            .map(|ing| {
+                Box::new(
+                    ing.with_timezone(timezone)
+                        .with_http_budget(http_budget.clone())
+                        .with_retry_policy(retry),
+                ) as Box<dyn Ingestor>
+            })
         });
-        push_configured_source_result(&mut successes, &mut failures, "github", ing);
+        ingestors.push(ConfiguredIngestor::new("github", "GitHub", ing));
     }
 
     if let Some(source) = config
@@ -11385,12 +13756,7 @@ fn collect_configured_sources(
             source.me,
             || discover_gitlab_user(&instance, None),
         )?;
-        let cache_dir = resolve_config_cache_dir(
-            &base_dir,
-            out_root,
-            source.cache_dir.as_ref(),
-            source.no_cache,
-        );
+        let cache_dir = cache_dir_for(source.cache_dir.as_ref(), source.no_cache);
         let ing = make_gitlab_ingestor(
             &user,
             window.since,
@@ -11403,8 +13769,14 @@ fn collect_configured_sources(
             cache_dir,
         )
         .context("create configured GitLab ingestor")
-        .and_then(|ing| ing.ingest().context("collect configured GitLab source"));
-        push_configured_source_result(&mut successes, &mut failures, "gitlab", ing);
+        .map(|ing| {
+            Box::new(
+                ing.with_timezone(timezone)
+                    .with_http_budget(http_budget.clone())
+                    .with_retry_policy(retry),
+            ) as Box<dyn Ingestor>
+        });
+        ingestors.push(ConfiguredIngestor::new("gitlab", "GitLab", ing));
     }
 
     if let Some(source) = config.sources.jira.as_ref().filter(|source| source.enabled) {
@@ -11417,12 +13789,7 @@ fn collect_configured_sources(
                 .and_then(|env_var| non_empty_string(Some(env_var)))
                 .and_then(|env_var| std::env::var(env_var).ok())
         });
-        let cache_dir = resolve_config_cache_dir(
-            &base_dir,
-            out_root,
-            source.cache_dir.as_ref(),
-            source.no_cache,
-        );
+        let cache_dir = cache_dir_for(source.cache_dir.as_ref(), source.no_cache);
         let ing = make_jira_ingestor(
             &user,
             auth_user,
@@ -11435,8 +13802,14 @@ fn collect_configured_sources(
             cache_dir,
         )
         .context("create configured Jira ingestor")
-        .and_then(|ing| ing.ingest().context("collect configured Jira source"));
-        push_configured_source_result(&mut successes, &mut failures, "jira", ing);
+        .map(|ing| {
+            Box::new(
+                ing.with_timezone(timezone)
+                    .with_http_budget(http_budget.clone())
+                    .with_retry_policy(retry),
+            ) as Box<dyn Ingestor>
+        });
+        ingestors.push(ConfiguredIngestor::new("jira", "Jira", ing));
     }
 
     if let Some(source) = config
@@ -11447,12 +13820,7 @@ fn collect_configured_sources(
     {
         let user_id = required_config_string("linear", "user_id", source.user_id.as_deref())?;
         let project = optional_config_string(source.project.as_deref());
-        let cache_dir = resolve_config_cache_dir(
-            &base_dir,
-            out_root,
-            source.cache_dir.as_ref(),
-            source.no_cache,
-        );
+        let cache_dir = cache_dir_for(source.cache_dir.as_ref(), source.no_cache);
         let ing = make_linear_ingestor(
             &user_id,
             window.since,
@@ -11464,22 +13832,27 @@ fn collect_configured_sources(
             cache_dir,
         )
         .context("create configured Linear ingestor")
-        .and_then(|ing| ing.ingest().context("collect configured Linear source"));
-        push_configured_source_result(&mut successes, &mut failures, "linear", ing);
+        .map(|ing| {
+            Box::new(
+                ing.with_timezone(timezone)
+                    .with_http_budget(http_budget.clone())
+                    .with_retry_policy(retry),
+            ) as Box<dyn Ingestor>
+        });
+        ingestors.push(ConfiguredIngestor::new("linear", "Linear", ing));
     }
 
     if let Some(source) = config.sources.git.as_ref().filter(|source| source.enabled) {
         let repo = required_config_path(&base_dir, "git", "repo", source.repo.as_ref())?;
-        let result = make_git_ingestor(
+        let ing = make_git_ingestor(
             &repo,
             window.since,
             window.until,
             optional_config_string(source.author.as_deref()),
             source.include_merges,
         )
-        .ingest()
-        .context("collect configured git source");
-        push_configured_source_result(&mut successes, &mut failures, "git", result);
+        .with_timezone(timezone);
+        ingestors.push(ConfiguredIngestor::new("git", "git", Ok(Box::new(ing))));
     }
 
     if let Some(source) = config.sources.json.as_ref().filter(|source| source.enabled) {
@@ -11490,8 +13863,7 @@ fn collect_configured_sources(
             events_path: events,
             coverage_path: coverage,
         };
-        let result = ing.ingest().context("collect configured JSON source");
-        push_configured_source_result(&mut successes, &mut failures, "json", result);
+        ingestors.push(ConfiguredIngestor::new("json", "JSON", Ok(Box::new(ing))));
     }
 
     if let Some(source) = config
@@ -11505,16 +13877,38 @@ fn collect_configured_sources(
             .or_else(|| optional_config_string(config.user.label.as_deref()))
             .unwrap_or_else(|| "user".to_string());
         let ing = ManualIngestor::new(&events, user, window.since, window.until);
-        let result = ing.ingest().context("collect configured manual source");
-        push_configured_source_result(&mut successes, &mut failures, "manual", result);
+        ingestors.push(ConfiguredIngestor::new(
+            "manual",
+            "manual",
+            Ok(Box::new(ing)),
+        ));
     }
 
-    if successes.is_empty() && failures.is_empty() {
+    if ingestors.is_empty() {
         anyhow::bail!(
             "No enabled sources found in {}; enable at least one [sources.<name>] section",
             config_path.display()
         );
     }
+    Ok(ingestors)
+}
+
+fn collect_configured_sources(
+    config_path: &Path,
+    config: &ShiplogConfig,
+    window: ResolvedWindow,
+    out_root: &Path,
+) -> Result<ConfiguredSourceOutputs> {
+    let mut successes = Vec::new();
+    let mut failures = Vec::new();
+    for configured in configured_ingestors(config_path, config, &window, out_root, false)? {
+        let label = configured.label;
+        let result = configured.ingestor.and_then(|ing| {
+            ing.ingest()
+                .with_context(|| format!("collect configured {label} source"))
+        });
+        push_configured_source_result(&mut successes, &mut failures, configured.name, result)?;
+    }
 
     if successes.is_empty() {
         let failed = failures
@@ -11563,6 +13957,7 @@ fn run_configured_multi_pipeline(
         since: window.since,
         until: window.until,
     };
+    merged.coverage.timezone = config_reporting_timezone(config)?.manifest_name();
     let skipped_sources =
         configured_skipped_source_records(&configured.failures, source_explanations);
     if !skipped_sources.is_empty() {
@@ -11639,6 +14034,185 @@ fn run_configured_multi_pipeline(
     })
 }
 
+/// Check a configured run against the `[coverage]` requirements.
+///
+/// The run-level requirement applies to the merged manifest; per-source
+/// requirements apply to each source's own coverage. A source with a
+/// requirement that was skipped or not collected never meets it.
+fn configured_coverage_shortfalls(
+    config: &ShiplogConfig,
+    result: &ConfiguredRunResult,
+) -> Result<Vec<CoverageShortfall>> {
+    if config.coverage.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let run_coverage = load_coverage_manifest(&result.outputs.out_dir)?;
+    let mut shortfalls = check_requirement(None, &run_coverage, &config.coverage.run_requirement());
+    for (name, requirement) in &config.coverage.sources {
+        if requirement.is_empty() {
+            continue;
+        }
+        let collected = result
+            .configured
+            .successes
+            .iter()
+            .find(|(source, _)| sources_match(source, name));
+        if let Some((source, ingest)) = collected {
+            shortfalls.extend(check_requirement(
+                Some(source),
+                &ingest.coverage,
+                requirement,
+            ));
+            continue;
+        }
+        let reason = result
+            .configured
+            .failures
+            .iter()
+            .find(|failure| sources_match(&failure.name, name))
+            .map(|failure| format!("skipped ({}), so coverage cannot be checked", failure.error))
+            .unwrap_or_else(|| "not collected in this run".to_string());
+        shortfalls.push(CoverageShortfall {
+            source: Some(name.clone()),
+            reason,
+        });
+    }
+    Ok(shortfalls)
+}
+
+/// Report unmet `[coverage]` requirements and exit with
+/// [`COVERAGE_REQUIREMENTS_EXIT_CODE`]. Returns normally when every
+/// requirement is met. Run artifacts are already written either way.
+fn enforce_coverage_requirements(
+    config: &ShiplogConfig,
+    result: &ConfiguredRunResult,
+) -> Result<()> {
+    let shortfalls = configured_coverage_shortfalls(config, result)?;
+    if shortfalls.is_empty() {
+        return Ok(());
+    }
+
+    std::io::Write::flush(&mut std::io::stdout()).ok();
+    eprintln!();
+    eprintln!("Coverage requirements not met:");
+    for shortfall in &shortfalls {
+        let scope = shortfall
+            .source
+            .as_deref()
+            .map(display_source_label)
+            .unwrap_or_else(|| "Run".to_string());
+        eprintln!("- {scope}: {}", shortfall.reason);
+    }
+    eprintln!(
+        "Artifacts were written, but this packet should not be published as-is. \
+         See {} for what was missed.",
+        display_path_for_cli(&result.outputs.coverage_report_md)
+    );
+    std::process::exit(COVERAGE_REQUIREMENTS_EXIT_CODE);
+}
+
+/// Send the run summary to every `[notify]` target. Delivery problems are
+/// reported as warnings; they never fail the run.
+fn notify_configured_run(config: &ShiplogConfig, result: &ConfiguredRunResult) {
+    let targets = config_notifiers(&config.notify);
+    if targets.is_empty() {
+        return;
+    }
+    let summary = match configured_run_notification(result) {
+        Ok(summary) => summary,
+        Err(err) => {
+            tracing::warn!("notifications skipped: {err:#}");
+            return;
+        }
+    };
+    for (name, notifier) in targets {
+        match notifier.and_then(|notifier| notifier.send(&summary)) {
+            Ok(()) => println!("Notified: {name}"),
+            Err(err) => tracing::warn!("{name} notification failed: {err:#}"),
+        }
+    }
+}
+
+fn configured_run_notification(result: &ConfiguredRunResult) -> Result<RunNotification> {
+    let out_dir = &result.outputs.out_dir;
+    let ingest =
+        load_run_ingest(out_dir).with_context(|| format!("load run {}", out_dir.display()))?;
+    let (workstreams, _, _) = load_effective_workstreams_for_run(out_dir)?;
+    let readiness = assess_readiness(&workstreams, &ingest.events, &ingest.coverage);
+    Ok(RunNotification {
+        run_id: result.run_id.clone(),
+        events: ingest.events.len(),
+        warnings: ingest.coverage.warnings,
+        readiness_score: readiness.score,
+        readiness_grade: readiness.grade,
+        packet: display_path_for_cli(&result.outputs.packet_md),
+    })
+}
+
+type ConfiguredNotifier = (&'static str, Result<Box<dyn Notifier>>);
+
+fn config_notifiers(notify: &ConfigNotify) -> Vec<ConfiguredNotifier> {
+    let mut targets: Vec<ConfiguredNotifier> = Vec::new();
+    if let Some(slack) = &notify.slack {
+        targets.push(("slack", config_slack_notifier(slack)));
+    }
+    if let Some(email) = &notify.email {
+        targets.push((
+            "email",
+            config_email_notifier(email)
+                .and_then(|notifier| with_smtp_password(notifier, email))
+                .map(|notifier| Box::new(notifier) as Box<dyn Notifier>),
+        ));
+    }
+    targets
+}
+
+fn config_slack_notifier(slack: &ConfigNotifySlack) -> Result<Box<dyn Notifier>> {
+    let env_var = optional_config_string(slack.webhook_url_env.as_deref())
+        .unwrap_or_else(|| DEFAULT_SLACK_WEBHOOK_ENV.to_string());
+    let url = std::env::var(&env_var)
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("{env_var} is not set"))?;
+    Ok(Box::new(SlackWebhook::new(url)?))
+}
+
+/// Build the email notifier from config alone; credentials are added by
+/// [`with_smtp_password`] so validation does not need the secret.
+fn config_email_notifier(email: &ConfigNotifyEmail) -> Result<SmtpEmail> {
+    let host = optional_config_string(email.smtp_host.as_deref())
+        .ok_or_else(|| anyhow::anyhow!("notify.email.smtp_host is required"))?;
+    let from = optional_config_string(email.from.as_deref())
+        .ok_or_else(|| anyhow::anyhow!("notify.email.from is required"))?;
+    let mut notifier = SmtpEmail::new(host, &from, &email.to)?;
+    if let Some(security) = optional_config_string(email.security.as_deref()) {
+        notifier = notifier.with_security(security.parse::<SmtpSecurity>()?);
+    }
+    if let Some(port) = email.smtp_port {
+        notifier = notifier.with_port(port);
+    }
+    Ok(notifier)
+}
+
+fn with_smtp_password(notifier: SmtpEmail, email: &ConfigNotifyEmail) -> Result<SmtpEmail> {
+    let Some(username) = optional_config_string(email.username.as_deref()) else {
+        return Ok(notifier);
+    };
+    let env_var = optional_config_string(email.password_env.as_deref())
+        .unwrap_or_else(|| DEFAULT_SMTP_PASSWORD_ENV.to_string());
+    let password = std::env::var(&env_var).map_err(|_| anyhow::anyhow!("{env_var} is not set"))?;
+    Ok(notifier.with_credentials(username, password))
+}
+
+fn validate_config_notify(notify: &ConfigNotify, issues: &mut Vec<ConfigIssue>) {
+    if let Some(email) = &notify.email
+        && let Err(err) = config_email_notifier(email)
+    {
+        issues.push(config_issue("Notify", format!("{err:#}")));
+    }
+}
+
 fn configured_skipped_source_records<'a>(
     failures: &'a [ConfiguredSourceFailure],
     explanations: &'a [IntakeSourceExplanation],
@@ -11712,6 +14286,16 @@ fn preserve_prior_curated_workstreams(out: &Path, run_dir: &Path) -> Result<Opti
             source_path, destination_path
         )
     })?;
+    let source_annotations = shiplog::annotate::annotations_path(&source_run_dir);
+    let destination_annotations = shiplog::annotate::annotations_path(run_dir);
+    if source_annotations.exists() && !destination_annotations.exists() {
+        std::fs::copy(&source_annotations, &destination_annotations).with_context(|| {
+            format!(
+                "copy prior annotations from {:?} to {:?}",
+                source_annotations, destination_annotations
+            )
+        })?;
+    }
 
     Ok(Some(PriorCuration {
         source_run_dir,
@@ -12090,41 +14674,232 @@ fn normalize_jira_instance(instance: &str) -> Result<String> {
     Ok(instance.trim().trim_end_matches('/').to_string())
 }
 
-fn linear_graphql_url() -> &'static str {
-    "https://api.linear.app/graphql"
+fn linear_graphql_url() -> &'static str {
+    "https://api.linear.app/graphql"
+}
+
+fn gitlab_api_base(instance: &str) -> Result<String> {
+    if instance.trim().is_empty() {
+        anyhow::bail!("GitLab instance cannot be empty");
+    }
+
+    let hostname = if instance.contains("://") {
+        reqwest::Url::parse(instance)
+            .ok()
+            .and_then(|u| u.host_str().map(|s| s.to_string()))
+            .ok_or_else(|| anyhow::anyhow!("Invalid GitLab instance URL: {instance}"))?
+    } else {
+        instance.to_string()
+    };
+
+    Ok(format!("https://{hostname}/api/v4"))
+}
+
+fn identity_client() -> Result<Client> {
+    Client::builder()
+        .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .context("build identity discovery HTTP client")
+}
+
+/// Apply `[bundle]` config settings (age recipients) to an engine.
+fn configure_engine_bundle<'a>(
+    engine: Engine<'a>,
+    config_path: &Path,
+    config: &ShiplogConfig,
+) -> Result<Engine<'a>> {
+    let recipients = &config.bundle.age_recipients;
+    shiplog::bundle::parse_age_recipients(recipients).context("read bundle.age_recipients")?;
+    let scopes = config_bundle_scopes(&config.bundle, &config.redaction)?;
+    let config_sha256 = std::fs::read(config_path)
+        .ok()
+        .map(|bytes| hex::encode(Sha256::digest(&bytes)));
+    let render_pdf = engine.render_pdf || config.bundle.pdf;
+    let mut engine = engine.with_secret_scanner(config_secret_scanner(&config.redaction)?);
+    if let Some(template) = config_packet_template(config_path, config)? {
+        let renderer = Box::new(cli_packet_renderer().with_template(template));
+        engine.renderer = Box::leak(renderer);
+    }
+    Ok(engine
+        .with_pdf_rendering(render_pdf)
+        .with_bundle_recipients(recipients.clone())
+        .with_bundle_scopes(scopes)
+        .with_custom_profiles(config_custom_profiles(&config.redaction))
+        .with_bundle_signer(config_bundle_signer(config_path, Some(config))?)
+        .with_config_digest(config_sha256)
+        .with_bundle_max_part_bytes(config_bundle_max_part_bytes(&config.bundle)?))
+}
+
+/// Key that signs bundle manifests: `SHIPLOG_BUNDLE_SIGNING_KEY`, else
+/// `bundle.signing_key_file` resolved next to the config.
+fn config_bundle_signer(
+    config_path: &Path,
+    config: Option<&ShiplogConfig>,
+) -> Result<Option<shiplog::bundle::BundleSigner>> {
+    if let Ok(key) = std::env::var(shiplog::bundle::SIGNING_KEY_ENV)
+        && !key.trim().is_empty()
+    {
+        return shiplog::bundle::BundleSigner::from_hex(&key)
+            .with_context(|| format!("read {}", shiplog::bundle::SIGNING_KEY_ENV))
+            .map(Some);
+    }
+    let Some(path) = config.and_then(|config| config.bundle.signing_key_file.as_ref()) else {
+        return Ok(None);
+    };
+    let path = resolve_config_path(&config_base_dir(config_path), path);
+    shiplog::bundle::BundleSigner::from_key_file(&path)
+        .context("read bundle.signing_key_file")
+        .map(Some)
+}
+
+/// Load and check the `[render] template` file, resolved next to the config.
+fn config_packet_template(
+    config_path: &Path,
+    config: &ShiplogConfig,
+) -> Result<Option<PacketTemplate>> {
+    let Some(path) = &config.render.template else {
+        return Ok(None);
+    };
+    load_packet_template(&resolve_config_path(&config_base_dir(config_path), path)).map(Some)
+}
+
+fn load_packet_template(path: &Path) -> Result<PacketTemplate> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("read render.template {}", path.display()))?;
+    PacketTemplate::new(source).with_context(|| format!("render.template {}", path.display()))
+}
+
+fn config_bundle_max_part_bytes(bundle: &ConfigBundle) -> Result<Option<u64>> {
+    match bundle.max_part_mb {
+        Some(0) => anyhow::bail!("bundle.max_part_mb must be greater than zero"),
+        Some(mb) => Ok(Some(mb.saturating_mul(1024 * 1024))),
+        None => Ok(None),
+    }
+}
+
+fn config_bundle_scopes(
+    bundle: &ConfigBundle,
+    redaction: &ConfigRedaction,
+) -> Result<shiplog::bundle::BundleScopes> {
+    let custom_profiles = config_custom_profiles(redaction);
+    let mut scopes = shiplog::bundle::BundleScopes::default();
+    for (name, scope) in &bundle.profiles {
+        let profile: BundleProfile = name
+            .parse()
+            .map_err(|err: String| anyhow::anyhow!("bundle.profiles.{name}: {err}"))?;
+        for pattern in scope.include.iter().chain(&scope.exclude) {
+            validate_bundle_scope_pattern(pattern)
+                .with_context(|| format!("bundle.profiles.{name}"))?;
+        }
+        match profile {
+            BundleProfile::Internal => scopes.internal = Some(scope.clone()),
+            BundleProfile::Manager => scopes.manager = Some(scope.clone()),
+            BundleProfile::Public => scopes.public = Some(scope.clone()),
+            BundleProfile::Custom(name) if custom_profiles.contains(&name) => {
+                scopes.custom.insert(name, scope.clone());
+            }
+            BundleProfile::Custom(name) => anyhow::bail!(
+                "bundle.profiles.{name}: unknown bundle profile: {name:?} (declare it under \
+                 [redaction.profiles.{name}])"
+            ),
+        }
+    }
+    Ok(scopes)
+}
+
+/// Parse `[redaction.profiles.<profile>]` tables: field overrides for the
+/// built-in profiles, and custom profiles for any other name.
+fn config_redaction_policies(
+    redaction: &ConfigRedaction,
+) -> Result<Vec<(BundleProfile, RedactionPolicy)>> {
+    redaction
+        .profiles
+        .iter()
+        .map(|(name, policy)| {
+            let profile: BundleProfile = name
+                .parse()
+                .map_err(|err: String| anyhow::anyhow!("redaction.profiles.{name}: {err}"))?;
+            if !profile.is_custom() && policy.base.is_some() {
+                anyhow::bail!("redaction.profiles.{name}: base is only allowed on custom profiles");
+            }
+            policy
+                .validate()
+                .with_context(|| format!("redaction.profiles.{name}"))?;
+            Ok((profile, policy.clone()))
+        })
+        .collect()
+}
+
+/// Names of the custom profiles declared under `[redaction.profiles]`.
+fn config_custom_profiles(redaction: &ConfigRedaction) -> Vec<String> {
+    redaction
+        .profiles
+        .keys()
+        .filter(|name| {
+            name.parse::<BundleProfile>()
+                .is_ok_and(|profile| profile.is_custom())
+        })
+        .cloned()
+        .collect()
+}
+
+/// Pre-bundle secret scanner that also flags `[redaction] internal_hosts`.
+fn config_secret_scanner(redaction: &ConfigRedaction) -> Result<SecretScanner> {
+    SecretScanner::new(&redaction.internal_hosts)
+        .context("build [redaction] internal_hosts matcher")
 }
 
-fn gitlab_api_base(instance: &str) -> Result<String> {
-    if instance.trim().is_empty() {
-        anyhow::bail!("GitLab instance cannot be empty");
+/// Redactor for CLI runs: the `[redaction.profiles]` overrides and custom
+/// profiles from config, and aliases shared across the output root when
+/// `[redaction] shared_aliases` is on.
+fn cli_redactor(redact_key: &str, redaction: &ConfigRedaction) -> Result<DeterministicRedactor> {
+    let mut redactor = DeterministicRedactor::new(redact_key.as_bytes())
+        .with_shared_cache(redaction.shared_aliases);
+    for (profile, policy) in config_redaction_policies(redaction)? {
+        redactor = match profile {
+            BundleProfile::Custom(name) => redactor.with_custom_profile(name, policy),
+            builtin => {
+                redactor.with_policy(RedactionProfile::from_profile_str(builtin.as_str()), policy)
+            }
+        };
     }
-
-    let hostname = if instance.contains("://") {
-        reqwest::Url::parse(instance)
-            .ok()
-            .and_then(|u| u.host_str().map(|s| s.to_string()))
-            .ok_or_else(|| anyhow::anyhow!("Invalid GitLab instance URL: {instance}"))?
-    } else {
-        instance.to_string()
-    };
-
-    Ok(format!("https://{hostname}/api/v4"))
+    Ok(redactor)
 }
 
-fn identity_client() -> Result<Client> {
-    Client::builder()
-        .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
-        .build()
-        .context("build identity discovery HTTP client")
+fn validate_bundle_scope_pattern(pattern: &str) -> Result<()> {
+    if pattern.trim().is_empty() {
+        anyhow::bail!("bundle scope patterns must not be empty");
+    }
+    if pattern.starts_with('/')
+        || pattern.contains('\\')
+        || pattern.split('/').any(|segment| segment == "..")
+    {
+        anyhow::bail!(
+            "bundle scope pattern {pattern:?} must be a run-relative path with forward slashes"
+        );
+    }
+    Ok(())
 }
 
 fn create_engine(
     redact_key: &str,
     clusterer: Box<dyn shiplog::ports::WorkstreamClusterer>,
     bundle_profile: &BundleProfile,
+) -> (Engine<'static>, &'static DeterministicRedactor) {
+    create_engine_with_redactor(
+        DeterministicRedactor::new(redact_key.as_bytes()),
+        clusterer,
+        bundle_profile,
+    )
+}
+
+fn create_engine_with_redactor(
+    redactor: DeterministicRedactor,
+    clusterer: Box<dyn shiplog::ports::WorkstreamClusterer>,
+    bundle_profile: &BundleProfile,
 ) -> (Engine<'static>, &'static DeterministicRedactor) {
     create_engine_with_renderer(
-        redact_key,
+        redactor,
         clusterer,
         Box::new(ModeMarkdownRenderer::new(
             RenderPacketMode::Packet,
@@ -12134,12 +14909,10 @@ fn create_engine(
 }
 
 fn create_engine_with_renderer(
-    redact_key: &str,
+    redactor: DeterministicRedactor,
     clusterer: Box<dyn shiplog::ports::WorkstreamClusterer>,
     renderer: Box<dyn Renderer>,
 ) -> (Engine<'static>, &'static DeterministicRedactor) {
-    let redactor = DeterministicRedactor::new(redact_key.as_bytes());
-
     // We need to leak these to give them 'static lifetime
     // This is acceptable for a CLI tool that runs once
     let renderer: &'static dyn Renderer = Box::leak(renderer);
@@ -12149,7 +14922,7 @@ fn create_engine_with_renderer(
     let redactor_trait: &'static dyn shiplog::ports::Redactor = redactor_ref;
 
     (
-        Engine::new(renderer, clusterer, redactor_trait),
+        Engine::new(renderer, clusterer, redactor_trait).with_run_index(true),
         redactor_ref,
     )
 }
@@ -12168,6 +14941,13 @@ impl ModeMarkdownRenderer {
             inner: cli_packet_renderer(),
         }
     }
+
+    fn with_template(mut self, template: Option<PacketTemplate>) -> Self {
+        if let Some(template) = template {
+            self.inner = self.inner.with_template(template);
+        }
+        self
+    }
 }
 
 fn cli_packet_renderer() -> MarkdownRenderer {
@@ -12188,7 +14968,49 @@ struct RenderExistingArgs<'a> {
     mode: RenderPacketMode,
     receipt_limit: Option<usize>,
     appendix: Option<RenderAppendixMode>,
+    heatmap: bool,
+    min_pr_size: Option<PrSizeArg>,
     zip: bool,
+    config: &'a Path,
+    acknowledge_pii: bool,
+    allow_secret_findings: bool,
+    pdf: bool,
+}
+
+/// Warn about likely PII in manual event text before share profiles are
+/// rendered. With `[redaction] block_public_on_pii`, findings stop the render
+/// until the user passes `--acknowledge-pii`.
+fn check_manual_event_pii(
+    config_path: &Path,
+    events: &[EventEnvelope],
+    acknowledged: bool,
+) -> Result<()> {
+    let redaction = if config_path.exists() {
+        load_shiplog_config(config_path)?.redaction
+    } else {
+        ConfigRedaction::default()
+    };
+    let findings = PiiScanner::new(&redaction.pii_terms)
+        .context("build [redaction] pii_terms matcher")?
+        .scan(events);
+    if findings.is_empty() {
+        return Ok(());
+    }
+
+    eprintln!("Possible PII in manual events:");
+    for finding in &findings {
+        eprintln!("- {finding}");
+    }
+    if redaction.block_public_on_pii && !acknowledged {
+        anyhow::bail!(
+            "share profiles not rendered: {} possible PII finding(s) in manual events. \
+             Edit manual_events.yaml and collect again, or rerun with --acknowledge-pii \
+             once you have reviewed them",
+            findings.len()
+        );
+    }
+    eprintln!("Review these before sharing manager or public packets.");
+    Ok(())
 }
 
 fn cli_render_options(
@@ -12203,6 +15025,8 @@ fn cli_render_options(
         appendix_mode: appendix
             .unwrap_or_else(|| default_appendix_for_profile(mode, bundle_profile))
             .into(),
+        heatmap: false,
+        min_pr_size: None,
     }
 }
 
@@ -12212,7 +15036,9 @@ fn default_receipt_limit_for_profile(
 ) -> usize {
     match (mode, bundle_profile) {
         (RenderPacketMode::Packet, BundleProfile::Manager) => MANAGER_RECEIPT_RENDER_LIMIT,
-        (RenderPacketMode::Packet, BundleProfile::Public) => PUBLIC_RECEIPT_RENDER_LIMIT,
+        (RenderPacketMode::Packet, BundleProfile::Public | BundleProfile::Custom(_)) => {
+            PUBLIC_RECEIPT_RENDER_LIMIT
+        }
         _ => WORKSTREAM_RECEIPT_RENDER_LIMIT,
     }
 }
@@ -12224,7 +15050,7 @@ fn default_appendix_for_profile(
     match mode {
         RenderPacketMode::Packet => match bundle_profile {
             BundleProfile::Internal | BundleProfile::Manager => RenderAppendixMode::Summary,
-            BundleProfile::Public => RenderAppendixMode::None,
+            BundleProfile::Public | BundleProfile::Custom(_) => RenderAppendixMode::None,
         },
         RenderPacketMode::Scaffold => RenderAppendixMode::None,
         RenderPacketMode::Receipts => RenderAppendixMode::Full,
@@ -12233,18 +15059,35 @@ fn default_appendix_for_profile(
 
 fn render_existing_run(args: RenderExistingArgs<'_>) -> Result<shiplog::engine::RunOutputs> {
     let clusterer: Box<dyn shiplog::ports::WorkstreamClusterer> = Box::new(RepoClusterer);
-    let renderer = Box::new(ModeMarkdownRenderer::new(
+    let mut options = cli_render_options(
         args.mode,
-        cli_render_options(
-            args.mode,
-            args.receipt_limit,
-            args.appendix,
-            &args.bundle_profile,
-        ),
-    ));
-    let (engine, redactor) =
-        create_engine_with_renderer(args.redaction_key.engine_key(), clusterer, renderer);
-    let engine = engine.with_profile_rendering(args.redaction_key.render_profiles());
+        args.receipt_limit,
+        args.appendix,
+        &args.bundle_profile,
+    );
+    options.heatmap = args.heatmap;
+    options.min_pr_size = args.min_pr_size.map(PrSize::from);
+    let config = if args.config.exists() {
+        Some(load_shiplog_config(args.config)?)
+    } else {
+        None
+    };
+    let template = match &config {
+        Some(config) => config_packet_template(args.config, config)?,
+        None => None,
+    };
+    let bundle_signer = config_bundle_signer(args.config, config.as_ref())?;
+    let redaction = config.map(|config| config.redaction).unwrap_or_default();
+    let redactor = cli_redactor(args.redaction_key.engine_key(), &redaction)?;
+    let renderer = Box::new(ModeMarkdownRenderer::new(args.mode, options).with_template(template));
+    let (engine, redactor) = create_engine_with_renderer(redactor, clusterer, renderer);
+    let engine = engine
+        .with_profile_rendering(args.redaction_key.render_profiles())
+        .with_custom_profiles(config_custom_profiles(&redaction))
+        .with_pdf_rendering(args.pdf)
+        .with_bundle_signer(bundle_signer)
+        .with_secret_scanner(config_secret_scanner(&redaction)?)
+        .with_secret_findings_allowed(args.allow_secret_findings);
 
     let run_dir = resolve_render_run_dir(args.out, args.run, args.latest)?;
     let events_path = run_dir.join("ledger.events.jsonl");
@@ -12262,6 +15105,9 @@ fn render_existing_run(args: RenderExistingArgs<'_>) -> Result<shiplog::engine::
         coverage_path,
     };
     let ingest = ing.ingest().context("ingest events")?;
+    if args.redaction_key.render_profiles() {
+        check_manual_event_pii(args.config, &ingest.events, args.acknowledge_pii)?;
+    }
     let render_user = args
         .user
         .map(str::to_string)
@@ -12333,53 +15179,110 @@ impl Renderer for ModeMarkdownRenderer {
 }
 
 fn build_clusterer(
+    strategy: ClusterStrategy,
     llm_cluster: bool,
+    summarize: Option<SummarizeMode>,
     llm_api_endpoint: &str,
     llm_model: &str,
     llm_api_key: Option<String>,
+    redact_key: &str,
 ) -> Box<dyn shiplog::ports::WorkstreamClusterer> {
-    if llm_cluster {
+    let clusterer: Box<dyn shiplog::ports::WorkstreamClusterer> = if llm_cluster {
         #[cfg(feature = "llm")]
         {
-            eprintln!(
-                "WARN: --llm-cluster sends event summaries (PR titles, repo names) to {llm_api_endpoint}"
+            tracing::warn!(
+                "--llm-cluster sends event summaries (PR titles, repo names) to {llm_api_endpoint}"
             );
-            let api_key = llm_api_key
-                .or_else(|| std::env::var("SHIPLOG_LLM_API_KEY").ok())
-                .unwrap_or_else(|| {
-                    eprintln!("ERROR: --llm-cluster requires --llm-api-key or SHIPLOG_LLM_API_KEY");
-                    std::process::exit(1);
-                });
-
-            let backend = shiplog::cluster_llm::OpenAiCompatibleBackend {
-                endpoint: llm_api_endpoint.to_string(),
-                api_key,
-                model: llm_model.to_string(),
-                temperature: 0.2,
-                timeout_secs: 60,
-            };
-            let config = shiplog::cluster_llm::LlmConfig {
-                api_endpoint: llm_api_endpoint.to_string(),
-                api_key: String::new(),
-                model: llm_model.to_string(),
-                ..Default::default()
-            };
+            let backend = llm_backend(
+                "--llm-cluster",
+                llm_api_endpoint,
+                llm_model,
+                llm_api_key.clone(),
+            );
+            let config = llm_config(llm_api_endpoint, llm_model);
             let llm = shiplog::cluster_llm::LlmClusterer::new(Box::new(backend), config);
             Box::new(shiplog::cluster_llm::LlmWithFallback::new(llm))
         }
         #[cfg(not(feature = "llm"))]
         {
             let _ = (llm_api_endpoint, llm_model, llm_api_key);
-            eprintln!(
-                "ERROR: --llm-cluster requires the 'llm' feature. Rebuild with: cargo build -p shiplog --features llm"
-            );
-            std::process::exit(1);
+            require_llm_feature("--llm-cluster")
         }
     } else {
-        Box::new(RepoClusterer)
+        strategy.clusterer()
+    };
+
+    let Some(mode) = summarize else {
+        return clusterer;
+    };
+    let summarizer: Box<dyn shiplog::ports::Summarizer> = match mode {
+        SummarizeMode::Heuristic => Box::new(HeuristicSummarizer),
+        SummarizeMode::Llm => {
+            #[cfg(feature = "llm")]
+            {
+                tracing::warn!(
+                    "--summarize llm sends manager-redacted event summaries to {llm_api_endpoint}"
+                );
+                let backend =
+                    llm_backend("--summarize llm", llm_api_endpoint, llm_model, llm_api_key);
+                let config = llm_config(llm_api_endpoint, llm_model);
+                let llm = shiplog::cluster_llm::LlmSummarizer::new(Box::new(backend), config);
+                Box::new(shiplog::cluster_llm::LlmSummarizerWithFallback::new(llm))
+            }
+            #[cfg(not(feature = "llm"))]
+            {
+                let _ = (llm_api_endpoint, llm_model, llm_api_key);
+                require_llm_feature("--summarize llm")
+            }
+        }
+    };
+    Box::new(SummarizingClusterer::new(
+        clusterer,
+        summarizer,
+        Box::new(DeterministicRedactor::new(redact_key.as_bytes())),
+    ))
+}
+
+#[cfg(feature = "llm")]
+fn llm_backend(
+    flag: &str,
+    llm_api_endpoint: &str,
+    llm_model: &str,
+    llm_api_key: Option<String>,
+) -> shiplog::cluster_llm::OpenAiCompatibleBackend {
+    let api_key = llm_api_key
+        .or_else(|| std::env::var("SHIPLOG_LLM_API_KEY").ok())
+        .unwrap_or_else(|| {
+            eprintln!("ERROR: {flag} requires --llm-api-key or SHIPLOG_LLM_API_KEY");
+            std::process::exit(1);
+        });
+    shiplog::cluster_llm::OpenAiCompatibleBackend {
+        endpoint: llm_api_endpoint.to_string(),
+        api_key,
+        model: llm_model.to_string(),
+        temperature: 0.2,
+        timeout_secs: 60,
     }
 }
 
+#[cfg(feature = "llm")]
+fn llm_config(llm_api_endpoint: &str, llm_model: &str) -> shiplog::cluster_llm::LlmConfig {
+    shiplog::cluster_llm::LlmConfig {
+        api_endpoint: llm_api_endpoint.to_string(),
+        api_key: String::new(),
+        model: llm_model.to_string(),
+        ..Default::default()
+    }
+}
+
+#[cfg(not(feature = "llm"))]
+fn require_llm_feature(flag: &str) -> ! {
+    eprintln!(
+        "ERROR: {flag} requires the 'llm' feature. Rebuild with: cargo build -p shiplog --features llm"
+    );
+    std::process::exit(1);
+}
+
 fn resolve_cache_dir(
     out_root: &Path,
     explicit_cache_dir: Option<PathBuf>,
@@ -12388,7 +15291,7 @@ fn resolve_cache_dir(
     if no_cache {
         None
     } else {
-        Some(explicit_cache_dir.unwrap_or_else(|| out_root.join(".cache")))
+        Some(explicit_cache_dir.unwrap_or_else(|| api_cache_dir(out_root)))
     }
 }
 
@@ -12515,8 +15418,235 @@ fn run_cache_clean(args: CacheCleanArgs) -> Result<()> {
     Ok(())
 }
 
+fn run_bundle_diff(args: BundleDiffArgs) -> Result<()> {
+    let from = resolve_run_dir_selector(&args.out, &args.from)?;
+    let to = resolve_run_dir_selector(&args.out, &args.to)?;
+    let diff = shiplog::bundle::diff_bundles(&from, &to)
+        .with_context(|| format!("compare runs {} and {}", from.display(), to.display()))?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        print!("{}", shiplog::render::md::render_bundle_diff(&diff));
+    }
+    Ok(())
+}
+
+/// A run directory given directly, or a run ID / "latest" under `out_dir`.
+fn resolve_run_dir_selector(out_dir: &Path, selector: &str) -> Result<PathBuf> {
+    let path = Path::new(selector);
+    if path.is_dir() {
+        return Ok(path.to_path_buf());
+    }
+    resolve_run_selector(out_dir, selector)
+}
+
+fn run_bundle_delta(args: BundleDeltaArgs) -> Result<()> {
+    let run_dir = resolve_render_run_dir(&args.out, args.run, args.latest)?;
+    let base = shiplog::bundle::read_bundle_manifest(&args.base)?;
+    let current = shiplog::bundle::read_bundle_manifest(&run_dir)?;
+    let archive = shiplog::bundle::delta_archive_path_for_profile(
+        &run_dir,
+        current.profile.as_str(),
+        args.format.into(),
+    );
+    let delta = shiplog::bundle::write_delta_archive(&run_dir, &archive, &base, args.format.into())
+        .with_context(|| format!("write delta bundle for {}", run_dir.display()))?;
+
+    println!("Delta bundle: {}", archive.display());
+    println!("Base run: {}", delta.base_run_id);
+    println!("Profile: {}", delta.profile);
+    println!(
+        "Files: {} added, {} changed, {} removed, {} unchanged",
+        delta.added.len(),
+        delta.changed.len(),
+        delta.removed.len(),
+        delta.unchanged
+    );
+    Ok(())
+}
+
+fn run_coverage_diff(args: CoverageDiffArgs) -> Result<()> {
+    let from = load_coverage_selector(&args.out, &args.from)?;
+    let to = load_coverage_selector(&args.out, &args.to)?;
+    let diff = shiplog::coverage::diff_coverage(&from, &to);
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        print_coverage_diff(&diff);
+    }
+    Ok(())
+}
+
+fn load_coverage_selector(out_dir: &Path, selector: &str) -> Result<CoverageManifest> {
+    let path = Path::new(selector);
+    let manifest_path = if path.is_file() {
+        path.to_path_buf()
+    } else if path.is_dir() {
+        path.join("coverage.manifest.json")
+    } else {
+        resolve_run_selector(out_dir, selector)?.join("coverage.manifest.json")
+    };
+    let text = std::fs::read_to_string(&manifest_path)
+        .with_context(|| format!("read {}", manifest_path.display()))?;
+    serde_json::from_str(&text).with_context(|| format!("parse {}", manifest_path.display()))
+}
+
+fn print_coverage_diff(diff: &shiplog::coverage::CoverageDiff) {
+    println!("Coverage diff: {} -> {}", diff.from_run, diff.to_run);
+    println!();
+
+    println!("Window:");
+    println!(
+        "- from: {}..{}",
+        diff.from_window.since, diff.from_window.until
+    );
+    println!("- to: {}..{}", diff.to_window.since, diff.to_window.until);
+    println!(
+        "- changed: {}",
+        if diff.window_changed() { "yes" } else { "no" }
+    );
+    println!();
+
+    println!("Completeness:");
+    println!("- from: {}", diff.from_completeness);
+    println!("- to: {}", diff.to_completeness);
+    println!();
+
+    println!("Sources:");
+    print_named_list("Added", &diff.sources_added);
+    print_named_list("Removed", &diff.sources_removed);
+    println!();
+
+    println!("Slices:");
+    print_coverage_slice_list("Newly complete", &diff.newly_complete);
+    print_coverage_slice_list("Newly partial", &diff.newly_partial);
+    print_coverage_slice_list("Still partial", &diff.still_partial);
+    print_coverage_slice_list("Removed", &diff.slices_removed);
+    println!();
+
+    if diff.gaps_closed() {
+        println!("Gaps: closed");
+    } else {
+        println!(
+            "Gaps: {} partial slice(s) remain",
+            diff.newly_partial.len() + diff.still_partial.len()
+        );
+    }
+}
+
+fn print_coverage_slice_list(label: &str, slices: &[CoverageSlice]) {
+    if slices.is_empty() {
+        println!("- {label}: None");
+        return;
+    }
+    println!("- {label}:");
+    for slice in slices {
+        println!(
+            "  - {}..{} {:?}: {}/{} fetched",
+            slice.window.since, slice.window.until, slice.query, slice.fetched, slice.total_count
+        );
+    }
+}
+
+fn run_bundle_verify(args: BundleVerifyArgs) -> Result<()> {
+    let report = shiplog::bundle::verify_bundle(&args.path, args.manifest.as_deref())
+        .with_context(|| format!("verify bundle {}", args.path.display()))?;
+    let mut problems = report.problems.clone();
+    if let Some(signer) = &args.signer
+        && !report.signed_by(signer)
+    {
+        problems.push(match &report.signature {
+            Some(check) if check.valid => format!(
+                "manifest is signed by {}, not the expected key {signer}",
+                check.public_key
+            ),
+            Some(_) => "manifest signature is invalid".to_string(),
+            None => format!("bundle is unsigned; expected a signature by {signer}"),
+        });
+    }
+
+    let mut leak_scan = None;
+    if args.leak_check && report.profile != BundleProfile::Internal {
+        let source_dir = match &args.manifest {
+            Some(manifest) if manifest.is_dir() => manifest.clone(),
+            Some(manifest) => manifest
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| PathBuf::from(".")),
+            None => shiplog::bundle::bundle_source_dir(&args.path)?,
+        };
+        let ingest = load_run_ingest(&source_dir)
+            .with_context(|| format!("load source run {} for leak check", source_dir.display()))?;
+        let (workstreams, _, _) = load_effective_workstreams_for_run(&source_dir)?;
+        let mut scanned = 0usize;
+        for (path, bytes) in &report.files {
+            if !path.ends_with(".md") {
+                continue;
+            }
+            scanned += 1;
+            let text = String::from_utf8_lossy(bytes);
+            for finding in public_packet_privacy_findings(&text, &ingest.events, &workstreams) {
+                problems.push(format!("{path}: {finding}"));
+            }
+        }
+        leak_scan = Some((source_dir, scanned));
+    }
+
+    println!("Bundle verify: {}", args.path.display());
+    println!("Profile: {}", report.profile);
+    match &report.provenance {
+        Some(provenance) => {
+            println!(
+                "Produced by: {} {}",
+                provenance.tool, provenance.tool_version
+            );
+            println!("Sources: {}", provenance.sources.join(", "));
+            println!("Coverage: {}", provenance.coverage_completeness);
+            println!(
+                "Redaction: {} (rules sha256 {})",
+                provenance.redaction_profile, provenance.redaction_rules_sha256
+            );
+        }
+        None => println!("Provenance: not recorded"),
+    }
+    println!("Checksums: {} verified", report.verified_files);
+    match &report.signature {
+        Some(check) if check.valid => println!("Signature: valid ({})", check.public_key),
+        Some(_) => println!("Signature: invalid"),
+        None => println!("Signature: unsigned"),
+    }
+    match (&leak_scan, args.leak_check) {
+        (Some((source_dir, scanned)), _) => println!(
+            "Leak check: scanned {scanned} packet(s) against {}",
+            source_dir.display()
+        ),
+        (None, true) => println!("Leak check: skipped for internal bundles"),
+        (None, false) => {}
+    }
+    println!();
+    println!("Problems:");
+    if problems.is_empty() {
+        println!("- None");
+    } else {
+        for problem in &problems {
+            println!("- {problem}");
+        }
+    }
+    println!();
+    if problems.is_empty() {
+        println!("Result: pass");
+        Ok(())
+    } else {
+        println!("Result: fail");
+        anyhow::bail!(
+            "bundle verification failed with {} problem(s)",
+            problems.len()
+        )
+    }
+}
+
 fn cache_command_root(out: &Path, cache_dir: Option<&PathBuf>) -> PathBuf {
-    cache_dir.cloned().unwrap_or_else(|| out.join(".cache"))
+    cache_dir.cloned().unwrap_or_else(|| api_cache_dir(out))
 }
 
 fn cache_db_targets(root: &Path, sources: &[CacheSource]) -> Vec<CacheDbTarget> {
@@ -12686,37 +15816,183 @@ fn github_auth_unavailable_reason(api_base: Option<&str>) -> String {
 }
 
 #[expect(clippy::too_many_arguments, reason = "policy:clippy-0001")]
-fn make_gitlab_ingestor(
+fn make_gitlab_ingestor(
+    user: &str,
+    since: NaiveDate,
+    until: NaiveDate,
+    state: &str,
+    instance: &str,
+    include_reviews: bool,
+    throttle_ms: u64,
+    token: Option<String>,
+    cache_dir: Option<PathBuf>,
+) -> Result<GitlabIngestor> {
+    let token = token.or_else(|| std::env::var("GITLAB_TOKEN").ok());
+    let state = state
+        .parse::<MrState>()
+        .with_context(|| format!("parse GitLab MR state {state:?}"))?;
+
+    let mut ing = GitlabIngestor::new(user.to_string(), since, until)
+        .with_state(state)
+        .with_include_reviews(include_reviews)
+        .with_throttle(throttle_ms)
+        .with_instance(instance.to_string())
+        .context("configure GitLab instance")?;
+
+    if let Some(token) = token {
+        ing = ing.with_token(token).context("configure GitLab token")?;
+    }
+
+    if let Some(cache_dir) = cache_dir {
+        ing = ing
+            .with_cache(cache_dir)
+            .context("configure GitLab API cache")?;
+    }
+
+    Ok(ing)
+}
+
+#[expect(clippy::too_many_arguments, reason = "policy:clippy-0001")]
+fn make_bitbucket_ingestor(
+    user: &str,
+    since: NaiveDate,
+    until: NaiveDate,
+    state: &str,
+    workspace: Option<String>,
+    max_pages: Option<u32>,
+    throttle_ms: u64,
+    auth_user: Option<String>,
+    app_password: Option<String>,
+    cache_dir: Option<PathBuf>,
+) -> Result<BitbucketIngestor> {
+    let auth_user = auth_user.or_else(|| std::env::var("BITBUCKET_USERNAME").ok());
+    let app_password = app_password.or_else(|| std::env::var("BITBUCKET_APP_PASSWORD").ok());
+    let state = state
+        .parse::<BitbucketPrState>()
+        .with_context(|| format!("parse Bitbucket pull request state {state:?}"))?;
+
+    let mut ing = BitbucketIngestor::new(user.to_string(), since, until)
+        .with_state(state)
+        .with_throttle(throttle_ms);
+
+    if let Some(workspace) = workspace {
+        ing = ing
+            .with_workspace(workspace)
+            .context("configure Bitbucket workspace")?;
+    }
+
+    if let Some(max_pages) = max_pages {
+        ing = ing
+            .with_max_pages(max_pages)
+            .context("configure Bitbucket page cap")?;
+    }
+
+    if let Some(app_password) = app_password {
+        // App passwords belong to an account; default to the reported user.
+        let auth_user = auth_user.unwrap_or_else(|| user.to_string());
+        ing = ing
+            .with_app_password(auth_user, app_password)
+            .context("configure Bitbucket app password")?;
+    }
+
+    if let Some(cache_dir) = cache_dir {
+        ing = ing
+            .with_cache(cache_dir)
+            .context("configure Bitbucket API cache")?;
+    }
+
+    Ok(ing)
+}
+
+#[expect(clippy::too_many_arguments, reason = "policy:clippy-0001")]
+fn make_gerrit_ingestor(
     user: &str,
     since: NaiveDate,
     until: NaiveDate,
-    state: &str,
     instance: &str,
+    status: &str,
     include_reviews: bool,
+    max_pages: Option<u32>,
     throttle_ms: u64,
-    token: Option<String>,
+    auth_user: Option<String>,
+    http_password: Option<String>,
     cache_dir: Option<PathBuf>,
-) -> Result<GitlabIngestor> {
-    let token = token.or_else(|| std::env::var("GITLAB_TOKEN").ok());
-    let state = state
-        .parse::<MrState>()
-        .with_context(|| format!("parse GitLab MR state {state:?}"))?;
+) -> Result<GerritIngestor> {
+    let auth_user = auth_user.or_else(|| std::env::var("GERRIT_USERNAME").ok());
+    let http_password = http_password.or_else(|| std::env::var("GERRIT_HTTP_PASSWORD").ok());
+    let status = status
+        .parse::<GerritChangeStatus>()
+        .with_context(|| format!("parse Gerrit change status {status:?}"))?;
 
-    let mut ing = GitlabIngestor::new(user.to_string(), since, until)
-        .with_state(state)
+    let mut ing = GerritIngestor::new(user.to_string(), since, until)
+        .with_status(status)
         .with_include_reviews(include_reviews)
         .with_throttle(throttle_ms)
         .with_instance(instance.to_string())
-        .context("configure GitLab instance")?;
+        .context("configure Gerrit instance")?;
+
+    if let Some(max_pages) = max_pages {
+        ing = ing
+            .with_max_pages(max_pages)
+            .context("configure Gerrit page cap")?;
+    }
+
+    // Without an HTTP password the REST API is queried anonymously.
+    if let Some(http_password) = http_password {
+        let auth_user = auth_user.unwrap_or_else(|| user.to_string());
+        ing = ing
+            .with_http_password(auth_user, http_password)
+            .context("configure Gerrit HTTP password")?;
+    }
+
+    if let Some(cache_dir) = cache_dir {
+        ing = ing
+            .with_cache(cache_dir)
+            .context("configure Gerrit API cache")?;
+    }
+
+    Ok(ing)
+}
+
+#[expect(clippy::too_many_arguments, reason = "policy:clippy-0001")]
+fn make_slack_ingestor(
+    user: &str,
+    since: NaiveDate,
+    until: NaiveDate,
+    channels: Vec<String>,
+    launch_channels: Vec<String>,
+    workspace: Option<String>,
+    max_pages: Option<u32>,
+    throttle_ms: u64,
+    token: Option<String>,
+    cache_dir: Option<PathBuf>,
+) -> Result<SlackIngestor> {
+    let token = token.or_else(|| std::env::var("SLACK_TOKEN").ok());
+
+    let mut ing = SlackIngestor::new(user.to_string(), since, until)
+        .with_channels(channels)
+        .context("configure Slack channels")?
+        .with_launch_channels(launch_channels)
+        .with_throttle(throttle_ms);
+
+    if let Some(workspace) = workspace {
+        ing = ing.with_workspace(workspace);
+    }
+
+    if let Some(max_pages) = max_pages {
+        ing = ing
+            .with_max_pages(max_pages)
+            .context("configure Slack page cap")?;
+    }
 
     if let Some(token) = token {
-        ing = ing.with_token(token).context("configure GitLab token")?;
+        ing = ing.with_token(token).context("configure Slack token")?;
     }
 
     if let Some(cache_dir) = cache_dir {
         ing = ing
             .with_cache(cache_dir)
-            .context("configure GitLab API cache")?;
+            .context("configure Slack API cache")?;
     }
 
     Ok(ing)
@@ -12819,10 +16095,30 @@ fn make_git_ingestor(
     ing
 }
 
+fn make_github_export_ingestor(
+    archive: &Path,
+    since: NaiveDate,
+    until: NaiveDate,
+    user: Option<String>,
+) -> GithubExportIngestor {
+    let mut ing = GithubExportIngestor::new(archive, since, until);
+    if let Some(user) = user {
+        ing = ing.with_user(user);
+    }
+    ing
+}
+
 mod commands;
 
 fn main() -> Result<()> {
-    commands::dispatch()
+    let result = commands::dispatch().and_then(|()| timings::finish());
+    if let Err(err) = &result
+        && let Some(ingest) = ingest_error(err)
+    {
+        eprintln!("Error: {err:?}");
+        std::process::exit(ingest.exit_code());
+    }
+    result
 }
 
 fn event_count_phrase(count: usize) -> String {
@@ -12841,37 +16137,77 @@ fn print_outputs(outputs: &shiplog::engine::RunOutputs, ws_source: WorkstreamSou
             WorkstreamSource::Generated => "newly generated",
         }
     );
+    if let Some(pdf) = &outputs.packet_pdf {
+        println!("- {}", display_path_for_cli(pdf));
+    }
+    println!("- {}", display_path_for_cli(&outputs.packet_json));
     println!("- {}", display_path_for_cli(&outputs.workstreams_yaml));
+    let unassigned = WorkstreamManager::unassigned_path(&outputs.out_dir);
+    if unassigned.exists() {
+        println!(
+            "- {} (new events no curated workstream claimed)",
+            display_path_for_cli(&unassigned)
+        );
+    }
     println!("- {}", display_path_for_cli(&outputs.ledger_events_jsonl));
     println!(
         "- {}",
         display_path_for_cli(&outputs.coverage_manifest_json)
     );
+    println!("- {}", display_path_for_cli(&outputs.coverage_report_md));
+    println!("- {}", display_path_for_cli(&outputs.coverage_gaps_json));
+    println!("- {}", display_path_for_cli(&outputs.needs_attention_json));
     let source_failures = outputs.out_dir.join(SOURCE_FAILURES_FILENAME);
     if source_failures.exists() {
         println!("- {}", display_path_for_cli(&source_failures));
     }
+    let search_index = outputs
+        .out_dir
+        .join(shiplog::bundle::FILE_SEARCH_INDEX_SQLITE);
+    if search_index.exists() {
+        println!("- {}", display_path_for_cli(&search_index));
+    }
     println!("- {}", display_path_for_cli(&outputs.bundle_manifest_json));
-    if let Some(ref z) = outputs.zip_path {
-        println!("- {}", display_path_for_cli(z));
+    for archive in output_archives(outputs) {
+        println!("- {}", display_path_for_cli(archive));
     }
 }
 
 fn print_outputs_simple(outputs: &shiplog::engine::RunOutputs) {
     println!("- {}", display_path_for_cli(&outputs.packet_md));
+    if let Some(pdf) = &outputs.packet_pdf {
+        println!("- {}", display_path_for_cli(pdf));
+    }
+    println!("- {}", display_path_for_cli(&outputs.packet_json));
     println!("- {}", display_path_for_cli(&outputs.workstreams_yaml));
+    let unassigned = WorkstreamManager::unassigned_path(&outputs.out_dir);
+    if unassigned.exists() {
+        println!(
+            "- {} (new events no curated workstream claimed)",
+            display_path_for_cli(&unassigned)
+        );
+    }
     println!("- {}", display_path_for_cli(&outputs.ledger_events_jsonl));
     println!(
         "- {}",
         display_path_for_cli(&outputs.coverage_manifest_json)
     );
+    println!("- {}", display_path_for_cli(&outputs.coverage_report_md));
+    println!("- {}", display_path_for_cli(&outputs.coverage_gaps_json));
+    println!("- {}", display_path_for_cli(&outputs.needs_attention_json));
     let source_failures = outputs.out_dir.join(SOURCE_FAILURES_FILENAME);
     if source_failures.exists() {
         println!("- {}", display_path_for_cli(&source_failures));
     }
+    let search_index = outputs
+        .out_dir
+        .join(shiplog::bundle::FILE_SEARCH_INDEX_SQLITE);
+    if search_index.exists() {
+        println!("- {}", display_path_for_cli(&search_index));
+    }
     println!("- {}", display_path_for_cli(&outputs.bundle_manifest_json));
-    if let Some(ref z) = outputs.zip_path {
-        println!("- {}", display_path_for_cli(z));
+    for archive in output_archives(outputs) {
+        println!("- {}", display_path_for_cli(archive));
     }
 }
 
@@ -12890,8 +16226,17 @@ fn print_share_outputs(
     println!("- {}", manifest_path.display());
     println!("- {}", outputs.coverage_manifest_json.display());
     println!("- {}", outputs.bundle_manifest_json.display());
-    if let Some(ref z) = outputs.zip_path {
-        println!("- {}", z.display());
+    for archive in output_archives(outputs) {
+        println!("- {}", archive.display());
+    }
+}
+
+/// The bundle archive, or every part when the bundle was split.
+fn output_archives(outputs: &shiplog::engine::RunOutputs) -> Vec<&PathBuf> {
+    if outputs.archive_parts.is_empty() {
+        outputs.zip_path.iter().collect()
+    } else {
+        outputs.archive_parts.iter().collect()
     }
 }
 
@@ -13171,7 +16516,7 @@ fn verify_share_manifest(options: ShareManifestVerifyOptions) -> Result<()> {
                 );
             }
         }
-        BundleProfile::Internal => {}
+        BundleProfile::Internal | BundleProfile::Custom(_) => {}
     }
 
     if !["explicit", "env", "config"].contains(&manifest.redaction_key_source.as_str()) {
@@ -13397,7 +16742,7 @@ fn verify_share_profile(options: ShareVerifyOptions, bundle_profile: BundleProfi
                 println!("- Strict scan is a guardrail, not a guarantee of perfect privacy.");
             }
         }
-        BundleProfile::Internal => {}
+        BundleProfile::Internal | BundleProfile::Custom(_) => {}
     }
     if attention.is_empty() {
         println!("Result: ready to render {bundle_profile} share output.");
@@ -13559,7 +16904,7 @@ fn explain_share_profile(
             println!("- Public profile uses the strictest redaction profile.");
             println!("- Review the rendered packet before sharing outside your organization.");
         }
-        BundleProfile::Internal => {}
+        BundleProfile::Internal | BundleProfile::Custom(_) => {}
     }
     println!("- Redaction is deterministic for a stable key; changing the key changes aliases.");
     println!();
@@ -13781,6 +17126,7 @@ fn share_profile_title(bundle_profile: &BundleProfile) -> &'static str {
         BundleProfile::Internal => "Internal",
         BundleProfile::Manager => "Manager",
         BundleProfile::Public => "Public",
+        BundleProfile::Custom(_) => "Custom",
     }
 }
 
@@ -13831,7 +17177,7 @@ fn share_explain_included_items(
             included
                 .push("public-safe summaries with the lowest default receipt density".to_string());
         }
-        BundleProfile::Internal => {}
+        BundleProfile::Internal | BundleProfile::Custom(_) => {}
     }
     included
 }
@@ -13852,7 +17198,7 @@ fn share_explain_removed_items(bundle_profile: &BundleProfile) -> Vec<String> {
                 "raw private URLs and original names where strict redaction applies".to_string(),
             );
         }
-        BundleProfile::Internal => {}
+        BundleProfile::Internal | BundleProfile::Custom(_) => {}
     }
     removed
 }
@@ -13973,6 +17319,65 @@ fn public_packet_privacy_findings(
                         .insert("public packet contains an original manual impact.".to_string());
                 }
             }
+            EventPayload::Issue(issue) => {
+                if packet_contains_sensitive_literal(packet, &issue.title) {
+                    findings.insert("public packet contains an original issue title.".to_string());
+                }
+            }
+            EventPayload::Deployment(deployment) => {
+                if let Some(version) = &deployment.version
+                    && packet_contains_sensitive_literal(packet, version)
+                {
+                    findings.insert(
+                        "public packet contains an original deployment version.".to_string(),
+                    );
+                }
+            }
+            EventPayload::Release(release) => {
+                if let Some(name) = &release.name
+                    && packet_contains_sensitive_literal(packet, name)
+                {
+                    findings.insert("public packet contains an original release name.".to_string());
+                }
+            }
+            EventPayload::Comment(comment) => {
+                if packet_contains_sensitive_literal(packet, &comment.target_title) {
+                    findings.insert(
+                        "public packet contains an original comment target title.".to_string(),
+                    );
+                }
+                if let Some(thread) = &comment.thread
+                    && packet_contains_sensitive_literal(packet, thread)
+                {
+                    findings
+                        .insert("public packet contains an original comment thread.".to_string());
+                }
+            }
+            EventPayload::Incident(incident) => {
+                if packet_contains_sensitive_literal(packet, &incident.title) {
+                    findings
+                        .insert("public packet contains an original incident title.".to_string());
+                }
+                if let Some(summary) = &incident.summary
+                    && packet_contains_sensitive_literal(packet, summary)
+                {
+                    findings
+                        .insert("public packet contains an original incident summary.".to_string());
+                }
+                if let Some(impact) = &incident.customer_impact
+                    && packet_contains_sensitive_literal(packet, impact)
+                {
+                    findings.insert(
+                        "public packet contains an original incident customer impact.".to_string(),
+                    );
+                }
+            }
+            EventPayload::Document(document) => {
+                if packet_contains_sensitive_literal(packet, &document.title) {
+                    findings
+                        .insert("public packet contains an original document title.".to_string());
+                }
+            }
         }
     }
 
@@ -14065,27 +17470,6 @@ fn print_workstreams_list(
     }
 }
 
-fn rename_workstream(workstreams: &mut WorkstreamsFile, from: &str, to: &str) -> Result<String> {
-    let new_title = to.trim();
-    if new_title.is_empty() {
-        anyhow::bail!("new workstream title cannot be blank");
-    }
-
-    let idx = find_workstream_index(workstreams, from)?;
-    if workstreams
-        .workstreams
-        .iter()
-        .enumerate()
-        .any(|(other_idx, workstream)| other_idx != idx && workstream.title == new_title)
-    {
-        anyhow::bail!("another workstream is already titled {new_title:?}");
-    }
-
-    let old_title = workstreams.workstreams[idx].title.clone();
-    workstreams.workstreams[idx].title = new_title.to_string();
-    Ok(old_title)
-}
-
 struct MoveWorkstreamResult {
     event_id: String,
     from_titles: Vec<String>,
@@ -14126,7 +17510,7 @@ fn move_event_to_workstream(
     target_selector: &str,
     ledger_events: &[EventEnvelope],
 ) -> Result<MoveWorkstreamResult> {
-    let target_idx = find_workstream_index(workstreams, target_selector)?;
+    let target_idx = WorkstreamManager::find_index(workstreams, target_selector)?;
     let event = ledger_events
         .iter()
         .find(|event| event.id.to_string() == event_selector)
@@ -14138,6 +17522,7 @@ fn move_event_to_workstream(
 
     let mut from_titles = Vec::new();
     let mut receipt_preserved = false;
+    let mut weight_override = None;
 
     for (idx, workstream) in workstreams.workstreams.iter_mut().enumerate() {
         let had_event = workstream
@@ -14159,16 +17544,22 @@ fn move_event_to_workstream(
         workstream
             .receipts
             .retain(|candidate| candidate.to_string() != event_key);
+        if let Some(weight) = workstream.receipt_weights.remove(&event_id) {
+            weight_override = Some(weight);
+        }
     }
 
     let target = &mut workstreams.workstreams[target_idx];
     let to_title = target.title.clone();
     target.events.push(event_id.clone());
     if receipt_preserved {
+        if let Some(weight) = weight_override {
+            target.receipt_weights.insert(event_id.clone(), weight);
+        }
         target.receipts.push(event_id);
     }
 
-    recompute_workstream_stats(workstreams, ledger_events);
+    WorkstreamManager::recompute_stats(workstreams, ledger_events);
 
     Ok(MoveWorkstreamResult {
         event_id: event_key,
@@ -14186,7 +17577,7 @@ fn print_workstream_receipts(
     ledger_events: &[EventEnvelope],
     workstream_selector: &str,
 ) -> Result<()> {
-    let idx = find_workstream_index(workstreams, workstream_selector)?;
+    let idx = WorkstreamManager::find_index(workstreams, workstream_selector)?;
     let workstream = &workstreams.workstreams[idx];
     let by_id: HashMap<_, _> = ledger_events
         .iter()
@@ -14209,7 +17600,17 @@ fn print_workstream_receipts(
 
     for receipt_id in &workstream.receipts {
         if let Some(event) = by_id.get(&receipt_id.to_string()) {
-            println!("{}", format_receipt_markdown(event));
+            let weight = receipt_weight(workstream, event);
+            let curated = if workstream.receipt_weights.contains_key(receipt_id) {
+                ", curated"
+            } else {
+                ""
+            };
+            println!(
+                "{} [weight: {}{curated}]",
+                format_receipt_markdown(event),
+                weight.as_str()
+            );
         } else {
             println!("- {} (missing from ledger.events.jsonl)", receipt_id);
         }
@@ -14224,7 +17625,7 @@ fn add_workstream_receipt(
     event_selector: &str,
     ledger_events: &[EventEnvelope],
 ) -> Result<ReceiptEditResult> {
-    let idx = find_workstream_index(workstreams, workstream_selector)?;
+    let idx = WorkstreamManager::find_index(workstreams, workstream_selector)?;
     let event = find_ledger_event(ledger_events, event_selector)?;
     let event_id = event.id.clone();
     let event_key = event.id.to_string();
@@ -14259,7 +17660,7 @@ fn remove_workstream_receipt(
     event_selector: &str,
     ledger_events: &[EventEnvelope],
 ) -> Result<ReceiptEditResult> {
-    let idx = find_workstream_index(workstreams, workstream_selector)?;
+    let idx = WorkstreamManager::find_index(workstreams, workstream_selector)?;
     let event = find_ledger_event(ledger_events, event_selector)?;
     let event_key = event.id.to_string();
     let event_title = event_title(event).to_string();
@@ -14276,7 +17677,8 @@ fn remove_workstream_receipt(
         );
     };
 
-    workstream.receipts.remove(receipt_idx);
+    let event_id = workstream.receipts.remove(receipt_idx);
+    workstream.receipt_weights.remove(&event_id);
 
     Ok(ReceiptEditResult {
         event_id: event_key,
@@ -14293,7 +17695,7 @@ fn create_workstream(
     if title.is_empty() {
         anyhow::bail!("workstream title cannot be blank");
     }
-    if find_workstream_index_optional(workstreams, title)?.is_some() {
+    if WorkstreamManager::find_index_optional(workstreams, title)?.is_some() {
         anyhow::bail!("a workstream already matches {title:?}");
     }
 
@@ -14306,6 +17708,8 @@ fn create_workstream(
         stats: WorkstreamStats::zero(),
         events: vec![],
         receipts: vec![],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     });
 
     Ok(CreateWorkstreamResult {
@@ -14325,7 +17729,7 @@ fn delete_workstream(
         anyhow::bail!("use either --move-to or --force, not both");
     }
 
-    let source_idx = find_workstream_index(workstreams, workstream_selector)?;
+    let source_idx = WorkstreamManager::find_index(workstreams, workstream_selector)?;
     let event_count = workstreams.workstreams[source_idx].events.len();
     let receipt_count = workstreams.workstreams[source_idx].receipts.len();
     let is_non_empty = event_count > 0 || receipt_count > 0;
@@ -14339,21 +17743,25 @@ fn delete_workstream(
 
     let mut moved_to_title = None;
     if let Some(target_selector) = move_to {
-        let target_idx = find_workstream_index(workstreams, target_selector)?;
+        let target_idx = WorkstreamManager::find_index(workstreams, target_selector)?;
         if source_idx == target_idx {
             anyhow::bail!("delete target and --move-to target must be different workstreams");
         }
 
         let events = workstreams.workstreams[source_idx].events.clone();
         let receipts = workstreams.workstreams[source_idx].receipts.clone();
+        let weights = workstreams.workstreams[source_idx].receipt_weights.clone();
         let target = &mut workstreams.workstreams[target_idx];
         moved_to_title = Some(target.title.clone());
         append_unique_event_ids(&mut target.events, events);
         append_unique_event_ids(&mut target.receipts, receipts);
+        for (event_id, weight) in weights {
+            target.receipt_weights.entry(event_id).or_insert(weight);
+        }
     }
 
     let deleted = workstreams.workstreams.remove(source_idx);
-    recompute_workstream_stats(workstreams, ledger_events);
+    WorkstreamManager::recompute_stats(workstreams, ledger_events);
 
     Ok(DeleteWorkstreamResult {
         deleted_title: deleted.title,
@@ -14395,7 +17803,7 @@ fn split_workstream(
         .build()
         .with_context(|| format!("compile split matching regex {pattern:?}"))?;
 
-    let from_idx = find_workstream_index(workstreams, from_selector)?;
+    let from_idx = WorkstreamManager::find_index(workstreams, from_selector)?;
     let from_title = workstreams.workstreams[from_idx].title.clone();
     let (target_idx, created_target) =
         resolve_split_target_index(workstreams, target_selector, create)?;
@@ -14462,7 +17870,7 @@ fn split_workstream(
     target.events.extend(moved_events.iter().cloned());
     target.receipts.extend(moved_receipts.iter().cloned());
 
-    recompute_workstream_stats(workstreams, ledger_events);
+    WorkstreamManager::recompute_stats(workstreams, ledger_events);
 
     Ok(SplitWorkstreamResult {
         event_count: moved_events.len(),
@@ -14484,7 +17892,7 @@ fn resolve_split_target_index(
         anyhow::bail!("target workstream title or ID cannot be blank");
     }
 
-    if let Some(idx) = find_workstream_index_optional(workstreams, target_selector)? {
+    if let Some(idx) = WorkstreamManager::find_index_optional(workstreams, target_selector)? {
         return Ok((idx, false));
     }
 
@@ -14501,6 +17909,8 @@ fn resolve_split_target_index(
         stats: WorkstreamStats::zero(),
         events: vec![],
         receipts: vec![],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     });
     Ok((idx, true))
 }
@@ -14531,6 +17941,12 @@ fn event_title(event: &EventEnvelope) -> &str {
         EventPayload::PullRequest(payload) => &payload.title,
         EventPayload::Review(payload) => &payload.pull_title,
         EventPayload::Manual(payload) => &payload.title,
+        EventPayload::Issue(payload) => &payload.title,
+        EventPayload::Deployment(payload) => &payload.environment,
+        EventPayload::Release(payload) => payload.name.as_deref().unwrap_or(&payload.version),
+        EventPayload::Comment(payload) => &payload.target_title,
+        EventPayload::Incident(payload) => &payload.title,
+        EventPayload::Document(payload) => &payload.title,
     }
 }
 
@@ -14557,65 +17973,6 @@ fn contains_event_id(event_ids: &[EventId], event_key: &str) -> bool {
         .any(|candidate| candidate.to_string() == event_key)
 }
 
-fn find_workstream_index(workstreams: &WorkstreamsFile, selector: &str) -> Result<usize> {
-    let selector = selector.trim();
-    if selector.is_empty() {
-        anyhow::bail!("workstream selector cannot be blank");
-    }
-
-    match find_workstream_index_optional(workstreams, selector)? {
-        Some(idx) => Ok(idx),
-        None => anyhow::bail!(
-            "no workstream matched {selector:?}; run `shiplog workstreams list` to see available titles and IDs"
-        ),
-    }
-}
-
-fn find_workstream_index_optional(
-    workstreams: &WorkstreamsFile,
-    selector: &str,
-) -> Result<Option<usize>> {
-    let selector = selector.trim();
-    if selector.is_empty() {
-        anyhow::bail!("workstream selector cannot be blank");
-    }
-
-    let matches: Vec<_> = workstreams
-        .workstreams
-        .iter()
-        .enumerate()
-        .filter(|(_, workstream)| {
-            workstream.title == selector || workstream.id.to_string() == selector
-        })
-        .map(|(idx, _)| idx)
-        .collect();
-
-    match matches.as_slice() {
-        [idx] => Ok(Some(*idx)),
-        [] => Ok(None),
-        _ => anyhow::bail!(
-            "multiple workstreams matched {selector:?}; use the workstream ID instead"
-        ),
-    }
-}
-
-fn recompute_workstream_stats(workstreams: &mut WorkstreamsFile, ledger_events: &[EventEnvelope]) {
-    let event_kinds: HashMap<_, _> = ledger_events
-        .iter()
-        .map(|event| (event.id.to_string(), event.kind.clone()))
-        .collect();
-
-    for workstream in &mut workstreams.workstreams {
-        workstream.stats = WorkstreamStats::zero();
-        let event_ids = workstream.events.clone();
-        for event_id in event_ids {
-            if let Some(kind) = event_kinds.get(&event_id.to_string()) {
-                workstream.bump_stats(kind);
-            }
-        }
-    }
-}
-
 fn write_curated_workstreams(run_dir: &Path, workstreams: &WorkstreamsFile) -> Result<()> {
     let curated_path = shiplog::workstreams::WorkstreamManager::curated_path(run_dir);
     shiplog::workstreams::write_workstreams(&curated_path, workstreams)
@@ -14856,9 +18213,7 @@ fn coverage_gap_count(coverage: &shiplog::schema::coverage::CoverageManifest) ->
         + coverage
             .slices
             .iter()
-            .filter(|slice| {
-                slice.incomplete_results.unwrap_or(false) || slice.fetched < slice.total_count
-            })
+            .filter(|slice| slice.is_partial())
             .count()
 }
 
@@ -15701,7 +19056,10 @@ fn workstream_quality_signals<'a>(
         .collect();
     let manual_events = events
         .iter()
-        .filter(|event| matches!(event.payload, EventPayload::Manual(_)))
+        .filter(|event| {
+            matches!(event.payload, EventPayload::Manual(_))
+                || matches!(event_source_bucket(event), WorkstreamSourceBucket::Manual)
+        })
         .count();
     let mut signals = WorkstreamQualitySignals {
         manual_events,
@@ -15788,7 +19146,14 @@ fn event_source_bucket(event: &EventEnvelope) -> WorkstreamSourceBucket {
         "manual" => WorkstreamSourceBucket::Manual,
         "github" | "gitlab" | "local_git" | "localgit" => WorkstreamSourceBucket::Code,
         _ => match event.payload {
-            EventPayload::PullRequest(_) | EventPayload::Review(_) => WorkstreamSourceBucket::Code,
+            EventPayload::PullRequest(_)
+            | EventPayload::Review(_)
+            | EventPayload::Deployment(_)
+            | EventPayload::Release(_)
+            | EventPayload::Comment(_) => WorkstreamSourceBucket::Code,
+            EventPayload::Issue(_) | EventPayload::Incident(_) | EventPayload::Document(_) => {
+                WorkstreamSourceBucket::Ticket
+            }
             EventPayload::Manual(_) => WorkstreamSourceBucket::Manual,
         },
     }
@@ -16387,9 +19752,12 @@ fn detect_evidence_debt(input: EvidenceDebtInput<'_>) -> Vec<EvidenceDebt> {
         );
     }
 
-    for slice in input.coverage.slices.iter().filter(|slice| {
-        slice.incomplete_results.unwrap_or(false) || slice.fetched < slice.total_count
-    }) {
+    for slice in input
+        .coverage
+        .slices
+        .iter()
+        .filter(|slice| slice.is_partial())
+    {
         debt.push(
             EvidenceDebt::new(
                 EvidenceDebtSeverity::Warning,
@@ -16986,7 +20354,7 @@ fn run_dir_entries_latest_first(out_dir: &Path) -> Result<Vec<std::fs::DirEntry>
         .filter(|e| e.path().join("ledger.events.jsonl").exists())
         .collect();
 
-    runs.sort_by_key(|entry| std::cmp::Reverse(entry.file_name()));
+    runs.sort_by_cached_key(|entry| std::cmp::Reverse(status::run_sort_key(&entry.path())));
 
     if runs.is_empty() {
         anyhow::bail!("{}", missing_latest_run_message(out_dir));
@@ -17150,6 +20518,9 @@ mod tests {
                 "gitlab-api-cache.db",
                 "jira-api-cache.db",
                 "linear-api-cache.db",
+                "bitbucket-api-cache.db",
+                "gerrit-api-cache.db",
+                "slack-api-cache.db",
             ]
         );
     }
@@ -17183,6 +20554,24 @@ mod tests {
         assert!(parse_cache_age("1w").is_err());
     }
 
+    #[test]
+    fn parse_journal_metrics_reads_value_baseline_and_unit() {
+        let metrics = parse_journal_metrics(&[
+            "p95 latency=420ms->180ms".to_string(),
+            "error rate = 0.5 %".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(metrics[0].to_string(), "p95 latency 420ms → 180ms");
+        assert_eq!(metrics[1].name, "error rate");
+        assert_eq!(metrics[1].unit.as_deref(), Some("%"));
+        assert!(metrics[1].baseline.is_none());
+
+        assert!(parse_journal_metrics(&["latency".to_string()]).is_err());
+        assert!(parse_journal_metrics(&["=5".to_string()]).is_err());
+        assert!(parse_journal_metrics(&["latency=fast".to_string()]).is_err());
+        assert!(parse_journal_metrics(&["latency=1s->5ms".to_string()]).is_err());
+    }
+
     #[test]
     fn classify_intake_repair_kind_maps_common_provider_failures() {
         for (source, reason, expected) in [
@@ -17267,6 +20656,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn configured_source_failures_prefer_typed_ingest_errors() {
+        // A 403 rate limit reads as "forbidden" to the text classifier.
+        let limited = anyhow::Error::new(IngestError::RateLimited {
+            system: "GitHub",
+            retry_after: Some(std::time::Duration::from_secs(90)),
+        })
+        .context("search forbidden for this slice");
+        let failure = ConfiguredSourceFailure::from_error("github", &limited);
+        assert_eq!(failure.repair_kind(), IntakeRepairKind::RateLimited);
+        assert_eq!(failure.retry_after_secs, Some(90));
+
+        let plain =
+            ConfiguredSourceFailure::from_error("jira", &anyhow::anyhow!("missing JIRA_TOKEN"));
+        assert_eq!(plain.repair_kind(), IntakeRepairKind::MissingToken);
+        assert_eq!(plain.retry_after_secs, None);
+    }
+
+    #[test]
+    fn configured_source_parse_errors_fail_the_run() {
+        let mut successes = Vec::new();
+        let mut failures = Vec::new();
+        let parse = anyhow::Error::new(IngestError::Parse {
+            system: "GitLab",
+            context: "GET /merge_requests".to_string(),
+            detail: "expected a sequence".to_string(),
+        });
+        let err =
+            push_configured_source_result(&mut successes, &mut failures, "gitlab", Err(parse))
+                .unwrap_err();
+        assert_eq!(ingest_error(&err).map(IngestError::exit_code), Some(7));
+        assert!(failures.is_empty());
+
+        let auth = anyhow::Error::new(IngestError::Auth {
+            system: "GitLab",
+            status: 401,
+            detail: String::new(),
+        });
+        push_configured_source_result(&mut successes, &mut failures, "gitlab", Err(auth)).unwrap();
+        assert_eq!(failures[0].repair_kind(), IntakeRepairKind::AuthRejected);
+    }
+
     #[test]
     fn cache_clean_mode_rejects_all_with_older_than() {
         let args = CacheCleanArgs {
@@ -17396,6 +20827,20 @@ mod tests {
         assert_eq!(window.label, WindowLabel::Year(2025));
     }
 
+    #[test]
+    fn config_retry_policy_reads_http_section() {
+        let mut config = ShiplogConfig::default();
+        assert_eq!(config_retry_policy(&config), RetryPolicy::default());
+
+        config.http.max_retries = Some(0);
+        config.http.max_retry_wait_secs = Some(90);
+        let policy = config_retry_policy(&config);
+
+        assert_eq!(policy.max_retries, 0);
+        assert_eq!(policy.max_delay, std::time::Duration::from_secs(90));
+        assert_eq!(policy.base_delay, RetryPolicy::default().base_delay);
+    }
+
     #[test]
     fn resolve_multi_window_uses_named_period_with_explicit_dates() {
         let mut config = ShiplogConfig::default();
@@ -17622,6 +21067,129 @@ mod tests {
         assert!(cache_dir.path().join("gitlab-api-cache.db").exists());
     }
 
+    #[test]
+    fn make_bitbucket_ingestor_configures_cli_options() {
+        let since = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+
+        let ing = make_bitbucket_ingestor(
+            "alice",
+            since,
+            until,
+            "all",
+            Some("acme".to_string()),
+            Some(4),
+            25,
+            None,
+            Some("app-password".to_string()),
+            Some(cache_dir.path().to_path_buf()),
+        )
+        .unwrap();
+
+        assert_eq!(ing.user, "alice");
+        assert_eq!(ing.state, BitbucketPrState::All);
+        assert_eq!(ing.workspace.as_deref(), Some("acme"));
+        assert_eq!(ing.max_pages, 4);
+        assert_eq!(ing.throttle_ms, 25);
+        assert_eq!(ing.app_password.as_deref(), Some("app-password"));
+        assert!(cache_dir.path().join("bitbucket-api-cache.db").exists());
+
+        let err = make_bitbucket_ingestor(
+            "alice", since, until, "approved", None, None, 0, None, None, None,
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("parse Bitbucket pull request state"));
+    }
+
+    #[test]
+    fn make_gerrit_ingestor_configures_cli_options() {
+        let since = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+
+        let ing = make_gerrit_ingestor(
+            "alice",
+            since,
+            until,
+            "review.example.com",
+            "abandoned",
+            true,
+            Some(2),
+            10,
+            Some("alice-bot".to_string()),
+            Some("http-password".to_string()),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(ing.instance, "https://review.example.com");
+        assert_eq!(ing.status, GerritChangeStatus::Abandoned);
+        assert!(ing.include_reviews);
+        assert_eq!(ing.max_pages, 2);
+        assert_eq!(ing.auth_user.as_deref(), Some("alice-bot"));
+        assert_eq!(ing.http_password.as_deref(), Some("http-password"));
+
+        let err = make_gerrit_ingestor(
+            "alice",
+            since,
+            until,
+            "review.example.com",
+            "draft",
+            false,
+            None,
+            0,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("parse Gerrit change status"));
+    }
+
+    #[test]
+    fn make_slack_ingestor_configures_cli_options() {
+        let since = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+
+        let ing = make_slack_ingestor(
+            "U024BE7LH",
+            since,
+            until,
+            vec!["#shipped".to_string(), "launches".to_string()],
+            vec!["#launches".to_string()],
+            Some("acme".to_string()),
+            Some(3),
+            25,
+            Some("xoxb-token".to_string()),
+            Some(cache_dir.path().to_path_buf()),
+        )
+        .unwrap();
+
+        assert_eq!(ing.channels, vec!["shipped", "launches"]);
+        assert_eq!(ing.launch_channels, vec!["launches"]);
+        assert_eq!(ing.workspace.as_deref(), Some("acme"));
+        assert_eq!(ing.max_pages, 3);
+        assert_eq!(ing.throttle_ms, 25);
+        assert_eq!(ing.token.as_deref(), Some("xoxb-token"));
+        assert!(cache_dir.path().join("slack-api-cache.db").exists());
+
+        let err = make_slack_ingestor(
+            "U024BE7LH",
+            since,
+            until,
+            Vec::new(),
+            Vec::new(),
+            None,
+            None,
+            0,
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("configure Slack channels"));
+    }
+
     #[test]
     fn make_gitlab_ingestor_rejects_invalid_state() {
         let since = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
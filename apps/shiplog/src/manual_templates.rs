@@ -0,0 +1,539 @@
+//! Manual-event templates.
+//!
+//! A template fixes an entry's type, default tags and workstream, and which
+//! fields it must fill, each with a prompt describing a useful answer. Entries
+//! written weeks after the fact tend to lose the receipt or the outcome;
+//! `shiplog add --template` refuses them until the required fields are given,
+//! and `shiplog journal scaffold` appends commented skeletons to
+//! `manual_events.yaml` for filling in by hand.
+//!
+//! `incident`, `design-review`, and `mentoring` are built in. A
+//! `[templates.<name>]` config section overrides a built-in's settings or
+//! defines a new template.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use clap::ValueEnum;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum TemplateField {
+    Description,
+    Workstream,
+    Tag,
+    Receipt,
+    Impact,
+    Metric,
+}
+
+impl TemplateField {
+    const ALL: [Self; 6] = [
+        Self::Description,
+        Self::Workstream,
+        Self::Tag,
+        Self::Receipt,
+        Self::Impact,
+        Self::Metric,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Self::Description => "description",
+            Self::Workstream => "workstream",
+            Self::Tag => "tag",
+            Self::Receipt => "receipt",
+            Self::Impact => "impact",
+            Self::Metric => "metric",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let value = value.strip_suffix('s').unwrap_or(value);
+        Self::ALL.into_iter().find(|field| field.key() == value)
+    }
+
+    fn default_prompt(self) -> &'static str {
+        match self {
+            Self::Description => "What happened, and what did you do?",
+            Self::Workstream => "Which project or area does this belong to?",
+            Self::Tag => "Which tags group this with related work?",
+            Self::Receipt => "Link the doc, ticket, or record that shows the work.",
+            Self::Impact => "What changed because of it?",
+            Self::Metric => "Which number moved, from what to what?",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ManualTemplate {
+    pub(crate) name: String,
+    pub(crate) event_type: JournalEventType,
+    pub(crate) workstream: Option<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) required: Vec<TemplateField>,
+    prompts: BTreeMap<TemplateField, String>,
+}
+
+impl ManualTemplate {
+    fn new(name: &str, event_type: JournalEventType) -> Self {
+        Self {
+            name: name.to_string(),
+            event_type,
+            workstream: None,
+            tags: Vec::new(),
+            required: Vec::new(),
+            prompts: BTreeMap::new(),
+        }
+    }
+
+    fn prompt(&self, field: TemplateField) -> &str {
+        self.prompts
+            .get(&field)
+            .map(String::as_str)
+            .unwrap_or_else(|| field.default_prompt())
+    }
+
+    fn requires(&self, field: TemplateField) -> bool {
+        self.required.contains(&field)
+    }
+}
+
+fn builtin_templates() -> Vec<ManualTemplate> {
+    let template = |name: &str,
+                    event_type: JournalEventType,
+                    required: &[TemplateField],
+                    prompts: &[(TemplateField, &str)]| {
+        let mut template = ManualTemplate::new(name, event_type);
+        template.tags = vec![name.to_string()];
+        template.required = required.to_vec();
+        template.prompts = prompts
+            .iter()
+            .map(|(field, prompt)| (*field, (*prompt).to_string()))
+            .collect();
+        template
+    };
+    vec![
+        template(
+            "incident",
+            JournalEventType::Incident,
+            &[
+                TemplateField::Description,
+                TemplateField::Receipt,
+                TemplateField::Impact,
+            ],
+            &[
+                (
+                    TemplateField::Description,
+                    "What broke, how was it detected, and what did you do?",
+                ),
+                (
+                    TemplateField::Receipt,
+                    "Link the incident record, timeline, or postmortem.",
+                ),
+                (
+                    TemplateField::Impact,
+                    "Who was affected, for how long, and what is different now?",
+                ),
+                (
+                    TemplateField::Metric,
+                    "Time to detect or mitigate, error rate, or affected requests.",
+                ),
+            ],
+        ),
+        template(
+            "design-review",
+            JournalEventType::Design,
+            &[
+                TemplateField::Description,
+                TemplateField::Workstream,
+                TemplateField::Receipt,
+            ],
+            &[
+                (
+                    TemplateField::Description,
+                    "Which decision was reviewed, and what was your role?",
+                ),
+                (
+                    TemplateField::Workstream,
+                    "Which project does the design belong to?",
+                ),
+                (
+                    TemplateField::Receipt,
+                    "Link the design doc or review notes.",
+                ),
+                (
+                    TemplateField::Impact,
+                    "What changed in the design because of the review?",
+                ),
+            ],
+        ),
+        template(
+            "mentoring",
+            JournalEventType::Mentoring,
+            &[TemplateField::Description, TemplateField::Impact],
+            &[
+                (
+                    TemplateField::Description,
+                    "Who did you support (by role), on what, and how often?",
+                ),
+                (
+                    TemplateField::Impact,
+                    "What can they do now that they could not before?",
+                ),
+            ],
+        ),
+    ]
+}
+
+/// Built-in and configured template names, sorted.
+pub(crate) fn template_names(config: Option<&ShiplogConfig>) -> Vec<String> {
+    let mut names = builtin_templates()
+        .into_iter()
+        .map(|template| template.name)
+        .collect::<BTreeSet<_>>();
+    if let Some(config) = config {
+        names.extend(config.templates.keys().cloned());
+    }
+    names.into_iter().collect()
+}
+
+pub(crate) fn resolve_template(
+    config: Option<&ShiplogConfig>,
+    name: &str,
+) -> Result<ManualTemplate> {
+    let builtin = builtin_templates()
+        .into_iter()
+        .find(|template| template.name == name);
+    let configured = config.and_then(|config| config.templates.get(name));
+    match (builtin, configured) {
+        (builtin, Some(configured)) => apply_config_template(
+            builtin.unwrap_or_else(|| ManualTemplate::new(name, JournalEventType::Note)),
+            configured,
+        ),
+        (Some(builtin), None) => Ok(builtin),
+        (None, None) => anyhow::bail!(
+            "unknown manual event template {name:?}; available: {}",
+            template_names(config).join(", ")
+        ),
+    }
+}
+
+fn apply_config_template(
+    mut template: ManualTemplate,
+    configured: &ConfigTemplate,
+) -> Result<ManualTemplate> {
+    if let Some(event_type) = &configured.event_type {
+        template.event_type = JournalEventType::from_str(event_type, true).map_err(|_| {
+            anyhow::anyhow!(
+                "unknown type {event_type:?}; expected one of {}",
+                journal_event_type_names().join(", ")
+            )
+        })?;
+    }
+    if let Some(workstream) = optional_config_string(configured.workstream.as_deref()) {
+        template.workstream = Some(workstream);
+    }
+    if let Some(tags) = &configured.tags {
+        template.tags = normalize_journal_tags(tags.clone())?;
+    }
+    if let Some(required) = &configured.required {
+        template.required = required
+            .iter()
+            .map(|value| parse_template_field(value))
+            .collect::<Result<_>>()?;
+    }
+    for (key, prompt) in &configured.prompts {
+        template
+            .prompts
+            .insert(parse_template_field(key)?, prompt.trim().to_string());
+    }
+    Ok(template)
+}
+
+fn parse_template_field(value: &str) -> Result<TemplateField> {
+    TemplateField::parse(value).ok_or_else(|| {
+        anyhow::anyhow!(
+            "unknown field {value:?}; expected one of {}",
+            TemplateField::ALL.map(TemplateField::key).join(", ")
+        )
+    })
+}
+
+fn journal_event_type_names() -> Vec<String> {
+    JournalEventType::value_variants()
+        .iter()
+        .filter_map(|variant| variant.to_possible_value())
+        .map(|value| value.get_name().to_string())
+        .collect()
+}
+
+/// Fail with each missing required field's flag and prompt.
+pub(crate) fn ensure_template_fields(
+    template: &ManualTemplate,
+    filled: impl Fn(TemplateField) -> bool,
+) -> Result<()> {
+    let missing = template
+        .required
+        .iter()
+        .copied()
+        .filter(|field| !filled(*field))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    let lines = missing
+        .iter()
+        .map(|field| format!("  --{}: {}", field.key(), template.prompt(*field)))
+        .collect::<Vec<_>>();
+    anyhow::bail!(
+        "template {} requires {}:\n{}",
+        template.name,
+        if missing.len() == 1 {
+            "a field"
+        } else {
+            "fields"
+        },
+        lines.join("\n")
+    )
+}
+
+pub(crate) fn validate_config_templates(config: &ShiplogConfig, issues: &mut Vec<ConfigIssue>) {
+    for name in config.templates.keys() {
+        if let Err(err) = resolve_template(Some(config), name) {
+            issues.push(config_issue("Template", format!("{name}: {err}")));
+        }
+    }
+}
+
+/// A commented `manual_events.yaml` entry for one template, with each
+/// field's prompt alongside it and required fields marked.
+fn render_template_scaffold(template: &ManualTemplate, date: NaiveDate) -> String {
+    let note = |field: TemplateField| {
+        let marker = if template.requires(field) {
+            "required: "
+        } else {
+            ""
+        };
+        format!("  # {marker}{}", template.prompt(field))
+    };
+    let tags = template
+        .tags
+        .iter()
+        .map(|tag| yaml_quote(tag))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let workstream = template.workstream.as_deref().unwrap_or_default();
+    let lines = [
+        format!(
+            "# Template: {} (or: shiplog add \"<title>\" --template {})",
+            template.name, template.name
+        ),
+        format!("#   - id: manual-{date}-{}", template.name),
+        format!("#     type: {}", ManualEventType::from(template.event_type)),
+        format!("#     date: \"{date}\""),
+        "#     title: \"\"".to_string(),
+        format!(
+            "#     description: \"\"{}",
+            note(TemplateField::Description)
+        ),
+        format!(
+            "#     workstream: {}{}",
+            yaml_quote(workstream),
+            note(TemplateField::Workstream)
+        ),
+        format!("#     tags: [{tags}]{}", note(TemplateField::Tag)),
+        format!("#     receipts:{}", note(TemplateField::Receipt)),
+        "#       - label: \"\"".to_string(),
+        "#         url: \"\"".to_string(),
+        format!("#     impact: \"\"{}", note(TemplateField::Impact)),
+        format!("#     metrics:{}", note(TemplateField::Metric)),
+        "#       - name: \"\"".to_string(),
+        "#         value: 0".to_string(),
+    ];
+    let mut block = lines.join("\n");
+    block.push('\n');
+    block
+}
+
+fn yaml_quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+pub(crate) fn run_journal_scaffold(args: JournalScaffoldArgs) -> Result<()> {
+    let config = if args.config.exists() {
+        Some(load_shiplog_config(&args.config)?)
+    } else {
+        None
+    };
+    let names = if args.templates.is_empty() {
+        template_names(config.as_ref())
+    } else {
+        args.templates.clone()
+    };
+    let templates = names
+        .iter()
+        .map(|name| resolve_template(config.as_ref(), name))
+        .collect::<Result<Vec<_>>>()?;
+    let events = args
+        .events
+        .clone()
+        .or_else(|| configured_manual_events_path(&args.config, true))
+        .unwrap_or_else(|| PathBuf::from(MANUAL_EVENTS_FILENAME));
+    let date = args.date.unwrap_or_else(|| Utc::now().date_naive());
+
+    let mut block = String::from(
+        "\n# Fill in a template below, then uncomment it as an item under `events:`\n\
+         # (change `events: []` to `events:` first if the list is empty).\n",
+    );
+    for template in &templates {
+        block.push('\n');
+        block.push_str(&render_template_scaffold(template, date));
+    }
+
+    if args.dry_run {
+        println!("Would append to {}:", events.display());
+        print!("{block}");
+        return Ok(());
+    }
+
+    let existing = read_scaffold_target(&events)?;
+    if let Some(parent) = events
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("create manual events directory {}", parent.display()))?;
+    }
+    let mut text = existing;
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text.push_str(&block);
+    std::fs::write(&events, text).with_context(|| format!("write {}", events.display()))?;
+
+    println!(
+        "Scaffolded {} template(s) in {}:",
+        templates.len(),
+        events.display()
+    );
+    for template in &templates {
+        println!("- {}", template.name);
+    }
+    println!("Next:");
+    println!("  fill in a template and uncomment it, or add an entry directly with:");
+    println!(
+        "  shiplog add \"<title>\" --template {}",
+        templates
+            .first()
+            .map(|template| template.name.as_str())
+            .unwrap_or("incident")
+    );
+    Ok(())
+}
+
+/// The current file text, checked to be a manual events file, or a fresh one.
+fn read_scaffold_target(events: &Path) -> Result<String> {
+    if !events.exists() {
+        return Ok(render_manual_events_template());
+    }
+    read_manual_events(events)?;
+    std::fs::read_to_string(events).with_context(|| format!("read {}", events.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(text: &str) -> ShiplogConfig {
+        toml::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn configured_template_overrides_builtin_settings() {
+        let config = config(
+            r#"
+[templates.incident]
+workstream = "On-call"
+required = ["impact", "metrics"]
+
+[templates.incident.prompts]
+metric = "Minutes to mitigate."
+
+[templates.launch-review]
+type = "launch"
+tags = ["launch"]
+required = ["receipt"]
+"#,
+        );
+
+        let incident = resolve_template(Some(&config), "incident").unwrap();
+        assert_eq!(incident.event_type, JournalEventType::Incident);
+        assert_eq!(incident.workstream.as_deref(), Some("On-call"));
+        assert_eq!(incident.tags, vec!["incident"]);
+        assert_eq!(
+            incident.required,
+            vec![TemplateField::Impact, TemplateField::Metric]
+        );
+        assert_eq!(
+            incident.prompt(TemplateField::Metric),
+            "Minutes to mitigate."
+        );
+
+        let launch = resolve_template(Some(&config), "launch-review").unwrap();
+        assert_eq!(launch.event_type, JournalEventType::Launch);
+        assert_eq!(
+            template_names(Some(&config)),
+            vec!["design-review", "incident", "launch-review", "mentoring"]
+        );
+    }
+
+    #[test]
+    fn invalid_configured_templates_are_reported() {
+        let config = config(
+            r#"
+[templates.bad-type]
+type = "retro"
+
+[templates.bad-field]
+required = ["owner"]
+"#,
+        );
+        let mut issues = Vec::new();
+        validate_config_templates(&config, &mut issues);
+        let details = issues
+            .iter()
+            .map(|issue| issue.detail.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(details.len(), 2);
+        assert!(details[0].starts_with("bad-field: unknown field \"owner\""));
+        assert!(details[1].starts_with("bad-type: unknown type \"retro\""));
+    }
+
+    #[test]
+    fn missing_required_fields_list_their_prompts() {
+        let template = resolve_template(None, "incident").unwrap();
+        let err = ensure_template_fields(&template, |field| field == TemplateField::Description)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("template incident requires fields:"));
+        assert!(err.contains("--receipt: Link the incident record"));
+        assert!(err.contains("--impact: Who was affected"));
+        assert!(!err.contains("--description"));
+    }
+
+    #[test]
+    fn scaffold_marks_required_fields_and_stays_commented() {
+        let template = resolve_template(None, "mentoring").unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+        let block = render_template_scaffold(&template, date);
+        assert!(block.lines().all(|line| line.starts_with('#')));
+        assert!(block.contains("#   - id: manual-2026-03-31-mentoring"));
+        assert!(block.contains("#     type: Mentoring"));
+        assert!(block.contains("#     impact: \"\"  # required: What can they do now"));
+        assert!(block.contains("#     workstream: \"\"  # Which project"));
+    }
+}
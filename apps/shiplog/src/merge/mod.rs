@@ -6,11 +6,11 @@
 
 use anyhow::{Result, anyhow};
 use chrono::Utc;
-use shiplog::ids::EventId;
 use shiplog::ids::RunId;
 use shiplog::ports::IngestOutput;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice};
 use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::version::SchemaVersion;
 use std::collections::HashMap;
 
 /// Strategy for handling duplicate events during merge.
@@ -65,20 +65,54 @@ pub struct MergeResult {
     pub report: MergeReport,
 }
 
-/// Merge multiple event lists into one, deduplicating by event ID.
+/// Merge multiple event lists into one, deduplicating by canonical identity.
 ///
-/// The strategy determines how to handle conflicts when the same event appears
-/// in multiple sources.
+/// Events that carry the same [`EventEnvelope::canonical_id`] collapse even
+/// when their event ids differ; other events deduplicate by event id. The strategy determines how to
+/// handle conflicts when the same event appears in multiple sources.
+/// Extension entries and contributors from the losing copies are kept when
+/// the winner does not already carry them.
 pub fn merge_events(
     sources: Vec<Vec<EventEnvelope>>,
     strategy: &MergeStrategy,
 ) -> Vec<EventEnvelope> {
-    let mut events_by_id: HashMap<EventId, EventEnvelope> = HashMap::new();
+    merge_event_streams(sources, strategy)
+}
+
+/// Merge event sources that are consumed one event at a time.
+///
+/// Behaves like [`merge_events`], but each source can be any iterator, such
+/// as a [`read_events`](crate::engine::read_events) ledger reader or a
+/// cloning iterator over borrowed events. Only the merged result is held in
+/// memory, never a second full copy of every input.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::merge::{MergeStrategy, merge_event_streams};
+/// use shiplog::schema::event::EventEnvelope;
+///
+/// let ledgers: Vec<Vec<EventEnvelope>> = vec![vec![], vec![]];
+/// let merged = merge_event_streams(
+///     ledgers.iter().map(|events| events.iter().cloned()),
+///     &MergeStrategy::KeepMostComplete,
+/// );
+/// assert!(merged.is_empty());
+/// ```
+pub fn merge_event_streams<I, S>(sources: I, strategy: &MergeStrategy) -> Vec<EventEnvelope>
+where
+    I: IntoIterator<Item = S>,
+    S: IntoIterator<Item = EventEnvelope>,
+{
+    let mut kept: Vec<EventEnvelope> = Vec::new();
+    let mut index = DuplicateIndex::default();
 
     for source in sources {
         for event in source {
-            match events_by_id.get(&event.id) {
-                Some(existing) => {
+            match index.find(&event) {
+                Some(slot) => {
+                    index.insert(&event, slot);
+                    let existing = &mut kept[slot];
                     let should_replace = match strategy {
                         MergeStrategy::KeepFirst => false,
                         MergeStrategy::KeepLast => event.occurred_at > existing.occurred_at,
@@ -87,17 +121,23 @@ pub fn merge_events(
                         }
                     };
                     if should_replace {
-                        events_by_id.insert(event.id.clone(), event);
+                        let mut event = event;
+                        absorb_duplicate(&mut event, existing);
+                        *existing = event;
+                    } else {
+                        absorb_duplicate(existing, &event);
                     }
+                    index.insert(&kept[slot], slot);
                 }
                 None => {
-                    events_by_id.insert(event.id.clone(), event);
+                    index.insert(&event, kept.len());
+                    kept.push(event);
                 }
             }
         }
     }
 
-    let mut result: Vec<EventEnvelope> = events_by_id.into_values().collect();
+    let mut result = kept;
     result.sort_by(|a, b| {
         a.occurred_at
             .cmp(&b.occurred_at)
@@ -115,6 +155,23 @@ pub fn merge_two(
     merge_events(vec![left.to_vec(), right.to_vec()], strategy)
 }
 
+/// Slices of a single-source manifest with unattributed slices credited to
+/// that source, so they stay attributable once merged with other sources.
+fn attributed_slices(coverage: &CoverageManifest) -> Vec<CoverageSlice> {
+    let sole_source = match coverage.sources.as_slice() {
+        [source] => Some(source),
+        _ => None,
+    };
+    coverage
+        .slices
+        .iter()
+        .map(|slice| CoverageSlice {
+            source: slice.source.clone().or_else(|| sole_source.cloned()),
+            ..slice.clone()
+        })
+        .collect()
+}
+
 /// Merge complete ingest outputs from multiple sources.
 pub fn merge_ingest_outputs(
     ingest_outputs: &[IngestOutput],
@@ -125,24 +182,30 @@ pub fn merge_ingest_outputs(
     }
 
     let base_coverage = &ingest_outputs[0].coverage;
-    let mut event_groups = Vec::with_capacity(ingest_outputs.len());
     let mut all_sources = Vec::new();
     let mut all_warnings = Vec::new();
     let mut all_slices: Vec<CoverageSlice> = Vec::new();
     let mut input_event_count = 0usize;
 
     let mut all_freshness = Vec::new();
+    let mut all_budget = Vec::new();
     for ingest in ingest_outputs {
         input_event_count += ingest.events.len();
-        event_groups.push(ingest.events.clone());
         all_sources.extend(ingest.coverage.sources.clone());
         all_warnings.extend(ingest.coverage.warnings.clone());
-        all_slices.extend(ingest.coverage.slices.clone());
+        all_slices.extend(attributed_slices(&ingest.coverage));
         all_freshness.extend(ingest.freshness.clone());
+        all_budget.extend(ingest.budget.clone());
     }
 
-    let merged_events = merge_events(event_groups, &resolution.into());
+    let merged_events = merge_event_streams(
+        ingest_outputs
+            .iter()
+            .map(|ingest| ingest.events.iter().cloned()),
+        &resolution.into(),
+    );
     let mut coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("merge"),
         generated_at: Utc::now(),
         user: base_coverage.user.clone(),
@@ -172,6 +235,9 @@ pub fn merge_ingest_outputs(
         } else {
             Completeness::Complete
         },
+        score: None,
+        timezone: base_coverage.timezone.clone(),
+        budget: all_budget.clone(),
     };
 
     let conflict_count = input_event_count.saturating_sub(merged_events.len());
@@ -191,6 +257,7 @@ pub fn merge_ingest_outputs(
             events: merged_events,
             coverage,
             freshness: all_freshness,
+            budget: all_budget,
         },
         report,
     })
@@ -204,38 +271,44 @@ pub fn merge_ingest_outputs_legacy(
     ingest_outputs: &[IngestOutput],
     resolution: ConflictResolution,
 ) -> Result<IngestOutput> {
-    use std::collections::HashMap;
-
     if ingest_outputs.is_empty() {
         return Err(anyhow!("No ingest outputs to merge"));
     }
 
-    let mut event_map: HashMap<String, Vec<EventEnvelope>> = HashMap::new();
+    let mut duplicate_groups: Vec<Vec<EventEnvelope>> = Vec::new();
+    let mut index = DuplicateIndex::default();
     let mut all_sources: Vec<String> = Vec::new();
     let mut all_warnings: Vec<String> = Vec::new();
     let mut all_slices: Vec<shiplog::schema::coverage::CoverageSlice> = Vec::new();
     let mut all_freshness: Vec<shiplog::schema::freshness::SourceFreshness> = Vec::new();
+    let mut all_budget: Vec<shiplog::schema::coverage::RateBudget> = Vec::new();
 
     let base_output = &ingest_outputs[0];
     let window = base_output.coverage.window.clone();
     let user = base_output.coverage.user.clone();
+    let timezone = base_output.coverage.timezone.clone();
 
     for ingest in ingest_outputs {
         for event in &ingest.events {
-            let id = event.id.to_string();
-            event_map.entry(id).or_default().push(event.clone());
+            let slot = index.find(event).unwrap_or_else(|| {
+                duplicate_groups.push(Vec::new());
+                duplicate_groups.len() - 1
+            });
+            index.insert(event, slot);
+            duplicate_groups[slot].push(event.clone());
         }
 
         all_sources.extend(ingest.coverage.sources.clone());
         all_warnings.extend(ingest.coverage.warnings.clone());
-        all_slices.extend(ingest.coverage.slices.clone());
+        all_slices.extend(attributed_slices(&ingest.coverage));
         all_freshness.extend(ingest.freshness.clone());
+        all_budget.extend(ingest.budget.clone());
     }
 
     let mut merged_events: Vec<EventEnvelope> = Vec::new();
     let mut conflict_count = 0usize;
 
-    for (_id, events) in event_map {
+    for events in duplicate_groups {
         if events.len() == 1 {
             merged_events.push(events[0].clone());
         } else {
@@ -269,6 +342,7 @@ pub fn merge_ingest_outputs_legacy(
     }
 
     let coverage = shiplog::schema::coverage::CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("merge"),
         generated_at: chrono::Utc::now(),
         user,
@@ -278,20 +352,75 @@ pub fn merge_ingest_outputs_legacy(
         slices: all_slices,
         warnings: all_warnings,
         completeness,
+        score: None,
+        timezone,
+        budget: all_budget.clone(),
     };
 
     Ok(IngestOutput {
         events: merged_events,
         coverage,
         freshness: all_freshness,
+        budget: all_budget,
     })
 }
 
+/// Positions of kept events, reachable by event id and by canonical id.
+///
+/// Two copies are duplicates when either key matches, so a fresh event that
+/// carries a canonical id still meets an older copy of itself that only
+/// shares the event id.
+#[derive(Default)]
+struct DuplicateIndex {
+    slots: HashMap<String, usize>,
+}
+
+impl DuplicateIndex {
+    fn find(&self, event: &EventEnvelope) -> Option<usize> {
+        event
+            .canonical_id
+            .as_ref()
+            .and_then(|canonical| self.slots.get(&format!("canonical:{canonical}")))
+            .or_else(|| self.slots.get(&format!("event:{}", event.id)))
+            .copied()
+    }
+
+    fn insert(&mut self, event: &EventEnvelope, slot: usize) {
+        if let Some(canonical) = &event.canonical_id {
+            self.slots.insert(format!("canonical:{canonical}"), slot);
+        }
+        self.slots.insert(format!("event:{}", event.id), slot);
+    }
+}
+
+/// Copy the canonical id, extension entries, and contributors from `other`
+/// that `kept` does not already have.
+fn absorb_duplicate(kept: &mut EventEnvelope, other: &EventEnvelope) {
+    if kept.canonical_id.is_none() {
+        kept.canonical_id.clone_from(&other.canonical_id);
+    }
+    for (key, value) in &other.extensions {
+        kept.extensions
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
+    for contributor in &other.contributors {
+        let known = contributor.login == kept.actor.login
+            || kept
+                .contributors
+                .iter()
+                .any(|existing| existing.login == contributor.login);
+        if !known {
+            kept.contributors.push(contributor.clone());
+        }
+    }
+}
+
 fn resolve_conflict_legacy(
     events: &[EventEnvelope],
     resolution: ConflictResolution,
 ) -> EventEnvelope {
-    match resolution {
+    let mut winner = match resolution {
         ConflictResolution::PreferFirst => events[0].clone(),
         ConflictResolution::PreferMostRecent => events
             .iter()
@@ -303,7 +432,11 @@ fn resolve_conflict_legacy(
             .max_by_key(|e| completeness_score_legacy(e))
             .cloned()
             .unwrap(),
+    };
+    for event in events {
+        absorb_duplicate(&mut winner, event);
     }
+    winner
 }
 
 fn completeness_score_legacy(event: &EventEnvelope) -> usize {
@@ -365,6 +498,54 @@ fn completeness_score_legacy(event: &EventEnvelope) -> usize {
                 score += 1;
             }
         }
+        shiplog::schema::event::EventPayload::Issue(issue) => {
+            if !issue.labels.is_empty() {
+                score += 1;
+            }
+            if issue.closed_at.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Deployment(deployment) => {
+            if deployment.version.is_some() {
+                score += 1;
+            }
+            if deployment.finished_at.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Release(release) => {
+            if release.name.is_some() {
+                score += 1;
+            }
+            if release.published_at.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Comment(comment) => {
+            if comment.target_number.is_some() {
+                score += 1;
+            }
+            if comment.thread.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Incident(incident) => {
+            if incident.resolved_at.is_some() {
+                score += 1;
+            }
+            if incident.summary.is_some() {
+                score += 1;
+            }
+            if incident.customer_impact.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Document(document)
+            if document.word_count_delta.is_some() =>
+        {
+            score += 1;
+        }
         _ => {}
     }
 
@@ -407,6 +588,60 @@ fn completeness_score(event: &EventEnvelope) -> u32 {
                 score += 2;
             }
         }
+        shiplog::schema::event::EventPayload::Issue(issue) => {
+            score += 6;
+            if !issue.labels.is_empty() {
+                score += 1;
+            }
+            if issue.closed_at.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Deployment(deployment) => {
+            score += 6;
+            if deployment.version.is_some() {
+                score += 1;
+            }
+            if deployment.finished_at.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Release(release) => {
+            score += 6;
+            if release.name.is_some() {
+                score += 1;
+            }
+            if release.published_at.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Comment(comment) => {
+            score += 4;
+            if comment.target_number.is_some() {
+                score += 1;
+            }
+            if comment.thread.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Incident(incident) => {
+            score += 6;
+            if incident.resolved_at.is_some() {
+                score += 1;
+            }
+            if incident.summary.is_some() {
+                score += 1;
+            }
+            if incident.customer_impact.is_some() {
+                score += 1;
+            }
+        }
+        shiplog::schema::event::EventPayload::Document(document) => {
+            score += 5;
+            if document.word_count_delta.is_some() {
+                score += 1;
+            }
+        }
     }
 
     // Check source completeness
@@ -435,13 +670,15 @@ mod tests {
     use super::*;
     use chrono::{NaiveDate, TimeZone, Utc};
     use shiplog::ids::EventId;
-    use shiplog::schema::coverage::{CoverageManifest, CoverageSlice, TimeWindow};
+    use shiplog::schema::coverage::{CoverageManifest, CoverageSlice, RateBudget, TimeWindow};
     use shiplog::schema::event::{
-        Actor, EventKind, EventPayload, ManualEvent, ManualEventType, RepoRef, RepoVisibility,
+        Actor, Contributor, ContributorRole, EventKind, EventPayload, IssueEvent, IssueState,
+        ManualEvent, ManualEventType, PullRequestEvent, PullRequestState, RepoRef, RepoVisibility,
         SourceRef, SourceSystem,
     };
     fn make_event(id: &str, occurred_at: chrono::DateTime<chrono::Utc>) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts([id]),
             kind: EventKind::Manual,
             occurred_at,
@@ -461,6 +698,9 @@ mod tests {
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![],
@@ -469,6 +709,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -489,6 +732,7 @@ mod tests {
         warning: &str,
     ) -> CoverageManifest {
         CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc.timestamp_nanos(1),
             user: "tester".to_string(),
@@ -508,9 +752,13 @@ mod tests {
                 fetched: w as u64,
                 incomplete_results: None,
                 notes: vec![],
+                source: None,
             }],
             warnings: vec![warning.to_string()],
             completeness,
+            score: None,
+            timezone: None,
+            budget: vec![],
         }
     }
 
@@ -543,6 +791,30 @@ mod tests {
         assert_eq!(result[0].occurred_at, event2.occurred_at);
     }
 
+    #[test]
+    fn merge_streams_jsonl_ledgers_without_collecting_them() {
+        let first = make_event("1", Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        let later = make_event("1", Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap());
+        let other = make_event("2", Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap());
+        let ledgers: Vec<Vec<u8>> = [vec![first], vec![later.clone(), other]]
+            .iter()
+            .map(|events| {
+                let mut buf = Vec::new();
+                crate::engine::write_events(&mut buf, events).unwrap();
+                buf
+            })
+            .collect();
+
+        let result = merge_event_streams(
+            ledgers
+                .iter()
+                .map(|buf| crate::engine::read_events(buf.as_slice()).map(Result::unwrap)),
+            &MergeStrategy::KeepLast,
+        );
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].occurred_at, later.occurred_at);
+    }
+
     #[test]
     fn merge_keeps_first_strategy() {
         let event1 = make_event("1", Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
@@ -609,6 +881,15 @@ mod tests {
             ],
             coverage: coverage(2, Completeness::Partial, "github", "a.warning"),
             freshness: Vec::new(),
+            budget: vec![RateBudget {
+                source: "github".to_string(),
+                host: "api.github.com".to_string(),
+                requests: 4,
+                rate_limited: 1,
+                limit: Some(30),
+                remaining: Some(0),
+                reset_at: None,
+            }],
         };
         let ingest_b = IngestOutput {
             events: vec![
@@ -617,6 +898,7 @@ mod tests {
             ],
             coverage: coverage(2, Completeness::Complete, "local_git", "b.warning"),
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
 
         let merged =
@@ -632,6 +914,19 @@ mod tests {
             Completeness::Partial
         );
         assert_eq!(merged.ingest_output.coverage.mode, "merged");
+        let slice_sources: Vec<_> = merged
+            .ingest_output
+            .coverage
+            .slices
+            .iter()
+            .map(|slice| slice.source.as_deref())
+            .collect();
+        assert_eq!(slice_sources, [Some("github"), Some("local_git")]);
+        assert_eq!(merged.ingest_output.budget.len(), 1);
+        assert_eq!(
+            merged.ingest_output.coverage.budget,
+            merged.ingest_output.budget
+        );
     }
 
     #[test]
@@ -675,6 +970,149 @@ mod tests {
         assert_eq!(result.len(), 1);
     }
 
+    #[test]
+    fn merge_keeps_extensions_from_discarded_duplicates() {
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap();
+        let mut older = make_event("x", t1);
+        older
+            .extensions
+            .insert("gitlab.pipeline_id".into(), 4412.into());
+        older
+            .extensions
+            .insert("public.ci_status".into(), "red".into());
+        let mut newer = make_event("x", t2);
+        newer
+            .extensions
+            .insert("public.ci_status".into(), "green".into());
+
+        for result in [
+            merge_two(&[older.clone()], &[newer.clone()], &MergeStrategy::KeepLast),
+            merge_two(&[newer.clone()], &[older.clone()], &MergeStrategy::KeepLast),
+        ] {
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].occurred_at, t2);
+            assert_eq!(result[0].extensions["public.ci_status"], "green");
+            assert_eq!(result[0].extensions["gitlab.pipeline_id"], 4412);
+        }
+
+        let legacy = merge_ingest_outputs_legacy(
+            &[
+                IngestOutput {
+                    events: vec![older],
+                    coverage: coverage(1, Completeness::Complete, "a", ""),
+                    freshness: Vec::new(),
+                    budget: Vec::new(),
+                },
+                IngestOutput {
+                    events: vec![newer],
+                    coverage: coverage(1, Completeness::Complete, "b", ""),
+                    freshness: Vec::new(),
+                    budget: Vec::new(),
+                },
+            ],
+            ConflictResolution::PreferMostRecent,
+        )
+        .unwrap();
+        assert_eq!(legacy.events[0].extensions.len(), 2);
+        assert_eq!(legacy.events[0].extensions["public.ci_status"], "green");
+    }
+
+    #[test]
+    fn merge_unions_contributors_across_duplicates() {
+        let t = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let pair = |login: &str, role| Contributor {
+            login: login.to_string(),
+            id: None,
+            role,
+        };
+        let mut from_git = make_event("x", t);
+        from_git.contributors = vec![pair("alice", ContributorRole::CoAuthor)];
+        let mut from_manual = make_event("x", t);
+        from_manual.contributors = vec![
+            pair("alice", ContributorRole::Pair),
+            pair("testuser", ContributorRole::Pair),
+            pair("bob", ContributorRole::Pair),
+        ];
+
+        let result = merge_two(&[from_git], &[from_manual], &MergeStrategy::KeepFirst);
+        let logins: Vec<_> = result[0]
+            .contributors
+            .iter()
+            .map(|c| (c.login.as_str(), c.role.clone()))
+            .collect();
+        assert_eq!(
+            logins,
+            vec![
+                ("alice", ContributorRole::CoAuthor),
+                ("bob", ContributorRole::Pair),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_collapses_same_pull_request_across_ingest_paths() {
+        let t = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let pr = |id: &str, repo: &str, number| {
+            let mut event = make_event(id, t);
+            event.kind = EventKind::PullRequest;
+            event.repo.full_name = repo.to_string();
+            event.payload = EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: "Add retries".to_string(),
+                state: PullRequestState::Merged,
+                created_at: t,
+                merged_at: Some(t),
+                additions: None,
+                deletions: None,
+                changed_files: None,
+                touched_paths_hint: vec![],
+                window: None,
+            });
+            event
+        };
+        let rest = pr("github-rest", "owner/test", 7).with_canonical_id();
+        // A teammate's ledger, ingested under a different event id scheme.
+        let teammate = pr("teammate", "Owner/Test", 7).with_canonical_id();
+        let other = pr("github-rest-8", "owner/test", 8).with_canonical_id();
+        // Without a stored canonical id, events still deduplicate by id only.
+        let unstamped = pr("unstamped", "owner/test", 7);
+        // An older ledger's copy of the same ingest still matches by id.
+        let older_copy = pr("github-rest", "owner/test", 7);
+
+        let result = merge_events(
+            vec![
+                vec![teammate, other, unstamped],
+                vec![rest.clone(), older_copy],
+            ],
+            &MergeStrategy::KeepFirst,
+        );
+        assert_eq!(result.len(), 3);
+        let kept = result
+            .iter()
+            .find(|e| e.canonical_id == rest.canonical_id)
+            .unwrap();
+        assert_eq!(kept.id, EventId::from_parts(["teammate"]));
+
+        let outputs = [
+            IngestOutput {
+                events: vec![pr("a", "owner/test", 7).with_canonical_id()],
+                coverage: coverage(1, Completeness::Complete, "github", ""),
+                freshness: Vec::new(),
+                budget: Vec::new(),
+            },
+            IngestOutput {
+                events: vec![rest],
+                coverage: coverage(1, Completeness::Complete, "json", ""),
+                freshness: Vec::new(),
+                budget: Vec::new(),
+            },
+        ];
+        let legacy =
+            merge_ingest_outputs_legacy(&outputs, ConflictResolution::PreferFirst).unwrap();
+        assert_eq!(legacy.events.len(), 1);
+    }
+
     #[test]
     fn merge_keeps_most_complete_strategy() {
         let t = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
@@ -688,6 +1126,33 @@ mod tests {
         assert_eq!(result[0].tags, rich.tags);
     }
 
+    #[test]
+    fn merge_keeps_most_complete_issue() {
+        let t = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let issue = |labels: Vec<String>, closed_at| {
+            let mut event = make_event("i", t);
+            event.kind = EventKind::Issue;
+            event.payload = EventPayload::Issue(IssueEvent {
+                number: 7,
+                title: "Flaky check".to_string(),
+                state: IssueState::Closed,
+                labels,
+                opened_at: t,
+                closed_at,
+                window: None,
+                effort: None,
+            });
+            event
+        };
+        let sparse = issue(vec![], None);
+        let rich = issue(vec!["bug".to_string()], Some(t));
+        let result = merge_events(
+            vec![vec![sparse], vec![rich.clone()]],
+            &MergeStrategy::KeepMostComplete,
+        );
+        assert_eq!(result, vec![rich]);
+    }
+
     #[test]
     fn merge_preserves_order_same_timestamp() {
         let t = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
@@ -737,6 +1202,7 @@ mod tests {
             )],
             coverage: coverage(1, Completeness::Complete, "github", ""),
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         let merged = merge_ingest_outputs(&[ingest], ConflictResolution::PreferFirst).unwrap();
         assert_eq!(merged.ingest_output.events.len(), 1);
@@ -752,6 +1218,7 @@ mod tests {
             )],
             coverage: coverage(1, Completeness::Complete, "github", ""),
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         let ingest_b = IngestOutput {
             events: vec![make_event(
@@ -760,6 +1227,7 @@ mod tests {
             )],
             coverage: coverage(1, Completeness::Complete, "local_git", ""),
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         let merged =
             merge_ingest_outputs(&[ingest_a, ingest_b], ConflictResolution::PreferFirst).unwrap();
@@ -783,6 +1251,7 @@ mod tests {
             events: vec![make_event("a", t)],
             coverage: coverage(1, Completeness::Complete, "github", ""),
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         let merged =
             merge_ingest_outputs_legacy(&[ingest.clone(), ingest], ConflictResolution::PreferFirst)
@@ -810,11 +1279,13 @@ mod tests {
             events: vec![make_event("a", t1), make_event("shared", t1)],
             coverage: coverage(2, Completeness::Complete, "github", "warn-a"),
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         let ingest_b = IngestOutput {
             events: vec![make_event("shared", t2), make_event("b", t2)],
             coverage: coverage(2, Completeness::Complete, "local_git", "warn-b"),
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         let merged =
             merge_ingest_outputs(&[ingest_a, ingest_b], ConflictResolution::PreferMostRecent)
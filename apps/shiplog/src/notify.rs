@@ -0,0 +1,279 @@
+//! Completion notifications for finished runs.
+//!
+//! When `collect multi` or `intake` finishes (including runs started by cron
+//! or CI), shiplog can post a short summary to a Slack incoming webhook or
+//! send it by email over SMTP. Targets are configured under `[notify]` in
+//! `shiplog.toml`; the CLI reports a failed delivery as a warning and never
+//! fails the run because of it.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+use reqwest::blocking::Client;
+
+use crate::readiness::ReadinessGrade;
+
+/// How long a single delivery may take before it is abandoned.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Most warnings listed in a notification; the rest are counted.
+const SUMMARY_WARNING_LIMIT: usize = 5;
+
+/// What a notification says about a finished run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunNotification {
+    /// Run the summary describes.
+    pub run_id: String,
+    /// Events in the run ledger.
+    pub events: usize,
+    /// Coverage warnings and skipped sources, in report order.
+    pub warnings: Vec<String>,
+    /// Weighted readiness score from 0 to 100.
+    pub readiness_score: u8,
+    /// Letter grade for `readiness_score`.
+    pub readiness_grade: ReadinessGrade,
+    /// Where the packet was written, as shown to the user.
+    pub packet: String,
+}
+
+impl RunNotification {
+    /// One-line subject for email and chat previews.
+    pub fn subject(&self) -> String {
+        format!(
+            "shiplog run {}: {} event{}, readiness {}/100 ({})",
+            self.run_id,
+            self.events,
+            if self.events == 1 { "" } else { "s" },
+            self.readiness_score,
+            self.readiness_grade
+        )
+    }
+
+    /// Plain-text body shared by every target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::notify::RunNotification;
+    /// use shiplog::readiness::ReadinessGrade;
+    ///
+    /// let summary = RunNotification {
+    ///     run_id: "run_1".into(),
+    ///     events: 12,
+    ///     warnings: vec!["jira: skipped (missing JIRA_TOKEN)".into()],
+    ///     readiness_score: 82,
+    ///     readiness_grade: ReadinessGrade::B,
+    ///     packet: "out/run_1/packet.md".into(),
+    /// };
+    ///
+    /// let text = summary.text();
+    /// assert!(text.contains("Events collected: 12"));
+    /// assert!(text.contains("Readiness: 82/100 (B)"));
+    /// assert!(text.contains("- jira: skipped (missing JIRA_TOKEN)"));
+    /// ```
+    pub fn text(&self) -> String {
+        let mut out = format!("shiplog run {} finished.\n\n", self.run_id);
+        out.push_str(&format!("Events collected: {}\n", self.events));
+        out.push_str(&format!(
+            "Readiness: {}/100 ({})\n",
+            self.readiness_score, self.readiness_grade
+        ));
+        out.push_str(&format!("Warnings: {}\n", self.warnings.len()));
+        for warning in self.warnings.iter().take(SUMMARY_WARNING_LIMIT) {
+            out.push_str(&format!("- {warning}\n"));
+        }
+        if self.warnings.len() > SUMMARY_WARNING_LIMIT {
+            out.push_str(&format!(
+                "- + {} more in coverage.report.md\n",
+                self.warnings.len() - SUMMARY_WARNING_LIMIT
+            ));
+        }
+        out.push_str(&format!("\nPacket: {}\n", self.packet));
+        out
+    }
+}
+
+/// A place run summaries can be delivered to.
+pub trait Notifier {
+    /// Short target name used in warnings, e.g. `slack`.
+    fn name(&self) -> &'static str;
+
+    /// Deliver `summary`.
+    fn send(&self, summary: &RunNotification) -> Result<()>;
+}
+
+/// Posts summaries to a Slack incoming webhook.
+#[derive(Clone, Debug)]
+pub struct SlackWebhook {
+    url: String,
+}
+
+impl SlackWebhook {
+    /// Create a notifier for the webhook at `url`.
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let url = url.into();
+        let parsed = url::Url::parse(&url).context("parse Slack webhook URL")?;
+        if !matches!(parsed.scheme(), "https" | "http") {
+            bail!("Slack webhook URL must use https, got {}", parsed.scheme());
+        }
+        Ok(Self { url })
+    }
+}
+
+impl Notifier for SlackWebhook {
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+
+    fn send(&self, summary: &RunNotification) -> Result<()> {
+        let client = Client::builder()
+            .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+            .timeout(DELIVERY_TIMEOUT)
+            .build()
+            .context("build reqwest client")?;
+        let body = serde_json::json!({ "text": summary.text() });
+        client
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .context("post to Slack webhook")?
+            .error_for_status()
+            .context("Slack webhook rejected the message")?;
+        Ok(())
+    }
+}
+
+/// How the SMTP connection is secured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with `STARTTLS` (port 587).
+    #[default]
+    StartTls,
+    /// TLS from the first byte (port 465).
+    Tls,
+    /// No encryption; only for local relays.
+    None,
+}
+
+impl std::str::FromStr for SmtpSecurity {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "starttls" => Ok(Self::StartTls),
+            "tls" => Ok(Self::Tls),
+            "none" => Ok(Self::None),
+            other => Err(anyhow!(
+                "unknown SMTP security {other:?}; use starttls, tls, or none"
+            )),
+        }
+    }
+}
+
+/// Sends summaries by email through an SMTP relay.
+#[derive(Clone)]
+pub struct SmtpEmail {
+    host: String,
+    port: Option<u16>,
+    security: SmtpSecurity,
+    credentials: Option<(String, String)>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl SmtpEmail {
+    /// Create a notifier that relays through `host` from `from` to every
+    /// address in `to`.
+    pub fn new(host: impl Into<String>, from: &str, to: &[String]) -> Result<Self> {
+        let host = host.into();
+        if host.trim().is_empty() {
+            bail!("SMTP host is empty");
+        }
+        let from = from
+            .parse()
+            .with_context(|| format!("parse SMTP from address {from:?}"))?;
+        if to.is_empty() {
+            bail!("SMTP notification needs at least one recipient");
+        }
+        let to = to
+            .iter()
+            .map(|address| {
+                address
+                    .parse()
+                    .with_context(|| format!("parse SMTP recipient {address:?}"))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            host,
+            port: None,
+            security: SmtpSecurity::default(),
+            credentials: None,
+            from,
+            to,
+        })
+    }
+
+    /// Connect to `port` instead of the default for the security mode.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Choose how the connection is secured.
+    pub fn with_security(mut self, security: SmtpSecurity) -> Self {
+        self.security = security;
+        self
+    }
+
+    /// Authenticate with `username` and `password`.
+    pub fn with_credentials(mut self, username: String, password: String) -> Self {
+        self.credentials = Some((username, password));
+        self
+    }
+
+    fn message(&self, summary: &RunNotification) -> Result<Message> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(summary.subject());
+        for to in &self.to {
+            builder = builder.to(to.clone());
+        }
+        builder
+            .header(ContentType::TEXT_PLAIN)
+            .body(summary.text())
+            .context("build notification email")
+    }
+}
+
+impl Notifier for SmtpEmail {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    fn send(&self, summary: &RunNotification) -> Result<()> {
+        let message = self.message(summary)?;
+        let mut transport = match self.security {
+            SmtpSecurity::StartTls => SmtpTransport::starttls_relay(&self.host)
+                .with_context(|| format!("configure STARTTLS relay {}", self.host))?,
+            SmtpSecurity::Tls => SmtpTransport::relay(&self.host)
+                .with_context(|| format!("configure TLS relay {}", self.host))?,
+            SmtpSecurity::None => SmtpTransport::builder_dangerous(&self.host),
+        };
+        if let Some(port) = self.port {
+            transport = transport.port(port);
+        }
+        if let Some((username, password)) = &self.credentials {
+            transport = transport.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+        transport
+            .timeout(Some(DELIVERY_TIMEOUT))
+            .build()
+            .send(&message)
+            .with_context(|| format!("send notification email via {}", self.host))?;
+        Ok(())
+    }
+}
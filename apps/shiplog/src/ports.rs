@@ -1,16 +1,17 @@
 #![warn(missing_docs)]
 //! Port trait definitions for the shiplog pipeline.
 //!
-//! Defines the four core abstractions: [`Ingestor`] (data collection),
-//! [`WorkstreamClusterer`] (event grouping), [`Renderer`] (output generation),
-//! and [`Redactor`] (privacy-aware projection). Adapters depend on ports;
-//! ports never depend on adapters.
+//! Defines the core abstractions: [`Ingestor`] (data collection),
+//! [`WorkstreamClusterer`] (event grouping), [`Summarizer`] (draft workstream
+//! summaries), [`Renderer`] (output generation), and [`Redactor`]
+//! (privacy-aware projection). Adapters depend on ports; ports never depend on
+//! adapters.
 
 use anyhow::Result;
-use shiplog::schema::coverage::CoverageManifest;
+use shiplog::schema::coverage::{CoverageManifest, RateBudget, TimeWindow};
 use shiplog::schema::event::EventEnvelope;
 use shiplog::schema::freshness::SourceFreshness;
-use shiplog::schema::workstream::WorkstreamsFile;
+use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
 
 /// Output of an ingestion run.
 ///
@@ -23,13 +24,14 @@ use shiplog::schema::workstream::WorkstreamsFile;
 ///
 /// ```
 /// use shiplog::ports::IngestOutput;
-/// use shiplog::schema::coverage::{CoverageManifest, Completeness, TimeWindow};
+/// use shiplog::schema::coverage::{CoverageManifest, Completeness, SchemaVersion, TimeWindow};
 /// use chrono::{NaiveDate, Utc};
 /// use shiplog::ids::RunId;
 ///
 /// let output = IngestOutput {
 ///     events: vec![],
 ///     coverage: CoverageManifest {
+///         schema_version: SchemaVersion::CURRENT,
 ///         run_id: RunId::now("test"),
 ///         generated_at: Utc::now(),
 ///         user: "octocat".into(),
@@ -42,8 +44,12 @@ use shiplog::schema::workstream::WorkstreamsFile;
 ///         slices: vec![],
 ///         warnings: vec![],
 ///         completeness: Completeness::Complete,
+///         score: None,
+///         timezone: None,
+///         budget: vec![],
 ///     },
 ///     freshness: vec![],
+///     budget: Vec::new(),
 /// };
 /// assert!(output.events.is_empty());
 /// ```
@@ -58,6 +64,10 @@ pub struct IngestOutput {
     /// tolerate `freshness.is_empty()` and fall back to other signals
     /// (source decisions, coverage) for those sources.
     pub freshness: Vec<SourceFreshness>,
+    /// API requests and rate-limit headroom per HTTP source, also recorded in
+    /// [`CoverageManifest::budget`]. Empty for adapters that send no API
+    /// requests.
+    pub budget: Vec<RateBudget>,
 }
 
 /// Basic ingestion trait.
@@ -81,6 +91,93 @@ pub struct IngestOutput {
 pub trait Ingestor {
     /// Fetch events from the data source and return them with coverage metadata.
     fn ingest(&self) -> Result<IngestOutput>;
+
+    /// Describe what [`ingest`](Ingestor::ingest) would request, without
+    /// sending anything.
+    ///
+    /// Returns `None` for sources that make no API calls (local files and
+    /// repositories). The default implementation returns `None`.
+    fn plan(&self) -> Result<Option<IngestPlan>> {
+        Ok(None)
+    }
+}
+
+/// What an [`Ingestor`] would fetch, as reported by [`Ingestor::plan`].
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ports::{IngestPlan, PlannedQuery};
+/// use shiplog::schema::coverage::TimeWindow;
+/// use chrono::NaiveDate;
+///
+/// let window = TimeWindow {
+///     since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///     until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+/// };
+/// let plan = IngestPlan {
+///     source: "github".into(),
+///     window: window.clone(),
+///     queries: vec![
+///         PlannedQuery {
+///             window: window.clone(),
+///             query: "is:pr author:octocat".into(),
+///             estimated_requests: 2,
+///             cached: false,
+///         },
+///         PlannedQuery {
+///             window,
+///             query: "is:pr reviewed-by:octocat".into(),
+///             estimated_requests: 2,
+///             cached: true,
+///         },
+///     ],
+///     notes: vec![],
+/// };
+/// assert_eq!(plan.estimated_requests(), 2);
+/// assert_eq!(plan.cached_queries(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IngestPlan {
+    /// Source name, as recorded in the coverage manifest.
+    pub source: String,
+    /// The whole collection window.
+    pub window: TimeWindow,
+    /// Queries in the order they would be sent.
+    pub queries: Vec<PlannedQuery>,
+    /// Costs the plan cannot count up front, such as per-item detail
+    /// fetches or discovery requests.
+    pub notes: Vec<String>,
+}
+
+impl IngestPlan {
+    /// Requests the plan expects to send; cached queries cost none.
+    pub fn estimated_requests(&self) -> u64 {
+        self.queries
+            .iter()
+            .filter(|query| !query.cached)
+            .map(|query| query.estimated_requests)
+            .sum()
+    }
+
+    /// Queries whose first page is already in the API cache.
+    pub fn cached_queries(&self) -> usize {
+        self.queries.iter().filter(|query| query.cached).count()
+    }
+}
+
+/// One query inside an [`IngestPlan`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedQuery {
+    /// Window the query covers.
+    pub window: TimeWindow,
+    /// Query text as the source receives it.
+    pub query: String,
+    /// Fewest requests the query costs on a cold cache. Windows over a
+    /// result cap are sliced further and cost more.
+    pub estimated_requests: u64,
+    /// Whether the query's first page would be served from the API cache.
+    pub cached: bool,
 }
 
 /// Workstream clustering.
@@ -92,7 +189,7 @@ pub trait Ingestor {
 /// ```rust,no_run
 /// use shiplog::ports::WorkstreamClusterer;
 /// use shiplog::schema::event::EventEnvelope;
-/// use shiplog::schema::workstream::WorkstreamsFile;
+/// use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
 /// use anyhow::Result;
 ///
 /// struct RepoClusterer;
@@ -108,6 +205,33 @@ pub trait WorkstreamClusterer {
     fn cluster(&self, events: &[EventEnvelope]) -> Result<WorkstreamsFile>;
 }
 
+/// Workstream summarization.
+///
+/// Drafts the narrative `summary` for one suggested workstream. Callers pass
+/// events that are already redacted, so adapters that call out to a model
+/// never see more than a manager packet would show.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::ports::Summarizer;
+/// use shiplog::schema::event::EventEnvelope;
+/// use shiplog::schema::workstream::Workstream;
+/// use anyhow::Result;
+///
+/// struct CountSummarizer;
+///
+/// impl Summarizer for CountSummarizer {
+///     fn summarize(&self, workstream: &Workstream, events: &[&EventEnvelope]) -> Result<String> {
+///         Ok(format!("{} events in {}.", events.len(), workstream.title))
+///     }
+/// }
+/// ```
+pub trait Summarizer {
+    /// Draft summary text for `workstream` from its redacted `events`.
+    fn summarize(&self, workstream: &Workstream, events: &[&EventEnvelope]) -> Result<String>;
+}
+
 /// Rendering.
 ///
 /// Renderers should be pure: input in, bytes out.
@@ -117,8 +241,8 @@ pub trait WorkstreamClusterer {
 /// ```rust,no_run
 /// use shiplog::ports::Renderer;
 /// use shiplog::schema::event::EventEnvelope;
-/// use shiplog::schema::workstream::WorkstreamsFile;
-/// use shiplog::schema::coverage::CoverageManifest;
+/// use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
+/// use shiplog::schema::coverage::{CoverageManifest, TimeWindow};
 /// use anyhow::Result;
 ///
 /// struct MarkdownRenderer;
@@ -157,7 +281,7 @@ pub trait Renderer {
 /// ```rust,no_run
 /// use shiplog::ports::Redactor;
 /// use shiplog::schema::event::EventEnvelope;
-/// use shiplog::schema::workstream::WorkstreamsFile;
+/// use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
 /// use anyhow::Result;
 ///
 /// struct NoOpRedactor;
@@ -180,4 +304,11 @@ pub trait Redactor {
         workstreams: &WorkstreamsFile,
         profile: &str,
     ) -> Result<WorkstreamsFile>;
+    /// Digest of the rule set this redactor applies for a profile, recorded
+    /// in bundle manifests. Defaults to the built-in profile rules.
+    fn rules_digest(&self, profile: &str) -> String {
+        shiplog::redact::redaction_rules_digest(
+            shiplog::redact::RedactionProfile::from_profile_str(profile),
+        )
+    }
 }
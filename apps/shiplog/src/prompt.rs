@@ -0,0 +1,62 @@
+//! `shiplog prompt`: an LLM handoff for drafting self-review prose.
+//!
+//! Redacts a run's events and workstreams for the chosen share profile and
+//! renders them as one prompt. The user pastes it into whichever model they
+//! trust; shiplog itself makes no model calls.
+
+use anyhow::{Context, Result};
+
+use crate::*;
+
+pub(super) fn run_prompt(args: PromptArgs) -> Result<()> {
+    let redaction_key = RedactionKey::resolve(args.redact_key, &args.bundle_profile)?;
+    let run_dir = resolve_render_run_dir(&args.out, args.run, args.latest)?;
+    let ingest =
+        load_run_ingest(&run_dir).with_context(|| format!("load run {}", run_dir.display()))?;
+    let (workstreams, _, _) = load_effective_workstreams_for_run(&run_dir)?;
+
+    let profile = args.bundle_profile.as_str();
+    let redactor = DeterministicRedactor::new(redaction_key.engine_key().as_bytes());
+    let _ = redactor.load_cache(&DeterministicRedactor::cache_path(&run_dir));
+    let events = redactor
+        .redact_events(&ingest.events, profile)
+        .with_context(|| format!("redact events for {profile} prompt"))?;
+    let workstreams = redactor
+        .redact_workstreams(&workstreams, profile)
+        .with_context(|| format!("redact workstreams for {profile} prompt"))?;
+
+    let coverage = &ingest.coverage;
+    // Profile packets never print the user label; keep it out of the prompt too.
+    let user = match args.bundle_profile {
+        BundleProfile::Internal => coverage.user.as_str(),
+        BundleProfile::Manager | BundleProfile::Public | BundleProfile::Custom(_) => "the author",
+    };
+    let window_label = format!("{}..{}", coverage.window.since, coverage.window.until);
+    let options = cli_render_options(
+        RenderPacketMode::Packet,
+        args.receipt_limit,
+        None,
+        &args.bundle_profile,
+    );
+    let prompt = cli_packet_renderer()
+        .render_llm_prompt(
+            user,
+            &window_label,
+            profile,
+            &events,
+            &workstreams,
+            coverage,
+            options,
+        )
+        .context("render LLM prompt")?;
+
+    match args.output {
+        Some(path) => {
+            std::fs::write(&path, &prompt).with_context(|| format!("write {}", path.display()))?;
+            println!("Wrote {profile} prompt: {}", display_path_for_cli(&path));
+            println!("Paste it into your LLM; shiplog did not send it anywhere.");
+        }
+        None => print!("{prompt}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,484 @@
+//! Google Docs publisher for `shiplog publish google-docs`.
+//!
+//! Creates one document per run and profile through the Drive API, optionally
+//! inside a folder, and fills it with a single Docs `batchUpdate`. Later
+//! publishes rename the document and replace its body. Headings, bullets,
+//! numbered lists, quotes, and code keep their structure as native paragraph
+//! styles; bold, italic, code, and links survive as text styles, which is what
+//! copy-pasting packet markdown into a doc loses.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, RequestBuilder};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{Block, Span, is_absolute_url};
+
+/// Google Docs REST API root.
+pub const DEFAULT_DOCS_API: &str = "https://docs.googleapis.com";
+
+/// Google Drive REST API root.
+pub const DEFAULT_DRIVE_API: &str = "https://www.googleapis.com";
+
+const DOCUMENT_MIME_TYPE: &str = "application/vnd.google-apps.document";
+
+/// Upper bound on any single API call.
+const REQUEST_TIMEOUT: Duration = Duration::from_mins(1);
+
+/// A document written by the publisher.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GoogleDoc {
+    /// Document id.
+    pub id: String,
+    /// Document link.
+    pub url: String,
+}
+
+impl GoogleDoc {
+    fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+        let url = format!("https://docs.google.com/document/d/{id}/edit");
+        Self { id, url }
+    }
+}
+
+#[derive(Deserialize)]
+struct DriveFile {
+    id: String,
+    #[serde(default)]
+    trashed: bool,
+}
+
+/// Client for the Drive files and Docs documents APIs.
+pub struct GoogleDocsPublisher {
+    docs_api: String,
+    drive_api: String,
+    token: String,
+    client: Client,
+}
+
+impl std::fmt::Debug for GoogleDocsPublisher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GoogleDocsPublisher")
+            .field("docs_api", &self.docs_api)
+            .field("drive_api", &self.drive_api)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GoogleDocsPublisher {
+    /// Create a publisher authenticating with an OAuth access `token` that
+    /// carries the `documents` and `drive.file` scopes.
+    pub fn new(token: impl Into<String>) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("build reqwest client")?;
+        Ok(Self {
+            docs_api: DEFAULT_DOCS_API.to_string(),
+            drive_api: DEFAULT_DRIVE_API.to_string(),
+            token: token.into(),
+            client,
+        })
+    }
+
+    /// Send both Docs and Drive calls to `api_base` instead of Google.
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        let api_base = api_base.into().trim_end_matches('/').to_string();
+        self.docs_api.clone_from(&api_base);
+        self.drive_api = api_base;
+        self
+    }
+
+    /// Write `blocks` to the document `existing`, or to a new document (in
+    /// `folder_id` when given) when there is none or it has been deleted.
+    pub fn publish(
+        &self,
+        folder_id: Option<&str>,
+        existing: Option<&str>,
+        title: &str,
+        blocks: &[Block],
+    ) -> Result<GoogleDoc> {
+        let content = DocContent::from_blocks(blocks);
+        if let Some(document_id) = existing {
+            match self.replace_document(document_id, title, &content)? {
+                Some(doc) => return Ok(doc),
+                None => {
+                    tracing::warn!("Google Doc {document_id} no longer exists; creating a new doc.")
+                }
+            }
+        }
+        self.create_document(folder_id, title, &content)
+    }
+
+    fn create_document(
+        &self,
+        folder_id: Option<&str>,
+        title: &str,
+        content: &DocContent,
+    ) -> Result<GoogleDoc> {
+        let mut body = json!({ "name": title, "mimeType": DOCUMENT_MIME_TYPE });
+        if let Some(folder_id) = folder_id {
+            body["parents"] = json!([folder_id]);
+        }
+        let file: DriveFile = self
+            .send(
+                self.request(
+                    reqwest::Method::POST,
+                    &format!("{}/drive/v3/files?supportsAllDrives=true", self.drive_api),
+                )
+                .json(&body),
+            )
+            .context("create Google Doc")?
+            .json()
+            .context("parse Drive file")?;
+        self.batch_update(&file.id, content.requests(1))?;
+        Ok(GoogleDoc::new(file.id))
+    }
+
+    /// Rename and rewrite an existing document. `None` when the document is
+    /// gone or trashed.
+    fn replace_document(
+        &self,
+        document_id: &str,
+        title: &str,
+        content: &DocContent,
+    ) -> Result<Option<GoogleDoc>> {
+        let response = self
+            .request(
+                reqwest::Method::PATCH,
+                &format!(
+                    "{}/drive/v3/files/{document_id}?fields=id,trashed&supportsAllDrives=true",
+                    self.drive_api
+                ),
+            )
+            .json(&json!({ "name": title }))
+            .send()
+            .context("rename Google Doc")?;
+        if matches!(response.status(), StatusCode::NOT_FOUND) {
+            return Ok(None);
+        }
+        let file: DriveFile = check(response)
+            .context("rename Google Doc")?
+            .json()
+            .context("parse Drive file")?;
+        if file.trashed {
+            return Ok(None);
+        }
+
+        let document: Value = self
+            .send(self.request(
+                reqwest::Method::GET,
+                &format!("{}/v1/documents/{document_id}", self.docs_api),
+            ))
+            .context("read Google Doc")?
+            .json()
+            .context("parse Google Doc")?;
+        let end = body_end_index(&document);
+        let mut requests = Vec::new();
+        // The body always ends with a newline that cannot be deleted.
+        if end > 2 {
+            requests.push(json!({
+                "deleteContentRange": {
+                    "range": { "startIndex": 1, "endIndex": end - 1 },
+                },
+            }));
+        }
+        requests.extend(content.requests(1));
+        self.batch_update(&file.id, requests)?;
+        Ok(Some(GoogleDoc::new(file.id)))
+    }
+
+    fn batch_update(&self, document_id: &str, requests: Vec<Value>) -> Result<()> {
+        if requests.is_empty() {
+            return Ok(());
+        }
+        self.send(
+            self.request(
+                reqwest::Method::POST,
+                &format!("{}/v1/documents/{document_id}:batchUpdate", self.docs_api),
+            )
+            .json(&json!({ "requests": requests })),
+        )
+        .context("write Google Doc content")?;
+        Ok(())
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str) -> RequestBuilder {
+        self.client.request(method, url).bearer_auth(&self.token)
+    }
+
+    fn send(&self, request: RequestBuilder) -> Result<reqwest::blocking::Response> {
+        check(request.send()?)
+    }
+}
+
+/// Turn non-2xx responses into errors carrying Google's message.
+fn check(response: reqwest::blocking::Response) -> Result<reqwest::blocking::Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body: Value = response.json().unwrap_or_default();
+    let message = body["error"]["message"]
+        .as_str()
+        .unwrap_or("no error message");
+    if status == StatusCode::UNAUTHORIZED {
+        bail!("Google rejected the access token ({status}): {message}");
+    }
+    if status == StatusCode::FORBIDDEN {
+        bail!(
+            "Google returned {status}: {message}; the token needs the documents and drive.file scopes"
+        );
+    }
+    Err(anyhow!("Google returned {status}: {message}"))
+}
+
+/// End index of the document body, just past its trailing newline.
+fn body_end_index(document: &Value) -> i64 {
+    document["body"]["content"]
+        .as_array()
+        .and_then(|content| content.last())
+        .and_then(|element| element["endIndex"].as_i64())
+        .unwrap_or(1)
+}
+
+/// Packet text laid out as document paragraphs, plus the styles to apply.
+///
+/// Offsets are in UTF-16 code units from the start of `text`, which is how
+/// Docs indexes a document.
+#[derive(Debug, Default)]
+pub struct DocContent {
+    text: String,
+    len: i64,
+    paragraphs: Vec<Paragraph>,
+    runs: Vec<Run>,
+}
+
+#[derive(Debug)]
+struct Paragraph {
+    start: i64,
+    end: i64,
+    style: ParagraphKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParagraphKind {
+    Heading(u8),
+    Normal,
+    Bullet,
+    Numbered,
+    Quote,
+    Code,
+}
+
+#[derive(Debug)]
+struct Run {
+    start: i64,
+    end: i64,
+    span: Span,
+}
+
+impl DocContent {
+    /// Lay out packet blocks as paragraphs.
+    pub fn from_blocks(blocks: &[Block]) -> Self {
+        let mut content = Self::default();
+        for block in blocks {
+            match block {
+                Block::Heading { level, spans } => {
+                    content.paragraph(ParagraphKind::Heading(*level), 0, spans);
+                }
+                Block::Paragraph(spans) => content.paragraph(ParagraphKind::Normal, 0, spans),
+                Block::Bullet { depth, spans } => {
+                    content.paragraph(ParagraphKind::Bullet, *depth, spans);
+                }
+                Block::Numbered { depth, spans } => {
+                    content.paragraph(ParagraphKind::Numbered, *depth, spans);
+                }
+                Block::Quote(spans) => content.paragraph(ParagraphKind::Quote, 0, spans),
+                Block::Code(text) => {
+                    // One paragraph per line so tables and code keep their rows.
+                    for line in text.lines() {
+                        content.paragraph(ParagraphKind::Code, 0, &[Span::plain(line)]);
+                    }
+                }
+                Block::Divider => content.paragraph(ParagraphKind::Normal, 0, &[]),
+            }
+        }
+        content
+    }
+
+    /// Text inserted into the document, one line per paragraph.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn paragraph(&mut self, style: ParagraphKind, depth: usize, spans: &[Span]) {
+        let start = self.len;
+        // Leading tabs become nesting levels when the bullets are created.
+        if matches!(style, ParagraphKind::Bullet | ParagraphKind::Numbered) {
+            self.push_text(&"\t".repeat(depth));
+        }
+        for span in spans {
+            let run_start = self.len;
+            self.push_text(&span.text);
+            let styled = span.bold || span.italic || span.code || span.link.is_some();
+            if styled && self.len > run_start {
+                self.runs.push(Run {
+                    start: run_start,
+                    end: self.len,
+                    span: span.clone(),
+                });
+            }
+        }
+        self.push_text("\n");
+        self.paragraphs.push(Paragraph {
+            start,
+            end: self.len,
+            style,
+        });
+    }
+
+    fn push_text(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.len += text.encode_utf16().count() as i64;
+    }
+
+    /// `batchUpdate` requests that insert and style the content at `index`
+    /// in an empty body.
+    ///
+    /// Styles that leave the text alone come first. Bullets go last, from the
+    /// end of the document backwards, because creating them strips the
+    /// leading tabs and would shift every later offset.
+    pub fn requests(&self, index: i64) -> Vec<Value> {
+        if self.text.is_empty() {
+            return Vec::new();
+        }
+        let range =
+            |start: i64, end: i64| json!({ "startIndex": index + start, "endIndex": index + end });
+        let whole = range(0, self.len);
+
+        let mut requests = vec![
+            json!({ "insertText": { "location": { "index": index }, "text": self.text } }),
+            // Inserted text inherits the style of the paragraph it lands in;
+            // reset it so a rewrite starts clean.
+            json!({ "deleteParagraphBullets": { "range": whole } }),
+            json!({
+                "updateParagraphStyle": {
+                    "range": whole,
+                    "paragraphStyle": { "namedStyleType": "NORMAL_TEXT" },
+                    "fields": "namedStyleType,indentStart,indentFirstLine",
+                },
+            }),
+            json!({
+                "updateTextStyle": {
+                    "range": whole,
+                    "textStyle": {},
+                    "fields": "bold,italic,link,weightedFontFamily",
+                },
+            }),
+        ];
+
+        for paragraph in &self.paragraphs {
+            let paragraph_range = range(paragraph.start, paragraph.end);
+            match paragraph.style {
+                ParagraphKind::Heading(level) => requests.push(json!({
+                    "updateParagraphStyle": {
+                        "range": paragraph_range,
+                        "paragraphStyle": { "namedStyleType": format!("HEADING_{}", level.clamp(1, 6)) },
+                        "fields": "namedStyleType",
+                    },
+                })),
+                ParagraphKind::Quote => requests.push(json!({
+                    "updateParagraphStyle": {
+                        "range": paragraph_range,
+                        "paragraphStyle": {
+                            "indentStart": { "magnitude": 36, "unit": "PT" },
+                            "indentFirstLine": { "magnitude": 36, "unit": "PT" },
+                        },
+                        "fields": "indentStart,indentFirstLine",
+                    },
+                })),
+                ParagraphKind::Code if paragraph.end - paragraph.start > 1 => {
+                    requests.push(json!({
+                        "updateTextStyle": {
+                            "range": range(paragraph.start, paragraph.end - 1),
+                            "textStyle": { "weightedFontFamily": { "fontFamily": "Roboto Mono" } },
+                            "fields": "weightedFontFamily",
+                        },
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        for run in &self.runs {
+            let mut style = serde_json::Map::new();
+            let mut fields = Vec::new();
+            if run.span.bold {
+                style.insert("bold".into(), json!(true));
+                fields.push("bold");
+            }
+            if run.span.italic {
+                style.insert("italic".into(), json!(true));
+                fields.push("italic");
+            }
+            if run.span.code {
+                style.insert(
+                    "weightedFontFamily".into(),
+                    json!({ "fontFamily": "Roboto Mono" }),
+                );
+                fields.push("weightedFontFamily");
+            }
+            if let Some(url) = run.span.link.as_ref().filter(|url| is_absolute_url(url)) {
+                style.insert("link".into(), json!({ "url": url }));
+                fields.push("link");
+            }
+            if fields.is_empty() {
+                continue;
+            }
+            requests.push(json!({
+                "updateTextStyle": {
+                    "range": range(run.start, run.end),
+                    "textStyle": style,
+                    "fields": fields.join(","),
+                },
+            }));
+        }
+
+        // Consecutive items of one kind form a single list so numbering and
+        // nesting carry across them.
+        let mut lists: Vec<(ParagraphKind, i64, i64)> = Vec::new();
+        for paragraph in &self.paragraphs {
+            if !matches!(
+                paragraph.style,
+                ParagraphKind::Bullet | ParagraphKind::Numbered
+            ) {
+                continue;
+            }
+            match lists.last_mut() {
+                Some((kind, _, end)) if *kind == paragraph.style && *end == paragraph.start => {
+                    *end = paragraph.end;
+                }
+                _ => lists.push((paragraph.style, paragraph.start, paragraph.end)),
+            }
+        }
+        for (kind, start, end) in lists.into_iter().rev() {
+            let preset = match kind {
+                ParagraphKind::Numbered => "NUMBERED_DECIMAL_ALPHA_ROMAN",
+                _ => "BULLET_DISC_CIRCLE_SQUARE",
+            };
+            requests.push(json!({
+                "createParagraphBullets": {
+                    "range": range(start, end),
+                    "bulletPreset": preset,
+                },
+            }));
+        }
+        requests
+    }
+}
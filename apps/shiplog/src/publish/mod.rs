@@ -0,0 +1,350 @@
+//! Publishers that push a rendered packet into external document tools.
+//!
+//! Packets are markdown written by [`crate::render::md`]. Publishers do not
+//! re-render from the ledger; they parse that markdown into [`Block`]s and map
+//! each block onto the target's native structure, so what gets published is
+//! exactly the packet (and profile) on disk.
+//!
+//! Each publish is recorded in the run directory as a [`PublishRecord`], so
+//! publishing the same run and profile again updates the existing page instead
+//! of creating a new one.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+pub mod google_docs;
+pub mod notion;
+
+/// A run of text with uniform formatting.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    /// Plain text.
+    pub text: String,
+    /// `**bold**`.
+    pub bold: bool,
+    /// `_italic_` or `*italic*`.
+    pub italic: bool,
+    /// `` `code` ``.
+    pub code: bool,
+    /// Target of `[text](url)`.
+    pub link: Option<String>,
+}
+
+impl Span {
+    /// Unformatted text.
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// One structural element of a packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Block {
+    /// `#` through `######`.
+    Heading {
+        /// 1 for `#`, 2 for `##`, and so on.
+        level: u8,
+        /// Heading text.
+        spans: Vec<Span>,
+    },
+    /// A paragraph; consecutive lines are joined with spaces.
+    Paragraph(Vec<Span>),
+    /// `- item`; `depth` is 0 for top-level items.
+    Bullet {
+        /// Nesting depth from indentation.
+        depth: usize,
+        /// Item text.
+        spans: Vec<Span>,
+    },
+    /// `1. item`; `depth` is 0 for top-level items.
+    Numbered {
+        /// Nesting depth from indentation.
+        depth: usize,
+        /// Item text.
+        spans: Vec<Span>,
+    },
+    /// `> quote`.
+    Quote(Vec<Span>),
+    /// Fenced code or a markdown table, kept verbatim.
+    Code(String),
+    /// `---`.
+    Divider,
+}
+
+/// Parse packet markdown into blocks.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::publish::{Block, Span, parse_markdown};
+///
+/// let blocks = parse_markdown("# Packet\n\n- Shipped [#12](https://example.com/12)\n");
+/// assert_eq!(
+///     blocks[0],
+///     Block::Heading { level: 1, spans: vec![Span::plain("Packet")] }
+/// );
+/// assert!(matches!(&blocks[1], Block::Bullet { depth: 0, spans } if spans[1].link.is_some()));
+/// ```
+pub fn parse_markdown(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut lines = markdown.lines().peekable();
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(parse_inline(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            flush(&mut paragraph, &mut blocks);
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push(line);
+            }
+            blocks.push(Block::Code(code.join("\n")));
+            continue;
+        }
+        if trimmed.starts_with('|') {
+            flush(&mut paragraph, &mut blocks);
+            let mut table = vec![trimmed];
+            while let Some(next) = lines.next_if(|next| next.trim().starts_with('|')) {
+                table.push(next.trim());
+            }
+            blocks.push(Block::Code(table.join("\n")));
+            continue;
+        }
+        if let Some((level, text)) = heading(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading {
+                level,
+                spans: parse_inline(text),
+            });
+            continue;
+        }
+        if matches!(trimmed, "---" | "***" | "___") {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Divider);
+            continue;
+        }
+        if let Some(text) = trimmed.strip_prefix('>') {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Quote(parse_inline(text.trim())));
+            continue;
+        }
+        let depth = indent_depth(line);
+        if let Some(text) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Bullet {
+                depth,
+                spans: parse_inline(text.trim()),
+            });
+            continue;
+        }
+        if let Some(text) = numbered_item(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Numbered {
+                depth,
+                spans: parse_inline(text),
+            });
+            continue;
+        }
+        paragraph.push(trimmed);
+    }
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+/// Plain text of `spans`, without formatting.
+pub fn spans_text(spans: &[Span]) -> String {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.bytes().take_while(|byte| *byte == b'#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let text = line[level..].strip_prefix(' ')?;
+    Some((level as u8, text.trim()))
+}
+
+fn numbered_item(line: &str) -> Option<&str> {
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..]
+        .strip_prefix(". ")
+        .or_else(|| line[digits..].strip_prefix(") "))
+        .map(str::trim)
+}
+
+/// Two spaces (or a tab) of indentation per nesting level.
+fn indent_depth(line: &str) -> usize {
+    let width: usize = line
+        .chars()
+        .take_while(|ch| ch.is_whitespace())
+        .map(|ch| if ch == '\t' { 4 } else { 1 })
+        .sum();
+    width / 2
+}
+
+/// Parse `**bold**`, `_italic_`/`*italic*`, `` `code` ``, and `[text](url)`.
+pub fn parse_inline(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut current = Span::default();
+    let mut rest = text;
+
+    let push = |spans: &mut Vec<Span>, current: &mut Span| {
+        if !current.text.is_empty() {
+            let next = Span {
+                text: String::new(),
+                ..current.clone()
+            };
+            spans.push(std::mem::replace(current, next));
+        }
+    };
+
+    while let Some(ch) = rest.chars().next() {
+        if ch == '`'
+            && let Some(end) = rest[1..].find('`')
+        {
+            push(&mut spans, &mut current);
+            spans.push(Span {
+                text: rest[1..1 + end].to_string(),
+                code: true,
+                ..current.clone()
+            });
+            rest = &rest[end + 2..];
+            continue;
+        }
+        if ch == '['
+            && let Some((label, url, consumed)) = link(rest)
+        {
+            push(&mut spans, &mut current);
+            for mut span in parse_inline(label) {
+                span.bold |= current.bold;
+                span.italic |= current.italic;
+                span.link = Some(url.to_string());
+                spans.push(span);
+            }
+            rest = &rest[consumed..];
+            continue;
+        }
+        if rest.starts_with("**") || rest.starts_with("__") {
+            push(&mut spans, &mut current);
+            current.bold = !current.bold;
+            rest = &rest[2..];
+            continue;
+        }
+        if (ch == '*' || ch == '_') && emphasis_boundary(&current, rest) {
+            push(&mut spans, &mut current);
+            current.italic = !current.italic;
+            rest = &rest[1..];
+            continue;
+        }
+        current.text.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    push(&mut spans, &mut current);
+    spans
+}
+
+/// Single `*`/`_` toggle italics only at word edges, so `snake_case` and
+/// `a * b` stay literal.
+fn emphasis_boundary(current: &Span, rest: &str) -> bool {
+    let next = rest[1..].chars().next();
+    let previous = current.text.chars().last();
+    if current.italic {
+        previous.is_some_and(|ch| !ch.is_whitespace())
+            && next.is_none_or(|ch| !ch.is_alphanumeric())
+    } else {
+        previous.is_none_or(|ch| !ch.is_alphanumeric())
+            && next.is_some_and(|ch| !ch.is_whitespace())
+            && rest[1..].contains(&rest[..1])
+    }
+}
+
+/// Targets only accept absolute links; packet links to local files stay text.
+pub(crate) fn is_absolute_url(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://") || url.starts_with("mailto:")
+}
+
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let close = text.find("](")?;
+    let label = &text[1..close];
+    let after = &text[close + 2..];
+    let end = after.find(')')?;
+    let url = &after[..end];
+    if label.is_empty() || url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((label, url, close + 2 + end + 1))
+}
+
+/// Where a run's packet was published, saved under `publish/` in the run
+/// directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublishRecord {
+    /// Publisher, e.g. `notion`.
+    pub target: String,
+    /// Bundle profile that was published.
+    pub profile: String,
+    /// Target's id for the published document.
+    pub document_id: String,
+    /// Link to the published document.
+    pub url: String,
+    /// When the document was last written.
+    pub published_at: DateTime<Utc>,
+}
+
+impl PublishRecord {
+    /// Record path for `target` and `profile` in `run_dir`.
+    pub fn path(run_dir: &Path, target: &str, profile: &str) -> PathBuf {
+        run_dir
+            .join("publish")
+            .join(format!("{target}.{profile}.json"))
+    }
+
+    /// Read the record at `path`, if one exists.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+        serde_json::from_str(&text)
+            .map(Some)
+            .with_context(|| format!("parse {}", path.display()))
+    }
+
+    /// Write the record to `path`, creating `publish/` if needed.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("create {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("write {}", path.display()))
+    }
+}
@@ -0,0 +1,325 @@
+//! Notion publisher for `shiplog publish notion`.
+//!
+//! Creates one child page per run and profile under a parent page the
+//! integration has been shared with, or rewrites that page's content on later
+//! publishes. Packet blocks map onto Notion's native headings, list items,
+//! quotes, code blocks, and dividers, with bold, italic, code, and links kept
+//! as rich-text annotations.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, RequestBuilder};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{Block, Span, is_absolute_url};
+
+/// Notion REST API root.
+pub const DEFAULT_NOTION_API: &str = "https://api.notion.com";
+
+/// API version sent in the `Notion-Version` header.
+pub const NOTION_VERSION: &str = "2022-06-28";
+
+/// Notion accepts at most this many blocks per children array.
+const MAX_CHILDREN: usize = 100;
+
+/// Notion rejects rich-text objects longer than this.
+const MAX_TEXT_LEN: usize = 2000;
+
+/// Notion accepts two levels of nested children in one request.
+const MAX_DEPTH: usize = 2;
+
+/// Upper bound on any single API call.
+const REQUEST_TIMEOUT: Duration = Duration::from_mins(1);
+
+/// A page written by the publisher.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct NotionPage {
+    /// Page id.
+    pub id: String,
+    /// Page link.
+    #[serde(default)]
+    pub url: String,
+}
+
+/// Client for the Notion pages and blocks APIs.
+pub struct NotionPublisher {
+    api_base: String,
+    token: String,
+    client: Client,
+}
+
+impl std::fmt::Debug for NotionPublisher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotionPublisher")
+            .field("api_base", &self.api_base)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NotionPublisher {
+    /// Create a publisher authenticating with an integration `token`.
+    pub fn new(token: impl Into<String>) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("build reqwest client")?;
+        Ok(Self {
+            api_base: DEFAULT_NOTION_API.to_string(),
+            token: token.into(),
+            client,
+        })
+    }
+
+    /// Talk to `api_base` instead of the public API.
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Write `blocks` to the page `existing`, or to a new page under
+    /// `parent_page_id` when there is none or it has been deleted.
+    pub fn publish(
+        &self,
+        parent_page_id: &str,
+        existing: Option<&str>,
+        title: &str,
+        blocks: &[Block],
+    ) -> Result<NotionPage> {
+        let children = notion_blocks(blocks);
+        if let Some(page_id) = existing {
+            match self.replace_page(page_id, title, &children)? {
+                Some(page) => return Ok(page),
+                None => {
+                    tracing::warn!("Notion page {page_id} no longer exists; creating a new page.")
+                }
+            }
+        }
+        self.create_page(parent_page_id, title, &children)
+    }
+
+    fn create_page(
+        &self,
+        parent_page_id: &str,
+        title: &str,
+        children: &[Value],
+    ) -> Result<NotionPage> {
+        let (first, rest) = children.split_at(children.len().min(MAX_CHILDREN));
+        let body = json!({
+            "parent": { "page_id": parent_page_id },
+            "properties": { "title": { "title": rich_text(&[Span::plain(title)]) } },
+            "children": first,
+        });
+        let page: NotionPage = self
+            .send(self.request(reqwest::Method::POST, "/v1/pages").json(&body))
+            .context("create Notion page")?
+            .json()
+            .context("parse Notion page")?;
+        self.append(&page.id, rest)?;
+        Ok(page)
+    }
+
+    /// Rewrite an existing page. `None` when the page is gone or archived.
+    fn replace_page(
+        &self,
+        page_id: &str,
+        title: &str,
+        children: &[Value],
+    ) -> Result<Option<NotionPage>> {
+        let body = json!({
+            "properties": { "title": { "title": rich_text(&[Span::plain(title)]) } },
+        });
+        let response = self
+            .request(reqwest::Method::PATCH, &format!("/v1/pages/{page_id}"))
+            .json(&body)
+            .send()
+            .context("update Notion page")?;
+        if matches!(response.status(), StatusCode::NOT_FOUND) {
+            return Ok(None);
+        }
+        let page: Value = check(response)
+            .context("update Notion page")?
+            .json()
+            .context("parse Notion page")?;
+        if page["archived"].as_bool() == Some(true) || page["in_trash"].as_bool() == Some(true) {
+            return Ok(None);
+        }
+
+        for child in self.child_ids(page_id)? {
+            self.send(self.request(reqwest::Method::DELETE, &format!("/v1/blocks/{child}")))
+                .context("clear Notion page")?;
+        }
+        self.append(page_id, children)?;
+        Ok(Some(NotionPage {
+            id: page_id.to_string(),
+            url: page["url"].as_str().unwrap_or_default().to_string(),
+        }))
+    }
+
+    fn child_ids(&self, page_id: &str) -> Result<Vec<String>> {
+        let mut ids = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut path = format!("/v1/blocks/{page_id}/children?page_size=100");
+            if let Some(cursor) = &cursor {
+                path.push_str(&format!("&start_cursor={cursor}"));
+            }
+            let page: Value = self
+                .send(self.request(reqwest::Method::GET, &path))
+                .context("list Notion page content")?
+                .json()
+                .context("parse Notion page content")?;
+            ids.extend(
+                page["results"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|block| block["id"].as_str().map(str::to_string)),
+            );
+            match page["next_cursor"].as_str() {
+                Some(next) if page["has_more"].as_bool() == Some(true) => {
+                    cursor = Some(next.to_string());
+                }
+                _ => return Ok(ids),
+            }
+        }
+    }
+
+    fn append(&self, page_id: &str, children: &[Value]) -> Result<()> {
+        for chunk in children.chunks(MAX_CHILDREN) {
+            self.send(
+                self.request(
+                    reqwest::Method::PATCH,
+                    &format!("/v1/blocks/{page_id}/children"),
+                )
+                .json(&json!({ "children": chunk })),
+            )
+            .context("append Notion page content")?;
+        }
+        Ok(())
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("{}{path}", self.api_base))
+            .bearer_auth(&self.token)
+            .header("Notion-Version", NOTION_VERSION)
+    }
+
+    fn send(&self, request: RequestBuilder) -> Result<reqwest::blocking::Response> {
+        check(request.send()?)
+    }
+}
+
+/// Turn non-2xx responses into errors carrying Notion's message.
+fn check(response: reqwest::blocking::Response) -> Result<reqwest::blocking::Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body: Value = response.json().unwrap_or_default();
+    let message = body["message"].as_str().unwrap_or("no error message");
+    if status == StatusCode::UNAUTHORIZED {
+        bail!("Notion rejected the token ({status}): {message}");
+    }
+    if status == StatusCode::NOT_FOUND {
+        bail!("Notion returned {status}: {message}; share the parent page with the integration");
+    }
+    Err(anyhow!("Notion returned {status}: {message}"))
+}
+
+/// Map packet blocks onto Notion block objects, nesting list items under
+/// their parent item.
+pub fn notion_blocks(blocks: &[Block]) -> Vec<Value> {
+    let mut roots: Vec<Value> = Vec::new();
+    for block in blocks {
+        let (depth, value) = match block {
+            Block::Heading { level, spans } => {
+                let kind = match level {
+                    1 => "heading_1",
+                    2 => "heading_2",
+                    _ => "heading_3",
+                };
+                (0, text_block(kind, spans))
+            }
+            Block::Paragraph(spans) => (0, text_block("paragraph", spans)),
+            Block::Bullet { depth, spans } => (*depth, text_block("bulleted_list_item", spans)),
+            Block::Numbered { depth, spans } => (*depth, text_block("numbered_list_item", spans)),
+            Block::Quote(spans) => (0, text_block("quote", spans)),
+            Block::Code(text) => (
+                0,
+                json!({
+                    "object": "block",
+                    "type": "code",
+                    "code": { "rich_text": rich_text(&[Span::plain(text.clone())]), "language": "plain text" },
+                }),
+            ),
+            Block::Divider => (
+                0,
+                json!({ "object": "block", "type": "divider", "divider": {} }),
+            ),
+        };
+        push_nested(&mut roots, depth.min(MAX_DEPTH), value);
+    }
+    roots
+}
+
+/// Append `value` as the last child `depth` levels down the trailing list
+/// item, or at the top level when there is no list item to nest under.
+fn push_nested(siblings: &mut Vec<Value>, depth: usize, value: Value) {
+    if depth > 0
+        && let Some(parent) = siblings.last_mut()
+        && let Some(kind) = parent["type"].as_str().map(str::to_string)
+        && matches!(kind.as_str(), "bulleted_list_item" | "numbered_list_item")
+    {
+        let children = parent[&kind]
+            .as_object_mut()
+            .expect("list item blocks are objects")
+            .entry("children")
+            .or_insert_with(|| Value::Array(Vec::new()));
+        if let Value::Array(children) = children {
+            push_nested(children, depth - 1, value);
+            return;
+        }
+    }
+    siblings.push(value);
+}
+
+fn text_block(kind: &str, spans: &[Span]) -> Value {
+    let mut block = serde_json::Map::new();
+    block.insert("object".into(), json!("block"));
+    block.insert("type".into(), json!(kind));
+    block.insert(kind.into(), json!({ "rich_text": rich_text(spans) }));
+    Value::Object(block)
+}
+
+/// Notion rich-text objects, split to stay under the per-object length limit.
+fn rich_text(spans: &[Span]) -> Vec<Value> {
+    let mut objects = Vec::new();
+    for span in spans {
+        let chars: Vec<char> = span.text.chars().collect();
+        for chunk in chars.chunks(MAX_TEXT_LEN) {
+            let content: String = chunk.iter().collect();
+            objects.push(json!({
+                "type": "text",
+                "text": {
+                    "content": content,
+                    "link": span
+                        .link
+                        .as_ref()
+                        .filter(|url| is_absolute_url(url))
+                        .map(|url| json!({ "url": url })),
+                },
+                "annotations": {
+                    "bold": span.bold,
+                    "italic": span.italic,
+                    "code": span.code,
+                },
+            }));
+        }
+    }
+    objects
+}
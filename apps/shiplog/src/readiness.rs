@@ -0,0 +1,398 @@
+//! Graded packet readiness.
+//!
+//! Combines four signals into one 0–100 score and a letter grade:
+//!
+//! - **evidence density**: curated receipts per workstream,
+//! - **claim completion**: workstreams with a written summary,
+//! - **coverage**: the coverage manifest score,
+//! - **manual balance**: how much of the ledger is manual context.
+//!
+//! The score describes the packet, not the person whose work it covers. Each
+//! component that falls short adds a remediation suggestion, ordered by how
+//! many points fixing it would recover.
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::coverage::{Completeness, CoverageManifest};
+use crate::schema::event::{EventEnvelope, EventPayload, SourceSystem};
+use crate::schema::workstream::{Workstream, WorkstreamsFile};
+
+/// Curated receipts a workstream needs for full evidence density.
+pub const TARGET_RECEIPTS_PER_WORKSTREAM: usize = 3;
+
+/// Manual share of events below which the packet lacks context.
+const MIN_MANUAL_SHARE: f64 = 0.05;
+
+/// Manual share of events above which claims lean on self-reporting.
+const MAX_MANUAL_SHARE: f64 = 0.5;
+
+/// Letter grade for a readiness score.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ReadinessGrade {
+    /// 90 and above.
+    A,
+    /// 75 to 89.
+    B,
+    /// 60 to 74.
+    C,
+    /// 40 to 59.
+    D,
+    /// Below 40.
+    F,
+}
+
+impl ReadinessGrade {
+    /// Grade for a 0–100 score.
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            90.. => Self::A,
+            75..=89 => Self::B,
+            60..=74 => Self::C,
+            40..=59 => Self::D,
+            _ => Self::F,
+        }
+    }
+}
+
+impl std::fmt::Display for ReadinessGrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letter = match self {
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::F => "F",
+        };
+        f.write_str(letter)
+    }
+}
+
+/// One input to the readiness score.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessComponentKind {
+    /// Curated receipts per workstream.
+    EvidenceDensity,
+    /// Workstreams with a written claim summary.
+    ClaimCompletion,
+    /// Coverage manifest score.
+    Coverage,
+    /// Manual context relative to source evidence.
+    ManualBalance,
+}
+
+impl ReadinessComponentKind {
+    /// Share of the overall score, in percent. The weights sum to 100.
+    pub fn weight(self) -> u8 {
+        match self {
+            Self::EvidenceDensity => 30,
+            Self::ClaimCompletion => 25,
+            Self::Coverage => 25,
+            Self::ManualBalance => 20,
+        }
+    }
+
+    /// Human label.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::EvidenceDensity => "Evidence density",
+            Self::ClaimCompletion => "Claim completion",
+            Self::Coverage => "Coverage",
+            Self::ManualBalance => "Manual balance",
+        }
+    }
+}
+
+/// Score for one component.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessComponent {
+    /// Which signal this is.
+    pub kind: ReadinessComponentKind,
+    /// Component score from 0 to 100.
+    pub score: u8,
+    /// Share of the overall score, in percent.
+    pub weight: u8,
+    /// What the score was computed from.
+    pub detail: String,
+}
+
+/// Readiness of one workstream.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkstreamReadiness {
+    /// Workstream title.
+    pub title: String,
+    /// Events assigned to the workstream.
+    pub events: usize,
+    /// Curated receipts.
+    pub receipts: usize,
+    /// Evidence density from 0 to 100.
+    pub density: u8,
+    /// Whether the workstream has a written summary.
+    pub claim_complete: bool,
+}
+
+/// A specific fix that would raise the score.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessSuggestion {
+    /// Component the fix improves.
+    pub component: ReadinessComponentKind,
+    /// Workstream the fix applies to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workstream: Option<String>,
+    /// What to do.
+    pub message: String,
+}
+
+/// Graded readiness of a packet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessAssessment {
+    /// Weighted score from 0 to 100.
+    pub score: u8,
+    /// Letter grade for `score`.
+    pub grade: ReadinessGrade,
+    /// Per-component scores in a fixed order.
+    pub components: Vec<ReadinessComponent>,
+    /// Per-workstream detail in workstream order.
+    pub workstreams: Vec<WorkstreamReadiness>,
+    /// Remediation suggestions, highest-impact first.
+    pub suggestions: Vec<ReadinessSuggestion>,
+}
+
+/// Assess packet readiness from a run's workstreams, events, and coverage.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::readiness::{ReadinessGrade, assess_readiness};
+/// use shiplog::schema::workstream::WorkstreamsFile;
+/// use shiplog_testkit::fixtures::test_coverage;
+/// use shiplog::schema::coverage::Completeness;
+/// use chrono::Utc;
+///
+/// let workstreams = WorkstreamsFile {
+///     version: 1,
+///     generated_at: Utc::now(),
+///     workstreams: vec![],
+/// };
+/// let coverage = test_coverage("octo", Completeness::Complete);
+///
+/// // Nothing collected yet: nothing to review.
+/// let assessment = assess_readiness(&workstreams, &[], &coverage);
+/// assert_eq!(assessment.grade, ReadinessGrade::F);
+/// assert!(!assessment.suggestions.is_empty());
+/// ```
+pub fn assess_readiness(
+    workstreams: &WorkstreamsFile,
+    events: &[EventEnvelope],
+    coverage: &CoverageManifest,
+) -> ReadinessAssessment {
+    let per_workstream: Vec<WorkstreamReadiness> = workstreams
+        .workstreams
+        .iter()
+        .map(workstream_readiness)
+        .collect();
+
+    let mut suggestions = Vec::new();
+    let components = vec![
+        evidence_density(&per_workstream, &mut suggestions),
+        claim_completion(&per_workstream, &mut suggestions),
+        coverage_component(coverage, &mut suggestions),
+        manual_balance(events, &mut suggestions),
+    ];
+
+    let score = components
+        .iter()
+        .map(|component| u32::from(component.score) * u32::from(component.weight))
+        .sum::<u32>();
+    let score = ((score + 50) / 100).min(100) as u8;
+
+    // Highest recoverable points first; workstream order breaks ties.
+    let lost = |kind: ReadinessComponentKind| {
+        components
+            .iter()
+            .find(|component| component.kind == kind)
+            .map_or(0, |component| {
+                u32::from(100 - component.score) * u32::from(component.weight)
+            })
+    };
+    suggestions.sort_by_key(|suggestion| std::cmp::Reverse(lost(suggestion.component)));
+
+    ReadinessAssessment {
+        score,
+        grade: ReadinessGrade::from_score(score),
+        components,
+        workstreams: per_workstream,
+        suggestions,
+    }
+}
+
+fn workstream_readiness(workstream: &Workstream) -> WorkstreamReadiness {
+    let receipts = workstream.receipts.len();
+    WorkstreamReadiness {
+        title: workstream.title.clone(),
+        events: workstream.events.len(),
+        receipts,
+        density: percent(
+            receipts.min(TARGET_RECEIPTS_PER_WORKSTREAM),
+            TARGET_RECEIPTS_PER_WORKSTREAM,
+        ),
+        claim_complete: workstream
+            .summary
+            .as_deref()
+            .is_some_and(|summary| !summary.trim().is_empty()),
+    }
+}
+
+fn evidence_density(
+    workstreams: &[WorkstreamReadiness],
+    suggestions: &mut Vec<ReadinessSuggestion>,
+) -> ReadinessComponent {
+    let kind = ReadinessComponentKind::EvidenceDensity;
+    if workstreams.is_empty() {
+        suggestions.push(ReadinessSuggestion {
+            component: kind,
+            workstream: None,
+            message: "Collect evidence and cluster it into workstreams.".to_string(),
+        });
+        return component(kind, 0, "no workstreams".to_string());
+    }
+
+    for workstream in workstreams.iter().filter(|w| w.density < 100) {
+        let missing = TARGET_RECEIPTS_PER_WORKSTREAM - workstream.receipts;
+        suggestions.push(ReadinessSuggestion {
+            component: kind,
+            workstream: Some(workstream.title.clone()),
+            message: format!(
+                "Select {missing} more receipt{} for {:?} ({} of {TARGET_RECEIPTS_PER_WORKSTREAM}).",
+                if missing == 1 { "" } else { "s" },
+                workstream.title,
+                workstream.receipts
+            ),
+        });
+    }
+    let total: usize = workstreams.iter().map(|w| usize::from(w.density)).sum();
+    let dense = workstreams.iter().filter(|w| w.density == 100).count();
+    component(
+        kind,
+        (total / workstreams.len()) as u8,
+        format!(
+            "{dense} of {} workstreams have {TARGET_RECEIPTS_PER_WORKSTREAM}+ receipts",
+            workstreams.len()
+        ),
+    )
+}
+
+fn claim_completion(
+    workstreams: &[WorkstreamReadiness],
+    suggestions: &mut Vec<ReadinessSuggestion>,
+) -> ReadinessComponent {
+    let kind = ReadinessComponentKind::ClaimCompletion;
+    if workstreams.is_empty() {
+        return component(kind, 0, "no workstreams".to_string());
+    }
+
+    for workstream in workstreams.iter().filter(|w| !w.claim_complete) {
+        suggestions.push(ReadinessSuggestion {
+            component: kind,
+            workstream: Some(workstream.title.clone()),
+            message: format!(
+                "Write a summary for {:?} in workstreams.yaml.",
+                workstream.title
+            ),
+        });
+    }
+    let complete = workstreams.iter().filter(|w| w.claim_complete).count();
+    component(
+        kind,
+        percent(complete, workstreams.len()),
+        format!(
+            "{complete} of {} workstreams have a summary",
+            workstreams.len()
+        ),
+    )
+}
+
+fn coverage_component(
+    coverage: &CoverageManifest,
+    suggestions: &mut Vec<ReadinessSuggestion>,
+) -> ReadinessComponent {
+    let kind = ReadinessComponentKind::Coverage;
+    let (score, detail) = match coverage.score {
+        Some(score) => (score, format!("coverage score {score}")),
+        None => {
+            let score = match coverage.completeness {
+                Completeness::Complete => 100,
+                Completeness::Partial | Completeness::Unknown => 50,
+            };
+            (score, format!("completeness {}", coverage.completeness))
+        }
+    };
+    if score < 100 {
+        suggestions.push(ReadinessSuggestion {
+            component: kind,
+            workstream: None,
+            message: format!(
+                "Coverage is {score}/100; repair skipped or partial sources and collect again."
+            ),
+        });
+    }
+    component(kind, score, detail)
+}
+
+fn manual_balance(
+    events: &[EventEnvelope],
+    suggestions: &mut Vec<ReadinessSuggestion>,
+) -> ReadinessComponent {
+    let kind = ReadinessComponentKind::ManualBalance;
+    if events.is_empty() {
+        return component(kind, 0, "no events".to_string());
+    }
+
+    let manual = events.iter().filter(|event| is_manual(event)).count();
+    let share = manual as f64 / events.len() as f64;
+    let score = if share < MIN_MANUAL_SHARE {
+        share / MIN_MANUAL_SHARE
+    } else if share <= MAX_MANUAL_SHARE {
+        1.0
+    } else {
+        (1.0 - share) / (1.0 - MAX_MANUAL_SHARE)
+    };
+    if share < MIN_MANUAL_SHARE {
+        suggestions.push(ReadinessSuggestion {
+            component: kind,
+            workstream: None,
+            message: "Add manual events for context the sources cannot see, such as design \
+                      work, incidents, or mentoring."
+                .to_string(),
+        });
+    } else if share > MAX_MANUAL_SHARE {
+        suggestions.push(ReadinessSuggestion {
+            component: kind,
+            workstream: None,
+            message: "Most events are manual; connect a source that can back them with receipts."
+                .to_string(),
+        });
+    }
+    component(
+        kind,
+        (score * 100.0).round() as u8,
+        format!("{manual} of {} events are manual", events.len()),
+    )
+}
+
+fn is_manual(event: &EventEnvelope) -> bool {
+    matches!(event.payload, EventPayload::Manual(_)) || event.source.system == SourceSystem::Manual
+}
+
+fn component(kind: ReadinessComponentKind, score: u8, detail: String) -> ReadinessComponent {
+    ReadinessComponent {
+        kind,
+        score,
+        weight: kind.weight(),
+        detail,
+    }
+}
+
+fn percent(part: usize, whole: usize) -> u8 {
+    (part * 100).checked_div(whole).unwrap_or(0) as u8
+}
@@ -4,6 +4,8 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
@@ -12,7 +14,7 @@ const HMAC_IPAD: u8 = 0x36;
 const HMAC_OPAD: u8 = 0x5C;
 
 /// RFC 2104 HMAC-SHA256 over the concatenation of `parts`.
-fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+pub(crate) fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
     let mut key_block = [0u8; SHA256_BLOCK_SIZE];
     if key.len() > SHA256_BLOCK_SIZE {
         let digested = Sha256::digest(key);
@@ -51,9 +53,28 @@ struct AliasCache {
     entries: BTreeMap<String, String>,
 }
 
+/// Parse an alias cache, rejecting versions this build does not write.
+fn parse_cache(text: &str, path: &Path) -> Result<AliasCache> {
+    let cache: AliasCache =
+        serde_json::from_str(text).with_context(|| format!("parse alias cache {path:?}"))?;
+    if cache.version != 1 {
+        core::hint::cold_path();
+        anyhow::bail!("unsupported alias cache version: {}", cache.version);
+    }
+    Ok(cache)
+}
+
 /// Canonical alias cache filename emitted in run directories.
 pub const CACHE_FILENAME: &str = "redaction.aliases.json";
 
+/// Which side keeps its alias when a save finds a value aliased both in
+/// memory and in the file.
+#[derive(Debug, Clone, Copy)]
+enum MergeOrder {
+    FileFirst,
+    MemoryFirst,
+}
+
 /// Thread-safe deterministic alias store backed by HMAC-SHA256.
 #[derive(Debug)]
 pub(crate) struct DeterministicAliasStore {
@@ -76,20 +97,21 @@ impl DeterministicAliasStore {
     }
 
     /// Load aliases from disk. Missing files are treated as a no-op.
+    ///
+    /// Aliases already in memory win over loaded ones.
     pub(crate) fn load_cache(&self, path: &Path) -> Result<()> {
         if !path.exists() {
             return Ok(());
         }
 
-        let text = std::fs::read_to_string(path)
+        let mut file =
+            File::open(path).with_context(|| format!("read alias cache from {path:?}"))?;
+        file.lock_shared()
+            .with_context(|| format!("lock alias cache {path:?}"))?;
+        let mut text = String::new();
+        file.read_to_string(&mut text)
             .with_context(|| format!("read alias cache from {path:?}"))?;
-        let cache: AliasCache =
-            serde_json::from_str(&text).with_context(|| format!("parse alias cache {path:?}"))?;
-
-        if cache.version != 1 {
-            core::hint::cold_path();
-            anyhow::bail!("unsupported alias cache version: {}", cache.version);
-        }
+        let cache = parse_cache(&text, path)?;
 
         if let Ok(mut current) = self.cache.lock() {
             for (k, v) in cache.entries {
@@ -100,20 +122,84 @@ impl DeterministicAliasStore {
         Ok(())
     }
 
-    /// Save aliases to disk.
+    /// Save aliases to disk, merged with whatever the file holds now.
+    ///
+    /// The file is locked for the read-merge-write, so runs saving the same
+    /// cache concurrently never drop each other's aliases. Aliases already in
+    /// the file win, and are adopted in memory too: the first alias written
+    /// for a value is the one every later packet uses.
     pub(crate) fn save_cache(&self, path: &Path) -> Result<()> {
-        let entries = self
-            .cache
-            .lock()
-            .map_err(|e| anyhow::anyhow!("lock alias cache: {e}"))?
-            .clone();
+        self.save_merged(path, MergeOrder::FileFirst)
+    }
+
+    /// Save aliases to a cache shared across runs. Unlike
+    /// [`Self::save_cache`], aliases in memory win over the file's, so the
+    /// run being saved is never re-aliased by what other runs wrote.
+    pub(crate) fn save_shared_cache(&self, path: &Path) -> Result<()> {
+        self.save_merged(path, MergeOrder::MemoryFirst)
+    }
+
+    /// Short keyed fingerprint of the store's key, for naming caches that
+    /// only runs under the same key may read.
+    pub(crate) fn key_fingerprint(&self) -> String {
+        let digest = hmac_sha256(&self.key, &[b"alias-cache".as_slice()]);
+        hex::encode(digest)[..12].to_string()
+    }
+
+    fn save_merged(&self, path: &Path, order: MergeOrder) -> Result<()> {
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .with_context(|| format!("open alias cache {path:?}"))?;
+        file.lock()
+            .with_context(|| format!("lock alias cache {path:?}"))?;
+        let mut text = String::new();
+        file.read_to_string(&mut text)
+            .with_context(|| format!("read alias cache from {path:?}"))?;
+        let on_disk = match serde_json::from_str::<AliasCache>(&text) {
+            Ok(cache) if cache.version != 1 => {
+                core::hint::cold_path();
+                anyhow::bail!(
+                    "unsupported alias cache version: {}; refusing to overwrite {path:?}",
+                    cache.version
+                );
+            }
+            Ok(cache) => cache.entries,
+            Err(_) if text.trim().is_empty() => BTreeMap::new(),
+            Err(err) => {
+                tracing::warn!(path = %path.display(), error = %err, "replacing unreadable alias cache");
+                BTreeMap::new()
+            }
+        };
+
+        let entries = {
+            let mut current = self
+                .cache
+                .lock()
+                .map_err(|e| anyhow::anyhow!("lock alias cache: {e}"))?;
+            match order {
+                MergeOrder::FileFirst => current.extend(on_disk),
+                MergeOrder::MemoryFirst => {
+                    for (k, v) in on_disk {
+                        current.entry(k).or_insert(v);
+                    }
+                }
+            }
+            current.clone()
+        };
 
         let cache = AliasCache {
             version: 1,
             entries,
         };
         let json = serde_json::to_string_pretty(&cache)?;
-        std::fs::write(path, json).with_context(|| format!("write alias cache to {path:?}"))?;
+        file.set_len(0)
+            .and_then(|()| file.seek(SeekFrom::Start(0)))
+            .and_then(|_| file.write_all(json.as_bytes()))
+            .with_context(|| format!("write alias cache to {path:?}"))?;
         Ok(())
     }
 
@@ -137,6 +223,31 @@ impl DeterministicAliasStore {
 
         alias
     }
+
+    /// Keyed digest for a (`kind`, `value`) pair. Unlike [`Self::alias`] it
+    /// is never cached, so the cache file cannot map it back to the value.
+    pub(crate) fn hash(&self, kind: &str, value: &str) -> String {
+        let digest = hmac_sha256(
+            &self.key,
+            &[
+                b"hash\n".as_slice(),
+                kind.as_bytes(),
+                b"\n",
+                value.as_bytes(),
+            ],
+        );
+        format!("{kind}-h{}", &hex::encode(digest)[..16])
+    }
+}
+
+impl super::repo::AliasResolver for DeterministicAliasStore {
+    fn alias(&self, kind: &str, value: &str) -> String {
+        DeterministicAliasStore::alias(self, kind, value)
+    }
+
+    fn hash(&self, kind: &str, value: &str) -> String {
+        DeterministicAliasStore::hash(self, kind, value)
+    }
 }
 
 #[cfg(test)]
@@ -273,4 +384,23 @@ mod tests {
             "uncached alias should use current key, not old key"
         );
     }
+
+    #[test]
+    fn hashes_are_keyed_and_never_cached() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cache_path = dir.path().join(CACHE_FILENAME);
+
+        let aliases = DeterministicAliasStore::new(b"key-A");
+        let hash = aliases.hash("actor", "octo");
+        assert_eq!(hash, aliases.hash("actor", "octo"));
+        assert_ne!(hash, aliases.alias("actor", "octo"));
+        assert_ne!(
+            hash,
+            DeterministicAliasStore::new(b"key-B").hash("actor", "octo")
+        );
+
+        aliases.save_cache(&cache_path).expect("save cache");
+        let text = std::fs::read_to_string(&cache_path).expect("read cache");
+        assert!(!text.contains(&hash));
+    }
 }
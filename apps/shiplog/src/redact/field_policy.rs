@@ -0,0 +1,364 @@
+//! User-configured field policies layered over the built-in profiles.
+//!
+//! A [`RedactionPolicy`] overrides how one profile treats a handful of field
+//! groups. Groups it leaves unset keep the profile's built-in behavior, so an
+//! empty policy changes nothing.
+
+use super::profile::RedactionProfile;
+use super::repo::{AliasResolver, redact_repo_public};
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use shiplog::schema::event::{EventEnvelope, EventPayload, RepoRef, RepoVisibility};
+use shiplog::schema::workstream::Workstream;
+
+/// Placeholder written over stripped text fields.
+const STRIPPED: &str = "[redacted]";
+
+/// What a profile does with one field group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldAction {
+    /// Leave the value as collected.
+    Keep,
+    /// Remove the value, or replace text with `[redacted]`.
+    Strip,
+    /// Replace the value with a stable alias recorded in the alias cache.
+    Alias,
+    /// Replace the value with a keyed digest that is never cached, so the
+    /// cache file cannot map it back.
+    Hash,
+}
+
+impl FieldAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Keep => "keep",
+            Self::Strip => "strip",
+            Self::Alias => "alias",
+            Self::Hash => "hash",
+        }
+    }
+
+    fn text<A: AliasResolver + ?Sized>(self, kind: &str, value: &str, aliases: &A) -> String {
+        match self {
+            Self::Keep => value.to_string(),
+            Self::Strip => STRIPPED.to_string(),
+            Self::Alias => aliases.alias(kind, value),
+            Self::Hash => aliases.hash(kind, value),
+        }
+    }
+}
+
+/// Per-profile overrides for redaction, read from
+/// `[redaction.profiles.<profile>]` in `shiplog.toml`. A table whose name is
+/// not a built-in profile declares a custom profile built on `base`.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::redact::{FieldAction, RedactionPolicy};
+///
+/// let policy: RedactionPolicy = toml::from_str("repo = \"hash\"\nlinks = \"strip\"").unwrap();
+/// assert_eq!(policy.repo, Some(FieldAction::Hash));
+/// assert!(policy.validate().is_ok());
+///
+/// let links_alias: RedactionPolicy = toml::from_str("links = \"alias\"").unwrap();
+/// assert!(links_alias.validate().is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RedactionPolicy {
+    /// Built-in profile a custom profile projects from before its overrides
+    /// apply; `public` when unset. Only custom profiles may set it.
+    pub base: Option<RedactionProfile>,
+    /// Repository names and URLs.
+    pub repo: Option<FieldAction>,
+    /// Titles of PRs, reviewed PRs, issues, comments, incidents, documents,
+    /// manual events, and workstreams.
+    pub titles: Option<FieldAction>,
+    /// Event links and source URLs. `keep` or `strip` only.
+    pub links: Option<FieldAction>,
+    /// Actor, contributor, and reviewed-PR author logins.
+    pub actors: Option<FieldAction>,
+    /// Manual event descriptions, impact, metrics, and evidence files.
+    /// `keep` or `strip` only.
+    pub manual: Option<FieldAction>,
+}
+
+impl RedactionPolicy {
+    /// True when the policy overrides no field group.
+    pub fn is_empty(&self) -> bool {
+        self.rules().is_empty()
+    }
+
+    /// Reject actions a field group cannot take: links and manual event
+    /// fields are kept or stripped, never aliased or hashed.
+    pub fn validate(&self) -> Result<()> {
+        for (field, action) in [("links", self.links), ("manual", self.manual)] {
+            if let Some(action @ (FieldAction::Alias | FieldAction::Hash)) = action {
+                bail!(
+                    "{field} cannot be {}; use \"keep\" or \"strip\"",
+                    action.as_str()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Rule lines for the overridden groups, folded into the profile's rules
+    /// digest.
+    pub(crate) fn rules(&self) -> Vec<String> {
+        [
+            ("repo", self.repo),
+            ("titles", self.titles),
+            ("links", self.links),
+            ("actors", self.actors),
+            ("manual", self.manual),
+        ]
+        .into_iter()
+        .filter_map(|(field, action)| Some(format!("policy.{field}: {}", action?.as_str())))
+        .collect()
+    }
+
+    /// Apply the overrides to `redacted`, the profile's projection of
+    /// `original`. Kept values are restored from `original`.
+    pub(crate) fn apply_event<A: AliasResolver + ?Sized>(
+        &self,
+        original: &EventEnvelope,
+        mut redacted: EventEnvelope,
+        aliases: &A,
+    ) -> EventEnvelope {
+        if let Some(action) = self.repo {
+            redacted.repo = match action {
+                FieldAction::Keep => original.repo.clone(),
+                FieldAction::Alias => redact_repo_public(&original.repo, aliases),
+                FieldAction::Strip | FieldAction::Hash => RepoRef {
+                    full_name: action.text("repo", &original.repo.full_name, aliases),
+                    html_url: None,
+                    visibility: RepoVisibility::Unknown,
+                },
+            };
+        }
+        if let Some(action) = self.titles {
+            apply_titles(&original.payload, &mut redacted.payload, action, aliases);
+        }
+        match self.links {
+            Some(FieldAction::Keep) => {
+                redacted.links = original.links.clone();
+                redacted.source.url = original.source.url.clone();
+            }
+            Some(FieldAction::Strip) => {
+                redacted.links.clear();
+                redacted.source.url = None;
+            }
+            Some(FieldAction::Alias | FieldAction::Hash) | None => {}
+        }
+        if let Some(action) = self.actors {
+            apply_actors(original, &mut redacted, action, aliases);
+        }
+        if let (Some(action), EventPayload::Manual(original), EventPayload::Manual(manual)) =
+            (self.manual, &original.payload, &mut redacted.payload)
+        {
+            if action == FieldAction::Keep {
+                manual.description = original.description.clone();
+                manual.impact = original.impact.clone();
+                manual.metrics = original.metrics.clone();
+                manual.evidence = original.evidence.clone();
+            } else {
+                manual.description = None;
+                manual.impact = None;
+                manual.metrics.clear();
+                manual.evidence.clear();
+            }
+        }
+        redacted
+    }
+
+    /// Apply the overrides to a workstream's projection.
+    pub(crate) fn apply_workstream<A: AliasResolver + ?Sized>(
+        &self,
+        original: &Workstream,
+        mut redacted: Workstream,
+        aliases: &A,
+    ) -> Workstream {
+        if let Some(action) = self.titles {
+            redacted.title = action.text("ws", &original.title, aliases);
+        }
+        redacted
+    }
+}
+
+fn apply_titles<A: AliasResolver + ?Sized>(
+    original: &EventPayload,
+    redacted: &mut EventPayload,
+    action: FieldAction,
+    aliases: &A,
+) {
+    let (original, title) = match (original, redacted) {
+        (EventPayload::PullRequest(o), EventPayload::PullRequest(r)) => (&o.title, &mut r.title),
+        (EventPayload::Review(o), EventPayload::Review(r)) => (&o.pull_title, &mut r.pull_title),
+        (EventPayload::Manual(o), EventPayload::Manual(r)) => (&o.title, &mut r.title),
+        (EventPayload::Issue(o), EventPayload::Issue(r)) => (&o.title, &mut r.title),
+        (EventPayload::Comment(o), EventPayload::Comment(r)) => {
+            (&o.target_title, &mut r.target_title)
+        }
+        (EventPayload::Incident(o), EventPayload::Incident(r)) => (&o.title, &mut r.title),
+        (EventPayload::Document(o), EventPayload::Document(r)) => (&o.title, &mut r.title),
+        _ => return,
+    };
+    *title = action.text("title", original, aliases);
+}
+
+fn apply_actors<A: AliasResolver + ?Sized>(
+    original: &EventEnvelope,
+    redacted: &mut EventEnvelope,
+    action: FieldAction,
+    aliases: &A,
+) {
+    if action == FieldAction::Keep {
+        redacted.actor = original.actor.clone();
+        redacted.contributors = original.contributors.clone();
+    } else if action == FieldAction::Strip {
+        redacted.actor.login = STRIPPED.to_string();
+        redacted.actor.id = None;
+        redacted.contributors.clear();
+    } else {
+        redacted.actor.login = action.text("actor", &original.actor.login, aliases);
+        redacted.actor.id = None;
+        redacted.contributors = original.contributors.clone();
+        for contributor in &mut redacted.contributors {
+            contributor.login = action.text("actor", &contributor.login, aliases);
+            contributor.id = None;
+        }
+    }
+    if let (EventPayload::Review(original), EventPayload::Review(review)) =
+        (&original.payload, &mut redacted.payload)
+    {
+        review.pull_author = match action {
+            FieldAction::Strip => None,
+            _ => original
+                .pull_author
+                .as_deref()
+                .map(|author| action.text("actor", author, aliases)),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shiplog::ids::EventId;
+    use shiplog::schema::event::*;
+
+    fn alias(kind: &str, value: &str) -> String {
+        format!("{kind}-alias:{value}")
+    }
+
+    fn review() -> EventEnvelope {
+        let at = Utc::now();
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["x", "1"]),
+            kind: EventKind::Review,
+            occurred_at: at,
+            actor: Actor {
+                login: "octo".into(),
+                id: Some(1),
+            },
+            repo: RepoRef {
+                full_name: "acme/secret".into(),
+                html_url: Some("https://github.com/acme/secret".into()),
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::Review(ReviewEvent {
+                pull_number: 7,
+                pull_title: "Launch codename".into(),
+                submitted_at: at,
+                state: "approved".into(),
+                window: None,
+                pull_created_at: None,
+                pull_author: Some("alice".into()),
+            }),
+            tags: vec![],
+            links: vec![Link {
+                label: "pr".into(),
+                url: "https://github.com/acme/secret/pull/7".into(),
+            }],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: Some("https://api.github.com/x".into()),
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    #[test]
+    fn empty_policy_leaves_the_projection_alone() {
+        let original = review();
+        let mut projected = original.clone();
+        projected.links.clear();
+        let out = RedactionPolicy::default().apply_event(&original, projected.clone(), &alias);
+        assert_eq!(out, projected);
+        assert!(RedactionPolicy::default().rules().is_empty());
+    }
+
+    #[test]
+    fn keep_restores_and_strip_removes() {
+        let original = review();
+        let mut projected = original.clone();
+        projected.repo.full_name = "repo-alias:acme/secret".into();
+        projected.links.clear();
+        projected.source.url = None;
+
+        let looser = RedactionPolicy {
+            repo: Some(FieldAction::Keep),
+            links: Some(FieldAction::Keep),
+            ..Default::default()
+        };
+        let out = looser.apply_event(&original, projected, &alias);
+        assert_eq!(out.repo, original.repo);
+        assert_eq!(out.links, original.links);
+        assert_eq!(out.source.url, original.source.url);
+
+        let stricter = RedactionPolicy {
+            titles: Some(FieldAction::Strip),
+            actors: Some(FieldAction::Strip),
+            links: Some(FieldAction::Strip),
+            ..Default::default()
+        };
+        let out = stricter.apply_event(&original, original.clone(), &alias);
+        assert_eq!(out.actor.login, "[redacted]");
+        assert!(out.links.is_empty());
+        let EventPayload::Review(review) = out.payload else {
+            panic!("review payload");
+        };
+        assert_eq!(review.pull_title, "[redacted]");
+        assert_eq!(review.pull_author, None);
+    }
+
+    #[test]
+    fn alias_actions_use_the_resolver() {
+        let original = review();
+        let policy = RedactionPolicy {
+            repo: Some(FieldAction::Alias),
+            actors: Some(FieldAction::Alias),
+            ..Default::default()
+        };
+        let out = policy.apply_event(&original, original.clone(), &alias);
+        assert_eq!(out.repo.full_name, "repo-alias:acme/secret");
+        assert_eq!(out.repo.html_url, None);
+        assert_eq!(out.actor.login, "actor-alias:octo");
+        let EventPayload::Review(review) = out.payload else {
+            panic!("review payload");
+        };
+        assert_eq!(review.pull_author.as_deref(), Some("actor-alias:alice"));
+        assert_eq!(
+            policy.rules(),
+            vec!["policy.repo: alias", "policy.actors: alias"]
+        );
+    }
+}
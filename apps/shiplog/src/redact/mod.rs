@@ -2,21 +2,29 @@
 //!
 //! Supports `internal`, `manager`, and `public` projections with stable alias
 //! generation backed by keyed hashing and optional alias cache persistence.
+//! Named custom profiles project from one of those and layer a
+//! [`RedactionPolicy`] on top; any other profile name is treated as `public`.
 
 use anyhow::Result;
 use shiplog::ports::Redactor;
 use shiplog::schema::event::EventEnvelope;
 use shiplog::schema::workstream::WorkstreamsFile;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 mod alias;
+mod field_policy;
+mod pii;
 mod policy;
 mod profile;
 mod projector;
 mod repo;
 
 use self::alias::DeterministicAliasStore;
-use self::projector::{project_events_with_aliases, project_workstreams_with_aliases};
+pub(crate) use self::alias::hmac_sha256;
+use self::policy::redact_event_with_aliases;
+use self::projector::{parse_profile, project_workstreams_with_aliases};
 
 /// Default filename for the alias cache (`redaction.aliases.json`).
 ///
@@ -29,6 +37,13 @@ use self::projector::{project_events_with_aliases, project_workstreams_with_alia
 /// ```
 pub use alias::CACHE_FILENAME;
 
+/// Per-profile field overrides configured in `shiplog.toml`; see
+/// [`RedactionPolicy`].
+pub use field_policy::{FieldAction, RedactionPolicy};
+
+/// Likely-PII scan over manual event text; see [`PiiScanner`].
+pub use pii::{PiiField, PiiFinding, PiiKind, PiiScanner};
+
 /// Redaction profile enum (`Internal`, `Manager`, `Public`).
 ///
 /// # Examples
@@ -45,6 +60,24 @@ pub use alias::CACHE_FILENAME;
 /// ```
 pub use profile::RedactionProfile;
 
+/// Hex SHA-256 identifying the structural rule set a profile applies.
+///
+/// The digest changes whenever the profile's redaction rules change, so a
+/// bundle manifest can record which rule set produced its packets.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::redact::{RedactionProfile, redaction_rules_digest};
+///
+/// let public = redaction_rules_digest(RedactionProfile::Public);
+/// assert_eq!(public.len(), 64);
+/// assert_ne!(public, redaction_rules_digest(RedactionProfile::Manager));
+/// ```
+pub fn redaction_rules_digest(profile: RedactionProfile) -> String {
+    policy::redaction_rules_digest(profile)
+}
+
 /// Deterministic redactor.
 ///
 /// This intentionally does not try to be clever.
@@ -63,6 +96,10 @@ pub use profile::RedactionProfile;
 /// ```
 pub struct DeterministicRedactor {
     aliases: DeterministicAliasStore,
+    shared_cache: bool,
+    policies: HashMap<RedactionProfile, RedactionPolicy>,
+    custom: HashMap<String, RedactionPolicy>,
+    alias_actors: bool,
 }
 
 impl DeterministicRedactor {
@@ -83,9 +120,170 @@ impl DeterministicRedactor {
     pub fn new(key: impl AsRef<[u8]>) -> Self {
         Self {
             aliases: DeterministicAliasStore::new(key),
+            shared_cache: false,
+            policies: HashMap::new(),
+            custom: HashMap::new(),
+            alias_actors: false,
         }
     }
 
+    /// Override how `profile` treats the field groups `policy` sets.
+    ///
+    /// The policy is applied on top of the profile's built-in projection and
+    /// folded into its [`rules_digest`](Redactor::rules_digest).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::ports::Redactor;
+    /// use shiplog::redact::{DeterministicRedactor, FieldAction, RedactionPolicy, RedactionProfile};
+    /// use shiplog_testkit::pr_event;
+    ///
+    /// let strict = RedactionPolicy {
+    ///     actors: Some(FieldAction::Strip),
+    ///     ..Default::default()
+    /// };
+    /// let r = DeterministicRedactor::new(b"key").with_policy(RedactionProfile::Public, strict);
+    /// let out = r.redact_events(&[pr_event("acme/app", 1, "Ship it")], "public").unwrap();
+    /// assert_eq!(out[0].actor.login, "[redacted]");
+    /// ```
+    #[must_use]
+    pub fn with_policy(mut self, profile: RedactionProfile, policy: RedactionPolicy) -> Self {
+        self.policies.insert(profile, policy);
+        self
+    }
+
+    /// Declare a custom profile named `name` that projects like
+    /// `policy.base` (`public` when unset) and then applies `policy`'s
+    /// overrides. Redacting for a name that was never declared still falls
+    /// back to the public projection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::ports::Redactor;
+    /// use shiplog::redact::{DeterministicRedactor, FieldAction, RedactionPolicy, RedactionProfile};
+    /// use shiplog_testkit::pr_event;
+    ///
+    /// let skip_level = RedactionPolicy {
+    ///     base: Some(RedactionProfile::Manager),
+    ///     titles: Some(FieldAction::Strip),
+    ///     ..Default::default()
+    /// };
+    /// let r = DeterministicRedactor::new(b"key").with_custom_profile("skip-level", skip_level);
+    /// let out = r.redact_events(&[pr_event("acme/app", 1, "Ship it")], "skip-level").unwrap();
+    /// assert_eq!(out[0].repo.full_name, "acme/app");
+    /// ```
+    #[must_use]
+    pub fn with_custom_profile(mut self, name: impl Into<String>, policy: RedactionPolicy) -> Self {
+        self.custom.insert(name.into(), policy);
+        self
+    }
+
+    /// Alias actor, contributor, and reviewed-PR author logins in every
+    /// profile, internal included, for packets that combine several people's
+    /// work. Aliases are keyed and stable, so one person keeps the same alias
+    /// across events and runs. A profile whose policy sets `actors` keeps that
+    /// setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::ports::Redactor;
+    /// use shiplog::redact::DeterministicRedactor;
+    /// use shiplog_testkit::pr_event;
+    ///
+    /// let r = DeterministicRedactor::new(b"key").with_actor_aliases(true);
+    /// let events = [pr_event("acme/app", 1, "Ship it"), pr_event("acme/app", 2, "Fix it")];
+    /// let out = r.redact_events(&events, "internal").unwrap();
+    /// assert_ne!(out[0].actor.login, events[0].actor.login);
+    /// assert_eq!(out[0].actor.login, out[1].actor.login);
+    /// ```
+    #[must_use]
+    pub fn with_actor_aliases(mut self, alias_actors: bool) -> Self {
+        self.alias_actors = alias_actors;
+        self
+    }
+
+    /// Also keep aliases in a cache at the output root, the run directory's
+    /// parent, so packets from different runs under the same redaction key
+    /// alias the same repo or workstream identically. Off by default.
+    ///
+    /// The shared cache is named after a fingerprint of the key, so rotating
+    /// the key starts a fresh one instead of carrying old aliases forward.
+    /// With this enabled, [`load_cache`](Self::load_cache) and
+    /// [`save_cache`](Self::save_cache) read and write the shared cache next
+    /// to the run's own. The run's aliases win on both, so re-rendering an
+    /// old run keeps its packets unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::ports::Redactor;
+    /// use shiplog::redact::DeterministicRedactor;
+    /// use shiplog_testkit::pr_event;
+    ///
+    /// let out = tempfile::tempdir().unwrap();
+    /// let (run_a, run_b) = (out.path().join("run_a"), out.path().join("run_b"));
+    /// std::fs::create_dir_all(&run_a).unwrap();
+    /// std::fs::create_dir_all(&run_b).unwrap();
+    /// let events = [pr_event("acme/secret", 1, "Ship it")];
+    ///
+    /// let first = DeterministicRedactor::new(b"key").with_shared_cache(true);
+    /// let a = first.redact_events(&events, "public").unwrap();
+    /// first.save_cache(&DeterministicRedactor::cache_path(&run_a)).unwrap();
+    ///
+    /// // A later run under the same key reuses the alias.
+    /// let second = DeterministicRedactor::new(b"key").with_shared_cache(true);
+    /// second.load_cache(&DeterministicRedactor::cache_path(&run_b)).unwrap();
+    /// let b = second.redact_events(&events, "public").unwrap();
+    /// assert_eq!(a[0].repo.full_name, b[0].repo.full_name);
+    /// ```
+    #[must_use]
+    pub fn with_shared_cache(mut self, shared_cache: bool) -> Self {
+        self.shared_cache = shared_cache;
+        self
+    }
+
+    /// Path to this key's alias cache shared by every run under the output
+    /// root that holds `run_dir`, or `None` when `run_dir` has no parent
+    /// directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::redact::DeterministicRedactor;
+    ///
+    /// let out = tempfile::tempdir().unwrap();
+    /// let r = DeterministicRedactor::new(b"key");
+    /// let shared = r.shared_cache_path(&out.path().join("run_1")).unwrap();
+    /// assert_eq!(shared.parent(), Some(out.path()));
+    ///
+    /// // A rotated key gets its own shared cache.
+    /// let rotated = DeterministicRedactor::new(b"new-key");
+    /// assert_ne!(rotated.shared_cache_path(&out.path().join("run_1")), Some(shared));
+    /// ```
+    pub fn shared_cache_path(&self, run_dir: &Path) -> Option<PathBuf> {
+        run_dir
+            .parent()
+            .filter(|root| !root.as_os_str().is_empty() && root.is_dir())
+            .map(|root| {
+                root.join(format!(
+                    "redaction.aliases.{}.json",
+                    self.aliases.key_fingerprint()
+                ))
+            })
+    }
+
+    /// The shared cache for the run cache at `path`, when enabled.
+    fn shared_cache_for(&self, path: &Path) -> Option<PathBuf> {
+        if !self.shared_cache {
+            return None;
+        }
+        path.parent()
+            .and_then(|run_dir| self.shared_cache_path(run_dir))
+    }
+
     /// Path to the alias cache file in a given output directory.
     ///
     /// # Examples
@@ -102,6 +300,7 @@ impl DeterministicRedactor {
     }
 
     /// Load cached aliases from disk. No-op if file is missing.
+    /// Aliases already in memory are kept.
     ///
     /// # Examples
     ///
@@ -114,10 +313,15 @@ impl DeterministicRedactor {
     /// r.load_cache(Path::new("/out/run_1/redaction.aliases.json")).unwrap();
     /// ```
     pub fn load_cache(&self, path: &Path) -> Result<()> {
-        self.aliases.load_cache(path)
+        self.aliases.load_cache(path)?;
+        if let Some(shared) = self.shared_cache_for(path) {
+            self.aliases.load_cache(&shared)?;
+        }
+        Ok(())
     }
 
-    /// Save current aliases to disk.
+    /// Save current aliases to disk, merged with the file's current aliases.
+    /// Concurrent saves to one cache are serialized with a file lock.
     ///
     /// # Examples
     ///
@@ -130,18 +334,52 @@ impl DeterministicRedactor {
     /// r.save_cache(Path::new("/out/run_1/redaction.aliases.json")).unwrap();
     /// ```
     pub fn save_cache(&self, path: &Path) -> Result<()> {
-        self.aliases.save_cache(path)
+        self.aliases.save_cache(path)?;
+        if let Some(shared) = self.shared_cache_for(path) {
+            self.aliases.save_shared_cache(&shared)?;
+        }
+        Ok(())
     }
 
+    #[cfg(test)]
     fn alias(&self, kind: &str, value: &str) -> String {
         self.aliases.alias(kind, value)
     }
+
+    /// The built-in projection `profile` starts from and the overrides
+    /// applied on top of it.
+    fn resolve(&self, profile: &str) -> (RedactionProfile, Option<Cow<'_, RedactionPolicy>>) {
+        let (base, policy) = match self.custom.get(profile) {
+            Some(policy) => (policy.base.unwrap_or_default(), Some(policy)),
+            None => {
+                let base = parse_profile(profile);
+                (base, self.policies.get(&base))
+            }
+        };
+        let mut policy = policy.map(Cow::Borrowed);
+        if self.alias_actors && policy.as_ref().is_none_or(|policy| policy.actors.is_none()) {
+            policy.get_or_insert_default().to_mut().actors = Some(FieldAction::Alias);
+        }
+        (base, policy.filter(|policy| !policy.is_empty()))
+    }
 }
 
 impl Redactor for DeterministicRedactor {
     fn redact_events(&self, events: &[EventEnvelope], profile: &str) -> Result<Vec<EventEnvelope>> {
-        let aliases = |kind: &str, value: &str| self.alias(kind, value);
-        Ok(project_events_with_aliases(events, profile, &aliases))
+        let (base, policy) = self.resolve(profile);
+        // One pass over the borrowed events: each is copied once, projected,
+        // and overridden before the next, so a profile never holds a second
+        // full copy of the ledger.
+        Ok(events
+            .iter()
+            .map(|original| {
+                let projected = redact_event_with_aliases(original.clone(), base, &self.aliases);
+                match &policy {
+                    Some(policy) => policy.apply_event(original, projected, &self.aliases),
+                    None => projected,
+                }
+            })
+            .collect())
     }
 
     fn redact_workstreams(
@@ -149,12 +387,29 @@ impl Redactor for DeterministicRedactor {
         workstreams: &WorkstreamsFile,
         profile: &str,
     ) -> Result<WorkstreamsFile> {
-        let aliases = |kind: &str, value: &str| self.alias(kind, value);
-        Ok(project_workstreams_with_aliases(
-            workstreams,
-            profile,
-            &aliases,
-        ))
+        let (base, policy) = self.resolve(profile);
+        let mut projected =
+            project_workstreams_with_aliases(workstreams, base.as_str(), &self.aliases);
+        if let Some(policy) = policy {
+            projected.workstreams = workstreams
+                .workstreams
+                .iter()
+                .zip(projected.workstreams)
+                .map(|(original, redacted)| {
+                    policy.apply_workstream(original, redacted, &self.aliases)
+                })
+                .collect();
+        }
+        Ok(projected)
+    }
+
+    fn rules_digest(&self, profile: &str) -> String {
+        let (base, policy) = self.resolve(profile);
+        let rules = policy
+            .as_deref()
+            .map(RedactionPolicy::rules)
+            .unwrap_or_default();
+        policy::redaction_rules_digest_with(base, &rules)
     }
 }
 
@@ -181,6 +436,7 @@ mod tests {
     fn public_profile_strips_titles_and_links() {
         let r = DeterministicRedactor::new(b"k");
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -215,6 +471,9 @@ mod tests {
                 url: Some("https://api.github.com/...".into()),
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
 
         let out = r.redact_events(&[ev], "public").unwrap();
@@ -237,6 +496,7 @@ mod tests {
         let sensitive_title = "Secret Feature: Internal Auth Bypass";
 
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -268,6 +528,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
 
         let out = r.redact_events(&[ev], "public").unwrap();
@@ -290,6 +553,7 @@ mod tests {
         let sensitive_repo = "acme-corp/top-secret-project";
 
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -321,6 +585,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
 
         let out = r.redact_events(&[ev], "public").unwrap();
@@ -351,6 +618,7 @@ mod tests {
         let sensitive_impact = "Affected 10,000 user records";
 
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::Manual,
             occurred_at: Utc::now(),
@@ -370,6 +638,9 @@ mod tests {
                 impact: Some(sensitive_impact.into()),
                 started_at: Some(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()),
                 ended_at: Some(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()),
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![],
@@ -378,6 +649,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
 
         let out = r.redact_events(&[ev], "public").unwrap();
@@ -416,6 +690,7 @@ mod tests {
 
         for url in urls {
             let ev = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: EventId::from_parts(["x", "1"]),
                 kind: EventKind::PullRequest,
                 occurred_at: Utc::now(),
@@ -450,6 +725,9 @@ mod tests {
                     url: Some(url.into()),
                     opaque_id: None,
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             };
 
             let out = r.redact_events(&[ev], "public").unwrap();
@@ -489,6 +767,8 @@ mod tests {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         };
 
         let ws_file = WorkstreamsFile {
@@ -540,6 +820,7 @@ mod tests {
         let sensitive_repo = "secret-org/secret-repo";
 
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -574,6 +855,9 @@ mod tests {
                 url: Some("https://api.github.com/secret".into()),
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
 
         let out = r.redact_events(&[ev], "internal").unwrap();
@@ -601,6 +885,7 @@ mod tests {
         let pr_title = "Feature: Add user authentication".to_string();
 
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -635,6 +920,9 @@ mod tests {
                 url: Some("https://api.github.com/...".into()),
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
 
         let out = r.redact_events(&[ev], "manager").unwrap();
@@ -671,6 +959,7 @@ mod tests {
         let r = DeterministicRedactor::new(b"test-key");
 
         let ev = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::Manual,
             occurred_at: Utc::now(),
@@ -690,6 +979,9 @@ mod tests {
                 impact: Some("Affected 1000 users for 5 minutes".into()),
                 started_at: Some(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()),
                 ended_at: Some(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()),
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![Link {
@@ -701,6 +993,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
 
         let out = r.redact_events(&[ev], "manager").unwrap();
@@ -742,6 +1037,8 @@ mod tests {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         };
 
         let ws_file = WorkstreamsFile {
@@ -848,6 +1145,8 @@ mod tests {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         };
 
         let ws_file = WorkstreamsFile {
@@ -894,6 +1193,296 @@ mod tests {
         );
     }
 
+    #[test]
+    fn save_merges_aliases_written_by_another_redactor() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("redaction.aliases.json");
+
+        let first = DeterministicRedactor::new(b"key-a");
+        let first_alias = first.alias("repo", "acme/shared");
+        let only_first = first.alias("repo", "acme/first");
+        let second = DeterministicRedactor::new(b"key-b");
+        let only_second = second.alias("repo", "acme/second");
+        assert_ne!(second.alias("repo", "acme/shared"), first_alias);
+
+        first.save_cache(&cache_path).unwrap();
+        second.save_cache(&cache_path).unwrap();
+
+        // The first alias saved for a value wins, on disk and in memory.
+        assert_eq!(second.alias("repo", "acme/shared"), first_alias);
+        let reloaded = DeterministicRedactor::new(b"key-c");
+        reloaded.load_cache(&cache_path).unwrap();
+        assert_eq!(reloaded.alias("repo", "acme/shared"), first_alias);
+        assert_eq!(reloaded.alias("repo", "acme/first"), only_first);
+        assert_eq!(reloaded.alias("repo", "acme/second"), only_second);
+    }
+
+    #[test]
+    fn save_refuses_to_overwrite_newer_cache_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("redaction.aliases.json");
+        let newer = serde_json::json!({ "version": 2, "entries": {} });
+        std::fs::write(&cache_path, serde_json::to_string(&newer).unwrap()).unwrap();
+
+        let err = DeterministicRedactor::new(b"key")
+            .save_cache(&cache_path)
+            .unwrap_err();
+        assert!(err.to_string().contains("unsupported alias cache version"));
+        assert!(
+            std::fs::read_to_string(&cache_path)
+                .unwrap()
+                .contains("\"version\":2")
+        );
+    }
+
+    #[test]
+    fn shared_cache_keeps_run_aliases_first() {
+        let out = tempfile::tempdir().unwrap();
+        let run = out.path().join("run_1");
+        std::fs::create_dir_all(&run).unwrap();
+        let run_cache = DeterministicRedactor::cache_path(&run);
+
+        let old = DeterministicRedactor::new(b"old-key");
+        let old_alias = old.alias("repo", "acme/foo");
+        old.save_cache(&run_cache).unwrap();
+
+        // Another run under the current key already shared its own alias.
+        let r = DeterministicRedactor::new(b"key").with_shared_cache(true);
+        let shared_path = r.shared_cache_path(&run).unwrap();
+        let other = DeterministicRedactor::new(b"other-key");
+        assert_ne!(other.alias("repo", "acme/foo"), old_alias);
+        other.save_cache(&shared_path).unwrap();
+
+        r.load_cache(&run_cache).unwrap();
+        assert_eq!(r.alias("repo", "acme/foo"), old_alias);
+        let fresh = r.alias("repo", "acme/bar");
+        r.save_cache(&run_cache).unwrap();
+        assert_eq!(r.alias("repo", "acme/foo"), old_alias);
+
+        let later = DeterministicRedactor::new(b"key").with_shared_cache(true);
+        later
+            .load_cache(&DeterministicRedactor::cache_path(
+                &out.path().join("run_2"),
+            ))
+            .unwrap();
+        assert_eq!(later.alias("repo", "acme/foo"), old_alias);
+        assert_eq!(later.alias("repo", "acme/bar"), fresh);
+    }
+
+    #[test]
+    fn shared_cache_is_scoped_to_the_redaction_key() {
+        let out = tempfile::tempdir().unwrap();
+        let (run_a, run_b) = (out.path().join("run_a"), out.path().join("run_b"));
+        std::fs::create_dir_all(&run_a).unwrap();
+
+        let before = DeterministicRedactor::new(b"old-key").with_shared_cache(true);
+        let old_alias = before.alias("repo", "acme/foo");
+        before
+            .save_cache(&DeterministicRedactor::cache_path(&run_a))
+            .unwrap();
+
+        let rotated = DeterministicRedactor::new(b"new-key").with_shared_cache(true);
+        rotated
+            .load_cache(&DeterministicRedactor::cache_path(&run_b))
+            .unwrap();
+        assert_ne!(rotated.alias("repo", "acme/foo"), old_alias);
+        assert_eq!(
+            rotated.alias("repo", "acme/foo"),
+            DeterministicRedactor::new(b"new-key").alias("repo", "acme/foo")
+        );
+    }
+
+    #[test]
+    fn shared_cache_is_off_by_default() {
+        let out = tempfile::tempdir().unwrap();
+        let run = out.path().join("run_1");
+        std::fs::create_dir_all(&run).unwrap();
+
+        let r = DeterministicRedactor::new(b"key");
+        r.alias("repo", "acme/foo");
+        r.save_cache(&DeterministicRedactor::cache_path(&run))
+            .unwrap();
+        assert!(!r.shared_cache_path(&run).unwrap().exists());
+    }
+
+    fn private_pr() -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["x", "1"]),
+            kind: EventKind::PullRequest,
+            occurred_at: Utc::now(),
+            actor: Actor {
+                login: "octo".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "acme/secret".into(),
+                html_url: Some("https://github.com/acme/secret".into()),
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number: 1,
+                title: "Launch codename".into(),
+                state: PullRequestState::Merged,
+                created_at: Utc::now(),
+                merged_at: Some(Utc::now()),
+                additions: Some(1),
+                deletions: Some(1),
+                changed_files: Some(1),
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: vec![],
+            links: vec![Link {
+                label: "pr".into(),
+                url: "https://github.com/acme/secret/pull/1".into(),
+            }],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    #[test]
+    fn policy_overrides_profile_output_and_rules_digest() {
+        let events = vec![private_pr()];
+        let looser_manager = RedactionPolicy {
+            links: Some(FieldAction::Keep),
+            ..Default::default()
+        };
+        let stricter_public = RedactionPolicy {
+            repo: Some(FieldAction::Hash),
+            actors: Some(FieldAction::Strip),
+            ..Default::default()
+        };
+        let r = DeterministicRedactor::new(b"k")
+            .with_policy(RedactionProfile::Manager, looser_manager)
+            .with_policy(RedactionProfile::Public, stricter_public);
+
+        let manager = r.redact_events(&events, "manager").unwrap();
+        assert_eq!(manager[0].links, events[0].links);
+        let public = r.redact_events(&events, "public").unwrap();
+        assert_eq!(public[0].actor.login, "[redacted]");
+        assert_ne!(public[0].repo.full_name, r.alias("repo", "acme/secret"));
+        assert!(!public[0].repo.full_name.contains("acme"));
+
+        assert_eq!(
+            r.rules_digest("internal"),
+            redaction_rules_digest(RedactionProfile::Internal)
+        );
+        assert_ne!(
+            r.rules_digest("public"),
+            redaction_rules_digest(RedactionProfile::Public)
+        );
+    }
+
+    #[test]
+    fn actor_aliases_apply_to_every_profile_unless_a_policy_sets_actors() {
+        let events = vec![private_pr()];
+        let r = DeterministicRedactor::new(b"k")
+            .with_actor_aliases(true)
+            .with_policy(
+                RedactionProfile::Public,
+                RedactionPolicy {
+                    actors: Some(FieldAction::Strip),
+                    ..Default::default()
+                },
+            );
+
+        let octo = r.alias("actor", "octo");
+        for profile in ["internal", "manager"] {
+            let out = r.redact_events(&events, profile).unwrap();
+            assert_eq!(out[0].actor.login, octo, "{profile}");
+            assert_eq!(out[0].actor.id, None);
+        }
+        let public = r.redact_events(&events, "public").unwrap();
+        assert_eq!(public[0].actor.login, "[redacted]");
+        assert_ne!(
+            r.rules_digest("internal"),
+            redaction_rules_digest(RedactionProfile::Internal)
+        );
+    }
+
+    #[test]
+    fn custom_profile_projects_from_its_base_and_unknown_names_fall_back_to_public() {
+        let events = vec![private_pr()];
+        let skip_level = RedactionPolicy {
+            base: Some(RedactionProfile::Manager),
+            titles: Some(FieldAction::Strip),
+            ..Default::default()
+        };
+        let r = DeterministicRedactor::new(b"k")
+            .with_policy(
+                RedactionProfile::Manager,
+                RedactionPolicy {
+                    links: Some(FieldAction::Keep),
+                    ..Default::default()
+                },
+            )
+            .with_custom_profile("skip-level", skip_level);
+
+        let custom = r.redact_events(&events, "skip-level").unwrap();
+        let manager = r.redact_events(&events, "manager").unwrap();
+        assert_eq!(custom[0].repo, manager[0].repo);
+        assert!(
+            custom[0].links.is_empty(),
+            "built-in manager overrides stay put"
+        );
+        match &custom[0].payload {
+            EventPayload::PullRequest(pr) => assert_eq!(pr.title, "[redacted]"),
+            other => panic!("unexpected payload {other:?}"),
+        }
+        assert_ne!(
+            r.rules_digest("skip-level"),
+            redaction_rules_digest(RedactionProfile::Manager)
+        );
+
+        let undeclared = r.redact_events(&events, "promo-committee").unwrap();
+        assert_eq!(undeclared, r.redact_events(&events, "public").unwrap());
+    }
+
+    #[test]
+    fn batch_redaction_matches_redacting_each_event_alone() {
+        let fixture = shiplog::fixtures::generate(&shiplog::fixtures::FixtureSpec {
+            events: 60,
+            ..Default::default()
+        })
+        .unwrap();
+        let mut events = fixture.events;
+        events.push(private_pr());
+        let r = DeterministicRedactor::new(b"k")
+            .with_actor_aliases(true)
+            .with_policy(
+                RedactionProfile::Manager,
+                RedactionPolicy {
+                    links: Some(FieldAction::Keep),
+                    ..Default::default()
+                },
+            )
+            .with_custom_profile(
+                "skip-level",
+                RedactionPolicy {
+                    base: Some(RedactionProfile::Manager),
+                    titles: Some(FieldAction::Hash),
+                    ..Default::default()
+                },
+            );
+
+        for profile in ["internal", "manager", "public", "skip-level"] {
+            let batch = r.redact_events(&events, profile).unwrap();
+            let one_by_one: Vec<_> = events
+                .iter()
+                .flat_map(|ev| r.redact_events(std::slice::from_ref(ev), profile).unwrap())
+                .collect();
+            assert_eq!(batch, one_by_one, "{profile}");
+        }
+    }
+
     // Property test using proptest: arbitrary strings should not leak through redaction
     proptest! {
         #[test]
@@ -904,6 +1493,7 @@ mod tests {
             let r = DeterministicRedactor::new(b"test-key");
 
             let ev = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: EventId::from_parts(["x","1"]),
                 kind: EventKind::PullRequest,
                 occurred_at: Utc::now(),
@@ -924,6 +1514,9 @@ mod tests {
                 tags: vec![],
                 links: vec![],
                 source: SourceRef { system: SourceSystem::Github, url: None, opaque_id: None },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             };
 
             let out = r.redact_events(&[ev], "public").unwrap();
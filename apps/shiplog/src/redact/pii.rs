@@ -0,0 +1,270 @@
+//! Likely-PII warnings for text users type into manual events.
+//!
+//! Structural redaction aliases repositories and drops fields, but it keeps
+//! manual titles in manager packets and cannot know that a description names
+//! a customer. This scan looks for email addresses, phone numbers, and terms
+//! from a configured dictionary so the user can fix the text before sharing.
+
+use regex::Regex;
+use shiplog::ids::EventId;
+use shiplog::schema::event::{EventEnvelope, EventPayload, SourceSystem};
+use std::fmt;
+
+/// Manual event field a finding was found in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PiiField {
+    Title,
+    Description,
+    Impact,
+}
+
+impl PiiField {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Impact => "impact",
+        }
+    }
+}
+
+/// What kind of PII a finding looks like.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PiiKind {
+    Email,
+    Phone,
+    /// A term from the configured dictionary, such as a customer name.
+    Term,
+}
+
+impl PiiKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email address",
+            Self::Phone => "phone number",
+            Self::Term => "dictionary term",
+        }
+    }
+}
+
+/// One likely-PII match in a manual event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PiiFinding {
+    pub event_id: EventId,
+    /// Title of the manual event, so the user can find the entry.
+    pub event_title: String,
+    pub field: PiiField,
+    pub kind: PiiKind,
+    pub matched: String,
+}
+
+impl fmt::Display for PiiFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "manual event \"{}\" {}: possible {} \"{}\"",
+            self.event_title,
+            self.field.as_str(),
+            self.kind.as_str(),
+            self.matched
+        )
+    }
+}
+
+/// Scans manual events for likely PII.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::redact::PiiScanner;
+///
+/// let scanner = PiiScanner::new(&["Globex".to_string()]).unwrap();
+/// assert!(scanner.scan(&[]).is_empty());
+/// ```
+pub struct PiiScanner {
+    email: Regex,
+    phone: Regex,
+    terms: Option<Regex>,
+}
+
+impl PiiScanner {
+    /// Build a scanner. `terms` match whole words, ignoring case; blank
+    /// terms are skipped.
+    pub fn new(terms: &[String]) -> anyhow::Result<Self> {
+        let terms: Vec<String> = terms
+            .iter()
+            .map(|term| term.trim())
+            .filter(|term| !term.is_empty())
+            .map(regex::escape)
+            .collect();
+        let terms = if terms.is_empty() {
+            None
+        } else {
+            Some(Regex::new(&format!(r"(?i)\b(?:{})\b", terms.join("|")))?)
+        };
+        Ok(Self {
+            email: Regex::new(
+                r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}",
+            )?,
+            phone: Regex::new(r"\+?\(?\d[\d ().-]{6,}\d")?,
+            terms,
+        })
+    }
+
+    /// Findings for every manual event, in event order.
+    pub fn scan(&self, events: &[EventEnvelope]) -> Vec<PiiFinding> {
+        let mut findings = Vec::new();
+        for event in events {
+            // Manual incidents are promoted to incident payloads on ingest.
+            let (title, description, impact) = match &event.payload {
+                EventPayload::Manual(manual) => (
+                    &manual.title,
+                    manual.description.as_deref(),
+                    manual.impact.as_deref(),
+                ),
+                EventPayload::Incident(incident) if event.source.system == SourceSystem::Manual => {
+                    (
+                        &incident.title,
+                        incident.summary.as_deref(),
+                        incident.customer_impact.as_deref(),
+                    )
+                }
+                _ => continue,
+            };
+            let fields = [
+                (PiiField::Title, Some(title.as_str())),
+                (PiiField::Description, description),
+                (PiiField::Impact, impact),
+            ];
+            for (field, text) in fields {
+                let Some(text) = text else { continue };
+                for (kind, matched) in self.matches(text) {
+                    findings.push(PiiFinding {
+                        event_id: event.id.clone(),
+                        event_title: title.clone(),
+                        field,
+                        kind,
+                        matched,
+                    });
+                }
+            }
+        }
+        findings
+    }
+
+    fn matches(&self, text: &str) -> Vec<(PiiKind, String)> {
+        let emails: Vec<_> = self.email.find_iter(text).collect();
+        let mut out: Vec<(PiiKind, String)> = emails
+            .iter()
+            .map(|m| (PiiKind::Email, m.as_str().to_string()))
+            .collect();
+        out.extend(
+            self.phone
+                .find_iter(text)
+                .map(|m| m.as_str().trim())
+                .filter(|candidate| looks_like_phone(candidate))
+                .map(|candidate| (PiiKind::Phone, candidate.to_string())),
+        );
+        if let Some(terms) = &self.terms {
+            // A term inside an address already reported as an email adds nothing.
+            out.extend(
+                terms
+                    .find_iter(text)
+                    .filter(|term| {
+                        !emails
+                            .iter()
+                            .any(|email| email.start() <= term.start() && term.end() <= email.end())
+                    })
+                    .map(|m| (PiiKind::Term, m.as_str().to_string())),
+            );
+        }
+        out
+    }
+}
+
+/// Phone numbers carry 9 to 15 digits. Dates, date ranges, and version
+/// strings also match the loose pattern but fall outside that range.
+fn looks_like_phone(candidate: &str) -> bool {
+    let digits = candidate.chars().filter(char::is_ascii_digit).count();
+    (9..=15).contains(&digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Utc};
+    use shiplog::schema::event::*;
+
+    fn manual(title: &str, description: Option<&str>) -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["manual", title]),
+            kind: EventKind::Manual,
+            occurred_at: Utc::now(),
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "manual".into(),
+                html_url: None,
+                visibility: RepoVisibility::Unknown,
+            },
+            payload: EventPayload::Manual(ManualEvent {
+                event_type: ManualEventType::Other,
+                title: title.into(),
+                description: description.map(str::to_string),
+                started_at: NaiveDate::from_ymd_opt(2025, 1, 6),
+                ended_at: None,
+                impact: None,
+                metrics: vec![],
+                effort: None,
+                evidence: vec![],
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Manual,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    #[test]
+    fn finds_emails_phones_and_dictionary_terms() {
+        let scanner = PiiScanner::new(&["Globex".to_string(), "  ".to_string()]).unwrap();
+        let events = vec![
+            manual(
+                "Escalation for GLOBEX",
+                Some("Called +1 (555) 010-4477 and mailed jane.doe@globex.example"),
+            ),
+            manual("Release 2025-01-06 - 2025-02-01, v1.2.3", None),
+        ];
+
+        let found: Vec<(PiiField, PiiKind, String)> = scanner
+            .scan(&events)
+            .into_iter()
+            .map(|finding| (finding.field, finding.kind, finding.matched))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (PiiField::Title, PiiKind::Term, "GLOBEX".to_string()),
+                (
+                    PiiField::Description,
+                    PiiKind::Email,
+                    "jane.doe@globex.example".to_string()
+                ),
+                (
+                    PiiField::Description,
+                    PiiKind::Phone,
+                    "+1 (555) 010-4477".to_string()
+                ),
+            ]
+        );
+    }
+}
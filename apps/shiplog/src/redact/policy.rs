@@ -2,9 +2,84 @@
 
 use super::profile::RedactionProfile;
 use super::repo::{AliasResolver, redact_repo_public};
-use shiplog::schema::event::{EventEnvelope, EventPayload};
+use sha2::{Digest, Sha256};
+use shiplog::schema::event::{
+    EXTENSION_INTERNAL_PREFIX, EXTENSION_PUBLIC_PREFIX, EventEnvelope, EventPayload,
+};
 use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
 
+/// Human-readable statement of every rule the profile applies.
+///
+/// Keep this list in step with the match arms below: its digest is recorded
+/// in bundle manifests so reviewers can tell which rule set produced a packet.
+pub(crate) fn redaction_rules(profile: RedactionProfile) -> &'static [&'static str] {
+    match profile {
+        RedactionProfile::Internal => &[],
+        RedactionProfile::Manager => &[
+            "event.links: cleared",
+            "event.extensions: internal.* removed",
+            "pull_request.touched_paths_hint: cleared",
+            "manual.description: removed",
+            "manual.impact: removed",
+            "manual.evidence: cleared",
+            "comment.thread: removed",
+            "incident.summary: removed",
+            "incident.customer_impact: removed",
+            "workstream.summary: removed",
+        ],
+        RedactionProfile::Public => &[
+            "event.repo: aliased",
+            "event.contributors: aliased",
+            "event.links: cleared",
+            "event.source.url: removed",
+            "event.source.opaque_id: removed",
+            "event.extensions: all but public.* removed",
+            "pull_request.title: [redacted]",
+            "pull_request.touched_paths_hint: cleared",
+            "review.pull_title: [redacted]",
+            "review.pull_author: aliased",
+            "manual.title: [redacted]",
+            "manual.description: removed",
+            "manual.impact: removed",
+            "manual.metrics: cleared",
+            "manual.evidence: cleared",
+            "issue.title: [redacted]",
+            "issue.labels: cleared",
+            "deployment.environment: aliased",
+            "deployment.version: removed",
+            "release.version: aliased",
+            "release.name: removed",
+            "comment.target_title: [redacted]",
+            "comment.thread: removed",
+            "incident.title: [redacted]",
+            "incident.summary: removed",
+            "incident.customer_impact: removed",
+            "document.title: [redacted]",
+            "workstream.title: aliased",
+            "workstream.summary: removed",
+            "workstream.tags: repo tag removed",
+        ],
+    }
+}
+
+/// Hex SHA-256 over the profile name and its [`redaction_rules`].
+pub(crate) fn redaction_rules_digest(profile: RedactionProfile) -> String {
+    redaction_rules_digest_with(profile, &[])
+}
+
+/// [`redaction_rules_digest`] with configured policy rules appended, so a
+/// profile overridden in `shiplog.toml` records a different digest.
+pub(crate) fn redaction_rules_digest_with(profile: RedactionProfile, extra: &[String]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(profile.as_str().as_bytes());
+    let extra = extra.iter().map(String::as_str);
+    for rule in redaction_rules(profile).iter().copied().chain(extra) {
+        hasher.update(b"\n");
+        hasher.update(rule.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
 /// Redact a single event for the selected profile.
 pub(crate) fn redact_event_with_aliases<A: AliasResolver + ?Sized>(
     mut event: EventEnvelope,
@@ -18,18 +93,37 @@ pub(crate) fn redact_event_with_aliases<A: AliasResolver + ?Sized>(
                 EventPayload::PullRequest(pr) => {
                     pr.touched_paths_hint.clear();
                 }
-                EventPayload::Review(_) => {}
+                EventPayload::Review(_)
+                | EventPayload::Issue(_)
+                | EventPayload::Deployment(_)
+                | EventPayload::Release(_)
+                | EventPayload::Document(_) => {}
                 EventPayload::Manual(m) => {
                     m.description = None;
                     m.impact = None;
+                    m.evidence.clear();
+                }
+                EventPayload::Comment(comment) => {
+                    comment.thread = None;
+                }
+                EventPayload::Incident(incident) => {
+                    incident.summary = None;
+                    incident.customer_impact = None;
                 }
             }
 
             event.links.clear();
             event
+                .extensions
+                .retain(|key, _| !key.starts_with(EXTENSION_INTERNAL_PREFIX));
+            event
         }
         RedactionProfile::Public => {
             event.repo = redact_repo_public(&event.repo, aliases);
+            for contributor in &mut event.contributors {
+                contributor.login = aliases.alias("actor", &contributor.login);
+                contributor.id = None;
+            }
 
             match &mut event.payload {
                 EventPayload::PullRequest(pr) => {
@@ -38,11 +132,40 @@ pub(crate) fn redact_event_with_aliases<A: AliasResolver + ?Sized>(
                 }
                 EventPayload::Review(r) => {
                     r.pull_title = "[redacted]".to_string();
+                    if let Some(author) = &mut r.pull_author {
+                        *author = aliases.alias("actor", author);
+                    }
                 }
                 EventPayload::Manual(m) => {
                     m.title = "[redacted]".to_string();
                     m.description = None;
                     m.impact = None;
+                    m.metrics.clear();
+                    m.evidence.clear();
+                }
+                EventPayload::Issue(issue) => {
+                    issue.title = "[redacted]".to_string();
+                    issue.labels.clear();
+                }
+                EventPayload::Deployment(deployment) => {
+                    deployment.environment = aliases.alias("env", &deployment.environment);
+                    deployment.version = None;
+                }
+                EventPayload::Release(release) => {
+                    release.version = aliases.alias("release", &release.version);
+                    release.name = None;
+                }
+                EventPayload::Comment(comment) => {
+                    comment.target_title = "[redacted]".to_string();
+                    comment.thread = None;
+                }
+                EventPayload::Incident(incident) => {
+                    incident.title = "[redacted]".to_string();
+                    incident.summary = None;
+                    incident.customer_impact = None;
+                }
+                EventPayload::Document(document) => {
+                    document.title = "[redacted]".to_string();
                 }
             }
 
@@ -50,23 +173,13 @@ pub(crate) fn redact_event_with_aliases<A: AliasResolver + ?Sized>(
             event.source.url = None;
             event.source.opaque_id = None;
             event
+                .extensions
+                .retain(|key, _| key.starts_with(EXTENSION_PUBLIC_PREFIX));
+            event
         }
     }
 }
 
-/// Redact all events for the selected profile.
-pub(crate) fn redact_events_with_aliases<A: AliasResolver + ?Sized>(
-    events: &[EventEnvelope],
-    profile: RedactionProfile,
-    aliases: &A,
-) -> Vec<EventEnvelope> {
-    events
-        .iter()
-        .cloned()
-        .map(|event| redact_event_with_aliases(event, profile, aliases))
-        .collect()
-}
-
 /// Redact a single workstream for the selected profile.
 pub(crate) fn redact_workstream_with_aliases<A: AliasResolver + ?Sized>(
     mut workstream: Workstream,
@@ -109,7 +222,7 @@ pub(crate) fn redact_workstreams_with_aliases<A: AliasResolver + ?Sized>(
 mod tests {
     use super::*;
     use chrono::{NaiveDate, Utc};
-    use shiplog::ids::{EventId, WorkstreamId};
+    use shiplog::ids::{EventId, OpaqueId, WorkstreamId};
     use shiplog::schema::event::*;
     use shiplog::schema::workstream::WorkstreamStats;
 
@@ -124,6 +237,7 @@ mod tests {
 
     fn sample_pr_event() -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -156,8 +270,11 @@ mod tests {
             source: SourceRef {
                 system: SourceSystem::Github,
                 url: Some("https://api.github.com/repos/org/repo/pulls/1".into()),
-                opaque_id: Some("github-pr-node-id".into()),
+                opaque_id: Some(OpaqueId::from_raw("test", "github-pr-node-id")),
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -173,6 +290,8 @@ mod tests {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         }
     }
@@ -214,19 +333,252 @@ mod tests {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
             impact: Some("Sensitive impact".into()),
+            effort: None,
+            metrics: vec![ImpactMetric {
+                name: "p95 latency".into(),
+                value: 180.into(),
+                unit: Some("ms".into()),
+                baseline: Some(420.into()),
+            }],
+            evidence: vec![shiplog::schema::event::EvidenceFile {
+                path: "evidence/b94d27b9934d-dashboard.png".into(),
+                label: Some("Error budget dashboard".into()),
+                sha256: None,
+            }],
         });
 
-        let out = redact_event_with_aliases(event, RedactionProfile::Manager, &alias);
+        let out = redact_event_with_aliases(event.clone(), RedactionProfile::Manager, &alias);
         match out.payload {
             EventPayload::Manual(m) => {
                 assert_eq!(m.title, "Database incident");
                 assert!(m.description.is_none());
                 assert!(m.impact.is_none());
+                assert_eq!(m.metrics.len(), 1, "numbers survive the manager view");
+                assert!(m.evidence.is_empty(), "evidence files stay internal");
+            }
+            _ => panic!("expected manual payload"),
+        }
+
+        let out = redact_event_with_aliases(event, RedactionProfile::Public, &alias);
+        match out.payload {
+            EventPayload::Manual(m) => {
+                assert!(m.metrics.is_empty());
+                assert!(m.evidence.is_empty());
             }
             _ => panic!("expected manual payload"),
         }
     }
 
+    #[test]
+    fn public_profile_aliases_deployment_and_release_identifiers() {
+        let mut deployment = sample_pr_event();
+        deployment.kind = EventKind::Deployment;
+        deployment.payload = EventPayload::Deployment(DeploymentEvent {
+            environment: "prod-eu-acme".into(),
+            version: Some("billing-api@2.3.1".into()),
+            status: DeploymentStatus::Succeeded,
+            started_at: Utc::now(),
+            finished_at: None,
+            window: None,
+        });
+        match redact_event_with_aliases(deployment, RedactionProfile::Public, &alias).payload {
+            EventPayload::Deployment(d) => {
+                assert_eq!(d.environment, alias("env", "prod-eu-acme"));
+                assert!(d.version.is_none());
+                assert_eq!(d.status, DeploymentStatus::Succeeded);
+            }
+            _ => panic!("expected deployment payload"),
+        }
+
+        let mut release = sample_pr_event();
+        release.kind = EventKind::Release;
+        release.payload = EventPayload::Release(ReleaseEvent {
+            version: "acme-billing-v2".into(),
+            name: Some("Billing GA".into()),
+            status: ReleaseStatus::Published,
+            created_at: Utc::now(),
+            published_at: None,
+            window: None,
+        });
+        match redact_event_with_aliases(release, RedactionProfile::Public, &alias).payload {
+            EventPayload::Release(r) => {
+                assert_eq!(r.version, alias("release", "acme-billing-v2"));
+                assert!(r.name.is_none());
+            }
+            _ => panic!("expected release payload"),
+        }
+    }
+
+    #[test]
+    fn comment_thread_context_is_removed_outside_internal() {
+        let mut event = sample_pr_event();
+        event.kind = EventKind::Comment;
+        event.payload = EventPayload::Comment(CommentEvent {
+            target: CommentTarget::PullRequest,
+            target_number: Some(1),
+            target_title: "Highly Sensitive".into(),
+            thread: Some("secret/path.rs".into()),
+            count: 4,
+            first_at: Utc::now(),
+            last_at: Utc::now(),
+            window: None,
+        });
+
+        match redact_event_with_aliases(event.clone(), RedactionProfile::Manager, &alias).payload {
+            EventPayload::Comment(c) => {
+                assert_eq!(c.target_title, "Highly Sensitive");
+                assert!(c.thread.is_none());
+                assert_eq!(c.count, 4);
+            }
+            _ => panic!("expected comment payload"),
+        }
+        match redact_event_with_aliases(event, RedactionProfile::Public, &alias).payload {
+            EventPayload::Comment(c) => {
+                assert_eq!(c.target_title, "[redacted]");
+                assert!(c.thread.is_none());
+            }
+            _ => panic!("expected comment payload"),
+        }
+    }
+
+    #[test]
+    fn document_title_is_redacted_only_for_public() {
+        let mut event = sample_pr_event();
+        event.kind = EventKind::Document;
+        event.payload = EventPayload::Document(DocumentEvent {
+            doc_type: DocumentType::Design,
+            title: "Highly Sensitive".into(),
+            system: "confluence".into(),
+            last_edited_at: Utc::now(),
+            word_count_delta: Some(1200),
+            window: None,
+        });
+
+        match redact_event_with_aliases(event.clone(), RedactionProfile::Manager, &alias).payload {
+            EventPayload::Document(d) => assert_eq!(d.title, "Highly Sensitive"),
+            _ => panic!("expected document payload"),
+        }
+        match redact_event_with_aliases(event, RedactionProfile::Public, &alias).payload {
+            EventPayload::Document(d) => {
+                assert_eq!(d.title, "[redacted]");
+                assert_eq!(d.system, "confluence");
+                assert_eq!(d.word_count_delta, Some(1200));
+            }
+            _ => panic!("expected document payload"),
+        }
+    }
+
+    #[test]
+    fn incident_narrative_is_removed_outside_internal() {
+        let mut event = sample_pr_event();
+        event.kind = EventKind::Incident;
+        event.payload = EventPayload::Incident(IncidentEvent {
+            title: "Highly Sensitive".into(),
+            severity: IncidentSeverity::Sev2,
+            role: IncidentRole::Commander,
+            detected_at: Utc::now(),
+            resolved_at: None,
+            duration_minutes: Some(90),
+            summary: Some("customer db credentials rotated".into()),
+            customer_impact: Some("acme corp checkout down".into()),
+            window: None,
+        });
+
+        match redact_event_with_aliases(event.clone(), RedactionProfile::Internal, &alias).payload {
+            EventPayload::Incident(i) => assert!(i.customer_impact.is_some()),
+            _ => panic!("expected incident payload"),
+        }
+        match redact_event_with_aliases(event.clone(), RedactionProfile::Manager, &alias).payload {
+            EventPayload::Incident(i) => {
+                assert_eq!(i.title, "Highly Sensitive");
+                assert!(i.summary.is_none());
+                assert!(i.customer_impact.is_none());
+                assert_eq!(i.severity, IncidentSeverity::Sev2);
+            }
+            _ => panic!("expected incident payload"),
+        }
+        match redact_event_with_aliases(event, RedactionProfile::Public, &alias).payload {
+            EventPayload::Incident(i) => {
+                assert_eq!(i.title, "[redacted]");
+                assert!(i.summary.is_none());
+                assert!(i.customer_impact.is_none());
+                assert_eq!(i.role, IncidentRole::Commander);
+                assert_eq!(i.duration_minutes, Some(90));
+            }
+            _ => panic!("expected incident payload"),
+        }
+    }
+
+    #[test]
+    fn extensions_are_filtered_by_key_prefix() {
+        let mut event = sample_pr_event();
+        event.extensions = [
+            ("public.ci_status", "green"),
+            ("gitlab.pipeline_id", "4412"),
+            ("internal.oncall_notes", "paged twice"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), serde_json::json!(value)))
+        .collect();
+
+        let keys = |profile| {
+            redact_event_with_aliases(event.clone(), profile, &alias)
+                .extensions
+                .into_keys()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(keys(RedactionProfile::Internal).len(), 3);
+        assert_eq!(
+            keys(RedactionProfile::Manager),
+            vec!["gitlab.pipeline_id", "public.ci_status"]
+        );
+        assert_eq!(keys(RedactionProfile::Public), vec!["public.ci_status"]);
+    }
+
+    #[test]
+    fn contributors_are_aliased_only_in_public_profile() {
+        let mut event = sample_pr_event();
+        event.contributors = vec![Contributor {
+            login: "pairing-partner".into(),
+            id: Some(42),
+            role: ContributorRole::Pair,
+        }];
+
+        let manager = redact_event_with_aliases(event.clone(), RedactionProfile::Manager, &alias);
+        assert_eq!(manager.contributors, event.contributors);
+
+        let public = redact_event_with_aliases(event, RedactionProfile::Public, &alias);
+        assert_eq!(public.contributors.len(), 1);
+        assert_ne!(public.contributors[0].login, "pairing-partner");
+        assert_eq!(public.contributors[0].id, None);
+        assert_eq!(public.contributors[0].role, ContributorRole::Pair);
+    }
+
+    #[test]
+    fn review_pull_author_is_aliased_only_in_public_profile() {
+        let mut event = sample_pr_event();
+        event.kind = EventKind::Review;
+        event.payload = EventPayload::Review(ReviewEvent {
+            pull_number: 1,
+            pull_title: "Highly Sensitive".into(),
+            submitted_at: Utc::now(),
+            pull_created_at: None,
+            pull_author: Some("teammate".into()),
+            state: "approved".into(),
+            window: None,
+        });
+        let author = |event: &EventEnvelope| match &event.payload {
+            EventPayload::Review(review) => review.pull_author.clone(),
+            _ => unreachable!(),
+        };
+
+        let manager = redact_event_with_aliases(event.clone(), RedactionProfile::Manager, &alias);
+        assert_eq!(author(&manager).as_deref(), Some("teammate"));
+
+        let public = redact_event_with_aliases(event, RedactionProfile::Public, &alias);
+        assert_eq!(author(&public), Some(alias("actor", "teammate")));
+    }
+
     #[test]
     fn workstream_public_profile_aliases_title_and_filters_repo_tag() {
         let ws_file = sample_workstreams();
@@ -254,4 +606,15 @@ mod tests {
         let resolver = |kind: &str, value: &str| format!("{kind}:{value}");
         assert_eq!(resolver.alias("repo", "org/repo"), "repo:org/repo");
     }
+
+    #[test]
+    fn rule_digests_are_stable_and_distinct_per_profile() {
+        let internal = redaction_rules_digest(RedactionProfile::Internal);
+        let manager = redaction_rules_digest(RedactionProfile::Manager);
+        let public = redaction_rules_digest(RedactionProfile::Public);
+        assert_eq!(public.len(), 64);
+        assert_eq!(public, redaction_rules_digest(RedactionProfile::Public));
+        assert_ne!(internal, manager);
+        assert_ne!(manager, public);
+    }
 }
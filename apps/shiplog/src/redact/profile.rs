@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 /// The tool produces multiple projections from the same ledger.
 /// Think of them as lenses, not forks.
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
 pub enum RedactionProfile {
     Internal,
     Manager,
@@ -1,9 +1,8 @@
 //! Profile-string projection dispatch for shiplog redaction.
 
-use super::policy::{redact_events_with_aliases, redact_workstreams_with_aliases};
+use super::policy::redact_workstreams_with_aliases;
 use super::profile::RedactionProfile;
 use super::repo::AliasResolver;
-use shiplog::schema::event::EventEnvelope;
 use shiplog::schema::workstream::WorkstreamsFile;
 
 /// Parse a raw profile string into a canonical profile.
@@ -12,16 +11,6 @@ pub(crate) fn parse_profile(profile: &str) -> RedactionProfile {
     RedactionProfile::from_profile_str(profile)
 }
 
-/// Project events using profile-string dispatch and an alias resolver.
-#[must_use]
-pub(crate) fn project_events_with_aliases<A: AliasResolver + ?Sized>(
-    events: &[EventEnvelope],
-    profile: &str,
-    aliases: &A,
-) -> Vec<EventEnvelope> {
-    redact_events_with_aliases(events, parse_profile(profile), aliases)
-}
-
 /// Project workstreams using profile-string dispatch and an alias resolver.
 #[must_use]
 pub(crate) fn project_workstreams_with_aliases<A: AliasResolver + ?Sized>(
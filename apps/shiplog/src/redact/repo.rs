@@ -5,8 +5,12 @@ use shiplog::schema::event::{RepoRef, RepoVisibility};
 /// Alias resolver used by public repository redaction.
 pub(crate) trait AliasResolver {
     fn alias(&self, kind: &str, value: &str) -> String;
+
+    /// Keyed digest of a value that is never written to the alias cache.
+    fn hash(&self, kind: &str, value: &str) -> String;
 }
 
+/// Closures resolve aliases and hashes the same way.
 impl<F> AliasResolver for F
 where
     F: Fn(&str, &str) -> String,
@@ -14,6 +18,10 @@ where
     fn alias(&self, kind: &str, value: &str) -> String {
         (self)(kind, value)
     }
+
+    fn hash(&self, kind: &str, value: &str) -> String {
+        (self)(kind, value)
+    }
 }
 
 /// Redact a repository reference for `public` profile projection.
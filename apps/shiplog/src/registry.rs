@@ -0,0 +1,313 @@
+//! Named ingestor factories.
+//!
+//! An [`IngestorRegistry`] maps source names to factories that build an
+//! [`Ingestor`] from a shared [`SourceConfig`]. The CLI registers the built-in
+//! sources and resolves `shiplog collect --source <name>` through it; crates
+//! that embed shiplog register their own sources the same way, then hand the
+//! resulting [`IngestOutput`](crate::ports::IngestOutput) to the engine.
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::ports::Ingestor;
+
+/// Builds an ingestor for one collection.
+pub type IngestorFactory = Box<dyn Fn(&SourceConfig) -> Result<Box<dyn Ingestor>> + Send + Sync>;
+
+/// Settings every source receives. Source-specific settings arrive as
+/// string `options`, the same shape as `--source-option key=value`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceConfig {
+    /// Source name the factory was registered under.
+    pub source: String,
+    /// Account, author, or user ID to report on, when the source needs one.
+    pub user: Option<String>,
+    /// Start date (inclusive).
+    pub since: NaiveDate,
+    /// End date (exclusive).
+    pub until: NaiveDate,
+    /// Directory relative path options are resolved against.
+    pub base_dir: PathBuf,
+    /// API cache directory, or `None` to disable caching.
+    pub cache_dir: Option<PathBuf>,
+    /// Source-specific options.
+    pub options: BTreeMap<String, String>,
+}
+
+impl SourceConfig {
+    /// Config for `source` over `since..until`, with no user, cache, or options.
+    pub fn new(source: impl Into<String>, since: NaiveDate, until: NaiveDate) -> Self {
+        Self {
+            source: source.into(),
+            user: None,
+            since,
+            until,
+            base_dir: PathBuf::from("."),
+            cache_dir: None,
+            options: BTreeMap::new(),
+        }
+    }
+
+    /// Parse `key=value` pairs into options. Later keys win.
+    pub fn with_option_pairs<I, S>(mut self, pairs: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for pair in pairs {
+            let pair = pair.as_ref();
+            let (key, value) = pair
+                .split_once('=')
+                .filter(|(key, _)| !key.trim().is_empty())
+                .with_context(|| format!("source option {pair:?} must look like key=value"))?;
+            self.options
+                .insert(key.trim().to_string(), value.trim().to_string());
+        }
+        Ok(self)
+    }
+
+    /// The configured user, or an error naming the source.
+    pub fn require_user(&self) -> Result<&str> {
+        self.user
+            .as_deref()
+            .filter(|user| !user.is_empty())
+            .with_context(|| format!("source {} needs --user", self.source))
+    }
+
+    /// A non-empty option value.
+    pub fn option(&self, key: &str) -> Option<&str> {
+        self.options
+            .get(key)
+            .map(String::as_str)
+            .filter(|value| !value.is_empty())
+    }
+
+    /// A required option value.
+    pub fn require_option(&self, key: &str) -> Result<&str> {
+        self.option(key)
+            .with_context(|| format!("source {} needs --source-option {key}=<value>", self.source))
+    }
+
+    /// Parse an option with [`FromStr`], or `None` when it is unset.
+    pub fn parse_option<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        self.option(key)
+            .map(|value| {
+                value.parse::<T>().map_err(|err| {
+                    anyhow::anyhow!("source {} option {key}={value:?}: {err}", self.source)
+                })
+            })
+            .transpose()
+    }
+
+    /// A path option resolved against [`SourceConfig::base_dir`].
+    pub fn path_option(&self, key: &str) -> Option<PathBuf> {
+        self.option(key).map(|value| self.base_dir.join(value))
+    }
+
+    /// A comma-separated list option.
+    pub fn list_option(&self, key: &str) -> Vec<String> {
+        self.option(key)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Reject options outside `known`, so a typo does not silently fall back
+    /// to a default.
+    pub fn expect_options(&self, known: &[&str]) -> Result<()> {
+        let unknown: Vec<&str> = self
+            .options
+            .keys()
+            .map(String::as_str)
+            .filter(|key| !known.contains(key))
+            .collect();
+        if unknown.is_empty() {
+            return Ok(());
+        }
+        let expected = if known.is_empty() {
+            "none".to_string()
+        } else {
+            known.join(", ")
+        };
+        anyhow::bail!(
+            "unknown option {} for source {}; expected: {expected}",
+            unknown.join(", "),
+            self.source
+        )
+    }
+}
+
+struct Registration {
+    summary: String,
+    factory: IngestorFactory,
+}
+
+/// Source names mapped to the factories that build their ingestors.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use shiplog::ingest::json::JsonIngestor;
+/// use shiplog::registry::{IngestorRegistry, SourceConfig};
+///
+/// let mut registry = IngestorRegistry::new();
+/// registry
+///     .register("ledger", "Events from a JSONL ledger", |config| {
+///         Ok(Box::new(JsonIngestor {
+///             events_path: config.require_option("events")?.into(),
+///             coverage_path: config.require_option("coverage")?.into(),
+///         }))
+///     })
+///     .unwrap();
+///
+/// let since = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// let until = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+/// let config = SourceConfig::new("ledger", since, until)
+///     .with_option_pairs(["events=ledger.events.jsonl", "coverage=coverage.manifest.json"])
+///     .unwrap();
+/// assert!(registry.create("ledger", &config).is_ok());
+/// assert!(registry.create("missing", &config).is_err());
+/// ```
+#[derive(Default)]
+pub struct IngestorRegistry {
+    sources: BTreeMap<String, Registration>,
+}
+
+impl IngestorRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `factory` under `name`. Names are case-sensitive and must be
+    /// unique.
+    pub fn register<F>(&mut self, name: &str, summary: &str, factory: F) -> Result<()>
+    where
+        F: Fn(&SourceConfig) -> Result<Box<dyn Ingestor>> + Send + Sync + 'static,
+    {
+        if name.trim().is_empty() {
+            anyhow::bail!("source name cannot be empty");
+        }
+        if self.sources.contains_key(name) {
+            anyhow::bail!("source {name} is already registered");
+        }
+        self.sources.insert(
+            name.to_string(),
+            Registration {
+                summary: summary.to_string(),
+                factory: Box::new(factory),
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether `name` is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.sources.contains_key(name)
+    }
+
+    /// Registered names with their one-line summaries, sorted by name.
+    pub fn sources(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.sources
+            .iter()
+            .map(|(name, registration)| (name.as_str(), registration.summary.as_str()))
+    }
+
+    /// Build the ingestor registered under `name`.
+    pub fn create(&self, name: &str, config: &SourceConfig) -> Result<Box<dyn Ingestor>> {
+        let registration = self.sources.get(name).with_context(|| {
+            let known: Vec<&str> = self.sources.keys().map(String::as_str).collect();
+            format!(
+                "unknown source {name}; registered sources: {}",
+                if known.is_empty() {
+                    "none".to_string()
+                } else {
+                    known.join(", ")
+                }
+            )
+        })?;
+        (registration.factory)(config).with_context(|| format!("create {name} ingestor"))
+    }
+}
+
+impl std::fmt::Debug for IngestorRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IngestorRegistry")
+            .field("sources", &self.sources.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::IngestOutput;
+
+    struct Fails;
+
+    impl Ingestor for Fails {
+        fn ingest(&self) -> Result<IngestOutput> {
+            anyhow::bail!("not collected")
+        }
+    }
+
+    #[test]
+    fn registers_creates_and_rejects_sources() {
+        let mut registry = IngestorRegistry::new();
+        registry
+            .register("stub", "Stub source", |config| {
+                config.expect_options(&["depth"])?;
+                let _depth: Option<u32> = config.parse_option("depth")?;
+                Ok(Box::new(Fails))
+            })
+            .unwrap();
+        assert!(
+            registry
+                .register("stub", "Again", |_| Ok(Box::new(Fails)))
+                .is_err()
+        );
+        assert_eq!(
+            registry.sources().collect::<Vec<_>>(),
+            vec![("stub", "Stub source")]
+        );
+
+        let since = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        let config = SourceConfig::new("stub", since, until)
+            .with_option_pairs(["depth=3"])
+            .unwrap();
+        assert!(registry.create("stub", &config).is_ok());
+
+        let typo = config.clone().with_option_pairs(["dpeth=3"]).unwrap();
+        let err = registry.create("stub", &typo).err().unwrap();
+        assert!(format!("{err:#}").contains("unknown option dpeth for source stub"));
+
+        let bad = config.clone().with_option_pairs(["depth=deep"]).unwrap();
+        assert!(registry.create("stub", &bad).is_err());
+
+        let err = registry.create("jira2", &config).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "unknown source jira2; registered sources: stub"
+        );
+        assert!(
+            SourceConfig::new("stub", since, until)
+                .with_option_pairs(["novalue"])
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,442 @@
+//! Structured `packet.json` renderer for downstream tooling.
+//!
+//! Carries the same facts as `packet.md` — summary counts, workstream claims
+//! with their receipts, and the coverage summary — in a stable schema, so
+//! dashboards can ingest packets without parsing Markdown. The contract is
+//! `contracts/schemas/packet.v1.schema.json`; renamed or removed fields bump
+//! [`PACKET_JSON_SCHEMA_VERSION`].
+
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use shiplog::ports::Renderer;
+use shiplog::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
+use shiplog::schema::event::{EventEnvelope, EventKind, EventPayload};
+use shiplog::schema::workstream::{ReceiptWeight, Workstream, WorkstreamStats, WorkstreamsFile};
+
+use crate::workstreams::{order_receipts_by_weight, receipt_weight};
+
+/// Version of the `packet.json` schema written by this build.
+pub const PACKET_JSON_SCHEMA_VERSION: u64 = 1;
+
+/// Contents of `packet.json`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PacketJson {
+    /// Schema version, see [`PACKET_JSON_SCHEMA_VERSION`].
+    pub schema_version: u64,
+    /// Run the packet was rendered from.
+    pub run_id: String,
+    /// User the packet is about.
+    pub user: String,
+    /// Human label for the review window.
+    pub window_label: String,
+    /// Review window, `until` exclusive.
+    pub window: TimeWindow,
+    /// Headline counts.
+    pub summary: PacketSummary,
+    /// Workstream claims, in packet order.
+    pub workstreams: Vec<PacketWorkstream>,
+    /// How complete the evidence behind the claims is.
+    pub coverage: PacketCoverage,
+}
+
+/// Headline counts for the whole packet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacketSummary {
+    /// Number of workstreams.
+    pub workstreams: usize,
+    /// Number of events in the ledger.
+    pub events: usize,
+    /// Number of receipts selected across all workstreams.
+    pub receipts: usize,
+    /// Event counts keyed by event kind, e.g. `PullRequest`.
+    pub events_by_kind: BTreeMap<String, usize>,
+}
+
+/// One workstream and the receipts backing its claim.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacketWorkstream {
+    /// Stable workstream id.
+    pub id: String,
+    /// Workstream title.
+    pub title: String,
+    /// Written claim, when one has been filled in.
+    pub claim: Option<String>,
+    /// Workstream tags.
+    pub tags: Vec<String>,
+    /// Event counts and effort for the workstream.
+    pub stats: WorkstreamStats,
+    /// Receipts, heaviest first as in the packet. Ids missing from the ledger
+    /// are skipped.
+    pub receipts: Vec<PacketReceipt>,
+}
+
+/// One receipt behind a claim.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacketReceipt {
+    /// Event id in `ledger.events.jsonl`.
+    pub id: String,
+    /// Event kind, e.g. `PullRequest`.
+    pub kind: String,
+    /// Title of the PR, issue, document, or other source object.
+    pub title: String,
+    /// When the event happened.
+    pub occurred_at: DateTime<Utc>,
+    /// Repository or project the event belongs to.
+    pub repo: String,
+    /// Link back to the source, when the source recorded one.
+    pub url: Option<String>,
+    /// Curated or derived weight of the receipt.
+    pub weight: ReceiptWeight,
+}
+
+/// Coverage summary for the packet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacketCoverage {
+    /// Overall completeness.
+    pub completeness: Completeness,
+    /// Coverage score out of 100, when computed.
+    pub score: Option<u8>,
+    /// Sources that contributed evidence.
+    pub sources: Vec<String>,
+    /// Number of query windows fetched.
+    pub slices: usize,
+    /// Number of query windows that fetched fewer results than reported.
+    pub partial_slices: usize,
+    /// Coverage warnings, as in the packet.
+    pub warnings: Vec<String>,
+}
+
+/// Renders `packet.json` through the [`Renderer`] port.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ports::Renderer;
+/// use shiplog::render::json::{JsonRenderer, PacketJson};
+/// use shiplog::schema::coverage::Completeness;
+/// use shiplog::schema::workstream::WorkstreamsFile;
+/// use shiplog_testkit::fixtures::{WorkstreamFixture, test_coverage};
+/// use shiplog_testkit::pr_event;
+/// use chrono::Utc;
+///
+/// let pr = pr_event("acme/billing", 7, "Retry failed invoices");
+/// let workstreams = WorkstreamsFile {
+///     version: 1,
+///     generated_at: Utc::now(),
+///     workstreams: vec![WorkstreamFixture::new("Billing").with_receipt(&pr).build()],
+/// };
+/// let coverage = test_coverage("octo", Completeness::Complete);
+///
+/// let json = JsonRenderer
+///     .render_packet_markdown("octo", "2025-Q1", &[pr], &workstreams, &coverage)
+///     .unwrap();
+/// let packet: PacketJson = serde_json::from_str(&json).unwrap();
+/// let receipt = &packet.workstreams[0].receipts[0];
+/// assert_eq!(receipt.title, "Retry failed invoices");
+/// assert_eq!(receipt.url.as_deref(), Some("https://github.com/acme/billing/pull/7"));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    /// Returns pretty-printed `packet.json` rather than Markdown.
+    fn render_packet_markdown(
+        &self,
+        user: &str,
+        window_label: &str,
+        events: &[EventEnvelope],
+        workstreams: &WorkstreamsFile,
+        coverage: &CoverageManifest,
+    ) -> Result<String> {
+        let packet = build_packet_json(user, window_label, events, workstreams, coverage);
+        let mut json = serde_json::to_string_pretty(&packet).context("serialize packet json")?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+/// Build the `packet.json` contents for a run.
+pub fn build_packet_json(
+    user: &str,
+    window_label: &str,
+    events: &[EventEnvelope],
+    workstreams: &WorkstreamsFile,
+    coverage: &CoverageManifest,
+) -> PacketJson {
+    let by_id: HashMap<&str, &EventEnvelope> =
+        events.iter().map(|e| (e.id.0.as_str(), e)).collect();
+
+    let mut events_by_kind = BTreeMap::new();
+    for event in events {
+        *events_by_kind.entry(kind_name(&event.kind)).or_insert(0) += 1;
+    }
+
+    let workstreams: Vec<PacketWorkstream> = workstreams
+        .workstreams
+        .iter()
+        .map(|ws| PacketWorkstream {
+            id: ws.id.0.clone(),
+            title: ws.title.clone(),
+            claim: ws.summary.clone(),
+            tags: ws.tags.clone(),
+            stats: ws.stats.clone(),
+            receipts: receipts(ws, &by_id),
+        })
+        .collect();
+
+    PacketJson {
+        schema_version: PACKET_JSON_SCHEMA_VERSION,
+        run_id: coverage.run_id.0.clone(),
+        user: user.to_string(),
+        window_label: window_label.to_string(),
+        window: coverage.window.clone(),
+        summary: PacketSummary {
+            workstreams: workstreams.len(),
+            events: events.len(),
+            receipts: workstreams.iter().map(|ws| ws.receipts.len()).sum(),
+            events_by_kind,
+        },
+        workstreams,
+        coverage: PacketCoverage {
+            completeness: coverage.completeness.clone(),
+            score: coverage.score,
+            sources: coverage.sources.clone(),
+            slices: coverage.slices.len(),
+            partial_slices: coverage.slices.iter().filter(|s| s.is_partial()).count(),
+            warnings: coverage.warnings.clone(),
+        },
+    }
+}
+
+fn receipts(ws: &Workstream, by_id: &HashMap<&str, &EventEnvelope>) -> Vec<PacketReceipt> {
+    let mut events: Vec<&EventEnvelope> = ws
+        .receipts
+        .iter()
+        .filter_map(|id| by_id.get(id.0.as_str()).copied())
+        .collect();
+    order_receipts_by_weight(ws, &mut events);
+    events.into_iter().map(|event| receipt(ws, event)).collect()
+}
+
+fn receipt(ws: &Workstream, event: &EventEnvelope) -> PacketReceipt {
+    event_receipt(event, receipt_weight(ws, event))
+}
+
+/// Describe one ledger event as a packet receipt with the given weight.
+pub(crate) fn event_receipt(event: &EventEnvelope, weight: ReceiptWeight) -> PacketReceipt {
+    PacketReceipt {
+        id: event.id.0.clone(),
+        kind: kind_name(&event.kind),
+        title: event_title(event).to_string(),
+        occurred_at: event.occurred_at,
+        repo: event.repo.full_name.clone(),
+        url: event
+            .links
+            .first()
+            .map(|link| link.url.clone())
+            .or_else(|| event.source.url.clone()),
+        weight,
+    }
+}
+
+fn kind_name(kind: &EventKind) -> String {
+    format!("{kind:?}")
+}
+
+fn event_title(event: &EventEnvelope) -> &str {
+    match &event.payload {
+        EventPayload::PullRequest(payload) => &payload.title,
+        EventPayload::Review(payload) => &payload.pull_title,
+        EventPayload::Manual(payload) => &payload.title,
+        EventPayload::Issue(payload) => &payload.title,
+        EventPayload::Deployment(payload) => &payload.environment,
+        EventPayload::Release(payload) => payload.name.as_deref().unwrap_or(&payload.version),
+        EventPayload::Comment(payload) => &payload.target_title,
+        EventPayload::Incident(payload) => &payload.title,
+        EventPayload::Document(payload) => &payload.title,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+    use shiplog::ids::{EventId, RunId, WorkstreamId};
+    use shiplog::schema::coverage::CoverageSlice;
+    use shiplog::schema::event::*;
+    use shiplog::schema::workstream::Workstream;
+
+    fn window() -> TimeWindow {
+        TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            until: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+        }
+    }
+
+    fn pr_event(number: u64, title: &str, additions: u64) -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["github", "pr", "acme/app", &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "acme/app".into(),
+                html_url: None,
+                visibility: RepoVisibility::Unknown,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: title.to_string(),
+                state: PullRequestState::Merged,
+                created_at: Utc.timestamp_opt(0, 0).unwrap(),
+                merged_at: Some(Utc.timestamp_opt(0, 0).unwrap()),
+                additions: Some(additions),
+                deletions: Some(0),
+                changed_files: Some(1),
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: Some(format!("https://github.com/acme/app/pull/{number}")),
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    fn coverage(slices: Vec<CoverageSlice>) -> CoverageManifest {
+        CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
+            run_id: RunId("run_1".into()),
+            generated_at: Utc.timestamp_opt(0, 0).unwrap(),
+            user: "octo".into(),
+            window: window(),
+            mode: "merged".into(),
+            sources: vec!["github".into()],
+            slices,
+            warnings: vec!["github: 1 query window was partial".into()],
+            completeness: Completeness::Partial,
+            score: Some(80),
+            timezone: None,
+            budget: vec![],
+        }
+    }
+
+    fn workstream(receipts: &[&EventEnvelope]) -> WorkstreamsFile {
+        WorkstreamsFile {
+            version: 1,
+            generated_at: Utc.timestamp_opt(0, 0).unwrap(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "app"]),
+                title: "App".into(),
+                summary: Some("Shipped the app".into()),
+                tags: vec![],
+                stats: WorkstreamStats::zero(),
+                events: receipts.iter().map(|e| e.id.clone()).collect(),
+                receipts: receipts.iter().map(|e| e.id.clone()).collect(),
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        }
+    }
+
+    #[test]
+    fn receipts_are_ordered_heaviest_first_and_unknown_ids_skipped() {
+        let small = pr_event(1, "Fix typo", 1);
+        let large = pr_event(2, "Rewrite the billing engine", 2_000);
+        let mut workstreams = workstream(&[&small, &large]);
+        workstreams.workstreams[0]
+            .receipts
+            .push(EventId::from_parts(["missing"]));
+
+        let packet = build_packet_json(
+            "octo",
+            "2025-Q1",
+            &[small, large],
+            &workstreams,
+            &coverage(vec![]),
+        );
+
+        let receipts = &packet.workstreams[0].receipts;
+        let titles: Vec<&str> = receipts.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, ["Rewrite the billing engine", "Fix typo"]);
+        assert_eq!(
+            receipts[0].url.as_deref(),
+            Some("https://github.com/acme/app/pull/2"),
+            "falls back to the source url without links"
+        );
+        assert_eq!(packet.summary.receipts, 2);
+        assert_eq!(packet.summary.events_by_kind["PullRequest"], 2);
+        assert_eq!(
+            packet.workstreams[0].claim.as_deref(),
+            Some("Shipped the app")
+        );
+    }
+
+    #[test]
+    fn coverage_summary_counts_partial_slices() {
+        let slice = |fetched| CoverageSlice {
+            window: window(),
+            query: "author:octo".into(),
+            total_count: 10,
+            fetched,
+            incomplete_results: None,
+            notes: vec![],
+            source: None,
+        };
+        let packet = build_packet_json(
+            "octo",
+            "2025-Q1",
+            &[],
+            &workstream(&[]),
+            &coverage(vec![slice(10), slice(4)]),
+        );
+
+        assert_eq!(packet.coverage.slices, 2);
+        assert_eq!(packet.coverage.partial_slices, 1);
+        assert_eq!(packet.coverage.score, Some(80));
+        assert_eq!(packet.coverage.completeness, Completeness::Partial);
+    }
+
+    #[test]
+    fn renderer_output_is_versioned_and_round_trips() {
+        let pr = pr_event(1, "Ship it", 100);
+        let json = JsonRenderer
+            .render_packet_markdown(
+                "octo",
+                "2025-Q1",
+                std::slice::from_ref(&pr),
+                &workstream(&[&pr]),
+                &coverage(vec![]),
+            )
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], PACKET_JSON_SCHEMA_VERSION);
+        assert_eq!(value["workstreams"][0]["receipts"][0]["weight"], "Medium");
+
+        let packet: PacketJson = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            packet,
+            build_packet_json(
+                "octo",
+                "2025-Q1",
+                std::slice::from_ref(&pr),
+                &workstream(&[&pr]),
+                &coverage(vec![])
+            )
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! "Needs Attention" block rendered at the top of the internal packet.
+
+use shiplog::attention::AttentionItem;
+
+/// Most items listed in the packet; the rest stay in `needs_attention.json`.
+const PACKET_ATTENTION_LIMIT: usize = 10;
+
+/// Render the needs-attention block, or nothing when there is nothing to fix.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::attention::{AttentionItem, AttentionKind, AttentionPriority};
+/// use shiplog::render::md::render_needs_attention;
+///
+/// assert_eq!(render_needs_attention(&[]), "");
+///
+/// let block = render_needs_attention(&[AttentionItem {
+///     priority: AttentionPriority::High,
+///     kind: AttentionKind::NoReceipts,
+///     subject: "Billing".into(),
+///     message: "\"Billing\" has no receipts.".into(),
+/// }]);
+/// assert!(block.starts_with("# Needs Attention\n"));
+/// assert!(block.contains("- **High** \"Billing\" has no receipts."));
+/// ```
+pub fn render_needs_attention(items: &[AttentionItem]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from("# Needs Attention\n\n");
+    out.push_str(
+        "_Fix these before sharing. This block is not included in manager or public packets._\n\n",
+    );
+    for item in items.iter().take(PACKET_ATTENTION_LIMIT) {
+        out.push_str(&format!(
+            "- **{}** {}\n",
+            item.priority.label(),
+            item.message
+        ));
+    }
+    if items.len() > PACKET_ATTENTION_LIMIT {
+        out.push_str(&format!(
+            "- _+ {} more in `needs_attention.json`_\n",
+            items.len() - PACKET_ATTENTION_LIMIT
+        ));
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,117 @@
+//! "What changed since the last packet?" report for two runs.
+
+use crate::bundle::{BundleDiff, EventChange};
+
+/// Render a [`BundleDiff`] as Markdown.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use shiplog::bundle::diff_bundles;
+/// use shiplog::render::md::render_bundle_diff;
+/// use std::path::Path;
+///
+/// let diff = diff_bundles(Path::new("./out/run_122"), Path::new("./out/run_123"))?;
+/// let report = render_bundle_diff(&diff);
+/// assert!(report.starts_with("# Changes: run_122 -> run_123\n"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn render_bundle_diff(diff: &BundleDiff) -> String {
+    let mut out = format!("# Changes: {} -> {}\n\n", diff.from_run, diff.to_run);
+    if diff.is_unchanged() {
+        out.push_str("No events or workstreams changed.\n\n");
+    }
+
+    out.push_str("## Events\n\n");
+    out.push_str(&format!(
+        "- Added: {}\n- Removed: {}\n- Changed: {}\n\n",
+        diff.events_added.len(),
+        diff.events_removed.len(),
+        diff.events_changed.len()
+    ));
+    render_events(&mut out, "Added", &diff.events_added);
+    render_events(&mut out, "Removed", &diff.events_removed);
+    render_events(&mut out, "Changed", &diff.events_changed);
+
+    out.push_str("## Workstreams\n\n");
+    let workstreams = &diff.workstreams;
+    if workstreams.is_unchanged() {
+        out.push_str("- No changes\n");
+    }
+    for title in &workstreams.added {
+        out.push_str(&format!("- Added: {title}\n"));
+    }
+    for title in &workstreams.removed {
+        out.push_str(&format!("- Removed: {title}\n"));
+    }
+    for change in &workstreams.changed {
+        out.push_str(&format!("- Changed: {}", change.title));
+        if let Some(previous) = &change.renamed_from {
+            out.push_str(&format!(" (renamed from {previous})"));
+        }
+        out.push_str(&format!(
+            ", +{} / -{} events\n",
+            change.events_added, change.events_removed
+        ));
+    }
+    out.push('\n');
+
+    let coverage = &diff.coverage;
+    out.push_str("## Coverage\n\n");
+    out.push_str(&format!(
+        "- Window: {}..{}",
+        coverage.to_window.since, coverage.to_window.until
+    ));
+    if coverage.window_changed() {
+        out.push_str(&format!(
+            " (was {}..{})",
+            coverage.from_window.since, coverage.from_window.until
+        ));
+    }
+    out.push('\n');
+    out.push_str(&format!("- Completeness: {}", coverage.to_completeness));
+    if coverage.from_completeness != coverage.to_completeness {
+        out.push_str(&format!(" (was {})", coverage.from_completeness));
+    }
+    out.push('\n');
+    if !coverage.sources_added.is_empty() {
+        out.push_str(&format!(
+            "- Sources added: {}\n",
+            coverage.sources_added.join(", ")
+        ));
+    }
+    if !coverage.sources_removed.is_empty() {
+        out.push_str(&format!(
+            "- Sources removed: {}\n",
+            coverage.sources_removed.join(", ")
+        ));
+    }
+    out.push_str(&format!(
+        "- Slices: {} newly complete, {} newly partial, {} still partial\n",
+        coverage.newly_complete.len(),
+        coverage.newly_partial.len(),
+        coverage.still_partial.len()
+    ));
+    out
+}
+
+fn render_events(out: &mut String, label: &str, events: &[EventChange]) {
+    if events.is_empty() {
+        return;
+    }
+    out.push_str(&format!("### {label}\n\n"));
+    for event in events {
+        out.push_str(&format!(
+            "- {} {} `{}`: {}",
+            event.occurred_at.format("%Y-%m-%d"),
+            event.kind,
+            event.repo,
+            event.title
+        ));
+        if !event.fields.is_empty() {
+            out.push_str(&format!(" ({})", event.fields.join(", ")));
+        }
+        out.push('\n');
+    }
+    out.push('\n');
+}
@@ -0,0 +1,95 @@
+//! Collaboration section: who the work was shared with, listed alphabetically
+//! rather than ranked, so it reads as evidence of reach and not a leaderboard.
+
+use shiplog::schema::event::EventEnvelope;
+use shiplog::stats::collaboration;
+
+/// Names listed per relationship before the rest are summarised.
+const NAMES_PER_GROUP: usize = 8;
+
+/// Render the `## Collaboration` section. Renders nothing when no source
+/// recorded collaborators.
+pub(super) fn render_collaboration(out: &mut String, events: &[EventEnvelope]) {
+    let collab = collaboration(events);
+    if collab.is_empty() {
+        return;
+    }
+
+    out.push_str("## Collaboration\n\n");
+    out.push_str(&format!(
+        "_{} distinct {}, listed alphabetically._\n\n",
+        collab.people(),
+        if collab.people() == 1 {
+            "person"
+        } else {
+            "people"
+        }
+    ));
+    for (label, people) in collab.groups() {
+        if people.is_empty() {
+            continue;
+        }
+        let mut names: Vec<&str> = people.iter().map(String::as_str).collect();
+        names.sort_by_key(|name| name.to_ascii_lowercase());
+        let mut line = names
+            .iter()
+            .take(NAMES_PER_GROUP)
+            .copied()
+            .collect::<Vec<_>>()
+            .join(", ");
+        if names.len() > NAMES_PER_GROUP {
+            line.push_str(&format!(", and {} more", names.len() - NAMES_PER_GROUP));
+        }
+        out.push_str(&format!("- **{label}** ({}): {line}\n", names.len()));
+    }
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shiplog::fixtures::{FixtureSpec, generate};
+    use shiplog::schema::event::{Contributor, ContributorRole};
+
+    #[test]
+    fn lists_groups_alphabetically_and_caps_long_lists() {
+        let mut events = generate(&FixtureSpec {
+            events: 1,
+            mix: "pr=1".parse().unwrap(),
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events;
+        events[0].contributors = ["zoe", "Amir", "kai"]
+            .into_iter()
+            .map(|login| Contributor {
+                login: login.into(),
+                id: None,
+                role: ContributorRole::Reviewer,
+            })
+            .chain((0..10).map(|n| Contributor {
+                login: format!("pair{n:02}"),
+                id: None,
+                role: ContributorRole::Pair,
+            }))
+            .collect();
+
+        let mut out = String::new();
+        render_collaboration(&mut out, &events);
+        assert!(
+            out.starts_with("## Collaboration\n\n_13 distinct people, listed alphabetically._\n\n")
+        );
+        assert!(out.contains("- **Reviewers on my PRs** (3): Amir, kai, zoe\n"));
+        assert!(out.contains(
+            "- **Pairing partners** (10): pair00, pair01, pair02, pair03, pair04, pair05, pair06, pair07, and 2 more\n"
+        ));
+        assert!(!out.contains("Co-authors"));
+    }
+
+    #[test]
+    fn renders_nothing_without_collaborators() {
+        let mut out = String::new();
+        render_collaboration(&mut out, &[]);
+        assert!(out.is_empty());
+    }
+}
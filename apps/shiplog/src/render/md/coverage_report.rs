@@ -0,0 +1,334 @@
+//! Standalone `coverage.report.md` rendering.
+//!
+//! The packet's coverage section is a short summary. This report spells out,
+//! per source and per query window, what was searched, what came back, what
+//! hit a cap, what each source is missing, and what to do next, so a reviewer
+//! can answer "is this everything?" without reading the manifest JSON.
+
+use std::collections::BTreeMap;
+
+use shiplog::coverage::{CoverageGaps, SourceGaps, coverage_gaps};
+use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::event::EventEnvelope;
+
+use super::source::{
+    display_source_label, included_source_summary, skipped_source_warning, skipped_source_warnings,
+    source_event_count, source_present,
+};
+
+/// Render the standalone coverage report for a run.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::render::md::render_coverage_report;
+/// use shiplog::ids::RunId;
+/// use shiplog::schema::coverage::*;
+/// use chrono::{NaiveDate, Utc};
+///
+/// let coverage = CoverageManifest {
+///     schema_version: SchemaVersion::CURRENT,
+///     run_id: RunId::now("test"),
+///     generated_at: Utc::now(),
+///     user: "octo".into(),
+///     window: TimeWindow {
+///         since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///         until: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+///     },
+///     mode: "merged".into(),
+///     sources: vec!["github".into()],
+///     slices: vec![],
+///     warnings: vec![],
+///     completeness: Completeness::Complete,
+///     score: None,
+///     timezone: None,
+///     budget: vec![],
+/// };
+///
+/// let report = render_coverage_report(&coverage, &[]);
+/// assert!(report.starts_with("# Coverage Report\n"));
+/// assert!(report.contains("## What to do\n"));
+/// ```
+pub fn render_coverage_report(coverage: &CoverageManifest, events: &[EventEnvelope]) -> String {
+    let mut out = String::new();
+    out.push_str("# Coverage Report\n\n");
+    render_verdict(&mut out, coverage);
+    render_sources(&mut out, coverage, events);
+    render_windows(&mut out, &coverage.slices);
+    render_caps(&mut out, &coverage.slices);
+    render_gaps(&mut out, &coverage_gaps(coverage, events));
+    render_warnings(&mut out, &coverage.warnings);
+    render_next_steps(&mut out, coverage);
+    out
+}
+
+fn render_verdict(out: &mut String, coverage: &CoverageManifest) {
+    out.push_str(&format!(
+        "Run `{}` searched {} to {} (exclusive) for `{}` in {} mode.\n\n",
+        coverage.run_id, coverage.window.since, coverage.window.until, coverage.user, coverage.mode
+    ));
+
+    let score = coverage
+        .score
+        .map(|score| format!(" Coverage score: {score}/100."))
+        .unwrap_or_default();
+    let verdict = match coverage.completeness {
+        Completeness::Complete => {
+            "Every query returned everything its source reported.".to_string()
+        }
+        Completeness::Partial => {
+            "Some data is missing: at least one source was skipped, capped, or \
+             reported incomplete results."
+                .to_string()
+        }
+        Completeness::Unknown => {
+            "Completeness could not be determined from what the sources reported.".to_string()
+        }
+    };
+    out.push_str(&format!(
+        "**Completeness:** {}. {verdict}{score}\n\n",
+        coverage.completeness
+    ));
+}
+
+fn render_sources(out: &mut String, coverage: &CoverageManifest, events: &[EventEnvelope]) {
+    out.push_str("## Sources\n\n");
+    let skipped = skipped_source_warnings(&coverage.warnings);
+    let included = included_source_summary(&coverage.sources, events, &skipped);
+
+    if included.is_empty() && skipped.is_empty() {
+        out.push_str("- No sources were recorded for this run.\n\n");
+        return;
+    }
+    for source in &included {
+        let count = source_event_count(events, source);
+        let noun = if count == 1 { "event" } else { "events" };
+        let origin = if source_present(std::slice::from_ref(source), "manual") {
+            " These are user-provided and are not checked against any system."
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "- **{}:** queried; {count} {noun} in the ledger.{origin}\n",
+            display_source_label(source)
+        ));
+    }
+    for skipped in &skipped {
+        out.push_str(&format!(
+            "- **{}:** not searched ({}).\n",
+            display_source_label(skipped.source),
+            skipped.reason
+        ));
+    }
+    out.push('\n');
+}
+
+fn render_windows(out: &mut String, slices: &[CoverageSlice]) {
+    out.push_str("## What was searched\n\n");
+    if slices.is_empty() {
+        out.push_str(
+            "No query slices were recorded, so there are no per-window counts to check \
+             against.\n\n",
+        );
+        return;
+    }
+
+    let mut windows: BTreeMap<_, Vec<&CoverageSlice>> = BTreeMap::new();
+    for slice in slices {
+        windows
+            .entry((slice.window.since, slice.window.until))
+            .or_default()
+            .push(slice);
+    }
+
+    for ((since, until), slices) in windows {
+        out.push_str(&format!("### {since} to {until}\n\n"));
+        for slice in slices {
+            out.push_str(&format!("- `{}`: {}\n", slice.query, slice_outcome(slice)));
+            for note in &slice.notes {
+                out.push_str(&format!("  - Note: {note}\n"));
+            }
+        }
+        out.push('\n');
+    }
+}
+
+fn slice_outcome(slice: &CoverageSlice) -> String {
+    let mut outcome = if slice.total_count == 0 && slice.fetched == 0 {
+        "the source reported no results".to_string()
+    } else if slice.fetched >= slice.total_count {
+        format!(
+            "fetched all {} reported {}",
+            slice.total_count,
+            results_noun(slice.total_count)
+        )
+    } else {
+        format!(
+            "fetched {} of {} reported {} ({} not retrieved)",
+            slice.fetched,
+            slice.total_count,
+            results_noun(slice.total_count),
+            slice.total_count - slice.fetched
+        )
+    };
+    if slice.incomplete_results.unwrap_or(false) {
+        outcome.push_str("; the source flagged these results as incomplete");
+    }
+    outcome.push('.');
+    outcome
+}
+
+fn results_noun(count: u64) -> &'static str {
+    if count == 1 { "result" } else { "results" }
+}
+
+fn render_caps(out: &mut String, slices: &[CoverageSlice]) {
+    out.push_str("## What hit caps\n\n");
+    let partial: Vec<_> = slices.iter().filter(|slice| slice.is_partial()).collect();
+    if partial.is_empty() {
+        out.push_str("Nothing hit a cap or came back incomplete.\n\n");
+        return;
+    }
+
+    let missing: u64 = partial
+        .iter()
+        .map(|slice| slice.total_count.saturating_sub(slice.fetched))
+        .sum();
+    out.push_str(&format!(
+        "{} of {} query slices came back short, leaving at least {missing} reported {} \
+         out of the ledger:\n\n",
+        partial.len(),
+        slices.len(),
+        results_noun(missing)
+    ));
+    for slice in partial {
+        out.push_str(&format!(
+            "- {} to {}: `{}`\n",
+            slice.window.since, slice.window.until, slice.query
+        ));
+    }
+    out.push('\n');
+}
+
+fn render_gaps(out: &mut String, gaps: &CoverageGaps) {
+    if gaps.sources.is_empty() {
+        return;
+    }
+    out.push_str("## Gaps by source\n\n");
+    if gaps.is_empty() {
+        out.push_str(
+            "Every source covered the whole window, fetched everything it reported, and \
+             contributed events.\n\n",
+        );
+        return;
+    }
+
+    for source in gaps.sources.iter().filter(|source| !source.is_empty()) {
+        out.push_str(&format!(
+            "- **{}:** {}.\n",
+            display_source_label(&source.source),
+            source_gap_summary(source)
+        ));
+    }
+    out.push_str("\nPer-source details are in `coverage.gaps.json`.\n\n");
+}
+
+fn source_gap_summary(source: &SourceGaps) -> String {
+    let mut parts = Vec::new();
+    match source.skipped.as_deref() {
+        Some("") => parts.push("not searched".to_string()),
+        Some(reason) => parts.push(format!("not searched ({reason})")),
+        None => {
+            if source.events == 0 {
+                parts.push("contributed no events".to_string());
+            }
+            if !source.uncovered.is_empty() {
+                parts.push(format!(
+                    "no query covered {}",
+                    window_list(&source.uncovered)
+                ));
+            }
+        }
+    }
+    if !source.capped.is_empty() {
+        let windows: Vec<TimeWindow> = source
+            .capped
+            .iter()
+            .map(|slice| slice.window.clone())
+            .collect();
+        parts.push(format!(
+            "{} came back short ({})",
+            if windows.len() == 1 {
+                "1 query slice".to_string()
+            } else {
+                format!("{} query slices", windows.len())
+            },
+            window_list(&windows)
+        ));
+    }
+    parts.join("; ")
+}
+
+fn window_list(windows: &[TimeWindow]) -> String {
+    windows
+        .iter()
+        .map(|window| format!("{} to {}", window.since, window.until))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render_warnings(out: &mut String, warnings: &[String]) {
+    let other: Vec<_> = warnings
+        .iter()
+        .filter(|warning| skipped_source_warning(warning).is_none())
+        .collect();
+    if other.is_empty() {
+        return;
+    }
+
+    out.push_str("## Warnings\n\n");
+    for warning in other {
+        out.push_str(&format!("- {warning}\n"));
+    }
+    out.push('\n');
+}
+
+fn render_next_steps(out: &mut String, coverage: &CoverageManifest) {
+    out.push_str("## What to do\n\n");
+    let mut steps = Vec::new();
+    if !skipped_source_warnings(&coverage.warnings).is_empty() {
+        steps.push(
+            "Run `shiplog doctor` to see why skipped sources were not searched, fix their \
+             configuration or tokens, and collect again."
+                .to_string(),
+        );
+    }
+    if coverage
+        .slices
+        .iter()
+        .any(|slice| slice.fetched < slice.total_count)
+    {
+        steps.push(
+            "Capped windows returned fewer results than the source reported. Collect again \
+             over a shorter date range so each query stays under the source's result limit."
+                .to_string(),
+        );
+    }
+    if coverage
+        .slices
+        .iter()
+        .any(|slice| slice.incomplete_results.unwrap_or(false))
+    {
+        steps.push(
+            "Sources that flagged incomplete results often recover on a retry; collect again \
+             and compare with `shiplog coverage diff`."
+                .to_string(),
+        );
+    }
+    if steps.is_empty() {
+        steps.push("Nothing to fix: the packet reflects everything the sources returned.".into());
+    }
+    for step in steps {
+        out.push_str(&format!("- {step}\n"));
+    }
+}
@@ -0,0 +1,176 @@
+//! Per-day activity heatmap rendered as a compact Markdown grid.
+//!
+//! One row per Monday-first week of the collection window, one cell per day.
+//! Cells shade from `·` (no events) to `█` (the busiest day), so the table
+//! reads as a calendar in plain text and as a grid once converted to HTML.
+
+use crate::coverage::ReportingTimezone;
+use chrono::{Datelike, Days, NaiveDate};
+use shiplog::schema::coverage::TimeWindow;
+use shiplog::schema::event::EventEnvelope;
+use std::collections::BTreeMap;
+
+/// Shades from no activity to the busiest day in the window.
+const SHADES: [&str; 5] = ["·", "░", "▒", "▓", "█"];
+
+/// Render the `## Activity` section for events inside `window`.
+///
+/// Days outside the window are left blank. Renders nothing when the window
+/// is empty.
+pub(super) fn render_activity_heatmap(
+    out: &mut String,
+    events: &[EventEnvelope],
+    window: &TimeWindow,
+    timezone: ReportingTimezone,
+) {
+    if window.since >= window.until {
+        return;
+    }
+
+    let mut per_day: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    for event in events {
+        let day = timezone.local_date(&event.occurred_at);
+        if window.contains(day) {
+            *per_day.entry(day).or_default() += 1;
+        }
+    }
+    let busiest = per_day
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+        .map(|(day, count)| (*day, *count));
+
+    out.push_str("## Activity\n\n");
+    match busiest {
+        Some((day, count)) => out.push_str(&format!(
+            "_Events per day, weeks starting Monday. Busiest day: {day} ({count} {})._\n\n",
+            if count == 1 { "event" } else { "events" }
+        )),
+        None => out.push_str("_No events in this window._\n\n"),
+    }
+    out.push_str("| Week of | Mon | Tue | Wed | Thu | Fri | Sat | Sun | Events |\n");
+    out.push_str("|---|:-:|:-:|:-:|:-:|:-:|:-:|:-:|---:|\n");
+
+    let max = busiest.map_or(0, |(_, count)| count);
+    let last_day = window.until.pred_opt().unwrap_or(window.until);
+    let mut week_start = window
+        .since
+        .checked_sub_days(Days::new(u64::from(
+            window.since.weekday().num_days_from_monday(),
+        )))
+        .unwrap_or(window.since);
+    while week_start <= last_day {
+        let mut row = format!("| {week_start} |");
+        let mut week_total = 0;
+        for offset in 0..7 {
+            let Some(day) = week_start.checked_add_days(Days::new(offset)) else {
+                row.push_str("  |");
+                continue;
+            };
+            if !window.contains(day) {
+                row.push_str("  |");
+                continue;
+            }
+            let count = per_day.get(&day).copied().unwrap_or(0);
+            week_total += count;
+            row.push_str(&format!(" {} |", shade(count, max)));
+        }
+        row.push_str(&format!(" {week_total} |\n"));
+        out.push_str(&row);
+        let Some(next) = week_start.checked_add_days(Days::new(7)) else {
+            break;
+        };
+        week_start = next;
+    }
+
+    out.push_str(&format!(
+        "\n_Scale: {} none → {} busiest._\n\n",
+        SHADES[0], SHADES[4]
+    ));
+}
+
+/// Shade for `count` relative to the busiest day: any activity is at least
+/// the lightest non-empty shade, and only the busiest days get the darkest.
+fn shade(count: usize, max: usize) -> &'static str {
+    if count == 0 || max == 0 {
+        return SHADES[0];
+    }
+    let level = (count * (SHADES.len() - 1)).div_ceil(max);
+    SHADES[level.clamp(1, SHADES.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use shiplog::fixtures::{FixtureSpec, generate};
+
+    fn events_on(days: &[(u32, u32)]) -> Vec<EventEnvelope> {
+        let mut events = generate(&FixtureSpec {
+            events: days.len(),
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events;
+        for (event, (month, day)) in events.iter_mut().zip(days) {
+            event.occurred_at = Utc.with_ymd_and_hms(2025, *month, *day, 12, 0, 0).unwrap();
+        }
+        events
+    }
+
+    fn window(since: (u32, u32), until: (u32, u32)) -> TimeWindow {
+        TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, since.0, since.1).unwrap(),
+            until: NaiveDate::from_ymd_opt(2025, until.0, until.1).unwrap(),
+        }
+    }
+
+    #[test]
+    fn renders_monday_first_weeks_with_blank_days_outside_the_window() {
+        // 2025-01-01 is a Wednesday; the window ends on Sunday 2025-01-12.
+        let events = events_on(&[(1, 1), (1, 6), (1, 6), (1, 6), (1, 6), (1, 9), (2, 1)]);
+        let mut out = String::new();
+        render_activity_heatmap(
+            &mut out,
+            &events,
+            &window((1, 1), (1, 13)),
+            ReportingTimezone::UTC,
+        );
+
+        assert!(out.starts_with("## Activity\n\n"));
+        assert!(out.contains("Busiest day: 2025-01-06 (4 events)."));
+        assert!(out.contains("| 2024-12-30 |  |  | ░ | · | · | · | · | 1 |\n"));
+        assert!(out.contains("| 2025-01-06 | █ | · | · | ░ | · | · | · | 5 |\n"));
+        assert!(!out.contains("2025-01-13"));
+    }
+
+    #[test]
+    fn shades_scale_to_the_busiest_day() {
+        assert_eq!(shade(0, 8), "·");
+        assert_eq!(shade(1, 8), "░");
+        assert_eq!(shade(3, 8), "▒");
+        assert_eq!(shade(6, 8), "▓");
+        assert_eq!(shade(8, 8), "█");
+        assert_eq!(shade(1, 1), "█");
+    }
+
+    #[test]
+    fn empty_window_renders_nothing_and_quiet_window_says_so() {
+        let mut out = String::new();
+        render_activity_heatmap(
+            &mut out,
+            &[],
+            &window((1, 5), (1, 5)),
+            ReportingTimezone::UTC,
+        );
+        assert!(out.is_empty());
+
+        render_activity_heatmap(
+            &mut out,
+            &[],
+            &window((1, 6), (1, 8)),
+            ReportingTimezone::UTC,
+        );
+        assert!(out.contains("_No events in this window._"));
+        assert!(out.contains("| 2025-01-06 | · | · |  |  |  |  |  | 0 |\n"));
+    }
+}
@@ -0,0 +1,34 @@
+//! Event lookup shared by the packet sections.
+
+use shiplog::ids::EventId;
+use shiplog::schema::event::EventEnvelope;
+use std::collections::HashMap;
+
+/// Events keyed by id, built once per render.
+///
+/// Keys and values borrow from the caller's slice, so sections that walk a
+/// workstream's ids never copy events or ids, however large the ledger.
+pub(crate) struct EventIndex<'a> {
+    by_id: HashMap<&'a str, &'a EventEnvelope>,
+}
+
+impl<'a> EventIndex<'a> {
+    pub(crate) fn new(events: &'a [EventEnvelope]) -> Self {
+        Self {
+            by_id: events.iter().map(|e| (e.id.0.as_str(), e)).collect(),
+        }
+    }
+
+    pub(crate) fn get(&self, id: &EventId) -> Option<&'a EventEnvelope> {
+        self.by_id.get(id.0.as_str()).copied()
+    }
+
+    /// The events behind `ids`, in order. Ids missing from the ledger are
+    /// skipped.
+    pub(crate) fn resolve<'i>(
+        &'i self,
+        ids: &'i [EventId],
+    ) -> impl Iterator<Item = &'a EventEnvelope> + 'i {
+        ids.iter().filter_map(|id| self.get(id))
+    }
+}
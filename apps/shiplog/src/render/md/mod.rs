@@ -3,22 +3,46 @@
 //! Converts canonical events, workstreams, and coverage metadata into an
 //! editable self-review packet with receipts and appendix sections.
 
-use crate::workstreams::WORKSTREAM_RECEIPT_RENDER_LIMIT;
+use crate::coverage::ReportingTimezone;
+use crate::workstreams::{
+    HeuristicSummarizer, WORKSTREAM_RECEIPT_RENDER_LIMIT, order_receipts_by_weight,
+};
 use anyhow::Result;
-use shiplog::ports::Renderer;
+use shiplog::ports::{Renderer, Summarizer};
 use shiplog::schema::coverage::CoverageManifest;
-use shiplog::schema::event::{EventEnvelope, EventKind};
+use shiplog::schema::event::{EventEnvelope, EventKind, EventPayload};
 use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
-use std::collections::HashMap;
+use shiplog::stats::{PrSize, event_pr_size, pr_size_distribution, review_turnaround};
 
+use self::collaboration::render_collaboration;
 use self::coverage::render_coverage;
+use self::heatmap::render_activity_heatmap;
+use self::index::EventIndex;
+use self::review_load::render_review_load;
 use self::source::display_source_list;
 
+mod attention;
+mod bundle_diff;
+mod collaboration;
 mod coverage;
+mod coverage_report;
+mod heatmap;
+mod index;
+mod prompt;
 pub mod receipt;
+mod review_load;
+mod review_status;
+mod rubric_gaps;
 mod source;
+mod template;
 
-pub use receipt::{format_receipt_markdown, manual_type_emoji};
+pub use attention::render_needs_attention;
+pub use bundle_diff::render_bundle_diff;
+pub use coverage_report::render_coverage_report;
+pub use receipt::{format_receipt_markdown, format_receipt_markdown_in, manual_type_emoji};
+pub use review_status::render_review_status;
+pub use rubric_gaps::render_rubric_gaps;
+pub use template::{PacketTemplate, packet_template_context};
 
 const WORKSTREAM_EVIDENCE_ANCHOR_LIMIT: usize = 3;
 
@@ -65,6 +89,11 @@ pub struct MarkdownRenderOptions {
     pub receipt_limit: usize,
     /// Appendix detail level.
     pub appendix_mode: AppendixMode,
+    /// Add a per-day activity heatmap after the summary.
+    pub heatmap: bool,
+    /// Leave PRs smaller than this out of the main receipts section. PRs
+    /// without line counts are always kept, and the appendix keeps everything.
+    pub min_pr_size: Option<PrSize>,
 }
 
 impl Default for MarkdownRenderOptions {
@@ -72,6 +101,8 @@ impl Default for MarkdownRenderOptions {
         Self {
             receipt_limit: WORKSTREAM_RECEIPT_RENDER_LIMIT,
             appendix_mode: AppendixMode::Full,
+            heatmap: false,
+            min_pr_size: None,
         }
     }
 }
@@ -114,12 +145,15 @@ impl Default for MarkdownRenderOptions {
 pub struct MarkdownRenderer {
     /// Section ordering configuration
     pub section_order: SectionOrder,
+    /// User template that replaces the built-in packet layout.
+    pub template: Option<PacketTemplate>,
 }
 
 impl Default for MarkdownRenderer {
     fn default() -> Self {
         Self {
             section_order: SectionOrder::Default,
+            template: None,
         }
     }
 }
@@ -153,6 +187,23 @@ impl MarkdownRenderer {
         self
     }
 
+    /// Render default packets through a user template instead of the
+    /// built-in layout. Scaffold, receipts, and prompt views are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::render::md::{MarkdownRenderer, PacketTemplate};
+    ///
+    /// let template = PacketTemplate::new("# {{ user }}\n").unwrap();
+    /// let renderer = MarkdownRenderer::new().with_template(template);
+    /// assert!(renderer.template.is_some());
+    /// ```
+    pub fn with_template(mut self, template: PacketTemplate) -> Self {
+        self.template = Some(template);
+        self
+    }
+
     /// Render a writing scaffold with coverage, workstream prompts, and evidence anchors.
     pub fn render_scaffold_markdown(
         &self,
@@ -185,10 +236,20 @@ impl MarkdownRenderer {
         coverage: &CoverageManifest,
         _options: MarkdownRenderOptions,
     ) -> Result<String> {
+        let timezone = ReportingTimezone::from_manifest(coverage.timezone.as_deref());
+        let index = EventIndex::new(events);
         let mut out = String::new();
         render_coverage(&mut out, coverage, events);
-        render_summary(&mut out, user, window_label, events, workstreams, coverage);
-        render_workstreams(&mut out, events, workstreams);
+        render_summary(
+            &mut out,
+            user,
+            window_label,
+            events,
+            &index,
+            workstreams,
+            coverage,
+        );
+        render_workstreams(&mut out, &index, workstreams, timezone);
         render_file_artifacts(&mut out);
         Ok(out)
     }
@@ -222,11 +283,27 @@ impl MarkdownRenderer {
         coverage: &CoverageManifest,
         options: MarkdownRenderOptions,
     ) -> Result<String> {
+        let timezone = ReportingTimezone::from_manifest(coverage.timezone.as_deref());
+        let index = EventIndex::new(events);
         let mut out = String::new();
-        render_summary(&mut out, user, window_label, events, workstreams, coverage);
+        render_summary(
+            &mut out,
+            user,
+            window_label,
+            events,
+            &index,
+            workstreams,
+            coverage,
+        );
         render_coverage(&mut out, coverage, events);
-        render_receipts(&mut out, events, workstreams, options);
-        render_appendix(&mut out, events, workstreams, options.appendix_mode);
+        render_receipts(&mut out, &index, workstreams, options, timezone);
+        render_appendix(
+            &mut out,
+            &index,
+            workstreams,
+            options.appendix_mode,
+            timezone,
+        );
         render_file_artifacts(&mut out);
         Ok(out)
     }
@@ -241,29 +318,93 @@ impl MarkdownRenderer {
         coverage: &CoverageManifest,
         options: MarkdownRenderOptions,
     ) -> Result<String> {
+        if let Some(template) = &self.template {
+            return template.render(user, window_label, events, workstreams, coverage);
+        }
+
+        let timezone = ReportingTimezone::from_manifest(coverage.timezone.as_deref());
+        let index = EventIndex::new(events);
         let mut out = String::new();
 
         // Render sections based on configured order
         match self.section_order {
             SectionOrder::Default => {
-                render_summary(&mut out, user, window_label, events, workstreams, coverage);
-                render_workstreams(&mut out, events, workstreams);
-                render_receipts(&mut out, events, workstreams, options);
+                render_summary(
+                    &mut out,
+                    user,
+                    window_label,
+                    events,
+                    &index,
+                    workstreams,
+                    coverage,
+                );
+                if options.heatmap {
+                    render_activity_heatmap(&mut out, events, &coverage.window, timezone);
+                }
+                render_workstreams(&mut out, &index, workstreams, timezone);
+                render_receipts(&mut out, &index, workstreams, options, timezone);
                 render_coverage(&mut out, coverage, events);
             }
             SectionOrder::CoverageFirst => {
                 render_coverage(&mut out, coverage, events);
-                render_summary(&mut out, user, window_label, events, workstreams, coverage);
-                render_workstreams(&mut out, events, workstreams);
-                render_receipts(&mut out, events, workstreams, options);
+                render_summary(
+                    &mut out,
+                    user,
+                    window_label,
+                    events,
+                    &index,
+                    workstreams,
+                    coverage,
+                );
+                if options.heatmap {
+                    render_activity_heatmap(&mut out, events, &coverage.window, timezone);
+                }
+                render_workstreams(&mut out, &index, workstreams, timezone);
+                render_receipts(&mut out, &index, workstreams, options, timezone);
             }
         }
 
-        render_appendix(&mut out, events, workstreams, options.appendix_mode);
+        render_appendix(
+            &mut out,
+            &index,
+            workstreams,
+            options.appendix_mode,
+            timezone,
+        );
         render_file_artifacts(&mut out);
 
         Ok(out)
     }
+
+    /// Render a prompt that hands workstreams, tagged receipts, claim
+    /// scaffolds, and coverage limits to an LLM of the user's choice.
+    ///
+    /// Nothing is sent anywhere. Pass events and workstreams already redacted
+    /// for `profile`; the prompt tells the model how that profile aliases
+    /// names. `options.receipt_limit` caps receipts per workstream.
+    pub fn render_llm_prompt(
+        &self,
+        user: &str,
+        window_label: &str,
+        profile: &str,
+        events: &[EventEnvelope],
+        workstreams: &WorkstreamsFile,
+        coverage: &CoverageManifest,
+        options: MarkdownRenderOptions,
+    ) -> Result<String> {
+        let mut out = String::new();
+        prompt::render_prompt(
+            &mut out,
+            user,
+            window_label,
+            profile,
+            events,
+            workstreams,
+            coverage,
+            options,
+        );
+        Ok(out)
+    }
 }
 
 impl Renderer for MarkdownRenderer {
@@ -291,6 +432,7 @@ fn render_summary(
     _user: &str,
     window_label: &str,
     events: &[EventEnvelope],
+    index: &EventIndex<'_>,
     workstreams: &WorkstreamsFile,
     coverage: &CoverageManifest,
 ) {
@@ -319,14 +461,49 @@ fn render_summary(
         .filter(|e| matches!(e.kind, EventKind::Manual))
         .count();
     out.push_str(&format!(
-        "**Events:** {}, {}, {}\n\n",
+        "**Events:** {}, {}, {}{}\n\n",
         count_label(pr_count, "PR", "PRs"),
         count_label(review_count, "review", "reviews"),
-        count_label(manual_count, "manual event", "manual events")
+        count_label(manual_count, "manual event", "manual events"),
+        optional_kind_counts(events.iter())
     ));
 
+    // PR size distribution, when sources reported line counts
+    let sizes = pr_size_distribution(events);
+    if sizes.sized() > 0 {
+        let buckets: Vec<String> = PrSize::ALL
+            .iter()
+            .map(|size| format!("{size} {}", sizes.count(*size)))
+            .collect();
+        let unknown = if sizes.unknown > 0 {
+            format!(" ({} unknown)", sizes.unknown)
+        } else {
+            String::new()
+        };
+        out.push_str(&format!(
+            "**PR sizes:** {}{unknown}\n\n",
+            buckets.join(", ")
+        ));
+    }
+
+    // Review turnaround, when the sources recorded when reviewed PRs opened
+    if let Some(turnaround) = review_turnaround(events) {
+        out.push_str(&format!(
+            "**Review turnaround:** median {}, p90 {} across {}\n\n",
+            format_minutes(turnaround.median_minutes),
+            format_minutes(turnaround.p90_minutes),
+            count_label(turnaround.reviews, "reviewed PR", "reviewed PRs")
+        ));
+    }
+
     // Completeness
-    out.push_str(&format!("**Coverage:** {:?}\n\n", coverage.completeness));
+    match coverage.score {
+        Some(score) => out.push_str(&format!(
+            "**Coverage:** {:?} (score {score}/100)\n\n",
+            coverage.completeness
+        )),
+        None => out.push_str(&format!("**Coverage:** {:?}\n\n", coverage.completeness)),
+    }
 
     // Sources
     out.push_str(&format!(
@@ -343,7 +520,13 @@ fn render_summary(
         out.push('\n');
     }
 
-    render_executive_summary(out, events, workstreams, coverage);
+    render_executive_summary(out, index, workstreams, coverage);
+    render_review_load(
+        out,
+        events,
+        ReportingTimezone::from_manifest(coverage.timezone.as_deref()),
+    );
+    render_collaboration(out, events);
 }
 
 /// Workstream-by-workstream one-liner overview. The doc contract
@@ -353,7 +536,7 @@ fn render_summary(
 /// skipped-items section when coverage warnings exist.
 fn render_executive_summary(
     out: &mut String,
-    events: &[EventEnvelope],
+    index: &EventIndex<'_>,
     workstreams: &WorkstreamsFile,
     coverage: &CoverageManifest,
 ) {
@@ -364,9 +547,6 @@ fn render_executive_summary(
             "_No workstreams yet — no evidence has been clustered into a workstream._\n\n",
         );
     } else {
-        let by_id: HashMap<&str, &EventEnvelope> =
-            events.iter().map(|e| (e.id.0.as_str(), e)).collect();
-
         // Cap at 15 lines to honor the doc's 5–15 line ceiling: list the
         // first 14 workstreams in full, then a "+ N more" line if needed.
         const MAX_LINES: usize = 14;
@@ -374,39 +554,19 @@ fn render_executive_summary(
         let shown = total.min(MAX_LINES);
 
         for ws in workstreams.workstreams.iter().take(shown) {
-            let ws_pr = ws
-                .events
-                .iter()
-                .filter(|id| {
-                    by_id
-                        .get(id.0.as_str())
-                        .is_some_and(|e| matches!(e.kind, EventKind::PullRequest))
-                })
-                .count();
-            let ws_review = ws
-                .events
-                .iter()
-                .filter(|id| {
-                    by_id
-                        .get(id.0.as_str())
-                        .is_some_and(|e| matches!(e.kind, EventKind::Review))
-                })
-                .count();
-            let ws_manual = ws
-                .events
-                .iter()
-                .filter(|id| {
-                    by_id
-                        .get(id.0.as_str())
-                        .is_some_and(|e| matches!(e.kind, EventKind::Manual))
-                })
-                .count();
+            let count_kind =
+                |kind: EventKind| index.resolve(&ws.events).filter(|e| e.kind == kind).count();
+            let ws_pr = count_kind(EventKind::PullRequest);
+            let ws_review = count_kind(EventKind::Review);
+            let ws_manual = count_kind(EventKind::Manual);
+            let ws_events = index.resolve(&ws.events);
 
             let counts = format!(
-                "{}, {}, {}",
+                "{}, {}, {}{}",
                 count_label(ws_pr, "PR", "PRs"),
                 count_label(ws_review, "review", "reviews"),
                 count_label(ws_manual, "manual event", "manual events"),
+                optional_kind_counts(ws_events),
             );
 
             let mut gaps: Vec<&str> = Vec::new();
@@ -442,7 +602,12 @@ fn render_executive_summary(
     }
 }
 
-fn render_workstreams(out: &mut String, events: &[EventEnvelope], workstreams: &WorkstreamsFile) {
+fn render_workstreams(
+    out: &mut String,
+    index: &EventIndex<'_>,
+    workstreams: &WorkstreamsFile,
+    timezone: ReportingTimezone,
+) {
     out.push_str("## Workstreams\n\n");
 
     if workstreams.workstreams.is_empty() {
@@ -450,9 +615,6 @@ fn render_workstreams(out: &mut String, events: &[EventEnvelope], workstreams: &
         return;
     }
 
-    let by_id: HashMap<String, &EventEnvelope> =
-        events.iter().map(|e| (e.id.0.clone(), e)).collect();
-
     for ws in &workstreams.workstreams {
         out.push_str(&format!("### {}\n\n", ws.title));
 
@@ -461,29 +623,102 @@ fn render_workstreams(out: &mut String, events: &[EventEnvelope], workstreams: &
             out.push_str("\n\n");
         }
 
-        render_evidence_anchors(out, &by_id, ws);
-        render_claim_prompts(out);
+        render_evidence_anchors(out, index, ws, timezone);
+        render_impact_metrics(out, index, ws);
+        render_claim_prompts(out, index, ws);
 
         // Stats
+        let mut optional = String::new();
+        for (label, count) in [
+            ("Issues", ws.stats.issues),
+            ("Deployments", ws.stats.deployments),
+            ("Releases", ws.stats.releases),
+            ("Comment threads", ws.stats.comments),
+            ("Incidents", ws.stats.incidents),
+            ("Docs", ws.stats.documents),
+        ] {
+            if count > 0 {
+                optional.push_str(&format!(", {label}: {count}"));
+            }
+        }
+        for (label, minutes) in [
+            ("Logged", ws.stats.logged_minutes),
+            ("Elapsed", ws.stats.elapsed_minutes),
+        ] {
+            if minutes > 0 {
+                optional.push_str(&format!(", {label}: {}", format_minutes(minutes)));
+            }
+        }
+        if let Some(turnaround) = review_turnaround(index.resolve(&ws.events)) {
+            optional.push_str(&format!(
+                ", Review turnaround: {}",
+                format_minutes(turnaround.median_minutes)
+            ));
+        }
         out.push_str(&format!(
-            "_PRs: {}, Reviews: {}, Manual: {}_\n\n",
+            "_PRs: {}, Reviews: {}, Manual: {}{optional}_\n\n",
             ws.stats.pull_requests, ws.stats.reviews, ws.stats.manual_events
         ));
     }
 }
 
+/// Compact duration such as `45m`, `3h`, or `3h 20m`.
+fn format_minutes(minutes: u64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+/// Counts for event kinds beyond PRs, reviews, and manual events.
+///
+/// Each kind is only listed when present, so packets from code-only sources
+/// keep their existing event lines. Comment events count every comment they
+/// roll up, not the number of threads.
+fn optional_kind_counts<'a>(events: impl Iterator<Item = &'a EventEnvelope>) -> String {
+    let (mut issues, mut deployments, mut releases, mut comments, mut incidents, mut documents) =
+        (0, 0, 0, 0, 0, 0);
+    for event in events {
+        match (&event.kind, &event.payload) {
+            (EventKind::Issue, _) => issues += 1,
+            (EventKind::Deployment, _) => deployments += 1,
+            (EventKind::Release, _) => releases += 1,
+            (EventKind::Comment, EventPayload::Comment(comment)) => {
+                comments += comment.count as usize;
+            }
+            (EventKind::Incident, _) => incidents += 1,
+            (EventKind::Document, _) => documents += 1,
+            _ => {}
+        }
+    }
+
+    let mut out = String::new();
+    for (count, singular, plural) in [
+        (issues, "issue", "issues"),
+        (deployments, "deployment", "deployments"),
+        (releases, "release", "releases"),
+        (comments, "comment", "comments"),
+        (incidents, "incident", "incidents"),
+        (documents, "doc", "docs"),
+    ] {
+        if count > 0 {
+            out.push_str(&format!(", {}", count_label(count, singular, plural)));
+        }
+    }
+    out
+}
+
 fn render_evidence_anchors(
     out: &mut String,
-    by_id: &HashMap<String, &EventEnvelope>,
+    index: &EventIndex<'_>,
     workstream: &Workstream,
+    timezone: ReportingTimezone,
 ) {
     out.push_str("**Evidence anchors**\n\n");
 
-    let available: Vec<_> = workstream
-        .receipts
-        .iter()
-        .filter_map(|id| by_id.get(&id.0).copied())
-        .collect();
+    let mut available: Vec<_> = index.resolve(&workstream.receipts).collect();
+    order_receipts_by_weight(workstream, &mut available);
 
     if available.is_empty() {
         out.push_str("- (none)\n\n");
@@ -491,7 +726,10 @@ fn render_evidence_anchors(
     }
 
     for event in available.iter().take(WORKSTREAM_EVIDENCE_ANCHOR_LIMIT) {
-        out.push_str(&format!("{}\n", format_receipt_markdown(event)));
+        out.push_str(&format!(
+            "{}\n",
+            format_receipt_markdown_in(event, timezone)
+        ));
     }
 
     let remaining = available
@@ -505,19 +743,76 @@ fn render_evidence_anchors(
     out.push('\n');
 }
 
-fn render_claim_prompts(out: &mut String) {
+/// Table of structured impact metrics recorded on the workstream's manual events.
+///
+/// Skipped entirely when no event carries metrics, so packets without them are
+/// unchanged.
+fn render_impact_metrics(out: &mut String, index: &EventIndex<'_>, workstream: &Workstream) {
+    let metrics: Vec<_> = index
+        .resolve(&workstream.events)
+        .filter_map(|event| match &event.payload {
+            EventPayload::Manual(manual) => Some(&manual.metrics),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    if metrics.is_empty() {
+        return;
+    }
+
+    out.push_str("**Impact metrics**\n\n");
+    out.push_str("| Metric | Result |\n");
+    out.push_str("|---|---|\n");
+    for metric in metrics {
+        out.push_str(&format!(
+            "| {} | {} |\n",
+            metric.name.replace('|', "\\|"),
+            metric.change()
+        ));
+    }
+    out.push('\n');
+}
+
+/// Prompts for turning a workstream into claims. The heuristic draft of the
+/// workstream fills the first one, so the author edits a claim instead of
+/// starting from a question.
+fn render_claim_prompts(out: &mut String, index: &EventIndex<'_>, workstream: &Workstream) {
     out.push_str("**Suggested claim prompts**\n\n");
-    out.push_str("- What changed for users, operators, or maintainers?\n");
+    match claim_draft(index, workstream) {
+        Some(draft) => out.push_str(&format!(
+            "- Draft claim: {draft} Confirm what this changed for users, operators, or maintainers.\n"
+        )),
+        None => out.push_str("- What changed for users, operators, or maintainers?\n"),
+    }
     out.push_str("- Which risk, delay, or repeated work did this reduce?\n");
     out.push_str("- Which evidence anchor best proves the change?\n");
     out.push_str("- What follow-up or gap should a reviewer know about?\n\n");
 }
 
+/// Heuristic draft for the first claim prompt, or `None` when it would only
+/// repeat the printed summary or quote redacted text.
+fn claim_draft(index: &EventIndex<'_>, workstream: &Workstream) -> Option<String> {
+    let events: Vec<&EventEnvelope> = index.resolve(&workstream.events).collect();
+    let draft = HeuristicSummarizer.summarize(workstream, &events).ok()?;
+    let draft = draft.trim();
+    let summary = workstream.summary.as_deref().map(str::trim);
+    if draft.is_empty() || Some(draft) == summary || contains_redaction_placeholder(draft) {
+        return None;
+    }
+    Some(draft.to_string())
+}
+
+fn contains_redaction_placeholder(text: &str) -> bool {
+    text.to_ascii_lowercase().contains("[redacted]")
+}
+
 fn render_receipts(
     out: &mut String,
-    events: &[EventEnvelope],
+    index: &EventIndex<'_>,
     workstreams: &WorkstreamsFile,
     options: MarkdownRenderOptions,
+    timezone: ReportingTimezone,
 ) {
     out.push_str("## Receipts\n\n");
 
@@ -526,31 +821,26 @@ fn render_receipts(
         return;
     }
 
-    let by_id: HashMap<String, &EventEnvelope> =
-        events.iter().map(|e| (e.id.0.clone(), e)).collect();
-
     for ws in &workstreams.workstreams {
         out.push_str(&format!("### Workstream: {}\n\n", ws.title));
 
+        let mut resolved: Vec<_> = index.resolve(&ws.receipts).collect();
+        order_receipts_by_weight(ws, &mut resolved);
+        let (receipts, trivial): (Vec<_>, Vec<_>) = resolved.into_iter().partition(|event| {
+            options
+                .min_pr_size
+                .is_none_or(|min| event_pr_size(event).is_none_or(|size| size >= min))
+        });
+
         // Split receipts into main (top N) and appendix (remainder)
-        let (main_receipts, appendix_receipts): (Vec<_>, Vec<_>) = if ws.receipts.is_empty() {
-            (Vec::new(), Vec::new())
-        } else if ws.receipts.len() <= options.receipt_limit {
-            (ws.receipts.clone(), Vec::new())
-        } else if options.receipt_limit == 0 {
-            (Vec::new(), ws.receipts.clone())
-        } else {
-            let (main, appendix) = ws.receipts.split_at(options.receipt_limit);
-            (main.to_vec(), appendix.to_vec())
-        };
+        let (main_receipts, appendix_receipts) =
+            receipts.split_at(receipts.len().min(options.receipt_limit));
 
         if main_receipts.is_empty() {
             out.push_str("- (none)\n");
         } else {
-            for id in &main_receipts {
-                if let Some(ev) = by_id.get(&id.0) {
-                    out.push_str(&format!("{}\n", format_receipt_markdown(ev)));
-                }
+            for ev in main_receipts {
+                out.push_str(&format!("{}\n", format_receipt_markdown_in(ev, timezone)));
             }
         }
 
@@ -560,6 +850,14 @@ fn render_receipts(
                 options.appendix_mode,
             ));
         }
+        if let Some(min) = options.min_pr_size
+            && !trivial.is_empty()
+        {
+            out.push_str(&format!(
+                "- *{} smaller than {min} not shown*\n",
+                count_label(trivial.len(), "PR", "PRs")
+            ));
+        }
         out.push('\n');
     }
 }
@@ -585,27 +883,30 @@ fn count_label(count: usize, singular: &str, plural: &str) -> String {
 
 fn render_appendix(
     out: &mut String,
-    events: &[EventEnvelope],
+    index: &EventIndex<'_>,
     workstreams: &WorkstreamsFile,
     mode: AppendixMode,
+    timezone: ReportingTimezone,
 ) {
     match mode {
-        AppendixMode::Full => render_full_appendix(out, events, workstreams),
+        AppendixMode::Full => render_full_appendix(out, index, workstreams, timezone),
         AppendixMode::Summary => render_appendix_summary(out, workstreams),
         AppendixMode::None => {}
     }
 }
 
-fn render_full_appendix(out: &mut String, events: &[EventEnvelope], workstreams: &WorkstreamsFile) {
+fn render_full_appendix(
+    out: &mut String,
+    index: &EventIndex<'_>,
+    workstreams: &WorkstreamsFile,
+    timezone: ReportingTimezone,
+) {
     out.push_str("## Appendix: All Receipts\n\n");
 
     if workstreams.workstreams.is_empty() {
         return;
     }
 
-    let by_id: HashMap<String, &EventEnvelope> =
-        events.iter().map(|e| (e.id.0.clone(), e)).collect();
-
     for ws in &workstreams.workstreams {
         if ws.events.is_empty() {
             continue;
@@ -614,10 +915,8 @@ fn render_full_appendix(out: &mut String, events: &[EventEnvelope], workstreams:
         out.push_str(&format!("### {}\n\n", ws.title));
 
         // Show all events for this workstream, not just receipts
-        for event_id in &ws.events {
-            if let Some(ev) = by_id.get(&event_id.0) {
-                out.push_str(&format!("{}\n", format_receipt_markdown(ev)));
-            }
+        for ev in index.resolve(&ws.events) {
+            out.push_str(&format!("{}\n", format_receipt_markdown_in(ev, timezone)));
         }
         out.push('\n');
     }
@@ -657,13 +956,14 @@ fn render_file_artifacts(out: &mut String) {
 mod tests {
     use super::*;
     use chrono::{NaiveDate, TimeZone, Utc};
-    use shiplog::ids::{EventId, RunId, WorkstreamId};
+    use shiplog::ids::{EventId, OpaqueId, RunId, WorkstreamId};
     use shiplog::schema::coverage::*;
     use shiplog::schema::event::*;
     use shiplog::schema::workstream::*;
 
     fn create_test_pr(id: &str, number: u64, title: &str) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["pr", id]),
             kind: EventKind::PullRequest,
             occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -696,13 +996,17 @@ mod tests {
             source: SourceRef {
                 system: SourceSystem::Github,
                 url: None,
-                opaque_id: Some(id.into()),
+                opaque_id: Some(OpaqueId::from_raw("test", id)),
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
     fn create_test_manual(id: &str, event_type: ManualEventType, title: &str) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["manual", id]),
             kind: EventKind::Manual,
             occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -722,17 +1026,44 @@ mod tests {
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![],
             source: SourceRef {
                 system: SourceSystem::Manual,
                 url: None,
-                opaque_id: Some(id.into()),
+                opaque_id: Some(OpaqueId::from_raw("test", id)),
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
+    #[test]
+    fn event_index_resolves_ids_in_order_and_skips_unknown() {
+        let events = vec![
+            create_test_pr("1", 1, "First"),
+            create_test_pr("2", 2, "Second"),
+        ];
+        let index = EventIndex::new(&events);
+        let ids = vec![
+            EventId::from_parts(["pr", "2"]),
+            EventId::from_parts(["pr", "missing"]),
+            EventId::from_parts(["pr", "1"]),
+        ];
+
+        let resolved: Vec<_> = index.resolve(&ids).map(|e| e.id.0.as_str()).collect();
+        assert_eq!(
+            resolved,
+            vec![events[1].id.0.as_str(), events[0].id.0.as_str()]
+        );
+        assert!(index.get(&ids[1]).is_none());
+    }
+
     #[test]
     fn test_snapshot_empty_packet() {
         let renderer = MarkdownRenderer::new();
@@ -743,6 +1074,7 @@ mod tests {
             workstreams: vec![],
         };
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -755,6 +1087,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let result = renderer
@@ -785,10 +1120,21 @@ mod tests {
                     pull_requests: 1,
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         };
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -801,15 +1147,74 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let result = renderer
             .render_packet_markdown("test", "2024", &events, &workstreams, &coverage)
             .unwrap();
 
+        assert!(result.contains(
+            "- Draft claim: 1 pull request in Authentication on 1970-01-01 (+10/-5 lines), \
+             including \"Fix authentication bug\". Confirm what this changed for users, \
+             operators, or maintainers.\n"
+        ));
+        assert!(!result.contains("- What changed for users, operators, or maintainers?"));
         insta::assert_snapshot!(result);
     }
 
+    #[test]
+    fn claim_prompts_skip_the_draft_for_redacted_workstreams() {
+        let renderer = MarkdownRenderer::new();
+        let events = vec![create_test_pr("1", 1, "[REDACTED]")];
+        let workstream = |summary: Option<&str>| Workstream {
+            id: WorkstreamId::from_parts(["ws", "1"]),
+            title: "Authentication".into(),
+            summary: summary.map(Into::into),
+            tags: vec![],
+            receipts: vec![EventId::from_parts(["pr", "1"])],
+            events: vec![EventId::from_parts(["pr", "1"])],
+            stats: WorkstreamStats::zero(),
+            receipt_weights: Default::default(),
+            rules: Default::default(),
+        };
+        let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
+            run_id: RunId::now("test"),
+            generated_at: Utc::now(),
+            user: "test".into(),
+            window: TimeWindow {
+                since: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                until: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            },
+            mode: "test".into(),
+            sources: vec!["github".into()],
+            slices: vec![],
+            warnings: vec![],
+            completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
+        };
+
+        // A placeholder summary and redacted event titles both suppress the
+        // draft; the open question takes its place.
+        for summary in [Some("[REDACTED]"), Some("Hardened login")] {
+            let workstreams = WorkstreamsFile {
+                version: 1,
+                generated_at: Utc::now(),
+                workstreams: vec![workstream(summary)],
+            };
+            let result = renderer
+                .render_packet_markdown("test", "2024", &events, &workstreams, &coverage)
+                .unwrap();
+            assert!(!result.contains("Draft claim"), "{result}");
+            assert!(result.contains("- What changed for users, operators, or maintainers?\n"));
+        }
+    }
+
     #[test]
     fn test_snapshot_partial_coverage() {
         let renderer = MarkdownRenderer::new();
@@ -828,10 +1233,21 @@ mod tests {
                     pull_requests: 1,
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         };
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -851,9 +1267,13 @@ mod tests {
                 fetched: 50,
                 incomplete_results: Some(true),
                 notes: vec![],
+                source: None,
             }],
             warnings: vec!["API rate limit hit".into()],
             completeness: Completeness::Partial,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let result = renderer
@@ -884,10 +1304,21 @@ mod tests {
                     pull_requests: 1,
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         };
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -900,6 +1331,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let result = renderer
@@ -915,6 +1349,7 @@ mod tests {
         let renderer = MarkdownRenderer::new();
         let pr_event = create_test_pr("1", 1, "Add feature");
         let review_event = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["review", "1"]),
             kind: EventKind::Review,
             occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -933,6 +1368,8 @@ mod tests {
                 submitted_at: Utc.timestamp_opt(0, 0).unwrap(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             tags: vec![],
             links: vec![Link {
@@ -944,6 +1381,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
         let events = vec![pr_event, review_event];
         let workstreams = WorkstreamsFile {
@@ -963,10 +1403,21 @@ mod tests {
                     pull_requests: 1,
                     reviews: 1,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         };
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -979,6 +1430,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let result = renderer
@@ -1012,7 +1466,17 @@ mod tests {
                         pull_requests: 1,
                         reviews: 0,
                         manual_events: 0,
+                        issues: 0,
+                        deployments: 0,
+                        releases: 0,
+                        comments: 0,
+                        incidents: 0,
+                        documents: 0,
+                        logged_minutes: 0,
+                        elapsed_minutes: 0,
                     },
+                    receipt_weights: Default::default(),
+                    rules: Default::default(),
                 },
                 Workstream {
                     id: WorkstreamId::from_parts(["ws", "b"]),
@@ -1031,11 +1495,22 @@ mod tests {
                         pull_requests: 2,
                         reviews: 0,
                         manual_events: 0,
+                        issues: 0,
+                        deployments: 0,
+                        releases: 0,
+                        comments: 0,
+                        incidents: 0,
+                        documents: 0,
+                        logged_minutes: 0,
+                        elapsed_minutes: 0,
                     },
+                    receipt_weights: Default::default(),
+                    rules: Default::default(),
                 },
             ],
         };
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -1048,6 +1523,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let result = renderer
@@ -1068,6 +1546,7 @@ mod tests {
             vec![]
         };
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["review", id]),
             kind: EventKind::Review,
             occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -1086,19 +1565,25 @@ mod tests {
                 submitted_at: Utc.timestamp_opt(0, 0).unwrap(),
                 state: state.into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             tags: vec![],
             links,
             source: SourceRef {
                 system: SourceSystem::Github,
                 url: None,
-                opaque_id: Some(id.into()),
+                opaque_id: Some(OpaqueId::from_raw("test", id)),
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
     fn make_coverage(slices: Vec<CoverageSlice>, warnings: Vec<String>) -> CoverageManifest {
         CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -1111,6 +1596,9 @@ mod tests {
             slices,
             warnings,
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         }
     }
 
@@ -1129,6 +1617,7 @@ mod tests {
                 fetched: 10,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             }],
             vec![],
         );
@@ -1152,6 +1641,7 @@ mod tests {
                 fetched: 50,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             }],
             vec![],
         );
@@ -1174,6 +1664,7 @@ mod tests {
                 "owner_filter:kept=EffortlessMetrics=2".into(),
                 "owner_filter:dropped=OtherOwner=1".into(),
             ],
+            source: None,
         }];
 
         let mut out = String::new();
@@ -1184,6 +1675,41 @@ mod tests {
         assert!(out.contains("Known gaps:\n- None recorded\n"));
     }
 
+    #[test]
+    fn summary_shows_coverage_score_when_recorded() {
+        let workstreams = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![],
+        };
+        let mut coverage = make_coverage(vec![], vec![]);
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &[],
+            &EventIndex::new(&[]),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Coverage:** Complete\n"));
+
+        coverage.completeness = Completeness::Partial;
+        coverage.score = Some(73);
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &[],
+            &EventIndex::new(&[]),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Coverage:** Partial (score 73/100)\n"));
+    }
+
     #[test]
     fn coverage_summary_complete_lists_no_known_gaps() {
         let coverage = make_coverage(vec![], vec![]);
@@ -1328,6 +1854,7 @@ mod tests {
                 fetched: 50,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             }],
             vec![],
         );
@@ -1350,6 +1877,7 @@ mod tests {
                 fetched: 50,
                 incomplete_results: Some(true),
                 notes: vec![],
+                source: None,
             }],
             vec!["API returned partial results".into()],
         );
@@ -1379,6 +1907,7 @@ mod tests {
                 fetched: 50,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             })
             .collect();
         let coverage = make_coverage(slices, vec![]);
@@ -1402,6 +1931,7 @@ mod tests {
                 fetched: 50,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             })
             .collect();
         let coverage = make_coverage(slices, vec![]);
@@ -1438,6 +1968,7 @@ mod tests {
                 fetched: 10,
                 incomplete_results: None,
                 notes: vec![],
+                source: None,
             }],
             vec![],
         );
@@ -1446,6 +1977,474 @@ mod tests {
         assert!(!out.contains("incomplete results"));
     }
 
+    #[test]
+    fn summary_counts_optional_kinds_only_when_present() {
+        let workstreams = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![],
+        };
+        let coverage = make_coverage(vec![], vec![]);
+        let pr = create_test_pr("1", 1, "Ship it");
+
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            std::slice::from_ref(&pr),
+            &EventIndex::new(std::slice::from_ref(&pr)),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Events:** 1 PR, 0 reviews, 0 manual events\n"));
+
+        let mut issue = pr.clone();
+        issue.id = EventId::from_parts(["issue", "2"]);
+        issue.kind = EventKind::Issue;
+        issue.payload = EventPayload::Issue(IssueEvent {
+            number: 2,
+            title: "Triage flaky test".into(),
+            state: IssueState::Closed,
+            labels: vec![],
+            opened_at: Utc.timestamp_opt(0, 0).unwrap(),
+            closed_at: None,
+            window: None,
+            effort: None,
+        });
+        let events = [pr.clone(), issue];
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Events:** 1 PR, 0 reviews, 0 manual events, 1 issue\n"));
+
+        let mut release = pr.clone();
+        release.id = EventId::from_parts(["release", "3"]);
+        release.kind = EventKind::Release;
+        release.payload = EventPayload::Release(ReleaseEvent {
+            version: "v1.0.0".into(),
+            name: None,
+            status: ReleaseStatus::Published,
+            created_at: Utc.timestamp_opt(0, 0).unwrap(),
+            published_at: None,
+            window: None,
+        });
+        let events = [pr.clone(), release.clone(), release];
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Events:** 1 PR, 0 reviews, 0 manual events, 2 releases\n"));
+
+        let mut comments = pr.clone();
+        comments.id = EventId::from_parts(["comment", "4"]);
+        comments.kind = EventKind::Comment;
+        comments.payload = EventPayload::Comment(CommentEvent {
+            target: CommentTarget::PullRequest,
+            target_number: Some(1),
+            target_title: "Ship it".into(),
+            thread: None,
+            count: 12,
+            first_at: Utc.timestamp_opt(0, 0).unwrap(),
+            last_at: Utc.timestamp_opt(0, 0).unwrap(),
+            window: None,
+        });
+        let events = [pr.clone(), comments.clone(), comments];
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Events:** 1 PR, 0 reviews, 0 manual events, 24 comments\n"));
+
+        let mut incident = pr.clone();
+        incident.id = EventId::from_parts(["incident", "5"]);
+        incident.kind = EventKind::Incident;
+        incident.payload = EventPayload::Incident(IncidentEvent {
+            title: "Outage".into(),
+            severity: IncidentSeverity::Sev1,
+            role: IncidentRole::Responder,
+            detected_at: Utc.timestamp_opt(0, 0).unwrap(),
+            resolved_at: None,
+            duration_minutes: None,
+            summary: None,
+            customer_impact: None,
+            window: None,
+        });
+        let events = [pr.clone(), incident.clone()];
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Events:** 1 PR, 0 reviews, 0 manual events, 1 incident\n"));
+
+        let mut doc = pr.clone();
+        doc.id = EventId::from_parts(["doc", "6"]);
+        doc.kind = EventKind::Document;
+        doc.payload = EventPayload::Document(DocumentEvent {
+            doc_type: DocumentType::Design,
+            title: "Design".into(),
+            system: "notion".into(),
+            last_edited_at: Utc.timestamp_opt(0, 0).unwrap(),
+            word_count_delta: None,
+            window: None,
+        });
+        let events = [pr.clone(), incident, doc.clone(), doc];
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &coverage,
+        );
+        assert!(out.contains("**Events:** 1 PR, 0 reviews, 0 manual events, 1 incident, 2 docs\n"));
+    }
+
+    #[test]
+    fn workstream_stats_line_shows_effort_when_present() {
+        let stats = |logged_minutes, elapsed_minutes| WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "1"]),
+                title: "Ops".into(),
+                summary: None,
+                tags: vec![],
+                receipts: vec![],
+                events: vec![],
+                stats: WorkstreamStats {
+                    manual_events: 2,
+                    logged_minutes,
+                    elapsed_minutes,
+                    ..WorkstreamStats::zero()
+                },
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        };
+
+        let mut out = String::new();
+        render_workstreams(
+            &mut out,
+            &EventIndex::new(&[]),
+            &stats(270, 45),
+            ReportingTimezone::UTC,
+        );
+        assert!(out.contains("_PRs: 0, Reviews: 0, Manual: 2, Logged: 4h 30m, Elapsed: 45m_\n"));
+
+        let mut out = String::new();
+        render_workstreams(
+            &mut out,
+            &EventIndex::new(&[]),
+            &stats(0, 0),
+            ReportingTimezone::UTC,
+        );
+        assert!(out.contains("_PRs: 0, Reviews: 0, Manual: 2_\n"));
+    }
+
+    #[test]
+    fn review_turnaround_renders_in_summary_and_workstream_stats() {
+        let mut fast = create_test_review("r1", "approved", false);
+        let mut slow = create_test_review("r2", "approved", false);
+        for (event, pull_number, hours) in [(&mut fast, 1, 2), (&mut slow, 2, 30)] {
+            if let EventPayload::Review(review) = &mut event.payload {
+                review.pull_number = pull_number;
+                review.pull_created_at = Some(review.submitted_at - chrono::Duration::hours(hours));
+            }
+        }
+        let unmeasured = create_test_review("r3", "approved", false);
+        let events = vec![fast.clone(), slow.clone(), unmeasured.clone()];
+        let workstreams = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "1"]),
+                title: "Reviews".into(),
+                summary: None,
+                tags: vec![],
+                receipts: vec![],
+                events: vec![fast.id.clone(), unmeasured.id.clone()],
+                stats: WorkstreamStats {
+                    reviews: 2,
+                    ..WorkstreamStats::zero()
+                },
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        };
+
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &make_coverage(vec![], vec![]),
+        );
+        assert!(out.contains("**Review turnaround:** median 2h, p90 30h across 2 reviewed PRs\n"));
+
+        let mut out = String::new();
+        render_workstreams(
+            &mut out,
+            &EventIndex::new(&events),
+            &workstreams,
+            ReportingTimezone::UTC,
+        );
+        assert!(out.contains("_PRs: 0, Reviews: 2, Manual: 0, Review turnaround: 2h_\n"));
+
+        let events = [unmeasured];
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &make_coverage(vec![], vec![]),
+        );
+        assert!(!out.contains("Review turnaround"));
+    }
+
+    #[test]
+    fn pr_sizes_render_in_summary_and_filter_receipts() {
+        let sized = |id: &str, number, lines| {
+            let mut pr = create_test_pr(id, number, &format!("PR {id}"));
+            if let EventPayload::PullRequest(payload) = &mut pr.payload {
+                payload.additions = Some(lines);
+                payload.deletions = None;
+            }
+            pr
+        };
+        let bump = sized("bump", 1, 2);
+        let feature = sized("feature", 2, 400);
+        let mut unknown = create_test_pr("unknown", 3, "PR unknown");
+        if let EventPayload::PullRequest(payload) = &mut unknown.payload {
+            payload.additions = None;
+            payload.deletions = None;
+        }
+        let events = vec![bump.clone(), feature.clone(), unknown.clone()];
+        let workstreams = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "1"]),
+                title: "Platform".into(),
+                summary: None,
+                tags: vec![],
+                receipts: vec![bump.id.clone(), feature.id.clone(), unknown.id.clone()],
+                events: vec![bump.id.clone(), feature.id.clone(), unknown.id.clone()],
+                stats: WorkstreamStats::zero(),
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        };
+
+        let mut out = String::new();
+        render_summary(
+            &mut out,
+            "test",
+            "2024",
+            &events,
+            &EventIndex::new(&events),
+            &workstreams,
+            &make_coverage(vec![], vec![]),
+        );
+        assert!(out.contains("**PR sizes:** XS 1, S 0, M 0, L 1, XL 0 (1 unknown)\n"));
+
+        let options = MarkdownRenderOptions {
+            min_pr_size: Some(PrSize::S),
+            ..MarkdownRenderOptions::default()
+        };
+        let mut out = String::new();
+        render_receipts(
+            &mut out,
+            &EventIndex::new(&events),
+            &workstreams,
+            options,
+            ReportingTimezone::UTC,
+        );
+        assert!(!out.contains("PR bump"));
+        assert!(out.contains("PR feature"));
+        assert!(out.contains("PR unknown"));
+        assert!(out.contains("- *1 PR smaller than S not shown*\n"));
+
+        let mut out = String::new();
+        render_receipts(
+            &mut out,
+            &EventIndex::new(&events),
+            &workstreams,
+            MarkdownRenderOptions::default(),
+            ReportingTimezone::UTC,
+        );
+        assert!(out.contains("PR bump"));
+        assert!(!out.contains("not shown"));
+    }
+
+    #[test]
+    fn workstream_renders_impact_metrics_table() {
+        let mut event = create_test_review("m1", "approved", false);
+        event.kind = EventKind::Manual;
+        event.payload = EventPayload::Manual(ManualEvent {
+            event_type: ManualEventType::Incident,
+            title: "Cache rollout".into(),
+            description: None,
+            started_at: None,
+            ended_at: None,
+            impact: None,
+            metrics: vec![ImpactMetric {
+                name: "p95 latency".into(),
+                value: 180.into(),
+                unit: Some("ms".into()),
+                baseline: Some(420.into()),
+            }],
+            effort: None,
+            evidence: Vec::new(),
+        });
+        let workstreams = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "1"]),
+                title: "Perf".into(),
+                summary: None,
+                tags: vec![],
+                receipts: vec![],
+                events: vec![event.id.clone()],
+                stats: WorkstreamStats::zero(),
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        };
+
+        let mut out = String::new();
+        render_workstreams(
+            &mut out,
+            &EventIndex::new(&[event]),
+            &workstreams,
+            ReportingTimezone::UTC,
+        );
+        assert!(out.contains("| Metric | Result |\n|---|---|\n| p95 latency | 420ms → 180ms |\n"));
+
+        let mut out = String::new();
+        render_workstreams(
+            &mut out,
+            &EventIndex::new(&[]),
+            &workstreams,
+            ReportingTimezone::UTC,
+        );
+        assert!(!out.contains("Impact metrics"));
+    }
+
+    #[test]
+    fn llm_prompt_tags_receipts_in_claim_scaffolds() {
+        let mut incident = create_test_manual("m1", ManualEventType::Incident, "Cache outage");
+        if let EventPayload::Manual(manual) = &mut incident.payload {
+            manual.impact = Some("Checkout recovered in 20 minutes".into());
+        }
+        let events = vec![
+            create_test_pr("1", 1, "Add cache"),
+            create_test_pr("2", 2, "Tune cache"),
+            incident,
+        ];
+        let workstreams = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "1"]),
+                title: "Cache".into(),
+                summary: None,
+                tags: vec![],
+                receipts: events.iter().map(|event| event.id.clone()).collect(),
+                events: events.iter().map(|event| event.id.clone()).collect(),
+                stats: WorkstreamStats::zero(),
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        };
+        let options = MarkdownRenderOptions {
+            receipt_limit: 2,
+            ..MarkdownRenderOptions::default()
+        };
+
+        let prompt = MarkdownRenderer::new()
+            .render_llm_prompt(
+                "octo",
+                "2024",
+                "manager",
+                &events,
+                &workstreams,
+                &make_coverage(vec![], vec![]),
+                options,
+            )
+            .unwrap();
+
+        assert!(prompt.starts_with("# Self-review drafting prompt\n"));
+        assert!(prompt.contains("- Share profile: manager."));
+        assert!(prompt.contains("## Coverage and Limits"));
+        // The incident outweighs the two small PRs, so it is cited first.
+        let r1 = prompt
+            .lines()
+            .find(|line| line.starts_with("- [R1] "))
+            .unwrap();
+        assert!(r1.contains("Cache outage"), "{r1}");
+        assert!(prompt.contains("- [R2] [PR] Add cache"));
+        assert!(prompt.contains("- ... and 1 more receipt not listed"));
+        assert!(prompt.contains("- In 2024, I ____ on Cache (cite [R1], [R2]).\n"));
+        assert!(
+            prompt.contains("- Recorded impact (Cache outage): Checkout recovered in 20 minutes")
+        );
+        assert!(prompt.contains("## What to write"));
+
+        let mut drafted = workstreams.clone();
+        drafted.workstreams[0].summary = Some("2 pull requests in Cache.".into());
+        let prompt = MarkdownRenderer::new()
+            .render_llm_prompt(
+                "octo",
+                "2024",
+                "manager",
+                &events,
+                &drafted,
+                &make_coverage(vec![], vec![]),
+                options,
+            )
+            .unwrap();
+        assert!(prompt.contains(
+            "- In 2024: 2 pull requests in Cache. Rewrite this draft as a first-person claim \
+             (cite [R1], [R2]).\n"
+        ));
+        assert!(!prompt.contains("I ____"));
+    }
+
     #[test]
     fn review_event_shows_review_tag_and_state() {
         // Kills Review match arm deletion in format_receipt_markdown
@@ -1501,10 +2500,21 @@ mod tests {
                     pull_requests: 0,
                     reviews: 0,
                     manual_events: 8,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         };
         let coverage = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "test".into(),
@@ -1517,6 +2527,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
 
         let result = renderer
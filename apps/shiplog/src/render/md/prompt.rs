@@ -0,0 +1,187 @@
+//! LLM handoff prompt rendering.
+//!
+//! Packs one run's workstreams, receipts, claim scaffolds, and coverage limits
+//! into a single text blob the user pastes into a model of their choosing.
+//! shiplog never sends it anywhere. Callers pass events and workstreams that
+//! are already redacted for the share profile named in the prompt.
+
+use crate::coverage::ReportingTimezone;
+use crate::workstreams::order_receipts_by_weight;
+use shiplog::schema::coverage::CoverageManifest;
+use shiplog::schema::event::{EventEnvelope, EventPayload};
+use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
+
+use super::coverage::render_coverage;
+use super::index::EventIndex;
+use super::{MarkdownRenderOptions, count_label, format_receipt_markdown_in};
+
+pub(crate) fn render_prompt(
+    out: &mut String,
+    user: &str,
+    window_label: &str,
+    profile: &str,
+    events: &[EventEnvelope],
+    workstreams: &WorkstreamsFile,
+    coverage: &CoverageManifest,
+    options: MarkdownRenderOptions,
+) {
+    let timezone = ReportingTimezone::from_manifest(coverage.timezone.as_deref());
+    let index = EventIndex::new(events);
+
+    render_instructions(out, user, window_label, profile);
+    render_coverage(out, coverage, events);
+    out.push_str("## Workstreams\n\n");
+    if workstreams.workstreams.is_empty() {
+        out.push_str("_No workstreams found_\n\n");
+    }
+    let mut next_tag = 1;
+    for ws in &workstreams.workstreams {
+        render_workstream(
+            out,
+            &index,
+            ws,
+            window_label,
+            options,
+            timezone,
+            &mut next_tag,
+        );
+    }
+    render_request(out);
+}
+
+fn render_instructions(out: &mut String, user: &str, window_label: &str, profile: &str) {
+    out.push_str("# Self-review drafting prompt\n\n");
+    out.push_str(&format!(
+        "You are helping {user} draft the narrative part of a self-review for {window_label}. \
+         The evidence below was collected by shiplog; it is the only source you may use.\n\n"
+    ));
+    out.push_str("Rules:\n\n");
+    out.push_str("- Write in the first person, in plain factual sentences.\n");
+    out.push_str("- Back every claim with at least one receipt, cited by its [R#] tag.\n");
+    out.push_str(
+        "- Do not invent outcomes, numbers, or people. Where a claim scaffold has a blank, \
+         leave it as a question for the author.\n",
+    );
+    out.push_str(
+        "- Respect the coverage limits: do not describe work from skipped sources or \
+         outside the window, and say so when a gap weakens a claim.\n",
+    );
+    out.push_str(&format!("- {}\n\n", profile_rule(profile)));
+}
+
+fn profile_rule(profile: &str) -> String {
+    match profile {
+        "manager" => "Share profile: manager. Some identifiers are aliased; keep aliases \
+                      exactly as written."
+            .to_string(),
+        "public" => "Share profile: public. Names, repositories, and links are aliased or \
+                     removed; keep aliases exactly as written and do not guess what they \
+                     stand for."
+            .to_string(),
+        "internal" => "Share profile: internal. The evidence is unredacted; keep it inside \
+                       tools your organization approves."
+            .to_string(),
+        other => format!("Share profile: {other}."),
+    }
+}
+
+fn render_workstream(
+    out: &mut String,
+    index: &EventIndex<'_>,
+    ws: &Workstream,
+    window_label: &str,
+    options: MarkdownRenderOptions,
+    timezone: ReportingTimezone,
+    next_tag: &mut usize,
+) {
+    out.push_str(&format!("### {}\n\n", ws.title));
+    if let Some(summary) = &ws.summary {
+        out.push_str(summary);
+        out.push_str("\n\n");
+    }
+    out.push_str(&format!(
+        "_PRs: {}, Reviews: {}, Manual: {}_\n\n",
+        ws.stats.pull_requests, ws.stats.reviews, ws.stats.manual_events
+    ));
+
+    out.push_str("Receipts:\n\n");
+    let mut receipts: Vec<&EventEnvelope> = index.resolve(&ws.receipts).collect();
+    order_receipts_by_weight(ws, &mut receipts);
+    let (shown, hidden) = receipts.split_at(receipts.len().min(options.receipt_limit));
+    let mut tags = Vec::with_capacity(shown.len());
+    for event in shown {
+        let tag = format!("[R{next_tag}]");
+        *next_tag += 1;
+        let line = format_receipt_markdown_in(event, timezone);
+        let line = line.strip_prefix("- ").unwrap_or(&line);
+        out.push_str(&format!("- {tag} {line}\n"));
+        tags.push(tag);
+    }
+    if shown.is_empty() {
+        out.push_str("- (none)\n");
+    }
+    if !hidden.is_empty() {
+        out.push_str(&format!(
+            "- ... and {} not listed\n",
+            count_label(hidden.len(), "more receipt", "more receipts")
+        ));
+    }
+    out.push('\n');
+
+    render_claim_scaffold(out, index, ws, window_label, &tags);
+}
+
+/// Fill-in-the-blank claims tied to the workstream's receipt tags, plus any
+/// impact the author already recorded on manual events. A drafted workstream
+/// summary replaces the opening blank.
+fn render_claim_scaffold(
+    out: &mut String,
+    index: &EventIndex<'_>,
+    ws: &Workstream,
+    window_label: &str,
+    tags: &[String],
+) {
+    let cite = if tags.is_empty() {
+        "no receipts; ask the author for one".to_string()
+    } else {
+        tags.join(", ")
+    };
+    out.push_str("Claim scaffold:\n\n");
+    match ws.summary.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        Some(summary) => out.push_str(&format!(
+            "- In {window_label}: {summary} Rewrite this draft as a first-person claim (cite {cite}).\n"
+        )),
+        None => out.push_str(&format!(
+            "- In {window_label}, I ____ on {} (cite {cite}).\n",
+            ws.title
+        )),
+    }
+    out.push_str("- This changed ____ for ____ (users, operators, or maintainers).\n");
+    out.push_str("- The risk, delay, or repeated work this reduced: ____.\n");
+
+    for event in index.resolve(&ws.events) {
+        let EventPayload::Manual(manual) = &event.payload else {
+            continue;
+        };
+        if let Some(impact) = &manual.impact {
+            out.push_str(&format!("- Recorded impact ({}): {impact}\n", manual.title));
+        }
+        for metric in &manual.metrics {
+            out.push_str(&format!(
+                "- Recorded metric: {} {}\n",
+                metric.name,
+                metric.change()
+            ));
+        }
+    }
+    out.push('\n');
+}
+
+fn render_request(out: &mut String) {
+    out.push_str("## What to write\n\n");
+    out.push_str(
+        "Draft one short paragraph per workstream, strongest evidence first, then a \
+         two-sentence overall summary. End with a list of the blanks and questions the \
+         author still needs to answer.\n",
+    );
+}
@@ -3,11 +3,43 @@
 //! This crate has a single responsibility:
 //! - converting one `EventEnvelope` into one display-ready receipt line
 
-use shiplog::schema::event::{EventEnvelope, EventKind, EventPayload, ManualEventType};
+use crate::coverage::ReportingTimezone;
+use shiplog::annotate::event_note;
+use shiplog::schema::event::{
+    CommentTarget, DeploymentStatus, DocumentType, EventEnvelope, EventKind, EventPayload,
+    IncidentEvent, IncidentRole, IncidentSeverity, ManualEventType,
+};
 
-/// Format one event into a markdown receipt line.
+/// Format one event into a markdown receipt line, dated in UTC.
+///
+/// Contributors besides the actor are credited at the end of the line.
 #[must_use]
 pub fn format_receipt_markdown(event: &EventEnvelope) -> String {
+    format_receipt_markdown_in(event, ReportingTimezone::UTC)
+}
+
+/// Format one event into a markdown receipt line, dated in `timezone`.
+///
+/// An annotation note, which only internal events carry, follows the
+/// contributors.
+#[must_use]
+pub fn format_receipt_markdown_in(event: &EventEnvelope, timezone: ReportingTimezone) -> String {
+    let mut line = receipt_line(event, timezone);
+    if !event.contributors.is_empty() {
+        let names: Vec<&str> = event
+            .contributors
+            .iter()
+            .map(|contributor| contributor.login.as_str())
+            .collect();
+        line = format!("{line} (with {})", names.join(", "));
+    }
+    if let Some(note) = event_note(event) {
+        line = format!("{line} — Note: {note}");
+    }
+    line
+}
+
+fn receipt_line(event: &EventEnvelope, timezone: ReportingTimezone) -> String {
     match (&event.kind, &event.payload) {
         (EventKind::PullRequest, EventPayload::PullRequest(pr)) => {
             let repo = &event.repo.full_name;
@@ -17,7 +49,7 @@ pub fn format_receipt_markdown(event: &EventEnvelope) -> String {
                 .find(|link| link.label == "pr")
                 .map(|link| link.url.as_str())
                 .unwrap_or("");
-            let date = event.occurred_at.format("%Y-%m-%d");
+            let date = timezone.local_date(&event.occurred_at);
 
             if url.is_empty() {
                 format!("- [PR] {} ({date}) — {repo}", pr.title)
@@ -33,7 +65,7 @@ pub fn format_receipt_markdown(event: &EventEnvelope) -> String {
                 .find(|link| link.label == "pr")
                 .map(|link| link.url.as_str())
                 .unwrap_or("");
-            let date = event.occurred_at.format("%Y-%m-%d");
+            let date = timezone.local_date(&event.occurred_at);
 
             if url.is_empty() {
                 format!("- [Review] {} ({date}) — {repo}", review.state)
@@ -41,25 +73,205 @@ pub fn format_receipt_markdown(event: &EventEnvelope) -> String {
                 format!("- [Review] {} ({date}) — [{repo}]({url})", review.state)
             }
         }
+        (EventKind::Issue, EventPayload::Issue(issue)) => {
+            let repo = &event.repo.full_name;
+            let url = event
+                .links
+                .iter()
+                .find(|link| link.label == "issue")
+                .map(|link| link.url.as_str())
+                .unwrap_or("");
+            let date = timezone.local_date(&event.occurred_at);
+
+            if url.is_empty() {
+                format!("- [Issue] {} ({date}) — {repo}", issue.title)
+            } else {
+                format!("- [Issue] {} ({date}) — [{repo}]({url})", issue.title)
+            }
+        }
+        (EventKind::Deployment, EventPayload::Deployment(deployment)) => {
+            let repo = &event.repo.full_name;
+            let date = timezone.local_date(&event.occurred_at);
+            let version = deployment
+                .version
+                .as_deref()
+                .map(|version| format!(" {version}"))
+                .unwrap_or_default();
+            format!(
+                "- [Deploy]{version} to {} ({date}, {}) — {repo}",
+                deployment.environment,
+                deployment_status_label(&deployment.status)
+            )
+        }
+        (EventKind::Release, EventPayload::Release(release)) => {
+            let repo = &event.repo.full_name;
+            let url = event
+                .links
+                .iter()
+                .find(|link| link.label == "release")
+                .map(|link| link.url.as_str())
+                .unwrap_or("");
+            let date = timezone.local_date(&event.occurred_at);
+            let label = match &release.name {
+                Some(name) if name != &release.version => format!("{} — {name}", release.version),
+                _ => release.version.clone(),
+            };
+
+            if url.is_empty() {
+                format!("- [Release] {label} ({date}) — {repo}")
+            } else {
+                format!("- [Release] {label} ({date}) — [{repo}]({url})")
+            }
+        }
+        (EventKind::Comment, EventPayload::Comment(comment)) => {
+            let repo = &event.repo.full_name;
+            let date = timezone.local_date(&event.occurred_at);
+            let target = match (&comment.target, comment.target_number) {
+                (CommentTarget::PullRequest, Some(number)) => format!("PR #{number}"),
+                (CommentTarget::Issue, Some(number)) => format!("issue #{number}"),
+                (CommentTarget::PullRequest, None) => "PR".to_string(),
+                (CommentTarget::Issue, None) => "issue".to_string(),
+                (CommentTarget::Ticket, _) => "ticket".to_string(),
+                (CommentTarget::Document, _) => "doc".to_string(),
+                (CommentTarget::Other, _) => "thread".to_string(),
+            };
+            let noun = if comment.count == 1 {
+                "comment"
+            } else {
+                "comments"
+            };
+            format!(
+                "- [Comments] {} {noun} on {target}: {} ({date}) — {repo}",
+                comment.count, comment.target_title
+            )
+        }
+        (EventKind::Incident, EventPayload::Incident(incident)) => {
+            let links: Vec<String> = event
+                .links
+                .iter()
+                .map(|link| format!("[{}]({})", link.label, link.url))
+                .collect();
+            let links_suffix = if links.is_empty() {
+                String::new()
+            } else {
+                format!(" — {}", links.join(", "))
+            };
+            let details = incident_details(incident);
+            let details = if details.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", details.join(", "))
+            };
+            let date = timezone.local_date(&event.occurred_at);
+            format!("- [🚨] {}{details} ({date}){links_suffix}", incident.title)
+        }
+        (EventKind::Document, EventPayload::Document(document)) => {
+            let links: Vec<String> = event
+                .links
+                .iter()
+                .map(|link| format!("[{}]({})", link.label, link.url))
+                .collect();
+            let links_suffix = if links.is_empty() {
+                String::new()
+            } else {
+                format!(" — {}", links.join(", "))
+            };
+            let mut details = vec![
+                document_type_label(&document.doc_type).to_string(),
+                document.system.clone(),
+            ];
+            if let Some(delta) = document.word_count_delta {
+                details.push(format!("{delta:+} words"));
+            }
+            let date = document.last_edited_at.format("%Y-%m-%d");
+            format!(
+                "- [Doc] {} [{}] ({date}){links_suffix}",
+                document.title,
+                details.join(", ")
+            )
+        }
         (EventKind::Manual, EventPayload::Manual(manual)) => {
             let emoji = manual_type_emoji(&manual.event_type);
             let links: Vec<String> = event
                 .links
                 .iter()
                 .map(|link| format!("[{}]({})", link.label, link.url))
+                .chain(manual.evidence.iter().map(|evidence| {
+                    format!(
+                        "[{}]({})",
+                        evidence.display_label(),
+                        evidence.path.replace(' ', "%20")
+                    )
+                }))
                 .collect();
             let links_suffix = if links.is_empty() {
                 String::new()
             } else {
                 format!(" — {}", links.join(", "))
             };
-            let date = event.occurred_at.format("%Y-%m-%d");
+            let date = timezone.local_date(&event.occurred_at);
             format!("- [{emoji}] {} ({date}){links_suffix}", manual.title)
         }
         _ => format!("- event {}", event.id),
     }
 }
 
+/// Lowercase outcome shown on deployment receipts.
+#[must_use]
+fn deployment_status_label(status: &DeploymentStatus) -> &'static str {
+    match status {
+        DeploymentStatus::Succeeded => "succeeded",
+        DeploymentStatus::Failed => "failed",
+        DeploymentStatus::RolledBack => "rolled back",
+        DeploymentStatus::InProgress => "in progress",
+        DeploymentStatus::Unknown => "unknown",
+    }
+}
+
+/// Lowercase document kind shown on document receipts.
+#[must_use]
+fn document_type_label(doc_type: &DocumentType) -> &'static str {
+    match doc_type {
+        DocumentType::Design => "design doc",
+        DocumentType::Adr => "ADR",
+        DocumentType::Rfc => "RFC",
+        DocumentType::Runbook => "runbook",
+        DocumentType::Postmortem => "postmortem",
+        DocumentType::Note => "note",
+        DocumentType::Other => "doc",
+    }
+}
+
+/// Severity, role, and time-to-resolve shown on incident receipts.
+///
+/// Unknown severity and role are left out rather than printed.
+fn incident_details(incident: &IncidentEvent) -> Vec<String> {
+    let mut details = Vec::new();
+    match incident.severity {
+        IncidentSeverity::Sev1 => details.push("SEV1".to_string()),
+        IncidentSeverity::Sev2 => details.push("SEV2".to_string()),
+        IncidentSeverity::Sev3 => details.push("SEV3".to_string()),
+        IncidentSeverity::Sev4 => details.push("SEV4".to_string()),
+        IncidentSeverity::Unknown => {}
+    }
+    match incident.role {
+        IncidentRole::Commander => details.push("commander".to_string()),
+        IncidentRole::Responder => details.push("responder".to_string()),
+        IncidentRole::Communications => details.push("comms".to_string()),
+        IncidentRole::Scribe => details.push("scribe".to_string()),
+        IncidentRole::Unknown => {}
+    }
+    if let Some(minutes) = incident.duration_minutes {
+        let (hours, minutes) = (minutes / 60, minutes % 60);
+        details.push(match (hours, minutes) {
+            (0, m) => format!("resolved in {m}m"),
+            (h, 0) => format!("resolved in {h}h"),
+            (h, m) => format!("resolved in {h}h {m}m"),
+        });
+    }
+    details
+}
+
 /// Emoji marker for manual event kinds.
 #[must_use]
 pub fn manual_type_emoji(event_type: &ManualEventType) -> &'static str {
@@ -84,6 +296,7 @@ mod tests {
 
     fn sample_pr_event() -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["pr", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc.with_ymd_and_hms(2025, 1, 2, 8, 0, 0).unwrap(),
@@ -118,6 +331,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -130,6 +346,46 @@ mod tests {
         assert!(line.contains("[acme/service](https://github.com/acme/service/pull/42)"));
     }
 
+    #[test]
+    fn credits_contributors_after_the_receipt() {
+        let mut event = sample_pr_event();
+        event.links.clear();
+        event.contributors = vec![
+            Contributor {
+                login: "alice".into(),
+                id: None,
+                role: ContributorRole::CoAuthor,
+            },
+            Contributor {
+                login: "bob".into(),
+                id: Some(7),
+                role: ContributorRole::Pair,
+            },
+        ];
+
+        let line = format_receipt_markdown(&event);
+        assert_eq!(
+            line,
+            "- [PR] Ship receipts (2025-01-02) — acme/service (with alice, bob)"
+        );
+    }
+
+    #[test]
+    fn appends_annotation_note() {
+        let mut event = sample_pr_event();
+        event.links.clear();
+        event.extensions.insert(
+            shiplog::annotate::NOTE_EXTENSION.to_string(),
+            "Unblocked the launch".into(),
+        );
+
+        let line = format_receipt_markdown(&event);
+        assert_eq!(
+            line,
+            "- [PR] Ship receipts (2025-01-02) — acme/service — Note: Unblocked the launch"
+        );
+    }
+
     #[test]
     fn formats_review_without_link() {
         let mut event = sample_pr_event();
@@ -140,6 +396,8 @@ mod tests {
             submitted_at: Utc.with_ymd_and_hms(2025, 1, 2, 9, 0, 0).unwrap(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         });
         event.links.clear();
 
@@ -147,6 +405,163 @@ mod tests {
         assert_eq!(line, "- [Review] approved (2025-01-02) — acme/service");
     }
 
+    #[test]
+    fn formats_issue_with_link() {
+        let mut event = sample_pr_event();
+        event.id = EventId::from_parts(["issue", "7"]);
+        event.kind = EventKind::Issue;
+        event.payload = EventPayload::Issue(IssueEvent {
+            number: 7,
+            title: "Flaky deploy check".into(),
+            state: IssueState::Closed,
+            labels: vec!["bug".into()],
+            opened_at: Utc.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap(),
+            closed_at: Some(Utc.with_ymd_and_hms(2025, 1, 2, 8, 0, 0).unwrap()),
+            window: None,
+            effort: None,
+        });
+        event.links = vec![Link {
+            label: "issue".into(),
+            url: "https://github.com/acme/service/issues/7".into(),
+        }];
+
+        let line = format_receipt_markdown(&event);
+        assert_eq!(
+            line,
+            "- [Issue] Flaky deploy check (2025-01-02) — [acme/service](https://github.com/acme/service/issues/7)"
+        );
+    }
+
+    #[test]
+    fn formats_deployment_with_environment_and_status() {
+        let mut event = sample_pr_event();
+        event.id = EventId::from_parts(["deploy", "1"]);
+        event.kind = EventKind::Deployment;
+        event.payload = EventPayload::Deployment(DeploymentEvent {
+            environment: "production".into(),
+            version: Some("v1.4.0".into()),
+            status: DeploymentStatus::RolledBack,
+            started_at: Utc.with_ymd_and_hms(2025, 1, 2, 7, 0, 0).unwrap(),
+            finished_at: Some(Utc.with_ymd_and_hms(2025, 1, 2, 8, 0, 0).unwrap()),
+            window: None,
+        });
+
+        let line = format_receipt_markdown(&event);
+        assert_eq!(
+            line,
+            "- [Deploy] v1.4.0 to production (2025-01-02, rolled back) — acme/service"
+        );
+    }
+
+    #[test]
+    fn formats_release_with_name_and_link() {
+        let mut event = sample_pr_event();
+        event.id = EventId::from_parts(["release", "1"]);
+        event.kind = EventKind::Release;
+        event.payload = EventPayload::Release(ReleaseEvent {
+            version: "v1.4.0".into(),
+            name: Some("Receipts GA".into()),
+            status: ReleaseStatus::Published,
+            created_at: Utc.with_ymd_and_hms(2025, 1, 2, 7, 0, 0).unwrap(),
+            published_at: Some(Utc.with_ymd_and_hms(2025, 1, 2, 8, 0, 0).unwrap()),
+            window: None,
+        });
+        event.links = vec![Link {
+            label: "release".into(),
+            url: "https://github.com/acme/service/releases/tag/v1.4.0".into(),
+        }];
+
+        let line = format_receipt_markdown(&event);
+        assert_eq!(
+            line,
+            "- [Release] v1.4.0 — Receipts GA (2025-01-02) — [acme/service](https://github.com/acme/service/releases/tag/v1.4.0)"
+        );
+    }
+
+    #[test]
+    fn formats_comment_thread_as_one_receipt() {
+        let mut event = sample_pr_event();
+        event.id = EventId::from_parts(["comment", "42"]);
+        event.kind = EventKind::Comment;
+        event.payload = EventPayload::Comment(CommentEvent {
+            target: CommentTarget::PullRequest,
+            target_number: Some(42),
+            target_title: "Ship receipts".into(),
+            thread: Some("src/render.rs".into()),
+            count: 9,
+            first_at: Utc.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap(),
+            last_at: Utc.with_ymd_and_hms(2025, 1, 2, 8, 0, 0).unwrap(),
+            window: None,
+        });
+
+        let line = format_receipt_markdown(&event);
+        assert_eq!(
+            line,
+            "- [Comments] 9 comments on PR #42: Ship receipts (2025-01-02) — acme/service"
+        );
+    }
+
+    #[test]
+    fn formats_incident_with_severity_role_and_duration() {
+        let mut event = sample_pr_event();
+        event.id = EventId::from_parts(["incident", "7"]);
+        event.kind = EventKind::Incident;
+        event.payload = EventPayload::Incident(IncidentEvent {
+            title: "Checkout outage".into(),
+            severity: IncidentSeverity::Sev2,
+            role: IncidentRole::Commander,
+            detected_at: Utc.with_ymd_and_hms(2025, 1, 2, 6, 30, 0).unwrap(),
+            resolved_at: Some(Utc.with_ymd_and_hms(2025, 1, 2, 8, 0, 0).unwrap()),
+            duration_minutes: Some(90),
+            summary: None,
+            customer_impact: None,
+            window: None,
+        });
+        event.links = vec![Link {
+            label: "postmortem".into(),
+            url: "https://internal/pm/7".into(),
+        }];
+
+        let line = format_receipt_markdown(&event);
+        assert_eq!(
+            line,
+            "- [🚨] Checkout outage [SEV2, commander, resolved in 1h 30m] (2025-01-02) — [postmortem](https://internal/pm/7)"
+        );
+
+        let EventPayload::Incident(incident) = &mut event.payload else {
+            unreachable!()
+        };
+        incident.severity = IncidentSeverity::Unknown;
+        incident.role = IncidentRole::Unknown;
+        incident.duration_minutes = None;
+        event.links.clear();
+        assert_eq!(
+            format_receipt_markdown(&event),
+            "- [🚨] Checkout outage (2025-01-02)"
+        );
+    }
+
+    #[test]
+    fn formats_document_with_type_system_and_word_delta() {
+        let mut event = sample_pr_event();
+        event.id = EventId::from_parts(["doc", "adr-12"]);
+        event.kind = EventKind::Document;
+        event.payload = EventPayload::Document(DocumentEvent {
+            doc_type: DocumentType::Adr,
+            title: "ADR-12: Queue per tenant".into(),
+            system: "adr".into(),
+            last_edited_at: Utc.with_ymd_and_hms(2025, 1, 3, 9, 0, 0).unwrap(),
+            word_count_delta: Some(850),
+            window: None,
+        });
+        event.links.clear();
+
+        assert_eq!(
+            format_receipt_markdown(&event),
+            "- [Doc] ADR-12: Queue per tenant [ADR, adr, +850 words] (2025-01-03)"
+        );
+    }
+
     #[test]
     fn formats_manual_event_with_type_emoji() {
         let mut event = sample_pr_event();
@@ -159,6 +574,9 @@ mod tests {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()),
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         });
         event.links = vec![Link {
             label: "runbook".into(),
@@ -172,6 +590,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_manual_evidence_as_relative_links_after_urls() {
+        let mut event = sample_pr_event();
+        event.kind = EventKind::Manual;
+        event.payload = EventPayload::Manual(ManualEvent {
+            event_type: ManualEventType::Launch,
+            title: "Checkout launch".into(),
+            description: None,
+            started_at: None,
+            ended_at: None,
+            impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: vec![
+                EvidenceFile {
+                    path: "evidence/b94d27b9934d-launch metrics.png".into(),
+                    label: None,
+                    sha256: None,
+                },
+                EvidenceFile {
+                    path: "evidence/e3b0c44298fc-notes.pdf".into(),
+                    label: Some("Go/no-go notes".into()),
+                    sha256: None,
+                },
+            ],
+        });
+        event.links = vec![Link {
+            label: "plan".into(),
+            url: "https://internal/plan".into(),
+        }];
+
+        let line = format_receipt_markdown(&event);
+        assert!(line.ends_with(
+            "— [plan](https://internal/plan), \
+             [launch metrics.png](evidence/b94d27b9934d-launch%20metrics.png), \
+             [Go/no-go notes](evidence/e3b0c44298fc-notes.pdf)"
+        ));
+    }
+
     #[test]
     fn manual_type_emoji_stays_stable() {
         assert_eq!(manual_type_emoji(&ManualEventType::Note), "📝");
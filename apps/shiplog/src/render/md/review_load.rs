@@ -0,0 +1,85 @@
+//! Review load subsection: review work is spread across other people's PRs,
+//! so it rarely shows up as a workstream of its own.
+
+use super::count_label;
+use crate::coverage::ReportingTimezone;
+use shiplog::schema::event::EventEnvelope;
+use shiplog::stats::review_load;
+
+/// Render the `## Review Load` section. Renders nothing without reviews.
+pub(super) fn render_review_load(
+    out: &mut String,
+    events: &[EventEnvelope],
+    timezone: ReportingTimezone,
+) {
+    let Some(load) = review_load(events, timezone) else {
+        return;
+    };
+
+    out.push_str("## Review Load\n\n");
+    out.push_str(&format!(
+        "- **Reviews:** {} across {} (median {} per active week)\n",
+        load.reviews,
+        count_label(load.weeks.len(), "active week", "active weeks"),
+        load.median_per_active_week()
+    ));
+    if let Some((week, count)) = load.busiest_week() {
+        out.push_str(&format!(
+            "- **Busiest week:** week of {week}, {}\n",
+            count_label(count, "review", "reviews")
+        ));
+    }
+    out.push_str(&format!("- **Repositories reviewed:** {}\n", load.repos));
+    if load.authors > 0 {
+        out.push_str(&format!("- **Authors helped:** {}\n", load.authors));
+    }
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use shiplog::fixtures::{FixtureSpec, generate};
+    use shiplog::schema::event::EventPayload;
+
+    #[test]
+    fn renders_volume_busiest_week_and_authors() {
+        let mut events = generate(&FixtureSpec {
+            events: 3,
+            mix: "review=1".parse().unwrap(),
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events;
+        for (event, (day, author)) in
+            events
+                .iter_mut()
+                .zip([(3, "alice"), (4, "bob"), (12, "alice")])
+        {
+            event.repo.full_name = "acme/api".into();
+            if let EventPayload::Review(review) = &mut event.payload {
+                review.submitted_at = Utc.with_ymd_and_hms(2025, 2, day, 12, 0, 0).unwrap();
+                review.pull_author = Some(author.into());
+            }
+        }
+
+        let mut out = String::new();
+        render_review_load(&mut out, &events, ReportingTimezone::UTC);
+        assert_eq!(
+            out,
+            "## Review Load\n\n\
+             - **Reviews:** 3 across 2 active weeks (median 1 per active week)\n\
+             - **Busiest week:** week of 2025-02-03, 2 reviews\n\
+             - **Repositories reviewed:** 1\n\
+             - **Authors helped:** 2\n\n"
+        );
+    }
+
+    #[test]
+    fn renders_nothing_without_reviews() {
+        let mut out = String::new();
+        render_review_load(&mut out, &[], ReportingTimezone::UTC);
+        assert!(out.is_empty());
+    }
+}
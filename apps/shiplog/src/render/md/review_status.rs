@@ -0,0 +1,80 @@
+//! "Review Status" banner rendered at the top of the internal packet.
+
+use shiplog::review_state::{ReviewCheck, ReviewState};
+use shiplog::schema::workstream::WorkstreamsFile;
+
+/// Most open workstream titles named per checklist line.
+const OPEN_WORKSTREAM_LIMIT: usize = 5;
+
+/// Share profiles that can be approved.
+const SHARE_PROFILES: [&str; 2] = ["manager", "public"];
+
+/// Render the review status banner for a run's checklist state.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::render::md::render_review_status;
+/// use shiplog::review_state::ReviewState;
+/// use shiplog::schema::workstream::WorkstreamsFile;
+///
+/// let workstreams = WorkstreamsFile {
+///     version: 1,
+///     generated_at: chrono::Utc::now(),
+///     workstreams: vec![],
+/// };
+/// let banner = render_review_status(&ReviewState::default(), &workstreams);
+/// assert!(banner.starts_with("# Review Status\n"));
+/// assert!(banner.contains("- Approved for sharing: none yet"));
+/// ```
+pub fn render_review_status(state: &ReviewState, workstreams: &WorkstreamsFile) -> String {
+    let mut out = String::from("# Review Status\n\n");
+    out.push_str(
+        "_Tracked in `review.state.json`. This block is not included in manager or public packets._\n\n",
+    );
+
+    let total = workstreams.workstreams.len();
+    for check in [ReviewCheck::ClaimsFilled, ReviewCheck::ReceiptsVerified] {
+        let open = state.open_workstreams(workstreams, check);
+        out.push_str(&format!(
+            "- {}: {} of {total} workstreams",
+            check.label(),
+            total - open.len()
+        ));
+        if !open.is_empty() {
+            out.push_str(&format!(
+                " (open: {}",
+                open.iter()
+                    .take(OPEN_WORKSTREAM_LIMIT)
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            if open.len() > OPEN_WORKSTREAM_LIMIT {
+                out.push_str(&format!(", +{} more", open.len() - OPEN_WORKSTREAM_LIMIT));
+            }
+            out.push(')');
+        }
+        out.push('\n');
+    }
+
+    let approved: Vec<String> = SHARE_PROFILES
+        .iter()
+        .filter_map(|profile| {
+            state
+                .approved_profiles
+                .get(*profile)
+                .map(|at| format!("{profile} ({})", at.format("%Y-%m-%d")))
+        })
+        .collect();
+    if approved.is_empty() {
+        out.push_str("- Approved for sharing: none yet\n");
+    } else {
+        out.push_str(&format!(
+            "- Approved for sharing: {}\n",
+            approved.join(", ")
+        ));
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,131 @@
+//! "Rubric Gap Analysis" section for `shiplog assess`.
+
+use shiplog::assess::{CompetencyAssessment, CompetencyStatus, RubricAssessment};
+
+/// Render a rubric assessment as a gap analysis section.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::assess::RubricAssessment;
+/// use shiplog::render::md::render_rubric_gaps;
+///
+/// let assessment = RubricAssessment {
+///     rubric: None,
+///     level: "Senior".to_string(),
+///     competencies: vec![],
+/// };
+/// let section = render_rubric_gaps(&assessment);
+/// assert!(section.starts_with("# Rubric Gap Analysis\n"));
+/// ```
+pub fn render_rubric_gaps(assessment: &RubricAssessment) -> String {
+    let mut out = String::from("# Rubric Gap Analysis\n\n");
+    let level = match &assessment.rubric {
+        Some(rubric) => format!("{rubric}, level {}", assessment.level),
+        None => format!("level {}", assessment.level),
+    };
+    out.push_str(&format!(
+        "_Compared against {level}. Matches come from keywords and event types, so \
+         thin or missing means the packet lacks receipts, not that the work did not happen._\n\n"
+    ));
+
+    let count = |status| {
+        assessment
+            .competencies
+            .iter()
+            .filter(|c| c.status == status)
+            .count()
+    };
+    out.push_str(&format!(
+        "- Strong: {} of {} competencies\n- Thin: {}\n- Missing: {}\n\n",
+        count(CompetencyStatus::Strong),
+        assessment.competencies.len(),
+        count(CompetencyStatus::Thin),
+        count(CompetencyStatus::Missing)
+    ));
+
+    if assessment.competencies.is_empty() {
+        return out;
+    }
+    out.push_str("| Competency | Status | Receipts | Matching events | Workstreams |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for competency in &assessment.competencies {
+        let workstreams = if competency.workstreams.is_empty() {
+            "-".to_string()
+        } else {
+            competency.workstreams.join(", ")
+        };
+        out.push_str(&format!(
+            "| {} | {} | {} of {} | {} | {workstreams} |\n",
+            competency.name,
+            competency.status.as_str(),
+            competency.receipts,
+            competency.min_receipts,
+            competency.evidence
+        ));
+    }
+
+    let gaps: Vec<&CompetencyAssessment> = assessment
+        .competencies
+        .iter()
+        .filter(|c| c.status != CompetencyStatus::Strong)
+        .collect();
+    if !gaps.is_empty() {
+        out.push_str("\n## Gaps\n\n");
+        for competency in gaps {
+            out.push_str(&format!(
+                "- **{}**: {}\n",
+                competency.name,
+                gap_hint(competency)
+            ));
+        }
+    }
+    out.push('\n');
+    out
+}
+
+fn gap_hint(competency: &CompetencyAssessment) -> String {
+    let expected = if competency.expected.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " Expected evidence: {}.",
+            competency
+                .expected
+                .iter()
+                .map(|kind| kind.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    match competency.status {
+        CompetencyStatus::Missing => format!(
+            "no matching events. Add a manual event or collect the source that holds this \
+             work.{expected}"
+        ),
+        CompetencyStatus::Thin | CompetencyStatus::Strong => {
+            let missing = competency.min_receipts.saturating_sub(competency.receipts);
+            let selectable = competency.evidence.saturating_sub(competency.receipts);
+            let next = if selectable >= missing {
+                format!(
+                    "Select {missing} more receipt{} in workstreams.yaml.",
+                    if missing == 1 { "" } else { "s" }
+                )
+            } else if selectable == 0 {
+                "Every match is already a receipt; capture more evidence.".to_string()
+            } else {
+                format!(
+                    "Select the {selectable} remaining match{} in workstreams.yaml and capture \
+                     more evidence.",
+                    if selectable == 1 { "" } else { "es" }
+                )
+            };
+            format!(
+                "{} matching event{}, {} curated. {next}{expected}",
+                competency.evidence,
+                if competency.evidence == 1 { "" } else { "s" },
+                competency.receipts
+            )
+        }
+    }
+}
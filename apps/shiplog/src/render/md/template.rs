@@ -0,0 +1,133 @@
+//! User-template packet rendering.
+//!
+//! A packet template replaces the built-in Markdown layout. It sees the same
+//! facts as `packet.json` plus every ledger event:
+//!
+//! - `user`, `window_label`, `run_id`, `window.since`, `window.until`
+//! - `stats`: `workstreams`, `events`, `receipts`, and `events_by_kind.<Kind>`
+//! - `workstreams`: each with `id`, `title`, `claim`, `tags`, `stats`, and
+//!   `receipts`, heaviest first
+//! - `events`: every event in ledger order
+//! - `coverage`: `completeness`, `score`, `sources`, `slices`,
+//!   `partial_slices`, and `warnings`
+//!
+//! Receipts and events carry `id`, `kind`, `title`, `date`, `occurred_at`,
+//! `repo`, `url`, and `weight`. `date` is the local date in the run's
+//! reporting timezone.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use shiplog::schema::coverage::CoverageManifest;
+use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::workstream::WorkstreamsFile;
+
+use crate::coverage::ReportingTimezone;
+use crate::render::json::{PacketReceipt, build_packet_json, event_receipt};
+use crate::template::{TemplateContext, TemplateEngine};
+use crate::workstreams::derived_receipt_weight;
+
+/// A user packet template, checked for syntax errors when loaded.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::render::md::PacketTemplate;
+/// use shiplog::schema::coverage::Completeness;
+/// use shiplog::schema::workstream::WorkstreamsFile;
+/// use shiplog_testkit::fixtures::{WorkstreamFixture, test_coverage};
+/// use shiplog_testkit::pr_event;
+/// use chrono::Utc;
+///
+/// let template = PacketTemplate::new(
+///     "# {{ user }}\n{% for ws in workstreams %}\n## {{ ws.title }}\n\
+///      {% for r in ws.receipts %}\n- {{ r.title }} ({{ r.date }})\n{% endfor %}\n{% endfor %}\n",
+/// )
+/// .unwrap();
+/// let pr = pr_event("acme/billing", 7, "Retry failed invoices");
+/// let workstreams = WorkstreamsFile {
+///     version: 1,
+///     generated_at: Utc::now(),
+///     workstreams: vec![WorkstreamFixture::new("Billing").with_receipt(&pr).build()],
+/// };
+/// let coverage = test_coverage("octo", Completeness::Complete);
+///
+/// let packet = template
+///     .render("octo", "2025-Q1", &[pr], &workstreams, &coverage)
+///     .unwrap();
+/// assert_eq!(packet, "# octo\n## Billing\n- Retry failed invoices (1970-01-01)\n");
+///
+/// assert!(PacketTemplate::new("{% for ws in workstreams %}").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PacketTemplate {
+    source: String,
+}
+
+impl PacketTemplate {
+    /// Wrap template text, rejecting unclosed tags, unknown tags, and
+    /// unbalanced blocks.
+    pub fn new(source: impl Into<String>) -> Result<Self> {
+        let source = source.into();
+        TemplateEngine::new()
+            .validate(&source)
+            .context("parse packet template")?;
+        Ok(Self { source })
+    }
+
+    /// Render the packet for a run.
+    pub fn render(
+        &self,
+        user: &str,
+        window_label: &str,
+        events: &[EventEnvelope],
+        workstreams: &WorkstreamsFile,
+        coverage: &CoverageManifest,
+    ) -> Result<String> {
+        let context = packet_template_context(user, window_label, events, workstreams, coverage)?;
+        TemplateEngine::new()
+            .render(&self.source, &context)
+            .context("render packet template")
+    }
+}
+
+/// Build the variables a packet template sees.
+pub fn packet_template_context(
+    user: &str,
+    window_label: &str,
+    events: &[EventEnvelope],
+    workstreams: &WorkstreamsFile,
+    coverage: &CoverageManifest,
+) -> Result<TemplateContext> {
+    let timezone = ReportingTimezone::from_manifest(coverage.timezone.as_deref());
+    let dated = |receipt: &PacketReceipt| -> Result<Value> {
+        let mut value = serde_json::to_value(receipt).context("serialize template receipt")?;
+        value["date"] = Value::String(timezone.local_date(&receipt.occurred_at).to_string());
+        Ok(value)
+    };
+
+    let packet = build_packet_json(user, window_label, events, workstreams, coverage);
+    let workstream_values = packet
+        .workstreams
+        .iter()
+        .map(|ws| {
+            let mut value = serde_json::to_value(ws).context("serialize template workstream")?;
+            value["receipts"] = Value::Array(ws.receipts.iter().map(dated).collect::<Result<_>>()?);
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let event_values = events
+        .iter()
+        .map(|event| dated(&event_receipt(event, derived_receipt_weight(event))))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut context = TemplateContext::new();
+    context.set("user", user);
+    context.set("window_label", window_label);
+    context.set("run_id", packet.run_id.as_str());
+    context.set("window", &packet.window);
+    context.set("stats", &packet.summary);
+    context.set("workstreams", &workstream_values);
+    context.set("events", &event_values);
+    context.set("coverage", &packet.coverage);
+    Ok(context)
+}
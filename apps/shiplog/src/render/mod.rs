@@ -1,3 +1,5 @@
 //! Built-in renderers used by the `shiplog` CLI.
 
+pub mod json;
 pub mod md;
+pub mod pdf;
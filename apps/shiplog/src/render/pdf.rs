@@ -0,0 +1,490 @@
+//! PDF layout for rendered packets.
+//!
+//! Lays a packet's Markdown out on US Letter pages with the PDF base-14
+//! fonts, so no font files or external converters are needed. Headings,
+//! bullets, quotes, rules, and code blocks keep their structure; inline
+//! emphasis is dropped and links are written as `label (url)` so the
+//! receipts stay readable on paper. Output is byte-for-byte deterministic
+//! for the same Markdown, which keeps bundle manifests stable.
+
+use std::fmt::Write as _;
+
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 54.0;
+const BODY_SIZE: f32 = 10.5;
+const CODE_SIZE: f32 = 9.0;
+const FOOTER_SIZE: f32 = 8.0;
+const LEADING: f32 = 1.35;
+const INDENT: f32 = 14.0;
+
+/// Render packet Markdown as a PDF document.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::render::pdf::render_packet_pdf;
+///
+/// let pdf = render_packet_pdf("# Summary\n\n- [PR] Add feature (2025-01-02)\n");
+/// assert!(pdf.starts_with(b"%PDF-1.4"));
+/// assert!(pdf.ends_with(b"%%EOF\n"));
+/// ```
+pub fn render_packet_pdf(markdown: &str) -> Vec<u8> {
+    let mut layout = Layout::new();
+    let mut in_code = false;
+    for raw in markdown.lines() {
+        let line = raw.trim_end();
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            layout.gap(BODY_SIZE * 0.3);
+            continue;
+        }
+        if in_code || line.trim_start().starts_with('|') {
+            layout.code(line);
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            layout.gap(BODY_SIZE * 0.5);
+        } else if trimmed.starts_with("<!--") || trimmed.starts_with('<') {
+            // HTML comments and tags carry no printable packet content.
+        } else if let Some((level, text)) = heading(trimmed) {
+            layout.heading(level, &plain_inline(text));
+        } else if trimmed.chars().all(|c| c == '-' || c == '*' || c == '_') && trimmed.len() >= 3 {
+            layout.rule();
+        } else if let Some(text) = trimmed.strip_prefix("> ").or_else(|| {
+            trimmed
+                .strip_prefix('_')
+                .and_then(|text| text.strip_suffix('_'))
+        }) {
+            layout.paragraph(&plain_inline(text), Font::Italic, INDENT, None);
+        } else if let Some(text) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            let depth = (line.len() - trimmed.len()) / 2;
+            let indent = INDENT * (depth as f32 + 1.0);
+            layout.paragraph(&plain_inline(text), Font::Regular, indent, Some('\u{2022}'));
+        } else {
+            let depth = (line.len() - trimmed.len()) / 2;
+            layout.paragraph(
+                &plain_inline(trimmed),
+                Font::Regular,
+                INDENT * depth as f32,
+                None,
+            );
+        }
+    }
+    layout.finish()
+}
+
+/// `(level, text)` for an ATX heading line.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) {
+        line[level..].strip_prefix(' ').map(|text| (level, text))
+    } else {
+        None
+    }
+}
+
+/// Strip inline Markdown: emphasis markers and code ticks are dropped, links
+/// become `label (url)`, and images keep their alt text.
+fn plain_inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        let (before, tail) = rest.split_at(start);
+        out.push_str(before);
+        let image = out.ends_with('!');
+        let parsed = tail.find("](").and_then(|close| {
+            let url_end = tail[close + 2..].find(')')? + close + 2;
+            Some((&tail[1..close], &tail[close + 2..url_end], url_end + 1))
+        });
+        match parsed {
+            Some((label, url, consumed)) => {
+                if image {
+                    out.pop();
+                    out.push_str(label);
+                } else if label == url || url.is_empty() {
+                    out.push_str(label);
+                } else {
+                    let _ = write!(out, "{label} ({url})");
+                }
+                rest = &tail[consumed..];
+            }
+            None => {
+                out.push('[');
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out.replace("**", "").replace("__", "").replace('`', "")
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Font {
+    Regular,
+    Bold,
+    Italic,
+    Mono,
+}
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Self::Regular => "F1",
+            Self::Bold => "F2",
+            Self::Italic => "F3",
+            Self::Mono => "F4",
+        }
+    }
+
+    /// Advance width of `c` at `size` points.
+    fn width(self, c: char, size: f32) -> f32 {
+        let units = match self {
+            Self::Mono => 600.0,
+            Self::Bold => helvetica_units(c) * 1.08,
+            Self::Regular | Self::Italic => helvetica_units(c),
+        };
+        units * size / 1000.0
+    }
+
+    fn text_width(self, text: &str, size: f32) -> f32 {
+        text.chars().map(|c| self.width(c, size)).sum()
+    }
+}
+
+/// Helvetica glyph widths from the standard AFM metrics, in 1/1000 em.
+fn helvetica_units(c: char) -> f32 {
+    const ASCII: [u16; 95] = [
+        278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278,
+        278, // ' '..'/'
+        556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584,
+        556, // '0'..'?'
+        1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722,
+        778, // '@'..'O'
+        667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469,
+        556, // 'P'..'_'
+        333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556,
+        556, // '`'..'o'
+        556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584, // 'p'..'~'
+    ];
+    match c {
+        ' '..='~' => f32::from(ASCII[c as usize - 32]),
+        '\u{2014}' => 1000.0,
+        '\u{2022}' => 350.0,
+        _ => 556.0,
+    }
+}
+
+/// WinAnsiEncoding byte for `c`; characters outside it are dropped.
+fn win_ansi(c: char) -> Option<u8> {
+    match c {
+        ' '..='~' => Some(c as u8),
+        '\u{a0}'..='\u{ff}' => Some(c as u32 as u8),
+        '\u{20ac}' => Some(0x80),
+        '\u{2026}' => Some(0x85),
+        '\u{2018}' => Some(0x91),
+        '\u{2019}' => Some(0x92),
+        '\u{201c}' => Some(0x93),
+        '\u{201d}' => Some(0x94),
+        '\u{2022}' => Some(0x95),
+        '\u{2013}' => Some(0x96),
+        '\u{2014}' => Some(0x97),
+        '\u{2122}' => Some(0x99),
+        '\u{2192}' => Some(b'>'),
+        _ => None,
+    }
+}
+
+/// A PDF string literal for `text`, with unencodable characters dropped.
+fn pdf_string(text: &str) -> String {
+    let mut out = String::from("(");
+    for byte in text.chars().filter_map(win_ansi) {
+        match byte {
+            b'(' | b')' | b'\\' => {
+                out.push('\\');
+                out.push(byte as char);
+            }
+            0x20..=0x7e => out.push(byte as char),
+            _ => {
+                let _ = write!(out, "\\{byte:03o}");
+            }
+        }
+    }
+    out.push(')');
+    out
+}
+
+/// Greedy word wrap to `width` points; words longer than a line are split.
+fn wrap(text: &str, font: Font, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        if font.text_width(&candidate, size) <= width {
+            line = candidate;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        for c in word.chars() {
+            if !line.is_empty() && font.text_width(&line, size) + font.width(c, size) > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            line.push(c);
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+struct Layout {
+    pages: Vec<String>,
+    ops: String,
+    y: f32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            ops: String::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    fn break_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.ops));
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    /// Start a new page unless `height` more points fit on this one.
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.break_page();
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        // A gap never starts a page.
+        if self.y < PAGE_HEIGHT - MARGIN {
+            self.y -= height;
+        }
+    }
+
+    fn text(&mut self, font: Font, size: f32, x: f32, y: f32, text: &str) {
+        let _ = writeln!(
+            self.ops,
+            "BT /{} {size:.1} Tf {x:.2} {y:.2} Td {} Tj ET",
+            font.resource(),
+            pdf_string(text)
+        );
+    }
+
+    /// Set one line of text and return its baseline.
+    fn line(&mut self, font: Font, size: f32, x: f32, text: &str) -> f32 {
+        let height = size * LEADING;
+        self.reserve(height);
+        let baseline = self.y - size;
+        self.text(font, size, x, baseline, text);
+        self.y -= height;
+        baseline
+    }
+
+    fn heading(&mut self, level: usize, text: &str) {
+        let size = match level {
+            1 => 18.0,
+            2 => 14.0,
+            3 => 12.0,
+            _ => 11.0,
+        };
+        self.gap(size * 0.6);
+        // Keep a heading with at least one line of what follows it.
+        self.reserve(size * LEADING + BODY_SIZE * LEADING);
+        let width = PAGE_WIDTH - 2.0 * MARGIN;
+        for line in wrap(text, Font::Bold, size, width) {
+            self.line(Font::Bold, size, MARGIN, &line);
+        }
+        if level == 1 {
+            self.rule();
+        }
+    }
+
+    fn paragraph(&mut self, text: &str, font: Font, indent: f32, bullet: Option<char>) {
+        let x = MARGIN + indent;
+        let width = PAGE_WIDTH - MARGIN - x;
+        for (index, line) in wrap(text, font, BODY_SIZE, width).iter().enumerate() {
+            let baseline = self.line(font, BODY_SIZE, x, line);
+            if index == 0
+                && let Some(bullet) = bullet
+            {
+                let bullet = bullet.to_string();
+                self.text(font, BODY_SIZE, x - INDENT * 0.7, baseline, &bullet);
+            }
+        }
+    }
+
+    fn code(&mut self, line: &str) {
+        let width = PAGE_WIDTH - 2.0 * MARGIN - INDENT;
+        let chars = (width / Font::Mono.width(' ', CODE_SIZE)) as usize;
+        let text: Vec<char> = line.chars().collect();
+        if text.is_empty() {
+            self.line(Font::Mono, CODE_SIZE, MARGIN + INDENT, "");
+        }
+        for chunk in text.chunks(chars.max(1)) {
+            let chunk: String = chunk.iter().collect();
+            self.line(Font::Mono, CODE_SIZE, MARGIN + INDENT, &chunk);
+        }
+    }
+
+    fn rule(&mut self) {
+        self.reserve(BODY_SIZE);
+        self.y -= BODY_SIZE * 0.4;
+        let _ = writeln!(
+            self.ops,
+            "0.5 w 0.6 G {MARGIN:.2} {y:.2} m {x:.2} {y:.2} l S 0 G",
+            y = self.y,
+            x = PAGE_WIDTH - MARGIN
+        );
+        self.y -= BODY_SIZE * 0.6;
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if !self.ops.is_empty() || self.pages.is_empty() {
+            self.pages.push(std::mem::take(&mut self.ops));
+        }
+        let count = self.pages.len();
+        let mut objects: Vec<String> = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            String::new(), // Pages, filled in below once the page ids are known.
+        ];
+        for name in [
+            "Helvetica",
+            "Helvetica-Bold",
+            "Helvetica-Oblique",
+            "Courier",
+        ] {
+            objects.push(format!(
+                "<< /Type /Font /Subtype /Type1 /BaseFont /{name} /Encoding /WinAnsiEncoding >>"
+            ));
+        }
+        let fonts = "<< /F1 3 0 R /F2 4 0 R /F3 5 0 R /F4 6 0 R >>";
+        let mut kids = Vec::with_capacity(count);
+        for (index, ops) in self.pages.iter().enumerate() {
+            let footer = format!(
+                "BT /F1 {FOOTER_SIZE:.1} Tf {x:.2} {y:.2} Td {} Tj ET\n",
+                pdf_string(&format!("Page {} of {count}", index + 1)),
+                x = PAGE_WIDTH - MARGIN - 48.0,
+                y = MARGIN / 2.0
+            );
+            let content = format!("{ops}{footer}");
+            let page_id = objects.len() + 1;
+            kids.push(format!("{page_id} 0 R"));
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font {fonts} >> /Contents {} 0 R >>",
+                page_id + 1
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{content}endstream",
+                content.len()
+            ));
+        }
+        objects[1] = format!(
+            "<< /Type /Pages /Kids [{}] /Count {count} >>",
+            kids.join(" ")
+        );
+        objects.push("<< /Producer (shiplog) >>".to_string());
+
+        let mut pdf = String::from("%PDF-1.4\n");
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            let _ = write!(pdf, "{} 0 obj\n{object}\nendobj\n", index + 1);
+        }
+        let xref = pdf.len();
+        let _ = write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(pdf, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            pdf,
+            "trailer\n<< /Size {} /Root 1 0 R /Info {} 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1,
+            objects.len()
+        );
+        pdf.into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(pdf: &[u8]) -> String {
+        String::from_utf8(pdf.to_vec()).expect("content streams are ASCII")
+    }
+
+    #[test]
+    fn xref_offsets_point_at_their_objects() {
+        let pdf = text(&render_packet_pdf("# Summary\n\nHello\n"));
+        let xref = pdf.find("\nxref\n").unwrap() + 1;
+        let startxref: usize = pdf
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(startxref, xref);
+        for (index, entry) in pdf[xref..].lines().skip(3).enumerate() {
+            let Some(offset) = entry.strip_suffix(" 00000 n ") else {
+                break;
+            };
+            let offset: usize = offset.parse().unwrap();
+            assert!(pdf[offset..].starts_with(&format!("{} 0 obj", index + 1)));
+        }
+    }
+
+    #[test]
+    fn links_keep_their_url_and_emphasis_is_dropped() {
+        assert_eq!(
+            plain_inline("**Window:** see [owner/repo](https://github.com/owner/repo/pull/1)"),
+            "Window: see owner/repo (https://github.com/owner/repo/pull/1)"
+        );
+        assert_eq!(plain_inline("`code` and [x] box"), "code and [x] box");
+    }
+
+    #[test]
+    fn long_packets_break_across_numbered_pages() {
+        let markdown: String = (0..150).map(|i| format!("- receipt {i}\n")).collect();
+        let pdf = text(&render_packet_pdf(&markdown));
+        assert!(pdf.contains("/Count 4"));
+        assert!(pdf.contains("(Page 4 of 4)"));
+        assert!(pdf.contains("(receipt 149)"));
+    }
+
+    #[test]
+    fn strings_are_escaped_and_encoded_as_win_ansi() {
+        assert_eq!(pdf_string("a (b) \\ c"), "(a \\(b\\) \\\\ c)");
+        assert_eq!(pdf_string("x \u{2014} y \u{26a0}\u{fe0f}"), "(x \\227 y )");
+    }
+
+    #[test]
+    fn wrap_splits_on_words_and_hard_breaks_long_ones() {
+        let lines = wrap("alpha beta gamma", Font::Regular, 10.0, 50.0);
+        assert_eq!(lines, vec!["alpha beta", "gamma"]);
+        let lines = wrap(&"x".repeat(40), Font::Mono, 10.0, 60.0);
+        assert!(lines.iter().all(|line| line.len() <= 10));
+    }
+}
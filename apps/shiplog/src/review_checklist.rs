@@ -0,0 +1,188 @@
+//! `shiplog review status|mark|approve`: the packet sign-off checklist.
+//!
+//! State lives in the run's `review.state.json`. Each change also rewrites the
+//! status banner at the top of the run's internal `packet.md`, so the packet
+//! reflects progress without a full re-render.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use shiplog::render::md::render_review_status;
+use shiplog::review_state::{REVIEW_STATE_FILENAME, ReviewCheck, ReviewState};
+
+use crate::*;
+
+const REVIEW_STATUS_HEADING: &str = "# Review Status\n";
+
+pub(super) fn print_review_status(run_dir: &Path, out: &Path) -> Result<()> {
+    let (workstreams, _, _) = load_effective_workstreams_for_run(run_dir)?;
+    let state = ReviewState::load(run_dir)?.unwrap_or_default();
+
+    println!("Review status: {}", display_path_for_cli(run_dir));
+    println!("Checklist:");
+    if workstreams.workstreams.is_empty() {
+        println!("- (no workstreams)");
+    }
+    for ws in &workstreams.workstreams {
+        let id = ws.id.to_string();
+        let claims = if state.is_done(&id, ReviewCheck::ClaimsFilled) {
+            "filled"
+        } else {
+            "open"
+        };
+        let receipts = if state.is_done(&id, ReviewCheck::ReceiptsVerified) {
+            "verified"
+        } else {
+            "open"
+        };
+        println!("- {}: claims {claims}, receipts {receipts}", ws.title);
+    }
+    println!("Approved for sharing:");
+    for profile in [BundleProfile::Manager, BundleProfile::Public] {
+        match state.approved_profiles.get(profile.as_str()) {
+            Some(at) => println!("- {profile}: approved {}", at.format("%Y-%m-%d %H:%M UTC")),
+            None => println!("- {profile}: not approved"),
+        }
+    }
+
+    let next = [ReviewCheck::ClaimsFilled, ReviewCheck::ReceiptsVerified]
+        .into_iter()
+        .find_map(|check| {
+            state
+                .open_workstreams(&workstreams, check)
+                .first()
+                .map(|title| (check, *title))
+        });
+    if let Some((check, title)) = next {
+        let item = match check {
+            ReviewCheck::ClaimsFilled => "claims",
+            ReviewCheck::ReceiptsVerified => "receipts",
+        };
+        let run = run_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        println!("Next, once it is done:");
+        println!(
+            "1. shiplog review mark {item} --workstream {} --out {} --run {}",
+            quote_cli_value(title),
+            quote_cli_value(&out.display().to_string()),
+            quote_cli_value(&run)
+        );
+    }
+    Ok(())
+}
+
+pub(super) fn mark_review_check(
+    run_dir: &Path,
+    check: ReviewCheck,
+    workstream: Option<&str>,
+    all: bool,
+    undo: bool,
+) -> Result<()> {
+    let (workstreams, _, _) = load_effective_workstreams_for_run(run_dir)?;
+    let targets: Vec<&Workstream> = if all {
+        workstreams.workstreams.iter().collect()
+    } else {
+        let selector = workstream.context("pass --workstream or --all")?;
+        vec![&workstreams.workstreams[WorkstreamManager::find_index(&workstreams, selector)?]]
+    };
+    if targets.is_empty() {
+        anyhow::bail!("run has no workstreams to mark");
+    }
+
+    let mut state = ReviewState::load(run_dir)?.unwrap_or_default();
+    let now = Utc::now();
+    for ws in targets {
+        let changed = state.set_check(&ws.id.to_string(), check, !undo, now);
+        let label = check.label().to_lowercase();
+        let outcome = match (undo, changed) {
+            (false, true) => format!("marked {label}"),
+            (false, false) => format!("{label} already marked"),
+            (true, true) => format!("cleared {label}"),
+            (true, false) => format!("{label} was not marked"),
+        };
+        println!("- {}: {outcome}", ws.title);
+    }
+    save_review_state(run_dir, &state, &workstreams)
+}
+
+pub(super) fn approve_share_profile(
+    run_dir: &Path,
+    profile: &BundleProfile,
+    revoke: bool,
+) -> Result<()> {
+    if matches!(profile, BundleProfile::Internal) {
+        anyhow::bail!("internal packets are not shared; approve manager or public");
+    }
+    let (workstreams, _, _) = load_effective_workstreams_for_run(run_dir)?;
+    let mut state = ReviewState::load(run_dir)?.unwrap_or_default();
+    if revoke {
+        if state.approved_profiles.remove(profile.as_str()).is_some() {
+            println!("Withdrew {profile} approval.");
+        } else {
+            println!("{profile} was not approved.");
+        }
+    } else {
+        state
+            .approved_profiles
+            .entry(profile.as_str().to_string())
+            .or_insert_with(Utc::now);
+        println!("Approved {profile} packet for sharing.");
+        let packet = run_dir
+            .join("profiles")
+            .join(profile.as_str())
+            .join("packet.md");
+        if !packet.exists() {
+            println!(
+                "Note: {} has not been rendered yet; run `shiplog share {profile}`.",
+                display_path_for_cli(&packet)
+            );
+        }
+    }
+    save_review_state(run_dir, &state, &workstreams)
+}
+
+fn save_review_state(
+    run_dir: &Path,
+    state: &ReviewState,
+    workstreams: &WorkstreamsFile,
+) -> Result<()> {
+    state.save(run_dir)?;
+    refresh_packet_review_status(run_dir, state, workstreams)?;
+    println!(
+        "Updated: {}",
+        display_path_for_cli(&run_dir.join(REVIEW_STATE_FILENAME))
+    );
+    Ok(())
+}
+
+/// Replace the status banner at the top of the internal packet, if the run
+/// has one. The next render rebuilds it from the same state.
+fn refresh_packet_review_status(
+    run_dir: &Path,
+    state: &ReviewState,
+    workstreams: &WorkstreamsFile,
+) -> Result<()> {
+    let path = run_dir.join("packet.md");
+    if !path.exists() {
+        return Ok(());
+    }
+    let packet =
+        std::fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+    let body = strip_review_status(&packet);
+    std::fs::write(
+        &path,
+        format!("{}{body}", render_review_status(state, workstreams)),
+    )
+    .with_context(|| format!("write {}", path.display()))
+}
+
+fn strip_review_status(packet: &str) -> &str {
+    let Some(rest) = packet.strip_prefix(REVIEW_STATUS_HEADING) else {
+        return packet;
+    };
+    match rest.find("\n# ") {
+        Some(end) => &rest[end + 1..],
+        None => "",
+    }
+}
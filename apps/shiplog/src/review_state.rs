@@ -0,0 +1,190 @@
+//! Sign-off and checklist state for preparing a run's packet.
+//!
+//! Packet prep often spans several sessions. `review.state.json` records which
+//! workstreams have their claim scaffolds filled and receipts verified, and
+//! which share profiles were approved, so the next session starts where the
+//! last one stopped. The internal packet leads with a status banner built
+//! from it.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::workstream::WorkstreamsFile;
+
+/// Run-level file holding the review checklist.
+pub const REVIEW_STATE_FILENAME: &str = "review.state.json";
+
+/// Current `review.state.json` schema version.
+pub const REVIEW_STATE_SCHEMA_VERSION: u8 = 1;
+
+/// Checklist item tracked per workstream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReviewCheck {
+    /// The workstream's claim scaffold has been written up.
+    ClaimsFilled,
+    /// The workstream's receipts have been checked against their sources.
+    ReceiptsVerified,
+}
+
+impl ReviewCheck {
+    /// Human label.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ClaimsFilled => "Claim scaffolds filled",
+            Self::ReceiptsVerified => "Receipts verified",
+        }
+    }
+}
+
+/// Checklist marks for one workstream, keyed by workstream id in
+/// [`ReviewState::workstreams`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkstreamReview {
+    /// When the claim scaffold was marked filled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claims_filled_at: Option<DateTime<Utc>>,
+    /// When the receipts were marked verified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipts_verified_at: Option<DateTime<Utc>>,
+}
+
+impl WorkstreamReview {
+    fn slot(&mut self, check: ReviewCheck) -> &mut Option<DateTime<Utc>> {
+        match check {
+            ReviewCheck::ClaimsFilled => &mut self.claims_filled_at,
+            ReviewCheck::ReceiptsVerified => &mut self.receipts_verified_at,
+        }
+    }
+
+    /// Whether `check` is marked.
+    pub fn is_done(&self, check: ReviewCheck) -> bool {
+        match check {
+            ReviewCheck::ClaimsFilled => self.claims_filled_at.is_some(),
+            ReviewCheck::ReceiptsVerified => self.receipts_verified_at.is_some(),
+        }
+    }
+}
+
+/// Contents of `review.state.json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewState {
+    /// Schema version, [`REVIEW_STATE_SCHEMA_VERSION`] when written.
+    pub schema_version: u8,
+    /// Checklist marks by workstream id.
+    #[serde(default)]
+    pub workstreams: BTreeMap<String, WorkstreamReview>,
+    /// Share profiles (`manager`, `public`) approved for sharing, with when.
+    #[serde(default)]
+    pub approved_profiles: BTreeMap<String, DateTime<Utc>>,
+}
+
+impl Default for ReviewState {
+    fn default() -> Self {
+        Self {
+            schema_version: REVIEW_STATE_SCHEMA_VERSION,
+            workstreams: BTreeMap::new(),
+            approved_profiles: BTreeMap::new(),
+        }
+    }
+}
+
+impl ReviewState {
+    /// Read a run's review state. `None` when the run has no state file yet.
+    pub fn load(run_dir: &Path) -> Result<Option<Self>> {
+        let path = run_dir.join(REVIEW_STATE_FILENAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text =
+            std::fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+        let state: Self =
+            serde_json::from_str(&text).with_context(|| format!("parse {}", path.display()))?;
+        if state.schema_version > REVIEW_STATE_SCHEMA_VERSION {
+            anyhow::bail!(
+                "{} uses review state schema {}; this shiplog reads up to {}",
+                path.display(),
+                state.schema_version,
+                REVIEW_STATE_SCHEMA_VERSION
+            );
+        }
+        Ok(Some(state))
+    }
+
+    /// Write the state to the run directory.
+    pub fn save(&self, run_dir: &Path) -> Result<()> {
+        let path = run_dir.join(REVIEW_STATE_FILENAME);
+        let mut text = serde_json::to_string_pretty(self).context("serialize review state")?;
+        text.push('\n');
+        std::fs::write(&path, text).with_context(|| format!("write {}", path.display()))
+    }
+
+    /// Mark or clear `check` for a workstream. Returns whether anything changed;
+    /// re-marking keeps the original time.
+    pub fn set_check(
+        &mut self,
+        workstream_id: &str,
+        check: ReviewCheck,
+        done: bool,
+        at: DateTime<Utc>,
+    ) -> bool {
+        let review = self
+            .workstreams
+            .entry(workstream_id.to_string())
+            .or_default();
+        let slot = review.slot(check);
+        let changed = slot.is_some() != done;
+        if changed {
+            *slot = done.then_some(at);
+        }
+        if *review == WorkstreamReview::default() {
+            self.workstreams.remove(workstream_id);
+        }
+        changed
+    }
+
+    /// Whether `check` is marked for a workstream.
+    pub fn is_done(&self, workstream_id: &str, check: ReviewCheck) -> bool {
+        self.workstreams
+            .get(workstream_id)
+            .is_some_and(|review| review.is_done(check))
+    }
+
+    /// Titles of the workstreams in `workstreams` that still lack `check`.
+    pub fn open_workstreams<'a>(
+        &self,
+        workstreams: &'a WorkstreamsFile,
+        check: ReviewCheck,
+    ) -> Vec<&'a str> {
+        workstreams
+            .workstreams
+            .iter()
+            .filter(|ws| !self.is_done(&ws.id.to_string(), check))
+            .map(|ws| ws.title.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn set_check_keeps_first_mark_and_drops_empty_entries() {
+        let first = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let later = Utc.with_ymd_and_hms(2026, 3, 3, 9, 0, 0).unwrap();
+        let mut state = ReviewState::default();
+
+        assert!(state.set_check("ws-1", ReviewCheck::ClaimsFilled, true, first));
+        assert!(!state.set_check("ws-1", ReviewCheck::ClaimsFilled, true, later));
+        assert_eq!(state.workstreams["ws-1"].claims_filled_at, Some(first));
+        assert!(!state.is_done("ws-1", ReviewCheck::ReceiptsVerified));
+
+        assert!(state.set_check("ws-1", ReviewCheck::ClaimsFilled, false, later));
+        assert!(state.workstreams.is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+//! `shiplog assess`: compare a run against a career-ladder rubric.
+
+use anyhow::{Context, Result};
+use shiplog::assess::{Rubric, assess_rubric};
+use shiplog::render::md::render_rubric_gaps;
+
+use crate::*;
+
+pub(super) fn run_assess(args: AssessArgs) -> Result<()> {
+    if !args.rubric.exists() {
+        anyhow::bail!(
+            "rubric not found: {}. Start from examples/configs/rubric.yaml.",
+            display_path_for_cli(&args.rubric)
+        );
+    }
+    let rubric = Rubric::load(&args.rubric)?;
+    let level = rubric.level(args.level.as_deref())?;
+    let run_dir = resolve_render_run_dir(&args.out, args.run, args.latest)?;
+    let ingest =
+        load_run_ingest(&run_dir).with_context(|| format!("load run {}", run_dir.display()))?;
+    let (workstreams, _, _) = load_effective_workstreams_for_run(&run_dir)?;
+
+    let assessment = assess_rubric(&rubric, level, &workstreams, &ingest.events)?;
+    let section = render_rubric_gaps(&assessment);
+    match args.output {
+        Some(path) => {
+            std::fs::write(&path, &section).with_context(|| format!("write {}", path.display()))?;
+            println!("Wrote rubric gap analysis: {}", display_path_for_cli(&path));
+        }
+        None => print!("{section}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,229 @@
+//! Index of the runs under an output root.
+//!
+//! Run directories are named by run ID, which says little about what a run
+//! covered or how far its curation got. `runs.index.json` sits next to the
+//! run directories and records each run's window, sources, event counts, and
+//! readiness, so `shiplog history` can list and find runs without loading
+//! every ledger. The engine updates the entry for a run each time it writes
+//! one.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::readiness::{ReadinessGrade, assess_readiness};
+use crate::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
+use crate::schema::event::EventEnvelope;
+use crate::schema::workstream::WorkstreamsFile;
+
+/// Output-root file holding the run index.
+pub const RUN_INDEX_FILENAME: &str = "runs.index.json";
+
+/// Current `runs.index.json` schema version.
+pub const RUN_INDEX_SCHEMA_VERSION: u8 = 1;
+
+/// Index entry for one run directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunIndexEntry {
+    /// Run ID from the coverage manifest.
+    pub run_id: String,
+    /// Directory name of the run under the output root.
+    pub dir: String,
+    /// When the run was first generated.
+    pub generated_at: DateTime<Utc>,
+    /// When the run's outputs were last written.
+    pub updated_at: DateTime<Utc>,
+    /// User the run targeted.
+    pub user: String,
+    /// Collection window.
+    pub window: TimeWindow,
+    /// Sources queried.
+    pub sources: Vec<String>,
+    /// Events in the ledger.
+    pub event_count: usize,
+    /// Events per source system.
+    pub events_by_source: BTreeMap<String, usize>,
+    /// Workstreams in the effective workstreams file.
+    pub workstream_count: usize,
+    /// Whether the run has a curated `workstreams.yaml`.
+    pub curated: bool,
+    /// Coverage completeness verdict.
+    pub completeness: Completeness,
+    /// Packet readiness score, 0 to 100.
+    pub readiness_score: u8,
+    /// Letter grade for `readiness_score`.
+    pub readiness_grade: ReadinessGrade,
+}
+
+impl RunIndexEntry {
+    /// Build the entry for a run from its written outputs.
+    pub fn new(
+        run_dir: &Path,
+        events: &[EventEnvelope],
+        workstreams: &WorkstreamsFile,
+        coverage: &CoverageManifest,
+        curated: bool,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        let mut events_by_source = BTreeMap::new();
+        for event in events {
+            *events_by_source
+                .entry(event.source.system.as_str().to_string())
+                .or_insert(0) += 1;
+        }
+        let readiness = assess_readiness(workstreams, events, coverage);
+        Self {
+            run_id: coverage.run_id.to_string(),
+            dir: run_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            generated_at: coverage.generated_at,
+            updated_at,
+            user: coverage.user.clone(),
+            window: coverage.window.clone(),
+            sources: coverage.sources.clone(),
+            event_count: events.len(),
+            events_by_source,
+            workstream_count: workstreams.workstreams.len(),
+            curated,
+            completeness: coverage.completeness.clone(),
+            readiness_score: readiness.score,
+            readiness_grade: readiness.grade,
+        }
+    }
+}
+
+/// Contents of `runs.index.json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunIndex {
+    /// Schema version, [`RUN_INDEX_SCHEMA_VERSION`] when written.
+    pub schema_version: u8,
+    /// Indexed runs, most recently updated first.
+    #[serde(default)]
+    pub runs: Vec<RunIndexEntry>,
+}
+
+impl Default for RunIndex {
+    fn default() -> Self {
+        Self {
+            schema_version: RUN_INDEX_SCHEMA_VERSION,
+            runs: Vec::new(),
+        }
+    }
+}
+
+impl RunIndex {
+    /// Read the index under an output root. `None` when there is none yet.
+    pub fn load(out_root: &Path) -> Result<Option<Self>> {
+        let path = out_root.join(RUN_INDEX_FILENAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text =
+            std::fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+        let index: Self =
+            serde_json::from_str(&text).with_context(|| format!("parse {}", path.display()))?;
+        if index.schema_version > RUN_INDEX_SCHEMA_VERSION {
+            anyhow::bail!(
+                "{} uses run index schema {}; this shiplog reads up to {}",
+                path.display(),
+                index.schema_version,
+                RUN_INDEX_SCHEMA_VERSION
+            );
+        }
+        Ok(Some(index))
+    }
+
+    /// Write the index to the output root.
+    pub fn save(&self, out_root: &Path) -> Result<()> {
+        let path = out_root.join(RUN_INDEX_FILENAME);
+        let mut text = serde_json::to_string_pretty(self).context("serialize run index")?;
+        text.push('\n');
+        std::fs::write(&path, text).with_context(|| format!("write {}", path.display()))
+    }
+
+    /// Add or replace the entry for `entry.dir`, keeping the newest first.
+    pub fn upsert(&mut self, entry: RunIndexEntry) {
+        self.runs.retain(|run| run.dir != entry.dir);
+        self.runs.push(entry);
+        self.runs
+            .sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.dir.cmp(&b.dir)));
+    }
+
+    /// Drop entries whose run directory no longer exists. Returns how many
+    /// were dropped.
+    pub fn retain_existing(&mut self, out_root: &Path) -> usize {
+        let before = self.runs.len();
+        self.runs.retain(|run| out_root.join(&run.dir).is_dir());
+        before - self.runs.len()
+    }
+}
+
+/// Record a freshly written run in the index of its output root, the run
+/// directory's parent, and in the output root's
+/// [history database](crate::history_db).
+pub fn record_run(
+    run_dir: &Path,
+    events: &[EventEnvelope],
+    workstreams: &WorkstreamsFile,
+    coverage: &CoverageManifest,
+    curated: bool,
+) -> Result<()> {
+    let Some(out_root) = run_dir.parent().filter(|root| root.is_dir()) else {
+        return Ok(());
+    };
+    let mut index = RunIndex::load(out_root)?.unwrap_or_default();
+    index.retain_existing(out_root);
+    let entry = RunIndexEntry::new(run_dir, events, workstreams, coverage, curated, Utc::now());
+    index.upsert(entry.clone());
+    index.save(out_root)?;
+    crate::history_db::record_run(run_dir, &entry, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn entry(dir: &str, updated_day: u32) -> RunIndexEntry {
+        RunIndexEntry {
+            run_id: dir.to_string(),
+            dir: dir.to_string(),
+            generated_at: Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap(),
+            updated_at: Utc
+                .with_ymd_and_hms(2026, 10, updated_day, 9, 0, 0)
+                .unwrap(),
+            user: "octo".to_string(),
+            window: TimeWindow {
+                since: NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+                until: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            },
+            sources: vec!["github".to_string()],
+            event_count: 3,
+            events_by_source: BTreeMap::from([("github".to_string(), 3)]),
+            workstream_count: 1,
+            curated: false,
+            completeness: Completeness::Complete,
+            readiness_score: 40,
+            readiness_grade: ReadinessGrade::D,
+        }
+    }
+
+    #[test]
+    fn upsert_replaces_by_dir_and_orders_newest_first() {
+        let mut index = RunIndex::default();
+        index.upsert(entry("run_a", 2));
+        index.upsert(entry("run_b", 3));
+        let mut curated = entry("run_a", 5);
+        curated.curated = true;
+        index.upsert(curated);
+
+        let dirs: Vec<&str> = index.runs.iter().map(|run| run.dir.as_str()).collect();
+        assert_eq!(dirs, ["run_a", "run_b"]);
+        assert!(index.runs[0].curated);
+    }
+}
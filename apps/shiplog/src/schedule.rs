@@ -0,0 +1,410 @@
+//! Cron schedules for unattended collection.
+//!
+//! `[defaults] schedule` takes a standard five-field cron expression
+//! (`minute hour day-of-month month day-of-week`). `shiplog daemon` sleeps
+//! until each [`Schedule::next_after`] time, and `shiplog schedule install`
+//! turns the same expression into systemd `OnCalendar=` lines or launchd
+//! `StartCalendarInterval` entries, so every runner fires at the same times.
+//!
+//! Fields accept `*`, numbers, `a-b` ranges, `,` lists, and `/n` steps.
+//! Months and weekdays also accept three-letter names (`JAN`, `MON`), and
+//! weekday `7` means Sunday. As in cron, when both day-of-month and
+//! day-of-week are restricted a day matching either one fires.
+
+use std::fmt;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc};
+
+use crate::coverage::ReportingTimezone;
+
+/// How far ahead [`Schedule::next_after`] searches before giving up, which
+/// only happens for dates that never exist (such as `0 0 31 2 *`).
+const SEARCH_DAYS: i64 = 366 * 8;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+const SYSTEMD_WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// A parsed five-field cron expression.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use shiplog::coverage::ReportingTimezone;
+/// use shiplog::schedule::Schedule;
+///
+/// let schedule: Schedule = "0 9 * * MON".parse().unwrap();
+/// let friday = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+/// let next = schedule.next_after(&friday, &ReportingTimezone::UTC).unwrap();
+/// assert_eq!(next, Utc.with_ymd_and_hms(2026, 10, 19, 9, 0, 0).unwrap());
+/// assert_eq!(schedule.systemd_on_calendar(), ["Mon *-*-* 09:00:00"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    expression: String,
+    minutes: Field,
+    hours: Field,
+    days: Field,
+    months: Field,
+    weekdays: Field,
+}
+
+/// Allowed values for one cron field, and whether it was `*`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    values: Vec<u32>,
+    any: bool,
+}
+
+impl Field {
+    fn contains(&self, value: u32) -> bool {
+        self.values.binary_search(&value).is_ok()
+    }
+
+    fn parse(text: &str, name: &str, min: u32, max: u32, names: &[&str]) -> Result<Self> {
+        let mut values = Vec::new();
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => {
+                    let step: u32 =
+                        step.parse().ok().filter(|step| *step > 0).ok_or_else(|| {
+                            anyhow!("{name} step `{step}` must be a positive number")
+                        })?;
+                    (range, step)
+                }
+                None => (part, 1),
+            };
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (
+                    field_value(start, name, min, max, names)?,
+                    field_value(end, name, min, max, names)?,
+                )
+            } else {
+                let value = field_value(range, name, min, max, names)?;
+                // `5/15` means every 15 starting at 5, as in Vixie cron.
+                (value, if part.contains('/') { max } else { value })
+            };
+            if start > end {
+                bail!("{name} range `{range}` runs backwards");
+            }
+            values.extend((start..=end).step_by(step as usize));
+        }
+        values.sort_unstable();
+        values.dedup();
+        Ok(Self {
+            values,
+            any: text == "*",
+        })
+    }
+}
+
+fn field_value(text: &str, name: &str, min: u32, max: u32, names: &[&str]) -> Result<u32> {
+    let value = match names
+        .iter()
+        .position(|candidate| candidate.eq_ignore_ascii_case(text))
+    {
+        Some(index) => index as u32 + min,
+        None => text
+            .parse()
+            .with_context(|| format!("{name} value `{text}` is not a number"))?,
+    };
+    if !(min..=max).contains(&value) {
+        bail!("{name} value `{text}` is outside {min}-{max}");
+    }
+    Ok(value)
+}
+
+impl FromStr for Schedule {
+    type Err = anyhow::Error;
+
+    fn from_str(expression: &str) -> Result<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            bail!(
+                "schedule `{expression}` needs five fields: minute hour day-of-month month day-of-week"
+            );
+        };
+        let mut weekdays = Field::parse(weekday, "day-of-week", 0, 7, &WEEKDAY_NAMES)?;
+        // Both 0 and 7 are Sunday.
+        if weekdays.contains(7) {
+            weekdays.values.retain(|value| *value != 7);
+            if !weekdays.contains(0) {
+                weekdays.values.insert(0, 0);
+            }
+        }
+        Ok(Self {
+            expression: fields.join(" "),
+            minutes: Field::parse(minute, "minute", 0, 59, &[])?,
+            hours: Field::parse(hour, "hour", 0, 23, &[])?,
+            days: Field::parse(day, "day-of-month", 1, 31, &[])?,
+            months: Field::parse(month, "month", 1, 12, &MONTH_NAMES)?,
+            weekdays,
+        })
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+impl Schedule {
+    /// Returns `true` when the schedule fires on `date`.
+    fn fires_on(&self, date: NaiveDate) -> bool {
+        if !self.months.contains(date.month()) {
+            return false;
+        }
+        let day = self.days.contains(date.day());
+        let weekday = self
+            .weekdays
+            .contains(date.weekday().num_days_from_sunday());
+        match (self.days.any, self.weekdays.any) {
+            (false, false) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+
+    /// First time strictly after `after` that the schedule fires, reading the
+    /// fields as wall-clock time in `timezone`.
+    ///
+    /// Times skipped by a DST change do not fire; times repeated by one fire
+    /// once, at the first occurrence.
+    pub fn next_after(
+        &self,
+        after: &DateTime<Utc>,
+        timezone: &ReportingTimezone,
+    ) -> Option<DateTime<Utc>> {
+        let start = timezone.local_date(after);
+        (0..SEARCH_DAYS)
+            .filter_map(|offset| start.checked_add_days(chrono::Days::new(offset as u64)))
+            .filter(|date| self.fires_on(*date))
+            .find_map(|date| {
+                self.hours.values.iter().find_map(|hour| {
+                    self.minutes.values.iter().find_map(|minute| {
+                        let time = NaiveTime::from_hms_opt(*hour, *minute, 0)?;
+                        timezone
+                            .resolve_local(date.and_time(time))
+                            .filter(|instant| instant > after)
+                    })
+                })
+            })
+    }
+
+    /// The next `count` fire times after `after`.
+    pub fn upcoming(
+        &self,
+        after: &DateTime<Utc>,
+        timezone: &ReportingTimezone,
+        count: usize,
+    ) -> Vec<DateTime<Utc>> {
+        let mut times = Vec::with_capacity(count);
+        let mut cursor = *after;
+        while times.len() < count {
+            let Some(next) = self.next_after(&cursor, timezone) else {
+                break;
+            };
+            times.push(next);
+            cursor = next;
+        }
+        times
+    }
+
+    /// systemd `OnCalendar=` values for this schedule.
+    ///
+    /// cron fires on days matching either a restricted day-of-month or a
+    /// restricted day-of-week, while systemd requires both, so that case
+    /// becomes two lines.
+    pub fn systemd_on_calendar(&self) -> Vec<String> {
+        let time = format!(
+            "{}:{}:00",
+            systemd_list(&self.hours),
+            systemd_list(&self.minutes)
+        );
+        let months = if self.months.any {
+            "*".to_string()
+        } else {
+            systemd_list(&self.months)
+        };
+        let weekdays = self
+            .weekdays
+            .values
+            .iter()
+            .map(|day| SYSTEMD_WEEKDAYS[*day as usize])
+            .collect::<Vec<_>>()
+            .join(",");
+        let on_days = format!("*-{months}-{} {time}", systemd_list(&self.days));
+        let on_weekdays = format!("{weekdays} *-{months}-* {time}");
+        match (self.days.any, self.weekdays.any) {
+            (true, true) => vec![format!("*-{months}-* {time}")],
+            (false, true) => vec![on_days],
+            (true, false) => vec![on_weekdays],
+            (false, false) => vec![on_days, on_weekdays],
+        }
+    }
+
+    /// launchd `StartCalendarInterval` entries for this schedule, as
+    /// `(key, value)` pairs per entry. Fields left as `*` are omitted, which
+    /// launchd reads as "every".
+    pub fn launchd_intervals(&self) -> Vec<Vec<(&'static str, u32)>> {
+        let mut entries = vec![Vec::new()];
+        entries = expand_interval(entries, "Month", &self.months);
+        entries = expand_interval(entries, "Hour", &self.hours);
+        entries = expand_interval(entries, "Minute", &self.minutes);
+        match (self.days.any, self.weekdays.any) {
+            (true, true) => entries,
+            (false, true) => expand_interval(entries, "Day", &self.days),
+            (true, false) => expand_interval(entries, "Weekday", &self.weekdays),
+            (false, false) => {
+                let mut both = expand_interval(entries.clone(), "Day", &self.days);
+                both.extend(expand_interval(entries, "Weekday", &self.weekdays));
+                both
+            }
+        }
+    }
+}
+
+/// One copy of each entry per value of a restricted `field`.
+fn expand_interval(
+    entries: Vec<Vec<(&'static str, u32)>>,
+    key: &'static str,
+    field: &Field,
+) -> Vec<Vec<(&'static str, u32)>> {
+    if field.any {
+        return entries;
+    }
+    entries
+        .into_iter()
+        .flat_map(|entry| {
+            field.values.iter().map(move |value| {
+                let mut entry = entry.clone();
+                entry.push((key, *value));
+                entry
+            })
+        })
+        .collect()
+}
+
+/// systemd user units that run `command` on `schedule`: a oneshot
+/// `.service` and the `.timer` that starts it.
+///
+/// `OnCalendar=` carries the timezone name so the timer fires at the same
+/// instants as `shiplog daemon`, whatever the machine's local timezone.
+/// `Persistent=true` catches up on a run missed while the machine was off.
+pub fn systemd_units(
+    schedule: &Schedule,
+    timezone: &ReportingTimezone,
+    command: &[String],
+    working_dir: &Path,
+) -> (String, String) {
+    let exec = command
+        .iter()
+        .map(|arg| systemd_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let service = format!(
+        "[Unit]\n\
+         Description=shiplog scheduled collection\n\
+         \n\
+         [Service]\n\
+         Type=oneshot\n\
+         WorkingDirectory={}\n\
+         ExecStart={exec}\n",
+        systemd_quote(&working_dir.display().to_string()),
+    );
+    let mut timer = format!(
+        "[Unit]\n\
+         Description=shiplog collection schedule ({schedule})\n\
+         \n\
+         [Timer]\n"
+    );
+    for calendar in schedule.systemd_on_calendar() {
+        let _ = writeln!(timer, "OnCalendar={calendar} {}", timezone.name());
+    }
+    timer.push_str("Persistent=true\n\n[Install]\nWantedBy=timers.target\n");
+    (service, timer)
+}
+
+/// A launchd agent plist labelled `label` that runs `command` on `schedule`.
+///
+/// launchd reads calendar intervals in the Mac's local timezone.
+pub fn launchd_plist(
+    schedule: &Schedule,
+    label: &str,
+    command: &[String],
+    working_dir: &Path,
+) -> String {
+    let mut plist = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\">\n\
+         <dict>\n",
+    );
+    let _ = writeln!(
+        plist,
+        "  <key>Label</key>\n  <string>{}</string>",
+        xml_escape(label)
+    );
+    plist.push_str("  <key>ProgramArguments</key>\n  <array>\n");
+    for arg in command {
+        let _ = writeln!(plist, "    <string>{}</string>", xml_escape(arg));
+    }
+    plist.push_str("  </array>\n");
+    let _ = writeln!(
+        plist,
+        "  <key>WorkingDirectory</key>\n  <string>{}</string>",
+        xml_escape(&working_dir.display().to_string())
+    );
+    plist.push_str("  <key>StartCalendarInterval</key>\n  <array>\n");
+    for entry in schedule.launchd_intervals() {
+        plist.push_str("    <dict>\n");
+        for (key, value) in entry {
+            let _ = writeln!(
+                plist,
+                "      <key>{key}</key>\n      <integer>{value}</integer>"
+            );
+        }
+        plist.push_str("    </dict>\n");
+    }
+    plist.push_str("  </array>\n</dict>\n</plist>\n");
+    plist
+}
+
+/// Quote a systemd command-line word when it holds whitespace or quotes, and
+/// escape `%` so it is not read as a unit specifier.
+fn systemd_quote(arg: &str) -> String {
+    let arg = arg.replace('%', "%%");
+    if arg.is_empty() || arg.contains(|ch: char| ch.is_whitespace() || matches!(ch, '"' | '\\')) {
+        format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        arg
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Comma list of zero-padded values, or `*` for every value.
+fn systemd_list(field: &Field) -> String {
+    if field.any {
+        return "*".to_string();
+    }
+    field
+        .values
+        .iter()
+        .map(|value| format!("{value:02}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
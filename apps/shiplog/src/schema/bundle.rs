@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use shiplog::ids::RunId;
+use shiplog::schema::coverage::Completeness;
 use std::fmt;
 use std::str::FromStr;
 
@@ -35,6 +36,10 @@ pub enum BundleProfile {
     Manager,
     /// Fully redacted public packet.
     Public,
+    /// A named profile declared under `[redaction.profiles.<name>]` in
+    /// `shiplog.toml`, such as `skip-level` or `promo-committee`.
+    #[serde(untagged)]
+    Custom(String),
 }
 
 impl BundleProfile {
@@ -54,8 +59,34 @@ impl BundleProfile {
             Self::Internal => "internal",
             Self::Manager => "manager",
             Self::Public => "public",
+            Self::Custom(name) => name,
         }
     }
+
+    /// True for profiles declared in configuration rather than built in.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+}
+
+/// Whether `name` can name a custom bundle profile: lowercase ASCII letters,
+/// digits, `-`, and `_`, starting with a letter. The name becomes a directory
+/// under `profiles/` and part of archive file names.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::bundle::is_custom_profile_name;
+///
+/// assert!(is_custom_profile_name("skip-level"));
+/// assert!(!is_custom_profile_name("../public"));
+/// assert!(!is_custom_profile_name("Skip Level"));
+/// ```
+pub fn is_custom_profile_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
 }
 
 impl fmt::Display for BundleProfile {
@@ -72,8 +103,10 @@ impl FromStr for BundleProfile {
             "internal" => Ok(Self::Internal),
             "manager" => Ok(Self::Manager),
             "public" => Ok(Self::Public),
-            other => Err(format!(
-                "unknown bundle profile: {other:?} (expected internal|manager|public)"
+            _ if is_custom_profile_name(s) => Ok(Self::Custom(s.to_string())),
+            _ => Err(format!(
+                "invalid bundle profile: {s:?} (expected internal|manager|public or a custom \
+                 profile name of lowercase letters, digits, '-', and '_')"
             )),
         }
     }
@@ -91,6 +124,153 @@ pub struct BundleManifest {
     pub profile: BundleProfile,
     /// Checksums for each file in the bundle.
     pub files: Vec<FileChecksum>,
+    /// Configured content selection, when it differs from the profile default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BundleScope>,
+    /// How the bundle was produced, when the writer recorded it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<BundleProvenance>,
+    /// Part index when the bundle was split into several archives.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<BundlePart>,
+}
+
+/// One archive of a bundle split to stay under an attachment size limit.
+///
+/// Every part also carries a copy of `bundle.manifest.json`, so any single
+/// part tells the receiver how many parts to expect and what each holds.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BundlePart {
+    /// 1-based part number.
+    pub index: u32,
+    /// Bundle-relative paths carried by this part.
+    pub files: Vec<String>,
+    /// Total uncompressed size of those files.
+    pub bytes: u64,
+}
+
+/// Attestation of how a bundle was produced and which redaction applied.
+///
+/// Digests are hex SHA-256. `config_sha256` is absent when the run did not
+/// load a `shiplog.toml`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BundleProvenance {
+    /// Producing tool name.
+    pub tool: String,
+    /// Producing tool version.
+    pub tool_version: String,
+    /// Digest of the configuration file the run used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_sha256: Option<String>,
+    /// Sources recorded in the run's coverage manifest.
+    pub sources: Vec<String>,
+    /// Overall coverage completeness of the run.
+    pub coverage_completeness: Completeness,
+    /// Redaction profile applied to the bundled packet.
+    pub redaction_profile: BundleProfile,
+    /// Digest of that profile's structural redaction rules.
+    pub redaction_rules_sha256: String,
+}
+
+/// Detached signature over the exact bytes of `bundle.manifest.json`,
+/// written next to it as `bundle.manifest.sig.json`.
+///
+/// The manifest lists a checksum for every bundled file, so a valid signature
+/// vouches for the whole bundle.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BundleSignature {
+    /// Signature scheme; `ed25519`.
+    pub algorithm: String,
+    /// Hex-encoded public key of the signer.
+    pub public_key: String,
+    /// Hex-encoded signature.
+    pub signature: String,
+}
+
+/// Per-profile override of which run files a bundle contains.
+///
+/// Patterns are run-relative paths with forward slashes. A pattern ending in
+/// `/` matches everything under that directory; any other pattern must match
+/// the whole path. When `include` is empty the profile's default file set is
+/// used; `exclude` is then applied on top. `redaction.aliases.json` and
+/// `bundle.manifest.json` are never bundled regardless of scope.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::bundle::BundleScope;
+///
+/// let scope = BundleScope {
+///     include: vec!["profiles/manager/".into(), "ledger.events.jsonl".into()],
+///     exclude: vec!["profiles/manager/share.manifest.json".into()],
+/// };
+/// assert!(scope.matches_include("profiles/manager/packet.md"));
+/// assert!(scope.matches_exclude("profiles/manager/share.manifest.json"));
+/// assert!(!scope.matches_include("coverage.manifest.json"));
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct BundleScope {
+    /// Paths to bundle instead of the profile default.
+    pub include: Vec<String>,
+    /// Paths removed from the bundle after `include` is applied.
+    pub exclude: Vec<String>,
+}
+
+impl BundleScope {
+    /// Whether `rel_path` matches one of the `include` patterns.
+    pub fn matches_include(&self, rel_path: &str) -> bool {
+        self.include
+            .iter()
+            .any(|pattern| scope_pattern_matches(pattern, rel_path))
+    }
+
+    /// Whether `rel_path` matches one of the `exclude` patterns.
+    pub fn matches_exclude(&self, rel_path: &str) -> bool {
+        self.exclude
+            .iter()
+            .any(|pattern| scope_pattern_matches(pattern, rel_path))
+    }
+}
+
+fn scope_pattern_matches(pattern: &str, rel_path: &str) -> bool {
+    if pattern.ends_with('/') {
+        rel_path.starts_with(pattern)
+    } else {
+        rel_path == pattern
+    }
+}
+
+/// Difference between a previous bundle manifest and the current one.
+///
+/// Written as `bundle.delta.json` inside incremental bundles, which carry only
+/// the `added` and `changed` files.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BundleDelta {
+    /// Run that produced the current bundle.
+    pub run_id: RunId,
+    /// Run recorded in the previous (base) manifest.
+    pub base_run_id: RunId,
+    /// When the delta was computed.
+    pub generated_at: DateTime<Utc>,
+    /// Redaction profile shared by both manifests.
+    #[serde(default)]
+    pub profile: BundleProfile,
+    /// Files absent from the base manifest.
+    pub added: Vec<FileChecksum>,
+    /// Files whose checksum differs from the base manifest.
+    pub changed: Vec<FileChecksum>,
+    /// Paths present in the base manifest but no longer bundled.
+    pub removed: Vec<String>,
+    /// Number of files identical to the base manifest (not shipped).
+    pub unchanged: usize,
+}
+
+impl BundleDelta {
+    /// Whether the current bundle is identical to the base.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -112,9 +292,26 @@ mod tests {
     }
 
     #[test]
-    fn bundle_profile_from_str_unknown() {
-        let res: Result<BundleProfile, _> = "bogus".parse();
-        assert!(res.is_err());
+    fn bundle_profile_from_str_custom_names() {
+        let parsed: BundleProfile = "skip-level".parse().unwrap();
+        assert_eq!(parsed, BundleProfile::Custom("skip-level".into()));
+        assert_eq!(parsed.as_str(), "skip-level");
+        assert!(parsed.is_custom());
+        for bad in ["", "Skip-Level", "../public", "a b", "-x", "x/y"] {
+            let res: Result<BundleProfile, _> = bad.parse();
+            assert!(res.is_err(), "{bad:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn bundle_profile_serializes_custom_names_untagged() {
+        let custom = BundleProfile::Custom("promo-committee".into());
+        let json = serde_json::to_string(&custom).unwrap();
+        assert_eq!(json, r#""promo-committee""#);
+        let back: BundleProfile = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, custom);
+        let manager: BundleProfile = serde_json::from_str(r#""Manager""#).unwrap();
+        assert_eq!(manager, BundleProfile::Manager);
     }
 
     #[test]
@@ -133,6 +330,33 @@ mod tests {
         assert_eq!(manifest.profile, BundleProfile::Internal);
     }
 
+    #[test]
+    fn bundle_manifest_without_scope_omits_field() {
+        let manifest = BundleManifest {
+            run_id: RunId("test-run".into()),
+            generated_at: Utc::now(),
+            profile: BundleProfile::Public,
+            files: vec![],
+            scope: None,
+            provenance: None,
+            parts: Vec::new(),
+        };
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert!(!json.contains("scope"));
+    }
+
+    #[test]
+    fn bundle_scope_directory_patterns_match_prefixes() {
+        let scope = BundleScope {
+            include: vec!["profiles/".into()],
+            exclude: vec!["profiles/public/packet.md".into()],
+        };
+        assert!(scope.matches_include("profiles/manager/packet.md"));
+        assert!(!scope.matches_include("profiles.md"));
+        assert!(scope.matches_exclude("profiles/public/packet.md"));
+        assert!(!scope.matches_exclude("profiles/public/packet.md.bak"));
+    }
+
     #[test]
     fn bundle_profile_as_str_returns_expected_values() {
         assert_eq!(BundleProfile::Internal.as_str(), "internal");
@@ -181,6 +405,9 @@ mod tests {
                     bytes: 200,
                 },
             ],
+            scope: None,
+            provenance: None,
+            parts: Vec::new(),
         };
         let json = serde_json::to_string(&manifest).unwrap();
         let back: BundleManifest = serde_json::from_str(&json).unwrap();
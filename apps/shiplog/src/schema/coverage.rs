@@ -1,6 +1,8 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+pub use super::version::SchemaVersion;
+
 /// A half-open date range `[since, until)` used for query windows.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TimeWindow {
@@ -72,6 +74,96 @@ pub struct CoverageSlice {
     pub incomplete_results: Option<bool>,
     /// Free-text notes about this slice.
     pub notes: Vec<String>,
+    /// Source that ran the query, named as in [`CoverageManifest::sources`].
+    /// Manifests written before slices were attributed omit it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl CoverageSlice {
+    /// Returns `true` if the source flagged the slice incomplete or fewer
+    /// results were fetched than it reported.
+    pub fn is_partial(&self) -> bool {
+        self.incomplete_results.unwrap_or(false) || self.fetched < self.total_count
+    }
+}
+
+/// API requests one source sent during a run and the rate limit the server
+/// last reported.
+///
+/// Read it next to a partial manifest to tell whether the gaps came from the
+/// server's rate limit rather than from the source's own result caps.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::coverage::RateBudget;
+///
+/// let budget = RateBudget {
+///     source: "github".into(),
+///     host: "api.github.com".into(),
+///     requests: 42,
+///     rate_limited: 0,
+///     limit: Some(30),
+///     remaining: Some(0),
+///     reset_at: None,
+/// };
+/// assert!(budget.is_exhausted());
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RateBudget {
+    /// Source that sent the requests, named as in [`CoverageManifest::sources`].
+    pub source: String,
+    /// API host the requests went to.
+    pub host: String,
+    /// Requests sent to the host, retries included. Cache hits are not sent.
+    pub requests: u64,
+    /// Responses that refused a request for rate limiting: `429`, or a `403`
+    /// that carried `Retry-After` or an exhausted `X-RateLimit-Remaining`.
+    #[serde(default)]
+    pub rate_limited: u64,
+    /// Requests allowed per rate-limit window (`X-RateLimit-Limit` or
+    /// `RateLimit-Limit`) when the server reported it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    /// Lowest `X-RateLimit-Remaining` / `RateLimit-Remaining` seen during the
+    /// run. `None` when the server sent neither header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<u64>,
+    /// When the window behind [`RateBudget::remaining`] was due to reset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl RateBudget {
+    /// Returns `true` when the server refused a request for rate limiting or
+    /// reported no requests left.
+    pub fn is_exhausted(&self) -> bool {
+        self.rate_limited > 0 || self.remaining == Some(0)
+    }
+
+    /// Coverage warning for an exhausted budget, or `None` when the source
+    /// stayed within its rate limit.
+    pub fn warning(&self) -> Option<String> {
+        if !self.is_exhausted() {
+            return None;
+        }
+        let mut warning = format!(
+            "{} rate limit on {} was exhausted after {} request(s)",
+            self.source, self.host, self.requests
+        );
+        if self.rate_limited > 0 {
+            warning.push_str(&format!(
+                " ({} rate-limited response(s))",
+                self.rate_limited
+            ));
+        }
+        if let Some(reset_at) = self.reset_at {
+            warning.push_str(&format!("; it resets at {}", reset_at.to_rfc3339()));
+        }
+        warning.push_str("; missing results may be rate-limit induced.");
+        Some(warning)
+    }
 }
 
 /// The coverage manifest for a run.
@@ -84,6 +176,7 @@ pub struct CoverageSlice {
 /// use chrono::{NaiveDate, Utc};
 ///
 /// let manifest = CoverageManifest {
+///     schema_version: SchemaVersion::CURRENT,
 ///     run_id: RunId::now("test"),
 ///     generated_at: Utc::now(),
 ///     user: "octocat".into(),
@@ -96,12 +189,18 @@ pub struct CoverageSlice {
 ///     slices: vec![],
 ///     warnings: vec![],
 ///     completeness: Completeness::Complete,
+///     score: None,
+///     timezone: None,
+///     budget: vec![],
 /// };
 /// assert_eq!(manifest.user, "octocat");
 /// assert_eq!(manifest.completeness, Completeness::Complete);
 /// ```
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CoverageManifest {
+    /// Schema version the manifest was written with.
+    #[serde(default = "SchemaVersion::legacy")]
+    pub schema_version: SchemaVersion,
     /// Unique identifier for the run that produced this manifest.
     pub run_id: shiplog::ids::RunId,
     /// When the manifest was generated.
@@ -110,6 +209,9 @@ pub struct CoverageManifest {
     pub user: String,
     /// Overall time window for the run.
     pub window: TimeWindow,
+    /// IANA timezone the window dates are read in. `None` means UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
     /// "created" or "merged".
     pub mode: String,
     /// Source systems queried (e.g. `["github"]`).
@@ -118,8 +220,16 @@ pub struct CoverageManifest {
     pub slices: Vec<CoverageSlice>,
     /// Human-readable warnings encountered during the run.
     pub warnings: Vec<String>,
+    /// API requests and rate-limit headroom per HTTP source. Empty for runs
+    /// that sent no API requests and for manifests written before it existed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub budget: Vec<RateBudget>,
     /// Overall completeness verdict.
     pub completeness: Completeness,
+    /// 0–100 score of how much of the reported work was fetched, weighted
+    /// by slice window length. `None` when no query slices were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<u8>,
 }
 
 #[cfg(test)]
@@ -188,6 +298,7 @@ mod tests {
             fetched: 10,
             incomplete_results: Some(false),
             notes: vec!["all fetched".into()],
+            source: None,
         };
         let json = serde_json::to_string(&slice).unwrap();
         let back: CoverageSlice = serde_json::from_str(&json).unwrap();
@@ -197,6 +308,7 @@ mod tests {
     #[test]
     fn coverage_manifest_serde_roundtrip() {
         let manifest = CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: shiplog::ids::RunId("test-run".into()),
             generated_at: chrono::Utc::now(),
             user: "testuser".into(),
@@ -210,9 +322,13 @@ mod tests {
                 fetched: 5,
                 incomplete_results: None,
                 notes: vec![],
+                source: None,
             }],
             warnings: vec!["test warning".into()],
             completeness: Completeness::Partial,
+            score: None,
+            timezone: None,
+            budget: vec![],
         };
         let json = serde_json::to_string(&manifest).unwrap();
         let back: CoverageManifest = serde_json::from_str(&json).unwrap();
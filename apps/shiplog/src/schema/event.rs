@@ -1,9 +1,12 @@
 use super::coverage::TimeWindow;
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
-use shiplog::ids::EventId;
+use shiplog::ids::{CanonicalId, EventId, OpaqueId};
+use std::collections::BTreeMap;
 use std::fmt;
 
+pub use super::version::SchemaVersion;
+
 /// Where a record came from.
 ///
 /// This is part of the trust story: a packet is only as good as its provenance.
@@ -156,13 +159,35 @@ impl<'de> Deserialize<'de> for SourceSystem {
 
 /// Provenance reference for an event, linking it back to its source system.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "StoredSourceRef")]
 pub struct SourceRef {
     /// The system that produced this event.
     pub system: SourceSystem,
     /// A stable URL when available. May be stripped during redaction.
     pub url: Option<String>,
-    /// Provider specific opaque id (GitHub node_id, etc.). May be stripped during redaction.
-    pub opaque_id: Option<String>,
+    /// Hashed provider id (GitHub node_id, etc.). May be stripped during redaction.
+    pub opaque_id: Option<OpaqueId>,
+}
+
+/// [`SourceRef`] as written, before a raw id from an older ledger is hashed.
+#[derive(Deserialize)]
+struct StoredSourceRef {
+    system: SourceSystem,
+    url: Option<String>,
+    opaque_id: Option<String>,
+}
+
+impl From<StoredSourceRef> for SourceRef {
+    fn from(stored: StoredSourceRef) -> Self {
+        let opaque_id = stored
+            .opaque_id
+            .map(|value| OpaqueId::from_stored(stored.system.as_str(), &value));
+        Self {
+            system: stored.system,
+            url: stored.url,
+            opaque_id,
+        }
+    }
 }
 
 /// The person or bot that triggered an event.
@@ -174,6 +199,41 @@ pub struct Actor {
     pub id: Option<u64>,
 }
 
+/// How a contributor other than the primary actor took part in an event.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ContributorRole {
+    /// Credited as a co-author (e.g. a `Co-authored-by:` trailer).
+    CoAuthor,
+    /// Reviewed the same change alongside the actor.
+    CoReviewer,
+    /// Paired with the actor on the work.
+    Pair,
+    /// Reviewed the actor's change.
+    Reviewer,
+}
+
+impl fmt::Display for ContributorRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CoAuthor => f.write_str("co-author"),
+            Self::CoReviewer => f.write_str("co-reviewer"),
+            Self::Pair => f.write_str("pair"),
+            Self::Reviewer => f.write_str("reviewer"),
+        }
+    }
+}
+
+/// A person who shared the work of an event with its [`Actor`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Contributor {
+    /// Login handle, or an email address when only that is known.
+    pub login: String,
+    /// Numeric user ID when known.
+    pub id: Option<u64>,
+    /// How this person contributed.
+    pub role: ContributorRole,
+}
+
 /// Visibility level of a repository.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum RepoVisibility {
@@ -224,6 +284,18 @@ pub enum EventKind {
     Review,
     /// A manually-entered event (non-GitHub work).
     Manual,
+    /// An issue opened or closed by the user.
+    Issue,
+    /// A deployment to an environment.
+    Deployment,
+    /// A published (or drafted) release.
+    Release,
+    /// Comments left by the user on one discussion thread.
+    Comment,
+    /// An incident the user responded to.
+    Incident,
+    /// A document or design doc the user wrote or edited.
+    Document,
 }
 
 impl fmt::Display for EventKind {
@@ -232,6 +304,12 @@ impl fmt::Display for EventKind {
             Self::PullRequest => f.write_str("PullRequest"),
             Self::Review => f.write_str("Review"),
             Self::Manual => f.write_str("Manual"),
+            Self::Issue => f.write_str("Issue"),
+            Self::Deployment => f.write_str("Deployment"),
+            Self::Release => f.write_str("Release"),
+            Self::Comment => f.write_str("Comment"),
+            Self::Incident => f.write_str("Incident"),
+            Self::Document => f.write_str("Document"),
         }
     }
 }
@@ -249,6 +327,7 @@ impl fmt::Display for EventKind {
 ///
 /// let ts = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
 /// let ev = EventEnvelope {
+///     schema_version: SchemaVersion::CURRENT,
 ///     id: EventId::from_parts(["github", "pr", "acme/widgets", "1"]),
 ///     kind: EventKind::PullRequest,
 ///     occurred_at: ts,
@@ -277,19 +356,32 @@ impl fmt::Display for EventKind {
 ///         url: None,
 ///         opaque_id: None,
 ///     },
+///     contributors: Vec::new(),
+///     extensions: Default::default(),
+///     canonical_id: None,
 /// };
 /// assert_eq!(ev.kind, EventKind::PullRequest);
 /// ```
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct EventEnvelope {
+    /// Schema version the event was written with.
+    #[serde(default = "SchemaVersion::legacy")]
+    pub schema_version: SchemaVersion,
     /// Deterministic, content-derived event identifier.
     pub id: EventId,
+    /// Cross-source identity of the underlying object, shared by copies of
+    /// the same pull request ingested through different paths.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical_id: Option<CanonicalId>,
     /// Top-level discriminant for the event type.
     pub kind: EventKind,
     /// When the event happened (UTC).
     pub occurred_at: DateTime<Utc>,
     /// Who triggered the event.
     pub actor: Actor,
+    /// Co-authors, co-reviewers, and pairing partners besides `actor`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contributors: Vec<Contributor>,
     /// Repository this event belongs to.
     pub repo: RepoRef,
     /// Type-specific event data.
@@ -300,8 +392,49 @@ pub struct EventEnvelope {
     pub links: Vec<Link>,
     /// Provenance metadata linking back to the source system.
     pub source: SourceRef,
+    /// Source-specific metadata that has no typed home, keyed by a
+    /// namespaced name such as `gitlab.pipeline_id`.
+    ///
+    /// Redaction filters entries by key prefix: see
+    /// [`EXTENSION_PUBLIC_PREFIX`] and [`EXTENSION_INTERNAL_PREFIX`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extensions: BTreeMap<String, serde_json::Value>,
 }
 
+impl EventEnvelope {
+    /// Compute the canonical id from the repository and payload.
+    ///
+    /// Pull requests are keyed by number and reviews by pull number plus
+    /// submission time. Other kinds have no portable source-native key and
+    /// return `None`.
+    pub fn derive_canonical_id(&self) -> Option<CanonicalId> {
+        let key = match &self.payload {
+            EventPayload::PullRequest(pr) => format!("pull/{}", pr.number),
+            EventPayload::Review(review) => format!(
+                "pull/{}/review/{}",
+                review.pull_number,
+                review.submitted_at.timestamp()
+            ),
+            _ => return None,
+        };
+        Some(CanonicalId::from_native(&self.repo.full_name, &key))
+    }
+
+    /// Stamp [`EventEnvelope::canonical_id`] when it can be derived.
+    pub fn with_canonical_id(mut self) -> Self {
+        if self.canonical_id.is_none() {
+            self.canonical_id = self.derive_canonical_id();
+        }
+        self
+    }
+}
+
+/// Extension keys starting with this prefix survive every redaction profile.
+pub const EXTENSION_PUBLIC_PREFIX: &str = "public.";
+
+/// Extension keys starting with this prefix appear only in internal packets.
+pub const EXTENSION_INTERNAL_PREFIX: &str = "internal.";
+
 /// Payload is tagged for forward-compatible evolution.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type", content = "data")]
@@ -312,6 +445,112 @@ pub enum EventPayload {
     Review(ReviewEvent),
     /// Manually-entered non-GitHub event.
     Manual(ManualEvent),
+    /// Issue opened or closed by the user.
+    Issue(IssueEvent),
+    /// Deployment shipped by the user.
+    Deployment(DeploymentEvent),
+    /// Release cut by the user.
+    Release(ReleaseEvent),
+    /// Comments left by the user on a thread.
+    Comment(CommentEvent),
+    /// Incident the user responded to.
+    Incident(IncidentEvent),
+    /// Document written or edited by the user.
+    Document(DocumentEvent),
+}
+
+impl EventPayload {
+    /// Time invested in this event, when the source reports or implies one.
+    ///
+    /// Issues and manual events carry a recorded [`Effort`]. Pull requests
+    /// report the open-to-merge span and incidents their time to resolve,
+    /// both as [`EffortBasis::Elapsed`]. Other kinds have no effort.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::schema::event::*;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let opened = Utc.with_ymd_and_hms(2025, 6, 1, 9, 0, 0).unwrap();
+    /// let payload = EventPayload::PullRequest(PullRequestEvent {
+    ///     number: 1,
+    ///     title: "Fix bug".into(),
+    ///     state: PullRequestState::Merged,
+    ///     created_at: opened,
+    ///     merged_at: Some(Utc.with_ymd_and_hms(2025, 6, 1, 11, 30, 0).unwrap()),
+    ///     additions: None,
+    ///     deletions: None,
+    ///     changed_files: None,
+    ///     touched_paths_hint: vec![],
+    ///     window: None,
+    /// });
+    /// assert_eq!(
+    ///     payload.effort(),
+    ///     Some(Effort { minutes: 150, basis: EffortBasis::Elapsed })
+    /// );
+    /// ```
+    pub fn effort(&self) -> Option<Effort> {
+        match self {
+            Self::PullRequest(pr) => pr
+                .merged_at
+                .map(|merged_at| Effort::elapsed(pr.created_at, merged_at)),
+            Self::Incident(incident) => incident.duration_minutes.map(|minutes| Effort {
+                minutes,
+                basis: EffortBasis::Elapsed,
+            }),
+            Self::Issue(issue) => issue.effort,
+            Self::Manual(manual) => manual.effort,
+            Self::Review(_)
+            | Self::Deployment(_)
+            | Self::Release(_)
+            | Self::Comment(_)
+            | Self::Document(_) => None,
+        }
+    }
+}
+
+/// How an [`Effort`] value was measured.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum EffortBasis {
+    /// Time logged against the work, such as Jira worklogs.
+    Logged,
+    /// Wall-clock span from start to finish, such as PR open-to-merge.
+    Elapsed,
+}
+
+/// Time invested in a piece of work.
+///
+/// Always stored in whole minutes so values from different sources add up.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Effort {
+    /// Time spent, in minutes.
+    pub minutes: u64,
+    /// How the time was measured.
+    pub basis: EffortBasis,
+}
+
+impl Effort {
+    /// Logged effort from a number of seconds, rounded down to whole minutes.
+    pub fn logged_seconds(seconds: u64) -> Self {
+        Self {
+            minutes: seconds / 60,
+            basis: EffortBasis::Logged,
+        }
+    }
+
+    /// Elapsed effort between two instants; zero when `end` precedes `start`.
+    pub fn elapsed(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            minutes: (end - start).num_minutes().max(0) as u64,
+            basis: EffortBasis::Elapsed,
+        }
+    }
+
+    /// Effort in fractional hours.
+    pub fn hours(&self) -> f64 {
+        self.minutes as f64 / 60.0
+    }
 }
 
 /// Lifecycle state of a pull request.
@@ -372,12 +611,393 @@ pub struct ReviewEvent {
     pub pull_title: String,
     /// When the review was submitted.
     pub submitted_at: DateTime<Utc>,
+    /// When the reviewed PR was opened, when the source reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_created_at: Option<DateTime<Utc>>,
+    /// Login of the reviewed PR's author, when the source reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_author: Option<String>,
     /// Review verdict (e.g. `"approved"`, `"changes_requested"`).
     pub state: String,
     /// Coverage window this event was fetched within.
     pub window: Option<TimeWindow>,
 }
 
+/// Lifecycle state of an issue.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum IssueState {
+    /// Still open.
+    Open,
+    /// Closed, whether completed or not planned.
+    Closed,
+    /// State could not be determined.
+    Unknown,
+}
+
+impl fmt::Display for IssueState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open => f.write_str("Open"),
+            Self::Closed => f.write_str("Closed"),
+            Self::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// An issue from a tracker (GitHub, GitLab, Jira, Linear).
+///
+/// Issues keep their own number, state, and labels instead of being folded
+/// into a [`ManualEvent`], so triage and bug-fix work stays countable.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IssueEvent {
+    /// Issue number within the repository or project.
+    pub number: u64,
+    /// Issue title.
+    pub title: String,
+    /// Current lifecycle state.
+    pub state: IssueState,
+    /// Labels attached to the issue.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// When the issue was opened.
+    pub opened_at: DateTime<Utc>,
+    /// When the issue was closed, if applicable.
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Coverage window this event was fetched within.
+    pub window: Option<TimeWindow>,
+    /// Time logged against the issue, when the tracker records it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<Effort>,
+}
+
+/// Outcome of a deployment.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum DeploymentStatus {
+    /// Deployed successfully.
+    Succeeded,
+    /// Deployment failed.
+    Failed,
+    /// Deployed, then rolled back.
+    RolledBack,
+    /// Still running when collected.
+    InProgress,
+    /// Status could not be determined.
+    Unknown,
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Succeeded => f.write_str("Succeeded"),
+            Self::Failed => f.write_str("Failed"),
+            Self::RolledBack => f.write_str("RolledBack"),
+            Self::InProgress => f.write_str("InProgress"),
+            Self::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// A deployment from a CI/CD system.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeploymentEvent {
+    /// Target environment (e.g. `"production"`, `"staging"`).
+    pub environment: String,
+    /// Version, tag, or commit that was deployed.
+    pub version: Option<String>,
+    /// Outcome of the deployment.
+    pub status: DeploymentStatus,
+    /// When the deployment started.
+    pub started_at: DateTime<Utc>,
+    /// When the deployment finished, if it has.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Coverage window this event was fetched within.
+    pub window: Option<TimeWindow>,
+}
+
+/// Publication state of a release.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ReleaseStatus {
+    /// Published as a full release.
+    Published,
+    /// Published as a pre-release.
+    Prerelease,
+    /// Drafted but not published.
+    Draft,
+    /// Status could not be determined.
+    Unknown,
+}
+
+impl fmt::Display for ReleaseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Published => f.write_str("Published"),
+            Self::Prerelease => f.write_str("Prerelease"),
+            Self::Draft => f.write_str("Draft"),
+            Self::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// A release from a forge or release pipeline.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReleaseEvent {
+    /// Version or tag (e.g. `"v1.4.0"`).
+    pub version: String,
+    /// Release name, when it differs from the version.
+    pub name: Option<String>,
+    /// Publication state.
+    pub status: ReleaseStatus,
+    /// When the release was created.
+    pub created_at: DateTime<Utc>,
+    /// When the release was published, if it has been.
+    pub published_at: Option<DateTime<Utc>>,
+    /// Coverage window this event was fetched within.
+    pub window: Option<TimeWindow>,
+}
+
+/// What a comment thread is attached to.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum CommentTarget {
+    /// A pull or merge request conversation.
+    PullRequest,
+    /// An issue thread.
+    Issue,
+    /// A tracker ticket (Jira, Linear).
+    Ticket,
+    /// A document or design doc.
+    Document,
+    /// Anything else.
+    Other,
+}
+
+impl fmt::Display for CommentTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PullRequest => f.write_str("PullRequest"),
+            Self::Issue => f.write_str("Issue"),
+            Self::Ticket => f.write_str("Ticket"),
+            Self::Document => f.write_str("Document"),
+            Self::Other => f.write_str("Other"),
+        }
+    }
+}
+
+/// Comments the user left on one thread, rolled up into a single event.
+///
+/// Collaboration ingestors emit one event per thread (or per thread and day)
+/// with a `count`, rather than one event per comment, so active reviewers do
+/// not drown the packet in receipts.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommentEvent {
+    /// What the thread is attached to.
+    pub target: CommentTarget,
+    /// Number of the PR, issue, or ticket, when it has one.
+    pub target_number: Option<u64>,
+    /// Title of the commented-on item.
+    pub target_title: String,
+    /// Thread context, such as a review thread subject or file path.
+    pub thread: Option<String>,
+    /// Number of comments rolled into this event.
+    pub count: u32,
+    /// When the first comment was posted.
+    pub first_at: DateTime<Utc>,
+    /// When the last comment was posted.
+    pub last_at: DateTime<Utc>,
+    /// Coverage window this event was fetched within.
+    pub window: Option<TimeWindow>,
+}
+
+/// Severity of an incident, highest first.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum IncidentSeverity {
+    /// Critical, customer-facing outage.
+    Sev1,
+    /// Major degradation.
+    Sev2,
+    /// Minor degradation.
+    Sev3,
+    /// Low impact or near miss.
+    Sev4,
+    /// Severity was not recorded.
+    Unknown,
+}
+
+impl fmt::Display for IncidentSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sev1 => f.write_str("Sev1"),
+            Self::Sev2 => f.write_str("Sev2"),
+            Self::Sev3 => f.write_str("Sev3"),
+            Self::Sev4 => f.write_str("Sev4"),
+            Self::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// The part the user played in an incident response.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum IncidentRole {
+    /// Incident commander.
+    Commander,
+    /// Hands-on responder.
+    Responder,
+    /// Owned status updates and stakeholder comms.
+    Communications,
+    /// Kept the timeline and notes.
+    Scribe,
+    /// Role was not recorded.
+    Unknown,
+}
+
+impl fmt::Display for IncidentRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Commander => f.write_str("Commander"),
+            Self::Responder => f.write_str("Responder"),
+            Self::Communications => f.write_str("Communications"),
+            Self::Scribe => f.write_str("Scribe"),
+            Self::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// An incident the user responded to.
+///
+/// Replaces [`ManualEventType::Incident`] manual events, which carried no
+/// severity or timing; see [`IncidentEvent::from_manual`] for the migration.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncidentEvent {
+    /// Incident title.
+    pub title: String,
+    /// Incident severity.
+    pub severity: IncidentSeverity,
+    /// The user's role in the response.
+    pub role: IncidentRole,
+    /// When the incident was detected.
+    pub detected_at: DateTime<Utc>,
+    /// When the incident was resolved, if it has been.
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Time to resolve in minutes, when known.
+    pub duration_minutes: Option<u64>,
+    /// What happened and how it was handled.
+    pub summary: Option<String>,
+    /// Customer-facing impact statement.
+    pub customer_impact: Option<String>,
+    /// Coverage window this event was fetched within.
+    pub window: Option<TimeWindow>,
+}
+
+impl IncidentEvent {
+    /// Convert a manual incident entry into a structured incident.
+    ///
+    /// Manual entries only carry dates, so detection and resolution fall on
+    /// midnight UTC of the start and end dates, and a single-day entry has no
+    /// known resolution time. Severity and role are left `Unknown`. `fallback`
+    /// is used when the entry has no dates at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::schema::event::*;
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    ///
+    /// let manual = ManualEvent {
+    ///     event_type: ManualEventType::Incident,
+    ///     title: "Checkout outage".into(),
+    ///     description: Some("Rolled back the bad config".into()),
+    ///     started_at: NaiveDate::from_ymd_opt(2025, 3, 1),
+    ///     ended_at: NaiveDate::from_ymd_opt(2025, 3, 2),
+    ///     impact: Some("Checkout restored".into()),
+    ///     effort: None,
+    ///     metrics: Vec::new(),
+    ///     evidence: Vec::new(),
+    /// };
+    /// let fallback = Utc.with_ymd_and_hms(2025, 3, 2, 12, 0, 0).unwrap();
+    /// let incident = IncidentEvent::from_manual(&manual, fallback);
+    /// assert_eq!(incident.severity, IncidentSeverity::Unknown);
+    /// assert_eq!(incident.duration_minutes, Some(24 * 60));
+    /// assert_eq!(incident.customer_impact.as_deref(), Some("Checkout restored"));
+    /// ```
+    pub fn from_manual(manual: &ManualEvent, fallback: DateTime<Utc>) -> Self {
+        let midnight = |date: NaiveDate| date.and_time(NaiveTime::MIN).and_utc();
+        let detected_at = manual
+            .started_at
+            .or(manual.ended_at)
+            .map(midnight)
+            .unwrap_or(fallback);
+        let resolved_at = manual
+            .ended_at
+            .map(midnight)
+            .filter(|resolved| *resolved > detected_at);
+        let duration_minutes = resolved_at
+            .and_then(|resolved| u64::try_from((resolved - detected_at).num_minutes()).ok());
+        Self {
+            title: manual.title.clone(),
+            severity: IncidentSeverity::Unknown,
+            role: IncidentRole::Unknown,
+            detected_at,
+            resolved_at,
+            duration_minutes,
+            summary: manual.description.clone(),
+            customer_impact: manual.impact.clone(),
+            window: None,
+        }
+    }
+}
+
+/// Kind of document, as classified by the source system or ingestor.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum DocumentType {
+    /// Design doc or technical proposal.
+    Design,
+    /// Architecture decision record.
+    Adr,
+    /// Request for comments.
+    Rfc,
+    /// Operational runbook or playbook.
+    Runbook,
+    /// Incident postmortem or retrospective.
+    Postmortem,
+    /// Meeting notes or a general page.
+    Note,
+    /// Anything else.
+    Other,
+}
+
+impl fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Design => f.write_str("Design"),
+            Self::Adr => f.write_str("Adr"),
+            Self::Rfc => f.write_str("Rfc"),
+            Self::Runbook => f.write_str("Runbook"),
+            Self::Postmortem => f.write_str("Postmortem"),
+            Self::Note => f.write_str("Note"),
+            Self::Other => f.write_str("Other"),
+        }
+    }
+}
+
+/// A document written or edited by the user.
+///
+/// Emitted by wiki and docs ingestors (Confluence, Notion, Google Drive, ADR
+/// directories) so written work renders apart from incidents and notes.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DocumentEvent {
+    /// Kind of document.
+    pub doc_type: DocumentType,
+    /// Document title.
+    pub title: String,
+    /// System the document lives in (e.g. `"confluence"`, `"notion"`).
+    pub system: String,
+    /// When the user last edited the document.
+    pub last_edited_at: DateTime<Utc>,
+    /// Net words added (negative when the edit removed text), when known.
+    pub word_count_delta: Option<i64>,
+    /// Coverage window this event was fetched within.
+    pub window: Option<TimeWindow>,
+}
+
 /// Types of manual events for non-GitHub work.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum ManualEventType {
@@ -436,6 +1056,118 @@ pub struct ManualEvent {
     pub ended_at: Option<NaiveDate>,
     /// Impact or outcome statement
     pub impact: Option<String>,
+    /// Measured outcomes backing the impact statement
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metrics: Vec<ImpactMetric>,
+    /// Time invested, when the source records it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<Effort>,
+    /// Local files backing the event, copied into the run's `evidence/` directory
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence: Vec<EvidenceFile>,
+}
+
+/// A measured outcome such as a latency drop or an error-rate change.
+///
+/// Numbers are kept apart from the prose `impact` statement so packets can
+/// tabulate them and redaction can treat them separately.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::event::ImpactMetric;
+///
+/// let metric = ImpactMetric {
+///     name: "p95 latency".into(),
+///     value: 180.into(),
+///     unit: Some("ms".into()),
+///     baseline: Some(420.into()),
+/// };
+/// assert_eq!(metric.to_string(), "p95 latency 420ms → 180ms");
+/// assert_eq!(metric.change(), "420ms → 180ms");
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImpactMetric {
+    /// What was measured (e.g. `"p95 latency"`).
+    pub name: String,
+    /// Value after the work.
+    pub value: serde_json::Number,
+    /// Unit appended to values when rendering (e.g. `"ms"`, `"%"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Value before the work, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<serde_json::Number>,
+}
+
+impl ImpactMetric {
+    /// The value with its unit, preceded by `baseline →` when a baseline is known.
+    pub fn change(&self) -> String {
+        let unit = self.unit.as_deref().unwrap_or("");
+        match &self.baseline {
+            Some(baseline) => format!("{baseline}{unit} → {}{unit}", self.value),
+            None => format!("{}{unit}", self.value),
+        }
+    }
+}
+
+impl fmt::Display for ImpactMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.change())
+    }
+}
+
+/// A local file, such as a screenshot or an exported dashboard, kept as a
+/// receipt when there is no URL to link.
+///
+/// In `manual_events.yaml` the path is relative to the YAML file. Collection
+/// checksums the file and copies it into the run's `evidence/` directory, so
+/// ledger events carry the run-relative copy and its SHA-256.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::event::EvidenceFile;
+///
+/// let evidence = EvidenceFile {
+///     path: "evidence/3f2a9c1d0b7e-latency.png".into(),
+///     label: None,
+///     sha256: None,
+/// };
+/// assert_eq!(evidence.display_label(), "latency.png");
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvidenceFile {
+    /// Where the file lives: relative to `manual_events.yaml` in entries,
+    /// relative to the run directory in the ledger.
+    pub path: String,
+    /// Link text shown in the packet; defaults to the file name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Hex SHA-256 of the file contents, recorded at collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+impl EvidenceFile {
+    /// The label, or the file name without the checksum prefix added when
+    /// the file was copied into `evidence/`.
+    pub fn display_label(&self) -> &str {
+        if let Some(label) = &self.label {
+            return label;
+        }
+        let name = self.path.rsplit(['/', '\\']).next().unwrap_or(&self.path);
+        match name.split_once('-') {
+            Some((prefix, rest))
+                if self.path.starts_with("evidence/")
+                    && prefix.len() == 12
+                    && prefix.bytes().all(|byte| byte.is_ascii_hexdigit()) =>
+            {
+                rest
+            }
+            _ => name,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -580,7 +1312,7 @@ mod tests {
         let sr = SourceRef {
             system: SourceSystem::Github,
             url: Some("https://api.github.com/repos/acme/widgets/pulls/1".into()),
-            opaque_id: Some("PR_abc".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "PR_abc")),
         };
         let json = serde_json::to_string(&sr).unwrap();
         let back: SourceRef = serde_json::from_str(&json).unwrap();
@@ -650,6 +1382,7 @@ mod tests {
 
         let ts = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
         let event = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["github", "pr", "acme/widgets", "42"]),
             kind: EventKind::PullRequest,
             occurred_at: ts,
@@ -681,18 +1414,144 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
         let json = serde_json::to_string(&event).unwrap();
         let back: EventEnvelope = serde_json::from_str(&json).unwrap();
         assert_eq!(event, back);
     }
 
+    #[test]
+    fn payload_effort_uses_recorded_or_elapsed_time() {
+        use chrono::TimeZone;
+        let at = |h| Utc.with_ymd_and_hms(2025, 1, 1, h, 0, 0).unwrap();
+
+        let incident = EventPayload::Incident(IncidentEvent {
+            title: "Outage".into(),
+            severity: IncidentSeverity::Sev2,
+            role: IncidentRole::Commander,
+            detected_at: at(1),
+            resolved_at: Some(at(3)),
+            duration_minutes: Some(120),
+            summary: None,
+            customer_impact: None,
+            window: None,
+        });
+        assert_eq!(
+            incident.effort(),
+            Some(Effort {
+                minutes: 120,
+                basis: EffortBasis::Elapsed
+            })
+        );
+
+        let logged = Effort::logged_seconds(5_430);
+        let issue = EventPayload::Issue(IssueEvent {
+            number: 7,
+            title: "Flaky test".into(),
+            state: IssueState::Closed,
+            labels: vec![],
+            opened_at: at(1),
+            closed_at: Some(at(9)),
+            window: None,
+            effort: Some(logged),
+        });
+        assert_eq!(issue.effort().map(|e| e.minutes), Some(90));
+        assert_eq!(logged.hours(), 1.5);
+
+        let review = EventPayload::Review(ReviewEvent {
+            pull_number: 1,
+            pull_title: "t".into(),
+            submitted_at: at(2),
+            state: "approved".into(),
+            window: None,
+            pull_created_at: None,
+            pull_author: None,
+        });
+        assert_eq!(review.effort(), None);
+        assert_eq!(Effort::elapsed(at(5), at(2)).minutes, 0);
+    }
+
+    #[test]
+    fn event_envelope_extensions_are_omitted_when_empty() {
+        let json = r#"{"id":"e1","kind":"Review","occurred_at":"2025-01-01T00:00:00Z",
+            "actor":{"login":"a","id":null},
+            "repo":{"full_name":"o/r","html_url":null,"visibility":"Public"},
+            "payload":{"type":"Review","data":{"pull_number":1,"pull_title":"t",
+            "submitted_at":"2025-01-01T00:00:00Z","state":"approved","window":null}},
+            "tags":[],"links":[],"source":{"system":"github","url":null,"opaque_id":null}}"#;
+        let mut event: EventEnvelope = serde_json::from_str(json).unwrap();
+        assert!(event.extensions.is_empty());
+        assert!(
+            !serde_json::to_string(&event)
+                .unwrap()
+                .contains("extensions")
+        );
+
+        event
+            .extensions
+            .insert("github.check_suite".into(), serde_json::json!({"id": 7}));
+        let back: EventEnvelope =
+            serde_json::from_str(&serde_json::to_string(&event).unwrap()).unwrap();
+        assert_eq!(back.extensions["github.check_suite"]["id"], 7);
+    }
+
+    #[test]
+    fn legacy_raw_opaque_ids_hash_like_a_fresh_ingest() {
+        let legacy: SourceRef =
+            serde_json::from_str(r#"{"system":"jira","url":null,"opaque_id":"10042"}"#).unwrap();
+        assert_eq!(legacy.opaque_id, Some(OpaqueId::from_raw("jira", "10042")));
+
+        let rewritten = serde_json::to_string(&legacy).unwrap();
+        let reread: SourceRef = serde_json::from_str(&rewritten).unwrap();
+        assert_eq!(reread, legacy, "hashed ids are not hashed again");
+    }
+
+    #[test]
+    fn canonical_id_is_derived_for_pull_requests_and_reviews_only() {
+        let json = r#"{"id":"e1","kind":"Review","occurred_at":"2025-01-01T00:00:00Z",
+            "actor":{"login":"a","id":null},
+            "repo":{"full_name":"O/R","html_url":null,"visibility":"Public"},
+            "payload":{"type":"Review","data":{"pull_number":1,"pull_title":"t",
+            "submitted_at":"2025-01-01T00:00:00Z","state":"approved","window":null}},
+            "tags":[],"links":[],"source":{"system":"github","url":null,"opaque_id":null}}"#;
+        let event: EventEnvelope = serde_json::from_str(json).unwrap();
+        assert!(event.canonical_id.is_none());
+        let expected = CanonicalId::from_native("o/r", "pull/1/review/1735689600");
+        assert_eq!(event.derive_canonical_id(), Some(expected.clone()));
+
+        let stamped = event.clone().with_canonical_id();
+        assert_eq!(stamped.canonical_id, Some(expected));
+        assert!(
+            serde_json::to_string(&stamped)
+                .unwrap()
+                .contains(r#""canonical_id":""#)
+        );
+
+        let mut manual = event;
+        manual.payload = EventPayload::Manual(ManualEvent {
+            event_type: ManualEventType::Note,
+            title: "t".into(),
+            description: None,
+            started_at: None,
+            ended_at: None,
+            impact: None,
+            metrics: Vec::new(),
+            effort: None,
+            evidence: Vec::new(),
+        });
+        assert_eq!(manual.derive_canonical_id(), None);
+    }
+
     #[test]
     fn event_envelope_review_serde_roundtrip() {
         use chrono::{TimeZone, Utc};
 
         let ts = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
         let event = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["github", "review", "acme/widgets", "42", "1"]),
             kind: EventKind::Review,
             occurred_at: ts,
@@ -711,6 +1570,8 @@ mod tests {
                 submitted_at: ts,
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             tags: vec![],
             links: vec![],
@@ -719,6 +1580,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
         let json = serde_json::to_string(&event).unwrap();
         let back: EventEnvelope = serde_json::from_str(&json).unwrap();
@@ -731,6 +1595,7 @@ mod tests {
 
         let ts = Utc.with_ymd_and_hms(2025, 3, 15, 10, 0, 0).unwrap();
         let event = EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["manual", "incident-1"]),
             kind: EventKind::Manual,
             occurred_at: ts,
@@ -750,6 +1615,9 @@ mod tests {
                 started_at: Some(NaiveDate::from_ymd_opt(2025, 3, 15).unwrap()),
                 ended_at: Some(NaiveDate::from_ymd_opt(2025, 3, 16).unwrap()),
                 impact: Some("Reduced MTTR".into()),
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec!["incident".into()],
             links: vec![Link {
@@ -761,6 +1629,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         };
         let json = serde_json::to_string(&event).unwrap();
         let back: EventEnvelope = serde_json::from_str(&json).unwrap();
@@ -788,6 +1659,9 @@ mod tests {
                     url: "https://docs/arch".into(),
                 }],
                 impact: Some("Improved service isolation".into()),
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             }],
         };
         let json = serde_json::to_string(&file).unwrap();
@@ -810,6 +1684,9 @@ mod tests {
             tags: vec![],
             receipts: vec![],
             impact: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         };
         let json = serde_json::to_string(&entry).unwrap();
         // The JSON should contain "type" not "event_type"
@@ -840,6 +1717,9 @@ mod tests {
             tags: vec![],
             receipts: vec![],
             impact: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         };
         let json = serde_json::to_string(&entry).unwrap();
         let back: ManualEventEntry = serde_json::from_str(&json).unwrap();
@@ -880,6 +1760,40 @@ pub struct ManualEventEntry {
     pub receipts: Vec<Link>,
     /// Impact statement
     pub impact: Option<String>,
+    /// Measured outcomes (name, value, unit, baseline)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metrics: Vec<ImpactMetric>,
+    /// Local evidence files (screenshots, dashboards, docs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence: Vec<EvidenceFile>,
+    /// Repeat this entry on a schedule instead of recording it once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repeat: Option<ManualRepeat>,
+}
+
+/// How often a recurring manual entry repeats.
+///
+/// The entry's `date` is the first occurrence. A range also bounds the last
+/// one; a single date repeats until the end of the collection window. Each
+/// occurrence inside the window becomes its own single-day event.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ManualRepeat {
+    /// Every 7 days.
+    Weekly,
+    /// Every 14 days.
+    Biweekly,
+    /// The same day each month, or the month's last day when it is shorter.
+    Monthly,
+}
+
+impl fmt::Display for ManualRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Weekly => f.write_str("Weekly"),
+            Self::Biweekly => f.write_str("Biweekly"),
+            Self::Monthly => f.write_str("Monthly"),
+        }
+    }
 }
 
 /// Date specification for manual events — either a single day or a range.
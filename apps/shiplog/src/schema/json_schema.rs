@@ -0,0 +1,1153 @@
+//! JSON Schema documents for shiplog's on-disk formats, plus a validator for
+//! the subset of JSON Schema they use.
+//!
+//! The schemas are written by hand to mirror the serde shape of each type
+//! (including the custom `SourceSystem` and `SchemaVersion` encodings), and
+//! target draft 2020-12 so external tools can consume them. The built-in
+//! validator exists so import paths can turn a serde failure into a list of
+//! field-level violations without pulling in a full schema engine.
+
+use super::version::SchemaVersion;
+use chrono::{DateTime, NaiveDate};
+use regex::Regex;
+use serde_json::{Map, Value, json};
+use std::fmt;
+use std::str::FromStr;
+
+/// JSON Schema dialect every document declares.
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+const CONTRACTS_BASE: &str = "https://github.com/EffortlessMetrics/shiplog/contracts/schemas";
+
+/// A shiplog file format with a published JSON Schema.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SchemaDocument {
+    /// One line of `ledger.events.jsonl`.
+    EventEnvelope,
+    /// `coverage.manifest.json`.
+    CoverageManifest,
+    /// `workstreams.yaml` / `workstreams.suggested.yaml`.
+    WorkstreamsFile,
+    /// `manual_events.yaml`.
+    ManualEventsFile,
+}
+
+impl SchemaDocument {
+    /// Every published document, in a stable order.
+    pub const ALL: [Self; 4] = [
+        Self::EventEnvelope,
+        Self::CoverageManifest,
+        Self::WorkstreamsFile,
+        Self::ManualEventsFile,
+    ];
+
+    /// Short kebab-case name, also used by `shiplog validate --kind`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::EventEnvelope => "event-envelope",
+            Self::CoverageManifest => "coverage-manifest",
+            Self::WorkstreamsFile => "workstreams",
+            Self::ManualEventsFile => "manual-events",
+        }
+    }
+
+    /// File name the schema is published under in `contracts/schemas/`,
+    /// e.g. `event-envelope.v0.3.0.schema.json`.
+    pub fn file_name(&self) -> String {
+        format!("{}.v{}.schema.json", self.name(), SchemaVersion::CURRENT)
+    }
+
+    /// Guess the document type from a file name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::schema::json_schema::SchemaDocument;
+    /// use std::path::Path;
+    ///
+    /// assert_eq!(
+    ///     SchemaDocument::infer(Path::new("out/run_1/ledger.events.jsonl")),
+    ///     Some(SchemaDocument::EventEnvelope)
+    /// );
+    /// assert_eq!(
+    ///     SchemaDocument::infer(Path::new("workstreams.suggested.yaml")),
+    ///     Some(SchemaDocument::WorkstreamsFile)
+    /// );
+    /// assert_eq!(SchemaDocument::infer(Path::new("notes.txt")), None);
+    /// ```
+    pub fn infer(path: &std::path::Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".jsonl") {
+            Some(Self::EventEnvelope)
+        } else if name.starts_with("coverage") && name.ends_with(".json") {
+            Some(Self::CoverageManifest)
+        } else if name.starts_with("workstreams") {
+            Some(Self::WorkstreamsFile)
+        } else if name.starts_with("manual_events") || name.starts_with("manual-events") {
+            Some(Self::ManualEventsFile)
+        } else {
+            None
+        }
+    }
+
+    /// Build the JSON Schema document.
+    pub fn schema(&self) -> Value {
+        let (title, root) = match self {
+            Self::EventEnvelope => ("event envelope", "EventEnvelope"),
+            Self::CoverageManifest => ("coverage manifest", "CoverageManifest"),
+            Self::WorkstreamsFile => ("workstreams file", "WorkstreamsFile"),
+            Self::ManualEventsFile => ("manual events file", "ManualEventsFile"),
+        };
+        let all = definitions();
+        let mut doc = all[root].clone();
+
+        // Publish only the definitions reachable from this document's root.
+        let mut defs = Map::new();
+        let mut pending = references(&doc);
+        while let Some(name) = pending.pop() {
+            if name == root || defs.contains_key(&name) {
+                continue;
+            }
+            let def = all[&name].clone();
+            pending.extend(references(&def));
+            defs.insert(name, def);
+        }
+
+        let obj = doc.as_object_mut().expect("root definition is an object");
+        obj.insert("$schema".into(), json!(DIALECT));
+        obj.insert(
+            "$id".into(),
+            json!(format!("{CONTRACTS_BASE}/{}", self.file_name())),
+        );
+        obj.insert(
+            "title".into(),
+            json!(format!("Shiplog {title} v{}", SchemaVersion::CURRENT)),
+        );
+        obj.insert("$defs".into(), Value::Object(defs));
+        doc
+    }
+
+    /// Validate `value` against this document's schema.
+    ///
+    /// Returns an empty list when the value conforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::schema::json_schema::SchemaDocument;
+    ///
+    /// let value = serde_json::json!({"since": "2025-01-01"});
+    /// let violations = SchemaDocument::CoverageManifest.validate(&value);
+    /// assert!(violations.iter().any(|v| v.message.contains("`run_id`")));
+    /// ```
+    pub fn validate(&self, value: &Value) -> Vec<SchemaViolation> {
+        let schema = self.schema();
+        let mut out = Vec::new();
+        check(&schema, &schema, value, "", &mut out);
+        out
+    }
+}
+
+impl fmt::Display for SchemaDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SchemaDocument {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|doc| doc.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(Self::name).collect();
+                anyhow::anyhow!("unknown schema {s:?}; expected one of {}", names.join(", "))
+            })
+    }
+}
+
+/// One place where a value does not conform to a schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON Pointer to the offending value (`""` for the document root).
+    pub path: String,
+    /// What was expected there.
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        write!(f, "{path}: {}", self.message)
+    }
+}
+
+/// Join violations into one line for error messages, keeping the first few.
+pub fn describe_violations(violations: &[SchemaViolation]) -> String {
+    const SHOWN: usize = 3;
+    let mut text = violations
+        .iter()
+        .take(SHOWN)
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+    if violations.len() > SHOWN {
+        text.push_str(&format!(" (+{} more)", violations.len() - SHOWN));
+    }
+    text
+}
+
+impl SchemaDocument {
+    /// Attach schema violations to a JSON deserialization error.
+    ///
+    /// serde stops at the first problem and reports it by line and column;
+    /// this re-reads `text` as untyped JSON and, when that succeeds, adds the
+    /// field-level violations as context. Syntax errors pass through as-is.
+    pub fn annotate_json(&self, text: &str, err: serde_json::Error) -> anyhow::Error {
+        let value = serde_json::from_str::<Value>(text).ok();
+        self.annotate(value, err.into())
+    }
+
+    /// YAML counterpart of [`SchemaDocument::annotate_json`].
+    pub fn annotate_yaml(&self, text: &str, err: serde_yaml::Error) -> anyhow::Error {
+        let value = serde_yaml::from_str::<Value>(text).ok();
+        self.annotate(value, err.into())
+    }
+
+    fn annotate(&self, value: Option<Value>, err: anyhow::Error) -> anyhow::Error {
+        let violations = value.map(|v| self.validate(&v)).unwrap_or_default();
+        if violations.is_empty() {
+            return err;
+        }
+        err.context(format!(
+            "does not match the {self} schema: {}",
+            describe_violations(&violations)
+        ))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Definitions
+// ---------------------------------------------------------------------------
+
+fn definitions() -> Map<String, Value> {
+    let mut defs = Map::new();
+    let mut def = |name: &str, schema: Value| {
+        defs.insert(name.to_string(), schema);
+    };
+
+    def(
+        "SchemaVersion",
+        json!({"type": "string", "pattern": "^[0-9]+\\.[0-9]+\\.[0-9]+$"}),
+    );
+    def(
+        "TimeWindow",
+        object(
+            &["since", "until"],
+            json!({"since": date(), "until": date()}),
+        ),
+    );
+    def(
+        "SourceSystem",
+        json!({
+            "description": "Lowercase source name such as \"github\"; legacy ledgers may use a single-key object.",
+            "anyOf": [{"type": "string"}, {"type": "object"}]
+        }),
+    );
+    def(
+        "SourceRef",
+        object(
+            &["system"],
+            json!({
+                "system": reference("SourceSystem"),
+                "url": nullable("string"),
+                "opaque_id": nullable("string"),
+            }),
+        ),
+    );
+    def(
+        "Actor",
+        object(
+            &["login"],
+            json!({"login": {"type": "string"}, "id": nullable_count()}),
+        ),
+    );
+    def(
+        "Contributor",
+        object(
+            &["login", "role"],
+            json!({
+                "login": {"type": "string"},
+                "id": nullable_count(),
+                "role": string_enum(&["CoAuthor", "CoReviewer", "Pair", "Reviewer"]),
+            }),
+        ),
+    );
+    def(
+        "RepoVisibility",
+        string_enum(&["Public", "Private", "Unknown"]),
+    );
+    def(
+        "RepoRef",
+        object(
+            &["full_name", "visibility"],
+            json!({
+                "full_name": {"type": "string"},
+                "html_url": nullable("string"),
+                "visibility": reference("RepoVisibility"),
+            }),
+        ),
+    );
+    def(
+        "Link",
+        object(
+            &["label", "url"],
+            json!({"label": {"type": "string"}, "url": {"type": "string"}}),
+        ),
+    );
+    def("EventKind", string_enum(PAYLOADS));
+    def(
+        "EventPayload",
+        json!({
+            "type": "object",
+            "required": ["type", "data"],
+            "properties": {"type": string_enum(PAYLOADS)},
+            "allOf": PAYLOADS.iter().map(|kind| json!({
+                "if": {"properties": {"type": {"const": kind}}},
+                "then": {"properties": {"data": reference(&format!("{kind}Event"))}}
+            })).collect::<Vec<_>>()
+        }),
+    );
+    def(
+        "EventEnvelope",
+        object(
+            &[
+                "id",
+                "kind",
+                "occurred_at",
+                "actor",
+                "repo",
+                "payload",
+                "tags",
+                "links",
+                "source",
+            ],
+            json!({
+                "schema_version": reference("SchemaVersion"),
+                "id": {"type": "string"},
+                "canonical_id": nullable("string"),
+                "kind": reference("EventKind"),
+                "occurred_at": date_time(),
+                "actor": reference("Actor"),
+                "contributors": array_of("Contributor"),
+                "repo": reference("RepoRef"),
+                "payload": reference("EventPayload"),
+                "tags": strings(),
+                "links": array_of("Link"),
+                "source": reference("SourceRef"),
+                "extensions": {"type": "object"},
+            }),
+        ),
+    );
+
+    def(
+        "PullRequestEvent",
+        object(
+            &[
+                "number",
+                "title",
+                "state",
+                "created_at",
+                "touched_paths_hint",
+            ],
+            json!({
+                "number": count(),
+                "title": {"type": "string"},
+                "state": string_enum(&["Open", "Closed", "Merged", "Unknown"]),
+                "created_at": date_time(),
+                "merged_at": nullable_date_time(),
+                "additions": nullable_count(),
+                "deletions": nullable_count(),
+                "changed_files": nullable_count(),
+                "touched_paths_hint": strings(),
+                "window": nullable_window(),
+            }),
+        ),
+    );
+    def(
+        "ReviewEvent",
+        object(
+            &["pull_number", "pull_title", "submitted_at", "state"],
+            json!({
+                "pull_number": count(),
+                "pull_title": {"type": "string"},
+                "submitted_at": date_time(),
+                "pull_created_at": nullable_date_time(),
+                "pull_author": nullable("string"),
+                "state": {"type": "string"},
+                "window": nullable_window(),
+            }),
+        ),
+    );
+    def(
+        "IssueEvent",
+        object(
+            &["number", "title", "state", "opened_at"],
+            json!({
+                "number": count(),
+                "title": {"type": "string"},
+                "state": string_enum(&["Open", "Closed", "Unknown"]),
+                "labels": strings(),
+                "opened_at": date_time(),
+                "closed_at": nullable_date_time(),
+                "window": nullable_window(),
+                "effort": nullable_effort(),
+            }),
+        ),
+    );
+    def(
+        "DeploymentEvent",
+        object(
+            &["environment", "status", "started_at"],
+            json!({
+                "environment": {"type": "string"},
+                "version": nullable("string"),
+                "status": string_enum(&["Succeeded", "Failed", "RolledBack", "InProgress", "Unknown"]),
+                "started_at": date_time(),
+                "finished_at": nullable_date_time(),
+                "window": nullable_window(),
+            }),
+        ),
+    );
+    def(
+        "ReleaseEvent",
+        object(
+            &["version", "status", "created_at"],
+            json!({
+                "version": {"type": "string"},
+                "name": nullable("string"),
+                "status": string_enum(&["Published", "Prerelease", "Draft", "Unknown"]),
+                "created_at": date_time(),
+                "published_at": nullable_date_time(),
+                "window": nullable_window(),
+            }),
+        ),
+    );
+    def(
+        "CommentEvent",
+        object(
+            &["target", "target_title", "count", "first_at", "last_at"],
+            json!({
+                "target": string_enum(&["PullRequest", "Issue", "Ticket", "Document", "Other"]),
+                "target_number": nullable_count(),
+                "target_title": {"type": "string"},
+                "thread": nullable("string"),
+                "count": count(),
+                "first_at": date_time(),
+                "last_at": date_time(),
+                "window": nullable_window(),
+            }),
+        ),
+    );
+    def(
+        "IncidentEvent",
+        object(
+            &["title", "severity", "role", "detected_at"],
+            json!({
+                "title": {"type": "string"},
+                "severity": string_enum(&["Sev1", "Sev2", "Sev3", "Sev4", "Unknown"]),
+                "role": string_enum(&["Commander", "Responder", "Communications", "Scribe", "Unknown"]),
+                "detected_at": date_time(),
+                "resolved_at": nullable_date_time(),
+                "duration_minutes": nullable_count(),
+                "summary": nullable("string"),
+                "customer_impact": nullable("string"),
+                "window": nullable_window(),
+            }),
+        ),
+    );
+    def(
+        "DocumentEvent",
+        object(
+            &["doc_type", "title", "system", "last_edited_at"],
+            json!({
+                "doc_type": string_enum(&["Design", "Adr", "Rfc", "Runbook", "Postmortem", "Note", "Other"]),
+                "title": {"type": "string"},
+                "system": {"type": "string"},
+                "last_edited_at": date_time(),
+                "word_count_delta": nullable("integer"),
+                "window": nullable_window(),
+            }),
+        ),
+    );
+    def("ManualEventType", string_enum(MANUAL_EVENT_TYPES));
+    def(
+        "ManualEvent",
+        object(
+            &["event_type", "title"],
+            json!({
+                "event_type": reference("ManualEventType"),
+                "title": {"type": "string"},
+                "description": nullable("string"),
+                "started_at": nullable_date(),
+                "ended_at": nullable_date(),
+                "impact": nullable("string"),
+                "metrics": array_of("ImpactMetric"),
+                "effort": nullable_effort(),
+                "evidence": array_of("EvidenceFile"),
+            }),
+        ),
+    );
+    def(
+        "EvidenceFile",
+        object(
+            &["path"],
+            json!({
+                "path": {"type": "string"},
+                "label": nullable("string"),
+                "sha256": nullable("string"),
+            }),
+        ),
+    );
+    def(
+        "ImpactMetric",
+        object(
+            &["name", "value"],
+            json!({
+                "name": {"type": "string"},
+                "value": {"type": "number"},
+                "unit": nullable("string"),
+                "baseline": nullable("number"),
+            }),
+        ),
+    );
+    def(
+        "Effort",
+        object(
+            &["minutes", "basis"],
+            json!({
+                "minutes": count(),
+                "basis": string_enum(&["Logged", "Elapsed"]),
+            }),
+        ),
+    );
+
+    def(
+        "CoverageSlice",
+        object(
+            &["window", "query", "total_count", "fetched", "notes"],
+            json!({
+                "window": reference("TimeWindow"),
+                "query": {"type": "string"},
+                "total_count": count(),
+                "fetched": count(),
+                "incomplete_results": nullable("boolean"),
+                "notes": strings(),
+                "source": {"type": "string"},
+            }),
+        ),
+    );
+    def(
+        "RateBudget",
+        object(
+            &["source", "host", "requests"],
+            json!({
+                "source": {"type": "string"},
+                "host": {"type": "string"},
+                "requests": count(),
+                "rate_limited": count(),
+                "limit": nullable_count(),
+                "remaining": nullable_count(),
+                "reset_at": nullable_date_time(),
+            }),
+        ),
+    );
+    def(
+        "CoverageManifest",
+        object(
+            &[
+                "run_id",
+                "generated_at",
+                "user",
+                "window",
+                "mode",
+                "sources",
+                "slices",
+                "warnings",
+                "completeness",
+            ],
+            json!({
+                "schema_version": reference("SchemaVersion"),
+                "run_id": {"type": "string"},
+                "generated_at": date_time(),
+                "user": {"type": "string"},
+                "window": reference("TimeWindow"),
+                "timezone": nullable("string"),
+                "mode": {"type": "string"},
+                "sources": strings(),
+                "slices": array_of("CoverageSlice"),
+                "warnings": strings(),
+                "budget": array_of("RateBudget"),
+                "completeness": string_enum(&["Complete", "Partial", "Unknown"]),
+                "score": {"type": ["integer", "null"], "minimum": 0, "maximum": 100},
+            }),
+        ),
+    );
+
+    def(
+        "WorkstreamStats",
+        object(
+            &["pull_requests", "reviews", "manual_events"],
+            json!({
+                "pull_requests": count(),
+                "reviews": count(),
+                "manual_events": count(),
+                "issues": count(),
+                "deployments": count(),
+                "releases": count(),
+                "comments": count(),
+                "incidents": count(),
+                "documents": count(),
+                "logged_minutes": count(),
+                "elapsed_minutes": count(),
+            }),
+        ),
+    );
+    def(
+        "Workstream",
+        object(
+            &["id", "title", "tags", "stats", "events", "receipts"],
+            json!({
+                "id": {"type": "string"},
+                "title": {"type": "string"},
+                "summary": nullable("string"),
+                "tags": strings(),
+                "stats": reference("WorkstreamStats"),
+                "events": strings(),
+                "receipts": strings(),
+                "receipt_weights": {
+                    "type": "object",
+                    "additionalProperties": string_enum(&["Low", "Medium", "High"]),
+                },
+                "rules": reference("WorkstreamRules"),
+            }),
+        ),
+    );
+    def(
+        "WorkstreamRules",
+        object(
+            &[],
+            json!({
+                "repos": strings(),
+                "tags": strings(),
+                "titles": strings(),
+            }),
+        ),
+    );
+    def(
+        "WorkstreamsFile",
+        object(
+            &["version", "generated_at", "workstreams"],
+            json!({
+                "version": count(),
+                "generated_at": date_time(),
+                "workstreams": array_of("Workstream"),
+            }),
+        ),
+    );
+
+    def(
+        "ManualDate",
+        json!({
+            "description": "A single date, or an inclusive start/end range.",
+            "anyOf": [
+                date(),
+                object(&["start", "end"], json!({"start": date(), "end": date()})),
+            ]
+        }),
+    );
+    def(
+        "ManualEventEntry",
+        object(
+            &["id", "type", "date", "title", "tags", "receipts"],
+            json!({
+                "id": {"type": "string"},
+                "type": reference("ManualEventType"),
+                "date": reference("ManualDate"),
+                "title": {"type": "string"},
+                "description": nullable("string"),
+                "workstream": nullable("string"),
+                "tags": strings(),
+                "receipts": array_of("Link"),
+                "impact": nullable("string"),
+                "metrics": array_of("ImpactMetric"),
+                "evidence": array_of("EvidenceFile"),
+                "repeat": {"anyOf": [reference("ManualRepeat"), {"type": "null"}]},
+            }),
+        ),
+    );
+    def(
+        "ManualRepeat",
+        string_enum(&["Weekly", "Biweekly", "Monthly"]),
+    );
+    def(
+        "ManualEventsFile",
+        object(
+            &["version", "generated_at", "events"],
+            json!({
+                "version": count(),
+                "generated_at": date_time(),
+                "events": array_of("ManualEventEntry"),
+            }),
+        ),
+    );
+
+    defs
+}
+
+/// `EventPayload` variants; each has a matching `{Kind}Event` definition.
+const PAYLOADS: &[&str] = &[
+    "PullRequest",
+    "Review",
+    "Manual",
+    "Issue",
+    "Deployment",
+    "Release",
+    "Comment",
+    "Incident",
+    "Document",
+];
+
+const MANUAL_EVENT_TYPES: &[&str] = &[
+    "Note",
+    "Incident",
+    "Design",
+    "Mentoring",
+    "Launch",
+    "Migration",
+    "Review",
+    "Other",
+];
+
+fn references(schema: &Value) -> Vec<String> {
+    match schema {
+        Value::Object(map) => map
+            .iter()
+            .flat_map(|(key, value)| match (key.as_str(), value.as_str()) {
+                ("$ref", Some(target)) => target
+                    .strip_prefix("#/$defs/")
+                    .map(str::to_string)
+                    .into_iter()
+                    .collect(),
+                _ => references(value),
+            })
+            .collect(),
+        Value::Array(items) => items.iter().flat_map(references).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn object(required: &[&str], properties: Value) -> Value {
+    json!({"type": "object", "required": required, "properties": properties})
+}
+
+fn reference(name: &str) -> Value {
+    json!({"$ref": format!("#/$defs/{name}")})
+}
+
+fn array_of(name: &str) -> Value {
+    json!({"type": "array", "items": reference(name)})
+}
+
+fn strings() -> Value {
+    json!({"type": "array", "items": {"type": "string"}})
+}
+
+fn string_enum(values: &[&str]) -> Value {
+    json!({"type": "string", "enum": values})
+}
+
+fn nullable(ty: &str) -> Value {
+    json!({"type": [ty, "null"]})
+}
+
+fn count() -> Value {
+    json!({"type": "integer", "minimum": 0})
+}
+
+fn nullable_count() -> Value {
+    json!({"type": ["integer", "null"], "minimum": 0})
+}
+
+fn date() -> Value {
+    json!({"type": "string", "format": "date"})
+}
+
+fn nullable_date() -> Value {
+    json!({"type": ["string", "null"], "format": "date"})
+}
+
+fn date_time() -> Value {
+    json!({"type": "string", "format": "date-time"})
+}
+
+fn nullable_date_time() -> Value {
+    json!({"type": ["string", "null"], "format": "date-time"})
+}
+
+fn nullable_window() -> Value {
+    json!({"anyOf": [reference("TimeWindow"), {"type": "null"}]})
+}
+
+fn nullable_effort() -> Value {
+    json!({"anyOf": [reference("Effort"), {"type": "null"}]})
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+fn check(root: &Value, schema: &Value, value: &Value, path: &str, out: &mut Vec<SchemaViolation>) {
+    let mut violation = |message: String| {
+        out.push(SchemaViolation {
+            path: path.to_string(),
+            message,
+        })
+    };
+
+    if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+        match target
+            .strip_prefix("#/$defs/")
+            .and_then(|name| root.get("$defs")?.get(name))
+        {
+            Some(resolved) => check(root, resolved, value, path, out),
+            None => violation(format!("unresolvable schema reference {target}")),
+        }
+        return;
+    }
+
+    if let Some(types) = schema.get("type")
+        && !type_matches(types, value)
+    {
+        violation(format!(
+            "expected {}, found {}",
+            type_label(types),
+            value_kind(value)
+        ));
+        return;
+    }
+
+    if let Some(expected) = schema.get("const")
+        && value != expected
+    {
+        violation(format!("expected {expected}, found {value}"));
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        let allowed: Vec<String> = allowed.iter().map(ToString::to_string).collect();
+        violation(format!(
+            "expected one of {}, found {value}",
+            allowed.join(", ")
+        ));
+    }
+
+    if let Some(text) = value.as_str() {
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str)
+            && !Regex::new(pattern).is_ok_and(|re| re.is_match(text))
+        {
+            violation(format!("{value} does not match pattern {pattern}"));
+        }
+        match schema.get("format").and_then(Value::as_str) {
+            Some("date") if NaiveDate::parse_from_str(text, "%Y-%m-%d").is_err() => {
+                violation(format!("expected a YYYY-MM-DD date, found {value}"));
+            }
+            Some("date-time") if DateTime::parse_from_rfc3339(text).is_err() => {
+                violation(format!("expected an RFC 3339 timestamp, found {value}"));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64)
+        && value.as_f64().is_some_and(|n| n < minimum)
+    {
+        violation(format!("expected a value >= {minimum}, found {value}"));
+    }
+
+    if let Some(fields) = value.as_object() {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !fields.contains_key(name) {
+                    violation(format!("missing required field `{name}`"));
+                }
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        if let Some(properties) = properties {
+            for (name, sub) in properties {
+                if let Some(field) = fields.get(name) {
+                    check(root, sub, field, &child_path(path, name), out);
+                }
+            }
+        }
+        if let Some(extra) = schema.get("additionalProperties") {
+            for (name, field) in fields {
+                if properties.is_none_or(|known| !known.contains_key(name)) {
+                    check(root, extra, field, &child_path(path, name), out);
+                }
+            }
+        }
+    }
+
+    if let (Some(items), Some(values)) = (schema.get("items"), value.as_array()) {
+        for (i, item) in values.iter().enumerate() {
+            check(root, items, item, &child_path(path, &i.to_string()), out);
+        }
+    }
+
+    if let Some(branches) = schema.get("allOf").and_then(Value::as_array) {
+        for branch in branches {
+            check(root, branch, value, path, out);
+        }
+    }
+
+    if let Some(condition) = schema.get("if")
+        && let Some(then) = schema.get("then")
+        && is_valid(root, condition, value, path)
+    {
+        check(root, then, value, path, out);
+    }
+
+    if let Some(branches) = schema.get("anyOf").and_then(Value::as_array) {
+        let mut best: Option<((bool, usize), Vec<SchemaViolation>)> = None;
+        for branch in branches {
+            let mut attempt = Vec::new();
+            check(root, branch, value, path, &mut attempt);
+            if attempt.is_empty() {
+                return;
+            }
+            // Prefer the branch that got furthest: one whose type the value
+            // has, then the deepest first violation.
+            let rank = (
+                accepts_type(root, branch, value),
+                attempt.first().map_or(0, |v| v.path.len()),
+            );
+            if best.as_ref().is_none_or(|(best_rank, _)| rank > *best_rank) {
+                best = Some((rank, attempt));
+            }
+        }
+        out.extend(best.map(|(_, attempt)| attempt).unwrap_or_default());
+    }
+}
+
+/// Whether `value` has the JSON type `schema` declares, following `$ref`s.
+fn accepts_type(root: &Value, schema: &Value, value: &Value) -> bool {
+    if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+        return target
+            .strip_prefix("#/$defs/")
+            .and_then(|name| root.get("$defs")?.get(name))
+            .is_some_and(|resolved| accepts_type(root, resolved, value));
+    }
+    schema
+        .get("type")
+        .is_none_or(|types| type_matches(types, value))
+}
+
+fn is_valid(root: &Value, schema: &Value, value: &Value, path: &str) -> bool {
+    let mut scratch = Vec::new();
+    check(root, schema, value, path, &mut scratch);
+    scratch.is_empty()
+}
+
+fn type_matches(types: &Value, value: &Value) -> bool {
+    match types {
+        Value::String(ty) => single_type_matches(ty, value),
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|ty| single_type_matches(ty, value)),
+        _ => true,
+    }
+}
+
+fn single_type_matches(ty: &str, value: &Value) -> bool {
+    match ty {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+fn type_label(types: &Value) -> String {
+    match types {
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        other => other.as_str().unwrap_or("value").to_string(),
+    }
+}
+
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn child_path(parent: &str, segment: &str) -> String {
+    format!("{parent}/{}", segment.replace('~', "~0").replace('/', "~1"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_reference_resolves() {
+        for doc in SchemaDocument::ALL {
+            let schema = doc.schema();
+            let text = schema.to_string();
+            for target in text.split("\"$ref\":\"").skip(1) {
+                let target = target.split('"').next().unwrap();
+                let name = target.strip_prefix("#/$defs/").unwrap();
+                assert!(
+                    schema["$defs"].get(name).is_some(),
+                    "{doc}: dangling reference {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for doc in SchemaDocument::ALL {
+            assert_eq!(doc.name().parse::<SchemaDocument>().unwrap(), doc);
+        }
+        assert!("ledger".parse::<SchemaDocument>().is_err());
+    }
+
+    #[test]
+    fn payload_data_is_checked_against_its_kind() {
+        let value = json!({"type": "Review", "data": {"pull_number": "7"}});
+        let schema = SchemaDocument::EventEnvelope.schema();
+        let mut out = Vec::new();
+        check(
+            &schema,
+            &reference("EventPayload"),
+            &value,
+            "/payload",
+            &mut out,
+        );
+        let rendered: Vec<String> = out.iter().map(ToString::to_string).collect();
+        assert!(
+            rendered.contains(&"/payload/data: missing required field `pull_title`".to_string()),
+            "{rendered:?}"
+        );
+        assert!(
+            rendered
+                .contains(&"/payload/data/pull_number: expected integer, found string".to_string()),
+            "{rendered:?}"
+        );
+    }
+
+    #[test]
+    fn any_of_reports_the_closest_branch() {
+        let value = json!({"id": "x", "type": "Note", "date": {"start": "2025-01-01"},
+            "title": "t", "tags": [], "receipts": []});
+        let schema = SchemaDocument::ManualEventsFile.schema();
+        let mut out = Vec::new();
+        check(
+            &schema,
+            &reference("ManualEventEntry"),
+            &value,
+            "",
+            &mut out,
+        );
+        assert_eq!(
+            out,
+            vec![SchemaViolation {
+                path: "/date".into(),
+                message: "missing required field `end`".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn formats_and_minimums_are_enforced() {
+        let value = json!({"since": "2025-13-01", "until": "2025-02-01"});
+        let violations = SchemaDocument::CoverageManifest.validate(&json!({
+            "run_id": "r", "generated_at": "yesterday", "user": "u", "window": value,
+            "mode": "m", "sources": [], "slices": [{"window": {"since": "2025-01-01",
+            "until": "2025-02-01"}, "query": "q", "total_count": -1, "fetched": 0,
+            "notes": []}], "warnings": [], "completeness": "Done"
+        }));
+        let mut paths: Vec<&str> = violations.iter().map(|v| v.path.as_str()).collect();
+        paths.sort_unstable();
+        assert_eq!(
+            paths,
+            vec![
+                "/completeness",
+                "/generated_at",
+                "/slices/0/total_count",
+                "/window/since",
+            ]
+        );
+    }
+
+    #[test]
+    fn receipt_weight_overrides_must_name_a_weight() {
+        let value = json!({"id": "w", "title": "t", "tags": [], "events": [], "receipts": [],
+            "stats": {"pull_requests": 0, "reviews": 0, "manual_events": 0},
+            "receipt_weights": {"abc": "High", "def": "Heavy"}});
+        let schema = SchemaDocument::WorkstreamsFile.schema();
+        let mut out = Vec::new();
+        check(&schema, &reference("Workstream"), &value, "", &mut out);
+        assert_eq!(
+            out,
+            vec![SchemaViolation {
+                path: "/receipt_weights/def".into(),
+                message: "expected one of \"Low\", \"Medium\", \"High\", found \"Heavy\"".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn describe_violations_truncates() {
+        let violation = |i: usize| SchemaViolation {
+            path: format!("/{i}"),
+            message: "bad".into(),
+        };
+        let all: Vec<_> = (0..5).map(violation).collect();
+        assert_eq!(
+            describe_violations(&all),
+            "/0: bad; /1: bad; /2: bad (+2 more)"
+        );
+        assert_eq!(
+            describe_violations(&[SchemaViolation {
+                path: String::new(),
+                message: "expected object, found array".into(),
+            }]),
+            "/: expected object, found array"
+        );
+    }
+}
@@ -0,0 +1,301 @@
+//! Upgrades for artifacts written by older shiplog builds.
+//!
+//! Every step is a pure function over typed values, so readers can migrate
+//! in memory on load and `shiplog runs migrate` can rewrite a run directory
+//! in place with the same code. Artifacts stamped with a version newer than
+//! [`SchemaVersion::CURRENT`] are rejected rather than guessed at.
+
+use super::coverage::CoverageManifest;
+use super::event::{EventEnvelope, EventKind, EventPayload, IncidentEvent, ManualEventType};
+use super::version::SchemaVersion;
+use super::workstream::WorkstreamsFile;
+use anyhow::{Result, bail};
+use shiplog::ids::EventId;
+
+/// Newest `workstreams.yaml` format version this build understands.
+pub const WORKSTREAMS_FILE_VERSION: u32 = 1;
+
+/// Summary of what a ledger migration changed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Oldest schema version found in the input, when older than current.
+    pub from: Option<SchemaVersion>,
+    /// Number of events whose `schema_version` was raised.
+    pub events_upgraded: usize,
+    /// IDs of manual incident events rewritten as structured incidents.
+    pub promoted_incidents: Vec<EventId>,
+}
+
+impl MigrationReport {
+    /// Returns `true` when the input was already current.
+    pub fn is_noop(&self) -> bool {
+        self.events_upgraded == 0 && self.promoted_incidents.is_empty()
+    }
+}
+
+/// Fail with a precise error when `version` was written by a newer build.
+pub fn ensure_supported(version: SchemaVersion, what: &str) -> Result<()> {
+    if !version.is_supported() {
+        bail!(
+            "{what} uses schema_version {version}, newer than this build supports ({}); upgrade shiplog to read it",
+            SchemaVersion::CURRENT
+        );
+    }
+    Ok(())
+}
+
+/// Rewrite a manual `Incident` event as a structured incident event.
+///
+/// Older ledgers recorded incidents as [`ManualEventType::Incident`] manual
+/// events. The event ID, repo, tags, links, and source are kept so receipts
+/// and curated workstreams still resolve; any other event is returned as-is.
+pub fn promote_manual_incident(mut event: EventEnvelope) -> EventEnvelope {
+    if let EventPayload::Manual(manual) = &event.payload
+        && manual.event_type == ManualEventType::Incident
+    {
+        let incident = IncidentEvent::from_manual(manual, event.occurred_at);
+        event.kind = EventKind::Incident;
+        event.payload = EventPayload::Incident(incident);
+    }
+    event
+}
+
+/// Upgrade a single event to [`SchemaVersion::CURRENT`].
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::migrate::migrate_event;
+/// use shiplog::schema::version::SchemaVersion;
+/// # let event: shiplog::schema::event::EventEnvelope = serde_json::from_str(
+/// #     r#"{"id":"e1","kind":"Review","occurred_at":"2025-01-01T00:00:00Z",
+/// #     "actor":{"login":"a","id":null},
+/// #     "repo":{"full_name":"o/r","html_url":null,"visibility":"Public"},
+/// #     "payload":{"type":"Review","data":{"pull_number":1,"pull_title":"t",
+/// #     "submitted_at":"2025-01-01T00:00:00Z","state":"approved","window":null}},
+/// #     "tags":[],"links":[],"source":{"system":"github","url":null,"opaque_id":null}}"#,
+/// # ).unwrap();
+///
+/// // Ledgers written before versions were recorded read as LEGACY.
+/// assert_eq!(event.schema_version, SchemaVersion::LEGACY);
+/// let event = migrate_event(event).unwrap();
+/// assert_eq!(event.schema_version, SchemaVersion::CURRENT);
+/// ```
+pub fn migrate_event(event: EventEnvelope) -> Result<EventEnvelope> {
+    ensure_supported(event.schema_version, &format!("event {}", event.id))?;
+    let mut event = if event.schema_version < SchemaVersion::new(0, 3, 0) {
+        promote_manual_incident(event)
+    } else {
+        event
+    };
+    event.schema_version = SchemaVersion::CURRENT;
+    Ok(event)
+}
+
+/// Upgrade every event in a ledger, reporting what changed.
+pub fn migrate_events(events: Vec<EventEnvelope>) -> Result<(Vec<EventEnvelope>, MigrationReport)> {
+    let mut report = MigrationReport::default();
+    let mut out = Vec::with_capacity(events.len());
+    for event in events {
+        let before = event.schema_version;
+        let was_manual = event.kind == EventKind::Manual;
+        let migrated = migrate_event(event)?;
+        if before < SchemaVersion::CURRENT {
+            report.events_upgraded += 1;
+            report.from = Some(report.from.map_or(before, |from| from.min(before)));
+        }
+        if was_manual && migrated.kind == EventKind::Incident {
+            report.promoted_incidents.push(migrated.id.clone());
+        }
+        out.push(migrated);
+    }
+    Ok((out, report))
+}
+
+/// Upgrade a coverage manifest, returning the version it was written with.
+pub fn migrate_coverage(
+    mut coverage: CoverageManifest,
+) -> Result<(CoverageManifest, SchemaVersion)> {
+    let from = coverage.schema_version;
+    ensure_supported(from, "coverage manifest")?;
+    coverage.schema_version = SchemaVersion::CURRENT;
+    Ok((coverage, from))
+}
+
+/// Carry ledger migration changes into a workstreams file.
+///
+/// Promoted incidents keep their event IDs, so only the per-workstream
+/// counters move from `manual_events` to `incidents`. Returns `true` when
+/// any workstream changed.
+pub fn migrate_workstreams(file: &mut WorkstreamsFile, report: &MigrationReport) -> Result<bool> {
+    if file.version > WORKSTREAMS_FILE_VERSION {
+        bail!(
+            "workstreams file version {} is newer than this build supports ({WORKSTREAMS_FILE_VERSION}); upgrade shiplog to read it",
+            file.version
+        );
+    }
+
+    let mut changed = false;
+    for ws in &mut file.workstreams {
+        let promoted = ws
+            .events
+            .iter()
+            .filter(|id| report.promoted_incidents.contains(id))
+            .count();
+        if promoted > 0 {
+            ws.stats.manual_events = ws.stats.manual_events.saturating_sub(promoted);
+            ws.stats.incidents += promoted;
+            changed = true;
+        }
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::coverage::{Completeness, TimeWindow};
+    use crate::schema::event::{
+        Actor, ManualEvent, RepoRef, RepoVisibility, SourceRef, SourceSystem,
+    };
+    use crate::schema::workstream::{Workstream, WorkstreamStats};
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use shiplog::ids::{OpaqueId, RunId, WorkstreamId};
+
+    fn manual_event(id: &str, event_type: ManualEventType) -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::LEGACY,
+            id: EventId::from_parts(["manual", id]),
+            kind: EventKind::Manual,
+            occurred_at: Utc.with_ymd_and_hms(2025, 3, 2, 12, 0, 0).unwrap(),
+            actor: Actor {
+                login: "alice".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "manual/general".into(),
+                html_url: None,
+                visibility: RepoVisibility::Unknown,
+            },
+            payload: EventPayload::Manual(ManualEvent {
+                event_type,
+                title: "Database failover".into(),
+                description: None,
+                started_at: NaiveDate::from_ymd_opt(2025, 3, 1),
+                ended_at: NaiveDate::from_ymd_opt(2025, 3, 2),
+                impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Manual,
+                url: None,
+                opaque_id: Some(OpaqueId::from_raw("test", id)),
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    #[test]
+    fn legacy_ledger_promotes_incidents_and_stamps_current() {
+        let events = vec![
+            manual_event("i1", ManualEventType::Incident),
+            manual_event("n1", ManualEventType::Note),
+        ];
+        let (events, report) = migrate_events(events).unwrap();
+
+        assert!(
+            events
+                .iter()
+                .all(|e| e.schema_version == SchemaVersion::CURRENT)
+        );
+        assert_eq!(events[0].kind, EventKind::Incident);
+        assert_eq!(events[1].kind, EventKind::Manual);
+        assert_eq!(report.from, Some(SchemaVersion::LEGACY));
+        assert_eq!(report.events_upgraded, 2);
+        assert_eq!(report.promoted_incidents, vec![events[0].id.clone()]);
+    }
+
+    #[test]
+    fn current_ledger_is_a_noop() {
+        let mut event = manual_event("i1", ManualEventType::Incident);
+        event.schema_version = SchemaVersion::CURRENT;
+        let (events, report) = migrate_events(vec![event.clone()]).unwrap();
+        assert_eq!(events, vec![event]);
+        assert!(report.is_noop());
+        assert_eq!(report.from, None);
+    }
+
+    #[test]
+    fn newer_versions_are_rejected() {
+        let mut event = manual_event("i1", ManualEventType::Note);
+        event.schema_version = SchemaVersion::new(0, 9, 0);
+        let err = migrate_events(vec![event]).unwrap_err().to_string();
+        assert!(err.contains("schema_version 0.9.0"), "{err}");
+        assert!(err.contains("upgrade shiplog"), "{err}");
+    }
+
+    #[test]
+    fn coverage_reports_original_version() {
+        let coverage = CoverageManifest {
+            schema_version: SchemaVersion::LEGACY,
+            run_id: RunId("run_1".into()),
+            generated_at: Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap(),
+            user: "alice".into(),
+            window: TimeWindow {
+                since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                until: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+            },
+            mode: "merged".into(),
+            sources: vec![],
+            slices: vec![],
+            warnings: vec![],
+            completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
+        };
+        let (coverage, from) = migrate_coverage(coverage).unwrap();
+        assert_eq!(from, SchemaVersion::LEGACY);
+        assert_eq!(coverage.schema_version, SchemaVersion::CURRENT);
+    }
+
+    #[test]
+    fn workstream_counters_follow_promoted_incidents() {
+        let incident = manual_event("i1", ManualEventType::Incident);
+        let note = manual_event("n1", ManualEventType::Note);
+        let mut stats = WorkstreamStats::zero();
+        stats.manual_events = 2;
+        let mut file = WorkstreamsFile {
+            version: 1,
+            generated_at: Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap(),
+            workstreams: vec![Workstream {
+                id: WorkstreamId::from_parts(["ws", "ops"]),
+                title: "Ops".into(),
+                summary: None,
+                tags: vec![],
+                stats,
+                events: vec![incident.id.clone(), note.id.clone()],
+                receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }],
+        };
+
+        let (_, report) = migrate_events(vec![incident, note]).unwrap();
+        assert!(migrate_workstreams(&mut file, &report).unwrap());
+        assert_eq!(file.workstreams[0].stats.manual_events, 1);
+        assert_eq!(file.workstreams[0].stats.incidents, 1);
+
+        // Re-running with an empty report leaves the file alone.
+        assert!(!migrate_workstreams(&mut file, &MigrationReport::default()).unwrap());
+
+        file.version = WORKSTREAMS_FILE_VERSION + 1;
+        assert!(migrate_workstreams(&mut file, &report).is_err());
+    }
+}
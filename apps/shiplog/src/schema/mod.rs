@@ -17,6 +17,7 @@
 //!
 //! let ts = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
 //! let envelope = EventEnvelope {
+//!     schema_version: SchemaVersion::CURRENT,
 //!     id: EventId::from_parts(["github", "pr", "acme/widgets", "42"]),
 //!     kind: EventKind::PullRequest,
 //!     occurred_at: ts,
@@ -45,6 +46,9 @@
 //!         url: None,
 //!         opaque_id: None,
 //!     },
+//!     contributors: Vec::new(),
+//!     extensions: Default::default(),
+//!     canonical_id: None,
 //! };
 //!
 //! // Round-trips through JSON:
@@ -61,5 +65,14 @@ pub mod coverage;
 pub mod event;
 /// Per-source freshness receipts for cache/fetch attribution.
 pub mod freshness;
+/// JSON Schema documents and validation for shiplog's file formats.
+pub mod json_schema;
+/// Upgrades for ledgers, coverage manifests, and workstreams from older builds.
+pub mod migrate;
+/// Proptest strategies for schema types, for downstream property tests.
+#[cfg(feature = "proptest")]
+pub mod strategy;
+/// Schema version stamped on events and coverage manifests.
+pub mod version;
 /// Workstream definitions and the top-level workstreams file format.
 pub mod workstream;
@@ -0,0 +1,891 @@
+//! Proptest strategies for the schema types.
+//!
+//! Enabled by the `proptest` feature. The strategies build well-formed
+//! [`EventEnvelope`]s of every payload kind, [`CoverageManifest`]s, and
+//! [`WorkstreamsFile`]s, so downstream crates and plugins can write
+//! round-trip and redaction-leak property tests without their own
+//! generators.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use shiplog::schema::event::EventEnvelope;
+//! use shiplog::schema::strategy::strategy_event_envelope;
+//!
+//! proptest!(|(event in strategy_event_envelope())| {
+//!     let json = serde_json::to_string(&event).unwrap();
+//!     let back: EventEnvelope = serde_json::from_str(&json).unwrap();
+//!     prop_assert_eq!(back, event);
+//! });
+//! ```
+
+use chrono::{Datelike, NaiveDate, TimeZone, Utc};
+use proptest::prelude::*;
+
+use crate::ids::{EventId, OpaqueId, RunId, WorkstreamId};
+use crate::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use crate::schema::event::*;
+use crate::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
+
+// ============================================================================
+// Base Strategies
+// ============================================================================
+
+/// Strategy for generating valid NaiveDate values
+pub fn strategy_naive_date() -> impl Strategy<Value = NaiveDate> {
+    // Generate dates from 2020-01-01 to 2030-12-31
+    (0u64..4000).prop_map(|days| {
+        NaiveDate::from_ymd_opt(2020, 1, 1)
+            .and_then(|start| start.checked_add_days(chrono::Days::new(days)))
+            .unwrap_or_default()
+    })
+}
+
+/// Strategy for generating valid `DateTime<Utc>` values
+pub fn strategy_datetime_utc() -> impl Strategy<Value = chrono::DateTime<Utc>> {
+    (strategy_naive_date(), 0u32..24, 0u32..60, 0u32..60).prop_map(|(date, h, m, s)| {
+        Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), h, m, s)
+            .single()
+            .unwrap_or_default()
+    })
+}
+
+/// Strategy for generating valid date ranges (since <= until)
+pub fn strategy_date_range() -> impl Strategy<Value = (NaiveDate, NaiveDate)> {
+    (strategy_naive_date(), strategy_naive_date())
+        .prop_map(|(d1, d2)| if d1 < d2 { (d1, d2) } else { (d2, d1) })
+}
+
+/// Strategy for generating non-empty strings
+pub fn strategy_non_empty_string() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_-]{1,100}"
+}
+
+/// Strategy for generating repository names in "owner/repo" format
+pub fn strategy_repo_name() -> impl Strategy<Value = String> {
+    "[a-z0-9_-]{3,20}/[a-z0-9_-]{3,50}"
+}
+
+/// Strategy for generating URLs
+pub fn strategy_url() -> impl Strategy<Value = String> {
+    "https://[a-z0-9.-]{5,50}/[a-z0-9._/-]{5,100}"
+}
+
+/// Strategy for generating PR numbers
+pub fn strategy_pr_number() -> impl Strategy<Value = u64> {
+    1u64..10000
+}
+
+/// Strategy for generating positive counts
+pub fn strategy_positive_count() -> impl Strategy<Value = usize> {
+    0usize..1000
+}
+
+/// Strategy for generating SourceSystem enum values
+pub fn strategy_source_system() -> impl Strategy<Value = SourceSystem> {
+    prop_oneof![
+        Just(SourceSystem::Github),
+        Just(SourceSystem::JsonImport),
+        Just(SourceSystem::LocalGit),
+        Just(SourceSystem::Manual),
+        Just(SourceSystem::Unknown),
+        "[a-z]{3,12}".prop_map(SourceSystem::Other),
+    ]
+}
+
+/// Strategy for generating RepoVisibility enum values
+pub fn strategy_repo_visibility() -> impl Strategy<Value = RepoVisibility> {
+    prop_oneof![
+        Just(RepoVisibility::Public),
+        Just(RepoVisibility::Private),
+        Just(RepoVisibility::Unknown),
+    ]
+}
+
+/// Strategy for generating PullRequestState enum values
+pub fn strategy_pr_state() -> impl Strategy<Value = PullRequestState> {
+    prop_oneof![
+        Just(PullRequestState::Open),
+        Just(PullRequestState::Closed),
+        Just(PullRequestState::Merged),
+        Just(PullRequestState::Unknown),
+    ]
+}
+
+/// Strategy for generating IssueState enum values
+pub fn strategy_issue_state() -> impl Strategy<Value = IssueState> {
+    prop_oneof![
+        Just(IssueState::Open),
+        Just(IssueState::Closed),
+        Just(IssueState::Unknown),
+    ]
+}
+
+/// Strategy for generating DeploymentStatus enum values
+pub fn strategy_deployment_status() -> impl Strategy<Value = DeploymentStatus> {
+    prop_oneof![
+        Just(DeploymentStatus::Succeeded),
+        Just(DeploymentStatus::Failed),
+        Just(DeploymentStatus::RolledBack),
+        Just(DeploymentStatus::InProgress),
+        Just(DeploymentStatus::Unknown),
+    ]
+}
+
+/// Strategy for generating ReleaseStatus enum values
+pub fn strategy_release_status() -> impl Strategy<Value = ReleaseStatus> {
+    prop_oneof![
+        Just(ReleaseStatus::Published),
+        Just(ReleaseStatus::Prerelease),
+        Just(ReleaseStatus::Draft),
+        Just(ReleaseStatus::Unknown),
+    ]
+}
+
+/// Strategy for generating CommentTarget enum values
+pub fn strategy_comment_target() -> impl Strategy<Value = CommentTarget> {
+    prop_oneof![
+        Just(CommentTarget::PullRequest),
+        Just(CommentTarget::Issue),
+        Just(CommentTarget::Ticket),
+        Just(CommentTarget::Document),
+        Just(CommentTarget::Other),
+    ]
+}
+
+/// Strategy for generating IncidentSeverity enum values
+pub fn strategy_incident_severity() -> impl Strategy<Value = IncidentSeverity> {
+    prop_oneof![
+        Just(IncidentSeverity::Sev1),
+        Just(IncidentSeverity::Sev2),
+        Just(IncidentSeverity::Sev3),
+        Just(IncidentSeverity::Sev4),
+        Just(IncidentSeverity::Unknown),
+    ]
+}
+
+/// Strategy for generating IncidentRole enum values
+pub fn strategy_incident_role() -> impl Strategy<Value = IncidentRole> {
+    prop_oneof![
+        Just(IncidentRole::Commander),
+        Just(IncidentRole::Responder),
+        Just(IncidentRole::Communications),
+        Just(IncidentRole::Scribe),
+        Just(IncidentRole::Unknown),
+    ]
+}
+
+/// Strategy for generating DocumentType enum values
+pub fn strategy_document_type() -> impl Strategy<Value = DocumentType> {
+    prop_oneof![
+        Just(DocumentType::Design),
+        Just(DocumentType::Adr),
+        Just(DocumentType::Rfc),
+        Just(DocumentType::Runbook),
+        Just(DocumentType::Postmortem),
+        Just(DocumentType::Note),
+        Just(DocumentType::Other),
+    ]
+}
+
+/// Strategy for generating EventKind enum values
+pub fn strategy_event_kind() -> impl Strategy<Value = EventKind> {
+    prop_oneof![
+        Just(EventKind::PullRequest),
+        Just(EventKind::Review),
+        Just(EventKind::Manual),
+        Just(EventKind::Issue),
+        Just(EventKind::Deployment),
+        Just(EventKind::Release),
+        Just(EventKind::Comment),
+        Just(EventKind::Incident),
+        Just(EventKind::Document),
+    ]
+}
+
+/// Strategy for generating Completeness enum values
+pub fn strategy_completeness() -> impl Strategy<Value = Completeness> {
+    prop_oneof![
+        Just(Completeness::Complete),
+        Just(Completeness::Partial),
+        Just(Completeness::Unknown),
+    ]
+}
+
+fn strategy_manual_event_type() -> impl Strategy<Value = ManualEventType> {
+    prop_oneof![
+        Just(ManualEventType::Note),
+        Just(ManualEventType::Incident),
+        Just(ManualEventType::Design),
+        Just(ManualEventType::Mentoring),
+        Just(ManualEventType::Launch),
+        Just(ManualEventType::Migration),
+        Just(ManualEventType::Review),
+        Just(ManualEventType::Other),
+    ]
+}
+
+// ============================================================================
+// Event Strategies
+// ============================================================================
+
+/// Strategy for generating Actor values
+pub fn strategy_actor() -> impl Strategy<Value = Actor> {
+    (
+        "[a-zA-Z0-9_-]{1,50}",
+        proptest::option::of(1u64..1_000_000u64),
+    )
+        .prop_map(|(login, id)| Actor { login, id })
+}
+
+/// Strategy for generating Contributor values
+pub fn strategy_contributor() -> impl Strategy<Value = Contributor> {
+    (
+        strategy_actor(),
+        prop_oneof![
+            Just(ContributorRole::CoAuthor),
+            Just(ContributorRole::CoReviewer),
+            Just(ContributorRole::Pair),
+            Just(ContributorRole::Reviewer),
+        ],
+    )
+        .prop_map(|(actor, role)| Contributor {
+            login: actor.login,
+            id: actor.id,
+            role,
+        })
+}
+
+/// Strategy for generating RepoRef values
+pub fn strategy_repo_ref() -> impl Strategy<Value = RepoRef> {
+    (
+        strategy_repo_name(),
+        strategy_url(),
+        strategy_repo_visibility(),
+    )
+        .prop_map(|(full_name, html_url, visibility)| RepoRef {
+            full_name,
+            html_url: Some(html_url),
+            visibility,
+        })
+}
+
+/// Strategy for generating Link values
+pub fn strategy_link() -> impl Strategy<Value = Link> {
+    ("[a-z]{1,20}", strategy_url()).prop_map(|(label, url)| Link { label, url })
+}
+
+/// Strategy for generating event extension maps with namespaced keys
+pub fn strategy_extensions()
+-> impl Strategy<Value = std::collections::BTreeMap<String, serde_json::Value>> {
+    proptest::collection::btree_map(
+        "(public|internal|github|gitlab)\\.[a-z_]{1,12}",
+        prop_oneof![
+            "[a-z]{1,12}".prop_map(serde_json::Value::from),
+            any::<u32>().prop_map(serde_json::Value::from),
+            any::<bool>().prop_map(serde_json::Value::from),
+        ],
+        0..3,
+    )
+}
+
+/// Strategy for generating SourceRef values
+pub fn strategy_source_ref() -> impl Strategy<Value = SourceRef> {
+    (
+        strategy_source_system(),
+        proptest::option::of(strategy_url()),
+        proptest::option::of("[a-zA-Z0-9_-]{1,64}"),
+    )
+        .prop_map(|(system, url, raw_id)| SourceRef {
+            opaque_id: raw_id.map(|raw| OpaqueId::from_raw(system.as_str(), &raw)),
+            system,
+            url,
+        })
+}
+
+/// Strategy for generating TimeWindow values
+pub fn strategy_time_window() -> impl Strategy<Value = TimeWindow> {
+    strategy_date_range().prop_map(|(since, until)| TimeWindow { since, until })
+}
+
+/// Strategy for generating PullRequestEvent values
+pub fn strategy_pr_payload() -> impl Strategy<Value = PullRequestEvent> {
+    (
+        strategy_pr_number(),
+        strategy_non_empty_string(),
+        strategy_pr_state(),
+        strategy_datetime_utc(),
+        proptest::option::of(strategy_datetime_utc()),
+        proptest::option::of(0u64..10000u64),
+        proptest::option::of(0u64..10000u64),
+        proptest::option::of(0u64..1000u64),
+        proptest::collection::vec("[a-zA-Z0-9_./-]{1,100}", 0..10),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(
+                number,
+                title,
+                state,
+                created_at,
+                merged_at,
+                additions,
+                deletions,
+                changed_files,
+                touched_paths_hint,
+                window,
+            )| PullRequestEvent {
+                number,
+                title,
+                state,
+                created_at,
+                merged_at,
+                additions,
+                deletions,
+                changed_files,
+                touched_paths_hint,
+                window,
+            },
+        )
+}
+
+/// Strategy for generating ReviewEvent values
+pub fn strategy_review_payload() -> impl Strategy<Value = ReviewEvent> {
+    (
+        strategy_pr_number(),
+        strategy_non_empty_string(),
+        prop_oneof![
+            Just("approved".to_string()),
+            Just("changes_requested".to_string()),
+            Just("commented".to_string()),
+            "[a-z_]{3,20}".prop_map(|s| s.to_lowercase()),
+        ],
+        strategy_datetime_utc(),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(pull_number, pull_title, state, submitted_at, window)| ReviewEvent {
+                pull_number,
+                pull_title,
+                submitted_at,
+                state,
+                window,
+                pull_created_at: None,
+                pull_author: None,
+            },
+        )
+}
+
+/// Strategy for generating ManualEvent values
+pub fn strategy_manual_payload() -> impl Strategy<Value = ManualEvent> {
+    (
+        strategy_manual_event_type(),
+        strategy_non_empty_string(),
+        proptest::option::of("[a-zA-Z0-9_ ,.:-]{10,500}"),
+        proptest::option::of(strategy_date_range()),
+        proptest::option::of("[a-zA-Z0-9_ ,.:-]{10,200}"),
+        proptest::option::of(strategy_effort()),
+    )
+        .prop_map(
+            |(event_type, title, description, date_range, impact, effort)| ManualEvent {
+                event_type,
+                title,
+                description,
+                started_at: date_range.map(|(since, _)| since),
+                ended_at: date_range.map(|(_, until)| until),
+                impact,
+                effort,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+            },
+        )
+}
+
+/// Strategy for generating Effort values
+pub fn strategy_effort() -> impl Strategy<Value = Effort> {
+    (
+        0u64..100_000u64,
+        prop_oneof![Just(EffortBasis::Logged), Just(EffortBasis::Elapsed)],
+    )
+        .prop_map(|(minutes, basis)| Effort { minutes, basis })
+}
+
+/// Strategy for generating IssueEvent values
+pub fn strategy_issue_payload() -> impl Strategy<Value = IssueEvent> {
+    (
+        strategy_pr_number(),
+        strategy_non_empty_string(),
+        strategy_issue_state(),
+        proptest::collection::vec("[a-z-]{1,20}", 0..5),
+        strategy_datetime_utc(),
+        proptest::option::of(strategy_datetime_utc()),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(number, title, state, labels, opened_at, closed_at, window)| IssueEvent {
+                number,
+                title,
+                state,
+                labels,
+                opened_at,
+                closed_at,
+                window,
+                effort: None,
+            },
+        )
+}
+
+/// Strategy for generating DeploymentEvent values
+pub fn strategy_deployment_payload() -> impl Strategy<Value = DeploymentEvent> {
+    (
+        "[a-z][a-z0-9-]{2,20}",
+        proptest::option::of("v[0-9]{1,2}\\.[0-9]{1,2}\\.[0-9]{1,2}"),
+        strategy_deployment_status(),
+        strategy_datetime_utc(),
+        proptest::option::of(strategy_datetime_utc()),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(environment, version, status, started_at, finished_at, window)| DeploymentEvent {
+                environment,
+                version,
+                status,
+                started_at,
+                finished_at,
+                window,
+            },
+        )
+}
+
+/// Strategy for generating ReleaseEvent values
+pub fn strategy_release_payload() -> impl Strategy<Value = ReleaseEvent> {
+    (
+        "v[0-9]{1,2}\\.[0-9]{1,2}\\.[0-9]{1,2}",
+        proptest::option::of(strategy_non_empty_string()),
+        strategy_release_status(),
+        strategy_datetime_utc(),
+        proptest::option::of(strategy_datetime_utc()),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(version, name, status, created_at, published_at, window)| ReleaseEvent {
+                version,
+                name,
+                status,
+                created_at,
+                published_at,
+                window,
+            },
+        )
+}
+
+/// Strategy for generating CommentEvent values
+pub fn strategy_comment_payload() -> impl Strategy<Value = CommentEvent> {
+    (
+        strategy_comment_target(),
+        proptest::option::of(strategy_pr_number()),
+        strategy_non_empty_string(),
+        proptest::option::of("[a-zA-Z0-9_ /.-]{5,80}"),
+        1u32..200,
+        strategy_datetime_utc(),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(target, target_number, target_title, thread, count, first_at, window)| CommentEvent {
+                target,
+                target_number,
+                target_title,
+                thread,
+                count,
+                first_at,
+                last_at: first_at,
+                window,
+            },
+        )
+}
+
+/// Strategy for generating IncidentEvent values
+pub fn strategy_incident_payload() -> impl Strategy<Value = IncidentEvent> {
+    (
+        strategy_non_empty_string(),
+        strategy_incident_severity(),
+        strategy_incident_role(),
+        strategy_datetime_utc(),
+        proptest::option::of(1u64..10_000),
+        proptest::option::of(strategy_non_empty_string()),
+        proptest::option::of(strategy_non_empty_string()),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(
+                title,
+                severity,
+                role,
+                detected_at,
+                duration_minutes,
+                summary,
+                customer_impact,
+                window,
+            )| {
+                IncidentEvent {
+                    title,
+                    severity,
+                    role,
+                    detected_at,
+                    resolved_at: duration_minutes
+                        .map(|minutes| detected_at + chrono::Duration::minutes(minutes as i64)),
+                    duration_minutes,
+                    summary,
+                    customer_impact,
+                    window,
+                }
+            },
+        )
+}
+
+/// Strategy for generating DocumentEvent values
+pub fn strategy_document_payload() -> impl Strategy<Value = DocumentEvent> {
+    (
+        strategy_document_type(),
+        strategy_non_empty_string(),
+        prop_oneof![
+            Just("confluence".to_string()),
+            Just("notion".to_string()),
+            Just("gdrive".to_string()),
+            Just("adr".to_string()),
+        ],
+        strategy_datetime_utc(),
+        proptest::option::of(-5_000i64..5_000),
+        proptest::option::of(strategy_time_window()),
+    )
+        .prop_map(
+            |(doc_type, title, system, last_edited_at, word_count_delta, window)| DocumentEvent {
+                doc_type,
+                title,
+                system,
+                last_edited_at,
+                word_count_delta,
+                window,
+            },
+        )
+}
+
+/// Strategy for generating EventPayload values
+pub fn strategy_event_payload() -> impl Strategy<Value = EventPayload> {
+    prop_oneof![
+        strategy_pr_payload().prop_map(EventPayload::PullRequest),
+        strategy_review_payload().prop_map(EventPayload::Review),
+        strategy_manual_payload().prop_map(EventPayload::Manual),
+        strategy_issue_payload().prop_map(EventPayload::Issue),
+        strategy_deployment_payload().prop_map(EventPayload::Deployment),
+        strategy_release_payload().prop_map(EventPayload::Release),
+        strategy_comment_payload().prop_map(EventPayload::Comment),
+        strategy_incident_payload().prop_map(EventPayload::Incident),
+        strategy_document_payload().prop_map(EventPayload::Document),
+    ]
+}
+
+/// Strategy for generating EventEnvelope values
+pub fn strategy_event_envelope() -> impl Strategy<Value = EventEnvelope> {
+    (
+        strategy_event_payload(),
+        strategy_actor(),
+        proptest::collection::vec(strategy_contributor(), 0..3),
+        strategy_repo_ref(),
+        strategy_source_ref(),
+        proptest::collection::vec(strategy_link(), 0..5),
+        proptest::collection::vec("[a-z]{1,20}", 0..5),
+        any::<u64>(),
+        strategy_datetime_utc(),
+        strategy_extensions(),
+    )
+        .prop_map(
+            |(
+                payload,
+                actor,
+                contributors,
+                repo,
+                source,
+                links,
+                tags,
+                nonce,
+                occurred_at,
+                extensions,
+            )| {
+                let (kind, id) = match &payload {
+                    EventPayload::PullRequest(pr) => (
+                        EventKind::PullRequest,
+                        EventId::from_parts([
+                            "github",
+                            "pr",
+                            &repo.full_name,
+                            &pr.number.to_string(),
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Review(r) => (
+                        EventKind::Review,
+                        EventId::from_parts([
+                            "github",
+                            "review",
+                            &repo.full_name,
+                            &r.pull_number.to_string(),
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Manual(manual) => (
+                        EventKind::Manual,
+                        EventId::from_parts([
+                            "manual",
+                            &repo.full_name,
+                            &manual.title,
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Issue(issue) => (
+                        EventKind::Issue,
+                        EventId::from_parts([
+                            "github",
+                            "issue",
+                            &repo.full_name,
+                            &issue.number.to_string(),
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Deployment(deployment) => (
+                        EventKind::Deployment,
+                        EventId::from_parts([
+                            "github",
+                            "deployment",
+                            &repo.full_name,
+                            &deployment.environment,
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Release(release) => (
+                        EventKind::Release,
+                        EventId::from_parts([
+                            "github",
+                            "release",
+                            &repo.full_name,
+                            &release.version,
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Comment(comment) => (
+                        EventKind::Comment,
+                        EventId::from_parts([
+                            "github",
+                            "comment",
+                            &repo.full_name,
+                            &comment.target_title,
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Incident(incident) => (
+                        EventKind::Incident,
+                        EventId::from_parts([
+                            "manual",
+                            "incident",
+                            &repo.full_name,
+                            &incident.title,
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                    EventPayload::Document(document) => (
+                        EventKind::Document,
+                        EventId::from_parts([
+                            &document.system,
+                            "document",
+                            &repo.full_name,
+                            &document.title,
+                            &nonce.to_string(),
+                        ]),
+                    ),
+                };
+
+                EventEnvelope {
+                    schema_version: SchemaVersion::CURRENT,
+                    id,
+                    kind,
+                    occurred_at,
+                    actor,
+                    contributors,
+                    repo,
+                    payload,
+                    tags,
+                    links,
+                    source,
+                    extensions,
+                    canonical_id: None,
+                }
+            },
+        )
+}
+
+/// Strategy for generating a vector of EventEnvelope values
+pub fn strategy_event_vec(max_size: usize) -> impl Strategy<Value = Vec<EventEnvelope>> {
+    proptest::collection::vec(strategy_event_envelope(), 0..=max_size)
+}
+
+// ============================================================================
+// Coverage Strategies
+// ============================================================================
+
+/// Strategy for generating CoverageSlice values
+pub fn strategy_coverage_slice() -> impl Strategy<Value = CoverageSlice> {
+    (
+        strategy_time_window(),
+        "[a-zA-Z0-9_:=, /.-]{5,120}",
+        0u64..5000u64,
+        0u64..5000u64,
+        proptest::collection::vec("[a-zA-Z0-9_ ,.:-]{5,120}", 0..5),
+        any::<bool>(),
+    )
+        .prop_map(|(window, query, a, b, notes, has_incomplete)| {
+            let total_count = a.max(b);
+            let fetched = a.min(b);
+            let incomplete_results = if has_incomplete {
+                Some(fetched < total_count)
+            } else {
+                None
+            };
+
+            CoverageSlice {
+                window,
+                query,
+                total_count,
+                fetched,
+                incomplete_results,
+                notes,
+                source: None,
+            }
+        })
+}
+
+/// Strategy for generating CoverageManifest values
+pub fn strategy_coverage_manifest() -> impl Strategy<Value = CoverageManifest> {
+    (
+        "[a-zA-Z0-9_-]{1,50}",
+        strategy_date_range(),
+        proptest::collection::vec(strategy_coverage_slice(), 0..10),
+        proptest::collection::vec("[a-zA-Z0-9_ ,.:-]{5,100}", 0..5),
+        strategy_completeness(),
+        strategy_datetime_utc(),
+    )
+        .prop_map(
+            |(user, (since, until), slices, warnings, completeness, generated_at)| {
+                CoverageManifest {
+                    schema_version: SchemaVersion::CURRENT,
+                    run_id: RunId::now("test"),
+                    generated_at,
+                    user,
+                    window: TimeWindow { since, until },
+                    mode: "merged".to_string(),
+                    sources: vec!["github".to_string()],
+                    slices,
+                    warnings,
+                    completeness,
+                    score: None,
+                    timezone: None,
+                    budget: vec![],
+                }
+            },
+        )
+}
+
+// ============================================================================
+// Workstream Strategies
+// ============================================================================
+
+/// Strategy for generating WorkstreamStats values
+pub fn strategy_workstream_stats() -> impl Strategy<Value = WorkstreamStats> {
+    (
+        strategy_positive_count(),
+        strategy_positive_count(),
+        strategy_positive_count(),
+    )
+        .prop_map(|(pull_requests, reviews, manual_events)| WorkstreamStats {
+            pull_requests,
+            reviews,
+            manual_events,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
+        })
+}
+
+/// Strategy for generating Workstream values
+pub fn strategy_workstream() -> impl Strategy<Value = Workstream> {
+    (
+        "[a-zA-Z0-9_ ]{5,100}",
+        proptest::option::of("[a-zA-Z0-9_ ,.:-]{10,500}"),
+        proptest::collection::vec("[a-z]{1,20}", 0..5),
+        proptest::collection::vec(strategy_event_envelope(), 0..20),
+    )
+        .prop_map(|(title, summary, tags, events)| {
+            let mut stats = WorkstreamStats::zero();
+            for event in &events {
+                match event.kind {
+                    EventKind::PullRequest => stats.pull_requests += 1,
+                    EventKind::Review => stats.reviews += 1,
+                    EventKind::Manual => stats.manual_events += 1,
+                    EventKind::Issue => stats.issues += 1,
+                    EventKind::Deployment => stats.deployments += 1,
+                    EventKind::Release => stats.releases += 1,
+                    EventKind::Comment => stats.comments += 1,
+                    EventKind::Incident => stats.incidents += 1,
+                    EventKind::Document => stats.documents += 1,
+                }
+            }
+
+            let event_ids: Vec<_> = events.iter().map(|e| e.id.clone()).collect();
+            let receipts = event_ids.iter().take(10).cloned().collect();
+
+            Workstream {
+                id: WorkstreamId::from_parts(["ws", &title.to_lowercase().replace(' ', "-")]),
+                title,
+                summary,
+                tags,
+                stats,
+                events: event_ids,
+                receipts,
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            }
+        })
+}
+
+/// Strategy for generating WorkstreamsFile values
+pub fn strategy_workstreams_file() -> impl Strategy<Value = WorkstreamsFile> {
+    (
+        proptest::collection::vec(strategy_workstream(), 0..10),
+        1u32..10u32,
+        strategy_datetime_utc(),
+    )
+        .prop_map(|(workstreams, version, generated_at)| WorkstreamsFile {
+            workstreams,
+            version,
+            generated_at,
+        })
+}
+
+// ============================================================================
+// ID Strategies
+// ============================================================================
+
+/// Strategy for generating EventId parts
+pub fn strategy_event_id_parts() -> impl Strategy<Value = Vec<String>> {
+    proptest::collection::vec("[a-zA-Z0-9_-]{1,50}", 1..5)
+}
+
+/// Strategy for generating WorkstreamId parts
+pub fn strategy_workstream_id_parts() -> impl Strategy<Value = Vec<String>> {
+    proptest::collection::vec("[a-zA-Z0-9_-]{1,50}", 1..3)
+}
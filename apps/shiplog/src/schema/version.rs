@@ -0,0 +1,133 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Version of the canonical event and coverage schema.
+///
+/// Stamped on every [`EventEnvelope`](super::event::EventEnvelope) and
+/// [`CoverageManifest`](super::coverage::CoverageManifest) so readers such as
+/// team aggregation can tell which build wrote a ledger instead of guessing.
+/// Serialized as a `"major.minor.patch"` string.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::version::SchemaVersion;
+///
+/// let v: SchemaVersion = "0.2.0".parse().unwrap();
+/// assert_eq!(v, SchemaVersion::LEGACY);
+/// assert!(v < SchemaVersion::CURRENT);
+/// assert_eq!(SchemaVersion::CURRENT.to_string(), "0.3.0");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SchemaVersion {
+    /// Major version.
+    pub major: u32,
+    /// Minor version.
+    pub minor: u32,
+    /// Patch version.
+    pub patch: u32,
+}
+
+impl SchemaVersion {
+    /// Version written by this build.
+    ///
+    /// `0.3.0` added the structured incident, document, and collaboration
+    /// payloads and retired `Manual` incidents.
+    pub const CURRENT: Self = Self::new(0, 3, 0);
+
+    /// Version assumed for files written before versions were recorded.
+    pub const LEGACY: Self = Self::new(0, 2, 0);
+
+    /// Build a version from its parts.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Serde default for files that predate the `schema_version` field.
+    pub fn legacy() -> Self {
+        Self::LEGACY
+    }
+
+    /// Returns `true` when this build can read the version, possibly after
+    /// migrating it.
+    pub fn is_supported(&self) -> bool {
+        *self <= Self::CURRENT
+    }
+}
+
+impl Default for SchemaVersion {
+    fn default() -> Self {
+        Self::CURRENT
+    }
+}
+
+impl fmt::Display for SchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for SchemaVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s
+            .trim()
+            .split('.')
+            .map(str::parse::<u32>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                anyhow::anyhow!("invalid schema_version {s:?}; expected MAJOR.MINOR.PATCH")
+            })?;
+        match parts.as_slice() {
+            [major, minor, patch] => Ok(Self::new(*major, *minor, *patch)),
+            _ => anyhow::bail!("invalid schema_version {s:?}; expected MAJOR.MINOR.PATCH"),
+        }
+    }
+}
+
+impl Serialize for SchemaVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SchemaVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_as_string() {
+        let json = serde_json::to_string(&SchemaVersion::CURRENT).unwrap();
+        assert_eq!(json, r#""0.3.0""#);
+        let back: SchemaVersion = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, SchemaVersion::CURRENT);
+    }
+
+    #[test]
+    fn rejects_malformed_versions() {
+        assert!("0.3".parse::<SchemaVersion>().is_err());
+        assert!("v0.3.0".parse::<SchemaVersion>().is_err());
+        assert!(serde_json::from_str::<SchemaVersion>(r#""next""#).is_err());
+    }
+
+    #[test]
+    fn newer_versions_are_unsupported() {
+        assert!(SchemaVersion::LEGACY.is_supported());
+        assert!(SchemaVersion::CURRENT.is_supported());
+        assert!(!SchemaVersion::new(0, 4, 0).is_supported());
+        assert!(!SchemaVersion::new(1, 0, 0).is_supported());
+    }
+}
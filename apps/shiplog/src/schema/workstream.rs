@@ -1,7 +1,8 @@
-use super::event::EventKind;
+use super::event::{Effort, EffortBasis, EventKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use shiplog::ids::{EventId, WorkstreamId};
+use std::collections::BTreeMap;
 
 /// Aggregate counters for events within a workstream.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -12,6 +13,38 @@ pub struct WorkstreamStats {
     pub reviews: usize,
     /// Number of manually-entered events in this workstream.
     pub manual_events: usize,
+    /// Number of issues in this workstream.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub issues: usize,
+    /// Number of deployments in this workstream.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub deployments: usize,
+    /// Number of releases in this workstream.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub releases: usize,
+    /// Number of comment threads in this workstream.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub comments: usize,
+    /// Number of incidents in this workstream.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub incidents: usize,
+    /// Number of documents in this workstream.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub documents: usize,
+    /// Minutes logged against events in this workstream.
+    #[serde(default, skip_serializing_if = "is_zero_minutes")]
+    pub logged_minutes: u64,
+    /// Minutes of elapsed time (PR open-to-merge, incident resolution).
+    #[serde(default, skip_serializing_if = "is_zero_minutes")]
+    pub elapsed_minutes: u64,
+}
+
+fn is_zero(count: &usize) -> bool {
+    *count == 0
+}
+
+fn is_zero_minutes(minutes: &u64) -> bool {
+    *minutes == 0
 }
 
 impl WorkstreamStats {
@@ -26,12 +59,49 @@ impl WorkstreamStats {
     /// assert_eq!(s.pull_requests, 0);
     /// assert_eq!(s.reviews, 0);
     /// assert_eq!(s.manual_events, 0);
+    /// assert_eq!(s.issues, 0);
     /// ```
     pub fn zero() -> Self {
         Self {
             pull_requests: 0,
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
+        }
+    }
+
+    /// Add one event's effort to the matching minute total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::schema::event::{Effort, EffortBasis};
+    /// use shiplog::schema::workstream::WorkstreamStats;
+    ///
+    /// let mut s = WorkstreamStats::zero();
+    /// s.add_effort(Some(Effort { minutes: 90, basis: EffortBasis::Logged }));
+    /// s.add_effort(None);
+    /// assert_eq!(s.logged_minutes, 90);
+    /// assert_eq!(s.elapsed_minutes, 0);
+    /// ```
+    pub fn add_effort(&mut self, effort: Option<Effort>) {
+        match effort {
+            Some(Effort {
+                minutes,
+                basis: EffortBasis::Logged,
+            }) => self.logged_minutes += minutes,
+            Some(Effort {
+                minutes,
+                basis: EffortBasis::Elapsed,
+            }) => self.elapsed_minutes += minutes,
+            None => {}
         }
     }
 }
@@ -53,6 +123,82 @@ pub struct Workstream {
     pub events: Vec<EventId>,
     /// Curated receipts (subset of events) used in the packet.
     pub receipts: Vec<EventId>,
+    /// Per-receipt weight overrides, keyed by event ID. Receipts without an
+    /// entry use the weight derived from their kind and size.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub receipt_weights: BTreeMap<EventId, ReceiptWeight>,
+    /// Rules that attach newly collected events to this workstream when a
+    /// curated file is refreshed.
+    #[serde(default, skip_serializing_if = "WorkstreamRules::is_empty")]
+    pub rules: WorkstreamRules,
+}
+
+/// Which newly collected events a curated workstream claims.
+///
+/// An event matches when any rule does.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::workstream::WorkstreamRules;
+///
+/// let rules: WorkstreamRules =
+///     serde_yaml::from_str("repos: [acme/billing, acme-payments/*]\ntitles: [invoice]").unwrap();
+/// assert_eq!(rules.repos.len(), 2);
+/// assert!(rules.tags.is_empty());
+/// assert!(WorkstreamRules::default().is_empty());
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkstreamRules {
+    /// Repository full names; `owner/*` matches every repository of an owner.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repos: Vec<String>,
+    /// Event tags or issue labels.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Case-insensitive substrings of event titles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub titles: Vec<String>,
+}
+
+impl WorkstreamRules {
+    /// Whether no rule is set.
+    pub fn is_empty(&self) -> bool {
+        self.repos.is_empty() && self.tags.is_empty() && self.titles.is_empty()
+    }
+}
+
+/// How strongly a receipt backs a claim.
+///
+/// Ordered weakest first, so `High > Low`.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::workstream::ReceiptWeight;
+///
+/// assert!(ReceiptWeight::High > ReceiptWeight::Medium);
+/// assert_eq!(ReceiptWeight::Low.as_str(), "low");
+/// ```
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReceiptWeight {
+    /// Supporting detail: small PRs, reviews, comment threads.
+    Low,
+    /// Ordinary evidence.
+    Medium,
+    /// Anchor evidence: large PRs, releases, incidents, measured impact.
+    High,
+}
+
+impl ReceiptWeight {
+    /// Lowercase label used in packets and reports.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
 }
 
 /// Top-level workstreams file used for persistence and curation.
@@ -75,6 +221,8 @@ pub struct Workstream {
 ///         stats: WorkstreamStats::zero(),
 ///         events: vec![],
 ///         receipts: vec![],
+///         receipt_weights: Default::default(),
+///         rules: Default::default(),
 ///     }],
 /// };
 /// assert_eq!(file.workstreams.len(), 1);
@@ -107,6 +255,8 @@ impl Workstream {
     ///     stats: WorkstreamStats::zero(),
     ///     events: vec![],
     ///     receipts: vec![],
+    ///     receipt_weights: Default::default(),
+    ///     rules: Default::default(),
     /// };
     /// ws.bump_stats(&EventKind::PullRequest);
     /// ws.bump_stats(&EventKind::PullRequest);
@@ -119,6 +269,12 @@ impl Workstream {
             EventKind::PullRequest => self.stats.pull_requests += 1,
             EventKind::Review => self.stats.reviews += 1,
             EventKind::Manual => self.stats.manual_events += 1,
+            EventKind::Issue => self.stats.issues += 1,
+            EventKind::Deployment => self.stats.deployments += 1,
+            EventKind::Release => self.stats.releases += 1,
+            EventKind::Comment => self.stats.comments += 1,
+            EventKind::Incident => self.stats.incidents += 1,
+            EventKind::Document => self.stats.documents += 1,
         }
     }
 }
@@ -137,6 +293,8 @@ mod tests {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }
     }
 
@@ -181,6 +339,14 @@ mod tests {
             pull_requests: 5,
             reviews: 3,
             manual_events: 2,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         };
         let json = serde_json::to_string(&stats).unwrap();
         let back: WorkstreamStats = serde_json::from_str(&json).unwrap();
@@ -198,9 +364,19 @@ mod tests {
                 pull_requests: 2,
                 reviews: 1,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![shiplog::ids::EventId::from_parts(["e1"])],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         };
         let json = serde_json::to_string(&ws).unwrap();
         let back: Workstream = serde_json::from_str(&json).unwrap();
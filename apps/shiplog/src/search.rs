@@ -0,0 +1,232 @@
+//! Full-text search over a run's event ledger.
+//!
+//! `shiplog collect --search-index` writes `search.index.sqlite` next to
+//! `ledger.events.jsonl`, and `shiplog search` queries it. The index is an
+//! SQLite FTS5 table ranked with BM25, so multi-word queries return the
+//! events that match every term, best matches first. Titles weigh more than
+//! repository names, which weigh more than descriptions, tags, and labels.
+//!
+//! The index is derived data: it can be deleted and rebuilt from the ledger
+//! at any time, and it is never included in share bundles.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OpenFlags, params};
+use serde::Serialize;
+
+use crate::schema::event::{EventEnvelope, EventPayload};
+
+/// BM25 column weights for `title`, `repo`, `kind`, and `body`.
+const RANK: &str = "bm25(events, 10.0, 4.0, 2.0, 1.0)";
+
+/// One event matching a search query.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SearchHit {
+    pub event_id: String,
+    pub kind: String,
+    pub occurred_at: DateTime<Utc>,
+    pub title: String,
+    pub repo: String,
+    /// Best link back to the event in its source system, when one is known.
+    pub url: Option<String>,
+}
+
+/// A full-text index over ledger events, stored in an SQLite database.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::fixtures::{FixtureSpec, generate};
+/// use shiplog::search::SearchIndex;
+///
+/// let dir = tempfile::tempdir()?;
+/// let fixture = generate(&FixtureSpec::default())?;
+/// let index = SearchIndex::build(dir.path().join("search.index.sqlite"), &fixture.events)?;
+///
+/// assert_eq!(index.len()?, fixture.events.len());
+/// let hits = index.search("retry handling", 5)?;
+/// assert!(!hits.is_empty());
+/// assert!(hits.iter().all(|hit| hit.title.contains("retry handling")));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct SearchIndex {
+    conn: Connection,
+}
+
+impl SearchIndex {
+    /// Build a fresh index at `path` from `events`, replacing any existing
+    /// index file.
+    pub fn build(path: impl AsRef<Path>, events: &[EventEnvelope]) -> Result<Self> {
+        let path = path.as_ref();
+        if path.exists() {
+            std::fs::remove_file(path)
+                .with_context(|| format!("remove stale search index {path:?}"))?;
+        }
+        let mut conn =
+            Connection::open(path).with_context(|| format!("create search index {path:?}"))?;
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE events USING fts5(
+                title,
+                repo,
+                kind,
+                body,
+                event_id UNINDEXED,
+                occurred_at UNINDEXED,
+                url UNINDEXED,
+                tokenize = 'porter unicode61'
+            )",
+        )
+        .context("create search index table (SQLite needs FTS5)")?;
+
+        let tx = conn.transaction().context("begin search index write")?;
+        {
+            let mut insert = tx.prepare(
+                "INSERT INTO events (title, repo, kind, body, event_id, occurred_at, url)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+            for event in events {
+                insert
+                    .execute(params![
+                        event_title(event),
+                        event.repo.full_name,
+                        event.kind.to_string(),
+                        event_body(event),
+                        event.id.to_string(),
+                        event.occurred_at,
+                        event_url(event),
+                    ])
+                    .with_context(|| format!("index event {}", event.id))?;
+            }
+        }
+        tx.commit().context("commit search index")?;
+        Ok(Self { conn })
+    }
+
+    /// Open an existing index read-only.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("open search index {path:?}"))?;
+        Ok(Self { conn })
+    }
+
+    /// Number of indexed events.
+    pub fn len(&self) -> Result<usize> {
+        let count: i64 = self
+            .conn
+            .query_row("SELECT count(*) FROM events", [], |row| row.get(0))
+            .context("count indexed events")?;
+        Ok(usize::try_from(count).unwrap_or_default())
+    }
+
+    /// Whether the index holds no events.
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Events matching every term in `terms`, best match first.
+    ///
+    /// Terms are whitespace-separated words matched case-insensitively and
+    /// with English stemming (`fixes` matches `fixed`). A trailing `*`
+    /// matches a prefix (`deploy*`). Punctuation and FTS5 operators in the
+    /// input are treated as plain text.
+    pub fn search(&self, terms: &str, limit: usize) -> Result<Vec<SearchHit>> {
+        let Some(query) = fts_query(terms) else {
+            bail!("search terms cannot be blank");
+        };
+        let mut statement = self.conn.prepare(&format!(
+            "SELECT event_id, kind, occurred_at, title, repo, url
+             FROM events WHERE events MATCH ?1
+             ORDER BY {RANK}, occurred_at DESC
+             LIMIT ?2"
+        ))?;
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let hits = statement
+            .query_map(params![query, limit], |row| {
+                Ok(SearchHit {
+                    event_id: row.get(0)?,
+                    kind: row.get(1)?,
+                    occurred_at: row.get(2)?,
+                    title: row.get(3)?,
+                    repo: row.get(4)?,
+                    url: row.get(5)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .with_context(|| format!("search index for {terms:?}"))?;
+        Ok(hits)
+    }
+}
+
+/// Turn free text into an FTS5 query that ANDs each word as a quoted string,
+/// keeping a trailing `*` as a prefix match.
+fn fts_query(terms: &str) -> Option<String> {
+    let words: Vec<String> = terms
+        .split_whitespace()
+        .filter_map(|word| {
+            let (word, prefix) = match word.strip_suffix('*') {
+                Some(stem) => (stem, true),
+                None => (word, false),
+            };
+            let word = word.trim_matches('"');
+            if word.is_empty() {
+                return None;
+            }
+            let quoted = format!("\"{}\"", word.replace('"', "\"\""));
+            Some(if prefix { format!("{quoted}*") } else { quoted })
+        })
+        .collect();
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+fn event_title(event: &EventEnvelope) -> &str {
+    match &event.payload {
+        EventPayload::PullRequest(payload) => &payload.title,
+        EventPayload::Review(payload) => &payload.pull_title,
+        EventPayload::Manual(payload) => &payload.title,
+        EventPayload::Issue(payload) => &payload.title,
+        EventPayload::Deployment(payload) => &payload.environment,
+        EventPayload::Release(payload) => payload.name.as_deref().unwrap_or(&payload.version),
+        EventPayload::Comment(payload) => &payload.target_title,
+        EventPayload::Incident(payload) => &payload.title,
+        EventPayload::Document(payload) => &payload.title,
+    }
+}
+
+/// Secondary text: descriptions, labels, tags, and link labels.
+fn event_body(event: &EventEnvelope) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    match &event.payload {
+        EventPayload::PullRequest(payload) => {
+            parts.extend(payload.touched_paths_hint.iter().map(String::as_str));
+        }
+        EventPayload::Manual(payload) => {
+            parts.extend(payload.description.as_deref());
+            parts.extend(payload.impact.as_deref());
+        }
+        EventPayload::Issue(payload) => parts.extend(payload.labels.iter().map(String::as_str)),
+        EventPayload::Deployment(payload) => parts.extend(payload.version.as_deref()),
+        EventPayload::Release(payload) => parts.push(&payload.version),
+        EventPayload::Incident(payload) => {
+            parts.extend(payload.summary.as_deref());
+            parts.extend(payload.customer_impact.as_deref());
+        }
+        EventPayload::Document(payload) => parts.push(&payload.system),
+        EventPayload::Review(_) | EventPayload::Comment(_) => {}
+    }
+    parts.extend(event.tags.iter().map(String::as_str));
+    parts.extend(event.links.iter().map(|link| link.label.as_str()));
+    parts.join("\n")
+}
+
+fn event_url(event: &EventEnvelope) -> Option<&str> {
+    event
+        .source
+        .url
+        .as_deref()
+        .or_else(|| event.links.first().map(|link| link.url.as_str()))
+        .or(event.repo.html_url.as_deref())
+}
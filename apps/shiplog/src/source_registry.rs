@@ -0,0 +1,210 @@
+//! Built-in sources for `shiplog collect --source <name>`.
+
+use anyhow::Result;
+use shiplog::registry::{IngestorRegistry, SourceConfig};
+
+use crate::*;
+
+/// Registry with every source that ships with shiplog.
+pub(super) fn builtin_ingestor_registry() -> Result<IngestorRegistry> {
+    let mut registry = IngestorRegistry::new();
+    registry.register("github", "GitHub pull requests and reviews", |config| {
+        config.expect_options(&[
+            "mode",
+            "repo_owners",
+            "include_reviews",
+            "no_details",
+            "throttle_ms",
+            "api_base",
+        ])?;
+        let ing = make_github_ingestor(
+            config.require_user()?,
+            config.since,
+            config.until,
+            config.option("mode").unwrap_or("merged"),
+            config.list_option("repo_owners"),
+            config.parse_option("include_reviews")?.unwrap_or(false),
+            config.parse_option("no_details")?.unwrap_or(false),
+            config.parse_option("throttle_ms")?.unwrap_or(0),
+            std::env::var("GITHUB_TOKEN").ok(),
+            config
+                .option("api_base")
+                .unwrap_or("https://api.github.com"),
+            config.cache_dir.clone(),
+        )?;
+        Ok(Box::new(ing))
+    })?;
+    registry.register(
+        "gitlab",
+        "GitLab merge requests and review notes",
+        |config| {
+            config.expect_options(&["state", "instance", "include_reviews", "throttle_ms"])?;
+            let ing = make_gitlab_ingestor(
+                config.require_user()?,
+                config.since,
+                config.until,
+                config.option("state").unwrap_or("merged"),
+                config.option("instance").unwrap_or("gitlab.com"),
+                config.parse_option("include_reviews")?.unwrap_or(false),
+                config.parse_option("throttle_ms")?.unwrap_or(0),
+                None,
+                config.cache_dir.clone(),
+            )?;
+            Ok(Box::new(ing))
+        },
+    )?;
+    registry.register("bitbucket", "Bitbucket Cloud pull requests", |config| {
+        config.expect_options(&[
+            "state",
+            "workspace",
+            "auth_user",
+            "max_pages",
+            "throttle_ms",
+        ])?;
+        let ing = make_bitbucket_ingestor(
+            config.require_user()?,
+            config.since,
+            config.until,
+            config.option("state").unwrap_or("merged"),
+            config.option("workspace").map(str::to_string),
+            config.parse_option("max_pages")?,
+            config.parse_option("throttle_ms")?.unwrap_or(0),
+            config.option("auth_user").map(str::to_string),
+            None,
+            config.cache_dir.clone(),
+        )?;
+        Ok(Box::new(ing))
+    })?;
+    registry.register("gerrit", "Gerrit changes and review votes", |config| {
+        config.expect_options(&[
+            "instance",
+            "status",
+            "include_reviews",
+            "auth_user",
+            "max_pages",
+            "throttle_ms",
+        ])?;
+        let ing = make_gerrit_ingestor(
+            config.require_user()?,
+            config.since,
+            config.until,
+            config.require_option("instance")?,
+            config.option("status").unwrap_or("merged"),
+            config.parse_option("include_reviews")?.unwrap_or(false),
+            config.parse_option("max_pages")?,
+            config.parse_option("throttle_ms")?.unwrap_or(0),
+            config.option("auth_user").map(str::to_string),
+            None,
+            config.cache_dir.clone(),
+        )?;
+        Ok(Box::new(ing))
+    })?;
+    registry.register("jira", "Jira issues", |config| {
+        config.expect_options(&["instance", "status", "auth_user", "throttle_ms"])?;
+        let ing = make_jira_ingestor(
+            config.require_user()?,
+            config.option("auth_user").map(str::to_string),
+            config.since,
+            config.until,
+            config.option("status").unwrap_or("done"),
+            config.require_option("instance")?,
+            config.parse_option("throttle_ms")?.unwrap_or(0),
+            None,
+            config.cache_dir.clone(),
+        )?;
+        Ok(Box::new(ing))
+    })?;
+    registry.register(
+        "linear",
+        "Linear issues (--user is the user ID)",
+        |config| {
+            config.expect_options(&["status", "project", "throttle_ms"])?;
+            let ing = make_linear_ingestor(
+                config.require_user()?,
+                config.since,
+                config.until,
+                config.option("status").unwrap_or("done"),
+                config.option("project").map(str::to_string),
+                config.parse_option("throttle_ms")?.unwrap_or(0),
+                None,
+                config.cache_dir.clone(),
+            )?;
+            Ok(Box::new(ing))
+        },
+    )?;
+    registry.register(
+        "slack",
+        "Slack ship-notes messages (--user is the member ID)",
+        |config| {
+            config.expect_options(&[
+                "channels",
+                "launch_channels",
+                "workspace",
+                "max_pages",
+                "throttle_ms",
+            ])?;
+            config.require_option("channels")?;
+            let ing = make_slack_ingestor(
+                config.require_user()?,
+                config.since,
+                config.until,
+                config.list_option("channels"),
+                config.list_option("launch_channels"),
+                config.option("workspace").map(str::to_string),
+                config.parse_option("max_pages")?,
+                config.parse_option("throttle_ms")?.unwrap_or(0),
+                None,
+                config.cache_dir.clone(),
+            )?;
+            Ok(Box::new(ing))
+        },
+    )?;
+    registry.register(
+        "git",
+        "Local git commits (--user is the author)",
+        |config| {
+            config.expect_options(&["repo", "include_merges"])?;
+            let repo = config
+                .path_option("repo")
+                .unwrap_or_else(|| config.base_dir.clone());
+            Ok(Box::new(make_git_ingestor(
+                &repo,
+                config.since,
+                config.until,
+                config.user.clone(),
+                config.parse_option("include_merges")?.unwrap_or(false),
+            )))
+        },
+    )?;
+    registry.register("github-export", "GitHub account export archive", |config| {
+        config.expect_options(&["archive"])?;
+        let archive = resolve_required_path(config, "archive")?;
+        Ok(Box::new(make_github_export_ingestor(
+            &archive,
+            config.since,
+            config.until,
+            config.user.clone(),
+        )))
+    })?;
+    registry.register("json", "JSONL ledger plus coverage manifest", |config| {
+        config.expect_options(&["events", "coverage"])?;
+        Ok(Box::new(JsonIngestor {
+            events_path: resolve_required_path(config, "events")?,
+            coverage_path: resolve_required_path(config, "coverage")?,
+        }))
+    })?;
+    registry.register("manual", "Manual events YAML", |config| {
+        config.expect_options(&["events"])?;
+        Ok(Box::new(ManualIngestor::new(
+            &resolve_required_path(config, "events")?,
+            config.user.clone().unwrap_or_else(|| "user".to_string()),
+            config.since,
+            config.until,
+        )))
+    })?;
+    Ok(registry)
+}
+
+fn resolve_required_path(config: &SourceConfig, key: &str) -> Result<PathBuf> {
+    Ok(config.base_dir.join(config.require_option(key)?))
+}
@@ -0,0 +1,154 @@
+//! Who the actor worked with, grouped by how they worked together.
+
+use crate::schema::event::{ContributorRole, EventEnvelope, EventPayload};
+use std::collections::BTreeSet;
+
+/// Distinct people the actor collaborated with, by relationship.
+///
+/// Logins are compared case-insensitively and kept as first seen. The actor
+/// is never listed as their own collaborator.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Collaboration {
+    /// Authors of PRs the actor reviewed.
+    pub reviewed_authors: BTreeSet<String>,
+    /// People who reviewed the actor's PRs.
+    pub reviewers: BTreeSet<String>,
+    /// Co-authors on the actor's changes.
+    pub co_authors: BTreeSet<String>,
+    /// People who reviewed the same changes alongside the actor.
+    pub co_reviewers: BTreeSet<String>,
+    /// Pairing partners.
+    pub pairs: BTreeSet<String>,
+}
+
+impl Collaboration {
+    /// `true` when no collaborators were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.groups().iter().all(|(_, people)| people.is_empty())
+    }
+
+    /// Distinct people across every relationship.
+    pub fn people(&self) -> usize {
+        self.groups()
+            .iter()
+            .flat_map(|(_, people)| people.iter())
+            .map(|login| login.to_ascii_lowercase())
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+
+    /// Each relationship with a display label, in a fixed order.
+    pub fn groups(&self) -> [(&'static str, &BTreeSet<String>); 5] {
+        [
+            ("Reviewed PRs by", &self.reviewed_authors),
+            ("Reviewers on my PRs", &self.reviewers),
+            ("Co-authors", &self.co_authors),
+            ("Co-reviewers", &self.co_reviewers),
+            ("Pairing partners", &self.pairs),
+        ]
+    }
+}
+
+/// Collect collaborators from PR authors on reviews and event contributors.
+pub fn collaboration<'a>(events: impl IntoIterator<Item = &'a EventEnvelope>) -> Collaboration {
+    let mut out = Collaboration::default();
+    let mut seen = BTreeSet::new();
+    for event in events {
+        let actor = event.actor.login.as_str();
+        if let EventPayload::Review(review) = &event.payload
+            && let Some(author) = review.pull_author.as_deref()
+        {
+            insert(
+                &mut out.reviewed_authors,
+                &mut seen,
+                "author",
+                author,
+                actor,
+            );
+        }
+        for contributor in &event.contributors {
+            let (group, key) = match contributor.role {
+                ContributorRole::Reviewer => (&mut out.reviewers, "reviewer"),
+                ContributorRole::CoAuthor => (&mut out.co_authors, "co-author"),
+                ContributorRole::CoReviewer => (&mut out.co_reviewers, "co-reviewer"),
+                ContributorRole::Pair => (&mut out.pairs, "pair"),
+            };
+            insert(group, &mut seen, key, &contributor.login, actor);
+        }
+    }
+    out
+}
+
+fn insert(
+    group: &mut BTreeSet<String>,
+    seen: &mut BTreeSet<(&'static str, String)>,
+    key: &'static str,
+    login: &str,
+    actor: &str,
+) {
+    if login.eq_ignore_ascii_case(actor) {
+        return;
+    }
+    if seen.insert((key, login.to_ascii_lowercase())) {
+        group.insert(login.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{FixtureSpec, generate};
+    use crate::schema::event::Contributor;
+
+    fn events(mix: &str, count: usize) -> Vec<EventEnvelope> {
+        generate(&FixtureSpec {
+            events: count,
+            mix: mix.parse().unwrap(),
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events
+    }
+
+    fn contributor(login: &str, role: ContributorRole) -> Contributor {
+        Contributor {
+            login: login.into(),
+            id: None,
+            role,
+        }
+    }
+
+    #[test]
+    fn groups_people_by_relationship_without_the_actor() {
+        let mut reviews = events("review=1", 3);
+        for (event, author) in reviews.iter_mut().zip(["alice", "Alice", "demo-dev"]) {
+            if let EventPayload::Review(review) = &mut event.payload {
+                review.pull_author = Some(author.into());
+            }
+        }
+        let mut prs = events("pr=1", 1);
+        prs[0].contributors = vec![
+            contributor("bob", ContributorRole::Reviewer),
+            contributor("alice", ContributorRole::CoAuthor),
+            contributor("demo-dev", ContributorRole::Pair),
+        ];
+
+        let collab = collaboration(reviews.iter().chain(&prs));
+        assert_eq!(
+            collab.reviewed_authors,
+            BTreeSet::from(["alice".to_string()])
+        );
+        assert_eq!(collab.reviewers, BTreeSet::from(["bob".to_string()]));
+        assert_eq!(collab.co_authors, BTreeSet::from(["alice".to_string()]));
+        assert!(collab.pairs.is_empty());
+        assert_eq!(collab.people(), 2);
+        assert!(!collab.is_empty());
+    }
+
+    #[test]
+    fn no_collaborators_is_empty() {
+        let collab = collaboration(&events("pr=1,review=1", 5));
+        assert!(collab.is_empty());
+        assert_eq!(collab.people(), 0);
+    }
+}
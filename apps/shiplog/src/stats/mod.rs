@@ -0,0 +1,14 @@
+//! Derived statistics over canonical events.
+//!
+//! These are computed from the ledger at render time rather than stored, so
+//! they stay consistent with whatever events survive filtering and redaction.
+
+mod collaboration;
+mod review_load;
+mod size;
+mod turnaround;
+
+pub use collaboration::{Collaboration, collaboration};
+pub use review_load::{ReviewLoad, review_load};
+pub use size::{PrSize, PrSizeDistribution, event_pr_size, pr_size, pr_size_distribution};
+pub use turnaround::{ReviewTurnaround, review_turnaround, review_turnaround_minutes};
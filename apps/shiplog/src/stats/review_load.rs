@@ -0,0 +1,137 @@
+//! Review load: how much reviewing happened, where, and for whom.
+
+use crate::coverage::ReportingTimezone;
+use crate::schema::event::{EventEnvelope, EventPayload};
+use chrono::{Datelike, Days, NaiveDate};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Review volume over a set of events.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReviewLoad {
+    /// Review events counted.
+    pub reviews: usize,
+    /// Reviews per Monday-first week, for weeks with at least one review.
+    pub weeks: BTreeMap<NaiveDate, usize>,
+    /// Distinct repositories reviewed in.
+    pub repos: usize,
+    /// Distinct PR authors reviewed, not counting the reviewer themselves.
+    /// Zero when no source reported PR authors.
+    pub authors: usize,
+}
+
+impl ReviewLoad {
+    /// Median reviews per week that had any reviews.
+    pub fn median_per_active_week(&self) -> usize {
+        let mut counts: Vec<usize> = self.weeks.values().copied().collect();
+        counts.sort_unstable();
+        counts
+            .get(counts.len().saturating_sub(1) / 2)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Week with the most reviews; the earliest wins a tie.
+    pub fn busiest_week(&self) -> Option<(NaiveDate, usize)> {
+        self.weeks
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(week, count)| (*week, *count))
+    }
+}
+
+/// Tally the review events in `events`, bucketing weeks in `timezone`.
+/// Returns `None` when there are no reviews.
+pub fn review_load<'a>(
+    events: impl IntoIterator<Item = &'a EventEnvelope>,
+    timezone: ReportingTimezone,
+) -> Option<ReviewLoad> {
+    let mut reviews = 0;
+    let mut weeks: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    let mut repos = BTreeSet::new();
+    let mut authors = BTreeSet::new();
+    for event in events {
+        let EventPayload::Review(review) = &event.payload else {
+            continue;
+        };
+        reviews += 1;
+        *weeks
+            .entry(week_start(timezone.local_date(&review.submitted_at)))
+            .or_default() += 1;
+        repos.insert(event.repo.full_name.as_str());
+        if let Some(author) = review.pull_author.as_deref()
+            && !author.eq_ignore_ascii_case(&event.actor.login)
+        {
+            authors.insert(author.to_ascii_lowercase());
+        }
+    }
+    (reviews > 0).then_some(ReviewLoad {
+        reviews,
+        weeks,
+        repos: repos.len(),
+        authors: authors.len(),
+    })
+}
+
+fn week_start(day: NaiveDate) -> NaiveDate {
+    day.checked_sub_days(Days::new(u64::from(day.weekday().num_days_from_monday())))
+        .unwrap_or(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{FixtureSpec, generate};
+    use chrono::{TimeZone, Utc};
+
+    fn review(repo: &str, day: u32, author: Option<&str>) -> EventEnvelope {
+        let mut event = generate(&FixtureSpec {
+            events: 1,
+            mix: "review=1".parse().unwrap(),
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events
+        .remove(0);
+        event.repo.full_name = repo.into();
+        let EventPayload::Review(payload) = &mut event.payload else {
+            panic!("fixture event should be a review");
+        };
+        payload.submitted_at = Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap();
+        payload.pull_author = author.map(str::to_string);
+        event
+    }
+
+    #[test]
+    fn counts_weeks_repos_and_authors() {
+        // 2025-03-10 and 2025-03-17 are Mondays.
+        let events = vec![
+            review("acme/api", 10, Some("alice")),
+            review("acme/api", 12, Some("Alice")),
+            review("acme/web", 14, Some("bob")),
+            review("acme/web", 18, None),
+            review("acme/infra", 25, Some("demo-dev")),
+        ];
+
+        let load = review_load(&events, ReportingTimezone::UTC).unwrap();
+        assert_eq!(load.reviews, 5);
+        assert_eq!(load.repos, 3);
+        assert_eq!(load.authors, 2);
+        assert_eq!(
+            load.weeks
+                .keys()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["2025-03-10", "2025-03-17", "2025-03-24"]
+        );
+        assert_eq!(
+            load.busiest_week(),
+            Some((NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(), 3))
+        );
+        assert_eq!(load.median_per_active_week(), 1);
+    }
+
+    #[test]
+    fn no_reviews_yields_none() {
+        assert_eq!(review_load(&[], ReportingTimezone::UTC), None);
+    }
+}
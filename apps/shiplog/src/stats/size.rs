@@ -0,0 +1,161 @@
+//! PR size buckets from lines changed.
+
+use crate::schema::event::{EventEnvelope, EventPayload, PullRequestEvent};
+use std::fmt;
+
+/// Size bucket for a PR by additions plus deletions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrSize {
+    /// Fewer than 10 lines: typo fixes, version bumps.
+    XS,
+    /// 10–49 lines.
+    S,
+    /// 50–249 lines.
+    M,
+    /// 250–999 lines.
+    L,
+    /// 1000 lines or more.
+    XL,
+}
+
+impl PrSize {
+    /// Every bucket, smallest first.
+    pub const ALL: [PrSize; 5] = [PrSize::XS, PrSize::S, PrSize::M, PrSize::L, PrSize::XL];
+
+    /// Bucket for a number of changed lines.
+    pub fn from_lines(lines: u64) -> Self {
+        match lines {
+            0..10 => PrSize::XS,
+            10..50 => PrSize::S,
+            50..250 => PrSize::M,
+            250..1000 => PrSize::L,
+            _ => PrSize::XL,
+        }
+    }
+
+    /// Short label used in packets, such as `XS`.
+    pub fn label(self) -> &'static str {
+        match self {
+            PrSize::XS => "XS",
+            PrSize::S => "S",
+            PrSize::M => "M",
+            PrSize::L => "L",
+            PrSize::XL => "XL",
+        }
+    }
+}
+
+impl fmt::Display for PrSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Size bucket for a PR, or `None` when the source reported neither
+/// additions nor deletions.
+pub fn pr_size(pr: &PullRequestEvent) -> Option<PrSize> {
+    if pr.additions.is_none() && pr.deletions.is_none() {
+        return None;
+    }
+    let lines = pr.additions.unwrap_or(0) + pr.deletions.unwrap_or(0);
+    Some(PrSize::from_lines(lines))
+}
+
+/// Size bucket for an event, when it is a PR with known line counts.
+pub fn event_pr_size(event: &EventEnvelope) -> Option<PrSize> {
+    match &event.payload {
+        EventPayload::PullRequest(pr) => pr_size(pr),
+        _ => None,
+    }
+}
+
+/// How many PRs fall in each size bucket.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrSizeDistribution {
+    /// Counts indexed like [`PrSize::ALL`].
+    pub counts: [usize; 5],
+    /// PRs whose source did not report line counts.
+    pub unknown: usize,
+}
+
+impl PrSizeDistribution {
+    /// PRs in `size`.
+    pub fn count(&self, size: PrSize) -> usize {
+        self.counts[size as usize]
+    }
+
+    /// PRs with a known size.
+    pub fn sized(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
+/// Tally the PR events in `events` by size.
+pub fn pr_size_distribution<'a>(
+    events: impl IntoIterator<Item = &'a EventEnvelope>,
+) -> PrSizeDistribution {
+    let mut distribution = PrSizeDistribution::default();
+    for event in events {
+        let EventPayload::PullRequest(pr) = &event.payload else {
+            continue;
+        };
+        match pr_size(pr) {
+            Some(size) => distribution.counts[size as usize] += 1,
+            None => distribution.unknown += 1,
+        }
+    }
+    distribution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{FixtureSpec, generate};
+
+    fn pr(additions: Option<u64>, deletions: Option<u64>) -> EventEnvelope {
+        let mut event = generate(&FixtureSpec {
+            events: 1,
+            mix: "pr=1".parse().unwrap(),
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events
+        .remove(0);
+        let EventPayload::PullRequest(payload) = &mut event.payload else {
+            panic!("fixture event should be a PR");
+        };
+        payload.additions = additions;
+        payload.deletions = deletions;
+        event
+    }
+
+    #[test]
+    fn buckets_by_additions_plus_deletions() {
+        assert_eq!(PrSize::from_lines(0), PrSize::XS);
+        assert_eq!(PrSize::from_lines(9), PrSize::XS);
+        assert_eq!(PrSize::from_lines(10), PrSize::S);
+        assert_eq!(PrSize::from_lines(249), PrSize::M);
+        assert_eq!(PrSize::from_lines(250), PrSize::L);
+        assert_eq!(PrSize::from_lines(1000), PrSize::XL);
+
+        assert_eq!(event_pr_size(&pr(Some(40), Some(15))), Some(PrSize::M));
+        assert_eq!(event_pr_size(&pr(None, Some(3))), Some(PrSize::XS));
+        assert_eq!(event_pr_size(&pr(None, None)), None);
+    }
+
+    #[test]
+    fn distribution_counts_unknown_sizes_separately() {
+        let events = vec![
+            pr(Some(1), Some(1)),
+            pr(Some(5), Some(0)),
+            pr(Some(300), Some(20)),
+            pr(None, None),
+        ];
+        let distribution = pr_size_distribution(&events);
+        assert_eq!(distribution.count(PrSize::XS), 2);
+        assert_eq!(distribution.count(PrSize::L), 1);
+        assert_eq!(distribution.count(PrSize::XL), 0);
+        assert_eq!(distribution.sized(), 3);
+        assert_eq!(distribution.unknown, 1);
+    }
+}
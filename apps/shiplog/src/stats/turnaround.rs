@@ -0,0 +1,145 @@
+//! Review turnaround: how long a PR waited between being opened and my review.
+
+use crate::schema::event::{EventEnvelope, EventPayload, ReviewEvent};
+use std::collections::HashMap;
+
+/// Turnaround across a set of reviewed PRs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReviewTurnaround {
+    /// Number of distinct PRs with a measurable turnaround.
+    pub reviews: usize,
+    /// Median minutes from PR opened to first review.
+    pub median_minutes: u64,
+    /// 90th percentile (nearest rank) minutes from PR opened to first review.
+    pub p90_minutes: u64,
+}
+
+/// Minutes from the PR being opened to this review, when the source recorded
+/// when the PR was opened.
+///
+/// Reviews that appear to predate the PR (clock skew, reopened PRs) count as
+/// zero rather than being dropped.
+pub fn review_turnaround_minutes(review: &ReviewEvent) -> Option<u64> {
+    let opened = review.pull_created_at?;
+    let minutes = (review.submitted_at - opened).num_minutes();
+    Some(u64::try_from(minutes).unwrap_or(0))
+}
+
+/// Aggregate turnaround over the review events in `events`.
+///
+/// Only the earliest review of each PR counts, so a PR reviewed three times
+/// measures how quickly it was first picked up. Returns `None` when no review
+/// has a known PR open time.
+pub fn review_turnaround<'a>(
+    events: impl IntoIterator<Item = &'a EventEnvelope>,
+) -> Option<ReviewTurnaround> {
+    let mut first: HashMap<(&str, u64), &ReviewEvent> = HashMap::new();
+    for event in events {
+        let EventPayload::Review(review) = &event.payload else {
+            continue;
+        };
+        if review.pull_created_at.is_none() {
+            continue;
+        }
+        first
+            .entry((event.repo.full_name.as_str(), review.pull_number))
+            .and_modify(|current| {
+                if review.submitted_at < current.submitted_at {
+                    *current = review;
+                }
+            })
+            .or_insert(review);
+    }
+
+    let mut minutes: Vec<u64> = first
+        .values()
+        .filter_map(|review| review_turnaround_minutes(review))
+        .collect();
+    if minutes.is_empty() {
+        return None;
+    }
+    minutes.sort_unstable();
+    Some(ReviewTurnaround {
+        reviews: minutes.len(),
+        median_minutes: nearest_rank(&minutes, 50),
+        p90_minutes: nearest_rank(&minutes, 90),
+    })
+}
+
+/// Nearest-rank percentile of an ascending, non-empty slice.
+fn nearest_rank(sorted: &[u64], percentile: usize) -> u64 {
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{FixtureSpec, generate};
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn review(
+        repo: &str,
+        pull_number: u64,
+        opened_hours_before: Option<i64>,
+        at_hour: u32,
+    ) -> EventEnvelope {
+        let mut event = generate(&FixtureSpec {
+            events: 1,
+            ..FixtureSpec::default()
+        })
+        .unwrap()
+        .events
+        .remove(0);
+        let submitted_at = Utc.with_ymd_and_hms(2025, 3, 10, at_hour, 0, 0).unwrap();
+        event.repo.full_name = repo.into();
+        event.payload = EventPayload::Review(ReviewEvent {
+            pull_number,
+            pull_title: format!("PR #{pull_number}"),
+            submitted_at,
+            pull_created_at: opened_hours_before.map(|hours| submitted_at - Duration::hours(hours)),
+            state: "approved".into(),
+            window: None,
+            pull_author: None,
+        });
+        event
+    }
+
+    #[test]
+    fn measures_minutes_from_open_to_review() {
+        let event = review("acme/api", 1, Some(3), 12);
+        let EventPayload::Review(payload) = &event.payload else {
+            unreachable!()
+        };
+        assert_eq!(review_turnaround_minutes(payload), Some(180));
+
+        let unknown = review("acme/api", 2, None, 12);
+        let EventPayload::Review(payload) = &unknown.payload else {
+            unreachable!()
+        };
+        assert_eq!(review_turnaround_minutes(payload), None);
+    }
+
+    #[test]
+    fn aggregates_first_review_per_pr() {
+        let events = vec![
+            // PR 1 opened at 09:00: reviewed at 10:00 and again at 15:00.
+            review("acme/api", 1, Some(6), 15),
+            review("acme/api", 1, Some(1), 10),
+            review("acme/api", 2, Some(2), 12),
+            review("acme/web", 1, Some(10), 12),
+            review("acme/web", 2, None, 12),
+        ];
+
+        let stats = review_turnaround(&events).unwrap();
+        assert_eq!(stats.reviews, 3);
+        assert_eq!(stats.median_minutes, 120);
+        assert_eq!(stats.p90_minutes, 600);
+    }
+
+    #[test]
+    fn no_measurable_reviews_yields_none() {
+        assert_eq!(review_turnaround(&[review("acme/api", 1, None, 12)]), None);
+        assert_eq!(review_turnaround(&[]), None);
+    }
+}
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
+use chrono::{DateTime, Utc};
 use serde::Serialize;
+use shiplog::ids::RunId;
 use std::{
     cmp::Reverse,
     fs,
@@ -393,7 +395,7 @@ fn resolve_run_receipt(
     problems: &mut Vec<ReviewLoopReceiptProblem>,
 ) -> ResolvedRunReceipt {
     let report_path = run_dir.join(INTAKE_REPORT_FILENAME);
-    let run_id = run_sort_key(run_dir);
+    let run_id = run_dir_name(run_dir);
 
     let (report_state, report_shape) = match fs::read_to_string(&report_path) {
         Ok(text) => match serde_json::from_str::<serde_json::Value>(&text) {
@@ -541,7 +543,16 @@ fn problem(
     }
 }
 
-fn run_sort_key(run_dir: &Path) -> String {
+/// Chronological sort key for a run directory, read from its run id.
+///
+/// Hand-named directories sort after minted ones, by name.
+pub(crate) fn run_sort_key(run_dir: &Path) -> (DateTime<Utc>, String) {
+    let run_id = RunId(run_dir_name(run_dir));
+    let (created, tiebreak) = run_id.sort_key();
+    (created, tiebreak.to_string())
+}
+
+fn run_dir_name(run_dir: &Path) -> String {
     run_dir
         .file_name()
         .and_then(|name| name.to_str())
@@ -7,17 +7,27 @@
 
 use anyhow::{Context, Result, anyhow};
 use chrono::{NaiveDate, Utc};
-use serde_json::Value;
 use shiplog::ports::IngestOutput;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
 use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::json_schema::SchemaDocument;
+use shiplog::schema::migrate::{migrate_coverage, migrate_event};
+use shiplog::schema::version::SchemaVersion;
+use std::collections::BTreeMap;
 use std::fs;
-use std::io::Write;
+use std::io::{BufReader, BufWriter};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 
+use crate::engine::{read_events, write_events};
 use crate::merge::{ConflictResolution, merge_ingest_outputs};
 use crate::team::core::TeamConfig;
 use crate::team::render::{self, TeamAggregateResult, TeamMemberSummary};
+use crate::team::trends::{self, TeamTrends, TrendPeriod};
+
+/// Repositories listed per member in the packet's member breakdown.
+const TOP_REPOS_PER_MEMBER: usize = 3;
 
 /// Stable output artifact locations from team packet generation.
 #[derive(Debug, Clone)]
@@ -31,12 +41,25 @@ pub struct TeamOutputFiles {
 #[derive(Debug)]
 pub struct TeamAggregator {
     config: TeamConfig,
+    anonymize_key: Option<Vec<u8>>,
 }
 
 impl TeamAggregator {
     /// Create an aggregator with the given config.
     pub fn new(config: TeamConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            anonymize_key: None,
+        }
+    }
+
+    /// Render members as `Member 1`, `Member 2`, ... instead of their IDs
+    /// and display names, numbered in an order keyed by `key`. Per-member
+    /// and total counts are unchanged; see
+    /// [`TeamAggregateResult::anonymized`].
+    pub fn with_anonymized_members(mut self, key: impl AsRef<[u8]>) -> Self {
+        self.anonymize_key = Some(key.as_ref().to_vec());
+        self
     }
 
     /// Aggregate every configured member into one output packet.
@@ -56,18 +79,29 @@ impl TeamAggregator {
         let mut incompatible_members = Vec::new();
         let mut warnings = Vec::new();
 
-        for member in &requested_members {
+        let ledger_dirs: Vec<PathBuf> = requested_members
+            .iter()
+            .map(|member| member_root.join(member))
+            .collect();
+        let loaded = load_ledgers(
+            &ledger_dirs,
+            self.config.since,
+            self.config.until,
+            self.config.effective_parallelism(),
+        );
+
+        for ((member, ledger_dir), loaded) in requested_members.iter().zip(ledger_dirs).zip(loaded)
+        {
             let alias = self
                 .config
                 .aliases
                 .get(member)
                 .cloned()
                 .unwrap_or_else(|| member.clone());
-            let ledger_dir = member_root.join(member);
 
-            match load_member_output(&ledger_dir, self.config.since, self.config.until) {
-                Ok(Some((output, member_warnings))) => {
-                    if is_compatible_schema(&ledger_dir, &self.config.required_schema_version)? {
+            match loaded {
+                Ok(Some((output, member_warnings, written))) => {
+                    if is_compatible_schema(written, &self.config.required_schema_version) {
                         included_members.push(member.clone());
                         let warning_count = member_warnings.len();
                         if warning_count > 0 {
@@ -80,6 +114,11 @@ impl TeamAggregator {
                             ledger_dir,
                             event_count: output.events.len(),
                             warnings: member_warnings,
+                            kind_counts: kind_counts(&output.events),
+                            top_repos: render::group_events_by_repo(&output.events)
+                                .into_iter()
+                                .take(TOP_REPOS_PER_MEMBER)
+                                .collect(),
                         });
                         member_outputs.push(output);
                     } else {
@@ -94,6 +133,8 @@ impl TeamAggregator {
                             ledger_dir,
                             event_count: 0,
                             warnings: vec![warning],
+                            kind_counts: BTreeMap::new(),
+                            top_repos: Vec::new(),
                         });
                     }
                 }
@@ -111,6 +152,8 @@ impl TeamAggregator {
                         ledger_dir,
                         event_count: 0,
                         warnings: vec![warning],
+                        kind_counts: BTreeMap::new(),
+                        top_repos: Vec::new(),
                     });
                 }
                 Err(err) => {
@@ -127,6 +170,8 @@ impl TeamAggregator {
                         ledger_dir,
                         event_count: 0,
                         warnings: vec![warning],
+                        kind_counts: BTreeMap::new(),
+                        top_repos: Vec::new(),
                     });
                 }
             }
@@ -178,8 +223,22 @@ impl TeamAggregator {
 
     /// Render a markdown packet from aggregate output.
     pub fn render_packet_markdown(&self, output: &TeamAggregateResult) -> Result<String> {
+        if let Some(key) = &self.anonymize_key {
+            return render::render_packet_markdown(&self.config, &output.anonymized(key));
+        }
         render::render_packet_markdown(&self.config, output)
     }
+
+    /// Build a trend table from several historical run directories per
+    /// member, keyed by member ID. Only configured members are read; the
+    /// configured date range bounds the table when set.
+    pub fn trends(
+        &self,
+        member_runs: &BTreeMap<String, Vec<PathBuf>>,
+        period: TrendPeriod,
+    ) -> Result<TeamTrends> {
+        trends::build_team_trends(&self.config, member_runs, period)
+    }
 }
 
 /// Persist team outputs in the current directory layout used by the shiplog app.
@@ -196,13 +255,9 @@ pub fn write_team_outputs(
     let coverage_path = out_dir.join("team.coverage.manifest.json");
 
     std::fs::write(&packet_path, packet_markdown)?;
-    let mut events_file =
-        std::fs::File::create(&events_path).context("create team events output")?;
-    for event in &result.events {
-        let line = serde_json::to_string(event).context("serialize team event")?;
-        events_file.write_all(line.as_bytes())?;
-        events_file.write_all(b"\n")?;
-    }
+    let events_file = fs::File::create(&events_path).context("create team events output")?;
+    write_events(BufWriter::new(events_file), &result.events)
+        .context("write team events output")?;
 
     let coverage_payload = serde_json::to_string_pretty(&result.coverage)
         .context("serialize team coverage manifest")?;
@@ -215,28 +270,79 @@ pub fn write_team_outputs(
     })
 }
 
-fn is_compatible_schema(ledger_dir: &Path, required_version: &Option<String>) -> Result<bool> {
-    let required = required_version.as_deref();
-    if required.is_none() {
-        return Ok(true);
+fn kind_counts(events: &[EventEnvelope]) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for event in events {
+        *counts.entry(event.kind.to_string()).or_default() += 1;
     }
+    counts
+}
 
-    let version_path = ledger_dir.join("coverage.manifest.json");
-    let text = fs::read_to_string(&version_path)
-        .with_context(|| format!("read coverage manifest {version_path:?}"))?;
-    let json: Value = serde_json::from_str(&text).context("parse coverage manifest")?;
-    let detected = json
-        .get("schema_version")
-        .and_then(Value::as_str)
-        .unwrap_or("0.2.0");
-    Ok(detected == required.unwrap())
+/// Compare the version a member's ledger was written with against the
+/// team's pinned version. Ledgers without a `schema_version` read as
+/// [`SchemaVersion::LEGACY`].
+pub(crate) fn is_compatible_schema(
+    written: SchemaVersion,
+    required_version: &Option<String>,
+) -> bool {
+    required_version
+        .as_deref()
+        .is_none_or(|required| written.to_string() == required.trim())
+}
+
+pub(crate) type LoadedMember = Option<(IngestOutput, Vec<String>, SchemaVersion)>;
+
+/// Load every ledger directory on up to `workers` threads.
+///
+/// Results come back in `ledger_dirs` order regardless of which thread
+/// finished first, so aggregate output is identical for any worker count.
+pub(crate) fn load_ledgers(
+    ledger_dirs: &[PathBuf],
+    since: Option<NaiveDate>,
+    until: Option<NaiveDate>,
+    workers: usize,
+) -> Vec<Result<LoadedMember>> {
+    let load = |ledger_dir: &PathBuf| load_member_output(ledger_dir, since, until);
+    let workers = workers.clamp(1, ledger_dirs.len().max(1));
+    if workers == 1 {
+        return ledger_dirs.iter().map(load).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut loaded: Vec<(usize, Result<LoadedMember>)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut out = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(ledger_dir) = ledger_dirs.get(index) else {
+                            break;
+                        };
+                        out.push((index, load(ledger_dir)));
+                    }
+                    out
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+            })
+            .collect()
+    });
+    loaded.sort_by_key(|(index, _)| *index);
+    loaded.into_iter().map(|(_, result)| result).collect()
 }
 
 fn load_member_output(
     ledger_dir: &Path,
     since: Option<NaiveDate>,
     until: Option<NaiveDate>,
-) -> Result<Option<(IngestOutput, Vec<String>)>> {
+) -> Result<LoadedMember> {
     let events_path = ledger_dir.join("ledger.events.jsonl");
     let coverage_path = ledger_dir.join("coverage.manifest.json");
 
@@ -244,26 +350,27 @@ fn load_member_output(
         return Ok(None);
     }
 
-    let events = read_events(&events_path)?;
-    let coverage = read_coverage(&coverage_path)?;
+    let (coverage, written) = migrate_coverage(read_coverage(&coverage_path)?)?;
     let mut warnings = Vec::new();
 
-    let filtered: Vec<EventEnvelope> = events
-        .into_iter()
-        .filter(|event| {
-            if let Some(since) = since
-                && event.occurred_at.date_naive() < since
-            {
-                return false;
-            }
-            if let Some(until) = until
-                && event.occurred_at.date_naive() >= until
-            {
-                return false;
-            }
-            true
-        })
-        .collect();
+    // Stream the ledger so only events inside the window are held in memory.
+    // Migration errors name the offending version; the caller adds the path.
+    let file = fs::File::open(&events_path).with_context(|| format!("read {events_path:?}"))?;
+    let mut filtered = Vec::new();
+    for event in read_events(BufReader::new(file)) {
+        let event = migrate_event(event.with_context(|| format!("in {events_path:?}"))?)?;
+        if let Some(since) = since
+            && event.occurred_at.date_naive() < since
+        {
+            continue;
+        }
+        if let Some(until) = until
+            && event.occurred_at.date_naive() >= until
+        {
+            continue;
+        }
+        filtered.push(event);
+    }
 
     if filtered.is_empty() {
         warnings.push(format!(
@@ -277,30 +384,18 @@ fn load_member_output(
             events: filtered,
             coverage,
             freshness: Vec::new(),
+            budget: Vec::new(),
         },
         warnings,
+        written,
     )))
 }
 
-fn read_events(path: &Path) -> Result<Vec<EventEnvelope>> {
-    let text = fs::read_to_string(path).with_context(|| format!("read {path:?}"))?;
-    let mut events = Vec::new();
-    for (i, line) in text.lines().enumerate() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-        let event: EventEnvelope = serde_json::from_str(line)
-            .with_context(|| format!("parse event line {} in {:?}", i + 1, path))?;
-        events.push(event);
-    }
-    Ok(events)
-}
-
 fn read_coverage(path: &Path) -> Result<CoverageManifest> {
     let text = fs::read_to_string(path).with_context(|| format!("read {path:?}"))?;
-    let cov: CoverageManifest =
-        serde_json::from_str(&text).with_context(|| format!("parse coverage manifest {path:?}"))?;
+    let cov: CoverageManifest = serde_json::from_str(&text)
+        .map_err(|err| SchemaDocument::CoverageManifest.annotate_json(&text, err))
+        .with_context(|| format!("parse coverage manifest {path:?}"))?;
     Ok(cov)
 }
 
@@ -312,6 +407,7 @@ fn empty_coverage(
 ) -> CoverageManifest {
     let today = Utc::now().date_naive();
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: shiplog::ids::RunId::now("team"),
         generated_at: Utc::now(),
         user: "team".to_string(),
@@ -324,6 +420,9 @@ fn empty_coverage(
         slices: Vec::new(),
         warnings,
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -333,10 +432,12 @@ mod tests {
     use chrono::{NaiveDate, TimeZone, Utc};
     use shiplog::ids::EventId;
     use shiplog::schema::coverage::TimeWindow;
+    use shiplog::schema::event::{EventEnvelope, EventKind};
     use std::io::Write;
 
     fn event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["team", id]),
             kind: shiplog::schema::event::EventKind::PullRequest,
             occurred_at: when,
@@ -373,11 +474,15 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
     fn coverage(run_id: &str) -> shiplog::schema::coverage::CoverageManifest {
         shiplog::schema::coverage::CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: shiplog::ids::RunId(run_id.to_string()),
             generated_at: Utc::now(),
             user: "alice".to_string(),
@@ -390,6 +495,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         }
     }
 
@@ -437,6 +545,40 @@ mod tests {
         assert!(result.warnings.is_empty());
     }
 
+    #[test]
+    fn parallel_load_matches_sequential_order() {
+        let root = tempfile::tempdir().unwrap();
+        let members: Vec<String> = (0..12).map(|i| format!("member-{i:02}")).collect();
+        for member in members.iter().step_by(2) {
+            write_run(root.path(), member, true);
+        }
+
+        let aggregate = |parallelism| {
+            TeamAggregator::new(TeamConfig {
+                members: members.iter().rev().cloned().collect(),
+                parallelism: Some(parallelism),
+                ..TeamConfig::default()
+            })
+            .aggregate(root.path())
+            .unwrap()
+        };
+        let sequential = aggregate(1);
+        let parallel = aggregate(4);
+
+        assert_eq!(parallel.included_members, sequential.included_members);
+        assert_eq!(parallel.missing_members, sequential.missing_members);
+        assert_eq!(parallel.warnings, sequential.warnings);
+        let ids = |result: &TeamAggregateResult| {
+            result
+                .events
+                .iter()
+                .map(|e| e.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&parallel), ids(&sequential));
+        assert_eq!(parallel.included_members.len(), 6);
+    }
+
     #[test]
     fn missing_member_reports_warning_and_continues() {
         let root = tempfile::tempdir().unwrap();
@@ -470,4 +612,58 @@ mod tests {
         assert!(packet.contains("# Team Shipping Packet"));
         assert!(packet.contains("Total events: 2"));
     }
+
+    #[test]
+    fn member_breakdown_is_opt_in_and_carries_disclaimer() {
+        let root = tempfile::tempdir().unwrap();
+        write_run(root.path(), "alice", true);
+        let config = TeamConfig {
+            members: vec!["alice".into()],
+            ..TeamConfig::default()
+        };
+
+        let agg = TeamAggregator::new(config.clone());
+        let output = agg.aggregate(root.path()).unwrap();
+        assert_eq!(output.members[0].kind_count(&EventKind::PullRequest), 2);
+        let packet = agg.render_packet_markdown(&output).unwrap();
+        assert!(!packet.contains("## Member Breakdown"));
+
+        let agg = TeamAggregator::new(TeamConfig {
+            member_breakdown: true,
+            ..config
+        });
+        let packet = agg.render_packet_markdown(&output).unwrap();
+        assert!(packet.contains("## Member Breakdown"));
+        assert!(packet.contains(render::MEMBER_BREAKDOWN_DISCLAIMER));
+        assert!(packet.contains("- Pull requests: 2\n- Reviews: 0\n"));
+        assert!(packet.contains("- Top repos: repo/one (1), repo/two (1)"));
+    }
+
+    #[test]
+    fn anonymized_packet_keeps_counts_and_hides_members() {
+        let root = tempfile::tempdir().unwrap();
+        write_run(root.path(), "alice", true);
+        write_run(root.path(), "bob", true);
+        let agg = TeamAggregator::new(TeamConfig {
+            members: vec!["alice".into(), "bob".into(), "carol".into()],
+            aliases: [("alice".to_string(), "Alice Smith".to_string())].into(),
+            ..TeamConfig::default()
+        })
+        .with_anonymized_members(b"team-key");
+
+        let output = agg.aggregate(root.path()).unwrap();
+        let packet = agg.render_packet_markdown(&output).unwrap();
+        assert!(packet.contains("Members configured: 3"));
+        assert!(packet.contains("Members included: 2"));
+        assert!(packet.contains("Total events: 4"));
+        assert!(packet.contains("### Member 1"));
+        assert!(packet.contains("Included events: 2"));
+        for name in ["alice", "Alice Smith", "bob", "carol"] {
+            assert!(!packet.contains(name), "{name} leaked:\n{packet}");
+        }
+        assert!(
+            !packet.contains(&root.path().display().to_string()),
+            "ledger path leaked:\n{packet}"
+        );
+    }
 }
@@ -35,22 +35,134 @@ pub struct TeamConfig {
     #[serde(default)]
     pub until: Option<NaiveDate>,
     /// Optional schema compatibility gate.
-    /// If present and member coverage has `schema_version`, incompatibility
-    /// causes the member to be skipped with a warning.
+    /// If present, members whose coverage manifest was written with a
+    /// different `schema_version` (missing reads as `0.2.0`) are skipped
+    /// with a warning.
     #[serde(default)]
     pub required_schema_version: Option<String>,
+    /// Maximum number of member ledgers loaded concurrently.
+    /// Defaults to the available CPU parallelism; `1` loads members one at a
+    /// time. Output ordering does not depend on this value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallelism: Option<usize>,
+    /// Render a per-member breakdown (events, PRs, reviews, top repos) with a
+    /// fairness disclaimer. Off by default.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub member_breakdown: bool,
+    /// Directory holding one ledger directory per member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_root: Option<PathBuf>,
 }
 
 impl TeamConfig {
-    /// Load config from YAML.
+    /// Load config from `team.toml`, or from YAML for any other extension.
     pub fn load(path: &Path) -> Result<Self> {
         let text =
             fs::read_to_string(path).with_context(|| format!("read team config {path:?}"))?;
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            return Self::from_toml_str(&text, path);
+        }
         let cfg: Self =
             serde_yaml::from_str(&text).with_context(|| format!("parse team config {path:?}"))?;
         Ok(cfg)
     }
 
+    /// Parse a `team.toml` document read from `path`.
+    ///
+    /// Unknown keys are rejected, and every validation error names the
+    /// offending key. Relative `template` and `member_root` paths resolve
+    /// against the directory holding `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::team::TeamConfig;
+    /// use std::path::Path;
+    ///
+    /// let cfg = TeamConfig::from_toml_str(
+    ///     r#"
+    /// members = ["alice", "bob"]
+    /// member_root = "ledgers"
+    /// schema_version = "0.2.0"
+    ///
+    /// [window]
+    /// since = 2025-01-01
+    /// until = 2025-04-01
+    ///
+    /// [aliases]
+    /// alice = "Alice Smith"
+    /// "#,
+    ///     Path::new("team/team.toml"),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(cfg.member_root.unwrap(), Path::new("team/ledgers"));
+    /// assert_eq!(cfg.required_schema_version.as_deref(), Some("0.2.0"));
+    ///
+    /// let err = TeamConfig::from_toml_str("parallelism = 0", Path::new("team.toml")).unwrap_err();
+    /// assert!(err.to_string().contains("`parallelism`"));
+    /// ```
+    pub fn from_toml_str(text: &str, path: &Path) -> Result<Self> {
+        let file: TeamFile = toml::from_str(text)
+            .map_err(|err| anyhow::anyhow!("parse team config {path:?}: {err}"))?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        let invalid = |key: &str, problem: String| {
+            anyhow::anyhow!("invalid team config {path:?}: `{key}` {problem}")
+        };
+
+        for (i, member) in file.members.iter().enumerate() {
+            if member.trim().is_empty() {
+                return Err(invalid(
+                    &format!("members[{i}]"),
+                    "must not be empty".into(),
+                ));
+            }
+        }
+        for (member, display) in &file.aliases {
+            if display.trim().is_empty() {
+                return Err(invalid(
+                    &format!("aliases.{member}"),
+                    "display name must not be empty".into(),
+                ));
+            }
+        }
+        for (i, section) in file.sections.iter().enumerate() {
+            let name = section.trim().to_ascii_lowercase();
+            if !DEFAULT_SECTIONS.contains(&name.as_str()) {
+                return Err(invalid(
+                    &format!("sections[{i}]"),
+                    format!(
+                        "has unknown section {section:?}; expected one of {}",
+                        DEFAULT_SECTIONS.join(", ")
+                    ),
+                ));
+            }
+        }
+        if let (Some(since), Some(until)) = (file.window.since, file.window.until)
+            && until <= since
+        {
+            return Err(invalid(
+                "window.until",
+                format!("({until}) must be after `window.since` ({since})"),
+            ));
+        }
+        if file.parallelism == Some(0) {
+            return Err(invalid("parallelism", "must be at least 1".into()));
+        }
+
+        Ok(Self {
+            members: file.members,
+            aliases: file.aliases,
+            sections: file.sections,
+            template: file.template.map(|template| base.join(template)),
+            since: file.window.since,
+            until: file.window.until,
+            required_schema_version: file.schema_version,
+            parallelism: file.parallelism,
+            member_breakdown: file.member_breakdown,
+            member_root: file.member_root.map(|root| base.join(root)),
+        })
+    }
+
     /// Normalize requested sections into a deterministic, deduplicated list.
     pub fn normalized_sections(&self) -> Vec<String> {
         if self.sections.is_empty() {
@@ -75,6 +187,17 @@ impl TeamConfig {
         }
     }
 
+    /// Number of member ledgers to load concurrently, never less than one.
+    pub fn effective_parallelism(&self) -> usize {
+        self.parallelism
+            .unwrap_or_else(|| {
+                std::thread::available_parallelism()
+                    .map(usize::from)
+                    .unwrap_or(1)
+            })
+            .max(1)
+    }
+
     /// Check if a section is enabled after normalization and deduplication.
     pub fn section_enabled(&self, section: &str) -> bool {
         self.normalized_sections()
@@ -123,59 +246,257 @@ pub fn parse_alias_list(alias_args: &[String]) -> Result<HashMap<String, String>
     Ok(aliases)
 }
 
-/// Resolve team command flags and optional config file into a normalized `TeamConfig`.
-pub fn resolve_team_config(
-    config: Option<PathBuf>,
-    members: Option<String>,
+/// On-disk shape of `team.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TeamFile {
+    members: Vec<String>,
+    aliases: HashMap<String, String>,
+    window: TeamFileWindow,
+    sections: Vec<String>,
+    template: Option<PathBuf>,
+    /// Maps to [`TeamConfig::required_schema_version`].
+    schema_version: Option<String>,
+    member_root: Option<PathBuf>,
+    parallelism: Option<usize>,
+    member_breakdown: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TeamFileWindow {
+    #[serde(deserialize_with = "deserialize_toml_date")]
     since: Option<NaiveDate>,
+    #[serde(deserialize_with = "deserialize_toml_date")]
     until: Option<NaiveDate>,
-    sections: Option<String>,
-    template: Option<PathBuf>,
-    required_schema_version: Option<String>,
-    alias: Vec<String>,
-) -> Result<TeamConfig> {
-    let mut cfg = match config {
-        Some(path) => TeamConfig::load(&path)?,
-        None => TeamConfig::default(),
-    };
+}
 
-    if let Some(raw_members) = members {
-        let parsed_members = parse_csv_list(&raw_members);
-        if !parsed_members.is_empty() {
-            cfg.members = parsed_members;
+/// Accept both TOML dates (`since = 2025-01-01`) and quoted strings.
+fn deserialize_toml_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let text = match toml::Value::deserialize(deserializer)? {
+        toml::Value::String(text) => text,
+        toml::Value::Datetime(datetime) if datetime.time.is_none() => datetime.to_string(),
+        other => {
+            return Err(D::Error::custom(format!(
+                "expected a date like 2025-01-01, found {}",
+                other.type_str()
+            )));
         }
-    }
+    };
+    NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+        .map(Some)
+        .map_err(|err| D::Error::custom(format!("invalid date {text:?}: {err}")))
+}
 
-    if let Some(raw_sections) = sections {
-        cfg.sections = parse_csv_list(&raw_sections);
-    }
+/// Environment variables read by [`TeamConfigOverrides::from_env`].
+pub const TEAM_ENV_VARS: &[&str] = &[
+    "SHIPLOG_TEAM_MEMBERS",
+    "SHIPLOG_TEAM_ALIASES",
+    "SHIPLOG_TEAM_SINCE",
+    "SHIPLOG_TEAM_UNTIL",
+    "SHIPLOG_TEAM_SECTIONS",
+    "SHIPLOG_TEAM_TEMPLATE",
+    "SHIPLOG_TEAM_SCHEMA_VERSION",
+    "SHIPLOG_TEAM_MEMBER_ROOT",
+    "SHIPLOG_TEAM_PARALLELISM",
+];
 
-    if let Some(template) = template {
-        cfg.template = Some(template);
-    }
+/// One override layer on top of a team config file.
+///
+/// Unset fields leave the lower layers untouched. Aliases merge per member
+/// instead of replacing the whole map.
+#[derive(Debug, Clone, Default)]
+pub struct TeamConfigOverrides {
+    pub members: Option<Vec<String>>,
+    pub aliases: HashMap<String, String>,
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+    pub sections: Option<Vec<String>>,
+    pub template: Option<PathBuf>,
+    pub required_schema_version: Option<String>,
+    pub member_root: Option<PathBuf>,
+    pub parallelism: Option<usize>,
+}
 
-    if let Some(since) = since {
-        cfg.since = Some(since);
+impl TeamConfigOverrides {
+    /// Read the `SHIPLOG_TEAM_*` variables listed in [`TEAM_ENV_VARS`].
+    pub fn from_env() -> Result<Self> {
+        Self::from_env_with(|name| std::env::var(name).ok())
     }
 
-    if let Some(until) = until {
-        cfg.until = Some(until);
+    /// Read overrides through `env`. Empty values count as unset, and parse
+    /// errors name the variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::team::TeamConfigOverrides;
+    ///
+    /// let env = TeamConfigOverrides::from_env_with(|name| match name {
+    ///     "SHIPLOG_TEAM_MEMBERS" => Some("alice,bob".into()),
+    ///     "SHIPLOG_TEAM_ALIASES" => Some("alice=Alice Smith".into()),
+    ///     _ => None,
+    /// })
+    /// .unwrap();
+    /// assert_eq!(env.members.unwrap(), vec!["alice", "bob"]);
+    /// assert_eq!(env.aliases["alice"], "Alice Smith");
+    ///
+    /// let err = TeamConfigOverrides::from_env_with(|name| {
+    ///     (name == "SHIPLOG_TEAM_SINCE").then(|| "last tuesday".into())
+    /// })
+    /// .unwrap_err();
+    /// assert!(err.to_string().starts_with("SHIPLOG_TEAM_SINCE"));
+    /// ```
+    pub fn from_env_with(env: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let var = |name: &str| env(name).filter(|value| !value.trim().is_empty());
+        let date = |name: &str| -> Result<Option<NaiveDate>> {
+            var(name)
+                .map(|raw| {
+                    NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d").map_err(|err| {
+                        anyhow::anyhow!("{name}: invalid date {raw:?} (expected YYYY-MM-DD): {err}")
+                    })
+                })
+                .transpose()
+        };
+
+        let aliases = match var("SHIPLOG_TEAM_ALIASES") {
+            Some(raw) => parse_alias_list(&parse_csv_list(&raw))
+                .map_err(|err| anyhow::anyhow!("SHIPLOG_TEAM_ALIASES: {err}"))?,
+            None => HashMap::new(),
+        };
+        let parallelism = var("SHIPLOG_TEAM_PARALLELISM")
+            .map(|raw| match raw.trim().parse::<usize>() {
+                Ok(workers) if workers > 0 => Ok(workers),
+                _ => Err(anyhow::anyhow!(
+                    "SHIPLOG_TEAM_PARALLELISM: expected a positive integer, got {raw:?}"
+                )),
+            })
+            .transpose()?;
+
+        Ok(Self {
+            members: var("SHIPLOG_TEAM_MEMBERS")
+                .map(|raw| parse_csv_list(&raw))
+                .filter(|members| !members.is_empty()),
+            aliases,
+            since: date("SHIPLOG_TEAM_SINCE")?,
+            until: date("SHIPLOG_TEAM_UNTIL")?,
+            sections: var("SHIPLOG_TEAM_SECTIONS").map(|raw| parse_csv_list(&raw)),
+            template: var("SHIPLOG_TEAM_TEMPLATE").map(PathBuf::from),
+            required_schema_version: var("SHIPLOG_TEAM_SCHEMA_VERSION"),
+            member_root: var("SHIPLOG_TEAM_MEMBER_ROOT").map(PathBuf::from),
+            parallelism,
+        })
     }
 
-    if let Some(version) = required_schema_version {
-        cfg.required_schema_version = Some(version);
+    fn apply(self, cfg: &mut TeamConfig) {
+        if let Some(members) = self.members {
+            cfg.members = members;
+        }
+        cfg.aliases.extend(self.aliases);
+        if let Some(sections) = self.sections {
+            cfg.sections = sections;
+        }
+        if let Some(template) = self.template {
+            cfg.template = Some(template);
+        }
+        if let Some(since) = self.since {
+            cfg.since = Some(since);
+        }
+        if let Some(until) = self.until {
+            cfg.until = Some(until);
+        }
+        if let Some(version) = self.required_schema_version {
+            cfg.required_schema_version = Some(version);
+        }
+        if let Some(member_root) = self.member_root {
+            cfg.member_root = Some(member_root);
+        }
+        if let Some(parallelism) = self.parallelism {
+            cfg.parallelism = Some(parallelism);
+        }
     }
+}
+
+/// Resolve a team config from layers, lowest precedence first: the config
+/// file (if any), then `env` overrides, then `cli` overrides.
+///
+/// A date range that only becomes invalid after layering is reported with
+/// the layer each bound came from.
+pub fn resolve_layered_team_config(
+    config: Option<&Path>,
+    env: TeamConfigOverrides,
+    cli: TeamConfigOverrides,
+) -> Result<TeamConfig> {
+    let mut cfg = match config {
+        Some(path) => TeamConfig::load(path)?,
+        None => TeamConfig::default(),
+    };
 
-    let alias_entries = parse_alias_list(&alias)?;
-    if !alias_entries.is_empty() {
-        cfg.aliases.extend(alias_entries);
+    let file_origin = |key: &str| match config {
+        Some(path) if path.extension().is_some_and(|ext| ext == "toml") => {
+            format!("`window.{key}` in {}", path.display())
+        }
+        Some(path) => format!("`{key}` in {}", path.display()),
+        None => "default".to_string(),
+    };
+    let mut since_origin = file_origin("since");
+    let mut until_origin = file_origin("until");
+    if env.since.is_some() {
+        since_origin = "SHIPLOG_TEAM_SINCE".to_string();
+    }
+    if env.until.is_some() {
+        until_origin = "SHIPLOG_TEAM_UNTIL".to_string();
+    }
+    if cli.since.is_some() {
+        since_origin = "--since".to_string();
     }
+    if cli.until.is_some() {
+        until_origin = "--until".to_string();
+    }
+
+    env.apply(&mut cfg);
+    cli.apply(&mut cfg);
 
     if let (Some(since), Some(until)) = (cfg.since, cfg.until)
         && until <= since
     {
-        anyhow::bail!("Invalid date range: until ({until}) must be after since ({since})");
+        anyhow::bail!(
+            "Invalid date range: until ({until}, from {until_origin}) must be after since ({since}, from {since_origin})"
+        );
     }
 
     Ok(cfg)
 }
+
+/// Resolve team command flags and optional config file into a normalized `TeamConfig`.
+///
+/// Equivalent to [`resolve_layered_team_config`] with no environment layer.
+pub fn resolve_team_config(
+    config: Option<PathBuf>,
+    members: Option<String>,
+    since: Option<NaiveDate>,
+    until: Option<NaiveDate>,
+    sections: Option<String>,
+    template: Option<PathBuf>,
+    required_schema_version: Option<String>,
+    alias: Vec<String>,
+) -> Result<TeamConfig> {
+    let cli = TeamConfigOverrides {
+        members: members
+            .map(|raw| parse_csv_list(&raw))
+            .filter(|members| !members.is_empty()),
+        aliases: parse_alias_list(&alias)?,
+        since,
+        until,
+        sections: sections.map(|raw| parse_csv_list(&raw)),
+        template,
+        required_schema_version,
+        ..TeamConfigOverrides::default()
+    };
+    resolve_layered_team_config(config.as_deref(), TeamConfigOverrides::default(), cli)
+}
@@ -7,8 +7,17 @@
 pub mod aggregate;
 pub mod core;
 pub mod render;
-mod template;
+pub mod trends;
 
 pub use aggregate::{TeamAggregator, TeamOutputFiles, write_team_outputs};
-pub use core::{TeamConfig, parse_alias_list, parse_csv_list, resolve_team_config};
-pub use render::{TeamAggregateResult, TeamMemberSummary, render_packet_markdown};
+pub use core::{
+    TEAM_ENV_VARS, TeamConfig, TeamConfigOverrides, parse_alias_list, parse_csv_list,
+    resolve_layered_team_config, resolve_team_config,
+};
+pub use render::{
+    MEMBER_BREAKDOWN_DISCLAIMER, TeamAggregateResult, TeamMemberSummary, render_packet_markdown,
+};
+pub use trends::{
+    TeamTrendRow, TeamTrends, TeamTrendsFiles, TrendPeriod, render_trends_markdown,
+    write_team_trends,
+};
@@ -4,15 +4,17 @@
 //! callers can evolve rendering independently from ingest/merge logic.
 
 use anyhow::{Context, Result};
+use regex::{NoExpand, Regex};
 use serde::{Deserialize, Serialize};
 use shiplog::schema::coverage::CoverageManifest;
-use shiplog::schema::event::EventEnvelope;
-use std::collections::HashMap;
+use shiplog::schema::event::{EventEnvelope, EventKind, EventPayload};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::redact::hmac_sha256;
 use crate::team::core::TeamConfig;
-use crate::team::template::{TemplateContext, TemplateEngine};
+use crate::template::{TemplateContext, TemplateEngine};
 
 /// One member's aggregation outcome.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +25,22 @@ pub struct TeamMemberSummary {
     pub ledger_dir: PathBuf,
     pub event_count: usize,
     pub warnings: Vec<String>,
+    /// Included events per kind, keyed by kind name (`PullRequest`, `Review`, ...).
+    #[serde(default)]
+    pub kind_counts: BTreeMap<String, usize>,
+    /// Repositories with the most included events, busiest first.
+    #[serde(default)]
+    pub top_repos: Vec<(String, usize)>,
+}
+
+impl TeamMemberSummary {
+    /// Included events of `kind`.
+    pub fn kind_count(&self, kind: &EventKind) -> usize {
+        self.kind_counts
+            .get(&kind.to_string())
+            .copied()
+            .unwrap_or_default()
+    }
 }
 
 /// Result of one team aggregation run.
@@ -38,6 +56,136 @@ pub struct TeamAggregateResult {
     pub warnings: Vec<String>,
 }
 
+impl TeamAggregateResult {
+    /// Copy of this result with every member replaced by a `Member N` label.
+    ///
+    /// Counts are kept; member IDs, display names, and ledger paths are
+    /// removed from the member lists, coverage, and warnings, and events whose
+    /// actor, contributor, or reviewed-PR author login is a member ID carry
+    /// the label instead. Labels follow an HMAC of the member ID under `key`,
+    /// normally the redaction key, so neither their order nor a digest of the
+    /// roster tells who is who.
+    pub fn anonymized(&self, key: &[u8]) -> Self {
+        let mut members: Vec<&TeamMemberSummary> = self.members.iter().collect();
+        members.sort_by_cached_key(|m| hmac_sha256(key, &[b"team-member\n", m.member.as_bytes()]));
+        let labels: HashMap<&str, String> = members
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.member.as_str(), format!("Member {}", i + 1)))
+            .collect();
+        let label = |member: &String| {
+            labels
+                .get(member.as_str())
+                .cloned()
+                .unwrap_or_else(|| "Member".to_string())
+        };
+        let scrub = |text: &String| scrub_members(text, &self.members, &labels);
+        let relabel = |list: &[String]| {
+            let mut out: Vec<String> = list.iter().map(label).collect();
+            out.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+            out
+        };
+
+        let mut coverage = self.coverage.clone();
+        coverage.user = "team".to_string();
+        coverage.sources = relabel(&coverage.sources);
+        coverage.warnings = coverage.warnings.iter().map(scrub).collect();
+
+        Self {
+            requested_members: relabel(&self.requested_members),
+            included_members: relabel(&self.included_members),
+            missing_members: relabel(&self.missing_members),
+            incompatible_members: relabel(&self.incompatible_members),
+            members: members
+                .into_iter()
+                .map(|m| TeamMemberSummary {
+                    member: label(&m.member),
+                    display_name: label(&m.member),
+                    included: m.included,
+                    ledger_dir: PathBuf::new(),
+                    event_count: m.event_count,
+                    warnings: m.warnings.iter().map(scrub).collect(),
+                    kind_counts: m.kind_counts.clone(),
+                    top_repos: m.top_repos.clone(),
+                })
+                .collect(),
+            coverage,
+            events: self
+                .events
+                .iter()
+                .cloned()
+                .map(|mut event| {
+                    if let Some(label) = labels.get(event.actor.login.as_str()) {
+                        event.actor.login = label.clone();
+                        event.actor.id = None;
+                    }
+                    for contributor in &mut event.contributors {
+                        if let Some(label) = labels.get(contributor.login.as_str()) {
+                            contributor.login = label.clone();
+                            contributor.id = None;
+                        }
+                    }
+                    if let EventPayload::Review(review) = &mut event.payload
+                        && let Some(label) = review
+                            .pull_author
+                            .as_deref()
+                            .and_then(|author| labels.get(author))
+                    {
+                        review.pull_author = Some(label.clone());
+                    }
+                    event
+                })
+                .collect(),
+            warnings: self.warnings.iter().map(scrub).collect(),
+        }
+    }
+}
+
+/// Replace ledger paths, member IDs, and display names in `text` with the
+/// member's label. IDs and names only match as whole words.
+fn scrub_members(
+    text: &str,
+    members: &[TeamMemberSummary],
+    labels: &HashMap<&str, String>,
+) -> String {
+    let mut out = text.to_string();
+    for member in members {
+        let Some(label) = labels.get(member.member.as_str()) else {
+            continue;
+        };
+        let ledger_dir = member.ledger_dir.display().to_string();
+        if !ledger_dir.is_empty() {
+            out = out.replace(&ledger_dir, &format!("<{label} ledger>"));
+        }
+        for name in [&member.member, &member.display_name] {
+            if name.is_empty() {
+                continue;
+            }
+            if let Ok(re) = Regex::new(&whole_word(name)) {
+                out = re.replace_all(&out, NoExpand(label)).into_owned();
+            }
+        }
+    }
+    out
+}
+
+/// Pattern matching `name` as a whole word. Word boundaries only apply at ends
+/// that are word characters, so names like `bot[1]` still match.
+fn whole_word(name: &str) -> String {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let start = if is_word(name.chars().next()) {
+        r"\b"
+    } else {
+        ""
+    };
+    let end = if is_word(name.chars().last()) {
+        r"\b"
+    } else {
+        ""
+    };
+    format!("{start}{}{end}", regex::escape(name))
+}
+
 /// Render a markdown packet for team-level output.
 pub fn render_packet_markdown(cfg: &TeamConfig, output: &TeamAggregateResult) -> Result<String> {
     if let Some(template) = &cfg.template {
@@ -135,6 +283,10 @@ fn render_default_packet(cfg: &TeamConfig, output: &TeamAggregateResult) -> Stri
         }
     }
 
+    if cfg.member_breakdown {
+        render_member_breakdown(&mut out, output);
+    }
+
     if !output.warnings.is_empty() {
         out.push_str("## Warnings\n\n");
         for warning in &output.warnings {
@@ -145,6 +297,46 @@ fn render_default_packet(cfg: &TeamConfig, output: &TeamAggregateResult) -> Stri
     out
 }
 
+/// Shown above every per-member breakdown. Evidence counts depend on which
+/// sources each member connected and what kind of work they do, so they must
+/// not be read as a ranking.
+pub const MEMBER_BREAKDOWN_DISCLAIMER: &str = "Counts reflect only the evidence each member's \
+ledger collected. They vary with role, connected sources, and the kind of work done, and are \
+not a measure of individual performance. Do not use them to rank or compare members.";
+
+fn render_member_breakdown(out: &mut String, output: &TeamAggregateResult) {
+    out.push_str("## Member Breakdown\n\n");
+    out.push_str(&format!("> {MEMBER_BREAKDOWN_DISCLAIMER}\n\n"));
+
+    // Listed in configured order, never sorted by volume.
+    let included: Vec<&TeamMemberSummary> = output.members.iter().filter(|m| m.included).collect();
+    if included.is_empty() {
+        out.push_str("No members included.\n\n");
+        return;
+    }
+    for member in included {
+        out.push_str(&format!("### {}\n", member.display_name));
+        out.push_str(&format!("- Events: {}\n", member.event_count));
+        out.push_str(&format!(
+            "- Pull requests: {}\n",
+            member.kind_count(&EventKind::PullRequest)
+        ));
+        out.push_str(&format!(
+            "- Reviews: {}\n",
+            member.kind_count(&EventKind::Review)
+        ));
+        if !member.top_repos.is_empty() {
+            let repos: Vec<String> = member
+                .top_repos
+                .iter()
+                .map(|(repo, count)| format!("{repo} ({count})"))
+                .collect();
+            out.push_str(&format!("- Top repos: {}\n", repos.join(", ")));
+        }
+        out.push('\n');
+    }
+}
+
 fn render_with_template(
     template: &str,
     cfg: &TeamConfig,
@@ -189,7 +381,7 @@ fn render_with_template(
         .context("render team template")
 }
 
-fn group_events_by_repo(events: &[EventEnvelope]) -> Vec<(String, usize)> {
+pub(crate) fn group_events_by_repo(events: &[EventEnvelope]) -> Vec<(String, usize)> {
     let mut by_repo: HashMap<String, usize> = HashMap::new();
     for event in events {
         *by_repo.entry(event.repo.full_name.clone()).or_default() += 1;
@@ -206,12 +398,15 @@ mod tests {
     use shiplog::ids::{EventId, RunId};
     use shiplog::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
     use shiplog::schema::event::{
-        Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
-        RepoVisibility, SourceRef, SourceSystem,
+        Actor, Contributor, ContributorRole, EventEnvelope, EventKind, EventPayload,
+        PullRequestEvent, PullRequestState, RepoRef, RepoVisibility, ReviewEvent, SourceRef,
+        SourceSystem,
     };
+    use shiplog::schema::version::SchemaVersion;
 
     fn event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["team", id]),
             kind: EventKind::PullRequest,
             occurred_at: when,
@@ -246,11 +441,15 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
     fn coverage() -> CoverageManifest {
         CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("team"),
             generated_at: Utc::now(),
             user: "alice".to_string(),
@@ -263,6 +462,9 @@ mod tests {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         }
     }
 
@@ -281,6 +483,8 @@ mod tests {
                     ledger_dir: PathBuf::from("alice"),
                     event_count: 2,
                     warnings: vec![],
+                    kind_counts: BTreeMap::new(),
+                    top_repos: vec![],
                 },
                 TeamMemberSummary {
                     member: "bob".to_string(),
@@ -289,6 +493,8 @@ mod tests {
                     ledger_dir: PathBuf::from("bob"),
                     event_count: 0,
                     warnings: vec!["Missing ledger".to_string()],
+                    kind_counts: BTreeMap::new(),
+                    top_repos: vec![],
                 },
             ],
             coverage: coverage(),
@@ -330,4 +536,57 @@ mod tests {
         assert!(packet.contains("Members=2"));
         assert!(packet.contains("Included=1"));
     }
+
+    #[test]
+    fn anonymized_result_names_no_roster_member() {
+        let when = Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap();
+        let mut review = event("3", "repo/one", when);
+        review.kind = EventKind::Review;
+        review.actor.login = "bob".to_string();
+        review.payload = EventPayload::Review(ReviewEvent {
+            pull_number: 7,
+            pull_title: "Ship it".to_string(),
+            submitted_at: when,
+            pull_created_at: None,
+            pull_author: Some("alice".to_string()),
+            state: "approved".to_string(),
+            window: None,
+        });
+        let mut pr = event("4", "repo/one", when);
+        pr.contributors.push(Contributor {
+            login: "bob".to_string(),
+            id: Some(2),
+            role: ContributorRole::Reviewer,
+        });
+        let mut result = sample_result();
+        result.events.extend([review, pr]);
+
+        let anonymized = result.anonymized(b"team-key");
+        let json = serde_json::to_string(&anonymized).unwrap();
+        for name in ["alice", "Alice", "bob", "Bob"] {
+            assert!(!json.contains(name), "{name} leaked: {json}");
+        }
+        let EventPayload::Review(review) = &anonymized.events[2].payload else {
+            panic!("expected a review");
+        };
+        assert_eq!(
+            review.pull_author.as_deref(),
+            Some(anonymized.events[0].actor.login.as_str())
+        );
+        assert_eq!(
+            anonymized.events[3].contributors[0].login,
+            anonymized.events[2].actor.login
+        );
+
+        // The numbering depends on the key, not only on the roster.
+        let alice_labels: std::collections::BTreeSet<String> = (0..16)
+            .map(|i| {
+                result.anonymized(format!("key-{i}").as_bytes()).events[0]
+                    .actor
+                    .login
+                    .clone()
+            })
+            .collect();
+        assert_eq!(alice_labels.len(), 2, "{alice_labels:?}");
+    }
 }
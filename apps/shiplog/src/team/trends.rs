@@ -0,0 +1,318 @@
+//! Team trends across several historical runs per member.
+//!
+//! A single team packet is one snapshot. Trends read every run directory a
+//! member has produced, collapse events that overlapping runs collected more
+//! than once, and bucket the result by month or quarter so managers can see
+//! trajectory.
+
+use anyhow::{Context, Result, anyhow};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use shiplog::schema::event::{EventEnvelope, EventKind};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::merge::{MergeStrategy, merge_events};
+use crate::team::aggregate::{is_compatible_schema, load_ledgers};
+use crate::team::core::TeamConfig;
+
+/// Width of one row in a trend table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendPeriod {
+    /// Calendar months, labelled `2025-01`.
+    #[default]
+    Month,
+    /// Calendar quarters, labelled `2025-Q1`.
+    Quarter,
+}
+
+impl TrendPeriod {
+    /// First day of the period containing `date`.
+    pub fn start_of(self, date: NaiveDate) -> NaiveDate {
+        let month = match self {
+            Self::Month => date.month(),
+            Self::Quarter => (date.month() - 1) / 3 * 3 + 1,
+        };
+        NaiveDate::from_ymd_opt(date.year(), month, 1).unwrap_or(date)
+    }
+
+    /// First day of the period after the one starting at `start`, or `None`
+    /// past the end of the supported date range.
+    fn next_start(self, start: NaiveDate) -> Option<NaiveDate> {
+        let months = match self {
+            Self::Month => 1,
+            Self::Quarter => 3,
+        };
+        start.checked_add_months(chrono::Months::new(months))
+    }
+
+    fn label(self, start: NaiveDate) -> String {
+        match self {
+            Self::Month => start.format("%Y-%m").to_string(),
+            Self::Quarter => format!("{}-Q{}", start.year(), (start.month() - 1) / 3 + 1),
+        }
+    }
+}
+
+impl std::fmt::Display for TrendPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Month => f.write_str("month"),
+            Self::Quarter => f.write_str("quarter"),
+        }
+    }
+}
+
+/// Team activity within one period.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamTrendRow {
+    /// Period label, e.g. `2025-03` or `2025-Q1`.
+    pub period: String,
+    /// Inclusive start of the period.
+    pub since: NaiveDate,
+    /// Exclusive end of the period.
+    pub until: NaiveDate,
+    pub events: usize,
+    pub pull_requests: usize,
+    pub reviews: usize,
+    /// Distinct repositories with at least one event.
+    pub active_repos: usize,
+    /// Distinct members with at least one event.
+    pub active_members: usize,
+}
+
+/// Trend table built by [`TeamAggregator::trends`](crate::team::TeamAggregator::trends).
+///
+/// Rows are contiguous: periods without activity appear with zero counts so
+/// gaps stay visible.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamTrends {
+    pub period: TrendPeriod,
+    /// Configured members, sorted and deduplicated.
+    pub members: Vec<String>,
+    /// Number of run directories that contributed events.
+    pub runs_read: usize,
+    pub rows: Vec<TeamTrendRow>,
+    pub warnings: Vec<String>,
+}
+
+/// Stable output artifact locations from team trend generation.
+#[derive(Debug, Clone)]
+pub struct TeamTrendsFiles {
+    pub json: PathBuf,
+    pub markdown: PathBuf,
+}
+
+#[derive(Default)]
+struct Bucket {
+    events: usize,
+    pull_requests: usize,
+    reviews: usize,
+    repos: BTreeSet<String>,
+    members: BTreeSet<String>,
+}
+
+pub(crate) fn build_team_trends(
+    config: &TeamConfig,
+    member_runs: &BTreeMap<String, Vec<PathBuf>>,
+    period: TrendPeriod,
+) -> Result<TeamTrends> {
+    if config.members.is_empty() {
+        return Err(anyhow!("No team members configured"));
+    }
+
+    let mut members = config.members.clone();
+    members.sort();
+    members.dedup();
+
+    let mut warnings = Vec::new();
+    let mut runs: Vec<(usize, PathBuf)> = Vec::new();
+    for (index, member) in members.iter().enumerate() {
+        match member_runs.get(member) {
+            Some(dirs) if !dirs.is_empty() => {
+                runs.extend(dirs.iter().map(|dir| (index, dir.clone())));
+            }
+            _ => warnings.push(format!("No runs provided for member '{member}'")),
+        }
+    }
+
+    let run_dirs: Vec<PathBuf> = runs.iter().map(|(_, dir)| dir.clone()).collect();
+    let loaded = load_ledgers(
+        &run_dirs,
+        config.since,
+        config.until,
+        config.effective_parallelism(),
+    );
+
+    let mut member_sources: Vec<Vec<Vec<EventEnvelope>>> = vec![Vec::new(); members.len()];
+    let mut runs_read = 0;
+    for ((index, run_dir), loaded) in runs.iter().zip(loaded) {
+        let member = &members[*index];
+        match loaded {
+            Ok(Some((output, _, written)))
+                if is_compatible_schema(written, &config.required_schema_version) =>
+            {
+                runs_read += 1;
+                member_sources[*index].push(output.events);
+            }
+            Ok(Some(_)) => warnings.push(format!(
+                "Run {} for '{member}' skipped due to schema incompatibility",
+                run_dir.display()
+            )),
+            Ok(None) => warnings.push(format!(
+                "Missing ledger or coverage for member '{member}' at {}",
+                run_dir.display()
+            )),
+            Err(err) => warnings.push(format!(
+                "Failed reading run for '{member}' from {}: {err}",
+                run_dir.display()
+            )),
+        }
+    }
+
+    // Overlapping runs of one member collapse through the shared merge
+    // pipeline; an event seen under two members still counts once.
+    let mut buckets: BTreeMap<NaiveDate, Bucket> = BTreeMap::new();
+    let mut seen = HashSet::new();
+    for (member, sources) in members.iter().zip(member_sources) {
+        for event in merge_events(sources, &MergeStrategy::KeepMostComplete) {
+            let bucket = buckets
+                .entry(period.start_of(event.occurred_at.date_naive()))
+                .or_default();
+            bucket.members.insert(member.clone());
+            if !seen.insert(event.id.clone()) {
+                continue;
+            }
+            bucket.events += 1;
+            match event.kind {
+                EventKind::PullRequest => bucket.pull_requests += 1,
+                EventKind::Review => bucket.reviews += 1,
+                _ => {}
+            }
+            bucket.repos.insert(event.repo.full_name);
+        }
+    }
+
+    let first = config
+        .since
+        .map(|since| period.start_of(since))
+        .or_else(|| buckets.keys().next().copied());
+    let last = config
+        .until
+        .and_then(|until| until.pred_opt())
+        .map(|until| period.start_of(until))
+        .or_else(|| buckets.keys().next_back().copied());
+
+    let mut rows = Vec::new();
+    if let (Some(first), Some(last)) = (first, last) {
+        let mut cursor = first;
+        while cursor <= last {
+            let Some(next) = period.next_start(cursor) else {
+                break;
+            };
+            let bucket = buckets.remove(&cursor).unwrap_or_default();
+            rows.push(TeamTrendRow {
+                period: period.label(cursor),
+                since: cursor,
+                until: next,
+                events: bucket.events,
+                pull_requests: bucket.pull_requests,
+                reviews: bucket.reviews,
+                active_repos: bucket.repos.len(),
+                active_members: bucket.members.len(),
+            });
+            cursor = next;
+        }
+    }
+
+    Ok(TeamTrends {
+        period,
+        members,
+        runs_read,
+        rows,
+        warnings,
+    })
+}
+
+/// Render the trend table as Markdown.
+pub fn render_trends_markdown(trends: &TeamTrends) -> String {
+    let mut out = String::new();
+    out.push_str("# Team Trends\n\n");
+    out.push_str(&format!("Period: {}\n", trends.period));
+    out.push_str(&format!("Members configured: {}\n", trends.members.len()));
+    out.push_str(&format!("Runs read: {}\n\n", trends.runs_read));
+
+    if trends.rows.is_empty() {
+        out.push_str("No activity in any run.\n\n");
+    } else {
+        out.push_str("| Period | Events | PRs | Reviews | Active repos | Active members |\n");
+        out.push_str("|---|---:|---:|---:|---:|---:|\n");
+        for row in &trends.rows {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                row.period,
+                row.events,
+                row.pull_requests,
+                row.reviews,
+                row.active_repos,
+                row.active_members
+            ));
+        }
+        out.push('\n');
+    }
+
+    if !trends.warnings.is_empty() {
+        out.push_str("## Warnings\n\n");
+        for warning in &trends.warnings {
+            out.push_str(&format!("- {warning}\n"));
+        }
+    }
+
+    out
+}
+
+/// Write `team.trends.json` and `team.trends.md` into `out_dir`.
+pub fn write_team_trends(out_dir: &Path, trends: &TeamTrends) -> Result<TeamTrendsFiles> {
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("create output directory {out_dir:?}"))?;
+
+    let json_path = out_dir.join("team.trends.json");
+    let markdown_path = out_dir.join("team.trends.md");
+
+    let payload = serde_json::to_string_pretty(trends).context("serialize team trends")?;
+    std::fs::write(&json_path, payload)?;
+    std::fs::write(&markdown_path, render_trends_markdown(trends))?;
+
+    Ok(TeamTrendsFiles {
+        json: json_path,
+        markdown: markdown_path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn quarter_start_and_label() {
+        let start = TrendPeriod::Quarter.start_of(date(2025, 8, 19));
+        assert_eq!(start, date(2025, 7, 1));
+        assert_eq!(TrendPeriod::Quarter.label(start), "2025-Q3");
+        assert_eq!(
+            TrendPeriod::Quarter.next_start(start),
+            Some(date(2025, 10, 1))
+        );
+    }
+
+    #[test]
+    fn month_wraps_into_next_year() {
+        let start = TrendPeriod::Month.start_of(date(2025, 12, 31));
+        assert_eq!(TrendPeriod::Month.label(start), "2025-12");
+        assert_eq!(TrendPeriod::Month.next_start(start), Some(date(2026, 1, 1)));
+    }
+}
@@ -1,10 +1,12 @@
-//! Jinja2-like template support for team packet rendering.
+//! Jinja2-like template support for user-defined packets.
 //!
 //! Provides a simple template engine supporting:
-//! - Variable substitution
+//! - Variable substitution, including dotted paths into objects
 //! - Conditional sections
 //! - Loops over collections
 //! - User-defined templates
+//!
+//! Team packets and individual packets both render user templates through it.
 
 use anyhow::{Result, anyhow};
 use serde::Serialize;
@@ -33,12 +35,12 @@ impl TemplateContext {
     }
 
     /// Check if a variable exists and is truthy
-    #[cfg(test)]
     pub fn is_truthy(&self, key: &str) -> bool {
         self.get(key).map(|v| v.is_truthy()).unwrap_or(false)
     }
 
     /// Get a variable as a string for rendering
+    #[cfg(test)]
     fn get_string(&self, key: &str) -> Option<String> {
         self.get(key).map(|v| v.to_string())
     }
@@ -58,8 +60,7 @@ pub enum TemplateValue {
 
 impl TemplateValue {
     /// Check if the value is truthy (for conditionals)
-    #[cfg(test)]
-    fn is_truthy(&self) -> bool {
+    pub fn is_truthy(&self) -> bool {
         match self {
             TemplateValue::Boolean(b) => *b,
             TemplateValue::String(s) => !s.is_empty(),
@@ -72,8 +73,7 @@ impl TemplateValue {
     }
 
     /// Get a field from an object value
-    #[allow(dead_code)]
-    fn get_field(&self, field: &str) -> Option<&TemplateValue> {
+    pub fn get_field(&self, field: &str) -> Option<&TemplateValue> {
         match self {
             TemplateValue::Object(obj) => obj.get(field),
             _ => None,
@@ -176,6 +176,29 @@ impl TemplateValue {
 }
 
 /// Template engine for rendering Jinja2-like templates
+///
+/// Supports `{{ path.to.value }}` substitution with `| join` and `| length`
+/// filters, `{% if %}` / `{% else %}` / `{% endif %}` blocks (with `not`),
+/// and `{% for item in list %}` / `{% endfor %}` loops, which expose
+/// `loop.index`, `loop.first`, and `loop.last`. A block tag alone on its line
+/// removes the whole line, so templates can indent tags without leaving blank
+/// lines behind. Missing variables render as empty text.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::template::{TemplateContext, TemplateEngine, TemplateValue};
+///
+/// let mut ctx = TemplateContext::new();
+/// ctx.set("title", "Q1");
+/// ctx.set(
+///     "items",
+///     vec![TemplateValue::from("Billing"), TemplateValue::from("Search")],
+/// );
+/// let template = "# {{ title }}\n{% for item in items %}\n- {{ item }}\n{% endfor %}\n";
+/// let out = TemplateEngine::new().render(template, &ctx).unwrap();
+/// assert_eq!(out, "# Q1\n- Billing\n- Search\n");
+/// ```
 #[derive(Debug, Clone)]
 pub struct TemplateEngine {
     /// Variable opening delimiter (default: "{{")
@@ -188,6 +211,45 @@ pub struct TemplateEngine {
     tag_close: String,
 }
 
+/// One lexical piece of a template.
+#[derive(Debug, PartialEq)]
+enum Token {
+    Text(String),
+    Var(String),
+    Tag(String),
+}
+
+/// Parsed template tree.
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Var(Expr),
+    If {
+        negated: bool,
+        cond: Expr,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+    For {
+        item: String,
+        list: Expr,
+        body: Vec<Node>,
+    },
+}
+
+/// A dotted variable path with optional filters, e.g. `ws.tags | join(", ")`.
+#[derive(Debug)]
+struct Expr {
+    path: Vec<String>,
+    filters: Vec<Filter>,
+}
+
+#[derive(Debug)]
+enum Filter {
+    Join(String),
+    Length,
+}
+
 impl TemplateEngine {
     /// Create a new template engine with default delimiters
     pub fn new() -> Self {
@@ -217,52 +279,91 @@ impl TemplateEngine {
 
     /// Render a template with the given context
     pub fn render(&self, template: &str, context: &TemplateContext) -> Result<String> {
+        let nodes = self.parse(template)?;
         let mut output = String::new();
+        let mut locals = Vec::new();
+        render_nodes(&nodes, context, &mut locals, &mut output);
+        Ok(output)
+    }
+
+    /// Check that a template parses: every tag and variable is closed, every
+    /// block is ended, and every tag is known.
+    pub fn validate(&self, template: &str) -> Result<()> {
+        self.parse(template).map(|_| ())
+    }
+
+    fn parse(&self, template: &str) -> Result<Vec<Node>> {
+        let tokens = self.tokenize(template)?;
+        let mut tokens = tokens.into_iter();
+        let (nodes, end) = parse_block(&mut tokens, &[])?;
+        match end {
+            None => Ok(nodes),
+            Some(tag) => Err(anyhow!("Unexpected tag: {tag}")),
+        }
+    }
+
+    fn tokenize(&self, template: &str) -> Result<Vec<Token>> {
+        let mut tokens = Vec::new();
         let mut remaining = template;
+        // Whether everything since the last newline is whitespace.
+        let mut line_start = true;
 
         while !remaining.is_empty() {
-            // Find the next token
-            if let Some(pos) = remaining.find(&self.var_open) {
-                // Add text before the token
-                output.push_str(&remaining[..pos]);
-                remaining = &remaining[pos..];
-
-                // Check for variable or tag
-                if remaining.starts_with(&self.tag_open) {
-                    // It's a tag
-                    let (tag, rest) = self.parse_tag(&remaining[self.tag_open.len()..])?;
-                    remaining = rest;
-                    output.push_str(&tag);
+            let next_var = remaining.find(&self.var_open);
+            let next_tag = remaining.find(&self.tag_open);
+            let (pos, is_tag) = match (next_var, next_tag) {
+                (Some(v), Some(t)) if t < v => (t, true),
+                (Some(v), _) => (v, false),
+                (None, Some(t)) => (t, true),
+                (None, None) => {
+                    push_text(&mut tokens, remaining);
+                    break;
+                }
+            };
+
+            let text = &remaining[..pos];
+            if !text.is_empty() {
+                line_start = match text.rfind('\n') {
+                    Some(nl) => text[nl + 1..].trim().is_empty(),
+                    None => line_start && text.trim().is_empty(),
+                };
+                push_text(&mut tokens, text);
+            }
+            remaining = &remaining[pos..];
+
+            if is_tag {
+                let (content, rest) = self.parse_tag(&remaining[self.tag_open.len()..])?;
+                remaining = rest;
+                let line_end = remaining.find('\n');
+                let trailing = &remaining[..line_end.unwrap_or(remaining.len())];
+                if line_start && trailing.trim().is_empty() {
+                    trim_line_indent(&mut tokens);
+                    remaining = &remaining[line_end.map_or(remaining.len(), |nl| nl + 1)..];
                 } else {
-                    // It's a variable
-                    let (var, rest) = self.parse_variable(&remaining[self.var_open.len()..])?;
-                    remaining = rest;
-                    if let Some(value) = context.get_string(&var) {
-                        output.push_str(&value);
-                    }
+                    line_start = false;
                 }
+                tokens.push(Token::Tag(content));
             } else {
-                // No more tokens, add remaining text
-                output.push_str(remaining);
-                break;
+                let (var, rest) = self.parse_variable(&remaining[self.var_open.len()..])?;
+                remaining = rest;
+                line_start = false;
+                tokens.push(Token::Var(var));
             }
         }
 
-        Ok(output)
+        Ok(tokens)
     }
 
-    /// Parse a tag (if/for/etc.)
+    /// Parse a tag body up to its closing delimiter
     fn parse_tag<'a>(&self, input: &'a str) -> Result<(String, &'a str)> {
         let end_pos = input
             .find(&self.tag_close)
             .ok_or_else(|| anyhow!("Unclosed tag: missing {}", self.tag_close))?;
 
-        let tag_content = input[..end_pos].trim();
+        let tag_content = input[..end_pos].trim().to_string();
         let remaining = &input[end_pos + self.tag_close.len()..];
 
-        // Parse the tag
-        let result = self.evaluate_tag(tag_content)?;
-        Ok((result, remaining))
+        Ok((tag_content, remaining))
     }
 
     /// Parse a variable reference
@@ -276,45 +377,210 @@ impl TemplateEngine {
 
         Ok((var_name, remaining))
     }
+}
+
+impl Default for TemplateEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    /// Evaluate a tag and return the rendered output
-    fn evaluate_tag(&self, tag_content: &str) -> Result<String> {
-        // Simple tag parsing (for full implementation, would need proper parsing)
-        let content = tag_content.trim();
+fn push_text(tokens: &mut Vec<Token>, text: &str) {
+    if let Some(Token::Text(prev)) = tokens.last_mut() {
+        prev.push_str(text);
+    } else {
+        tokens.push(Token::Text(text.to_string()));
+    }
+}
 
-        // Handle if tags
-        if content.starts_with("if ") {
-            // For now, just return empty string
-            // Full implementation would need to track nesting and evaluate conditionals
-            Ok(String::new())
-        }
-        // Handle endif tags
-        else if content == "endif" {
-            Ok(String::new())
-        }
-        // Handle for tags
-        else if content.starts_with("for ") {
-            // For now, just return empty string
-            // Full implementation would need to track nesting and iterate
-            Ok(String::new())
-        }
-        // Handle endfor tags
-        else if content == "endfor" {
-            Ok(String::new())
+/// Drop the indentation before a standalone block tag.
+fn trim_line_indent(tokens: &mut [Token]) {
+    if let Some(Token::Text(prev)) = tokens.last_mut() {
+        let keep = prev.rfind('\n').map_or(0, |nl| nl + 1);
+        prev.truncate(keep);
+    }
+}
+
+/// Parse nodes until one of `ends` (or the end of input when `ends` is
+/// empty). Returns the nodes and the tag that ended the block.
+fn parse_block(
+    tokens: &mut impl Iterator<Item = Token>,
+    ends: &[&str],
+) -> Result<(Vec<Node>, Option<String>)> {
+    let mut nodes = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(text) => nodes.push(Node::Text(text)),
+            Token::Var(var) => nodes.push(Node::Var(parse_expr(&var)?)),
+            Token::Tag(tag) => {
+                if ends.contains(&tag.as_str()) {
+                    return Ok((nodes, Some(tag)));
+                }
+                nodes.push(parse_tag_node(&tag, tokens)?);
+            }
         }
-        // Unknown tag
-        else {
-            Err(anyhow!("Unknown tag: {}", content))
+    }
+    match ends.last() {
+        Some(end) => Err(anyhow!("Unclosed block: missing {{% {end} %}}")),
+        None => Ok((nodes, None)),
+    }
+}
+
+fn parse_tag_node(tag: &str, tokens: &mut impl Iterator<Item = Token>) -> Result<Node> {
+    if let Some(cond) = tag.strip_prefix("if ") {
+        let (negated, cond) = match cond.trim().strip_prefix("not ") {
+            Some(rest) => (true, rest),
+            None => (false, cond),
+        };
+        let cond = parse_expr(cond)?;
+        let (then, end) = parse_block(tokens, &["else", "endif"])?;
+        let otherwise = if end.as_deref() == Some("else") {
+            parse_block(tokens, &["endif"])?.0
+        } else {
+            Vec::new()
+        };
+        return Ok(Node::If {
+            negated,
+            cond,
+            then,
+            otherwise,
+        });
+    }
+    if let Some(spec) = tag.strip_prefix("for ") {
+        let (item, list) = spec
+            .split_once(" in ")
+            .ok_or_else(|| anyhow!("Invalid for tag: expected `for item in list`, got {tag:?}"))?;
+        let item = item.trim();
+        if item.is_empty() || item.contains(char::is_whitespace) {
+            return Err(anyhow!("Invalid for tag: bad loop variable in {tag:?}"));
         }
+        let list = parse_expr(list)?;
+        let (body, _) = parse_block(tokens, &["endfor"])?;
+        return Ok(Node::For {
+            item: item.to_string(),
+            list,
+            body,
+        });
+    }
+    match tag {
+        "else" | "endif" | "endfor" => Err(anyhow!("Unexpected tag: {tag}")),
+        _ => Err(anyhow!("Unknown tag: {tag}")),
     }
 }
 
-impl Default for TemplateEngine {
-    fn default() -> Self {
-        Self::new()
+fn parse_expr(source: &str) -> Result<Expr> {
+    let mut parts = source.split('|');
+    let path = parts.next().unwrap_or_default().trim();
+    if path.is_empty() {
+        return Err(anyhow!("Empty variable reference"));
+    }
+    let filters = parts
+        .map(|filter| {
+            let filter = filter.trim();
+            if filter == "length" {
+                return Ok(Filter::Length);
+            }
+            if filter == "join" {
+                return Ok(Filter::Join(", ".to_string()));
+            }
+            if let Some(arg) = filter
+                .strip_prefix("join(")
+                .and_then(|rest| rest.strip_suffix(')'))
+            {
+                let arg = arg.trim();
+                let sep = arg
+                    .strip_prefix('"')
+                    .and_then(|a| a.strip_suffix('"'))
+                    .ok_or_else(|| anyhow!("join expects a quoted separator, got {arg}"))?;
+                return Ok(Filter::Join(sep.to_string()));
+            }
+            Err(anyhow!("Unknown filter: {filter}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Expr {
+        path: path.split('.').map(|s| s.trim().to_string()).collect(),
+        filters,
+    })
+}
+
+fn render_nodes(
+    nodes: &[Node],
+    context: &TemplateContext,
+    locals: &mut Vec<(String, TemplateValue)>,
+    out: &mut String,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(expr) => out.push_str(&evaluate(expr, context, locals).to_string()),
+            Node::If {
+                negated,
+                cond,
+                then,
+                otherwise,
+            } => {
+                let truthy = evaluate(cond, context, locals).is_truthy() != *negated;
+                render_nodes(if truthy { then } else { otherwise }, context, locals, out);
+            }
+            Node::For { item, list, body } => {
+                let TemplateValue::List(items) = evaluate(list, context, locals) else {
+                    continue;
+                };
+                let count = items.len();
+                for (index, value) in items.into_iter().enumerate() {
+                    let mut info = HashMap::new();
+                    info.insert("index".to_string(), count_value(index + 1));
+                    info.insert("first".to_string(), TemplateValue::from(index == 0));
+                    info.insert("last".to_string(), TemplateValue::from(index + 1 == count));
+                    locals.push(("loop".to_string(), TemplateValue::Object(info)));
+                    locals.push((item.clone(), value));
+                    render_nodes(body, context, locals, out);
+                    locals.truncate(locals.len() - 2);
+                }
+            }
+        }
     }
 }
 
+fn evaluate(
+    expr: &Expr,
+    context: &TemplateContext,
+    locals: &[(String, TemplateValue)],
+) -> TemplateValue {
+    let Some((head, rest)) = expr.path.split_first() else {
+        return TemplateValue::Null;
+    };
+    let mut value = locals
+        .iter()
+        .rev()
+        .find(|(name, _)| name == head)
+        .map(|(_, value)| value)
+        .or_else(|| context.get(head));
+    for field in rest {
+        value = value.and_then(|v| v.get_field(field));
+    }
+    let value = value.cloned().unwrap_or(TemplateValue::Null);
+    expr.filters
+        .iter()
+        .fold(value, |value, filter| match (filter, value) {
+            (Filter::Length, TemplateValue::List(items)) => count_value(items.len()),
+            (Filter::Length, TemplateValue::Object(fields)) => count_value(fields.len()),
+            (Filter::Length, TemplateValue::String(s)) => count_value(s.chars().count()),
+            (Filter::Length, _) => TemplateValue::from(0),
+            (Filter::Join(sep), TemplateValue::List(items)) => TemplateValue::from(
+                items
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(sep),
+            ),
+            (Filter::Join(_), other) => other,
+        })
+}
+
+fn count_value(n: usize) -> TemplateValue {
+    TemplateValue::Number(i64::try_from(n).unwrap_or(i64::MAX))
+}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -505,39 +771,159 @@ mod tests {
         assert!(err_msg.contains("Unclosed tag"), "error was: {err_msg}");
     }
 
-    // --- evaluate_tag tests ---
+    // --- block tag tests ---
 
     #[test]
-    fn evaluate_tag_unknown_returns_error() {
+    fn render_unknown_tag_returns_error() {
         let engine = TemplateEngine::new();
-        let result = engine.evaluate_tag("block content");
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(err_msg.contains("Unknown tag"), "error was: {err_msg}");
+        let err = engine
+            .render("{% block content %}", &TemplateContext::new())
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown tag"), "error was: {err}");
+    }
+
+    #[test]
+    fn render_unclosed_block_returns_error() {
+        let engine = TemplateEngine::new();
+        let err = engine
+            .render("{% if x %}never closed", &TemplateContext::new())
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("missing {% endif %}"),
+            "error was: {err}"
+        );
+        let err = engine
+            .render("{% for x in xs %}never closed", &TemplateContext::new())
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("missing {% endfor %}"),
+            "error was: {err}"
+        );
+    }
+
+    #[test]
+    fn render_stray_end_tag_returns_error() {
+        let engine = TemplateEngine::new();
+        let err = engine
+            .render("text {% endif %}", &TemplateContext::new())
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Unexpected tag: endif"),
+            "error was: {err}"
+        );
+    }
+
+    #[test]
+    fn render_if_else_and_not() {
+        let engine = TemplateEngine::new();
+        let mut ctx = TemplateContext::new();
+        ctx.set("shown", true);
+        ctx.set("empty", "");
+        let template = "{% if shown %}A{% else %}B{% endif %}\
+                        {% if empty %}C{% else %}D{% endif %}\
+                        {% if not empty %}E{% endif %}\
+                        {% if missing %}F{% endif %}";
+        assert_eq!(engine.render(template, &ctx).unwrap(), "ADE");
+    }
+
+    #[test]
+    fn render_for_loop_exposes_items_and_loop_state() {
+        let engine = TemplateEngine::new();
+        let mut ctx = TemplateContext::new();
+        ctx.set(
+            "names",
+            vec![
+                TemplateValue::from("a"),
+                TemplateValue::from("b"),
+                TemplateValue::from("c"),
+            ],
+        );
+        let template = "{% for n in names %}{{ loop.index }}={{ n }}{% if not loop.last %}, {% endif %}{% endfor %}";
+        assert_eq!(engine.render(template, &ctx).unwrap(), "1=a, 2=b, 3=c");
+    }
+
+    #[test]
+    fn render_for_over_missing_or_scalar_renders_nothing() {
+        let engine = TemplateEngine::new();
+        let mut ctx = TemplateContext::new();
+        ctx.set("n", 3);
+        let template =
+            "[{% for x in n %}{{ x }}{% endfor %}{% for x in nope %}{{ x }}{% endfor %}]";
+        assert_eq!(engine.render(template, &ctx).unwrap(), "[]");
+    }
+
+    #[test]
+    fn render_nested_loops_resolve_dotted_paths() {
+        let engine = TemplateEngine::new();
+        let mut ctx = TemplateContext::new();
+        let value = serde_json::json!([
+            {"title": "Billing", "receipts": [{"title": "PR 1"}, {"title": "PR 2"}]},
+            {"title": "Search", "receipts": []}
+        ]);
+        ctx.set("workstreams", &value);
+        let template = "{% for ws in workstreams %}{{ ws.title }}:\
+                        {% for r in ws.receipts %} {{ r.title }}{% endfor %}\
+                        {% if not ws.receipts %} none{% endif %};{% endfor %}";
+        assert_eq!(
+            engine.render(template, &ctx).unwrap(),
+            "Billing: PR 1 PR 2;Search: none;"
+        );
     }
 
     #[test]
-    fn evaluate_tag_if_returns_empty() {
+    fn render_standalone_block_tags_drop_their_lines() {
         let engine = TemplateEngine::new();
-        assert_eq!(engine.evaluate_tag("if show_details").unwrap(), "");
+        let mut ctx = TemplateContext::new();
+        ctx.set(
+            "items",
+            vec![TemplateValue::from("x"), TemplateValue::from("y")],
+        );
+        let template = "# Title\n\n  {% for i in items %}\n- {{ i }}\n  {% endfor %}\n\nEnd\n";
+        assert_eq!(
+            engine.render(template, &ctx).unwrap(),
+            "# Title\n\n- x\n- y\n\nEnd\n"
+        );
     }
 
     #[test]
-    fn evaluate_tag_endif_returns_empty() {
+    fn render_inline_block_tags_keep_surrounding_text() {
         let engine = TemplateEngine::new();
-        assert_eq!(engine.evaluate_tag("endif").unwrap(), "");
+        let mut ctx = TemplateContext::new();
+        ctx.set("on", true);
+        assert_eq!(
+            engine
+                .render("a {% if on %}b{% endif %} c\n", &ctx)
+                .unwrap(),
+            "a b c\n"
+        );
     }
 
     #[test]
-    fn evaluate_tag_for_returns_empty() {
+    fn render_filters_join_and_length() {
         let engine = TemplateEngine::new();
-        assert_eq!(engine.evaluate_tag("for item in items").unwrap(), "");
+        let mut ctx = TemplateContext::new();
+        ctx.set(
+            "tags",
+            vec![TemplateValue::from("a"), TemplateValue::from("b")],
+        );
+        let template = "{{ tags | join }}|{{ tags | join(\" / \") }}|{{ tags | length }}";
+        assert_eq!(engine.render(template, &ctx).unwrap(), "a, b|a / b|2");
+        let err = engine.render("{{ tags | upper }}", &ctx).unwrap_err();
+        assert!(
+            err.to_string().contains("Unknown filter"),
+            "error was: {err}"
+        );
     }
 
     #[test]
-    fn evaluate_tag_endfor_returns_empty() {
+    fn validate_reports_syntax_errors_without_a_context() {
         let engine = TemplateEngine::new();
-        assert_eq!(engine.evaluate_tag("endfor").unwrap(), "");
+        assert!(
+            engine
+                .validate("{% for x in xs %}{{ x }}{% endfor %}")
+                .is_ok()
+        );
+        assert!(engine.validate("{% for x xs %}{% endfor %}").is_err());
     }
 
     // --- get_field tests ---
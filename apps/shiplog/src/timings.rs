@@ -0,0 +1,341 @@
+//! Where a run's time went.
+//!
+//! [`TimingLayer`] watches the stage spans the pipeline already opens and adds
+//! up how long each stayed open, per source when the span names one. After a
+//! command that wrote a run, [`finish`] saves the totals in the run directory
+//! as `run.timings.json` and, with `--timings`, prints them as a table.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::Serialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::Subscriber;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context as LayerContext;
+use tracing_subscriber::registry::LookupSpan;
+
+pub(crate) const RUN_TIMINGS_FILENAME: &str = "run.timings.json";
+const RUN_TIMINGS_SCHEMA_VERSION: u8 = 1;
+
+/// Span names that are timed, in pipeline order.
+const TIMED_STAGES: &[&str] = &[
+    "ingest",
+    "detail_fetch",
+    "merge",
+    "cluster",
+    "render",
+    "bundle",
+];
+/// The engine span whose `out_dir` names the run being written.
+const PIPELINE_SPAN: &str = "pipeline";
+
+static RECORDED: Mutex<Recorded> = Mutex::new(Recorded {
+    started: None,
+    show: false,
+    run_dir: None,
+    stages: Vec::new(),
+});
+
+struct Recorded {
+    started: Option<Instant>,
+    show: bool,
+    run_dir: Option<PathBuf>,
+    stages: Vec<(StageKey, Duration)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct StageKey {
+    stage: &'static str,
+    source: Option<String>,
+}
+
+impl Recorded {
+    fn add(&mut self, key: StageKey, elapsed: Duration) {
+        match self.stages.iter_mut().find(|(seen, _)| *seen == key) {
+            Some((_, total)) => *total += elapsed,
+            None => self.stages.push((key, elapsed)),
+        }
+    }
+}
+
+fn recorded() -> std::sync::MutexGuard<'static, Recorded> {
+    RECORDED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Start the run clock. `show` prints the table when the command finishes.
+pub(crate) fn start(show: bool) {
+    let mut recorded = recorded();
+    recorded.started = Some(Instant::now());
+    recorded.show = show;
+}
+
+/// Adds stage span durations to the run's totals.
+pub(crate) struct TimingLayer;
+
+struct SpanStart {
+    key: StageKey,
+    started: Instant,
+}
+
+#[derive(Default)]
+struct SpanFields {
+    source: Option<String>,
+    out_dir: Option<String>,
+}
+
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, format!("{value:?}"));
+    }
+}
+
+impl SpanFields {
+    fn record(&mut self, field: &Field, value: String) {
+        match field.name() {
+            "source" => self.source = Some(value),
+            "out_dir" => self.out_dir = Some(value),
+            _ => {}
+        }
+    }
+}
+
+impl<S> Layer<S> for TimingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: LayerContext<'_, S>) {
+        let name = attrs.metadata().name();
+        if name == PIPELINE_SPAN {
+            let mut fields = SpanFields::default();
+            attrs.record(&mut fields);
+            if let Some(out_dir) = fields.out_dir {
+                recorded().run_dir = Some(PathBuf::from(out_dir));
+            }
+            return;
+        }
+        let Some(stage) = TIMED_STAGES.iter().copied().find(|stage| *stage == name) else {
+            return;
+        };
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = SpanFields::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(SpanStart {
+            key: StageKey {
+                stage,
+                source: fields.source,
+            },
+            started: Instant::now(),
+        });
+    }
+
+    fn on_close(&self, id: Id, ctx: LayerContext<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        if let Some(start) = extensions.get::<SpanStart>() {
+            recorded().add(start.key.clone(), start.started.elapsed());
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RunTimingsFile {
+    schema_version: u8,
+    run_id: String,
+    generated_at: String,
+    total_ms: u64,
+    stages: Vec<StageTiming>,
+}
+
+#[derive(Debug, Serialize)]
+struct StageTiming {
+    stage: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    ms: u64,
+}
+
+/// Write `run.timings.json` for the run the command produced, if any, and
+/// print the table when `--timings` asked for it.
+pub(crate) fn finish() -> Result<()> {
+    let (total, show, run_dir, stages) = {
+        let mut recorded = recorded();
+        let total = recorded
+            .started
+            .take()
+            .map(|started| started.elapsed())
+            .unwrap_or_default();
+        (
+            total,
+            recorded.show,
+            recorded.run_dir.take(),
+            std::mem::take(&mut recorded.stages),
+        )
+    };
+    if let Some(run_dir) = &run_dir {
+        write_run_timings(run_dir, total, &stages)?;
+    }
+    if show && !stages.is_empty() {
+        print!("{}", timings_table(total, &stages));
+    }
+    Ok(())
+}
+
+fn write_run_timings(
+    run_dir: &Path,
+    total: Duration,
+    stages: &[(StageKey, Duration)],
+) -> Result<()> {
+    let file = RunTimingsFile {
+        schema_version: RUN_TIMINGS_SCHEMA_VERSION,
+        run_id: run_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        generated_at: Utc::now().to_rfc3339(),
+        total_ms: millis(total),
+        stages: ordered(stages)
+            .into_iter()
+            .map(|(key, elapsed)| StageTiming {
+                stage: key.stage,
+                source: key.source.clone(),
+                ms: millis(*elapsed),
+            })
+            .collect(),
+    };
+    let json = serde_json::to_string_pretty(&file)?;
+    let path = run_dir.join(RUN_TIMINGS_FILENAME);
+    std::fs::write(&path, format!("{json}\n")).with_context(|| format!("write {}", path.display()))
+}
+
+/// Pipeline order, then the order sources were first seen.
+fn ordered(stages: &[(StageKey, Duration)]) -> Vec<&(StageKey, Duration)> {
+    let mut ordered: Vec<_> = stages.iter().collect();
+    ordered.sort_by_key(|(key, _)| {
+        TIMED_STAGES
+            .iter()
+            .position(|stage| *stage == key.stage)
+            .unwrap_or(TIMED_STAGES.len())
+    });
+    ordered
+}
+
+fn timings_table(total: Duration, stages: &[(StageKey, Duration)]) -> String {
+    let rows: Vec<(&str, &str, String)> = ordered(stages)
+        .into_iter()
+        .map(|(key, elapsed)| {
+            (
+                key.stage,
+                key.source.as_deref().unwrap_or("-"),
+                format_elapsed(*elapsed),
+            )
+        })
+        .collect();
+    let stage_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0).max(5);
+    let source_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0).max(6);
+
+    let mut out = String::from("Timings:\n");
+    out.push_str(&format!(
+        "  {:stage_width$}  {:source_width$}  time\n",
+        "stage", "source"
+    ));
+    for (stage, source, elapsed) in &rows {
+        out.push_str(&format!(
+            "  {stage:stage_width$}  {source:source_width$}  {elapsed}\n"
+        ));
+    }
+    out.push_str(&format!(
+        "  {:stage_width$}  {:source_width$}  {}\n",
+        "total",
+        "",
+        format_elapsed(total)
+    ));
+    out
+}
+
+fn millis(elapsed: Duration) -> u64 {
+    u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// `412ms`, `12.4s`, or `12m 03s`.
+fn format_elapsed(elapsed: Duration) -> String {
+    let ms = millis(elapsed);
+    if ms < 1_000 {
+        format!("{ms}ms")
+    } else if ms < 60_000 {
+        format!("{:.1}s", elapsed.as_secs_f64())
+    } else {
+        let secs = elapsed.as_secs();
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(stage: &'static str, source: Option<&str>) -> StageKey {
+        StageKey {
+            stage,
+            source: source.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn repeated_spans_add_up_per_stage_and_source() {
+        let mut recorded = Recorded {
+            started: None,
+            show: false,
+            run_dir: None,
+            stages: Vec::new(),
+        };
+        recorded.add(key("render", None), Duration::from_millis(40));
+        recorded.add(key("ingest", Some("github")), Duration::from_secs(3));
+        recorded.add(key("render", None), Duration::from_millis(60));
+        recorded.add(key("ingest", Some("jira")), Duration::from_secs(1));
+
+        let order: Vec<_> = ordered(&recorded.stages)
+            .into_iter()
+            .map(|(key, elapsed)| (key.stage, key.source.as_deref(), millis(*elapsed)))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("ingest", Some("github"), 3_000),
+                ("ingest", Some("jira"), 1_000),
+                ("render", None, 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn table_lists_stages_then_total() {
+        let stages = vec![
+            (key("ingest", Some("github")), Duration::from_secs(372)),
+            (key("bundle", None), Duration::from_millis(85)),
+        ];
+        assert_eq!(
+            timings_table(Duration::from_millis(372_900), &stages),
+            "Timings:\n\
+             \x20 stage   source  time\n\
+             \x20 ingest  github  6m 12s\n\
+             \x20 bundle  -       85ms\n\
+             \x20 total           6m 12s\n"
+        );
+        assert_eq!(format_elapsed(Duration::from_millis(12_400)), "12.4s");
+    }
+}
@@ -0,0 +1,292 @@
+//! Release lookup and checksum-verified binary replacement for
+//! `shiplog self-update`.
+//!
+//! Releases publish one raw binary per target plus `SHA256SUMS.txt` (see
+//! `.github/workflows/release.yml`). This module finds the latest release,
+//! picks the asset for the running platform, refuses any download whose
+//! SHA-256 does not match the published sum, and swaps the running executable
+//! for the verified one.
+
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+/// Repository releases are fetched from unless `SHIPLOG_RELEASE_REPO` is set.
+pub const DEFAULT_RELEASE_REPO: &str = "EffortlessMetrics/shiplog";
+
+/// GitHub REST API root.
+pub const DEFAULT_API_BASE: &str = "https://api.github.com";
+
+/// Name of the checksum asset attached to every release.
+pub const CHECKSUMS_ASSET: &str = "SHA256SUMS.txt";
+
+/// Where to look for releases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseSource {
+    /// REST API root, e.g. `https://api.github.com`.
+    pub api_base: String,
+    /// `owner/repo` publishing the releases.
+    pub repo: String,
+}
+
+impl ReleaseSource {
+    /// The defaults, overridden by `SHIPLOG_RELEASE_REPO` (as in the install
+    /// scripts) and `SHIPLOG_RELEASE_API`.
+    pub fn from_env() -> Self {
+        let var = |name| {
+            std::env::var(name)
+                .ok()
+                .filter(|value: &String| !value.trim().is_empty())
+        };
+        Self {
+            api_base: var("SHIPLOG_RELEASE_API").unwrap_or_else(|| DEFAULT_API_BASE.to_string()),
+            repo: var("SHIPLOG_RELEASE_REPO").unwrap_or_else(|| DEFAULT_RELEASE_REPO.to_string()),
+        }
+    }
+}
+
+/// A downloadable file attached to a release.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ReleaseAsset {
+    /// File name, e.g. `shiplog-x86_64-unknown-linux-gnu`.
+    pub name: String,
+    /// Direct download URL.
+    pub browser_download_url: String,
+}
+
+/// The parts of a GitHub release `self-update` uses.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Release {
+    /// Tag, e.g. `v0.12.0`.
+    pub tag_name: String,
+    /// Release page.
+    #[serde(default)]
+    pub html_url: String,
+    /// Attached files.
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+impl Release {
+    /// Version from the tag with any leading `v` removed.
+    pub fn version(&self) -> &str {
+        self.tag_name.trim_start_matches('v')
+    }
+
+    /// Find an attached file by name.
+    pub fn asset(&self, name: &str) -> Option<&ReleaseAsset> {
+        self.assets.iter().find(|asset| asset.name == name)
+    }
+}
+
+/// Release asset name for the platform this binary was built for, or `None`
+/// where no prebuilt binary is published.
+pub fn platform_asset_name() -> Option<&'static str> {
+    if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        Some("shiplog-x86_64-unknown-linux-gnu")
+    } else if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+        Some("shiplog-x86_64-apple-darwin")
+    } else if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        Some("shiplog-aarch64-apple-darwin")
+    } else if cfg!(all(target_os = "windows", target_arch = "x86_64")) {
+        Some("shiplog-x86_64-pc-windows-msvc.exe")
+    } else {
+        None
+    }
+}
+
+/// Compare two `major.minor.patch` versions. A pre-release sorts before the
+/// release it precedes; pre-release labels are otherwise not ordered.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use shiplog::update::compare_versions;
+///
+/// assert_eq!(compare_versions("0.12.0", "0.11.3").unwrap(), Ordering::Greater);
+/// assert_eq!(compare_versions("v0.11.0", "0.11.0").unwrap(), Ordering::Equal);
+/// assert_eq!(compare_versions("0.12.0-rc.1", "0.12.0").unwrap(), Ordering::Less);
+/// ```
+pub fn compare_versions(a: &str, b: &str) -> Result<Ordering> {
+    let (a_core, a_pre) = parse_version(a)?;
+    let (b_core, b_pre) = parse_version(b)?;
+    Ok(a_core.cmp(&b_core).then(b_pre.cmp(&a_pre)))
+}
+
+fn parse_version(version: &str) -> Result<([u64; 3], bool)> {
+    let version = version.trim().trim_start_matches('v');
+    let (core, pre) = match version.split_once('-') {
+        Some((core, _)) => (core, true),
+        None => (version.split('+').next().unwrap_or(version), false),
+    };
+    let mut parts = [0; 3];
+    let mut fields = core.split('.');
+    for part in &mut parts {
+        let field = fields
+            .next()
+            .ok_or_else(|| anyhow!("version {version:?} is not major.minor.patch"))?;
+        *part = field
+            .parse()
+            .with_context(|| format!("parse version {version:?}"))?;
+    }
+    if fields.next().is_some() {
+        bail!("version {version:?} is not major.minor.patch");
+    }
+    Ok((parts, pre))
+}
+
+/// Look up the expected SHA-256 for `asset` in a `sha256sum`-style listing.
+/// Entries may name the file bare or under a directory, as the release
+/// workflow writes them.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::update::expected_checksum;
+///
+/// let sums = "abc123  ./shiplog-x86_64-apple-darwin/shiplog-x86_64-apple-darwin\n";
+/// assert_eq!(
+///     expected_checksum(sums, "shiplog-x86_64-apple-darwin"),
+///     Some("abc123".to_string())
+/// );
+/// assert_eq!(expected_checksum(sums, "shiplog-aarch64-apple-darwin"), None);
+/// ```
+pub fn expected_checksum(sums: &str, asset: &str) -> Option<String> {
+    sums.lines().find_map(|line| {
+        let (hash, path) = line.trim().split_once(char::is_whitespace)?;
+        let path = path.trim_start().trim_start_matches('*');
+        let name = path.rsplit(['/', '\\']).next()?;
+        (name == asset).then(|| hash.to_ascii_lowercase())
+    })
+}
+
+/// Fail unless `bytes` hash to the sum listed for `asset`.
+pub fn verify_checksum(bytes: &[u8], sums: &str, asset: &str) -> Result<()> {
+    let expected = expected_checksum(sums, asset)
+        .ok_or_else(|| anyhow!("{CHECKSUMS_ASSET} has no entry for {asset}"))?;
+    let actual = hex::encode(Sha256::digest(bytes));
+    if actual != expected {
+        bail!("checksum mismatch for {asset}: expected {expected}, got {actual}");
+    }
+    Ok(())
+}
+
+/// Talks to the release API and downloads assets.
+#[derive(Clone, Debug)]
+pub struct Updater {
+    source: ReleaseSource,
+    client: Client,
+}
+
+impl Updater {
+    /// Create an updater for `source`.
+    pub fn new(source: ReleaseSource) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent(concat!("shiplog/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .context("build reqwest client")?;
+        Ok(Self { source, client })
+    }
+
+    /// Fetch the latest published release.
+    pub fn latest_release(&self) -> Result<Release> {
+        let url = format!(
+            "{}/repos/{}/releases/latest",
+            self.source.api_base.trim_end_matches('/'),
+            self.source.repo
+        );
+        self.client
+            .get(&url)
+            .header("Accept", "application/vnd.github+json")
+            .send()
+            .with_context(|| format!("fetch latest release of {}", self.source.repo))?
+            .error_for_status()
+            .with_context(|| format!("fetch latest release of {}", self.source.repo))?
+            .json()
+            .context("parse latest release")
+    }
+
+    /// Download `asset_name` from `release` and verify it against the
+    /// release's `SHA256SUMS.txt`.
+    pub fn download_verified(&self, release: &Release, asset_name: &str) -> Result<Vec<u8>> {
+        let asset = release
+            .asset(asset_name)
+            .ok_or_else(|| anyhow!("release {} has no {asset_name} asset", release.tag_name))?;
+        let sums = release.asset(CHECKSUMS_ASSET).ok_or_else(|| {
+            anyhow!(
+                "release {} has no {CHECKSUMS_ASSET}; refusing an unverifiable update",
+                release.tag_name
+            )
+        })?;
+        let sums = String::from_utf8(self.download(sums)?)
+            .with_context(|| format!("read {CHECKSUMS_ASSET}"))?;
+        let bytes = self.download(asset)?;
+        verify_checksum(&bytes, &sums, asset_name)?;
+        Ok(bytes)
+    }
+
+    fn download(&self, asset: &ReleaseAsset) -> Result<Vec<u8>> {
+        let response = self
+            .client
+            .get(&asset.browser_download_url)
+            .send()
+            .with_context(|| format!("download {}", asset.name))?
+            .error_for_status()
+            .with_context(|| format!("download {}", asset.name))?;
+        Ok(response
+            .bytes()
+            .with_context(|| format!("read {}", asset.name))?
+            .to_vec())
+    }
+}
+
+/// Replace the executable at `target` with `bytes`.
+///
+/// The new binary is written next to `target` and renamed over it, so an
+/// interrupted update leaves the old binary in place. On Windows, where a
+/// running executable cannot be overwritten, the old binary is first moved
+/// aside to `<name>.old`.
+pub fn replace_executable(target: &Path, bytes: &[u8]) -> Result<()> {
+    let dir = target
+        .parent()
+        .ok_or_else(|| anyhow!("{} has no parent directory", target.display()))?;
+    let file_name = target
+        .file_name()
+        .ok_or_else(|| anyhow!("{} has no file name", target.display()))?
+        .to_string_lossy();
+    let staged = dir.join(format!(".{file_name}.update"));
+    std::fs::write(&staged, bytes).with_context(|| format!("write {}", staged.display()))?;
+    set_executable(&staged)?;
+
+    if cfg!(windows) {
+        let old = old_executable_path(target);
+        let _ = std::fs::remove_file(&old);
+        std::fs::rename(target, &old)
+            .with_context(|| format!("move {} aside", target.display()))?;
+    }
+    std::fs::rename(&staged, target).with_context(|| format!("replace {}", target.display()))?;
+    Ok(())
+}
+
+fn old_executable_path(target: &Path) -> PathBuf {
+    let mut name = target.as_os_str().to_owned();
+    name.push(".old");
+    PathBuf::from(name)
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("mark {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -2,17 +2,56 @@
 //!
 //! This module intentionally has a single responsibility:
 //! assign events to workstream buckets based on repository and build
-//! deterministic workstream IDs, stats, and receipt ordering.
+//! deterministic workstream IDs, stats, and receipt ordering. It also names
+//! the built-in strategies a config can choose between.
 
 use anyhow::Result;
 use chrono::Utc;
+use serde::Deserialize;
 use shiplog::ids::WorkstreamId;
 use shiplog::ports::WorkstreamClusterer;
 use shiplog::schema::event::EventEnvelope;
 use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 use std::collections::BTreeMap;
 
+use super::labels::LabelClusterer;
 use super::receipt_policy::{should_include_cluster_receipt, truncate_cluster_receipts};
+use super::titles::TitleSimilarityClusterer;
+
+/// Built-in clustering strategy, chosen with `[workstreams] clusterer`.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::workstreams::ClusterStrategy;
+///
+/// let strategy: ClusterStrategy = serde_json::from_str("\"title\"").unwrap();
+/// assert_eq!(strategy, ClusterStrategy::Title);
+/// assert_eq!(ClusterStrategy::default(), ClusterStrategy::Repo);
+/// assert!(strategy.clusterer().cluster(&[]).unwrap().workstreams.is_empty());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClusterStrategy {
+    /// One workstream per repository ([`RepoClusterer`]).
+    #[default]
+    Repo,
+    /// One workstream per label or tag ([`LabelClusterer`]).
+    Label,
+    /// Per-repository title topics ([`TitleSimilarityClusterer`]).
+    Title,
+}
+
+impl ClusterStrategy {
+    /// Clusterer implementing this strategy.
+    pub fn clusterer(self) -> Box<dyn WorkstreamClusterer> {
+        match self {
+            Self::Repo => Box::new(RepoClusterer),
+            Self::Label => Box::new(LabelClusterer),
+            Self::Title => Box::new(TitleSimilarityClusterer),
+        }
+    }
+}
 
 /// Default clustering strategy for shiplog.
 ///
@@ -44,30 +83,17 @@ impl WorkstreamClusterer for RepoClusterer {
                 .push(ev);
         }
 
-        let mut workstreams = Vec::new();
-        for (repo, evs) in by_repo {
-            let id = WorkstreamId::from_parts(["repo", &repo]);
-            let mut ws = Workstream {
-                id,
-                title: repo.clone(),
-                summary: None,
-                tags: vec!["repo".to_string()],
-                stats: WorkstreamStats::zero(),
-                events: vec![],
-                receipts: vec![],
-            };
-
-            for ev in evs {
-                ws.events.push(ev.id.clone());
-                ws.bump_stats(&ev.kind);
-                if should_include_cluster_receipt(&ev.kind, ws.receipts.len()) {
-                    ws.receipts.push(ev.id.clone());
-                }
-            }
-            truncate_cluster_receipts(&mut ws.receipts);
-
-            workstreams.push(ws);
-        }
+        let workstreams = by_repo
+            .into_iter()
+            .map(|(repo, evs)| {
+                build_workstream(
+                    WorkstreamId::from_parts(["repo", &repo]),
+                    repo,
+                    vec!["repo".to_string()],
+                    &evs,
+                )
+            })
+            .collect();
 
         Ok(WorkstreamsFile {
             version: 1,
@@ -77,6 +103,37 @@ impl WorkstreamClusterer for RepoClusterer {
     }
 }
 
+/// Build one suggested workstream with stats and a compact receipt list.
+pub(super) fn build_workstream(
+    id: WorkstreamId,
+    title: String,
+    tags: Vec<String>,
+    events: &[&EventEnvelope],
+) -> Workstream {
+    let mut ws = Workstream {
+        id,
+        title,
+        summary: None,
+        tags,
+        stats: WorkstreamStats::zero(),
+        events: vec![],
+        receipts: vec![],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
+    };
+
+    for ev in events {
+        ws.events.push(ev.id.clone());
+        ws.bump_stats(&ev.kind);
+        ws.stats.add_effort(ev.payload.effort());
+        if should_include_cluster_receipt(&ev.kind, ws.receipts.len()) {
+            ws.receipts.push(ev.id.clone());
+        }
+    }
+    truncate_cluster_receipts(&mut ws.receipts);
+    ws
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,6 +147,7 @@ mod tests {
 
     fn make_event(repo_name: &str, event_id: &str, number: u64, kind: EventKind) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["x", event_id]),
             kind: kind.clone(),
             occurred_at: Utc::now(),
@@ -121,6 +179,8 @@ mod tests {
                     submitted_at: Utc::now(),
                     state: "approved".into(),
                     window: None,
+                    pull_created_at: None,
+                    pull_author: None,
                 }),
                 EventKind::Manual => EventPayload::Manual(ManualEvent {
                     event_type: ManualEventType::Note,
@@ -129,6 +189,64 @@ mod tests {
                     started_at: None,
                     ended_at: None,
                     impact: None,
+                    effort: None,
+                    metrics: Vec::new(),
+                    evidence: Vec::new(),
+                }),
+                EventKind::Issue => EventPayload::Issue(IssueEvent {
+                    number,
+                    title: "Issue".into(),
+                    state: IssueState::Closed,
+                    labels: vec![],
+                    opened_at: Utc::now(),
+                    closed_at: Some(Utc::now()),
+                    window: None,
+                    effort: None,
+                }),
+                EventKind::Deployment => EventPayload::Deployment(DeploymentEvent {
+                    environment: "production".into(),
+                    version: Some(format!("v{number}")),
+                    status: DeploymentStatus::Succeeded,
+                    started_at: Utc::now(),
+                    finished_at: Some(Utc::now()),
+                    window: None,
+                }),
+                EventKind::Release => EventPayload::Release(ReleaseEvent {
+                    version: format!("v{number}"),
+                    name: None,
+                    status: ReleaseStatus::Published,
+                    created_at: Utc::now(),
+                    published_at: Some(Utc::now()),
+                    window: None,
+                }),
+                EventKind::Comment => EventPayload::Comment(CommentEvent {
+                    target: CommentTarget::PullRequest,
+                    target_number: Some(number),
+                    target_title: format!("Thread #{number}"),
+                    thread: None,
+                    count: 1,
+                    first_at: Utc::now(),
+                    last_at: Utc::now(),
+                    window: None,
+                }),
+                EventKind::Incident => EventPayload::Incident(IncidentEvent {
+                    title: format!("Incident #{number}"),
+                    severity: IncidentSeverity::Unknown,
+                    role: IncidentRole::Unknown,
+                    detected_at: Utc::now(),
+                    resolved_at: None,
+                    duration_minutes: None,
+                    summary: None,
+                    customer_impact: None,
+                    window: None,
+                }),
+                EventKind::Document => EventPayload::Document(DocumentEvent {
+                    doc_type: DocumentType::Design,
+                    title: format!("Doc #{number}"),
+                    system: "confluence".to_string(),
+                    last_edited_at: Utc::now(),
+                    word_count_delta: None,
+                    window: None,
                 }),
             },
             tags: vec![],
@@ -138,6 +256,9 @@ mod tests {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -0,0 +1,495 @@
+//! Programmatic edits to a workstreams file.
+//!
+//! Merge, split, rename, and re-tag operations on [`WorkstreamManager`].
+//! Each keeps every event in at most one workstream, carries receipt anchors
+//! and curated receipt weights along with their events, and recomputes stats
+//! from the ledger, so the result still validates against the run.
+
+use anyhow::Result;
+use shiplog::ids::{EventId, WorkstreamId};
+use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
+use std::collections::{BTreeSet, HashMap};
+
+use super::layout::WorkstreamManager;
+
+/// What [`WorkstreamManager::merge`] moved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeOutcome {
+    /// Title of the workstream that was merged away.
+    pub from_title: String,
+    /// Title of the workstream that absorbed it.
+    pub into_title: String,
+    /// Events moved into the surviving workstream.
+    pub event_count: usize,
+    /// Receipt anchors moved into the surviving workstream.
+    pub receipt_count: usize,
+}
+
+/// What [`WorkstreamManager::split`] moved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitOutcome {
+    /// Title of the workstream the events left.
+    pub from_title: String,
+    /// ID of the new workstream.
+    pub id: WorkstreamId,
+    /// Title of the new workstream.
+    pub title: String,
+    /// Events moved into the new workstream.
+    pub event_count: usize,
+    /// Receipt anchors moved into the new workstream.
+    pub receipt_count: usize,
+}
+
+impl WorkstreamManager {
+    /// Index of the workstream whose title or ID is `selector`.
+    ///
+    /// Fails when nothing matches or when a title is shared by several
+    /// workstreams.
+    pub fn find_index(workstreams: &WorkstreamsFile, selector: &str) -> Result<usize> {
+        let selector = selector.trim();
+        match Self::find_index_optional(workstreams, selector)? {
+            Some(idx) => Ok(idx),
+            None => anyhow::bail!(
+                "no workstream matched {selector:?}; run `shiplog workstreams list` to see available titles and IDs"
+            ),
+        }
+    }
+
+    /// Like [`find_index`](Self::find_index), but `None` when nothing matches.
+    pub fn find_index_optional(
+        workstreams: &WorkstreamsFile,
+        selector: &str,
+    ) -> Result<Option<usize>> {
+        let selector = selector.trim();
+        if selector.is_empty() {
+            anyhow::bail!("workstream selector cannot be blank");
+        }
+
+        let matches: Vec<_> = workstreams
+            .workstreams
+            .iter()
+            .enumerate()
+            .filter(|(_, workstream)| {
+                workstream.title == selector || workstream.id.to_string() == selector
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+
+        match matches.as_slice() {
+            [idx] => Ok(Some(*idx)),
+            [] => Ok(None),
+            _ => anyhow::bail!(
+                "multiple workstreams matched {selector:?}; use the workstream ID instead"
+            ),
+        }
+    }
+
+    /// Retitle a workstream, keeping its ID. Returns the old title.
+    pub fn rename(
+        workstreams: &mut WorkstreamsFile,
+        selector: &str,
+        title: &str,
+    ) -> Result<String> {
+        let title = title.trim();
+        if title.is_empty() {
+            anyhow::bail!("new workstream title cannot be blank");
+        }
+
+        let idx = Self::find_index(workstreams, selector)?;
+        if title_taken(workstreams, title, Some(idx)) {
+            anyhow::bail!("another workstream is already titled {title:?}");
+        }
+
+        Ok(std::mem::replace(
+            &mut workstreams.workstreams[idx].title,
+            title.to_string(),
+        ))
+    }
+
+    /// Add and remove tags on a workstream. Returns the resulting tags.
+    ///
+    /// Existing tag order is kept, new tags are appended, and duplicates are
+    /// ignored.
+    pub fn retag(
+        workstreams: &mut WorkstreamsFile,
+        selector: &str,
+        add: &[String],
+        remove: &[String],
+    ) -> Result<Vec<String>> {
+        let add: Vec<&str> = add.iter().map(|tag| tag.trim()).collect();
+        let remove: BTreeSet<&str> = remove.iter().map(|tag| tag.trim()).collect();
+        if add.iter().chain(&remove).any(|tag| tag.is_empty()) {
+            anyhow::bail!("workstream tags cannot be blank");
+        }
+        if add.is_empty() && remove.is_empty() {
+            anyhow::bail!("nothing to retag; pass tags to add or remove");
+        }
+        if let Some(tag) = add.iter().find(|tag| remove.contains(*tag)) {
+            anyhow::bail!("tag {tag:?} cannot be both added and removed");
+        }
+
+        let idx = Self::find_index(workstreams, selector)?;
+        let tags = &mut workstreams.workstreams[idx].tags;
+        tags.retain(|tag| !remove.contains(tag.as_str()));
+        for tag in add {
+            if !tags.iter().any(|existing| existing == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+        Ok(tags.clone())
+    }
+
+    /// Fold workstream `from` into `into` and remove it.
+    ///
+    /// `into` keeps its ID, title, and summary (taking `from`'s summary only
+    /// if it has none) and gains `from`'s events, receipts, receipt weights,
+    /// and tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::schema::workstream::WorkstreamsFile;
+    /// use shiplog::workstreams::WorkstreamManager;
+    /// use shiplog_testkit::fixtures::WorkstreamFixture;
+    /// use shiplog_testkit::pr_event;
+    /// use chrono::Utc;
+    ///
+    /// let invoices = pr_event("acme/billing", 1, "Retry failed invoices");
+    /// let exports = pr_event("acme/exports", 2, "Export invoices as CSV");
+    /// let mut file = WorkstreamsFile {
+    ///     version: 1,
+    ///     generated_at: Utc::now(),
+    ///     workstreams: vec![
+    ///         WorkstreamFixture::new("Billing").with_event(&invoices).build(),
+    ///         WorkstreamFixture::new("Exports").with_event(&exports).build(),
+    ///     ],
+    /// };
+    ///
+    /// let ledger = [invoices, exports];
+    /// let merged = WorkstreamManager::merge(&mut file, "Exports", "Billing", &ledger).unwrap();
+    /// assert_eq!(merged.event_count, 1);
+    /// assert_eq!(file.workstreams.len(), 1);
+    /// assert_eq!(file.workstreams[0].stats.pull_requests, 2);
+    /// ```
+    pub fn merge(
+        workstreams: &mut WorkstreamsFile,
+        from: &str,
+        into: &str,
+        ledger_events: &[EventEnvelope],
+    ) -> Result<MergeOutcome> {
+        let from_idx = Self::find_index(workstreams, from)?;
+        let into_idx = Self::find_index(workstreams, into)?;
+        if from_idx == into_idx {
+            anyhow::bail!("cannot merge a workstream into itself");
+        }
+
+        let source = workstreams.workstreams.remove(from_idx);
+        let into_idx = if into_idx > from_idx {
+            into_idx - 1
+        } else {
+            into_idx
+        };
+        let target = &mut workstreams.workstreams[into_idx];
+        let event_count = append_unique(&mut target.events, source.events);
+        let receipt_count = append_unique(&mut target.receipts, source.receipts);
+        for (event_id, weight) in source.receipt_weights {
+            target.receipt_weights.entry(event_id).or_insert(weight);
+        }
+        for tag in source.tags {
+            if !target.tags.contains(&tag) {
+                target.tags.push(tag);
+            }
+        }
+        if target.summary.is_none() {
+            target.summary = source.summary;
+        }
+        let into_title = target.title.clone();
+
+        Self::recompute_stats(workstreams, ledger_events);
+        Ok(MergeOutcome {
+            from_title: source.title,
+            into_title,
+            event_count,
+            receipt_count,
+        })
+    }
+
+    /// Move the listed events out of workstream `from` into a new workstream
+    /// titled `title`, appended after the existing ones.
+    ///
+    /// Every listed event must belong to `from`. Receipt anchors and curated
+    /// weights for those events move with them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::schema::workstream::WorkstreamsFile;
+    /// use shiplog::workstreams::WorkstreamManager;
+    /// use shiplog_testkit::fixtures::WorkstreamFixture;
+    /// use shiplog_testkit::pr_event;
+    /// use chrono::Utc;
+    ///
+    /// let invoices = pr_event("acme/billing", 1, "Retry failed invoices");
+    /// let tax = pr_event("acme/billing", 2, "Round tax totals");
+    /// let mut file = WorkstreamsFile {
+    ///     version: 1,
+    ///     generated_at: Utc::now(),
+    ///     workstreams: vec![WorkstreamFixture::new("Billing")
+    ///         .with_event(&invoices)
+    ///         .with_event(&tax)
+    ///         .with_receipt(&tax)
+    ///         .build()],
+    /// };
+    ///
+    /// let ids = [tax.id.to_string()];
+    /// let ledger = [invoices, tax];
+    /// let split = WorkstreamManager::split(&mut file, "Billing", &ids, "Tax", &ledger).unwrap();
+    /// assert_eq!((split.event_count, split.receipt_count), (1, 1));
+    /// assert_eq!(file.workstreams[1].title, "Tax");
+    /// assert_eq!(file.workstreams[0].stats.pull_requests, 1);
+    /// ```
+    pub fn split(
+        workstreams: &mut WorkstreamsFile,
+        from: &str,
+        event_ids: &[String],
+        title: &str,
+        ledger_events: &[EventEnvelope],
+    ) -> Result<SplitOutcome> {
+        let title = title.trim();
+        if title.is_empty() {
+            anyhow::bail!("new workstream title cannot be blank");
+        }
+        if event_ids.is_empty() {
+            anyhow::bail!("choose at least one event to split out");
+        }
+        let from_idx = Self::find_index(workstreams, from)?;
+        if title_taken(workstreams, title, None) {
+            anyhow::bail!("another workstream is already titled {title:?}");
+        }
+        let id = WorkstreamId::from_parts(["split", title]);
+        if workstreams.workstreams.iter().any(|ws| ws.id == id) {
+            anyhow::bail!("a workstream with ID {id} already exists");
+        }
+
+        let source = &mut workstreams.workstreams[from_idx];
+        let from_title = source.title.clone();
+        let mut selected = BTreeSet::new();
+        for event_id in event_ids {
+            let event_id = event_id.trim();
+            if !source.events.iter().any(|id| id.to_string() == event_id) {
+                anyhow::bail!("event {event_id:?} is not assigned to workstream {from_title:?}");
+            }
+            selected.insert(event_id);
+        }
+
+        let is_selected = |id: &EventId| selected.contains(id.to_string().as_str());
+        let (events, kept): (Vec<_>, Vec<_>) = source.events.drain(..).partition(is_selected);
+        source.events = kept;
+        let (receipts, kept): (Vec<_>, Vec<_>) = source.receipts.drain(..).partition(is_selected);
+        source.receipts = kept;
+        let receipt_weights = receipts
+            .iter()
+            .filter_map(|id| Some((id.clone(), source.receipt_weights.remove(id)?)))
+            .collect();
+
+        let outcome = SplitOutcome {
+            from_title,
+            id: id.clone(),
+            title: title.to_string(),
+            event_count: events.len(),
+            receipt_count: receipts.len(),
+        };
+        workstreams.workstreams.push(Workstream {
+            id,
+            title: title.to_string(),
+            summary: None,
+            tags: Vec::new(),
+            stats: WorkstreamStats::zero(),
+            events,
+            receipts,
+            receipt_weights,
+            rules: Default::default(),
+        });
+
+        Self::recompute_stats(workstreams, ledger_events);
+        Ok(outcome)
+    }
+
+    /// Recount every workstream's stats from its events in the ledger.
+    ///
+    /// Event IDs missing from the ledger are not counted.
+    pub fn recompute_stats(workstreams: &mut WorkstreamsFile, ledger_events: &[EventEnvelope]) {
+        let by_id: HashMap<_, _> = ledger_events
+            .iter()
+            .map(|event| (event.id.to_string(), event))
+            .collect();
+
+        for workstream in &mut workstreams.workstreams {
+            workstream.stats = WorkstreamStats::zero();
+            for event_id in workstream.events.clone() {
+                if let Some(event) = by_id.get(&event_id.to_string()) {
+                    workstream.bump_stats(&event.kind);
+                    workstream.stats.add_effort(event.payload.effort());
+                }
+            }
+        }
+    }
+}
+
+/// Whether a workstream other than `except` already uses `title`.
+fn title_taken(workstreams: &WorkstreamsFile, title: &str, except: Option<usize>) -> bool {
+    workstreams
+        .workstreams
+        .iter()
+        .enumerate()
+        .any(|(idx, workstream)| Some(idx) != except && workstream.title == title)
+}
+
+/// Append IDs not already present, returning how many were appended.
+fn append_unique(target: &mut Vec<EventId>, incoming: Vec<EventId>) -> usize {
+    let before = target.len();
+    for event_id in incoming {
+        if !target.contains(&event_id) {
+            target.push(event_id);
+        }
+    }
+    target.len() - before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use shiplog::schema::event::*;
+    use shiplog::schema::workstream::ReceiptWeight;
+
+    fn pr(number: u64) -> EventEnvelope {
+        let at = Utc.with_ymd_and_hms(2025, 3, 3, 12, 0, 0).unwrap();
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["test", &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: at,
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "acme/mono".into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: format!("PR {number}"),
+                state: PullRequestState::Merged,
+                created_at: at,
+                merged_at: Some(at),
+                additions: None,
+                deletions: None,
+                changed_files: None,
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    fn workstream(title: &str, events: &[&EventEnvelope], tags: &[&str]) -> Workstream {
+        let ids: Vec<_> = events.iter().map(|event| event.id.clone()).collect();
+        Workstream {
+            id: WorkstreamId::from_parts(["test", title]),
+            title: title.into(),
+            summary: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            stats: WorkstreamStats::zero(),
+            events: ids.clone(),
+            receipts: ids,
+            receipt_weights: Default::default(),
+            rules: Default::default(),
+        }
+    }
+
+    fn file(workstreams: Vec<Workstream>) -> WorkstreamsFile {
+        WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams,
+        }
+    }
+
+    #[test]
+    fn merge_carries_weights_tags_and_summary() {
+        let (a, b) = (pr(1), pr(2));
+        let mut source = workstream("Exports", &[&b], &["data", "billing"]);
+        source.summary = Some("CSV exports".into());
+        source
+            .receipt_weights
+            .insert(b.id.clone(), ReceiptWeight::High);
+        let mut ws = file(vec![source, workstream("Billing", &[&a], &["billing"])]);
+
+        let outcome = WorkstreamManager::merge(&mut ws, "Exports", "Billing", &[a, b]).unwrap();
+
+        assert_eq!(outcome.into_title, "Billing");
+        assert_eq!((outcome.event_count, outcome.receipt_count), (1, 1));
+        let merged = &ws.workstreams[0];
+        assert_eq!(ws.workstreams.len(), 1);
+        assert_eq!(merged.tags, vec!["billing", "data"]);
+        assert_eq!(merged.summary.as_deref(), Some("CSV exports"));
+        assert_eq!(merged.stats.pull_requests, 2);
+        assert_eq!(merged.receipt_weights.len(), 1);
+        assert!(WorkstreamManager::merge(&mut ws, "Billing", "Billing", &[]).is_err());
+    }
+
+    #[test]
+    fn split_rejects_events_outside_the_source() {
+        let (a, b, c) = (pr(1), pr(2), pr(3));
+        let mut ws = file(vec![
+            workstream("Billing", &[&a, &b], &[]),
+            workstream("Search", &[&c], &[]),
+        ]);
+        let foreign = [c.id.to_string()];
+
+        let err = WorkstreamManager::split(&mut ws, "Billing", &foreign, "Tax", &[]).unwrap_err();
+        assert!(err.to_string().contains("not assigned to workstream"));
+        let err = WorkstreamManager::split(&mut ws, "Billing", &[a.id.to_string()], "Search", &[])
+            .unwrap_err();
+        assert!(err.to_string().contains("already titled"));
+        assert_eq!(ws.workstreams[0].events.len(), 2);
+    }
+
+    #[test]
+    fn rename_and_retag_keep_ids() {
+        let a = pr(1);
+        let mut ws = file(vec![workstream("Billing", &[&a], &["repo", "q1"])]);
+        let id = ws.workstreams[0].id.clone();
+
+        let old = WorkstreamManager::rename(&mut ws, &id.to_string(), " Payments ").unwrap();
+        let tags = WorkstreamManager::retag(
+            &mut ws,
+            "Payments",
+            &["platform".into(), "q1".into()],
+            &["repo".into()],
+        )
+        .unwrap();
+
+        assert_eq!(old, "Billing");
+        assert_eq!(ws.workstreams[0].title, "Payments");
+        assert_eq!(ws.workstreams[0].id, id);
+        assert_eq!(tags, vec!["q1", "platform"]);
+        assert!(WorkstreamManager::retag(&mut ws, "Payments", &[], &[]).is_err());
+        assert!(
+            WorkstreamManager::retag(&mut ws, "Payments", &["x".into()], &["x".into()]).is_err()
+        );
+    }
+}
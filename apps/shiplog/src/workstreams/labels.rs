@@ -0,0 +1,189 @@
+//! Label-based workstream clustering.
+//!
+//! Grouping by repository puts all of a monorepo's work in one workstream.
+//! [`LabelClusterer`] groups events by their labels instead: event tags plus
+//! issue labels. Events without labels fall back to repository grouping.
+
+use anyhow::Result;
+use chrono::Utc;
+use shiplog::ids::WorkstreamId;
+use shiplog::ports::WorkstreamClusterer;
+use shiplog::schema::event::{EventEnvelope, EventPayload};
+use shiplog::schema::workstream::WorkstreamsFile;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::cluster::{RepoClusterer, build_workstream};
+
+/// Cluster events by label, one workstream per label.
+///
+/// An event with several labels joins the workstream of its most common
+/// label across the whole run, so shared labels such as `billing` win over
+/// one-off labels. Ties go to the alphabetically first label.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ports::WorkstreamClusterer;
+/// use shiplog::workstreams::LabelClusterer;
+/// use shiplog_testkit::pr_event;
+///
+/// let mut invoices = pr_event("acme/mono", 1, "Retry failed invoices");
+/// invoices.tags = vec!["billing".into()];
+/// let mut search = pr_event("acme/mono", 2, "Rank exact matches first");
+/// search.tags = vec!["search".into()];
+///
+/// let ws = LabelClusterer.cluster(&[invoices, search]).unwrap();
+/// let titles: Vec<_> = ws.workstreams.iter().map(|ws| ws.title.as_str()).collect();
+/// assert_eq!(titles, ["billing", "search"]);
+/// ```
+pub struct LabelClusterer;
+
+impl WorkstreamClusterer for LabelClusterer {
+    fn cluster(&self, events: &[EventEnvelope]) -> Result<WorkstreamsFile> {
+        let labels: Vec<BTreeSet<&str>> = events.iter().map(event_labels).collect();
+        let mut frequency: BTreeMap<&str, usize> = BTreeMap::new();
+        for label in labels.iter().flatten() {
+            *frequency.entry(label).or_default() += 1;
+        }
+
+        let mut by_label: BTreeMap<&str, Vec<&EventEnvelope>> = BTreeMap::new();
+        let mut unlabeled = Vec::new();
+        for (event, labels) in events.iter().zip(&labels) {
+            // Iterating in label order and keeping the first maximum breaks
+            // ties alphabetically.
+            let best = labels.iter().copied().reduce(|best, label| {
+                if frequency[label] > frequency[best] {
+                    label
+                } else {
+                    best
+                }
+            });
+            match best {
+                Some(label) => by_label.entry(label).or_default().push(event),
+                None => unlabeled.push(event.clone()),
+            }
+        }
+
+        let mut workstreams: Vec<_> = by_label
+            .into_iter()
+            .map(|(label, events)| {
+                build_workstream(
+                    WorkstreamId::from_parts(["label", label]),
+                    label.to_string(),
+                    vec!["label".to_string()],
+                    &events,
+                )
+            })
+            .collect();
+        workstreams.extend(RepoClusterer.cluster(&unlabeled)?.workstreams);
+
+        Ok(WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams,
+        })
+    }
+}
+
+/// Non-empty, trimmed tags and issue labels of one event.
+pub(super) fn event_labels(event: &EventEnvelope) -> BTreeSet<&str> {
+    let issue_labels = match &event.payload {
+        EventPayload::Issue(issue) => issue.labels.as_slice(),
+        _ => &[],
+    };
+    event
+        .tags
+        .iter()
+        .chain(issue_labels)
+        .map(|label| label.trim())
+        .filter(|label| !label.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use shiplog::ids::EventId;
+    use shiplog::schema::event::*;
+
+    fn pr(repo: &str, number: u64, tags: &[&str]) -> EventEnvelope {
+        let at = Utc.with_ymd_and_hms(2025, 3, 3, 12, 0, 0).unwrap();
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["test", repo, &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: at,
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: repo.into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: format!("PR {number}"),
+                state: PullRequestState::Merged,
+                created_at: at,
+                merged_at: Some(at),
+                additions: None,
+                deletions: None,
+                changed_files: None,
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    fn titles(file: &WorkstreamsFile) -> Vec<(&str, usize)> {
+        file.workstreams
+            .iter()
+            .map(|ws| (ws.title.as_str(), ws.events.len()))
+            .collect()
+    }
+
+    #[test]
+    fn events_join_their_most_common_label() {
+        let events = vec![
+            pr("acme/mono", 1, &["billing"]),
+            pr("acme/mono", 2, &["billing", "urgent"]),
+            pr("acme/mono", 3, &["search"]),
+            pr("acme/mono", 4, &["search", "billing"]),
+        ];
+        let file = LabelClusterer.cluster(&events).unwrap();
+        assert_eq!(titles(&file), vec![("billing", 3), ("search", 1)]);
+        assert_eq!(file.workstreams[0].stats.pull_requests, 3);
+        assert_eq!(file.workstreams[0].tags, vec!["label"]);
+    }
+
+    #[test]
+    fn unlabeled_events_fall_back_to_their_repo() {
+        let events = vec![
+            pr("acme/mono", 1, &["billing"]),
+            pr("acme/mono", 2, &[" "]),
+            pr("acme/docs", 3, &[]),
+        ];
+        let file = LabelClusterer.cluster(&events).unwrap();
+        assert_eq!(
+            titles(&file),
+            vec![("billing", 1), ("acme/docs", 1), ("acme/mono", 1)]
+        );
+        assert_eq!(
+            file.workstreams[2].id,
+            WorkstreamId::from_parts(["repo", "acme/mono"])
+        );
+    }
+}
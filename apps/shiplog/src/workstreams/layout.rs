@@ -3,12 +3,14 @@
 //! This module owns the loading/saving rules for workstream files:
 //! - `workstreams.yaml` (curated, user-owned)
 //! - `workstreams.suggested.yaml` (machine-generated)
+//! - `workstreams.unassigned.yaml` (new events no curated workstream claimed)
 //!
 //! It intentionally has one responsibility: stable workstream-file semantics.
 
 use anyhow::{Context, Result};
 use shiplog::ports::WorkstreamClusterer;
 use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::json_schema::SchemaDocument;
 use shiplog::schema::workstream::WorkstreamsFile;
 use std::path::{Path, PathBuf};
 
@@ -18,6 +20,9 @@ pub const CURATED_FILENAME: &str = "workstreams.yaml";
 /// Machine-generated suggested workstream file.
 pub const SUGGESTED_FILENAME: &str = "workstreams.suggested.yaml";
 
+/// New events that reconciliation could not attach to a curated workstream.
+pub const UNASSIGNED_FILENAME: &str = "workstreams.unassigned.yaml";
+
 /// Load an existing YAML file if present, otherwise run clustering.
 ///
 /// # Examples
@@ -179,6 +184,21 @@ impl WorkstreamManager {
         out_dir.join(Self::SUGGESTED_FILENAME)
     }
 
+    /// Get the unassigned-events file path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shiplog::workstreams::WorkstreamManager;
+    /// use std::path::Path;
+    ///
+    /// let path = WorkstreamManager::unassigned_path(Path::new("./out/run_1"));
+    /// assert!(path.ends_with("workstreams.unassigned.yaml"));
+    /// ```
+    pub fn unassigned_path(out_dir: &Path) -> PathBuf {
+        out_dir.join(UNASSIGNED_FILENAME)
+    }
+
     /// Try to load curated then suggested workstreams.
     ///
     /// Returns `None` when neither file exists.
@@ -209,8 +229,9 @@ impl WorkstreamManager {
 fn read_workstreams(path: &Path) -> Result<WorkstreamsFile> {
     let text =
         std::fs::read_to_string(path).with_context(|| format!("read workstreams from {path:?}"))?;
-    let workstreams: WorkstreamsFile =
-        serde_yaml::from_str(&text).with_context(|| format!("parse workstreams yaml {path:?}"))?;
+    let workstreams: WorkstreamsFile = serde_yaml::from_str(&text)
+        .map_err(|err| SchemaDocument::WorkstreamsFile.annotate_yaml(&text, err))
+        .with_context(|| format!("parse workstreams yaml {path:?}"))?;
     Ok(workstreams)
 }
 
@@ -224,11 +245,13 @@ mod tests {
         Actor, EventEnvelope, EventKind, EventPayload, Link, RepoRef, RepoVisibility, ReviewEvent,
         SourceRef, SourceSystem,
     };
+    use shiplog::schema::version::SchemaVersion;
     use shiplog::schema::workstream::{Workstream, WorkstreamStats};
     use tempfile::tempdir;
 
     fn make_event(repo_name: &str, event_id: &str, number: u64) -> EventEnvelope {
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["test", event_id]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -247,6 +270,8 @@ mod tests {
                 submitted_at: Utc::now(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             tags: vec![],
             links: vec![Link {
@@ -258,6 +283,9 @@ mod tests {
                 url: Some("https://api.example.com".into()),
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         }
     }
 
@@ -273,6 +301,8 @@ mod tests {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         }
     }
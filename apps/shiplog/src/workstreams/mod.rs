@@ -1,8 +1,10 @@
-//! Repository-based workstream clustering and workstream file contracts.
+//! Workstream clustering and workstream file contracts.
 //!
-//! Clustering, curated/suggested file lifecycle policies, and receipt display
-//! limits live as modules under this crate so workstream phases do not become
-//! separate package contracts.
+//! Repository, label, and title clustering, curated/suggested file lifecycle
+//! policies, workstream edits, reconciliation of curated files with new
+//! events, draft summaries, receipt weights, and receipt display limits live
+//! as modules under this crate so workstream phases do not become separate
+//! package contracts.
 //!
 //! # Examples
 //!
@@ -31,15 +33,34 @@
 //! ```
 
 pub mod cluster;
+pub mod edit;
+pub mod labels;
 pub mod layout;
 pub mod receipt_policy;
+pub mod reconcile;
+pub mod summary;
+pub mod titles;
+pub mod weight;
 
-pub use cluster::RepoClusterer;
+pub use cluster::{ClusterStrategy, RepoClusterer};
+pub use edit::{MergeOutcome, SplitOutcome};
+pub use labels::LabelClusterer;
 pub use layout::{
-    CURATED_FILENAME, SUGGESTED_FILENAME, WorkstreamManager, load_or_cluster, write_workstreams,
+    CURATED_FILENAME, SUGGESTED_FILENAME, UNASSIGNED_FILENAME, WorkstreamManager, load_or_cluster,
+    write_workstreams,
 };
 pub use receipt_policy::{
-    WORKSTREAM_RECEIPT_LIMIT_MANUAL, WORKSTREAM_RECEIPT_LIMIT_REVIEW,
+    WORKSTREAM_RECEIPT_LIMIT_COMMENT, WORKSTREAM_RECEIPT_LIMIT_DEPLOYMENT,
+    WORKSTREAM_RECEIPT_LIMIT_DOCUMENT, WORKSTREAM_RECEIPT_LIMIT_INCIDENT,
+    WORKSTREAM_RECEIPT_LIMIT_ISSUE, WORKSTREAM_RECEIPT_LIMIT_MANUAL,
+    WORKSTREAM_RECEIPT_LIMIT_RELEASE, WORKSTREAM_RECEIPT_LIMIT_REVIEW,
     WORKSTREAM_RECEIPT_LIMIT_TOTAL, WORKSTREAM_RECEIPT_RENDER_LIMIT, max_cluster_receipts_for_kind,
     should_include_cluster_receipt, should_render_receipt_at, truncate_cluster_receipts,
 };
+pub use reconcile::{
+    MatchReason, ReconciledEvent, Reconciliation, UnassignedEvent, UnassignedEventsFile,
+    read_unassigned_events, reconcile_workstreams, write_unassigned_events,
+};
+pub use summary::{HeuristicSummarizer, SUMMARY_INPUT_PROFILE, SummarizingClusterer};
+pub use titles::TitleSimilarityClusterer;
+pub use weight::{derived_receipt_weight, order_receipts_by_weight, receipt_weight};
@@ -8,6 +8,24 @@ use shiplog::schema::event::EventKind;
 /// Max receipts kept during clustering for Review events.
 pub const WORKSTREAM_RECEIPT_LIMIT_REVIEW: usize = 5;
 
+/// Max receipts kept during clustering for Issue events.
+pub const WORKSTREAM_RECEIPT_LIMIT_ISSUE: usize = 5;
+
+/// Max receipts kept during clustering for Deployment events.
+pub const WORKSTREAM_RECEIPT_LIMIT_DEPLOYMENT: usize = 3;
+
+/// Max receipts kept during clustering for Release events.
+pub const WORKSTREAM_RECEIPT_LIMIT_RELEASE: usize = 5;
+
+/// Max receipts kept during clustering for Comment events.
+pub const WORKSTREAM_RECEIPT_LIMIT_COMMENT: usize = 3;
+
+/// Max receipts kept during clustering for Incident events.
+pub const WORKSTREAM_RECEIPT_LIMIT_INCIDENT: usize = 5;
+
+/// Max receipts kept during clustering for Document events.
+pub const WORKSTREAM_RECEIPT_LIMIT_DOCUMENT: usize = 5;
+
 /// Max receipts kept during clustering for Manual events.
 pub const WORKSTREAM_RECEIPT_LIMIT_MANUAL: usize = 7;
 
@@ -29,6 +47,12 @@ pub fn max_cluster_receipts_for_kind(kind: &EventKind) -> usize {
         EventKind::PullRequest => usize::MAX,
         EventKind::Review => WORKSTREAM_RECEIPT_LIMIT_REVIEW,
         EventKind::Manual => WORKSTREAM_RECEIPT_LIMIT_MANUAL,
+        EventKind::Issue => WORKSTREAM_RECEIPT_LIMIT_ISSUE,
+        EventKind::Deployment => WORKSTREAM_RECEIPT_LIMIT_DEPLOYMENT,
+        EventKind::Release => WORKSTREAM_RECEIPT_LIMIT_RELEASE,
+        EventKind::Comment => WORKSTREAM_RECEIPT_LIMIT_COMMENT,
+        EventKind::Incident => WORKSTREAM_RECEIPT_LIMIT_INCIDENT,
+        EventKind::Document => WORKSTREAM_RECEIPT_LIMIT_DOCUMENT,
     }
 }
 
@@ -0,0 +1,462 @@
+//! Reconciliation of curated workstreams with newly collected events.
+//!
+//! A curated `workstreams.yaml` is user-owned, so a refresh never re-clusters
+//! it. Without reconciliation, events collected after curation would belong
+//! to no workstream and never reach the packet. [`reconcile_workstreams`]
+//! attaches each new event to the one curated workstream it clearly belongs
+//! to and reports the rest, which are written to
+//! `workstreams.unassigned.yaml` for review.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shiplog::ids::{EventId, WorkstreamId};
+use shiplog::schema::event::{EventEnvelope, EventKind};
+use shiplog::schema::workstream::{Workstream, WorkstreamRules, WorkstreamsFile};
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+use super::labels::event_labels;
+use super::layout::WorkstreamManager;
+use super::summary::event_title;
+
+/// Why a new event was attached to a workstream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchReason {
+    /// The workstream's `rules` matched the event.
+    Rule,
+    /// The workstream already held events from the event's repository.
+    Repo,
+    /// The workstream's tags share a label with the event.
+    Tag,
+}
+
+/// A new event attached to a curated workstream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReconciledEvent {
+    /// The attached event.
+    pub event_id: EventId,
+    /// The workstream it joined.
+    pub workstream_id: WorkstreamId,
+    /// What matched.
+    pub matched_by: MatchReason,
+}
+
+/// A new event that no single curated workstream claimed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnassignedEvent {
+    /// Event ID, for `shiplog workstreams move --event`.
+    pub id: EventId,
+    /// Event kind.
+    pub kind: EventKind,
+    /// Repository full name.
+    pub repo: String,
+    /// Event title, when it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// When the event happened.
+    pub occurred_at: DateTime<Utc>,
+    /// Titles of the workstreams that matched equally well, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<String>,
+}
+
+/// Contents of `workstreams.unassigned.yaml`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnassignedEventsFile {
+    /// Format version.
+    pub version: u32,
+    /// When the file was written.
+    pub generated_at: DateTime<Utc>,
+    /// Events waiting for a workstream, in ledger order.
+    pub events: Vec<UnassignedEvent>,
+}
+
+/// Outcome of [`reconcile_workstreams`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Reconciliation {
+    /// Events attached to a workstream, in ledger order.
+    pub assigned: Vec<ReconciledEvent>,
+    /// Events left for review, in ledger order.
+    pub unassigned: Vec<UnassignedEvent>,
+}
+
+/// Attach newly collected events to curated workstreams.
+///
+/// New events are those in `events` that neither a workstream nor the
+/// `previous` ledger lists; events the user left unassigned on purpose stay
+/// that way. Each new event is matched in order of precedence:
+///
+/// 1. a workstream whose `rules` match its repository, a label, or its title;
+/// 2. the workstream that already holds events from its repository, narrowed
+///    by shared tags when several do;
+/// 3. the workstream whose tags share one of its labels.
+///
+/// The first step with exactly one match decides. An event matched by several
+/// workstreams at a step, or by none at all, stays unassigned. Attached
+/// events are not made receipt anchors, and stats are recomputed from the
+/// ledger.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::schema::workstream::WorkstreamsFile;
+/// use shiplog::workstreams::{MatchReason, reconcile_workstreams};
+/// use shiplog_testkit::fixtures::WorkstreamFixture;
+/// use shiplog_testkit::pr_event;
+/// use chrono::Utc;
+///
+/// let curated = pr_event("acme/billing", 1, "Retry failed invoices");
+/// let mut workstreams = WorkstreamsFile {
+///     version: 1,
+///     generated_at: Utc::now(),
+///     workstreams: vec![WorkstreamFixture::new("Billing").with_event(&curated).build()],
+/// };
+/// let ledger = [
+///     curated,
+///     pr_event("acme/billing", 2, "Round tax totals"),
+///     pr_event("acme/search", 3, "Rank exact matches first"),
+/// ];
+///
+/// let result = reconcile_workstreams(&mut workstreams, &ledger, &[]);
+/// assert_eq!(result.assigned[0].matched_by, MatchReason::Repo);
+/// assert_eq!(result.unassigned[0].repo, "acme/search");
+/// assert_eq!(workstreams.workstreams[0].events.len(), 2);
+/// ```
+pub fn reconcile_workstreams(
+    workstreams: &mut WorkstreamsFile,
+    events: &[EventEnvelope],
+    previous: &[EventEnvelope],
+) -> Reconciliation {
+    let assigned: HashSet<&EventId> = workstreams
+        .workstreams
+        .iter()
+        .flat_map(|ws| &ws.events)
+        .chain(previous.iter().map(|event| &event.id))
+        .collect();
+    let new_events: Vec<&EventEnvelope> = events
+        .iter()
+        .filter(|event| !assigned.contains(&event.id))
+        .collect();
+    if new_events.is_empty() {
+        return Reconciliation::default();
+    }
+
+    // Repositories are taken from curated assignments only, so the outcome
+    // does not depend on the order new events are attached in.
+    let repos: Vec<BTreeSet<String>> = workstreams
+        .workstreams
+        .iter()
+        .map(|ws| {
+            let ids: HashSet<&EventId> = ws.events.iter().collect();
+            events
+                .iter()
+                .filter(|event| ids.contains(&event.id))
+                .map(|event| event.repo.full_name.to_lowercase())
+                .collect()
+        })
+        .collect();
+
+    let mut result = Reconciliation::default();
+    let mut attachments = Vec::new();
+    for event in new_events {
+        match match_event(&workstreams.workstreams, &repos, event) {
+            Ok((idx, matched_by)) => {
+                attachments.push((idx, event.id.clone()));
+                result.assigned.push(ReconciledEvent {
+                    event_id: event.id.clone(),
+                    workstream_id: workstreams.workstreams[idx].id.clone(),
+                    matched_by,
+                });
+            }
+            Err(candidates) => result.unassigned.push(UnassignedEvent {
+                id: event.id.clone(),
+                kind: event.kind.clone(),
+                repo: event.repo.full_name.clone(),
+                title: event_title(event).map(str::to_string),
+                occurred_at: event.occurred_at,
+                candidates: candidates
+                    .into_iter()
+                    .map(|idx| workstreams.workstreams[idx].title.clone())
+                    .collect(),
+            }),
+        }
+    }
+
+    if !attachments.is_empty() {
+        for (idx, event_id) in attachments {
+            workstreams.workstreams[idx].events.push(event_id);
+        }
+        WorkstreamManager::recompute_stats(workstreams, events);
+    }
+    result
+}
+
+/// Read the events listed in `workstreams.unassigned.yaml`, or none when the
+/// file does not exist.
+pub fn read_unassigned_events(path: &Path) -> Result<Vec<UnassignedEvent>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("read unassigned events from {path:?}"))?;
+    let file: UnassignedEventsFile = serde_yaml::from_str(&text)
+        .with_context(|| format!("parse unassigned events yaml {path:?}"))?;
+    Ok(file.events)
+}
+
+/// Write the unassigned events to `path`, or remove a stale file when every
+/// event found a workstream.
+pub fn write_unassigned_events(path: &Path, reconciliation: &Reconciliation) -> Result<()> {
+    if reconciliation.unassigned.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(path)
+                .with_context(|| format!("remove stale unassigned events {path:?}"))?;
+        }
+        return Ok(());
+    }
+
+    let file = UnassignedEventsFile {
+        version: 1,
+        generated_at: Utc::now(),
+        events: reconciliation.unassigned.clone(),
+    };
+    let yaml = serde_yaml::to_string(&file)?;
+    std::fs::write(path, yaml).with_context(|| format!("write unassigned events to {path:?}"))
+}
+
+/// The single workstream index an event belongs to, or the tied candidates.
+fn match_event(
+    workstreams: &[Workstream],
+    repos: &[BTreeSet<String>],
+    event: &EventEnvelope,
+) -> std::result::Result<(usize, MatchReason), Vec<usize>> {
+    let all: Vec<usize> = (0..workstreams.len()).collect();
+    let labels: BTreeSet<String> = event_labels(event)
+        .into_iter()
+        .map(str::to_lowercase)
+        .collect();
+    let shares_tag = |idx: &usize| {
+        workstreams[*idx]
+            .tags
+            .iter()
+            .any(|tag| labels.contains(&tag.trim().to_lowercase()))
+    };
+
+    let by_rule: Vec<usize> = all
+        .iter()
+        .copied()
+        .filter(|idx| rules_match(&workstreams[*idx].rules, event, &labels))
+        .collect();
+    match by_rule.as_slice() {
+        [idx] => return Ok((*idx, MatchReason::Rule)),
+        [] => {}
+        _ => return Err(by_rule),
+    }
+
+    let repo = event.repo.full_name.to_lowercase();
+    let by_repo: Vec<usize> = all
+        .iter()
+        .copied()
+        .filter(|idx| repos[*idx].contains(&repo))
+        .collect();
+    match by_repo.as_slice() {
+        [idx] => return Ok((*idx, MatchReason::Repo)),
+        [] => {}
+        _ => {
+            let narrowed: Vec<usize> = by_repo.iter().copied().filter(shares_tag).collect();
+            return match narrowed.as_slice() {
+                [idx] => Ok((*idx, MatchReason::Tag)),
+                _ => Err(by_repo),
+            };
+        }
+    }
+
+    let by_tag: Vec<usize> = all.into_iter().filter(shares_tag).collect();
+    match by_tag.as_slice() {
+        [idx] => Ok((*idx, MatchReason::Tag)),
+        _ => Err(by_tag),
+    }
+}
+
+/// Whether any of a workstream's rules claims the event.
+///
+/// Repositories compare case-insensitively and `owner/*` matches a whole
+/// owner; tags compare case-insensitively against event tags and issue
+/// labels; titles match as case-insensitive substrings.
+fn rules_match(rules: &WorkstreamRules, event: &EventEnvelope, labels: &BTreeSet<String>) -> bool {
+    let repo = event.repo.full_name.to_lowercase();
+    let repo_matches = rules.repos.iter().any(|rule| {
+        let rule = rule.trim().to_lowercase();
+        match rule.strip_suffix("/*") {
+            Some(owner) => repo
+                .strip_prefix(owner)
+                .is_some_and(|rest| rest.starts_with('/')),
+            None => rule == repo,
+        }
+    });
+    let tag_matches = rules
+        .tags
+        .iter()
+        .any(|tag| labels.contains(&tag.trim().to_lowercase()));
+    let title = event_title(event).unwrap_or_default().to_lowercase();
+    let title_matches = rules.titles.iter().any(|needle| {
+        let needle = needle.trim().to_lowercase();
+        !needle.is_empty() && title.contains(&needle)
+    });
+    repo_matches || tag_matches || title_matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use shiplog::schema::event::*;
+    use shiplog::schema::workstream::WorkstreamStats;
+
+    fn pr(repo: &str, number: u64, title: &str, tags: &[&str]) -> EventEnvelope {
+        let at = Utc.with_ymd_and_hms(2025, 3, 3, 12, 0, 0).unwrap();
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["test", repo, &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: at,
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: repo.into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: title.into(),
+                state: PullRequestState::Merged,
+                created_at: at,
+                merged_at: Some(at),
+                additions: None,
+                deletions: None,
+                changed_files: None,
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    fn workstream(title: &str, events: &[&EventEnvelope], tags: &[&str]) -> Workstream {
+        Workstream {
+            id: WorkstreamId::from_parts(["test", title]),
+            title: title.into(),
+            summary: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            stats: WorkstreamStats::zero(),
+            events: events.iter().map(|event| event.id.clone()).collect(),
+            receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
+        }
+    }
+
+    fn file(workstreams: Vec<Workstream>) -> WorkstreamsFile {
+        WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams,
+        }
+    }
+
+    #[test]
+    fn explicit_rules_win_over_repository_history() {
+        let old = pr("acme/mono", 1, "Retry invoices", &[]);
+        let new = pr("acme/mono", 2, "Search: rank exact matches", &[]);
+        let mut search = workstream("Search", &[], &[]);
+        search.rules.titles = vec!["SEARCH".into()];
+        let mut ws = file(vec![workstream("Billing", &[&old], &[]), search]);
+
+        let result = reconcile_workstreams(&mut ws, &[old, new.clone()], &[]);
+
+        assert_eq!(result.assigned.len(), 1);
+        assert_eq!(result.assigned[0].matched_by, MatchReason::Rule);
+        assert_eq!(ws.workstreams[1].events, vec![new.id]);
+        assert_eq!(ws.workstreams[1].stats.pull_requests, 1);
+        assert!(ws.workstreams[1].receipts.is_empty());
+    }
+
+    #[test]
+    fn owner_wildcards_and_labels_match_rules() {
+        let rules = WorkstreamRules {
+            repos: vec!["Acme/*".into()],
+            ..Default::default()
+        };
+        let labels = BTreeSet::new();
+        assert!(rules_match(&rules, &pr("acme/web", 1, "x", &[]), &labels));
+        assert!(!rules_match(
+            &rules,
+            &pr("acmecorp/web", 2, "x", &[]),
+            &labels
+        ));
+
+        let rules = WorkstreamRules {
+            tags: vec!["Billing".into()],
+            ..Default::default()
+        };
+        let labels = BTreeSet::from(["billing".to_string()]);
+        assert!(rules_match(&rules, &pr("other/repo", 3, "x", &[]), &labels));
+    }
+
+    #[test]
+    fn shared_repositories_are_narrowed_by_tags_or_left_unassigned() {
+        let a = pr("acme/mono", 1, "Retry invoices", &[]);
+        let b = pr("acme/mono", 2, "Rank matches", &[]);
+        let tagged = pr("acme/mono", 3, "Tax totals", &["billing"]);
+        let untagged = pr("acme/mono", 4, "Bump deps", &[]);
+        let left_out = pr("acme/mono", 5, "Invoice typo", &["billing"]);
+        let mut ws = file(vec![
+            workstream("Billing", &[&a], &["billing"]),
+            workstream("Search", &[&b], &[]),
+        ]);
+
+        let result = reconcile_workstreams(
+            &mut ws,
+            &[a, b, tagged.clone(), untagged.clone(), left_out.clone()],
+            &[left_out],
+        );
+
+        assert_eq!(result.assigned.len(), 1);
+        assert_eq!(result.assigned[0].event_id, tagged.id);
+        assert_eq!(result.assigned[0].matched_by, MatchReason::Tag);
+        assert_eq!(result.unassigned.len(), 1);
+        assert_eq!(result.unassigned[0].id, untagged.id);
+        assert_eq!(result.unassigned[0].candidates, vec!["Billing", "Search"]);
+    }
+
+    #[test]
+    fn unassigned_file_round_trips_and_stale_file_is_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("workstreams.unassigned.yaml");
+        let event = pr("acme/web", 1, "Landing page", &[]);
+        let mut ws = file(vec![]);
+        let result = reconcile_workstreams(&mut ws, std::slice::from_ref(&event), &[]);
+
+        write_unassigned_events(&path, &result).unwrap();
+        let written = read_unassigned_events(&path).unwrap();
+        assert_eq!(written[0].title.as_deref(), Some("Landing page"));
+
+        write_unassigned_events(&path, &Reconciliation::default()).unwrap();
+        assert!(!path.exists());
+        assert!(read_unassigned_events(&path).unwrap().is_empty());
+    }
+}
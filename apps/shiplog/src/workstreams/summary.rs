@@ -0,0 +1,471 @@
+//! Draft summaries for suggested workstreams.
+//!
+//! [`SummarizingClusterer`] wraps another clusterer and fills empty
+//! `Workstream.summary` fields through a [`Summarizer`]. It only runs when
+//! suggestions are generated, so a curated `workstreams.yaml` is never
+//! rewritten. Summarizers see events redacted with the manager profile.
+
+use anyhow::{Context, Result};
+use shiplog::ports::{Redactor, Summarizer, WorkstreamClusterer};
+use shiplog::schema::event::{EventEnvelope, EventKind, EventPayload};
+use shiplog::schema::workstream::{Workstream, WorkstreamsFile};
+use std::collections::BTreeMap;
+
+/// Profile applied to events before they reach a summarizer.
+pub const SUMMARY_INPUT_PROFILE: &str = "manager";
+
+/// Titles quoted in a heuristic summary.
+const HEURISTIC_TITLE_LIMIT: usize = 3;
+
+/// Repositories named in a heuristic summary that spans several.
+const HEURISTIC_REPO_LIMIT: usize = 2;
+
+/// Deterministic summarizer that needs no model.
+///
+/// Counts the workstream's events by kind, names its busiest repositories
+/// when it spans several, states the date span and PR line totals, and
+/// quotes the first few titles.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ids::WorkstreamId;
+/// use shiplog::ports::Summarizer;
+/// use shiplog::schema::workstream::{Workstream, WorkstreamStats};
+/// use shiplog::workstreams::HeuristicSummarizer;
+///
+/// let ws = Workstream {
+///     id: WorkstreamId::from_parts(["repo", "acme/widgets"]),
+///     title: "acme/widgets".into(),
+///     summary: None,
+///     tags: vec![],
+///     stats: WorkstreamStats::zero(),
+///     events: vec![],
+///     receipts: vec![],
+///     receipt_weights: Default::default(),
+///     rules: Default::default(),
+/// };
+/// // No events, nothing to say.
+/// assert_eq!(HeuristicSummarizer.summarize(&ws, &[]).unwrap(), "");
+/// ```
+pub struct HeuristicSummarizer;
+
+impl Summarizer for HeuristicSummarizer {
+    fn summarize(&self, workstream: &Workstream, events: &[&EventEnvelope]) -> Result<String> {
+        if events.is_empty() {
+            return Ok(String::new());
+        }
+
+        let mut counts: BTreeMap<KindOrder, usize> = BTreeMap::new();
+        for event in events {
+            *counts.entry(KindOrder::of(&event.kind)).or_default() += 1;
+        }
+        let parts: Vec<String> = counts
+            .iter()
+            .map(|(kind, count)| kind.count_phrase(*count))
+            .collect();
+
+        let first = events.iter().map(|ev| ev.occurred_at).min();
+        let last = events.iter().map(|ev| ev.occurred_at).max();
+        let span = match (first, last) {
+            (Some(first), Some(last)) if first.date_naive() == last.date_naive() => {
+                format!(" on {}", first.format("%Y-%m-%d"))
+            }
+            (Some(first), Some(last)) => format!(
+                " between {} and {}",
+                first.format("%Y-%m-%d"),
+                last.format("%Y-%m-%d")
+            ),
+            _ => String::new(),
+        };
+
+        let repos = top_repos(events)
+            .map(|repos| format!(" across {repos}"))
+            .unwrap_or_default();
+        let lines = lines_changed(events)
+            .map(|(additions, deletions)| format!(" (+{additions}/-{deletions} lines)"))
+            .unwrap_or_default();
+
+        let mut summary = format!(
+            "{} in {}{repos}{span}{lines}",
+            join_phrases(&parts),
+            workstream.title
+        );
+        let titles: Vec<String> = events
+            .iter()
+            .copied()
+            .filter_map(event_title)
+            .take(HEURISTIC_TITLE_LIMIT)
+            .map(|title| format!("\"{title}\""))
+            .collect();
+        if !titles.is_empty() {
+            summary.push_str(", including ");
+            summary.push_str(&join_phrases(&titles));
+        }
+        summary.push('.');
+        Ok(summary)
+    }
+}
+
+/// Clusterer wrapper that prefills empty workstream summaries.
+///
+/// Summaries already set by the inner clusterer are kept. Each workstream's
+/// events are redacted with [`SUMMARY_INPUT_PROFILE`] before the summarizer
+/// sees them.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ports::WorkstreamClusterer;
+/// use shiplog::redact::DeterministicRedactor;
+/// use shiplog::workstreams::{HeuristicSummarizer, RepoClusterer, SummarizingClusterer};
+///
+/// let clusterer = SummarizingClusterer::new(
+///     Box::new(RepoClusterer),
+///     Box::new(HeuristicSummarizer),
+///     Box::new(DeterministicRedactor::new(b"redact-key")),
+/// );
+/// assert!(clusterer.cluster(&[]).unwrap().workstreams.is_empty());
+/// ```
+pub struct SummarizingClusterer {
+    inner: Box<dyn WorkstreamClusterer>,
+    summarizer: Box<dyn Summarizer>,
+    redactor: Box<dyn Redactor>,
+}
+
+impl SummarizingClusterer {
+    pub fn new(
+        inner: Box<dyn WorkstreamClusterer>,
+        summarizer: Box<dyn Summarizer>,
+        redactor: Box<dyn Redactor>,
+    ) -> Self {
+        Self {
+            inner,
+            summarizer,
+            redactor,
+        }
+    }
+}
+
+impl WorkstreamClusterer for SummarizingClusterer {
+    fn cluster(&self, events: &[EventEnvelope]) -> Result<WorkstreamsFile> {
+        let mut file = self.inner.cluster(events)?;
+        if file.workstreams.iter().all(|ws| ws.summary.is_some()) {
+            return Ok(file);
+        }
+
+        let redacted = self
+            .redactor
+            .redact_events(events, SUMMARY_INPUT_PROFILE)
+            .context("redact events for workstream summaries")?;
+        let by_id: BTreeMap<&str, &EventEnvelope> =
+            redacted.iter().map(|ev| (ev.id.0.as_str(), ev)).collect();
+        let redacted_workstreams = self
+            .redactor
+            .redact_workstreams(&file, SUMMARY_INPUT_PROFILE)
+            .context("redact workstreams for summaries")?;
+
+        for (ws, redacted_ws) in file
+            .workstreams
+            .iter_mut()
+            .zip(&redacted_workstreams.workstreams)
+        {
+            if ws.summary.is_some() {
+                continue;
+            }
+            let ws_events: Vec<&EventEnvelope> = ws
+                .events
+                .iter()
+                .filter_map(|id| by_id.get(id.0.as_str()).copied())
+                .collect();
+            let summary = self
+                .summarizer
+                .summarize(redacted_ws, &ws_events)
+                .with_context(|| format!("summarize workstream {}", ws.title))?;
+            let summary = summary.trim();
+            if !summary.is_empty() {
+                ws.summary = Some(summary.to_string());
+            }
+        }
+        Ok(file)
+    }
+}
+
+/// Event kinds in the order a summary lists them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum KindOrder {
+    PullRequest,
+    Review,
+    Issue,
+    Deployment,
+    Release,
+    Incident,
+    Document,
+    Comment,
+    Manual,
+}
+
+impl KindOrder {
+    fn of(kind: &EventKind) -> Self {
+        match kind {
+            EventKind::PullRequest => Self::PullRequest,
+            EventKind::Review => Self::Review,
+            EventKind::Issue => Self::Issue,
+            EventKind::Deployment => Self::Deployment,
+            EventKind::Release => Self::Release,
+            EventKind::Incident => Self::Incident,
+            EventKind::Document => Self::Document,
+            EventKind::Comment => Self::Comment,
+            EventKind::Manual => Self::Manual,
+        }
+    }
+
+    fn count_phrase(self, count: usize) -> String {
+        let (singular, plural) = match self {
+            Self::PullRequest => ("pull request", "pull requests"),
+            Self::Review => ("review", "reviews"),
+            Self::Issue => ("issue", "issues"),
+            Self::Deployment => ("deployment", "deployments"),
+            Self::Release => ("release", "releases"),
+            Self::Incident => ("incident", "incidents"),
+            Self::Document => ("document", "documents"),
+            Self::Comment => ("comment thread", "comment threads"),
+            Self::Manual => ("manual event", "manual events"),
+        };
+        let noun = if count == 1 { singular } else { plural };
+        format!("{count} {noun}")
+    }
+}
+
+pub(super) fn event_title(event: &EventEnvelope) -> Option<&str> {
+    let title = match &event.payload {
+        EventPayload::PullRequest(pr) => pr.title.as_str(),
+        EventPayload::Review(review) => review.pull_title.as_str(),
+        EventPayload::Manual(manual) => manual.title.as_str(),
+        EventPayload::Issue(issue) => issue.title.as_str(),
+        EventPayload::Incident(incident) => incident.title.as_str(),
+        EventPayload::Document(document) => document.title.as_str(),
+        EventPayload::Comment(comment) => comment.target_title.as_str(),
+        EventPayload::Release(release) => release.name.as_deref().unwrap_or(&release.version),
+        EventPayload::Deployment(_) => return None,
+    };
+    let title = title.trim();
+    (!title.is_empty()).then_some(title)
+}
+
+/// Busiest repositories by event count, or `None` when there is only one.
+fn top_repos(events: &[&EventEnvelope]) -> Option<String> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for event in events {
+        *counts.entry(event.repo.full_name.as_str()).or_default() += 1;
+    }
+    if counts.len() < 2 {
+        return None;
+    }
+    let mut ranked: Vec<(&str, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    let mut names: Vec<String> = ranked
+        .iter()
+        .take(HEURISTIC_REPO_LIMIT)
+        .map(|(name, _)| (*name).to_string())
+        .collect();
+    let rest = ranked.len().saturating_sub(HEURISTIC_REPO_LIMIT);
+    if rest > 0 {
+        let noun = if rest == 1 { "repo" } else { "repos" };
+        names.push(format!("{rest} more {noun}"));
+    }
+    Some(join_phrases(&names))
+}
+
+/// Total PR additions and deletions, or `None` when no PR reported either.
+fn lines_changed(events: &[&EventEnvelope]) -> Option<(u64, u64)> {
+    let mut totals = None;
+    for event in events {
+        let EventPayload::PullRequest(pr) = &event.payload else {
+            continue;
+        };
+        if pr.additions.is_none() && pr.deletions.is_none() {
+            continue;
+        }
+        let (additions, deletions) = totals.get_or_insert((0, 0));
+        *additions += pr.additions.unwrap_or(0);
+        *deletions += pr.deletions.unwrap_or(0);
+    }
+    totals
+}
+
+fn join_phrases(parts: &[String]) -> String {
+    match parts {
+        [] => String::new(),
+        [only] => only.clone(),
+        [first, second] => format!("{first} and {second}"),
+        [rest @ .., last] => format!("{}, and {last}", rest.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redact::DeterministicRedactor;
+    use crate::workstreams::RepoClusterer;
+    use chrono::{TimeZone, Utc};
+    use shiplog::ids::EventId;
+    use shiplog::schema::event::*;
+
+    fn pr(repo: &str, number: u64, title: &str, day: u32) -> EventEnvelope {
+        let at = Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap();
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["test", repo, &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: at,
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: repo.into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: title.into(),
+                state: PullRequestState::Merged,
+                created_at: at,
+                merged_at: Some(at),
+                additions: None,
+                deletions: None,
+                changed_files: None,
+                touched_paths_hint: vec!["src/secret/path.rs".into()],
+                window: None,
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    struct RecordingSummarizer;
+
+    impl Summarizer for RecordingSummarizer {
+        fn summarize(&self, _ws: &Workstream, events: &[&EventEnvelope]) -> Result<String> {
+            let leaked = events.iter().any(|ev| match &ev.payload {
+                EventPayload::PullRequest(pr) => !pr.touched_paths_hint.is_empty(),
+                _ => false,
+            });
+            Ok(format!("{} events, paths leaked: {leaked}", events.len()))
+        }
+    }
+
+    fn summarizing(summarizer: Box<dyn Summarizer>) -> SummarizingClusterer {
+        SummarizingClusterer::new(
+            Box::new(RepoClusterer),
+            summarizer,
+            Box::new(DeterministicRedactor::new(b"test-key")),
+        )
+    }
+
+    #[test]
+    fn heuristic_summary_counts_kinds_and_quotes_titles() {
+        let events = vec![
+            pr("acme/auth", 1, "Add OAuth", 3),
+            pr("acme/auth", 2, "Fix token refresh", 17),
+        ];
+        let ws = summarizing(Box::new(HeuristicSummarizer))
+            .cluster(&events)
+            .unwrap();
+        assert_eq!(
+            ws.workstreams[0].summary.as_deref(),
+            Some(
+                "2 pull requests in acme/auth between 2025-03-03 and 2025-03-17, \
+                 including \"Add OAuth\" and \"Fix token refresh\"."
+            )
+        );
+    }
+
+    #[test]
+    fn heuristic_summary_names_top_repos_and_line_totals() {
+        let mut events = [
+            pr("acme/api", 1, "Add rate limits", 3),
+            pr("acme/api", 2, "Tune rate limits", 4),
+            pr("acme/web", 3, "Show quota", 5),
+            pr("acme/cli", 4, "Print quota", 5),
+        ];
+        for (event, lines) in events.iter_mut().zip([(120, 30), (8, 2), (40, 0)]) {
+            if let EventPayload::PullRequest(pr) = &mut event.payload {
+                pr.additions = Some(lines.0);
+                pr.deletions = Some(lines.1);
+            }
+        }
+        let ws = Workstream {
+            title: "Quotas".into(),
+            ..RepoClusterer.cluster(&events[..1]).unwrap().workstreams[0].clone()
+        };
+        assert_eq!(
+            HeuristicSummarizer
+                .summarize(&ws, &events.iter().collect::<Vec<_>>())
+                .unwrap(),
+            "4 pull requests in Quotas across acme/api, acme/cli, and 1 more repo \
+             between 2025-03-03 and 2025-03-05 (+168/-32 lines), including \
+             \"Add rate limits\", \"Tune rate limits\", and \"Show quota\"."
+        );
+    }
+
+    #[test]
+    fn summarizer_sees_only_redacted_events_of_its_workstream() {
+        let events = vec![
+            pr("acme/auth", 1, "Add OAuth", 3),
+            pr("acme/auth", 2, "Fix token refresh", 17),
+            pr("acme/billing", 3, "Invoice export", 5),
+        ];
+        let ws = summarizing(Box::new(RecordingSummarizer))
+            .cluster(&events)
+            .unwrap();
+        let summaries: Vec<_> = ws
+            .workstreams
+            .iter()
+            .map(|ws| ws.summary.as_deref())
+            .collect();
+        assert_eq!(
+            summaries,
+            vec![
+                Some("2 events, paths leaked: false"),
+                Some("1 events, paths leaked: false"),
+            ]
+        );
+    }
+
+    #[test]
+    fn existing_summaries_are_kept() {
+        struct Preset;
+        impl WorkstreamClusterer for Preset {
+            fn cluster(&self, events: &[EventEnvelope]) -> Result<WorkstreamsFile> {
+                let mut file = RepoClusterer.cluster(events)?;
+                for ws in &mut file.workstreams {
+                    ws.summary = Some("Written by the clusterer".into());
+                }
+                Ok(file)
+            }
+        }
+        let clusterer = SummarizingClusterer::new(
+            Box::new(Preset),
+            Box::new(RecordingSummarizer),
+            Box::new(DeterministicRedactor::new(b"test-key")),
+        );
+        let ws = clusterer
+            .cluster(&[pr("acme/auth", 1, "Add OAuth", 3)])
+            .unwrap();
+        assert_eq!(
+            ws.workstreams[0].summary.as_deref(),
+            Some("Written by the clusterer")
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! Title-based workstream clustering.
+//!
+//! [`TitleSimilarityClusterer`] splits each repository's events by what their
+//! titles say they touch: the conventional-commit scope when there is one
+//! (`feat(billing): ...`), otherwise the significant title word shared with
+//! the most other events in the repository. Events whose titles share nothing
+//! stay in a per-repository workstream.
+
+use anyhow::Result;
+use chrono::Utc;
+use shiplog::ids::WorkstreamId;
+use shiplog::ports::WorkstreamClusterer;
+use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::workstream::WorkstreamsFile;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::cluster::build_workstream;
+use super::summary::event_title;
+
+/// Title words shorter than this never form a topic.
+const MIN_TOPIC_WORD_LEN: usize = 3;
+
+/// Words too common in titles to say what the work was about.
+const STOP_WORDS: &[&str] = &[
+    "add", "added", "adds", "and", "allow", "are", "bump", "but", "can", "change", "changes",
+    "chore", "clean", "cleanup", "code", "docs", "don", "feat", "fix", "fixed", "fixes", "for",
+    "from", "handle", "improve", "into", "make", "merge", "more", "move", "new", "not", "now",
+    "only", "out", "refactor", "release", "remove", "rename", "revert", "support", "test", "tests",
+    "the", "this", "update", "updated", "updates", "use", "when", "wip", "with",
+];
+
+/// Cluster each repository's events by title topic.
+///
+/// A topic needs at least two events; one-off topics stay with the rest of
+/// the repository so the packet is not split into single-receipt workstreams.
+///
+/// # Examples
+///
+/// ```
+/// use shiplog::ports::WorkstreamClusterer;
+/// use shiplog::workstreams::TitleSimilarityClusterer;
+/// use shiplog_testkit::pr_event;
+///
+/// let events = [
+///     pr_event("acme/mono", 1, "feat(billing): retry failed invoices"),
+///     pr_event("acme/mono", 2, "Fix billing export totals"),
+///     pr_event("acme/mono", 3, "Rank exact search matches first"),
+/// ];
+/// let ws = TitleSimilarityClusterer.cluster(&events).unwrap();
+/// let titles: Vec<_> = ws.workstreams.iter().map(|ws| ws.title.as_str()).collect();
+/// assert_eq!(titles, ["acme/mono: billing", "acme/mono"]);
+/// ```
+pub struct TitleSimilarityClusterer;
+
+impl WorkstreamClusterer for TitleSimilarityClusterer {
+    fn cluster(&self, events: &[EventEnvelope]) -> Result<WorkstreamsFile> {
+        let mut by_repo: BTreeMap<&str, Vec<&EventEnvelope>> = BTreeMap::new();
+        for event in events {
+            by_repo
+                .entry(event.repo.full_name.as_str())
+                .or_default()
+                .push(event);
+        }
+
+        let mut workstreams = Vec::new();
+        for (repo, events) in by_repo {
+            let (topics, rest) = split_by_topic(&events);
+            for (topic, events) in topics {
+                workstreams.push(build_workstream(
+                    WorkstreamId::from_parts(["title", repo, &topic]),
+                    format!("{repo}: {topic}"),
+                    vec!["topic".to_string()],
+                    &events,
+                ));
+            }
+            if !rest.is_empty() {
+                workstreams.push(build_workstream(
+                    WorkstreamId::from_parts(["repo", repo]),
+                    repo.to_string(),
+                    vec!["repo".to_string()],
+                    &rest,
+                ));
+            }
+        }
+
+        Ok(WorkstreamsFile {
+            version: 1,
+            generated_at: Utc::now(),
+            workstreams,
+        })
+    }
+}
+
+type TopicGroups<'a> = (
+    BTreeMap<String, Vec<&'a EventEnvelope>>,
+    Vec<&'a EventEnvelope>,
+);
+
+/// Group one repository's events by topic, returning the topics and the
+/// events that have none.
+fn split_by_topic<'a>(events: &[&'a EventEnvelope]) -> TopicGroups<'a> {
+    let candidates: Vec<BTreeSet<String>> = events
+        .iter()
+        .map(|event| event_title(event).map(topic_words).unwrap_or_default())
+        .collect();
+    let mut frequency: BTreeMap<&str, usize> = BTreeMap::new();
+    for word in candidates.iter().flatten() {
+        *frequency.entry(word).or_default() += 1;
+    }
+
+    let mut topics: BTreeMap<String, Vec<&EventEnvelope>> = BTreeMap::new();
+    let mut rest = Vec::new();
+    for (event, words) in events.iter().zip(&candidates) {
+        let scope = event_title(event).and_then(conventional_scope);
+        let topic = scope
+            .filter(|scope| frequency.get(scope.as_str()).copied().unwrap_or(0) > 1)
+            .or_else(|| {
+                words
+                    .iter()
+                    .filter(|word| frequency[word.as_str()] > 1)
+                    .reduce(|best, word| {
+                        if frequency[word.as_str()] > frequency[best.as_str()] {
+                            word
+                        } else {
+                            best
+                        }
+                    })
+                    .cloned()
+            });
+        match topic {
+            Some(topic) => topics.entry(topic).or_default().push(event),
+            None => rest.push(*event),
+        }
+    }
+    (topics, rest)
+}
+
+/// Scope of a conventional-commit title such as `fix(api)!: ...`.
+fn conventional_scope(title: &str) -> Option<String> {
+    let (kind, rest) = title.trim().split_once('(')?;
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let (scope, rest) = rest.split_once(')')?;
+    let rest = rest.strip_prefix('!').unwrap_or(rest);
+    if !rest.starts_with(':') {
+        return None;
+    }
+    let scope = scope.trim().to_lowercase();
+    (!scope.is_empty()).then_some(scope)
+}
+
+/// Lowercased significant words of a title, plus its conventional-commit
+/// scope, which lets `feat(billing): ...` and `Fix billing export` meet.
+fn topic_words(title: &str) -> BTreeSet<String> {
+    let scope = conventional_scope(title);
+    let text = match (&scope, title.split_once(':')) {
+        (Some(_), Some((_, description))) => description,
+        _ => title,
+    };
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| {
+            word.chars().count() >= MIN_TOPIC_WORD_LEN
+                && !word.chars().all(|c| c.is_ascii_digit())
+                && !STOP_WORDS.contains(&word.as_str())
+        })
+        .chain(scope)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use shiplog::ids::EventId;
+    use shiplog::schema::event::*;
+
+    fn pr(repo: &str, number: u64, title: &str) -> EventEnvelope {
+        let at = Utc.with_ymd_and_hms(2025, 3, 3, 12, 0, 0).unwrap();
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["test", repo, &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: at,
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: repo.into(),
+                html_url: None,
+                visibility: RepoVisibility::Private,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: title.into(),
+                state: PullRequestState::Merged,
+                created_at: at,
+                merged_at: Some(at),
+                additions: None,
+                deletions: None,
+                changed_files: None,
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    fn titles(file: &WorkstreamsFile) -> Vec<(&str, usize)> {
+        file.workstreams
+            .iter()
+            .map(|ws| (ws.title.as_str(), ws.events.len()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_conventional_commit_scopes() {
+        assert_eq!(
+            conventional_scope("feat(Billing): retry"),
+            Some("billing".into())
+        );
+        assert_eq!(conventional_scope("fix(api)!: drop v1"), Some("api".into()));
+        assert_eq!(conventional_scope("Fix (api) docs"), None);
+        assert_eq!(conventional_scope("feat(): empty"), None);
+        assert_eq!(conventional_scope("chore(deps) bump"), None);
+    }
+
+    #[test]
+    fn topic_words_skip_stop_words_numbers_and_short_words() {
+        let words = topic_words("fix(ui): Add v2 dark mode to settings #1234");
+        assert_eq!(
+            words.into_iter().collect::<Vec<_>>(),
+            vec!["dark", "mode", "settings", "ui"]
+        );
+    }
+
+    #[test]
+    fn splits_a_monorepo_by_shared_title_topics() {
+        let events = vec![
+            pr("acme/mono", 1, "feat(billing): retry failed invoices"),
+            pr("acme/mono", 2, "fix(billing): round tax totals"),
+            pr("acme/mono", 3, "Speed up search indexing"),
+            pr("acme/mono", 4, "Search synonyms for product names"),
+            pr("acme/mono", 5, "Update README badges"),
+            pr("acme/web", 6, "Search page layout"),
+        ];
+        let file = TitleSimilarityClusterer.cluster(&events).unwrap();
+        assert_eq!(
+            titles(&file),
+            vec![
+                ("acme/mono: billing", 2),
+                ("acme/mono: search", 2),
+                ("acme/mono", 1),
+                ("acme/web", 1),
+            ]
+        );
+        assert_eq!(file.workstreams[0].tags, vec!["topic"]);
+        assert_ne!(file.workstreams[0].id, file.workstreams[1].id);
+    }
+
+    #[test]
+    fn one_off_scopes_stay_with_the_repo() {
+        let events = vec![
+            pr("acme/mono", 1, "feat(billing): retry failed invoices"),
+            pr("acme/mono", 2, "chore(ci): cache cargo"),
+        ];
+        let file = TitleSimilarityClusterer.cluster(&events).unwrap();
+        assert_eq!(titles(&file), vec![("acme/mono", 2)]);
+    }
+}
@@ -0,0 +1,167 @@
+//! Receipt weights: how strongly each receipt backs its workstream's claims.
+//!
+//! A weight is derived from the event's annotated importance when it has one,
+//! otherwise from its kind and, for pull requests, its size bucket. A curated `workstreams.yaml` overrides any receipt through the
+//! workstream's `receipt_weights` map. Renderers list heavier receipts first,
+//! and the intake report downgrades claims backed only by light receipts.
+
+use crate::stats::{PrSize, pr_size};
+use shiplog::annotate::event_importance;
+use shiplog::schema::event::{EventEnvelope, EventPayload};
+use shiplog::schema::workstream::{ReceiptWeight, Workstream};
+
+/// Weight for an event when no curated override is present.
+///
+/// - Annotated importance wins: 4-5 is high, 3 medium, 1-2 low.
+/// - Pull requests: `L`/`XL` are high, `M` or unknown size medium, `XS`/`S` low.
+/// - Releases, incidents, and manual events with impact metrics are high.
+/// - Reviews and comment threads are low.
+/// - Everything else is medium.
+#[must_use]
+pub fn derived_receipt_weight(event: &EventEnvelope) -> ReceiptWeight {
+    if let Some(importance) = event_importance(event) {
+        return match importance {
+            4.. => ReceiptWeight::High,
+            3 => ReceiptWeight::Medium,
+            _ => ReceiptWeight::Low,
+        };
+    }
+    match &event.payload {
+        EventPayload::PullRequest(pr) => match pr_size(pr) {
+            Some(PrSize::L | PrSize::XL) => ReceiptWeight::High,
+            Some(PrSize::XS | PrSize::S) => ReceiptWeight::Low,
+            Some(PrSize::M) | None => ReceiptWeight::Medium,
+        },
+        EventPayload::Release(_) | EventPayload::Incident(_) => ReceiptWeight::High,
+        EventPayload::Manual(manual) if !manual.metrics.is_empty() => ReceiptWeight::High,
+        EventPayload::Review(_) | EventPayload::Comment(_) => ReceiptWeight::Low,
+        EventPayload::Manual(_)
+        | EventPayload::Issue(_)
+        | EventPayload::Deployment(_)
+        | EventPayload::Document(_) => ReceiptWeight::Medium,
+    }
+}
+
+/// Weight of `event` as a receipt of `workstream`: the curated override when
+/// one is set, otherwise [`derived_receipt_weight`].
+#[must_use]
+pub fn receipt_weight(workstream: &Workstream, event: &EventEnvelope) -> ReceiptWeight {
+    workstream
+        .receipt_weights
+        .get(&event.id)
+        .copied()
+        .unwrap_or_else(|| derived_receipt_weight(event))
+}
+
+/// Sort receipts heaviest first. Receipts of equal weight keep their order.
+pub fn order_receipts_by_weight(workstream: &Workstream, receipts: &mut [&EventEnvelope]) {
+    receipts.sort_by_key(|event| std::cmp::Reverse(receipt_weight(workstream, event)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shiplog::annotate::IMPORTANCE_EXTENSION;
+    use shiplog::ids::{EventId, WorkstreamId};
+    use shiplog::schema::event::*;
+    use shiplog::schema::workstream::WorkstreamStats;
+
+    fn sized(number: u64, lines: u64) -> EventEnvelope {
+        EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
+            id: EventId::from_parts(["test", "pr", &number.to_string()]),
+            kind: EventKind::PullRequest,
+            occurred_at: Utc::now(),
+            actor: Actor {
+                login: "user".into(),
+                id: None,
+            },
+            repo: RepoRef {
+                full_name: "acme/widgets".into(),
+                html_url: None,
+                visibility: RepoVisibility::Unknown,
+            },
+            payload: EventPayload::PullRequest(PullRequestEvent {
+                number,
+                title: format!("PR {number}"),
+                state: PullRequestState::Merged,
+                created_at: Utc::now(),
+                merged_at: Some(Utc::now()),
+                additions: Some(lines),
+                deletions: Some(0),
+                changed_files: None,
+                touched_paths_hint: vec![],
+                window: None,
+            }),
+            tags: vec![],
+            links: vec![],
+            source: SourceRef {
+                system: SourceSystem::Github,
+                url: None,
+                opaque_id: None,
+            },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
+        }
+    }
+
+    fn workstream(receipts: &[&EventEnvelope]) -> Workstream {
+        Workstream {
+            id: WorkstreamId::from_parts(["ws", "weights"]),
+            title: "weights".into(),
+            summary: None,
+            tags: vec![],
+            stats: WorkstreamStats::zero(),
+            events: receipts.iter().map(|event| event.id.clone()).collect(),
+            receipts: receipts.iter().map(|event| event.id.clone()).collect(),
+            receipt_weights: Default::default(),
+            rules: Default::default(),
+        }
+    }
+
+    #[test]
+    fn pull_request_weight_follows_size() {
+        assert_eq!(derived_receipt_weight(&sized(1, 5)), ReceiptWeight::Low);
+        assert_eq!(
+            derived_receipt_weight(&sized(2, 120)),
+            ReceiptWeight::Medium
+        );
+        assert_eq!(derived_receipt_weight(&sized(3, 1500)), ReceiptWeight::High);
+    }
+
+    #[test]
+    fn annotated_importance_overrides_size() {
+        let mut small = sized(1, 5);
+        small
+            .extensions
+            .insert(IMPORTANCE_EXTENSION.to_string(), 5.into());
+        assert_eq!(derived_receipt_weight(&small), ReceiptWeight::High);
+
+        let mut large = sized(2, 1500);
+        large
+            .extensions
+            .insert(IMPORTANCE_EXTENSION.to_string(), 2.into());
+        assert_eq!(derived_receipt_weight(&large), ReceiptWeight::Low);
+    }
+
+    #[test]
+    fn curated_override_wins_and_ordering_is_stable() {
+        let small = sized(1, 5);
+        let medium_a = sized(2, 120);
+        let medium_b = sized(3, 80);
+        let large = sized(4, 1500);
+        let mut ws = workstream(&[&small, &medium_a, &medium_b, &large]);
+        ws.receipt_weights
+            .insert(small.id.clone(), ReceiptWeight::High);
+
+        let mut receipts = vec![&small, &medium_a, &medium_b, &large];
+        order_receipts_by_weight(&ws, &mut receipts);
+        let order: Vec<&EventId> = receipts.iter().map(|event| &event.id).collect();
+        assert_eq!(
+            order,
+            vec![&small.id, &large.id, &medium_a.id, &medium_b.id]
+        );
+    }
+}
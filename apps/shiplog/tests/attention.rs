@@ -0,0 +1,154 @@
+//! Integration tests for the needs-attention analyzer.
+
+use chrono::{NaiveDate, Utc};
+use shiplog::attention::{AttentionKind, AttentionPriority, analyze_run};
+use shiplog::ids::WorkstreamId;
+use shiplog::render::md::render_needs_attention;
+use shiplog::schema::coverage::{Completeness, CoverageSlice, TimeWindow};
+use shiplog::schema::event::{EventEnvelope, Link};
+use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
+use shiplog_testkit::fixtures::test_coverage;
+use shiplog_testkit::pr_event;
+
+fn workstream(title: &str, receipts: &[&EventEnvelope], summary: Option<&str>) -> Workstream {
+    Workstream {
+        id: WorkstreamId::from_parts(["ws", title]),
+        title: title.to_string(),
+        summary: summary.map(str::to_string),
+        tags: vec![],
+        stats: WorkstreamStats::zero(),
+        events: receipts.iter().map(|event| event.id.clone()).collect(),
+        receipts: receipts.iter().map(|event| event.id.clone()).collect(),
+        receipt_weights: Default::default(),
+        rules: Default::default(),
+    }
+}
+
+fn file(workstreams: Vec<Workstream>) -> WorkstreamsFile {
+    WorkstreamsFile {
+        version: 1,
+        generated_at: Utc::now(),
+        workstreams,
+    }
+}
+
+fn linked(number: u64) -> EventEnvelope {
+    let mut event = pr_event("acme/app", number, "Ship change");
+    event.links = vec![Link {
+        label: "pr".into(),
+        url: format!("https://github.com/acme/app/pull/{number}"),
+    }];
+    event
+}
+
+fn unlinked(number: u64) -> EventEnvelope {
+    let mut event = pr_event("acme/app", number, "Ship change");
+    event.links.clear();
+    event.source.url = None;
+    event
+}
+
+#[test]
+fn clean_run_needs_no_attention() {
+    let event = linked(1);
+    let workstreams = file(vec![workstream("Billing", &[&event], Some("Shipped"))]);
+    let coverage = test_coverage("octo", Completeness::Complete);
+
+    let items = analyze_run(std::slice::from_ref(&event), &workstreams, &coverage);
+
+    assert!(items.is_empty(), "{items:?}");
+    assert_eq!(render_needs_attention(&items), "");
+}
+
+#[test]
+fn items_are_ordered_high_to_low() {
+    let a = unlinked(1);
+    let b = unlinked(2);
+    let workstreams = file(vec![
+        workstream("Billing", &[&a, &b], None),
+        workstream("Search", &[], Some("Shipped")),
+    ]);
+    let coverage = test_coverage("octo", Completeness::Complete);
+
+    let items = analyze_run(&[a.clone(), b.clone()], &workstreams, &coverage);
+
+    let summary: Vec<(AttentionPriority, AttentionKind, &str)> = items
+        .iter()
+        .map(|item| (item.priority, item.kind, item.subject.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (AttentionPriority::High, AttentionKind::NoReceipts, "Search"),
+            (
+                AttentionPriority::Medium,
+                AttentionKind::UnfilledClaim,
+                "Billing"
+            ),
+            (
+                AttentionPriority::Low,
+                AttentionKind::MissingLinks,
+                "Billing"
+            ),
+        ]
+    );
+    assert!(
+        items[2]
+            .message
+            .starts_with("2 receipts in \"Billing\" have no link")
+    );
+}
+
+#[test]
+fn partial_slices_are_listed_by_window() {
+    let event = linked(1);
+    let workstreams = file(vec![workstream("Billing", &[&event], Some("Shipped"))]);
+    let mut coverage = test_coverage("octo", Completeness::Partial);
+    coverage.slices = vec![CoverageSlice {
+        window: TimeWindow {
+            since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
+        },
+        query: "author:octo is:merged".into(),
+        total_count: 1200,
+        fetched: 1000,
+        incomplete_results: Some(false),
+        notes: vec![],
+        source: None,
+    }];
+
+    let items = analyze_run(&[event], &workstreams, &coverage);
+
+    assert_eq!(items.len(), 1, "{items:?}");
+    assert_eq!(items[0].kind, AttentionKind::PartialCoverage);
+    assert_eq!(items[0].subject, "2025-01-01..2025-02-01");
+    assert!(items[0].message.contains("fetched 1000 of 1200"));
+}
+
+#[test]
+fn partial_coverage_without_slices_names_the_run_window() {
+    let event = linked(1);
+    let workstreams = file(vec![workstream("Billing", &[&event], Some("Shipped"))]);
+    let coverage = test_coverage("octo", Completeness::Partial);
+
+    let items = analyze_run(&[event], &workstreams, &coverage);
+
+    assert_eq!(items.len(), 1, "{items:?}");
+    assert!(items[0].message.contains("coverage.report.md"));
+}
+
+#[test]
+fn packet_block_caps_the_list() {
+    let workstreams = file(
+        (0..12)
+            .map(|n| workstream(&format!("Stream {n}"), &[], Some("Shipped")))
+            .collect(),
+    );
+    let coverage = test_coverage("octo", Completeness::Complete);
+
+    let items = analyze_run(&[], &workstreams, &coverage);
+    let block = render_needs_attention(&items);
+
+    assert_eq!(block.matches("- **High**").count(), 10);
+    assert!(block.contains("+ 2 more in `needs_attention.json`"));
+}
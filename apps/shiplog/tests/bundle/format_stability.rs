@@ -42,6 +42,9 @@ fn bundle_manifest_full_json() {
                 bytes: 4096,
             },
         ],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -64,6 +67,9 @@ fn bundle_manifest_full_yaml() {
                 bytes: 256,
             },
         ],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_yaml_snapshot!(manifest);
 }
@@ -79,6 +85,9 @@ fn bundle_manifest_empty_files() {
         generated_at: ts(),
         profile: BundleProfile::Internal,
         files: vec![],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -94,6 +103,9 @@ fn bundle_manifest_public_profile() {
             sha256: "aabbcc".into(),
             bytes: 100,
         }],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -120,6 +132,9 @@ fn bundle_manifest_edge_cases() {
                 bytes: u64::MAX,
             },
         ],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -5,14 +5,17 @@ use assert_cmd::Command;
 use chrono::{Duration, NaiveDate, TimeZone, Utc};
 use predicates::prelude::*;
 use shiplog::cache::ApiCache;
-use shiplog::ids::{EventId, RunId, WorkstreamId};
+use shiplog::ids::{EventId, OpaqueId, RunId, WorkstreamId};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, Link, ManualDate, ManualEvent, ManualEventType,
     ManualEventsFile, PullRequestEvent, PullRequestState, RepoRef, RepoVisibility, ReviewEvent,
     SourceRef, SourceSystem,
 };
-use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
+use shiplog::schema::version::SchemaVersion;
+use shiplog::schema::workstream::{
+    ReceiptWeight, Workstream, WorkstreamRules, WorkstreamStats, WorkstreamsFile,
+};
 use std::collections::BTreeMap;
 use std::io::{ErrorKind, Read, Write};
 use std::net::{SocketAddr, TcpListener, TcpStream};
@@ -72,6 +75,26 @@ fn seed_github_cache(cache_dir: &Path) -> PathBuf {
 }
 
 /// Run `collect json` into `tmp` and return the run directory path.
+fn collect_json_events_into(out: &Path, events: &Path) -> PathBuf {
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "json",
+            "--events",
+            events.to_str().unwrap(),
+            "--coverage",
+            fixture_dir()
+                .join("coverage.manifest.json")
+                .to_str()
+                .unwrap(),
+        ])
+        .assert()
+        .success();
+    out.join("run_fixture")
+}
+
 fn collect_json_into(tmp: &Path) -> PathBuf {
     let fixtures = fixture_dir();
     shiplog_cmd()
@@ -92,6 +115,14 @@ fn collect_json_into(tmp: &Path) -> PathBuf {
 
 fn assert_packet_opens_with_coverage(packet: &str) {
     let trimmed = packet.trim_start();
+    // The internal packet may lead with a needs-attention block.
+    let trimmed = match trimmed.strip_prefix("# Needs Attention") {
+        Some(rest) => rest
+            .find("\n#")
+            .map(|at| rest[at..].trim_start())
+            .expect("needs-attention block should be followed by the packet"),
+        None => trimmed,
+    };
     assert!(
         trimmed.starts_with("# Packet Readiness") || trimmed.starts_with("## Coverage and Limits"),
         "packet should put readiness or coverage and gaps first"
@@ -730,6 +761,26 @@ fn assert_golden_intake_report(run_dir: &Path, readiness: &str) -> (String, serd
     assert_eq!(report_json["schema_version"], 1);
     assert_eq!(report_json["readiness"], readiness);
     assert_intake_report_schema_contract(&report_json);
+    let assessment = &report_json["readiness_assessment"];
+    assert!(
+        assessment["score"]
+            .as_u64()
+            .is_some_and(|score| score <= 100),
+        "intake.report.json should carry a 0-100 readiness score"
+    );
+    assert_eq!(
+        assessment["components"].as_array().map(Vec::len),
+        Some(4),
+        "readiness assessment should score all four components"
+    );
+    assert!(
+        report_md.contains(&format!(
+            "Score: **{}/100 ({})**",
+            assessment["score"],
+            assessment["grade"].as_str().unwrap()
+        )),
+        "intake.report.md should render the readiness score"
+    );
     assert!(
         report_json["run_id"]
             .as_str()
@@ -1102,7 +1153,7 @@ fn fixture_source(system: SourceSystem, slug: &str) -> SourceRef {
     SourceRef {
         system,
         url: Some(format!("https://example.invalid/{source}/{slug}")),
-        opaque_id: Some(format!("{source}-{slug}")),
+        opaque_id: Some(OpaqueId::from_raw("test", &format!("{source}-{slug}"))),
     }
 }
 
@@ -1117,6 +1168,7 @@ fn fixture_pr_event(
     let occurred_at = fixture_time(day);
     let number_string = number.to_string();
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts([source.as_str(), "pr", repo, number_string.as_str()]),
         kind: EventKind::PullRequest,
         occurred_at,
@@ -1137,6 +1189,9 @@ fn fixture_pr_event(
         tags: vec!["fixture".into()],
         links: vec![fixture_link(&source, &number_string)],
         source: fixture_source(system, &number_string),
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -1151,6 +1206,7 @@ fn fixture_review_event(
     let occurred_at = fixture_time(day);
     let pull_number_string = pull_number.to_string();
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts([
             source.as_str(),
             "review",
@@ -1168,10 +1224,15 @@ fn fixture_review_event(
             submitted_at: occurred_at,
             state: "approved".into(),
             window: Some(fixture_window()),
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec!["fixture".into(), "review".into()],
         links: vec![fixture_link(&source, &format!("review-{pull_number}"))],
         source: fixture_source(system, &format!("review-{pull_number}")),
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -1186,6 +1247,7 @@ fn fixture_manual_event(
     let occurred_at = fixture_time(day);
     let slug = title.to_ascii_lowercase().replace(' ', "-");
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts([source.as_str(), "manual", repo, slug.as_str()]),
         kind: EventKind::Manual,
         occurred_at,
@@ -1200,10 +1262,16 @@ fn fixture_manual_event(
             started_at: Some(occurred_at.date_naive()),
             ended_at: Some(occurred_at.date_naive()),
             impact: Some("Captured evidence that would otherwise be easy to lose.".into()),
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec!["fixture".into()],
         links: vec![fixture_link(&source, &slug)],
         source: fixture_source(system, &slug),
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -1294,6 +1362,7 @@ fn all_source_fixture_coverage() -> CoverageManifest {
     ];
 
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("run_all_sources".into()),
         generated_at: fixture_time(20),
         user: "octo".into(),
@@ -1309,10 +1378,14 @@ fn all_source_fixture_coverage() -> CoverageManifest {
                 fetched: *count,
                 incomplete_results: Some(false),
                 notes: vec!["fixture".into()],
+                source: None,
             })
             .collect(),
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -4198,6 +4271,206 @@ fn github_auth_cli_fallback_collects_without_token_and_keeps_secret_out_of_artif
     Ok(())
 }
 
+#[test]
+fn collect_multi_http_budget_stops_early_and_marks_coverage_partial() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    // Two search requests fit the budget; the PR detail request does not.
+    let server = RecordedGithubCliServer::start(2)?;
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        format!(
+            r#"[shiplog]
+config_version = 1
+
+[http]
+max_requests_per_host = 2
+
+[sources.github]
+enabled = true
+user = "octocat"
+api_base = "{}"
+include_reviews = false
+"#,
+            server.base_url()
+        ),
+    )?;
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env("GITHUB_TOKEN", "dummy-token")
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "multi",
+            "--since",
+            "2026-02-01",
+            "--until",
+            "2026-03-01",
+        ])
+        .assert()
+        .success();
+
+    let requests = server.finish()?;
+    assert_eq!(requests.len(), 2, "budget should stop the third request");
+    assert!(
+        requests.iter().all(|line| !line.contains("/pulls/")),
+        "PR detail request should be refused: {requests:?}"
+    );
+
+    let run_dir = first_run_dir(&out);
+    assert_ledger_event_count(&run_dir, 1);
+    let coverage: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        run_dir.join("coverage.manifest.json"),
+    )?)?;
+    assert_eq!(coverage["completeness"], "Partial");
+    let warnings = coverage["warnings"].to_string();
+    assert!(
+        warnings.contains("http.max_requests_per_host") && warnings.contains("coverage is partial"),
+        "coverage should explain the cutoff: {warnings}"
+    );
+    Ok(())
+}
+
+#[test]
+fn collect_multi_uses_configured_workstream_clusterer() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let fixtures = fixture_dir();
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        format!(
+            r#"[shiplog]
+config_version = 1
+
+[workstreams]
+clusterer = "label"
+
+[sources.json]
+enabled = true
+events = "{}"
+coverage = "{}"
+"#,
+            fixtures.join("ledger.events.jsonl").display(),
+            fixtures.join("coverage.manifest.json").display()
+        ),
+    )?;
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "multi",
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2026-01-01",
+        ])
+        .assert()
+        .success();
+
+    let suggested =
+        std::fs::read_to_string(first_run_dir(&out).join("workstreams.suggested.yaml"))?;
+    let workstreams: serde_yaml::Value = serde_yaml::from_str(&suggested)?;
+    let titles: Vec<&str> = workstreams["workstreams"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|ws| ws["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["compliance", "migration", "reliability"]);
+    Ok(())
+}
+
+#[test]
+fn collect_multi_dry_run_plans_sources_without_requests_or_a_run() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[shiplog]
+config_version = 1
+
+[sources.github]
+enabled = true
+user = "octocat"
+api_base = "http://127.0.0.1:9"
+include_reviews = true
+no_cache = true
+
+[sources.manual]
+enabled = true
+events = "manual_events.yaml"
+"#,
+    )?;
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env("GITHUB_TOKEN", "dummy-token")
+        .args([
+            "collect",
+            "--dry-run",
+            "--out",
+            out.to_str().unwrap(),
+            "multi",
+            "--since",
+            "2026-01-01",
+            "--until",
+            "2026-03-01",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "- github: ~8 requests, 0 of 4 queries cached",
+        ))
+        .stdout(predicate::str::contains(
+            "2026-01-01..2026-02-01 is:pr is:merged author:octocat merged:2026-01-01..2026-01-31 (~2 requests)",
+        ))
+        .stdout(predicate::str::contains("- manual: local, no API requests"))
+        .stdout(predicate::str::contains("Estimated API requests: ~8"));
+
+    assert!(!out.exists(), "a dry run must not write a run");
+    Ok(())
+}
+
+#[test]
+fn collect_dry_run_with_default_cache_leaves_out_dir_absent() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env("GITHUB_TOKEN", "dummy-token")
+        .args([
+            "collect",
+            "--dry-run",
+            "--out",
+            out.to_str().unwrap(),
+            "github",
+            "--user",
+            "octocat",
+            "--since",
+            "2026-01-01",
+            "--until",
+            "2026-03-01",
+            "--api-base",
+            "http://127.0.0.1:9",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("- github: ~"))
+        .stdout(predicate::str::contains("0 of"));
+
+    assert!(
+        !out.exists(),
+        "a dry run must not create the output directory"
+    );
+    Ok(())
+}
+
 #[test]
 fn github_activity_scout_writes_checkpoint_progress_on_budget_stop() -> CliTestResult {
     let tmp = TempDir::new()?;
@@ -4612,6 +4885,7 @@ cache_dir = "./out/github-full/.cache"
     write_coverage_manifest(
         &jan_dir.join("coverage.manifest.json"),
         &CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId("github_activity_window_jan".to_string()),
             generated_at: Utc.with_ymd_and_hms(2026, 5, 19, 0, 0, 0).unwrap(),
             user: "octocat".to_string(),
@@ -4629,9 +4903,13 @@ cache_dir = "./out/github-full/.cache"
                     "owner_filter:kept=acme=1".to_string(),
                     "owner_filter:dropped=none".to_string(),
                 ],
+                source: None,
             }],
             warnings: Vec::new(),
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         },
     );
     std::fs::write(jan_dir.join("freshness.json"), "[]\n")?;
@@ -6843,6 +7121,49 @@ fn journal_add_writes_date_ranges() {
     );
 }
 
+#[test]
+fn journal_import_appends_brag_doc_bullets_once() {
+    let tmp = TempDir::new().unwrap();
+    let brag = tmp.path().join("brag.md");
+    let manual_events = tmp.path().join("manual_events.yaml");
+    std::fs::write(
+        &brag,
+        "## Payments\n\n- 2026-05-08: Debugged customer import incident (https://example.invalid/OPS-123)\n- Paired on flaky tests\n",
+    )
+    .unwrap();
+    let import = |extra: &[&str]| {
+        let mut cmd = shiplog_cmd();
+        cmd.args(["journal", "import", brag.to_str().unwrap(), "--events"])
+            .arg(&manual_events)
+            .args(extra);
+        cmd.assert().success()
+    };
+
+    import(&["--dry-run"])
+        .stdout(predicate::str::contains("Would import 1 manual events"))
+        .stdout(predicate::str::contains("line 4: Paired on flaky tests"));
+    assert!(!manual_events.exists());
+
+    import(&["--headings-as-workstreams", "--tag", "2026-review"]).stdout(
+        predicate::str::contains(
+            "- manual-2026-05-08-debugged-customer-import-incident | 2026-05-08 | Debugged customer import incident",
+        ),
+    );
+    let file: ManualEventsFile =
+        serde_yaml::from_str(&std::fs::read_to_string(&manual_events).unwrap()).unwrap();
+    assert_eq!(file.events.len(), 1);
+    assert_eq!(file.events[0].workstream.as_deref(), Some("Payments"));
+    assert_eq!(file.events[0].tags, ["brag-doc", "2026-review"]);
+    assert_eq!(
+        file.events[0].receipts[0].url,
+        "https://example.invalid/OPS-123"
+    );
+
+    import(&[])
+        .stdout(predicate::str::contains("Imported 0 manual events"))
+        .stdout(predicate::str::contains("Already present: 1"));
+}
+
 #[test]
 fn doctor_reports_missing_config_actionably() {
     let tmp = TempDir::new().unwrap();
@@ -8743,14 +9064,13 @@ events = "./missing.yaml"
 }
 
 #[test]
-fn config_validate_rejects_invalid_default_out_file() {
+fn config_validate_rejects_invalid_age_recipients() {
     let tmp = TempDir::new().unwrap();
     write_manual_events(&tmp.path().join("manual_events.yaml"));
-    std::fs::write(tmp.path().join("out-file"), "not a directory").unwrap();
     std::fs::write(
         tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-out = "./out-file"
+        r#"[bundle]
+age_recipients = ["age1notakey"]
 
 [sources.manual]
 enabled = true
@@ -8764,54 +9084,63 @@ events = "./manual_events.yaml"
         .args(["config", "validate"])
         .assert()
         .failure()
-        .stdout(predicate::str::contains("Output: error"))
-        .stdout(predicate::str::contains("exists but is not a directory"));
+        .stdout(predicate::str::contains("Bundle: error"))
+        .stdout(predicate::str::contains("invalid age recipient"));
 }
 
 #[test]
-fn config_migrate_dry_run_leaves_legacy_config_unchanged() {
+fn config_validate_rejects_invalid_bundle_profile_scopes() {
     let tmp = TempDir::new().unwrap();
-    let config_path = tmp.path().join("shiplog.toml");
-    let original = r#"[sources.manual]
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[bundle.profiles.manager]
+include = ["profiles/manager/packet.md", "../secrets.txt"]
+
+[sources.manual]
 enabled = true
 events = "./manual_events.yaml"
-"#;
-    std::fs::write(&config_path, original).unwrap();
+"#,
+    )
+    .unwrap();
 
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["config", "migrate", "--dry-run"])
+        .args(["config", "validate"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "would add [shiplog] config_version = 1",
-        ));
+        .failure()
+        .stdout(predicate::str::contains("Bundle: error"))
+        .stdout(predicate::str::contains("bundle.profiles.manager"))
+        .stdout(predicate::str::contains("run-relative path"));
 
-    let after = std::fs::read_to_string(config_path).unwrap();
-    assert_eq!(after, original);
-}
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[bundle.profiles.exec]
+exclude = ["coverage.manifest.json"]
 
-#[test]
-fn config_migrate_requires_existing_config_even_for_dry_run() {
-    let tmp = TempDir::new().unwrap();
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
 
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["config", "migrate", "--dry-run"])
+        .args(["config", "validate"])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("run `shiplog init` first"));
-
-    assert!(!tmp.path().join("shiplog.toml").exists());
-}
+        .stdout(predicate::str::contains("unknown bundle profile"));
 
-#[test]
-fn config_migrate_adds_explicit_version_to_legacy_config() {
-    let tmp = TempDir::new().unwrap();
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
     std::fs::write(
         tmp.path().join("shiplog.toml"),
-        r#"[sources.manual]
+        r#"[bundle.profiles.exec]
+exclude = ["coverage.manifest.json"]
+
+[redaction.profiles.exec]
+base = "public"
+
+[sources.manual]
 enabled = true
 events = "./manual_events.yaml"
 "#,
@@ -8820,289 +9149,272 @@ events = "./manual_events.yaml"
 
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["config", "migrate"])
+        .args(["config", "validate"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "added [shiplog] config_version = 1",
-        ))
-        .stdout(predicate::str::contains("Config migrated"));
+        .success();
+}
 
-    let config = std::fs::read_to_string(tmp.path().join("shiplog.toml")).unwrap();
-    assert!(config.starts_with("[shiplog]\nconfig_version = 1\n\n"));
+#[test]
+fn config_validate_rejects_zero_bundle_part_size() {
+    let tmp = TempDir::new().unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[bundle]
+max_part_mb = 0
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
 
     shiplog_cmd()
         .current_dir(tmp.path())
         .args(["config", "validate"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Version: ok, 1"));
+        .failure()
+        .stdout(predicate::str::contains("Bundle: error"))
+        .stdout(predicate::str::contains("bundle.max_part_mb"));
 }
 
 #[test]
-fn config_migrate_inserts_version_into_existing_shiplog_table() {
+fn config_validate_rejects_invalid_coverage_requirements() {
     let tmp = TempDir::new().unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
     std::fs::write(
         tmp.path().join("shiplog.toml"),
-        r#"[shiplog]
-# local config metadata
-schema_note = "keep this"
+        r#"[coverage]
+min_score = 120
+
+[coverage.sources.bitbucket]
+require_complete = true
 
 [sources.manual]
-enabled = false
+enabled = true
+events = "./manual_events.yaml"
 "#,
     )
     .unwrap();
 
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["config", "migrate"])
+        .args(["config", "validate"])
         .assert()
-        .success();
+        .failure()
+        .stdout(predicate::str::contains("Coverage: error"))
+        .stdout(predicate::str::contains("coverage.min_score is 120"))
+        .stdout(predicate::str::contains(
+            "coverage.sources.bitbucket is not a known source",
+        ));
+}
 
-    let config = std::fs::read_to_string(tmp.path().join("shiplog.toml")).unwrap();
-    assert_eq!(config.matches("[shiplog]").count(), 1);
-    assert!(config.contains("[shiplog]\nconfig_version = 1\n# local config metadata"));
-    assert!(config.contains("schema_note = \"keep this\""));
+#[test]
+fn completions_prints_a_script_for_the_requested_shell() {
+    shiplog_cmd()
+        .args(["completions", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("_shiplog()"))
+        .stdout(predicate::str::contains("collect"));
 }
 
 #[test]
-fn config_migrate_current_config_is_noop() {
+fn completions_dir_writes_the_conventional_file_name() {
     let tmp = TempDir::new().unwrap();
-    let original = r#"[shiplog]
-config_version = 1
-
-[sources.manual]
-enabled = false
-"#;
-    std::fs::write(tmp.path().join("shiplog.toml"), original).unwrap();
+    let dir = tmp.path().join("completions");
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .args(["config", "migrate"])
+        .args(["completions", "zsh", "--dir"])
+        .arg(&dir)
         .assert()
         .success()
-        .stdout(predicate::str::contains("already current"));
+        .stdout(predicate::str::contains("_shiplog"));
 
-    let after = std::fs::read_to_string(tmp.path().join("shiplog.toml")).unwrap();
-    assert_eq!(after, original);
+    let script = std::fs::read_to_string(dir.join("_shiplog")).unwrap();
+    assert!(script.starts_with("#compdef shiplog"), "{script}");
 }
 
 #[test]
-fn config_migrate_unsupported_version_fails_without_writing() {
-    let tmp = TempDir::new().unwrap();
-    let original = r#"[shiplog]
-config_version = 2
-
-[sources.manual]
-enabled = false
-"#;
-    let config_path = tmp.path().join("shiplog.toml");
-    std::fs::write(&config_path, original).unwrap();
-
+fn man_prints_the_top_level_page() {
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .args(["config", "migrate"])
+        .arg("man")
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("unsupported config_version 2"));
-
-    let after = std::fs::read_to_string(config_path).unwrap();
-    assert_eq!(after, original);
+        .success()
+        .stdout(predicate::str::contains(".TH shiplog 1"))
+        .stdout(predicate::str::contains("completions"));
 }
 
 #[test]
-fn config_explain_prints_effective_defaults_and_sources() {
+fn man_dir_writes_one_page_per_subcommand() {
     let tmp = TempDir::new().unwrap();
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-out = "./review-out"
-window = "year:2025"
-profile = "internal"
-include_reviews = true
-
-[user]
-label = "Octo"
-
-[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
-
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-"#,
-    )
-    .unwrap();
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .args(["config", "explain"])
+        .args(["man", "--dir"])
+        .arg(tmp.path())
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Config metadata:"))
-        .stdout(predicate::str::contains("- config_version: 1 (implicit)"))
-        .stdout(predicate::str::contains("Resolved defaults:"))
-        .stdout(predicate::str::contains(
-            "- window: year:2025 -> 2025-01-01..2026-01-01",
-        ))
-        .stdout(predicate::str::contains("- out: ./review-out ->"))
-        .stdout(predicate::str::contains("- user.label: Octo"))
-        .stdout(predicate::str::contains("Configured periods:"))
-        .stdout(predicate::str::contains("- none"))
-        .stdout(predicate::str::contains("Enabled sources:"))
-        .stdout(predicate::str::contains("- json: events"))
-        .stdout(predicate::str::contains("- manual: events"))
-        .stdout(predicate::str::contains("user Octo"));
+        .success();
+
+    for page in ["shiplog.1", "shiplog-collect.1", "shiplog-collect-multi.1"] {
+        assert!(tmp.path().join(page).is_file(), "missing {page}");
+    }
 }
 
 #[test]
-fn config_explain_prints_named_periods() {
+fn config_validate_rejects_unknown_timezone() {
     let tmp = TempDir::new().unwrap();
     write_manual_events(&tmp.path().join("manual_events.yaml"));
     std::fs::write(
         tmp.path().join("shiplog.toml"),
         r#"[defaults]
-window = "year:2025"
-
-[periods."2026-H1"]
-since = "2026-01-01"
-until = "2026-07-01"
-
-[periods."review-cycle"]
-preset = "year:2025"
+timezone = "Pacific/Atlantis"
 
 [sources.manual]
 enabled = true
 events = "./manual_events.yaml"
-user = "octo"
 "#,
     )
     .unwrap();
 
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["config", "explain"])
+        .args(["config", "validate"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Configured periods:"))
-        .stdout(predicate::str::contains(
-            "- 2026-H1: 2026-H1 (2026-01-01..2026-07-01)",
-        ))
+        .failure()
+        .stdout(predicate::str::contains("Timezone: error"))
         .stdout(predicate::str::contains(
-            "- review-cycle: review-cycle (2025-01-01..2026-01-01)",
+            "unknown timezone \"Pacific/Atlantis\"",
         ));
 }
 
 #[test]
-fn periods_list_prints_named_windows_without_existing_runs() {
+fn config_validate_rejects_invalid_notify_email() {
     let tmp = TempDir::new().unwrap();
     write_manual_events(&tmp.path().join("manual_events.yaml"));
     std::fs::write(
         tmp.path().join("shiplog.toml"),
-        r#"[periods."2026-H1"]
-since = "2026-01-01"
-until = "2026-07-01"
-
-[periods."review-cycle"]
-preset = "year:2025"
-
-[sources.manual]
+        r#"[sources.manual]
 enabled = true
 events = "./manual_events.yaml"
+
+[notify.email]
+smtp_host = "smtp.example.com"
+security = "ssl"
+from = "shiplog@example.com"
+to = ["me@example.com"]
 "#,
     )
     .unwrap();
 
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["periods", "list", "--out", "out"])
+        .args(["config", "validate"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Configured periods:"))
-        .stdout(predicate::str::contains("- 2026-H1"))
-        .stdout(predicate::str::contains(
-            "definition: explicit 2026-01-01..2026-07-01",
-        ))
-        .stdout(predicate::str::contains("window: 2026-01-01..2026-07-01"))
-        .stdout(predicate::str::contains("latest run: none"))
-        .stdout(predicate::str::contains(
-            "shiplog intake --config \"shiplog.toml\" --out \"out\" --period \"2026-H1\"",
-        ))
-        .stdout(predicate::str::contains("- review-cycle"))
-        .stdout(predicate::str::contains("definition: preset year:2025"));
+        .failure()
+        .stdout(predicate::str::contains("Notify: error"))
+        .stdout(predicate::str::contains("unknown SMTP security \"ssl\""));
 }
 
-#[test]
-fn periods_explain_shows_latest_matching_run_and_next_commands() {
-    let tmp = TempDir::new().unwrap();
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
+fn write_notify_manual_config(dir: &Path, notify: &str) -> PathBuf {
+    write_manual_events(&dir.join("manual_events.yaml"));
+    let config = dir.join("shiplog.toml");
     std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[periods."review-cycle"]
-preset = "year:2025"
+        &config,
+        format!(
+            r#"[defaults]
+window = "year:2025"
 
 [sources.manual]
 enabled = true
 events = "./manual_events.yaml"
-"#,
+user = "octo"
+
+{notify}"#
+        ),
     )
     .unwrap();
+    config
+}
+
+#[test]
+fn collect_multi_posts_completion_summary_to_slack() {
+    let tmp = TempDir::new().unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let webhook = format!("http://{}/hooks/T000", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0; 4096];
+        // The JSON body is the last thing reqwest writes.
+        while !request.ends_with(b"}") {
+            let read = stream.read(&mut buf).unwrap();
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..read]);
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok")
+            .unwrap();
+        String::from_utf8(request).unwrap()
+    });
+    let config = write_notify_manual_config(
+        tmp.path(),
+        "[notify.slack]\nwebhook_url_env = \"TEST_SLACK_WEBHOOK\"\n",
+    );
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
+        .env("TEST_SLACK_WEBHOOK", &webhook)
         .args([
-            "intake",
-            "--period",
-            "review-cycle",
+            "collect",
             "--out",
-            "out",
-            "--no-open",
+            tmp.path().join("out").to_str().unwrap(),
+            "multi",
+            "--config",
+            config.to_str().unwrap(),
         ])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains("Notified: slack"));
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /hooks/T000"), "{request}");
+    assert!(request.contains("Events collected: 1"), "{request}");
+    assert!(request.contains("Readiness: "), "{request}");
+}
+
+#[test]
+fn collect_multi_warns_but_succeeds_when_notification_cannot_be_sent() {
+    let tmp = TempDir::new().unwrap();
+    let config = write_notify_manual_config(tmp.path(), "[notify.slack]\n");
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .args(["periods", "explain", "review-cycle", "--out", "out"])
+        .env_remove("SHIPLOG_SLACK_WEBHOOK_URL")
+        .args([
+            "collect",
+            "--out",
+            tmp.path().join("out").to_str().unwrap(),
+            "multi",
+            "--config",
+            config.to_str().unwrap(),
+        ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Period: review-cycle"))
-        .stdout(predicate::str::contains("Definition: preset year:2025"))
-        .stdout(predicate::str::contains("Window: 2025-01-01..2026-01-01"))
-        .stdout(predicate::str::contains("Latest run: "))
-        .stdout(predicate::str::contains("Coverage:"))
-        .stdout(predicate::str::contains(
-            "shiplog review --config \"shiplog.toml\" --out \"out\" --period \"review-cycle\"",
+        .stderr(predicate::str::contains(
+            "Warning: slack notification failed: SHIPLOG_SLACK_WEBHOOK_URL is not set",
         ));
 }
 
 #[test]
-fn periods_explain_unknown_period_fails_clearly() {
+fn config_validate_rejects_invalid_default_out_file() {
     let tmp = TempDir::new().unwrap();
     write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(tmp.path().join("out-file"), "not a directory").unwrap();
     std::fs::write(
         tmp.path().join("shiplog.toml"),
-        r#"[periods."review-cycle"]
-preset = "year:2025"
+        r#"[defaults]
+out = "./out-file"
 
 [sources.manual]
 enabled = true
@@ -9113,971 +9425,859 @@ events = "./manual_events.yaml"
 
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["periods", "explain", "missing"])
+        .args(["config", "validate"])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("unknown period \"missing\""));
+        .stdout(predicate::str::contains("Output: error"))
+        .stdout(predicate::str::contains("exists but is not a directory"));
 }
 
-// ── 3. collect --help shows collect-specific options ───────────────────────
-
 #[test]
-fn collect_help_shows_sources_and_options() {
-    shiplog_cmd()
-        .args(["collect", "--help"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("github"))
-        .stdout(predicate::str::contains("multi"))
-        .stdout(predicate::str::contains("gitlab"))
-        .stdout(predicate::str::contains("jira"))
-        .stdout(predicate::str::contains("linear"))
-        .stdout(predicate::str::contains("json"))
-        .stdout(predicate::str::contains("--out"))
-        .stdout(predicate::str::contains("--regen"));
-}
+fn config_migrate_dry_run_leaves_legacy_config_unchanged() {
+    let tmp = TempDir::new().unwrap();
+    let config_path = tmp.path().join("shiplog.toml");
+    let original = r#"[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#;
+    std::fs::write(&config_path, original).unwrap();
 
-#[test]
-fn collect_multi_help_shows_config_and_conflict_policy() {
     shiplog_cmd()
-        .args(["collect", "multi", "--help"])
+        .current_dir(tmp.path())
+        .args(["config", "migrate", "--dry-run"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("--config"))
-        .stdout(predicate::str::contains("--period"))
-        .stdout(predicate::str::contains("--last-6-months"))
-        .stdout(predicate::str::contains("--conflict"))
-        .stdout(predicate::str::contains("prefer-most-recent"));
-}
+        .stdout(predicate::str::contains(
+            "would add [shiplog] config_version = 1",
+        ));
 
-#[test]
-fn collect_github_help_shows_github_flags() {
-    shiplog_cmd()
-        .args(["collect", "github", "--help"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("--user"))
-        .stdout(predicate::str::contains("--me"))
-        .stdout(predicate::str::contains("--since"))
-        .stdout(predicate::str::contains("--until"))
-        .stdout(predicate::str::contains("--last-6-months"))
-        .stdout(predicate::str::contains("--last-quarter"))
-        .stdout(predicate::str::contains("--year"))
-        .stdout(predicate::str::contains("--mode"))
-        .stdout(predicate::str::contains("--include-reviews"))
-        .stdout(predicate::str::contains("--no-details"));
+    let after = std::fs::read_to_string(config_path).unwrap();
+    assert_eq!(after, original);
 }
 
 #[test]
-fn collect_gitlab_help_shows_gitlab_flags() {
+fn config_migrate_requires_existing_config_even_for_dry_run() {
+    let tmp = TempDir::new().unwrap();
+
     shiplog_cmd()
-        .args(["collect", "gitlab", "--help"])
+        .current_dir(tmp.path())
+        .args(["config", "migrate", "--dry-run"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("--user"))
-        .stdout(predicate::str::contains("--me"))
-        .stdout(predicate::str::contains("--since"))
-        .stdout(predicate::str::contains("--until"))
-        .stdout(predicate::str::contains("--state"))
-        .stdout(predicate::str::contains("--instance"))
-        .stdout(predicate::str::contains("--include-reviews"))
-        .stdout(predicate::str::contains("--throttle-ms"))
-        .stdout(predicate::str::contains("--token"))
-        .stdout(predicate::str::contains("--cache-dir"))
-        .stdout(predicate::str::contains("--no-cache"));
+        .failure()
+        .stderr(predicate::str::contains("run `shiplog init` first"));
+
+    assert!(!tmp.path().join("shiplog.toml").exists());
 }
 
 #[test]
-fn collect_jira_help_shows_jira_flags() {
+fn config_migrate_adds_explicit_version_to_legacy_config() {
+    let tmp = TempDir::new().unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
+
     shiplog_cmd()
-        .args(["collect", "jira", "--help"])
+        .current_dir(tmp.path())
+        .args(["config", "migrate"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("--user"))
-        .stdout(predicate::str::contains("--auth-user"))
-        .stdout(predicate::str::contains("--since"))
-        .stdout(predicate::str::contains("--until"))
-        .stdout(predicate::str::contains("--status"))
-        .stdout(predicate::str::contains("--instance"))
-        .stdout(predicate::str::contains("--throttle-ms"))
-        .stdout(predicate::str::contains("--token"))
-        .stdout(predicate::str::contains("--cache-dir"))
-        .stdout(predicate::str::contains("--no-cache"));
-}
+        .stdout(predicate::str::contains(
+            "added [shiplog] config_version = 1",
+        ))
+        .stdout(predicate::str::contains("Config migrated"));
+
+    let config = std::fs::read_to_string(tmp.path().join("shiplog.toml")).unwrap();
+    assert!(config.starts_with("[shiplog]\nconfig_version = 1\n\n"));
 
-#[test]
-fn collect_linear_help_shows_linear_flags() {
     shiplog_cmd()
-        .args(["collect", "linear", "--help"])
+        .current_dir(tmp.path())
+        .args(["config", "validate"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("--user-id"))
-        .stdout(predicate::str::contains("--since"))
-        .stdout(predicate::str::contains("--until"))
-        .stdout(predicate::str::contains("--status"))
-        .stdout(predicate::str::contains("--project"))
-        .stdout(predicate::str::contains("--throttle-ms"))
-        .stdout(predicate::str::contains("--api-key"))
-        .stdout(predicate::str::contains("--cache-dir"))
-        .stdout(predicate::str::contains("--no-cache"));
+        .stdout(predicate::str::contains("Version: ok, 1"));
 }
 
 #[test]
-fn collect_json_help_shows_json_flags() {
-    shiplog_cmd()
-        .args(["collect", "json", "--help"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("--events"))
-        .stdout(predicate::str::contains("--coverage"));
-}
+fn config_migrate_inserts_version_into_existing_shiplog_table() {
+    let tmp = TempDir::new().unwrap();
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[shiplog]
+# local config metadata
+schema_note = "keep this"
 
-// ── 4. render --help shows render-specific options ─────────────────────────
+[sources.manual]
+enabled = false
+"#,
+    )
+    .unwrap();
 
-#[test]
-fn render_help_shows_render_options() {
     shiplog_cmd()
-        .args(["render", "--help"])
+        .current_dir(tmp.path())
+        .args(["config", "migrate"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("--out"))
-        .stdout(predicate::str::contains("--run"))
-        .stdout(predicate::str::contains("--latest"))
-        .stdout(predicate::str::contains("--user"))
-        .stdout(predicate::str::contains("--mode"))
-        .stdout(predicate::str::contains("--receipt-limit"))
-        .stdout(predicate::str::contains("--appendix"))
-        .stdout(predicate::str::contains("--redact-key"));
+        .success();
+
+    let config = std::fs::read_to_string(tmp.path().join("shiplog.toml")).unwrap();
+    assert_eq!(config.matches("[shiplog]").count(), 1);
+    assert!(config.contains("[shiplog]\nconfig_version = 1\n# local config metadata"));
+    assert!(config.contains("schema_note = \"keep this\""));
 }
 
 #[test]
-fn cache_stats_and_inspect_report_existing_cache_without_tokens() {
+fn config_migrate_current_config_is_noop() {
     let tmp = TempDir::new().unwrap();
-    let cache_dir = tmp.path().join(".cache");
-    let cache_path = seed_github_cache(&cache_dir);
+    let original = r#"[shiplog]
+config_version = 1
 
-    shiplog_cmd()
-        .args([
-            "cache",
-            "stats",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-        ])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Cache root:"))
-        .stdout(predicate::str::contains("github:"))
-        .stdout(predicate::str::contains(cache_path.display().to_string()))
-        .stdout(predicate::str::contains(
-            "entries: total 2, valid 1, expired 1",
-        ));
+[sources.manual]
+enabled = false
+"#;
+    std::fs::write(tmp.path().join("shiplog.toml"), original).unwrap();
 
     shiplog_cmd()
-        .args([
-            "cache",
-            "inspect",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-        ])
+        .current_dir(tmp.path())
+        .args(["config", "migrate"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("oldest:"))
-        .stdout(predicate::str::contains("newest:"));
+        .stdout(predicate::str::contains("already current"));
+
+    let after = std::fs::read_to_string(tmp.path().join("shiplog.toml")).unwrap();
+    assert_eq!(after, original);
 }
 
 #[test]
-fn cache_stats_does_not_create_missing_databases() {
+fn config_migrate_unsupported_version_fails_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let cache_dir = tmp.path().join(".cache");
-    std::fs::create_dir_all(&cache_dir).unwrap();
-    let missing = cache_dir.join("gitlab-api-cache.db");
+    let original = r#"[shiplog]
+config_version = 2
+
+[sources.manual]
+enabled = false
+"#;
+    let config_path = tmp.path().join("shiplog.toml");
+    std::fs::write(&config_path, original).unwrap();
 
     shiplog_cmd()
-        .args([
-            "cache",
-            "stats",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "gitlab",
-        ])
+        .current_dir(tmp.path())
+        .args(["config", "migrate"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("gitlab: missing"))
-        .stdout(predicate::str::contains("No cache databases found"));
+        .failure()
+        .stderr(predicate::str::contains("unsupported config_version 2"));
 
-    assert!(
-        !missing.exists(),
-        "stats should not create a missing cache database"
-    );
+    let after = std::fs::read_to_string(config_path).unwrap();
+    assert_eq!(after, original);
 }
 
 #[test]
-fn cache_stats_and_inspect_do_not_initialize_existing_empty_file() {
+fn config_explain_prints_effective_defaults_and_sources() {
     let tmp = TempDir::new().unwrap();
-    let cache_dir = tmp.path().join(".cache");
-    std::fs::create_dir_all(&cache_dir).unwrap();
-    let empty_cache = cache_dir.join("github-api-cache.db");
-    std::fs::File::create(&empty_cache).unwrap();
-    assert_eq!(std::fs::metadata(&empty_cache).unwrap().len(), 0);
-
-    shiplog_cmd()
-        .args([
-            "cache",
-            "stats",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("cache_entries"));
-    assert_eq!(
-        std::fs::metadata(&empty_cache).unwrap().len(),
-        0,
-        "stats should not initialize an existing empty cache file"
-    );
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+out = "./review-out"
+window = "year:2025"
+profile = "internal"
+include_reviews = true
+
+[user]
+label = "Octo"
+
+[sources.json]
+enabled = true
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args([
-            "cache",
-            "inspect",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-        ])
+        .current_dir(tmp.path())
+        .args(["config", "explain"])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("cache_entries"));
-    assert_eq!(
-        std::fs::metadata(&empty_cache).unwrap().len(),
-        0,
-        "inspect should not initialize an existing empty cache file"
-    );
+        .success()
+        .stdout(predicate::str::contains("Config metadata:"))
+        .stdout(predicate::str::contains("- config_version: 1 (implicit)"))
+        .stdout(predicate::str::contains("Resolved defaults:"))
+        .stdout(predicate::str::contains(
+            "- window: year:2025 -> 2025-01-01..2026-01-01",
+        ))
+        .stdout(predicate::str::contains("- out: ./review-out ->"))
+        .stdout(predicate::str::contains("- user.label: Octo"))
+        .stdout(predicate::str::contains("Configured periods:"))
+        .stdout(predicate::str::contains("- none"))
+        .stdout(predicate::str::contains("Enabled sources:"))
+        .stdout(predicate::str::contains("- json: events"))
+        .stdout(predicate::str::contains("- manual: events"))
+        .stdout(predicate::str::contains("user Octo"));
 }
 
 #[test]
-fn cache_clean_expired_removes_only_expired_entries() {
+fn config_explain_prints_named_periods() {
     let tmp = TempDir::new().unwrap();
-    let cache_dir = tmp.path().join(".cache");
-    let cache_path = seed_github_cache(&cache_dir);
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+window = "year:2025"
+
+[periods."2026-H1"]
+since = "2026-01-01"
+until = "2026-07-01"
+
+[periods."review-cycle"]
+preset = "year:2025"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#,
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args([
-            "cache",
-            "clean",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-        ])
+        .current_dir(tmp.path())
+        .args(["config", "explain"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("github: removed 1 entries"));
-
-    let cache = ApiCache::open(cache_path).unwrap();
-    let stats = cache.stats().unwrap();
-    assert_eq!(stats.total_entries, 1);
-    assert_eq!(stats.valid_entries, 1);
-    assert_eq!(stats.expired_entries, 0);
+        .stdout(predicate::str::contains("Configured periods:"))
+        .stdout(predicate::str::contains(
+            "- 2026-H1: 2026-H1 (2026-01-01..2026-07-01)",
+        ))
+        .stdout(predicate::str::contains(
+            "- review-cycle: review-cycle (2025-01-01..2026-01-01)",
+        ));
 }
 
 #[test]
-fn cache_clean_older_than_dry_run_reports_without_mutating() {
+fn periods_list_prints_named_windows_without_existing_runs() {
     let tmp = TempDir::new().unwrap();
-    let cache_dir = tmp.path().join(".cache");
-    let cache_path = seed_github_cache(&cache_dir);
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[periods."2026-H1"]
+since = "2026-01-01"
+until = "2026-07-01"
+
+[periods."review-cycle"]
+preset = "year:2025"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args([
-            "cache",
-            "clean",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-            "--older-than",
-            "0m",
-            "--dry-run",
-        ])
+        .current_dir(tmp.path())
+        .args(["periods", "list", "--out", "out"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("github: would remove 2 entries"));
-
-    let cache = ApiCache::open(cache_path).unwrap();
-    assert_eq!(cache.stats().unwrap().total_entries, 2);
+        .stdout(predicate::str::contains("Configured periods:"))
+        .stdout(predicate::str::contains("- 2026-H1"))
+        .stdout(predicate::str::contains(
+            "definition: explicit 2026-01-01..2026-07-01",
+        ))
+        .stdout(predicate::str::contains("window: 2026-01-01..2026-07-01"))
+        .stdout(predicate::str::contains("latest run: none"))
+        .stdout(predicate::str::contains(
+            "shiplog intake --config \"shiplog.toml\" --out \"out\" --period \"2026-H1\"",
+        ))
+        .stdout(predicate::str::contains("- review-cycle"))
+        .stdout(predicate::str::contains("definition: preset year:2025"));
 }
 
 #[test]
-fn cache_clean_all_requires_yes_and_preserves_unrelated_files() {
+fn periods_explain_shows_latest_matching_run_and_next_commands() {
     let tmp = TempDir::new().unwrap();
-    let cache_dir = tmp.path().join(".cache");
-    let cache_path = seed_github_cache(&cache_dir);
-    let unrelated = cache_dir.join("notes.txt");
-    std::fs::write(&unrelated, "not a cache database").unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[periods."review-cycle"]
+preset = "year:2025"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
 
     shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
         .args([
-            "cache",
-            "clean",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-            "--all",
+            "intake",
+            "--period",
+            "review-cycle",
+            "--out",
+            "out",
+            "--no-open",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("cache clean --all requires --yes"));
-
-    assert_eq!(
-        ApiCache::open(&cache_path)
-            .unwrap()
-            .stats()
-            .unwrap()
-            .total_entries,
-        2
-    );
+        .success();
 
     shiplog_cmd()
-        .args([
-            "cache",
-            "clean",
-            "--cache-dir",
-            cache_dir.to_str().unwrap(),
-            "--source",
-            "github",
-            "--all",
-            "--yes",
-        ])
+        .current_dir(tmp.path())
+        .args(["periods", "explain", "review-cycle", "--out", "out"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("github: removed 2 entries"));
+        .stdout(predicate::str::contains("Period: review-cycle"))
+        .stdout(predicate::str::contains("Definition: preset year:2025"))
+        .stdout(predicate::str::contains("Window: 2025-01-01..2026-01-01"))
+        .stdout(predicate::str::contains("Latest run: "))
+        .stdout(predicate::str::contains("Coverage:"))
+        .stdout(predicate::str::contains(
+            "shiplog review --config \"shiplog.toml\" --out \"out\" --period \"review-cycle\"",
+        ));
+}
 
-    assert!(
-        cache_path.exists(),
-        "clean should not delete cache database files"
-    );
-    assert!(
-        unrelated.exists(),
-        "clean should not delete unrelated files"
-    );
-    assert_eq!(
-        ApiCache::open(cache_path)
-            .unwrap()
-            .stats()
-            .unwrap()
-            .total_entries,
-        0
-    );
+#[test]
+fn periods_explain_unknown_period_fails_clearly() {
+    let tmp = TempDir::new().unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[periods."review-cycle"]
+preset = "year:2025"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["periods", "explain", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown period \"missing\""));
 }
 
-// ── 5. collect json with sample fixture data ───────────────────────────────
+// ── 3. collect --help shows collect-specific options ───────────────────────
 
 #[test]
-fn collect_json_produces_all_outputs() {
-    let tmp = TempDir::new().unwrap();
-    let fixtures = fixture_dir();
+fn collect_help_shows_sources_and_options() {
+    shiplog_cmd()
+        .args(["collect", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("github"))
+        .stdout(predicate::str::contains("multi"))
+        .stdout(predicate::str::contains("gitlab"))
+        .stdout(predicate::str::contains("jira"))
+        .stdout(predicate::str::contains("linear"))
+        .stdout(predicate::str::contains("json"))
+        .stdout(predicate::str::contains("--out"))
+        .stdout(predicate::str::contains("--regen"));
+}
 
-    let mut cmd = shiplog_cmd();
-    cmd.env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "collect",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "json",
-            "--events",
-            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
-            "--coverage",
-            fixtures.join("coverage.manifest.json").to_str().unwrap(),
-        ])
+#[test]
+fn collect_multi_help_shows_config_and_conflict_policy() {
+    shiplog_cmd()
+        .args(["collect", "multi", "--help"])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains("--config"))
+        .stdout(predicate::str::contains("--period"))
+        .stdout(predicate::str::contains("--last-6-months"))
+        .stdout(predicate::str::contains("--conflict"))
+        .stdout(predicate::str::contains("prefer-most-recent"));
+}
 
-    let run_dir = tmp.path().join("run_fixture");
-    assert!(run_dir.join("packet.md").exists(), "missing packet.md");
-    assert!(
-        run_dir.join("ledger.events.jsonl").exists(),
-        "missing ledger.events.jsonl"
-    );
-    assert!(
-        run_dir.join("coverage.manifest.json").exists(),
-        "missing coverage.manifest.json"
-    );
-    assert!(
-        run_dir.join("workstreams.suggested.yaml").exists(),
-        "missing workstreams.suggested.yaml"
-    );
-    assert!(
-        run_dir.join("bundle.manifest.json").exists(),
-        "missing bundle.manifest.json"
-    );
-    assert!(
-        !run_dir.join("profiles/manager/packet.md").exists(),
-        "manager profile should require an explicit redaction key"
-    );
-    assert!(
-        !run_dir.join("profiles/public/packet.md").exists(),
-        "public profile should require an explicit redaction key"
-    );
+#[test]
+fn collect_github_help_shows_github_flags() {
+    shiplog_cmd()
+        .args(["collect", "github", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--user"))
+        .stdout(predicate::str::contains("--me"))
+        .stdout(predicate::str::contains("--since"))
+        .stdout(predicate::str::contains("--until"))
+        .stdout(predicate::str::contains("--last-6-months"))
+        .stdout(predicate::str::contains("--last-quarter"))
+        .stdout(predicate::str::contains("--year"))
+        .stdout(predicate::str::contains("--mode"))
+        .stdout(predicate::str::contains("--include-reviews"))
+        .stdout(predicate::str::contains("--no-details"));
 }
 
 #[test]
-fn merge_existing_runs_writes_combined_packet() {
-    let json_tmp = TempDir::new().unwrap();
-    let manual_tmp = TempDir::new().unwrap();
-    let merge_tmp = TempDir::new().unwrap();
+fn collect_gitlab_help_shows_gitlab_flags() {
+    shiplog_cmd()
+        .args(["collect", "gitlab", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--user"))
+        .stdout(predicate::str::contains("--me"))
+        .stdout(predicate::str::contains("--since"))
+        .stdout(predicate::str::contains("--until"))
+        .stdout(predicate::str::contains("--state"))
+        .stdout(predicate::str::contains("--instance"))
+        .stdout(predicate::str::contains("--include-reviews"))
+        .stdout(predicate::str::contains("--throttle-ms"))
+        .stdout(predicate::str::contains("--token"))
+        .stdout(predicate::str::contains("--cache-dir"))
+        .stdout(predicate::str::contains("--no-cache"));
+}
 
-    let json_run = collect_json_into(json_tmp.path());
-    let manual_run = collect_manual_into(manual_tmp.path());
+#[test]
+fn collect_jira_help_shows_jira_flags() {
+    shiplog_cmd()
+        .args(["collect", "jira", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--user"))
+        .stdout(predicate::str::contains("--auth-user"))
+        .stdout(predicate::str::contains("--since"))
+        .stdout(predicate::str::contains("--until"))
+        .stdout(predicate::str::contains("--status"))
+        .stdout(predicate::str::contains("--instance"))
+        .stdout(predicate::str::contains("--throttle-ms"))
+        .stdout(predicate::str::contains("--token"))
+        .stdout(predicate::str::contains("--cache-dir"))
+        .stdout(predicate::str::contains("--no-cache"));
+}
 
+#[test]
+fn collect_linear_help_shows_linear_flags() {
     shiplog_cmd()
-        .args([
-            "merge",
-            "--out",
-            merge_tmp.path().to_str().unwrap(),
-            "--input",
-            json_run.to_str().unwrap(),
-            "--input",
-            manual_run.to_str().unwrap(),
-            "--conflict",
-            "prefer-most-recent",
-        ])
+        .args(["collect", "linear", "--help"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Merged and wrote:"))
-        .stdout(predicate::str::contains("- inputs: 2"));
+        .stdout(predicate::str::contains("--user-id"))
+        .stdout(predicate::str::contains("--since"))
+        .stdout(predicate::str::contains("--until"))
+        .stdout(predicate::str::contains("--status"))
+        .stdout(predicate::str::contains("--project"))
+        .stdout(predicate::str::contains("--throttle-ms"))
+        .stdout(predicate::str::contains("--api-key"))
+        .stdout(predicate::str::contains("--cache-dir"))
+        .stdout(predicate::str::contains("--no-cache"));
+}
 
-    let run_dir = first_run_dir(merge_tmp.path());
-    assert!(run_dir.join("packet.md").exists(), "missing merged packet");
-    assert!(
-        run_dir.join("ledger.events.jsonl").exists(),
-        "missing merged ledger"
-    );
-    assert!(
-        run_dir.join("coverage.manifest.json").exists(),
-        "missing merged coverage"
-    );
+#[test]
+fn collect_json_help_shows_json_flags() {
+    shiplog_cmd()
+        .args(["collect", "json", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--events"))
+        .stdout(predicate::str::contains("--coverage"));
+}
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert!(
-        packet.contains("Payments ledger rewrite"),
-        "merged packet should include JSON fixture evidence"
-    );
-    assert!(
-        packet.contains("Manual incident follow-up"),
-        "merged packet should include manual evidence"
-    );
+// ── 4. render --help shows render-specific options ─────────────────────────
 
-    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    assert!(
-        coverage.contains("\"github\""),
-        "merged coverage should include github source"
-    );
-    assert!(
-        coverage.contains("\"manual\""),
-        "merged coverage should include manual source"
-    );
+#[test]
+fn render_help_shows_render_options() {
+    shiplog_cmd()
+        .args(["render", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--out"))
+        .stdout(predicate::str::contains("--run"))
+        .stdout(predicate::str::contains("--latest"))
+        .stdout(predicate::str::contains("--user"))
+        .stdout(predicate::str::contains("--mode"))
+        .stdout(predicate::str::contains("--receipt-limit"))
+        .stdout(predicate::str::contains("--appendix"))
+        .stdout(predicate::str::contains("--redact-key"));
 }
 
 #[test]
-fn collect_multi_from_config_merges_json_and_manual_sources() {
+fn cache_stats_and_inspect_report_existing_cache_without_tokens() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    let cache_dir = tmp.path().join(".cache");
+    let cache_path = seed_github_cache(&cache_dir);
 
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-window = "year:2025"
-include_reviews = true
-
-[user]
-label = "octo"
-
-[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
-
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-user = "octo"
-"#,
-    )
-    .unwrap();
+    shiplog_cmd()
+        .args([
+            "cache",
+            "stats",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Cache root:"))
+        .stdout(predicate::str::contains("github:"))
+        .stdout(predicate::str::contains(cache_path.display().to_string()))
+        .stdout(predicate::str::contains(
+            "entries: total 2, valid 1, expired 1",
+        ));
 
     shiplog_cmd()
         .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "multi",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "cache",
+            "inspect",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Collected configured sources:"))
-        .stdout(predicate::str::contains("- json: success"))
-        .stdout(predicate::str::contains("- manual: success, 1 event"))
-        .stdout(predicate::str::contains("Merged and wrote:"));
+        .stdout(predicate::str::contains("oldest:"))
+        .stdout(predicate::str::contains("newest:"));
+}
 
-    let run_dir = first_run_dir(&out);
-    assert!(run_dir.join("packet.md").exists(), "missing merged packet");
-    assert!(
-        run_dir.join("ledger.events.jsonl").exists(),
-        "missing merged ledger"
-    );
-    assert!(
-        run_dir.join("coverage.manifest.json").exists(),
-        "missing merged coverage"
-    );
+#[test]
+fn cache_stats_does_not_create_missing_databases() {
+    let tmp = TempDir::new().unwrap();
+    let cache_dir = tmp.path().join(".cache");
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    let missing = cache_dir.join("gitlab-api-cache.db");
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert_packet_opens_with_coverage(&packet);
-    assert_packet_uses_summary_appendix(&packet);
-    assert!(
-        packet.contains("Included:\n- GitHub: 3 events\n- Manual: 1 event\n"),
-        "configured multi packet should summarize successful sources in Coverage and Limits"
-    );
-    assert!(
-        packet.contains("Skipped:\n- None recorded\n"),
-        "configured multi packet should make absence of skipped sources explicit"
-    );
-    assert!(
-        packet.contains("Known gaps:\n- Manual events are user-provided\n"),
-        "configured multi packet should flag manual evidence as user-provided"
-    );
-    assert!(
-        packet.contains("- **Sources:** GitHub, Manual"),
-        "configured multi packet should carry merged source details"
-    );
-    assert!(
-        packet.contains("Payments ledger rewrite"),
-        "configured multi packet should include JSON fixture evidence"
-    );
-    assert!(
-        packet.contains("Manual incident follow-up"),
-        "configured multi packet should include manual evidence"
-    );
-    assert!(
-        packet.contains("workstreams.suggested.yaml"),
-        "configured multi packet should name generated workstream suggestions"
-    );
-    assert!(
-        packet.contains("**Suggested claim prompts**"),
-        "configured multi packet should include claim prompts for review-cycle writing"
-    );
-    assert!(
-        packet.contains("bundle.manifest.json"),
-        "configured multi packet should name the generated bundle manifest"
-    );
-    assert!(
-        !packet.contains("manual_events.yaml"),
-        "configured multi packet should not list manual_events.yaml as a generated run artifact"
-    );
+    shiplog_cmd()
+        .args([
+            "cache",
+            "stats",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "gitlab",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("gitlab: missing"))
+        .stdout(predicate::str::contains("No cache databases found"));
 
-    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    assert!(
-        coverage.contains("\"github\""),
-        "configured multi coverage should include JSON fixture source"
-    );
     assert!(
-        coverage.contains("\"manual\""),
-        "configured multi coverage should include manual source"
+        !missing.exists(),
+        "stats should not create a missing cache database"
     );
 }
 
 #[test]
-fn collect_multi_uses_named_config_period() {
+fn cache_stats_and_inspect_do_not_initialize_existing_empty_file() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
-
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-window = "year:2025"
-
-[periods."review-cycle"]
-since = "2026-01-01"
-until = "2026-07-01"
-
-[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
-"#,
-    )
-    .unwrap();
+    let cache_dir = tmp.path().join(".cache");
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    let empty_cache = cache_dir.join("github-api-cache.db");
+    std::fs::File::create(&empty_cache).unwrap();
+    assert_eq!(std::fs::metadata(&empty_cache).unwrap().len(), 0);
 
     shiplog_cmd()
         .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "multi",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--period",
-            "review-cycle",
+            "cache",
+            "stats",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Collected configured sources:"));
-
-    let run_dir = first_run_dir(&out);
-    let coverage: serde_json::Value = serde_json::from_str(
-        &std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap(),
-    )
-    .unwrap();
-    assert_eq!(coverage["window"]["since"], "2026-01-01");
-    assert_eq!(coverage["window"]["until"], "2026-07-01");
+        .failure()
+        .stderr(predicate::str::contains("cache_entries"));
+    assert_eq!(
+        std::fs::metadata(&empty_cache).unwrap().len(),
+        0,
+        "stats should not initialize an existing empty cache file"
+    );
 
     shiplog_cmd()
         .args([
-            "review",
-            "--out",
-            out.to_str().unwrap(),
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--period",
-            "review-cycle",
+            "cache",
+            "inspect",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Window: 2026-01-01..2026-07-01"))
-        .stdout(predicate::str::contains("Evidence debt:"));
+        .failure()
+        .stderr(predicate::str::contains("cache_entries"));
+    assert_eq!(
+        std::fs::metadata(&empty_cache).unwrap().len(),
+        0,
+        "inspect should not initialize an existing empty cache file"
+    );
 }
 
 #[test]
-fn collect_multi_cli_dates_override_named_period() {
+fn cache_clean_expired_removes_only_expired_entries() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
-
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[periods."review-cycle"]
-since = "2026-01-01"
-until = "2026-07-01"
-
-[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
-"#,
-    )
-    .unwrap();
+    let cache_dir = tmp.path().join(".cache");
+    let cache_path = seed_github_cache(&cache_dir);
 
     shiplog_cmd()
         .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "multi",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--period",
-            "review-cycle",
-            "--year",
-            "2025",
+            "cache",
+            "clean",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
         ])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains("github: removed 1 entries"));
 
-    let run_dir = first_run_dir(&out);
-    let coverage: serde_json::Value = serde_json::from_str(
-        &std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap(),
-    )
-    .unwrap();
-    assert_eq!(coverage["window"]["since"], "2025-01-01");
-    assert_eq!(coverage["window"]["until"], "2026-01-01");
+    let cache = ApiCache::open(cache_path).unwrap();
+    let stats = cache.stats().unwrap();
+    assert_eq!(stats.total_entries, 1);
+    assert_eq!(stats.valid_entries, 1);
+    assert_eq!(stats.expired_entries, 0);
 }
 
 #[test]
-fn collect_multi_unknown_period_fails_clearly() {
+fn cache_clean_older_than_dry_run_reports_without_mutating() {
     let tmp = TempDir::new().unwrap();
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
-"#,
-    )
-    .unwrap();
+    let cache_dir = tmp.path().join(".cache");
+    let cache_path = seed_github_cache(&cache_dir);
 
     shiplog_cmd()
         .args([
-            "collect",
-            "multi",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--period",
-            "missing",
+            "cache",
+            "clean",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
+            "--older-than",
+            "0m",
+            "--dry-run",
         ])
         .assert()
-        .failure()
-        .stderr(
-            predicate::str::contains("unknown period")
-                .and(predicate::str::contains("[periods.\"missing\"]")),
-        );
+        .success()
+        .stdout(predicate::str::contains("github: would remove 2 entries"));
+
+    let cache = ApiCache::open(cache_path).unwrap();
+    assert_eq!(cache.stats().unwrap().total_entries, 2);
 }
 
 #[test]
-fn intake_uses_named_config_period_and_records_report_window() {
+fn cache_clean_all_requires_yes_and_preserves_unrelated_files() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
+    let cache_dir = tmp.path().join(".cache");
+    let cache_path = seed_github_cache(&cache_dir);
+    let unrelated = cache_dir.join("notes.txt");
+    std::fs::write(&unrelated, "not a cache database").unwrap();
 
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[periods."review-cycle"]
-since = "2026-01-01"
-until = "2026-07-01"
+    shiplog_cmd()
+        .args([
+            "cache",
+            "clean",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
+            "--all",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cache clean --all requires --yes"));
 
-[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
-"#,
-    )
-    .unwrap();
+    assert_eq!(
+        ApiCache::open(&cache_path)
+            .unwrap()
+            .stats()
+            .unwrap()
+            .total_entries,
+        2
+    );
 
     shiplog_cmd()
         .args([
-            "intake",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--out",
-            out.to_str().unwrap(),
-            "--period",
-            "review-cycle",
-            "--no-open",
+            "cache",
+            "clean",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+            "--source",
+            "github",
+            "--all",
+            "--yes",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Review intake complete."));
+        .stdout(predicate::str::contains("github: removed 2 entries"));
 
-    let run_dir = first_run_dir(&out);
-    let (_, report) = assert_golden_intake_report(&run_dir, "Ready for review");
-    assert_eq!(report["period"], "review-cycle");
-    assert_eq!(report["window"]["since"], "2026-01-01");
-    assert_eq!(report["window"]["until"], "2026-07-01");
+    assert!(
+        cache_path.exists(),
+        "clean should not delete cache database files"
+    );
+    assert!(
+        unrelated.exists(),
+        "clean should not delete unrelated files"
+    );
     assert_eq!(
-        report["window"]["label"],
-        "review-cycle (2026-01-01..2026-07-01)"
+        ApiCache::open(cache_path)
+            .unwrap()
+            .stats()
+            .unwrap()
+            .total_entries,
+        0
     );
 }
 
+// ── 5. collect json with sample fixture data ───────────────────────────────
+
 #[test]
-fn collect_json_all_source_fixture_packet_is_coherent() {
+fn collect_json_produces_all_outputs() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
-    let events_path = tmp.path().join("ledger.events.jsonl");
-    let coverage_path = tmp.path().join("coverage.manifest.json");
-    let events = all_source_fixture_events();
-    let coverage = all_source_fixture_coverage();
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
+    let fixtures = fixture_dir();
 
-    shiplog_cmd()
+    let mut cmd = shiplog_cmd();
+    cmd.env_remove("SHIPLOG_REDACT_KEY")
         .args([
             "collect",
             "--out",
-            out.to_str().unwrap(),
+            tmp.path().to_str().unwrap(),
             "json",
             "--events",
-            events_path.to_str().unwrap(),
+            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
             "--coverage",
-            coverage_path.to_str().unwrap(),
-            "--user",
-            "octo",
-            "--window-label",
-            "all-source fixture",
+            fixtures.join("coverage.manifest.json").to_str().unwrap(),
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Collected and wrote:"));
+        .success();
 
-    let run_dir = out.join("run_all_sources");
-    assert!(run_dir.join("packet.md").exists(), "missing packet");
+    let run_dir = tmp.path().join("run_fixture");
+    assert!(run_dir.join("packet.md").exists(), "missing packet.md");
     assert!(
         run_dir.join("ledger.events.jsonl").exists(),
-        "missing ledger"
+        "missing ledger.events.jsonl"
     );
     assert!(
         run_dir.join("coverage.manifest.json").exists(),
-        "missing coverage"
+        "missing coverage.manifest.json"
     );
-
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert_packet_opens_with_coverage(&packet);
-    assert_packet_uses_summary_appendix(&packet);
-
-    for expected in [
-        "- GitHub: 2 events",
-        "- GitLab: 1 event",
-        "- Jira: 1 event",
-        "- Linear: 1 event",
-        "- Local git: 1 event",
-        "- Manual: 1 event",
-        "- JSON import: 1 event",
-    ] {
-        assert!(
-            packet.contains(expected),
-            "all-source packet should include source summary line {expected:?}"
-        );
-    }
-
     assert!(
-        packet.contains("Skipped:\n- None recorded\n"),
-        "all-source packet should make absence of skipped sources explicit"
+        run_dir.join("workstreams.suggested.yaml").exists(),
+        "missing workstreams.suggested.yaml"
     );
     assert!(
-        packet.contains("Known gaps:\n- Manual events are user-provided\n"),
-        "all-source packet should keep user-provided manual evidence visible as a gap"
+        run_dir.join("bundle.manifest.json").exists(),
+        "missing bundle.manifest.json"
     );
     assert!(
-        packet.contains(
-            "- **Sources:** GitHub, GitLab, Jira, Linear, Local git, Manual, JSON import"
-        ),
-        "all-source packet should carry readable source details"
+        !run_dir.join("profiles/manager/packet.md").exists(),
+        "manager profile should require an explicit redaction key"
     );
-
-    for title in [
-        "GitHub release automation",
-        "GitLab self-hosted deploy fix",
-        "Jira OPS-42 rollout checklist",
-        "Linear issue triage",
-        "Local git hotfix commit",
-        "Manual customer debugging note",
-        "Imported architecture decision",
-    ] {
-        assert!(
-            packet.contains(title),
-            "all-source packet should include evidence title {title:?}"
-        );
-    }
     assert!(
-        packet.contains("[Review] approved"),
-        "all-source packet should include the review receipt"
-    );
+        !run_dir.join("profiles/public/packet.md").exists(),
+        "public profile should require an explicit redaction key"
+    );
+}
+
+#[test]
+fn merge_existing_runs_writes_combined_packet() {
+    let json_tmp = TempDir::new().unwrap();
+    let manual_tmp = TempDir::new().unwrap();
+    let merge_tmp = TempDir::new().unwrap();
+
+    let json_run = collect_json_into(json_tmp.path());
+    let manual_run = collect_manual_into(manual_tmp.path());
+
+    shiplog_cmd()
+        .args([
+            "merge",
+            "--out",
+            merge_tmp.path().to_str().unwrap(),
+            "--input",
+            json_run.to_str().unwrap(),
+            "--input",
+            manual_run.to_str().unwrap(),
+            "--conflict",
+            "prefer-most-recent",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Merged and wrote:"))
+        .stdout(predicate::str::contains("- inputs: 2"));
 
+    let run_dir = first_run_dir(merge_tmp.path());
+    assert!(run_dir.join("packet.md").exists(), "missing merged packet");
     assert!(
-        packet.contains("**Suggested claim prompts**"),
-        "all-source packet should include claim prompts for review-cycle writing"
+        run_dir.join("ledger.events.jsonl").exists(),
+        "missing merged ledger"
     );
     assert!(
-        packet.contains("workstreams.suggested.yaml"),
-        "all-source packet should name generated workstream suggestions"
+        run_dir.join("coverage.manifest.json").exists(),
+        "missing merged coverage"
     );
+
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
     assert!(
-        packet.contains("bundle.manifest.json"),
-        "all-source packet should name the generated bundle manifest"
+        packet.contains("Payments ledger rewrite"),
+        "merged packet should include JSON fixture evidence"
+    );
+    assert!(
+        packet.contains("Manual incident follow-up"),
+        "merged packet should include manual evidence"
     );
 
-    let written_coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    for source in [
-        "\"github\"",
-        "\"gitlab\"",
-        "\"jira\"",
-        "\"linear\"",
-        "\"local_git\"",
-        "\"manual\"",
-        "\"json_import\"",
-    ] {
-        assert!(
-            written_coverage.contains(source),
-            "written coverage should preserve source {source}"
-        );
-    }
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    assert!(
+        coverage.contains("\"github\""),
+        "merged coverage should include github source"
+    );
+    assert!(
+        coverage.contains("\"manual\""),
+        "merged coverage should include manual source"
+    );
 }
 
 #[test]
-fn collect_multi_uses_config_default_out_when_cli_out_is_omitted() {
+fn collect_multi_exits_with_coverage_code_when_requirements_are_unmet() {
     let tmp = TempDir::new().unwrap();
-    let configured_out = tmp.path().join("configured-out");
+    let out = tmp.path().join("out");
     let fixtures = fixture_dir();
     std::fs::copy(
         fixtures.join("ledger.events.jsonl"),
         tmp.path().join("ledger.events.jsonl"),
     )
     .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
+    let coverage = std::fs::read_to_string(fixtures.join("coverage.manifest.json"))
+        .unwrap()
+        .replace("\"fetched\": 3", "\"fetched\": 1")
+        .replace("\"Complete\"", "\"Partial\"");
+    std::fs::write(tmp.path().join("coverage.manifest.json"), coverage).unwrap();
     write_manual_events(&tmp.path().join("manual_events.yaml"));
-
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-out = "./configured-out"
+    let config = |requirements: &str| {
+        std::fs::write(
+            tmp.path().join("shiplog.toml"),
+            format!(
+                r#"[defaults]
 window = "year:2025"
 
+[user]
+label = "octo"
+
+{requirements}
+
 [sources.json]
 enabled = true
 events = "./ledger.events.jsonl"
@@ -10087,82 +10287,70 @@ coverage = "./coverage.manifest.json"
 enabled = true
 events = "./manual_events.yaml"
 user = "octo"
-"#,
-    )
-    .unwrap();
-
-    shiplog_cmd()
-        .current_dir(tmp.path())
-        .args(["collect", "multi"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Collected configured sources:"));
-
-    let run_dir = first_run_dir(&configured_out);
-    assert!(run_dir.join("packet.md").exists(), "missing merged packet");
-    assert!(
-        !tmp.path().join("out").exists(),
-        "collect multi should not write ./out when defaults.out is set"
-    );
-}
-
-#[test]
-fn collect_multi_uses_config_default_profile_for_redaction_safety() {
-    let tmp = TempDir::new().unwrap();
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
-
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-profile = "public"
-window = "year:2025"
-
-[redaction]
-key_env = "SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG"
+"#
+            ),
+        )
+        .unwrap();
+    };
+    let collect = || {
+        let mut cmd = shiplog_cmd();
+        cmd.args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "multi",
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+        ]);
+        cmd
+    };
 
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-user = "octo"
-"#,
-    )
-    .unwrap();
+    config("[coverage.sources.manual]\nrequire_complete = true");
+    collect().assert().success();
 
-    shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG")
-        .args(["collect", "multi"])
+    config("[coverage]\nrequire_complete = true\n\n[coverage.sources.json]\nmin_score = 90");
+    collect()
         .assert()
-        .failure()
-        .stderr(
-            predicate::str::contains(
-                "public profile requires --redact-key or SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG",
-            )
-            .and(predicate::str::contains("Try:"))
-            .and(predicate::str::contains(redaction_key_env_command(
-                "SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG",
-            )))
-            .and(predicate::str::contains(
-                "rerun this command with --bundle-profile public",
-            )),
-        );
+        .code(3)
+        .stdout(predicate::str::contains("Merged and wrote:"))
+        .stderr(predicate::str::contains("Coverage requirements not met:"))
+        .stderr(predicate::str::contains(
+            "- Run: completeness is Partial, Complete required",
+        ))
+        .stderr(predicate::str::contains("score 33/100 is below 90"))
+        .stderr(predicate::str::contains("coverage.report.md"));
 }
 
 #[test]
-fn collect_multi_records_partial_source_failures() {
+fn collect_multi_from_config_merges_json_and_manual_sources() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
     write_manual_events(&tmp.path().join("manual_events.yaml"));
 
     std::fs::write(
         tmp.path().join("shiplog.toml"),
         r#"[defaults]
 window = "year:2025"
+include_reviews = true
+
+[user]
+label = "octo"
 
 [sources.json]
 enabled = true
-events = "./missing-ledger.events.jsonl"
-coverage = "./missing-coverage.manifest.json"
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
 
 [sources.manual]
 enabled = true
@@ -10183,70 +10371,88 @@ user = "octo"
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("- json: skipped"))
-        .stdout(predicate::str::contains("- manual: success"))
+        .stdout(predicate::str::contains("Collected configured sources:"))
+        .stdout(predicate::str::contains("- json: success"))
+        .stdout(predicate::str::contains("- manual: success, 1 event"))
         .stdout(predicate::str::contains("Merged and wrote:"));
 
     let run_dir = first_run_dir(&out);
+    assert!(run_dir.join("packet.md").exists(), "missing merged packet");
+    assert!(
+        run_dir.join("ledger.events.jsonl").exists(),
+        "missing merged ledger"
+    );
+    assert!(
+        run_dir.join("coverage.manifest.json").exists(),
+        "missing merged coverage"
+    );
+
     let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert_packet_opens_with_coverage(&packet);
+    assert_packet_uses_summary_appendix(&packet);
     assert!(
-        packet.contains("Manual incident follow-up"),
-        "configured multi packet should include successful source evidence"
+        packet.contains("Included:\n- GitHub: 3 events\n- Manual: 1 event\n"),
+        "configured multi packet should summarize successful sources in Coverage and Limits"
     );
     assert!(
-        packet.contains("Skipped:\n- JSON:"),
-        "configured multi packet should list skipped sources in the packet coverage summary"
+        packet.contains("Skipped:\n- None recorded\n"),
+        "configured multi packet should make absence of skipped sources explicit"
     );
+    let known_gaps = packet
+        .split("Known gaps:\n")
+        .nth(1)
+        .and_then(|rest| rest.split("\n\n").next())
+        .expect("configured multi packet should list known gaps");
     assert!(
-        !packet
-            .split("Included:")
-            .nth(1)
-            .unwrap()
-            .split("Skipped:")
-            .next()
-            .unwrap()
-            .contains("JSON"),
-        "configured multi packet should not list skipped sources as included"
+        known_gaps.contains("- Manual events are user-provided"),
+        "configured multi packet should flag manual evidence as user-provided"
     );
-
-    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
     assert!(
-        coverage.contains("\"Partial\""),
-        "configured multi coverage should mark skipped sources as partial"
+        known_gaps.contains("- Activity gap: no events from any source"),
+        "year window over quarter-only fixtures should flag empty weeks"
     );
     assert!(
-        coverage.contains("Configured source json was skipped"),
-        "configured multi coverage should record skipped source warning"
+        packet.contains("- **Sources:** GitHub, Manual"),
+        "configured multi packet should carry merged source details"
     );
-
-    let source_failures_path = run_dir.join("source.failures.json");
     assert!(
-        source_failures_path.exists(),
-        "configured multi should write source failure receipts"
+        packet.contains("Payments ledger rewrite"),
+        "configured multi packet should include JSON fixture evidence"
     );
-    let source_failures: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(source_failures_path).unwrap()).unwrap();
-    assert_eq!(source_failures["schema_version"], 1);
-    assert_eq!(source_failures["failures"].as_array().unwrap().len(), 1);
-    assert_eq!(source_failures["failures"][0]["source"], "json");
-    assert_eq!(source_failures["failures"][0]["kind"], "missing_file");
     assert!(
-        source_failures["failures"][0]["rerun_command"]
-            .as_str()
-            .unwrap()
-            .contains("shiplog intake --config"),
-        "source failure receipt should include a safe rerun command"
+        packet.contains("Manual incident follow-up"),
+        "configured multi packet should include manual evidence"
     );
     assert!(
-        std::fs::read_to_string(run_dir.join("bundle.manifest.json"))
-            .unwrap()
-            .contains("source.failures.json"),
-        "bundle manifest should receipt the source failure artifact"
+        packet.contains("workstreams.suggested.yaml"),
+        "configured multi packet should name generated workstream suggestions"
     );
-}
+    assert!(
+        packet.contains("**Suggested claim prompts**"),
+        "configured multi packet should include claim prompts for review-cycle writing"
+    );
+    assert!(
+        packet.contains("bundle.manifest.json"),
+        "configured multi packet should name the generated bundle manifest"
+    );
+    assert!(
+        !packet.contains("manual_events.yaml"),
+        "configured multi packet should not list manual_events.yaml as a generated run artifact"
+    );
+
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    assert!(
+        coverage.contains("\"github\""),
+        "configured multi coverage should include JSON fixture source"
+    );
+    assert!(
+        coverage.contains("\"manual\""),
+        "configured multi coverage should include manual source"
+    );
+}
 
 #[test]
-fn intake_from_config_writes_packet_and_review_next_steps() {
+fn collect_multi_uses_named_config_period() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
     let fixtures = fixture_dir();
@@ -10260,603 +10466,567 @@ fn intake_from_config_writes_packet_and_review_next_steps() {
         tmp.path().join("coverage.manifest.json"),
     )
     .unwrap();
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
 
     std::fs::write(
         tmp.path().join("shiplog.toml"),
         r#"[defaults]
 window = "year:2025"
-include_reviews = true
 
-[user]
-label = "octo"
+[periods."review-cycle"]
+since = "2026-01-01"
+until = "2026-07-01"
 
 [sources.json]
 enabled = true
 events = "./ledger.events.jsonl"
 coverage = "./coverage.manifest.json"
-
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-user = "octo"
 "#,
     )
     .unwrap();
 
-    let assert = shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
+    shiplog_cmd()
         .args([
-            "intake",
+            "collect",
             "--out",
             out.to_str().unwrap(),
+            "multi",
             "--config",
             tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--last-6-months",
-            "--no-open",
+            "--period",
+            "review-cycle",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-
-    assert!(stdout.contains("Review intake complete."));
-    assert!(stdout.contains("- JSON: success"));
-    assert!(stdout.contains("- Manual: success"));
-    assert!(stdout.contains("Evidence debt:"));
-    assert!(stdout.contains("Intake readiness:"));
-    assert!(stdout.contains("Intake status: Needs curation"));
-    assert!(stdout.contains("Packet readiness: Ready with caveats"));
-    assert!(stdout.contains("- Packet rendered"));
-    assert!(stdout.contains("- Review inspection completed"));
-    assert!(stdout.contains("shiplog share explain manager --out"));
-    assert!(!stdout.contains("--bundle-profile manager"));
-    assert!(stdout.contains("Open:"));
-    assert!(stdout.contains("Open later:"));
-    assert_eq!(
-        stdout.lines().filter(|line| *line == "Next:").count(),
-        1,
-        "intake should print one top-level next-action block. stdout:\n{stdout}"
-    );
+        .success()
+        .stdout(predicate::str::contains("Collected configured sources:"));
 
     let run_dir = first_run_dir(&out);
-    assert!(run_dir.join("packet.md").exists(), "missing intake packet");
-    assert!(
-        run_dir.join("ledger.events.jsonl").exists(),
-        "missing intake ledger"
-    );
-    assert!(
-        run_dir.join("coverage.manifest.json").exists(),
-        "missing intake coverage"
-    );
-    assert!(
-        run_dir.join("workstreams.suggested.yaml").exists(),
-        "missing intake workstream suggestions"
-    );
-    assert!(
-        run_dir.join("bundle.manifest.json").exists(),
-        "missing intake bundle manifest"
-    );
+    let coverage: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(coverage["window"]["since"], "2026-01-01");
+    assert_eq!(coverage["window"]["until"], "2026-07-01");
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert_packet_opens_with_coverage(&packet);
-    assert!(
-        packet.contains("- GitHub: 3 events"),
-        "intake packet should include successful configured sources"
-    );
+    shiplog_cmd()
+        .args([
+            "review",
+            "--out",
+            out.to_str().unwrap(),
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--period",
+            "review-cycle",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Window: 2026-01-01..2026-07-01"))
+        .stdout(predicate::str::contains("Evidence debt:"));
 }
 
 #[test]
-fn golden_intake_manual_only_success_is_review_ready() {
+fn collect_multi_cli_dates_override_named_period() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
 
     std::fs::write(
         tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-window = "year:2025"
-
-[user]
-label = "octo"
+        r#"[periods."review-cycle"]
+since = "2026-01-01"
+until = "2026-07-01"
 
-[sources.manual]
+[sources.json]
 enabled = true
-events = "./manual_events.yaml"
-user = "octo"
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
 "#,
     )
     .unwrap();
 
-    let assert = shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
+    shiplog_cmd()
         .args([
-            "intake",
+            "collect",
             "--out",
             out.to_str().unwrap(),
+            "multi",
             "--config",
             tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--period",
+            "review-cycle",
             "--year",
             "2025",
-            "--no-open",
-            "--explain",
         ])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-
-    assert!(stdout.contains("Review intake complete."));
-    assert!(stdout.contains("- Manual: success, 1 event"));
-    assert!(stdout.contains("Skipped:\n- None"));
-    assert!(stdout.contains("Source decisions:"));
-    assert!(stdout.contains("- Manual: included, manual_events.yaml found"));
-    assert!(stdout.contains("Intake readiness:"));
-    assert!(stdout.contains("Intake status: Ready for review"));
-    assert!(stdout.contains("Packet readiness: Ready with caveats"));
-    assert!(stdout.contains("Needs attention:\n- None"));
-    assert!(stdout.contains("shiplog share explain manager --out"));
-    assert!(!stdout.contains("--bundle-profile manager"));
-    assert!(stdout.contains("shiplog open packet --out"));
 
     let run_dir = first_run_dir(&out);
-    assert_intake_artifacts(&run_dir);
-    assert_ledger_event_count(&run_dir, 1);
+    let coverage: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(coverage["window"]["since"], "2025-01-01");
+    assert_eq!(coverage["window"]["until"], "2026-01-01");
+}
 
-    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Ready for review");
-    assert!(report_md.contains("Intake status: **Ready for review**"));
-    assert!(report_md.contains("Packet readiness: **Ready with caveats**"));
-    assert!(report_md.contains("- Manual: 1 event"));
-    assert!(report_md.contains("## Share Commands"));
-    assert!(report_md.contains("shiplog share manager"));
-    assert!(report_md.contains("SHIPLOG_REDACT_KEY"));
+#[test]
+fn collect_multi_unknown_period_fails_clearly() {
+    let tmp = TempDir::new().unwrap();
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[sources.json]
+enabled = true
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
+"#,
+    )
+    .unwrap();
 
-    assert_eq!(report_json["included_sources"][0]["source"], "manual");
-    assert_eq!(report_json["included_sources"][0]["source_key"], "manual");
-    assert_eq!(report_json["included_sources"][0]["source_label"], "Manual");
-    assert_eq!(report_json["included_sources"][0]["event_count"], 1);
-    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 0);
-    assert!(
-        !report_json["top_fixups"].as_array().unwrap().is_empty(),
-        "manual-only intake should keep fixups visible in the report"
-    );
-    let evidence_debt = report_json["evidence_debt"].as_array().unwrap();
-    assert!(
-        evidence_debt
-            .iter()
-            .any(|item| item["kind"] == "manual-only-workstream"),
-        "intake report JSON should include packet-quality evidence debt"
-    );
-    assert!(
-        evidence_debt.iter().all(|item| item["next_step"]
-            .as_str()
-            .is_some_and(|step| !step.is_empty())),
-        "intake report evidence debt should include actionable next steps"
-    );
-    assert!(
-        report_json["share_commands"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .any(|command| command.as_str().unwrap().contains("shiplog share manager"))
-    );
-    let claim_candidates = report_json["packet_quality"]["claim_candidates"]
-        .as_array()
-        .expect("packet_quality should expose claim_candidates");
-    assert!(
-        claim_candidates.iter().any(|candidate| {
-            candidate["evidence_strength"].as_str() == Some("manual_only")
-                && candidate["supporting_sources"]
-                    .as_array()
-                    .is_some_and(|sources| {
-                        sources
-                            .iter()
-                            .any(|source| source.as_str() == Some("manual"))
-                    })
-                && candidate["missing_context_prompts"]
-                    .as_array()
-                    .is_some_and(|prompts| !prompts.is_empty())
-                && candidate["safe_profiles"]
-                    .as_array()
-                    .is_some_and(|profiles| {
-                        profiles
-                            .iter()
-                            .any(|profile| profile.as_str() == Some("manager"))
-                    })
-        }),
-        "manual-only intake should emit a conservative claim candidate with prompts"
-    );
-
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert_packet_opens_with_coverage(&packet);
-    insta::assert_snapshot!(
-        "manual_only_review_ready_packet_surface",
-        review_ready_packet_surface(
-            &packet,
-            out.to_str().expect("--out path should be utf-8"),
-            run_dir
-                .file_name()
-                .and_then(|name| name.to_str())
-                .expect("run directory should have a utf-8 name")
-        )
-    );
-    assert!(
-        packet.contains("Ready with caveats.") && packet.contains("manual_only"),
-        "manual-only packet should surface readiness caveats in packet.md"
-    );
-    assert_packet_quality_report_matches_packet(&report_json, &packet);
-    assert!(
-        packet.contains("# Claim Candidates")
-            && packet.contains("Evidence strength: `manual_only`")
-            && packet.contains("Missing context:"),
-        "manual-only packet should render claim candidates with missing-context prompts"
-    );
-    assert!(packet.contains("- Manual: 1 event"));
-    assert!(packet.contains("Skipped:\n- None recorded\n"));
-    assert!(packet.contains("Known gaps:\n- Manual events are user-provided\n"));
-    assert!(packet.contains("Manual incident follow-up"));
-
-    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    assert!(coverage.contains("\"Complete\""));
-    assert!(coverage.contains("\"manual\""));
+    shiplog_cmd()
+        .args([
+            "collect",
+            "multi",
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--period",
+            "missing",
+        ])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("unknown period")
+                .and(predicate::str::contains("[periods.\"missing\"]")),
+        );
 }
 
 #[test]
-fn intake_journal_suggestions_use_configured_manual_events_path() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn intake_uses_named_config_period_and_records_report_window() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let config_dir = tmp.path().join("config");
-    let manual_dir = config_dir.join("journal");
-    std::fs::create_dir_all(&manual_dir)?;
-    let config_path = config_dir.join("shiplog.toml");
-    let events_path = config_dir.join("json.events.jsonl");
-    let coverage_path = config_dir.join("json.coverage.json");
-    let manual_events = manual_dir.join("manual_events.yaml");
-    let events: Vec<_> = (0..10)
-        .map(|idx| {
-            fixture_pr_event(
-                SourceSystem::Github,
-                "acme/platform",
-                700 + idx as u64,
-                &format!("Platform reliability fix {}", idx + 1),
-                2 + idx,
-            )
-        })
-        .collect();
-    let coverage = CoverageManifest {
-        run_id: RunId("run_configured_manual_next_steps".into()),
-        generated_at: fixture_time(20),
-        user: "octo".into(),
-        window: fixture_window(),
-        mode: "fixture".into(),
-        sources: vec!["github".into(), "manual".into()],
-        slices: vec![CoverageSlice {
-            window: fixture_window(),
-            query: "github fixture".into(),
-            total_count: events.len() as u64,
-            fetched: events.len() as u64,
-            incomplete_results: Some(false),
-            notes: vec!["fixture".into()],
-        }],
-        warnings: vec![],
-        completeness: Completeness::Complete,
-    };
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
-    std::fs::write(
-        &manual_events,
-        "version: 1\ngenerated_at: 2026-01-01T00:00:00Z\nevents: []\n",
-    )?;
-    std::fs::write(
-        &config_path,
-        r#"[defaults]
-window = "year:2025"
-include_reviews = true
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
 
-[user]
-label = "octo"
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[periods."review-cycle"]
+since = "2026-01-01"
+until = "2026-07-01"
 
 [sources.json]
 enabled = true
-events = "./json.events.jsonl"
-coverage = "./json.coverage.json"
-
-[sources.manual]
-enabled = true
-events = "./journal/manual_events.yaml"
-user = "octo"
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
 "#,
-    )?;
+    )
+    .unwrap();
 
-    let assert = shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
+    shiplog_cmd()
         .args([
             "intake",
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
             "--out",
             out.to_str().unwrap(),
-            "--config",
-            config_path.to_str().unwrap(),
-            "--year",
-            "2025",
+            "--period",
+            "review-cycle",
             "--no-open",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    let manual_events_arg = manual_events.display().to_string();
+        .success()
+        .stdout(predicate::str::contains("Review intake complete."));
+
+    let run_dir = first_run_dir(&out);
+    let (_, report) = assert_golden_intake_report(&run_dir, "Ready for review");
+    assert_eq!(report["period"], "review-cycle");
+    assert_eq!(report["window"]["since"], "2026-01-01");
+    assert_eq!(report["window"]["until"], "2026-07-01");
+    assert_eq!(
+        report["window"]["label"],
+        "review-cycle (2026-01-01..2026-07-01)"
+    );
+}
+
+#[test]
+fn collect_json_all_source_fixture_packet_is_coherent() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    let events_path = tmp.path().join("ledger.events.jsonl");
+    let coverage_path = tmp.path().join("coverage.manifest.json");
+    let events = all_source_fixture_events();
+    let coverage = all_source_fixture_coverage();
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "json",
+            "--events",
+            events_path.to_str().unwrap(),
+            "--coverage",
+            coverage_path.to_str().unwrap(),
+            "--user",
+            "octo",
+            "--window-label",
+            "all-source fixture",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Collected and wrote:"));
 
+    let run_dir = out.join("run_all_sources");
+    assert!(run_dir.join("packet.md").exists(), "missing packet");
     assert!(
-        stdout.contains("- JSON collected 10 events"),
-        "Good section should keep positive evidence contributions visible. stdout:\n{stdout}"
+        run_dir.join("ledger.events.jsonl").exists(),
+        "missing ledger"
     );
     assert!(
-        !stdout.contains("- Manual collected 0 events"),
-        "Good section should not describe a zero-event source as positive evidence. stdout:\n{stdout}"
+        run_dir.join("coverage.manifest.json").exists(),
+        "missing coverage"
     );
-    assert!(stdout.contains("shiplog journal add --date"));
-    assert!(stdout.contains("--events"));
-    assert!(stdout.contains(&manual_events_arg));
 
-    let run_dir = first_run_dir(&out);
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md"))?;
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json"))?;
-    let report_json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
-        run_dir.join("intake.report.json"),
-    )?)?;
-    let good = report_json["good"].as_array().unwrap();
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert_packet_opens_with_coverage(&packet);
+    assert_packet_uses_summary_appendix(&packet);
+
+    for expected in [
+        "- GitHub: 2 events",
+        "- GitLab: 1 event",
+        "- Jira: 1 event",
+        "- Linear: 1 event",
+        "- Local git: 1 event",
+        "- Manual: 1 event",
+        "- JSON import: 1 event",
+    ] {
+        assert!(
+            packet.contains(expected),
+            "all-source packet should include source summary line {expected:?}"
+        );
+    }
+
     assert!(
-        good.iter()
-            .any(|item| item.as_str() == Some("JSON collected 10 events")),
-        "intake report should keep positive source evidence in good={good:?}"
+        packet.contains("Skipped:\n- None recorded\n"),
+        "all-source packet should make absence of skipped sources explicit"
     );
     assert!(
-        good.iter().all(|item| item
-            .as_str()
-            .is_none_or(|text| !text.contains("Manual collected 0 events"))),
-        "intake report good array should not call zero-event manual evidence good={good:?}"
+        packet.contains("Known gaps:\n- Manual events are user-provided\n"),
+        "all-source packet should keep user-provided manual evidence visible as a gap"
     );
-    let next_commands = report_json["next_commands"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter_map(|command| command.as_str())
-        .collect::<Vec<_>>();
     assert!(
-        next_commands
-            .first()
-            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
-        "repairable intake should route the top-level handoff through repair plan first: {next_commands:?}"
+        packet.contains(
+            "- **Sources:** GitHub, GitLab, Jira, Linear, Local git, Manual, JSON import"
+        ),
+        "all-source packet should carry readable source details"
     );
+
+    for title in [
+        "GitHub release automation",
+        "GitLab self-hosted deploy fix",
+        "Jira OPS-42 rollout checklist",
+        "Linear issue triage",
+        "Local git hotfix commit",
+        "Manual customer debugging note",
+        "Imported architecture decision",
+    ] {
+        assert!(
+            packet.contains(title),
+            "all-source packet should include evidence title {title:?}"
+        );
+    }
     assert!(
-        next_commands
-            .iter()
-            .all(|command| !command.contains("shiplog journal add --date")),
-        "top-level next commands should not bypass repair items with direct journal add commands: {next_commands:?}"
+        packet.contains("[Review] approved"),
+        "all-source packet should include the review receipt"
     );
+
     assert!(
-        next_commands
-            .iter()
-            .all(|command| !command.contains("shiplog workstreams split ")),
-        "top-level next commands should not advertise write-producing workstream edits while repair plan is the first handoff: {next_commands:?}"
+        packet.contains("**Suggested claim prompts**"),
+        "all-source packet should include claim prompts for review-cycle writing"
     );
-    for (field, commands) in [
-        (
-            "top_fixups",
-            report_json["top_fixups"]
-                .as_array()
-                .unwrap()
-                .iter()
-                .filter_map(|fixup| fixup["command"].as_str())
-                .collect::<Vec<_>>(),
-        ),
-        (
-            "evidence_debt",
-            report_json["evidence_debt"]
-                .as_array()
-                .unwrap()
-                .iter()
-                .filter_map(|debt| debt["next_step"].as_str())
-                .collect::<Vec<_>>(),
-        ),
+    assert!(
+        packet.contains("workstreams.suggested.yaml"),
+        "all-source packet should name generated workstream suggestions"
+    );
+    assert!(
+        packet.contains("bundle.manifest.json"),
+        "all-source packet should name the generated bundle manifest"
+    );
+
+    let written_coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    for source in [
+        "\"github\"",
+        "\"gitlab\"",
+        "\"jira\"",
+        "\"linear\"",
+        "\"local_git\"",
+        "\"manual\"",
+        "\"json_import\"",
     ] {
-        let journal_commands: Vec<_> = commands
-            .into_iter()
-            .filter(|command| command.contains("shiplog journal add --date"))
-            .collect();
         assert!(
-            !journal_commands.is_empty(),
-            "{field} should include at least one direct journal add suggestion"
+            written_coverage.contains(source),
+            "written coverage should preserve source {source}"
         );
-        for command in journal_commands {
-            assert!(
-                command.contains("--events") && command.contains(&manual_events_arg),
-                "{field} journal command should target configured manual events file: {command}"
-            );
-        }
     }
+}
 
-    let review_assert = shiplog_cmd()
-        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
-        .assert()
-        .success();
-    let review_stdout = String::from_utf8(review_assert.get_output().stdout.clone())?;
-    assert!(review_stdout.contains("shiplog journal add --date"));
-    assert!(review_stdout.contains("--events"));
-    assert!(review_stdout.contains(&manual_events_arg));
+#[test]
+fn collect_multi_uses_config_default_out_when_cli_out_is_omitted() {
+    let tmp = TempDir::new().unwrap();
+    let configured_out = tmp.path().join("configured-out");
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
 
-    let fixups_assert = shiplog_cmd()
-        .args([
-            "review",
-            "fixups",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--commands-only",
-        ])
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+out = "./configured-out"
+window = "year:2025"
+
+[sources.json]
+enabled = true
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#,
+    )
+    .unwrap();
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["collect", "multi"])
         .assert()
-        .success();
-    let fixups_stdout = String::from_utf8(fixups_assert.get_output().stdout.clone())?;
-    assert!(fixups_stdout.contains("shiplog journal add --date"));
-    assert!(fixups_stdout.contains("--events"));
-    assert!(fixups_stdout.contains(&manual_events_arg));
+        .success()
+        .stdout(predicate::str::contains("Collected configured sources:"));
 
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md"))?
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json"))?
+    let run_dir = first_run_dir(&configured_out);
+    assert!(run_dir.join("packet.md").exists(), "missing merged packet");
+    assert!(
+        !tmp.path().join("out").exists(),
+        "collect multi should not write ./out when defaults.out is set"
     );
+}
 
-    Ok(())
+#[test]
+fn collect_multi_uses_config_default_profile_for_redaction_safety() {
+    let tmp = TempDir::new().unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+profile = "public"
+window = "year:2025"
+
+[redaction]
+key_env = "SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#,
+    )
+    .unwrap();
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG")
+        .args(["collect", "multi"])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains(
+                "public profile requires --redact-key or SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG",
+            )
+            .and(predicate::str::contains("Try:"))
+            .and(predicate::str::contains(redaction_key_env_command(
+                "SHIPLOG_TEST_REDACT_KEY_FOR_CONFIG",
+            )))
+            .and(predicate::str::contains(
+                "rerun this command with --bundle-profile public",
+            )),
+        );
 }
 
 #[test]
-fn golden_intake_all_source_fixture_surfaces_every_source_without_network() {
+fn collect_multi_records_partial_source_failures() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let events_path = tmp.path().join("all-source.events.jsonl");
-    let coverage_path = tmp.path().join("all-source.coverage.json");
-    let events = all_source_fixture_events();
-    let coverage = all_source_fixture_coverage();
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
 
     std::fs::write(
         tmp.path().join("shiplog.toml"),
         r#"[defaults]
 window = "year:2025"
-include_reviews = true
-
-[user]
-label = "octo"
 
 [sources.json]
 enabled = true
-events = "./all-source.events.jsonl"
-coverage = "./all-source.coverage.json"
+events = "./missing-ledger.events.jsonl"
+coverage = "./missing-coverage.manifest.json"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
 "#,
     )
     .unwrap();
 
-    let assert = shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
+    shiplog_cmd()
         .args([
-            "intake",
+            "collect",
             "--out",
             out.to_str().unwrap(),
+            "multi",
             "--config",
             tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--year",
-            "2025",
-            "--no-open",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-
-    assert!(stdout.contains("Review intake complete."));
-    assert!(stdout.contains("- JSON: success, 8 events"));
-    assert!(stdout.contains("Skipped:\n- None"));
-    assert!(stdout.contains("Intake readiness:"));
-    assert!(stdout.contains("Good:"));
-    assert!(stdout.contains("- Packet rendered"));
-    assert!(stdout.contains("- Evidence ledger written"));
-    assert!(stdout.contains("- Coverage manifest written"));
-    assert!(stdout.contains("shiplog share explain manager --out"));
-    assert!(!stdout.contains("--bundle-profile manager"));
-    assert!(stdout.contains("shiplog open packet --out"));
+        .success()
+        .stdout(predicate::str::contains("- json: skipped"))
+        .stdout(predicate::str::contains("- manual: success"))
+        .stdout(predicate::str::contains("Merged and wrote:"));
 
     let run_dir = first_run_dir(&out);
-    assert_intake_artifacts(&run_dir);
-    assert_ledger_event_count(&run_dir, 8);
-    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Ready for review");
-    assert!(report_md.contains("- JSON: 8 events"));
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
     assert!(
-        report_json["included_sources"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .any(|source| source["source_key"] == "json"
-                && source["source"] == "json"
-                && source["source_label"] == "JSON"
-                && source["event_count"] == 8)
+        packet.contains("Manual incident follow-up"),
+        "configured multi packet should include successful source evidence"
     );
-    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 0);
     assert!(
-        report_json["share_commands"]
-            .as_array()
+        packet.contains("Skipped:\n- JSON:"),
+        "configured multi packet should list skipped sources in the packet coverage summary"
+    );
+    assert!(
+        !packet
+            .split("Included:")
+            .nth(1)
             .unwrap()
-            .iter()
-            .any(|command| command.as_str().unwrap().contains("shiplog share public")),
-        "all-source intake report should keep share commands visible"
+            .split("Skipped:")
+            .next()
+            .unwrap()
+            .contains("JSON"),
+        "configured multi packet should not list skipped sources as included"
     );
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert_packet_opens_with_coverage(&packet);
-    assert_packet_uses_summary_appendix(&packet);
-
-    for expected in [
-        "- GitHub: 2 events",
-        "- GitLab: 1 event",
-        "- Jira: 1 event",
-        "- Linear: 1 event",
-        "- Local git: 1 event",
-        "- Manual: 1 event",
-        "- JSON import: 1 event",
-        "Skipped:\n- None recorded\n",
-        "Known gaps:\n- Manual events are user-provided\n",
-        "GitHub release automation",
-        "GitLab self-hosted deploy fix",
-        "Jira OPS-42 rollout checklist",
-        "Linear issue triage",
-        "Local git hotfix commit",
-        "Manual customer debugging note",
-        "Imported architecture decision",
-        "**Suggested claim prompts**",
-    ] {
-        assert!(
-            packet.contains(expected),
-            "golden all-source intake packet should contain {expected:?}"
-        );
-    }
-
     let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    for source in [
-        "\"github\"",
-        "\"gitlab\"",
-        "\"jira\"",
-        "\"linear\"",
-        "\"local_git\"",
-        "\"manual\"",
-        "\"json_import\"",
-    ] {
-        assert!(
-            coverage.contains(source),
-            "golden all-source intake coverage should preserve {source}"
-        );
-    }
+    assert!(
+        coverage.contains("\"Partial\""),
+        "configured multi coverage should mark skipped sources as partial"
+    );
+    assert!(
+        coverage.contains("Configured source json was skipped"),
+        "configured multi coverage should record skipped source warning"
+    );
+
+    let source_failures_path = run_dir.join("source.failures.json");
+    assert!(
+        source_failures_path.exists(),
+        "configured multi should write source failure receipts"
+    );
+    let source_failures: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(source_failures_path).unwrap()).unwrap();
+    assert_eq!(source_failures["schema_version"], 1);
+    assert_eq!(source_failures["failures"].as_array().unwrap().len(), 1);
+    assert_eq!(source_failures["failures"][0]["source"], "json");
+    assert_eq!(source_failures["failures"][0]["kind"], "missing_file");
+    assert!(
+        source_failures["failures"][0]["rerun_command"]
+            .as_str()
+            .unwrap()
+            .contains("shiplog intake --config"),
+        "source failure receipt should include a safe rerun command"
+    );
+    assert!(
+        std::fs::read_to_string(run_dir.join("bundle.manifest.json"))
+            .unwrap()
+            .contains("source.failures.json"),
+        "bundle manifest should receipt the source failure artifact"
+    );
 }
 
 #[test]
-fn intake_summary_and_report_skipped_sources_mirror_skipped_decisions() {
+fn intake_from_config_writes_packet_and_review_next_steps() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+window = "year:2025"
+include_reviews = true
+
+[user]
+label = "octo"
+
+[sources.json]
+enabled = true
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#,
+    )
+    .unwrap();
 
     let assert = shiplog_cmd()
         .current_dir(tmp.path())
@@ -10868,103 +11038,97 @@ fn intake_summary_and_report_skipped_sources_mirror_skipped_decisions() {
             "intake",
             "--out",
             out.to_str().unwrap(),
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--last-6-months",
             "--no-open",
-            "--explain",
         ])
         .assert()
         .success();
     let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("Skipped:\n- GitHub: GitHub authentication unavailable"));
-    assert!(
-        !stdout.contains("Skipped:\n- None\n\nSource decisions:\n- GitHub: skipped"),
-        "summary should not say no sources were skipped while source decisions list skipped sources"
+    assert!(stdout.contains("Review intake complete."));
+    assert!(stdout.contains("- JSON: success"));
+    assert!(stdout.contains("- Manual: success"));
+    assert!(stdout.contains("Evidence debt:"));
+    assert!(stdout.contains("Intake readiness:"));
+    assert!(stdout.contains("Intake status: Needs curation"));
+    assert!(stdout.contains("Packet readiness: Ready with caveats"));
+    assert!(stdout.contains("- Packet rendered"));
+    assert!(stdout.contains("- Review inspection completed"));
+    assert!(stdout.contains("shiplog share explain manager --out"));
+    assert!(!stdout.contains("--bundle-profile manager"));
+    assert!(stdout.contains("Open:"));
+    assert!(stdout.contains("Open later:"));
+    assert_eq!(
+        stdout.lines().filter(|line| *line == "Next:").count(),
+        1,
+        "intake should print one top-level next-action block. stdout:\n{stdout}"
     );
 
     let run_dir = first_run_dir(&out);
-    let report_json: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
-            .unwrap();
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage: serde_json::Value = serde_json::from_str(
-        &std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap(),
-    )
-    .unwrap();
-    let decisions = report_json["source_decisions"].as_array().unwrap();
-    let skipped_sources = report_json["skipped_sources"].as_array().unwrap();
-    let skipped_decisions = decisions
-        .iter()
-        .filter(|decision| decision["decision"].as_str() == Some("skipped"))
-        .collect::<Vec<_>>();
-
-    assert_eq!(
-        skipped_sources.len(),
-        skipped_decisions.len(),
-        "skipped_sources should mirror skipped source decisions"
+    assert!(run_dir.join("packet.md").exists(), "missing intake packet");
+    assert!(
+        run_dir.join("ledger.events.jsonl").exists(),
+        "missing intake ledger"
     );
-    for decision in skipped_decisions {
-        let source_key = decision["source_key"].as_str().unwrap();
-        let reason = decision["reason"].as_str().unwrap();
-        assert!(
-            skipped_sources.iter().any(|source| {
-                source["source_key"].as_str() == Some(source_key)
-                    && source["reason"].as_str() == Some(reason)
-            }),
-            "skipped decision {source_key:?} should be present in skipped_sources"
-        );
-    }
     assert!(
-        packet.contains("Skipped:\n- GitHub: GitHub authentication unavailable"),
-        "packet coverage summary should mirror skipped source decisions. packet:\n{packet}"
+        run_dir.join("coverage.manifest.json").exists(),
+        "missing intake coverage"
     );
     assert!(
-        !packet.contains("Skipped:\n- None recorded\n\nKnown gaps:"),
-        "packet coverage summary should not claim no skipped sources when the report records skipped decisions"
+        run_dir.join("workstreams.suggested.yaml").exists(),
+        "missing intake workstream suggestions"
     );
-    assert_eq!(
-        coverage["completeness"], "Partial",
-        "coverage manifest should mark autodetected skipped sources as partial"
+    assert!(
+        run_dir.join("bundle.manifest.json").exists(),
+        "missing intake bundle manifest"
     );
-    let warnings = coverage["warnings"].as_array().unwrap();
+
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert_packet_opens_with_coverage(&packet);
     assert!(
-        warnings.iter().any(|warning| {
-            warning
-                .as_str()
-                .is_some_and(|warning| warning.contains("Configured source github was skipped"))
-        }),
-        "coverage warnings should receipt autodetected skipped sources"
+        packet.contains("- GitHub: 3 events"),
+        "intake packet should include successful configured sources"
     );
 }
 
 #[test]
-fn intake_created_git_config_outside_repo_points_to_current_repo() {
-    let Some(repo) = create_local_git_repo() else {
-        eprintln!(
-            "skipping intake_created_git_config_outside_repo_points_to_current_repo: git not available"
-        );
-        return;
-    };
-    let scratch = repo.path().join("scratch");
-    std::fs::create_dir_all(&scratch).unwrap();
-    let config = scratch.join("shiplog.toml");
-    let out = scratch.join("out");
+fn golden_intake_manual_only_success_is_review_ready() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
 
-    let assert = shiplog_cmd()
-        .current_dir(repo.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args([
-            "intake",
-            "--config",
-            config.to_str().unwrap(),
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+window = "year:2025"
+
+[user]
+label = "octo"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#,
+    )
+    .unwrap();
+
+    let assert = shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args([
+            "intake",
             "--out",
             out.to_str().unwrap(),
-            "--source",
-            "git",
-            "--source",
-            "manual",
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--year",
+            "2025",
             "--no-open",
             "--explain",
         ])
@@ -10972,71 +11136,195 @@ fn intake_created_git_config_outside_repo_points_to_current_repo() {
         .success();
     let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("Collected:\n- Local git: success"));
-    assert!(stdout.contains("- Local git: included, repo"));
+    assert!(stdout.contains("Review intake complete."));
+    assert!(stdout.contains("- Manual: success, 1 event"));
+    assert!(stdout.contains("Skipped:\n- None"));
+    assert!(stdout.contains("Source decisions:"));
+    assert!(stdout.contains("- Manual: included, manual_events.yaml found"));
+    assert!(stdout.contains("Intake readiness:"));
+    assert!(stdout.contains("Intake status: Ready for review"));
+    assert!(stdout.contains("Packet readiness: Ready with caveats"));
+    assert!(stdout.contains("Needs attention:\n- None"));
+    assert!(stdout.contains("shiplog share explain manager --out"));
+    assert!(!stdout.contains("--bundle-profile manager"));
+    assert!(stdout.contains("shiplog open packet --out"));
+
+    let run_dir = first_run_dir(&out);
+    assert_intake_artifacts(&run_dir);
+    assert_ledger_event_count(&run_dir, 1);
+
+    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Ready for review");
+    assert!(report_md.contains("Intake status: **Ready for review**"));
+    assert!(report_md.contains("Packet readiness: **Ready with caveats**"));
+    assert!(report_md.contains("- Manual: 1 event"));
+    assert!(report_md.contains("## Share Commands"));
+    assert!(report_md.contains("shiplog share manager"));
+    assert!(report_md.contains("SHIPLOG_REDACT_KEY"));
+
+    assert_eq!(report_json["included_sources"][0]["source"], "manual");
+    assert_eq!(report_json["included_sources"][0]["source_key"], "manual");
+    assert_eq!(report_json["included_sources"][0]["source_label"], "Manual");
+    assert_eq!(report_json["included_sources"][0]["event_count"], 1);
+    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 0);
     assert!(
-        !stdout.contains("- Local git: skipped"),
-        "intake-created config should point at the current git repository when --config is outside cwd. stdout:\n{stdout}"
+        !report_json["top_fixups"].as_array().unwrap().is_empty(),
+        "manual-only intake should keep fixups visible in the report"
     );
-
-    let normalized_repo_path = repo.path().display().to_string().replace('\\', "/");
-    let config_text = std::fs::read_to_string(&config).unwrap();
+    let evidence_debt = report_json["evidence_debt"].as_array().unwrap();
     assert!(
-        config_text.contains(&format!("repo = \"{normalized_repo_path}\"")),
-        "intake-created git config should preserve the current repo path. config:\n{config_text}"
+        evidence_debt
+            .iter()
+            .any(|item| item["kind"] == "manual-only-workstream"),
+        "intake report JSON should include packet-quality evidence debt"
     );
-
-    let run_dir = first_run_dir(&out);
-    let report_json: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
-            .unwrap();
     assert!(
-        report_json["included_sources"]
+        evidence_debt.iter().all(|item| item["next_step"]
+            .as_str()
+            .is_some_and(|step| !step.is_empty())),
+        "intake report evidence debt should include actionable next steps"
+    );
+    assert!(
+        report_json["share_commands"]
             .as_array()
             .unwrap()
             .iter()
-            .any(|source| source["source_key"] == "git"),
-        "intake-created config should collect the current git repository"
+            .any(|command| command.as_str().unwrap().contains("shiplog share manager"))
+    );
+    let claim_candidates = report_json["packet_quality"]["claim_candidates"]
+        .as_array()
+        .expect("packet_quality should expose claim_candidates");
+    assert!(
+        claim_candidates.iter().any(|candidate| {
+            candidate["evidence_strength"].as_str() == Some("manual_only")
+                && candidate["supporting_sources"]
+                    .as_array()
+                    .is_some_and(|sources| {
+                        sources
+                            .iter()
+                            .any(|source| source.as_str() == Some("manual"))
+                    })
+                && candidate["missing_context_prompts"]
+                    .as_array()
+                    .is_some_and(|prompts| !prompts.is_empty())
+                && candidate["safe_profiles"]
+                    .as_array()
+                    .is_some_and(|profiles| {
+                        profiles
+                            .iter()
+                            .any(|profile| profile.as_str() == Some("manager"))
+                    })
+        }),
+        "manual-only intake should emit a conservative claim candidate with prompts"
+    );
+
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert_packet_opens_with_coverage(&packet);
+    insta::assert_snapshot!(
+        "manual_only_review_ready_packet_surface",
+        review_ready_packet_surface(
+            &packet,
+            out.to_str().expect("--out path should be utf-8"),
+            run_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .expect("run directory should have a utf-8 name")
+        )
+    );
+    assert!(
+        packet.contains("Ready with caveats.") && packet.contains("manual_only"),
+        "manual-only packet should surface readiness caveats in packet.md"
+    );
+    assert_packet_quality_report_matches_packet(&report_json, &packet);
+    assert!(
+        packet.contains("# Claim Candidates")
+            && packet.contains("Evidence strength: `manual_only`")
+            && packet.contains("Missing context:"),
+        "manual-only packet should render claim candidates with missing-context prompts"
     );
+    assert!(packet.contains("- Manual: 1 event"));
+    assert!(packet.contains("Skipped:\n- None recorded\n"));
+    assert!(packet.contains("Known gaps:\n- Manual events are user-provided\n"));
+    assert!(packet.contains("Manual incident follow-up"));
+
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    assert!(coverage.contains("\"Complete\""));
+    assert!(coverage.contains("\"manual\""));
 }
 
 #[test]
-fn guided_git_repo_without_origin_collects_from_dot_repo() {
-    let Some(repo) = create_local_git_repo() else {
-        eprintln!(
-            "skipping guided_git_repo_without_origin_collects_from_dot_repo: git not available"
-        );
-        return;
+fn intake_journal_suggestions_use_configured_manual_events_path() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let config_dir = tmp.path().join("config");
+    let manual_dir = config_dir.join("journal");
+    std::fs::create_dir_all(&manual_dir)?;
+    let config_path = config_dir.join("shiplog.toml");
+    let events_path = config_dir.join("json.events.jsonl");
+    let coverage_path = config_dir.join("json.coverage.json");
+    let manual_events = manual_dir.join("manual_events.yaml");
+    let events: Vec<_> = (0..10)
+        .map(|idx| {
+            fixture_pr_event(
+                SourceSystem::Github,
+                "acme/platform",
+                700 + idx as u64,
+                &format!("Platform reliability fix {}", idx + 1),
+                2 + idx,
+            )
+        })
+        .collect();
+    let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
+        run_id: RunId("run_configured_manual_next_steps".into()),
+        generated_at: fixture_time(20),
+        user: "octo".into(),
+        window: fixture_window(),
+        mode: "fixture".into(),
+        sources: vec!["github".into(), "manual".into()],
+        slices: vec![CoverageSlice {
+            window: fixture_window(),
+            query: "github fixture".into(),
+            total_count: events.len() as u64,
+            fetched: events.len() as u64,
+            incomplete_results: Some(false),
+            notes: vec!["fixture".into()],
+            source: None,
+        }],
+        warnings: vec![],
+        completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
-    let out = repo.path().join("out");
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
     std::fs::write(
-        repo.path().join("manual_events.yaml"),
+        &manual_events,
         "version: 1\ngenerated_at: 2026-01-01T00:00:00Z\nevents: []\n",
-    )
-    .unwrap();
+    )?;
     std::fs::write(
-        repo.path().join("shiplog.toml"),
-        r#"[shiplog]
-config_version = 1
+        &config_path,
+        r#"[defaults]
+window = "year:2025"
+include_reviews = true
 
-[defaults]
-window = "last-6-months"
-profile = "internal"
+[user]
+label = "octo"
 
-[sources.git]
+[sources.json]
 enabled = true
-repo = "."
-include_merges = false
+events = "./json.events.jsonl"
+coverage = "./json.coverage.json"
 
 [sources.manual]
 enabled = true
-events = "./manual_events.yaml"
+events = "./journal/manual_events.yaml"
+user = "octo"
 "#,
-    )
-    .unwrap();
+    )?;
 
     let assert = shiplog_cmd()
-        .current_dir(repo.path())
+        .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
@@ -11045,63 +11333,168 @@ events = "./manual_events.yaml"
             "intake",
             "--out",
             out.to_str().unwrap(),
+            "--config",
+            config_path.to_str().unwrap(),
             "--year",
             "2025",
             "--no-open",
-            "--explain",
         ])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    let manual_events_arg = manual_events.display().to_string();
 
     assert!(
-        stdout.contains("Collected:\n- Local git: success"),
-        "repo = \".\" should collect local git evidence even when no origin remote exists. stdout:\n{stdout}"
+        stdout.contains("- JSON collected 10 events"),
+        "Good section should keep positive evidence contributions visible. stdout:\n{stdout}"
     );
     assert!(
-        !stdout.contains("Could not determine repository name"),
-        "local git source should derive repo identity from the workdir before failing setup. stdout:\n{stdout}"
+        !stdout.contains("- Manual collected 0 events"),
+        "Good section should not describe a zero-event source as positive evidence. stdout:\n{stdout}"
     );
+    assert!(stdout.contains("shiplog journal add --date"));
+    assert!(stdout.contains("--events"));
+    assert!(stdout.contains(&manual_events_arg));
 
     let run_dir = first_run_dir(&out);
-    let report_json: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
-            .unwrap();
-    assert!(
-        report_json["included_sources"]
-            .as_array()
-            .unwrap()
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md"))?;
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json"))?;
+    let report_json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        run_dir.join("intake.report.json"),
+    )?)?;
+    let good = report_json["good"].as_array().unwrap();
+    assert!(
+        good.iter()
+            .any(|item| item.as_str() == Some("JSON collected 10 events")),
+        "intake report should keep positive source evidence in good={good:?}"
+    );
+    assert!(
+        good.iter().all(|item| item
+            .as_str()
+            .is_none_or(|text| !text.contains("Manual collected 0 events"))),
+        "intake report good array should not call zero-event manual evidence good={good:?}"
+    );
+    let next_commands = report_json["next_commands"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|command| command.as_str())
+        .collect::<Vec<_>>();
+    assert!(
+        next_commands
+            .first()
+            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
+        "repairable intake should route the top-level handoff through repair plan first: {next_commands:?}"
+    );
+    assert!(
+        next_commands
             .iter()
-            .any(|source| source["source_key"] == "git" && source["event_count"] == 1),
-        "report should include the local git event collected from repo = \".\""
+            .all(|command| !command.contains("shiplog journal add --date")),
+        "top-level next commands should not bypass repair items with direct journal add commands: {next_commands:?}"
+    );
+    assert!(
+        next_commands
+            .iter()
+            .all(|command| !command.contains("shiplog workstreams split ")),
+        "top-level next commands should not advertise write-producing workstream edits while repair plan is the first handoff: {next_commands:?}"
+    );
+    for (field, commands) in [
+        (
+            "top_fixups",
+            report_json["top_fixups"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter_map(|fixup| fixup["command"].as_str())
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "evidence_debt",
+            report_json["evidence_debt"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter_map(|debt| debt["next_step"].as_str())
+                .collect::<Vec<_>>(),
+        ),
+    ] {
+        let journal_commands: Vec<_> = commands
+            .into_iter()
+            .filter(|command| command.contains("shiplog journal add --date"))
+            .collect();
+        assert!(
+            !journal_commands.is_empty(),
+            "{field} should include at least one direct journal add suggestion"
+        );
+        for command in journal_commands {
+            assert!(
+                command.contains("--events") && command.contains(&manual_events_arg),
+                "{field} journal command should target configured manual events file: {command}"
+            );
+        }
+    }
+
+    let review_assert = shiplog_cmd()
+        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
+        .assert()
+        .success();
+    let review_stdout = String::from_utf8(review_assert.get_output().stdout.clone())?;
+    assert!(review_stdout.contains("shiplog journal add --date"));
+    assert!(review_stdout.contains("--events"));
+    assert!(review_stdout.contains(&manual_events_arg));
+
+    let fixups_assert = shiplog_cmd()
+        .args([
+            "review",
+            "fixups",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+            "--commands-only",
+        ])
+        .assert()
+        .success();
+    let fixups_stdout = String::from_utf8(fixups_assert.get_output().stdout.clone())?;
+    assert!(fixups_stdout.contains("shiplog journal add --date"));
+    assert!(fixups_stdout.contains("--events"));
+    assert!(fixups_stdout.contains(&manual_events_arg));
+
+    assert_eq!(
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md"))?
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json"))?
     );
+
+    Ok(())
 }
 
 #[test]
-fn intake_source_decisions_skip_configured_git_directory_that_is_not_repo() {
+fn golden_intake_all_source_fixture_surfaces_every_source_without_network() {
     let tmp = TempDir::new().unwrap();
-    let not_repo = tmp.path().join("not-a-repo");
-    let config = tmp.path().join("shiplog.toml");
     let out = tmp.path().join("out");
-    std::fs::create_dir_all(&not_repo).unwrap();
-    std::fs::write(
-        &config,
-        r#"[shiplog]
-config_version = 1
+    let events_path = tmp.path().join("all-source.events.jsonl");
+    let coverage_path = tmp.path().join("all-source.coverage.json");
+    let events = all_source_fixture_events();
+    let coverage = all_source_fixture_coverage();
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
 
-[defaults]
-window = "last-6-months"
-profile = "internal"
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+window = "year:2025"
+include_reviews = true
 
-[sources.git]
-enabled = true
-repo = "./not-a-repo"
-include_merges = false
+[user]
+label = "octo"
 
-[sources.manual]
+[sources.json]
 enabled = true
-events = "./manual_events.yaml"
-user = "Tester"
+events = "./all-source.events.jsonl"
+coverage = "./all-source.coverage.json"
 "#,
     )
     .unwrap();
@@ -11109,209 +11502,290 @@ user = "Tester"
     let assert = shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
-        .env("GH_CONFIG_DIR", tmp.path().join("gh-config"))
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
         .args([
             "intake",
-            "--config",
-            config.to_str().unwrap(),
             "--out",
             out.to_str().unwrap(),
-            "--source",
-            "git",
-            "--source",
-            "manual",
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--year",
+            "2025",
             "--no-open",
-            "--explain",
         ])
         .assert()
         .success();
     let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("- Local git: skipped, repo"));
-    assert!(stdout.contains("is not a git repo"));
-    assert!(
-        !stdout.contains("collect configured git source"),
-        "non-repository git paths should be diagnosed before runtime collection. stdout:\n{stdout}"
-    );
+    assert!(stdout.contains("Review intake complete."));
+    assert!(stdout.contains("- JSON: success, 8 events"));
+    assert!(stdout.contains("Skipped:\n- None"));
+    assert!(stdout.contains("Intake readiness:"));
+    assert!(stdout.contains("Good:"));
+    assert!(stdout.contains("- Packet rendered"));
+    assert!(stdout.contains("- Evidence ledger written"));
+    assert!(stdout.contains("- Coverage manifest written"));
+    assert!(stdout.contains("shiplog share explain manager --out"));
+    assert!(!stdout.contains("--bundle-profile manager"));
+    assert!(stdout.contains("shiplog open packet --out"));
 
     let run_dir = first_run_dir(&out);
-    let report_json: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
-            .unwrap();
+    assert_intake_artifacts(&run_dir);
+    assert_ledger_event_count(&run_dir, 8);
+    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Ready for review");
+    assert!(report_md.contains("- JSON: 8 events"));
     assert!(
-        report_json["skipped_sources"]
+        report_json["included_sources"]
             .as_array()
             .unwrap()
             .iter()
-            .any(|source| source["source_key"] == "git"
-                && source["reason"]
-                    .as_str()
-                    .is_some_and(|reason| reason.contains("is not a git repo"))),
-        "skipped_sources should include the preflight Local git repair reason"
+            .any(|source| source["source_key"] == "json"
+                && source["source"] == "json"
+                && source["source_label"] == "JSON"
+                && source["event_count"] == 8)
     );
-
-    let source_failures: serde_json::Value = serde_json::from_str(
-        &std::fs::read_to_string(run_dir.join("source.failures.json")).unwrap(),
-    )
-    .unwrap();
+    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 0);
     assert!(
-        source_failures["failures"]
+        report_json["share_commands"]
             .as_array()
             .unwrap()
             .iter()
-            .any(|failure| failure["source"] == "git"
-                && failure["kind"] == "local_source_unavailable"),
-        "source failure receipt should classify an existing non-repo directory as local_source_unavailable"
+            .any(|command| command.as_str().unwrap().contains("shiplog share public")),
+        "all-source intake report should keep share commands visible"
     );
+
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert_packet_opens_with_coverage(&packet);
+    assert_packet_uses_summary_appendix(&packet);
+
+    for expected in [
+        "- GitHub: 2 events",
+        "- GitLab: 1 event",
+        "- Jira: 1 event",
+        "- Linear: 1 event",
+        "- Local git: 1 event",
+        "- Manual: 1 event",
+        "- JSON import: 1 event",
+        "Skipped:\n- None recorded\n",
+        "Known gaps:\n- Manual events are user-provided\n",
+        "GitHub release automation",
+        "GitLab self-hosted deploy fix",
+        "Jira OPS-42 rollout checklist",
+        "Linear issue triage",
+        "Local git hotfix commit",
+        "Manual customer debugging note",
+        "Imported architecture decision",
+        "**Suggested claim prompts**",
+    ] {
+        assert!(
+            packet.contains(expected),
+            "golden all-source intake packet should contain {expected:?}"
+        );
+    }
+
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    for source in [
+        "\"github\"",
+        "\"gitlab\"",
+        "\"jira\"",
+        "\"linear\"",
+        "\"local_git\"",
+        "\"manual\"",
+        "\"json_import\"",
+    ] {
+        assert!(
+            coverage.contains(source),
+            "golden all-source intake coverage should preserve {source}"
+        );
+    }
 }
 
 #[test]
-fn golden_intake_manager_share_missing_key_fails_closed() {
+fn intake_summary_and_report_skipped_sources_mirror_skipped_decisions() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Review intake complete."))
-        .stdout(predicate::str::contains("Packet readiness: Needs evidence"));
-
-    let run_dir = first_run_dir(&out);
-    assert_intake_artifacts(&run_dir);
-    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs evidence");
-    assert!(report_md.contains("Packet readiness: **Needs evidence**"));
-    assert!(
-        report_json["share_commands"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .any(|command| command.as_str().unwrap().contains("shiplog share manager")),
-        "intake report should keep manager share recovery command visible"
-    );
-
-    shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "share",
-            "manager",
+            "intake",
             "--out",
             out.to_str().unwrap(),
-            "--latest",
+            "--no-open",
+            "--explain",
         ])
         .assert()
-        .failure()
-        .stderr(
-            predicate::str::contains("manager share requires --redact-key or SHIPLOG_REDACT_KEY")
-                .and(predicate::str::contains("shiplog share manager --latest")),
-        );
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
+    assert!(stdout.contains("Skipped:\n- GitHub: GitHub authentication unavailable"));
     assert!(
-        !run_dir.join("profiles/manager/packet.md").exists(),
-        "manager share packet should not be written after intake without a key"
+        !stdout.contains("Skipped:\n- None\n\nSource decisions:\n- GitHub: skipped"),
+        "summary should not say no sources were skipped while source decisions list skipped sources"
     );
 
-    shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "share",
-            "verify",
-            "public",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "public share requires --redact-key or SHIPLOG_REDACT_KEY",
-        ));
+    let run_dir = first_run_dir(&out);
+    let report_json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
+            .unwrap();
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap(),
+    )
+    .unwrap();
+    let decisions = report_json["source_decisions"].as_array().unwrap();
+    let skipped_sources = report_json["skipped_sources"].as_array().unwrap();
+    let skipped_decisions = decisions
+        .iter()
+        .filter(|decision| decision["decision"].as_str() == Some("skipped"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        skipped_sources.len(),
+        skipped_decisions.len(),
+        "skipped_sources should mirror skipped source decisions"
+    );
+    for decision in skipped_decisions {
+        let source_key = decision["source_key"].as_str().unwrap();
+        let reason = decision["reason"].as_str().unwrap();
+        assert!(
+            skipped_sources.iter().any(|source| {
+                source["source_key"].as_str() == Some(source_key)
+                    && source["reason"].as_str() == Some(reason)
+            }),
+            "skipped decision {source_key:?} should be present in skipped_sources"
+        );
+    }
     assert!(
-        !run_dir.join("profiles/public/packet.md").exists(),
-        "public share verify should not write a packet without a key"
+        packet.contains("Skipped:\n- GitHub: GitHub authentication unavailable"),
+        "packet coverage summary should mirror skipped source decisions. packet:\n{packet}"
+    );
+    assert!(
+        !packet.contains("Skipped:\n- None recorded\n\nKnown gaps:"),
+        "packet coverage summary should not claim no skipped sources when the report records skipped decisions"
+    );
+    assert_eq!(
+        coverage["completeness"], "Partial",
+        "coverage manifest should mark autodetected skipped sources as partial"
+    );
+    let warnings = coverage["warnings"].as_array().unwrap();
+    assert!(
+        warnings.iter().any(|warning| {
+            warning
+                .as_str()
+                .is_some_and(|warning| warning.contains("Configured source github was skipped"))
+        }),
+        "coverage warnings should receipt autodetected skipped sources"
     );
 }
 
 #[test]
-fn intake_creates_minimal_config_and_manual_rescue_packet() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+fn intake_created_git_config_outside_repo_points_to_current_repo() {
+    let Some(repo) = create_local_git_repo() else {
+        eprintln!(
+            "skipping intake_created_git_config_outside_repo_points_to_current_repo: git not available"
+        );
+        return;
+    };
+    let scratch = repo.path().join("scratch");
+    std::fs::create_dir_all(&scratch).unwrap();
+    let config = scratch.join("shiplog.toml");
+    let out = scratch.join("out");
 
-    shiplog_cmd()
-        .current_dir(tmp.path())
+    let assert = shiplog_cmd()
+        .current_dir(repo.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "intake",
+            "--config",
+            config.to_str().unwrap(),
+            "--out",
+            out.to_str().unwrap(),
+            "--source",
+            "git",
+            "--source",
+            "manual",
+            "--no-open",
+            "--explain",
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Review intake complete."))
-        .stdout(predicate::str::contains("Config: created"))
-        .stdout(predicate::str::contains("- Manual: success, 0 events"))
-        .stdout(predicate::str::contains("Packet:"))
-        .stdout(predicate::str::contains("Intake readiness:"))
-        .stdout(predicate::str::contains("Packet readiness: Needs evidence"))
-        .stdout(predicate::str::contains(
-            "No events collected; add manual evidence or enable a source.",
-        ))
-        .stdout(predicate::str::contains("Open later:"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
+    assert!(stdout.contains("Collected:\n- Local git: success"));
+    assert!(stdout.contains("- Local git: included, repo"));
     assert!(
-        tmp.path().join("shiplog.toml").exists(),
-        "intake should create shiplog.toml"
+        !stdout.contains("- Local git: skipped"),
+        "intake-created config should point at the current git repository when --config is outside cwd. stdout:\n{stdout}"
     );
+
+    let normalized_repo_path = repo.path().display().to_string().replace('\\', "/");
+    let config_text = std::fs::read_to_string(&config).unwrap();
     assert!(
-        tmp.path().join("manual_events.yaml").exists(),
-        "intake should create manual_events.yaml"
+        config_text.contains(&format!("repo = \"{normalized_repo_path}\"")),
+        "intake-created git config should preserve the current repo path. config:\n{config_text}"
     );
+
     let run_dir = first_run_dir(&out);
-    assert!(run_dir.join("packet.md").exists(), "missing rescue packet");
-    assert!(
-        run_dir.join("coverage.manifest.json").exists(),
-        "missing rescue coverage"
-    );
-    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs evidence");
-    assert!(report_md.contains("No events collected"));
+    let report_json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
+            .unwrap();
     assert!(
-        report_json["needs_attention"]
+        report_json["included_sources"]
             .as_array()
             .unwrap()
             .iter()
-            .any(|item| item.as_str().unwrap().contains("No events collected")),
-        "empty rescue report should explain the evidence gap"
-    );
-    let repair_items = report_json["repair_items"].as_array().unwrap();
-    assert!(
-        repair_items
-            .iter()
-            .any(|item| item["kind"] == "manual_evidence_missing"
-                && item["source_key"] == "manual"
-                && item["action"]["kind"] == "journal_add"
-                && item["receipt_refs"]
-                    .as_array()
-                    .unwrap()
-                    .iter()
-                    .any(|receipt| receipt["field"] == "needs_attention")),
-        "empty rescue report should expose a receipt-derived manual evidence repair item"
+            .any(|source| source["source_key"] == "git"),
+        "intake-created config should collect the current git repository"
     );
 }
 
 #[test]
-fn intake_explain_reports_source_decisions_for_rescue_config() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+fn guided_git_repo_without_origin_collects_from_dot_repo() {
+    let Some(repo) = create_local_git_repo() else {
+        eprintln!(
+            "skipping guided_git_repo_without_origin_collects_from_dot_repo: git not available"
+        );
+        return;
+    };
+    let out = repo.path().join("out");
+    std::fs::write(
+        repo.path().join("manual_events.yaml"),
+        "version: 1\ngenerated_at: 2026-01-01T00:00:00Z\nevents: []\n",
+    )
+    .unwrap();
+    std::fs::write(
+        repo.path().join("shiplog.toml"),
+        r#"[shiplog]
+config_version = 1
 
-    shiplog_cmd()
-        .current_dir(tmp.path())
+[defaults]
+window = "last-6-months"
+profile = "internal"
+
+[sources.git]
+enabled = true
+repo = "."
+include_merges = false
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
+    )
+    .unwrap();
+
+    let assert = shiplog_cmd()
+        .current_dir(repo.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
@@ -11320,316 +11794,313 @@ fn intake_explain_reports_source_decisions_for_rescue_config() {
             "intake",
             "--out",
             out.to_str().unwrap(),
+            "--year",
+            "2025",
             "--no-open",
             "--explain",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Source decisions:"))
-        .stdout(predicate::str::contains(
-            "- GitHub: skipped, GitHub authentication unavailable",
-        ))
-        .stdout(predicate::str::contains("Fix:"))
-        .stdout(predicate::str::contains(
-            "Set sources.github.user explicitly",
-        ))
-        .stdout(predicate::str::contains(
-            "- Local git: skipped, current directory is not a git repo",
-        ))
-        .stdout(predicate::str::contains(
-            "Run intake from a git repository root.",
-        ))
-        .stdout(predicate::str::contains(
-            "- Manual: included, manual_events.yaml found",
-        ))
-        .stdout(predicate::str::contains(
-            "Use `shiplog journal add` to capture missing context without editing YAML.",
-        ));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+
+    assert!(
+        stdout.contains("Collected:\n- Local git: success"),
+        "repo = \".\" should collect local git evidence even when no origin remote exists. stdout:\n{stdout}"
+    );
+    assert!(
+        !stdout.contains("Could not determine repository name"),
+        "local git source should derive repo identity from the workdir before failing setup. stdout:\n{stdout}"
+    );
+
+    let run_dir = first_run_dir(&out);
+    let report_json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
+            .unwrap();
+    assert!(
+        report_json["included_sources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|source| source["source_key"] == "git" && source["event_count"] == 1),
+        "report should include the local git event collected from repo = \".\""
+    );
 }
 
 #[test]
-fn intake_records_configured_missing_tokens_as_skipped_sources() {
+fn intake_source_decisions_skip_configured_git_directory_that_is_not_repo() {
     let tmp = TempDir::new().unwrap();
+    let not_repo = tmp.path().join("not-a-repo");
+    let config = tmp.path().join("shiplog.toml");
     let out = tmp.path().join("out");
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
-
+    std::fs::create_dir_all(&not_repo).unwrap();
     std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-window = "year:2025"
+        &config,
+        r#"[shiplog]
+config_version = 1
 
-[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
+[defaults]
+window = "last-6-months"
+profile = "internal"
 
-[sources.jira]
+[sources.git]
 enabled = true
-user = "712020:account-id"
-instance = "example.atlassian.net"
-status = "done"
+repo = "./not-a-repo"
+include_merges = false
 
-[sources.linear]
+[sources.manual]
 enabled = true
-user_id = "linear-user-id"
-status = "done"
+events = "./manual_events.yaml"
+user = "Tester"
 "#,
     )
     .unwrap();
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
         .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env("GH_CONFIG_DIR", tmp.path().join("gh-config"))
+        .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
         .args([
             "intake",
+            "--config",
+            config.to_str().unwrap(),
             "--out",
             out.to_str().unwrap(),
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--source",
+            "git",
+            "--source",
+            "manual",
             "--no-open",
+            "--explain",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("- JSON: success"))
-        .stdout(predicate::str::contains("- Jira: missing JIRA_TOKEN"))
-        .stdout(predicate::str::contains("- Linear: missing LINEAR_API_KEY"))
-        .stdout(predicate::str::contains("Skipped sources:"))
-        .stdout(predicate::str::contains("Intake readiness:"))
-        .stdout(predicate::str::contains("Intake status: Needs curation"))
-        .stdout(predicate::str::contains(
-            "Packet readiness: Ready with caveats",
-        ))
-        .stdout(predicate::str::contains(
-            "- Jira skipped: missing JIRA_TOKEN",
-        ))
-        .stdout(predicate::str::contains(
-            "- Linear skipped: missing LINEAR_API_KEY",
-        ))
-        .stdout(predicate::str::contains("Repair sources:"))
-        .stdout(predicate::str::contains("kind: missing_token"))
-        .stdout(predicate::str::contains("shiplog sources status"))
-        .stdout(predicate::str::contains("--source jira"))
-        .stdout(predicate::str::contains("--source linear"))
-        .stdout(predicate::str::contains("shiplog doctor --setup --config"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    let run_dir = first_run_dir(&out);
-    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs curation");
-    let source_failures_path = run_dir.join("source.failures.json");
+    assert!(stdout.contains("- Local git: skipped, repo"));
+    assert!(stdout.contains("is not a git repo"));
     assert!(
-        source_failures_path.exists(),
-        "intake should write source failure receipts for skipped configured sources"
-    );
-    let source_failures: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&source_failures_path).unwrap()).unwrap();
-    assert_eq!(source_failures["schema_version"], 1);
-    assert_eq!(
-        source_failures["run_id"].as_str().unwrap(),
-        run_dir.file_name().unwrap().to_string_lossy().as_ref()
+        !stdout.contains("collect configured git source"),
+        "non-repository git paths should be diagnosed before runtime collection. stdout:\n{stdout}"
     );
-    assert_eq!(source_failures["failures"].as_array().unwrap().len(), 2);
+
+    let run_dir = first_run_dir(&out);
+    let report_json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(run_dir.join("intake.report.json")).unwrap())
+            .unwrap();
     assert!(
-        source_failures["failures"]
+        report_json["skipped_sources"]
             .as_array()
             .unwrap()
             .iter()
-            .any(|failure| failure["source"] == "jira"
-                && failure["kind"] == "missing_token"
-                && failure["rerun_command"]
+            .any(|source| source["source_key"] == "git"
+                && source["reason"]
                     .as_str()
-                    .unwrap()
-                    .contains("shiplog intake --config"))
+                    .is_some_and(|reason| reason.contains("is not a git repo"))),
+        "skipped_sources should include the preflight Local git repair reason"
     );
+
+    let source_failures: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(run_dir.join("source.failures.json")).unwrap(),
+    )
+    .unwrap();
     assert!(
-        report_json["artifacts"]
+        source_failures["failures"]
             .as_array()
             .unwrap()
             .iter()
-            .any(|artifact| artifact["path"]
-                .as_str()
-                .unwrap()
-                .ends_with("source.failures.json")),
-        "intake report should expose the source failure receipt artifact"
+            .any(|failure| failure["source"] == "git"
+                && failure["kind"] == "local_source_unavailable"),
+        "source failure receipt should classify an existing non-repo directory as local_source_unavailable"
     );
-    assert!(report_md.contains("## Skipped Sources"));
-    assert!(report_md.contains("## Repair Sources"));
-    assert!(report_md.contains("- Jira: missing JIRA_TOKEN"));
-    assert!(report_md.contains("- Linear: missing LINEAR_API_KEY"));
-    assert!(report_md.contains("kind: `missing_token`"));
-    assert!(report_md.contains("export JIRA_TOKEN=..."));
-    assert!(report_md.contains("shiplog identify jira --auth-user <email>"));
-    assert!(report_md.contains("export LINEAR_API_KEY=..."));
-    assert!(report_md.contains("shiplog identify linear"));
-    assert!(report_md.contains("shiplog sources status"));
-    assert!(report_md.contains("--source jira"));
-    assert!(report_md.contains("--source linear"));
+}
 
-    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 2);
-    assert_eq!(report_json["repair_sources"].as_array().unwrap().len(), 2);
+#[test]
+fn golden_intake_manager_share_missing_key_fails_closed() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Review intake complete."))
+        .stdout(predicate::str::contains("Packet readiness: Needs evidence"));
+
+    let run_dir = first_run_dir(&out);
+    assert_intake_artifacts(&run_dir);
+    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs evidence");
+    assert!(report_md.contains("Packet readiness: **Needs evidence**"));
     assert!(
-        report_json["skipped_sources"]
+        report_json["share_commands"]
             .as_array()
             .unwrap()
             .iter()
-            .any(|source| source["source"] == "jira"
-                && source["source_key"] == "jira"
-                && source["source_label"] == "Jira"
-                && source["reason"] == "missing JIRA_TOKEN")
+            .any(|command| command.as_str().unwrap().contains("shiplog share manager")),
+        "intake report should keep manager share recovery command visible"
     );
+
+    shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
+        .args([
+            "share",
+            "manager",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+        ])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("manager share requires --redact-key or SHIPLOG_REDACT_KEY")
+                .and(predicate::str::contains("shiplog share manager --latest")),
+        );
+
     assert!(
-        report_json["next_commands"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .any(|command| command.as_str().unwrap().contains("shiplog doctor")),
-        "skipped-source intake report should keep repair/rerun next commands"
+        !run_dir.join("profiles/manager/packet.md").exists(),
+        "manager share packet should not be written after intake without a key"
     );
+
+    shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
+        .args([
+            "share",
+            "verify",
+            "public",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "public share requires --redact-key or SHIPLOG_REDACT_KEY",
+        ));
     assert!(
-        report_json["source_decisions"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .any(|decision| decision["source"] == "jira"
-                && decision["source_key"] == "jira"
-                && decision["source_label"] == "Jira"
-                && decision["hint_lines"]
-                    .as_array()
-                    .unwrap()
-                    .iter()
-                    .any(|line| line.as_str().unwrap().contains("JIRA_TOKEN")))
+        !run_dir.join("profiles/public/packet.md").exists(),
+        "public share verify should not write a packet without a key"
     );
+}
+
+#[test]
+fn intake_creates_minimal_config_and_manual_rescue_packet() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Review intake complete."))
+        .stdout(predicate::str::contains("Config: created"))
+        .stdout(predicate::str::contains("- Manual: success, 0 events"))
+        .stdout(predicate::str::contains("Packet:"))
+        .stdout(predicate::str::contains("Intake readiness:"))
+        .stdout(predicate::str::contains("Packet readiness: Needs evidence"))
+        .stdout(predicate::str::contains(
+            "No events collected; add manual evidence or enable a source.",
+        ))
+        .stdout(predicate::str::contains("Open later:"));
+
     assert!(
-        report_json["repair_sources"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .any(|repair| repair["source"] == "jira"
-                && repair["source_key"] == "jira"
-                && repair["source_label"] == "Jira"
-                && repair["kind"] == "missing_token"
-                && repair["commands"].as_array().unwrap().iter().any(|line| {
-                    let Some(line) = line.as_str() else {
-                        return false;
-                    };
-                    line.contains("shiplog sources status") && line.contains("--source jira")
-                }))
+        tmp.path().join("shiplog.toml").exists(),
+        "intake should create shiplog.toml"
     );
     assert!(
-        report_json["repair_sources"]
+        tmp.path().join("manual_events.yaml").exists(),
+        "intake should create manual_events.yaml"
+    );
+    let run_dir = first_run_dir(&out);
+    assert!(run_dir.join("packet.md").exists(), "missing rescue packet");
+    assert!(
+        run_dir.join("coverage.manifest.json").exists(),
+        "missing rescue coverage"
+    );
+    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs evidence");
+    assert!(report_md.contains("No events collected"));
+    assert!(
+        report_json["needs_attention"]
             .as_array()
             .unwrap()
             .iter()
-            .filter(|repair| repair["source_key"] == "jira" || repair["source_key"] == "linear")
-            .flat_map(|repair| repair["commands"].as_array().into_iter().flatten())
-            .filter_map(|command| command.as_str())
-            .all(|command| !command.contains("export ") && !command.contains("shiplog identify")),
-        "setup-blocked repair sources should hand off to read-only setup status instead of token or identity commands: {:?}",
-        report_json["repair_sources"]
+            .any(|item| item.as_str().unwrap().contains("No events collected")),
+        "empty rescue report should explain the evidence gap"
     );
     let repair_items = report_json["repair_items"].as_array().unwrap();
     assert!(
         repair_items
             .iter()
-            .any(|item| item["kind"] == "source_skipped_configuration"
-                && item["source_key"] == "jira"
-                && item["action"]["kind"] == "configure_source"
-                && item["action"]["command"]
-                    .as_str()
-                    .is_some_and(|command| command.contains("shiplog sources status")
-                        && command.contains("--source jira"))
-                && item["receipt_refs"]
-                    .as_array()
-                    .unwrap()
-                    .iter()
-                    .any(|receipt| receipt["field"] == "repair_sources"
-                        && receipt["source_key"] == "jira")),
-        "skipped Jira source should produce a receipt-derived repair item"
-    );
-    assert!(
-        repair_items
-            .iter()
-            .any(|item| item["kind"] == "artifact_missing_or_unopened"
-                && item["action"]["kind"] == "open_artifact"
+            .any(|item| item["kind"] == "manual_evidence_missing"
+                && item["source_key"] == "manual"
+                && item["action"]["kind"] == "journal_add"
                 && item["receipt_refs"]
                     .as_array()
                     .unwrap()
                     .iter()
-                    .any(|receipt| receipt["field"] == "artifacts")),
-        "source failure artifact should produce an artifact repair item"
+                    .any(|receipt| receipt["field"] == "needs_attention")),
+        "empty rescue report should expose a receipt-derived manual evidence repair item"
     );
-    for (source_key, source_label) in [("jira", "Jira"), ("linear", "Linear")] {
-        assert!(
-            report_json["source_decisions"]
-                .as_array()
-                .unwrap()
-                .iter()
-                .any(|decision| decision["source_key"] == source_key
-                    && decision["source_label"] == source_label),
-            "source_decisions should expose canonical identity for {source_key}"
-        );
-        assert!(
-            report_json["source_freshness"]
-                .as_array()
-                .unwrap()
-                .iter()
-                .any(|freshness| freshness["source_key"] == source_key
-                    && freshness["source_label"] == source_label
-                    && freshness["status"] == "unavailable"),
-            "source_freshness should join to source_decisions on source_key for {source_key}"
-        );
-    }
+}
 
-    let repair_plan = shiplog_cmd()
+#[test]
+fn intake_explain_reports_source_decisions_for_rescue_config() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
         .current_dir(tmp.path())
-        .args(["repair", "plan", "--out", out.to_str().unwrap(), "--latest"])
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args([
+            "intake",
+            "--out",
+            out.to_str().unwrap(),
+            "--no-open",
+            "--explain",
+        ])
         .assert()
-        .success();
-    let repair_plan_stdout = String::from_utf8(repair_plan.get_output().stdout.clone()).unwrap();
-    assert!(
-        repair_plan_stdout.contains("shiplog sources status")
-            && repair_plan_stdout.contains("--source jira")
-            && repair_plan_stdout.contains("--source linear"),
-        "repair plan should route provider setup gaps through read-only source status. stdout:\n{repair_plan_stdout}"
-    );
-    assert!(
-        !repair_plan_stdout.contains("shiplog identify")
-            && !repair_plan_stdout.contains("export JIRA_TOKEN")
-            && !repair_plan_stdout.contains("export LINEAR_API_KEY"),
-        "repair plan should not print setup-blocked provider token/identity commands. stdout:\n{repair_plan_stdout}"
-    );
-
-    assert!(
-        coverage.contains("Configured source jira was skipped: missing JIRA_TOKEN"),
-        "intake coverage should record skipped Jira source"
-    );
-    assert!(
-        coverage.contains("Configured source linear was skipped: missing LINEAR_API_KEY"),
-        "intake coverage should record skipped Linear source"
-    );
-    assert!(
-        coverage.contains("\"Partial\""),
-        "intake coverage should mark partial source collection"
-    );
+        .success()
+        .stdout(predicate::str::contains("Source decisions:"))
+        .stdout(predicate::str::contains(
+            "- GitHub: skipped, GitHub authentication unavailable",
+        ))
+        .stdout(predicate::str::contains("Fix:"))
+        .stdout(predicate::str::contains(
+            "Set sources.github.user explicitly",
+        ))
+        .stdout(predicate::str::contains(
+            "- Local git: skipped, current directory is not a git repo",
+        ))
+        .stdout(predicate::str::contains(
+            "Run intake from a git repository root.",
+        ))
+        .stdout(predicate::str::contains(
+            "- Manual: included, manual_events.yaml found",
+        ))
+        .stdout(predicate::str::contains(
+            "Use `shiplog journal add` to capture missing context without editing YAML.",
+        ));
 }
 
 #[test]
-fn intake_records_remote_filter_failures_without_network_and_keeps_repair_guidance() {
+fn intake_records_configured_missing_tokens_as_skipped_sources() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
     let fixtures = fixture_dir();
-    let cache_dir = tmp.path().join(".cache");
-    let github_cache_path = seed_github_cache(&cache_dir);
     std::fs::copy(
         fixtures.join("ledger.events.jsonl"),
         tmp.path().join("ledger.events.jsonl"),
@@ -11651,194 +12122,472 @@ enabled = true
 events = "./ledger.events.jsonl"
 coverage = "./coverage.manifest.json"
 
-[sources.gitlab]
-enabled = true
-user = "octo"
-instance = "https://gitlab.self-hosted.example/platform/reliability"
-state = "needs_review"
-cache_dir = "./.cache"
-
 [sources.jira]
 enabled = true
 user = "712020:account-id"
 instance = "example.atlassian.net"
-status = "blocked"
-cache_dir = "./.cache"
+status = "done"
 
 [sources.linear]
 enabled = true
 user_id = "linear-user-id"
-status = "waiting"
-project = "OPS"
-cache_dir = "./.cache"
+status = "done"
 "#,
     )
     .unwrap();
 
-    let gitlab_token = "edge-gitlab-token-do-not-print";
-    let jira_token = "edge-jira-token-do-not-print";
-    let linear_key = "edge-linear-key-do-not-print";
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .current_dir(tmp.path())
-        .env("GITLAB_TOKEN", gitlab_token)
-        .env("JIRA_TOKEN", jira_token)
-        .env("LINEAR_API_KEY", linear_key)
-        .env_remove("GITHUB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
         .args([
             "intake",
             "--out",
             out.to_str().unwrap(),
             "--config",
             tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--year",
-            "2025",
             "--no-open",
-            "--explain",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+        .success()
+        .stdout(predicate::str::contains("- JSON: success"))
+        .stdout(predicate::str::contains("- Jira: missing JIRA_TOKEN"))
+        .stdout(predicate::str::contains("- Linear: missing LINEAR_API_KEY"))
+        .stdout(predicate::str::contains("Skipped sources:"))
+        .stdout(predicate::str::contains("Intake readiness:"))
+        .stdout(predicate::str::contains("Intake status: Needs curation"))
+        .stdout(predicate::str::contains(
+            "Packet readiness: Ready with caveats",
+        ))
+        .stdout(predicate::str::contains(
+            "- Jira skipped: missing JIRA_TOKEN",
+        ))
+        .stdout(predicate::str::contains(
+            "- Linear skipped: missing LINEAR_API_KEY",
+        ))
+        .stdout(predicate::str::contains("Repair sources:"))
+        .stdout(predicate::str::contains("kind: missing_token"))
+        .stdout(predicate::str::contains("shiplog sources status"))
+        .stdout(predicate::str::contains("--source jira"))
+        .stdout(predicate::str::contains("--source linear"))
+        .stdout(predicate::str::contains("shiplog doctor --setup --config"));
 
-    assert!(stdout.contains("Review intake complete."));
-    assert!(stdout.contains("- JSON: success"));
-    assert!(stdout.contains("- GitLab: create configured GitLab ingestor"));
-    assert!(stdout.contains("Invalid MR state: needs_review"));
-    assert!(stdout.contains("- Jira: create configured Jira ingestor"));
-    assert!(stdout.contains("Invalid issue status: blocked"));
-    assert!(stdout.contains("- Linear: create configured Linear ingestor"));
-    assert!(stdout.contains("Invalid issue status: waiting"));
-    assert!(stdout.contains("Source decisions:"));
-    assert!(stdout.contains("- GitLab: skipped, create configured GitLab ingestor"));
-    assert!(stdout.contains("- Jira: skipped, create configured Jira ingestor"));
-    assert!(stdout.contains("- Linear: skipped, create configured Linear ingestor"));
+    let run_dir = first_run_dir(&out);
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs curation");
+    let source_failures_path = run_dir.join("source.failures.json");
     assert!(
-        !stdout.contains("- GitLab: included"),
-        "runtime GitLab filter failure should override stale preflight inclusion"
-    );
+        source_failures_path.exists(),
+        "intake should write source failure receipts for skipped configured sources"
+    );
+    let source_failures: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&source_failures_path).unwrap()).unwrap();
+    assert_eq!(source_failures["schema_version"], 1);
+    assert_eq!(
+        source_failures["run_id"].as_str().unwrap(),
+        run_dir.file_name().unwrap().to_string_lossy().as_ref()
+    );
+    assert_eq!(source_failures["failures"].as_array().unwrap().len(), 2);
     assert!(
-        !stdout.contains("- Jira: included"),
-        "runtime Jira filter failure should override stale preflight inclusion"
+        source_failures["failures"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|failure| failure["source"] == "jira"
+                && failure["kind"] == "missing_token"
+                && failure["rerun_command"]
+                    .as_str()
+                    .unwrap()
+                    .contains("shiplog intake --config"))
     );
     assert!(
-        !stdout.contains("- Linear: included"),
-        "runtime Linear filter failure should override stale preflight inclusion"
+        report_json["artifacts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|artifact| artifact["path"]
+                .as_str()
+                .unwrap()
+                .ends_with("source.failures.json")),
+        "intake report should expose the source failure receipt artifact"
     );
-    assert!(stdout.contains("Repair sources:"));
-    assert!(stdout.contains("kind: invalid_filter"));
-    assert!(stdout.contains("Set sources.gitlab.state to opened, merged, closed, or all."));
-    assert!(stdout.contains("Set sources.jira.status to open, in_progress, done, closed, or all."));
-    assert!(stdout.contains(
-        "Set sources.linear.status to backlog, todo, in_progress, done, cancelled, or all."
-    ));
-    assert!(stdout.contains("Retry with `shiplog intake --last-6-months --explain`."));
-
-    for secret in [gitlab_token, jira_token, linear_key] {
-        assert!(
-            !stdout.contains(secret),
-            "intake stdout should not print secret sentinel {secret:?}"
-        );
-    }
+    assert!(report_md.contains("## Skipped Sources"));
+    assert!(report_md.contains("## Repair Sources"));
+    assert!(report_md.contains("- Jira: missing JIRA_TOKEN"));
+    assert!(report_md.contains("- Linear: missing LINEAR_API_KEY"));
+    assert!(report_md.contains("kind: `missing_token`"));
+    assert!(report_md.contains("export JIRA_TOKEN=..."));
+    assert!(report_md.contains("shiplog identify jira --auth-user <email>"));
+    assert!(report_md.contains("export LINEAR_API_KEY=..."));
+    assert!(report_md.contains("shiplog identify linear"));
+    assert!(report_md.contains("shiplog sources status"));
+    assert!(report_md.contains("--source jira"));
+    assert!(report_md.contains("--source linear"));
 
-    let run_dir = first_run_dir(&out);
+    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 2);
+    assert_eq!(report_json["repair_sources"].as_array().unwrap().len(), 2);
     assert!(
-        github_cache_path.exists(),
-        "intake should not delete existing source cache files"
+        report_json["skipped_sources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|source| source["source"] == "jira"
+                && source["source_key"] == "jira"
+                && source["source_label"] == "Jira"
+                && source["reason"] == "missing JIRA_TOKEN")
     );
-    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    assert!(coverage.contains("\"Partial\""));
-    assert!(coverage.contains("Configured source gitlab was skipped"));
-    assert!(coverage.contains("Configured source jira was skipped"));
-    assert!(coverage.contains("Configured source linear was skipped"));
-    assert!(coverage.contains("Invalid MR state: needs_review"));
-    assert!(coverage.contains("Invalid issue status: blocked"));
-    assert!(coverage.contains("Invalid issue status: waiting"));
-
-    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs curation");
-    for secret in [gitlab_token, jira_token, linear_key] {
-        assert!(
-            !report_md.contains(secret) && !report_json.to_string().contains(secret),
-            "intake report should not print secret sentinel {secret:?}"
-        );
-    }
-    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 3);
-    let source_decisions = report_json["source_decisions"].as_array().unwrap();
-    for source_key in ["gitlab", "jira", "linear"] {
-        assert!(
-            source_decisions.iter().any(|decision| {
-                decision["source_key"] == source_key
-                    && decision["decision"] == "skipped"
-                    && decision["reason"]
-                        .as_str()
-                        .is_some_and(|reason| reason.starts_with("create configured"))
-            }),
-            "source_decisions should report {source_key} runtime filter failure as skipped"
-        );
-        assert!(
-            !source_decisions.iter().any(|decision| {
-                decision["source_key"] == source_key && decision["decision"] == "included"
-            }),
-            "source_decisions should not keep stale {source_key} inclusion after runtime failure"
-        );
-    }
-    let repairs = report_json["repair_sources"].as_array().unwrap();
-    assert!(repairs.iter().any(|repair| {
-        repair["source"] == "gitlab"
-            && repair["source_key"] == "gitlab"
-            && repair["source_label"] == "GitLab"
-            && repair["kind"] == "invalid_filter"
-            && repair["commands"]
-                .as_array()
-                .unwrap()
-                .iter()
-                .any(|command| {
-                    command.as_str().is_some_and(|command| {
-                        command.contains("shiplog sources status")
-                            && command.contains("--source gitlab")
-                    })
-                })
-    }));
-    assert!(repairs.iter().any(|repair| {
-        repair["source"] == "jira"
-            && repair["source_key"] == "jira"
-            && repair["source_label"] == "Jira"
-            && repair["kind"] == "invalid_filter"
-            && repair["commands"]
-                .as_array()
-                .unwrap()
-                .iter()
-                .any(|command| {
-                    command.as_str().is_some_and(|command| {
-                        command.contains("shiplog sources status")
-                            && command.contains("--source jira")
-                    })
-                })
-    }));
-    assert!(repairs.iter().any(|repair| {
-        repair["source"] == "linear"
-            && repair["source_key"] == "linear"
-            && repair["source_label"] == "Linear"
-            && repair["kind"] == "invalid_filter"
-            && repair["commands"]
-                .as_array()
-                .unwrap()
-                .iter()
-                .any(|command| {
-                    command.as_str().is_some_and(|command| {
-                        command.contains("shiplog sources status")
-                            && command.contains("--source linear")
-                    })
-                })
-    }));
     assert!(
         report_json["next_commands"]
             .as_array()
             .unwrap()
             .iter()
             .any(|command| command.as_str().unwrap().contains("shiplog doctor")),
-        "intake report should keep rerun/repair guidance visible"
+        "skipped-source intake report should keep repair/rerun next commands"
+    );
+    assert!(
+        report_json["source_decisions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|decision| decision["source"] == "jira"
+                && decision["source_key"] == "jira"
+                && decision["source_label"] == "Jira"
+                && decision["hint_lines"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|line| line.as_str().unwrap().contains("JIRA_TOKEN")))
+    );
+    assert!(
+        report_json["repair_sources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|repair| repair["source"] == "jira"
+                && repair["source_key"] == "jira"
+                && repair["source_label"] == "Jira"
+                && repair["kind"] == "missing_token"
+                && repair["commands"].as_array().unwrap().iter().any(|line| {
+                    let Some(line) = line.as_str() else {
+                        return false;
+                    };
+                    line.contains("shiplog sources status") && line.contains("--source jira")
+                }))
+    );
+    assert!(
+        report_json["repair_sources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|repair| repair["source_key"] == "jira" || repair["source_key"] == "linear")
+            .flat_map(|repair| repair["commands"].as_array().into_iter().flatten())
+            .filter_map(|command| command.as_str())
+            .all(|command| !command.contains("export ") && !command.contains("shiplog identify")),
+        "setup-blocked repair sources should hand off to read-only setup status instead of token or identity commands: {:?}",
+        report_json["repair_sources"]
+    );
+    let repair_items = report_json["repair_items"].as_array().unwrap();
+    assert!(
+        repair_items
+            .iter()
+            .any(|item| item["kind"] == "source_skipped_configuration"
+                && item["source_key"] == "jira"
+                && item["action"]["kind"] == "configure_source"
+                && item["action"]["command"]
+                    .as_str()
+                    .is_some_and(|command| command.contains("shiplog sources status")
+                        && command.contains("--source jira"))
+                && item["receipt_refs"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|receipt| receipt["field"] == "repair_sources"
+                        && receipt["source_key"] == "jira")),
+        "skipped Jira source should produce a receipt-derived repair item"
+    );
+    assert!(
+        repair_items
+            .iter()
+            .any(|item| item["kind"] == "artifact_missing_or_unopened"
+                && item["action"]["kind"] == "open_artifact"
+                && item["receipt_refs"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|receipt| receipt["field"] == "artifacts")),
+        "source failure artifact should produce an artifact repair item"
     );
+    for (source_key, source_label) in [("jira", "Jira"), ("linear", "Linear")] {
+        assert!(
+            report_json["source_decisions"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|decision| decision["source_key"] == source_key
+                    && decision["source_label"] == source_label),
+            "source_decisions should expose canonical identity for {source_key}"
+        );
+        assert!(
+            report_json["source_freshness"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|freshness| freshness["source_key"] == source_key
+                    && freshness["source_label"] == source_label
+                    && freshness["status"] == "unavailable"),
+            "source_freshness should join to source_decisions on source_key for {source_key}"
+        );
+    }
 
-    shiplog_cmd()
+    let repair_plan = shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["repair", "plan", "--out", out.to_str().unwrap(), "--latest"])
+        .assert()
+        .success();
+    let repair_plan_stdout = String::from_utf8(repair_plan.get_output().stdout.clone()).unwrap();
+    assert!(
+        repair_plan_stdout.contains("shiplog sources status")
+            && repair_plan_stdout.contains("--source jira")
+            && repair_plan_stdout.contains("--source linear"),
+        "repair plan should route provider setup gaps through read-only source status. stdout:\n{repair_plan_stdout}"
+    );
+    assert!(
+        !repair_plan_stdout.contains("shiplog identify")
+            && !repair_plan_stdout.contains("export JIRA_TOKEN")
+            && !repair_plan_stdout.contains("export LINEAR_API_KEY"),
+        "repair plan should not print setup-blocked provider token/identity commands. stdout:\n{repair_plan_stdout}"
+    );
+
+    assert!(
+        coverage.contains("Configured source jira was skipped: missing JIRA_TOKEN"),
+        "intake coverage should record skipped Jira source"
+    );
+    assert!(
+        coverage.contains("Configured source linear was skipped: missing LINEAR_API_KEY"),
+        "intake coverage should record skipped Linear source"
+    );
+    assert!(
+        coverage.contains("\"Partial\""),
+        "intake coverage should mark partial source collection"
+    );
+}
+
+#[test]
+fn intake_records_remote_filter_failures_without_network_and_keeps_repair_guidance() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    let fixtures = fixture_dir();
+    let cache_dir = tmp.path().join(".cache");
+    let github_cache_path = seed_github_cache(&cache_dir);
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
+
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+window = "year:2025"
+
+[sources.json]
+enabled = true
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
+
+[sources.gitlab]
+enabled = true
+user = "octo"
+instance = "https://gitlab.self-hosted.example/platform/reliability"
+state = "needs_review"
+cache_dir = "./.cache"
+
+[sources.jira]
+enabled = true
+user = "712020:account-id"
+instance = "example.atlassian.net"
+status = "blocked"
+cache_dir = "./.cache"
+
+[sources.linear]
+enabled = true
+user_id = "linear-user-id"
+status = "waiting"
+project = "OPS"
+cache_dir = "./.cache"
+"#,
+    )
+    .unwrap();
+
+    let gitlab_token = "edge-gitlab-token-do-not-print";
+    let jira_token = "edge-jira-token-do-not-print";
+    let linear_key = "edge-linear-key-do-not-print";
+    let assert = shiplog_cmd()
+        .current_dir(tmp.path())
+        .env("GITLAB_TOKEN", gitlab_token)
+        .env("JIRA_TOKEN", jira_token)
+        .env("LINEAR_API_KEY", linear_key)
+        .env_remove("GITHUB_TOKEN")
+        .args([
+            "intake",
+            "--out",
+            out.to_str().unwrap(),
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--year",
+            "2025",
+            "--no-open",
+            "--explain",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("Review intake complete."));
+    assert!(stdout.contains("- JSON: success"));
+    assert!(stdout.contains("- GitLab: create configured GitLab ingestor"));
+    assert!(stdout.contains("Invalid MR state: needs_review"));
+    assert!(stdout.contains("- Jira: create configured Jira ingestor"));
+    assert!(stdout.contains("Invalid issue status: blocked"));
+    assert!(stdout.contains("- Linear: create configured Linear ingestor"));
+    assert!(stdout.contains("Invalid issue status: waiting"));
+    assert!(stdout.contains("Source decisions:"));
+    assert!(stdout.contains("- GitLab: skipped, create configured GitLab ingestor"));
+    assert!(stdout.contains("- Jira: skipped, create configured Jira ingestor"));
+    assert!(stdout.contains("- Linear: skipped, create configured Linear ingestor"));
+    assert!(
+        !stdout.contains("- GitLab: included"),
+        "runtime GitLab filter failure should override stale preflight inclusion"
+    );
+    assert!(
+        !stdout.contains("- Jira: included"),
+        "runtime Jira filter failure should override stale preflight inclusion"
+    );
+    assert!(
+        !stdout.contains("- Linear: included"),
+        "runtime Linear filter failure should override stale preflight inclusion"
+    );
+    assert!(stdout.contains("Repair sources:"));
+    assert!(stdout.contains("kind: invalid_filter"));
+    assert!(stdout.contains("Set sources.gitlab.state to opened, merged, closed, or all."));
+    assert!(stdout.contains("Set sources.jira.status to open, in_progress, done, closed, or all."));
+    assert!(stdout.contains(
+        "Set sources.linear.status to backlog, todo, in_progress, done, cancelled, or all."
+    ));
+    assert!(stdout.contains("Retry with `shiplog intake --last-6-months --explain`."));
+
+    for secret in [gitlab_token, jira_token, linear_key] {
+        assert!(
+            !stdout.contains(secret),
+            "intake stdout should not print secret sentinel {secret:?}"
+        );
+    }
+
+    let run_dir = first_run_dir(&out);
+    assert!(
+        github_cache_path.exists(),
+        "intake should not delete existing source cache files"
+    );
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    assert!(coverage.contains("\"Partial\""));
+    assert!(coverage.contains("Configured source gitlab was skipped"));
+    assert!(coverage.contains("Configured source jira was skipped"));
+    assert!(coverage.contains("Configured source linear was skipped"));
+    assert!(coverage.contains("Invalid MR state: needs_review"));
+    assert!(coverage.contains("Invalid issue status: blocked"));
+    assert!(coverage.contains("Invalid issue status: waiting"));
+
+    let (report_md, report_json) = assert_golden_intake_report(&run_dir, "Needs curation");
+    for secret in [gitlab_token, jira_token, linear_key] {
+        assert!(
+            !report_md.contains(secret) && !report_json.to_string().contains(secret),
+            "intake report should not print secret sentinel {secret:?}"
+        );
+    }
+    assert_eq!(report_json["skipped_sources"].as_array().unwrap().len(), 3);
+    let source_decisions = report_json["source_decisions"].as_array().unwrap();
+    for source_key in ["gitlab", "jira", "linear"] {
+        assert!(
+            source_decisions.iter().any(|decision| {
+                decision["source_key"] == source_key
+                    && decision["decision"] == "skipped"
+                    && decision["reason"]
+                        .as_str()
+                        .is_some_and(|reason| reason.starts_with("create configured"))
+            }),
+            "source_decisions should report {source_key} runtime filter failure as skipped"
+        );
+        assert!(
+            !source_decisions.iter().any(|decision| {
+                decision["source_key"] == source_key && decision["decision"] == "included"
+            }),
+            "source_decisions should not keep stale {source_key} inclusion after runtime failure"
+        );
+    }
+    let repairs = report_json["repair_sources"].as_array().unwrap();
+    assert!(repairs.iter().any(|repair| {
+        repair["source"] == "gitlab"
+            && repair["source_key"] == "gitlab"
+            && repair["source_label"] == "GitLab"
+            && repair["kind"] == "invalid_filter"
+            && repair["commands"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|command| {
+                    command.as_str().is_some_and(|command| {
+                        command.contains("shiplog sources status")
+                            && command.contains("--source gitlab")
+                    })
+                })
+    }));
+    assert!(repairs.iter().any(|repair| {
+        repair["source"] == "jira"
+            && repair["source_key"] == "jira"
+            && repair["source_label"] == "Jira"
+            && repair["kind"] == "invalid_filter"
+            && repair["commands"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|command| {
+                    command.as_str().is_some_and(|command| {
+                        command.contains("shiplog sources status")
+                            && command.contains("--source jira")
+                    })
+                })
+    }));
+    assert!(repairs.iter().any(|repair| {
+        repair["source"] == "linear"
+            && repair["source_key"] == "linear"
+            && repair["source_label"] == "Linear"
+            && repair["kind"] == "invalid_filter"
+            && repair["commands"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|command| {
+                    command.as_str().is_some_and(|command| {
+                        command.contains("shiplog sources status")
+                            && command.contains("--source linear")
+                    })
+                })
+    }));
+    assert!(
+        report_json["next_commands"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|command| command.as_str().unwrap().contains("shiplog doctor")),
+        "intake report should keep rerun/repair guidance visible"
+    );
+
+    shiplog_cmd()
         .args([
             "cache",
             "stats",
@@ -11848,1739 +12597,3372 @@ cache_dir = "./.cache"
             "github",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("github:"))
-        .stdout(predicate::str::contains(
-            "entries: total 2, valid 1, expired 1",
+        .success()
+        .stdout(predicate::str::contains("github:"))
+        .stdout(predicate::str::contains(
+            "entries: total 2, valid 1, expired 1",
+        ));
+}
+
+#[test]
+fn intake_rerun_reuses_prior_curation_without_overwriting_manual_events() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    let manual_events = tmp.path().join("manual_events.yaml");
+    let config_path = tmp.path().join("shiplog.toml");
+    write_manual_events(&manual_events);
+
+    std::fs::write(
+        &config_path,
+        r#"[defaults]
+window = "year:2025"
+
+[user]
+label = "octo"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#,
+    )
+    .unwrap();
+
+    let out_arg = out.to_str().unwrap().to_string();
+    let config_arg = config_path.to_str().unwrap().to_string();
+    let intake_args = [
+        "intake",
+        "--out",
+        out_arg.as_str(),
+        "--config",
+        config_arg.as_str(),
+        "--year",
+        "2025",
+        "--no-open",
+    ];
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(intake_args)
+        .assert()
+        .success();
+
+    let first_run = first_run_dir(&out);
+    let suggested_text =
+        std::fs::read_to_string(first_run.join("workstreams.suggested.yaml")).unwrap();
+    let mut curated: WorkstreamsFile = serde_yaml::from_str(&suggested_text).unwrap();
+    curated.workstreams[0].title = "Curated Reliability".to_string();
+    std::fs::write(
+        first_run.join("workstreams.yaml"),
+        serde_yaml::to_string(&curated).unwrap(),
+    )
+    .unwrap();
+    let first_curated_before = std::fs::read_to_string(first_run.join("workstreams.yaml")).unwrap();
+    let manual_before = std::fs::read_to_string(&manual_events).unwrap();
+
+    let assert = shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(intake_args)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Curation:"))
+        .stdout(predicate::str::contains("Prior workstream curation reused"));
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("workstreams.yaml"));
+
+    let runs = all_run_dirs(&out);
+    assert_eq!(runs.len(), 2, "intake rerun should create a new run");
+    let second_run = runs
+        .into_iter()
+        .find(|run| run != &first_run)
+        .expect("expected a second intake run");
+
+    assert_eq!(
+        std::fs::read_to_string(first_run.join("workstreams.yaml")).unwrap(),
+        first_curated_before,
+        "intake rerun should not overwrite prior curated workstreams"
+    );
+    assert_eq!(
+        std::fs::read_to_string(second_run.join("workstreams.yaml")).unwrap(),
+        first_curated_before,
+        "intake rerun should copy prior curated workstreams into the new run"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&manual_events).unwrap(),
+        manual_before,
+        "intake rerun should not rewrite manual_events.yaml"
+    );
+
+    let (report_md, report_json) = assert_golden_intake_report(&second_run, "Ready for review");
+    assert!(report_md.contains("## Curation Notes"));
+    assert!(report_md.contains("Prior workstream curation reused"));
+
+    assert!(
+        report_json["curation_notes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|note| note
+                .as_str()
+                .unwrap()
+                .contains("Prior workstream curation reused"))
+    );
+}
+
+#[test]
+fn intake_explain_reports_configured_source_decisions() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    let fixtures = fixture_dir();
+    std::fs::copy(
+        fixtures.join("ledger.events.jsonl"),
+        tmp.path().join("ledger.events.jsonl"),
+    )
+    .unwrap();
+    std::fs::copy(
+        fixtures.join("coverage.manifest.json"),
+        tmp.path().join("coverage.manifest.json"),
+    )
+    .unwrap();
+
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+window = "year:2025"
+
+[sources.json]
+enabled = true
+events = "./ledger.events.jsonl"
+coverage = "./coverage.manifest.json"
+
+[sources.jira]
+enabled = true
+user = "712020:account-id"
+instance = "example.atlassian.net"
+status = "done"
+
+[sources.linear]
+enabled = true
+user_id = "linear-user-id"
+status = "done"
+"#,
+    )
+    .unwrap();
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args([
+            "intake",
+            "--out",
+            out.to_str().unwrap(),
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "--no-open",
+            "--explain",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Source decisions:"))
+        .stdout(predicate::str::contains(
+            "- JSON: included, events and coverage files found",
+        ))
+        .stdout(predicate::str::contains(
+            "- Jira: skipped, missing JIRA_TOKEN",
+        ))
+        .stdout(predicate::str::contains("export JIRA_TOKEN=..."))
+        .stdout(predicate::str::contains(
+            "shiplog identify jira --auth-user <email>",
+        ))
+        .stdout(predicate::str::contains(
+            "- Linear: skipped, missing LINEAR_API_KEY",
+        ))
+        .stdout(predicate::str::contains("export LINEAR_API_KEY=..."))
+        .stdout(predicate::str::contains("shiplog identify linear"))
+        .stdout(predicate::str::contains(
+            "Retry with `shiplog intake --last-6-months --explain`.",
+        ));
+}
+
+#[test]
+fn collect_multi_without_enabled_sources_fails_actionably() {
+    let tmp = TempDir::new().unwrap();
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[sources.json]
+enabled = false
+"#,
+    )
+    .unwrap();
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            tmp.path().join("out").to_str().unwrap(),
+            "multi",
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No enabled sources found"));
+}
+
+#[test]
+fn merge_missing_input_run_fails_actionably() {
+    let tmp = TempDir::new().unwrap();
+    let missing = tmp.path().join("missing-run");
+
+    shiplog_cmd()
+        .args([
+            "merge",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--input",
+            missing.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No ledger.events.jsonl found"));
+}
+
+#[test]
+fn collect_json_public_profile_without_key_fails_closed() {
+    let tmp = TempDir::new().unwrap();
+    let fixtures = fixture_dir();
+
+    let mut cmd = shiplog_cmd();
+    cmd.env_remove("SHIPLOG_REDACT_KEY")
+        .args([
+            "collect",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--bundle-profile",
+            "public",
+            "json",
+            "--events",
+            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
+            "--coverage",
+            fixtures.join("coverage.manifest.json").to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("public profile requires --redact-key or SHIPLOG_REDACT_KEY")
+                .and(predicate::str::contains("Try:"))
+                .and(predicate::str::contains(redaction_key_env_command(
+                    "SHIPLOG_REDACT_KEY",
+                )))
+                .and(predicate::str::contains(
+                    "rerun this command with --bundle-profile public",
+                )),
+        );
+}
+
+#[test]
+fn collect_json_packet_contains_expected_content() {
+    let tmp = TempDir::new().unwrap();
+    let fixtures = fixture_dir();
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "json",
+            "--events",
+            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
+            "--coverage",
+            fixtures.join("coverage.manifest.json").to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let packet = std::fs::read_to_string(tmp.path().join("run_fixture/packet.md")).unwrap();
+    // Fixture data contains PRs from acme/payments and acme/platform
+    assert!(
+        packet.contains("acme/payments") || packet.contains("acme/platform"),
+        "packet.md should reference fixture repos"
+    );
+}
+
+#[test]
+fn workstreams_list_shows_latest_run_workstreams() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args(["workstreams", "list", "--out", tmp.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Workstreams:"))
+        .stdout(predicate::str::contains("suggested workstreams"))
+        .stdout(predicate::str::contains("Count:"))
+        .stdout(predicate::str::contains("acme/platform"))
+        .stdout(predicate::str::contains("events="));
+}
+
+#[test]
+fn runs_list_shows_run_summaries() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args(["runs", "list", "--out", tmp.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Runs:"))
+        .stdout(predicate::str::contains("run_fixture"))
+        .stdout(predicate::str::contains("sources: github"))
+        .stdout(predicate::str::contains("events: 3"))
+        .stdout(predicate::str::contains("coverage: Complete"))
+        .stdout(predicate::str::contains("packet:"));
+}
+
+#[test]
+fn history_lists_indexed_runs_and_finds_curated_ones() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let out_arg = tmp.path().to_str().unwrap();
+
+    let index = std::fs::read_to_string(tmp.path().join("runs.index.json")).unwrap();
+    assert!(index.contains("\"dir\": \"run_fixture\""), "{index}");
+
+    shiplog_cmd()
+        .args(["history", "list", "--out", out_arg])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Runs: 1 of 1"))
+        .stdout(predicate::str::contains("- run_fixture"))
+        .stdout(predicate::str::contains("window: 2025-01-01..2025-04-01"))
+        .stdout(predicate::str::contains("sources: github (3)"))
+        .stdout(predicate::str::contains("workstreams: 2 (suggested)"))
+        .stdout(predicate::str::contains("readiness: "));
+    shiplog_cmd()
+        .args(["history", "list", "--out", out_arg, "--curated"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Runs: 0 of 1"));
+
+    std::fs::copy(
+        run_dir.join("workstreams.suggested.yaml"),
+        run_dir.join("workstreams.yaml"),
+    )
+    .unwrap();
+    shiplog_cmd()
+        .args([
+            "history",
+            "list",
+            "--out",
+            out_arg,
+            "--curated",
+            "--rebuild",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Runs: 1 of 1"))
+        .stdout(predicate::str::contains("workstreams: 2 (curated)"));
+
+    shiplog_cmd()
+        .args([
+            "history",
+            "open",
+            "latest",
+            "--out",
+            out_arg,
+            "--print-path",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("packet.md"));
+    shiplog_cmd()
+        .args(["history", "open", "2019-13", "--out", out_arg])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no indexed run matches \"2019-13\"",
+        ));
+}
+
+#[test]
+fn history_shipped_and_covering_query_the_history_db() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+    let out_arg = tmp.path().to_str().unwrap();
+    assert!(tmp.path().join("history.db").is_file());
+
+    shiplog_cmd()
+        .args([
+            "history",
+            "shipped",
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2025-02-15",
+            "--out",
+            out_arg,
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Shipped: 2025-01-01..2025-02-15"))
+        .stdout(predicate::str::contains("Events: 2"))
+        .stdout(predicate::str::contains(
+            "2025-01-15 PullRequest acme/payments: Payments ledger rewrite (run run_fixture)",
+        ))
+        .stdout(predicate::str::contains("Schema hardening").not());
+
+    let output = shiplog_cmd()
+        .args([
+            "history",
+            "shipped",
+            "--since",
+            "2025-03-01",
+            "--until",
+            "2025-04-01",
+            "--out",
+            out_arg,
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let shipped: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(shipped.as_array().unwrap().len(), 1);
+    assert_eq!(shipped[0]["run_dir"], "run_fixture");
+
+    shiplog_cmd()
+        .args(["history", "covering", "2025-03-10", "--out", out_arg])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Covering 2025-03-10: run_fixture"))
+        .stdout(predicate::str::contains("outputs: "))
+        .stdout(predicate::str::contains("packet.md"));
+    shiplog_cmd()
+        .args(["history", "covering", "2025-04-01", "--out", out_arg])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no recorded run covers 2025-04-01",
+        ));
+
+    // Runs the database has not seen yet are recorded on first query.
+    std::fs::remove_file(tmp.path().join("history.db")).unwrap();
+    shiplog_cmd()
+        .args(["history", "covering", "2025-01-01", "--out", out_arg])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("run_fixture"))
+        .stderr(predicate::str::contains("Recorded: "));
+}
+
+#[test]
+fn runs_show_latest_shows_run_details() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args([
+            "runs",
+            "show",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "latest",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Run: run_fixture"))
+        .stdout(predicate::str::contains("User: octo"))
+        .stdout(predicate::str::contains("Window: 2025-01-01..2025-04-01"))
+        .stdout(predicate::str::contains("Sources: github"))
+        .stdout(predicate::str::contains("Events: 3"))
+        .stdout(predicate::str::contains("Gaps: 0"))
+        .stdout(predicate::str::contains("Warnings: none"));
+}
+
+#[test]
+fn runs_migrate_upgrades_unversioned_run_in_place() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let out_arg = tmp.path().to_str().unwrap();
+
+    // Rewrite the run as a pre-versioning build would have written it.
+    let events_path = run_dir.join("ledger.events.jsonl");
+    let coverage_path = run_dir.join("coverage.manifest.json");
+    let mut legacy_ledger = String::new();
+    for line in std::fs::read_to_string(&events_path).unwrap().lines() {
+        let mut event: serde_json::Value = serde_json::from_str(line).unwrap();
+        event.as_object_mut().unwrap().remove("schema_version");
+        legacy_ledger.push_str(&format!("{event}\n"));
+    }
+    std::fs::write(&events_path, &legacy_ledger).unwrap();
+    let mut coverage: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&coverage_path).unwrap()).unwrap();
+    coverage.as_object_mut().unwrap().remove("schema_version");
+    std::fs::write(&coverage_path, coverage.to_string()).unwrap();
+
+    shiplog_cmd()
+        .args(["runs", "migrate", "--out", out_arg, "--latest", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Ledger: would migrate 0.2.0 -> 0.3.0 (3 events, 0 incidents promoted)",
+        ))
+        .stdout(predicate::str::contains(
+            "Coverage: would migrate 0.2.0 -> 0.3.0",
+        ));
+    assert_eq!(
+        std::fs::read_to_string(&events_path).unwrap(),
+        legacy_ledger
+    );
+
+    shiplog_cmd()
+        .args(["runs", "migrate", "--out", out_arg, "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Ledger: migrated 0.2.0 -> 0.3.0"))
+        .stdout(predicate::str::contains("Run migrated"));
+    let ledger = std::fs::read_to_string(&events_path).unwrap();
+    assert_eq!(ledger.matches(r#""schema_version":"0.3.0""#).count(), 3);
+    assert!(
+        std::fs::read_to_string(&coverage_path)
+            .unwrap()
+            .contains(r#""schema_version": "0.3.0""#)
+    );
+
+    shiplog_cmd()
+        .args(["runs", "migrate", "--out", out_arg, "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Migration: already current (0.3.0)",
+        ));
+}
+
+#[test]
+fn runs_migrate_rejects_ledgers_from_newer_builds() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let coverage_path = run_dir.join("coverage.manifest.json");
+    let coverage = std::fs::read_to_string(&coverage_path).unwrap().replace(
+        r#""schema_version": "0.3.0""#,
+        r#""schema_version": "9.0.0""#,
+    );
+    std::fs::write(&coverage_path, coverage).unwrap();
+
+    shiplog_cmd()
+        .args([
+            "runs",
+            "migrate",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--latest",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "coverage manifest uses schema_version 9.0.0",
+        ));
+}
+
+#[test]
+fn validate_accepts_a_collected_run_directory() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args(["validate", run_dir.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "ledger.events.jsonl: ok (event-envelope,",
+        ))
+        .stdout(predicate::str::contains(
+            "coverage.manifest.json: ok (coverage-manifest, 1 records)",
+        ));
+}
+
+#[test]
+fn validate_reports_line_and_field_for_malformed_ledgers() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let ledger = run_dir.join("ledger.events.jsonl");
+    let text = std::fs::read_to_string(&ledger).unwrap();
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    let mut event: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+    event["occurred_at"] = serde_json::json!("yesterday");
+    lines[1] = event.to_string();
+    std::fs::write(&ledger, lines.join("\n") + "\n").unwrap();
+
+    shiplog_cmd()
+        .args(["validate", ledger.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "line 2: /occurred_at: expected an RFC 3339 timestamp",
+        ))
+        .stderr(predicate::str::contains(
+            "1 of 1 files failed schema validation",
+        ));
+}
+
+#[test]
+fn validate_prints_published_schema() {
+    let output = shiplog_cmd()
+        .args(["validate", "--print-schema", "event-envelope"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["title"], "Shiplog event envelope v0.3.0");
+    let published = std::fs::read_to_string(
+        repo_root().join("contracts/schemas/event-envelope.v0.3.0.schema.json"),
+    )
+    .unwrap();
+    assert_eq!(
+        schema,
+        serde_json::from_str::<serde_json::Value>(&published).unwrap()
+    );
+}
+
+#[test]
+fn runs_compare_summarizes_cross_run_changes_without_writing_artifacts() {
+    let tmp = TempDir::new().unwrap();
+    let from_run = collect_json_into(tmp.path());
+    let from_packet_before = std::fs::read_to_string(from_run.join("packet.md")).unwrap();
+    let from_coverage_before =
+        std::fs::read_to_string(from_run.join("coverage.manifest.json")).unwrap();
+
+    let events_path = tmp.path().join("all-source.events.jsonl");
+    let coverage_path = tmp.path().join("all-source.coverage.json");
+    let events = all_source_fixture_events();
+    let coverage = all_source_fixture_coverage();
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "json",
+            "--events",
+            events_path.to_str().unwrap(),
+            "--coverage",
+            coverage_path.to_str().unwrap(),
+            "--user",
+            "octo",
+            "--window-label",
+            "all-source fixture",
+        ])
+        .assert()
+        .success();
+
+    let to_run = tmp.path().join("run_all_sources");
+    let to_packet_before = std::fs::read_to_string(to_run.join("packet.md")).unwrap();
+    let to_coverage_before =
+        std::fs::read_to_string(to_run.join("coverage.manifest.json")).unwrap();
+
+    let assert = shiplog_cmd()
+        .args([
+            "runs",
+            "compare",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--from",
+            "run_fixture",
+            "--to",
+            "run_all_sources",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let out_hint = format!("--out \"{}\"", tmp.path().display());
+
+    assert!(stdout.contains("Compare: run_fixture -> run_all_sources"));
+    assert!(stdout.contains("Events:"));
+    assert!(stdout.contains("- from: 3"));
+    assert!(stdout.contains("- to: 8"));
+    assert!(stdout.contains("- delta: +5"));
+    assert!(stdout.contains("Sources:"));
+    assert!(stdout.contains("- Added: Local git, GitLab, Jira, JSON, Linear, Manual"));
+    assert!(stdout.contains("- Continued: GitHub"));
+    assert!(stdout.contains("Workstreams:"));
+    assert!(stdout.contains("- Added:"));
+    assert!(stdout.contains("acme/release-tools"));
+    assert!(stdout.contains("- Removed:"));
+    assert!(stdout.contains("acme/platform"));
+    assert!(stdout.contains("Coverage:"));
+    assert!(stdout.contains("- from: Complete, gaps: 0"));
+    assert!(stdout.contains("- to: Complete, gaps: 0"));
+    assert!(stdout.contains(&format!("shiplog review {out_hint}")));
+    assert!(stdout.contains("--run run_all_sources"));
+
+    assert_eq!(
+        from_packet_before,
+        std::fs::read_to_string(from_run.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        from_coverage_before,
+        std::fs::read_to_string(from_run.join("coverage.manifest.json")).unwrap()
+    );
+    assert_eq!(
+        to_packet_before,
+        std::fs::read_to_string(to_run.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        to_coverage_before,
+        std::fs::read_to_string(to_run.join("coverage.manifest.json")).unwrap()
+    );
+}
+
+#[test]
+fn runs_compare_resolves_named_period_selectors() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    let events_path = tmp.path().join("period-to.events.jsonl");
+    let coverage_path = tmp.path().join("period-to.coverage.json");
+    let events = all_source_fixture_events();
+    let mut coverage = all_source_fixture_coverage();
+    coverage.run_id = RunId("run_period_to".into());
+    coverage.window = TimeWindow {
+        since: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+        until: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+    };
+    for slice in &mut coverage.slices {
+        slice.window = coverage.window.clone();
+    }
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "json",
+            "--events",
+            events_path.to_str().unwrap(),
+            "--coverage",
+            coverage_path.to_str().unwrap(),
+            "--user",
+            "octo",
+            "--window-label",
+            "period to fixture",
+        ])
+        .assert()
+        .success();
+
+    let config_path = tmp.path().join("shiplog.toml");
+    std::fs::write(
+        &config_path,
+        r#"[periods."2025-Q1"]
+since = "2025-01-01"
+until = "2025-04-01"
+
+[periods."2025-Q2"]
+since = "2025-04-01"
+until = "2025-07-01"
+"#,
+    )
+    .unwrap();
+
+    let assert = shiplog_cmd()
+        .args([
+            "runs",
+            "compare",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--config",
+            config_path.to_str().unwrap(),
+            "--from-period",
+            "2025-Q1",
+            "--to-period",
+            "2025-Q2",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let out_hint = format!("--out \"{}\"", tmp.path().display());
+
+    assert!(stdout.contains("Compare: run_fixture -> run_period_to"));
+    assert!(stdout.contains("- from: 3"));
+    assert!(stdout.contains("- to: 8"));
+    assert!(stdout.contains("- Added: Local git, GitLab, Jira, JSON, Linear, Manual"));
+    assert!(stdout.contains(&format!("shiplog review {out_hint}")));
+    assert!(stdout.contains("--run run_period_to"));
+}
+
+#[test]
+fn runs_compare_requires_selector_for_each_side() {
+    let tmp = TempDir::new().unwrap();
+
+    shiplog_cmd()
+        .args([
+            "runs",
+            "compare",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--to",
+            "latest",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing --from or --from-period"));
+}
+
+#[test]
+fn coverage_diff_reports_closed_gaps_and_source_changes() {
+    let tmp = TempDir::new().unwrap();
+    let mut before = all_source_fixture_coverage();
+    before.run_id = RunId("run_before".into());
+    before.sources.retain(|source| source != "jira");
+    before.slices.retain(|slice| slice.query != "jira fixture");
+    before.slices[0].fetched = 1;
+    before.completeness = Completeness::Partial;
+    let mut after = all_source_fixture_coverage();
+    after.run_id = RunId("run_after".into());
+    for coverage in [&before, &after] {
+        let run_dir = tmp.path().join(coverage.run_id.to_string());
+        std::fs::create_dir_all(&run_dir).unwrap();
+        write_coverage_manifest(&run_dir.join("coverage.manifest.json"), coverage);
+    }
+
+    shiplog_cmd()
+        .args([
+            "coverage",
+            "diff",
+            "run_before",
+            "run_after",
+            "--out",
+            tmp.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Coverage diff: run_before -> run_after",
+        ))
+        .stdout(predicate::str::contains("- changed: no"))
+        .stdout(predicate::str::contains("- Added: jira"))
+        .stdout(predicate::str::contains("\"github fixture\": 2/2 fetched"))
+        .stdout(predicate::str::contains("Gaps: closed"));
+
+    let manifest_path = tmp.path().join("run_before/coverage.manifest.json");
+    let assert = shiplog_cmd()
+        .args([
+            "coverage",
+            "diff",
+            manifest_path.to_str().unwrap(),
+            "run_after",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--json",
+        ])
+        .assert()
+        .success();
+    let json: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+    assert_eq!(json["sources_added"], serde_json::json!(["jira"]));
+    assert_eq!(json["newly_complete"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn runs_compare_rejects_run_and_period_for_same_side() {
+    let tmp = TempDir::new().unwrap();
+
+    shiplog_cmd()
+        .args([
+            "runs",
+            "compare",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--from",
+            "run_fixture",
+            "--from-period",
+            "2025-Q1",
+            "--to",
+            "latest",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "use either --from or --from-period, not both",
+        ));
+}
+
+#[test]
+fn review_latest_summarizes_run_attention_items_without_writing_artifacts() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+
+    let assert = shiplog_cmd()
+        .args(["review", "--out", tmp.path().to_str().unwrap(), "--latest"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let out_hint = format!("--out \"{}\"", tmp.path().display());
+
+    assert!(stdout.contains("Run: run_fixture"));
+    assert!(stdout.contains("Coverage:"));
+    assert!(stdout.contains("- GitHub: 3 event(s)"));
+    assert!(stdout.contains("Completeness: Complete"));
+    assert!(stdout.contains("Curation:"));
+    assert!(stdout.contains("- Validation: ok"));
+    assert!(stdout.contains("Evidence debt:"));
+    assert!(stdout.contains("- No obvious evidence debt detected."));
+    assert!(stdout.contains("Next:"));
+    assert!(stdout.contains(&format!("shiplog render {out_hint}")));
+    assert!(stdout.contains("--run run_fixture --mode scaffold"));
+
+    assert_eq!(
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+    );
+}
+
+#[test]
+fn review_weekly_summarizes_latest_run_without_writing_artifacts() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+
+    let assert = shiplog_cmd()
+        .args([
+            "review",
+            "weekly",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--latest",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let out_hint = format!("--out \"{}\"", tmp.path().display());
+
+    assert!(stdout.contains("Weekly review: run_fixture"));
+    assert!(stdout.contains("New evidence:"));
+    assert!(stdout.contains("- total: 3 event(s)"));
+    assert!(stdout.contains("- GitHub: 3 event(s)"));
+    assert!(stdout.contains("Evidence debt:"));
+    assert!(stdout.contains("- No obvious evidence debt detected."));
+    assert!(stdout.contains("Next:"));
+    assert!(stdout.contains(&format!("shiplog render {out_hint}")));
+    assert!(stdout.contains("--run run_fixture --mode scaffold"));
+
+    assert_eq!(
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+    );
+}
+
+#[test]
+fn review_latest_surfaces_skipped_sources_and_manual_context() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[defaults]
+window = "year:2025"
+
+[sources.json]
+enabled = true
+events = "./missing-ledger.events.jsonl"
+coverage = "./missing-coverage.manifest.json"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#,
+    )
+    .unwrap();
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "multi",
+            "--config",
+            tmp.path().join("shiplog.toml").to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let run_dir = first_run_dir(&out);
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+
+    let assert = shiplog_cmd()
+        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("Coverage:"));
+    assert!(stdout.contains("- Manual: 1 event(s)"));
+    assert!(stdout.contains("Completeness: Partial"));
+    assert!(stdout.contains("Skipped sources:"));
+    assert!(stdout.contains("- JSON:"));
+    assert!(stdout.contains("Evidence debt:"));
+    assert!(stdout.contains("[warning] missing-source"));
+    assert!(stdout.contains("[warning] partial-coverage"));
+    assert!(stdout.contains("[info] manual-context"));
+    assert!(stdout.contains("shiplog doctor"));
+
+    let weekly_assert = shiplog_cmd()
+        .args([
+            "review",
+            "weekly",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+        ])
+        .assert()
+        .success();
+    let weekly_stdout = String::from_utf8(weekly_assert.get_output().stdout.clone()).unwrap();
+
+    assert!(weekly_stdout.contains("Weekly review:"));
+    assert!(weekly_stdout.contains("New evidence:"));
+    assert!(weekly_stdout.contains("- Manual: 1 event(s)"));
+    assert!(weekly_stdout.contains("Source gaps:"));
+    assert!(weekly_stdout.contains("- JSON:"));
+    assert!(weekly_stdout.contains("Evidence debt:"));
+    assert!(weekly_stdout.contains("[warning] missing-source"));
+    assert!(weekly_stdout.contains("shiplog doctor"));
+
+    let fixups_assert = shiplog_cmd()
+        .args([
+            "review",
+            "fixups",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+        ])
+        .assert()
+        .success();
+    let fixups_stdout = String::from_utf8(fixups_assert.get_output().stdout.clone()).unwrap();
+
+    assert!(fixups_stdout.contains("Review fixups:"));
+    assert!(fixups_stdout.contains("Repair skipped source setup"));
+    assert!(fixups_stdout.contains("Skipped sources: JSON."));
+    assert!(fixups_stdout.contains("shiplog doctor"));
+
+    assert_eq!(
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+    );
+}
+
+#[test]
+fn review_suggests_journal_add_for_broad_workstream_without_manual_context() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    let events_path = tmp.path().join("ledger.events.jsonl");
+    let coverage_path = tmp.path().join("coverage.manifest.json");
+    let events: Vec<_> = (0..10)
+        .map(|idx| {
+            fixture_pr_event(
+                SourceSystem::Github,
+                "acme/platform",
+                200 + idx as u64,
+                &format!("Platform reliability fix {}", idx + 1),
+                2 + idx,
+            )
+        })
+        .collect();
+    let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
+        run_id: RunId("run_broad_code".into()),
+        generated_at: fixture_time(20),
+        user: "octo".into(),
+        window: fixture_window(),
+        mode: "fixture".into(),
+        sources: vec!["github".into()],
+        slices: vec![CoverageSlice {
+            window: fixture_window(),
+            query: "github fixture".into(),
+            total_count: events.len() as u64,
+            fetched: events.len() as u64,
+            incomplete_results: Some(false),
+            notes: vec!["fixture".into()],
+            source: None,
+        }],
+        warnings: vec![],
+        completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
+    };
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "json",
+            "--events",
+            events_path.to_str().unwrap(),
+            "--coverage",
+            coverage_path.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let run_dir = out.join("run_broad_code");
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+
+    let assert = shiplog_cmd()
+        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("[info] manual-context"));
+    assert!(stdout.contains("broad workstream(s) have no manual outcome note"));
+    assert!(stdout.contains("shiplog journal add --date"));
+    assert!(stdout.contains("--title \"Outcome note for acme/platform\""));
+    assert!(stdout.contains("--workstream \"acme/platform\""));
+    assert!(
+        !stdout.contains("[info] code-only-workstream"),
+        "manual-context debt should cover broad code-only workstreams without printing a duplicate outcome-context debt item. stdout:\n{stdout}"
+    );
+
+    assert_eq!(
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+    );
+}
+
+#[test]
+fn review_checklist_marks_persist_and_lead_the_internal_packet() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let run_dir = collect_json_into(tmp.path());
+    let out = tmp.path().to_str().context("temp path is not UTF-8")?;
+    let review = |args: &[&str]| {
+        let mut cmd = shiplog_cmd();
+        cmd.arg("review")
+            .args(args)
+            .args(["--out", out, "--run", "run_fixture"]);
+        cmd
+    };
+
+    review(&["mark", "claims", "--workstream", "acme/platform"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "acme/platform: marked claim scaffolds filled",
+        ));
+    review(&["mark", "receipts", "--all"]).assert().success();
+    review(&["approve", "manager"]).assert().success();
+    review(&["approve", "internal"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("internal packets are not shared"));
+
+    review(&["status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "- acme/payments: claims open, receipts verified",
+        ))
+        .stdout(predicate::str::contains(
+            "- acme/platform: claims filled, receipts verified",
+        ))
+        .stdout(predicate::str::contains("- public: not approved"))
+        .stdout(predicate::str::contains(
+            "review mark claims --workstream \"acme/payments\"",
+        ));
+
+    let banner_lines = [
+        "- Claim scaffolds filled: 1 of 2 workstreams (open: acme/payments)",
+        "- Receipts verified: 2 of 2 workstreams",
+        "- Approved for sharing: manager (",
+    ];
+    let packet = std::fs::read_to_string(run_dir.join("packet.md"))?;
+    assert!(packet.starts_with("# Review Status\n"));
+    assert_eq!(packet.matches("# Review Status").count(), 1);
+    for line in banner_lines {
+        assert!(packet.contains(line), "missing {line:?} in:\n{packet}");
+    }
+
+    shiplog_cmd()
+        .args(["render", "--out", out, "--run", "run_fixture"])
+        .assert()
+        .success();
+    let packet = std::fs::read_to_string(run_dir.join("packet.md"))?;
+    assert!(packet.starts_with("# Review Status\n"));
+    for line in banner_lines {
+        assert!(packet.contains(line), "render dropped {line:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn review_fixups_ranks_curation_actions_without_writing_artifacts() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+    let events_path = tmp.path().join("ledger.events.jsonl");
+    let coverage_path = tmp.path().join("coverage.manifest.json");
+    let events: Vec<_> = (0..10)
+        .map(|idx| {
+            fixture_pr_event(
+                SourceSystem::Github,
+                "acme/platform",
+                300 + idx as u64,
+                &format!("Platform reliability fix {}", idx + 1),
+                2 + idx,
+            )
+        })
+        .collect();
+    let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
+        run_id: RunId("run_fixups".into()),
+        generated_at: fixture_time(20),
+        user: "octo".into(),
+        window: fixture_window(),
+        mode: "fixture".into(),
+        sources: vec!["github".into()],
+        slices: vec![CoverageSlice {
+            window: fixture_window(),
+            query: "github fixture".into(),
+            total_count: events.len() as u64,
+            fetched: events.len() as u64,
+            incomplete_results: Some(false),
+            notes: vec!["fixture".into()],
+            source: None,
+        }],
+        warnings: vec![],
+        completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
+    };
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
+
+    shiplog_cmd()
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "json",
+            "--events",
+            events_path.to_str().unwrap(),
+            "--coverage",
+            coverage_path.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let run_dir = out.join("run_fixups");
+    let curated = WorkstreamsFile {
+        version: 1,
+        generated_at: fixture_time(21),
+        workstreams: vec![Workstream {
+            id: WorkstreamId::from_parts(["repo", "acme/platform"]),
+            title: "acme/platform".into(),
+            summary: None,
+            tags: vec!["repo".into()],
+            stats: WorkstreamStats {
+                pull_requests: events.len(),
+                reviews: 0,
+                manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
+            },
+            events: events.iter().map(|event| event.id.clone()).collect(),
+            receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
+        }],
+    };
+    std::fs::write(
+        run_dir.join("workstreams.yaml"),
+        serde_yaml::to_string(&curated).unwrap(),
+    )
+    .unwrap();
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    let workstreams_before = std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap();
+
+    let assert = shiplog_cmd()
+        .args([
+            "review",
+            "fixups",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains("Review fixups: run_fixups"));
+    assert!(stdout.contains("Top fixups:"));
+    assert!(stdout.contains("1. Add outcome context for \"acme/platform\""));
+    assert!(stdout.contains("shiplog journal add --date"));
+    assert!(stdout.contains("--title \"Outcome note for acme/platform\""));
+    assert!(stdout.contains("2. Select anchor receipts for \"acme/platform\""));
+    assert!(stdout.contains("shiplog workstreams receipts --out"));
+    assert!(stdout.contains("--run run_fixups --workstream \"acme/platform\""));
+    assert!(stdout.contains("3. Split broad workstream \"acme/platform\""));
+    assert!(stdout.contains("shiplog workstreams split --out"));
+    assert!(stdout.contains("--run run_fixups --from \"acme/platform\""));
+    assert!(stdout.contains("shiplog review --out"));
+    assert!(stdout.contains("shiplog render --out"));
+    assert!(stdout.contains("--mode scaffold"));
+
+    let commands_assert = shiplog_cmd()
+        .args([
+            "review",
+            "fixups",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+            "--commands-only",
+        ])
+        .assert()
+        .success();
+    let commands_stdout = String::from_utf8(commands_assert.get_output().stdout.clone()).unwrap();
+    assert!(!commands_stdout.contains("Review fixups:"));
+    assert!(!commands_stdout.contains("Top fixups:"));
+    assert!(!commands_stdout.contains("Next:"));
+    let commands: Vec<_> = commands_stdout
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    assert_eq!(
+        commands.len(),
+        4,
+        "commands-only should print just the ranked fixup commands"
+    );
+    assert!(commands[0].starts_with("shiplog journal add --date"));
+    assert!(commands[1].starts_with("shiplog workstreams receipts --out"));
+    assert!(commands[2].starts_with("shiplog workstreams split --out"));
+    assert!(commands[3].starts_with("shiplog journal add --date"));
+    assert!(commands.iter().all(|line| line.starts_with("shiplog ")));
+
+    let templates_assert = shiplog_cmd()
+        .args([
+            "review",
+            "fixups",
+            "--out",
+            out.to_str().unwrap(),
+            "--run",
+            "run_fixups",
+            "--journal-template",
+        ])
+        .assert()
+        .success();
+    let templates_stdout = String::from_utf8(templates_assert.get_output().stdout.clone()).unwrap();
+    assert!(!templates_stdout.contains("Review fixups:"));
+    assert!(!templates_stdout.contains("Top fixups:"));
+    assert!(!templates_stdout.contains("shiplog workstreams"));
+    let templates: Vec<_> = templates_stdout
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    assert_eq!(
+        templates.len(),
+        1,
+        "journal-template should dedupe repeated context gaps for one workstream"
+    );
+    assert!(templates[0].starts_with("shiplog journal add --date"));
+    assert!(templates[0].contains("--title \"Outcome note for acme/platform\""));
+    assert!(templates[0].contains("--workstream \"acme/platform\""));
+    assert!(templates[0].contains("--description \"<replace with factual context or outcome>\""));
+
+    shiplog_cmd()
+        .args([
+            "review",
+            "fixups",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+            "--commands-only",
+            "--journal-template",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "either --commands-only or --journal-template",
         ));
+
+    assert_eq!(
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+    );
+    assert_eq!(
+        workstreams_before,
+        std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap()
+    );
 }
 
 #[test]
-fn intake_rerun_reuses_prior_curation_without_overwriting_manual_events() {
+fn review_surfaces_packet_quality_evidence_debt_categories() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let manual_events = tmp.path().join("manual_events.yaml");
-    let config_path = tmp.path().join("shiplog.toml");
-    write_manual_events(&manual_events);
-
-    std::fs::write(
-        &config_path,
-        r#"[defaults]
-window = "year:2025"
+    let events_path = tmp.path().join("ledger.events.jsonl");
+    let coverage_path = tmp.path().join("coverage.manifest.json");
+    let mut events = Vec::new();
 
-[user]
-label = "octo"
+    let misc_events: Vec<_> = (0..5)
+        .map(|idx| {
+            fixture_pr_event(
+                SourceSystem::Github,
+                "acme/misc",
+                400 + idx as u64,
+                &format!("Misc cleanup {}", idx + 1),
+                2 + idx,
+            )
+        })
+        .collect();
+    let ticket_events: Vec<_> = (0..5)
+        .map(|idx| {
+            fixture_manual_event(
+                SourceSystem::Other("jira".into()),
+                "acme/support",
+                &format!("Support ticket {}", idx + 1),
+                ManualEventType::Other,
+                8 + idx,
+            )
+        })
+        .collect();
+    let manual_events = [fixture_manual_event(
+        SourceSystem::Manual,
+        "acme/manual",
+        "Customer reliability context",
+        ManualEventType::Incident,
+        14,
+    )];
+    let release_events: Vec<_> = (0..6)
+        .map(|idx| {
+            fixture_pr_event(
+                SourceSystem::Github,
+                "acme/release",
+                500 + idx as u64,
+                &format!("Release automation {}", idx + 1),
+                15 + idx,
+            )
+        })
+        .collect();
 
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-user = "octo"
-"#,
-    )
-    .unwrap();
+    events.extend(misc_events.iter().cloned());
+    events.extend(ticket_events.iter().cloned());
+    events.extend(manual_events.iter().cloned());
+    events.extend(release_events.iter().cloned());
 
-    let out_arg = out.to_str().unwrap().to_string();
-    let config_arg = config_path.to_str().unwrap().to_string();
-    let intake_args = [
-        "intake",
-        "--out",
-        out_arg.as_str(),
-        "--config",
-        config_arg.as_str(),
-        "--year",
-        "2025",
-        "--no-open",
-    ];
+    let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
+        run_id: RunId("run_quality_debt".into()),
+        generated_at: fixture_time(25),
+        user: "octo".into(),
+        window: fixture_window(),
+        mode: "fixture".into(),
+        sources: vec!["github".into(), "jira".into(), "manual".into()],
+        slices: vec![CoverageSlice {
+            window: fixture_window(),
+            query: "quality fixture".into(),
+            total_count: events.len() as u64,
+            fetched: events.len() as u64,
+            incomplete_results: Some(false),
+            notes: vec!["fixture".into()],
+            source: None,
+        }],
+        warnings: vec![],
+        completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
+    };
+    write_events_jsonl(&events_path, &events);
+    write_coverage_manifest(&coverage_path, &coverage);
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(intake_args)
+        .args([
+            "collect",
+            "--out",
+            out.to_str().unwrap(),
+            "json",
+            "--events",
+            events_path.to_str().unwrap(),
+            "--coverage",
+            coverage_path.to_str().unwrap(),
+        ])
         .assert()
         .success();
 
-    let first_run = first_run_dir(&out);
-    let suggested_text =
-        std::fs::read_to_string(first_run.join("workstreams.suggested.yaml")).unwrap();
-    let mut curated: WorkstreamsFile = serde_yaml::from_str(&suggested_text).unwrap();
-    curated.workstreams[0].title = "Curated Reliability".to_string();
+    let run_dir = out.join("run_quality_debt");
+    let curated = WorkstreamsFile {
+        version: 1,
+        generated_at: fixture_time(26),
+        workstreams: vec![
+            Workstream {
+                id: WorkstreamId::from_parts(["quality", "misc"]),
+                title: "Misc".into(),
+                summary: None,
+                tags: vec!["misc".into()],
+                stats: WorkstreamStats {
+                    pull_requests: misc_events.len(),
+                    reviews: 0,
+                    manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
+                },
+                events: misc_events.iter().map(|event| event.id.clone()).collect(),
+                receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            },
+            Workstream {
+                id: WorkstreamId::from_parts(["quality", "tickets"]),
+                title: "Customer Tickets".into(),
+                summary: None,
+                tags: vec!["tickets".into()],
+                stats: WorkstreamStats {
+                    pull_requests: 0,
+                    reviews: 0,
+                    manual_events: ticket_events.len(),
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
+                },
+                events: ticket_events.iter().map(|event| event.id.clone()).collect(),
+                receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            },
+            Workstream {
+                id: WorkstreamId::from_parts(["quality", "manual"]),
+                title: "Manual Context".into(),
+                summary: None,
+                tags: vec!["manual".into()],
+                stats: WorkstreamStats {
+                    pull_requests: 0,
+                    reviews: 0,
+                    manual_events: manual_events.len(),
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
+                },
+                events: manual_events.iter().map(|event| event.id.clone()).collect(),
+                receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            },
+            Workstream {
+                id: WorkstreamId::from_parts(["quality", "release"]),
+                title: "Release Automation".into(),
+                summary: None,
+                tags: vec!["release".into()],
+                stats: WorkstreamStats {
+                    pull_requests: release_events.len(),
+                    reviews: 0,
+                    manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
+                },
+                events: release_events
+                    .iter()
+                    .map(|event| event.id.clone())
+                    .collect(),
+                receipts: release_events
+                    .iter()
+                    .map(|event| event.id.clone())
+                    .collect(),
+                receipt_weights: Default::default(),
+                rules: Default::default(),
+            },
+        ],
+    };
     std::fs::write(
-        first_run.join("workstreams.yaml"),
+        run_dir.join("workstreams.yaml"),
         serde_yaml::to_string(&curated).unwrap(),
     )
     .unwrap();
-    let first_curated_before = std::fs::read_to_string(first_run.join("workstreams.yaml")).unwrap();
-    let manual_before = std::fs::read_to_string(&manual_events).unwrap();
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    let workstreams_before = std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap();
 
     let assert = shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(intake_args)
+        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let out_hint = format!("--out \"{}\"", out.display());
+
+    for needle in [
+        "[warning] no-selected-receipts".to_string(),
+        "[info] thin-workstream".to_string(),
+        "[warning] large-misc-workstream".to_string(),
+        "[info] code-only-workstream".to_string(),
+        "[info] ticket-only-workstream".to_string(),
+        "[info] manual-only-workstream".to_string(),
+        "[info] too-many-selected-receipts".to_string(),
+        format!("shiplog workstreams receipts {out_hint}"),
+        "--run run_quality_debt --workstream <title>".to_string(),
+        format!("shiplog workstreams split {out_hint}"),
+        "--run run_quality_debt".to_string(),
+        "shiplog journal add --date".to_string(),
+        "shiplog journal list".to_string(),
+    ] {
+        assert!(
+            stdout.contains(&needle),
+            "review output should mention {needle:?}"
+        );
+    }
+
+    let fixups_assert = shiplog_cmd()
+        .args([
+            "review",
+            "fixups",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+            "--commands-only",
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Curation:"))
-        .stdout(predicate::str::contains("Prior workstream curation reused"));
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    assert!(stdout.contains("workstreams.yaml"));
-
-    let runs = all_run_dirs(&out);
-    assert_eq!(runs.len(), 2, "intake rerun should create a new run");
-    let second_run = runs
-        .into_iter()
-        .find(|run| run != &first_run)
-        .expect("expected a second intake run");
+        .success();
+    let fixups_stdout = String::from_utf8(fixups_assert.get_output().stdout.clone()).unwrap();
+    assert!(fixups_stdout.contains("shiplog workstreams split"));
+    assert!(fixups_stdout.contains("shiplog workstreams receipts"));
+    assert!(fixups_stdout.contains("shiplog journal add"));
 
     assert_eq!(
-        std::fs::read_to_string(first_run.join("workstreams.yaml")).unwrap(),
-        first_curated_before,
-        "intake rerun should not overwrite prior curated workstreams"
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
     );
     assert_eq!(
-        std::fs::read_to_string(second_run.join("workstreams.yaml")).unwrap(),
-        first_curated_before,
-        "intake rerun should copy prior curated workstreams into the new run"
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
     );
     assert_eq!(
-        std::fs::read_to_string(&manual_events).unwrap(),
-        manual_before,
-        "intake rerun should not rewrite manual_events.yaml"
-    );
-
-    let (report_md, report_json) = assert_golden_intake_report(&second_run, "Ready for review");
-    assert!(report_md.contains("## Curation Notes"));
-    assert!(report_md.contains("Prior workstream curation reused"));
-
-    assert!(
-        report_json["curation_notes"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .any(|note| note
-                .as_str()
-                .unwrap()
-                .contains("Prior workstream curation reused"))
+        workstreams_before,
+        std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap()
     );
 }
 
 #[test]
-fn intake_explain_reports_configured_source_decisions() {
+fn review_strict_fails_when_evidence_debt_exists_without_writing_artifacts() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let fixtures = fixture_dir();
-    std::fs::copy(
-        fixtures.join("ledger.events.jsonl"),
-        tmp.path().join("ledger.events.jsonl"),
-    )
-    .unwrap();
-    std::fs::copy(
-        fixtures.join("coverage.manifest.json"),
-        tmp.path().join("coverage.manifest.json"),
-    )
-    .unwrap();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
 
     std::fs::write(
         tmp.path().join("shiplog.toml"),
         r#"[defaults]
 window = "year:2025"
 
-[sources.json]
-enabled = true
-events = "./ledger.events.jsonl"
-coverage = "./coverage.manifest.json"
-
-[sources.jira]
-enabled = true
-user = "712020:account-id"
-instance = "example.atlassian.net"
-status = "done"
-
-[sources.linear]
+[sources.manual]
 enabled = true
-user_id = "linear-user-id"
-status = "done"
+events = "./manual_events.yaml"
+user = "octo"
 "#,
     )
     .unwrap();
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
         .args([
-            "intake",
+            "collect",
             "--out",
             out.to_str().unwrap(),
+            "multi",
             "--config",
             tmp.path().join("shiplog.toml").to_str().unwrap(),
-            "--no-open",
-            "--explain",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Source decisions:"))
-        .stdout(predicate::str::contains(
-            "- JSON: included, events and coverage files found",
-        ))
-        .stdout(predicate::str::contains(
-            "- Jira: skipped, missing JIRA_TOKEN",
-        ))
-        .stdout(predicate::str::contains("export JIRA_TOKEN=..."))
-        .stdout(predicate::str::contains(
-            "shiplog identify jira --auth-user <email>",
-        ))
-        .stdout(predicate::str::contains(
-            "- Linear: skipped, missing LINEAR_API_KEY",
-        ))
-        .stdout(predicate::str::contains("export LINEAR_API_KEY=..."))
-        .stdout(predicate::str::contains("shiplog identify linear"))
-        .stdout(predicate::str::contains(
-            "Retry with `shiplog intake --last-6-months --explain`.",
-        ));
-}
+        .success();
 
-#[test]
-fn collect_multi_without_enabled_sources_fails_actionably() {
-    let tmp = TempDir::new().unwrap();
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[sources.json]
-enabled = false
-"#,
-    )
-    .unwrap();
+    let run_dir = first_run_dir(&out);
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
 
     shiplog_cmd()
         .args([
-            "collect",
+            "review",
             "--out",
-            tmp.path().join("out").to_str().unwrap(),
-            "multi",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            out.to_str().unwrap(),
+            "--latest",
+            "--strict",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("No enabled sources found"));
+        .stdout(predicate::str::contains("Evidence debt:"))
+        .stdout(predicate::str::contains("[info] manual-context"))
+        .stderr(predicate::str::contains("review found"));
+
+    assert_eq!(
+        packet_before,
+        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+    );
 }
 
 #[test]
-fn merge_missing_input_run_fails_actionably() {
+fn open_packet_latest_prints_packet_path_when_forced() {
     let tmp = TempDir::new().unwrap();
-    let missing = tmp.path().join("missing-run");
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let before = file_tree_manifest(tmp.path());
+    let assert = shiplog_cmd()
         .args([
-            "merge",
+            "open",
+            "packet",
             "--out",
             tmp.path().to_str().unwrap(),
-            "--input",
-            missing.to_str().unwrap(),
+            "--latest",
+            "--print-path",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("No ledger.events.jsonl found"));
+        .success()
+        .stdout(predicate::str::contains("run_fixture"))
+        .stdout(predicate::str::contains("packet.md"));
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let after = file_tree_manifest(tmp.path());
+
+    assert_eq!(
+        before, after,
+        "open packet --latest --print-path should not write or rewrite files"
+    );
+    assert!(
+        !stdout.contains(r"\\?\"),
+        "open packet --print-path should not expose a Windows verbatim path prefix: {stdout}"
+    );
 }
 
 #[test]
-fn collect_json_public_profile_without_key_fails_closed() {
+fn open_without_artifact_defaults_to_latest_packet() {
     let tmp = TempDir::new().unwrap();
-    let fixtures = fixture_dir();
+    let out = tmp.path().join("out");
+    collect_json_into(&out);
 
-    let mut cmd = shiplog_cmd();
-    cmd.env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "collect",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--bundle-profile",
-            "public",
-            "json",
-            "--events",
-            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
-            "--coverage",
-            fixtures.join("coverage.manifest.json").to_str().unwrap(),
-        ])
+    let before = file_tree_manifest(&out);
+    let assert = shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["open", "--print-path"])
         .assert()
-        .failure()
-        .stderr(
-            predicate::str::contains("public profile requires --redact-key or SHIPLOG_REDACT_KEY")
-                .and(predicate::str::contains("Try:"))
-                .and(predicate::str::contains(redaction_key_env_command(
-                    "SHIPLOG_REDACT_KEY",
-                )))
-                .and(predicate::str::contains(
-                    "rerun this command with --bundle-profile public",
-                )),
-        );
+        .success()
+        .stdout(predicate::str::contains("run_fixture"))
+        .stdout(predicate::str::contains("packet.md"));
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let after = file_tree_manifest(&out);
+
+    assert_eq!(
+        before, after,
+        "open --print-path should not write or rewrite files"
+    );
+    assert!(
+        !stdout.contains(r"\\?\"),
+        "open --print-path should not expose a Windows verbatim path prefix: {stdout}"
+    );
 }
 
 #[test]
-fn collect_json_packet_contains_expected_content() {
+fn open_packet_latest_selects_lexicographically_newest_run() {
     let tmp = TempDir::new().unwrap();
-    let fixtures = fixture_dir();
+    let out = tmp.path();
+    let newest = "2026-05-13T09-00-00Z-newest";
+    let older = "2026-05-12T09-00-00Z-older";
+
+    write_minimal_open_run(out, newest, "newest packet");
+    std::thread::sleep(std::time::Duration::from_millis(25));
+    write_minimal_open_run(out, older, "older packet");
 
     shiplog_cmd()
         .args([
-            "collect",
+            "open",
+            "packet",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "json",
-            "--events",
-            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
-            "--coverage",
-            fixtures.join("coverage.manifest.json").to_str().unwrap(),
+            out.to_str().unwrap(),
+            "--latest",
+            "--print-path",
         ])
         .assert()
-        .success();
-
-    let packet = std::fs::read_to_string(tmp.path().join("run_fixture/packet.md")).unwrap();
-    // Fixture data contains PRs from acme/payments and acme/platform
-    assert!(
-        packet.contains("acme/payments") || packet.contains("acme/platform"),
-        "packet.md should reference fixture repos"
-    );
+        .success()
+        .stdout(predicate::str::contains(newest))
+        .stdout(predicate::str::contains("packet.md"))
+        .stdout(predicate::str::contains(older).not());
 }
 
 #[test]
-fn workstreams_list_shows_latest_run_workstreams() {
+fn open_latest_without_runs_prints_intake_command_to_create_one() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let out = tmp.path().join("missing runs");
+    let out_arg = out.to_str().unwrap();
 
     shiplog_cmd()
-        .args(["workstreams", "list", "--out", tmp.path().to_str().unwrap()])
+        .args([
+            "open",
+            "packet",
+            "--out",
+            out_arg,
+            "--latest",
+            "--print-path",
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Workstreams:"))
-        .stdout(predicate::str::contains("suggested workstreams"))
-        .stdout(predicate::str::contains("Count:"))
-        .stdout(predicate::str::contains("acme/platform"))
-        .stdout(predicate::str::contains("events="));
+        .failure()
+        .stderr(predicate::str::contains("No run directories found"))
+        .stderr(predicate::str::contains(
+            "shiplog intake --last-6-months --explain",
+        ))
+        .stderr(predicate::str::contains("--out"))
+        .stderr(predicate::str::contains(out_arg));
+}
+
+fn write_minimal_open_run(out: &Path, run_id: &str, packet_body: &str) {
+    let run = out.join(run_id);
+    std::fs::create_dir_all(&run).unwrap();
+    std::fs::write(run.join("ledger.events.jsonl"), "").unwrap();
+    std::fs::write(run.join("packet.md"), packet_body).unwrap();
 }
 
 #[test]
-fn runs_list_shows_run_summaries() {
+fn open_workstreams_latest_prints_effective_workstreams_path_when_forced() {
     let tmp = TempDir::new().unwrap();
     collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .args(["runs", "list", "--out", tmp.path().to_str().unwrap()])
+        .args([
+            "open",
+            "workstreams",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--latest",
+            "--print-path",
+        ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Runs:"))
         .stdout(predicate::str::contains("run_fixture"))
-        .stdout(predicate::str::contains("sources: github"))
-        .stdout(predicate::str::contains("events: 3"))
-        .stdout(predicate::str::contains("coverage: Complete"))
-        .stdout(predicate::str::contains("packet:"));
+        .stdout(predicate::str::contains("workstreams.suggested.yaml"));
 }
 
 #[test]
-fn runs_show_latest_shows_run_details() {
+fn open_out_latest_prints_run_directory_when_forced() {
     let tmp = TempDir::new().unwrap();
     collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "runs",
-            "show",
+            "open",
+            "out",
             "--out",
             tmp.path().to_str().unwrap(),
-            "--run",
-            "latest",
+            "--latest",
+            "--print-path",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Run: run_fixture"))
-        .stdout(predicate::str::contains("User: octo"))
-        .stdout(predicate::str::contains("Window: 2025-01-01..2025-04-01"))
-        .stdout(predicate::str::contains("Sources: github"))
-        .stdout(predicate::str::contains("Events: 3"))
-        .stdout(predicate::str::contains("Gaps: 0"))
-        .stdout(predicate::str::contains("Warnings: none"));
+        .stdout(predicate::str::contains("run_fixture"));
 }
 
 #[test]
-fn runs_compare_summarizes_cross_run_changes_without_writing_artifacts() {
+fn open_intake_report_latest_prints_report_path_when_forced() {
     let tmp = TempDir::new().unwrap();
-    let from_run = collect_json_into(tmp.path());
-    let from_packet_before = std::fs::read_to_string(from_run.join("packet.md")).unwrap();
-    let from_coverage_before =
-        std::fs::read_to_string(from_run.join("coverage.manifest.json")).unwrap();
-
-    let events_path = tmp.path().join("all-source.events.jsonl");
-    let coverage_path = tmp.path().join("all-source.coverage.json");
-    let events = all_source_fixture_events();
-    let coverage = all_source_fixture_coverage();
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
+    let out = tmp.path().join("out");
 
     shiplog_cmd()
-        .args([
-            "collect",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "json",
-            "--events",
-            events_path.to_str().unwrap(),
-            "--coverage",
-            coverage_path.to_str().unwrap(),
-            "--user",
-            "octo",
-            "--window-label",
-            "all-source fixture",
-        ])
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
 
-    let to_run = tmp.path().join("run_all_sources");
-    let to_packet_before = std::fs::read_to_string(to_run.join("packet.md")).unwrap();
-    let to_coverage_before =
-        std::fs::read_to_string(to_run.join("coverage.manifest.json")).unwrap();
-
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .args([
-            "runs",
-            "compare",
+            "open",
+            "intake-report",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--from",
-            "run_fixture",
-            "--to",
-            "run_all_sources",
+            out.to_str().unwrap(),
+            "--latest",
+            "--print-path",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let out_hint = format!("--out \"{}\"", tmp.path().display());
-
-    assert!(stdout.contains("Compare: run_fixture -> run_all_sources"));
-    assert!(stdout.contains("Events:"));
-    assert!(stdout.contains("- from: 3"));
-    assert!(stdout.contains("- to: 8"));
-    assert!(stdout.contains("- delta: +5"));
-    assert!(stdout.contains("Sources:"));
-    assert!(stdout.contains("- Added: Local git, GitLab, Jira, JSON, Linear, Manual"));
-    assert!(stdout.contains("- Continued: GitHub"));
-    assert!(stdout.contains("Workstreams:"));
-    assert!(stdout.contains("- Added:"));
-    assert!(stdout.contains("acme/release-tools"));
-    assert!(stdout.contains("- Removed:"));
-    assert!(stdout.contains("acme/platform"));
-    assert!(stdout.contains("Coverage:"));
-    assert!(stdout.contains("- from: Complete, gaps: 0"));
-    assert!(stdout.contains("- to: Complete, gaps: 0"));
-    assert!(stdout.contains(&format!("shiplog review {out_hint}")));
-    assert!(stdout.contains("--run run_all_sources"));
-
-    assert_eq!(
-        from_packet_before,
-        std::fs::read_to_string(from_run.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        from_coverage_before,
-        std::fs::read_to_string(from_run.join("coverage.manifest.json")).unwrap()
-    );
-    assert_eq!(
-        to_packet_before,
-        std::fs::read_to_string(to_run.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        to_coverage_before,
-        std::fs::read_to_string(to_run.join("coverage.manifest.json")).unwrap()
-    );
+        .success()
+        .stdout(predicate::str::contains("intake.report.md"));
 }
 
 #[test]
-fn runs_compare_resolves_named_period_selectors() {
+fn open_report_alias_prints_intake_report_path_when_forced() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
-
-    let events_path = tmp.path().join("period-to.events.jsonl");
-    let coverage_path = tmp.path().join("period-to.coverage.json");
-    let events = all_source_fixture_events();
-    let mut coverage = all_source_fixture_coverage();
-    coverage.run_id = RunId("run_period_to".into());
-    coverage.window = TimeWindow {
-        since: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
-        until: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
-    };
-    for slice in &mut coverage.slices {
-        slice.window = coverage.window.clone();
-    }
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
+    let out = tmp.path().join("out");
 
     shiplog_cmd()
-        .args([
-            "collect",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "json",
-            "--events",
-            events_path.to_str().unwrap(),
-            "--coverage",
-            coverage_path.to_str().unwrap(),
-            "--user",
-            "octo",
-            "--window-label",
-            "period to fixture",
-        ])
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
 
-    let config_path = tmp.path().join("shiplog.toml");
-    std::fs::write(
-        &config_path,
-        r#"[periods."2025-Q1"]
-since = "2025-01-01"
-until = "2025-04-01"
-
-[periods."2025-Q2"]
-since = "2025-04-01"
-until = "2025-07-01"
-"#,
-    )
-    .unwrap();
-
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .args([
-            "runs",
-            "compare",
+            "open",
+            "report",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--config",
-            config_path.to_str().unwrap(),
-            "--from-period",
-            "2025-Q1",
-            "--to-period",
-            "2025-Q2",
+            out.to_str().unwrap(),
+            "--latest",
+            "--print-path",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let out_hint = format!("--out \"{}\"", tmp.path().display());
-
-    assert!(stdout.contains("Compare: run_fixture -> run_period_to"));
-    assert!(stdout.contains("- from: 3"));
-    assert!(stdout.contains("- to: 8"));
-    assert!(stdout.contains("- Added: Local git, GitLab, Jira, JSON, Linear, Manual"));
-    assert!(stdout.contains(&format!("shiplog review {out_hint}")));
-    assert!(stdout.contains("--run run_period_to"));
+        .success()
+        .stdout(predicate::str::contains("intake.report.md"));
 }
 
-#[test]
-fn runs_compare_requires_selector_for_each_side() {
-    let tmp = TempDir::new().unwrap();
+#[test]
+fn report_validate_accepts_latest_and_direct_path() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success();
+
+    let run_dir = first_run_dir(&out);
+    let report_path = run_dir.join("intake.report.json");
 
     shiplog_cmd()
         .args([
-            "runs",
-            "compare",
+            "report",
+            "validate",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--to",
-            "latest",
+            out.to_str().unwrap(),
+            "--latest",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("missing --from or --from-period"));
+        .success()
+        .stdout(predicate::str::contains("Report valid:"))
+        .stdout(predicate::str::contains("Schema: v1"))
+        .stdout(predicate::str::contains("Intake status:"))
+        .stdout(predicate::str::contains("Packet readiness:"))
+        .stdout(predicate::str::contains("Artifacts:"));
+
+    shiplog_cmd()
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Report valid:"))
+        .stdout(predicate::str::contains("intake.report.json"));
 }
 
 #[test]
-fn runs_compare_rejects_run_and_period_for_same_side() {
+fn report_validate_rejects_unsupported_schema_version() {
     let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success();
+
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["schema_version"] = serde_json::json!(2);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
         .args([
-            "runs",
-            "compare",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--from",
-            "run_fixture",
-            "--from-period",
-            "2025-Q1",
-            "--to",
-            "latest",
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
         .failure()
         .stderr(predicate::str::contains(
-            "use either --from or --from-period, not both",
+            "unsupported intake report schema_version 2",
         ));
 }
 
 #[test]
-fn review_latest_summarizes_run_attention_items_without_writing_artifacts() {
+fn report_validate_rejects_secret_sentinels() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    let out = tmp.path().join("out");
 
-    let assert = shiplog_cmd()
-        .args(["review", "--out", tmp.path().to_str().unwrap(), "--latest"])
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let out_hint = format!("--out \"{}\"", tmp.path().display());
-
-    assert!(stdout.contains("Run: run_fixture"));
-    assert!(stdout.contains("Coverage:"));
-    assert!(stdout.contains("- GitHub: 3 event(s)"));
-    assert!(stdout.contains("Completeness: Complete"));
-    assert!(stdout.contains("Curation:"));
-    assert!(stdout.contains("- Validation: ok"));
-    assert!(stdout.contains("Evidence debt:"));
-    assert!(stdout.contains("- No obvious evidence debt detected."));
-    assert!(stdout.contains("Next:"));
-    assert!(stdout.contains(&format!("shiplog render {out_hint}")));
-    assert!(stdout.contains("--run run_fixture --mode scaffold"));
-
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
-    );
-}
 
-#[test]
-fn review_weekly_summarizes_latest_run_without_writing_artifacts() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["good"] = serde_json::json!(["super-secret"]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .args([
-            "review",
-            "weekly",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--latest",
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let out_hint = format!("--out \"{}\"", tmp.path().display());
-
-    assert!(stdout.contains("Weekly review: run_fixture"));
-    assert!(stdout.contains("New evidence:"));
-    assert!(stdout.contains("- total: 3 event(s)"));
-    assert!(stdout.contains("- GitHub: 3 event(s)"));
-    assert!(stdout.contains("Evidence debt:"));
-    assert!(stdout.contains("- No obvious evidence debt detected."));
-    assert!(stdout.contains("Next:"));
-    assert!(stdout.contains(&format!("shiplog render {out_hint}")));
-    assert!(stdout.contains("--run run_fixture --mode scaffold"));
-
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
-    );
+        .failure()
+        .stderr(predicate::str::contains("contains secret sentinel"));
 }
 
 #[test]
-fn review_latest_surfaces_skipped_sources_and_manual_context() {
+fn report_validate_accepts_legacy_repair_sources_without_kind() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
-
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-window = "year:2025"
 
-[sources.json]
-enabled = true
-events = "./missing-ledger.events.jsonl"
-coverage = "./missing-coverage.manifest.json"
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success();
 
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-user = "octo"
-"#,
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["repair_sources"] = serde_json::json!([
+        {
+            "source": "Jira",
+            "reason": "missing JIRA_TOKEN",
+            "commands": ["export JIRA_TOKEN=..."]
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
     )
     .unwrap();
 
     shiplog_cmd()
         .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "multi",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
-        .success();
-
-    let run_dir = first_run_dir(&out);
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-
-    let assert = shiplog_cmd()
-        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
-        .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+        .success()
+        .stdout(predicate::str::contains("Report valid:"));
+}
 
-    assert!(stdout.contains("Coverage:"));
-    assert!(stdout.contains("- Manual: 1 event(s)"));
-    assert!(stdout.contains("Completeness: Partial"));
-    assert!(stdout.contains("Skipped sources:"));
-    assert!(stdout.contains("- JSON:"));
-    assert!(stdout.contains("Evidence debt:"));
-    assert!(stdout.contains("[warning] missing-source"));
-    assert!(stdout.contains("[warning] partial-coverage"));
-    assert!(stdout.contains("[info] manual-context"));
-    assert!(stdout.contains("shiplog doctor"));
+#[test]
+fn report_validate_accepts_legacy_fixups_without_id_or_kind() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
 
-    let weekly_assert = shiplog_cmd()
-        .args([
-            "review",
-            "weekly",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-        ])
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
-    let weekly_stdout = String::from_utf8(weekly_assert.get_output().stdout.clone()).unwrap();
 
-    assert!(weekly_stdout.contains("Weekly review:"));
-    assert!(weekly_stdout.contains("New evidence:"));
-    assert!(weekly_stdout.contains("- Manual: 1 event(s)"));
-    assert!(weekly_stdout.contains("Source gaps:"));
-    assert!(weekly_stdout.contains("- JSON:"));
-    assert!(weekly_stdout.contains("Evidence debt:"));
-    assert!(weekly_stdout.contains("[warning] missing-source"));
-    assert!(weekly_stdout.contains("shiplog doctor"));
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["top_fixups"] = serde_json::json!([
+        {
+            "title": "Add outcome context",
+            "detail": null,
+            "command": "shiplog journal add --title \"Outcome note\""
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
-    let fixups_assert = shiplog_cmd()
+    shiplog_cmd()
         .args([
-            "review",
-            "fixups",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
-        .success();
-    let fixups_stdout = String::from_utf8(fixups_assert.get_output().stdout.clone()).unwrap();
-
-    assert!(fixups_stdout.contains("Review fixups:"));
-    assert!(fixups_stdout.contains("Repair skipped source setup"));
-    assert!(fixups_stdout.contains("Skipped sources: JSON."));
-    assert!(fixups_stdout.contains("shiplog doctor"));
-
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
-    );
+        .success()
+        .stdout(predicate::str::contains("Report valid:"));
 }
 
 #[test]
-fn review_suggests_journal_add_for_broad_workstream_without_manual_context() {
+fn report_validate_accepts_legacy_reports_without_actions() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let events_path = tmp.path().join("ledger.events.jsonl");
-    let coverage_path = tmp.path().join("coverage.manifest.json");
-    let events: Vec<_> = (0..10)
-        .map(|idx| {
-            fixture_pr_event(
-                SourceSystem::Github,
-                "acme/platform",
-                200 + idx as u64,
-                &format!("Platform reliability fix {}", idx + 1),
-                2 + idx,
-            )
-        })
-        .collect();
-    let coverage = CoverageManifest {
-        run_id: RunId("run_broad_code".into()),
-        generated_at: fixture_time(20),
-        user: "octo".into(),
-        window: fixture_window(),
-        mode: "fixture".into(),
-        sources: vec!["github".into()],
-        slices: vec![CoverageSlice {
-            window: fixture_window(),
-            query: "github fixture".into(),
-            total_count: events.len() as u64,
-            fetched: events.len() as u64,
-            incomplete_results: Some(false),
-            notes: vec!["fixture".into()],
-        }],
-        warnings: vec![],
-        completeness: Completeness::Complete,
-    };
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success();
+
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report
+        .as_object_mut()
+        .expect("report should be an object")
+        .remove("actions");
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
         .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "json",
-            "--events",
-            events_path.to_str().unwrap(),
-            "--coverage",
-            coverage_path.to_str().unwrap(),
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains("Report valid:"));
+}
 
-    let run_dir = out.join("run_broad_code");
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+#[test]
+fn report_validate_accepts_legacy_reports_without_repair_items() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
 
-    let assert = shiplog_cmd()
-        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("[info] manual-context"));
-    assert!(stdout.contains("broad workstream(s) have no manual outcome note"));
-    assert!(stdout.contains("shiplog journal add --date"));
-    assert!(stdout.contains("--title \"Outcome note for acme/platform\""));
-    assert!(stdout.contains("--workstream \"acme/platform\""));
-    assert!(
-        !stdout.contains("[info] code-only-workstream"),
-        "manual-context debt should cover broad code-only workstreams without printing a duplicate outcome-context debt item. stdout:\n{stdout}"
-    );
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report
+        .as_object_mut()
+        .expect("report should be an object")
+        .remove("repair_items");
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
-    );
+    shiplog_cmd()
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Report valid:"));
 }
 
 #[test]
-fn review_fixups_ranks_curation_actions_without_writing_artifacts() {
+fn report_validate_accepts_legacy_reports_without_packet_quality() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let events_path = tmp.path().join("ledger.events.jsonl");
-    let coverage_path = tmp.path().join("coverage.manifest.json");
-    let events: Vec<_> = (0..10)
-        .map(|idx| {
-            fixture_pr_event(
-                SourceSystem::Github,
-                "acme/platform",
-                300 + idx as u64,
-                &format!("Platform reliability fix {}", idx + 1),
-                2 + idx,
-            )
-        })
-        .collect();
-    let coverage = CoverageManifest {
-        run_id: RunId("run_fixups".into()),
-        generated_at: fixture_time(20),
-        user: "octo".into(),
-        window: fixture_window(),
-        mode: "fixture".into(),
-        sources: vec!["github".into()],
-        slices: vec![CoverageSlice {
-            window: fixture_window(),
-            query: "github fixture".into(),
-            total_count: events.len() as u64,
-            fetched: events.len() as u64,
-            incomplete_results: Some(false),
-            notes: vec!["fixture".into()],
-        }],
-        warnings: vec![],
-        completeness: Completeness::Complete,
-    };
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
 
     shiplog_cmd()
-        .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "json",
-            "--events",
-            events_path.to_str().unwrap(),
-            "--coverage",
-            coverage_path.to_str().unwrap(),
-        ])
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
-        .success();
-
-    let run_dir = out.join("run_fixups");
-    let curated = WorkstreamsFile {
-        version: 1,
-        generated_at: fixture_time(21),
-        workstreams: vec![Workstream {
-            id: WorkstreamId::from_parts(["repo", "acme/platform"]),
-            title: "acme/platform".into(),
-            summary: None,
-            tags: vec!["repo".into()],
-            stats: WorkstreamStats {
-                pull_requests: events.len(),
-                reviews: 0,
-                manual_events: 0,
-            },
-            events: events.iter().map(|event| event.id.clone()).collect(),
-            receipts: vec![],
-        }],
-    };
+        .success();
+
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report
+        .as_object_mut()
+        .expect("report should be an object")
+        .remove("packet_quality");
     std::fs::write(
-        run_dir.join("workstreams.yaml"),
-        serde_yaml::to_string(&curated).unwrap(),
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
     )
     .unwrap();
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    let workstreams_before = std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap();
 
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .args([
-            "review",
-            "fixups",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
+        .success()
+        .stdout(predicate::str::contains("Report valid:"));
+}
+
+#[test]
+fn report_validate_accepts_legacy_packet_quality_without_candidates_or_share_posture() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("Review fixups: run_fixups"));
-    assert!(stdout.contains("Top fixups:"));
-    assert!(stdout.contains("1. Add outcome context for \"acme/platform\""));
-    assert!(stdout.contains("shiplog journal add --date"));
-    assert!(stdout.contains("--title \"Outcome note for acme/platform\""));
-    assert!(stdout.contains("2. Select anchor receipts for \"acme/platform\""));
-    assert!(stdout.contains("shiplog workstreams receipts --out"));
-    assert!(stdout.contains("--run run_fixups --workstream \"acme/platform\""));
-    assert!(stdout.contains("3. Split broad workstream \"acme/platform\""));
-    assert!(stdout.contains("shiplog workstreams split --out"));
-    assert!(stdout.contains("--run run_fixups --from \"acme/platform\""));
-    assert!(stdout.contains("shiplog review --out"));
-    assert!(stdout.contains("shiplog render --out"));
-    assert!(stdout.contains("--mode scaffold"));
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    remove_packet_quality_fields_from_report(&report_path, &["claim_candidates", "share_posture"])
+        .unwrap();
 
-    let commands_assert = shiplog_cmd()
+    shiplog_cmd()
         .args([
-            "review",
-            "fixups",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--commands-only",
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
+        .success()
+        .stdout(predicate::str::contains("Report valid:"));
+}
+
+#[test]
+fn repair_plan_latest_renders_repair_items_from_latest_report() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out_arg.as_str(), "--no-open"])
+        .assert()
         .success();
-    let commands_stdout = String::from_utf8(commands_assert.get_output().stdout.clone()).unwrap();
-    assert!(!commands_stdout.contains("Review fixups:"));
-    assert!(!commands_stdout.contains("Top fixups:"));
-    assert!(!commands_stdout.contains("Next:"));
-    let commands: Vec<_> = commands_stdout
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .collect();
-    assert_eq!(
-        commands.len(),
-        4,
-        "commands-only should print just the ranked fixup commands"
-    );
-    assert!(commands[0].starts_with("shiplog journal add --date"));
-    assert!(commands[1].starts_with("shiplog workstreams receipts --out"));
-    assert!(commands[2].starts_with("shiplog workstreams split --out"));
-    assert!(commands[3].starts_with("shiplog journal add --date"));
-    assert!(commands.iter().all(|line| line.starts_with("shiplog ")));
 
-    let templates_assert = shiplog_cmd()
-        .args([
-            "review",
-            "fixups",
-            "--out",
-            out.to_str().unwrap(),
-            "--run",
-            "run_fixups",
-            "--journal-template",
-        ])
+    shiplog_cmd()
+        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Repair plan:"))
+        .stdout(predicate::str::contains("Intake status:"))
+        .stdout(predicate::str::contains("Packet readiness:"))
+        .stdout(predicate::str::contains("Repair queue:"))
+        .stdout(predicate::str::contains("repair_"))
+        .stdout(predicate::str::contains("manual_evidence_missing"))
+        .stdout(predicate::str::contains("Action: journal_add"))
+        .stdout(predicate::str::contains("Command: shiplog journal add"))
+        .stdout(predicate::str::contains("--from-repair"))
+        .stdout(predicate::str::contains("Receipts:"));
+
+    Ok(())
+}
+
+#[test]
+fn repair_plan_latest_accepts_relative_out_report_paths() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out_arg = "relative-out";
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out_arg, "--no-open"])
         .assert()
         .success();
-    let templates_stdout = String::from_utf8(templates_assert.get_output().stdout.clone()).unwrap();
-    assert!(!templates_stdout.contains("Review fixups:"));
-    assert!(!templates_stdout.contains("Top fixups:"));
-    assert!(!templates_stdout.contains("shiplog workstreams"));
-    let templates: Vec<_> = templates_stdout
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .collect();
-    assert_eq!(
-        templates.len(),
-        1,
-        "journal-template should dedupe repeated context gaps for one workstream"
-    );
-    assert!(templates[0].starts_with("shiplog journal add --date"));
-    assert!(templates[0].contains("--title \"Outcome note for acme/platform\""));
-    assert!(templates[0].contains("--workstream \"acme/platform\""));
-    assert!(templates[0].contains("--description \"<replace with factual context or outcome>\""));
 
     shiplog_cmd()
-        .args([
-            "review",
-            "fixups",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--commands-only",
-            "--journal-template",
-        ])
+        .current_dir(tmp.path())
+        .args(["repair", "plan", "--out", out_arg, "--latest"])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "either --commands-only or --journal-template",
-        ));
+        .success()
+        .stdout(predicate::str::contains("Repair plan:"))
+        .stdout(predicate::str::contains("Repair queue:"))
+        .stdout(predicate::str::contains(
+            "shiplog journal add --from-repair",
+        ))
+        .stdout(predicate::str::contains("--out \"relative-out\" --latest"));
+
+    Ok(())
+}
+
+#[test]
+fn repair_plan_latest_without_runs_prints_intake_command() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("missing runs");
+    let out_arg = out.to_string_lossy().to_string();
+
+    shiplog_cmd()
+        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Repair plan: no latest intake report found",
+        ))
+        .stdout(predicate::str::contains(
+            "shiplog intake --last-6-months --explain",
+        ))
+        .stdout(predicate::str::contains("--out"))
+        .stdout(predicate::str::contains(out_arg));
+
+    Ok(())
+}
+
+#[test]
+fn repair_plan_latest_handles_legacy_report_without_repair_items() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out_arg.as_str(), "--no-open"])
+        .assert()
+        .success();
+
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let report_text = std::fs::read_to_string(&report_path)?;
+    let mut report: serde_json::Value = serde_json::from_str(&report_text)?;
+    let Some(report_object) = report.as_object_mut() else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "report should be an object",
+        )
+        .into());
+    };
+    report_object.remove("repair_items");
+    report_object.remove("packet_quality");
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report)?),
+    )?;
+
+    shiplog_cmd()
+        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Intake status:"))
+        .stdout(predicate::str::contains("Packet readiness:").not())
+        .stdout(predicate::str::contains(
+            "Repair items: unavailable in this compatible v1 report.",
+        ))
+        .stdout(predicate::str::contains(
+            "shiplog intake --last-6-months --explain",
+        ))
+        .stdout(predicate::str::contains("--out"));
 
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
-    );
-    assert_eq!(
-        workstreams_before,
-        std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap()
-    );
+    Ok(())
 }
 
 #[test]
-fn review_surfaces_packet_quality_evidence_debt_categories() {
-    let tmp = TempDir::new().unwrap();
+fn repair_plan_latest_handles_empty_repair_queue() -> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
-    let events_path = tmp.path().join("ledger.events.jsonl");
-    let coverage_path = tmp.path().join("coverage.manifest.json");
-    let mut events = Vec::new();
-
-    let misc_events: Vec<_> = (0..5)
-        .map(|idx| {
-            fixture_pr_event(
-                SourceSystem::Github,
-                "acme/misc",
-                400 + idx as u64,
-                &format!("Misc cleanup {}", idx + 1),
-                2 + idx,
-            )
-        })
-        .collect();
-    let ticket_events: Vec<_> = (0..5)
-        .map(|idx| {
-            fixture_manual_event(
-                SourceSystem::Other("jira".into()),
-                "acme/support",
-                &format!("Support ticket {}", idx + 1),
-                ManualEventType::Other,
-                8 + idx,
-            )
-        })
-        .collect();
-    let manual_events = [fixture_manual_event(
-        SourceSystem::Manual,
-        "acme/manual",
-        "Customer reliability context",
-        ManualEventType::Incident,
-        14,
-    )];
-    let release_events: Vec<_> = (0..6)
-        .map(|idx| {
-            fixture_pr_event(
-                SourceSystem::Github,
-                "acme/release",
-                500 + idx as u64,
-                &format!("Release automation {}", idx + 1),
-                15 + idx,
-            )
-        })
-        .collect();
-
-    events.extend(misc_events.iter().cloned());
-    events.extend(ticket_events.iter().cloned());
-    events.extend(manual_events.iter().cloned());
-    events.extend(release_events.iter().cloned());
-
-    let coverage = CoverageManifest {
-        run_id: RunId("run_quality_debt".into()),
-        generated_at: fixture_time(25),
-        user: "octo".into(),
-        window: fixture_window(),
-        mode: "fixture".into(),
-        sources: vec!["github".into(), "jira".into(), "manual".into()],
-        slices: vec![CoverageSlice {
-            window: fixture_window(),
-            query: "quality fixture".into(),
-            total_count: events.len() as u64,
-            fetched: events.len() as u64,
-            incomplete_results: Some(false),
-            notes: vec!["fixture".into()],
-        }],
-        warnings: vec![],
-        completeness: Completeness::Complete,
-    };
-    write_events_jsonl(&events_path, &events);
-    write_coverage_manifest(&coverage_path, &coverage);
+    let out_arg = out.to_string_lossy().to_string();
 
     shiplog_cmd()
-        .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "json",
-            "--events",
-            events_path.to_str().unwrap(),
-            "--coverage",
-            coverage_path.to_str().unwrap(),
-        ])
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out_arg.as_str(), "--no-open"])
         .assert()
         .success();
 
-    let run_dir = out.join("run_quality_debt");
-    let curated = WorkstreamsFile {
-        version: 1,
-        generated_at: fixture_time(26),
-        workstreams: vec![
-            Workstream {
-                id: WorkstreamId::from_parts(["quality", "misc"]),
-                title: "Misc".into(),
-                summary: None,
-                tags: vec!["misc".into()],
-                stats: WorkstreamStats {
-                    pull_requests: misc_events.len(),
-                    reviews: 0,
-                    manual_events: 0,
-                },
-                events: misc_events.iter().map(|event| event.id.clone()).collect(),
-                receipts: vec![],
-            },
-            Workstream {
-                id: WorkstreamId::from_parts(["quality", "tickets"]),
-                title: "Customer Tickets".into(),
-                summary: None,
-                tags: vec!["tickets".into()],
-                stats: WorkstreamStats {
-                    pull_requests: 0,
-                    reviews: 0,
-                    manual_events: ticket_events.len(),
-                },
-                events: ticket_events.iter().map(|event| event.id.clone()).collect(),
-                receipts: vec![],
-            },
-            Workstream {
-                id: WorkstreamId::from_parts(["quality", "manual"]),
-                title: "Manual Context".into(),
-                summary: None,
-                tags: vec!["manual".into()],
-                stats: WorkstreamStats {
-                    pull_requests: 0,
-                    reviews: 0,
-                    manual_events: manual_events.len(),
-                },
-                events: manual_events.iter().map(|event| event.id.clone()).collect(),
-                receipts: vec![],
-            },
-            Workstream {
-                id: WorkstreamId::from_parts(["quality", "release"]),
-                title: "Release Automation".into(),
-                summary: None,
-                tags: vec!["release".into()],
-                stats: WorkstreamStats {
-                    pull_requests: release_events.len(),
-                    reviews: 0,
-                    manual_events: 0,
-                },
-                events: release_events
-                    .iter()
-                    .map(|event| event.id.clone())
-                    .collect(),
-                receipts: release_events
-                    .iter()
-                    .map(|event| event.id.clone())
-                    .collect(),
-            },
-        ],
-    };
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let report_text = std::fs::read_to_string(&report_path)?;
+    let mut report: serde_json::Value = serde_json::from_str(&report_text)?;
+    report["repair_items"] = serde_json::json!([]);
     std::fs::write(
-        run_dir.join("workstreams.yaml"),
-        serde_yaml::to_string(&curated).unwrap(),
-    )
-    .unwrap();
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
-    let workstreams_before = std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap();
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report)?),
+    )?;
 
-    let assert = shiplog_cmd()
-        .args(["review", "--out", out.to_str().unwrap(), "--latest"])
+    shiplog_cmd()
+        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let out_hint = format!("--out \"{}\"", out.display());
+        .success()
+        .stdout(predicate::str::contains("Repair queue: 0 item(s)"))
+        .stdout(predicate::str::contains("No repair items found."));
 
-    for needle in [
-        "[warning] no-selected-receipts".to_string(),
-        "[info] thin-workstream".to_string(),
-        "[warning] large-misc-workstream".to_string(),
-        "[info] code-only-workstream".to_string(),
-        "[info] ticket-only-workstream".to_string(),
-        "[info] manual-only-workstream".to_string(),
-        "[info] too-many-selected-receipts".to_string(),
-        format!("shiplog workstreams receipts {out_hint}"),
-        "--run run_quality_debt --workstream <title>".to_string(),
-        format!("shiplog workstreams split {out_hint}"),
-        "--run run_quality_debt".to_string(),
-        "shiplog journal add --date".to_string(),
-        "shiplog journal list".to_string(),
-    ] {
-        assert!(
-            stdout.contains(&needle),
-            "review output should mention {needle:?}"
-        );
-    }
+    Ok(())
+}
 
-    let fixups_assert = shiplog_cmd()
+#[test]
+fn repair_plan_latest_rejects_invalid_report_json() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let run = out.join("run_bad");
+    std::fs::create_dir_all(&run)?;
+    std::fs::write(run.join("ledger.events.jsonl"), "")?;
+    std::fs::write(run.join("intake.report.json"), "not json\n")?;
+    let out_arg = out.to_string_lossy().to_string();
+
+    shiplog_cmd()
+        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("parse"))
+        .stderr(predicate::str::contains("intake.report.json"));
+
+    Ok(())
+}
+
+#[test]
+fn repair_plan_run_rejects_path_traversal() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+
+    shiplog_cmd()
         .args([
-            "review",
-            "fixups",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--commands-only",
+            "repair",
+            "plan",
+            "--out",
+            out_arg.as_str(),
+            "--run",
+            "../outside",
         ])
         .assert()
-        .success();
-    let fixups_stdout = String::from_utf8(fixups_assert.get_output().stdout.clone()).unwrap();
-    assert!(fixups_stdout.contains("shiplog workstreams split"));
-    assert!(fixups_stdout.contains("shiplog workstreams receipts"));
-    assert!(fixups_stdout.contains("shiplog journal add"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "repair plan --run must be a single run directory name",
+        ));
 
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
+    Ok(())
+}
+
+#[test]
+fn repair_diff_latest_shows_cleared_new_still_open_and_changed_items() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, base_report) = load_first_intake_report(&out);
+
+    write_repair_diff_report(
+        &base_report,
+        &out,
+        "run_900_old",
+        serde_json::json!([
+            repair_diff_item(
+                "repair_001_still_old",
+                "manual:still",
+                "still open",
+                "shiplog journal add --from-repair repair_001_still_old",
+                "manual event exists"
+            ),
+            repair_diff_item(
+                "repair_002_changed_old",
+                "manual:changed",
+                "old reason",
+                "shiplog journal add --from-repair repair_002_changed_old",
+                "old clear condition"
+            ),
+            repair_diff_item(
+                "repair_003_cleared_old",
+                "manual:cleared",
+                "cleared reason",
+                "shiplog journal add --from-repair repair_003_cleared_old",
+                "cleared condition"
+            )
+        ]),
     );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+    write_repair_diff_report(
+        &base_report,
+        &out,
+        "run_901_new",
+        serde_json::json!([
+            repair_diff_item(
+                "repair_001_still_new",
+                "manual:still",
+                "still open",
+                "shiplog journal add --from-repair repair_001_still_old",
+                "manual event exists"
+            ),
+            repair_diff_item(
+                "repair_002_changed_new",
+                "manual:changed",
+                "new reason",
+                "shiplog journal add --from-repair repair_002_changed_new",
+                "new clear condition"
+            ),
+            repair_diff_item(
+                "repair_004_new",
+                "manual:new",
+                "new reason",
+                "shiplog journal add --from-repair repair_004_new",
+                "new condition"
+            )
+        ]),
     );
+
+    let before = file_tree_manifest(tmp.path());
+    shiplog_cmd()
+        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Repair diff: run_900_old -> run_901_new",
+        ))
+        .stdout(predicate::str::contains("Cleared: 1"))
+        .stdout(predicate::str::contains("manual:cleared"))
+        .stdout(predicate::str::contains("New: 1"))
+        .stdout(predicate::str::contains("manual:new"))
+        .stdout(predicate::str::contains("Still open: 1"))
+        .stdout(predicate::str::contains("manual:still"))
+        .stdout(predicate::str::contains("Changed: 1"))
+        .stdout(predicate::str::contains("manual:changed"))
+        .stdout(predicate::str::contains("Reason: old reason -> new reason"))
+        .stdout(predicate::str::contains(
+            "Clears when: old clear condition -> new clear condition",
+        ))
+        .stdout(predicate::str::contains("Next:"))
+        .stdout(predicate::str::contains(format!(
+            "shiplog runs diff --out \"{}\" --latest",
+            out.display()
+        )))
+        .stdout(predicate::str::contains(format!(
+            "shiplog open packet --out \"{}\" --run run_901_new",
+            out.display()
+        )))
+        .stdout(predicate::str::contains(format!(
+            "shiplog share explain manager --out \"{}\" --run run_901_new",
+            out.display()
+        )));
+    let after = file_tree_manifest(tmp.path());
+
     assert_eq!(
-        workstreams_before,
-        std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap()
+        before, after,
+        "repair diff should not write or rewrite files"
     );
+
+    Ok(())
 }
 
 #[test]
-fn review_strict_fails_when_evidence_debt_exists_without_writing_artifacts() {
-    let tmp = TempDir::new().unwrap();
+fn repair_diff_clears_source_repairs_only_after_source_contributes() -> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    let out_arg = out.to_string_lossy().to_string();
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, base_report) = load_first_intake_report(&out);
 
+    write_repair_diff_report(
+        &base_report,
+        &out,
+        "run_900_source_old",
+        serde_json::json!([repair_diff_item(
+            "repair_001_source_github_old",
+            "source:github:source_skipped_configuration",
+            "GitHub needs repair: GITHUB_TOKEN not found",
+            "export GITHUB_TOKEN=...",
+            "GitHub source contributes evidence on a rerun"
+        )]),
+    );
+    let newer_report_path = write_repair_diff_report(
+        &base_report,
+        &out,
+        "run_901_source_new",
+        serde_json::json!([]),
+    );
+    let mut newer_report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&newer_report_path)?)?;
+    newer_report["included_sources"] = serde_json::json!([
+        {
+            "source": "github",
+            "source_key": "github",
+            "source_label": "GitHub",
+            "event_count": 1,
+            "summary": "GitHub collected 1 event"
+        }
+    ]);
     std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[defaults]
-window = "year:2025"
+        &newer_report_path,
+        format!("{}\n", serde_json::to_string_pretty(&newer_report)?),
+    )?;
 
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-user = "octo"
-"#,
-    )
-    .unwrap();
+    shiplog_cmd()
+        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Cleared: 1"))
+        .stdout(predicate::str::contains(
+            "source:github:source_skipped_configuration",
+        ))
+        .stdout(predicate::str::contains("Still open: 0"));
+
+    Ok(())
+}
+
+#[test]
+fn repair_diff_latest_without_two_reports_prints_next_command() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("missing-out");
+    let out_arg = out.to_string_lossy().to_string();
 
     shiplog_cmd()
-        .args([
-            "collect",
-            "--out",
-            out.to_str().unwrap(),
-            "multi",
-            "--config",
-            tmp.path().join("shiplog.toml").to_str().unwrap(),
-        ])
+        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains(
+            "fewer than two compatible repair reports found",
+        ))
+        .stdout(predicate::str::contains("Compatible reports: 0"))
+        .stdout(predicate::str::contains(
+            "shiplog intake --last-6-months --explain",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn repair_diff_latest_reports_legacy_reports_without_repair_items() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (original_report_path, base_report) = load_first_intake_report(&out);
+    let mut original_legacy_report = base_report.clone();
+    original_legacy_report
+        .as_object_mut()
+        .expect("report should be an object")
+        .remove("repair_items");
+    std::fs::write(
+        &original_report_path,
+        format!(
+            "{}\n",
+            serde_json::to_string_pretty(&original_legacy_report)?
+        ),
+    )?;
+
+    let mut legacy_report = base_report.clone();
+    legacy_report
+        .as_object_mut()
+        .expect("report should be an object")
+        .remove("repair_items");
+    write_repair_diff_report(
+        &legacy_report,
+        &out,
+        "run_901_legacy",
+        serde_json::json!([]),
+    );
+    let legacy_path = out.join("run_901_legacy").join("intake.report.json");
+    let mut legacy_report_text: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&legacy_path)?)?;
+    legacy_report_text
+        .as_object_mut()
+        .expect("report should be an object")
+        .remove("repair_items");
+    std::fs::write(
+        &legacy_path,
+        format!("{}\n", serde_json::to_string_pretty(&legacy_report_text)?),
+    )?;
 
-    let run_dir = first_run_dir(&out);
-    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    write_repair_diff_report(
+        &base_report,
+        &out,
+        "run_900_compatible",
+        serde_json::json!([]),
+    );
 
     shiplog_cmd()
-        .args([
-            "review",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--strict",
-        ])
+        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
         .assert()
-        .failure()
-        .stdout(predicate::str::contains("Evidence debt:"))
-        .stdout(predicate::str::contains("[info] manual-context"))
-        .stderr(predicate::str::contains("review found"));
+        .success()
+        .stdout(predicate::str::contains(
+            "fewer than two compatible repair reports found",
+        ))
+        .stdout(predicate::str::contains("Compatible reports: 1"))
+        .stdout(predicate::str::contains(
+            "Skipped reports without repair_items:",
+        ))
+        .stdout(predicate::str::contains(
+            "shiplog intake --last-6-months --explain",
+        ));
 
-    assert_eq!(
-        packet_before,
-        std::fs::read_to_string(run_dir.join("packet.md")).unwrap()
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
-    );
+    Ok(())
 }
 
 #[test]
-fn open_packet_latest_prints_packet_path_when_forced() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+fn repair_diff_latest_handles_empty_repair_queues() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, base_report) = load_first_intake_report(&out);
 
-    let before = file_tree_manifest(tmp.path());
-    let assert = shiplog_cmd()
-        .args([
-            "open",
-            "packet",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--latest",
-            "--print-path",
-        ])
+    write_repair_diff_report(&base_report, &out, "run_900_old", serde_json::json!([]));
+    write_repair_diff_report(&base_report, &out, "run_901_new", serde_json::json!([]));
+
+    shiplog_cmd()
+        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("run_fixture"))
-        .stdout(predicate::str::contains("packet.md"));
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let after = file_tree_manifest(tmp.path());
+        .stdout(predicate::str::contains("Cleared: 0"))
+        .stdout(predicate::str::contains("New: 0"))
+        .stdout(predicate::str::contains("Still open: 0"))
+        .stdout(predicate::str::contains("Changed: 0"))
+        .stdout(predicate::str::contains("No repair state changes."));
 
-    assert_eq!(
-        before, after,
-        "open packet --latest --print-path should not write or rewrite files"
-    );
-    assert!(
-        !stdout.contains(r"\\?\"),
-        "open packet --print-path should not expose a Windows verbatim path prefix: {stdout}"
-    );
+    Ok(())
 }
 
 #[test]
-fn open_without_artifact_defaults_to_latest_packet() {
-    let tmp = TempDir::new().unwrap();
+fn runs_diff_reports_changed_repairs_as_neutral_changes() -> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
-    collect_json_into(&out);
+    let out_arg = out.to_string_lossy().to_string();
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, base_report) = load_first_intake_report(&out);
+
+    write_repair_diff_report(
+        &base_report,
+        &out,
+        "run_900_old",
+        serde_json::json!([repair_diff_item(
+            "repair_001_changed_old",
+            "manual:changed",
+            "old reason",
+            "shiplog journal add --from-repair repair_001_changed_old",
+            "old clear condition"
+        )]),
+    );
+    write_repair_diff_report(
+        &base_report,
+        &out,
+        "run_901_new",
+        serde_json::json!([repair_diff_item(
+            "repair_001_changed_new",
+            "manual:changed",
+            "new reason",
+            "shiplog journal add --from-repair repair_001_changed_new",
+            "new clear condition"
+        )]),
+    );
 
-    let before = file_tree_manifest(&out);
     let assert = shiplog_cmd()
-        .current_dir(tmp.path())
-        .args(["open", "--print-path"])
+        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("run_fixture"))
-        .stdout(predicate::str::contains("packet.md"));
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let after = file_tree_manifest(&out);
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
 
-    assert_eq!(
-        before, after,
-        "open --print-path should not write or rewrite files"
+    assert!(
+        stdout.contains("Changed:\n- repair manual:changed changed"),
+        "changed repair items should be surfaced as neutral changes. stdout:\n{stdout}"
     );
     assert!(
-        !stdout.contains(r"\\?\"),
-        "open --print-path should not expose a Windows verbatim path prefix: {stdout}"
+        !stdout.contains("Regressed:\n- repair manual:changed"),
+        "changed repair items should not be labeled as regressions. stdout:\n{stdout}"
     );
-}
-
-#[test]
-fn open_packet_latest_selects_lexicographically_newest_run() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path();
-    let newest = "2026-05-13T09-00-00Z-newest";
-    let older = "2026-05-12T09-00-00Z-older";
-
-    write_minimal_open_run(out, newest, "newest packet");
-    std::thread::sleep(std::time::Duration::from_millis(25));
-    write_minimal_open_run(out, older, "older packet");
 
-    shiplog_cmd()
-        .args([
-            "open",
-            "packet",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--print-path",
-        ])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains(newest))
-        .stdout(predicate::str::contains("packet.md"))
-        .stdout(predicate::str::contains(older).not());
+    Ok(())
 }
 
 #[test]
-fn open_latest_without_runs_prints_intake_command_to_create_one() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("missing runs");
-    let out_arg = out.to_str().unwrap();
+fn runs_diff_latest_reports_quality_without_writing() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
 
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, first_report) = load_first_intake_report(&out);
+    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
+        .current_dir(tmp.path())
         .args([
-            "open",
-            "packet",
+            "journal",
+            "add",
+            "--from-repair",
+            repair_id.as_str(),
             "--out",
-            out_arg,
+            out_arg.as_str(),
             "--latest",
-            "--print-path",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("No run directories found"))
-        .stderr(predicate::str::contains(
-            "shiplog intake --last-6-months --explain",
-        ))
-        .stderr(predicate::str::contains("--out"))
-        .stderr(predicate::str::contains(out_arg));
-}
-
-fn write_minimal_open_run(out: &Path, run_id: &str, packet_body: &str) {
-    let run = out.join(run_id);
-    std::fs::create_dir_all(&run).unwrap();
-    std::fs::write(run.join("ledger.events.jsonl"), "").unwrap();
-    std::fs::write(run.join("packet.md"), packet_body).unwrap();
-}
+        .success();
+    run_intake_without_provider_tokens(tmp.path(), &out);
 
-#[test]
-fn open_workstreams_latest_prints_effective_workstreams_path_when_forced() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let before = file_tree_manifest(tmp.path());
+    let repair_diff = shiplog_cmd()
+        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
+        .assert()
+        .success();
+    let repair_diff_stdout = String::from_utf8(repair_diff.get_output().stdout.clone())?;
+    assert!(
+        repair_diff_stdout.contains("Still open:")
+            && repair_diff_stdout.contains("source:github:source_skipped_configuration"),
+        "repair diff should keep unproven source repairs open after a journal-only repair. stdout:\n{repair_diff_stdout}"
+    );
+    let repair_diff_cleared = repair_diff_stdout
+        .split("Cleared:")
+        .nth(1)
+        .and_then(|section| section.split("New:").next())
+        .unwrap_or("");
+    assert!(
+        !repair_diff_cleared.contains("source:github:source_skipped_configuration"),
+        "repair diff should not mark an unproven source repair as cleared. stdout:\n{repair_diff_stdout}"
+    );
 
     shiplog_cmd()
-        .args([
-            "open",
-            "workstreams",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--latest",
-            "--print-path",
-        ])
+        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("run_fixture"))
-        .stdout(predicate::str::contains("workstreams.suggested.yaml"));
-}
+        .stdout(predicate::str::contains("Packet quality diff:"))
+        .stdout(predicate::str::contains("manual evidence count 0 -> 1"))
+        .stdout(predicate::str::contains("claim candidates 0 -> 1"))
+        .stdout(predicate::str::contains(
+            "source:github:source_skipped_configuration still open",
+        ))
+        .stdout(
+            predicate::str::contains("source:github:source_skipped_configuration cleared").not(),
+        )
+        .stdout(predicate::str::contains(format!(
+            "shiplog open packet --out \"{}\"",
+            out.display()
+        )))
+        .stdout(predicate::str::contains(format!(
+            "shiplog share explain manager --out \"{}\"",
+            out.display()
+        )));
+    let after = file_tree_manifest(tmp.path());
 
-#[test]
-fn open_out_latest_prints_run_directory_when_forced() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    assert_eq!(before, after, "runs diff should not write or rewrite files");
 
-    shiplog_cmd()
-        .args([
-            "open",
-            "out",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--latest",
-            "--print-path",
-        ])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("run_fixture"));
+    Ok(())
 }
 
 #[test]
-fn open_intake_report_latest_prints_report_path_when_forced() {
-    let tmp = TempDir::new().unwrap();
+fn intake_setup_blocked_manual_handoff_starts_with_doctor() -> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+    let fixtures = fixture_dir();
+    let events = fixtures
+        .join("ledger.events.jsonl")
+        .display()
+        .to_string()
+        .replace('\\', "/");
+    let coverage = fixtures
+        .join("coverage.manifest.json")
+        .display()
+        .to_string()
+        .replace('\\', "/");
+    std::fs::write(tmp.path().join("manual_events.yaml"), "events: []\n")?;
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        format!(
+            r#"[shiplog]
+config_version = 1
 
-    shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success();
+[defaults]
+out = "./out"
+window = "last-6-months"
+profile = "internal"
 
-    shiplog_cmd()
-        .args([
-            "open",
-            "intake-report",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--print-path",
-        ])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("intake.report.md"));
-}
+[sources.json]
+enabled = true
+events = "{events}"
+coverage = "{coverage}"
 
-#[test]
-fn open_report_alias_prints_intake_report_path_when_forced() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#
+        ),
+    )?;
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success();
-
-    shiplog_cmd()
         .args([
-            "open",
-            "report",
+            "intake",
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--print-path",
+            out_arg.as_str(),
+            "--no-open",
+            "--explain",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("intake.report.md"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    assert!(
+        stdout.contains(&format!(
+            "Next:\n1. shiplog doctor --setup\n2. shiplog sources status\n3. shiplog repair plan --out \"{}\" --latest",
+            out.display()
+        )),
+        "setup-blocked intake should start with doctor/source status before repair plan. stdout:\n{stdout}"
+    );
+    assert!(
+        !stdout.contains("journal add --from-repair"),
+        "malformed manual setup should not offer journal repair writes before setup is fixed. stdout:\n{stdout}"
+    );
+
+    let (_, report) = load_first_intake_report(&out);
+    let next_commands = report["next_commands"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|command| command.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(next_commands.first(), Some(&"shiplog doctor --setup"));
+    assert_eq!(next_commands.get(1), Some(&"shiplog sources status"));
+    assert!(
+        next_commands
+            .get(2)
+            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
+        "repair plan should remain available after setup preflight: {next_commands:?}"
+    );
+    assert!(
+        next_commands
+            .iter()
+            .all(|command| !command.contains("journal add --from-repair")),
+        "persisted next commands should not include dead-end journal repair writes: {next_commands:?}"
+    );
+
+    Ok(())
 }
 
 #[test]
-fn report_validate_accepts_latest_and_direct_path() {
-    let tmp = TempDir::new().unwrap();
+fn intake_setup_blocked_provider_handoff_starts_with_doctor() -> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[shiplog]
+config_version = 1
 
-    shiplog_cmd()
+[defaults]
+out = "./out"
+window = "last-6-months"
+profile = "internal"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+
+[sources.github]
+enabled = true
+user = "octo"
+"#,
+    )?;
+
+    let assert = shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success();
-
-    let run_dir = first_run_dir(&out);
-    let report_path = run_dir.join("intake.report.json");
-
-    shiplog_cmd()
         .args([
-            "report",
-            "validate",
+            "intake",
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            out_arg.as_str(),
+            "--no-open",
+            "--explain",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Report valid:"))
-        .stdout(predicate::str::contains("Schema: v1"))
-        .stdout(predicate::str::contains("Intake status:"))
-        .stdout(predicate::str::contains("Packet readiness:"))
-        .stdout(predicate::str::contains("Artifacts:"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    assert!(
+        stdout.contains(&format!(
+            "Next:\n1. shiplog doctor --setup\n2. shiplog sources status\n3. shiplog repair plan --out \"{}\" --latest",
+            out.display()
+        )),
+        "missing provider token should route through setup status before repair plan. stdout:\n{stdout}"
+    );
+    assert!(
+        stdout.contains("GitHub: GitHub authentication unavailable"),
+        "intake should still explain the concrete provider setup gap. stdout:\n{stdout}"
+    );
 
-    shiplog_cmd()
-        .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
-        ])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Report valid:"))
-        .stdout(predicate::str::contains("intake.report.json"));
+    let (_, report) = load_first_intake_report(&out);
+    let next_commands = report["next_commands"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|command| command.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(next_commands.first(), Some(&"shiplog doctor --setup"));
+    assert_eq!(next_commands.get(1), Some(&"shiplog sources status"));
+    assert!(
+        next_commands
+            .get(2)
+            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
+        "repair plan should remain available after setup preflight: {next_commands:?}"
+    );
+
+    Ok(())
 }
 
 #[test]
-fn report_validate_rejects_unsupported_schema_version() {
-    let tmp = TempDir::new().unwrap();
+fn repaired_rerun_intake_hands_off_to_repair_diff_first() -> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
 
-    shiplog_cmd()
+    let first = shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "intake",
+            "--out",
+            out_arg.as_str(),
+            "--no-open",
+            "--explain",
+        ])
         .assert()
         .success();
+    let first_stdout = String::from_utf8(first.get_output().stdout.clone())?;
+    assert!(
+        first_stdout.contains(&format!(
+            "Next:\n1. shiplog repair plan --out \"{}\" --latest",
+            out.display()
+        )),
+        "first repairable intake should start at repair plan. stdout:\n{first_stdout}"
+    );
+    assert!(
+        !first_stdout.contains("shiplog repair diff"),
+        "first repairable intake should not offer repair diff before a prior report exists. stdout:\n{first_stdout}"
+    );
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["schema_version"] = serde_json::json!(2);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
-
+    let (_, first_report) = load_first_intake_report(&out);
+    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
+        .current_dir(tmp.path())
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "journal",
+            "add",
+            "--from-repair",
+            repair_id.as_str(),
+            "--out",
+            out_arg.as_str(),
+            "--latest",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "unsupported intake report schema_version 2",
-        ));
-}
-
-#[test]
-fn report_validate_rejects_secret_sentinels() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+        .success();
 
-    shiplog_cmd()
+    let second = shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "intake",
+            "--out",
+            out_arg.as_str(),
+            "--no-open",
+            "--explain",
+        ])
         .assert()
         .success();
+    let second_stdout = String::from_utf8(second.get_output().stdout.clone())?;
+    assert!(
+        second_stdout.contains(&format!(
+            "Next:\n1. shiplog repair diff --out \"{}\" --latest\n2. shiplog repair plan --out \"{}\" --latest",
+            out.display(),
+            out.display()
+        )),
+        "repaired rerun should hand off to repair diff before planning more work. stdout:\n{second_stdout}"
+    );
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["good"] = serde_json::json!(["super-secret"]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    let latest_run = all_run_dirs(&out)
+        .into_iter()
+        .last()
+        .expect("rerun should create a latest run");
+    let latest_report: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        latest_run.join("intake.report.json"),
+    )?)?;
+    let next_commands = latest_report["next_commands"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|command| command.as_str())
+        .collect::<Vec<_>>();
+    assert!(
+        next_commands
+            .first()
+            .is_some_and(|command| command.starts_with("shiplog repair diff ")),
+        "latest report should persist repair diff as the first next command: {next_commands:?}"
+    );
+    assert!(
+        next_commands
+            .get(1)
+            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
+        "latest report should keep repair plan as the second next command: {next_commands:?}"
+    );
 
-    shiplog_cmd()
-        .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("contains secret sentinel"));
+    Ok(())
 }
 
 #[test]
-fn report_validate_accepts_legacy_repair_sources_without_kind() {
-    let tmp = TempDir::new().unwrap();
+fn runs_diff_latest_handles_legacy_reports_without_packet_quality() -> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
 
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, first_report) = load_first_intake_report(&out);
+    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
         .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "journal",
+            "add",
+            "--from-repair",
+            repair_id.as_str(),
+            "--out",
+            out_arg.as_str(),
+            "--latest",
+        ])
         .assert()
         .success();
+    run_intake_without_provider_tokens(tmp.path(), &out);
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["repair_sources"] = serde_json::json!([
-        {
-            "source": "Jira",
-            "reason": "missing JIRA_TOKEN",
-            "commands": ["export JIRA_TOKEN=..."]
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    let run_dirs = all_run_dirs(&out);
+    assert!(
+        run_dirs.len() >= 2,
+        "legacy packet-quality diff test needs two run directories"
+    );
+    for run_dir in &run_dirs {
+        remove_packet_quality_from_report(&run_dir.join("intake.report.json"))?;
+    }
 
+    let before = file_tree_manifest(tmp.path());
     shiplog_cmd()
-        .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
-        ])
+        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Report valid:"));
+        .stdout(predicate::str::contains("Packet quality diff:"))
+        .stdout(predicate::str::contains("Reports:"))
+        .stdout(predicate::str::contains("Improved:"))
+        .stdout(predicate::str::contains("evidence events 0 -> 1"))
+        .stdout(predicate::str::contains("manual evidence count 0 -> 1"))
+        .stdout(predicate::str::contains("coverage gaps 6 -> 0"))
+        .stdout(predicate::str::contains(
+            "source:github:source_skipped_configuration still open",
+        ))
+        .stdout(
+            predicate::str::contains("source:github:source_skipped_configuration cleared").not(),
+        )
+        .stdout(predicate::str::contains("packet readiness unavailable"))
+        .stdout(predicate::str::contains(
+            "packet quality unavailable; rerun intake for review-ready signals",
+        ))
+        .stdout(
+            predicate::str::contains("packet readiness Needs evidence -> Ready for review").not(),
+        )
+        .stdout(predicate::str::contains("packet readiness: Ready for review").not())
+        .stdout(predicate::str::contains(format!(
+            "shiplog open packet --out \"{}\"",
+            out.display()
+        )));
+    let after = file_tree_manifest(tmp.path());
+
+    assert_eq!(
+        before, after,
+        "runs diff should not write or rewrite legacy report files"
+    );
+
+    Ok(())
 }
 
 #[test]
-fn report_validate_accepts_legacy_fixups_without_id_or_kind() {
-    let tmp = TempDir::new().unwrap();
+fn runs_diff_latest_handles_legacy_packet_quality_without_candidates_or_share_posture()
+-> CliTestResult {
+    let tmp = TempDir::new()?;
     let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
 
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, first_report) = load_first_intake_report(&out);
+    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
         .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "journal",
+            "add",
+            "--from-repair",
+            repair_id.as_str(),
+            "--out",
+            out_arg.as_str(),
+            "--latest",
+        ])
         .assert()
         .success();
+    run_intake_without_provider_tokens(tmp.path(), &out);
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["top_fixups"] = serde_json::json!([
-        {
-            "title": "Add outcome context",
-            "detail": null,
-            "command": "shiplog journal add --title \"Outcome note\""
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    let run_dirs = all_run_dirs(&out);
+    assert!(
+        run_dirs.len() >= 2,
+        "legacy partial packet-quality diff test needs two run directories"
+    );
+    for run_dir in &run_dirs {
+        remove_packet_quality_fields_from_report(
+            &run_dir.join("intake.report.json"),
+            &["claim_candidates", "share_posture"],
+        )?;
+    }
 
+    let before = file_tree_manifest(tmp.path());
     shiplog_cmd()
-        .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
-        ])
+        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Report valid:"));
+        .stdout(predicate::str::contains("Packet quality diff:"))
+        .stdout(predicate::str::contains(
+            "packet quality incomplete; rerun intake for review-ready signals",
+        ))
+        .stdout(predicate::str::contains("claim candidates 0 -> 1").not())
+        .stdout(predicate::str::contains(format!(
+            "shiplog open packet --out \"{}\"",
+            out.display()
+        )));
+    let after = file_tree_manifest(tmp.path());
+
+    assert_eq!(
+        before, after,
+        "runs diff should not write or rewrite partial legacy packet-quality reports"
+    );
+
+    Ok(())
 }
 
 #[test]
-fn report_validate_accepts_legacy_reports_without_actions() {
+fn report_validate_rejects_unknown_source_key() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
 
@@ -13597,10 +15979,7 @@ fn report_validate_accepts_legacy_reports_without_actions() {
     let report_path = first_run_dir(&out).join("intake.report.json");
     let mut report: serde_json::Value =
         serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report
-        .as_object_mut()
-        .expect("report should be an object")
-        .remove("actions");
+    report["source_decisions"][0]["source_key"] = serde_json::json!("storybook");
     std::fs::write(
         &report_path,
         format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
@@ -13615,12 +15994,14 @@ fn report_validate_accepts_legacy_reports_without_actions() {
             report_path.to_str().unwrap(),
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Report valid:"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "source_decisions source_key \"storybook\" is not supported",
+        ));
 }
 
 #[test]
-fn report_validate_accepts_legacy_reports_without_repair_items() {
+fn report_validate_rejects_source_identity_mismatch() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
 
@@ -13637,10 +16018,7 @@ fn report_validate_accepts_legacy_reports_without_repair_items() {
     let report_path = first_run_dir(&out).join("intake.report.json");
     let mut report: serde_json::Value =
         serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report
-        .as_object_mut()
-        .expect("report should be an object")
-        .remove("repair_items");
+    report["source_decisions"][0]["source"] = serde_json::json!("gitlab");
     std::fs::write(
         &report_path,
         format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
@@ -13655,12 +16033,15 @@ fn report_validate_accepts_legacy_reports_without_repair_items() {
             report_path.to_str().unwrap(),
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Report valid:"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "intake report source_decisions source",
+        ))
+        .stderr(predicate::str::contains("not source_key"));
 }
 
 #[test]
-fn report_validate_accepts_legacy_reports_without_packet_quality() {
+fn report_validate_rejects_source_label_mismatch() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
 
@@ -13677,10 +16058,7 @@ fn report_validate_accepts_legacy_reports_without_packet_quality() {
     let report_path = first_run_dir(&out).join("intake.report.json");
     let mut report: serde_json::Value =
         serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report
-        .as_object_mut()
-        .expect("report should be an object")
-        .remove("packet_quality");
+    report["source_freshness"][0]["source_label"] = serde_json::json!("GitLab");
     std::fs::write(
         &report_path,
         format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
@@ -13695,12 +16073,15 @@ fn report_validate_accepts_legacy_reports_without_packet_quality() {
             report_path.to_str().unwrap(),
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Report valid:"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "intake report source_freshness source_label",
+        ))
+        .stderr(predicate::str::contains("does not match source_key"));
 }
 
 #[test]
-fn report_validate_accepts_legacy_packet_quality_without_candidates_or_share_posture() {
+fn report_validate_rejects_unknown_fixup_kind() {
     let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
 
@@ -13715,8 +16096,22 @@ fn report_validate_accepts_legacy_packet_quality_without_candidates_or_share_pos
         .success();
 
     let report_path = first_run_dir(&out).join("intake.report.json");
-    remove_packet_quality_fields_from_report(&report_path, &["claim_candidates", "share_posture"])
-        .unwrap();
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["top_fixups"] = serde_json::json!([
+        {
+            "id": "fixup_mystery",
+            "kind": "mystery",
+            "title": "Mystery fixup",
+            "detail": null,
+            "command": "shiplog review --latest"
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
         .args([
@@ -13726,101 +16121,16 @@ fn report_validate_accepts_legacy_packet_quality_without_candidates_or_share_pos
             report_path.to_str().unwrap(),
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Report valid:"));
-}
-
-#[test]
-fn repair_plan_latest_renders_repair_items_from_latest_report() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-
-    shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out_arg.as_str(), "--no-open"])
-        .assert()
-        .success();
-
-    shiplog_cmd()
-        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Repair plan:"))
-        .stdout(predicate::str::contains("Intake status:"))
-        .stdout(predicate::str::contains("Packet readiness:"))
-        .stdout(predicate::str::contains("Repair queue:"))
-        .stdout(predicate::str::contains("repair_"))
-        .stdout(predicate::str::contains("manual_evidence_missing"))
-        .stdout(predicate::str::contains("Action: journal_add"))
-        .stdout(predicate::str::contains("Command: shiplog journal add"))
-        .stdout(predicate::str::contains("--from-repair"))
-        .stdout(predicate::str::contains("Receipts:"));
-
-    Ok(())
-}
-
-#[test]
-fn repair_plan_latest_accepts_relative_out_report_paths() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out_arg = "relative-out";
-
-    shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out_arg, "--no-open"])
-        .assert()
-        .success();
-
-    shiplog_cmd()
-        .current_dir(tmp.path())
-        .args(["repair", "plan", "--out", out_arg, "--latest"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Repair plan:"))
-        .stdout(predicate::str::contains("Repair queue:"))
-        .stdout(predicate::str::contains(
-            "shiplog journal add --from-repair",
-        ))
-        .stdout(predicate::str::contains("--out \"relative-out\" --latest"));
-
-    Ok(())
-}
-
-#[test]
-fn repair_plan_latest_without_runs_prints_intake_command() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("missing runs");
-    let out_arg = out.to_string_lossy().to_string();
-
-    shiplog_cmd()
-        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Repair plan: no latest intake report found",
-        ))
-        .stdout(predicate::str::contains(
-            "shiplog intake --last-6-months --explain",
-        ))
-        .stdout(predicate::str::contains("--out"))
-        .stdout(predicate::str::contains(out_arg));
-
-    Ok(())
+        .failure()
+        .stderr(predicate::str::contains(
+            "top_fixups kind \"mystery\" is not supported",
+        ));
 }
 
 #[test]
-fn repair_plan_latest_handles_legacy_report_without_repair_items() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_invalid_fixup_id() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
 
     shiplog_cmd()
         .current_dir(tmp.path())
@@ -13828,49 +16138,46 @@ fn repair_plan_latest_handles_legacy_report_without_repair_items() -> CliTestRes
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out_arg.as_str(), "--no-open"])
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
 
     let report_path = first_run_dir(&out).join("intake.report.json");
-    let report_text = std::fs::read_to_string(&report_path)?;
-    let mut report: serde_json::Value = serde_json::from_str(&report_text)?;
-    let Some(report_object) = report.as_object_mut() else {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "report should be an object",
-        )
-        .into());
-    };
-    report_object.remove("repair_items");
-    report_object.remove("packet_quality");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["top_fixups"] = serde_json::json!([
+        {
+            "id": "Fixup Bad Id",
+            "kind": "manual_context",
+            "title": "Bad fixup id",
+            "detail": null,
+            "command": "shiplog review --latest"
+        }
+    ]);
     std::fs::write(
         &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report)?),
-    )?;
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Intake status:"))
-        .stdout(predicate::str::contains("Packet readiness:").not())
-        .stdout(predicate::str::contains(
-            "Repair items: unavailable in this compatible v1 report.",
-        ))
-        .stdout(predicate::str::contains(
-            "shiplog intake --last-6-months --explain",
-        ))
-        .stdout(predicate::str::contains("--out"));
-
-    Ok(())
+        .failure()
+        .stderr(predicate::str::contains(
+            "top_fixups id must match fixup_[a-z0-9_]+",
+        ));
 }
 
 #[test]
-fn repair_plan_latest_handles_empty_repair_queue() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_unknown_action_kind() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
 
     shiplog_cmd()
         .current_dir(tmp.path())
@@ -13878,2427 +16185,2847 @@ fn repair_plan_latest_handles_empty_repair_queue() -> CliTestResult {
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out_arg.as_str(), "--no-open"])
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
 
     let report_path = first_run_dir(&out).join("intake.report.json");
-    let report_text = std::fs::read_to_string(&report_path)?;
-    let mut report: serde_json::Value = serde_json::from_str(&report_text)?;
-    report["repair_items"] = serde_json::json!([]);
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["actions"] = serde_json::json!([
+        {
+            "id": "action_mystery",
+            "kind": "mystery",
+            "label": "Mystery action",
+            "command": "shiplog review --latest",
+            "writes": false,
+            "risk": "low"
+        }
+    ]);
     std::fs::write(
         &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report)?),
-    )?;
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Repair queue: 0 item(s)"))
-        .stdout(predicate::str::contains("No repair items found."));
-
-    Ok(())
+        .failure()
+        .stderr(predicate::str::contains(
+            "actions kind \"mystery\" is not supported",
+        ));
 }
 
 #[test]
-fn repair_plan_latest_rejects_invalid_report_json() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_invalid_action_id() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let run = out.join("run_bad");
-    std::fs::create_dir_all(&run)?;
-    std::fs::write(run.join("ledger.events.jsonl"), "")?;
-    std::fs::write(run.join("intake.report.json"), "not json\n")?;
-    let out_arg = out.to_string_lossy().to_string();
 
     shiplog_cmd()
-        .args(["repair", "plan", "--out", out_arg.as_str(), "--latest"])
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("parse"))
-        .stderr(predicate::str::contains("intake.report.json"));
-
-    Ok(())
-}
+        .success();
 
-#[test]
-fn repair_plan_run_rejects_path_traversal() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["actions"] = serde_json::json!([
+        {
+            "id": "Action Bad",
+            "kind": "fixup",
+            "label": "Bad action",
+            "command": "shiplog review --latest",
+            "writes": false,
+            "risk": "low"
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
         .args([
-            "repair",
-            "plan",
-            "--out",
-            out_arg.as_str(),
-            "--run",
-            "../outside",
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
         .failure()
         .stderr(predicate::str::contains(
-            "repair plan --run must be a single run directory name",
+            "actions id must match action_[a-z0-9_]+",
         ));
-
-    Ok(())
 }
 
 #[test]
-fn repair_diff_latest_shows_cleared_new_still_open_and_changed_items() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_unknown_repair_item_kind() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, base_report) = load_first_intake_report(&out);
-
-    write_repair_diff_report(
-        &base_report,
-        &out,
-        "run_900_old",
-        serde_json::json!([
-            repair_diff_item(
-                "repair_001_still_old",
-                "manual:still",
-                "still open",
-                "shiplog journal add --from-repair repair_001_still_old",
-                "manual event exists"
-            ),
-            repair_diff_item(
-                "repair_002_changed_old",
-                "manual:changed",
-                "old reason",
-                "shiplog journal add --from-repair repair_002_changed_old",
-                "old clear condition"
-            ),
-            repair_diff_item(
-                "repair_003_cleared_old",
-                "manual:cleared",
-                "cleared reason",
-                "shiplog journal add --from-repair repair_003_cleared_old",
-                "cleared condition"
-            )
-        ]),
-    );
-    write_repair_diff_report(
-        &base_report,
-        &out,
-        "run_901_new",
-        serde_json::json!([
-            repair_diff_item(
-                "repair_001_still_new",
-                "manual:still",
-                "still open",
-                "shiplog journal add --from-repair repair_001_still_old",
-                "manual event exists"
-            ),
-            repair_diff_item(
-                "repair_002_changed_new",
-                "manual:changed",
-                "new reason",
-                "shiplog journal add --from-repair repair_002_changed_new",
-                "new clear condition"
-            ),
-            repair_diff_item(
-                "repair_004_new",
-                "manual:new",
-                "new reason",
-                "shiplog journal add --from-repair repair_004_new",
-                "new condition"
-            )
-        ]),
-    );
 
-    let before = file_tree_manifest(tmp.path());
     shiplog_cmd()
-        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Repair diff: run_900_old -> run_901_new",
-        ))
-        .stdout(predicate::str::contains("Cleared: 1"))
-        .stdout(predicate::str::contains("manual:cleared"))
-        .stdout(predicate::str::contains("New: 1"))
-        .stdout(predicate::str::contains("manual:new"))
-        .stdout(predicate::str::contains("Still open: 1"))
-        .stdout(predicate::str::contains("manual:still"))
-        .stdout(predicate::str::contains("Changed: 1"))
-        .stdout(predicate::str::contains("manual:changed"))
-        .stdout(predicate::str::contains("Reason: old reason -> new reason"))
-        .stdout(predicate::str::contains(
-            "Clears when: old clear condition -> new clear condition",
-        ))
-        .stdout(predicate::str::contains("Next:"))
-        .stdout(predicate::str::contains(format!(
-            "shiplog runs diff --out \"{}\" --latest",
-            out.display()
-        )))
-        .stdout(predicate::str::contains(format!(
-            "shiplog open packet --out \"{}\" --run run_901_new",
-            out.display()
-        )))
-        .stdout(predicate::str::contains(format!(
-            "shiplog share explain manager --out \"{}\" --run run_901_new",
-            out.display()
-        )));
-    let after = file_tree_manifest(tmp.path());
-
-    assert_eq!(
-        before, after,
-        "repair diff should not write or rewrite files"
-    );
-
-    Ok(())
-}
-
-#[test]
-fn repair_diff_clears_source_repairs_only_after_source_contributes() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, base_report) = load_first_intake_report(&out);
+        .success();
 
-    write_repair_diff_report(
-        &base_report,
-        &out,
-        "run_900_source_old",
-        serde_json::json!([repair_diff_item(
-            "repair_001_source_github_old",
-            "source:github:source_skipped_configuration",
-            "GitHub needs repair: GITHUB_TOKEN not found",
-            "export GITHUB_TOKEN=...",
-            "GitHub source contributes evidence on a rerun"
-        )]),
-    );
-    let newer_report_path = write_repair_diff_report(
-        &base_report,
-        &out,
-        "run_901_source_new",
-        serde_json::json!([]),
-    );
-    let mut newer_report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&newer_report_path)?)?;
-    newer_report["included_sources"] = serde_json::json!([
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["repair_items"] = serde_json::json!([
         {
-            "source": "github",
-            "source_key": "github",
-            "source_label": "GitHub",
-            "event_count": 1,
-            "summary": "GitHub collected 1 event"
+            "repair_id": "repair_001_mystery",
+            "repair_key": "mystery",
+            "kind": "mystery",
+            "reason": "Mystery repair",
+            "action": {
+                "kind": "no_safe_action"
+            },
+            "clears_when": "the mystery clears",
+            "receipt_refs": [
+                {
+                    "field": "evidence_debt"
+                }
+            ]
         }
     ]);
     std::fs::write(
-        &newer_report_path,
-        format!("{}\n", serde_json::to_string_pretty(&newer_report)?),
-    )?;
-
-    shiplog_cmd()
-        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Cleared: 1"))
-        .stdout(predicate::str::contains(
-            "source:github:source_skipped_configuration",
-        ))
-        .stdout(predicate::str::contains("Still open: 0"));
-
-    Ok(())
-}
-
-#[test]
-fn repair_diff_latest_without_two_reports_prints_next_command() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("missing-out");
-    let out_arg = out.to_string_lossy().to_string();
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "fewer than two compatible repair reports found",
-        ))
-        .stdout(predicate::str::contains("Compatible reports: 0"))
-        .stdout(predicate::str::contains(
-            "shiplog intake --last-6-months --explain",
+        .failure()
+        .stderr(predicate::str::contains(
+            "repair_items kind \"mystery\" is not supported",
         ));
-
-    Ok(())
 }
 
 #[test]
-fn repair_diff_latest_reports_legacy_reports_without_repair_items() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_invalid_repair_item_id() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (original_report_path, base_report) = load_first_intake_report(&out);
-    let mut original_legacy_report = base_report.clone();
-    original_legacy_report
-        .as_object_mut()
-        .expect("report should be an object")
-        .remove("repair_items");
-    std::fs::write(
-        &original_report_path,
-        format!(
-            "{}\n",
-            serde_json::to_string_pretty(&original_legacy_report)?
-        ),
-    )?;
 
-    let mut legacy_report = base_report.clone();
-    legacy_report
-        .as_object_mut()
-        .expect("report should be an object")
-        .remove("repair_items");
-    write_repair_diff_report(
-        &legacy_report,
-        &out,
-        "run_901_legacy",
-        serde_json::json!([]),
-    );
-    let legacy_path = out.join("run_901_legacy").join("intake.report.json");
-    let mut legacy_report_text: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&legacy_path)?)?;
-    legacy_report_text
-        .as_object_mut()
-        .expect("report should be an object")
-        .remove("repair_items");
-    std::fs::write(
-        &legacy_path,
-        format!("{}\n", serde_json::to_string_pretty(&legacy_report_text)?),
-    )?;
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success();
 
-    write_repair_diff_report(
-        &base_report,
-        &out,
-        "run_900_compatible",
-        serde_json::json!([]),
-    );
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["repair_items"] = serde_json::json!([
+        {
+            "repair_id": "Repair Bad",
+            "repair_key": "manual:manual_evidence_missing",
+            "source_key": "manual",
+            "source_label": "Manual",
+            "kind": "manual_evidence_missing",
+            "reason": "Missing manual evidence",
+            "action": {
+                "kind": "journal_add",
+                "command": "shiplog journal add --title \"Manual evidence\""
+            },
+            "clears_when": "manual source contributes evidence",
+            "receipt_refs": [
+                {
+                    "field": "journal_suggestions",
+                    "source_key": "manual"
+                }
+            ]
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "fewer than two compatible repair reports found",
-        ))
-        .stdout(predicate::str::contains("Compatible reports: 1"))
-        .stdout(predicate::str::contains(
-            "Skipped reports without repair_items:",
-        ))
-        .stdout(predicate::str::contains(
-            "shiplog intake --last-6-months --explain",
+        .failure()
+        .stderr(predicate::str::contains(
+            "repair_items repair_id must match repair_[a-z0-9_]+",
         ));
-
-    Ok(())
 }
 
 #[test]
-fn repair_diff_latest_handles_empty_repair_queues() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_unknown_repair_action_kind() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, base_report) = load_first_intake_report(&out);
-
-    write_repair_diff_report(&base_report, &out, "run_900_old", serde_json::json!([]));
-    write_repair_diff_report(&base_report, &out, "run_901_new", serde_json::json!([]));
 
     shiplog_cmd()
-        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Cleared: 0"))
-        .stdout(predicate::str::contains("New: 0"))
-        .stdout(predicate::str::contains("Still open: 0"))
-        .stdout(predicate::str::contains("Changed: 0"))
-        .stdout(predicate::str::contains("No repair state changes."));
+        .success();
 
-    Ok(())
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["repair_items"] = serde_json::json!([
+        {
+            "repair_id": "repair_001_bad_action",
+            "repair_key": "manual:manual_evidence_missing",
+            "source_key": "manual",
+            "source_label": "Manual",
+            "kind": "manual_evidence_missing",
+            "reason": "Missing manual evidence",
+            "action": {
+                "kind": "mutate_provider"
+            },
+            "clears_when": "manual source contributes evidence",
+            "receipt_refs": [
+                {
+                    "field": "journal_suggestions",
+                    "source_key": "manual"
+                }
+            ]
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
+
+    shiplog_cmd()
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "repair_items action.kind \"mutate_provider\" is not supported",
+        ));
 }
 
 #[test]
-fn runs_diff_reports_changed_repairs_as_neutral_changes() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_unknown_repair_kind() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, base_report) = load_first_intake_report(&out);
 
-    write_repair_diff_report(
-        &base_report,
-        &out,
-        "run_900_old",
-        serde_json::json!([repair_diff_item(
-            "repair_001_changed_old",
-            "manual:changed",
-            "old reason",
-            "shiplog journal add --from-repair repair_001_changed_old",
-            "old clear condition"
-        )]),
-    );
-    write_repair_diff_report(
-        &base_report,
-        &out,
-        "run_901_new",
-        serde_json::json!([repair_diff_item(
-            "repair_001_changed_new",
-            "manual:changed",
-            "new reason",
-            "shiplog journal add --from-repair repair_001_changed_new",
-            "new clear condition"
-        )]),
-    );
-
-    let assert = shiplog_cmd()
-        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
 
-    assert!(
-        stdout.contains("Changed:\n- repair manual:changed changed"),
-        "changed repair items should be surfaced as neutral changes. stdout:\n{stdout}"
-    );
-    assert!(
-        !stdout.contains("Regressed:\n- repair manual:changed"),
-        "changed repair items should not be labeled as regressions. stdout:\n{stdout}"
-    );
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["repair_sources"] = serde_json::json!([
+        {
+            "source": "Jira",
+            "kind": "mystery",
+            "reason": "missing JIRA_TOKEN",
+            "commands": ["export JIRA_TOKEN=..."]
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
-    Ok(())
+    shiplog_cmd()
+        .args([
+            "report",
+            "validate",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "repair_sources kind \"mystery\" is not supported",
+        ));
 }
 
 #[test]
-fn runs_diff_latest_reports_quality_without_writing() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_validate_rejects_missing_artifact() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
 
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, first_report) = load_first_intake_report(&out);
-    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
         .current_dir(tmp.path())
-        .args([
-            "journal",
-            "add",
-            "--from-repair",
-            repair_id.as_str(),
-            "--out",
-            out_arg.as_str(),
-            "--latest",
-        ])
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
-    run_intake_without_provider_tokens(tmp.path(), &out);
 
-    let before = file_tree_manifest(tmp.path());
-    let repair_diff = shiplog_cmd()
-        .args(["repair", "diff", "--out", out_arg.as_str(), "--latest"])
-        .assert()
-        .success();
-    let repair_diff_stdout = String::from_utf8(repair_diff.get_output().stdout.clone())?;
-    assert!(
-        repair_diff_stdout.contains("Still open:")
-            && repair_diff_stdout.contains("source:github:source_skipped_configuration"),
-        "repair diff should keep unproven source repairs open after a journal-only repair. stdout:\n{repair_diff_stdout}"
-    );
-    let repair_diff_cleared = repair_diff_stdout
-        .split("Cleared:")
-        .nth(1)
-        .and_then(|section| section.split("New:").next())
-        .unwrap_or("");
-    assert!(
-        !repair_diff_cleared.contains("source:github:source_skipped_configuration"),
-        "repair diff should not mark an unproven source repair as cleared. stdout:\n{repair_diff_stdout}"
-    );
+    let run_dir = first_run_dir(&out);
+    std::fs::remove_file(run_dir.join("packet.md")).unwrap();
 
     shiplog_cmd()
-        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
+        .args([
+            "report",
+            "validate",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Packet quality diff:"))
-        .stdout(predicate::str::contains("manual evidence count 0 -> 1"))
-        .stdout(predicate::str::contains("claim candidates 0 -> 1"))
-        .stdout(predicate::str::contains(
-            "source:github:source_skipped_configuration still open",
-        ))
-        .stdout(
-            predicate::str::contains("source:github:source_skipped_configuration cleared").not(),
-        )
-        .stdout(predicate::str::contains(format!(
-            "shiplog open packet --out \"{}\"",
-            out.display()
-        )))
-        .stdout(predicate::str::contains(format!(
-            "shiplog share explain manager --out \"{}\"",
-            out.display()
-        )));
-    let after = file_tree_manifest(tmp.path());
-
-    assert_eq!(before, after, "runs diff should not write or rewrite files");
-
-    Ok(())
+        .failure()
+        .stderr(predicate::str::contains("artifact missing for packet"));
 }
 
 #[test]
-fn intake_setup_blocked_manual_handoff_starts_with_doctor() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_summarize_prints_operator_view_without_writing() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-    let fixtures = fixture_dir();
-    let events = fixtures
-        .join("ledger.events.jsonl")
-        .display()
-        .to_string()
-        .replace('\\', "/");
-    let coverage = fixtures
-        .join("coverage.manifest.json")
-        .display()
-        .to_string()
-        .replace('\\', "/");
-    std::fs::write(tmp.path().join("manual_events.yaml"), "events: []\n")?;
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        format!(
-            r#"[shiplog]
-config_version = 1
-
-[defaults]
-out = "./out"
-window = "last-6-months"
-profile = "internal"
-
-[sources.json]
-enabled = true
-events = "{events}"
-coverage = "{coverage}"
-
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-"#
-        ),
-    )?;
 
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success();
+
+    let run_dir = first_run_dir(&out);
+    let report_path = run_dir.join("intake.report.json");
+    let mut report_json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    let actions = report_json
+        .get_mut("actions")
+        .and_then(serde_json::Value::as_array_mut)
+        .expect("intake report should include machine actions");
+    actions.push(serde_json::json!({
+        "id": "action_share_manager_summary_regression",
+        "kind": "share_manager",
+        "label": "Render manager share",
+        "command": format!("shiplog share manager --out \"{}\" --run run_summary", out.display()),
+        "writes": true,
+        "risk": "medium"
+    }));
+    actions.push(serde_json::json!({
+        "id": "action_share_public_summary_regression",
+        "kind": "share_public",
+        "label": "Render public share",
+        "command": format!("shiplog share public --out \"{}\" --run run_summary", out.display()),
+        "writes": true,
+        "risk": "high"
+    }));
+    std::fs::write(
+        &report_path,
+        serde_json::to_string_pretty(&report_json).unwrap(),
+    )
+    .unwrap();
+    let report_modified = std::fs::metadata(&report_path).unwrap().modified().unwrap();
+
+    let assert = shiplog_cmd()
         .args([
-            "intake",
+            "report",
+            "summarize",
             "--out",
-            out_arg.as_str(),
-            "--no-open",
-            "--explain",
+            out.to_str().unwrap(),
+            "--latest",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    assert!(
-        stdout.contains(&format!(
-            "Next:\n1. shiplog doctor --setup\n2. shiplog sources status\n3. shiplog repair plan --out \"{}\" --latest",
-            out.display()
-        )),
-        "setup-blocked intake should start with doctor/source status before repair plan. stdout:\n{stdout}"
-    );
+        .success()
+        .stdout(predicate::str::contains("Report summary:"))
+        .stdout(predicate::str::contains("Intake status:"))
+        .stdout(predicate::str::contains("Packet readiness:"))
+        .stdout(predicate::str::contains("Sources:"))
+        .stdout(predicate::str::contains("Evidence debt:"))
+        .stdout(predicate::str::contains("Top repairs:"))
+        .stdout(predicate::str::contains("missing_token"))
+        .stdout(predicate::str::contains("Top fixups:"))
+        .stdout(predicate::str::contains("Share explain next:"))
+        .stdout(predicate::str::contains("shiplog share explain manager"))
+        .stdout(predicate::str::contains("shiplog share explain public"))
+        .stdout(predicate::str::contains("shiplog share manager").not())
+        .stdout(predicate::str::contains("Packet:"))
+        .stdout(predicate::str::contains("Intake report:"));
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let window_line = stdout
+        .lines()
+        .find(|line| line.starts_with("Window: "))
+        .expect("report summarize should print a Window line");
     assert!(
-        !stdout.contains("journal add --from-repair"),
-        "malformed manual setup should not offer journal repair writes before setup is fixed. stdout:\n{stdout}"
+        !window_line.contains(") ("),
+        "window line should not duplicate the date range: {window_line}"
     );
 
-    let (_, report) = load_first_intake_report(&out);
-    let next_commands = report["next_commands"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter_map(|command| command.as_str())
-        .collect::<Vec<_>>();
-    assert_eq!(next_commands.first(), Some(&"shiplog doctor --setup"));
-    assert_eq!(next_commands.get(1), Some(&"shiplog sources status"));
-    assert!(
-        next_commands
-            .get(2)
-            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
-        "repair plan should remain available after setup preflight: {next_commands:?}"
-    );
-    assert!(
-        next_commands
-            .iter()
-            .all(|command| !command.contains("journal add --from-repair")),
-        "persisted next commands should not include dead-end journal repair writes: {next_commands:?}"
+    assert_eq!(
+        report_modified,
+        std::fs::metadata(&report_path).unwrap().modified().unwrap(),
+        "report summarize should be read-only"
     );
-
-    Ok(())
 }
 
 #[test]
-fn intake_setup_blocked_provider_handoff_starts_with_doctor() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
-    write_manual_events(&tmp.path().join("manual_events.yaml"));
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[shiplog]
-config_version = 1
-
-[defaults]
-out = "./out"
-window = "last-6-months"
-profile = "internal"
-
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-
-[sources.github]
-enabled = true
-user = "octo"
-"#,
-    )?;
+fn report_summarize_accepts_direct_path_and_rejects_invalid_reports() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
 
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .assert()
+        .success();
+
+    let report_path = first_run_dir(&out).join("intake.report.json");
+
+    shiplog_cmd()
         .args([
-            "intake",
-            "--out",
-            out_arg.as_str(),
-            "--no-open",
-            "--explain",
+            "report",
+            "summarize",
+            "--path",
+            report_path.to_str().unwrap(),
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    assert!(
-        stdout.contains(&format!(
-            "Next:\n1. shiplog doctor --setup\n2. shiplog sources status\n3. shiplog repair plan --out \"{}\" --latest",
-            out.display()
-        )),
-        "missing provider token should route through setup status before repair plan. stdout:\n{stdout}"
-    );
-    assert!(
-        stdout.contains("GitHub: GitHub authentication unavailable"),
-        "intake should still explain the concrete provider setup gap. stdout:\n{stdout}"
-    );
+        .success()
+        .stdout(predicate::str::contains("Report summary:"))
+        .stdout(predicate::str::contains("intake.report.json"));
 
-    let (_, report) = load_first_intake_report(&out);
-    let next_commands = report["next_commands"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter_map(|command| command.as_str())
-        .collect::<Vec<_>>();
-    assert_eq!(next_commands.first(), Some(&"shiplog doctor --setup"));
-    assert_eq!(next_commands.get(1), Some(&"shiplog sources status"));
-    assert!(
-        next_commands
-            .get(2)
-            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
-        "repair plan should remain available after setup preflight: {next_commands:?}"
-    );
+    let mut report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    report["schema_version"] = serde_json::json!(2);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+    )
+    .unwrap();
 
-    Ok(())
+    shiplog_cmd()
+        .args([
+            "report",
+            "summarize",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unsupported intake report schema_version 2",
+        ));
 }
 
 #[test]
-fn repaired_rerun_intake_hands_off_to_repair_diff_first() -> CliTestResult {
-    let tmp = TempDir::new()?;
+fn report_export_agent_pack_writes_derived_control_surface_without_mutating_report() {
+    let tmp = TempDir::new().unwrap();
     let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
 
-    let first = shiplog_cmd()
+    shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args([
-            "intake",
-            "--out",
-            out_arg.as_str(),
-            "--no-open",
-            "--explain",
-        ])
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
-    let first_stdout = String::from_utf8(first.get_output().stdout.clone())?;
-    assert!(
-        first_stdout.contains(&format!(
-            "Next:\n1. shiplog repair plan --out \"{}\" --latest",
-            out.display()
-        )),
-        "first repairable intake should start at repair plan. stdout:\n{first_stdout}"
-    );
-    assert!(
-        !first_stdout.contains("shiplog repair diff"),
-        "first repairable intake should not offer repair diff before a prior report exists. stdout:\n{first_stdout}"
-    );
 
-    let (_, first_report) = load_first_intake_report(&out);
-    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
+    let run_dir = first_run_dir(&out);
+    let report_path = run_dir.join("intake.report.json");
+    let report_text = std::fs::read_to_string(&report_path).unwrap();
+    let report_json: serde_json::Value = serde_json::from_str(&report_text).unwrap();
+    let report_modified = std::fs::metadata(&report_path).unwrap().modified().unwrap();
+    let pack_path = tmp.path().join("agent-pack.json");
+
     shiplog_cmd()
-        .current_dir(tmp.path())
         .args([
-            "journal",
-            "add",
-            "--from-repair",
-            repair_id.as_str(),
+            "report",
+            "export-agent-pack",
             "--out",
-            out_arg.as_str(),
+            out.to_str().unwrap(),
             "--latest",
+            "--output",
+            pack_path.to_str().unwrap(),
         ])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains("Agent pack:"))
+        .stdout(predicate::str::contains("agent-pack.json"));
 
-    let second = shiplog_cmd()
+    assert_eq!(
+        report_modified,
+        std::fs::metadata(&report_path).unwrap().modified().unwrap(),
+        "agent pack export should not rewrite intake.report.json"
+    );
+
+    let pack_text = std::fs::read_to_string(&pack_path).unwrap();
+    let pack_json: serde_json::Value = serde_json::from_str(&pack_text).unwrap();
+    assert_eq!(pack_json["schema_version"], 1);
+    assert_eq!(pack_json["source_report"]["schema_version"], 1);
+    assert!(
+        pack_json["source_report"]["path"]
+            .as_str()
+            .unwrap()
+            .contains("intake.report.json")
+    );
+    assert_eq!(pack_json["run"]["run_id"], report_json["run_id"]);
+    assert_eq!(pack_json["run"]["readiness"], report_json["readiness"]);
+    assert_eq!(
+        pack_json["summary"]["skipped_source_count"].as_u64(),
+        Some(report_json["skipped_sources"].as_array().unwrap().len() as u64)
+    );
+    assert_eq!(
+        pack_json["summary"]["repair_count"].as_u64(),
+        Some(report_json["repair_items"].as_array().unwrap().len() as u64)
+    );
+    assert!(pack_json["gaps"]["needs_attention"].is_array());
+    assert!(pack_json["gaps"]["skipped_sources"].is_array());
+    assert!(pack_json["gaps"]["evidence_debt"].is_array());
+    assert!(pack_json["repairs"].is_array());
+    assert_eq!(
+        pack_json["repairs"], report_json["repair_items"],
+        "agent pack repairs should mirror intake report repair_items when present"
+    );
+    assert!(pack_json["fixups"].is_array());
+    assert!(pack_json["actions"].is_array());
+    assert!(pack_json["share_status"]["commands"].is_array());
+    assert!(pack_json["artifacts"].is_array());
+    assert!(
+        !pack_text.contains("super-secret"),
+        "agent pack should not contain known secret sentinel values"
+    );
+}
+
+#[test]
+fn report_export_agent_pack_accepts_direct_path_and_stdout() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out");
+
+    shiplog_cmd()
         .current_dir(tmp.path())
         .env_remove("GITHUB_TOKEN")
         .env_remove("GITLAB_TOKEN")
         .env_remove("JIRA_TOKEN")
         .env_remove("LINEAR_API_KEY")
-        .args([
-            "intake",
-            "--out",
-            out_arg.as_str(),
-            "--no-open",
-            "--explain",
-        ])
+        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
         .assert()
         .success();
-    let second_stdout = String::from_utf8(second.get_output().stdout.clone())?;
-    assert!(
-        second_stdout.contains(&format!(
-            "Next:\n1. shiplog repair diff --out \"{}\" --latest\n2. shiplog repair plan --out \"{}\" --latest",
-            out.display(),
-            out.display()
-        )),
-        "repaired rerun should hand off to repair diff before planning more work. stdout:\n{second_stdout}"
-    );
 
-    let latest_run = all_run_dirs(&out)
-        .into_iter()
-        .last()
-        .expect("rerun should create a latest run");
-    let latest_report: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
-        latest_run.join("intake.report.json"),
-    )?)?;
-    let next_commands = latest_report["next_commands"]
-        .as_array()
-        .unwrap()
-        .iter()
-        .filter_map(|command| command.as_str())
-        .collect::<Vec<_>>();
-    assert!(
-        next_commands
-            .first()
-            .is_some_and(|command| command.starts_with("shiplog repair diff ")),
-        "latest report should persist repair diff as the first next command: {next_commands:?}"
-    );
+    let report_path = first_run_dir(&out).join("intake.report.json");
+    let output = shiplog_cmd()
+        .args([
+            "report",
+            "export-agent-pack",
+            "--path",
+            report_path.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+
     assert!(
-        next_commands
-            .get(1)
-            .is_some_and(|command| command.starts_with("shiplog repair plan ")),
-        "latest report should keep repair plan as the second next command: {next_commands:?}"
+        output.status.success(),
+        "export-agent-pack should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
     );
+    let pack_json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(pack_json["schema_version"], 1);
+    assert_eq!(pack_json["source_report"]["schema_version"], 1);
+    assert!(pack_json["summary"]["action_count"].is_number());
+}
 
-    Ok(())
+#[test]
+fn open_intake_report_fails_clearly_when_report_is_missing() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args([
+            "open",
+            "intake-report",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--print-path",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Intake report not found"))
+        .stderr(predicate::str::contains("shiplog intake"));
 }
 
 #[test]
-fn runs_diff_latest_handles_legacy_reports_without_packet_quality() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
+fn workstreams_validate_accepts_latest_run_workstreams() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
 
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, first_report) = load_first_intake_report(&out);
-    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
-        .current_dir(tmp.path())
         .args([
-            "journal",
-            "add",
-            "--from-repair",
-            repair_id.as_str(),
+            "workstreams",
+            "validate",
             "--out",
-            out_arg.as_str(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
         ])
         .assert()
-        .success();
-    run_intake_without_provider_tokens(tmp.path(), &out);
+        .success()
+        .stdout(predicate::str::contains("Workstreams valid:"))
+        .stdout(predicate::str::contains("assigned events"));
+}
 
-    let run_dirs = all_run_dirs(&out);
-    assert!(
-        run_dirs.len() >= 2,
-        "legacy packet-quality diff test needs two run directories"
-    );
-    for run_dir in &run_dirs {
-        remove_packet_quality_from_report(&run_dir.join("intake.report.json"))?;
-    }
+#[test]
+fn workstreams_validate_rejects_blank_title() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    std::fs::write(
+        run_dir.join("workstreams.yaml"),
+        r#"version: 1
+generated_at: "2026-01-01T00:00:00Z"
+workstreams:
+  - id: "blank-title"
+    title: ""
+    summary: null
+    tags: []
+    stats:
+      pull_requests: 1
+      reviews: 0
+      manual_events: 0
+    events:
+      - "fixture_pr_acme_payments_42"
+    receipts:
+      - "fixture_pr_acme_payments_42"
+"#,
+    )
+    .unwrap();
 
-    let before = file_tree_manifest(tmp.path());
     shiplog_cmd()
-        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
+        .args([
+            "workstreams",
+            "validate",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Packet quality diff:"))
-        .stdout(predicate::str::contains("Reports:"))
-        .stdout(predicate::str::contains("Improved:"))
-        .stdout(predicate::str::contains("evidence events 0 -> 1"))
-        .stdout(predicate::str::contains("manual evidence count 0 -> 1"))
-        .stdout(predicate::str::contains("coverage gaps 6 -> 0"))
-        .stdout(predicate::str::contains(
-            "source:github:source_skipped_configuration still open",
-        ))
-        .stdout(
-            predicate::str::contains("source:github:source_skipped_configuration cleared").not(),
-        )
-        .stdout(predicate::str::contains("packet readiness unavailable"))
-        .stdout(predicate::str::contains(
-            "packet quality unavailable; rerun intake for review-ready signals",
-        ))
-        .stdout(
-            predicate::str::contains("packet readiness Needs evidence -> Ready for review").not(),
-        )
-        .stdout(predicate::str::contains("packet readiness: Ready for review").not())
-        .stdout(predicate::str::contains(format!(
-            "shiplog open packet --out \"{}\"",
-            out.display()
-        )));
-    let after = file_tree_manifest(tmp.path());
-
-    assert_eq!(
-        before, after,
-        "runs diff should not write or rewrite legacy report files"
-    );
-
-    Ok(())
+        .failure()
+        .stderr(predicate::str::contains("blank title"));
 }
 
 #[test]
-fn runs_diff_latest_handles_legacy_packet_quality_without_candidates_or_share_posture()
--> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
+fn workstreams_rename_promotes_suggested_to_curated() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, first_report) = load_first_intake_report(&out);
-    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
-        .current_dir(tmp.path())
         .args([
-            "journal",
-            "add",
-            "--from-repair",
-            repair_id.as_str(),
+            "workstreams",
+            "rename",
             "--out",
-            out_arg.as_str(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--from",
+            "acme/platform",
+            "--to",
+            "Platform Reliability",
         ])
         .assert()
-        .success();
-    run_intake_without_provider_tokens(tmp.path(), &out);
+        .success()
+        .stdout(predicate::str::contains("Renamed workstream"))
+        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
 
-    let run_dirs = all_run_dirs(&out);
+    assert!(run_dir.join("workstreams.suggested.yaml").exists());
+    let curated = load_curated_workstreams(&run_dir);
     assert!(
-        run_dirs.len() >= 2,
-        "legacy partial packet-quality diff test needs two run directories"
+        curated
+            .workstreams
+            .iter()
+            .any(|workstream| workstream.title == "Platform Reliability")
     );
-    for run_dir in &run_dirs {
-        remove_packet_quality_fields_from_report(
-            &run_dir.join("intake.report.json"),
-            &["claim_candidates", "share_posture"],
-        )?;
-    }
+    assert!(
+        !curated
+            .workstreams
+            .iter()
+            .any(|workstream| workstream.title == "acme/platform")
+    );
+}
+
+#[test]
+fn workstreams_move_event_reassigns_event_and_validates() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
-    let before = file_tree_manifest(tmp.path());
     shiplog_cmd()
-        .args(["runs", "diff", "--out", out_arg.as_str(), "--latest"])
+        .args([
+            "workstreams",
+            "move",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--event",
+            "fixture_pr_acme_payments_42",
+            "--to",
+            "acme/platform",
+        ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Packet quality diff:"))
         .stdout(predicate::str::contains(
-            "packet quality incomplete; rerun intake for review-ready signals",
-        ))
-        .stdout(predicate::str::contains("claim candidates 0 -> 1").not())
-        .stdout(predicate::str::contains(format!(
-            "shiplog open packet --out \"{}\"",
-            out.display()
-        )));
-    let after = file_tree_manifest(tmp.path());
+            "Moved event fixture_pr_acme_payments_42 to acme/platform",
+        ));
 
-    assert_eq!(
-        before, after,
-        "runs diff should not write or rewrite partial legacy packet-quality reports"
+    let curated = load_curated_workstreams(&run_dir);
+    let platform = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "acme/platform")
+        .expect("platform workstream should exist");
+    assert!(
+        platform
+            .events
+            .iter()
+            .any(|event_id| event_id.to_string() == "fixture_pr_acme_payments_42")
     );
 
-    Ok(())
-}
-
-#[test]
-fn report_validate_rejects_unknown_source_key() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let payments = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "acme/payments")
+        .expect("payments workstream should exist");
+    assert!(
+        payments
+            .events
+            .iter()
+            .all(|event_id| event_id.to_string() != "fixture_pr_acme_payments_42")
+    );
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "validate",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+        ])
         .assert()
         .success();
+}
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["source_decisions"][0]["source_key"] = serde_json::json!("storybook");
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+#[test]
+fn workstreams_move_unknown_event_fails_without_writing_curated() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "move",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--event",
+            "missing-event",
+            "--to",
+            "acme/platform",
         ])
         .assert()
         .failure()
         .stderr(predicate::str::contains(
-            "source_decisions source_key \"storybook\" is not supported",
+            "was not found in ledger.events.jsonl",
         ));
+
+    assert!(!run_dir.join("workstreams.yaml").exists());
 }
 
 #[test]
-fn report_validate_rejects_source_identity_mismatch() {
+fn workstreams_receipts_lists_selected_workstream_receipts() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "receipts",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+        ])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains("Receipts: acme/platform"))
+        .stdout(predicate::str::contains("Count: 2"))
+        .stdout(predicate::str::contains(
+            "Schema hardening for audit exports",
+        ))
+        .stdout(predicate::str::contains("APPROVED"));
+}
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["source_decisions"][0]["source"] = serde_json::json!("gitlab");
+#[test]
+fn curated_receipt_weight_is_listed_and_orders_prompt_receipts() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let run_dir = collect_json_into(tmp.path());
+    let out = tmp.path().to_str().context("temp path is not UTF-8")?;
+
+    let text = std::fs::read_to_string(run_dir.join("workstreams.suggested.yaml"))?;
+    let mut workstreams: WorkstreamsFile = serde_yaml::from_str(&text)?;
+    let platform = workstreams
+        .workstreams
+        .iter_mut()
+        .find(|ws| ws.title == "acme/platform")
+        .context("fixture has an acme/platform workstream")?;
+    platform.receipt_weights.insert(
+        EventId("fixture_review_acme_platform_77_1".into()),
+        ReceiptWeight::High,
+    );
+    platform.receipt_weights.insert(
+        EventId("fixture_pr_acme_platform_13".into()),
+        ReceiptWeight::Low,
+    );
     std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+        run_dir.join("workstreams.yaml"),
+        serde_yaml::to_string(&workstreams)?,
+    )?;
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "receipts",
+            "--out",
+            out,
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "intake report source_decisions source",
-        ))
-        .stderr(predicate::str::contains("not source_key"));
+        .success()
+        .stdout(
+            predicate::str::contains("APPROVED")
+                .and(predicate::str::contains("[weight: high, curated]")),
+        );
+
+    let assert = shiplog_cmd()
+        .args([
+            "prompt",
+            "--out",
+            out,
+            "--run",
+            "run_fixture",
+            "--bundle-profile",
+            "internal",
+        ])
+        .assert()
+        .success();
+    let prompt = String::from_utf8(assert.get_output().stdout.clone())?;
+    let review = prompt
+        .find("APPROVED")
+        .context("prompt lists the review receipt")?;
+    let pull = prompt
+        .find("Schema hardening for audit exports")
+        .context("prompt lists the pull request receipt")?;
+    assert!(review < pull, "curated weights should put the review first");
+    Ok(())
 }
 
 #[test]
-fn report_validate_rejects_source_label_mismatch() {
+fn workstreams_receipt_remove_promotes_suggested_to_curated_and_keeps_assignment() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "receipt",
+            "remove",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--event",
+            "fixture_pr_acme_platform_13",
+        ])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains(
+            "Removed receipt anchor fixture_pr_acme_platform_13 from acme/platform",
+        ))
+        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["source_freshness"][0]["source_label"] = serde_json::json!("GitLab");
+    assert!(run_dir.join("workstreams.suggested.yaml").exists());
+    let curated = load_curated_workstreams(&run_dir);
+    let platform = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "acme/platform")
+        .expect("platform workstream should exist");
+    assert!(
+        platform
+            .events
+            .iter()
+            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+    );
+    assert!(
+        platform
+            .receipts
+            .iter()
+            .all(|event_id| event_id.to_string() != "fixture_pr_acme_platform_13")
+    );
+}
+
+#[test]
+fn workstreams_receipt_add_promotes_suggested_to_curated_and_validates() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
     std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
+        run_dir.join("workstreams.suggested.yaml"),
+        r#"version: 1
+generated_at: "2026-01-01T00:00:00Z"
+workstreams:
+  - id: "repo-acme-platform"
+    title: "acme/platform"
+    summary: null
+    tags:
+      - platform
+    stats:
+      pull_requests: 1
+      reviews: 1
+      manual_events: 0
+    events:
+      - "fixture_pr_acme_platform_13"
+      - "fixture_review_acme_platform_77_1"
+    receipts:
+      - "fixture_review_acme_platform_77_1"
+"#,
     )
     .unwrap();
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "receipt",
+            "add",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--event",
+            "fixture_pr_acme_platform_13",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "intake report source_freshness source_label",
+        .success()
+        .stdout(predicate::str::contains(
+            "Added receipt anchor fixture_pr_acme_platform_13 to acme/platform",
         ))
-        .stderr(predicate::str::contains("does not match source_key"));
-}
+        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
 
-#[test]
-fn report_validate_rejects_unknown_fixup_kind() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let curated = load_curated_workstreams(&run_dir);
+    let platform = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "acme/platform")
+        .expect("platform workstream should exist");
+    assert!(
+        platform
+            .receipts
+            .iter()
+            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+    );
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "validate",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+        ])
         .assert()
         .success();
+}
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["top_fixups"] = serde_json::json!([
-        {
-            "id": "fixup_mystery",
-            "kind": "mystery",
-            "title": "Mystery fixup",
-            "detail": null,
-            "command": "shiplog review --latest"
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+#[test]
+fn workstreams_receipt_add_rejects_event_not_in_workstream_without_writing() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "receipt",
+            "add",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--event",
+            "fixture_pr_acme_payments_42",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains(
-            "top_fixups kind \"mystery\" is not supported",
-        ));
+        .stderr(predicate::str::contains("is not assigned to workstream"));
+
+    assert!(!run_dir.join("workstreams.yaml").exists());
 }
 
 #[test]
-fn report_validate_rejects_invalid_fixup_id() {
+fn workstreams_receipt_add_rejects_duplicate_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
-
-    shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success();
-
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["top_fixups"] = serde_json::json!([
-        {
-            "id": "Fixup Bad Id",
-            "kind": "manual_context",
-            "title": "Bad fixup id",
-            "detail": null,
-            "command": "shiplog review --latest"
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "receipt",
+            "add",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--event",
+            "fixture_pr_acme_platform_13",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains(
-            "top_fixups id must match fixup_[a-z0-9_]+",
-        ));
+        .stderr(predicate::str::contains("already a receipt anchor"));
+
+    assert!(!run_dir.join("workstreams.yaml").exists());
 }
 
 #[test]
-fn report_validate_rejects_unknown_action_kind() {
+fn workstreams_receipt_remove_rejects_missing_receipt_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "receipt",
+            "remove",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--event",
+            "fixture_pr_acme_payments_42",
+        ])
         .assert()
-        .success();
+        .failure()
+        .stderr(predicate::str::contains("is not a receipt anchor"));
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["actions"] = serde_json::json!([
-        {
-            "id": "action_mystery",
-            "kind": "mystery",
-            "label": "Mystery action",
-            "command": "shiplog review --latest",
-            "writes": false,
-            "risk": "low"
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    assert!(!run_dir.join("workstreams.yaml").exists());
+}
+
+#[test]
+fn workstreams_create_promotes_suggested_to_curated() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "create",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--title",
+            "Platform Reliability",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "actions kind \"mystery\" is not supported",
-        ));
+        .success()
+        .stdout(predicate::str::contains(
+            "Created workstream: Platform Reliability",
+        ))
+        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
+
+    assert!(run_dir.join("workstreams.suggested.yaml").exists());
+    let curated = load_curated_workstreams(&run_dir);
+    let created = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "Platform Reliability")
+        .expect("created workstream should exist");
+    assert!(created.events.is_empty());
+    assert!(created.receipts.is_empty());
+    assert_eq!(created.stats.pull_requests, 0);
+    assert_eq!(created.stats.reviews, 0);
 }
 
 #[test]
-fn report_validate_rejects_invalid_action_id() {
+fn workstreams_create_rejects_blank_and_duplicate_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "create",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--title",
+            "   ",
+        ])
         .assert()
-        .success();
-
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["actions"] = serde_json::json!([
-        {
-            "id": "Action Bad",
-            "kind": "fixup",
-            "label": "Bad action",
-            "command": "shiplog review --latest",
-            "writes": false,
-            "risk": "low"
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+        .failure()
+        .stderr(predicate::str::contains("title cannot be blank"));
+    assert!(!run_dir.join("workstreams.yaml").exists());
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "create",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--title",
+            "acme/platform",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains(
-            "actions id must match action_[a-z0-9_]+",
-        ));
+        .stderr(predicate::str::contains("already matches"));
+    assert!(!run_dir.join("workstreams.yaml").exists());
 }
 
 #[test]
-fn report_validate_rejects_unknown_repair_item_kind() {
+fn workstreams_delete_empty_workstream_succeeds() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "create",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--title",
+            "Temporary Bucket",
+        ])
         .assert()
         .success();
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["repair_items"] = serde_json::json!([
-        {
-            "repair_id": "repair_001_mystery",
-            "repair_key": "mystery",
-            "kind": "mystery",
-            "reason": "Mystery repair",
-            "action": {
-                "kind": "no_safe_action"
-            },
-            "clears_when": "the mystery clears",
-            "receipt_refs": [
-                {
-                    "field": "evidence_debt"
-                }
-            ]
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    shiplog_cmd()
+        .args([
+            "workstreams",
+            "delete",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "Temporary Bucket",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Deleted workstream: Temporary Bucket",
+        ));
+
+    let curated = load_curated_workstreams(&run_dir);
+    assert!(
+        !curated
+            .workstreams
+            .iter()
+            .any(|workstream| workstream.title == "Temporary Bucket")
+    );
+}
+
+#[test]
+fn workstreams_delete_non_empty_requires_move_or_force_without_writing() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "delete",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains(
-            "repair_items kind \"mystery\" is not supported",
-        ));
+        .stderr(predicate::str::contains("--move-to"))
+        .stderr(predicate::str::contains("--force"));
+
+    assert!(!run_dir.join("workstreams.yaml").exists());
 }
 
 #[test]
-fn report_validate_rejects_invalid_repair_item_id() {
+fn workstreams_delete_non_empty_moves_events_and_recomputes_stats() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "create",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--title",
+            "Misc",
+        ])
         .assert()
         .success();
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["repair_items"] = serde_json::json!([
-        {
-            "repair_id": "Repair Bad",
-            "repair_key": "manual:manual_evidence_missing",
-            "source_key": "manual",
-            "source_label": "Manual",
-            "kind": "manual_evidence_missing",
-            "reason": "Missing manual evidence",
-            "action": {
-                "kind": "journal_add",
-                "command": "shiplog journal add --title \"Manual evidence\""
-            },
-            "clears_when": "manual source contributes evidence",
-            "receipt_refs": [
-                {
-                    "field": "journal_suggestions",
-                    "source_key": "manual"
-                }
-            ]
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    shiplog_cmd()
+        .args([
+            "workstreams",
+            "delete",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--move-to",
+            "Misc",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Deleted workstream: acme/platform",
+        ))
+        .stdout(predicate::str::contains("Moved 2 event(s)"));
+
+    let curated = load_curated_workstreams(&run_dir);
+    assert!(
+        !curated
+            .workstreams
+            .iter()
+            .any(|workstream| workstream.title == "acme/platform")
+    );
+    let misc = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "Misc")
+        .expect("move target should exist");
+    assert_eq!(misc.stats.pull_requests, 1);
+    assert_eq!(misc.stats.reviews, 1);
+    assert!(
+        misc.events
+            .iter()
+            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+    );
+    assert!(
+        misc.events
+            .iter()
+            .any(|event_id| event_id.to_string() == "fixture_review_acme_platform_77_1")
+    );
 
     shiplog_cmd()
         .args([
-            "report",
+            "workstreams",
             "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "repair_items repair_id must match repair_[a-z0-9_]+",
-        ));
+        .success();
 }
 
 #[test]
-fn report_validate_rejects_unknown_repair_action_kind() {
+fn workstreams_delete_self_move_fails_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "delete",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--move-to",
+            "acme/platform",
+        ])
         .assert()
-        .success();
+        .failure()
+        .stderr(predicate::str::contains("must be different"));
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["repair_items"] = serde_json::json!([
-        {
-            "repair_id": "repair_001_bad_action",
-            "repair_key": "manual:manual_evidence_missing",
-            "source_key": "manual",
-            "source_label": "Manual",
-            "kind": "manual_evidence_missing",
-            "reason": "Missing manual evidence",
-            "action": {
-                "kind": "mutate_provider"
-            },
-            "clears_when": "manual source contributes evidence",
-            "receipt_refs": [
-                {
-                    "field": "journal_suggestions",
-                    "source_key": "manual"
-                }
-            ]
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+    assert!(!run_dir.join("workstreams.yaml").exists());
+}
+
+#[test]
+fn workstreams_delete_missing_move_target_fails_without_writing() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "report",
-            "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "delete",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/platform",
+            "--move-to",
+            "Missing Bucket",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains(
-            "repair_items action.kind \"mutate_provider\" is not supported",
-        ));
+        .stderr(predicate::str::contains("no workstream matched"));
+
+    assert!(!run_dir.join("workstreams.yaml").exists());
 }
 
 #[test]
-fn report_validate_rejects_unknown_repair_kind() {
+fn workstreams_delete_force_discards_assignments_and_validates() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success();
-
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["repair_sources"] = serde_json::json!([
-        {
-            "source": "Jira",
-            "kind": "mystery",
-            "reason": "missing JIRA_TOKEN",
-            "commands": ["export JIRA_TOKEN=..."]
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+        .args([
+            "workstreams",
+            "delete",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--workstream",
+            "acme/payments",
+            "--force",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Deleted workstream: acme/payments",
+        ))
+        .stdout(predicate::str::contains("Discarded 1 event assignment"));
 
+    let curated = load_curated_workstreams(&run_dir);
+    assert!(
+        !curated
+            .workstreams
+            .iter()
+            .any(|workstream| workstream.title == "acme/payments")
+    );
     shiplog_cmd()
         .args([
-            "report",
+            "workstreams",
             "validate",
-            "--path",
-            report_path.to_str().unwrap(),
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "repair_sources kind \"mystery\" is not supported",
-        ));
+        .success();
 }
 
 #[test]
-fn report_validate_rejects_missing_artifact() {
+fn workstreams_split_creates_target_and_recomputes_stats() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "split",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--from",
+            "acme/platform",
+            "--to",
+            "Audit Exports",
+            "--matching",
+            "schema|compliance",
+            "--create",
+        ])
         .assert()
-        .success();
+        .success()
+        .stdout(predicate::str::contains(
+            "Split 1 event(s) from acme/platform to Audit Exports",
+        ))
+        .stdout(predicate::str::contains("Created target workstream"))
+        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
 
-    let run_dir = first_run_dir(&out);
-    std::fs::remove_file(run_dir.join("packet.md")).unwrap();
+    assert!(run_dir.join("workstreams.suggested.yaml").exists());
+    let curated = load_curated_workstreams(&run_dir);
+    let audit = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "Audit Exports")
+        .expect("split target should be created");
+    assert_eq!(audit.stats.pull_requests, 1);
+    assert_eq!(audit.stats.reviews, 0);
+    assert!(
+        audit
+            .events
+            .iter()
+            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+    );
+
+    let platform = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "acme/platform")
+        .expect("source workstream should remain");
+    assert_eq!(platform.stats.pull_requests, 0);
+    assert_eq!(platform.stats.reviews, 1);
+    assert!(
+        platform
+            .events
+            .iter()
+            .all(|event_id| event_id.to_string() != "fixture_pr_acme_platform_13")
+    );
 
     shiplog_cmd()
         .args([
-            "report",
+            "workstreams",
             "validate",
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("artifact missing for packet"));
+        .success();
 }
 
 #[test]
-fn report_summarize_prints_operator_view_without_writing() {
+fn workstreams_split_matches_source_url() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success();
-
-    let run_dir = first_run_dir(&out);
-    let report_path = run_dir.join("intake.report.json");
-    let mut report_json: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    let actions = report_json
-        .get_mut("actions")
-        .and_then(serde_json::Value::as_array_mut)
-        .expect("intake report should include machine actions");
-    actions.push(serde_json::json!({
-        "id": "action_share_manager_summary_regression",
-        "kind": "share_manager",
-        "label": "Render manager share",
-        "command": format!("shiplog share manager --out \"{}\" --run run_summary", out.display()),
-        "writes": true,
-        "risk": "medium"
-    }));
-    actions.push(serde_json::json!({
-        "id": "action_share_public_summary_regression",
-        "kind": "share_public",
-        "label": "Render public share",
-        "command": format!("shiplog share public --out \"{}\" --run run_summary", out.display()),
-        "writes": true,
-        "risk": "high"
-    }));
-    std::fs::write(
-        &report_path,
-        serde_json::to_string_pretty(&report_json).unwrap(),
-    )
-    .unwrap();
-    let report_modified = std::fs::metadata(&report_path).unwrap().modified().unwrap();
-
-    let assert = shiplog_cmd()
         .args([
-            "report",
-            "summarize",
+            "workstreams",
+            "split",
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--from",
+            "acme/platform",
+            "--to",
+            "Review Receipts",
+            "--matching",
+            "reviews/1",
+            "--create",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Report summary:"))
-        .stdout(predicate::str::contains("Intake status:"))
-        .stdout(predicate::str::contains("Packet readiness:"))
-        .stdout(predicate::str::contains("Sources:"))
-        .stdout(predicate::str::contains("Evidence debt:"))
-        .stdout(predicate::str::contains("Top repairs:"))
-        .stdout(predicate::str::contains("missing_token"))
-        .stdout(predicate::str::contains("Top fixups:"))
-        .stdout(predicate::str::contains("Share explain next:"))
-        .stdout(predicate::str::contains("shiplog share explain manager"))
-        .stdout(predicate::str::contains("shiplog share explain public"))
-        .stdout(predicate::str::contains("shiplog share manager").not())
-        .stdout(predicate::str::contains("Packet:"))
-        .stdout(predicate::str::contains("Intake report:"));
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let window_line = stdout
-        .lines()
-        .find(|line| line.starts_with("Window: "))
-        .expect("report summarize should print a Window line");
-    assert!(
-        !window_line.contains(") ("),
-        "window line should not duplicate the date range: {window_line}"
-    );
+        .stdout(predicate::str::contains(
+            "Split 1 event(s) from acme/platform to Review Receipts",
+        ));
 
-    assert_eq!(
-        report_modified,
-        std::fs::metadata(&report_path).unwrap().modified().unwrap(),
-        "report summarize should be read-only"
+    let curated = load_curated_workstreams(&run_dir);
+    let review_receipts = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "Review Receipts")
+        .expect("source-url split target should be created");
+    assert!(
+        review_receipts
+            .events
+            .iter()
+            .any(|event_id| event_id.to_string() == "fixture_review_acme_platform_77_1")
     );
 }
 
 #[test]
-fn report_summarize_accepts_direct_path_and_rejects_invalid_reports() {
+fn workstreams_split_missing_target_requires_create_without_writing_curated() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "workstreams",
+            "split",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--from",
+            "acme/platform",
+            "--to",
+            "Audit Exports",
+            "--matching",
+            "Schema",
+        ])
         .assert()
-        .success();
+        .failure()
+        .stderr(predicate::str::contains("add --create to create it"));
+
+    assert!(!run_dir.join("workstreams.yaml").exists());
+}
+
+#[test]
+fn workstreams_edit_split_retag_and_merge_round_trip() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let edit = |args: &[&str]| {
+        shiplog_cmd()
+            .args(["workstreams", "edit"])
+            .args(args)
+            .args([
+                "--out",
+                tmp.path().to_str().unwrap(),
+                "--run",
+                "run_fixture",
+            ])
+            .assert()
+    };
+
+    edit(&[
+        "split",
+        "--from",
+        "acme/platform",
+        "--event",
+        "fixture_pr_acme_platform_13",
+        "--title",
+        "Audit Exports",
+    ])
+    .success()
+    .stdout(predicate::str::contains(
+        "Split 1 event(s) from acme/platform into new workstream Audit Exports",
+    ))
+    .stdout(predicate::str::contains("Created curated workstreams.yaml"));
+    edit(&[
+        "retag",
+        "--workstream",
+        "Audit Exports",
+        "--add",
+        "compliance",
+    ])
+    .success()
+    .stdout(predicate::str::contains("Tags: compliance"));
+
+    let curated = load_curated_workstreams(&run_dir);
+    let audit = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "Audit Exports")
+        .expect("split should create a workstream");
+    assert_eq!(audit.stats.pull_requests, 1);
+    assert_eq!(audit.tags, vec!["compliance"]);
+
+    edit(&[
+        "merge",
+        "--from",
+        "Audit Exports",
+        "--into",
+        "acme/platform",
+    ])
+    .success()
+    .stdout(predicate::str::contains(
+        "Merged workstream Audit Exports into acme/platform",
+    ));
 
-    let report_path = first_run_dir(&out).join("intake.report.json");
+    let curated = load_curated_workstreams(&run_dir);
+    assert!(
+        !curated
+            .workstreams
+            .iter()
+            .any(|workstream| workstream.title == "Audit Exports")
+    );
+    let platform = curated
+        .workstreams
+        .iter()
+        .find(|workstream| workstream.title == "acme/platform")
+        .expect("merge target should remain");
+    assert_eq!(platform.stats.pull_requests, 1);
+    assert_eq!(platform.stats.reviews, 1);
+    assert!(platform.tags.iter().any(|tag| tag == "compliance"));
 
     shiplog_cmd()
         .args([
-            "report",
-            "summarize",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "validate",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Report summary:"))
-        .stdout(predicate::str::contains("intake.report.json"));
+        .success();
+}
 
-    let mut report: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
-    report["schema_version"] = serde_json::json!(2);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report).unwrap()),
-    )
-    .unwrap();
+#[test]
+fn workstreams_edit_split_of_unassigned_event_fails_without_writing() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "report",
-            "summarize",
-            "--path",
-            report_path.to_str().unwrap(),
+            "workstreams",
+            "edit",
+            "split",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--from",
+            "acme/platform",
+            "--event",
+            "not_an_event",
+            "--title",
+            "Audit Exports",
         ])
         .assert()
         .failure()
         .stderr(predicate::str::contains(
-            "unsupported intake report schema_version 2",
+            "is not assigned to workstream \"acme/platform\"",
         ));
+
+    assert!(!run_dir.join("workstreams.yaml").exists());
+}
+
+fn load_curated_workstreams(run_dir: &Path) -> WorkstreamsFile {
+    let text = std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap();
+    serde_yaml::from_str(&text).unwrap()
 }
 
 #[test]
-fn report_export_agent_pack_writes_derived_control_surface_without_mutating_report() {
-    let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+fn run_git_produces_outputs() {
+    let Some(repo) = create_local_git_repo() else {
+        eprintln!("skipping run_git_produces_outputs: git not available");
+        return;
+    };
+    let out = TempDir::new().unwrap();
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
+        .args([
+            "run",
+            "--out",
+            out.path().to_str().unwrap(),
+            "git",
+            "--repo",
+            repo.path().to_str().unwrap(),
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2025-02-01",
+        ])
         .assert()
         .success();
 
-    let run_dir = first_run_dir(&out);
-    let report_path = run_dir.join("intake.report.json");
-    let report_text = std::fs::read_to_string(&report_path).unwrap();
-    let report_json: serde_json::Value = serde_json::from_str(&report_text).unwrap();
-    let report_modified = std::fs::metadata(&report_path).unwrap().modified().unwrap();
-    let pack_path = tmp.path().join("agent-pack.json");
+    let run_dir = first_run_dir(out.path());
+    assert!(run_dir.join("packet.md").exists(), "missing packet.md");
+    assert!(
+        run_dir.join("ledger.events.jsonl").exists(),
+        "missing ledger.events.jsonl"
+    );
+    assert!(
+        run_dir.join("coverage.manifest.json").exists(),
+        "missing coverage.manifest.json"
+    );
+    assert!(
+        run_dir.join("workstreams.suggested.yaml").exists(),
+        "missing workstreams.suggested.yaml"
+    );
+}
+
+#[test]
+fn refresh_git_preserves_existing_workstreams() {
+    let Some(repo) = create_local_git_repo() else {
+        eprintln!("skipping refresh_git_preserves_existing_workstreams: git not available");
+        return;
+    };
+    let out = TempDir::new().unwrap();
 
     shiplog_cmd()
         .args([
-            "report",
-            "export-agent-pack",
+            "collect",
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
-            "--output",
-            pack_path.to_str().unwrap(),
+            out.path().to_str().unwrap(),
+            "git",
+            "--repo",
+            repo.path().to_str().unwrap(),
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2025-02-01",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Agent pack:"))
-        .stdout(predicate::str::contains("agent-pack.json"));
+        .success();
 
-    assert_eq!(
-        report_modified,
-        std::fs::metadata(&report_path).unwrap().modified().unwrap(),
-        "agent pack export should not rewrite intake.report.json"
-    );
+    let run_dir = first_run_dir(out.path());
+    shiplog_cmd()
+        .args([
+            "refresh",
+            "--out",
+            out.path().to_str().unwrap(),
+            "--run-dir",
+            run_dir.to_str().unwrap(),
+            "git",
+            "--repo",
+            repo.path().to_str().unwrap(),
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2025-02-01",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Refreshed while preserving workstream curation",
+        ));
 
-    let pack_text = std::fs::read_to_string(&pack_path).unwrap();
-    let pack_json: serde_json::Value = serde_json::from_str(&pack_text).unwrap();
-    assert_eq!(pack_json["schema_version"], 1);
-    assert_eq!(pack_json["source_report"]["schema_version"], 1);
+    assert!(run_dir.join("packet.md").exists(), "missing packet.md");
     assert!(
-        pack_json["source_report"]["path"]
-            .as_str()
-            .unwrap()
-            .contains("intake.report.json")
-    );
-    assert_eq!(pack_json["run"]["run_id"], report_json["run_id"]);
-    assert_eq!(pack_json["run"]["readiness"], report_json["readiness"]);
-    assert_eq!(
-        pack_json["summary"]["skipped_source_count"].as_u64(),
-        Some(report_json["skipped_sources"].as_array().unwrap().len() as u64)
-    );
-    assert_eq!(
-        pack_json["summary"]["repair_count"].as_u64(),
-        Some(report_json["repair_items"].as_array().unwrap().len() as u64)
-    );
-    assert!(pack_json["gaps"]["needs_attention"].is_array());
-    assert!(pack_json["gaps"]["skipped_sources"].is_array());
-    assert!(pack_json["gaps"]["evidence_debt"].is_array());
-    assert!(pack_json["repairs"].is_array());
-    assert_eq!(
-        pack_json["repairs"], report_json["repair_items"],
-        "agent pack repairs should mirror intake report repair_items when present"
+        run_dir.join("ledger.events.jsonl").exists(),
+        "missing ledger.events.jsonl"
     );
-    assert!(pack_json["fixups"].is_array());
-    assert!(pack_json["actions"].is_array());
-    assert!(pack_json["share_status"]["commands"].is_array());
-    assert!(pack_json["artifacts"].is_array());
     assert!(
-        !pack_text.contains("super-secret"),
-        "agent pack should not contain known secret sentinel values"
+        run_dir.join("workstreams.suggested.yaml").exists(),
+        "missing workstreams.suggested.yaml"
     );
 }
 
 #[test]
-fn report_export_agent_pack_accepts_direct_path_and_stdout() {
+fn refresh_run_dir_latest_alias_on_collected_directory() {
     let tmp = TempDir::new().unwrap();
-    let out = tmp.path().join("out");
+    let fixtures = fixture_dir();
+    let _run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
-        .args(["intake", "--out", out.to_str().unwrap(), "--no-open"])
-        .assert()
-        .success();
-
-    let report_path = first_run_dir(&out).join("intake.report.json");
-    let output = shiplog_cmd()
         .args([
-            "report",
-            "export-agent-pack",
-            "--path",
-            report_path.to_str().unwrap(),
+            "refresh",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run-dir",
+            "latest",
+            "json",
+            "--events",
+            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
+            "--coverage",
+            fixtures.join("coverage.manifest.json").to_str().unwrap(),
         ])
-        .output()
-        .unwrap();
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Refreshed while preserving workstream curation",
+        ));
 
     assert!(
-        output.status.success(),
-        "export-agent-pack should succeed: {}",
-        String::from_utf8_lossy(&output.stderr)
+        tmp.path().join("run_fixture/packet.md").exists(),
+        "packet.md should exist after refresh --run-dir latest"
     );
-    let pack_json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
-    assert_eq!(pack_json["schema_version"], 1);
-    assert_eq!(pack_json["source_report"]["schema_version"], 1);
-    assert!(pack_json["summary"]["action_count"].is_number());
 }
 
 #[test]
-fn open_intake_report_fails_clearly_when_report_is_missing() {
+fn refresh_attaches_new_events_to_curated_workstreams() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let fixtures = fixture_dir();
+    let ledger = std::fs::read_to_string(fixtures.join("ledger.events.jsonl")).unwrap();
+    let initial = tmp.path().join("initial.events.jsonl");
+    std::fs::write(
+        &initial,
+        ledger
+            .lines()
+            .filter(|line| line.contains("fixture_pr_acme_platform_13"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+    .unwrap();
+    let out = tmp.path().join("out");
+    let refresh = |events: &Path| {
+        shiplog_cmd()
+            .args([
+                "refresh",
+                "--out",
+                out.to_str().unwrap(),
+                "--run-dir",
+                "latest",
+                "json",
+                "--events",
+                events.to_str().unwrap(),
+                "--coverage",
+                fixtures.join("coverage.manifest.json").to_str().unwrap(),
+            ])
+            .assert()
+            .success()
+    };
 
+    let run_dir = collect_json_events_into(&out, &initial);
     shiplog_cmd()
         .args([
-            "open",
-            "intake-report",
+            "workstreams",
+            "create",
             "--out",
-            tmp.path().to_str().unwrap(),
+            out.to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--print-path",
+            "--title",
+            "Payments",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("Intake report not found"))
-        .stderr(predicate::str::contains("shiplog intake"));
-}
+        .success();
 
-#[test]
-fn workstreams_validate_accepts_latest_run_workstreams() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    refresh(&fixtures.join("ledger.events.jsonl"))
+        .stdout(predicate::str::contains("workstreams.unassigned.yaml"));
+    let curated = load_curated_workstreams(&run_dir);
+    let platform = &curated.workstreams[0];
+    assert_eq!(platform.title, "acme/platform");
+    assert_eq!(
+        (platform.stats.pull_requests, platform.stats.reviews),
+        (1, 1)
+    );
+    let unassigned = std::fs::read_to_string(run_dir.join("workstreams.unassigned.yaml")).unwrap();
+    assert!(unassigned.contains("fixture_pr_acme_payments_42"));
+    assert!(!unassigned.contains("fixture_review_acme_platform_77_1"));
+
+    // A rule added after the first refresh picks up the pending event.
+    let mut curated = curated;
+    curated.workstreams[1].rules = WorkstreamRules {
+        repos: vec!["acme/payments".into()],
+        ..Default::default()
+    };
+    std::fs::write(
+        run_dir.join("workstreams.yaml"),
+        serde_yaml::to_string(&curated).unwrap(),
+    )
+    .unwrap();
+    refresh(&fixtures.join("ledger.events.jsonl"));
 
+    assert!(!run_dir.join("workstreams.unassigned.yaml").exists());
+    let curated = load_curated_workstreams(&run_dir);
+    assert_eq!(curated.workstreams[1].stats.pull_requests, 1);
+    assert_eq!(curated.workstreams[1].rules.repos, vec!["acme/payments"]);
     shiplog_cmd()
         .args([
             "workstreams",
             "validate",
             "--out",
-            tmp.path().to_str().unwrap(),
+            out.to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Workstreams valid:"))
-        .stdout(predicate::str::contains("assigned events"));
+        .success();
 }
 
 #[test]
-fn workstreams_validate_rejects_blank_title() {
+fn annotations_survive_refresh_and_reach_the_packet() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
     std::fs::write(
-        run_dir.join("workstreams.yaml"),
-        r#"version: 1
-generated_at: "2026-01-01T00:00:00Z"
-workstreams:
-  - id: "blank-title"
-    title: ""
-    summary: null
-    tags: []
-    stats:
-      pull_requests: 1
-      reviews: 0
-      manual_events: 0
-    events:
-      - "fixture_pr_acme_payments_42"
-    receipts:
-      - "fixture_pr_acme_payments_42"
-"#,
+        run_dir.join("annotations.yaml"),
+        "version: 1\nannotations:\n  fixture_pr_acme_platform_13:\n    tags: [launch]\n    note: Unblocked the Q3 billing migration.\n    importance: 5\n",
     )
     .unwrap();
+    let out = tmp.path().to_str().unwrap();
+
+    shiplog_cmd()
+        .args(["render", "--out", out, "--run", "run_fixture"])
+        .assert()
+        .success();
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("— Note: Unblocked the Q3 billing migration."));
+    let packet_json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(run_dir.join("packet.json")).unwrap())
+            .unwrap();
+    let receipt = packet_json["workstreams"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|ws| ws["receipts"].as_array().unwrap())
+        .find(|receipt| receipt["id"] == "fixture_pr_acme_platform_13")
+        .unwrap();
+    assert_eq!(receipt["weight"], "High");
 
+    // The ledger keeps the collected events; annotations stay in their file.
+    let ledger = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
+    assert!(!ledger.contains("Unblocked"));
+
+    let fixtures = fixture_dir();
     shiplog_cmd()
         .args([
-            "workstreams",
-            "validate",
+            "refresh",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            out,
+            "--run-dir",
+            "latest",
+            "json",
+            "--events",
+            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
+            "--coverage",
+            fixtures.join("coverage.manifest.json").to_str().unwrap(),
         ])
         .assert()
+        .success();
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("— Note: Unblocked the Q3 billing migration."));
+
+    std::fs::write(
+        run_dir.join("annotations.yaml"),
+        "version: 1\nannotations:\n  fixture_pr_acme_platform_13:\n    importance: 7\n",
+    )
+    .unwrap();
+    shiplog_cmd()
+        .args(["render", "--out", out, "--run", "run_fixture"])
+        .assert()
         .failure()
-        .stderr(predicate::str::contains("blank title"));
+        .stderr(predicate::str::contains("importance must be 1-5"));
 }
 
+// ── 6. render on a pre-populated output directory ──────────────────────────
+
 #[test]
-fn workstreams_rename_promotes_suggested_to_curated() {
+fn render_on_collected_directory() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "workstreams",
-            "rename",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--from",
-            "acme/platform",
-            "--to",
-            "Platform Reliability",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Renamed workstream"))
-        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
+        .stdout(predicate::str::contains("Rendered"));
 
-    assert!(run_dir.join("workstreams.suggested.yaml").exists());
-    let curated = load_curated_workstreams(&run_dir);
-    assert!(
-        curated
-            .workstreams
-            .iter()
-            .any(|workstream| workstream.title == "Platform Reliability")
-    );
     assert!(
-        !curated
-            .workstreams
-            .iter()
-            .any(|workstream| workstream.title == "acme/platform")
+        tmp.path().join("run_fixture/packet.md").exists(),
+        "packet.md should exist after render"
     );
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert_packet_opens_with_coverage(&packet);
+    assert_packet_uses_summary_appendix(&packet);
 }
 
 #[test]
-fn workstreams_move_event_reassigns_event_and_validates() {
+fn render_pdf_writes_packet_pdf_and_plain_render_removes_it() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
+    let out = tmp.path().to_str().unwrap();
 
     shiplog_cmd()
-        .args([
-            "workstreams",
-            "move",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--event",
-            "fixture_pr_acme_payments_42",
-            "--to",
-            "acme/platform",
-        ])
+        .args(["render", "--out", out, "--run", "run_fixture", "--pdf"])
         .assert()
         .success()
-        .stdout(predicate::str::contains(
-            "Moved event fixture_pr_acme_payments_42 to acme/platform",
-        ));
+        .stdout(predicate::str::contains("packet.pdf"));
 
-    let curated = load_curated_workstreams(&run_dir);
-    let platform = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "acme/platform")
-        .expect("platform workstream should exist");
-    assert!(
-        platform
-            .events
-            .iter()
-            .any(|event_id| event_id.to_string() == "fixture_pr_acme_payments_42")
-    );
+    let pdf = std::fs::read(run_dir.join("packet.pdf")).unwrap();
+    assert!(pdf.starts_with(b"%PDF-1.4\n"));
+    assert!(pdf.ends_with(b"%%EOF\n"));
 
-    let payments = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "acme/payments")
-        .expect("payments workstream should exist");
+    shiplog_cmd()
+        .args(["render", "--out", out, "--run", "run_fixture"])
+        .assert()
+        .success();
     assert!(
-        payments
-            .events
-            .iter()
-            .all(|event_id| event_id.to_string() != "fixture_pr_acme_payments_42")
+        !run_dir.join("packet.pdf").exists(),
+        "a render without --pdf should not leave a stale packet.pdf"
     );
+}
+
+#[test]
+fn render_uses_configured_packet_template() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    std::fs::create_dir(tmp.path().join("templates")).unwrap();
+    std::fs::write(
+        tmp.path().join("templates/packet.md"),
+        "# Shipped by {{ user }}\n\
+         {% for ws in workstreams %}\n\
+         - {{ ws.title }}: {{ ws.receipts | length }} receipts\n\
+         {% endfor %}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        "[render]\ntemplate = \"templates/packet.md\"\n",
+    )
+    .unwrap();
 
     shiplog_cmd()
-        .args([
-            "workstreams",
-            "validate",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
+        .current_dir(tmp.path())
+        .args(["render", "--out", ".", "--run", "run_fixture"])
         .assert()
         .success();
+
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    // The internal packet may still lead with a needs-attention block.
+    assert!(packet.contains("# Shipped by "), "{packet}");
+    assert!(packet.contains(" receipts\n"), "{packet}");
+    assert!(!packet.contains("## Coverage and Limits"), "{packet}");
+
+    std::fs::write(
+        tmp.path().join("templates/packet.md"),
+        "{% for ws in workstreams %}\n- {{ ws.title }}\n",
+    )
+    .unwrap();
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["config", "validate"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Render: error"))
+        .stdout(predicate::str::contains("endfor"));
 }
 
 #[test]
-fn workstreams_move_unknown_event_fails_without_writing_curated() {
+fn collect_json_writes_packet_json_matching_its_contract() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+
+    let packet: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(run_dir.join("packet.json")).unwrap())
+            .unwrap();
+    let schema_path = repo_root().join("contracts/schemas/packet.v1.schema.json");
+    let schema: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&schema_path).unwrap()).unwrap();
+
+    assert_eq!(
+        packet["schema_version"],
+        schema["properties"]["schema_version"]["const"]
+    );
+    for field in schema["required"].as_array().unwrap() {
+        let field = field.as_str().unwrap();
+        assert!(
+            packet.get(field).is_some(),
+            "packet.json should contain {field}"
+        );
+    }
+    let declared = schema["properties"].as_object().unwrap();
+    for field in packet.as_object().unwrap().keys() {
+        assert!(
+            declared.contains_key(field),
+            "packet.json field {field} should be declared in the contract"
+        );
+    }
+    let receipts: usize = packet["workstreams"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|ws| ws["receipts"].as_array().unwrap().len())
+        .sum();
+    assert!(receipts > 0, "fixture workstreams should carry receipts");
+    assert_eq!(packet["summary"]["receipts"], receipts);
+    assert_schema_field_names_are_not_secret_bearing(&schema);
+}
+
+#[test]
+fn collect_json_writes_coverage_first_packet() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert_packet_opens_with_coverage(&packet);
+    assert_packet_uses_summary_appendix(&packet);
+}
+
+#[test]
+fn render_scaffold_mode_writes_prompt_focused_packet() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "workstreams",
-            "move",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--event",
-            "missing-event",
-            "--to",
-            "acme/platform",
+            "--mode",
+            "scaffold",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "was not found in ledger.events.jsonl",
-        ));
+        .success()
+        .stdout(predicate::str::contains("Rendered"));
 
-    assert!(!run_dir.join("workstreams.yaml").exists());
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("## Coverage and Limits"));
+    assert!(packet.contains("**Suggested claim prompts**"));
+    assert!(
+        !packet.contains("\n## Receipts\n"),
+        "scaffold mode should omit the full receipts section"
+    );
+    assert!(
+        !packet.contains("\n## Appendix: All Receipts\n"),
+        "scaffold mode should omit the full appendix"
+    );
 }
 
 #[test]
-fn workstreams_receipts_lists_selected_workstream_receipts() {
+fn render_receipts_mode_writes_audit_focused_packet() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "workstreams",
-            "receipts",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "acme/platform",
+            "--mode",
+            "receipts",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Receipts: acme/platform"))
-        .stdout(predicate::str::contains("Count: 2"))
-        .stdout(predicate::str::contains(
-            "Schema hardening for audit exports",
-        ))
-        .stdout(predicate::str::contains("APPROVED"));
+        .stdout(predicate::str::contains("Rendered"));
+
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("## Coverage and Limits"));
+    assert!(packet.contains("\n## Receipts\n"));
+    assert!(packet.contains("\n## Appendix: All Receipts\n"));
+    assert!(
+        !packet.contains("**Suggested claim prompts**"),
+        "receipts mode should omit writing prompts"
+    );
 }
 
 #[test]
-fn workstreams_receipt_remove_promotes_suggested_to_curated_and_keeps_assignment() {
+fn render_receipt_limit_summary_appendix_preserves_canonical_files() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
+    let ledger_before = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
 
     shiplog_cmd()
         .args([
-            "workstreams",
-            "receipt",
-            "remove",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--event",
-            "fixture_pr_acme_platform_13",
+            "--mode",
+            "packet",
+            "--receipt-limit",
+            "1",
+            "--appendix",
+            "summary",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains(
-            "Removed receipt anchor fixture_pr_acme_platform_13 from acme/platform",
-        ))
-        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
+        .stdout(predicate::str::contains("Rendered"));
 
-    assert!(run_dir.join("workstreams.suggested.yaml").exists());
-    let curated = load_curated_workstreams(&run_dir);
-    let platform = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "acme/platform")
-        .expect("platform workstream should exist");
-    assert!(
-        platform
-            .events
-            .iter()
-            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("\n## Receipts\n"));
+    assert!(packet.contains("... and"));
+    assert!(packet.contains("## Appendix: Receipt Summary"));
+    assert!(!packet.contains("## Appendix: All Receipts"));
+    assert!(packet.contains("- Assigned events:"));
+    assert!(packet.contains("- Curated receipt anchors:"));
+    assert_eq!(
+        ledger_before,
+        std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap()
     );
-    assert!(
-        platform
-            .receipts
-            .iter()
-            .all(|event_id| event_id.to_string() != "fixture_pr_acme_platform_13")
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
     );
 }
 
 #[test]
-fn workstreams_receipt_add_promotes_suggested_to_curated_and_validates() {
+fn render_receipt_limit_zero_appendix_none_keeps_omission_notice() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
-    std::fs::write(
-        run_dir.join("workstreams.suggested.yaml"),
-        r#"version: 1
-generated_at: "2026-01-01T00:00:00Z"
-workstreams:
-  - id: "repo-acme-platform"
-    title: "acme/platform"
-    summary: null
-    tags:
-      - platform
-    stats:
-      pull_requests: 1
-      reviews: 1
-      manual_events: 0
-    events:
-      - "fixture_pr_acme_platform_13"
-      - "fixture_review_acme_platform_77_1"
-    receipts:
-      - "fixture_review_acme_platform_77_1"
-"#,
-    )
-    .unwrap();
+    let ledger_before = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
+    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
 
     shiplog_cmd()
         .args([
-            "workstreams",
-            "receipt",
-            "add",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--event",
-            "fixture_pr_acme_platform_13",
+            "--mode",
+            "packet",
+            "--receipt-limit",
+            "0",
+            "--appendix",
+            "none",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains(
-            "Added receipt anchor fixture_pr_acme_platform_13 to acme/platform",
-        ))
-        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
+        .stdout(predicate::str::contains("Rendered"));
 
-    let curated = load_curated_workstreams(&run_dir);
-    let platform = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "acme/platform")
-        .expect("platform workstream should exist");
-    assert!(
-        platform
-            .receipts
-            .iter()
-            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("\n## Receipts\n"));
+    assert!(packet.contains("- (none)"));
+    assert!(packet.contains("omitted by appendix settings"));
+    assert!(!packet.contains("## Appendix:"));
+    assert_eq!(
+        ledger_before,
+        std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap()
+    );
+    assert_eq!(
+        coverage_before,
+        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
     );
-
-    shiplog_cmd()
-        .args([
-            "workstreams",
-            "validate",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
-        .assert()
-        .success();
 }
 
 #[test]
-fn workstreams_receipt_add_rejects_event_not_in_workstream_without_writing() {
+fn render_receipts_mode_honors_receipt_controls() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "workstreams",
-            "receipt",
-            "add",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--event",
-            "fixture_pr_acme_payments_42",
+            "--mode",
+            "receipts",
+            "--receipt-limit",
+            "1",
+            "--appendix",
+            "full",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("is not assigned to workstream"));
+        .success()
+        .stdout(predicate::str::contains("Rendered"));
 
-    assert!(!run_dir.join("workstreams.yaml").exists());
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("\n## Receipts\n"));
+    assert!(packet.contains("... and"));
+    assert!(packet.contains("## Appendix: All Receipts"));
+    assert!(!packet.contains("**Suggested claim prompts**"));
 }
 
 #[test]
-fn workstreams_receipt_add_rejects_duplicate_without_writing() {
+fn render_public_profile_without_key_fails_closed() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let mut cmd = shiplog_cmd();
+    cmd.env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "receipt",
-            "add",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--event",
-            "fixture_pr_acme_platform_13",
+            "--bundle-profile",
+            "public",
         ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("already a receipt anchor"));
-
-    assert!(!run_dir.join("workstreams.yaml").exists());
+        .stderr(
+            predicate::str::contains("public profile requires --redact-key or SHIPLOG_REDACT_KEY")
+                .and(predicate::str::contains("Try:"))
+                .and(predicate::str::contains(redaction_key_env_command(
+                    "SHIPLOG_REDACT_KEY",
+                )))
+                .and(predicate::str::contains(
+                    "rerun this command with --bundle-profile public",
+                )),
+        );
 }
 
 #[test]
-fn workstreams_receipt_remove_rejects_missing_receipt_without_writing() {
+fn render_public_profile_with_key_writes_public_packet() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
     shiplog_cmd()
         .args([
-            "workstreams",
-            "receipt",
-            "remove",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--event",
-            "fixture_pr_acme_payments_42",
+            "--bundle-profile",
+            "public",
+            "--redact-key",
+            "stable-test-key",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("is not a receipt anchor"));
+        .success()
+        .stdout(predicate::str::contains("Rendered"));
 
-    assert!(!run_dir.join("workstreams.yaml").exists());
+    let public_packet_path = tmp.path().join("run_fixture/profiles/public/packet.md");
+    assert!(
+        public_packet_path.exists(),
+        "public packet should be written when a redaction key is provided"
+    );
+
+    let public_packet = std::fs::read_to_string(public_packet_path).unwrap();
+    assert!(
+        public_packet.contains("omitted by appendix settings"),
+        "public profile should default to a low-density packet with explicit omission notes"
+    );
+    assert!(
+        !public_packet.contains("## Appendix:"),
+        "public profile should omit the receipt appendix by default"
+    );
 }
 
 #[test]
-fn workstreams_create_promotes_suggested_to_curated() {
+fn render_custom_profile_from_config_writes_its_packet_and_zip() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
-        .args([
-            "workstreams",
-            "create",
+    let render = |profile: &str| {
+        let mut cmd = shiplog_cmd();
+        cmd.current_dir(tmp.path()).args([
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--title",
-            "Platform Reliability",
-        ])
+            "--bundle-profile",
+            profile,
+            "--redact-key",
+            "stable-test-key",
+            "--zip",
+        ]);
+        cmd
+    };
+    render("skip-level")
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Created workstream: Platform Reliability",
-        ))
-        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown bundle profile: \"skip-level\"",
+        ));
 
-    assert!(run_dir.join("workstreams.suggested.yaml").exists());
-    let curated = load_curated_workstreams(&run_dir);
-    let created = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "Platform Reliability")
-        .expect("created workstream should exist");
-    assert!(created.events.is_empty());
-    assert!(created.receipts.is_empty());
-    assert_eq!(created.stats.pull_requests, 0);
-    assert_eq!(created.stats.reviews, 0);
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        "[redaction.profiles.skip-level]\nbase = \"manager\"\ntitles = \"strip\"\n",
+    )
+    .unwrap();
+    render("skip-level").assert().success();
+
+    let run_dir = tmp.path().join("run_fixture");
+    let packet = std::fs::read_to_string(run_dir.join("profiles/skip-level/packet.md")).unwrap();
+    assert!(packet.contains("[redacted]"));
+    assert!(run_dir.join("profiles/manager/packet.md").exists());
+    assert!(tmp.path().join("run_fixture.skip-level.zip").exists());
 }
 
 #[test]
-fn workstreams_create_rejects_blank_and_duplicate_without_writing() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+fn render_share_profiles_blocks_on_manual_event_pii_until_acknowledged() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let run_dir = collect_manual_into(tmp.path());
+    let config = tmp.path().join("shiplog.toml");
+    std::fs::write(
+        &config,
+        "[redaction]\npii_terms = [\"Support\"]\nblock_public_on_pii = true\n",
+    )?;
+    let out = tmp.path().to_str().context("temp path is not UTF-8")?;
+    let config = config.to_str().context("config path is not UTF-8")?;
+    let render = [
+        "render",
+        "--out",
+        out,
+        "--config",
+        config,
+        "--bundle-profile",
+        "public",
+        "--redact-key",
+        "stable-test-key",
+    ];
 
     shiplog_cmd()
-        .args([
-            "workstreams",
-            "create",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--title",
-            "   ",
-        ])
+        .args(render)
         .assert()
         .failure()
-        .stderr(predicate::str::contains("title cannot be blank"));
-    assert!(!run_dir.join("workstreams.yaml").exists());
+        .stderr(predicate::str::contains(
+            "manual event \"Manual incident follow-up\" description: possible dictionary term \"support\"",
+        ))
+        .stderr(predicate::str::contains("--acknowledge-pii"));
+    assert!(!run_dir.join("profiles/public/packet.md").exists());
 
     shiplog_cmd()
-        .args([
-            "workstreams",
-            "create",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--title",
-            "acme/platform",
-        ])
+        .args(render)
+        .arg("--acknowledge-pii")
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("already matches"));
-    assert!(!run_dir.join("workstreams.yaml").exists());
+        .success()
+        .stderr(predicate::str::contains("Possible PII in manual events:"));
+    assert!(run_dir.join("profiles/public/packet.md").exists());
+    Ok(())
 }
 
 #[test]
-fn workstreams_delete_empty_workstream_succeeds() {
+fn share_manager_without_key_fails_closed() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let mut cmd = shiplog_cmd();
+    cmd.env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "create",
+            "share",
+            "manager",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--title",
-            "Temporary Bucket",
         ])
         .assert()
-        .success();
+        .failure()
+        .stderr(
+            predicate::str::contains("manager share requires --redact-key or SHIPLOG_REDACT_KEY")
+                .and(predicate::str::contains("Try:"))
+                .and(predicate::str::contains(redaction_key_env_command(
+                    "SHIPLOG_REDACT_KEY",
+                )))
+                .and(predicate::str::contains("shiplog share manager --latest")),
+        );
 
-    shiplog_cmd()
+    assert!(
+        !tmp.path()
+            .join("run_fixture/profiles/manager/packet.md")
+            .exists(),
+        "manager share packet should not be written without a redaction key"
+    );
+    assert!(
+        !tmp.path()
+            .join("run_fixture/profiles/manager/share.manifest.json")
+            .exists(),
+        "manager share manifest should not be written without a redaction key"
+    );
+}
+
+#[test]
+fn share_explain_manager_without_key_reports_block_without_writing() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "delete",
+            "share",
+            "explain",
+            "manager",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "Temporary Bucket",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Deleted workstream: Temporary Bucket",
-        ));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    let curated = load_curated_workstreams(&run_dir);
+    assert!(stdout.contains("Manager profile:"));
+    assert!(stdout.contains("Status: blocked"));
+    assert!(stdout.contains("Redaction key: missing"));
+    assert!(stdout.contains("Included:"));
+    assert!(stdout.contains("- packet readiness and claim candidates"));
+    assert!(stdout.contains("source-backed evidence counts: GitHub"));
+    assert!(stdout.contains("Removed:"));
+    assert!(stdout.contains("- opaque provider identifiers"));
+    assert!(stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY"));
+    assert!(stdout.contains("Needs review:\n- None"));
+    assert!(stdout.contains("Profile packet: not written yet"));
+    assert!(stdout.contains("Share manifest: not written yet"));
+    assert!(stdout.contains("Render when ready:"));
+    assert!(stdout.contains("shiplog share manager --out"));
+    assert!(!stdout.contains("\nNext:\n"));
     assert!(
-        !curated
-            .workstreams
-            .iter()
-            .any(|workstream| workstream.title == "Temporary Bucket")
+        !tmp.path()
+            .join("run_fixture/profiles/manager/packet.md")
+            .exists(),
+        "manager explain should not render the share packet"
+    );
+    assert!(
+        !tmp.path()
+            .join("run_fixture/profiles/manager/share.manifest.json")
+            .exists(),
+        "manager explain should not write the share manifest"
     );
 }
 
 #[test]
-fn workstreams_delete_non_empty_requires_move_or_force_without_writing() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+fn share_explain_manager_surfaces_packet_evidence_debt_without_writing() -> CliTestResult {
+    let Some(repo) = create_local_git_repo() else {
+        return Ok(());
+    };
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let repo_path = repo.path().display().to_string().replace('\\', "/");
+
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        format!(
+            r#"[defaults]
+window = "year:2025"
+
+[user]
+label = "shiplog test"
+
+[sources.git]
+enabled = true
+repo = "{repo_path}"
+include_merges = false
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "shiplog test"
+"#
+        ),
+    )?;
+    std::fs::write(
+        tmp.path().join("manual_events.yaml"),
+        "version: 1\ngenerated_at: 2026-01-01T00:00:00Z\nevents: []\n",
+    )?;
 
     shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GITLAB_TOKEN")
+        .env_remove("JIRA_TOKEN")
+        .env_remove("LINEAR_API_KEY")
         .args([
-            "workstreams",
-            "delete",
+            "intake",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--workstream",
-            "acme/platform",
+            out.to_str().unwrap(),
+            "--year",
+            "2025",
+            "--no-open",
+            "--explain",
+        ])
+        .assert()
+        .success();
+
+    let (report_path, mut report) = load_first_intake_report(&out);
+    let evidence_debt_summary = "Manual outcome context is missing.";
+    report["evidence_debt"] = serde_json::json!([
+        {
+            "severity": "info",
+            "kind": "manual-context",
+            "summary": evidence_debt_summary,
+            "detail": "Injected fixture evidence debt",
+            "next_step": "shiplog journal add --date 2025-01-15 --title \"Outcome note\""
+        }
+    ]);
+    report["packet_quality"]["packet_readiness"]["summary"] =
+        serde_json::json!("Ready with caveats.");
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report)?),
+    )?;
+
+    let readiness = report["packet_quality"]["packet_readiness"]["summary"]
+        .as_str()
+        .expect("packet readiness summary should be present")
+        .trim_end_matches('.');
+
+    let before = file_tree_manifest(tmp.path());
+    let assert = shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("SHIPLOG_REDACT_KEY")
+        .args([
+            "share",
+            "explain",
+            "manager",
+            "--out",
+            out.to_str().unwrap(),
+            "--latest",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("--move-to"))
-        .stderr(predicate::str::contains("--force"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    let after = file_tree_manifest(tmp.path());
 
-    assert!(!run_dir.join("workstreams.yaml").exists());
+    assert!(
+        stdout.contains(&format!("Packet readiness: {readiness}")),
+        "share explain should surface packet readiness caveats. stdout:\n{stdout}"
+    );
+    assert!(
+        stdout.contains(&format!("Evidence debt: {evidence_debt_summary}")),
+        "share explain should surface report evidence debt before sharing. stdout:\n{stdout}"
+    );
+    assert!(
+        !stdout.contains("Needs review:\n- None"),
+        "share explain should not claim there is nothing to review when evidence debt exists. stdout:\n{stdout}"
+    );
+    assert_eq!(
+        before, after,
+        "share explain should remain read-only while surfacing evidence debt"
+    );
+
+    Ok(())
 }
 
 #[test]
-fn workstreams_delete_non_empty_moves_events_and_recomputes_stats() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+fn share_explain_manager_surfaces_open_source_repairs_without_writing() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let out_arg = out.to_string_lossy().to_string();
 
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (_, first_report) = load_first_intake_report(&out);
+    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
+        .current_dir(tmp.path())
         .args([
-            "workstreams",
-            "create",
+            "journal",
+            "add",
+            "--from-repair",
+            repair_id.as_str(),
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--title",
-            "Misc",
+            out_arg.as_str(),
+            "--latest",
         ])
         .assert()
         .success();
+    run_intake_without_provider_tokens(tmp.path(), &out);
 
-    shiplog_cmd()
+    let before = file_tree_manifest(tmp.path());
+    let assert = shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "delete",
+            "share",
+            "explain",
+            "manager",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--move-to",
-            "Misc",
+            out_arg.as_str(),
+            "--latest",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Deleted workstream: acme/platform",
-        ))
-        .stdout(predicate::str::contains("Moved 2 event(s)"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    let after = file_tree_manifest(tmp.path());
 
-    let curated = load_curated_workstreams(&run_dir);
     assert!(
-        !curated
-            .workstreams
-            .iter()
-            .any(|workstream| workstream.title == "acme/platform")
+        stdout.contains("Open source repair:")
+            && stdout.contains("GitHub needs repair: GitHub authentication unavailable"),
+        "share explain should name source repairs that remain open after journal-only repair. stdout:\n{stdout}"
     );
-    let misc = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "Misc")
-        .expect("move target should exist");
-    assert_eq!(misc.stats.pull_requests, 1);
-    assert_eq!(misc.stats.reviews, 1);
     assert!(
-        misc.events
-            .iter()
-            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+        !stdout.contains("Needs review:\n- None"),
+        "share explain should not claim nothing needs review while source repairs remain open. stdout:\n{stdout}"
     );
-    assert!(
-        misc.events
-            .iter()
-            .any(|event_id| event_id.to_string() == "fixture_review_acme_platform_77_1")
+    assert_eq!(
+        before, after,
+        "share explain should remain read-only while surfacing open source repairs"
     );
 
-    shiplog_cmd()
-        .args([
-            "workstreams",
-            "validate",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
-        .assert()
-        .success();
+    Ok(())
 }
 
 #[test]
-fn workstreams_delete_self_move_fails_without_writing() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+fn share_explain_uses_report_receipts_not_packet_markdown() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let run_dir = first_run_dir(&out);
+    let (report_path, mut report) = load_first_intake_report(&out);
+    let report_only_debt = "Report-only share posture debt.";
+    let markdown_only_bait = "Markdown-only share posture bait.";
+    report["evidence_debt"] = serde_json::json!([
+        {
+            "severity": "info",
+            "kind": "manual-context",
+            "summary": report_only_debt,
+            "detail": "Injected report-only receipt",
+            "next_step": "shiplog journal add --date 2025-01-15 --title \"Outcome note\""
+        }
+    ]);
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report)?),
+    )?;
+    std::fs::write(
+        run_dir.join("packet.md"),
+        format!("# Packet Readiness\n\nReady.\n\n{markdown_only_bait}\n"),
+    )?;
 
-    shiplog_cmd()
+    let before = file_tree_manifest(tmp.path());
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "delete",
+            "share",
+            "explain",
+            "manager",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--move-to",
-            "acme/platform",
+            out.to_str().unwrap(),
+            "--latest",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("must be different"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    let after = file_tree_manifest(tmp.path());
 
-    assert!(!run_dir.join("workstreams.yaml").exists());
+    assert!(
+        stdout.contains(&format!("Evidence debt: {report_only_debt}")),
+        "share explain should use intake.report.json evidence debt. stdout:\n{stdout}"
+    );
+    assert!(
+        !stdout.contains(markdown_only_bait),
+        "share explain should not scrape packet.md for share posture. stdout:\n{stdout}"
+    );
+    assert_eq!(
+        before, after,
+        "share explain should stay read-only while reading report receipts"
+    );
+
+    Ok(())
 }
 
 #[test]
-fn workstreams_delete_missing_move_target_fails_without_writing() {
+fn share_explain_public_without_key_reports_public_posture_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "delete",
+            "share",
+            "explain",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--workstream",
-            "acme/platform",
-            "--move-to",
-            "Missing Bucket",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("no workstream matched"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(!run_dir.join("workstreams.yaml").exists());
+    assert!(stdout.contains("Public profile:"));
+    assert!(stdout.contains("Status: blocked"));
+    assert!(stdout.contains("Included:"));
+    assert!(stdout.contains("public-safe summaries with the lowest default receipt density"));
+    assert!(stdout.contains("Removed:"));
+    assert!(stdout.contains("raw private URLs and original names where strict redaction applies"));
+    assert!(stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY"));
+    assert!(
+        stdout.contains(
+            "Public profile should be reviewed after rendering; strict scan is a guardrail"
+        )
+    );
+    assert!(stdout.contains("Public profile uses the strictest redaction profile."));
+    assert!(stdout.contains("Render when ready:"));
+    assert!(stdout.contains("shiplog share public --out"));
+    assert!(!stdout.contains("\nNext:\n"));
+    assert!(
+        !tmp.path()
+            .join("run_fixture/profiles/public/packet.md")
+            .exists(),
+        "public explain should not render the share packet"
+    );
+    assert!(
+        !tmp.path()
+            .join("run_fixture/profiles/public/share.manifest.json")
+            .exists(),
+        "public explain should not write the share manifest"
+    );
 }
 
-#[test]
-fn workstreams_delete_force_discards_assignments_and_validates() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+#[test]
+fn share_readiness_missing_key_is_consistent_across_doctor_and_share_commands() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    collect_json_into(tmp.path());
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[shiplog]
+config_version = 1
+
+[defaults]
+out = "./out"
+window = "last-6-months"
+profile = "manager"
+
+[sources.json]
+enabled = true
+events = "./run_fixture/ledger.events.jsonl"
+coverage = "./run_fixture/coverage.manifest.json"
+"#,
+    )?;
+
+    let before = file_tree_manifest(tmp.path());
 
-    shiplog_cmd()
-        .args([
-            "workstreams",
-            "delete",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--workstream",
-            "acme/payments",
-            "--force",
-        ])
+    let doctor = shiplog_cmd()
+        .current_dir(tmp.path())
+        .env_remove("SHIPLOG_REDACT_KEY")
+        .args(["doctor", "--setup", "--for", "manager-share"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Deleted workstream: acme/payments",
-        ))
-        .stdout(predicate::str::contains("Discarded 1 event assignment"));
-
-    let curated = load_curated_workstreams(&run_dir);
+        .failure();
+    let doctor_stdout = String::from_utf8(doctor.get_output().stdout.clone())?;
     assert!(
-        !curated
-            .workstreams
-            .iter()
-            .any(|workstream| workstream.title == "acme/payments")
+        doctor_stdout.contains("Manager share")
+            && doctor_stdout.contains("Public share")
+            && doctor_stdout.contains("SHIPLOG_REDACT_KEY not set"),
+        "doctor --setup should report the shared redaction setup blocker. stdout:\n{doctor_stdout}"
     );
-    shiplog_cmd()
+
+    let manager_explain = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "validate",
+            "share",
+            "explain",
+            "manager",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
@@ -16306,611 +19033,817 @@ fn workstreams_delete_force_discards_assignments_and_validates() {
         ])
         .assert()
         .success();
-}
-
-#[test]
-fn workstreams_split_creates_target_and_recomputes_stats() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    let manager_explain_stdout = String::from_utf8(manager_explain.get_output().stdout.clone())?;
+    assert!(
+        manager_explain_stdout.contains("Manager profile:")
+            && manager_explain_stdout.contains("Status: blocked")
+            && manager_explain_stdout.contains("Redaction key: missing")
+            && manager_explain_stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY"),
+        "share explain manager should report the same missing-key blocker. stdout:\n{manager_explain_stdout}"
+    );
 
-    shiplog_cmd()
+    let public_explain = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "split",
+            "share",
+            "explain",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--from",
-            "acme/platform",
-            "--to",
-            "Audit Exports",
-            "--matching",
-            "schema|compliance",
-            "--create",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Split 1 event(s) from acme/platform to Audit Exports",
-        ))
-        .stdout(predicate::str::contains("Created target workstream"))
-        .stdout(predicate::str::contains("Created curated workstreams.yaml"));
-
-    assert!(run_dir.join("workstreams.suggested.yaml").exists());
-    let curated = load_curated_workstreams(&run_dir);
-    let audit = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "Audit Exports")
-        .expect("split target should be created");
-    assert_eq!(audit.stats.pull_requests, 1);
-    assert_eq!(audit.stats.reviews, 0);
+        .success();
+    let public_explain_stdout = String::from_utf8(public_explain.get_output().stdout.clone())?;
     assert!(
-        audit
-            .events
-            .iter()
-            .any(|event_id| event_id.to_string() == "fixture_pr_acme_platform_13")
+        public_explain_stdout.contains("Public profile:")
+            && public_explain_stdout.contains("Status: blocked")
+            && public_explain_stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY")
+            && public_explain_stdout.contains("Public profile should be reviewed after rendering"),
+        "share explain public should report the same missing-key blocker and public review caveat. stdout:\n{public_explain_stdout}"
     );
 
-    let platform = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "acme/platform")
-        .expect("source workstream should remain");
-    assert_eq!(platform.stats.pull_requests, 0);
-    assert_eq!(platform.stats.reviews, 1);
+    for profile in ["manager", "public"] {
+        let share_stderr = String::from_utf8(
+            shiplog_cmd()
+                .env_remove("SHIPLOG_REDACT_KEY")
+                .args([
+                    "share",
+                    profile,
+                    "--out",
+                    tmp.path().to_str().unwrap(),
+                    "--run",
+                    "run_fixture",
+                ])
+                .assert()
+                .failure()
+                .get_output()
+                .stderr
+                .clone(),
+        )?;
+        assert!(
+            share_stderr.contains(&format!(
+                "{profile} share requires --redact-key or SHIPLOG_REDACT_KEY"
+            )),
+            "share {profile} should fail closed on the same missing key. stderr:\n{share_stderr}"
+        );
+
+        let verify_stderr = String::from_utf8(
+            shiplog_cmd()
+                .env_remove("SHIPLOG_REDACT_KEY")
+                .args([
+                    "share",
+                    "verify",
+                    profile,
+                    "--out",
+                    tmp.path().to_str().unwrap(),
+                    "--run",
+                    "run_fixture",
+                ])
+                .assert()
+                .failure()
+                .get_output()
+                .stderr
+                .clone(),
+        )?;
+        assert!(
+            verify_stderr.contains(&format!(
+                "{profile} share requires --redact-key or SHIPLOG_REDACT_KEY"
+            )),
+            "share verify {profile} should fail closed on the same missing key. stderr:\n{verify_stderr}"
+        );
+    }
+
+    let after = file_tree_manifest(tmp.path());
+    assert_eq!(
+        before, after,
+        "doctor, share explain, share verify, and failed share rendering should not write artifacts while redaction setup is blocked"
+    );
     assert!(
-        platform
-            .events
-            .iter()
-            .all(|event_id| event_id.to_string() != "fixture_pr_acme_platform_13")
+        !tmp.path().join("run_fixture/profiles/manager").exists()
+            && !tmp.path().join("run_fixture/profiles/public").exists(),
+        "blocked share readiness checks should not create profile directories"
     );
 
-    shiplog_cmd()
-        .args([
-            "workstreams",
-            "validate",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
-        .assert()
-        .success();
+    Ok(())
 }
 
 #[test]
-fn workstreams_split_matches_source_url() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+fn share_explain_public_surfaces_packet_debt_without_writing() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (report_path, mut report) = load_first_intake_report(&out);
+    let evidence_debt_summary = "Public packet needs human outcome context.";
+    report["evidence_debt"] = serde_json::json!([
+        {
+            "severity": "info",
+            "kind": "manual-context",
+            "summary": evidence_debt_summary,
+            "detail": "Injected fixture public-share debt",
+            "next_step": "shiplog journal add --date 2025-01-15 --title \"Outcome note\""
+        }
+    ]);
+    report["packet_quality"]["packet_readiness"]["summary"] =
+        serde_json::json!("Ready with caveats.");
+    std::fs::write(
+        &report_path,
+        format!("{}\n", serde_json::to_string_pretty(&report)?),
+    )?;
 
-    shiplog_cmd()
+    let before = file_tree_manifest(tmp.path());
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "workstreams",
-            "split",
+            "share",
+            "explain",
+            "public",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--from",
-            "acme/platform",
-            "--to",
-            "Review Receipts",
-            "--matching",
-            "reviews/1",
-            "--create",
+            out.to_str().unwrap(),
+            "--latest",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Split 1 event(s) from acme/platform to Review Receipts",
-        ));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    let after = file_tree_manifest(tmp.path());
 
-    let curated = load_curated_workstreams(&run_dir);
-    let review_receipts = curated
-        .workstreams
-        .iter()
-        .find(|workstream| workstream.title == "Review Receipts")
-        .expect("source-url split target should be created");
     assert!(
-        review_receipts
-            .events
-            .iter()
-            .any(|event_id| event_id.to_string() == "fixture_review_acme_platform_77_1")
+        stdout.contains("Packet readiness: Ready with caveats"),
+        "public share explain should surface packet readiness caveats. stdout:\n{stdout}"
+    );
+    assert!(
+        stdout.contains(&format!("Evidence debt: {evidence_debt_summary}")),
+        "public share explain should surface packet evidence debt. stdout:\n{stdout}"
+    );
+    assert!(
+        stdout.contains(
+            "Public profile should be reviewed after rendering; strict scan is a guardrail"
+        ),
+        "public share explain should keep strict-review caveats visible. stdout:\n{stdout}"
+    );
+    assert!(
+        stdout.contains("shiplog share verify public")
+            && stdout.contains("--strict")
+            && stdout.contains("Render when ready:"),
+        "public share explain should route rendering through strict verification. stdout:\n{stdout}"
+    );
+    assert_eq!(
+        before, after,
+        "public share explain should remain read-only while surfacing packet debt"
     );
+
+    Ok(())
 }
 
 #[test]
-fn workstreams_split_missing_target_requires_create_without_writing_curated() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+fn share_explain_legacy_report_without_packet_quality_prompts_rerun_without_writing()
+-> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (report_path, _) = load_first_intake_report(&out);
+    remove_packet_quality_from_report(&report_path)?;
 
-    shiplog_cmd()
-        .args([
-            "workstreams",
-            "split",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--from",
-            "acme/platform",
-            "--to",
-            "Audit Exports",
-            "--matching",
-            "Schema",
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("add --create to create it"));
+    let before = file_tree_manifest(tmp.path());
+    for profile in ["manager", "public"] {
+        let assert = shiplog_cmd()
+            .env_remove("SHIPLOG_REDACT_KEY")
+            .args([
+                "share",
+                "explain",
+                profile,
+                "--out",
+                out.to_str().unwrap(),
+                "--latest",
+            ])
+            .assert()
+            .success();
+        let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
 
-    assert!(!run_dir.join("workstreams.yaml").exists());
-}
+        assert!(
+            stdout.contains("Packet quality unavailable: rerun intake for review-ready signals."),
+            "share explain {profile} should prompt rerun for legacy reports. stdout:\n{stdout}"
+        );
+        assert!(
+            !stdout.contains("Packet readiness: Ready")
+                && !stdout.contains("Packet readiness: Ready for review"),
+            "share explain {profile} should not invent readiness for legacy reports. stdout:\n{stdout}"
+        );
+    }
+    let after = file_tree_manifest(tmp.path());
+    assert_eq!(
+        before, after,
+        "share explain should not write while explaining legacy report quality gaps"
+    );
 
-fn load_curated_workstreams(run_dir: &Path) -> WorkstreamsFile {
-    let text = std::fs::read_to_string(run_dir.join("workstreams.yaml")).unwrap();
-    serde_yaml::from_str(&text).unwrap()
+    Ok(())
 }
 
 #[test]
-fn run_git_produces_outputs() {
-    let Some(repo) = create_local_git_repo() else {
-        eprintln!("skipping run_git_produces_outputs: git not available");
-        return;
-    };
-    let out = TempDir::new().unwrap();
+fn share_explain_legacy_packet_quality_without_candidates_or_share_posture_prompts_rerun_without_writing()
+-> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    run_intake_without_provider_tokens(tmp.path(), &out);
+    let (report_path, _) = load_first_intake_report(&out);
+    remove_packet_quality_fields_from_report(&report_path, &["claim_candidates", "share_posture"])?;
 
-    shiplog_cmd()
+    let before = file_tree_manifest(tmp.path());
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "run",
+            "share",
+            "explain",
+            "manager",
             "--out",
-            out.path().to_str().unwrap(),
-            "git",
-            "--repo",
-            repo.path().to_str().unwrap(),
-            "--since",
-            "2025-01-01",
-            "--until",
-            "2025-02-01",
+            out.to_str().unwrap(),
+            "--latest",
         ])
         .assert()
         .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+    let after = file_tree_manifest(tmp.path());
 
-    let run_dir = first_run_dir(out.path());
-    assert!(run_dir.join("packet.md").exists(), "missing packet.md");
-    assert!(
-        run_dir.join("ledger.events.jsonl").exists(),
-        "missing ledger.events.jsonl"
-    );
     assert!(
-        run_dir.join("coverage.manifest.json").exists(),
-        "missing coverage.manifest.json"
+        stdout.contains(
+            "Packet quality incomplete: rerun intake for claim candidates, share posture signals."
+        ),
+        "share explain should prompt rerun for partial legacy packet_quality. stdout:\n{stdout}"
     );
-    assert!(
-        run_dir.join("workstreams.suggested.yaml").exists(),
-        "missing workstreams.suggested.yaml"
+    assert_eq!(
+        before, after,
+        "share explain should not write while explaining partial legacy packet_quality"
     );
+
+    Ok(())
 }
 
 #[test]
-fn refresh_git_preserves_existing_workstreams() {
-    let Some(repo) = create_local_git_repo() else {
-        eprintln!("skipping refresh_git_preserves_existing_workstreams: git not available");
-        return;
-    };
-    let out = TempDir::new().unwrap();
+fn share_explain_latest_profiles_are_read_only() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+    let before = file_tree_manifest(tmp.path());
 
-    shiplog_cmd()
-        .args([
-            "collect",
-            "--out",
-            out.path().to_str().unwrap(),
-            "git",
-            "--repo",
-            repo.path().to_str().unwrap(),
-            "--since",
-            "2025-01-01",
-            "--until",
-            "2025-02-01",
-        ])
-        .assert()
-        .success();
+    for profile in ["manager", "public"] {
+        let assert = shiplog_cmd()
+            .env_remove("SHIPLOG_REDACT_KEY")
+            .args([
+                "share",
+                "explain",
+                profile,
+                "--out",
+                tmp.path().to_str().unwrap(),
+                "--latest",
+            ])
+            .assert()
+            .success();
+        let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+        assert!(
+            stdout.contains("Profile packet: not written yet")
+                && stdout.contains("Share manifest: not written yet")
+                && stdout.contains(&redaction_key_env_command("SHIPLOG_REDACT_KEY")),
+            "share explain {profile} should describe existing share artifacts without rendering. stdout:\n{stdout}"
+        );
+    }
+
+    let after = file_tree_manifest(tmp.path());
+    assert_eq!(
+        before, after,
+        "share explain --latest should not write or rewrite files"
+    );
+}
+
+#[test]
+fn share_verify_manager_without_key_fails_closed_without_writing() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
 
-    let run_dir = first_run_dir(out.path());
     shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "refresh",
+            "share",
+            "verify",
+            "manager",
             "--out",
-            out.path().to_str().unwrap(),
-            "--run-dir",
-            run_dir.to_str().unwrap(),
-            "git",
-            "--repo",
-            repo.path().to_str().unwrap(),
-            "--since",
-            "2025-01-01",
-            "--until",
-            "2025-02-01",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Refreshed while preserving workstream curation",
-        ));
+        .failure()
+        .stderr(
+            predicate::str::contains("manager share requires --redact-key or SHIPLOG_REDACT_KEY")
+                .and(predicate::str::contains("shiplog share manager --latest")),
+        );
 
-    assert!(run_dir.join("packet.md").exists(), "missing packet.md");
-    assert!(
-        run_dir.join("ledger.events.jsonl").exists(),
-        "missing ledger.events.jsonl"
-    );
     assert!(
-        run_dir.join("workstreams.suggested.yaml").exists(),
-        "missing workstreams.suggested.yaml"
+        !tmp.path()
+            .join("run_fixture/profiles/manager/packet.md")
+            .exists(),
+        "manager verify should not write a share packet without a redaction key"
     );
 }
 
 #[test]
-fn refresh_run_dir_latest_alias_on_collected_directory() {
+fn share_verify_manager_uses_env_key_without_writing_or_printing_secret() {
     let tmp = TempDir::new().unwrap();
-    let fixtures = fixture_dir();
-    let _run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
+        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
         .args([
-            "refresh",
+            "share",
+            "verify",
+            "manager",
             "--out",
             tmp.path().to_str().unwrap(),
-            "--run-dir",
-            "latest",
-            "json",
-            "--events",
-            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
-            "--coverage",
-            fixtures.join("coverage.manifest.json").to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains(
-            "Refreshed while preserving workstream curation",
-        ));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
+    assert!(stdout.contains("Share verify: manager"));
+    assert!(stdout.contains("Good:"));
+    assert!(stdout.contains("- Redaction key found"));
+    assert!(stdout.contains("Needs attention:\n- None"));
+    assert!(stdout.contains("- Packet: not written yet; share can render it."));
+    assert!(stdout.contains("Workstreams:"));
+    assert!(stdout.contains("Coverage:"));
+    assert!(stdout.contains("- Status: Complete"));
+    assert!(stdout.contains("- Gaps: 0"));
+    assert!(stdout.contains("Skipped sources:\n- None"));
+    assert!(stdout.contains("Result: ready to render manager share output."));
+    assert!(stdout.contains("shiplog share manager --out"));
+    assert!(!stdout.contains("stable-env-key"));
     assert!(
-        tmp.path().join("run_fixture/packet.md").exists(),
-        "packet.md should exist after refresh --run-dir latest"
+        !tmp.path()
+            .join("run_fixture/profiles/manager/packet.md")
+            .exists(),
+        "manager verify should not render the share packet"
     );
 }
 
-// ── 6. render on a pre-populated output directory ──────────────────────────
-
 #[test]
-fn render_on_collected_directory() {
+fn share_verify_public_accepts_explicit_key_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "render",
+            "share",
+            "verify",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
+            "--redact-key",
+            "stable-test-key",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Rendered"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
+    assert!(stdout.contains("Share verify: public"));
+    assert!(stdout.contains("Public profile will use the strictest redaction profile."));
+    assert!(stdout.contains("shiplog share public --out"));
+    assert!(!stdout.contains("stable-test-key"));
     assert!(
-        tmp.path().join("run_fixture/packet.md").exists(),
-        "packet.md should exist after render"
+        !tmp.path()
+            .join("run_fixture/profiles/public/packet.md")
+            .exists(),
+        "public verify should not render the share packet"
     );
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert_packet_opens_with_coverage(&packet);
-    assert_packet_uses_summary_appendix(&packet);
-}
-
-#[test]
-fn collect_json_writes_coverage_first_packet() {
-    let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
-
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert_packet_opens_with_coverage(&packet);
-    assert_packet_uses_summary_appendix(&packet);
 }
 
 #[test]
-fn render_scaffold_mode_writes_prompt_focused_packet() {
+fn share_verify_public_strict_scans_in_memory_without_writing() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "render",
+            "share",
+            "verify",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--mode",
-            "scaffold",
+            "--redact-key",
+            "stable-test-key",
+            "--strict",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Rendered"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert!(packet.contains("## Coverage and Limits"));
-    assert!(packet.contains("**Suggested claim prompts**"));
+    assert!(stdout.contains("Share verify: public"));
+    assert!(stdout.contains("Strict public scan checked in-memory public render"));
     assert!(
-        !packet.contains("\n## Receipts\n"),
-        "scaffold mode should omit the full receipts section"
+        stdout
+            .contains("Strict public scan inspected in-memory public render for obvious raw URLs")
     );
+    assert!(stdout.contains("Strict scan is a guardrail, not a guarantee of perfect privacy."));
+    assert!(stdout.contains("Result: ready to render public share output."));
+    assert!(!stdout.contains("stable-test-key"));
     assert!(
-        !packet.contains("\n## Appendix: All Receipts\n"),
-        "scaffold mode should omit the full appendix"
+        !tmp.path()
+            .join("run_fixture/profiles/public/packet.md")
+            .exists(),
+        "strict public verify should not write the share packet"
     );
 }
 
 #[test]
-fn render_receipts_mode_writes_audit_focused_packet() {
+fn share_verify_public_strict_fails_on_existing_unredacted_packet() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
+    let public_dir = run_dir.join("profiles/public");
+    std::fs::create_dir_all(&public_dir).unwrap();
+    std::fs::write(
+        public_dir.join("packet.md"),
+        "# Public Packet\n\nLeaked https://github.com/acme/platform and acme/platform.\n",
+    )
+    .unwrap();
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "render",
+            "share",
+            "verify",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--mode",
-            "receipts",
+            "--redact-key",
+            "stable-test-key",
+            "--strict",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Rendered"));
+        .failure();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert!(packet.contains("## Coverage and Limits"));
-    assert!(packet.contains("\n## Receipts\n"));
-    assert!(packet.contains("\n## Appendix: All Receipts\n"));
+    assert!(stdout.contains("Share verify: public"));
+    assert!(stdout.contains("Strict public scan: public packet contains raw URL(s)."));
     assert!(
-        !packet.contains("**Suggested claim prompts**"),
-        "receipts mode should omit writing prompts"
+        stdout.contains("Strict public scan: public packet contains an original repository name.")
     );
+    assert!(stdout.contains("Result: review attention items before sharing public output."));
+    assert!(stderr.contains("strict public verification found"));
+    assert!(!stdout.contains("stable-test-key"));
+    assert!(!stderr.contains("stable-test-key"));
 }
 
 #[test]
-fn render_receipt_limit_summary_appendix_preserves_canonical_files() {
+fn share_manager_uses_env_key_without_printing_secret() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
-    let ledger_before = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
+        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
         .args([
-            "render",
+            "share",
+            "manager",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--mode",
-            "packet",
-            "--receipt-limit",
-            "1",
-            "--appendix",
-            "summary",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Rendered"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert!(packet.contains("\n## Receipts\n"));
-    assert!(packet.contains("... and"));
-    assert!(packet.contains("## Appendix: Receipt Summary"));
-    assert!(!packet.contains("## Appendix: All Receipts"));
-    assert!(packet.contains("- Assigned events:"));
-    assert!(packet.contains("- Curated receipt anchors:"));
+    assert!(stdout.contains("Wrote manager share output:"));
+    assert!(stdout.contains("profiles"));
+    assert!(stdout.contains("manager"));
+    assert!(!stdout.contains("stable-env-key"));
+    assert!(
+        tmp.path()
+            .join("run_fixture/profiles/manager/packet.md")
+            .exists(),
+        "manager share packet should be written with SHIPLOG_REDACT_KEY"
+    );
+    let manager_manifest_path = tmp
+        .path()
+        .join("run_fixture/profiles/manager/share.manifest.json");
+    assert!(
+        manager_manifest_path.exists(),
+        "manager share manifest should be written"
+    );
+    let manager_manifest_text = std::fs::read_to_string(&manager_manifest_path).unwrap();
+    assert!(!manager_manifest_text.contains("stable-env-key"));
+    let manager_manifest: serde_json::Value = serde_json::from_str(&manager_manifest_text).unwrap();
+    assert_eq!(manager_manifest["schema_version"], 1);
+    assert_eq!(manager_manifest["profile"], "manager");
+    assert_eq!(manager_manifest["input_run_id"], "run_fixture");
+    assert_eq!(manager_manifest["redaction_key_source"], "env");
+    assert_eq!(manager_manifest["coverage_completeness"], "Complete");
+    assert_eq!(manager_manifest["skipped_source_count"], 0);
     assert_eq!(
-        ledger_before,
-        std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap()
+        manager_manifest["strict_verify_result"]["status"],
+        "not_applicable"
     );
     assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
+        manager_manifest["packet_path"],
+        "profiles/manager/packet.md"
+    );
+    assert!(manager_manifest["zip_path"].is_null());
+    assert_eq!(manager_manifest["checksum"]["algorithm"], "sha256");
+    assert!(
+        manager_manifest["checksum"]["packet_sha256"]
+            .as_str()
+            .is_some_and(|value| value.len() == 64)
+    );
+    assert!(manager_manifest["checksum"]["zip_sha256"].is_null());
+    let manager_packet =
+        std::fs::read_to_string(tmp.path().join("run_fixture/profiles/manager/packet.md")).unwrap();
+    assert!(
+        manager_packet.contains("**Window:** 2025-01-01..2025-04-01"),
+        "share should derive the render window from coverage"
     );
 }
 
 #[test]
-fn render_receipt_limit_zero_appendix_none_keeps_omission_notice() {
+fn bundle_delta_ships_only_files_changed_since_base_manifest() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
-    let ledger_before = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
-    let coverage_before = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+
+    let mut base: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(run_dir.join("bundle.manifest.json")).unwrap(),
+    )
+    .unwrap();
+    for file in base["files"].as_array_mut().unwrap() {
+        if file["path"] == "packet.md" {
+            file["sha256"] = serde_json::Value::String("0".repeat(64));
+        }
+    }
+    let base_path = tmp.path().join("base.manifest.json");
+    std::fs::write(&base_path, serde_json::to_string(&base).unwrap()).unwrap();
 
     shiplog_cmd()
         .args([
-            "render",
+            "bundle",
+            "delta",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--mode",
-            "packet",
-            "--receipt-limit",
-            "0",
-            "--appendix",
-            "none",
+            "--base",
+            base_path.to_str().unwrap(),
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Rendered"));
+        .stdout(predicate::str::contains("0 added, 1 changed, 0 removed"));
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert!(packet.contains("\n## Receipts\n"));
-    assert!(packet.contains("- (none)"));
-    assert!(packet.contains("omitted by appendix settings"));
-    assert!(!packet.contains("## Appendix:"));
-    assert_eq!(
-        ledger_before,
-        std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap()
-    );
-    assert_eq!(
-        coverage_before,
-        std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap()
-    );
+    let archive = tmp.path().join("run_fixture.delta.zip");
+    let zip = zip::ZipArchive::new(std::fs::File::open(&archive).unwrap()).unwrap();
+    let names: Vec<&str> = zip.file_names().collect();
+    assert!(names.contains(&"packet.md"));
+    assert!(names.contains(&"bundle.delta.json"));
+    assert!(!names.contains(&"ledger.events.jsonl"));
+}
+
+#[test]
+fn bundle_diff_reports_events_dropped_since_earlier_run() {
+    let tmp = TempDir::new().unwrap();
+    let run_dir = collect_json_into(tmp.path());
+    let later = tmp.path().join("later");
+    std::fs::create_dir_all(&later).unwrap();
+    for name in ["coverage.manifest.json", "workstreams.suggested.yaml"] {
+        std::fs::copy(run_dir.join(name), later.join(name)).unwrap();
+    }
+    let ledger = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
+    let kept: Vec<&str> = ledger.lines().skip(1).collect();
+    std::fs::write(later.join("ledger.events.jsonl"), kept.join("\n")).unwrap();
+
+    shiplog_cmd()
+        .args([
+            "bundle",
+            "diff",
+            "run_fixture",
+            later.to_str().unwrap(),
+            "--out",
+            tmp.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "# Changes: run_fixture -> run_fixture",
+        ))
+        .stdout(predicate::str::contains("- Added: 0\n- Removed: 1\n"));
+
+    let output = shiplog_cmd()
+        .args([
+            "bundle",
+            "diff",
+            run_dir.to_str().unwrap(),
+            later.to_str().unwrap(),
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(diff["events_removed"].as_array().unwrap().len(), 1);
+    assert!(diff["events_added"].as_array().unwrap().is_empty());
 }
 
 #[test]
-fn render_receipts_mode_honors_receipt_controls() {
+fn bundle_verify_passes_public_zip_and_fails_tampered_run() {
     let tmp = TempDir::new().unwrap();
     let run_dir = collect_json_into(tmp.path());
-
     shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "render",
+            "share",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--mode",
-            "receipts",
-            "--receipt-limit",
-            "1",
-            "--appendix",
-            "full",
+            "--redact-key",
+            "stable-test-key",
+            "--zip",
         ])
         .assert()
+        .success();
+    let zip = tmp.path().join("run_fixture.public.zip");
+
+    shiplog_cmd()
+        .args(["bundle", "verify", zip.to_str().unwrap(), "--leak-check"])
+        .assert()
         .success()
-        .stdout(predicate::str::contains("Rendered"));
+        .stdout(predicate::str::contains("Profile: public"))
+        .stdout(predicate::str::contains("Produced by: shiplog"))
+        .stdout(predicate::str::contains("Redaction: public (rules sha256"))
+        .stdout(predicate::str::contains("Leak check: scanned 1 packet(s)"))
+        .stdout(predicate::str::contains("Result: pass"));
 
-    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
-    assert!(packet.contains("\n## Receipts\n"));
-    assert!(packet.contains("... and"));
-    assert!(packet.contains("## Appendix: All Receipts"));
-    assert!(!packet.contains("**Suggested claim prompts**"));
+    std::fs::write(
+        run_dir.join("profiles/public/packet.md"),
+        "# edited after bundling",
+    )
+    .unwrap();
+    shiplog_cmd()
+        .args(["bundle", "verify", run_dir.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "checksum mismatch: profiles/public/packet.md",
+        ))
+        .stdout(predicate::str::contains("Result: fail"));
 }
 
 #[test]
-fn render_public_profile_without_key_fails_closed() {
+fn bundle_verify_checks_signed_zip_against_expected_signer() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
-
-    let mut cmd = shiplog_cmd();
-    cmd.env_remove("SHIPLOG_REDACT_KEY")
+    let run_dir = collect_json_into(tmp.path());
+    shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
+        .env("SHIPLOG_BUNDLE_SIGNING_KEY", "2a".repeat(32))
         .args([
-            "render",
+            "share",
+            "manager",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--bundle-profile",
-            "public",
+            "--redact-key",
+            "stable-test-key",
+            "--zip",
+        ])
+        .assert()
+        .success();
+    let signature: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(run_dir.join("bundle.manifest.sig.json")).unwrap(),
+    )
+    .unwrap();
+    let public_key = signature["public_key"].as_str().unwrap();
+    let zip = tmp.path().join("run_fixture.manager.zip");
+    std::fs::remove_dir_all(&run_dir).unwrap();
+
+    shiplog_cmd()
+        .args([
+            "bundle",
+            "verify",
+            zip.to_str().unwrap(),
+            "--signer",
+            public_key,
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Signature: valid ({public_key})"
+        )))
+        .stdout(predicate::str::contains("Result: pass"));
+
+    shiplog_cmd()
+        .args([
+            "bundle",
+            "verify",
+            zip.to_str().unwrap(),
+            "--signer",
+            &"0".repeat(64),
         ])
         .assert()
         .failure()
-        .stderr(
-            predicate::str::contains("public profile requires --redact-key or SHIPLOG_REDACT_KEY")
-                .and(predicate::str::contains("Try:"))
-                .and(predicate::str::contains(redaction_key_env_command(
-                    "SHIPLOG_REDACT_KEY",
-                )))
-                .and(predicate::str::contains(
-                    "rerun this command with --bundle-profile public",
-                )),
-        );
+        .stdout(predicate::str::contains("not the expected key"));
 }
 
 #[test]
-fn render_public_profile_with_key_writes_public_packet() {
+fn share_public_with_explicit_key_can_write_zip() {
     let tmp = TempDir::new().unwrap();
     collect_json_into(tmp.path());
 
-    shiplog_cmd()
+    let assert = shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "render",
+            "share",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
-            "--bundle-profile",
-            "public",
             "--redact-key",
             "stable-test-key",
+            "--zip",
         ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Rendered"));
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    let public_packet_path = tmp.path().join("run_fixture/profiles/public/packet.md");
+    assert!(stdout.contains("Wrote public share output:"));
+    assert!(!stdout.contains("stable-test-key"));
     assert!(
-        public_packet_path.exists(),
-        "public packet should be written when a redaction key is provided"
+        tmp.path()
+            .join("run_fixture/profiles/public/packet.md")
+            .exists(),
+        "public share packet should be written with an explicit key"
     );
-
-    let public_packet = std::fs::read_to_string(public_packet_path).unwrap();
     assert!(
-        public_packet.contains("omitted by appendix settings"),
-        "public profile should default to a low-density packet with explicit omission notes"
+        tmp.path().join("run_fixture.public.zip").exists(),
+        "public share zip should be written when --zip is set"
+    );
+    let public_manifest_text = std::fs::read_to_string(
+        tmp.path()
+            .join("run_fixture/profiles/public/share.manifest.json"),
+    )
+    .unwrap();
+    assert!(!public_manifest_text.contains("stable-test-key"));
+    let public_manifest: serde_json::Value = serde_json::from_str(&public_manifest_text).unwrap();
+    assert_eq!(public_manifest["profile"], "public");
+    assert_eq!(public_manifest["redaction_key_source"], "explicit");
+    assert_eq!(public_manifest["strict_verify_result"]["status"], "passed");
+    assert_eq!(
+        public_manifest["strict_verify_result"]["source"],
+        "profiles/public/packet.md"
     );
+    assert_eq!(public_manifest["packet_path"], "profiles/public/packet.md");
+    assert_eq!(public_manifest["zip_path"], "../run_fixture.public.zip");
     assert!(
-        !public_packet.contains("## Appendix:"),
-        "public profile should omit the receipt appendix by default"
+        public_manifest["checksum"]["zip_sha256"]
+            .as_str()
+            .is_some_and(|value| value.len() == 64)
     );
 }
 
 #[test]
-fn share_manager_without_key_fails_closed() {
+fn share_verify_manifest_public_validates_packet_and_zip_receipts() {
     let tmp = TempDir::new().unwrap();
     collect_json_into(tmp.path());
 
-    let mut cmd = shiplog_cmd();
-    cmd.env_remove("SHIPLOG_REDACT_KEY")
+    shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
             "share",
-            "manager",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
             "run_fixture",
+            "--redact-key",
+            "stable-test-key",
+            "--zip",
         ])
         .assert()
-        .failure()
-        .stderr(
-            predicate::str::contains("manager share requires --redact-key or SHIPLOG_REDACT_KEY")
-                .and(predicate::str::contains("Try:"))
-                .and(predicate::str::contains(redaction_key_env_command(
-                    "SHIPLOG_REDACT_KEY",
-                )))
-                .and(predicate::str::contains("shiplog share manager --latest")),
-        );
-
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/manager/packet.md")
-            .exists(),
-        "manager share packet should not be written without a redaction key"
-    );
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/manager/share.manifest.json")
-            .exists(),
-        "manager share manifest should not be written without a redaction key"
-    );
-}
-
-#[test]
-fn share_explain_manager_without_key_reports_block_without_writing() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+        .success();
 
     let assert = shiplog_cmd()
         .env_remove("SHIPLOG_REDACT_KEY")
         .args([
             "share",
-            "explain",
-            "manager",
+            "verify",
+            "manifest",
+            "--profile",
+            "public",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
@@ -16920,1461 +19853,1492 @@ fn share_explain_manager_without_key_reports_block_without_writing() {
         .success();
     let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("Manager profile:"));
-    assert!(stdout.contains("Status: blocked"));
-    assert!(stdout.contains("Redaction key: missing"));
-    assert!(stdout.contains("Included:"));
-    assert!(stdout.contains("- packet readiness and claim candidates"));
-    assert!(stdout.contains("source-backed evidence counts: GitHub"));
-    assert!(stdout.contains("Removed:"));
-    assert!(stdout.contains("- opaque provider identifiers"));
-    assert!(stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY"));
-    assert!(stdout.contains("Needs review:\n- None"));
-    assert!(stdout.contains("Profile packet: not written yet"));
-    assert!(stdout.contains("Share manifest: not written yet"));
-    assert!(stdout.contains("Render when ready:"));
-    assert!(stdout.contains("shiplog share manager --out"));
-    assert!(!stdout.contains("\nNext:\n"));
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/manager/packet.md")
-            .exists(),
-        "manager explain should not render the share packet"
-    );
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/manager/share.manifest.json")
-            .exists(),
-        "manager explain should not write the share manifest"
-    );
-}
-
-#[test]
-fn share_explain_manager_surfaces_packet_evidence_debt_without_writing() -> CliTestResult {
-    let Some(repo) = create_local_git_repo() else {
-        return Ok(());
-    };
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let repo_path = repo.path().display().to_string().replace('\\', "/");
-
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        format!(
-            r#"[defaults]
-window = "year:2025"
-
-[user]
-label = "shiplog test"
-
-[sources.git]
-enabled = true
-repo = "{repo_path}"
-include_merges = false
-
-[sources.manual]
-enabled = true
-events = "./manual_events.yaml"
-user = "shiplog test"
-"#
-        ),
-    )?;
-    std::fs::write(
-        tmp.path().join("manual_events.yaml"),
-        "version: 1\ngenerated_at: 2026-01-01T00:00:00Z\nevents: []\n",
-    )?;
+    assert!(stdout.contains("Share manifest verify: public"));
+    assert!(stdout.contains("Manifest schema v1"));
+    assert!(stdout.contains("Profile matches public"));
+    assert!(stdout.contains("Packet checksum matches profiles/public/packet.md"));
+    assert!(stdout.contains("Zip checksum matches ../run_fixture.public.zip"));
+    assert!(stdout.contains("Public strict result recorded as passed"));
+    assert!(stdout.contains("Result: share manifest verified."));
+    assert!(!stdout.contains("stable-test-key"));
+}
+
+#[test]
+fn share_verify_manifest_detects_packet_checksum_mismatch() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
 
     shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("GITHUB_TOKEN")
-        .env_remove("GITLAB_TOKEN")
-        .env_remove("JIRA_TOKEN")
-        .env_remove("LINEAR_API_KEY")
+        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
         .args([
-            "intake",
+            "share",
+            "manager",
             "--out",
-            out.to_str().unwrap(),
-            "--year",
-            "2025",
-            "--no-open",
-            "--explain",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
         .success();
-
-    let (report_path, mut report) = load_first_intake_report(&out);
-    let evidence_debt_summary = "Manual outcome context is missing.";
-    report["evidence_debt"] = serde_json::json!([
-        {
-            "severity": "info",
-            "kind": "manual-context",
-            "summary": evidence_debt_summary,
-            "detail": "Injected fixture evidence debt",
-            "next_step": "shiplog journal add --date 2025-01-15 --title \"Outcome note\""
-        }
-    ]);
-    report["packet_quality"]["packet_readiness"]["summary"] =
-        serde_json::json!("Ready with caveats.");
     std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report)?),
-    )?;
-
-    let readiness = report["packet_quality"]["packet_readiness"]["summary"]
-        .as_str()
-        .expect("packet readiness summary should be present")
-        .trim_end_matches('.');
+        tmp.path().join("run_fixture/profiles/manager/packet.md"),
+        "# Tampered manager packet\n",
+    )
+    .unwrap();
 
-    let before = file_tree_manifest(tmp.path());
-    let assert = shiplog_cmd()
-        .current_dir(tmp.path())
+    shiplog_cmd()
         .env_remove("SHIPLOG_REDACT_KEY")
         .args([
             "share",
-            "explain",
+            "verify",
+            "manifest",
+            "--profile",
             "manager",
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    let after = file_tree_manifest(tmp.path());
-
-    assert!(
-        stdout.contains(&format!("Packet readiness: {readiness}")),
-        "share explain should surface packet readiness caveats. stdout:\n{stdout}"
-    );
-    assert!(
-        stdout.contains(&format!("Evidence debt: {evidence_debt_summary}")),
-        "share explain should surface report evidence debt before sharing. stdout:\n{stdout}"
-    );
-    assert!(
-        !stdout.contains("Needs review:\n- None"),
-        "share explain should not claim there is nothing to review when evidence debt exists. stdout:\n{stdout}"
-    );
-    assert_eq!(
-        before, after,
-        "share explain should remain read-only while surfacing evidence debt"
-    );
-
-    Ok(())
+        .failure()
+        .stderr(predicate::str::contains("share packet checksum mismatch"));
 }
 
 #[test]
-fn share_explain_manager_surfaces_open_source_repairs_without_writing() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    let out_arg = out.to_string_lossy().to_string();
+fn share_verify_manifest_rejects_profile_mismatch() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
 
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (_, first_report) = load_first_intake_report(&out);
-    let repair_id = first_repair_id_with_action(&first_report, "journal_add");
     shiplog_cmd()
-        .current_dir(tmp.path())
+        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
         .args([
-            "journal",
-            "add",
-            "--from-repair",
-            repair_id.as_str(),
+            "share",
+            "manager",
             "--out",
-            out_arg.as_str(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
         .success();
-    run_intake_without_provider_tokens(tmp.path(), &out);
+    let public_manifest_dir = tmp.path().join("run_fixture/profiles/public");
+    std::fs::create_dir_all(&public_manifest_dir).unwrap();
+    std::fs::copy(
+        tmp.path()
+            .join("run_fixture/profiles/manager/share.manifest.json"),
+        public_manifest_dir.join("share.manifest.json"),
+    )
+    .unwrap();
 
-    let before = file_tree_manifest(tmp.path());
-    let assert = shiplog_cmd()
-        .current_dir(tmp.path())
+    shiplog_cmd()
         .env_remove("SHIPLOG_REDACT_KEY")
         .args([
             "share",
-            "explain",
-            "manager",
+            "verify",
+            "manifest",
+            "--profile",
+            "public",
             "--out",
-            out_arg.as_str(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    let after = file_tree_manifest(tmp.path());
-
-    assert!(
-        stdout.contains("Open source repair:")
-            && stdout.contains("GitHub needs repair: GitHub authentication unavailable"),
-        "share explain should name source repairs that remain open after journal-only repair. stdout:\n{stdout}"
-    );
-    assert!(
-        !stdout.contains("Needs review:\n- None"),
-        "share explain should not claim nothing needs review while source repairs remain open. stdout:\n{stdout}"
-    );
-    assert_eq!(
-        before, after,
-        "share explain should remain read-only while surfacing open source repairs"
-    );
-
-    Ok(())
+        .failure()
+        .stderr(predicate::str::contains(
+            "share manifest profile mismatch: expected public, found manager",
+        ));
 }
 
 #[test]
-fn share_explain_uses_report_receipts_not_packet_markdown() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let run_dir = first_run_dir(&out);
-    let (report_path, mut report) = load_first_intake_report(&out);
-    let report_only_debt = "Report-only share posture debt.";
-    let markdown_only_bait = "Markdown-only share posture bait.";
-    report["evidence_debt"] = serde_json::json!([
-        {
-            "severity": "info",
-            "kind": "manual-context",
-            "summary": report_only_debt,
-            "detail": "Injected report-only receipt",
-            "next_step": "shiplog journal add --date 2025-01-15 --title \"Outcome note\""
-        }
-    ]);
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report)?),
-    )?;
+fn share_verify_manifest_rejects_packet_path_traversal() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
+        .args([
+            "share",
+            "manager",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+        ])
+        .assert()
+        .success();
+    let manifest_path = tmp
+        .path()
+        .join("run_fixture/profiles/manager/share.manifest.json");
+    let mut manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+    manifest["packet_path"] = serde_json::json!("../outside.md");
     std::fs::write(
-        run_dir.join("packet.md"),
-        format!("# Packet Readiness\n\nReady.\n\n{markdown_only_bait}\n"),
-    )?;
+        &manifest_path,
+        format!("{}\n", serde_json::to_string_pretty(&manifest).unwrap()),
+    )
+    .unwrap();
 
-    let before = file_tree_manifest(tmp.path());
-    let assert = shiplog_cmd()
+    shiplog_cmd()
         .env_remove("SHIPLOG_REDACT_KEY")
         .args([
             "share",
-            "explain",
+            "verify",
+            "manifest",
+            "--profile",
             "manager",
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    let after = file_tree_manifest(tmp.path());
+        .failure()
+        .stderr(predicate::str::contains(
+            "share manifest packet_path must not traverse outside the run directory",
+        ));
+}
+
+#[test]
+fn render_public_profile_receipt_options_override_profile_defaults() {
+    let tmp = TempDir::new().unwrap();
+    collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args([
+            "render",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "run_fixture",
+            "--bundle-profile",
+            "public",
+            "--redact-key",
+            "stable-test-key",
+            "--receipt-limit",
+            "3",
+            "--appendix",
+            "summary",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Rendered"));
 
+    let public_packet =
+        std::fs::read_to_string(tmp.path().join("run_fixture/profiles/public/packet.md")).unwrap();
     assert!(
-        stdout.contains(&format!("Evidence debt: {report_only_debt}")),
-        "share explain should use intake.report.json evidence debt. stdout:\n{stdout}"
+        public_packet.contains("## Appendix: Receipt Summary"),
+        "explicit --appendix should override public profile defaults"
     );
     assert!(
-        !stdout.contains(markdown_only_bait),
-        "share explain should not scrape packet.md for share posture. stdout:\n{stdout}"
+        !public_packet.contains("## Appendix: All Receipts"),
+        "summary appendix override should not render full receipt detail"
     );
-    assert_eq!(
-        before, after,
-        "share explain should stay read-only while reading report receipts"
+}
+
+#[test]
+fn render_latest_on_collected_directory() {
+    let tmp = TempDir::new().unwrap();
+    let _run_dir = collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args(["render", "--out", tmp.path().to_str().unwrap(), "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Rendered"));
+
+    assert!(
+        tmp.path().join("run_fixture/packet.md").exists(),
+        "packet.md should exist after render --latest"
     );
+}
 
-    Ok(())
+#[test]
+fn render_run_latest_alias_on_collected_directory() {
+    let tmp = TempDir::new().unwrap();
+    let _run_dir = collect_json_into(tmp.path());
+
+    shiplog_cmd()
+        .args([
+            "render",
+            "--out",
+            tmp.path().to_str().unwrap(),
+            "--run",
+            "latest",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Rendered"));
 }
 
 #[test]
-fn share_explain_public_without_key_reports_public_posture_without_writing() {
+fn render_nonexistent_run_dir_fails() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
 
-    let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+    shiplog_cmd()
         .args([
-            "share",
-            "explain",
-            "public",
+            "render",
             "--out",
             tmp.path().to_str().unwrap(),
             "--run",
-            "run_fixture",
+            "nonexistent_run",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+        .failure();
+}
 
-    assert!(stdout.contains("Public profile:"));
-    assert!(stdout.contains("Status: blocked"));
-    assert!(stdout.contains("Included:"));
-    assert!(stdout.contains("public-safe summaries with the lowest default receipt density"));
-    assert!(stdout.contains("Removed:"));
-    assert!(stdout.contains("raw private URLs and original names where strict redaction applies"));
-    assert!(stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY"));
-    assert!(
-        stdout.contains(
-            "Public profile should be reviewed after rendering; strict scan is a guardrail"
-        )
-    );
-    assert!(stdout.contains("Public profile uses the strictest redaction profile."));
-    assert!(stdout.contains("Render when ready:"));
-    assert!(stdout.contains("shiplog share public --out"));
-    assert!(!stdout.contains("\nNext:\n"));
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/public/packet.md")
-            .exists(),
-        "public explain should not render the share packet"
-    );
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/public/share.manifest.json")
-            .exists(),
-        "public explain should not write the share manifest"
+// ── 7. invalid subcommand returns error ────────────────────────────────────
+
+#[test]
+fn invalid_subcommand_returns_error() {
+    shiplog_cmd()
+        .arg("nonexistent")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unrecognized subcommand"));
+}
+
+#[test]
+fn no_subcommand_shows_home_screen() {
+    shiplog_cmd()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No packet exists yet."))
+        .stdout(predicate::str::contains("Start: shiplog intake"));
+}
+
+// ── 8. missing required args return helpful error messages ─────────────────
+
+#[test]
+fn collect_without_source_fails_with_help() {
+    shiplog_cmd().arg("collect").assert().failure().stderr(
+        predicate::str::contains("github")
+            .or(predicate::str::contains("json"))
+            .or(predicate::str::contains("subcommand")),
     );
 }
 
 #[test]
-fn share_readiness_missing_key_is_consistent_across_doctor_and_share_commands() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    collect_json_into(tmp.path());
-    std::fs::write(
-        tmp.path().join("shiplog.toml"),
-        r#"[shiplog]
-config_version = 1
+fn collect_github_missing_user_fails() {
+    shiplog_cmd()
+        .args([
+            "collect",
+            "github",
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2025-12-31",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("provide --user").or(predicate::str::contains("--me")));
+}
 
-[defaults]
-out = "./out"
-window = "last-6-months"
-profile = "manager"
+#[test]
+fn collect_github_user_and_me_conflict_fails() {
+    shiplog_cmd()
+        .args(["collect", "github", "--user", "octocat", "--me"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("use either --user or --me"));
+}
 
-[sources.json]
-enabled = true
-events = "./run_fixture/ledger.events.jsonl"
-coverage = "./run_fixture/coverage.manifest.json"
-"#,
-    )?;
+#[test]
+fn collect_github_me_without_token_fails_actionably() {
+    shiplog_cmd()
+        .env_remove("GITHUB_TOKEN")
+        .args(["collect", "github", "--me"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Could not infer GitHub user: --me requires --token or GITHUB_TOKEN",
+        ));
+}
 
-    let before = file_tree_manifest(tmp.path());
+#[test]
+fn collect_gitlab_me_without_token_fails_actionably() {
+    shiplog_cmd()
+        .env_remove("GITLAB_TOKEN")
+        .args(["collect", "gitlab", "--me"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Could not infer GitLab user: --me requires --token or GITLAB_TOKEN",
+        ));
+}
 
-    let doctor = shiplog_cmd()
-        .current_dir(tmp.path())
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args(["doctor", "--setup", "--for", "manager-share"])
+#[test]
+fn collect_github_partial_date_window_fails() {
+    shiplog_cmd()
+        .args([
+            "collect",
+            "github",
+            "--user",
+            "octocat",
+            "--until",
+            "2025-12-31",
+        ])
         .assert()
-        .failure();
-    let doctor_stdout = String::from_utf8(doctor.get_output().stdout.clone())?;
-    assert!(
-        doctor_stdout.contains("Manager share")
-            && doctor_stdout.contains("Public share")
-            && doctor_stdout.contains("SHIPLOG_REDACT_KEY not set"),
-        "doctor --setup should report the shared redaction setup blocker. stdout:\n{doctor_stdout}"
-    );
+        .failure()
+        .stderr(
+            predicate::str::contains("provide both --since and --until")
+                .or(predicate::str::contains("error")),
+        );
+}
 
-    let manager_explain = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+#[test]
+fn collect_github_invalid_date_fails() {
+    shiplog_cmd()
         .args([
-            "share",
-            "explain",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            "collect",
+            "github",
+            "--user",
+            "octocat",
+            "--since",
+            "not-a-date",
+            "--until",
+            "2025-12-31",
         ])
         .assert()
-        .success();
-    let manager_explain_stdout = String::from_utf8(manager_explain.get_output().stdout.clone())?;
-    assert!(
-        manager_explain_stdout.contains("Manager profile:")
-            && manager_explain_stdout.contains("Status: blocked")
-            && manager_explain_stdout.contains("Redaction key: missing")
-            && manager_explain_stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY"),
-        "share explain manager should report the same missing-key blocker. stdout:\n{manager_explain_stdout}"
-    );
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
 
-    let public_explain = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+#[test]
+fn collect_json_missing_coverage_fails() {
+    shiplog_cmd()
+        .args(["collect", "json", "--events", "some_file.jsonl"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--coverage").or(predicate::str::contains("required")));
+}
+
+#[test]
+fn collect_json_missing_events_file_fails() {
+    let tmp = TempDir::new().unwrap();
+
+    shiplog_cmd()
         .args([
-            "share",
-            "explain",
-            "public",
+            "collect",
             "--out",
             tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            "json",
+            "--events",
+            "/nonexistent/events.jsonl",
+            "--coverage",
+            "/nonexistent/coverage.json",
         ])
         .assert()
-        .success();
-    let public_explain_stdout = String::from_utf8(public_explain.get_output().stdout.clone())?;
-    assert!(
-        public_explain_stdout.contains("Public profile:")
-            && public_explain_stdout.contains("Status: blocked")
-            && public_explain_stdout.contains("Blocked:\n- missing SHIPLOG_REDACT_KEY")
-            && public_explain_stdout.contains("Public profile should be reviewed after rendering"),
-        "share explain public should report the same missing-key blocker and public review caveat. stdout:\n{public_explain_stdout}"
-    );
+        .failure();
+}
 
-    for profile in ["manager", "public"] {
-        let share_stderr = String::from_utf8(
-            shiplog_cmd()
-                .env_remove("SHIPLOG_REDACT_KEY")
-                .args([
-                    "share",
-                    profile,
-                    "--out",
-                    tmp.path().to_str().unwrap(),
-                    "--run",
-                    "run_fixture",
-                ])
-                .assert()
-                .failure()
-                .get_output()
-                .stderr
-                .clone(),
-        )?;
-        assert!(
-            share_stderr.contains(&format!(
-                "{profile} share requires --redact-key or SHIPLOG_REDACT_KEY"
-            )),
-            "share {profile} should fail closed on the same missing key. stderr:\n{share_stderr}"
+#[test]
+fn render_unknown_flag_fails() {
+    shiplog_cmd()
+        .args(["render", "--bogus-flag"])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("unexpected argument").or(predicate::str::contains("error")),
         );
+}
 
-        let verify_stderr = String::from_utf8(
-            shiplog_cmd()
-                .env_remove("SHIPLOG_REDACT_KEY")
-                .args([
-                    "share",
-                    "verify",
-                    profile,
-                    "--out",
-                    tmp.path().to_str().unwrap(),
-                    "--run",
-                    "run_fixture",
-                ])
-                .assert()
-                .failure()
-                .get_output()
-                .stderr
-                .clone(),
-        )?;
-        assert!(
-            verify_stderr.contains(&format!(
-                "{profile} share requires --redact-key or SHIPLOG_REDACT_KEY"
-            )),
-            "share verify {profile} should fail closed on the same missing key. stderr:\n{verify_stderr}"
-        );
-    }
+// ── 9. import subcommand exists and shows help ─────────────────────────────
 
-    let after = file_tree_manifest(tmp.path());
-    assert_eq!(
-        before, after,
-        "doctor, share explain, share verify, and failed share rendering should not write artifacts while redaction setup is blocked"
-    );
-    assert!(
-        !tmp.path().join("run_fixture/profiles/manager").exists()
-            && !tmp.path().join("run_fixture/profiles/public").exists(),
-        "blocked share readiness checks should not create profile directories"
-    );
+#[test]
+fn import_help_shows_options() {
+    shiplog_cmd()
+        .args(["import", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--dir"))
+        .stdout(predicate::str::contains("--out"))
+        .stdout(predicate::str::contains("--user"));
+}
 
-    Ok(())
+#[test]
+fn import_from_fixture_dir_succeeds() {
+    let tmp = TempDir::new().unwrap();
+    let fixtures = fixture_dir();
+
+    shiplog_cmd()
+        .args([
+            "import",
+            "--dir",
+            fixtures.to_str().unwrap(),
+            "--out",
+            tmp.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Imported"));
+
+    let run_dir = tmp.path().join("run_fixture");
+    assert!(run_dir.join("packet.md").exists());
+    assert!(run_dir.join("ledger.events.jsonl").exists());
+    assert!(run_dir.join("coverage.manifest.json").exists());
 }
 
 #[test]
-fn share_explain_public_surfaces_packet_debt_without_writing() -> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (report_path, mut report) = load_first_intake_report(&out);
-    let evidence_debt_summary = "Public packet needs human outcome context.";
-    report["evidence_debt"] = serde_json::json!([
-        {
-            "severity": "info",
-            "kind": "manual-context",
-            "summary": evidence_debt_summary,
-            "detail": "Injected fixture public-share debt",
-            "next_step": "shiplog journal add --date 2025-01-15 --title \"Outcome note\""
-        }
-    ]);
-    report["packet_quality"]["packet_readiness"]["summary"] =
-        serde_json::json!("Ready with caveats.");
-    std::fs::write(
-        &report_path,
-        format!("{}\n", serde_json::to_string_pretty(&report)?),
-    )?;
+fn import_missing_dir_fails() {
+    let tmp = TempDir::new().unwrap();
 
-    let before = file_tree_manifest(tmp.path());
-    let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+    shiplog_cmd()
         .args([
-            "share",
-            "explain",
-            "public",
+            "import",
+            "--dir",
+            tmp.path().join("nonexistent").to_str().unwrap(),
             "--out",
-            out.to_str().unwrap(),
-            "--latest",
+            tmp.path().to_str().unwrap(),
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    let after = file_tree_manifest(tmp.path());
+        .failure();
+}
 
-    assert!(
-        stdout.contains("Packet readiness: Ready with caveats"),
-        "public share explain should surface packet readiness caveats. stdout:\n{stdout}"
-    );
-    assert!(
-        stdout.contains(&format!("Evidence debt: {evidence_debt_summary}")),
-        "public share explain should surface packet evidence debt. stdout:\n{stdout}"
-    );
-    assert!(
-        stdout.contains(
-            "Public profile should be reviewed after rendering; strict scan is a guardrail"
-        ),
-        "public share explain should keep strict-review caveats visible. stdout:\n{stdout}"
-    );
-    assert!(
-        stdout.contains("shiplog share verify public")
-            && stdout.contains("--strict")
-            && stdout.contains("Render when ready:"),
-        "public share explain should route rendering through strict verification. stdout:\n{stdout}"
-    );
-    assert_eq!(
-        before, after,
-        "public share explain should remain read-only while surfacing packet debt"
-    );
+// ── additional subcommand help checks ──────────────────────────────────────
 
-    Ok(())
+#[test]
+fn refresh_help_shows_options() {
+    shiplog_cmd()
+        .args(["refresh", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("github"))
+        .stdout(predicate::str::contains("multi").not())
+        .stdout(predicate::str::contains("--out"))
+        .stdout(predicate::str::contains("--run-dir"));
 }
 
 #[test]
-fn share_explain_legacy_report_without_packet_quality_prompts_rerun_without_writing()
--> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (report_path, _) = load_first_intake_report(&out);
-    remove_packet_quality_from_report(&report_path)?;
+fn run_help_shows_options() {
+    shiplog_cmd()
+        .args(["run", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("github"))
+        .stdout(predicate::str::contains("multi").not())
+        .stdout(predicate::str::contains("--out"));
+}
 
-    let before = file_tree_manifest(tmp.path());
-    for profile in ["manager", "public"] {
-        let assert = shiplog_cmd()
-            .env_remove("SHIPLOG_REDACT_KEY")
-            .args([
-                "share",
-                "explain",
-                profile,
-                "--out",
-                out.to_str().unwrap(),
-                "--latest",
-            ])
-            .assert()
-            .success();
-        let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
+fn write_scheduled_manual_config(dir: &Path, defaults: &str) -> PathBuf {
+    write_manual_events(&dir.join("manual_events.yaml"));
+    let config = dir.join("shiplog.toml");
+    std::fs::write(
+        &config,
+        format!(
+            r#"[defaults]
+window = "year:2025"
+{defaults}
 
-        assert!(
-            stdout.contains("Packet quality unavailable: rerun intake for review-ready signals."),
-            "share explain {profile} should prompt rerun for legacy reports. stdout:\n{stdout}"
-        );
-        assert!(
-            !stdout.contains("Packet readiness: Ready")
-                && !stdout.contains("Packet readiness: Ready for review"),
-            "share explain {profile} should not invent readiness for legacy reports. stdout:\n{stdout}"
-        );
-    }
-    let after = file_tree_manifest(tmp.path());
-    assert_eq!(
-        before, after,
-        "share explain should not write while explaining legacy report quality gaps"
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+user = "octo"
+"#
+        ),
+    )
+    .unwrap();
+    config
+}
+
+#[test]
+fn schedule_install_writes_systemd_units_for_collect_multi() {
+    let tmp = TempDir::new().unwrap();
+    write_scheduled_manual_config(
+        tmp.path(),
+        "timezone = \"America/New_York\"\nschedule = \"0 9 * * MON\"",
     );
+    let units = tmp.path().join("units");
 
-    Ok(())
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["schedule", "install", "--format", "systemd", "--dir"])
+        .arg(&units)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("shiplog-collect.timer"))
+        .stdout(predicate::str::contains(
+            "systemctl --user enable --now shiplog-collect.timer",
+        ));
+
+    let service = std::fs::read_to_string(units.join("shiplog-collect.service")).unwrap();
+    let timer = std::fs::read_to_string(units.join("shiplog-collect.timer")).unwrap();
+    assert!(service.contains(" collect multi --config "));
+    assert!(service.contains("shiplog.toml\n"));
+    assert!(timer.contains("OnCalendar=Mon *-*-* 09:00:00 America/New_York\n"));
 }
 
 #[test]
-fn share_explain_legacy_packet_quality_without_candidates_or_share_posture_prompts_rerun_without_writing()
--> CliTestResult {
-    let tmp = TempDir::new()?;
-    let out = tmp.path().join("out");
-    run_intake_without_provider_tokens(tmp.path(), &out);
-    let (report_path, _) = load_first_intake_report(&out);
-    remove_packet_quality_fields_from_report(&report_path, &["claim_candidates", "share_posture"])?;
+fn schedule_install_prints_launchd_plist() {
+    let tmp = TempDir::new().unwrap();
+    write_scheduled_manual_config(tmp.path(), "schedule = \"15 7 * * *\"");
 
-    let before = file_tree_manifest(tmp.path());
-    let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "share",
-            "explain",
-            "manager",
-            "--out",
-            out.to_str().unwrap(),
-            "--latest",
-        ])
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["schedule", "install", "--format", "launchd", "--print"])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone())?;
-    let after = file_tree_manifest(tmp.path());
+        .success()
+        .stdout(predicate::str::contains(
+            "# com.effortlessmetrics.shiplog.collect.plist",
+        ))
+        .stdout(predicate::str::contains(
+            "<key>Hour</key>\n      <integer>7</integer>\n      <key>Minute</key>\n      <integer>15</integer>",
+        ));
+    assert!(!tmp.path().join("units").exists());
+}
 
-    assert!(
-        stdout.contains(
-            "Packet quality incomplete: rerun intake for claim candidates, share posture signals."
-        ),
-        "share explain should prompt rerun for partial legacy packet_quality. stdout:\n{stdout}"
-    );
-    assert_eq!(
-        before, after,
-        "share explain should not write while explaining partial legacy packet_quality"
+#[test]
+fn schedule_next_lists_upcoming_runs_in_the_reporting_timezone() {
+    let tmp = TempDir::new().unwrap();
+    write_scheduled_manual_config(
+        tmp.path(),
+        "timezone = \"Asia/Tokyo\"\nschedule = \"0 9 * * MON\"",
     );
 
-    Ok(())
+    let output = shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["schedule", "next", "--count", "2"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+
+    assert!(stdout.contains("Schedule: `0 9 * * MON` (Asia/Tokyo)"));
+    assert_eq!(stdout.matches("- Mon ").count(), 2);
+    assert_eq!(stdout.matches(" 09:00 +09:00").count(), 2);
 }
 
 #[test]
-fn share_explain_latest_profiles_are_read_only() {
+fn daemon_requires_a_configured_schedule() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
-    let before = file_tree_manifest(tmp.path());
-
-    for profile in ["manager", "public"] {
-        let assert = shiplog_cmd()
-            .env_remove("SHIPLOG_REDACT_KEY")
-            .args([
-                "share",
-                "explain",
-                profile,
-                "--out",
-                tmp.path().to_str().unwrap(),
-                "--latest",
-            ])
-            .assert()
-            .success();
-        let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-        assert!(
-            stdout.contains("Profile packet: not written yet")
-                && stdout.contains("Share manifest: not written yet")
-                && stdout.contains(&redaction_key_env_command("SHIPLOG_REDACT_KEY")),
-            "share explain {profile} should describe existing share artifacts without rendering. stdout:\n{stdout}"
-        );
-    }
+    write_scheduled_manual_config(tmp.path(), "");
 
-    let after = file_tree_manifest(tmp.path());
-    assert_eq!(
-        before, after,
-        "share explain --latest should not write or rewrite files"
-    );
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .arg("daemon")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("has no [defaults] schedule"));
 }
 
 #[test]
-fn share_verify_manager_without_key_fails_closed_without_writing() {
+fn config_validate_rejects_invalid_schedule() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    write_scheduled_manual_config(tmp.path(), "schedule = \"0 25 * * *\"");
 
     shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "share",
-            "verify",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
+        .current_dir(tmp.path())
+        .args(["config", "validate"])
         .assert()
         .failure()
-        .stderr(
-            predicate::str::contains("manager share requires --redact-key or SHIPLOG_REDACT_KEY")
-                .and(predicate::str::contains("shiplog share manager --latest")),
-        );
-
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/manager/packet.md")
-            .exists(),
-        "manager verify should not write a share packet without a redaction key"
-    );
+        .stdout(predicate::str::contains("Schedule: error"))
+        .stdout(predicate::str::contains("hour value `25` is outside 0-23"));
 }
 
 #[test]
-fn share_verify_manager_uses_env_key_without_writing_or_printing_secret() {
+fn generate_fixture_writes_a_ledger_that_collect_json_renders() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let fixture = tmp.path().join("demo");
+    let out = tmp.path().join("out");
 
-    let assert = shiplog_cmd()
-        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
+    shiplog_cmd()
         .args([
-            "share",
-            "verify",
-            "manager",
+            "generate-fixture",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            fixture.to_str().unwrap(),
+            "--events",
+            "120",
+            "--repos",
+            "3",
+            "--since",
+            "2026-01-01",
+            "--until",
+            "2026-04-01",
+            "--mix",
+            "pr=3,review=1",
+            "--dates",
+            "bursty",
+            "--incomplete-months",
+            "1",
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-
-    assert!(stdout.contains("Share verify: manager"));
-    assert!(stdout.contains("Good:"));
-    assert!(stdout.contains("- Redaction key found"));
-    assert!(stdout.contains("Needs attention:\n- None"));
-    assert!(stdout.contains("- Packet: not written yet; share can render it."));
-    assert!(stdout.contains("Workstreams:"));
-    assert!(stdout.contains("Coverage:"));
-    assert!(stdout.contains("- Status: Complete"));
-    assert!(stdout.contains("- Gaps: 0"));
-    assert!(stdout.contains("Skipped sources:\n- None"));
-    assert!(stdout.contains("Result: ready to render manager share output."));
-    assert!(stdout.contains("shiplog share manager --out"));
-    assert!(!stdout.contains("stable-env-key"));
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/manager/packet.md")
-            .exists(),
-        "manager verify should not render the share packet"
-    );
-}
-
-#[test]
-fn share_verify_public_accepts_explicit_key_without_writing() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+        .success()
+        .stdout(predicate::str::contains(
+            "Wrote 120 synthetic events across 3 repositories",
+        ))
+        .stdout(predicate::str::contains("Coverage: Partial"))
+        .stdout(predicate::str::contains("shiplog collect json --events"));
 
-    let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+    shiplog_cmd()
         .args([
-            "share",
-            "verify",
-            "public",
+            "collect",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--redact-key",
-            "stable-test-key",
+            out.to_str().unwrap(),
+            "json",
+            "--events",
+            fixture.join("ledger.events.jsonl").to_str().unwrap(),
+            "--coverage",
+            fixture.join("coverage.manifest.json").to_str().unwrap(),
         ])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let run_dir = first_run_dir(&out);
+    assert_ledger_event_count(&run_dir, 120);
+    assert!(run_dir.join("packet.md").exists());
 
-    assert!(stdout.contains("Share verify: public"));
-    assert!(stdout.contains("Public profile will use the strictest redaction profile."));
-    assert!(stdout.contains("shiplog share public --out"));
-    assert!(!stdout.contains("stable-test-key"));
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/public/packet.md")
-            .exists(),
-        "public verify should not render the share packet"
-    );
+    shiplog_cmd()
+        .args(["generate-fixture", "--mix", "commits=1"])
+        .current_dir(tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown event kind `commits`"));
 }
 
 #[test]
-fn share_verify_public_strict_scans_in_memory_without_writing() {
+fn collect_search_index_lets_search_rank_events_by_terms() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
-
-    let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+    let fixtures = fixture_dir();
+    shiplog_cmd()
         .args([
-            "share",
-            "verify",
-            "public",
+            "collect",
             "--out",
             tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--redact-key",
-            "stable-test-key",
-            "--strict",
+            "--search-index",
+            "json",
+            "--events",
+            fixtures.join("ledger.events.jsonl").to_str().unwrap(),
+            "--coverage",
+            fixtures.join("coverage.manifest.json").to_str().unwrap(),
         ])
         .assert()
-        .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+        .success()
+        .stdout(predicate::str::contains("search.index.sqlite"));
+    let run_dir = tmp.path().join("run_fixture");
+    let index = run_dir.join("search.index.sqlite");
+    assert!(index.exists());
 
-    assert!(stdout.contains("Share verify: public"));
-    assert!(stdout.contains("Strict public scan checked in-memory public render"));
-    assert!(
-        stdout
-            .contains("Strict public scan inspected in-memory public render for obvious raw URLs")
-    );
-    assert!(stdout.contains("Strict scan is a guardrail, not a guarantee of perfect privacy."));
-    assert!(stdout.contains("Result: ready to render public share output."));
-    assert!(!stdout.contains("stable-test-key"));
-    assert!(
-        !tmp.path()
-            .join("run_fixture/profiles/public/packet.md")
-            .exists(),
-        "strict public verify should not write the share packet"
-    );
+    shiplog_cmd()
+        .args(["search", "ledger", "rewrite", "--out"])
+        .arg(tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 match for \"ledger rewrite\""))
+        .stdout(predicate::str::contains(
+            "PullRequest acme/payments: Payments ledger rewrite",
+        ))
+        .stdout(predicate::str::contains(
+            "https://api.github.com/repos/acme/payments/pulls/42",
+        ))
+        .stderr(predicate::str::contains("Indexed").not());
+
+    let output = shiplog_cmd()
+        .args(["search", "audit", "--json", "--out"])
+        .arg(tmp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let hits: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(hits.as_array().unwrap().len(), 1);
+    assert_eq!(hits[0]["title"], "Schema hardening for audit exports");
+
+    shiplog_cmd()
+        .args(["search", "kubernetes", "--out"])
+        .arg(tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No events match \"kubernetes\""));
+
+    std::fs::remove_file(&index).unwrap();
+    shiplog_cmd()
+        .args(["search", "flake*", "--out"])
+        .arg(tmp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Indexed 3 events"))
+        .stdout(predicate::str::contains(
+            "CI stabilization (flake reduction)",
+        ));
+
+    shiplog_cmd()
+        .args(["render", "--out"])
+        .arg(tmp.path())
+        .assert()
+        .success();
+    let manifest = std::fs::read_to_string(run_dir.join("bundle.manifest.json")).unwrap();
+    assert!(!manifest.contains("search.index.sqlite"));
 }
 
 #[test]
-fn share_verify_public_strict_fails_on_existing_unredacted_packet() {
+fn collect_manual_copies_evidence_files_into_the_run_and_links_them() {
     let tmp = TempDir::new().unwrap();
-    let run_dir = collect_json_into(tmp.path());
-    let public_dir = run_dir.join("profiles/public");
-    std::fs::create_dir_all(&public_dir).unwrap();
+    std::fs::create_dir(tmp.path().join("shots")).unwrap();
+    std::fs::write(tmp.path().join("shots/error-budget.png"), "hello world").unwrap();
+    let manual_events = tmp.path().join("manual_events.yaml");
     std::fs::write(
-        public_dir.join("packet.md"),
-        "# Public Packet\n\nLeaked https://github.com/acme/platform and acme/platform.\n",
+        &manual_events,
+        r#"version: 1
+generated_at: 2026-01-01T00:00:00Z
+events:
+  - id: slo-review
+    type: Note
+    date: 2025-02-15
+    title: SLO review
+    tags: []
+    receipts: []
+    evidence:
+      - path: shots/error-budget.png
+        label: error budget dashboard
+      - path: shots/missing.pdf
+"#,
     )
     .unwrap();
 
-    let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+    shiplog_cmd()
+        .args(["collect", "--out"])
+        .arg(tmp.path().join("out"))
+        .args(["manual", "--events"])
+        .arg(&manual_events)
         .args([
-            "share",
-            "verify",
-            "public",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--redact-key",
-            "stable-test-key",
-            "--strict",
+            "--user",
+            "octo",
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2025-04-01",
         ])
         .assert()
-        .failure();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
-    let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        .success();
+    let run_dir = first_run_dir(&tmp.path().join("out"));
 
-    assert!(stdout.contains("Share verify: public"));
-    assert!(stdout.contains("Strict public scan: public packet contains raw URL(s)."));
-    assert!(
-        stdout.contains("Strict public scan: public packet contains an original repository name.")
+    let staged = "evidence/b94d27b9934d-error-budget.png";
+    assert_eq!(
+        std::fs::read_to_string(run_dir.join(staged)).unwrap(),
+        "hello world"
     );
-    assert!(stdout.contains("Result: review attention items before sharing public output."));
-    assert!(stderr.contains("strict public verification found"));
-    assert!(!stdout.contains("stable-test-key"));
-    assert!(!stderr.contains("stable-test-key"));
+    let ledger = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
+    assert!(ledger.contains(&format!("\"path\":\"{staged}\"")));
+    assert!(!ledger.contains("shots/"));
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains(&format!("[error budget dashboard]({staged})")));
+    let manifest = std::fs::read_to_string(run_dir.join("bundle.manifest.json")).unwrap();
+    assert!(manifest.contains(staged));
+    let coverage = std::fs::read_to_string(run_dir.join("coverage.manifest.json")).unwrap();
+    assert!(coverage.contains("evidence file not found"));
 }
 
 #[test]
-fn share_manager_uses_env_key_without_printing_secret() {
+fn collect_manual_stages_evidence_from_a_source_dir_named_evidence() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    std::fs::create_dir(tmp.path().join("evidence")).unwrap();
+    std::fs::write(tmp.path().join("evidence/shot.png"), "hello world").unwrap();
+    std::fs::write(
+        tmp.path().join("manual_events.yaml"),
+        r#"version: 1
+generated_at: 2026-01-01T00:00:00Z
+events:
+  - id: slo-review
+    type: Note
+    date: 2025-02-15
+    title: SLO review
+    tags: []
+    receipts: []
+    evidence:
+      - path: evidence/shot.png
+        label: shot
+"#,
+    )
+    .unwrap();
 
-    let assert = shiplog_cmd()
-        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
+    shiplog_cmd()
+        .current_dir(tmp.path())
+        .args(["collect", "--out", "out", "manual", "--events"])
+        .arg("manual_events.yaml")
         .args([
-            "share",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            "--user",
+            "octo",
+            "--since",
+            "2025-01-01",
+            "--until",
+            "2025-04-01",
         ])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let run_dir = first_run_dir(&tmp.path().join("out"));
 
-    assert!(stdout.contains("Wrote manager share output:"));
-    assert!(stdout.contains("profiles"));
-    assert!(stdout.contains("manager"));
-    assert!(!stdout.contains("stable-env-key"));
-    assert!(
-        tmp.path()
-            .join("run_fixture/profiles/manager/packet.md")
-            .exists(),
-        "manager share packet should be written with SHIPLOG_REDACT_KEY"
-    );
-    let manager_manifest_path = tmp
-        .path()
-        .join("run_fixture/profiles/manager/share.manifest.json");
-    assert!(
-        manager_manifest_path.exists(),
-        "manager share manifest should be written"
-    );
-    let manager_manifest_text = std::fs::read_to_string(&manager_manifest_path).unwrap();
-    assert!(!manager_manifest_text.contains("stable-env-key"));
-    let manager_manifest: serde_json::Value = serde_json::from_str(&manager_manifest_text).unwrap();
-    assert_eq!(manager_manifest["schema_version"], 1);
-    assert_eq!(manager_manifest["profile"], "manager");
-    assert_eq!(manager_manifest["input_run_id"], "run_fixture");
-    assert_eq!(manager_manifest["redaction_key_source"], "env");
-    assert_eq!(manager_manifest["coverage_completeness"], "Complete");
-    assert_eq!(manager_manifest["skipped_source_count"], 0);
-    assert_eq!(
-        manager_manifest["strict_verify_result"]["status"],
-        "not_applicable"
-    );
+    let staged = "evidence/b94d27b9934d-shot.png";
     assert_eq!(
-        manager_manifest["packet_path"],
-        "profiles/manager/packet.md"
+        std::fs::read_to_string(run_dir.join(staged)).unwrap(),
+        "hello world"
     );
-    assert!(manager_manifest["zip_path"].is_null());
-    assert_eq!(manager_manifest["checksum"]["algorithm"], "sha256");
-    assert!(
-        manager_manifest["checksum"]["packet_sha256"]
-            .as_str()
-            .is_some_and(|value| value.len() == 64)
-    );
-    assert!(manager_manifest["checksum"]["zip_sha256"].is_null());
-    let manager_packet =
-        std::fs::read_to_string(tmp.path().join("run_fixture/profiles/manager/packet.md")).unwrap();
+    let ledger = std::fs::read_to_string(run_dir.join("ledger.events.jsonl")).unwrap();
     assert!(
-        manager_packet.contains("**Window:** 2025-01-01..2025-04-01"),
-        "share should derive the render window from coverage"
+        ledger.contains(&format!("\"path\":\"{staged}\"")),
+        "{ledger}"
     );
+    assert!(ledger.contains(
+        "\"sha256\":\"b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9\""
+    ));
 }
 
 #[test]
-fn share_public_with_explicit_key_can_write_zip() {
+fn render_heatmap_adds_activity_grid_after_summary() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let run_dir = collect_json_into(tmp.path());
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(!packet_before.contains("## Activity"));
 
-    let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "share",
-            "public",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--redact-key",
-            "stable-test-key",
-            "--zip",
-        ])
+    shiplog_cmd()
+        .args(["render", "--out"])
+        .arg(tmp.path())
+        .args(["--run", "run_fixture", "--heatmap"])
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("Wrote public share output:"));
-    assert!(!stdout.contains("stable-test-key"));
-    assert!(
-        tmp.path()
-            .join("run_fixture/profiles/public/packet.md")
-            .exists(),
-        "public share packet should be written with an explicit key"
-    );
-    assert!(
-        tmp.path().join("run_fixture.public.zip").exists(),
-        "public share zip should be written when --zip is set"
-    );
-    let public_manifest_text = std::fs::read_to_string(
-        tmp.path()
-            .join("run_fixture/profiles/public/share.manifest.json"),
-    )
-    .unwrap();
-    assert!(!public_manifest_text.contains("stable-test-key"));
-    let public_manifest: serde_json::Value = serde_json::from_str(&public_manifest_text).unwrap();
-    assert_eq!(public_manifest["profile"], "public");
-    assert_eq!(public_manifest["redaction_key_source"], "explicit");
-    assert_eq!(public_manifest["strict_verify_result"]["status"], "passed");
-    assert_eq!(
-        public_manifest["strict_verify_result"]["source"],
-        "profiles/public/packet.md"
-    );
-    assert_eq!(public_manifest["packet_path"], "profiles/public/packet.md");
-    assert_eq!(public_manifest["zip_path"], "../run_fixture.public.zip");
-    assert!(
-        public_manifest["checksum"]["zip_sha256"]
-            .as_str()
-            .is_some_and(|value| value.len() == 64)
-    );
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let activity = packet.find("## Activity\n").expect("activity section");
+    assert!(packet.find("# Summary\n").unwrap() < activity);
+    assert!(activity < packet.find("## Workstreams").unwrap());
+    assert!(packet.contains("| Week of | Mon | Tue | Wed | Thu | Fri | Sat | Sun | Events |"));
+    assert!(packet.contains("Busiest day:"));
 }
 
 #[test]
-fn share_verify_manifest_public_validates_packet_and_zip_receipts() {
+fn render_min_pr_size_hides_small_prs_from_receipts() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let run_dir = collect_json_into(tmp.path());
+    let packet_before = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet_before.contains("**PR sizes:** XS 0, S 0, M 0, L 1, XL 1\n"));
 
     shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "share",
-            "public",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--redact-key",
-            "stable-test-key",
-            "--zip",
-        ])
+        .args(["render", "--out"])
+        .arg(tmp.path())
+        .args(["--run", "run_fixture", "--min-pr-size", "xl"])
         .assert()
         .success();
 
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    let receipts = packet
+        .split("\n## Receipts")
+        .nth(1)
+        .expect("receipts section");
+    let receipts = &receipts[..receipts.find("\n## ").unwrap()];
+    assert!(receipts.contains("Payments ledger rewrite"));
+    assert!(!receipts.contains("Schema hardening for audit exports"));
+    assert!(receipts.contains("*1 PR smaller than XL not shown*"));
+}
+
+#[test]
+fn log_format_json_writes_structured_progress_to_stderr() {
+    let tmp = TempDir::new().unwrap();
+    let fixtures = fixture_dir();
     let assert = shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "share",
-            "verify",
-            "manifest",
-            "--profile",
-            "public",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
+        .env_remove("SHIPLOG_LOG")
+        .args(["--log-format", "json", "collect", "--out"])
+        .arg(tmp.path())
+        .args(["json", "--events"])
+        .arg(fixtures.join("ledger.events.jsonl"))
+        .arg("--coverage")
+        .arg(fixtures.join("coverage.manifest.json"))
         .assert()
         .success();
-    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
 
-    assert!(stdout.contains("Share manifest verify: public"));
-    assert!(stdout.contains("Manifest schema v1"));
-    assert!(stdout.contains("Profile matches public"));
-    assert!(stdout.contains("Packet checksum matches profiles/public/packet.md"));
-    assert!(stdout.contains("Zip checksum matches ../run_fixture.public.zip"));
-    assert!(stdout.contains("Public strict result recorded as passed"));
-    assert!(stdout.contains("Result: share manifest verified."));
-    assert!(!stdout.contains("stable-test-key"));
+    let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+    let lines: Vec<serde_json::Value> = stderr
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each stderr line is JSON"))
+        .collect();
+    let done = lines
+        .iter()
+        .find(|line| line["fields"]["message"] == "wrote run outputs")
+        .expect("pipeline progress event");
+    assert_eq!(done["level"], "INFO");
+    assert_eq!(done["span"]["name"], "pipeline");
+    assert_eq!(done["span"]["mode"], "run");
+
+    // The default format stays quiet below warnings.
+    let tmp = TempDir::new().unwrap();
+    shiplog_cmd()
+        .env_remove("SHIPLOG_LOG")
+        .args(["collect", "--out"])
+        .arg(tmp.path())
+        .args(["json", "--events"])
+        .arg(fixtures.join("ledger.events.jsonl"))
+        .arg("--coverage")
+        .arg(fixtures.join("coverage.manifest.json"))
+        .assert()
+        .success()
+        .stderr("");
+}
+
+#[test]
+fn collect_writes_run_timings_and_prints_table_on_request() {
+    let tmp = TempDir::new().unwrap();
+    let fixtures = fixture_dir();
+    shiplog_cmd()
+        .args(["--timings", "collect", "--out"])
+        .arg(tmp.path())
+        .args(["json", "--events"])
+        .arg(fixtures.join("ledger.events.jsonl"))
+        .arg("--coverage")
+        .arg(fixtures.join("coverage.manifest.json"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Timings:\n"))
+        .stdout(predicate::str::contains("  ingest   json"));
+
+    let timings: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(tmp.path().join("run_fixture/run.timings.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(timings["schema_version"], 1);
+    assert_eq!(timings["run_id"], "run_fixture");
+    assert!(timings["total_ms"].is_u64());
+    let stages: Vec<(&str, Option<&str>)> = timings["stages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|stage| (stage["stage"].as_str().unwrap(), stage["source"].as_str()))
+        .collect();
+    assert_eq!(
+        stages,
+        vec![
+            ("ingest", Some("json")),
+            ("cluster", None),
+            ("render", None),
+            ("bundle", None),
+        ]
+    );
 }
 
 #[test]
-fn share_verify_manifest_detects_packet_checksum_mismatch() {
+fn backfill_collects_quarters_checkpoints_and_merges() {
     let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
-
-    shiplog_cmd()
-        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
-        .args([
-            "share",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
-        .assert()
-        .success();
+    let out = tmp.path().join("out");
+    write_manual_events(&tmp.path().join("manual_events.yaml"));
+    let config = tmp.path().join("shiplog.toml");
     std::fs::write(
-        tmp.path().join("run_fixture/profiles/manager/packet.md"),
-        "# Tampered manager packet\n",
+        &config,
+        r#"[user]
+label = "octo"
+
+[sources.manual]
+enabled = true
+events = "./manual_events.yaml"
+"#,
     )
     .unwrap();
+    let backfill = |resume: bool| {
+        let mut cmd = shiplog_cmd();
+        cmd.args(["backfill", "--config"])
+            .arg(&config)
+            .arg("--out")
+            .arg(&out)
+            .args(["--since", "2025-01-01", "--until", "2025-07-01"]);
+        if resume {
+            cmd.arg("--resume");
+        }
+        cmd.assert().success()
+    };
 
-    shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
-        .args([
-            "share",
-            "verify",
-            "manifest",
-            "--profile",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("share packet checksum mismatch"));
+    backfill(false)
+        .stdout(predicate::str::contains(
+            "Backfilling 2025-01-01..2025-07-01 in 2 quarter chunk(s):",
+        ))
+        .stdout(predicate::str::contains("- 2025-Q1: 1 event, "))
+        .stdout(predicate::str::contains("- 2025-Q2: 0 events, "))
+        .stdout(predicate::str::contains("Merged historical run:"));
+
+    let read_index = || -> serde_json::Value {
+        serde_json::from_str(&std::fs::read_to_string(out.join("backfill.index.json")).unwrap())
+            .unwrap()
+    };
+    let index = read_index();
+    assert_eq!(index["schema_version"], "backfill.index.v1");
+    assert_eq!(index["state"], "completed");
+    let chunks = index["chunks"].as_array().unwrap();
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks.iter().all(|chunk| chunk["state"] == "completed"));
+    assert_eq!(chunks[0]["since"], "2025-01-01");
+    assert_eq!(chunks[0]["until"], "2025-04-01");
+    assert_eq!(chunks[0]["events"], 1);
+    assert_eq!(index["merged"]["events"], 1);
+    let merged_dir = out.join(index["merged"]["run_id"].as_str().unwrap());
+    let coverage: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(merged_dir.join("coverage.manifest.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(coverage["window"]["since"], "2025-01-01");
+    assert_eq!(coverage["window"]["until"], "2025-07-01");
+    let ledger = std::fs::read_to_string(merged_dir.join("ledger.events.jsonl")).unwrap();
+    assert!(ledger.contains("Manual incident follow-up"));
+
+    let runs_before = std::fs::read_dir(&out).unwrap().count();
+    backfill(true)
+        .stdout(predicate::str::contains("- 2025-Q1: already collected"))
+        .stdout(predicate::str::contains("- 2025-Q2: already collected"));
+    assert_eq!(std::fs::read_dir(&out).unwrap().count(), runs_before);
+    assert_eq!(read_index()["merged"], index["merged"]);
 }
 
 #[test]
-fn share_verify_manifest_rejects_profile_mismatch() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+fn backfill_uses_configured_workstream_clusterer() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let out = tmp.path().join("out");
+    let fixtures = fixture_dir();
+    let config = tmp.path().join("shiplog.toml");
+    std::fs::write(
+        &config,
+        format!(
+            r#"[shiplog]
+config_version = 1
+
+[workstreams]
+clusterer = "label"
+
+[sources.json]
+enabled = true
+events = "{}"
+coverage = "{}"
+"#,
+            fixtures.join("ledger.events.jsonl").display(),
+            fixtures.join("coverage.manifest.json").display()
+        ),
+    )?;
 
     shiplog_cmd()
-        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
-        .args([
-            "share",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-        ])
+        .args(["backfill", "--config"])
+        .arg(&config)
+        .arg("--out")
+        .arg(&out)
+        .args(["--since", "2025-01-01", "--until", "2026-01-01"])
         .assert()
         .success();
-    let public_manifest_dir = tmp.path().join("run_fixture/profiles/public");
-    std::fs::create_dir_all(&public_manifest_dir).unwrap();
-    std::fs::copy(
-        tmp.path()
-            .join("run_fixture/profiles/manager/share.manifest.json"),
-        public_manifest_dir.join("share.manifest.json"),
-    )
-    .unwrap();
 
+    let index: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(out.join("backfill.index.json"))?)?;
+    let merged_dir = out.join(index["merged"]["run_id"].as_str().unwrap());
+    let suggested = std::fs::read_to_string(merged_dir.join("workstreams.suggested.yaml"))?;
+    let workstreams: serde_yaml::Value = serde_yaml::from_str(&suggested)?;
+    let titles: Vec<&str> = workstreams["workstreams"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|ws| ws["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["compliance", "migration", "reliability"]);
+    Ok(())
+}
+
+#[test]
+fn add_template_requires_fields_and_scaffold_appends_skeletons() -> CliTestResult {
+    let tmp = TempDir::new()?;
     shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+        .current_dir(tmp.path())
         .args([
-            "share",
-            "verify",
-            "manifest",
-            "--profile",
-            "public",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            "add",
+            "Mitigated the checkout outage",
+            "--date",
+            "2026-03-15",
+            "--template",
+            "incident",
+            "--description",
+            "Rolled back the cache change",
         ])
         .assert()
         .failure()
         .stderr(predicate::str::contains(
-            "share manifest profile mismatch: expected public, found manager",
-        ));
-}
-
-#[test]
-fn share_verify_manifest_rejects_packet_path_traversal() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+            "template incident requires fields:",
+        ))
+        .stderr(predicate::str::contains(
+            "--receipt: Link the incident record",
+        ))
+        .stderr(predicate::str::contains("--impact: Who was affected"));
+    assert!(!tmp.path().join("manual_events.yaml").exists());
 
     shiplog_cmd()
-        .env("SHIPLOG_REDACT_KEY", "stable-env-key")
+        .current_dir(tmp.path())
         .args([
-            "share",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            "add",
+            "Mitigated the checkout outage",
+            "--date",
+            "2026-03-15",
+            "--template",
+            "incident",
+            "--description",
+            "Rolled back the cache change",
+            "--receipt",
+            "postmortem=https://example.com/pm/42",
+            "--impact",
+            "Checkout errors stopped within 20 minutes",
         ])
         .assert()
-        .success();
-    let manifest_path = tmp
-        .path()
-        .join("run_fixture/profiles/manager/share.manifest.json");
-    let mut manifest: serde_json::Value =
-        serde_json::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
-    manifest["packet_path"] = serde_json::json!("../outside.md");
-    std::fs::write(
-        &manifest_path,
-        format!("{}\n", serde_json::to_string_pretty(&manifest).unwrap()),
-    )
-    .unwrap();
+        .success()
+        .stdout(predicate::str::contains("Type: Incident"))
+        .stdout(predicate::str::contains("Tags: incident"));
 
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        r#"[templates.launch-review]
+type = "launch"
+required = ["receipt"]
+"#,
+    )?;
     shiplog_cmd()
-        .env_remove("SHIPLOG_REDACT_KEY")
+        .current_dir(tmp.path())
         .args([
-            "share",
-            "verify",
-            "manifest",
-            "--profile",
-            "manager",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
+            "journal",
+            "scaffold",
+            "--template",
+            "launch-review",
+            "--template",
+            "mentoring",
+            "--date",
+            "2026-03-31",
         ])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "share manifest packet_path must not traverse outside the run directory",
-        ));
-}
+        .success()
+        .stdout(predicate::str::contains("Scaffolded 2 template(s)"));
 
-#[test]
-fn render_public_profile_receipt_options_override_profile_defaults() {
-    let tmp = TempDir::new().unwrap();
-    collect_json_into(tmp.path());
+    let manual = std::fs::read_to_string(tmp.path().join("manual_events.yaml"))?;
+    assert!(manual.contains("Mitigated the checkout outage"));
+    assert!(manual.contains("#   - id: manual-2026-03-31-launch-review"));
+    assert!(manual.contains("#     type: Launch"));
+    assert!(manual.contains("#     receipts:  # required: Link the doc"));
+    assert!(manual.contains("#     impact: \"\"  # required: What can they do now"));
 
     shiplog_cmd()
-        .args([
-            "render",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "run_fixture",
-            "--bundle-profile",
-            "public",
-            "--redact-key",
-            "stable-test-key",
-            "--receipt-limit",
-            "3",
-            "--appendix",
-            "summary",
-        ])
+        .current_dir(tmp.path())
+        .args(["journal", "list"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Rendered"));
-
-    let public_packet =
-        std::fs::read_to_string(tmp.path().join("run_fixture/profiles/public/packet.md")).unwrap();
-    assert!(
-        public_packet.contains("## Appendix: Receipt Summary"),
-        "explicit --appendix should override public profile defaults"
-    );
-    assert!(
-        !public_packet.contains("## Appendix: All Receipts"),
-        "summary appendix override should not render full receipt detail"
-    );
+        .stdout(predicate::str::contains("Mitigated the checkout outage"));
+    Ok(())
 }
 
 #[test]
-fn render_latest_on_collected_directory() {
-    let tmp = TempDir::new().unwrap();
-    let _run_dir = collect_json_into(tmp.path());
+fn prompt_prints_redacted_llm_handoff_for_the_profile() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    collect_json_into(tmp.path());
+    let out = tmp.path().to_str().unwrap();
 
-    shiplog_cmd()
-        .args(["render", "--out", tmp.path().to_str().unwrap(), "--latest"])
+    let internal = shiplog_cmd()
+        .args(["prompt", "--out", out, "--latest"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Rendered"));
-
-    assert!(
-        tmp.path().join("run_fixture/packet.md").exists(),
-        "packet.md should exist after render --latest"
-    );
-}
-
-#[test]
-fn render_run_latest_alias_on_collected_directory() {
-    let tmp = TempDir::new().unwrap();
-    let _run_dir = collect_json_into(tmp.path());
+        .stdout(predicate::str::starts_with(
+            "# Self-review drafting prompt\n",
+        ))
+        .stdout(predicate::str::contains("- Share profile: internal."))
+        .stdout(predicate::str::contains("## Coverage and Limits"))
+        .stdout(predicate::str::contains("- [R1] [PR] "))
+        .stdout(predicate::str::contains("Claim scaffold:"))
+        .stdout(predicate::str::contains("## What to write"));
+    let internal = String::from_utf8(internal.get_output().stdout.clone())?;
 
+    let prompt_path = tmp.path().join("prompt.public.md");
     shiplog_cmd()
         .args([
-            "render",
+            "prompt",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "latest",
+            out,
+            "--latest",
+            "--bundle-profile",
+            "public",
+            "--redact-key",
+            "test-key",
+            "--output",
+            prompt_path.to_str().unwrap(),
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Rendered"));
-}
-
-#[test]
-fn render_nonexistent_run_dir_fails() {
-    let tmp = TempDir::new().unwrap();
+        .stdout(predicate::str::contains("Wrote public prompt:"));
+    let public = std::fs::read_to_string(&prompt_path)?;
+    assert!(public.contains("- Share profile: public."));
+    assert!(public.contains("You are helping the author draft"));
+    assert!(public.contains("- [R1] [PR] [redacted]"));
+    assert_ne!(internal, public);
 
     shiplog_cmd()
+        .env_remove("SHIPLOG_REDACT_KEY")
         .args([
-            "render",
+            "prompt",
             "--out",
-            tmp.path().to_str().unwrap(),
-            "--run",
-            "nonexistent_run",
+            out,
+            "--latest",
+            "--bundle-profile",
+            "manager",
         ])
         .assert()
-        .failure();
+        .failure()
+        .stderr(predicate::str::contains(
+            "manager profile requires --redact-key",
+        ));
+    Ok(())
 }
 
-// ── 7. invalid subcommand returns error ────────────────────────────────────
-
 #[test]
-fn invalid_subcommand_returns_error() {
+fn assess_reports_strong_thin_and_missing_competencies() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    collect_json_into(tmp.path());
+    let out_arg = tmp.path().to_str().context("temp path")?;
+    let rubric = tmp.path().join("rubric.yaml");
+    std::fs::write(
+        &rubric,
+        "name: Test ladder\n\
+         levels:\n\
+         \x20 - name: Mid\n\
+         \x20   competencies:\n\
+         \x20     - name: Delivery\n\
+         \x20       evidence: [pull_request]\n\
+         \x20 - name: Senior\n\
+         \x20   competencies:\n\
+         \x20     - name: Code review\n\
+         \x20       evidence: [review]\n\
+         \x20       min_receipts: 1\n\
+         \x20     - name: Delivery\n\
+         \x20       evidence: [pull_request]\n\
+         \x20       min_receipts: 5\n\
+         \x20     - name: Mentoring\n\
+         \x20       keywords: [mentoring]\n",
+    )?;
+    let rubric_arg = rubric.to_str().context("rubric path")?;
+
     shiplog_cmd()
-        .arg("nonexistent")
+        .args(["assess", "--rubric", rubric_arg, "--out", out_arg])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("unrecognized subcommand"));
-}
+        .stderr(predicate::str::contains("pick one of: Mid, Senior"));
 
-#[test]
-fn no_subcommand_shows_home_screen() {
     shiplog_cmd()
+        .args([
+            "assess", "--rubric", rubric_arg, "--level", "senior", "--out", out_arg,
+        ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("No packet exists yet."))
-        .stdout(predicate::str::contains("Start: shiplog intake"));
+        .stdout(predicate::str::contains("# Rubric Gap Analysis"))
+        .stdout(predicate::str::contains("Test ladder, level Senior"))
+        .stdout(predicate::str::contains(
+            "| Code review | strong | 1 of 1 | 1 | acme/platform |",
+        ))
+        .stdout(predicate::str::contains("| Delivery | thin | 2 of 5 | 2 |"))
+        .stdout(predicate::str::contains(
+            "| Mentoring | missing | 0 of 2 | 0 | - |",
+        ))
+        .stdout(predicate::str::contains(
+            "Every match is already a receipt; capture more evidence.",
+        ));
+    Ok(())
 }
 
-// ── 8. missing required args return helpful error messages ─────────────────
-
 #[test]
-fn collect_without_source_fails_with_help() {
-    shiplog_cmd().arg("collect").assert().failure().stderr(
-        predicate::str::contains("github")
-            .or(predicate::str::contains("json"))
-            .or(predicate::str::contains("subcommand")),
+fn collect_summarize_prefills_suggested_summaries_and_leaves_curated_alone() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let fixtures = fixture_dir();
+    let collect = |extra: &[&str]| {
+        let mut cmd = shiplog_cmd();
+        cmd.args(["collect", "--out", tmp.path().to_str().unwrap()])
+            .args(extra)
+            .args([
+                "json",
+                "--events",
+                fixtures.join("ledger.events.jsonl").to_str().unwrap(),
+                "--coverage",
+                fixtures.join("coverage.manifest.json").to_str().unwrap(),
+            ])
+            .assert()
+            .success();
+    };
+
+    collect(&["--summarize", "heuristic"]);
+    let run_dir = tmp.path().join("run_fixture");
+    let suggested = std::fs::read_to_string(run_dir.join("workstreams.suggested.yaml"))?;
+    assert!(
+        suggested.contains("summary: ") && suggested.contains(" pull request"),
+        "suggested workstreams should carry heuristic summaries:\n{suggested}"
     );
-}
 
-#[test]
-fn collect_github_missing_user_fails() {
-    shiplog_cmd()
-        .args([
-            "collect",
-            "github",
-            "--since",
-            "2025-01-01",
-            "--until",
-            "2025-12-31",
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("provide --user").or(predicate::str::contains("--me")));
+    let curated: String = suggested
+        .lines()
+        .map(|line| match line.split_once("summary: ") {
+            Some((indent, _)) => format!("{indent}summary: null\n"),
+            None => format!("{line}\n"),
+        })
+        .collect();
+    std::fs::write(run_dir.join("workstreams.yaml"), &curated)?;
+
+    collect(&["--summarize", "heuristic"]);
+    assert_eq!(
+        std::fs::read_to_string(run_dir.join("workstreams.yaml"))?,
+        curated,
+        "curated workstreams are user-owned"
+    );
+    Ok(())
 }
 
 #[test]
-fn collect_github_user_and_me_conflict_fails() {
+fn collect_source_builds_registered_ingestors_by_name() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let fixtures = fixture_dir();
+    let events = format!(
+        "events={}",
+        fixtures.join("ledger.events.jsonl").to_str().unwrap()
+    );
+    let coverage = format!(
+        "coverage={}",
+        fixtures.join("coverage.manifest.json").to_str().unwrap()
+    );
+
     shiplog_cmd()
-        .args(["collect", "github", "--user", "octocat", "--me"])
+        .args(["collect", "--out", tmp.path().to_str().unwrap()])
+        .args(["--source", "json", "--source-option", &events])
+        .args(["--source-option", &coverage])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("use either --user or --me"));
-}
+        .success()
+        .stdout(predicate::str::contains("Collected and wrote:"));
+    assert!(tmp.path().join("run_fixture/packet.md").exists());
 
-#[test]
-fn collect_github_me_without_token_fails_actionably() {
     shiplog_cmd()
-        .env_remove("GITHUB_TOKEN")
-        .args(["collect", "github", "--me"])
+        .args(["collect", "--out", tmp.path().to_str().unwrap()])
+        .args(["--source", "json", "--source-option", "event=x"])
         .assert()
         .failure()
         .stderr(predicate::str::contains(
-            "Could not infer GitHub user: --me requires --token or GITHUB_TOKEN",
+            "unknown option event for source json; expected: events, coverage",
         ));
-}
-
-#[test]
-fn collect_gitlab_me_without_token_fails_actionably() {
     shiplog_cmd()
-        .env_remove("GITLAB_TOKEN")
-        .args(["collect", "gitlab", "--me"])
+        .args(["collect", "--source", "svn"])
         .assert()
         .failure()
         .stderr(predicate::str::contains(
-            "Could not infer GitLab user: --me requires --token or GITLAB_TOKEN",
+            "unknown source svn; registered sources: bitbucket, gerrit, git, github",
         ));
-}
-
-#[test]
-fn collect_github_partial_date_window_fails() {
     shiplog_cmd()
         .args([
-            "collect",
-            "github",
-            "--user",
-            "octocat",
-            "--until",
-            "2025-12-31",
+            "collect", "--source", "json", "manual", "--events", "x.yaml",
         ])
         .assert()
         .failure()
-        .stderr(
-            predicate::str::contains("provide both --since and --until")
-                .or(predicate::str::contains("error")),
-        );
+        .stderr(predicate::str::contains("not both"));
+    Ok(())
 }
 
 #[test]
-fn collect_github_invalid_date_fails() {
-    shiplog_cmd()
-        .args([
-            "collect",
-            "github",
-            "--user",
-            "octocat",
-            "--since",
-            "not-a-date",
-            "--until",
-            "2025-12-31",
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("invalid value"));
-}
+fn collect_incremental_fetches_only_the_uncovered_window_into_the_latest_run() -> CliTestResult {
+    let tmp = TempDir::new()?;
+    let events = tmp.path().join("manual.yaml");
+    let event = |id: &str, date: &str| {
+        format!(
+            "  - id: {id}\n    type: Note\n    date: {date}\n    title: {id}\n    \
+             description: Shipped.\n    tags: []\n    receipts: []\n"
+        )
+    };
+    std::fs::write(
+        &events,
+        format!(
+            "version: 1\ngenerated_at: 2025-01-01T00:00:00Z\nevents:\n{}",
+            event("february", "2025-02-10")
+        ),
+    )?;
+    let out = tmp.path().join("out");
+    let collect = |until: &str, incremental: bool| {
+        let mut cmd = shiplog_cmd();
+        cmd.args(["collect", "--out", out.to_str().unwrap()])
+            .args(["--source", "manual", "--user", "octo", "--source-option"])
+            .arg(format!("events={}", events.display()))
+            .args(["--since", "2025-01-01", "--until", until]);
+        if incremental {
+            cmd.arg("--incremental");
+        }
+        cmd.assert().success()
+    };
 
-#[test]
-fn collect_json_missing_coverage_fails() {
-    shiplog_cmd()
-        .args(["collect", "json", "--events", "some_file.jsonl"])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("--coverage").or(predicate::str::contains("required")));
-}
+    collect("2025-04-01", false);
+    let mut text = std::fs::read_to_string(&events)?;
+    text.push_str(&event("april", "2025-04-10"));
+    std::fs::write(&events, text)?;
 
-#[test]
-fn collect_json_missing_events_file_fails() {
-    let tmp = TempDir::new().unwrap();
+    collect("2025-05-01", true)
+        .stdout(predicate::str::contains(
+            "- manual: queried 2025-04-01..2025-05-01",
+        ))
+        .stdout(predicate::str::contains("- events: 1 before, 2 after"));
+    collect("2025-05-01", true).stdout(predicate::str::contains("- manual: already covered"));
 
-    shiplog_cmd()
-        .args([
-            "collect",
-            "--out",
-            tmp.path().to_str().unwrap(),
-            "json",
-            "--events",
-            "/nonexistent/events.jsonl",
-            "--coverage",
-            "/nonexistent/coverage.json",
-        ])
-        .assert()
-        .failure();
+    let runs: Vec<_> = std::fs::read_dir(&out)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .collect();
+    assert_eq!(
+        runs.len(),
+        1,
+        "incremental collection updates the run in place"
+    );
+    let coverage: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        runs[0].path().join("coverage.manifest.json"),
+    )?)?;
+    assert_eq!(coverage["window"]["until"], "2025-05-01");
+    Ok(())
 }
 
 #[test]
-fn render_unknown_flag_fails() {
+fn collect_source_bitbucket_requires_an_app_password_before_querying() -> CliTestResult {
+    let tmp = TempDir::new()?;
     shiplog_cmd()
-        .args(["render", "--bogus-flag"])
+        .env_remove("BITBUCKET_APP_PASSWORD")
+        .args(["collect", "--out", tmp.path().to_str().unwrap()])
+        .args(["--source", "bitbucket", "--user", "alice"])
+        .args(["--source-option", "workspace=acme", "--no-cache"])
+        .args(["--since", "2025-01-01", "--until", "2025-02-01"])
         .assert()
         .failure()
-        .stderr(
-            predicate::str::contains("unexpected argument").or(predicate::str::contains("error")),
-        );
-}
-
-// ── 9. import subcommand exists and shows help ─────────────────────────────
-
-#[test]
-fn import_help_shows_options() {
+        .stderr(predicate::str::contains("app password is required"));
     shiplog_cmd()
-        .args(["import", "--help"])
+        .args(["collect", "--source", "bitbucket", "--user", "alice"])
+        .args(["--source-option", "state=approved"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("--dir"))
-        .stdout(predicate::str::contains("--out"))
-        .stdout(predicate::str::contains("--user"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "parse Bitbucket pull request state",
+        ));
+    Ok(())
 }
 
 #[test]
-fn import_from_fixture_dir_succeeds() {
-    let tmp = TempDir::new().unwrap();
-    let fixtures = fixture_dir();
-
+fn collect_source_gerrit_validates_options_before_querying() -> CliTestResult {
     shiplog_cmd()
-        .args([
-            "import",
-            "--dir",
-            fixtures.to_str().unwrap(),
-            "--out",
-            tmp.path().to_str().unwrap(),
-        ])
+        .args(["collect", "--source", "gerrit", "--user", "alice"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Imported"));
-
-    let run_dir = tmp.path().join("run_fixture");
-    assert!(run_dir.join("packet.md").exists());
-    assert!(run_dir.join("ledger.events.jsonl").exists());
-    assert!(run_dir.join("coverage.manifest.json").exists());
-}
-
-#[test]
-fn import_missing_dir_fails() {
-    let tmp = TempDir::new().unwrap();
-
+        .failure()
+        .stderr(predicate::str::contains(
+            "source gerrit needs --source-option instance=<value>",
+        ));
     shiplog_cmd()
-        .args([
-            "import",
-            "--dir",
-            tmp.path().join("nonexistent").to_str().unwrap(),
-            "--out",
-            tmp.path().to_str().unwrap(),
-        ])
+        .args(["collect", "--source", "gerrit", "--user", "alice"])
+        .args(["--source-option", "instance=https://review.example.com"])
+        .args(["--source-option", "status=draft"])
         .assert()
-        .failure();
+        .failure()
+        .stderr(predicate::str::contains("parse Gerrit change status"));
+    Ok(())
 }
 
-// ── additional subcommand help checks ──────────────────────────────────────
-
 #[test]
-fn refresh_help_shows_options() {
+fn collect_source_slack_requires_channels_and_a_token_before_querying() -> CliTestResult {
     shiplog_cmd()
-        .args(["refresh", "--help"])
+        .args(["collect", "--source", "slack", "--user", "U024BE7LH"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("github"))
-        .stdout(predicate::str::contains("multi").not())
-        .stdout(predicate::str::contains("--out"))
-        .stdout(predicate::str::contains("--run-dir"));
-}
-
-#[test]
-fn run_help_shows_options() {
+        .failure()
+        .stderr(predicate::str::contains(
+            "source slack needs --source-option channels=<value>",
+        ));
     shiplog_cmd()
-        .args(["run", "--help"])
+        .env_remove("SLACK_TOKEN")
+        .args(["collect", "--source", "slack", "--user", "U024BE7LH"])
+        .args(["--source-option", "channels=shipped"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("github"))
-        .stdout(predicate::str::contains("multi").not())
-        .stdout(predicate::str::contains("--out"));
+        .failure()
+        .stderr(predicate::str::contains("Slack token is required"));
+    Ok(())
 }
@@ -12,6 +12,7 @@ use shiplog::schema::event::*;
 
 fn make_pr(repo: &str, num: u64, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["it", repo, &num.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -43,11 +44,15 @@ fn make_pr(repo: &str, num: u64, title: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_review(repo: &str, num: u64) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["it", "review", repo, &num.to_string()]),
         kind: EventKind::Review,
         occurred_at: Utc::now(),
@@ -66,6 +71,8 @@ fn make_review(repo: &str, num: u64) -> EventEnvelope {
             submitted_at: Utc::now(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![],
@@ -74,11 +81,15 @@ fn make_review(repo: &str, num: u64) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_manual(num: u64) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["it", "manual", &num.to_string()]),
         kind: EventKind::Manual,
         occurred_at: Utc::now(),
@@ -98,6 +109,9 @@ fn make_manual(num: u64) -> EventEnvelope {
             started_at: None,
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
@@ -106,6 +120,9 @@ fn make_manual(num: u64) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
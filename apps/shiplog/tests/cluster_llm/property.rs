@@ -11,6 +11,7 @@ use std::collections::HashSet;
 fn make_pr_event(num: u64, title: &str) -> EventEnvelope {
     use chrono::Utc;
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["prop", "clllm", &num.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -42,6 +43,9 @@ fn make_pr_event(num: u64, title: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
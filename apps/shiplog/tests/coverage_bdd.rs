@@ -39,6 +39,7 @@ fn complete_coverage_with_all_receipts() {
                     fetched: 10,
                     incomplete_results: Some(false),
                     notes: vec![],
+                    source: None,
                 })
                 .collect();
 
@@ -194,6 +195,7 @@ fn slice_notes_document_why_incomplete() {
                 fetched: 30,
                 incomplete_results: Some(true),
                 notes: vec![note],
+                source: None,
             };
 
             ctx.numbers
@@ -473,6 +475,7 @@ fn partial_coverage_per_slice_status() {
                     } else {
                         vec![]
                     },
+                    source: None,
                 })
                 .collect();
 
@@ -24,6 +24,7 @@ fn zero_percent_coverage_fetched_zero_of_many() {
         fetched: 0,
         incomplete_results: Some(true),
         notes: vec!["Completely rate limited".to_string()],
+        source: None,
     };
     assert_eq!(slice.fetched, 0);
     assert!(slice.fetched < slice.total_count);
@@ -42,6 +43,7 @@ fn zero_events_total_is_complete() {
         fetched: 0,
         incomplete_results: Some(false),
         notes: vec![],
+        source: None,
     };
     assert_eq!(slice.fetched, slice.total_count);
     assert_eq!(slice.total_count, 0);
@@ -76,6 +78,7 @@ fn full_coverage_all_slices_complete() {
             fetched: 10,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         })
         .collect();
 
@@ -114,6 +117,7 @@ fn single_event_single_slice_all_fetched() {
         fetched: 1,
         incomplete_results: Some(false),
         notes: vec![],
+        source: None,
     };
     assert_eq!(slice.fetched, 1);
     assert_eq!(slice.total_count, 1);
@@ -146,6 +150,7 @@ fn very_large_total_count_all_fetched() {
         fetched: 1_000_000,
         incomplete_results: Some(false),
         notes: vec![],
+        source: None,
     };
     assert_eq!(slice.fetched, slice.total_count);
 }
@@ -163,6 +168,7 @@ fn very_large_total_with_partial_fetch() {
         fetched: 1000,
         incomplete_results: Some(true),
         notes: vec!["Extremely large result set".to_string()],
+        source: None,
     };
     assert!(slice.fetched < slice.total_count);
     assert_eq!(slice.incomplete_results, Some(true));
@@ -185,6 +191,7 @@ fn many_slices_all_complete() {
             fetched: 50,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         })
         .collect();
 
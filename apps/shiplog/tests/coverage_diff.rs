@@ -0,0 +1,134 @@
+//! Integration tests for run-to-run coverage comparison.
+
+use chrono::{NaiveDate, Utc};
+use shiplog::coverage::diff_coverage;
+use shiplog::ids::RunId;
+use shiplog::schema::coverage::{
+    Completeness, CoverageManifest, CoverageSlice, SchemaVersion, TimeWindow,
+};
+
+fn window(since: (i32, u32), until: (i32, u32)) -> TimeWindow {
+    TimeWindow {
+        since: NaiveDate::from_ymd_opt(since.0, since.1, 1).unwrap(),
+        until: NaiveDate::from_ymd_opt(until.0, until.1, 1).unwrap(),
+    }
+}
+
+fn slice(window: TimeWindow, total_count: u64, fetched: u64) -> CoverageSlice {
+    CoverageSlice {
+        window,
+        query: "author:octo is:pr is:merged".into(),
+        total_count,
+        fetched,
+        incomplete_results: Some(false),
+        notes: vec![],
+        source: None,
+    }
+}
+
+fn manifest(
+    window: TimeWindow,
+    sources: &[&str],
+    slices: Vec<CoverageSlice>,
+    completeness: Completeness,
+) -> CoverageManifest {
+    CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
+        run_id: RunId::now("test"),
+        generated_at: Utc::now(),
+        user: "octo".into(),
+        window,
+        mode: "merged".into(),
+        sources: sources.iter().map(|s| s.to_string()).collect(),
+        slices,
+        warnings: vec![],
+        completeness,
+        score: None,
+        timezone: None,
+        budget: vec![],
+    }
+}
+
+#[test]
+fn rerun_that_fetches_everything_reports_closed_gaps() {
+    let jan = window((2025, 1), (2025, 2));
+    let feb = window((2025, 2), (2025, 3));
+    let quarter = window((2025, 1), (2025, 4));
+    let before = manifest(
+        quarter.clone(),
+        &["github"],
+        vec![slice(jan.clone(), 10, 10), slice(feb.clone(), 12, 7)],
+        Completeness::Partial,
+    );
+    let after = manifest(
+        quarter,
+        &["github"],
+        vec![slice(jan, 10, 10), slice(feb.clone(), 12, 12)],
+        Completeness::Complete,
+    );
+
+    let diff = diff_coverage(&before, &after);
+
+    assert!(!diff.window_changed());
+    assert_eq!(diff.newly_complete.len(), 1);
+    assert_eq!(diff.newly_complete[0].window, feb);
+    assert!(diff.newly_partial.is_empty());
+    assert!(diff.still_partial.is_empty());
+    assert!(diff.gaps_closed());
+}
+
+#[test]
+fn slices_that_stay_or_become_partial_are_reported_separately() {
+    let jan = window((2025, 1), (2025, 2));
+    let feb = window((2025, 2), (2025, 3));
+    let quarter = window((2025, 1), (2025, 4));
+    let mut flagged = slice(feb.clone(), 5, 5);
+    flagged.incomplete_results = Some(true);
+    let before = manifest(
+        quarter.clone(),
+        &["github"],
+        vec![slice(jan.clone(), 10, 4), slice(feb.clone(), 5, 5)],
+        Completeness::Partial,
+    );
+    let after = manifest(
+        quarter,
+        &["github"],
+        vec![slice(jan.clone(), 10, 6), flagged],
+        Completeness::Partial,
+    );
+
+    let diff = diff_coverage(&before, &after);
+
+    assert_eq!(diff.still_partial.len(), 1);
+    assert_eq!(diff.still_partial[0].window, jan);
+    assert_eq!(diff.newly_partial.len(), 1);
+    assert_eq!(diff.newly_partial[0].window, feb);
+    assert!(!diff.gaps_closed());
+}
+
+#[test]
+fn window_and_source_changes_are_reported() {
+    let q1 = window((2025, 1), (2025, 4));
+    let q2 = window((2025, 4), (2025, 7));
+    let before = manifest(
+        q1.clone(),
+        &["github", "manual"],
+        vec![slice(q1.clone(), 3, 3)],
+        Completeness::Complete,
+    );
+    let after = manifest(
+        q2.clone(),
+        &["GitHub", "jira"],
+        vec![slice(q2.clone(), 4, 4)],
+        Completeness::Complete,
+    );
+
+    let diff = diff_coverage(&before, &after);
+
+    assert!(diff.window_changed());
+    assert_eq!(diff.sources_added, vec!["jira".to_string()]);
+    assert_eq!(diff.sources_removed, vec!["manual".to_string()]);
+    assert_eq!(diff.newly_complete.len(), 1);
+    assert_eq!(diff.slices_removed.len(), 1);
+    assert_eq!(diff.slices_removed[0].window, q1);
+}
@@ -6,6 +6,7 @@
 use chrono::{NaiveDate, TimeZone, Utc};
 use shiplog::ids::RunId;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::version::SchemaVersion;
 
 fn ts() -> chrono::DateTime<Utc> {
     Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()
@@ -18,6 +19,7 @@ fn ts() -> chrono::DateTime<Utc> {
 #[test]
 fn coverage_manifest_full_json() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("stability_test_run".into()),
         generated_at: ts(),
         user: "octocat".into(),
@@ -38,6 +40,7 @@ fn coverage_manifest_full_json() {
                 fetched: 15,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             },
             CoverageSlice {
                 window: TimeWindow {
@@ -52,10 +55,14 @@ fn coverage_manifest_full_json() {
                     "GitHub search API limit reached".into(),
                     "Results may be incomplete".into(),
                 ],
+                source: None,
             },
         ],
         warnings: vec!["Incomplete results for February slice".into()],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -63,6 +70,7 @@ fn coverage_manifest_full_json() {
 #[test]
 fn coverage_manifest_full_yaml() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("yaml_test_run".into()),
         generated_at: ts(),
         user: "octocat".into(),
@@ -82,9 +90,13 @@ fn coverage_manifest_full_yaml() {
             fetched: 10,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         }],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
     insta::assert_yaml_snapshot!(manifest);
 }
@@ -96,6 +108,7 @@ fn coverage_manifest_full_yaml() {
 #[test]
 fn coverage_manifest_minimal() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("min".into()),
         generated_at: ts(),
         user: "u".into(),
@@ -108,6 +121,9 @@ fn coverage_manifest_minimal() {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Unknown,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -119,6 +135,7 @@ fn coverage_manifest_minimal() {
 #[test]
 fn coverage_manifest_edge_cases() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("".into()),
         generated_at: ts(),
         user: "user with spaces & <special>".into(),
@@ -138,6 +155,7 @@ fn coverage_manifest_edge_cases() {
             fetched: 0,
             incomplete_results: None,
             notes: vec!["".into(), "Special chars: <>&\"'".into()],
+            source: None,
         }],
         warnings: vec![
             "".into(),
@@ -145,6 +163,9 @@ fn coverage_manifest_edge_cases() {
             "Multi\nline\nwarning".into(),
         ],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -161,6 +182,7 @@ fn coverage_slice_no_incomplete_results() {
         fetched: 0,
         incomplete_results: None,
         notes: vec![],
+        source: None,
     };
     insta::assert_json_snapshot!(slice);
 }
@@ -0,0 +1,124 @@
+//! Integration tests for calendar-week activity gap detection.
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shiplog::coverage::{
+    GapCause, ReportingTimezone, activity_gaps, refresh_activity_gap_warnings,
+};
+use shiplog::schema::coverage::{Completeness, TimeWindow};
+use shiplog::schema::event::{EventEnvelope, SourceSystem};
+use shiplog_testkit::fixtures::test_coverage;
+use shiplog_testkit::pr_event;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+/// Monday 2025-01-06 through Monday 2025-03-31: twelve full weeks.
+fn quarter() -> TimeWindow {
+    TimeWindow {
+        since: date(1, 6),
+        until: date(3, 31),
+    }
+}
+
+fn event(system: SourceSystem, week: u32) -> EventEnvelope {
+    let mut event = pr_event("acme/app", week.into(), "Weekly change");
+    let day = date(1, 7) + chrono::Days::new((week * 7).into());
+    event.occurred_at = Utc.from_utc_datetime(&day.and_hms_opt(12, 0, 0).unwrap());
+    event.source.system = system;
+    event
+}
+
+fn weekly(system: SourceSystem, weeks: impl IntoIterator<Item = u32>) -> Vec<EventEnvelope> {
+    weeks
+        .into_iter()
+        .map(|week| event(system.clone(), week))
+        .collect()
+}
+
+#[test]
+fn short_interior_break_is_likely_vacation() {
+    let events = weekly(SourceSystem::Github, (0..5).chain(7..12));
+
+    let gaps = activity_gaps(&quarter(), &events, ReportingTimezone::UTC);
+
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].cause, GapCause::LikelyVacation);
+    assert_eq!(gaps[0].weeks, 2);
+    assert_eq!(gaps[0].window.since, date(2, 10));
+    assert_eq!(gaps[0].window.until, date(2, 24));
+    assert_eq!(
+        gaps[0].warning(),
+        "Activity gap: no events from any source for 2 weeks from 2025-02-10 to 2025-02-24; \
+         likely vacation"
+    );
+}
+
+#[test]
+fn silence_to_the_end_of_the_window_is_likely_missing_source() {
+    let events = weekly(SourceSystem::Github, 0..6);
+
+    let gaps = activity_gaps(&quarter(), &events, ReportingTimezone::UTC);
+
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].cause, GapCause::LikelyMissingSource);
+    assert_eq!(gaps[0].weeks, 6);
+    assert_eq!(gaps[0].stopped, vec!["github".to_string()]);
+    assert!(
+        gaps[0]
+            .warning()
+            .ends_with("likely missing source (github has no events after 2025-02-17)")
+    );
+}
+
+#[test]
+fn source_that_goes_dark_across_a_gap_is_named() {
+    let mut events = weekly(SourceSystem::Github, 0..4);
+    events.extend(weekly(SourceSystem::Manual, (0..4).chain(6..12)));
+
+    let gaps = activity_gaps(&quarter(), &events, ReportingTimezone::UTC);
+
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].cause, GapCause::LikelyMissingSource);
+    assert_eq!(gaps[0].stopped, vec!["github".to_string()]);
+    assert!(gaps[0].started.is_empty());
+}
+
+#[test]
+fn long_interior_gap_is_likely_missing_source() {
+    let events = weekly(SourceSystem::Github, (0..3).chain(8..12));
+
+    let gaps = activity_gaps(&quarter(), &events, ReportingTimezone::UTC);
+
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].cause, GapCause::LikelyMissingSource);
+    assert!(gaps[0].warning().ends_with("(longer than 3 weeks)"));
+}
+
+#[test]
+fn sparse_activity_is_not_flagged() {
+    let events = weekly(SourceSystem::Github, [0, 5, 10]);
+
+    assert!(activity_gaps(&quarter(), &events, ReportingTimezone::UTC).is_empty());
+}
+
+#[test]
+fn refreshing_replaces_stale_gap_warnings_only() {
+    let mut coverage = test_coverage("octo", Completeness::Complete);
+    coverage.window = quarter();
+    coverage.warnings = vec![
+        "Activity gap: stale".to_string(),
+        "Configured source jira was skipped: missing token".to_string(),
+    ];
+    let events = weekly(SourceSystem::Github, (0..5).chain(7..12));
+
+    refresh_activity_gap_warnings(&mut coverage, &events);
+    refresh_activity_gap_warnings(&mut coverage, &events);
+
+    assert_eq!(coverage.warnings.len(), 2);
+    assert_eq!(
+        coverage.warnings[0],
+        "Configured source jira was skipped: missing token"
+    );
+    assert!(coverage.warnings[1].ends_with("likely vacation"));
+}
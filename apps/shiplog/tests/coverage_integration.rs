@@ -72,6 +72,7 @@ fn manifest_with_slices_records_fetch_details() -> Result<()> {
             fetched: 10,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         })
         .collect();
 
@@ -99,6 +100,7 @@ fn incomplete_slice_signals_partial_coverage() -> Result<()> {
         fetched: 30,
         incomplete_results: Some(true),
         notes: vec!["Rate limited after 30 results".to_string()],
+        source: None,
     };
 
     assert!(slice.fetched < slice.total_count);
@@ -121,6 +123,7 @@ fn complete_slice_fetches_all_results() -> Result<()> {
         fetched: 25,
         incomplete_results: Some(false),
         notes: vec![],
+        source: None,
     };
 
     assert_eq!(slice.fetched, slice.total_count);
@@ -161,6 +164,7 @@ fn manifest_slices_with_mixed_completeness() -> Result<()> {
             fetched: 10,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         },
         CoverageSlice {
             window: windows[1].clone(),
@@ -169,6 +173,7 @@ fn manifest_slices_with_mixed_completeness() -> Result<()> {
             fetched: 30,
             incomplete_results: Some(true),
             notes: vec!["GitHub search limit reached".to_string()],
+            source: None,
         },
     ];
 
@@ -363,6 +368,7 @@ fn coverage_slices_align_with_month_windows() -> Result<()> {
             fetched: 5,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         })
         .collect();
 
@@ -0,0 +1,138 @@
+//! Integration tests for per-source coverage gap analysis.
+
+use chrono::NaiveDate;
+use shiplog::coverage::coverage_gaps;
+use shiplog::render::md::render_coverage_report;
+use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::event::SourceSystem;
+use shiplog_testkit::fixtures::test_coverage;
+use shiplog_testkit::pr_event;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+fn window(since: NaiveDate, until: NaiveDate) -> TimeWindow {
+    TimeWindow { since, until }
+}
+
+fn slice(source: Option<&str>, window: TimeWindow, total: u64, fetched: u64) -> CoverageSlice {
+    CoverageSlice {
+        window,
+        query: format!("{}:query", source.unwrap_or("legacy")),
+        total_count: total,
+        fetched,
+        incomplete_results: Some(false),
+        notes: vec![],
+        source: source.map(str::to_string),
+    }
+}
+
+/// Q1 2025 from GitHub and Jira.
+fn two_sources() -> CoverageManifest {
+    let mut coverage = test_coverage("octo", Completeness::Partial);
+    coverage.sources = vec!["github".into(), "jira".into()];
+    coverage
+}
+
+#[test]
+fn uncovered_ranges_are_the_window_minus_each_sources_slices() {
+    let mut coverage = two_sources();
+    coverage.slices = vec![
+        slice(Some("github"), window(date(1, 1), date(2, 1)), 3, 3),
+        slice(Some("github"), window(date(3, 1), date(4, 1)), 2, 2),
+        slice(Some("jira"), window(date(1, 1), date(4, 1)), 0, 0),
+    ];
+    let events = vec![pr_event("acme/app", 1, "Ship it")];
+
+    let gaps = coverage_gaps(&coverage, &events);
+
+    let github = &gaps.sources[0];
+    assert_eq!(github.source, "github");
+    assert_eq!(github.events, 1);
+    assert_eq!(github.slices, 2);
+    assert_eq!(github.uncovered, vec![window(date(2, 1), date(3, 1))]);
+    let jira = &gaps.sources[1];
+    assert!(jira.uncovered.is_empty());
+    let silent: Vec<_> = gaps
+        .zero_event_sources()
+        .map(|source| source.source.as_str())
+        .collect();
+    assert_eq!(silent, ["jira"]);
+    assert!(!gaps.is_empty());
+}
+
+#[test]
+fn capped_slices_are_listed_per_source() {
+    let mut coverage = two_sources();
+    coverage.slices = vec![
+        slice(Some("github"), window(date(1, 1), date(4, 1)), 1200, 1000),
+        slice(Some("jira"), window(date(1, 1), date(4, 1)), 4, 4),
+    ];
+    let mut jira_event = pr_event("acme/app", 2, "Ticket");
+    jira_event.source.system = SourceSystem::Other("jira".into());
+    let events = vec![pr_event("acme/app", 1, "Ship it"), jira_event];
+
+    let gaps = coverage_gaps(&coverage, &events);
+
+    assert_eq!(gaps.sources[0].capped.len(), 1);
+    assert_eq!(gaps.sources[0].capped[0].fetched, 1000);
+    assert!(gaps.sources[1].is_empty());
+}
+
+#[test]
+fn skipped_sources_are_uncovered_for_the_whole_window() {
+    let mut coverage = test_coverage("octo", Completeness::Partial);
+    coverage.slices = vec![slice(None, window(date(1, 1), date(4, 1)), 1, 1)];
+    coverage
+        .warnings
+        .push("Configured source gitlab was skipped: missing GITLAB_TOKEN".into());
+
+    let gaps = coverage_gaps(&coverage, &[pr_event("acme/app", 1, "Ship it")]);
+
+    // The unattributed slice belongs to the manifest's only source.
+    assert!(gaps.sources[0].is_empty());
+    let gitlab = &gaps.sources[1];
+    assert_eq!(gitlab.source, "gitlab");
+    assert_eq!(gitlab.skipped.as_deref(), Some("missing GITLAB_TOKEN"));
+    assert_eq!(gitlab.uncovered, vec![coverage.window.clone()]);
+    assert_eq!(gaps.zero_event_sources().count(), 0);
+}
+
+#[test]
+fn coverage_report_lists_gaps_by_source() {
+    let mut coverage = two_sources();
+    coverage.slices = vec![
+        slice(Some("github"), window(date(1, 1), date(3, 1)), 5, 4),
+        slice(Some("jira"), window(date(1, 1), date(4, 1)), 0, 0),
+    ];
+    let events = vec![pr_event("acme/app", 1, "Ship it")];
+
+    let report = render_coverage_report(&coverage, &events);
+
+    assert!(report.contains("## Gaps by source\n\n"), "{report}");
+    assert!(
+        report.contains(
+            "- **GitHub:** no query covered 2025-03-01 to 2025-04-01; 1 query slice came back \
+             short (2025-01-01 to 2025-03-01).\n"
+        ),
+        "{report}"
+    );
+    assert!(
+        report.contains("- **Jira:** contributed no events.\n"),
+        "{report}"
+    );
+}
+
+#[test]
+fn coverage_report_says_when_nothing_is_missing() {
+    let mut coverage = test_coverage("octo", Completeness::Complete);
+    coverage.slices = vec![slice(Some("github"), coverage.window.clone(), 1, 1)];
+
+    let report = render_coverage_report(&coverage, &[pr_event("acme/app", 1, "Ship it")]);
+
+    assert!(report.contains(
+        "## Gaps by source\n\nEvery source covered the whole window, fetched everything it \
+         reported, and contributed events.\n"
+    ));
+}
@@ -121,6 +121,7 @@ proptest! {
             total_count: total,
             incomplete_results: Some(fetched < total),
             notes: vec![],
+            source: None,
         };
 
         prop_assert!(slice.fetched <= slice.total_count);
@@ -145,6 +146,7 @@ proptest! {
             total_count,
             incomplete_results: Some(fetched_count < total_count),
             notes: vec![],
+            source: None,
         };
 
         prop_assert_eq!(slice.incomplete_results, Some(slice.fetched < slice.total_count));
@@ -0,0 +1,109 @@
+//! Integration tests for minimum coverage requirements.
+
+use chrono::{NaiveDate, Utc};
+use shiplog::coverage::{CoverageRequirement, check_requirement};
+use shiplog::ids::RunId;
+use shiplog::schema::coverage::{
+    Completeness, CoverageManifest, CoverageSlice, SchemaVersion, TimeWindow,
+};
+
+fn quarter() -> TimeWindow {
+    TimeWindow {
+        since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        until: NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+    }
+}
+
+fn manifest(fetched: u64, completeness: Completeness) -> CoverageManifest {
+    CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
+        run_id: RunId::now("test"),
+        generated_at: Utc::now(),
+        user: "octo".into(),
+        window: quarter(),
+        mode: "merged".into(),
+        sources: vec!["github".into()],
+        slices: vec![CoverageSlice {
+            window: quarter(),
+            query: "author:octo is:pr is:merged".into(),
+            total_count: 10,
+            fetched,
+            incomplete_results: Some(false),
+            notes: vec![],
+            source: None,
+        }],
+        warnings: vec![],
+        completeness,
+        score: None,
+        timezone: None,
+        budget: vec![],
+    }
+}
+
+fn requirement(require_complete: bool, min_score: Option<u8>) -> CoverageRequirement {
+    CoverageRequirement {
+        require_complete,
+        min_score,
+    }
+}
+
+#[test]
+fn empty_requirement_is_always_met() {
+    let coverage = manifest(0, Completeness::Unknown);
+
+    assert!(CoverageRequirement::default().is_empty());
+    assert!(check_requirement(None, &coverage, &CoverageRequirement::default()).is_empty());
+}
+
+#[test]
+fn complete_run_meets_both_requirements() {
+    let coverage = manifest(10, Completeness::Complete);
+
+    assert!(check_requirement(None, &coverage, &requirement(true, Some(100))).is_empty());
+}
+
+#[test]
+fn each_unmet_requirement_is_reported() {
+    let coverage = manifest(6, Completeness::Partial);
+
+    let shortfalls = check_requirement(Some("github"), &coverage, &requirement(true, Some(80)));
+
+    let reasons: Vec<_> = shortfalls.iter().map(|s| s.reason.as_str()).collect();
+    assert_eq!(
+        reasons,
+        vec![
+            "completeness is Partial, Complete required",
+            "score 60/100 is below 80",
+        ]
+    );
+    assert!(
+        shortfalls
+            .iter()
+            .all(|s| s.source.as_deref() == Some("github"))
+    );
+}
+
+#[test]
+fn recorded_score_takes_precedence_over_slices() {
+    let mut coverage = manifest(10, Completeness::Complete);
+    coverage.score = Some(40);
+
+    let shortfalls = check_requirement(None, &coverage, &requirement(false, Some(50)));
+
+    assert_eq!(shortfalls.len(), 1);
+    assert_eq!(shortfalls[0].reason, "score 40/100 is below 50");
+    assert_eq!(shortfalls[0].source, None);
+}
+
+#[test]
+fn score_requirement_fails_without_slices() {
+    let mut coverage = manifest(10, Completeness::Complete);
+    coverage.slices.clear();
+
+    let shortfalls = check_requirement(None, &coverage, &requirement(false, Some(1)));
+
+    assert_eq!(
+        shortfalls[0].reason,
+        "no query slices to score, score of 1 required"
+    );
+}
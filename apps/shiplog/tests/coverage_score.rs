@@ -0,0 +1,59 @@
+//! Tests for the numeric coverage score.
+
+use chrono::NaiveDate;
+use shiplog::coverage::coverage_score;
+use shiplog::schema::coverage::{CoverageSlice, TimeWindow};
+
+fn slice(days: u32, total_count: u64, fetched: u64, incomplete: Option<bool>) -> CoverageSlice {
+    let since = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    CoverageSlice {
+        window: TimeWindow {
+            since,
+            until: since + chrono::Days::new(days.into()),
+        },
+        query: "author:octo".into(),
+        total_count,
+        fetched,
+        incomplete_results: incomplete,
+        notes: vec![],
+        source: None,
+    }
+}
+
+#[test]
+fn no_slices_have_no_score() {
+    assert_eq!(coverage_score(&[]), None);
+}
+
+#[test]
+fn fully_fetched_slices_score_100() {
+    let slices = vec![slice(31, 40, 40, Some(false)), slice(28, 0, 0, None)];
+    assert_eq!(coverage_score(&slices), Some(100));
+}
+
+#[test]
+fn partial_fetch_scores_its_ratio() {
+    assert_eq!(coverage_score(&[slice(7, 4, 1, Some(false))]), Some(25));
+}
+
+#[test]
+fn incomplete_results_halve_the_slice() {
+    assert_eq!(coverage_score(&[slice(7, 10, 10, Some(true))]), Some(50));
+}
+
+#[test]
+fn longer_windows_weigh_more() {
+    let slices = vec![slice(90, 10, 10, None), slice(10, 10, 0, None)];
+    assert_eq!(coverage_score(&slices), Some(90));
+}
+
+#[test]
+fn overfetched_slices_are_capped() {
+    assert_eq!(coverage_score(&[slice(7, 5, 8, None)]), Some(100));
+}
+
+#[test]
+fn empty_windows_still_count_once() {
+    let slices = vec![slice(0, 10, 0, None), slice(1, 10, 10, None)];
+    assert_eq!(coverage_score(&slices), Some(50));
+}
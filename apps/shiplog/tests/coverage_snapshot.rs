@@ -29,6 +29,7 @@ fn snapshot_complete_coverage_slice() {
         fetched: 15,
         incomplete_results: Some(false),
         notes: vec![],
+        source: None,
     };
 
     insta::assert_json_snapshot!("complete_coverage_slice", slice);
@@ -49,6 +50,7 @@ fn snapshot_incomplete_coverage_slice() {
             "GitHub search API limit reached".to_string(),
             "Consider using smaller date windows".to_string(),
         ],
+        source: None,
     };
 
     insta::assert_json_snapshot!("incomplete_coverage_slice", slice);
@@ -88,6 +90,7 @@ fn snapshot_coverage_slices_with_mixed_status() {
             fetched: 10,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         },
         CoverageSlice {
             window: windows[1].clone(),
@@ -96,6 +99,7 @@ fn snapshot_coverage_slices_with_mixed_status() {
             fetched: 30,
             incomplete_results: Some(true),
             notes: vec!["Rate limited".to_string()],
+            source: None,
         },
         CoverageSlice {
             window: windows[2].clone(),
@@ -104,6 +108,7 @@ fn snapshot_coverage_slices_with_mixed_status() {
             fetched: 8,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         },
     ];
 
@@ -0,0 +1,99 @@
+//! Integration tests for reading window dates in a reporting timezone.
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use shiplog::coverage::ReportingTimezone;
+use shiplog::render::md::format_receipt_markdown_in;
+use shiplog::schema::coverage::TimeWindow;
+use shiplog_testkit::pr_event;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn los_angeles() -> ReportingTimezone {
+    "America/Los_Angeles".parse().unwrap()
+}
+
+#[test]
+fn friday_evening_in_los_angeles_stays_on_friday() {
+    // 2025-03-29 02:30 UTC is Friday 2025-03-28 19:30 in Los Angeles.
+    let instant = Utc.with_ymd_and_hms(2025, 3, 29, 2, 30, 0).unwrap();
+
+    assert_eq!(
+        ReportingTimezone::UTC.local_date(&instant),
+        date(2025, 3, 29)
+    );
+    assert_eq!(los_angeles().local_date(&instant), date(2025, 3, 28));
+
+    let q1 = TimeWindow {
+        since: date(2025, 1, 1),
+        until: date(2025, 3, 29),
+    };
+    assert!(!ReportingTimezone::UTC.contains(&q1, &instant));
+    assert!(los_angeles().contains(&q1, &instant));
+}
+
+#[test]
+fn query_bounds_use_local_midnight_and_follow_dst() {
+    let tz = los_angeles();
+    assert_eq!(
+        tz.query_bound(date(2025, 1, 15)),
+        "2025-01-15T00:00:00-08:00"
+    );
+    assert_eq!(
+        tz.query_bound(date(2025, 7, 15)),
+        "2025-07-15T00:00:00-07:00"
+    );
+    assert_eq!(
+        ReportingTimezone::UTC.query_bound(date(2025, 7, 15)),
+        "2025-07-15"
+    );
+}
+
+#[test]
+fn day_start_skips_a_missing_local_midnight() {
+    // Santiago springs forward at midnight, so 2024-09-08 starts at 01:00.
+    let tz: ReportingTimezone = "America/Santiago".parse().unwrap();
+    assert_eq!(
+        tz.day_start(date(2024, 9, 8)).to_rfc3339(),
+        "2024-09-08T01:00:00-03:00"
+    );
+}
+
+#[test]
+fn manifest_name_is_omitted_for_utc() {
+    assert_eq!(ReportingTimezone::UTC.manifest_name(), None);
+    assert_eq!(
+        "utc".parse::<ReportingTimezone>().unwrap(),
+        ReportingTimezone::UTC
+    );
+    assert_eq!(
+        los_angeles().manifest_name().as_deref(),
+        Some("America/Los_Angeles")
+    );
+    assert_eq!(
+        ReportingTimezone::from_manifest(Some("America/Los_Angeles")),
+        los_angeles()
+    );
+    assert_eq!(
+        ReportingTimezone::from_manifest(None),
+        ReportingTimezone::UTC
+    );
+}
+
+#[test]
+fn unknown_timezone_names_are_rejected() {
+    let err = "Pacific/Atlantis".parse::<ReportingTimezone>().unwrap_err();
+    assert!(err.to_string().contains("IANA name"), "{err}");
+}
+
+#[test]
+fn receipt_dates_follow_the_reporting_timezone() {
+    let mut event = pr_event("acme/app", 7, "Late Friday fix");
+    event.occurred_at = Utc.with_ymd_and_hms(2025, 3, 29, 2, 30, 0).unwrap();
+
+    let utc = format_receipt_markdown_in(&event, ReportingTimezone::UTC);
+    let local = format_receipt_markdown_in(&event, los_angeles());
+    assert!(utc.contains("2025-03-29"), "{utc}");
+    assert!(local.contains("2025-03-28"), "{local}");
+}
@@ -22,6 +22,7 @@ use shiplog_testkit::bdd::assertions::*;
 
 fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", repo, &number.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -59,6 +60,9 @@ fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
             url: Some("https://api.github.com/...".into()),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -66,6 +70,7 @@ fn test_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
     IngestOutput {
         events,
         coverage: CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId("bdd_run".into()),
             generated_at: Utc.timestamp_opt(0, 0).unwrap(),
             user: "tester".into(),
@@ -78,8 +83,12 @@ fn test_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         },
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
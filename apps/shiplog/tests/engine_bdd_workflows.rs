@@ -25,6 +25,7 @@ use shiplog_testkit::bdd::assertions::*;
 
 fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", repo, &number.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -62,6 +63,9 @@ fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
             url: Some("https://api.github.com/...".into()),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -69,6 +73,7 @@ fn test_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
     IngestOutput {
         events,
         coverage: CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: shiplog::ids::RunId("bdd_workflow_run".into()),
             generated_at: Utc.timestamp_opt(0, 0).unwrap(),
             user: "tester".into(),
@@ -81,8 +86,12 @@ fn test_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         },
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
@@ -113,9 +122,19 @@ fn make_curated_workstreams(
                 pull_requests: event_ids.len(),
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: event_ids,
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     }
 }
@@ -21,6 +21,7 @@ fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
 
 fn make_coverage(user: &str, run_id: &str) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId(run_id.into()),
         generated_at: Utc.timestamp_opt(0, 0).unwrap(),
         user: user.into(),
@@ -33,6 +34,9 @@ fn make_coverage(user: &str, run_id: &str) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -41,6 +45,7 @@ fn make_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
         events,
         coverage: make_coverage("tester", "cross_plat_run"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
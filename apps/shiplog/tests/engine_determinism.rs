@@ -11,6 +11,7 @@ use shiplog::redact::DeterministicRedactor;
 use shiplog::schema::bundle::BundleProfile;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
 use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::version::SchemaVersion;
 use shiplog::workstreams::RepoClusterer;
 use shiplog_testkit::TestMarkdownRenderer as MarkdownRenderer;
 
@@ -28,6 +29,7 @@ fn test_events() -> Vec<EventEnvelope> {
 
 fn test_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("determinism_test_run".into()),
         generated_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
         user: "testuser".into(),
@@ -40,6 +42,9 @@ fn test_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -48,6 +53,7 @@ fn test_ingest() -> IngestOutput {
         events: test_events(),
         coverage: test_coverage(),
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
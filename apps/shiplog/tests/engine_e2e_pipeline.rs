@@ -8,7 +8,7 @@
 use chrono::{NaiveDate, TimeZone, Utc};
 use shiplog::bundle::{DIR_PROFILES, FILE_PACKET_MD, PROFILE_MANAGER, PROFILE_PUBLIC};
 use shiplog::engine::{Engine, WorkstreamSource};
-use shiplog::ids::RunId;
+use shiplog::ids::{OpaqueId, RunId};
 use shiplog::ports::{IngestOutput, Redactor, Renderer, WorkstreamClusterer};
 use shiplog::redact::DeterministicRedactor;
 use shiplog::schema::bundle::BundleProfile;
@@ -32,6 +32,7 @@ fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
 
 fn review_event(repo: &str, pr_number: u64, pr_title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: shiplog::ids::EventId::from_parts(["github", "review", repo, &pr_number.to_string()]),
         kind: EventKind::Review,
         occurred_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
@@ -53,6 +54,8 @@ fn review_event(repo: &str, pr_number: u64, pr_title: &str) -> EventEnvelope {
                 since: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
                 until: NaiveDate::from_ymd_opt(2025, 2, 1).unwrap(),
             }),
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec!["review".into()],
         links: vec![Link {
@@ -64,13 +67,17 @@ fn review_event(repo: &str, pr_number: u64, pr_title: &str) -> EventEnvelope {
             url: Some(format!(
                 "https://api.github.com/repos/{repo}/pulls/{pr_number}/reviews/1"
             )),
-            opaque_id: Some("1".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "1")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage(user: &str, run_id: &str) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId(run_id.into()),
         generated_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
         user: user.into(),
@@ -83,6 +90,9 @@ fn make_coverage(user: &str, run_id: &str) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -91,6 +101,7 @@ fn make_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
         events,
         coverage: make_coverage("tester", "e2e_run"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
@@ -104,6 +115,7 @@ fn read_json_fixture_ingest(events_path: &Path, coverage_path: &Path) -> IngestO
         events,
         coverage,
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
@@ -432,6 +444,7 @@ fn empty_coverage_slices_produces_valid_output() {
         events,
         coverage,
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
 
     let dir = tempfile::tempdir().unwrap();
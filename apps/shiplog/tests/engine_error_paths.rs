@@ -75,6 +75,7 @@ impl Redactor for NoopRedactor {
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("error_test".into()),
         generated_at: Utc.timestamp_opt(0, 0).unwrap(),
         user: "tester".into(),
@@ -87,6 +88,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -95,6 +99,7 @@ fn make_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
         events,
         coverage: make_coverage(),
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
@@ -387,6 +392,8 @@ fn import_empty_events_with_provided_workstreams_succeeds() {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
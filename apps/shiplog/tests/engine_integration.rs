@@ -25,6 +25,7 @@ fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
 
 fn make_coverage(user: &str, run_id: &str) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId(run_id.into()),
         generated_at: Utc.timestamp_opt(0, 0).unwrap(),
         user: user.into(),
@@ -37,6 +38,9 @@ fn make_coverage(user: &str, run_id: &str) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -45,6 +49,7 @@ fn make_ingest(events: Vec<EventEnvelope>) -> IngestOutput {
         events,
         coverage: make_coverage("tester", "integration_run"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     }
 }
 
@@ -207,9 +212,19 @@ fn run_uses_curated_workstreams_when_present() {
                 pull_requests: 1,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     shiplog::workstreams::write_workstreams(&WorkstreamManager::curated_path(&out), &ws).unwrap();
@@ -248,6 +263,8 @@ fn run_uses_suggested_workstreams_when_only_suggested_exists() {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     shiplog::workstreams::write_workstreams(&WorkstreamManager::suggested_path(&out), &ws).unwrap();
@@ -578,9 +595,19 @@ fn import_with_provided_workstreams_writes_curated() {
                 pull_requests: 1,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -694,6 +721,8 @@ fn refresh_with_curated_workstreams_preserves_curation() {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     shiplog::workstreams::write_workstreams(&WorkstreamManager::curated_path(&out), &ws).unwrap();
@@ -737,6 +766,8 @@ fn refresh_with_suggested_workstreams_uses_suggested() {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     shiplog::workstreams::write_workstreams(&WorkstreamManager::suggested_path(&out), &ws).unwrap();
@@ -865,11 +896,13 @@ fn merge_deduplicates_identical_events() {
         events: vec![event.clone()],
         coverage: cov.clone(),
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
     let ingest2 = IngestOutput {
         events: vec![event],
         coverage: make_coverage("tester", "merge2"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
 
     let merged = engine
@@ -890,11 +923,13 @@ fn merge_combines_distinct_events() {
         events: vec![pr_event("acme/foo", 1, "first")],
         coverage: make_coverage("tester", "m1"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
     let ingest2 = IngestOutput {
         events: vec![pr_event("acme/bar", 2, "second")],
         coverage: make_coverage("tester", "m2"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
 
     let merged = engine
@@ -1037,3 +1072,153 @@ fn coverage_manifest_is_valid_json() {
     assert_eq!(parsed.user, "tester");
     assert_eq!(parsed.mode, "merged");
 }
+
+// ---------------------------------------------------------------------------
+// collect_incremental() tests
+// ---------------------------------------------------------------------------
+
+fn day(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+fn window(since: NaiveDate, until: NaiveDate) -> TimeWindow {
+    TimeWindow { since, until }
+}
+
+/// A run over January 2025 that collected `sources`.
+fn seed_incremental_run(engine: &Engine<'_>, out: &std::path::Path, sources: &[&str]) {
+    let mut ingest = make_ingest(vec![pr_event("acme/foo", 1, "january")]);
+    ingest.coverage.generated_at = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+    ingest.coverage.sources = sources.iter().map(|s| s.to_string()).collect();
+    engine
+        .run(
+            ingest,
+            "tester",
+            "2025-01-01..2025-02-01",
+            out,
+            false,
+            &BundleProfile::Internal,
+        )
+        .unwrap();
+}
+
+/// Collect `requested` incrementally, returning the result and every
+/// `(source, window)` fetched.
+fn collect_incremental(
+    engine: &Engine<'_>,
+    out: &std::path::Path,
+    requested: &TimeWindow,
+    sources: &[&str],
+) -> (
+    shiplog::engine::IncrementalOutputs,
+    Vec<(String, TimeWindow)>,
+) {
+    let mut calls = Vec::new();
+    let result = engine
+        .collect_incremental(
+            out,
+            requested,
+            sources,
+            false,
+            &BundleProfile::Internal,
+            |source, delta| {
+                calls.push((source.to_string(), delta.clone()));
+                let mut ingest = make_ingest(vec![]);
+                ingest.coverage.window = delta.clone();
+                ingest.coverage.sources = vec![source.to_string()];
+                Ok(ingest)
+            },
+        )
+        .unwrap();
+    (result, calls)
+}
+
+fn read_coverage(out: &std::path::Path) -> CoverageManifest {
+    serde_json::from_str(&std::fs::read_to_string(out.join("coverage.manifest.json")).unwrap())
+        .unwrap()
+}
+
+#[test]
+fn collect_incremental_fetches_the_gap_before_a_disjoint_window() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("run");
+    let engine = real_engine();
+    seed_incremental_run(&engine, &out, &["github"]);
+
+    let (_, calls) = collect_incremental(&engine, &out, &window(day(3, 1), day(4, 1)), &["github"]);
+
+    // February lies between the two windows; it is fetched with March.
+    assert_eq!(
+        calls,
+        vec![("github".to_string(), window(day(2, 1), day(4, 1)))]
+    );
+    let coverage = read_coverage(&out);
+    assert_eq!(coverage.window, window(day(1, 1), day(4, 1)));
+    assert_eq!(coverage.completeness, Completeness::Complete);
+}
+
+#[test]
+fn collect_incremental_fetches_a_new_source_for_the_whole_run_window() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("run");
+    let engine = real_engine();
+    seed_incremental_run(&engine, &out, &["github"]);
+    let requested = window(day(2, 1), day(3, 1));
+
+    // jira is new, so January is fetched for it as well as February.
+    let (_, calls) = collect_incremental(&engine, &out, &requested, &["github", "jira"]);
+    assert_eq!(
+        calls,
+        vec![
+            ("github".to_string(), window(day(2, 1), day(3, 1))),
+            ("jira".to_string(), window(day(1, 1), day(3, 1))),
+        ]
+    );
+    let coverage = read_coverage(&out);
+    assert!(coverage.sources.iter().any(|source| source == "jira"));
+    assert_eq!(coverage.completeness, Completeness::Complete);
+
+    let (result, calls) = collect_incremental(&engine, &out, &requested, &["github", "jira"]);
+    assert!(calls.is_empty(), "already covered: {calls:?}");
+    assert!(result.deltas.iter().all(|delta| delta.is_current()));
+}
+
+#[test]
+fn collect_incremental_marks_unrequested_sources_uncovered_in_the_widened_window() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("run");
+    let engine = real_engine();
+    seed_incremental_run(&engine, &out, &["github", "jira"]);
+    let requested = window(day(1, 1), day(3, 1));
+
+    let (result, calls) = collect_incremental(&engine, &out, &requested, &["github"]);
+    assert_eq!(
+        calls,
+        vec![("github".to_string(), window(day(2, 1), day(3, 1)))]
+    );
+    assert_eq!(result.unrequested.len(), 1);
+    assert_eq!(result.unrequested[0].source, "jira");
+    assert_eq!(
+        result.unrequested[0].uncovered,
+        vec![window(day(2, 1), day(3, 1))]
+    );
+    let coverage = read_coverage(&out);
+    assert_eq!(coverage.completeness, Completeness::Partial);
+    assert!(
+        coverage
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("jira 2025-02-01..2025-03-01")),
+        "{:?}",
+        coverage.warnings
+    );
+
+    // Requesting jira again fills the month it missed and completes the run.
+    let (result, calls) = collect_incremental(&engine, &out, &requested, &["github", "jira"]);
+    assert_eq!(
+        calls,
+        vec![("jira".to_string(), window(day(2, 1), day(3, 1)))]
+    );
+    assert!(result.unrequested.is_empty());
+    assert_eq!(read_coverage(&out).completeness, Completeness::Complete);
+}
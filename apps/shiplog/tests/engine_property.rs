@@ -34,6 +34,7 @@ proptest! {
             events,
             coverage,
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         // We only care that it doesn't panic; errors are acceptable.
         let _ = engine.run(
@@ -59,6 +60,7 @@ proptest! {
             events,
             coverage,
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
 
         if let Ok((outputs, _)) = engine.run(
@@ -91,6 +93,7 @@ proptest! {
             events,
             coverage,
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
 
         if let Ok((outputs, _)) = engine.run(
@@ -121,6 +124,7 @@ proptest! {
             events,
             coverage,
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
 
         if let Ok((outputs, _)) = engine.run(
@@ -150,6 +154,7 @@ proptest! {
             events,
             coverage,
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
         let _ = engine.import(
             ingest,
@@ -174,6 +179,7 @@ proptest! {
             events,
             coverage,
             freshness: Vec::new(),
+            budget: Vec::new(),
         };
 
         let merged = engine.merge(
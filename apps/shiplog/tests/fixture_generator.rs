@@ -0,0 +1,196 @@
+//! Tests for the synthetic ledger generator behind `shiplog generate-fixture`.
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use shiplog::engine::read_events;
+use shiplog::fixtures::{DateSpread, EventMix, FixtureSpec, generate};
+use shiplog::schema::coverage::{Completeness, TimeWindow};
+use shiplog::schema::event::EventKind;
+
+fn spec(events: usize) -> FixtureSpec {
+    FixtureSpec {
+        events,
+        ..FixtureSpec::default()
+    }
+}
+
+#[test]
+fn same_spec_gives_the_same_events_and_seeds_change_them() {
+    let first = generate(&spec(300)).unwrap();
+    let again = generate(&spec(300)).unwrap();
+    let reseeded = generate(&FixtureSpec {
+        seed: 1,
+        ..spec(300)
+    })
+    .unwrap();
+
+    assert_eq!(first.events, again.events);
+    assert_ne!(first.events, reseeded.events);
+    assert!(
+        first
+            .events
+            .windows(2)
+            .all(|pair| pair[0].occurred_at <= pair[1].occurred_at)
+    );
+    let ids: BTreeSet<_> = first
+        .events
+        .iter()
+        .map(|event| event.id.0.as_str())
+        .collect();
+    assert_eq!(ids.len(), 300, "event ids should be unique");
+}
+
+#[test]
+fn events_stay_in_the_window_and_spread_over_the_requested_repos() {
+    let window = TimeWindow {
+        since: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+        until: NaiveDate::from_ymd_opt(2026, 6, 1).unwrap(),
+    };
+    for dates in [
+        DateSpread::Uniform,
+        DateSpread::Weekdays,
+        DateSpread::Bursty,
+    ] {
+        let fixture = generate(&FixtureSpec {
+            window: window.clone(),
+            repos: 4,
+            dates,
+            ..spec(500)
+        })
+        .unwrap();
+
+        assert!(
+            fixture
+                .events
+                .iter()
+                .all(|event| window.contains(event.occurred_at.date_naive())),
+            "{dates} events should fall inside the window"
+        );
+        let repos: BTreeSet<_> = fixture
+            .events
+            .iter()
+            .map(|event| event.repo.full_name.as_str())
+            .collect();
+        assert_eq!(repos.len(), 4, "{dates} should use every repo");
+    }
+
+    let weekdays = generate(&spec(500)).unwrap();
+    assert!(
+        weekdays
+            .events
+            .iter()
+            .all(|event| !matches!(event.occurred_at.weekday(), Weekday::Sat | Weekday::Sun))
+    );
+}
+
+#[test]
+fn mix_controls_which_kinds_appear() {
+    let mix: EventMix = "pr=1,manual=1".parse().unwrap();
+    let fixture = generate(&FixtureSpec { mix, ..spec(200) }).unwrap();
+
+    let kinds: BTreeSet<String> = fixture
+        .events
+        .iter()
+        .map(|event| event.kind.to_string())
+        .collect();
+    assert_eq!(
+        kinds,
+        BTreeSet::from(["Manual".into(), "PullRequest".into()])
+    );
+    assert_eq!(fixture.coverage.sources, ["github", "manual"]);
+    assert!(
+        fixture
+            .events
+            .iter()
+            .filter(|event| event.kind == EventKind::PullRequest)
+            .all(|event| event.canonical_id.is_some())
+    );
+}
+
+#[test]
+fn mix_and_spread_parse_and_display_round_trip() {
+    let mix: EventMix = "pr=6, review=3,issue=2,manual=1".parse().unwrap();
+    assert_eq!(mix, EventMix::default());
+    assert_eq!(mix.to_string().parse::<EventMix>().unwrap(), mix);
+    assert!("pr=0".parse::<EventMix>().is_err());
+    assert!(
+        "commits=3"
+            .parse::<EventMix>()
+            .unwrap_err()
+            .to_string()
+            .contains("unknown event kind `commits`")
+    );
+
+    assert_eq!("bursty".parse::<DateSpread>().unwrap(), DateSpread::Bursty);
+    assert!("weekly".parse::<DateSpread>().is_err());
+}
+
+#[test]
+fn coverage_counts_each_month_and_marks_incomplete_months_partial() {
+    let complete = generate(&spec(240)).unwrap();
+    assert_eq!(complete.coverage.slices.len(), 12);
+    assert_eq!(
+        complete
+            .coverage
+            .slices
+            .iter()
+            .map(|slice| slice.fetched)
+            .sum::<u64>(),
+        240
+    );
+    assert_eq!(complete.coverage.completeness, Completeness::Complete);
+    assert_eq!(complete.coverage.score, Some(100));
+
+    let partial = generate(&FixtureSpec {
+        incomplete_months: 2,
+        ..spec(240)
+    })
+    .unwrap();
+    let incomplete = partial
+        .coverage
+        .slices
+        .iter()
+        .filter(|slice| slice.total_count > slice.fetched)
+        .count();
+    assert_eq!(incomplete, 2);
+    assert_eq!(partial.coverage.completeness, Completeness::Partial);
+    assert!(partial.coverage.score < Some(100));
+}
+
+#[test]
+fn write_to_produces_a_ledger_the_json_source_reads_back() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let fixture = generate(&spec(25)).unwrap();
+
+    fixture.write_to(&tmp.path().join("demo")).unwrap();
+
+    let file = std::fs::File::open(tmp.path().join("demo/ledger.events.jsonl")).unwrap();
+    let events = read_events(std::io::BufReader::new(file))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(events, fixture.events);
+    assert!(tmp.path().join("demo/coverage.manifest.json").exists());
+}
+
+#[test]
+fn invalid_specs_are_rejected() {
+    assert!(
+        generate(&FixtureSpec {
+            repos: 0,
+            ..spec(10)
+        })
+        .is_err()
+    );
+    let day = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+    assert!(
+        generate(&FixtureSpec {
+            window: TimeWindow {
+                since: day,
+                until: day
+            },
+            ..spec(10)
+        })
+        .is_err()
+    );
+}
@@ -142,7 +142,7 @@ proptest! {
         prop_assert_ne!(id1, id2);
     }
 
-    // RunId suffix after "<prefix>_" is numeric nanoseconds.
+    // RunId suffix after "<prefix>_" is a 26-character Crockford base32 ULID.
     #[test]
     fn prop_run_id_suffix_format(prefix in "[a-z]{3,20}") {
         let id = RunId::now(&prefix);
@@ -150,8 +150,9 @@ proptest! {
         let expected_prefix = format!("{}_", prefix);
         prop_assert!(id_str.starts_with(&expected_prefix));
         let suffix = &id_str[prefix.len() + 1..];
-        prop_assert!(!suffix.is_empty());
-        prop_assert!(suffix.chars().all(|c| c.is_ascii_digit()));
+        prop_assert_eq!(suffix.len(), 26);
+        prop_assert!(suffix.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()));
+        prop_assert!(RunId::parse(&id_str).is_ok());
     }
 
     // Display matches inner value.
@@ -0,0 +1,274 @@
+//! Tests for importing GitHub account data-export archives.
+
+use std::path::Path;
+
+use assert_cmd::Command;
+use chrono::NaiveDate;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use predicates::prelude::*;
+use shiplog::ids::EventId;
+use shiplog::ingest::github_export::GithubExportIngestor;
+use shiplog::ports::Ingestor;
+use shiplog::schema::event::{ContributorRole, EventPayload, IssueState, PullRequestState};
+
+fn files() -> Vec<(&'static str, serde_json::Value)> {
+    vec![
+        (
+            "users_000001.json",
+            serde_json::json!([{ "type": "user", "url": "https://github.com/octo", "login": "octo" }]),
+        ),
+        (
+            "pull_requests_000001.json",
+            serde_json::json!([
+                {
+                    "type": "pull_request",
+                    "url": "https://github.com/acme/api/pull/12",
+                    "user": "https://github.com/octo",
+                    "repository": "https://github.com/acme/api",
+                    "title": "Add rate limiting",
+                    "created_at": "2025-02-03T10:00:00Z",
+                    "merged_at": "2025-02-05T16:00:00Z",
+                    "closed_at": "2025-02-05T16:00:00Z"
+                },
+                {
+                    "type": "pull_request",
+                    "url": "https://github.com/acme/api/pull/13",
+                    "user": "https://github.com/someone-else",
+                    "title": "Not mine",
+                    "created_at": "2025-02-04T10:00:00Z",
+                    "merged_at": null,
+                    "closed_at": null
+                },
+                {
+                    "type": "pull_request",
+                    "url": "https://github.com/acme/api/pull/2",
+                    "user": "https://github.com/octo",
+                    "title": "Before the window",
+                    "created_at": "2024-06-01T10:00:00Z",
+                    "merged_at": null,
+                    "closed_at": "2024-06-02T10:00:00Z"
+                }
+            ]),
+        ),
+        (
+            "issues_000001.json",
+            serde_json::json!([{
+                "type": "issue",
+                "url": "https://github.com/acme/web/issues/7",
+                "user": "https://github.com/octo",
+                "title": "Login page flickers",
+                "created_at": "2025-03-01T09:00:00Z",
+                "closed_at": null
+            }]),
+        ),
+        (
+            "pull_request_reviews_000001.json",
+            serde_json::json!([
+                {
+                    "type": "pull_request_review",
+                    "url": "https://github.com/acme/api/pull/13/files#pullrequestreview-9001",
+                    "pull_request": "https://github.com/acme/api/pull/13",
+                    "user": "https://github.com/octo",
+                    "state": 40,
+                    "submitted_at": "2025-02-06T12:00:00Z",
+                    "created_at": "2025-02-06T11:00:00Z"
+                },
+                {
+                    "type": "pull_request_review",
+                    "url": "https://github.com/acme/api/pull/12/files#pullrequestreview-9002",
+                    "pull_request": "https://github.com/acme/api/pull/12",
+                    "user": "https://github.com/reviewer-rae",
+                    "state": 40,
+                    "submitted_at": "2025-02-04T12:00:00Z",
+                    "created_at": "2025-02-04T11:00:00Z"
+                }
+            ]),
+        ),
+        ("schema.json", serde_json::json!({ "version": "1.2.0" })),
+    ]
+}
+
+fn write_archive(path: &Path, files: &[(&str, serde_json::Value)]) {
+    let gz = GzEncoder::new(std::fs::File::create(path).unwrap(), Compression::default());
+    let mut tar = tar::Builder::new(gz);
+    for (name, value) in files {
+        let body = serde_json::to_vec(value).unwrap();
+        let mut header = tar::Header::new_gnu();
+        header.set_size(body.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, format!("export/{name}"), body.as_slice())
+            .unwrap();
+    }
+    tar.into_inner().unwrap().finish().unwrap();
+}
+
+fn window() -> (NaiveDate, NaiveDate) {
+    (
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+    )
+}
+
+#[test]
+fn imports_authored_prs_issues_and_reviews_inside_the_window() {
+    let tmp = tempfile::tempdir().unwrap();
+    let archive = tmp.path().join("export.tar.gz");
+    write_archive(&archive, &files());
+    let (since, until) = window();
+
+    let output = GithubExportIngestor::new(&archive, since, until)
+        .ingest()
+        .unwrap();
+
+    assert_eq!(output.events.len(), 3);
+    assert_eq!(output.coverage.user, "octo");
+    assert_eq!(output.coverage.mode, "export");
+    assert_eq!(output.coverage.slices[0].total_count, 4);
+    assert_eq!(output.coverage.slices[0].fetched, 3);
+    assert!(output.coverage.warnings.is_empty());
+
+    let pr = &output.events[0];
+    assert_eq!(pr.repo.full_name, "acme/api");
+    assert_eq!(
+        pr.occurred_at.to_rfc3339(),
+        "2025-02-05T16:00:00+00:00",
+        "merged PRs are dated by merge time"
+    );
+    let EventPayload::PullRequest(payload) = &pr.payload else {
+        panic!("expected a pull request, got {:?}", pr.payload);
+    };
+    assert_eq!(payload.number, 12);
+    assert_eq!(payload.state, PullRequestState::Merged);
+    assert_eq!(pr.contributors.len(), 1);
+    assert_eq!(pr.contributors[0].login, "reviewer-rae");
+    assert_eq!(pr.contributors[0].role, ContributorRole::Reviewer);
+
+    let EventPayload::Review(review) = &output.events[1].payload else {
+        panic!("expected a review, got {:?}", output.events[1].payload);
+    };
+    assert_eq!(review.pull_number, 13);
+    assert_eq!(review.pull_title, "Not mine");
+    assert_eq!(review.pull_author.as_deref(), Some("someone-else"));
+    assert_eq!(review.state, "approved");
+
+    let EventPayload::Issue(issue) = &output.events[2].payload else {
+        panic!("expected an issue, got {:?}", output.events[2].payload);
+    };
+    assert_eq!(issue.number, 7);
+    assert_eq!(issue.state, IssueState::Open);
+    assert_eq!(output.events[2].repo.full_name, "acme/web");
+}
+
+#[test]
+fn event_ids_match_the_api_ingestor_so_merges_dedupe() {
+    let tmp = tempfile::tempdir().unwrap();
+    let archive = tmp.path().join("export.tar.gz");
+    write_archive(&archive, &files());
+    let (since, until) = window();
+
+    let output = GithubExportIngestor::new(&archive, since, until)
+        .ingest()
+        .unwrap();
+
+    assert_eq!(
+        output.events[0].id,
+        EventId::from_parts(["github", "pr", "acme/api", "12"])
+    );
+    assert_eq!(
+        output.events[1].id,
+        EventId::from_parts(["github", "review", "acme/api", "13", "9001"])
+    );
+}
+
+#[test]
+fn reads_an_extracted_directory() {
+    let tmp = tempfile::tempdir().unwrap();
+    for (name, value) in files() {
+        std::fs::write(tmp.path().join(name), serde_json::to_vec(&value).unwrap()).unwrap();
+    }
+    let (since, until) = window();
+
+    let output = GithubExportIngestor::new(tmp.path(), since, until)
+        .ingest()
+        .unwrap();
+
+    assert_eq!(output.events.len(), 3);
+}
+
+#[test]
+fn user_filter_is_case_insensitive_and_excludes_other_authors() {
+    let tmp = tempfile::tempdir().unwrap();
+    let archive = tmp.path().join("export.tar.gz");
+    write_archive(&archive, &files());
+    let (since, until) = window();
+
+    let output = GithubExportIngestor::new(&archive, since, until)
+        .with_user("Someone-Else")
+        .ingest()
+        .unwrap();
+
+    assert_eq!(output.events.len(), 1);
+    assert_eq!(output.events[0].actor.login, "Someone-Else");
+}
+
+#[test]
+fn multiple_users_require_an_explicit_user() {
+    let tmp = tempfile::tempdir().unwrap();
+    let archive = tmp.path().join("export.tar.gz");
+    let mut files = files();
+    files[0].1 = serde_json::json!([
+        { "url": "https://github.com/octo", "login": "octo" },
+        { "url": "https://github.com/hubot", "login": "hubot" }
+    ]);
+    write_archive(&archive, &files);
+    let (since, until) = window();
+
+    let err = GithubExportIngestor::new(&archive, since, until)
+        .ingest()
+        .unwrap_err();
+
+    assert!(err.to_string().contains("lists 2 users; pass --user"));
+}
+
+#[test]
+fn archives_without_content_files_warn() {
+    let tmp = tempfile::tempdir().unwrap();
+    let archive = tmp.path().join("export.tar.gz");
+    write_archive(&archive, &files()[..1]);
+    let (since, until) = window();
+
+    let output = GithubExportIngestor::new(&archive, since, until)
+        .ingest()
+        .unwrap();
+
+    assert!(output.events.is_empty());
+    assert!(output.coverage.warnings[0].contains("contains no pull_requests"));
+}
+
+#[test]
+fn collect_github_export_writes_a_packet() {
+    let tmp = tempfile::tempdir().unwrap();
+    let archive = tmp.path().join("export.tar.gz");
+    write_archive(&archive, &files());
+    let out = tmp.path().join("out");
+
+    Command::from_std(std::process::Command::new(env!("CARGO_BIN_EXE_shiplog")))
+        .args(["collect", "--out"])
+        .arg(&out)
+        .args(["github-export", "--archive"])
+        .arg(&archive)
+        .args(["--since", "2025-01-01", "--until", "2025-04-01"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Collected and wrote:"));
+
+    let run_dir = std::fs::read_dir(&out)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.is_dir())
+        .unwrap();
+    let packet = std::fs::read_to_string(run_dir.join("packet.md")).unwrap();
+    assert!(packet.contains("Add rate limiting"));
+}
@@ -1,8 +1,8 @@
 //! Comprehensive tests for shiplog JSON ingest module: JSONL parsing, error handling, edge cases.
 
 use chrono::{NaiveDate, Utc};
-use shiplog::ids::{EventId, RunId};
-use shiplog::ingest::json::{JsonIngestor, parse_events_jsonl};
+use shiplog::ids::{EventId, OpaqueId, RunId};
+use shiplog::ingest::json::{JsonIngestor, parse_events_jsonl, read_events_jsonl};
 use shiplog::ports::Ingestor;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
 use shiplog::schema::event::*;
@@ -15,6 +15,7 @@ use support::{FILE_COVERAGE_MANIFEST_JSON, FILE_LEDGER_EVENTS_JSONL};
 
 fn make_event(repo: &str, id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -46,11 +47,15 @@ fn make_event(repo: &str, id: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_manual_event(id: &str, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", id]),
         kind: EventKind::Manual,
         occurred_at: Utc::now(),
@@ -70,6 +75,9 @@ fn make_manual_event(id: &str, title: &str) -> EventEnvelope {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec!["manual".into()],
         links: vec![Link {
@@ -79,13 +87,17 @@ fn make_manual_event(id: &str, title: &str) -> EventEnvelope {
         source: SourceRef {
             system: SourceSystem::Manual,
             url: None,
-            opaque_id: Some(id.into()),
+            opaque_id: Some(OpaqueId::from_raw("test", id)),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc::now(),
         user: "tester".into(),
@@ -98,6 +110,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -189,6 +204,20 @@ fn parse_invalid_json_on_first_line() {
     assert!(msg.contains("line 1"), "Expected line 1 in: {msg}");
 }
 
+#[test]
+fn read_events_jsonl_streams_a_reader_with_line_numbers_and_schema_violations() {
+    let line = serde_json::to_string(&make_event("org/repo", "1")).unwrap();
+    let text = format!("{line}\n\n{{\"id\": 7}}\n");
+    let err = read_events_jsonl(std::io::Cursor::new(text.as_bytes()), "ledger").unwrap_err();
+    assert_eq!(err.line(), 3);
+    assert_eq!(err.input_source(), "ledger");
+    assert!(!err.violations().is_empty(), "{err}");
+
+    let events =
+        read_events_jsonl(std::io::Cursor::new(format!("{line}\n\n{line}")), "ledger").unwrap();
+    assert_eq!(events.len(), 2);
+}
+
 #[test]
 fn parse_mixed_event_types() {
     let pr_event = make_event("org/repo", "pr1");
@@ -347,7 +376,10 @@ fn ingestor_preserves_all_event_fields() {
     assert_eq!(loaded.links.len(), 1);
     assert_eq!(loaded.links[0].label, "doc");
     assert_eq!(loaded.source.system, SourceSystem::Manual);
-    assert_eq!(loaded.source.opaque_id.as_deref(), Some("x"));
+    assert_eq!(
+        loaded.source.opaque_id,
+        Some(OpaqueId::from_raw("test", "x"))
+    );
 }
 
 #[test]
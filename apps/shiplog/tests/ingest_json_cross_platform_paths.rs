@@ -17,6 +17,7 @@ use support::{FILE_COVERAGE_MANIFEST_JSON, FILE_LEDGER_EVENTS_JSONL};
 
 fn make_event(repo: &str, id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -48,11 +49,15 @@ fn make_event(repo: &str, id: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc::now(),
         user: "tester".into(),
@@ -65,6 +70,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -4,7 +4,7 @@
 //! and empty lines in JSONL files.
 
 use chrono::{NaiveDate, Utc};
-use shiplog::ids::{EventId, RunId};
+use shiplog::ids::{EventId, OpaqueId, RunId};
 use shiplog::ingest::json::{JsonIngestor, parse_events_jsonl};
 use shiplog::ports::Ingestor;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, TimeWindow};
@@ -18,6 +18,7 @@ use support::{FILE_COVERAGE_MANIFEST_JSON, FILE_LEDGER_EVENTS_JSONL};
 
 fn make_pr_event(repo: &str, id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -49,11 +50,15 @@ fn make_pr_event(repo: &str, id: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_review_event(id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", "review", id]),
         kind: EventKind::Review,
         occurred_at: Utc::now(),
@@ -72,19 +77,25 @@ fn make_review_event(id: &str) -> EventEnvelope {
             submitted_at: Utc::now(),
             state: "APPROVED".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![],
         source: SourceRef {
             system: SourceSystem::Github,
             url: None,
-            opaque_id: Some(id.into()),
+            opaque_id: Some(OpaqueId::from_raw("test", id)),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_manual_event(id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", id]),
         kind: EventKind::Manual,
         occurred_at: Utc::now(),
@@ -104,19 +115,26 @@ fn make_manual_event(id: &str) -> EventEnvelope {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
         source: SourceRef {
             system: SourceSystem::Manual,
             url: None,
-            opaque_id: Some(id.into()),
+            opaque_id: Some(OpaqueId::from_raw("test", id)),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc::now(),
         user: "tester".into(),
@@ -129,6 +147,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -175,6 +196,60 @@ fn valid_json_but_wrong_schema_reports_error() {
     assert!(msg.contains("line 1"), "expected line ref in: {msg}");
 }
 
+#[test]
+fn wrong_schema_line_lists_field_violations() {
+    let mut value = serde_json::to_value(make_pr_event("org/repo", "1")).unwrap();
+    value["payload"]["data"]["number"] = serde_json::json!("42");
+    value["repo"]["visibility"] = serde_json::json!("Internal");
+    let text = format!("{value}\n");
+
+    let err = parse_events_jsonl(&text, "typed").unwrap_err();
+    let rendered: Vec<String> = err.violations().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "/payload/data/number: expected integer, found string",
+            "/repo/visibility: expected one of \"Public\", \"Private\", \"Unknown\", found \"Internal\"",
+        ]
+    );
+    let msg = err.to_string();
+    assert!(msg.contains("(schema: /payload/data/number"), "{msg}");
+}
+
+#[test]
+fn syntax_errors_carry_no_schema_violations() {
+    let err = parse_events_jsonl("{broken", "syntax").unwrap_err();
+    assert!(err.violations().is_empty());
+    assert!(!err.to_string().contains("schema:"));
+}
+
+#[test]
+fn malformed_coverage_names_offending_fields() {
+    let tmp = tempfile::tempdir().unwrap();
+    let (events_path, coverage_path) = write_events_and_coverage(
+        tmp.path(),
+        &[make_pr_event("org/repo", "1")],
+        &make_coverage(),
+    );
+    let mut coverage = serde_json::to_value(make_coverage()).unwrap();
+    coverage["window"]["since"] = serde_json::json!("January");
+    std::fs::write(&coverage_path, coverage.to_string()).unwrap();
+
+    let err = JsonIngestor {
+        events_path,
+        coverage_path,
+    }
+    .ingest()
+    .unwrap_err();
+    let msg = format!("{err:#}");
+    assert!(
+        msg.contains(
+            "does not match the coverage-manifest schema: /window/since: expected a YYYY-MM-DD date"
+        ),
+        "{msg}"
+    );
+}
+
 #[test]
 fn json_array_instead_of_jsonl_reports_error() {
     let ev = make_pr_event("org/repo", "1");
@@ -1,6 +1,7 @@
 //! BDD-style integration tests for the manual event ingestor.
 
 use chrono::{NaiveDate, Utc};
+use shiplog::ids::OpaqueId;
 use shiplog::ingest::manual::{ManualIngestor, create_entry, write_manual_events};
 use shiplog::ports::Ingestor;
 use shiplog::schema::event::{ManualDate, ManualEventType, ManualEventsFile};
@@ -80,7 +81,8 @@ fn when_ingestor_runs(ctx: &mut ScenarioContext) -> Result<(), String> {
     if let Some(ev) = output.events.first()
         && let Some(id) = &ev.source.opaque_id
     {
-        ctx.strings.insert("first_event_id".to_string(), id.clone());
+        ctx.strings
+            .insert("first_event_id".to_string(), id.to_string());
     }
     Ok(())
 }
@@ -159,7 +161,8 @@ fn then_only_in_range_event_is_present(ctx: &ScenarioContext) -> Result<(), Stri
     let count = assert_present(ctx.number("event_count"), "event_count")?;
     assert_eq(count, 1, "event count")?;
     let first_id = assert_present(ctx.string("first_event_id"), "first_event_id")?;
-    assert_eq(first_id, "in-range", "first event id")
+    let expected = OpaqueId::from_raw("manual", "in-range");
+    assert_eq(first_id, expected.as_str(), "first event id")
 }
 
 #[test]
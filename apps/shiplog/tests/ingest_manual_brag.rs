@@ -0,0 +1,152 @@
+//! Tests for parsing brag-doc markdown into manual entries.
+
+use chrono::NaiveDate;
+use shiplog::ingest::manual::brag::BragDocParser;
+use shiplog::schema::event::ManualDate;
+
+fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+const DOC: &str = "\
+# Brag doc
+
+## 2025
+
+### March
+
+- Mar 4: Shipped the [billing retry queue](https://github.com/acme/billing/pull/88)
+  - Cut failed renewals from 3% to 0.4%
+  - Dashboard: https://grafana.example.com/d/renewals.
+- Led the DB failover drill
+
+### Q2
+
+* **Mentored** two new hires through onboarding
+1. 14 May 2025 - Wrote the auth migration design doc
+
+## Platform team
+
+- 2025-07-09 Moved CI to the new runners
+- Paired on flaky tests
+";
+
+#[test]
+fn bullets_take_dates_from_text_or_the_nearest_dated_heading() {
+    let import = BragDocParser::new().parse(DOC);
+
+    let dates: Vec<_> = import.entries.iter().map(|entry| &entry.date).collect();
+    assert_eq!(
+        dates,
+        [
+            &ManualDate::Single(day(2025, 3, 4)),
+            &ManualDate::Range {
+                start: day(2025, 3, 1),
+                end: day(2025, 3, 31)
+            },
+            &ManualDate::Range {
+                start: day(2025, 4, 1),
+                end: day(2025, 6, 30)
+            },
+            &ManualDate::Single(day(2025, 5, 14)),
+            &ManualDate::Single(day(2025, 7, 9)),
+        ]
+    );
+    assert_eq!(import.skipped.len(), 1);
+    assert_eq!(import.skipped[0].line, 20);
+    assert_eq!(import.skipped[0].text, "Paired on flaky tests");
+}
+
+#[test]
+fn titles_drop_dates_markup_and_link_targets() {
+    let import = BragDocParser::new().parse(DOC);
+
+    let titles: Vec<_> = import
+        .entries
+        .iter()
+        .map(|entry| entry.title.as_str())
+        .collect();
+    assert_eq!(
+        titles,
+        [
+            "Shipped the billing retry queue",
+            "Led the DB failover drill",
+            "Mentored two new hires through onboarding",
+            "Wrote the auth migration design doc",
+            "Moved CI to the new runners",
+        ]
+    );
+}
+
+#[test]
+fn indented_lines_become_description_and_links_become_receipts() {
+    let import = BragDocParser::new().parse(DOC);
+    let entry = &import.entries[0];
+
+    assert_eq!(
+        entry.description.as_deref(),
+        Some("Cut failed renewals from 3% to 0.4%\nDashboard:")
+    );
+    let receipts: Vec<_> = entry
+        .receipts
+        .iter()
+        .map(|link| (link.label.as_str(), link.url.as_str()))
+        .collect();
+    assert_eq!(
+        receipts,
+        [
+            (
+                "billing retry queue",
+                "https://github.com/acme/billing/pull/88"
+            ),
+            ("link", "https://grafana.example.com/d/renewals"),
+        ]
+    );
+}
+
+#[test]
+fn undated_headings_are_reported_as_sections() {
+    let import = BragDocParser::new().parse(DOC);
+
+    assert_eq!(import.entries[0].section.as_deref(), Some("Brag doc"));
+    assert_eq!(import.entries[4].section.as_deref(), Some("Platform team"));
+}
+
+#[test]
+fn default_year_dates_yearless_bullets_outside_year_headings() {
+    let doc = "- Jan 9: Fixed the export job\n";
+
+    assert!(BragDocParser::new().parse(doc).entries.is_empty());
+    let import = BragDocParser::new().with_default_year(2024).parse(doc);
+    assert_eq!(import.entries[0].date, ManualDate::Single(day(2024, 1, 9)));
+}
+
+#[test]
+fn month_words_inside_other_words_are_not_dates() {
+    let import = BragDocParser::new().parse("- Made 3 decisions about the mayday runbook\n");
+
+    assert!(import.entries.is_empty());
+    assert_eq!(import.skipped.len(), 1);
+}
+
+#[test]
+fn custom_date_patterns_run_before_the_defaults() {
+    let parser = BragDocParser::new()
+        .with_date_patterns([r"\b(?P<day>\d{1,2})/(?P<month>\d{1,2})/(?P<year>\d{4})\b"])
+        .unwrap();
+
+    let import = parser.parse("- 03/02/2025 Ran the quarterly restore test\n");
+
+    assert_eq!(import.entries[0].date, ManualDate::Single(day(2025, 2, 3)));
+    assert_eq!(import.entries[0].title, "Ran the quarterly restore test");
+}
+
+#[test]
+fn custom_date_patterns_need_a_month_group() {
+    let err = BragDocParser::new()
+        .with_date_patterns([r"(?P<year>\d{4})"])
+        .unwrap_err();
+
+    assert!(err.to_string().contains("named `month` group"));
+    assert!(BragDocParser::new().with_date_patterns(["("]).is_err());
+}
@@ -35,6 +35,9 @@ fn minimal_entry(id: &str, event_type: ManualEventType, d: NaiveDate) -> ManualE
         tags: vec![],
         receipts: vec![],
         impact: None,
+        metrics: Vec::new(),
+        evidence: Vec::new(),
+        repeat: None,
     }
 }
 
@@ -260,6 +263,9 @@ fn mixed_single_and_range_dates_in_one_file() {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             },
             ManualEventEntry {
                 id: "range-date".into(),
@@ -274,6 +280,9 @@ fn mixed_single_and_range_dates_in_one_file() {
                 tags: vec![],
                 receipts: vec![],
                 impact: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+                repeat: None,
             },
         ],
     };
@@ -307,6 +316,9 @@ fn unicode_in_title_and_description_roundtrips() {
                 url: "https://example.com/文書".into(),
             }],
             impact: Some("改善 — improved performance by 50%".into()),
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         }],
     };
     write_manual_events(&path, &file).unwrap();
@@ -337,6 +349,9 @@ fn unicode_events_are_ingested_correctly() {
             tags: vec![],
             receipts: vec![],
             impact: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         }],
     };
     write_manual_events(&path, &file).unwrap();
@@ -419,6 +434,9 @@ fn fully_populated_entry_roundtrips() {
                 },
             ],
             impact: Some("Reduced MTTR from 4h to 45min".into()),
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         }],
     };
     write_manual_events(&path, &file).unwrap();
@@ -1,6 +1,7 @@
 //! Integration test proving manual ingest delegates to the manual event contract.
 
 use chrono::NaiveDate;
+use shiplog::ids::OpaqueId;
 use shiplog::ingest::manual::{
     ManualIngestor, create_empty_file, create_entry, write_manual_events,
 };
@@ -35,6 +36,9 @@ fn ingest_manual_events_uses_manual_event_contract() {
     let output = ingestor.ingest().expect("ingest success");
 
     assert_eq!(output.events.len(), 1);
-    assert_eq!(output.events[0].source.opaque_id.as_deref(), Some("inside"));
+    assert_eq!(
+        output.events[0].source.opaque_id,
+        Some(OpaqueId::from_raw("manual", "inside"))
+    );
     assert_eq!(output.coverage.slices[0].total_count, 2);
 }
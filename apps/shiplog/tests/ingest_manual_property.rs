@@ -2,6 +2,7 @@
 
 use chrono::{NaiveDate, Utc};
 use proptest::prelude::*;
+use shiplog::ids::OpaqueId;
 use shiplog::ingest::manual::{ManualIngestor, write_manual_events};
 use shiplog::ports::Ingestor;
 use shiplog::schema::event::{ManualDate, ManualEventEntry, ManualEventType, ManualEventsFile};
@@ -60,6 +61,9 @@ fn arb_entry() -> impl Strategy<Value = ManualEventEntry> {
             tags: Vec::new(),
             receipts: Vec::new(),
             impact: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         })
 }
 
@@ -128,8 +132,11 @@ proptest! {
 
         // Every returned event must overlap the [since, until) window.
         for ev in &output.events {
-            let opaque = ev.source.opaque_id.as_deref().unwrap();
-            let entry = entries.iter().find(|e| e.id == opaque).unwrap();
+            let opaque = ev.source.opaque_id.as_ref().unwrap();
+            let entry = entries
+                .iter()
+                .find(|e| OpaqueId::from_raw("manual", &e.id) == *opaque)
+                .unwrap();
             let (start, end) = match &entry.date {
                 ManualDate::Single(d) => (*d, *d),
                 ManualDate::Range { start, end } => (*start, *end),
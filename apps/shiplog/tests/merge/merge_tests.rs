@@ -5,9 +5,11 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, ManualEvent, ManualEventType, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 
 fn make_event(id: &str, occurred_at: chrono::DateTime<Utc>) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts([id]),
         kind: EventKind::Manual,
         occurred_at,
@@ -27,6 +29,9 @@ fn make_event(id: &str, occurred_at: chrono::DateTime<Utc>) -> EventEnvelope {
             started_at: None,
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
@@ -35,6 +40,9 @@ fn make_event(id: &str, occurred_at: chrono::DateTime<Utc>) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -0,0 +1,183 @@
+//! Integration tests for completion notifications.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+use shiplog::notify::{Notifier, RunNotification, SlackWebhook, SmtpEmail, SmtpSecurity};
+use shiplog::readiness::ReadinessGrade;
+
+fn notification(warnings: usize) -> RunNotification {
+    RunNotification {
+        run_id: "run_1".into(),
+        events: 12,
+        warnings: (0..warnings).map(|n| format!("warning {n}")).collect(),
+        readiness_score: 82,
+        readiness_grade: ReadinessGrade::B,
+        packet: "out/run_1/packet.md".into(),
+    }
+}
+
+/// Accept one HTTP request, answer `status`, and return the request body.
+fn serve_one_http(status: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/hooks/T000", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':')
+                && name.eq_ignore_ascii_case("content-length")
+            {
+                length = value.trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        let mut stream = reader.into_inner();
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok"
+        )
+        .unwrap();
+        String::from_utf8(body).unwrap()
+    });
+    (url, handle)
+}
+
+/// Speak just enough SMTP to accept one message, and return its DATA.
+fn serve_one_smtp() -> (u16, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let mut reader = BufReader::new(stream);
+        writer.write_all(b"220 localhost ESMTP\r\n").unwrap();
+        let mut data = String::new();
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap() == 0 {
+                break;
+            }
+            let command = line.to_ascii_uppercase();
+            if command.starts_with("EHLO") {
+                writer.write_all(b"250 localhost\r\n").unwrap();
+            } else if command.starts_with("DATA") {
+                writer.write_all(b"354 go ahead\r\n").unwrap();
+                loop {
+                    let mut body_line = String::new();
+                    reader.read_line(&mut body_line).unwrap();
+                    if body_line == ".\r\n" {
+                        break;
+                    }
+                    data.push_str(&body_line);
+                }
+                writer.write_all(b"250 queued\r\n").unwrap();
+            } else if command.starts_with("QUIT") {
+                writer.write_all(b"221 bye\r\n").unwrap();
+                break;
+            } else {
+                writer.write_all(b"250 ok\r\n").unwrap();
+            }
+        }
+        data
+    });
+    (port, handle)
+}
+
+#[test]
+fn text_caps_warnings_and_points_at_the_coverage_report() {
+    let text = notification(7).text();
+
+    assert!(text.contains("Warnings: 7"));
+    assert!(text.contains("- warning 4"));
+    assert!(!text.contains("- warning 5"));
+    assert!(text.contains("- + 2 more in coverage.report.md"));
+    assert!(text.ends_with("Packet: out/run_1/packet.md\n"));
+}
+
+#[test]
+fn subject_summarizes_events_and_readiness() {
+    assert_eq!(
+        notification(0).subject(),
+        "shiplog run run_1: 12 events, readiness 82/100 (B)"
+    );
+}
+
+#[test]
+fn slack_webhook_posts_the_summary_as_text() {
+    let (url, server) = serve_one_http("200 OK");
+
+    SlackWebhook::new(url)
+        .unwrap()
+        .send(&notification(1))
+        .unwrap();
+
+    let body: serde_json::Value = serde_json::from_str(&server.join().unwrap()).unwrap();
+    let text = body["text"].as_str().unwrap();
+    assert!(text.starts_with("shiplog run run_1 finished."));
+    assert!(text.contains("- warning 0"));
+}
+
+#[test]
+fn slack_webhook_reports_rejected_posts() {
+    let (url, server) = serve_one_http("404 Not Found");
+
+    let err = SlackWebhook::new(url)
+        .unwrap()
+        .send(&notification(0))
+        .unwrap_err();
+    server.join().unwrap();
+
+    assert!(format!("{err:#}").contains("Slack webhook rejected the message"));
+}
+
+#[test]
+fn slack_webhook_rejects_non_http_urls() {
+    let err = SlackWebhook::new("ftp://hooks.slack.com/x").unwrap_err();
+
+    assert!(err.to_string().contains("must use https"));
+}
+
+#[test]
+fn smtp_email_sends_subject_and_body() {
+    let (port, server) = serve_one_smtp();
+
+    SmtpEmail::new(
+        "127.0.0.1",
+        "shiplog <shiplog@example.com>",
+        &["me@example.com".to_string()],
+    )
+    .unwrap()
+    .with_security(SmtpSecurity::None)
+    .with_port(port)
+    .send(&notification(0))
+    .unwrap();
+
+    let data = server.join().unwrap();
+    assert!(data.contains("Subject: shiplog run run_1: 12 events, readiness 82/100 (B)"));
+    assert!(data.contains("To: me@example.com"));
+    assert!(data.contains("Readiness: 82/100 (B)"));
+}
+
+#[test]
+fn smtp_email_validates_addresses_and_security() {
+    assert!(
+        SmtpEmail::new(
+            "smtp.example.com",
+            "not an address",
+            &["me@example.com".into()]
+        )
+        .is_err()
+    );
+    assert!(SmtpEmail::new("smtp.example.com", "shiplog@example.com", &[]).is_err());
+    assert_eq!("tls".parse::<SmtpSecurity>().unwrap(), SmtpSecurity::Tls);
+    assert!("ssl".parse::<SmtpSecurity>().is_err());
+}
@@ -156,6 +156,9 @@ fn multi_source_json_plus_manual_combined_output() -> Result<()> {
             tags: vec!["incident".to_string(), "payments".to_string()],
             receipts: vec![],
             impact: Some("Reduced error rate from 5% to 0.1%".to_string()),
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         }],
     };
     write_manual_events(&manual_path, &manual_file)?;
@@ -186,11 +189,11 @@ fn multi_source_json_plus_manual_combined_output() -> Result<()> {
         merged.events.len()
     );
 
-    // Verify manual event is present
-    let has_manual = merged
-        .events
-        .iter()
-        .any(|e| matches!(e.payload, shiplog::schema::event::EventPayload::Manual(_)));
+    // Verify the manual incident is present, promoted to a structured incident
+    let has_manual = merged.events.iter().any(|e| {
+        e.source.system == shiplog::schema::event::SourceSystem::Manual
+            && matches!(e.payload, shiplog::schema::event::EventPayload::Incident(_))
+    });
     assert!(has_manual, "merged output should contain manual events");
 
     // Run the full pipeline with merged data
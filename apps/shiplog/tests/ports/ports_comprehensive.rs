@@ -2,7 +2,7 @@
 
 use anyhow::anyhow;
 use chrono::{NaiveDate, TimeZone, Utc};
-use shiplog::ids::{EventId, RunId, WorkstreamId};
+use shiplog::ids::{EventId, OpaqueId, RunId, WorkstreamId};
 use shiplog::ports::{IngestOutput, Ingestor, Redactor, Renderer, WorkstreamClusterer};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::{
@@ -10,12 +10,14 @@ use shiplog::schema::event::{
     PullRequestEvent, PullRequestState, RepoRef, RepoVisibility, ReviewEvent, SourceRef,
     SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 
 // ── Helpers ─────────────────────────────────────────────────────────────────
 
 fn make_coverage(user: &str) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc::now(),
         user: user.into(),
@@ -28,6 +30,9 @@ fn make_coverage(user: &str) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -44,6 +49,8 @@ fn make_workstreams(count: usize) -> WorkstreamsFile {
                 events: vec![EventId::from_parts(["ev", &i.to_string()])],
                 receipts: vec![],
                 stats: WorkstreamStats::zero(),
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             })
             .collect(),
     }
@@ -51,6 +58,7 @@ fn make_workstreams(count: usize) -> WorkstreamsFile {
 
 fn make_pr_event(id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["pr", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
@@ -83,13 +91,17 @@ fn make_pr_event(id: &str) -> EventEnvelope {
         source: SourceRef {
             system: SourceSystem::Github,
             url: Some("https://api.github.com".into()),
-            opaque_id: Some("pr-10".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "pr-10")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_manual_event(id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", id]),
         kind: EventKind::Manual,
         occurred_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
@@ -109,6 +121,9 @@ fn make_manual_event(id: &str) -> EventEnvelope {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 1, 16).unwrap()),
             impact: Some("High".into()),
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
@@ -117,11 +132,15 @@ fn make_manual_event(id: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_review_event(id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["review", id]),
         kind: EventKind::Review,
         occurred_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
@@ -140,6 +159,8 @@ fn make_review_event(id: &str) -> EventEnvelope {
             state: "APPROVED".into(),
             submitted_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec!["review".into()],
         links: vec![],
@@ -148,6 +169,9 @@ fn make_review_event(id: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -267,6 +291,8 @@ impl WorkstreamClusterer for CountingClusterer {
                     events: events.iter().map(|e| e.id.clone()).collect(),
                     receipts: vec![],
                     stats: WorkstreamStats::zero(),
+                    receipt_weights: Default::default(),
+                    rules: Default::default(),
                 }]
             },
         })
@@ -402,6 +428,7 @@ fn ingest_output_clone() {
         events: vec![make_pr_event("1")],
         coverage: make_coverage("user"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
     let cloned = output.clone();
     assert_eq!(cloned.events.len(), 1);
@@ -414,6 +441,7 @@ fn ingest_output_debug() {
         events: vec![],
         coverage: make_coverage("debug_user"),
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
     let debug = format!("{:?}", output);
     assert!(debug.contains("IngestOutput"));
@@ -433,6 +461,7 @@ fn ingest_output_with_coverage_slices() {
         fetched: 50,
         incomplete_results: Some(false),
         notes: vec!["note".into()],
+        source: None,
     });
     cov.completeness = Completeness::Complete;
 
@@ -440,6 +469,7 @@ fn ingest_output_with_coverage_slices() {
         events: vec![],
         coverage: cov,
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
     assert_eq!(output.coverage.slices.len(), 1);
     assert_eq!(output.coverage.slices[0].total_count, 50);
@@ -455,6 +485,7 @@ fn ingest_output_partial_completeness() {
         events: vec![],
         coverage: cov,
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
     assert_eq!(output.coverage.completeness, Completeness::Partial);
     assert_eq!(output.coverage.warnings.len(), 1);
@@ -486,6 +517,7 @@ fn pipeline_ingest_cluster_render_redact() {
                 events: vec![make_pr_event("p1"), make_review_event("r1")],
                 coverage: make_coverage("octocat"),
                 freshness: Vec::new(),
+                budget: Vec::new(),
             })
         }
     }
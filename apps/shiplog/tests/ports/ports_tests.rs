@@ -8,6 +8,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, Link, PullRequestEvent, PullRequestState,
     RepoRef, RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 use std::sync::Arc;
 
@@ -19,6 +20,7 @@ impl Ingestor for MockIngestor {
         Ok(IngestOutput {
             events: vec![],
             coverage: CoverageManifest {
+                schema_version: SchemaVersion::CURRENT,
                 run_id: RunId::now("test"),
                 generated_at: Utc::now(),
                 user: "test".to_string(),
@@ -31,8 +33,12 @@ impl Ingestor for MockIngestor {
                 slices: vec![],
                 warnings: vec![],
                 completeness: Completeness::Complete,
+                score: None,
+                timezone: None,
+                budget: vec![],
             },
             freshness: Vec::new(),
+            budget: Vec::new(),
         })
     }
 }
@@ -55,6 +61,8 @@ impl WorkstreamClusterer for MockClusterer {
                     events: vec![e.id.clone()],
                     receipts: vec![],
                     stats: WorkstreamStats::zero(),
+                    receipt_weights: Default::default(),
+                    rules: Default::default(),
                 })
                 .collect(),
         })
@@ -105,6 +113,7 @@ impl Redactor for MockRedactor {
 
 fn create_test_pr_event(id: &str, number: u64, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["pr", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -139,6 +148,9 @@ fn create_test_pr_event(id: &str, number: u64, title: &str) -> EventEnvelope {
             url: Some("https://api.github.com/".to_string()),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -183,6 +195,7 @@ fn mock_renderer_produces_output() {
                 workstreams: vec![],
             },
             &CoverageManifest {
+                schema_version: SchemaVersion::CURRENT,
                 run_id: RunId::now("test"),
                 generated_at: Utc::now(),
                 user: "test".to_string(),
@@ -195,6 +208,9 @@ fn mock_renderer_produces_output() {
                 slices: vec![],
                 warnings: vec![],
                 completeness: Completeness::Complete,
+                score: None,
+                timezone: None,
+                budget: vec![],
             },
         )
         .unwrap();
@@ -228,6 +244,8 @@ fn mock_redactor_preserves_workstreams() {
             events: vec![],
             receipts: vec![],
             stats: WorkstreamStats::zero(),
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -260,6 +278,7 @@ fn trait_objects_work_with_arc() {
                 workstreams: vec![],
             },
             &CoverageManifest {
+                schema_version: SchemaVersion::CURRENT,
                 run_id: RunId::now("test"),
                 generated_at: Utc::now(),
                 user: "test".to_string(),
@@ -272,6 +291,9 @@ fn trait_objects_work_with_arc() {
                 slices: vec![],
                 warnings: vec![],
                 completeness: Completeness::Complete,
+                score: None,
+                timezone: None,
+                budget: vec![],
             },
         )
         .unwrap();
@@ -290,6 +312,7 @@ fn ingest_output_with_events() {
     let output = IngestOutput {
         events: vec![event.clone()],
         coverage: CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("test"),
             generated_at: Utc::now(),
             user: "tester".to_string(),
@@ -302,8 +325,12 @@ fn ingest_output_with_events() {
             slices: vec![],
             warnings: vec![],
             completeness: Completeness::Complete,
+            score: None,
+            timezone: None,
+            budget: vec![],
         },
         freshness: Vec::new(),
+        budget: Vec::new(),
     };
 
     assert_eq!(output.events.len(), 1);
@@ -0,0 +1,511 @@
+//! Tests for packet publishing: markdown parsing, Notion and Google Docs
+//! mapping, and `shiplog publish` against local stand-ins for their APIs.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::{Value, json};
+use shiplog::publish::google_docs::{DocContent, GoogleDocsPublisher};
+use shiplog::publish::notion::{NotionPublisher, notion_blocks};
+use shiplog::publish::{Block, PublishRecord, Span, parse_markdown};
+
+#[derive(Clone, Debug)]
+struct Request {
+    method: String,
+    path: String,
+    body: Value,
+}
+
+/// Answer `requests` requests with `respond(method, path)`, recording each.
+fn serve(
+    requests: usize,
+    respond: impl Fn(&str, &str) -> (u16, Value) + Send + 'static,
+) -> (String, Arc<Mutex<Vec<Request>>>, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&seen);
+    let handle = thread::spawn(move || {
+        for _ in 0..requests {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap_or_default().to_string();
+            let path = parts.next().unwrap_or_default().to_string();
+            let (status, response) = respond(&method, &path);
+            recorded.lock().unwrap().push(Request {
+                method,
+                path,
+                body: serde_json::from_slice(&body).unwrap_or(Value::Null),
+            });
+            let response = response.to_string();
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+        }
+    });
+    (base, seen, handle)
+}
+
+#[test]
+fn markdown_parses_into_packet_blocks() {
+    let blocks = parse_markdown(
+        "# Packet\n\nReady with **caveats**.\nSee below.\n\n- Top\n  - Nested `code`\n1. First\n> Quote\n\n| a | b |\n|---|---|\n\n---\n",
+    );
+
+    assert_eq!(
+        blocks,
+        [
+            Block::Heading {
+                level: 1,
+                spans: vec![Span::plain("Packet")]
+            },
+            Block::Paragraph(vec![
+                Span::plain("Ready with "),
+                Span {
+                    text: "caveats".into(),
+                    bold: true,
+                    ..Span::default()
+                },
+                Span::plain(". See below."),
+            ]),
+            Block::Bullet {
+                depth: 0,
+                spans: vec![Span::plain("Top")]
+            },
+            Block::Bullet {
+                depth: 1,
+                spans: vec![
+                    Span::plain("Nested "),
+                    Span {
+                        text: "code".into(),
+                        code: true,
+                        ..Span::default()
+                    },
+                ]
+            },
+            Block::Numbered {
+                depth: 0,
+                spans: vec![Span::plain("First")]
+            },
+            Block::Quote(vec![Span::plain("Quote")]),
+            Block::Code("| a | b |\n|---|---|".into()),
+            Block::Divider,
+        ]
+    );
+}
+
+#[test]
+fn inline_links_and_italics_keep_their_targets() {
+    let blocks =
+        parse_markdown("- _Shipped_ [PR #12](https://github.com/acme/api/pull/12) in snake_case\n");
+
+    let Block::Bullet { spans, .. } = &blocks[0] else {
+        panic!("expected a bullet, got {blocks:?}");
+    };
+    assert!(spans[0].italic);
+    assert_eq!(spans[0].text, "Shipped");
+    assert_eq!(spans[2].text, "PR #12");
+    assert_eq!(
+        spans[2].link.as_deref(),
+        Some("https://github.com/acme/api/pull/12")
+    );
+    assert_eq!(spans[3].text, " in snake_case");
+    assert!(!spans[3].italic);
+}
+
+#[test]
+fn notion_blocks_nest_list_items_and_drop_relative_links() {
+    let blocks = notion_blocks(&parse_markdown(
+        "## Claims\n- Parent [notes](./notes.md)\n  - Child\n",
+    ));
+
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[0]["type"], "heading_2");
+    let parent = &blocks[1]["bulleted_list_item"];
+    assert_eq!(parent["rich_text"][1]["text"]["content"], "notes");
+    assert_eq!(parent["rich_text"][1]["text"]["link"], Value::Null);
+    assert_eq!(
+        parent["children"][0]["bulleted_list_item"]["rich_text"][0]["text"]["content"],
+        "Child"
+    );
+}
+
+#[test]
+fn notion_publish_creates_then_rewrites_the_same_page() {
+    let (base, seen, server) = serve(5, |method, path| match (method, path) {
+        ("POST", "/v1/pages") => (
+            200,
+            json!({ "id": "page-1", "url": "https://notion.so/page-1" }),
+        ),
+        ("PATCH", "/v1/pages/page-1") => (
+            200,
+            json!({ "id": "page-1", "url": "https://notion.so/page-1", "archived": false }),
+        ),
+        ("GET", _) => (
+            200,
+            json!({ "results": [{ "id": "old-block" }], "has_more": false, "next_cursor": null }),
+        ),
+        _ => (200, json!({})),
+    });
+    let publisher = NotionPublisher::new("secret").unwrap().with_api_base(&base);
+    let blocks = parse_markdown("# Packet\n- One\n");
+
+    let created = publisher
+        .publish("parent-1", None, "Packet", &blocks)
+        .unwrap();
+    let updated = publisher
+        .publish("parent-1", Some(&created.id), "Packet v2", &blocks)
+        .unwrap();
+    server.join().unwrap();
+
+    assert_eq!(created.url, "https://notion.so/page-1");
+    assert_eq!(updated.id, "page-1");
+    let seen = seen.lock().unwrap();
+    let calls: Vec<_> = seen
+        .iter()
+        .map(|request| format!("{} {}", request.method, request.path))
+        .collect();
+    assert_eq!(
+        calls,
+        [
+            "POST /v1/pages",
+            "PATCH /v1/pages/page-1",
+            "GET /v1/blocks/page-1/children?page_size=100",
+            "DELETE /v1/blocks/old-block",
+            "PATCH /v1/blocks/page-1/children",
+        ]
+    );
+    assert_eq!(seen[0].body["parent"]["page_id"], "parent-1");
+    assert_eq!(seen[0].body["children"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        seen[1].body["properties"]["title"]["title"][0]["text"]["content"],
+        "Packet v2"
+    );
+    assert_eq!(seen[4].body["children"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn notion_publish_recreates_a_deleted_page() {
+    let (base, seen, server) = serve(2, |method, _| match method {
+        "PATCH" => (404, json!({ "message": "Could not find page" })),
+        _ => (
+            200,
+            json!({ "id": "page-2", "url": "https://notion.so/page-2" }),
+        ),
+    });
+    let publisher = NotionPublisher::new("secret").unwrap().with_api_base(&base);
+
+    let page = publisher
+        .publish("parent-1", Some("gone"), "Packet", &parse_markdown("# P\n"))
+        .unwrap();
+    server.join().unwrap();
+
+    assert_eq!(page.id, "page-2");
+    assert_eq!(seen.lock().unwrap()[1].method, "POST");
+}
+
+#[test]
+fn notion_publish_reports_rejected_tokens() {
+    let (base, _seen, server) = serve(1, |_, _| {
+        (401, json!({ "message": "API token is invalid." }))
+    });
+    let publisher = NotionPublisher::new("bad").unwrap().with_api_base(&base);
+
+    let err = publisher
+        .publish("parent-1", None, "Packet", &parse_markdown("# P\n"))
+        .unwrap_err();
+    server.join().unwrap();
+
+    assert!(format!("{err:#}").contains("Notion rejected the token"));
+}
+
+#[test]
+fn google_docs_content_keeps_headings_lists_and_links() {
+    let content = DocContent::from_blocks(&parse_markdown(
+        "# Packet\n- **Shipped** [PR](https://example.com/1)\n  - Détail\n1. One\n",
+    ));
+
+    assert_eq!(content.text(), "Packet\nShipped PR\n\tDétail\nOne\n");
+    let requests = content.requests(1);
+    assert_eq!(requests[0]["insertText"]["location"]["index"], 1);
+    assert!(requests.iter().any(|request| {
+        request["updateParagraphStyle"]["paragraphStyle"]["namedStyleType"] == "HEADING_1"
+            && request["updateParagraphStyle"]["range"]["endIndex"] == 8
+    }));
+    assert!(requests.iter().any(|request| {
+        request["updateTextStyle"]["textStyle"]["link"]["url"] == "https://example.com/1"
+            && request["updateTextStyle"]["range"]["startIndex"] == 16
+            && request["updateTextStyle"]["range"]["endIndex"] == 18
+    }));
+    assert!(
+        requests
+            .iter()
+            .any(|request| request["updateTextStyle"]["fields"] == "bold")
+    );
+
+    // Bullets come last and run from the end of the doc backwards.
+    let bullets: Vec<_> = requests
+        .iter()
+        .filter_map(|request| request.get("createParagraphBullets"))
+        .collect();
+    assert_eq!(bullets.len(), 2);
+    assert_eq!(bullets[0]["bulletPreset"], "NUMBERED_DECIMAL_ALPHA_ROMAN");
+    assert_eq!(bullets[0]["range"]["startIndex"], 27);
+    assert_eq!(bullets[1]["bulletPreset"], "BULLET_DISC_CIRCLE_SQUARE");
+    assert_eq!(bullets[1]["range"]["startIndex"], 8);
+    assert_eq!(bullets[1]["range"]["endIndex"], 27);
+    assert!(
+        requests
+            .last()
+            .unwrap()
+            .get("createParagraphBullets")
+            .is_some()
+    );
+}
+
+#[test]
+fn google_docs_publish_creates_then_rewrites_the_same_doc() {
+    let (base, seen, server) = serve(5, |method, path| match (method, path) {
+        ("GET", _) => (
+            200,
+            json!({ "body": { "content": [{ "endIndex": 1 }, { "endIndex": 42 }] } }),
+        ),
+        _ => (200, json!({ "id": "doc-1", "trashed": false })),
+    });
+    let publisher = GoogleDocsPublisher::new("secret")
+        .unwrap()
+        .with_api_base(&base);
+    let blocks = parse_markdown("# Packet\n- One\n");
+
+    let created = publisher
+        .publish(Some("folder-1"), None, "Packet", &blocks)
+        .unwrap();
+    let updated = publisher
+        .publish(Some("folder-1"), Some(&created.id), "Packet v2", &blocks)
+        .unwrap();
+    server.join().unwrap();
+
+    assert_eq!(created.url, "https://docs.google.com/document/d/doc-1/edit");
+    assert_eq!(updated, created);
+    let seen = seen.lock().unwrap();
+    let calls: Vec<_> = seen
+        .iter()
+        .map(|request| format!("{} {}", request.method, request.path))
+        .collect();
+    assert_eq!(
+        calls,
+        [
+            "POST /drive/v3/files?supportsAllDrives=true",
+            "POST /v1/documents/doc-1:batchUpdate",
+            "PATCH /drive/v3/files/doc-1?fields=id,trashed&supportsAllDrives=true",
+            "GET /v1/documents/doc-1",
+            "POST /v1/documents/doc-1:batchUpdate",
+        ]
+    );
+    assert_eq!(seen[0].body["name"], "Packet");
+    assert_eq!(seen[0].body["parents"][0], "folder-1");
+    assert_eq!(
+        seen[0].body["mimeType"],
+        "application/vnd.google-apps.document"
+    );
+    assert_eq!(seen[2].body["name"], "Packet v2");
+    assert_eq!(
+        seen[4].body["requests"][0]["deleteContentRange"]["range"],
+        json!({ "startIndex": 1, "endIndex": 41 })
+    );
+    assert!(seen[4].body["requests"][1].get("insertText").is_some());
+}
+
+#[test]
+fn google_docs_publish_recreates_a_trashed_doc() {
+    let (base, seen, server) = serve(3, |method, _| match method {
+        "PATCH" => (200, json!({ "id": "gone", "trashed": true })),
+        _ => (200, json!({ "id": "doc-2" })),
+    });
+    let publisher = GoogleDocsPublisher::new("secret")
+        .unwrap()
+        .with_api_base(&base);
+
+    let doc = publisher
+        .publish(None, Some("gone"), "Packet", &parse_markdown("# P\n"))
+        .unwrap();
+    server.join().unwrap();
+
+    assert_eq!(doc.id, "doc-2");
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen[1].method, "POST");
+    assert_eq!(seen[1].body.get("parents"), None);
+}
+
+#[test]
+fn google_docs_publish_explains_missing_scopes() {
+    let (base, _seen, server) = serve(1, |_, _| {
+        (
+            403,
+            json!({ "error": { "message": "Request had insufficient authentication scopes." } }),
+        )
+    });
+    let publisher = GoogleDocsPublisher::new("token")
+        .unwrap()
+        .with_api_base(&base);
+
+    let err = publisher
+        .publish(None, None, "Packet", &parse_markdown("# P\n"))
+        .unwrap_err();
+    server.join().unwrap();
+
+    assert!(format!("{err:#}").contains("documents and drive.file scopes"));
+}
+
+fn seed_run(out: &std::path::Path) -> std::path::PathBuf {
+    let run_dir = out.join("run_1");
+    std::fs::create_dir_all(&run_dir).unwrap();
+    std::fs::write(run_dir.join("ledger.events.jsonl"), "").unwrap();
+    std::fs::write(
+        run_dir.join("packet.md"),
+        "# Packet Readiness\n\nReady.\n\n- Shipped it\n",
+    )
+    .unwrap();
+    run_dir
+}
+
+#[test]
+fn publish_notion_cli_records_the_page_and_updates_it_next_time() {
+    let tmp = tempfile::tempdir().unwrap();
+    let out = tmp.path().join("out");
+    let run_dir = seed_run(&out);
+    let (base, seen, server) = serve(4, |method, path| match (method, path) {
+        ("GET", _) => (200, json!({ "results": [], "has_more": false })),
+        ("PATCH", "/v1/pages/page-9") => (
+            200,
+            json!({ "id": "page-9", "url": "https://notion.so/page-9" }),
+        ),
+        _ => (
+            200,
+            json!({ "id": "page-9", "url": "https://notion.so/page-9" }),
+        ),
+    });
+    let publish = || {
+        let mut cmd = Command::from_std(std::process::Command::new(env!("CARGO_BIN_EXE_shiplog")));
+        cmd.current_dir(tmp.path())
+            .env("NOTION_TOKEN", "secret")
+            .env("SHIPLOG_NOTION_API", &base)
+            .args([
+                "publish",
+                "notion",
+                "--out",
+                "out",
+                "--parent-page",
+                "parent-1",
+            ]);
+        cmd.assert().success()
+    };
+
+    publish().stdout(predicate::str::contains(
+        "Created Notion page for out/run_1/packet.md: https://notion.so/page-9",
+    ));
+    let record = PublishRecord::load(&PublishRecord::path(&run_dir, "notion", "internal"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.document_id, "page-9");
+
+    publish().stdout(predicate::str::contains("Updated Notion page"));
+    server.join().unwrap();
+    let seen = seen.lock().unwrap();
+    assert_eq!(
+        seen[0].body["properties"]["title"]["title"][0]["text"]["content"],
+        "shiplog packet run_1"
+    );
+}
+
+#[test]
+fn publish_notion_cli_explains_missing_setup() {
+    let tmp = tempfile::tempdir().unwrap();
+    seed_run(&tmp.path().join("out"));
+    let publish = || {
+        let mut cmd = Command::from_std(std::process::Command::new(env!("CARGO_BIN_EXE_shiplog")));
+        cmd.current_dir(tmp.path())
+            .env_remove("NOTION_TOKEN")
+            .args(["publish", "notion", "--out", "out"]);
+        cmd
+    };
+
+    publish()
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --parent-page"));
+    publish()
+        .args(["--parent-page", "p"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("NOTION_TOKEN is not set"));
+    publish()
+        .env("NOTION_TOKEN", "secret")
+        .args(["--parent-page", "p", "--bundle-profile", "manager"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "run `shiplog share manager` first",
+        ));
+}
+
+#[test]
+fn publish_google_docs_cli_records_the_doc_per_profile() {
+    let tmp = tempfile::tempdir().unwrap();
+    let out = tmp.path().join("out");
+    let run_dir = seed_run(&out);
+    std::fs::write(
+        tmp.path().join("shiplog.toml"),
+        "[publish.google_docs]\nfolder_id = \"folder-9\"\ntoken_env = \"MY_GOOGLE_TOKEN\"\n",
+    )
+    .unwrap();
+    let (base, seen, server) = serve(2, |_, _| (200, json!({ "id": "doc-9" })));
+
+    let mut cmd = Command::from_std(std::process::Command::new(env!("CARGO_BIN_EXE_shiplog")));
+    cmd.current_dir(tmp.path())
+        .env("MY_GOOGLE_TOKEN", "secret")
+        .env("SHIPLOG_GOOGLE_API", &base)
+        .args(["publish", "google-docs", "--out", "out", "--latest"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Created Google Doc for out/run_1/packet.md: https://docs.google.com/document/d/doc-9/edit",
+    ));
+    server.join().unwrap();
+
+    assert_eq!(seen.lock().unwrap()[0].body["parents"][0], "folder-9");
+    let record = PublishRecord::load(&PublishRecord::path(&run_dir, "google-docs", "internal"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.target, "google-docs");
+    assert_eq!(record.document_id, "doc-9");
+    assert!(
+        PublishRecord::load(&PublishRecord::path(&run_dir, "notion", "internal"))
+            .unwrap()
+            .is_none()
+    );
+}
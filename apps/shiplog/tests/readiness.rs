@@ -0,0 +1,185 @@
+//! Integration tests for graded packet readiness.
+
+use chrono::Utc;
+use shiplog::ids::WorkstreamId;
+use shiplog::readiness::{ReadinessComponentKind, ReadinessGrade, assess_readiness};
+use shiplog::schema::coverage::Completeness;
+use shiplog::schema::event::{EventEnvelope, SourceSystem};
+use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
+use shiplog_testkit::fixtures::test_coverage;
+use shiplog_testkit::pr_event;
+
+fn events(code: u64, manual: u64) -> Vec<EventEnvelope> {
+    let mut events: Vec<EventEnvelope> = (0..code)
+        .map(|n| pr_event("acme/app", n, "Ship change"))
+        .collect();
+    events.extend((code..code + manual).map(|n| {
+        let mut event = pr_event("acme/app", n, "Design review");
+        event.source.system = SourceSystem::Manual;
+        event
+    }));
+    events
+}
+
+fn workstream(title: &str, events: &[EventEnvelope], receipts: usize, summary: bool) -> Workstream {
+    Workstream {
+        id: WorkstreamId::from_parts(["ws", title]),
+        title: title.to_string(),
+        summary: summary.then(|| format!("{title} shipped")),
+        tags: vec![],
+        stats: WorkstreamStats::zero(),
+        events: events.iter().map(|event| event.id.clone()).collect(),
+        receipts: events
+            .iter()
+            .take(receipts)
+            .map(|event| event.id.clone())
+            .collect(),
+        receipt_weights: Default::default(),
+        rules: Default::default(),
+    }
+}
+
+fn file(workstreams: Vec<Workstream>) -> WorkstreamsFile {
+    WorkstreamsFile {
+        version: 1,
+        generated_at: Utc::now(),
+        workstreams,
+    }
+}
+
+fn component_score(
+    assessment: &shiplog::readiness::ReadinessAssessment,
+    kind: ReadinessComponentKind,
+) -> u8 {
+    assessment
+        .components
+        .iter()
+        .find(|component| component.kind == kind)
+        .map(|component| component.score)
+        .unwrap()
+}
+
+#[test]
+fn curated_balanced_packet_grades_a() {
+    let events = events(9, 1);
+    let workstreams = file(vec![
+        workstream("Billing", &events[..5], 3, true),
+        workstream("Search", &events[5..], 3, true),
+    ]);
+    let coverage = test_coverage("octo", Completeness::Complete);
+
+    let assessment = assess_readiness(&workstreams, &events, &coverage);
+
+    assert_eq!(assessment.score, 100);
+    assert_eq!(assessment.grade, ReadinessGrade::A);
+    assert!(assessment.suggestions.is_empty());
+}
+
+#[test]
+fn missing_receipts_and_summaries_produce_workstream_suggestions() {
+    let events = events(9, 1);
+    let workstreams = file(vec![
+        workstream("Billing", &events[..5], 3, true),
+        workstream("Search", &events[5..], 1, false),
+    ]);
+    let coverage = test_coverage("octo", Completeness::Complete);
+
+    let assessment = assess_readiness(&workstreams, &events, &coverage);
+
+    assert_eq!(
+        component_score(&assessment, ReadinessComponentKind::EvidenceDensity),
+        66
+    );
+    assert_eq!(
+        component_score(&assessment, ReadinessComponentKind::ClaimCompletion),
+        50
+    );
+    let search: Vec<&str> = assessment
+        .suggestions
+        .iter()
+        .filter(|s| s.workstream.as_deref() == Some("Search"))
+        .map(|s| s.message.as_str())
+        .collect();
+    assert_eq!(search.len(), 2, "{search:?}");
+    assert!(search.iter().any(|m| m.contains("Select 2 more receipts")));
+    assert!(search.iter().any(|m| m.contains("Write a summary")));
+}
+
+#[test]
+fn suggestions_are_ordered_by_recoverable_points() {
+    let events = events(10, 0);
+    let workstreams = file(vec![workstream("Billing", &events, 3, false)]);
+    let coverage = test_coverage("octo", Completeness::Complete);
+
+    let assessment = assess_readiness(&workstreams, &events, &coverage);
+
+    // Claim completion loses 25 points, manual balance 20.
+    let order: Vec<ReadinessComponentKind> =
+        assessment.suggestions.iter().map(|s| s.component).collect();
+    assert_eq!(
+        order,
+        vec![
+            ReadinessComponentKind::ClaimCompletion,
+            ReadinessComponentKind::ManualBalance
+        ]
+    );
+    assert_eq!(assessment.score, 55);
+    assert_eq!(assessment.grade, ReadinessGrade::D);
+}
+
+#[test]
+fn coverage_uses_manifest_score_then_completeness() {
+    let events = events(9, 1);
+    let workstreams = file(vec![workstream("Billing", &events, 3, true)]);
+
+    let mut coverage = test_coverage("octo", Completeness::Partial);
+    let partial = assess_readiness(&workstreams, &events, &coverage);
+    assert_eq!(
+        component_score(&partial, ReadinessComponentKind::Coverage),
+        50
+    );
+
+    coverage.score = Some(80);
+    let scored = assess_readiness(&workstreams, &events, &coverage);
+    assert_eq!(
+        component_score(&scored, ReadinessComponentKind::Coverage),
+        80
+    );
+    assert!(
+        scored
+            .suggestions
+            .iter()
+            .any(|s| s.message.contains("Coverage is 80/100"))
+    );
+}
+
+#[test]
+fn mostly_manual_ledgers_lose_balance_points() {
+    let events = events(2, 8);
+    let workstreams = file(vec![workstream("Billing", &events, 3, true)]);
+    let coverage = test_coverage("octo", Completeness::Complete);
+
+    let assessment = assess_readiness(&workstreams, &events, &coverage);
+
+    assert_eq!(
+        component_score(&assessment, ReadinessComponentKind::ManualBalance),
+        40
+    );
+    assert!(
+        assessment
+            .suggestions
+            .iter()
+            .any(|s| s.message.contains("Most events are manual"))
+    );
+}
+
+#[test]
+fn grade_boundaries() {
+    assert_eq!(ReadinessGrade::from_score(90), ReadinessGrade::A);
+    assert_eq!(ReadinessGrade::from_score(89), ReadinessGrade::B);
+    assert_eq!(ReadinessGrade::from_score(75), ReadinessGrade::B);
+    assert_eq!(ReadinessGrade::from_score(74), ReadinessGrade::C);
+    assert_eq!(ReadinessGrade::from_score(60), ReadinessGrade::C);
+    assert_eq!(ReadinessGrade::from_score(59), ReadinessGrade::D);
+    assert_eq!(ReadinessGrade::from_score(39), ReadinessGrade::F);
+}
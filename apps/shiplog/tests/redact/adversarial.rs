@@ -8,6 +8,7 @@ use shiplog::schema::event::*;
 
 fn make_event(title: &str, repo: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", title]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -42,6 +43,9 @@ fn make_event(title: &str, repo: &str) -> EventEnvelope {
             url: Some(format!("https://api.github.com/repos/{repo}/pulls/1")),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -167,6 +171,7 @@ fn manager_profile_keeps_title_and_repo() {
 fn review_event_title_stripped_in_public() {
     let r = DeterministicRedactor::new(b"key");
     let ev = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["review", "1"]),
         kind: EventKind::Review,
         occurred_at: Utc::now(),
@@ -185,6 +190,8 @@ fn review_event_title_stripped_in_public() {
             submitted_at: Utc::now(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![],
@@ -193,6 +200,9 @@ fn review_event_title_stripped_in_public() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     let out = r.redact_events(&[ev], "public").unwrap();
     let json = serde_json::to_string(&out).unwrap();
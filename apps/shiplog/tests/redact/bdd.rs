@@ -19,6 +19,7 @@ use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 fn sample_pr_event(repo: &str, title: &str, number: u64) -> EventEnvelope {
     let now = Utc::now();
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["bdd", "pr", repo, &number.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: now,
@@ -55,6 +56,9 @@ fn sample_pr_event(repo: &str, title: &str, number: u64) -> EventEnvelope {
             )),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -70,6 +74,8 @@ fn sample_workstreams_file() -> WorkstreamsFile {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     }
 }
@@ -515,6 +521,7 @@ fn scenario_review_event_redaction() {
         .given("a review event with metadata", |ctx| {
             let now = Utc::now();
             let ev = EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: EventId::from_parts(["bdd", "review", "org/app", "7"]),
                 kind: EventKind::Review,
                 occurred_at: now,
@@ -533,6 +540,8 @@ fn scenario_review_event_redaction() {
                     submitted_at: now,
                     state: "approved".into(),
                     window: None,
+                    pull_created_at: None,
+                    pull_author: None,
                 }),
                 tags: vec![],
                 links: vec![Link {
@@ -544,6 +553,9 @@ fn scenario_review_event_redaction() {
                     url: Some("https://api.github.com/repos/org/app/pulls/7/reviews/1".into()),
                     opaque_id: None,
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             };
             let json = serde_json::to_string(&[&ev]).unwrap();
             ctx.data.insert("events".into(), json.into_bytes());
@@ -75,9 +75,19 @@ fn redact_workstreams_deterministic_across_100_iterations() {
                     pull_requests: 2,
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["repo", "secret-org/private-repo"]),
@@ -88,9 +98,19 @@ fn redact_workstreams_deterministic_across_100_iterations() {
                     pull_requests: 1,
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -16,6 +16,7 @@ use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 
 fn pr_event_with(title: &str, repo: &str, paths: Vec<String>) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["edge", "pr", "1"]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -50,11 +51,15 @@ fn pr_event_with(title: &str, repo: &str, paths: Vec<String>) -> EventEnvelope {
             url: Some(format!("https://api.github.com/repos/{repo}/pulls/1")),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn review_event_with(pull_title: &str, repo: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["edge", "review", "1"]),
         kind: EventKind::Review,
         occurred_at: Utc::now(),
@@ -73,6 +78,8 @@ fn review_event_with(pull_title: &str, repo: &str) -> EventEnvelope {
             submitted_at: Utc::now(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![Link {
@@ -86,6 +93,9 @@ fn review_event_with(pull_title: &str, repo: &str) -> EventEnvelope {
             )),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -95,6 +105,7 @@ fn manual_event_with(
     impact: Option<&str>,
 ) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["edge", "manual", "1"]),
         kind: EventKind::Manual,
         occurred_at: Utc::now(),
@@ -114,6 +125,9 @@ fn manual_event_with(
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()),
             impact: impact.map(String::from),
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec!["incident".into()],
         links: vec![Link {
@@ -125,6 +139,9 @@ fn manual_event_with(
             url: Some("https://internal.api/incidents/42".into()),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -144,9 +161,19 @@ fn make_workstreams_file(titles: &[(&str, Option<&str>, Vec<&str>)]) -> Workstre
                     pull_requests: 3,
                     reviews: 2,
                     manual_events: 1,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             })
             .collect(),
     }
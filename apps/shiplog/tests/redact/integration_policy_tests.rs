@@ -1,7 +1,7 @@
 //! Integration tests for shiplog::redact facade policy behavior.
 
 use chrono::Utc;
-use shiplog::ids::{EventId, WorkstreamId};
+use shiplog::ids::{EventId, OpaqueId, WorkstreamId};
 use shiplog::ports::Redactor;
 use shiplog::redact::{DeterministicRedactor, RedactionProfile};
 use shiplog::schema::event::*;
@@ -10,6 +10,7 @@ use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 fn sample_events() -> Vec<EventEnvelope> {
     vec![
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["integration", "event", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: Utc::now(),
@@ -42,10 +43,14 @@ fn sample_events() -> Vec<EventEnvelope> {
             source: SourceRef {
                 system: SourceSystem::Github,
                 url: Some("https://api.github.com/repos/acme/private-repo/pulls/1".into()),
-                opaque_id: Some("github-pr-node-id".into()),
+                opaque_id: Some(OpaqueId::from_raw("test", "github-pr-node-id")),
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         },
         EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: EventId::from_parts(["integration", "event", "2"]),
             kind: EventKind::Manual,
             occurred_at: Utc::now(),
@@ -65,6 +70,9 @@ fn sample_events() -> Vec<EventEnvelope> {
                 started_at: None,
                 ended_at: None,
                 impact: Some("Sensitive impact".into()),
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![Link {
@@ -74,8 +82,11 @@ fn sample_events() -> Vec<EventEnvelope> {
             source: SourceRef {
                 system: SourceSystem::Manual,
                 url: Some("https://internal/api/incidents/1".into()),
-                opaque_id: Some("manual-incident-1".into()),
+                opaque_id: Some(OpaqueId::from_raw("test", "manual-incident-1")),
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         },
     ]
 }
@@ -92,6 +103,8 @@ fn sample_workstreams() -> WorkstreamsFile {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     }
 }
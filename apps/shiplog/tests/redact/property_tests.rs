@@ -46,6 +46,28 @@ proptest! {
                     prop_assert!(man.description.is_none());
                     prop_assert!(man.impact.is_none());
                 }
+                EventPayload::Issue(issue) => {
+                    prop_assert_eq!(&issue.title, "[redacted]");
+                    prop_assert!(issue.labels.is_empty());
+                }
+                EventPayload::Deployment(deployment) => {
+                    prop_assert!(deployment.version.is_none());
+                }
+                EventPayload::Release(release) => {
+                    prop_assert!(release.name.is_none());
+                }
+                EventPayload::Comment(comment) => {
+                    prop_assert_eq!(&comment.target_title, "[redacted]");
+                    prop_assert!(comment.thread.is_none());
+                }
+                EventPayload::Incident(incident) => {
+                    prop_assert_eq!(&incident.title, "[redacted]");
+                    prop_assert!(incident.summary.is_none());
+                    prop_assert!(incident.customer_impact.is_none());
+                }
+                EventPayload::Document(document) => {
+                    prop_assert_eq!(&document.title, "[redacted]");
+                }
             }
         }
     }
@@ -167,6 +189,28 @@ proptest! {
                     prop_assert!(man.description.is_none());
                     prop_assert!(man.impact.is_none());
                 }
+                EventPayload::Issue(issue) => {
+                    prop_assert_eq!(&issue.title, "[redacted]");
+                    prop_assert!(issue.labels.is_empty());
+                }
+                EventPayload::Deployment(deployment) => {
+                    prop_assert!(deployment.version.is_none());
+                }
+                EventPayload::Release(release) => {
+                    prop_assert!(release.name.is_none());
+                }
+                EventPayload::Comment(comment) => {
+                    prop_assert_eq!(&comment.target_title, "[redacted]");
+                    prop_assert!(comment.thread.is_none());
+                }
+                EventPayload::Incident(incident) => {
+                    prop_assert_eq!(&incident.title, "[redacted]");
+                    prop_assert!(incident.summary.is_none());
+                    prop_assert!(incident.customer_impact.is_none());
+                }
+                EventPayload::Document(document) => {
+                    prop_assert_eq!(&document.title, "[redacted]");
+                }
             }
         }
     }
@@ -260,6 +304,12 @@ proptest! {
                 EventPayload::PullRequest(pr) => &pr.title,
                 EventPayload::Review(rev) => &rev.pull_title,
                 EventPayload::Manual(man) => &man.title,
+                EventPayload::Issue(issue) => &issue.title,
+                EventPayload::Deployment(deployment) => &deployment.environment,
+                EventPayload::Release(release) => &release.version,
+                EventPayload::Comment(comment) => &comment.target_title,
+                EventPayload::Incident(incident) => &incident.title,
+                EventPayload::Document(document) => &document.title,
             };
             // Only check non-trivial titles (avoid matching "[redacted]" or very short strings)
             if title.len() > 5 && title != "[redacted]" {
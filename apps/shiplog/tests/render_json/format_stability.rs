@@ -6,7 +6,7 @@
 
 use crate::artifact_json::{write_coverage_manifest, write_events_jsonl};
 use chrono::{NaiveDate, TimeZone, Utc};
-use shiplog::ids::{EventId, RunId};
+use shiplog::ids::{EventId, OpaqueId, RunId};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::*;
 
@@ -23,6 +23,7 @@ fn window() -> TimeWindow {
 
 fn pr_envelope() -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", "acme/widgets", "42"]),
         kind: EventKind::PullRequest,
         occurred_at: ts(),
@@ -55,13 +56,17 @@ fn pr_envelope() -> EventEnvelope {
         source: SourceRef {
             system: SourceSystem::Github,
             url: Some("https://api.github.com/repos/acme/widgets/pulls/42".into()),
-            opaque_id: Some("PR_abc123".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "PR_abc123")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn review_envelope() -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "review", "acme/widgets", "42", "1"]),
         kind: EventKind::Review,
         occurred_at: ts(),
@@ -80,6 +85,8 @@ fn review_envelope() -> EventEnvelope {
             submitted_at: ts(),
             state: "approved".into(),
             window: Some(window()),
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec!["review".into()],
         links: vec![Link {
@@ -89,13 +96,17 @@ fn review_envelope() -> EventEnvelope {
         source: SourceRef {
             system: SourceSystem::Github,
             url: None,
-            opaque_id: Some("PRR_def456".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "PRR_def456")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn manual_envelope() -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "acme/widgets", "incident-1"]),
         kind: EventKind::Manual,
         occurred_at: ts(),
@@ -115,6 +126,9 @@ fn manual_envelope() -> EventEnvelope {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 3, 15).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 3, 16).unwrap()),
             impact: Some("Reduced MTTR by 50%".into()),
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec!["incident".into(), "oncall".into()],
         links: vec![Link {
@@ -126,6 +140,9 @@ fn manual_envelope() -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -192,6 +209,7 @@ fn format_stability_jsonl_mixed_events() {
 #[test]
 fn format_stability_coverage_manifest_full() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("stability_test_run".into()),
         generated_at: ts(),
         user: "octocat".into(),
@@ -209,6 +227,7 @@ fn format_stability_coverage_manifest_full() {
                 fetched: 15,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             },
             CoverageSlice {
                 window: TimeWindow {
@@ -223,10 +242,14 @@ fn format_stability_coverage_manifest_full() {
                     "GitHub search API limit reached".into(),
                     "Results may be incomplete".into(),
                 ],
+                source: None,
             },
         ],
         warnings: vec!["Incomplete results for February slice".into()],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -244,6 +267,7 @@ fn format_stability_coverage_manifest_full() {
 #[test]
 fn format_stability_coverage_manifest_minimal() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("min".into()),
         generated_at: ts(),
         user: "u".into(),
@@ -256,6 +280,9 @@ fn format_stability_coverage_manifest_minimal() {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Unknown,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -273,6 +300,7 @@ fn format_stability_coverage_manifest_minimal() {
 #[test]
 fn format_stability_coverage_manifest_edge_cases() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("".into()),
         generated_at: ts(),
         user: "user with spaces & <special>".into(),
@@ -292,6 +320,7 @@ fn format_stability_coverage_manifest_edge_cases() {
             fetched: 0,
             incomplete_results: None,
             notes: vec!["".into(), "Special chars: <>&\"'".into()],
+            source: None,
         }],
         warnings: vec![
             "".into(),
@@ -299,6 +328,9 @@ fn format_stability_coverage_manifest_edge_cases() {
             "Multi\nline\nwarning".into(),
         ],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -316,6 +348,7 @@ fn format_stability_coverage_manifest_edge_cases() {
 #[test]
 fn format_stability_jsonl_edge_cases() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["edge"]),
         kind: EventKind::PullRequest,
         occurred_at: ts(),
@@ -348,8 +381,11 @@ fn format_stability_jsonl_edge_cases() {
         source: SourceRef {
             system: SourceSystem::Other("custom/system".into()),
             url: Some("".into()),
-            opaque_id: Some("".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let dir = tempfile::tempdir().unwrap();
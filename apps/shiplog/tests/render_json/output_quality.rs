@@ -3,9 +3,13 @@
 //! Validates JSON schema compliance (required fields), null/missing field handling,
 //! array ordering consistency, and pretty-print vs compact output.
 
-use crate::artifact_json::{write_coverage_manifest, write_events_jsonl};
+use crate::artifact_json::{
+    write_coverage_gaps, write_coverage_manifest, write_events_jsonl, write_needs_attention,
+};
 use chrono::{NaiveDate, TimeZone, Utc};
-use shiplog::ids::{EventId, RunId};
+use shiplog::attention::{AttentionItem, AttentionKind, AttentionPriority, NeedsAttention};
+use shiplog::coverage::{CoverageGaps, coverage_gaps};
+use shiplog::ids::{EventId, OpaqueId, RunId};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::*;
 use shiplog_testkit::pr_event;
@@ -14,6 +18,7 @@ use shiplog_testkit::pr_event;
 
 fn deterministic_coverage(completeness: Completeness) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("quality_test_run_2025".into()),
         generated_at: Utc.timestamp_opt(1_704_067_200, 0).unwrap(), // 2024-01-01T00:00:00Z
         user: "testuser".into(),
@@ -26,6 +31,9 @@ fn deterministic_coverage(completeness: Completeness) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -145,6 +153,7 @@ fn coverage_manifest_has_all_required_fields() {
 fn null_optional_fields_serialized_correctly() {
     // Event with all optionals set to None
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", "nulls"]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -176,6 +185,9 @@ fn null_optional_fields_serialized_correctly() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -387,6 +399,7 @@ fn snapshot_coverage_with_multiple_slices_and_warnings() {
             fetched: 100,
             incomplete_results: Some(true),
             notes: vec!["Rate limited".into()],
+            source: None,
         },
         CoverageSlice {
             window: TimeWindow {
@@ -398,6 +411,7 @@ fn snapshot_coverage_with_multiple_slices_and_warnings() {
             fetched: 50,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         },
     ];
     cov.warnings = vec![
@@ -418,6 +432,7 @@ fn snapshot_coverage_with_multiple_slices_and_warnings() {
 #[test]
 fn snapshot_event_with_all_fields_populated() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", "acme/repo", "99"]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(1_704_067_200, 0).unwrap(),
@@ -459,8 +474,11 @@ fn snapshot_event_with_all_fields_populated() {
         source: SourceRef {
             system: SourceSystem::Github,
             url: Some("https://api.github.com/repos/acme/repo/pulls/99".into()),
-            opaque_id: Some("PR_abc123".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "PR_abc123")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -476,6 +494,7 @@ fn snapshot_event_with_all_fields_populated() {
 #[test]
 fn review_event_roundtrip_preserves_state() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["review", "roundtrip"]),
         kind: EventKind::Review,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -494,6 +513,8 @@ fn review_event_roundtrip_preserves_state() {
             submitted_at: Utc.timestamp_opt(0, 0).unwrap(),
             state: "changes_requested".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec!["review".into()],
         links: vec![],
@@ -502,6 +523,9 @@ fn review_event_roundtrip_preserves_state() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -524,6 +548,7 @@ fn review_event_roundtrip_preserves_state() {
 #[test]
 fn manual_event_roundtrip_preserves_type() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "roundtrip"]),
         kind: EventKind::Manual,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -543,6 +568,9 @@ fn manual_event_roundtrip_preserves_type() {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 1, 3).unwrap()),
             impact: Some("Improved service isolation".into()),
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec!["design".into(), "architecture".into()],
         links: vec![Link {
@@ -552,8 +580,11 @@ fn manual_event_roundtrip_preserves_type() {
         source: SourceRef {
             system: SourceSystem::Manual,
             url: None,
-            opaque_id: Some("manual-1".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "manual-1")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -565,3 +596,50 @@ fn manual_event_roundtrip_preserves_type() {
 
     assert_eq!(loaded, event);
 }
+
+#[test]
+fn needs_attention_roundtrip_uses_snake_case_enums() {
+    let attention = NeedsAttention {
+        run_id: "quality_test_run_2025".into(),
+        items: vec![AttentionItem {
+            priority: AttentionPriority::High,
+            kind: AttentionKind::NoReceipts,
+            subject: "Billing".into(),
+            message: "\"Billing\" has no receipts.".into(),
+        }],
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("needs_attention.json");
+    write_needs_attention(&path, &attention).unwrap();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["items"][0]["priority"], "high");
+    assert_eq!(json["items"][0]["kind"], "no_receipts");
+
+    let loaded: NeedsAttention = serde_json::from_str(&text).unwrap();
+    assert_eq!(loaded, attention);
+}
+
+#[test]
+fn coverage_gaps_roundtrip_omits_unskipped_reason() {
+    let mut coverage = deterministic_coverage(Completeness::Partial);
+    coverage
+        .warnings
+        .push("Configured source jira was skipped: missing JIRA_TOKEN".into());
+    let gaps = coverage_gaps(&coverage, &[]);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("coverage.gaps.json");
+    write_coverage_gaps(&path, &gaps).unwrap();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["sources"][0]["source"], "github");
+    assert!(json["sources"][0].get("skipped").is_none());
+    assert_eq!(json["sources"][1]["skipped"], "missing JIRA_TOKEN");
+
+    let loaded: CoverageGaps = serde_json::from_str(&text).unwrap();
+    assert_eq!(loaded, gaps);
+}
@@ -46,6 +46,7 @@ fn jsonl_multiple_events_all_preserved() {
 #[test]
 fn coverage_manifest_roundtrip_preserves_all_fields() {
     let cov = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc::now(),
         user: "testuser".to_string(),
@@ -58,6 +59,9 @@ fn coverage_manifest_roundtrip_preserves_all_fields() {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -89,6 +93,7 @@ fn empty_events_produces_valid_output() {
 #[test]
 fn coverage_with_zero_slices_is_valid() {
     let cov = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc::now(),
         user: "testuser".to_string(),
@@ -101,6 +106,9 @@ fn coverage_with_zero_slices_is_valid() {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -116,6 +124,7 @@ fn coverage_with_zero_slices_is_valid() {
 #[test]
 fn coverage_with_slices_shows_partial() {
     let cov = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc::now(),
         user: "testuser".to_string(),
@@ -135,9 +144,13 @@ fn coverage_with_slices_shows_partial() {
             fetched: 500,
             incomplete_results: Some(true),
             notes: vec![],
+            source: None,
         }],
         warnings: vec!["API cap hit".to_string()],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let dir = tempfile::tempdir().unwrap();
@@ -156,6 +169,7 @@ fn coverage_with_slices_shows_partial() {
 // Helper function to create test events
 fn create_test_event(id: &str, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -187,5 +201,8 @@ fn create_test_event(id: &str, title: &str) -> EventEnvelope {
             url: Some("https://api.github.com/".to_string()),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
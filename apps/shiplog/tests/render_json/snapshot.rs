@@ -4,6 +4,7 @@ use crate::artifact_json::{write_coverage_manifest, write_events_jsonl};
 use chrono::{NaiveDate, TimeZone, Utc};
 use shiplog::ids::RunId;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+use shiplog::schema::version::SchemaVersion;
 use shiplog_testkit::pr_event;
 
 // ── JSONL snapshot tests ────────────────────────────────────────────────
@@ -66,6 +67,7 @@ fn snapshot_special_characters_in_title() {
 
 fn deterministic_coverage(completeness: Completeness) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("snapshot_run_2025".into()),
         generated_at: Utc.timestamp_opt(1_704_067_200, 0).unwrap(), // 2024-01-01T00:00:00Z
         user: "testuser".into(),
@@ -78,6 +80,9 @@ fn deterministic_coverage(completeness: Completeness) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -106,6 +111,7 @@ fn snapshot_coverage_manifest_partial_with_slices() {
         fetched: 200,
         incomplete_results: Some(true),
         notes: vec!["API rate limit reached".into()],
+        source: None,
     }];
     cov.warnings = vec!["Incomplete data for January 2025".into()];
 
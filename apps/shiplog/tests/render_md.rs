@@ -1,3 +1,5 @@
+#[path = "render_md/coverage_report.rs"]
+mod coverage_report;
 #[path = "render_md/cross_platform_paths.rs"]
 mod cross_platform_paths;
 #[path = "render_md/error_paths.rs"]
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use shiplog::ids::RunId;
+use shiplog::render::md::render_coverage_report;
+use shiplog::schema::coverage::{Completeness, CoverageSlice, TimeWindow};
+use shiplog::schema::event::SourceSystem;
+use shiplog_testkit::{fixtures::test_coverage, pr_event};
+
+fn month(month: u32) -> TimeWindow {
+    TimeWindow {
+        since: NaiveDate::from_ymd_opt(2025, month, 1).unwrap(),
+        until: NaiveDate::from_ymd_opt(2025, month + 1, 1).unwrap(),
+    }
+}
+
+fn slice(window: TimeWindow, total_count: u64, fetched: u64) -> CoverageSlice {
+    CoverageSlice {
+        window,
+        query: "is:pr author:octo is:merged".into(),
+        total_count,
+        fetched,
+        incomplete_results: Some(false),
+        notes: vec![],
+        source: None,
+    }
+}
+
+// ── Snapshot: partial run with caps, an incomplete slice, and a skip ────
+
+#[test]
+fn snapshot_partial_coverage_report() {
+    let mut coverage = test_coverage("octo", Completeness::Partial);
+    coverage.run_id = RunId("run_fixture".into());
+    coverage.sources = vec!["github".into(), "manual".into()];
+    let mut flagged = slice(month(2), 40, 40);
+    flagged.incomplete_results = Some(true);
+    flagged.notes = vec!["search API timed out".into()];
+    coverage.slices = vec![
+        slice(month(1), 12, 12),
+        flagged,
+        slice(month(3), 1200, 1000),
+    ];
+    coverage.warnings = vec![
+        "Configured source jira was skipped: JIRA_TOKEN is not set".into(),
+        "GitHub search rate limit reached twice".into(),
+    ];
+    coverage.score = Some(72);
+    let mut manual = pr_event("acme/app", 7, "Incident write-up");
+    manual.source.system = SourceSystem::Manual;
+    let events = vec![pr_event("acme/app", 1, "Ship it"), manual];
+
+    insta::assert_snapshot!(render_coverage_report(&coverage, &events));
+}
+
+// ── Complete runs say so plainly ────────────────────────────────────────
+
+#[test]
+fn complete_report_has_nothing_to_fix() {
+    let mut coverage = test_coverage("octo", Completeness::Complete);
+    coverage.slices = vec![slice(month(1), 3, 3), slice(month(2), 0, 0)];
+    let events = vec![pr_event("acme/app", 1, "Ship it")];
+
+    let report = render_coverage_report(&coverage, &events);
+
+    assert!(report.contains("Every query returned everything its source reported."));
+    assert!(report.contains("- **GitHub:** queried; 1 event in the ledger.\n"));
+    assert!(report.contains("fetched all 3 reported results."));
+    assert!(report.contains("the source reported no results."));
+    assert!(report.contains("Nothing hit a cap or came back incomplete.\n"));
+    assert!(report.contains("- Nothing to fix"));
+    assert!(!report.contains("## Warnings"));
+}
+
+#[test]
+fn report_without_slices_says_there_is_nothing_to_check() {
+    let coverage = test_coverage("octo", Completeness::Unknown);
+
+    let report = render_coverage_report(&coverage, &[]);
+
+    assert!(report.contains("No query slices were recorded"));
+    assert!(report.contains("Completeness could not be determined"));
+}
@@ -15,6 +15,7 @@ use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 
 fn pr_event(id: &str, repo: &str, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["render-md-path", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -46,6 +47,9 @@ fn pr_event(id: &str, repo: &str, title: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -62,15 +66,26 @@ fn make_workstreams(events: &[EventEnvelope]) -> WorkstreamsFile {
                 pull_requests: events.len(),
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: events.iter().map(|e| e.id.clone()).collect(),
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     }
 }
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("render-md-path-test"),
         generated_at: Utc::now(),
         user: "tester".into(),
@@ -83,6 +98,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -4,7 +4,7 @@
 //! partial coverage with warnings, and workstream-event mismatches.
 
 use chrono::{NaiveDate, TimeZone, Utc};
-use shiplog::ids::{EventId, RunId, WorkstreamId};
+use shiplog::ids::{EventId, OpaqueId, RunId, WorkstreamId};
 use shiplog::ports::Renderer;
 use shiplog::render::md::MarkdownRenderer;
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
@@ -12,6 +12,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, Link, PullRequestEvent, PullRequestState,
     RepoRef, RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 
 // ---------------------------------------------------------------------------
@@ -20,6 +21,7 @@ use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 
 fn pr_event(id: &str, number: u64, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["errtest", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -52,13 +54,17 @@ fn pr_event(id: &str, number: u64, title: &str) -> EventEnvelope {
         source: SourceRef {
             system: SourceSystem::Github,
             url: None,
-            opaque_id: Some(id.into()),
+            opaque_id: Some(OpaqueId::from_raw("test", id)),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn base_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("render_error_test".into()),
         generated_at: Utc.timestamp_opt(0, 0).unwrap(),
         user: "tester".into(),
@@ -71,6 +77,9 @@ fn base_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -145,6 +154,14 @@ fn render_workstream_with_orphan_receipts_does_not_panic() {
                 pull_requests: 3,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![
                 EventId::from_parts(["errtest", "1"]),
@@ -155,6 +172,8 @@ fn render_workstream_with_orphan_receipts_does_not_panic() {
                 EventId::from_parts(["errtest", "1"]),
                 EventId::from_parts(["missing", "99"]),
             ],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -198,9 +217,19 @@ fn render_many_workstreams_succeeds() {
                     pull_requests: event_ids.len(),
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: event_ids.clone(),
                 receipts: event_ids,
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }
         })
         .collect();
@@ -246,13 +275,24 @@ fn render_partial_coverage_with_many_warnings() {
                 pull_requests: 1,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![events[0].id.clone()],
             receipts: vec![events[0].id.clone()],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
     let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         warnings: vec![
             "Rate limit hit during fetch".into(),
             "Some PRs may be missing".into(),
@@ -270,6 +310,7 @@ fn render_partial_coverage_with_many_warnings() {
                 fetched: 1000,
                 incomplete_results: Some(true),
                 notes: vec![],
+                source: None,
             },
             CoverageSlice {
                 window: TimeWindow {
@@ -281,6 +322,7 @@ fn render_partial_coverage_with_many_warnings() {
                 fetched: 50,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             },
         ],
         ..base_coverage()
@@ -309,6 +351,7 @@ fn render_partial_coverage_with_many_warnings() {
 fn render_coverage_with_empty_sources() {
     let renderer = MarkdownRenderer::new();
     let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         sources: vec![],
         ..base_coverage()
     };
@@ -356,9 +399,19 @@ fn render_large_event_list_succeeds() {
                 pull_requests: 500,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: event_ids.clone(),
             receipts: event_ids,
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -399,9 +452,19 @@ fn render_with_unicode_event_titles_does_not_panic() {
                 pull_requests: 1,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![events[0].id.clone()],
             receipts: vec![events[0].id.clone()],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -467,9 +530,19 @@ fn render_workstream_with_zero_stats_succeeds() {
                 pull_requests: 0,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -503,9 +576,19 @@ fn render_workstream_with_very_long_title_does_not_panic() {
                 pull_requests: 0,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -527,6 +610,7 @@ fn render_workstream_with_very_long_title_does_not_panic() {
 fn render_multiple_warnings_all_appear_in_output() {
     let renderer = MarkdownRenderer::new();
     let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         warnings: vec![
             "Warning Alpha: first issue".into(),
             "Warning Beta: second issue".into(),
@@ -27,6 +27,7 @@ fn window() -> TimeWindow {
 
 fn deterministic_coverage(completeness: Completeness) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("stability_test_run".into()),
         generated_at: ts(),
         user: "octocat".into(),
@@ -36,11 +37,15 @@ fn deterministic_coverage(completeness: Completeness) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
 fn pr_envelope(repo: &str, number: u64, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", repo, &number.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: ts(),
@@ -77,11 +82,15 @@ fn pr_envelope(repo: &str, number: u64, title: &str) -> EventEnvelope {
             )),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn review_envelope(repo: &str, pull_number: u64) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "review", repo, &pull_number.to_string(), "1"]),
         kind: EventKind::Review,
         occurred_at: ts(),
@@ -100,6 +109,8 @@ fn review_envelope(repo: &str, pull_number: u64) -> EventEnvelope {
             submitted_at: ts(),
             state: "approved".into(),
             window: Some(window()),
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![Link {
@@ -111,11 +122,15 @@ fn review_envelope(repo: &str, pull_number: u64) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn manual_envelope(title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "acme/widgets", &title.replace(' ', "-")]),
         kind: EventKind::Manual,
         occurred_at: ts(),
@@ -135,6 +150,9 @@ fn manual_envelope(title: &str) -> EventEnvelope {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 3, 15).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 3, 16).unwrap()),
             impact: Some("Reduced MTTR by 50%".into()),
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec!["incident".into()],
         links: vec![],
@@ -143,6 +161,9 @@ fn manual_envelope(title: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -174,9 +195,19 @@ fn format_stability_packet_all_event_types() {
             pull_requests: 1,
             reviews: 1,
             manual_events: 1,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![pr.id.clone(), review.id.clone(), manual.id.clone()],
         receipts: vec![pr.id.clone(), review.id.clone(), manual.id.clone()],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
 
     let workstreams = make_workstreams(vec![ws]);
@@ -211,9 +242,19 @@ fn format_stability_packet_multiple_workstreams() {
             pull_requests: 2,
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![pr1.id.clone(), pr2.id.clone()],
         receipts: vec![pr1.id.clone(), pr2.id.clone()],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
     let ws2 = Workstream {
         id: WorkstreamId::from_parts(["repo", "acme/infra"]),
@@ -224,9 +265,19 @@ fn format_stability_packet_multiple_workstreams() {
             pull_requests: 2,
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![pr3.id.clone(), pr4.id.clone()],
         receipts: vec![pr3.id.clone(), pr4.id.clone()],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
 
     let workstreams = make_workstreams(vec![ws1, ws2]);
@@ -257,9 +308,19 @@ fn format_stability_packet_coverage_first() {
             pull_requests: 1,
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![pr.id.clone()],
         receipts: vec![pr.id.clone()],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
 
     let workstreams = make_workstreams(vec![ws]);
@@ -291,13 +352,24 @@ fn format_stability_packet_partial_coverage() {
             pull_requests: 1,
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![pr.id.clone()],
         receipts: vec![pr.id.clone()],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
 
     let workstreams = make_workstreams(vec![ws]);
     let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("stability_test_run".into()),
         generated_at: ts(),
         user: "octocat".into(),
@@ -315,6 +387,7 @@ fn format_stability_packet_partial_coverage() {
                 fetched: 100,
                 incomplete_results: Some(true),
                 notes: vec![],
+                source: None,
             },
             CoverageSlice {
                 window: TimeWindow {
@@ -326,6 +399,7 @@ fn format_stability_packet_partial_coverage() {
                 fetched: 30,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             },
         ],
         warnings: vec![
@@ -333,6 +407,9 @@ fn format_stability_packet_partial_coverage() {
             "Some repositories may be missing".into(),
         ],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let result = MarkdownRenderer::new()
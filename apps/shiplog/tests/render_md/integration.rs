@@ -2,6 +2,7 @@ use chrono::{NaiveDate, Utc};
 use shiplog::ids::{EventId, RunId, WorkstreamId};
 use shiplog::ports::Renderer;
 use shiplog::render::md::MarkdownRenderer;
+use shiplog::schema::version::SchemaVersion;
 use shiplog::schema::{
     coverage::{CoverageManifest, TimeWindow},
     event::{
@@ -14,6 +15,7 @@ use shiplog::workstreams::WORKSTREAM_RECEIPT_RENDER_LIMIT;
 
 fn pull_request_event(id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["render-md", id]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -45,11 +47,15 @@ fn pull_request_event(id: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn coverage_manifest() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("render-md-receipts"),
         generated_at: Utc::now(),
         user: "agent".into(),
@@ -62,6 +68,9 @@ fn coverage_manifest() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: shiplog::schema::coverage::Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -85,9 +94,19 @@ fn markdown_renderer_shows_only_render_limit_in_receipt_summary() {
             pull_requests: event_ids.len(),
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: event_ids.clone(),
         receipts: event_ids,
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
 
     let workstreams = WorkstreamsFile {
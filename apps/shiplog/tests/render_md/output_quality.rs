@@ -18,6 +18,7 @@ use shiplog_testkit::{fixtures::WorkstreamFixture, pr_event};
 
 fn deterministic_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: shiplog::ids::RunId("quality_test_run".into()),
         generated_at: Utc.timestamp_opt(0, 0).unwrap(),
         user: "testuser".into(),
@@ -30,6 +31,9 @@ fn deterministic_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -148,6 +152,7 @@ fn links_use_markdown_format() {
 fn links_absent_when_no_url() {
     // Event with no links at all
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "nolink"]),
         kind: EventKind::Manual,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -167,6 +172,9 @@ fn links_absent_when_no_url() {
             started_at: None,
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
@@ -175,6 +183,9 @@ fn links_absent_when_no_url() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     let ws = WorkstreamFixture::new("Planning")
         .with_event(&event)
@@ -207,9 +218,19 @@ fn workstream_stats_line_format() {
             pull_requests: 3,
             reviews: 2,
             manual_events: 1,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: events.iter().map(|e| e.id.clone()).collect(),
         receipts: events.iter().map(|e| e.id.clone()).collect(),
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
     let workstreams = WorkstreamsFile {
         version: 1,
@@ -236,9 +257,19 @@ fn workstream_stats_zero_values() {
             pull_requests: 0,
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![events[0].id.clone()],
         receipts: vec![events[0].id.clone()],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
     let workstreams = WorkstreamsFile {
         version: 1,
@@ -401,6 +432,7 @@ fn snapshot_multiple_workstreams_with_mixed_stats() {
     let pr3 = pr_event("acme/web", 3, "Dashboard redesign");
 
     let review = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["review", "multi-1"]),
         kind: EventKind::Review,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -419,6 +451,8 @@ fn snapshot_multiple_workstreams_with_mixed_stats() {
             submitted_at: Utc.timestamp_opt(0, 0).unwrap(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![Link {
@@ -430,6 +464,9 @@ fn snapshot_multiple_workstreams_with_mixed_stats() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let events = vec![pr1, pr2, pr3, review];
@@ -443,6 +480,14 @@ fn snapshot_multiple_workstreams_with_mixed_stats() {
             pull_requests: 2,
             reviews: 1,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![
             events[0].id.clone(),
@@ -454,6 +499,8 @@ fn snapshot_multiple_workstreams_with_mixed_stats() {
             events[1].id.clone(),
             events[3].id.clone(),
         ],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
 
     let ws_web = Workstream {
@@ -465,9 +512,19 @@ fn snapshot_multiple_workstreams_with_mixed_stats() {
             pull_requests: 1,
             reviews: 0,
             manual_events: 0,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         events: vec![events[2].id.clone()],
         receipts: vec![events[2].id.clone()],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
 
     let workstreams = WorkstreamsFile {
@@ -519,8 +576,15 @@ fn evidence_anchors_and_claim_prompts_present_for_each_workstream() {
         "Each workstream should have claim prompts"
     );
 
+    // Workstreams without a summary still get a drafted first claim.
+    assert_eq!(
+        md.matches("- Draft claim: ").count(),
+        2,
+        "Each workstream should have a drafted claim"
+    );
+    assert!(md.contains("including \"PR A\""), "Draft should quote PR A");
     for prompt in &[
-        "What changed for users, operators, or maintainers?",
+        "Confirm what this changed for users, operators, or maintainers.",
         "Which risk, delay, or repeated work did this reduce?",
         "Which evidence anchor best proves the change?",
         "What follow-up or gap should a reviewer know about?",
@@ -9,6 +9,7 @@ use shiplog_testkit::bdd::{Scenario, ScenarioContext};
 
 fn given_events(ctx: &mut ScenarioContext) {
     let pr = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["pr", "bdd", "1"]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.with_ymd_and_hms(2025, 1, 5, 12, 0, 0).unwrap(),
@@ -43,9 +44,13 @@ fn given_events(ctx: &mut ScenarioContext) {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let manual = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "bdd", "1"]),
         kind: EventKind::Manual,
         occurred_at: Utc.with_ymd_and_hms(2025, 1, 5, 15, 0, 0).unwrap(),
@@ -65,6 +70,9 @@ fn given_events(ctx: &mut ScenarioContext) {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 1, 5).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 1, 5).unwrap()),
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![Link {
@@ -76,6 +84,9 @@ fn given_events(ctx: &mut ScenarioContext) {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     ctx.strings
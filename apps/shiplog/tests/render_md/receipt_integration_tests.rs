@@ -19,6 +19,7 @@ fn formats_testkit_pr_fixture_without_losing_repo_or_url() {
 #[test]
 fn formats_manual_fixture_with_links_and_emoji() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "integration", "1"]),
         kind: EventKind::Manual,
         occurred_at: Utc.with_ymd_and_hms(2025, 2, 1, 12, 0, 0).unwrap(),
@@ -38,6 +39,9 @@ fn formats_manual_fixture_with_links_and_emoji() {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![Link {
@@ -49,6 +53,9 @@ fn formats_manual_fixture_with_links_and_emoji() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let line = format_receipt_markdown(&event);
@@ -8,6 +8,7 @@ use shiplog::schema::event::*;
 
 fn build_pr_event(repo: &str, title: &str, number: u64, with_link: bool) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["pr", "prop", &number.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.with_ymd_and_hms(2025, 1, 3, 10, 0, 0).unwrap(),
@@ -46,6 +47,9 @@ fn build_pr_event(repo: &str, title: &str, number: u64, with_link: bool) -> Even
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
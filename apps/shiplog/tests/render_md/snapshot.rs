@@ -207,6 +207,8 @@ fn receipt_limit_controls_main_receipts() {
             MarkdownRenderOptions {
                 receipt_limit: 1,
                 appendix_mode: AppendixMode::Full,
+                heatmap: false,
+                min_pr_size: None,
             },
         )
         .unwrap();
@@ -258,6 +260,8 @@ fn appendix_summary_lists_counts() {
             MarkdownRenderOptions {
                 receipt_limit: 1,
                 appendix_mode: AppendixMode::Summary,
+                heatmap: false,
+                min_pr_size: None,
             },
         )
         .unwrap();
@@ -300,6 +304,8 @@ fn appendix_none_omits_appendix() {
             MarkdownRenderOptions {
                 receipt_limit: 1,
                 appendix_mode: AppendixMode::None,
+                heatmap: false,
+                min_pr_size: None,
             },
         )
         .unwrap();
@@ -336,6 +342,8 @@ fn receipt_limit_zero_moves_all_receipts_to_full_appendix() {
             MarkdownRenderOptions {
                 receipt_limit: 0,
                 appendix_mode: AppendixMode::Full,
+                heatmap: false,
+                min_pr_size: None,
             },
         )
         .unwrap();
@@ -387,6 +395,8 @@ fn receipts_mode_honors_appendix_options() {
             MarkdownRenderOptions {
                 receipt_limit: 1,
                 appendix_mode: AppendixMode::None,
+                heatmap: false,
+                min_pr_size: None,
             },
         )
         .unwrap();
@@ -461,6 +471,7 @@ fn snapshot_coverage_warnings_and_partial() {
         workstreams: vec![ws],
     };
     let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("test"),
         generated_at: Utc.timestamp_opt(0, 0).unwrap(),
         user: "testuser".into(),
@@ -481,6 +492,7 @@ fn snapshot_coverage_warnings_and_partial() {
                 fetched: 100,
                 incomplete_results: Some(true),
                 notes: vec![],
+                source: None,
             },
             CoverageSlice {
                 window: TimeWindow {
@@ -492,6 +504,7 @@ fn snapshot_coverage_warnings_and_partial() {
                 fetched: 30,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             },
         ],
         warnings: vec![
@@ -499,6 +512,9 @@ fn snapshot_coverage_warnings_and_partial() {
             "Some repositories may be missing".into(),
         ],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
 
     let result = MarkdownRenderer::new()
@@ -514,6 +530,7 @@ fn snapshot_coverage_warnings_and_partial() {
 fn snapshot_mixed_event_types() {
     let pr = pr_event("owner/repo", 42, "Implement feature X");
     let review = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["review", "snap-1"]),
         kind: EventKind::Review,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -532,6 +549,8 @@ fn snapshot_mixed_event_types() {
             submitted_at: Utc.timestamp_opt(0, 0).unwrap(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![Link {
@@ -543,8 +562,12 @@ fn snapshot_mixed_event_types() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     let manual = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "snap-1"]),
         kind: EventKind::Manual,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -564,6 +587,9 @@ fn snapshot_mixed_event_types() {
             started_at: None,
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
@@ -572,6 +598,9 @@ fn snapshot_mixed_event_types() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
 
     let events = vec![pr, review, manual];
@@ -584,6 +613,14 @@ fn snapshot_mixed_event_types() {
             pull_requests: 1,
             reviews: 1,
             manual_events: 1,
+            issues: 0,
+            deployments: 0,
+            releases: 0,
+            comments: 0,
+            incidents: 0,
+            documents: 0,
+            logged_minutes: 0,
+            elapsed_minutes: 0,
         },
         receipts: vec![
             events[0].id.clone(),
@@ -595,6 +632,8 @@ fn snapshot_mixed_event_types() {
             events[1].id.clone(),
             events[2].id.clone(),
         ],
+        receipt_weights: Default::default(),
+        rules: Default::default(),
     };
     let workstreams = WorkstreamsFile {
         version: 1,
@@ -0,0 +1,140 @@
+//! Tests for cron schedules: parsing, next-run times, and the systemd and
+//! launchd units `shiplog schedule install` writes.
+
+use std::path::Path;
+
+use chrono::{DateTime, TimeZone, Utc};
+use shiplog::coverage::ReportingTimezone;
+use shiplog::schedule::{Schedule, launchd_plist, systemd_units};
+
+fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .unwrap()
+}
+
+fn schedule(expression: &str) -> Schedule {
+    expression.parse().unwrap()
+}
+
+#[test]
+fn next_after_steps_through_lists_ranges_and_steps() {
+    let utc = ReportingTimezone::UTC;
+    let every_quarter_hour = schedule("*/15 9-10 * * MON-FRI");
+
+    assert_eq!(
+        every_quarter_hour.upcoming(&at(2026, 10, 16, 10, 40), &utc, 3),
+        [
+            at(2026, 10, 16, 10, 45),
+            at(2026, 10, 19, 9, 0),
+            at(2026, 10, 19, 9, 15)
+        ]
+    );
+    assert_eq!(
+        schedule("30 8 1,15 JAN,jul *").next_after(&at(2026, 1, 15, 8, 30), &utc),
+        Some(at(2026, 7, 1, 8, 30))
+    );
+    assert_eq!(
+        schedule("0 0 * * 7").next_after(&at(2026, 10, 17, 0, 0), &utc),
+        Some(at(2026, 10, 18, 0, 0))
+    );
+}
+
+#[test]
+fn restricted_day_of_month_and_weekday_fire_on_either() {
+    // The 1st of November 2026 is a Sunday; Friday the 6th matches the weekday.
+    let times =
+        schedule("0 12 1 * FRI").upcoming(&at(2026, 10, 31, 0, 0), &ReportingTimezone::UTC, 3);
+
+    assert_eq!(
+        times,
+        [
+            at(2026, 11, 1, 12, 0),
+            at(2026, 11, 6, 12, 0),
+            at(2026, 11, 13, 12, 0)
+        ]
+    );
+}
+
+#[test]
+fn next_after_reads_fields_in_the_reporting_timezone() {
+    let los_angeles: ReportingTimezone = "America/Los_Angeles".parse().unwrap();
+
+    assert_eq!(
+        schedule("0 9 * * MON").next_after(&at(2026, 10, 17, 0, 0), &los_angeles),
+        Some(at(2026, 10, 19, 16, 0))
+    );
+    // 02:30 does not exist on 2026-03-08 in Los Angeles, so that day is skipped.
+    assert_eq!(
+        schedule("30 2 * * *").next_after(&at(2026, 3, 8, 0, 0), &los_angeles),
+        Some(at(2026, 3, 9, 9, 30))
+    );
+    // 01:30 happens twice on 2026-11-01; only the first one fires.
+    let fall_back = schedule("30 1 * * *").upcoming(&at(2026, 11, 1, 0, 0), &los_angeles, 2);
+    assert_eq!(fall_back, [at(2026, 11, 1, 8, 30), at(2026, 11, 2, 9, 30)]);
+}
+
+#[test]
+fn impossible_dates_never_fire() {
+    assert_eq!(
+        schedule("0 0 31 2 *").next_after(&at(2026, 1, 1, 0, 0), &ReportingTimezone::UTC),
+        None
+    );
+}
+
+#[test]
+fn invalid_expressions_name_the_bad_field() {
+    let error = |expression: &str| format!("{:#}", expression.parse::<Schedule>().unwrap_err());
+
+    assert!(error("0 9 * *").contains("needs five fields"));
+    assert!(error("60 9 * * *").contains("minute value `60` is outside 0-59"));
+    assert!(error("0 9 * * FUN").contains("day-of-week value `FUN` is not a number"));
+    assert!(error("0 17-9 * * *").contains("hour range `17-9` runs backwards"));
+    assert!(error("*/0 * * * *").contains("minute step `0`"));
+}
+
+#[test]
+fn systemd_units_keep_the_timezone_and_split_either_day_rules() {
+    let timezone: ReportingTimezone = "Europe/Berlin".parse().unwrap();
+    let command = [
+        "/opt/ship log/shiplog".to_string(),
+        "collect".to_string(),
+        "multi".to_string(),
+        "--config".to_string(),
+        "/home/me/100%/shiplog.toml".to_string(),
+    ];
+
+    let (service, timer) = systemd_units(
+        &schedule("0 9 1 * MON"),
+        &timezone,
+        &command,
+        Path::new("/home/me/100%"),
+    );
+
+    assert!(service.contains("Type=oneshot\n"));
+    assert!(service.contains("WorkingDirectory=/home/me/100%%\n"));
+    assert!(service.contains(
+        "ExecStart=\"/opt/ship log/shiplog\" collect multi --config /home/me/100%%/shiplog.toml\n"
+    ));
+    assert!(timer.contains("OnCalendar=*-*-01 09:00:00 Europe/Berlin\n"));
+    assert!(timer.contains("OnCalendar=Mon *-*-* 09:00:00 Europe/Berlin\n"));
+    assert!(timer.contains("Persistent=true\n"));
+    assert!(timer.contains("WantedBy=timers.target\n"));
+}
+
+#[test]
+fn launchd_plist_lists_one_interval_per_fire_time() {
+    let plist = launchd_plist(
+        &schedule("0,30 9 * * MON,FRI"),
+        "com.example.shiplog",
+        &["/usr/local/bin/shiplog".to_string(), "collect".to_string()],
+        Path::new("/Users/me/R&D"),
+    );
+
+    assert!(plist.contains("<string>com.example.shiplog</string>"));
+    assert!(plist.contains("<string>/Users/me/R&amp;D</string>"));
+    assert_eq!(plist.matches("<key>Weekday</key>").count(), 4);
+    assert_eq!(plist.matches("<key>Day</key>").count(), 0);
+    assert!(plist.contains(
+        "      <key>Hour</key>\n      <integer>9</integer>\n      <key>Minute</key>\n      <integer>30</integer>\n      <key>Weekday</key>\n      <integer>5</integer>\n"
+    ));
+}
@@ -4,6 +4,8 @@ mod adversarial;
 mod compat;
 #[path = "schema/format_stability.rs"]
 mod format_stability;
+#[path = "schema/json_schema.rs"]
+mod json_schema;
 #[path = "schema/property.rs"]
 mod property;
 #[path = "schema/property_tests.rs"]
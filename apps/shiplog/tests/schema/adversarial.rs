@@ -281,6 +281,7 @@ fn time_window_same_dates() {
 #[test]
 fn full_event_serde_round_trip() {
     let ev = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["rt"]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -315,6 +316,9 @@ fn full_event_serde_round_trip() {
             url: Some("https://api.github.com".into()),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     let json = serde_json::to_string(&ev).unwrap();
     let back: EventEnvelope = serde_json::from_str(&json).unwrap();
@@ -5,7 +5,7 @@
 //! changes.
 
 use chrono::{NaiveDate, TimeZone, Utc};
-use shiplog::ids::{EventId, RunId, WorkstreamId};
+use shiplog::ids::{EventId, OpaqueId, RunId, WorkstreamId};
 use shiplog::schema::bundle::{BundleManifest, BundleProfile, FileChecksum};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::*;
@@ -29,6 +29,7 @@ fn window() -> TimeWindow {
 #[test]
 fn format_event_envelope_pr_yaml() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", "acme/widgets", "42"]),
         kind: EventKind::PullRequest,
         occurred_at: ts(),
@@ -61,8 +62,11 @@ fn format_event_envelope_pr_yaml() {
         source: SourceRef {
             system: SourceSystem::Github,
             url: Some("https://api.github.com/repos/acme/widgets/pulls/42".into()),
-            opaque_id: Some("PR_abc123".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "PR_abc123")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_yaml_snapshot!(event);
 }
@@ -74,6 +78,7 @@ fn format_event_envelope_pr_yaml() {
 #[test]
 fn format_event_envelope_minimal_pr() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["min"]),
         kind: EventKind::PullRequest,
         occurred_at: ts(),
@@ -105,6 +110,9 @@ fn format_event_envelope_minimal_pr() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_json_snapshot!(event);
 }
@@ -112,6 +120,7 @@ fn format_event_envelope_minimal_pr() {
 #[test]
 fn format_event_envelope_minimal_review() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["min-review"]),
         kind: EventKind::Review,
         occurred_at: ts(),
@@ -130,6 +139,8 @@ fn format_event_envelope_minimal_review() {
             submitted_at: ts(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![],
@@ -138,6 +149,9 @@ fn format_event_envelope_minimal_review() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_json_snapshot!(event);
 }
@@ -145,6 +159,7 @@ fn format_event_envelope_minimal_review() {
 #[test]
 fn format_event_envelope_minimal_manual() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["min-manual"]),
         kind: EventKind::Manual,
         occurred_at: ts(),
@@ -164,6 +179,9 @@ fn format_event_envelope_minimal_manual() {
             started_at: None,
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
@@ -172,6 +190,9 @@ fn format_event_envelope_minimal_manual() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_json_snapshot!(event);
 }
@@ -183,6 +204,7 @@ fn format_event_envelope_minimal_manual() {
 #[test]
 fn format_event_envelope_edge_cases() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["edge"]),
         kind: EventKind::PullRequest,
         occurred_at: ts(),
@@ -215,8 +237,11 @@ fn format_event_envelope_edge_cases() {
         source: SourceRef {
             system: SourceSystem::Other("custom/system".into()),
             url: Some("".into()),
-            opaque_id: Some("".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_json_snapshot!(event);
 }
@@ -246,6 +271,9 @@ fn format_manual_events_file_full_yaml() {
                 url: "https://wiki.internal/p42".into(),
             }],
             impact: Some("Reduced MTTR by 50%".into()),
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         }],
     };
     insta::assert_yaml_snapshot!(file);
@@ -266,6 +294,9 @@ fn format_manual_events_file_minimal_yaml() {
             tags: vec![],
             receipts: vec![],
             impact: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
+            repeat: None,
         }],
     };
     insta::assert_yaml_snapshot!(file);
@@ -309,12 +340,22 @@ fn format_workstreams_file_yaml() {
                     pull_requests: 5,
                     reviews: 3,
                     manual_events: 1,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![
                     EventId::from_parts(["github", "pr", "acme/widgets", "42"]),
                     EventId::from_parts(["github", "pr", "acme/widgets", "43"]),
                 ],
                 receipts: vec![EventId::from_parts(["github", "pr", "acme/widgets", "42"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["ws", "infra"]),
@@ -324,6 +365,8 @@ fn format_workstreams_file_yaml() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -337,6 +380,7 @@ fn format_workstreams_file_yaml() {
 #[test]
 fn format_coverage_manifest_yaml() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("test_run_456".into()),
         generated_at: ts(),
         user: "octocat".into(),
@@ -354,6 +398,7 @@ fn format_coverage_manifest_yaml() {
                 fetched: 15,
                 incomplete_results: Some(false),
                 notes: vec![],
+                source: None,
             },
             CoverageSlice {
                 window: TimeWindow {
@@ -365,10 +410,14 @@ fn format_coverage_manifest_yaml() {
                 fetched: 30,
                 incomplete_results: Some(true),
                 notes: vec!["Rate limited".into()],
+                source: None,
             },
         ],
         warnings: vec!["Incomplete results for February".into()],
         completeness: Completeness::Partial,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
     insta::assert_yaml_snapshot!(manifest);
 }
@@ -395,6 +444,9 @@ fn format_bundle_manifest_yaml() {
                 bytes: 2048,
             },
         ],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_yaml_snapshot!(manifest);
 }
@@ -406,6 +458,7 @@ fn format_bundle_manifest_yaml() {
 #[test]
 fn format_coverage_manifest_empty() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("empty".into()),
         generated_at: ts(),
         user: "".into(),
@@ -418,6 +471,9 @@ fn format_coverage_manifest_empty() {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Unknown,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -429,6 +485,9 @@ fn format_bundle_manifest_empty_files() {
         generated_at: ts(),
         profile: BundleProfile::Internal,
         files: vec![],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_json_snapshot!(manifest);
 }
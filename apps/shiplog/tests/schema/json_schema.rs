@@ -0,0 +1,128 @@
+//! JSON Schema contract tests: generated values conform, malformed values
+//! are reported field by field, and the checked-in contracts stay current.
+
+use proptest::prelude::*;
+use shiplog::schema::json_schema::SchemaDocument;
+use std::path::PathBuf;
+
+fn contracts_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../contracts/schemas")
+}
+
+proptest! {
+    #[test]
+    fn generated_events_conform(event in shiplog_testkit::proptest::strategy_event_envelope()) {
+        let value = serde_json::to_value(&event).unwrap();
+        let violations = SchemaDocument::EventEnvelope.validate(&value);
+        prop_assert!(violations.is_empty(), "{violations:?}");
+    }
+
+    #[test]
+    fn generated_coverage_conforms(cov in shiplog_testkit::proptest::strategy_coverage_manifest()) {
+        let value = serde_json::to_value(&cov).unwrap();
+        let violations = SchemaDocument::CoverageManifest.validate(&value);
+        prop_assert!(violations.is_empty(), "{violations:?}");
+    }
+
+    #[test]
+    fn generated_workstreams_conform(file in shiplog_testkit::proptest::strategy_workstreams_file()) {
+        let value = serde_json::to_value(&file).unwrap();
+        let violations = SchemaDocument::WorkstreamsFile.validate(&value);
+        prop_assert!(violations.is_empty(), "{violations:?}");
+    }
+}
+
+#[test]
+fn manual_events_yaml_conforms() {
+    let yaml = r#"
+version: 1
+generated_at: 2025-01-01T00:00:00Z
+events:
+  - id: launch-1
+    type: Launch
+    date: 2025-02-03
+    title: Shipped billing v2
+    tags: [billing]
+    receipts:
+      - label: announcement
+        url: https://example.com/launch
+  - id: oncall-1
+    type: Incident
+    date:
+      start: 2025-02-10
+      end: 2025-02-11
+    title: Paged for queue backlog
+    description: Drained the dead-letter queue
+    workstream: platform
+    tags: []
+    receipts: []
+    impact: null
+"#;
+    let value: serde_json::Value = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(SchemaDocument::ManualEventsFile.validate(&value), vec![]);
+}
+
+#[test]
+fn malformed_event_reports_each_field() {
+    let value = serde_json::json!({
+        "id": "e1",
+        "kind": "PullRequest",
+        "occurred_at": "2025-01-01",
+        "actor": {"login": "octo"},
+        "repo": {"full_name": "acme/widgets", "visibility": "Internal"},
+        "payload": {"type": "PullRequest", "data": {"number": -4, "title": "t",
+            "state": "Merged", "created_at": "2025-01-01T00:00:00Z",
+            "touched_paths_hint": []}},
+        "tags": [],
+        "source": {"system": "github"}
+    });
+    let rendered: Vec<String> = SchemaDocument::EventEnvelope
+        .validate(&value)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    for expected in [
+        "/: missing required field `links`",
+        "/occurred_at: expected an RFC 3339 timestamp, found \"2025-01-01\"",
+        "/repo/visibility: expected one of \"Public\", \"Private\", \"Unknown\", found \"Internal\"",
+        "/payload/data/number: expected a value >= 0, found -4",
+    ] {
+        assert!(
+            rendered.iter().any(|line| line == expected),
+            "missing {expected:?} in {rendered:#?}"
+        );
+    }
+}
+
+#[test]
+fn legacy_events_without_schema_version_conform() {
+    let value = serde_json::json!({
+        "id": "e1",
+        "kind": "Review",
+        "occurred_at": "2025-01-01T00:00:00Z",
+        "actor": {"login": "octo", "id": null},
+        "repo": {"full_name": "acme/widgets", "html_url": null, "visibility": "Public"},
+        "payload": {"type": "Review", "data": {"pull_number": 1, "pull_title": "t",
+            "submitted_at": "2025-01-01T00:00:00Z", "state": "approved"}},
+        "tags": [],
+        "links": [],
+        "source": {"system": "github", "url": null, "opaque_id": null}
+    });
+    assert_eq!(SchemaDocument::EventEnvelope.validate(&value), vec![]);
+}
+
+#[test]
+fn checked_in_contracts_match_generated_schemas() {
+    for doc in SchemaDocument::ALL {
+        let path = contracts_dir().join(doc.file_name());
+        let checked_in = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("read {}: {err}", path.display()));
+        let generated = format!("{}\n", serde_json::to_string_pretty(&doc.schema()).unwrap());
+        assert!(
+            checked_in == generated,
+            "{} is stale; regenerate with `shiplog validate --print-schema {doc} > {}`",
+            path.display(),
+            path.display()
+        );
+    }
+}
@@ -250,6 +250,12 @@ proptest! {
             (EventPayload::PullRequest(_), EventPayload::PullRequest(_)) => {},
             (EventPayload::Review(_), EventPayload::Review(_)) => {},
             (EventPayload::Manual(_), EventPayload::Manual(_)) => {},
+            (EventPayload::Issue(_), EventPayload::Issue(_)) => {},
+            (EventPayload::Deployment(_), EventPayload::Deployment(_)) => {},
+            (EventPayload::Release(_), EventPayload::Release(_)) => {},
+            (EventPayload::Comment(_), EventPayload::Comment(_)) => {},
+            (EventPayload::Incident(_), EventPayload::Incident(_)) => {},
+            (EventPayload::Document(_), EventPayload::Document(_)) => {},
             _ => prop_assert!(false, "payload discriminant changed after roundtrip"),
         }
     }
@@ -4,7 +4,7 @@
 //! accidental serialization-breaking changes.
 
 use chrono::{NaiveDate, TimeZone, Utc};
-use shiplog::ids::EventId;
+use shiplog::ids::{EventId, OpaqueId};
 use shiplog::schema::bundle::{BundleManifest, BundleProfile, FileChecksum};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
 use shiplog::schema::event::*;
@@ -207,6 +207,7 @@ fn snapshot_completeness_unknown() {
 #[test]
 fn snapshot_event_envelope_pull_request() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", "acme/widgets", "42"]),
         kind: EventKind::PullRequest,
         occurred_at: ts(),
@@ -239,8 +240,11 @@ fn snapshot_event_envelope_pull_request() {
         source: SourceRef {
             system: SourceSystem::Github,
             url: Some("https://api.github.com/repos/acme/widgets/pulls/42".into()),
-            opaque_id: Some("PR_abc123".into()),
+            opaque_id: Some(OpaqueId::from_raw("test", "PR_abc123")),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_json_snapshot!(event);
 }
@@ -248,6 +252,7 @@ fn snapshot_event_envelope_pull_request() {
 #[test]
 fn snapshot_event_envelope_review() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "review", "acme/widgets", "42", "1"]),
         kind: EventKind::Review,
         occurred_at: ts(),
@@ -266,6 +271,8 @@ fn snapshot_event_envelope_review() {
             submitted_at: ts(),
             state: "approved".into(),
             window: Some(window()),
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec!["review".into()],
         links: vec![],
@@ -274,6 +281,9 @@ fn snapshot_event_envelope_review() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_json_snapshot!(event);
 }
@@ -281,6 +291,7 @@ fn snapshot_event_envelope_review() {
 #[test]
 fn snapshot_event_envelope_manual() {
     let event = EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", "acme/widgets", "incident-response"]),
         kind: EventKind::Manual,
         occurred_at: ts(),
@@ -300,6 +311,9 @@ fn snapshot_event_envelope_manual() {
             started_at: Some(NaiveDate::from_ymd_opt(2025, 3, 15).unwrap()),
             ended_at: Some(NaiveDate::from_ymd_opt(2025, 3, 16).unwrap()),
             impact: Some("Reduced MTTR by 50%".into()),
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec!["incident".into(), "oncall".into()],
         links: vec![Link {
@@ -311,6 +325,9 @@ fn snapshot_event_envelope_manual() {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     };
     insta::assert_json_snapshot!(event);
 }
@@ -346,6 +363,7 @@ fn snapshot_time_window() {
 #[test]
 fn snapshot_coverage_manifest() {
     let manifest = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: shiplog::ids::RunId("test_run_123".into()),
         generated_at: ts(),
         user: "octocat".into(),
@@ -359,9 +377,13 @@ fn snapshot_coverage_manifest() {
             fetched: 42,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         }],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -377,6 +399,9 @@ fn snapshot_bundle_manifest() {
             sha256: "abc123".into(),
             bytes: 4096,
         }],
+        scope: None,
+        provenance: None,
+        parts: Vec::new(),
     };
     insta::assert_json_snapshot!(manifest);
 }
@@ -395,9 +420,19 @@ fn snapshot_workstreams_file() {
                 pull_requests: 3,
                 reviews: 1,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![EventId::from_parts(["github", "pr", "acme/widgets", "42"])],
             receipts: vec![EventId::from_parts(["github", "pr", "acme/widgets", "42"])],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     insta::assert_json_snapshot!(ws);
@@ -0,0 +1,114 @@
+//! Tests for the full-text ledger index behind `shiplog search`.
+
+use chrono::{TimeZone, Utc};
+use shiplog::fixtures::{FixtureSpec, generate};
+use shiplog::schema::event::{
+    EventEnvelope, EventKind, EventPayload, IssueEvent, IssueState, ManualEvent, ManualEventType,
+};
+use shiplog::search::SearchIndex;
+use tempfile::TempDir;
+
+fn events() -> Vec<EventEnvelope> {
+    let mut events = generate(&FixtureSpec {
+        events: 3,
+        ..FixtureSpec::default()
+    })
+    .unwrap()
+    .events;
+    let mut titled = |index: usize, title: &str, body: Option<&str>| {
+        let event = &mut events[index];
+        event.occurred_at = Utc
+            .with_ymd_and_hms(2025, 3, 1 + index as u32, 9, 0, 0)
+            .unwrap();
+        event.kind = match body {
+            Some(_) => EventKind::Manual,
+            None => EventKind::Issue,
+        };
+        event.payload = match body {
+            Some(description) => EventPayload::Manual(ManualEvent {
+                event_type: ManualEventType::Note,
+                title: title.into(),
+                description: Some(description.into()),
+                started_at: None,
+                ended_at: None,
+                impact: None,
+                metrics: vec![],
+                effort: None,
+                evidence: Vec::new(),
+            }),
+            None => EventPayload::Issue(IssueEvent {
+                number: index as u64,
+                title: title.into(),
+                state: IssueState::Closed,
+                labels: vec!["payments".into()],
+                opened_at: event.occurred_at,
+                closed_at: None,
+                window: None,
+                effort: None,
+            }),
+        };
+    };
+    titled(0, "Fixed webhook retries", None);
+    titled(
+        1,
+        "Quarterly planning",
+        Some("Agreed to prioritise webhook retry work"),
+    );
+    titled(2, "Rotate signing keys", None);
+    events
+}
+
+fn index(tmp: &TempDir) -> SearchIndex {
+    SearchIndex::build(tmp.path().join("search.index.sqlite"), &events()).unwrap()
+}
+
+#[test]
+fn multi_word_queries_match_every_term_with_stemming() {
+    let tmp = TempDir::new().unwrap();
+    let index = index(&tmp);
+
+    let hits = index.search("webhook retry", 10).unwrap();
+    let titles: Vec<_> = hits.iter().map(|hit| hit.title.as_str()).collect();
+    assert_eq!(titles, ["Fixed webhook retries", "Quarterly planning"]);
+
+    assert!(index.search("webhook signing", 10).unwrap().is_empty());
+    assert_eq!(index.search("fixes", 10).unwrap().len(), 1);
+    assert_eq!(index.search("payments", 10).unwrap().len(), 2);
+}
+
+#[test]
+fn prefixes_punctuation_and_limits_are_handled() {
+    let tmp = TempDir::new().unwrap();
+    let index = index(&tmp);
+
+    assert_eq!(
+        index.search("rot*", 10).unwrap()[0].title,
+        "Rotate signing keys"
+    );
+    assert_eq!(index.search("\"signing\" (keys:", 10).unwrap().len(), 1);
+    assert_eq!(index.search("webhook", 1).unwrap().len(), 1);
+    assert!(
+        index
+            .search("  \"\" ", 10)
+            .unwrap_err()
+            .to_string()
+            .contains("search terms cannot be blank")
+    );
+}
+
+#[test]
+fn rebuilding_replaces_the_index_and_open_reads_it_back() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("search.index.sqlite");
+    SearchIndex::build(&path, &events()).unwrap();
+    SearchIndex::build(&path, &events()[..1]).unwrap();
+
+    let index = SearchIndex::open(&path).unwrap();
+    assert_eq!(index.len().unwrap(), 1);
+    let hit = &index.search("webhook", 10).unwrap()[0];
+    assert_eq!(hit.kind, "Issue");
+    assert_eq!(
+        hit.occurred_at,
+        Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap()
+    );
+}
@@ -0,0 +1,225 @@
+//! Tests for release lookup, checksum verification, and `shiplog self-update`.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use sha2::{Digest, Sha256};
+use shiplog::update::{
+    ReleaseSource, Updater, compare_versions, expected_checksum, replace_executable,
+    verify_checksum,
+};
+
+/// Serve `routes` (path to body) for `requests` requests, then stop.
+fn serve(
+    requests: usize,
+    routes: impl FnOnce(&str) -> BTreeMap<String, Vec<u8>>,
+) -> (String, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let routes = routes(&base);
+    let handle = thread::spawn(move || {
+        for _ in 0..requests {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header == "\r\n" {
+                    break;
+                }
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+            let (status, body) = match routes.get(path) {
+                Some(body) => ("200 OK", body.as_slice()),
+                None => ("404 Not Found", b"missing".as_slice()),
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(body).unwrap();
+        }
+    });
+    (base, handle)
+}
+
+fn release_routes(
+    base: &str,
+    tag: &str,
+    asset: &str,
+    binary: &[u8],
+    sums: &str,
+) -> BTreeMap<String, Vec<u8>> {
+    let release = serde_json::json!({
+        "tag_name": tag,
+        "html_url": format!("{base}/releases/{tag}"),
+        "assets": [
+            { "name": asset, "browser_download_url": format!("{base}/download/{asset}") },
+            { "name": "SHA256SUMS.txt", "browser_download_url": format!("{base}/download/SHA256SUMS.txt") },
+        ],
+    });
+    BTreeMap::from([
+        (
+            "/repos/acme/shiplog/releases/latest".to_string(),
+            serde_json::to_vec(&release).unwrap(),
+        ),
+        (format!("/download/{asset}"), binary.to_vec()),
+        (
+            "/download/SHA256SUMS.txt".to_string(),
+            sums.as_bytes().to_vec(),
+        ),
+    ])
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+fn source(base: &str) -> ReleaseSource {
+    ReleaseSource {
+        api_base: base.to_string(),
+        repo: "acme/shiplog".into(),
+    }
+}
+
+#[test]
+fn versions_compare_numerically() {
+    assert_eq!(
+        compare_versions("0.10.0", "0.9.9").unwrap(),
+        Ordering::Greater
+    );
+    assert_eq!(compare_versions("1.0.0", "1.0.0").unwrap(), Ordering::Equal);
+    assert_eq!(
+        compare_versions("1.0.0", "1.0.0-beta.2").unwrap(),
+        Ordering::Greater
+    );
+    assert!(compare_versions("latest", "1.0.0").is_err());
+}
+
+#[test]
+fn checksum_lookup_accepts_bare_and_binary_mode_names() {
+    let sums = "AAA  shiplog-x86_64-unknown-linux-gnu\nbbb *shiplog-x86_64-pc-windows-msvc.exe\n";
+
+    assert_eq!(
+        expected_checksum(sums, "shiplog-x86_64-unknown-linux-gnu").as_deref(),
+        Some("aaa")
+    );
+    assert_eq!(
+        expected_checksum(sums, "shiplog-x86_64-pc-windows-msvc.exe").as_deref(),
+        Some("bbb")
+    );
+}
+
+#[test]
+fn verify_checksum_rejects_mismatches_and_missing_entries() {
+    let sums = format!("{}  ./a/shiplog-a\n", sha256(b"good"));
+
+    verify_checksum(b"good", &sums, "shiplog-a").unwrap();
+    let mismatch = verify_checksum(b"evil", &sums, "shiplog-a").unwrap_err();
+    assert!(mismatch.to_string().contains("checksum mismatch"));
+    let missing = verify_checksum(b"good", &sums, "shiplog-b").unwrap_err();
+    assert!(missing.to_string().contains("has no entry for shiplog-b"));
+}
+
+#[test]
+fn download_verified_returns_bytes_that_match_the_published_sum() {
+    let binary = b"#!/bin/sh\necho new\n".to_vec();
+    let sums = format!("{}  ./shiplog-test/shiplog-test\n", sha256(&binary));
+    let served = binary.clone();
+    let (base, server) = serve(3, move |base| {
+        release_routes(base, "v9.0.0", "shiplog-test", &served, &sums)
+    });
+    let updater = Updater::new(source(&base)).unwrap();
+
+    let release = updater.latest_release().unwrap();
+    let bytes = updater.download_verified(&release, "shiplog-test").unwrap();
+    server.join().unwrap();
+
+    assert_eq!(release.version(), "9.0.0");
+    assert_eq!(bytes, binary);
+}
+
+#[test]
+fn download_verified_refuses_a_tampered_binary() {
+    let sums = format!("{}  shiplog-test\n", sha256(b"published"));
+    let (base, server) = serve(3, move |base| {
+        release_routes(base, "v9.0.0", "shiplog-test", b"tampered", &sums)
+    });
+    let updater = Updater::new(source(&base)).unwrap();
+
+    let release = updater.latest_release().unwrap();
+    let err = updater
+        .download_verified(&release, "shiplog-test")
+        .unwrap_err();
+    server.join().unwrap();
+
+    assert!(
+        err.to_string()
+            .contains("checksum mismatch for shiplog-test")
+    );
+}
+
+#[test]
+fn replace_executable_swaps_the_file_in_place() {
+    let tmp = tempfile::tempdir().unwrap();
+    let target = tmp.path().join("shiplog");
+    std::fs::write(&target, b"old").unwrap();
+
+    replace_executable(&target, b"new").unwrap();
+
+    assert_eq!(std::fs::read(&target).unwrap(), b"new");
+    assert!(!tmp.path().join(".shiplog.update").exists());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&target).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
+
+fn self_update_cmd(base: &str) -> Command {
+    let mut command = Command::from_std(std::process::Command::new(env!("CARGO_BIN_EXE_shiplog")));
+    command
+        .env("SHIPLOG_RELEASE_API", base)
+        .env("SHIPLOG_RELEASE_REPO", "acme/shiplog");
+    command
+}
+
+#[test]
+fn check_reports_a_newer_release_without_downloading() {
+    let (base, server) = serve(1, |base| {
+        release_routes(base, "v99.0.0", "shiplog-test", b"", "")
+    });
+
+    self_update_cmd(&base)
+        .args(["self-update", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("shiplog 99.0.0 is available"))
+        .stdout(predicate::str::contains("Run `shiplog self-update`"));
+    server.join().unwrap();
+}
+
+#[test]
+fn check_reports_up_to_date_when_the_release_is_not_newer() {
+    let current = format!("v{}", env!("CARGO_PKG_VERSION"));
+    let (base, server) = serve(1, move |base| {
+        release_routes(base, &current, "shiplog-test", b"", "")
+    });
+
+    self_update_cmd(&base)
+        .arg("self-update")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is up to date"));
+    server.join().unwrap();
+}
@@ -7,6 +7,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::team::{TeamAggregator, TeamConfig};
 use shiplog_testkit::bdd::{Scenario, assertions};
 use std::io::Write;
@@ -18,6 +19,7 @@ use std::path::Path;
 
 fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["team", id]),
         kind: EventKind::PullRequest,
         occurred_at: when,
@@ -52,11 +54,15 @@ fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelop
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage(run_id: &str) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: shiplog::ids::RunId(run_id.to_string()),
         generated_at: Utc::now(),
         user: "user".to_string(),
@@ -69,6 +75,9 @@ fn make_coverage(run_id: &str) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -7,7 +7,11 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
-use shiplog::team::{TeamAggregator, TeamConfig, write_team_outputs};
+use shiplog::schema::version::SchemaVersion;
+use shiplog::team::{
+    TeamAggregator, TeamConfig, TrendPeriod, write_team_outputs, write_team_trends,
+};
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::Path;
 
@@ -17,6 +21,7 @@ use std::path::Path;
 
 fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["team", id]),
         kind: EventKind::PullRequest,
         occurred_at: when,
@@ -51,11 +56,15 @@ fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelop
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage(run_id: &str) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: shiplog::ids::RunId(run_id.to_string()),
         generated_at: Utc::now(),
         user: "alice".to_string(),
@@ -68,6 +77,9 @@ fn make_coverage(run_id: &str) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -308,6 +320,74 @@ fn aggregate_schema_version_compatible() {
     assert_eq!(result.included_members, vec!["alice"]);
 }
 
+#[test]
+fn aggregate_pins_unversioned_ledgers_to_legacy_schema() {
+    let dir = tempfile::tempdir().unwrap();
+    let when = Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap();
+    let mut legacy = make_coverage("run");
+    legacy.schema_version = SchemaVersion::LEGACY;
+    write_member(
+        dir.path(),
+        "alice",
+        &[make_event("e1", "org/repo", when)],
+        &legacy,
+    );
+    write_member(
+        dir.path(),
+        "bob",
+        &[make_event("e2", "org/repo", when)],
+        &make_coverage("run"),
+    );
+    // Strip the field from alice's manifest, as older builds wrote it.
+    let path = dir.path().join("alice").join("coverage.manifest.json");
+    let mut json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    json.as_object_mut().unwrap().remove("schema_version");
+    std::fs::write(&path, json.to_string()).unwrap();
+
+    let agg = TeamAggregator::new(TeamConfig {
+        members: vec!["alice".into(), "bob".into()],
+        required_schema_version: Some("0.2.0".into()),
+        ..TeamConfig::default()
+    });
+    let result = agg.aggregate(dir.path()).unwrap();
+    assert_eq!(result.included_members, vec!["alice"]);
+    assert_eq!(result.incompatible_members, vec!["bob"]);
+    assert!(
+        result
+            .events
+            .iter()
+            .all(|e| e.schema_version == SchemaVersion::CURRENT)
+    );
+}
+
+#[test]
+fn aggregate_skips_ledgers_from_newer_builds() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cov = make_coverage("run");
+    cov.schema_version = SchemaVersion::new(9, 0, 0);
+    let when = Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap();
+    write_member(
+        dir.path(),
+        "alice",
+        &[make_event("e1", "org/repo", when)],
+        &cov,
+    );
+
+    let agg = TeamAggregator::new(TeamConfig {
+        members: vec!["alice".into()],
+        ..TeamConfig::default()
+    });
+    let result = agg.aggregate(dir.path()).unwrap();
+    assert!(result.included_members.is_empty());
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| w.contains("newer than this build supports"))
+    );
+}
+
 // ---------------------------------------------------------------------------
 // TeamAggregator::render_packet_markdown
 // ---------------------------------------------------------------------------
@@ -403,6 +483,104 @@ fn write_team_outputs_empty_events() {
     assert!(events_text.trim().is_empty());
 }
 
+// ---------------------------------------------------------------------------
+// TeamAggregator::trends
+// ---------------------------------------------------------------------------
+
+#[test]
+fn trends_collapse_overlapping_runs_and_keep_empty_periods() {
+    let dir = tempfile::tempdir().unwrap();
+    let jan = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+    let mar = Utc.with_ymd_and_hms(2025, 3, 3, 12, 0, 0).unwrap();
+    let cov = make_coverage("run");
+    write_member(
+        dir.path(),
+        "alice-q1",
+        &[make_event("a1", "org/repo-a", jan)],
+        &cov,
+    );
+    write_member(
+        dir.path(),
+        "alice-q1-rerun",
+        &[
+            make_event("a1", "org/repo-a", jan),
+            make_event("a2", "org/repo-b", mar),
+        ],
+        &cov,
+    );
+    write_member(
+        dir.path(),
+        "bob-q1",
+        &[make_event("b1", "org/repo-a", mar)],
+        &cov,
+    );
+
+    let agg = TeamAggregator::new(TeamConfig {
+        members: vec!["alice".into(), "bob".into(), "ghost".into()],
+        ..TeamConfig::default()
+    });
+    let runs = BTreeMap::from([
+        (
+            "alice".to_string(),
+            vec![
+                dir.path().join("alice-q1"),
+                dir.path().join("alice-q1-rerun"),
+            ],
+        ),
+        ("bob".to_string(), vec![dir.path().join("bob-q1")]),
+    ]);
+    let trends = agg.trends(&runs, TrendPeriod::Month).unwrap();
+
+    assert_eq!(trends.runs_read, 3);
+    let periods: Vec<&str> = trends.rows.iter().map(|r| r.period.as_str()).collect();
+    assert_eq!(periods, vec!["2025-01", "2025-02", "2025-03"]);
+    assert_eq!(trends.rows[0].events, 1);
+    assert_eq!(trends.rows[1].events, 0);
+    assert_eq!(trends.rows[2].events, 2);
+    assert_eq!(trends.rows[2].pull_requests, 2);
+    assert_eq!(trends.rows[2].active_repos, 2);
+    assert_eq!(trends.rows[2].active_members, 2);
+    assert!(
+        trends
+            .warnings
+            .iter()
+            .any(|w| w.contains("No runs provided for member 'ghost'"))
+    );
+
+    let quarterly = agg.trends(&runs, TrendPeriod::Quarter).unwrap();
+    assert_eq!(quarterly.rows.len(), 1);
+    assert_eq!(quarterly.rows[0].period, "2025-Q1");
+    assert_eq!(quarterly.rows[0].events, 3);
+
+    let out = dir.path().join("out");
+    let files = write_team_trends(&out, &trends).unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&files.json).unwrap()).unwrap();
+    assert_eq!(json["period"], "month");
+    assert_eq!(json["rows"].as_array().unwrap().len(), 3);
+    let markdown = std::fs::read_to_string(&files.markdown).unwrap();
+    assert!(markdown.contains("| 2025-02 | 0 | 0 | 0 | 0 | 0 |"));
+}
+
+#[test]
+fn trends_follow_configured_window() {
+    let dir = tempfile::tempdir().unwrap();
+    write_member_default(dir.path(), "alice");
+
+    let agg = TeamAggregator::new(TeamConfig {
+        members: vec!["alice".into()],
+        since: Some(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap()),
+        until: Some(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()),
+        ..TeamConfig::default()
+    });
+    let runs = BTreeMap::from([("alice".to_string(), vec![dir.path().join("alice")])]);
+    let trends = agg.trends(&runs, TrendPeriod::Month).unwrap();
+
+    let periods: Vec<&str> = trends.rows.iter().map(|r| r.period.as_str()).collect();
+    assert_eq!(periods, vec!["2024-12", "2025-01", "2025-02"]);
+    assert_eq!(trends.rows[1].events, 2);
+}
+
 // ---------------------------------------------------------------------------
 // snapshot: aggregate result coverage JSON
 // ---------------------------------------------------------------------------
@@ -1,9 +1,12 @@
 //! Integration tests for shiplog::team: config loading, parsing, resolution.
 
 use chrono::NaiveDate;
-use shiplog::team::{TeamConfig, parse_alias_list, parse_csv_list, resolve_team_config};
+use shiplog::team::{
+    TEAM_ENV_VARS, TeamConfig, TeamConfigOverrides, parse_alias_list, parse_csv_list,
+    resolve_layered_team_config, resolve_team_config,
+};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ---------------------------------------------------------------------------
 // parse_csv_list
@@ -264,6 +267,9 @@ fn team_config_yaml_round_trip() {
         since: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
         until: Some(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()),
         required_schema_version: Some("0.2.0".into()),
+        parallelism: Some(4),
+        member_breakdown: true,
+        member_root: Some(PathBuf::from("ledgers")),
     };
     let yaml = serde_yaml::to_string(&cfg).unwrap();
     let cfg2: TeamConfig = serde_yaml::from_str(&yaml).unwrap();
@@ -273,6 +279,8 @@ fn team_config_yaml_round_trip() {
     assert_eq!(cfg.since, cfg2.since);
     assert_eq!(cfg.until, cfg2.until);
     assert_eq!(cfg.required_schema_version, cfg2.required_schema_version);
+    assert_eq!(cfg.parallelism, cfg2.parallelism);
+    assert_eq!(cfg.member_breakdown, cfg2.member_breakdown);
 }
 
 #[test]
@@ -285,6 +293,9 @@ fn team_config_snapshot_yaml() {
         since: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
         until: Some(NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()),
         required_schema_version: None,
+        parallelism: None,
+        member_breakdown: false,
+        member_root: None,
     };
     // Use JSON for deterministic key ordering in snapshots
     let json = serde_json::to_string_pretty(&cfg).unwrap();
@@ -452,6 +463,148 @@ fn resolve_config_sets_required_schema_version() {
     assert_eq!(cfg.required_schema_version, Some("1.0.0".to_string()));
 }
 
+// ---------------------------------------------------------------------------
+// TeamConfig::load (TOML) and layered overrides
+// ---------------------------------------------------------------------------
+
+fn write_team_toml(dir: &Path, text: &str) -> PathBuf {
+    let path = dir.join("team.toml");
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+#[test]
+fn load_full_team_toml() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_team_toml(
+        dir.path(),
+        r#"
+members = ["alice", "bob"]
+sections = ["summary", "receipts"]
+template = "team-template.md"
+schema_version = "0.2.0"
+member_root = "ledgers"
+parallelism = 2
+member_breakdown = true
+
+[window]
+since = 2025-01-01
+until = "2025-04-01"
+
+[aliases]
+alice = "Alice Smith"
+"#,
+    );
+
+    let cfg = TeamConfig::load(&path).unwrap();
+    assert_eq!(cfg.members, vec!["alice", "bob"]);
+    assert_eq!(cfg.aliases["alice"], "Alice Smith");
+    assert_eq!(cfg.sections, vec!["summary", "receipts"]);
+    assert_eq!(cfg.template, Some(dir.path().join("team-template.md")));
+    assert_eq!(cfg.member_root, Some(dir.path().join("ledgers")));
+    assert_eq!(cfg.since, NaiveDate::from_ymd_opt(2025, 1, 1));
+    assert_eq!(cfg.until, NaiveDate::from_ymd_opt(2025, 4, 1));
+    assert_eq!(cfg.required_schema_version.as_deref(), Some("0.2.0"));
+    assert_eq!(cfg.parallelism, Some(2));
+    assert!(cfg.member_breakdown);
+}
+
+#[test]
+fn team_toml_errors_name_the_offending_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let cases = [
+        ("memebrs = [\"alice\"]", "memebrs"),
+        ("members = [\"alice\", \" \"]", "`members[1]`"),
+        ("sections = [\"summary\", \"charts\"]", "`sections[1]`"),
+        (
+            "[window]\nsince = 2025-04-01\nuntil = 2025-01-01",
+            "`window.until`",
+        ),
+        ("[window]\nsince = \"soon\"", "since"),
+        ("[aliases]\nalice = \"\"", "`aliases.alice`"),
+        ("parallelism = 0", "`parallelism`"),
+    ];
+    for (text, key) in cases {
+        let path = write_team_toml(dir.path(), text);
+        let err = TeamConfig::load(&path).unwrap_err().to_string();
+        assert!(err.contains(key), "{text:?} -> {err}");
+    }
+}
+
+#[test]
+fn layered_config_precedence_is_file_then_env_then_cli() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_team_toml(
+        dir.path(),
+        r#"
+members = ["alice"]
+member_root = "ledgers"
+
+[window]
+since = 2025-01-01
+until = 2025-04-01
+
+[aliases]
+alice = "Alice File"
+bob = "Bob File"
+"#,
+    );
+    let env = TeamConfigOverrides::from_env_with(|name| match name {
+        "SHIPLOG_TEAM_MEMBERS" => Some("alice,bob".into()),
+        "SHIPLOG_TEAM_ALIASES" => Some("bob=Bob Env".into()),
+        "SHIPLOG_TEAM_UNTIL" => Some("2025-07-01".into()),
+        "SHIPLOG_TEAM_PARALLELISM" => Some("3".into()),
+        _ => None,
+    })
+    .unwrap();
+    let cli = TeamConfigOverrides {
+        until: NaiveDate::from_ymd_opt(2025, 10, 1),
+        parallelism: Some(1),
+        ..TeamConfigOverrides::default()
+    };
+
+    let cfg = resolve_layered_team_config(Some(&path), env, cli).unwrap();
+    assert_eq!(cfg.members, vec!["alice", "bob"]);
+    assert_eq!(cfg.aliases["alice"], "Alice File");
+    assert_eq!(cfg.aliases["bob"], "Bob Env");
+    assert_eq!(cfg.since, NaiveDate::from_ymd_opt(2025, 1, 1));
+    assert_eq!(cfg.until, NaiveDate::from_ymd_opt(2025, 10, 1));
+    assert_eq!(cfg.parallelism, Some(1));
+    assert_eq!(cfg.member_root, Some(dir.path().join("ledgers")));
+}
+
+#[test]
+fn layered_date_range_error_names_each_layer() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_team_toml(dir.path(), "[window]\nsince = 2025-03-01\n");
+    let env = TeamConfigOverrides::from_env_with(|name| {
+        (name == "SHIPLOG_TEAM_UNTIL").then(|| "2025-02-01".into())
+    })
+    .unwrap();
+
+    let err = resolve_layered_team_config(Some(&path), env, TeamConfigOverrides::default())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Invalid date range"), "{err}");
+    assert!(err.contains("from SHIPLOG_TEAM_UNTIL"), "{err}");
+    assert!(err.contains("`window.since`"), "{err}");
+}
+
+#[test]
+fn env_overrides_reject_bad_values_by_name() {
+    for (name, value) in [
+        ("SHIPLOG_TEAM_UNTIL", "next week"),
+        ("SHIPLOG_TEAM_PARALLELISM", "0"),
+        ("SHIPLOG_TEAM_ALIASES", "=Nobody"),
+    ] {
+        assert!(TEAM_ENV_VARS.contains(&name));
+        let err = TeamConfigOverrides::from_env_with(|var| (var == name).then(|| value.into()))
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with(name), "{name}={value:?} -> {err}");
+    }
+}
+
 // ---------------------------------------------------------------------------
 // proptest: parse_csv_list properties
 // ---------------------------------------------------------------------------
@@ -10,6 +10,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use std::io::Write;
 use std::path::Path;
 
@@ -25,6 +26,7 @@ use shiplog::team::{
 
 fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["team", id]),
         kind: EventKind::PullRequest,
         occurred_at: when,
@@ -59,11 +61,15 @@ fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelop
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: shiplog::ids::RunId("facade-test".into()),
         generated_at: Utc::now(),
         user: "member".into(),
@@ -76,6 +82,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -165,5 +174,7 @@ fn facade_type_team_member_summary_accessible() {
         ledger_dir: std::path::PathBuf::from("test"),
         event_count: 0,
         warnings: vec![],
+        kind_counts: Default::default(),
+        top_repos: vec![],
     };
 }
@@ -7,6 +7,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::team::TeamConfig;
 use shiplog::team::{TeamAggregateResult, TeamMemberSummary, render_packet_markdown};
 use shiplog_testkit::bdd::{Scenario, assertions};
@@ -18,6 +19,7 @@ use std::path::PathBuf;
 
 fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["team", id]),
         kind: EventKind::PullRequest,
         occurred_at: when,
@@ -52,11 +54,15 @@ fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelop
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("test-run".to_string()),
         generated_at: Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap(),
         user: "team".to_string(),
@@ -69,6 +75,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -87,6 +96,8 @@ fn sample_result() -> TeamAggregateResult {
                 ledger_dir: PathBuf::from("alice"),
                 event_count: 2,
                 warnings: vec![],
+                kind_counts: Default::default(),
+                top_repos: vec![],
             },
             TeamMemberSummary {
                 member: "bob".into(),
@@ -95,6 +106,8 @@ fn sample_result() -> TeamAggregateResult {
                 ledger_dir: PathBuf::from("bob"),
                 event_count: 1,
                 warnings: vec![],
+                kind_counts: Default::default(),
+                top_repos: vec![],
             },
         ],
         coverage: make_coverage(),
@@ -7,6 +7,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::team::TeamConfig;
 use shiplog::team::{TeamAggregateResult, TeamMemberSummary, render_packet_markdown};
 use std::path::PathBuf;
@@ -17,6 +18,7 @@ use std::path::PathBuf;
 
 fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["team", id]),
         kind: EventKind::PullRequest,
         occurred_at: when,
@@ -51,11 +53,15 @@ fn make_event(id: &str, repo: &str, when: chrono::DateTime<Utc>) -> EventEnvelop
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_coverage() -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId("test-run".to_string()),
         generated_at: Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap(),
         user: "team".to_string(),
@@ -68,6 +74,9 @@ fn make_coverage() -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -85,6 +94,8 @@ fn make_result(events: Vec<EventEnvelope>, warnings: Vec<String>) -> TeamAggrega
                 ledger_dir: PathBuf::from("alice"),
                 event_count: events.len(),
                 warnings: vec![],
+                kind_counts: Default::default(),
+                top_repos: vec![],
             },
             TeamMemberSummary {
                 member: "bob".into(),
@@ -93,6 +104,8 @@ fn make_result(events: Vec<EventEnvelope>, warnings: Vec<String>) -> TeamAggrega
                 ledger_dir: PathBuf::from("bob"),
                 event_count: 0,
                 warnings: vec!["Missing ledger".to_string()],
+                kind_counts: Default::default(),
+                top_repos: vec![],
             },
         ],
         coverage: make_coverage(),
@@ -206,6 +219,8 @@ fn no_warnings_section_when_no_warnings() {
             ledger_dir: PathBuf::from("alice"),
             event_count: 1,
             warnings: vec![],
+            kind_counts: Default::default(),
+            top_repos: vec![],
         }],
         coverage: make_coverage(),
         events: vec![make_event("e1", "org/repo", when)],
@@ -319,6 +334,8 @@ fn member_summary_json_round_trip() {
         ledger_dir: PathBuf::from("alice"),
         event_count: 5,
         warnings: vec!["something".into()],
+        kind_counts: Default::default(),
+        top_repos: vec![],
     };
     let json = serde_json::to_string(&summary).unwrap();
     let back: TeamMemberSummary = serde_json::from_str(&json).unwrap();
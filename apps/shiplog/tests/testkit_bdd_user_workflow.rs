@@ -12,6 +12,7 @@ mod user_workflow_tests {
     use shiplog::redact::DeterministicRedactor;
     use shiplog::schema::bundle::BundleProfile;
     use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
+    use shiplog::schema::version::SchemaVersion;
     use shiplog::workstreams::RepoClusterer;
     use shiplog_testkit::TestMarkdownRenderer as MarkdownRenderer;
     use shiplog_testkit::bdd::Scenario;
@@ -22,6 +23,7 @@ mod user_workflow_tests {
 
     fn make_coverage(user: &str, completeness: Completeness) -> CoverageManifest {
         CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: RunId::now("bdd-test"),
             generated_at: Utc::now(),
             user: user.to_string(),
@@ -34,6 +36,9 @@ mod user_workflow_tests {
             slices: vec![],
             warnings: vec![],
             completeness,
+            score: None,
+            timezone: None,
+            budget: vec![],
         }
     }
 
@@ -84,6 +89,7 @@ mod user_workflow_tests {
                     events,
                     coverage,
                     freshness: Vec::new(),
+                    budget: Vec::new(),
                 };
                 let dir = tempfile::tempdir().unwrap();
                 let out_dir = dir.path().join("happy_path_run");
@@ -246,6 +252,7 @@ mod user_workflow_tests {
                             events,
                             coverage,
                             freshness: Vec::new(),
+                            budget: Vec::new(),
                         };
                         let engine = build_engine();
                         let (outputs, ws_source) = engine
@@ -482,6 +489,7 @@ mod user_workflow_tests {
                     events,
                     coverage,
                     freshness: Vec::new(),
+                    budget: Vec::new(),
                 };
                 let dir = tempfile::tempdir().unwrap();
                 let out_dir = dir.path().join("empty_run");
@@ -575,6 +583,7 @@ mod user_workflow_tests {
                         fetched,
                         incomplete_results: Some(fetched < total),
                         notes: vec![],
+                        source: None,
                     });
                 }
 
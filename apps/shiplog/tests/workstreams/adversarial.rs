@@ -8,6 +8,7 @@ use shiplog::workstreams::RepoClusterer;
 
 fn make_pr_event(repo: &str, id_suffix: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["test", id_suffix]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -39,11 +40,15 @@ fn make_pr_event(repo: &str, id_suffix: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_review_event(repo: &str, id_suffix: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["review", id_suffix]),
         kind: EventKind::Review,
         occurred_at: Utc::now(),
@@ -62,6 +67,8 @@ fn make_review_event(repo: &str, id_suffix: &str) -> EventEnvelope {
             submitted_at: Utc::now(),
             state: "approved".into(),
             window: None,
+            pull_created_at: None,
+            pull_author: None,
         }),
         tags: vec![],
         links: vec![],
@@ -70,11 +77,15 @@ fn make_review_event(repo: &str, id_suffix: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn make_manual_event(repo: &str, id_suffix: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["manual", id_suffix]),
         kind: EventKind::Manual,
         occurred_at: Utc::now(),
@@ -94,6 +105,9 @@ fn make_manual_event(repo: &str, id_suffix: &str) -> EventEnvelope {
             started_at: None,
             ended_at: None,
             impact: None,
+            effort: None,
+            metrics: Vec::new(),
+            evidence: Vec::new(),
         }),
         tags: vec![],
         links: vec![],
@@ -102,6 +116,9 @@ fn make_manual_event(repo: &str, id_suffix: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -17,6 +17,7 @@ use shiplog_testkit::bdd::assertions::*;
 
 fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["bdd", id_suffix]),
         kind: kind.clone(),
         occurred_at: Utc::now(),
@@ -48,6 +49,8 @@ fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> Even
                 submitted_at: Utc::now(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             EventKind::Manual => EventPayload::Manual(ManualEvent {
                 event_type: ManualEventType::Note,
@@ -56,6 +59,64 @@ fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> Even
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+            }),
+            EventKind::Issue => EventPayload::Issue(IssueEvent {
+                number,
+                title: format!("Issue #{number}"),
+                state: IssueState::Closed,
+                labels: vec![],
+                opened_at: Utc::now(),
+                closed_at: Some(Utc::now()),
+                window: None,
+                effort: None,
+            }),
+            EventKind::Deployment => EventPayload::Deployment(DeploymentEvent {
+                environment: "production".into(),
+                version: Some(format!("v{number}")),
+                status: DeploymentStatus::Succeeded,
+                started_at: Utc::now(),
+                finished_at: Some(Utc::now()),
+                window: None,
+            }),
+            EventKind::Release => EventPayload::Release(ReleaseEvent {
+                version: format!("v{number}"),
+                name: None,
+                status: ReleaseStatus::Published,
+                created_at: Utc::now(),
+                published_at: Some(Utc::now()),
+                window: None,
+            }),
+            EventKind::Comment => EventPayload::Comment(CommentEvent {
+                target: CommentTarget::PullRequest,
+                target_number: Some(number),
+                target_title: format!("Thread #{number}"),
+                thread: None,
+                count: 1,
+                first_at: Utc::now(),
+                last_at: Utc::now(),
+                window: None,
+            }),
+            EventKind::Incident => EventPayload::Incident(IncidentEvent {
+                title: format!("Incident #{number}"),
+                severity: IncidentSeverity::Unknown,
+                role: IncidentRole::Unknown,
+                detected_at: Utc::now(),
+                resolved_at: None,
+                duration_minutes: None,
+                summary: None,
+                customer_impact: None,
+                window: None,
+            }),
+            EventKind::Document => EventPayload::Document(DocumentEvent {
+                doc_type: DocumentType::Design,
+                title: format!("Doc #{number}"),
+                system: "confluence".to_string(),
+                last_edited_at: Utc::now(),
+                word_count_delta: None,
+                window: None,
             }),
         },
         tags: vec![],
@@ -65,6 +126,9 @@ fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> Even
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -80,6 +144,8 @@ fn make_workstreams(title: &str) -> WorkstreamsFile {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     }
 }
@@ -13,6 +13,7 @@ use shiplog::workstreams::WORKSTREAM_RECEIPT_LIMIT_TOTAL;
 
 fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["edge", id]),
         kind: kind.clone(),
         occurred_at: Utc::now(),
@@ -44,6 +45,8 @@ fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
                 submitted_at: Utc::now(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             EventKind::Manual => EventPayload::Manual(ManualEvent {
                 event_type: ManualEventType::Note,
@@ -52,6 +55,64 @@ fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+            }),
+            EventKind::Issue => EventPayload::Issue(IssueEvent {
+                number,
+                title: format!("Issue #{number}"),
+                state: IssueState::Closed,
+                labels: vec![],
+                opened_at: Utc::now(),
+                closed_at: Some(Utc::now()),
+                window: None,
+                effort: None,
+            }),
+            EventKind::Deployment => EventPayload::Deployment(DeploymentEvent {
+                environment: "production".into(),
+                version: Some(format!("v{number}")),
+                status: DeploymentStatus::Succeeded,
+                started_at: Utc::now(),
+                finished_at: Some(Utc::now()),
+                window: None,
+            }),
+            EventKind::Release => EventPayload::Release(ReleaseEvent {
+                version: format!("v{number}"),
+                name: None,
+                status: ReleaseStatus::Published,
+                created_at: Utc::now(),
+                published_at: Some(Utc::now()),
+                window: None,
+            }),
+            EventKind::Comment => EventPayload::Comment(CommentEvent {
+                target: CommentTarget::PullRequest,
+                target_number: Some(number),
+                target_title: format!("Thread #{number}"),
+                thread: None,
+                count: 1,
+                first_at: Utc::now(),
+                last_at: Utc::now(),
+                window: None,
+            }),
+            EventKind::Incident => EventPayload::Incident(IncidentEvent {
+                title: format!("Incident #{number}"),
+                severity: IncidentSeverity::Unknown,
+                role: IncidentRole::Unknown,
+                detected_at: Utc::now(),
+                resolved_at: None,
+                duration_minutes: None,
+                summary: None,
+                customer_impact: None,
+                window: None,
+            }),
+            EventKind::Document => EventPayload::Document(DocumentEvent {
+                doc_type: DocumentType::Design,
+                title: format!("Doc #{number}"),
+                system: "confluence".to_string(),
+                last_edited_at: Utc::now(),
+                word_count_delta: None,
+                window: None,
             }),
         },
         tags: vec![],
@@ -61,6 +122,9 @@ fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -235,6 +299,8 @@ impl WorkstreamClusterer for SingleBucketClusterer {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         };
 
         for ev in events {
@@ -10,6 +10,7 @@ use shiplog::workstreams::{
 
 fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: shiplog::ids::EventId::from_parts(["integration", id]),
         kind: kind.clone(),
         occurred_at: chrono::Utc::now(),
@@ -41,6 +42,8 @@ fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
                 submitted_at: chrono::Utc::now(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             EventKind::Manual => EventPayload::Manual(ManualEvent {
                 event_type: ManualEventType::Note,
@@ -49,6 +52,64 @@ fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+            }),
+            EventKind::Issue => EventPayload::Issue(IssueEvent {
+                number,
+                title: "Integration issue".into(),
+                state: IssueState::Closed,
+                labels: vec![],
+                opened_at: chrono::Utc::now(),
+                closed_at: Some(chrono::Utc::now()),
+                window: None,
+                effort: None,
+            }),
+            EventKind::Deployment => EventPayload::Deployment(DeploymentEvent {
+                environment: "production".into(),
+                version: Some(format!("v{number}")),
+                status: DeploymentStatus::Succeeded,
+                started_at: chrono::Utc::now(),
+                finished_at: Some(chrono::Utc::now()),
+                window: None,
+            }),
+            EventKind::Release => EventPayload::Release(ReleaseEvent {
+                version: format!("v{number}"),
+                name: None,
+                status: ReleaseStatus::Published,
+                created_at: chrono::Utc::now(),
+                published_at: Some(chrono::Utc::now()),
+                window: None,
+            }),
+            EventKind::Comment => EventPayload::Comment(CommentEvent {
+                target: CommentTarget::PullRequest,
+                target_number: Some(number),
+                target_title: format!("Thread #{number}"),
+                thread: None,
+                count: 1,
+                first_at: chrono::Utc::now(),
+                last_at: chrono::Utc::now(),
+                window: None,
+            }),
+            EventKind::Incident => EventPayload::Incident(IncidentEvent {
+                title: format!("Incident #{number}"),
+                severity: IncidentSeverity::Unknown,
+                role: IncidentRole::Unknown,
+                detected_at: chrono::Utc::now(),
+                resolved_at: None,
+                duration_minutes: None,
+                summary: None,
+                customer_impact: None,
+                window: None,
+            }),
+            EventKind::Document => EventPayload::Document(DocumentEvent {
+                doc_type: DocumentType::Design,
+                title: format!("Doc #{number}"),
+                system: "confluence".to_string(),
+                last_edited_at: chrono::Utc::now(),
+                word_count_delta: None,
+                window: None,
             }),
         },
         tags: vec![],
@@ -58,6 +119,9 @@ fn event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
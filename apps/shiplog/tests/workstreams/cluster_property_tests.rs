@@ -84,7 +84,15 @@ proptest! {
     fn prop_stats_sum_matches_event_count(events in strategy_event_vec(50)) {
         let workstreams = clustered_workstreams(&events);
         for ws in &workstreams {
-            let stat_total = ws.stats.pull_requests + ws.stats.reviews + ws.stats.manual_events;
+            let stat_total = ws.stats.pull_requests
+                + ws.stats.reviews
+                + ws.stats.manual_events
+                + ws.stats.issues
+                + ws.stats.deployments
+                + ws.stats.releases
+                + ws.stats.comments
+                + ws.stats.incidents
+                + ws.stats.documents;
             prop_assert_eq!(stat_total, ws.events.len());
         }
     }
@@ -12,6 +12,7 @@ fn fixed_event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvel
         .single()
         .unwrap();
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["snap", id]),
         kind: kind.clone(),
         occurred_at: ts,
@@ -43,6 +44,8 @@ fn fixed_event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvel
                 submitted_at: ts,
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             EventKind::Manual => EventPayload::Manual(ManualEvent {
                 event_type: ManualEventType::Note,
@@ -51,6 +54,64 @@ fn fixed_event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvel
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+            }),
+            EventKind::Issue => EventPayload::Issue(IssueEvent {
+                number,
+                title: format!("Issue #{number}"),
+                state: IssueState::Closed,
+                labels: vec![],
+                opened_at: ts,
+                closed_at: Some(ts),
+                window: None,
+                effort: None,
+            }),
+            EventKind::Deployment => EventPayload::Deployment(DeploymentEvent {
+                environment: "production".into(),
+                version: Some(format!("v{number}")),
+                status: DeploymentStatus::Succeeded,
+                started_at: ts,
+                finished_at: Some(ts),
+                window: None,
+            }),
+            EventKind::Release => EventPayload::Release(ReleaseEvent {
+                version: format!("v{number}"),
+                name: None,
+                status: ReleaseStatus::Published,
+                created_at: ts,
+                published_at: Some(ts),
+                window: None,
+            }),
+            EventKind::Comment => EventPayload::Comment(CommentEvent {
+                target: CommentTarget::PullRequest,
+                target_number: Some(number),
+                target_title: format!("Thread #{number}"),
+                thread: None,
+                count: 1,
+                first_at: ts,
+                last_at: ts,
+                window: None,
+            }),
+            EventKind::Incident => EventPayload::Incident(IncidentEvent {
+                title: format!("Incident #{number}"),
+                severity: IncidentSeverity::Unknown,
+                role: IncidentRole::Unknown,
+                detected_at: ts,
+                resolved_at: None,
+                duration_minutes: None,
+                summary: None,
+                customer_impact: None,
+                window: None,
+            }),
+            EventKind::Document => EventPayload::Document(DocumentEvent {
+                doc_type: DocumentType::Design,
+                title: format!("Doc #{number}"),
+                system: "confluence".to_string(),
+                last_edited_at: ts,
+                word_count_delta: None,
+                window: None,
             }),
         },
         tags: vec![],
@@ -60,6 +121,9 @@ fn fixed_event(repo: &str, id: &str, number: u64, kind: EventKind) -> EventEnvel
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -18,6 +18,7 @@ use tempfile::tempdir;
 
 fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["edge", id_suffix]),
         kind: kind.clone(),
         occurred_at: Utc::now(),
@@ -49,6 +50,8 @@ fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> Even
                 submitted_at: Utc::now(),
                 state: "approved".into(),
                 window: None,
+                pull_created_at: None,
+                pull_author: None,
             }),
             EventKind::Manual => EventPayload::Manual(ManualEvent {
                 event_type: ManualEventType::Note,
@@ -57,6 +60,64 @@ fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> Even
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
+            }),
+            EventKind::Issue => EventPayload::Issue(IssueEvent {
+                number,
+                title: format!("Issue #{number}"),
+                state: IssueState::Closed,
+                labels: vec![],
+                opened_at: Utc::now(),
+                closed_at: Some(Utc::now()),
+                window: None,
+                effort: None,
+            }),
+            EventKind::Deployment => EventPayload::Deployment(DeploymentEvent {
+                environment: "production".into(),
+                version: Some(format!("v{number}")),
+                status: DeploymentStatus::Succeeded,
+                started_at: Utc::now(),
+                finished_at: Some(Utc::now()),
+                window: None,
+            }),
+            EventKind::Release => EventPayload::Release(ReleaseEvent {
+                version: format!("v{number}"),
+                name: None,
+                status: ReleaseStatus::Published,
+                created_at: Utc::now(),
+                published_at: Some(Utc::now()),
+                window: None,
+            }),
+            EventKind::Comment => EventPayload::Comment(CommentEvent {
+                target: CommentTarget::PullRequest,
+                target_number: Some(number),
+                target_title: format!("Thread #{number}"),
+                thread: None,
+                count: 1,
+                first_at: Utc::now(),
+                last_at: Utc::now(),
+                window: None,
+            }),
+            EventKind::Incident => EventPayload::Incident(IncidentEvent {
+                title: format!("Incident #{number}"),
+                severity: IncidentSeverity::Unknown,
+                role: IncidentRole::Unknown,
+                detected_at: Utc::now(),
+                resolved_at: None,
+                duration_minutes: None,
+                summary: None,
+                customer_impact: None,
+                window: None,
+            }),
+            EventKind::Document => EventPayload::Document(DocumentEvent {
+                doc_type: DocumentType::Design,
+                title: format!("Doc #{number}"),
+                system: "confluence".to_string(),
+                last_edited_at: Utc::now(),
+                word_count_delta: None,
+                window: None,
             }),
         },
         tags: vec![],
@@ -66,6 +127,9 @@ fn make_event(repo: &str, id_suffix: &str, number: u64, kind: EventKind) -> Even
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -81,6 +145,8 @@ fn make_ws_file(title: &str, events: Vec<EventId>) -> WorkstreamsFile {
             stats: WorkstreamStats::zero(),
             events,
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     }
 }
@@ -104,9 +170,19 @@ fn yaml_roundtrip_with_special_chars_in_title() {
                 pull_requests: 2,
                 reviews: 1,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -150,6 +226,8 @@ fn yaml_roundtrip_with_unicode_repo_names() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["repo", "орг/проект"]),
@@ -159,6 +237,8 @@ fn yaml_roundtrip_with_unicode_repo_names() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -194,9 +274,19 @@ fn curated_completely_replaces_suggested_content() {
                     pull_requests: 5,
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![EventId::from_parts(["e", "1"])],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["repo", "b"]),
@@ -206,6 +296,8 @@ fn curated_completely_replaces_suggested_content() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -223,9 +315,19 @@ fn curated_completely_replaces_suggested_content() {
                 pull_requests: 10,
                 reviews: 3,
                 manual_events: 1,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![EventId::from_parts(["e", "1"])],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -260,6 +362,8 @@ fn suggested_is_preserved_when_curated_absent() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["repo", "b"]),
@@ -269,6 +373,8 @@ fn suggested_is_preserved_when_curated_absent() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -355,6 +461,8 @@ fn stats_zero_for_empty_workstream_from_yaml() {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -496,9 +604,19 @@ fn curated_yaml_with_many_workstreams_roundtrips() {
                 pull_requests: i,
                 reviews: i / 2,
                 manual_events: i % 3,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![EventId::from_parts(["e", &format!("{i}")])],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         })
         .collect();
 
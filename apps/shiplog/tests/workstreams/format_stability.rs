@@ -32,6 +32,14 @@ fn format_stability_workstreams_yaml_full() {
                     pull_requests: 8,
                     reviews: 12,
                     manual_events: 2,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![
                     EventId::from_parts(["github", "pr", "acme/widgets", "10"]),
@@ -42,6 +50,8 @@ fn format_stability_workstreams_yaml_full() {
                     EventId::from_parts(["github", "pr", "acme/widgets", "10"]),
                     EventId::from_parts(["github", "pr", "acme/widgets", "11"]),
                 ],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["repo", "acme/infra"]),
@@ -52,9 +62,19 @@ fn format_stability_workstreams_yaml_full() {
                     pull_requests: 3,
                     reviews: 1,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![EventId::from_parts(["github", "pr", "acme/infra", "5"])],
                 receipts: vec![EventId::from_parts(["github", "pr", "acme/infra", "5"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -87,12 +107,22 @@ fn format_stability_workstreams_suggested_yaml() {
                 pull_requests: 5,
                 reviews: 3,
                 manual_events: 1,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![
                 EventId::from_parts(["github", "pr", "acme/api", "1"]),
                 EventId::from_parts(["github", "pr", "acme/api", "2"]),
             ],
             receipts: vec![EventId::from_parts(["github", "pr", "acme/api", "1"])],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -141,6 +171,8 @@ fn format_stability_workstreams_yaml_minimal() {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -170,9 +202,19 @@ fn format_stability_workstreams_yaml_edge_cases() {
                 pull_requests: usize::MAX,
                 reviews: 0,
                 manual_events: usize::MAX,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![EventId::from_parts(["special", "chars", "a&b<c>d"])],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
 
@@ -203,9 +245,19 @@ fn format_stability_workstreams_yaml_roundtrip() {
                     pull_requests: 5,
                     reviews: 3,
                     manual_events: 1,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![EventId::from_parts(["github", "pr", "acme/widgets", "42"])],
                 receipts: vec![EventId::from_parts(["github", "pr", "acme/widgets", "42"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["ws", "perf"]),
@@ -215,6 +267,8 @@ fn format_stability_workstreams_yaml_roundtrip() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
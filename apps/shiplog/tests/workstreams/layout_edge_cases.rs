@@ -33,9 +33,19 @@ fn make_ws_file_with_n(n: usize) -> WorkstreamsFile {
                 pull_requests: i,
                 reviews: 0,
                 manual_events: 0,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![EventId::from_parts(["e", &format!("{i}")])],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         })
         .collect();
 
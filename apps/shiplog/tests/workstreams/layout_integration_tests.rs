@@ -3,6 +3,7 @@
 use chrono::{TimeZone, Utc};
 use shiplog::ids::EventId;
 use shiplog::schema::event::EventEnvelope;
+use shiplog::schema::version::SchemaVersion;
 use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 use shiplog::workstreams::RepoClusterer;
 use shiplog::workstreams::{
@@ -12,6 +13,7 @@ use tempfile::tempdir;
 
 fn make_event(repo_name: &str, id: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", id]),
         kind: shiplog::schema::event::EventKind::PullRequest,
         occurred_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).single().unwrap(),
@@ -45,6 +47,9 @@ fn make_event(repo_name: &str, id: &str) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -60,6 +65,8 @@ fn make_file(title: &str) -> WorkstreamsFile {
             stats: WorkstreamStats::zero(),
             events: vec![EventId::from_parts(["event", title])],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     }
 }
@@ -158,9 +165,19 @@ fn integration_multiple_workstreams_roundtrip() {
                     pull_requests: 3,
                     reviews: 1,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![EventId::from_parts(["e", "1"])],
                 receipts: vec![EventId::from_parts(["e", "1"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: shiplog::ids::WorkstreamId::from_parts(["repo", "b"]),
@@ -170,6 +187,8 @@ fn integration_multiple_workstreams_roundtrip() {
                 stats: WorkstreamStats::zero(),
                 events: vec![],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -41,6 +41,8 @@ fn workstream() -> impl Strategy<Value = Workstream> {
             stats: WorkstreamStats::zero(),
             events,
             receipts,
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         })
 }
 
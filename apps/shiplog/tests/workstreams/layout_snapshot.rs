@@ -24,12 +24,22 @@ fn fixed_ws_file() -> WorkstreamsFile {
                     pull_requests: 5,
                     reviews: 2,
                     manual_events: 1,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![
                     EventId::from_parts(["github", "pr1"]),
                     EventId::from_parts(["github", "pr2"]),
                 ],
                 receipts: vec![EventId::from_parts(["github", "pr1"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["repo", "acme/lib"]),
@@ -40,9 +50,19 @@ fn fixed_ws_file() -> WorkstreamsFile {
                     pull_requests: 1,
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![EventId::from_parts(["github", "lib1"])],
                 receipts: vec![EventId::from_parts(["github", "lib1"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     }
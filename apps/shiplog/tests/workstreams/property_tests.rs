@@ -88,6 +88,12 @@ proptest! {
             let mut pr_count = 0;
             let mut review_count = 0;
             let mut manual_count = 0;
+            let mut issue_count = 0;
+            let mut deployment_count = 0;
+            let mut release_count = 0;
+            let mut comment_count = 0;
+            let mut incident_count = 0;
+            let mut document_count = 0;
 
             for event_id in &ws.events {
                 if let Some(event) = events.iter().find(|e| &e.id == event_id) {
@@ -95,6 +101,12 @@ proptest! {
                         shiplog::schema::event::EventKind::PullRequest => pr_count += 1,
                         shiplog::schema::event::EventKind::Review => review_count += 1,
                         shiplog::schema::event::EventKind::Manual => manual_count += 1,
+                        shiplog::schema::event::EventKind::Issue => issue_count += 1,
+                        shiplog::schema::event::EventKind::Deployment => deployment_count += 1,
+                        shiplog::schema::event::EventKind::Release => release_count += 1,
+                        shiplog::schema::event::EventKind::Comment => comment_count += 1,
+                        shiplog::schema::event::EventKind::Incident => incident_count += 1,
+                        shiplog::schema::event::EventKind::Document => document_count += 1,
                     }
                 }
             }
@@ -103,6 +115,12 @@ proptest! {
             prop_assert_eq!(ws.stats.pull_requests, pr_count);
             prop_assert_eq!(ws.stats.reviews, review_count);
             prop_assert_eq!(ws.stats.manual_events, manual_count);
+            prop_assert_eq!(ws.stats.issues, issue_count);
+            prop_assert_eq!(ws.stats.deployments, deployment_count);
+            prop_assert_eq!(ws.stats.releases, release_count);
+            prop_assert_eq!(ws.stats.comments, comment_count);
+            prop_assert_eq!(ws.stats.incidents, incident_count);
+            prop_assert_eq!(ws.stats.documents, document_count);
         }
     }
 
@@ -3,18 +3,27 @@
 use proptest::prelude::*;
 use shiplog::schema::event::EventKind;
 use shiplog::workstreams::{
-    WORKSTREAM_RECEIPT_LIMIT_MANUAL, WORKSTREAM_RECEIPT_LIMIT_REVIEW,
+    WORKSTREAM_RECEIPT_LIMIT_COMMENT, WORKSTREAM_RECEIPT_LIMIT_DEPLOYMENT,
+    WORKSTREAM_RECEIPT_LIMIT_DOCUMENT, WORKSTREAM_RECEIPT_LIMIT_INCIDENT,
+    WORKSTREAM_RECEIPT_LIMIT_ISSUE, WORKSTREAM_RECEIPT_LIMIT_MANUAL,
+    WORKSTREAM_RECEIPT_LIMIT_RELEASE, WORKSTREAM_RECEIPT_LIMIT_REVIEW,
     WORKSTREAM_RECEIPT_LIMIT_TOTAL, WORKSTREAM_RECEIPT_RENDER_LIMIT, max_cluster_receipts_for_kind,
     should_include_cluster_receipt, should_render_receipt_at, truncate_cluster_receipts,
 };
 
 proptest! {
     #[test]
-    fn prop_cluster_receipt_boundary_is_kind_specific(kind_code in 0u8..3, count in 0usize..64) {
+    fn prop_cluster_receipt_boundary_is_kind_specific(kind_code in 0u8..9, count in 0usize..64) {
         let kind = match kind_code {
             0 => EventKind::PullRequest,
             1 => EventKind::Review,
-            _ => EventKind::Manual,
+            2 => EventKind::Manual,
+            3 => EventKind::Issue,
+            4 => EventKind::Deployment,
+            5 => EventKind::Release,
+            6 => EventKind::Comment,
+            7 => EventKind::Incident,
+            _ => EventKind::Document,
         };
 
         let included = should_include_cluster_receipt(&kind, count);
@@ -22,6 +31,12 @@ proptest! {
             EventKind::PullRequest => true,
             EventKind::Review => count < WORKSTREAM_RECEIPT_LIMIT_REVIEW,
             EventKind::Manual => count < WORKSTREAM_RECEIPT_LIMIT_MANUAL,
+            EventKind::Issue => count < WORKSTREAM_RECEIPT_LIMIT_ISSUE,
+            EventKind::Deployment => count < WORKSTREAM_RECEIPT_LIMIT_DEPLOYMENT,
+            EventKind::Release => count < WORKSTREAM_RECEIPT_LIMIT_RELEASE,
+            EventKind::Comment => count < WORKSTREAM_RECEIPT_LIMIT_COMMENT,
+            EventKind::Incident => count < WORKSTREAM_RECEIPT_LIMIT_INCIDENT,
+            EventKind::Document => count < WORKSTREAM_RECEIPT_LIMIT_DOCUMENT,
         };
 
         prop_assert_eq!(included, expected);
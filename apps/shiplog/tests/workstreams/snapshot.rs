@@ -30,6 +30,14 @@ fn workstreams_file_full_yaml() {
                     pull_requests: 8,
                     reviews: 12,
                     manual_events: 2,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![
                     EventId::from_parts(["github", "pr", "acme/widgets", "10"]),
@@ -37,6 +45,8 @@ fn workstreams_file_full_yaml() {
                     EventId::from_parts(["github", "review", "acme/widgets", "10", "approved"]),
                 ],
                 receipts: vec![EventId::from_parts(["github", "pr", "acme/widgets", "10"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
             Workstream {
                 id: WorkstreamId::from_parts(["repo", "acme/infra"]),
@@ -47,9 +57,19 @@ fn workstreams_file_full_yaml() {
                     pull_requests: 3,
                     reviews: 1,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: vec![EventId::from_parts(["github", "pr", "acme/infra", "5"])],
                 receipts: vec![EventId::from_parts(["github", "pr", "acme/infra", "5"])],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             },
         ],
     };
@@ -70,9 +90,19 @@ fn workstreams_file_full_json() {
                 pull_requests: 5,
                 reviews: 3,
                 manual_events: 1,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![EventId::from_parts(["github", "pr", "acme/widgets", "42"])],
             receipts: vec![EventId::from_parts(["github", "pr", "acme/widgets", "42"])],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     insta::assert_json_snapshot!(ws);
@@ -105,6 +135,8 @@ fn workstreams_file_minimal_workstream() {
             stats: WorkstreamStats::zero(),
             events: vec![],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     insta::assert_json_snapshot!(ws);
@@ -128,9 +160,19 @@ fn workstreams_file_edge_cases() {
                 pull_requests: usize::MAX,
                 reviews: 0,
                 manual_events: usize::MAX,
+                issues: 0,
+                deployments: 0,
+                releases: 0,
+                comments: 0,
+                incidents: 0,
+                documents: 0,
+                logged_minutes: 0,
+                elapsed_minutes: 0,
             },
             events: vec![EventId::from_parts([""])],
             receipts: vec![],
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         }],
     };
     insta::assert_json_snapshot!(ws);
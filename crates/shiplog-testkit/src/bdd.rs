@@ -226,6 +226,12 @@ pub mod builders {
                     EventKind::PullRequest => "pr",
                     EventKind::Review => "review",
                     EventKind::Manual => "manual",
+                    EventKind::Issue => "issue",
+                    EventKind::Deployment => "deployment",
+                    EventKind::Release => "release",
+                    EventKind::Comment => "comment",
+                    EventKind::Incident => "incident",
+                    EventKind::Document => "document",
                 },
                 &self.repo,
                 &self.number.to_string(),
@@ -241,6 +247,7 @@ pub mod builders {
 
             match self.kind {
                 EventKind::PullRequest => EventEnvelope {
+                    schema_version: SchemaVersion::CURRENT,
                     id,
                     kind: EventKind::PullRequest,
                     occurred_at,
@@ -271,6 +278,9 @@ pub mod builders {
                         url: None,
                         opaque_id: None,
                     },
+                    contributors: Vec::new(),
+                    extensions: Default::default(),
+                    canonical_id: None,
                 },
                 _ => panic!("Builder not implemented for {:?}", self.kind),
             }
@@ -307,6 +317,7 @@ pub mod builders {
 
         pub fn build(self) -> CoverageManifest {
             CoverageManifest {
+                schema_version: SchemaVersion::CURRENT,
                 run_id: shiplog::ids::RunId::now("test"),
                 generated_at: Utc::now(),
                 user: self.user,
@@ -319,6 +330,9 @@ pub mod builders {
                 slices: vec![],
                 warnings: vec![],
                 completeness: self.completeness,
+                score: None,
+                timezone: None,
+                budget: vec![],
             }
         }
     }
@@ -126,6 +126,8 @@ mod workflow_tests {
                 stats: shiplog::schema::workstream::WorkstreamStats::zero(),
                 events: vec![shiplog::ids::EventId::from_parts(["e", title])],
                 receipts: vec![],
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }],
         }
     }
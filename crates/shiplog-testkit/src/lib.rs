@@ -77,6 +77,12 @@ impl Renderer for TestMarkdownRenderer {
                 EventPayload::PullRequest(pr) => &pr.title,
                 EventPayload::Review(review) => &review.pull_title,
                 EventPayload::Manual(manual) => &manual.title,
+                EventPayload::Issue(issue) => &issue.title,
+                EventPayload::Deployment(deployment) => &deployment.environment,
+                EventPayload::Release(release) => &release.version,
+                EventPayload::Comment(comment) => &comment.target_title,
+                EventPayload::Incident(incident) => &incident.title,
+                EventPayload::Document(document) => &document.title,
             };
             out.push_str(&format!(
                 "- {:?}: {} from {:?} - {}\n",
@@ -102,6 +108,7 @@ mod bdd_later_tests;
 /// render/cluster/redact tests.
 pub fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["github", "pr", repo, &number.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: Utc.timestamp_opt(0, 0).unwrap(),
@@ -139,6 +146,9 @@ pub fn pr_event(repo: &str, number: u64, title: &str) -> EventEnvelope {
             url: Some("https://api.github.com/...".into()),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -200,9 +210,19 @@ pub mod fixtures {
                     pull_requests: self.events.len(),
                     reviews: 0,
                     manual_events: 0,
+                    issues: 0,
+                    deployments: 0,
+                    releases: 0,
+                    comments: 0,
+                    incidents: 0,
+                    documents: 0,
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
                 },
                 events: self.events,
                 receipts: self.receipts,
+                receipt_weights: Default::default(),
+                rules: Default::default(),
             }
         }
     }
@@ -230,6 +250,7 @@ pub mod fixtures {
     /// Creates a coverage manifest for testing
     pub fn test_coverage(user: &str, completeness: Completeness) -> CoverageManifest {
         CoverageManifest {
+            schema_version: SchemaVersion::CURRENT,
             run_id: shiplog::ids::RunId::now("test"),
             generated_at: Utc::now(),
             user: user.to_string(),
@@ -242,6 +263,9 @@ pub mod fixtures {
             slices: vec![],
             warnings: vec![],
             completeness,
+            score: None,
+            timezone: None,
+            budget: vec![],
         }
     }
 }
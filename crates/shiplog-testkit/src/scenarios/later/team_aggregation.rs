@@ -13,6 +13,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::team::TeamAggregator;
 use shiplog::team::{TeamConfig, parse_alias_list, parse_csv_list, resolve_team_config};
 use std::collections::HashMap;
@@ -25,6 +26,7 @@ fn member_event(member: &str, repo: &str, idx: u64) -> EventEnvelope {
         .single()
         .expect("valid timestamp");
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["team", member, &idx.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: when,
@@ -59,11 +61,15 @@ fn member_event(member: &str, repo: &str, idx: u64) -> EventEnvelope {
             url: Some(format!("https://github.com/{repo}/pull/{idx}")),
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
 fn member_coverage(member: &str) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("team"),
         generated_at: Utc::now(),
         user: member.to_string(),
@@ -76,6 +82,9 @@ fn member_coverage(member: &str) -> CoverageManifest {
         slices: vec![],
         warnings: vec![],
         completeness: Completeness::Complete,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -271,6 +280,7 @@ pub fn team_aggregate_runtime_constructor_contract() -> Scenario {
                 since: None,
                 until: None,
                 required_schema_version: None,
+                ..TeamConfig::default()
             };
 
             let _aggregator = TeamAggregator::new(cfg);
@@ -14,7 +14,7 @@ use crate::bdd::builders::CoverageBuilder;
 #[cfg(feature = "merge_pipeline")]
 use crate::bdd::builders::EventBuilder;
 use chrono::{NaiveDate, TimeZone, Utc};
-use shiplog::ids::{EventId, RunId};
+use shiplog::ids::{EventId, OpaqueId, RunId};
 use shiplog::merge::{ConflictResolution, MergeStrategy, merge_events, merge_ingest_outputs};
 use shiplog::ports::{IngestOutput, Renderer, WorkstreamClusterer};
 use shiplog::schema::coverage::{Completeness, CoverageManifest, CoverageSlice, TimeWindow};
@@ -22,6 +22,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, PullRequestEvent, PullRequestState, RepoRef,
     RepoVisibility, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::workstreams::RepoClusterer;
 use std::collections::BTreeSet;
 
@@ -50,6 +51,7 @@ fn source_event(
 ) -> EventEnvelope {
     let when = timestamp(offset_seconds);
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["bdd", "multi-source", id_key]),
         kind: EventKind::PullRequest,
         occurred_at: when,
@@ -82,8 +84,11 @@ fn source_event(
         source: SourceRef {
             system: source_system(source),
             url: Some(format!("https://example.test/{source}/{number}")),
-            opaque_id: Some(format!("{source}-{number}")),
+            opaque_id: Some(OpaqueId::from_raw("test", &format!("{source}-{number}"))),
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
@@ -114,6 +119,7 @@ fn coverage_for(
     warning: Option<&str>,
 ) -> CoverageManifest {
     CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: RunId::now("bdd"),
         generated_at: Utc::now(),
         user: "bdd-user".to_string(),
@@ -133,9 +139,13 @@ fn coverage_for(
             fetched: 1,
             incomplete_results: Some(false),
             notes: vec![],
+            source: None,
         }],
         warnings: warning.map(|w| vec![w.to_string()]).unwrap_or_default(),
         completeness,
+        score: None,
+        timezone: None,
+        budget: vec![],
     }
 }
 
@@ -266,6 +276,7 @@ pub fn multi_source_merge_coverage() -> Scenario {
                     Some("github coverage warning"),
                 ),
                 freshness: Vec::new(),
+                budget: Vec::new(),
             };
             let input_b = IngestOutput {
                 events: vec![source_event(
@@ -282,6 +293,7 @@ pub fn multi_source_merge_coverage() -> Scenario {
                     Some("local_git coverage warning"),
                 ),
                 freshness: Vec::new(),
+                budget: Vec::new(),
             };
             let input_c = IngestOutput {
                 events: vec![source_event(
@@ -294,6 +306,7 @@ pub fn multi_source_merge_coverage() -> Scenario {
                 )],
                 coverage: coverage_for("manual", Completeness::Complete, None),
                 freshness: Vec::new(),
+                budget: Vec::new(),
             };
 
             let merged = merge_ingest_outputs(
@@ -423,11 +436,13 @@ pub fn multi_source_merge_conflicts() -> Scenario {
                 events: vec![older],
                 coverage: coverage_for("github", Completeness::Complete, None),
                 freshness: Vec::new(),
+                budget: Vec::new(),
             };
             let input_b = IngestOutput {
                 events: vec![newer],
                 coverage: coverage_for("local_git", Completeness::Complete, None),
                 freshness: Vec::new(),
+                budget: Vec::new(),
             };
             let merged =
                 merge_ingest_outputs(&[input_a, input_b], ConflictResolution::PreferMostRecent)
@@ -665,6 +680,7 @@ pub fn multi_source_render() -> Scenario {
                 .cluster(&events)
                 .map_err(|err| format!("repo clustering failed: {err}"))?;
             let coverage = CoverageManifest {
+                schema_version: SchemaVersion::CURRENT,
                 run_id: RunId::now("render"),
                 generated_at: Utc::now(),
                 user: "bdd-user".to_string(),
@@ -681,6 +697,9 @@ pub fn multi_source_render() -> Scenario {
                 slices: vec![],
                 warnings: vec![],
                 completeness: Completeness::Complete,
+                score: None,
+                timezone: None,
+                budget: vec![],
             };
 
             let packet = MarkdownRenderer::new()
@@ -791,11 +810,13 @@ pub fn multi_source_merge_pipeline_contract() -> Scenario {
                     events: vec![event_a, event_c],
                     coverage: coverage_a,
                     freshness: Vec::new(),
+                    budget: Vec::new(),
                 };
                 let input_b = IngestOutput {
                     events: vec![event_b],
                     coverage: coverage_b,
                     freshness: Vec::new(),
+                    budget: Vec::new(),
                 };
 
                 let merged =
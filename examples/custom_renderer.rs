@@ -73,6 +73,12 @@ impl Renderer for PlainTextRenderer {
                 EventPayload::PullRequest(pr) => &pr.title,
                 EventPayload::Review(r) => &r.pull_title,
                 EventPayload::Manual(m) => &m.title,
+                EventPayload::Issue(issue) => &issue.title,
+                EventPayload::Deployment(deployment) => &deployment.environment,
+                EventPayload::Release(release) => &release.version,
+                EventPayload::Comment(comment) => &comment.target_title,
+                EventPayload::Incident(incident) => &incident.title,
+                EventPayload::Document(document) => &document.title,
             };
             out.push_str(&format!(
                 "  [{:?}] {} — {}\n",
@@ -79,6 +79,12 @@ fn print_events(events: &[EventEnvelope]) {
             EventPayload::PullRequest(pr) => pr.title.clone(),
             EventPayload::Review(r) => r.pull_title.clone(),
             EventPayload::Manual(m) => m.title.clone(),
+            EventPayload::Issue(issue) => issue.title.clone(),
+            EventPayload::Deployment(deployment) => deployment.environment.clone(),
+            EventPayload::Release(release) => release.version.clone(),
+            EventPayload::Comment(comment) => comment.target_title.clone(),
+            EventPayload::Incident(incident) => incident.title.clone(),
+            EventPayload::Document(document) => document.title.clone(),
         };
         println!("  Repo:  {}", ev.repo.full_name);
         println!("  Title: {title}");
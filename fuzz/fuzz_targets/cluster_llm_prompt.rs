@@ -8,6 +8,7 @@ use shiplog::schema::event::*;
 
 fn make_event(num: u64) -> EventEnvelope {
     EventEnvelope {
+        schema_version: SchemaVersion::CURRENT,
         id: EventId::from_parts(["cluster", "llm", "prompt", &num.to_string()]),
         kind: EventKind::PullRequest,
         occurred_at: Utc::now(),
@@ -39,6 +40,9 @@ fn make_event(num: u64) -> EventEnvelope {
             url: None,
             opaque_id: None,
         },
+        contributors: Vec::new(),
+        extensions: Default::default(),
+        canonical_id: None,
     }
 }
 
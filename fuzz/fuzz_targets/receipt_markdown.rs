@@ -9,6 +9,7 @@ use shiplog::schema::event::{
     PullRequestEvent, PullRequestState, RepoRef, RepoVisibility, ReviewEvent, SourceRef,
     SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 
 fuzz_target!(|data: &[u8]| {
     let selector = data.first().copied().unwrap_or(0) % 3;
@@ -29,6 +30,7 @@ fuzz_target!(|data: &[u8]| {
         .expect("valid fuzz timestamp");
     let event = match selector {
         0 => EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: shiplog::ids::EventId::from_parts(["fuzz", "pr", "1"]),
             kind: EventKind::PullRequest,
             occurred_at: ts,
@@ -63,8 +65,12 @@ fuzz_target!(|data: &[u8]| {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         },
         1 => EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: shiplog::ids::EventId::from_parts(["fuzz", "review", "1"]),
             kind: EventKind::Review,
             occurred_at: ts,
@@ -83,6 +89,7 @@ fuzz_target!(|data: &[u8]| {
                 submitted_at: ts,
                 state: "approved".to_string(),
                 window: None,
+                pull_author: None,
             }),
             tags: vec![],
             links: vec![Link {
@@ -94,8 +101,12 @@ fuzz_target!(|data: &[u8]| {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         },
         _ => EventEnvelope {
+            schema_version: SchemaVersion::CURRENT,
             id: shiplog::ids::EventId::from_parts(["fuzz", "manual", "1"]),
             kind: EventKind::Manual,
             occurred_at: ts,
@@ -115,6 +126,9 @@ fuzz_target!(|data: &[u8]| {
                 started_at: None,
                 ended_at: None,
                 impact: None,
+                effort: None,
+                metrics: Vec::new(),
+                evidence: Vec::new(),
             }),
             tags: vec![],
             links: vec![Link {
@@ -126,6 +140,9 @@ fuzz_target!(|data: &[u8]| {
                 url: None,
                 opaque_id: None,
             },
+            contributors: Vec::new(),
+            extensions: Default::default(),
+            canonical_id: None,
         },
     };
 
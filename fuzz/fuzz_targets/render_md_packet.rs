@@ -14,6 +14,7 @@ use shiplog::schema::event::{
     Actor, EventEnvelope, EventKind, EventPayload, Link, PullRequestEvent, PullRequestState,
     RepoRef, RepoVisibility, ReviewEvent, SourceRef, SourceSystem,
 };
+use shiplog::schema::version::SchemaVersion;
 use shiplog::schema::workstream::{Workstream, WorkstreamStats, WorkstreamsFile};
 
 fuzz_target!(|data: &[u8]| {
@@ -50,6 +51,7 @@ fuzz_target!(|data: &[u8]| {
 
         let event = match kind_sel {
             0 => EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: shiplog::ids::EventId::from_parts(["fuzz", "pr", &i.to_string()]),
                 kind: EventKind::PullRequest,
                 occurred_at: ts,
@@ -84,8 +86,12 @@ fuzz_target!(|data: &[u8]| {
                     url: None,
                     opaque_id: None,
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             },
             1 => EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: shiplog::ids::EventId::from_parts(["fuzz", "review", &i.to_string()]),
                 kind: EventKind::Review,
                 occurred_at: ts,
@@ -104,6 +110,7 @@ fuzz_target!(|data: &[u8]| {
                     submitted_at: ts,
                     state: "approved".into(),
                     window: None,
+                    pull_author: None,
                 }),
                 tags: vec![],
                 links: vec![],
@@ -112,8 +119,12 @@ fuzz_target!(|data: &[u8]| {
                     url: None,
                     opaque_id: None,
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             },
             _ => EventEnvelope {
+                schema_version: SchemaVersion::CURRENT,
                 id: shiplog::ids::EventId::from_parts(["fuzz", "manual", &i.to_string()]),
                 kind: EventKind::Manual,
                 occurred_at: ts,
@@ -133,6 +144,9 @@ fuzz_target!(|data: &[u8]| {
                     started_at: None,
                     ended_at: None,
                     impact: None,
+                    effort: None,
+                    metrics: Vec::new(),
+                    evidence: Vec::new(),
                 }),
                 tags: vec![],
                 links: vec![],
@@ -141,6 +155,9 @@ fuzz_target!(|data: &[u8]| {
                     url: None,
                     opaque_id: None,
                 },
+                contributors: Vec::new(),
+                extensions: Default::default(),
+                canonical_id: None,
             },
         };
         events.push(event);
@@ -173,9 +190,13 @@ fuzz_target!(|data: &[u8]| {
                     .iter()
                     .filter(|e| matches!(e.kind, EventKind::Manual))
                     .count(),
+                    logged_minutes: 0,
+                    elapsed_minutes: 0,
             },
             events: event_ids.clone(),
             receipts: event_ids,
+            receipt_weights: Default::default(),
+            rules: Default::default(),
         });
     }
 
@@ -192,6 +213,7 @@ fuzz_target!(|data: &[u8]| {
     };
 
     let coverage = CoverageManifest {
+        schema_version: SchemaVersion::CURRENT,
         run_id: shiplog::ids::RunId("fuzz-run".into()),
         generated_at: ts,
         user: "fuzzer".into(),
@@ -201,6 +223,8 @@ fuzz_target!(|data: &[u8]| {
         slices: vec![],
         warnings: vec![],
         completeness,
+        score: None,
+        timezone: None,
     };
 
     let renderer = if order_sel == 0 {